@@ -85,6 +85,8 @@
             CssPropertyType::Filter => CssProperty::Filter(StyleFilterVecValue::$content_type),
             CssPropertyType::BackdropFilter => CssProperty::BackdropFilter(StyleFilterVecValue::$content_type),
             CssPropertyType::TextShadow => CssProperty::TextShadow(StyleBoxShadowValue::$content_type),
+            CssPropertyType::VerticalAlign => CssProperty::VerticalAlign(LayoutVerticalAlignValue::$content_type),
+            CssPropertyType::TextDecoration => CssProperty::TextDecoration(StyleTextDecorationValue::$content_type),
         }
     })}
 
@@ -167,6 +169,8 @@
                 CssProperty::Filter(_) => CssPropertyType::Filter,
                 CssProperty::BackdropFilter(_) => CssPropertyType::BackdropFilter,
                 CssProperty::TextShadow(_) => CssPropertyType::TextShadow,
+                CssProperty::VerticalAlign(_) => CssPropertyType::VerticalAlign,
+                CssProperty::TextDecoration(_) => CssPropertyType::TextDecoration,
             }
         }
 
@@ -250,6 +254,8 @@
         pub const fn filter(input: StyleFilterVec) -> Self { CssProperty::Filter(StyleFilterVecValue::Exact(input)) }
         pub const fn backdrop_filter(input: StyleFilterVec) -> Self { CssProperty::BackdropFilter(StyleFilterVecValue::Exact(input)) }
         pub const fn text_shadow(input: StyleBoxShadow) -> Self { CssProperty::TextShadow(StyleBoxShadowValue::Exact(input)) }
+        pub const fn vertical_align(input: LayoutVerticalAlign) -> Self { CssProperty::VerticalAlign(LayoutVerticalAlignValue::Exact(input)) }
+        pub const fn text_decoration(input: StyleTextDecoration) -> Self { CssProperty::TextDecoration(StyleTextDecorationValue::Exact(input)) }
     }
 
     const FP_PRECISION_MULTIPLIER: f32 = 1000.0;
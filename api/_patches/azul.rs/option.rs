@@ -173,6 +173,9 @@
     impl_option!(AzSystemClipboard, AzOptionSystemClipboard, copy = false,  clone = false, [Debug]);
     impl_option!(AzFileTypeList, AzOptionFileTypeList, copy = false, [Debug, Clone]);
     impl_option!(AzWindowState, AzOptionWindowState, copy = false, [Debug, Clone]);
+    impl_option!(AzDataGridRange, AzOptionDataGridRange, [Debug, Copy, Clone]);
+    impl_option!(AzDate, AzOptionDate, [Debug, Copy, Clone]);
+    impl_option!(AzTime, AzOptionTime, [Debug, Copy, Clone]);
     impl_option!(AzKeyboardState, AzOptionKeyboardState, copy = false, [Debug, Clone]);
     impl_option!(AzMouseState, AzOptionMouseState, [Debug, Clone]);
     impl_option!(AzNodeGraphOnNodeAdded, AzOptionNodeGraphOnNodeAdded, [Debug, Copy, Clone]);
@@ -183,6 +186,9 @@
     impl_option!(AzNodeGraphOnNodeInputDisconnected, AzOptionNodeGraphOnNodeInputDisconnected, [Debug, Copy, Clone]);
     impl_option!(AzNodeGraphOnNodeOutputDisconnected, AzOptionNodeGraphOnNodeOutputDisconnected, [Debug, Copy, Clone]);
     impl_option!(AzNodeGraphOnNodeFieldEdited, AzOptionNodeGraphOnNodeFieldEdited, [Debug, Copy, Clone]);
+    impl_option!(AzNodeGraphOnNodeGraphZoomed, AzOptionNodeGraphOnNodeGraphZoomed, [Debug, Copy, Clone]);
+    impl_option!(AzNodeGraphOnSelectionChanged, AzOptionNodeGraphOnSelectionChanged, [Debug, Copy, Clone]);
+    impl_option!(AzNodeGraphOnHistoryChanged, AzOptionNodeGraphOnHistoryChanged, [Debug, Copy, Clone]);
     impl_option!(AzGl, AzOptionGl, copy = false, [Debug, Clone]);
     impl_option!(AzPixelValueNoPercent, AzOptionPixelValueNoPercent, copy = false, [Debug, Copy, Clone]);
     impl_option!(AzSvgPoint, AzOptionSvgPoint, [Debug, Copy, Clone]);
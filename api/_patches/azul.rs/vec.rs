@@ -376,6 +376,24 @@
     impl_vec_clone!(AzStyleFilter, AzStyleFilterVec, AzStyleFilterVecDestructor);
     impl_vec!(AzListViewRow, AzListViewRowVec, AzListViewRowVecDestructor, az_list_view_vec_destructor, AzListViewRowVec_delete);
     impl_vec_clone!(AzListViewRow, AzListViewRowVec, AzListViewRowVecDestructor);
+    impl_vec!(AzSplitterPane, AzSplitterPaneVec, AzSplitterPaneVecDestructor, az_splitter_pane_vec_destructor, AzSplitterPaneVec_delete);
+    impl_vec_clone!(AzSplitterPane, AzSplitterPaneVec, AzSplitterPaneVecDestructor);
+    impl_vec!(AzSplitterPaneState, AzSplitterPaneStateVec, AzSplitterPaneStateVecDestructor, az_splitter_pane_state_vec_destructor, AzSplitterPaneStateVec_delete);
+    impl_vec_clone!(AzSplitterPaneState, AzSplitterPaneStateVec, AzSplitterPaneStateVecDestructor);
+    impl_vec!(AzKeyboardShortcut, AzKeyboardShortcutVec, AzKeyboardShortcutVecDestructor, az_keyboard_shortcut_vec_destructor, AzKeyboardShortcutVec_delete);
+    impl_vec_clone!(AzKeyboardShortcut, AzKeyboardShortcutVec, AzKeyboardShortcutVecDestructor);
+    impl_vec!(AzNodeGraphNodeId, AzNodeGraphNodeIdVec, AzNodeGraphNodeIdVecDestructor, az_node_graph_node_id_vec_destructor, AzNodeGraphNodeIdVec_delete);
+    impl_vec_clone!(AzNodeGraphNodeId, AzNodeGraphNodeIdVec, AzNodeGraphNodeIdVecDestructor);
+    impl_vec!(AzNodeGraphEditOp, AzNodeGraphEditOpVec, AzNodeGraphEditOpVecDestructor, az_node_graph_edit_op_vec_destructor, AzNodeGraphEditOpVec_delete);
+    impl_vec_clone!(AzNodeGraphEditOp, AzNodeGraphEditOpVec, AzNodeGraphEditOpVecDestructor);
+    impl_vec!(AzNodeGraphEdit, AzNodeGraphEditVec, AzNodeGraphEditVecDestructor, az_node_graph_edit_vec_destructor, AzNodeGraphEditVec_delete);
+    impl_vec_clone!(AzNodeGraphEdit, AzNodeGraphEditVec, AzNodeGraphEditVecDestructor);
+    impl_vec!(AzTreeViewItem, AzTreeViewItemVec, AzTreeViewItemVecDestructor, az_tree_view_item_vec_destructor, AzTreeViewItemVec_delete);
+    impl_vec_clone!(AzTreeViewItem, AzTreeViewItemVec, AzTreeViewItemVecDestructor);
+    impl_vec!(AzTextLineMetrics, AzTextLineMetricsVec, AzTextLineMetricsVecDestructor, az_text_line_metrics_vec_destructor, AzTextLineMetricsVec_delete);
+    impl_vec_clone!(AzTextLineMetrics, AzTextLineMetricsVec, AzTextLineMetricsVecDestructor);
+    impl_vec!(AzInlineTextRun, AzInlineTextRunVec, AzInlineTextRunVecDestructor, az_inline_text_run_vec_destructor, AzInlineTextRunVec_delete);
+    impl_vec_clone!(AzInlineTextRun, AzInlineTextRunVec, AzInlineTextRunVecDestructor);
     impl_vec!(AzAccessibilityState,  AzAccessibilityStateVec,  AzAccessibilityStateVecDestructor, az_accessibility_state_vec_destructor, AzAccessibilityStateVec_delete);
     impl_vec_clone!(AzAccessibilityState,  AzAccessibilityStateVec,  AzAccessibilityStateVecDestructor);
     impl_vec!(AzMenuItem,  AzMenuItemVec,  AzMenuItemVecDestructor, az_menu_item_vec_destructor, AzMenuItemVec_delete);
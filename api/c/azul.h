@@ -142,6 +142,18 @@ union AzNodeTypeFieldValue;
 typedef union AzNodeTypeFieldValue AzNodeTypeFieldValue;
 typedef AzUpdate (*AzNodeGraphOnNodeFieldEditedCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzNodeGraphNodeId C, size_t D, AzNodeTypeId E, AzNodeTypeFieldValue F);
 
+struct AzLogicalPosition;
+typedef struct AzLogicalPosition AzLogicalPosition;
+typedef AzUpdate (*AzNodeGraphOnNodeGraphZoomedCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, float C, AzLogicalPosition D);
+
+struct AzNodeGraphNodeIdVec;
+typedef struct AzNodeGraphNodeIdVec AzNodeGraphNodeIdVec;
+typedef AzUpdate (*AzNodeGraphOnSelectionChangedCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzNodeGraphNodeIdVec C);
+
+struct AzNodeGraphHistory;
+typedef struct AzNodeGraphHistory AzNodeGraphHistory;
+typedef AzUpdate (*AzNodeGraphOnHistoryChangedCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzNodeGraphHistory C);
+
 struct AzListViewState;
 typedef struct AzListViewState AzListViewState;
 typedef AzUpdate (*AzListViewOnLazyLoadScrollCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzListViewState* const C);
@@ -150,6 +162,70 @@ typedef AzUpdate (*AzListViewOnColumnClickCallbackType)(AzRefAny* restrict A, Az
 
 typedef AzUpdate (*AzListViewOnRowClickCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzListViewState* const C, size_t D);
 
+enum AzListViewSelectionChange;
+typedef enum AzListViewSelectionChange AzListViewSelectionChange;
+typedef AzUpdate (*AzListViewOnRowSelectCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzListViewState* const C, size_t D, AzListViewSelectionChange E);
+
+typedef AzUpdate (*AzListViewOnColumnResizeCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzListViewState* const C, size_t D, float E);
+
+struct AzListViewRowVec;
+typedef struct AzListViewRowVec AzListViewRowVec;
+typedef AzListViewRowVec (*AzListViewRowProviderCallbackType)(AzRefAny* restrict A, size_t B, size_t C);
+
+typedef AzUpdate (*AzTreeViewOnExpandCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, size_t C, bool  D);
+
+enum AzTreeViewSelectionChange;
+typedef enum AzTreeViewSelectionChange AzTreeViewSelectionChange;
+typedef AzUpdate (*AzTreeViewOnSelectCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, size_t C, AzTreeViewSelectionChange D);
+
+typedef AzUpdate (*AzTreeViewOnLazyLoadCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, size_t C);
+
+typedef AzUpdate (*AzTreeViewOnScrollCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, size_t C);
+
+struct AzString;
+typedef struct AzString AzString;
+typedef AzString (*AzDataGridCellProviderCallbackType)(AzRefAny* restrict A, size_t B, size_t C);
+
+struct AzNodeDataInlineCssPropertyVec;
+typedef struct AzNodeDataInlineCssPropertyVec AzNodeDataInlineCssPropertyVec;
+typedef AzNodeDataInlineCssPropertyVec (*AzDataGridCellStyleCallbackType)(AzRefAny* restrict A, size_t B, size_t C, bool  D);
+
+struct AzDataGridRange;
+typedef struct AzDataGridRange AzDataGridRange;
+typedef AzUpdate (*AzDataGridOnSelectCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzDataGridRange C);
+
+typedef AzUpdate (*AzDataGridOnScrollCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, size_t C, size_t D);
+
+typedef AzUpdate (*AzDataGridOnCellEditCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, size_t C, size_t D, AzString E);
+
+typedef AzUpdate (*AzDataGridOnColumnResizeCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, size_t C, float D);
+
+typedef AzUpdate (*AzDataGridOnRowResizeCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, size_t C, float D);
+
+typedef AzString (*AzDataGridOnCopyRangeCallbackType)(AzRefAny* restrict A, AzDataGridRange B);
+
+typedef AzUpdate (*AzDataGridOnClearRangeCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzDataGridRange C);
+
+struct AzDatePickerState;
+typedef struct AzDatePickerState AzDatePickerState;
+typedef AzUpdate (*AzDatePickerOnDateChangeCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzDatePickerState* const C);
+
+struct AzTime;
+typedef struct AzTime AzTime;
+typedef AzUpdate (*AzTimePickerOnTimeChangeCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzTime* const C);
+
+struct AzDateTimePickerState;
+typedef struct AzDateTimePickerState AzDateTimePickerState;
+typedef AzUpdate (*AzDateTimePickerOnDateTimeChangeCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzDateTimePickerState* const C);
+
+struct AzDialogResult;
+typedef struct AzDialogResult AzDialogResult;
+typedef AzUpdate (*AzDialogOnCloseCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzDialogResult* const C);
+
+struct AzSplitterState;
+typedef struct AzSplitterState AzSplitterState;
+typedef AzUpdate (*AzSplitterOnResizeCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzSplitterState* const C);
+
 typedef AzUpdate (*AzDropDownOnChoiceChangeCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, size_t C);
 
 typedef void (*AzParsedFontDestructorFnType)(void* restrict A);
@@ -198,10 +274,20 @@ struct AzStyleFontFamilyVec;
 typedef struct AzStyleFontFamilyVec AzStyleFontFamilyVec;
 typedef void (*AzStyleFontFamilyVecDestructorType)(AzStyleFontFamilyVec* restrict A);
 
-struct AzListViewRowVec;
-typedef struct AzListViewRowVec AzListViewRowVec;
 typedef void (*AzListViewRowVecDestructorType)(AzListViewRowVec* restrict A);
 
+struct AzTreeViewItemVec;
+typedef struct AzTreeViewItemVec AzTreeViewItemVec;
+typedef void (*AzTreeViewItemVecDestructorType)(AzTreeViewItemVec* restrict A);
+
+struct AzSplitterPaneVec;
+typedef struct AzSplitterPaneVec AzSplitterPaneVec;
+typedef void (*AzSplitterPaneVecDestructorType)(AzSplitterPaneVec* restrict A);
+
+struct AzSplitterPaneStateVec;
+typedef struct AzSplitterPaneStateVec AzSplitterPaneStateVec;
+typedef void (*AzSplitterPaneStateVecDestructorType)(AzSplitterPaneStateVec* restrict A);
+
 struct AzStyleFilterVec;
 typedef struct AzStyleFilterVec AzStyleFilterVec;
 typedef void (*AzStyleFilterVecDestructorType)(AzStyleFilterVec* restrict A);
@@ -218,6 +304,16 @@ struct AzInputOutputTypeIdInfoMapVec;
 typedef struct AzInputOutputTypeIdInfoMapVec AzInputOutputTypeIdInfoMapVec;
 typedef void (*AzInputOutputTypeIdInfoMapVecDestructorType)(AzInputOutputTypeIdInfoMapVec* restrict A);
 
+typedef void (*AzNodeGraphNodeIdVecDestructorType)(AzNodeGraphNodeIdVec* restrict A);
+
+struct AzNodeGraphEditOpVec;
+typedef struct AzNodeGraphEditOpVec AzNodeGraphEditOpVec;
+typedef void (*AzNodeGraphEditOpVecDestructorType)(AzNodeGraphEditOpVec* restrict A);
+
+struct AzNodeGraphEditVec;
+typedef struct AzNodeGraphEditVec AzNodeGraphEditVec;
+typedef void (*AzNodeGraphEditVecDestructorType)(AzNodeGraphEditVec* restrict A);
+
 struct AzNodeIdNodeMapVec;
 typedef struct AzNodeIdNodeMapVec AzNodeIdNodeMapVec;
 typedef void (*AzNodeIdNodeMapVecDestructorType)(AzNodeIdNodeMapVec* restrict A);
@@ -250,6 +346,10 @@ struct AzAccessibilityStateVec;
 typedef struct AzAccessibilityStateVec AzAccessibilityStateVec;
 typedef void (*AzAccessibilityStateVecDestructorType)(AzAccessibilityStateVec* restrict A);
 
+struct AzKeyboardShortcutVec;
+typedef struct AzKeyboardShortcutVec AzKeyboardShortcutVec;
+typedef void (*AzKeyboardShortcutVecDestructorType)(AzKeyboardShortcutVec* restrict A);
+
 struct AzMenuItemVec;
 typedef struct AzMenuItemVec AzMenuItemVec;
 typedef void (*AzMenuItemVecDestructorType)(AzMenuItemVec* restrict A);
@@ -282,6 +382,14 @@ struct AzInlineGlyphVec;
 typedef struct AzInlineGlyphVec AzInlineGlyphVec;
 typedef void (*AzInlineGlyphVecDestructorType)(AzInlineGlyphVec* restrict A);
 
+struct AzTextLineMetricsVec;
+typedef struct AzTextLineMetricsVec AzTextLineMetricsVec;
+typedef void (*AzTextLineMetricsVecDestructorType)(AzTextLineMetricsVec* restrict A);
+
+struct AzInlineTextRunVec;
+typedef struct AzInlineTextRunVec AzInlineTextRunVec;
+typedef void (*AzInlineTextRunVecDestructorType)(AzInlineTextRunVec* restrict A);
+
 struct AzInlineTextHitVec;
 typedef struct AzInlineTextHitVec AzInlineTextHitVec;
 typedef void (*AzInlineTextHitVecDestructorType)(AzInlineTextHitVec* restrict A);
@@ -302,8 +410,6 @@ struct AzIdOrClassVec;
 typedef struct AzIdOrClassVec AzIdOrClassVec;
 typedef void (*AzIdOrClassVecDestructorType)(AzIdOrClassVec* restrict A);
 
-struct AzNodeDataInlineCssPropertyVec;
-typedef struct AzNodeDataInlineCssPropertyVec AzNodeDataInlineCssPropertyVec;
 typedef void (*AzNodeDataInlineCssPropertyVecDestructorType)(AzNodeDataInlineCssPropertyVec* restrict A);
 
 struct AzStyleBackgroundContentVec;
@@ -390,6 +496,18 @@ struct AzStylesheetVec;
 typedef struct AzStylesheetVec AzStylesheetVec;
 typedef void (*AzStylesheetVecDestructorType)(AzStylesheetVec* restrict A);
 
+struct AzCssFontFaceVec;
+typedef struct AzCssFontFaceVec AzCssFontFaceVec;
+typedef void (*AzCssFontFaceVecDestructorType)(AzCssFontFaceVec* restrict A);
+
+struct AzCssFontFaceSourceVec;
+typedef struct AzCssFontFaceSourceVec AzCssFontFaceSourceVec;
+typedef void (*AzCssFontFaceSourceVecDestructorType)(AzCssFontFaceSourceVec* restrict A);
+
+struct AzCssUnicodeRangeVec;
+typedef struct AzCssUnicodeRangeVec AzCssUnicodeRangeVec;
+typedef void (*AzCssUnicodeRangeVecDestructorType)(AzCssUnicodeRangeVec* restrict A);
+
 struct AzCssRuleBlockVec;
 typedef struct AzCssRuleBlockVec AzCssRuleBlockVec;
 typedef void (*AzCssRuleBlockVecDestructorType)(AzCssRuleBlockVec* restrict A);
@@ -1267,6 +1385,19 @@ struct AzCssNthChildPattern {
 };
 typedef struct AzCssNthChildPattern AzCssNthChildPattern;
 
+enum AzCssFontFaceStyle {
+   AzCssFontFaceStyle_Normal,
+   AzCssFontFaceStyle_Italic,
+   AzCssFontFaceStyle_Oblique,
+};
+typedef enum AzCssFontFaceStyle AzCssFontFaceStyle;
+
+struct AzCssUnicodeRange {
+    uint32_t start;
+    uint32_t end;
+};
+typedef struct AzCssUnicodeRange AzCssUnicodeRange;
+
 enum AzCssPropertyType {
    AzCssPropertyType_TextColor,
    AzCssPropertyType_FontSize,
@@ -1342,6 +1473,8 @@ enum AzCssPropertyType {
    AzCssPropertyType_Filter,
    AzCssPropertyType_BackdropFilter,
    AzCssPropertyType_TextShadow,
+   AzCssPropertyType_VerticalAlign,
+   AzCssPropertyType_TextDecoration,
 };
 typedef enum AzCssPropertyType AzCssPropertyType;
 
@@ -1429,6 +1562,7 @@ enum AzLayoutDisplay {
    AzLayoutDisplay_Flex,
    AzLayoutDisplay_Block,
    AzLayoutDisplay_InlineBlock,
+   AzLayoutDisplay_Inline,
 };
 typedef enum AzLayoutDisplay AzLayoutDisplay;
 
@@ -1456,6 +1590,16 @@ enum AzLayoutPosition {
 };
 typedef enum AzLayoutPosition AzLayoutPosition;
 
+enum AzLayoutVerticalAlign {
+   AzLayoutVerticalAlign_Baseline,
+   AzLayoutVerticalAlign_Top,
+   AzLayoutVerticalAlign_Middle,
+   AzLayoutVerticalAlign_Bottom,
+   AzLayoutVerticalAlign_Sub,
+   AzLayoutVerticalAlign_Super,
+};
+typedef enum AzLayoutVerticalAlign AzLayoutVerticalAlign;
+
 enum AzLayoutFlexWrap {
    AzLayoutFlexWrap_Wrap,
    AzLayoutFlexWrap_NoWrap,
@@ -1580,6 +1724,14 @@ enum AzStyleTextAlign {
 };
 typedef enum AzStyleTextAlign AzStyleTextAlign;
 
+enum AzStyleTextDecoration {
+   AzStyleTextDecoration_None,
+   AzStyleTextDecoration_Underline,
+   AzStyleTextDecoration_Overline,
+   AzStyleTextDecoration_LineThrough,
+};
+typedef enum AzStyleTextDecoration AzStyleTextDecoration;
+
 struct AzRibbon {
     int32_t tab_active;
 };
@@ -1716,6 +1868,21 @@ struct AzNodeGraphOnNodeFieldEditedCallback {
 };
 typedef struct AzNodeGraphOnNodeFieldEditedCallback AzNodeGraphOnNodeFieldEditedCallback;
 
+struct AzNodeGraphOnNodeGraphZoomedCallback {
+    AzNodeGraphOnNodeGraphZoomedCallbackType cb;
+};
+typedef struct AzNodeGraphOnNodeGraphZoomedCallback AzNodeGraphOnNodeGraphZoomedCallback;
+
+struct AzNodeGraphOnSelectionChangedCallback {
+    AzNodeGraphOnSelectionChangedCallbackType cb;
+};
+typedef struct AzNodeGraphOnSelectionChangedCallback AzNodeGraphOnSelectionChangedCallback;
+
+struct AzNodeGraphOnHistoryChangedCallback {
+    AzNodeGraphOnHistoryChangedCallbackType cb;
+};
+typedef struct AzNodeGraphOnHistoryChangedCallback AzNodeGraphOnHistoryChangedCallback;
+
 struct AzInputOutputTypeId {
     uint64_t inner;
 };
@@ -1749,6 +1916,22 @@ struct AzNodeDragAmount {
 };
 typedef struct AzNodeDragAmount AzNodeDragAmount;
 
+enum AzNodeGraphError {
+   AzNodeGraphError_NodeMimeTypeMismatch,
+   AzNodeGraphError_NodeInvalidIndex,
+   AzNodeGraphError_NodeInvalidNode,
+   AzNodeGraphError_NoRootNode,
+   AzNodeGraphError_InvalidJson,
+};
+typedef enum AzNodeGraphError AzNodeGraphError;
+
+enum AzListViewSelectionChange {
+   AzListViewSelectionChange_Replace,
+   AzListViewSelectionChange_Toggle,
+   AzListViewSelectionChange_Extend,
+};
+typedef enum AzListViewSelectionChange AzListViewSelectionChange;
+
 struct AzListViewOnLazyLoadScrollCallback {
     AzListViewOnLazyLoadScrollCallbackType cb;
 };
@@ -1764,6 +1947,186 @@ struct AzListViewOnRowClickCallback {
 };
 typedef struct AzListViewOnRowClickCallback AzListViewOnRowClickCallback;
 
+struct AzListViewOnRowSelectCallback {
+    AzListViewOnRowSelectCallbackType cb;
+};
+typedef struct AzListViewOnRowSelectCallback AzListViewOnRowSelectCallback;
+
+struct AzListViewOnColumnResizeCallback {
+    AzListViewOnColumnResizeCallbackType cb;
+};
+typedef struct AzListViewOnColumnResizeCallback AzListViewOnColumnResizeCallback;
+
+struct AzListViewRowProviderCallback {
+    AzListViewRowProviderCallbackType cb;
+};
+typedef struct AzListViewRowProviderCallback AzListViewRowProviderCallback;
+
+enum AzTreeViewSelectionMode {
+   AzTreeViewSelectionMode_Single,
+   AzTreeViewSelectionMode_Multiple,
+};
+typedef enum AzTreeViewSelectionMode AzTreeViewSelectionMode;
+
+enum AzTreeViewSelectionChange {
+   AzTreeViewSelectionChange_Replace,
+   AzTreeViewSelectionChange_Toggle,
+   AzTreeViewSelectionChange_Extend,
+};
+typedef enum AzTreeViewSelectionChange AzTreeViewSelectionChange;
+
+struct AzTreeViewOnExpandCallback {
+    AzTreeViewOnExpandCallbackType cb;
+};
+typedef struct AzTreeViewOnExpandCallback AzTreeViewOnExpandCallback;
+
+struct AzTreeViewOnSelectCallback {
+    AzTreeViewOnSelectCallbackType cb;
+};
+typedef struct AzTreeViewOnSelectCallback AzTreeViewOnSelectCallback;
+
+struct AzTreeViewOnLazyLoadCallback {
+    AzTreeViewOnLazyLoadCallbackType cb;
+};
+typedef struct AzTreeViewOnLazyLoadCallback AzTreeViewOnLazyLoadCallback;
+
+struct AzTreeViewOnScrollCallback {
+    AzTreeViewOnScrollCallbackType cb;
+};
+typedef struct AzTreeViewOnScrollCallback AzTreeViewOnScrollCallback;
+
+struct AzDataGridCell {
+    size_t row;
+    size_t column;
+};
+typedef struct AzDataGridCell AzDataGridCell;
+
+struct AzDataGridCellProviderCallback {
+    AzDataGridCellProviderCallbackType cb;
+};
+typedef struct AzDataGridCellProviderCallback AzDataGridCellProviderCallback;
+
+struct AzDataGridCellStyleCallback {
+    AzDataGridCellStyleCallbackType cb;
+};
+typedef struct AzDataGridCellStyleCallback AzDataGridCellStyleCallback;
+
+struct AzDataGridOnSelectCallback {
+    AzDataGridOnSelectCallbackType cb;
+};
+typedef struct AzDataGridOnSelectCallback AzDataGridOnSelectCallback;
+
+struct AzDataGridOnScrollCallback {
+    AzDataGridOnScrollCallbackType cb;
+};
+typedef struct AzDataGridOnScrollCallback AzDataGridOnScrollCallback;
+
+struct AzDataGridOnCellEditCallback {
+    AzDataGridOnCellEditCallbackType cb;
+};
+typedef struct AzDataGridOnCellEditCallback AzDataGridOnCellEditCallback;
+
+struct AzDataGridOnColumnResizeCallback {
+    AzDataGridOnColumnResizeCallbackType cb;
+};
+typedef struct AzDataGridOnColumnResizeCallback AzDataGridOnColumnResizeCallback;
+
+struct AzDataGridOnRowResizeCallback {
+    AzDataGridOnRowResizeCallbackType cb;
+};
+typedef struct AzDataGridOnRowResizeCallback AzDataGridOnRowResizeCallback;
+
+struct AzDataGridOnCopyRangeCallback {
+    AzDataGridOnCopyRangeCallbackType cb;
+};
+typedef struct AzDataGridOnCopyRangeCallback AzDataGridOnCopyRangeCallback;
+
+struct AzDataGridOnClearRangeCallback {
+    AzDataGridOnClearRangeCallbackType cb;
+};
+typedef struct AzDataGridOnClearRangeCallback AzDataGridOnClearRangeCallback;
+
+struct AzDate {
+    int32_t year;
+    uint8_t month;
+    uint8_t day;
+};
+typedef struct AzDate AzDate;
+
+enum AzWeekday {
+   AzWeekday_Monday,
+   AzWeekday_Tuesday,
+   AzWeekday_Wednesday,
+   AzWeekday_Thursday,
+   AzWeekday_Friday,
+   AzWeekday_Saturday,
+   AzWeekday_Sunday,
+};
+typedef enum AzWeekday AzWeekday;
+
+struct AzTime {
+    uint8_t hour;
+    uint8_t minute;
+    uint8_t second;
+};
+typedef struct AzTime AzTime;
+
+struct AzDatePickerOnDateChangeCallback {
+    AzDatePickerOnDateChangeCallbackType cb;
+};
+typedef struct AzDatePickerOnDateChangeCallback AzDatePickerOnDateChangeCallback;
+
+struct AzTimePickerOnTimeChangeCallback {
+    AzTimePickerOnTimeChangeCallbackType cb;
+};
+typedef struct AzTimePickerOnTimeChangeCallback AzTimePickerOnTimeChangeCallback;
+
+struct AzDateTimePickerOnDateTimeChangeCallback {
+    AzDateTimePickerOnDateTimeChangeCallbackType cb;
+};
+typedef struct AzDateTimePickerOnDateTimeChangeCallback AzDateTimePickerOnDateTimeChangeCallback;
+
+enum AzDialogIcon {
+   AzDialogIcon_None,
+   AzDialogIcon_Info,
+   AzDialogIcon_Warning,
+   AzDialogIcon_Error,
+   AzDialogIcon_Question,
+};
+typedef enum AzDialogIcon AzDialogIcon;
+
+enum AzDialogButtons {
+   AzDialogButtons_Ok,
+   AzDialogButtons_OkCancel,
+   AzDialogButtons_YesNo,
+   AzDialogButtons_YesNoCancel,
+};
+typedef enum AzDialogButtons AzDialogButtons;
+
+enum AzDialogButton {
+   AzDialogButton_Ok,
+   AzDialogButton_Cancel,
+   AzDialogButton_Yes,
+   AzDialogButton_No,
+};
+typedef enum AzDialogButton AzDialogButton;
+
+struct AzDialogOnCloseCallback {
+    AzDialogOnCloseCallbackType cb;
+};
+typedef struct AzDialogOnCloseCallback AzDialogOnCloseCallback;
+
+enum AzSplitterDirection {
+   AzSplitterDirection_Horizontal,
+   AzSplitterDirection_Vertical,
+};
+typedef enum AzSplitterDirection AzSplitterDirection;
+
+struct AzSplitterOnResizeCallback {
+    AzSplitterOnResizeCallbackType cb;
+};
+typedef struct AzSplitterOnResizeCallback AzSplitterOnResizeCallback;
+
 struct AzDropDownOnChoiceChangeCallback {
     AzDropDownOnChoiceChangeCallbackType cb;
 };
@@ -2341,6 +2704,66 @@ union AzListViewRowVecDestructor {
 };
 typedef union AzListViewRowVecDestructor AzListViewRowVecDestructor;
 
+enum AzTreeViewItemVecDestructorTag {
+   AzTreeViewItemVecDestructorTag_DefaultRust,
+   AzTreeViewItemVecDestructorTag_NoDestructor,
+   AzTreeViewItemVecDestructorTag_External,
+};
+typedef enum AzTreeViewItemVecDestructorTag AzTreeViewItemVecDestructorTag;
+
+struct AzTreeViewItemVecDestructorVariant_DefaultRust { AzTreeViewItemVecDestructorTag tag; };
+typedef struct AzTreeViewItemVecDestructorVariant_DefaultRust AzTreeViewItemVecDestructorVariant_DefaultRust;
+struct AzTreeViewItemVecDestructorVariant_NoDestructor { AzTreeViewItemVecDestructorTag tag; };
+typedef struct AzTreeViewItemVecDestructorVariant_NoDestructor AzTreeViewItemVecDestructorVariant_NoDestructor;
+struct AzTreeViewItemVecDestructorVariant_External { AzTreeViewItemVecDestructorTag tag; AzTreeViewItemVecDestructorType payload; };
+typedef struct AzTreeViewItemVecDestructorVariant_External AzTreeViewItemVecDestructorVariant_External;
+union AzTreeViewItemVecDestructor {
+    AzTreeViewItemVecDestructorVariant_DefaultRust DefaultRust;
+    AzTreeViewItemVecDestructorVariant_NoDestructor NoDestructor;
+    AzTreeViewItemVecDestructorVariant_External External;
+};
+typedef union AzTreeViewItemVecDestructor AzTreeViewItemVecDestructor;
+
+enum AzSplitterPaneVecDestructorTag {
+   AzSplitterPaneVecDestructorTag_DefaultRust,
+   AzSplitterPaneVecDestructorTag_NoDestructor,
+   AzSplitterPaneVecDestructorTag_External,
+};
+typedef enum AzSplitterPaneVecDestructorTag AzSplitterPaneVecDestructorTag;
+
+struct AzSplitterPaneVecDestructorVariant_DefaultRust { AzSplitterPaneVecDestructorTag tag; };
+typedef struct AzSplitterPaneVecDestructorVariant_DefaultRust AzSplitterPaneVecDestructorVariant_DefaultRust;
+struct AzSplitterPaneVecDestructorVariant_NoDestructor { AzSplitterPaneVecDestructorTag tag; };
+typedef struct AzSplitterPaneVecDestructorVariant_NoDestructor AzSplitterPaneVecDestructorVariant_NoDestructor;
+struct AzSplitterPaneVecDestructorVariant_External { AzSplitterPaneVecDestructorTag tag; AzSplitterPaneVecDestructorType payload; };
+typedef struct AzSplitterPaneVecDestructorVariant_External AzSplitterPaneVecDestructorVariant_External;
+union AzSplitterPaneVecDestructor {
+    AzSplitterPaneVecDestructorVariant_DefaultRust DefaultRust;
+    AzSplitterPaneVecDestructorVariant_NoDestructor NoDestructor;
+    AzSplitterPaneVecDestructorVariant_External External;
+};
+typedef union AzSplitterPaneVecDestructor AzSplitterPaneVecDestructor;
+
+enum AzSplitterPaneStateVecDestructorTag {
+   AzSplitterPaneStateVecDestructorTag_DefaultRust,
+   AzSplitterPaneStateVecDestructorTag_NoDestructor,
+   AzSplitterPaneStateVecDestructorTag_External,
+};
+typedef enum AzSplitterPaneStateVecDestructorTag AzSplitterPaneStateVecDestructorTag;
+
+struct AzSplitterPaneStateVecDestructorVariant_DefaultRust { AzSplitterPaneStateVecDestructorTag tag; };
+typedef struct AzSplitterPaneStateVecDestructorVariant_DefaultRust AzSplitterPaneStateVecDestructorVariant_DefaultRust;
+struct AzSplitterPaneStateVecDestructorVariant_NoDestructor { AzSplitterPaneStateVecDestructorTag tag; };
+typedef struct AzSplitterPaneStateVecDestructorVariant_NoDestructor AzSplitterPaneStateVecDestructorVariant_NoDestructor;
+struct AzSplitterPaneStateVecDestructorVariant_External { AzSplitterPaneStateVecDestructorTag tag; AzSplitterPaneStateVecDestructorType payload; };
+typedef struct AzSplitterPaneStateVecDestructorVariant_External AzSplitterPaneStateVecDestructorVariant_External;
+union AzSplitterPaneStateVecDestructor {
+    AzSplitterPaneStateVecDestructorVariant_DefaultRust DefaultRust;
+    AzSplitterPaneStateVecDestructorVariant_NoDestructor NoDestructor;
+    AzSplitterPaneStateVecDestructorVariant_External External;
+};
+typedef union AzSplitterPaneStateVecDestructor AzSplitterPaneStateVecDestructor;
+
 enum AzStyleFilterVecDestructorTag {
    AzStyleFilterVecDestructorTag_DefaultRust,
    AzStyleFilterVecDestructorTag_NoDestructor,
@@ -2421,6 +2844,66 @@ union AzInputOutputTypeIdInfoMapVecDestructor {
 };
 typedef union AzInputOutputTypeIdInfoMapVecDestructor AzInputOutputTypeIdInfoMapVecDestructor;
 
+enum AzNodeGraphNodeIdVecDestructorTag {
+   AzNodeGraphNodeIdVecDestructorTag_DefaultRust,
+   AzNodeGraphNodeIdVecDestructorTag_NoDestructor,
+   AzNodeGraphNodeIdVecDestructorTag_External,
+};
+typedef enum AzNodeGraphNodeIdVecDestructorTag AzNodeGraphNodeIdVecDestructorTag;
+
+struct AzNodeGraphNodeIdVecDestructorVariant_DefaultRust { AzNodeGraphNodeIdVecDestructorTag tag; };
+typedef struct AzNodeGraphNodeIdVecDestructorVariant_DefaultRust AzNodeGraphNodeIdVecDestructorVariant_DefaultRust;
+struct AzNodeGraphNodeIdVecDestructorVariant_NoDestructor { AzNodeGraphNodeIdVecDestructorTag tag; };
+typedef struct AzNodeGraphNodeIdVecDestructorVariant_NoDestructor AzNodeGraphNodeIdVecDestructorVariant_NoDestructor;
+struct AzNodeGraphNodeIdVecDestructorVariant_External { AzNodeGraphNodeIdVecDestructorTag tag; AzNodeGraphNodeIdVecDestructorType payload; };
+typedef struct AzNodeGraphNodeIdVecDestructorVariant_External AzNodeGraphNodeIdVecDestructorVariant_External;
+union AzNodeGraphNodeIdVecDestructor {
+    AzNodeGraphNodeIdVecDestructorVariant_DefaultRust DefaultRust;
+    AzNodeGraphNodeIdVecDestructorVariant_NoDestructor NoDestructor;
+    AzNodeGraphNodeIdVecDestructorVariant_External External;
+};
+typedef union AzNodeGraphNodeIdVecDestructor AzNodeGraphNodeIdVecDestructor;
+
+enum AzNodeGraphEditOpVecDestructorTag {
+   AzNodeGraphEditOpVecDestructorTag_DefaultRust,
+   AzNodeGraphEditOpVecDestructorTag_NoDestructor,
+   AzNodeGraphEditOpVecDestructorTag_External,
+};
+typedef enum AzNodeGraphEditOpVecDestructorTag AzNodeGraphEditOpVecDestructorTag;
+
+struct AzNodeGraphEditOpVecDestructorVariant_DefaultRust { AzNodeGraphEditOpVecDestructorTag tag; };
+typedef struct AzNodeGraphEditOpVecDestructorVariant_DefaultRust AzNodeGraphEditOpVecDestructorVariant_DefaultRust;
+struct AzNodeGraphEditOpVecDestructorVariant_NoDestructor { AzNodeGraphEditOpVecDestructorTag tag; };
+typedef struct AzNodeGraphEditOpVecDestructorVariant_NoDestructor AzNodeGraphEditOpVecDestructorVariant_NoDestructor;
+struct AzNodeGraphEditOpVecDestructorVariant_External { AzNodeGraphEditOpVecDestructorTag tag; AzNodeGraphEditOpVecDestructorType payload; };
+typedef struct AzNodeGraphEditOpVecDestructorVariant_External AzNodeGraphEditOpVecDestructorVariant_External;
+union AzNodeGraphEditOpVecDestructor {
+    AzNodeGraphEditOpVecDestructorVariant_DefaultRust DefaultRust;
+    AzNodeGraphEditOpVecDestructorVariant_NoDestructor NoDestructor;
+    AzNodeGraphEditOpVecDestructorVariant_External External;
+};
+typedef union AzNodeGraphEditOpVecDestructor AzNodeGraphEditOpVecDestructor;
+
+enum AzNodeGraphEditVecDestructorTag {
+   AzNodeGraphEditVecDestructorTag_DefaultRust,
+   AzNodeGraphEditVecDestructorTag_NoDestructor,
+   AzNodeGraphEditVecDestructorTag_External,
+};
+typedef enum AzNodeGraphEditVecDestructorTag AzNodeGraphEditVecDestructorTag;
+
+struct AzNodeGraphEditVecDestructorVariant_DefaultRust { AzNodeGraphEditVecDestructorTag tag; };
+typedef struct AzNodeGraphEditVecDestructorVariant_DefaultRust AzNodeGraphEditVecDestructorVariant_DefaultRust;
+struct AzNodeGraphEditVecDestructorVariant_NoDestructor { AzNodeGraphEditVecDestructorTag tag; };
+typedef struct AzNodeGraphEditVecDestructorVariant_NoDestructor AzNodeGraphEditVecDestructorVariant_NoDestructor;
+struct AzNodeGraphEditVecDestructorVariant_External { AzNodeGraphEditVecDestructorTag tag; AzNodeGraphEditVecDestructorType payload; };
+typedef struct AzNodeGraphEditVecDestructorVariant_External AzNodeGraphEditVecDestructorVariant_External;
+union AzNodeGraphEditVecDestructor {
+    AzNodeGraphEditVecDestructorVariant_DefaultRust DefaultRust;
+    AzNodeGraphEditVecDestructorVariant_NoDestructor NoDestructor;
+    AzNodeGraphEditVecDestructorVariant_External External;
+};
+typedef union AzNodeGraphEditVecDestructor AzNodeGraphEditVecDestructor;
+
 enum AzNodeIdNodeMapVecDestructorTag {
    AzNodeIdNodeMapVecDestructorTag_DefaultRust,
    AzNodeIdNodeMapVecDestructorTag_NoDestructor,
@@ -2581,6 +3064,26 @@ union AzAccessibilityStateVecDestructor {
 };
 typedef union AzAccessibilityStateVecDestructor AzAccessibilityStateVecDestructor;
 
+enum AzKeyboardShortcutVecDestructorTag {
+   AzKeyboardShortcutVecDestructorTag_DefaultRust,
+   AzKeyboardShortcutVecDestructorTag_NoDestructor,
+   AzKeyboardShortcutVecDestructorTag_External,
+};
+typedef enum AzKeyboardShortcutVecDestructorTag AzKeyboardShortcutVecDestructorTag;
+
+struct AzKeyboardShortcutVecDestructorVariant_DefaultRust { AzKeyboardShortcutVecDestructorTag tag; };
+typedef struct AzKeyboardShortcutVecDestructorVariant_DefaultRust AzKeyboardShortcutVecDestructorVariant_DefaultRust;
+struct AzKeyboardShortcutVecDestructorVariant_NoDestructor { AzKeyboardShortcutVecDestructorTag tag; };
+typedef struct AzKeyboardShortcutVecDestructorVariant_NoDestructor AzKeyboardShortcutVecDestructorVariant_NoDestructor;
+struct AzKeyboardShortcutVecDestructorVariant_External { AzKeyboardShortcutVecDestructorTag tag; AzKeyboardShortcutVecDestructorType payload; };
+typedef struct AzKeyboardShortcutVecDestructorVariant_External AzKeyboardShortcutVecDestructorVariant_External;
+union AzKeyboardShortcutVecDestructor {
+    AzKeyboardShortcutVecDestructorVariant_DefaultRust DefaultRust;
+    AzKeyboardShortcutVecDestructorVariant_NoDestructor NoDestructor;
+    AzKeyboardShortcutVecDestructorVariant_External External;
+};
+typedef union AzKeyboardShortcutVecDestructor AzKeyboardShortcutVecDestructor;
+
 enum AzMenuItemVecDestructorTag {
    AzMenuItemVecDestructorTag_DefaultRust,
    AzMenuItemVecDestructorTag_NoDestructor,
@@ -2741,6 +3244,46 @@ union AzInlineGlyphVecDestructor {
 };
 typedef union AzInlineGlyphVecDestructor AzInlineGlyphVecDestructor;
 
+enum AzTextLineMetricsVecDestructorTag {
+   AzTextLineMetricsVecDestructorTag_DefaultRust,
+   AzTextLineMetricsVecDestructorTag_NoDestructor,
+   AzTextLineMetricsVecDestructorTag_External,
+};
+typedef enum AzTextLineMetricsVecDestructorTag AzTextLineMetricsVecDestructorTag;
+
+struct AzTextLineMetricsVecDestructorVariant_DefaultRust { AzTextLineMetricsVecDestructorTag tag; };
+typedef struct AzTextLineMetricsVecDestructorVariant_DefaultRust AzTextLineMetricsVecDestructorVariant_DefaultRust;
+struct AzTextLineMetricsVecDestructorVariant_NoDestructor { AzTextLineMetricsVecDestructorTag tag; };
+typedef struct AzTextLineMetricsVecDestructorVariant_NoDestructor AzTextLineMetricsVecDestructorVariant_NoDestructor;
+struct AzTextLineMetricsVecDestructorVariant_External { AzTextLineMetricsVecDestructorTag tag; AzTextLineMetricsVecDestructorType payload; };
+typedef struct AzTextLineMetricsVecDestructorVariant_External AzTextLineMetricsVecDestructorVariant_External;
+union AzTextLineMetricsVecDestructor {
+    AzTextLineMetricsVecDestructorVariant_DefaultRust DefaultRust;
+    AzTextLineMetricsVecDestructorVariant_NoDestructor NoDestructor;
+    AzTextLineMetricsVecDestructorVariant_External External;
+};
+typedef union AzTextLineMetricsVecDestructor AzTextLineMetricsVecDestructor;
+
+enum AzInlineTextRunVecDestructorTag {
+   AzInlineTextRunVecDestructorTag_DefaultRust,
+   AzInlineTextRunVecDestructorTag_NoDestructor,
+   AzInlineTextRunVecDestructorTag_External,
+};
+typedef enum AzInlineTextRunVecDestructorTag AzInlineTextRunVecDestructorTag;
+
+struct AzInlineTextRunVecDestructorVariant_DefaultRust { AzInlineTextRunVecDestructorTag tag; };
+typedef struct AzInlineTextRunVecDestructorVariant_DefaultRust AzInlineTextRunVecDestructorVariant_DefaultRust;
+struct AzInlineTextRunVecDestructorVariant_NoDestructor { AzInlineTextRunVecDestructorTag tag; };
+typedef struct AzInlineTextRunVecDestructorVariant_NoDestructor AzInlineTextRunVecDestructorVariant_NoDestructor;
+struct AzInlineTextRunVecDestructorVariant_External { AzInlineTextRunVecDestructorTag tag; AzInlineTextRunVecDestructorType payload; };
+typedef struct AzInlineTextRunVecDestructorVariant_External AzInlineTextRunVecDestructorVariant_External;
+union AzInlineTextRunVecDestructor {
+    AzInlineTextRunVecDestructorVariant_DefaultRust DefaultRust;
+    AzInlineTextRunVecDestructorVariant_NoDestructor NoDestructor;
+    AzInlineTextRunVecDestructorVariant_External External;
+};
+typedef union AzInlineTextRunVecDestructor AzInlineTextRunVecDestructor;
+
 enum AzInlineTextHitVecDestructorTag {
    AzInlineTextHitVecDestructorTag_DefaultRust,
    AzInlineTextHitVecDestructorTag_NoDestructor,
@@ -3281,6 +3824,66 @@ union AzStylesheetVecDestructor {
 };
 typedef union AzStylesheetVecDestructor AzStylesheetVecDestructor;
 
+enum AzCssFontFaceVecDestructorTag {
+   AzCssFontFaceVecDestructorTag_DefaultRust,
+   AzCssFontFaceVecDestructorTag_NoDestructor,
+   AzCssFontFaceVecDestructorTag_External,
+};
+typedef enum AzCssFontFaceVecDestructorTag AzCssFontFaceVecDestructorTag;
+
+struct AzCssFontFaceVecDestructorVariant_DefaultRust { AzCssFontFaceVecDestructorTag tag; };
+typedef struct AzCssFontFaceVecDestructorVariant_DefaultRust AzCssFontFaceVecDestructorVariant_DefaultRust;
+struct AzCssFontFaceVecDestructorVariant_NoDestructor { AzCssFontFaceVecDestructorTag tag; };
+typedef struct AzCssFontFaceVecDestructorVariant_NoDestructor AzCssFontFaceVecDestructorVariant_NoDestructor;
+struct AzCssFontFaceVecDestructorVariant_External { AzCssFontFaceVecDestructorTag tag; AzCssFontFaceVecDestructorType payload; };
+typedef struct AzCssFontFaceVecDestructorVariant_External AzCssFontFaceVecDestructorVariant_External;
+union AzCssFontFaceVecDestructor {
+    AzCssFontFaceVecDestructorVariant_DefaultRust DefaultRust;
+    AzCssFontFaceVecDestructorVariant_NoDestructor NoDestructor;
+    AzCssFontFaceVecDestructorVariant_External External;
+};
+typedef union AzCssFontFaceVecDestructor AzCssFontFaceVecDestructor;
+
+enum AzCssFontFaceSourceVecDestructorTag {
+   AzCssFontFaceSourceVecDestructorTag_DefaultRust,
+   AzCssFontFaceSourceVecDestructorTag_NoDestructor,
+   AzCssFontFaceSourceVecDestructorTag_External,
+};
+typedef enum AzCssFontFaceSourceVecDestructorTag AzCssFontFaceSourceVecDestructorTag;
+
+struct AzCssFontFaceSourceVecDestructorVariant_DefaultRust { AzCssFontFaceSourceVecDestructorTag tag; };
+typedef struct AzCssFontFaceSourceVecDestructorVariant_DefaultRust AzCssFontFaceSourceVecDestructorVariant_DefaultRust;
+struct AzCssFontFaceSourceVecDestructorVariant_NoDestructor { AzCssFontFaceSourceVecDestructorTag tag; };
+typedef struct AzCssFontFaceSourceVecDestructorVariant_NoDestructor AzCssFontFaceSourceVecDestructorVariant_NoDestructor;
+struct AzCssFontFaceSourceVecDestructorVariant_External { AzCssFontFaceSourceVecDestructorTag tag; AzCssFontFaceSourceVecDestructorType payload; };
+typedef struct AzCssFontFaceSourceVecDestructorVariant_External AzCssFontFaceSourceVecDestructorVariant_External;
+union AzCssFontFaceSourceVecDestructor {
+    AzCssFontFaceSourceVecDestructorVariant_DefaultRust DefaultRust;
+    AzCssFontFaceSourceVecDestructorVariant_NoDestructor NoDestructor;
+    AzCssFontFaceSourceVecDestructorVariant_External External;
+};
+typedef union AzCssFontFaceSourceVecDestructor AzCssFontFaceSourceVecDestructor;
+
+enum AzCssUnicodeRangeVecDestructorTag {
+   AzCssUnicodeRangeVecDestructorTag_DefaultRust,
+   AzCssUnicodeRangeVecDestructorTag_NoDestructor,
+   AzCssUnicodeRangeVecDestructorTag_External,
+};
+typedef enum AzCssUnicodeRangeVecDestructorTag AzCssUnicodeRangeVecDestructorTag;
+
+struct AzCssUnicodeRangeVecDestructorVariant_DefaultRust { AzCssUnicodeRangeVecDestructorTag tag; };
+typedef struct AzCssUnicodeRangeVecDestructorVariant_DefaultRust AzCssUnicodeRangeVecDestructorVariant_DefaultRust;
+struct AzCssUnicodeRangeVecDestructorVariant_NoDestructor { AzCssUnicodeRangeVecDestructorTag tag; };
+typedef struct AzCssUnicodeRangeVecDestructorVariant_NoDestructor AzCssUnicodeRangeVecDestructorVariant_NoDestructor;
+struct AzCssUnicodeRangeVecDestructorVariant_External { AzCssUnicodeRangeVecDestructorTag tag; AzCssUnicodeRangeVecDestructorType payload; };
+typedef struct AzCssUnicodeRangeVecDestructorVariant_External AzCssUnicodeRangeVecDestructorVariant_External;
+union AzCssUnicodeRangeVecDestructor {
+    AzCssUnicodeRangeVecDestructorVariant_DefaultRust DefaultRust;
+    AzCssUnicodeRangeVecDestructorVariant_NoDestructor NoDestructor;
+    AzCssUnicodeRangeVecDestructorVariant_External External;
+};
+typedef union AzCssUnicodeRangeVecDestructor AzCssUnicodeRangeVecDestructor;
+
 enum AzCssRuleBlockVecDestructorTag {
    AzCssRuleBlockVecDestructorTag_DefaultRust,
    AzCssRuleBlockVecDestructorTag_NoDestructor,
@@ -3994,6 +4597,21 @@ struct AzHidpiAdjustedBounds {
 };
 typedef struct AzHidpiAdjustedBounds AzHidpiAdjustedBounds;
 
+struct AzTextLineMetrics {
+    AzLogicalRect bounds;
+    float baseline_px;
+    size_t glyph_count;
+};
+typedef struct AzTextLineMetrics AzTextLineMetrics;
+
+struct AzInlineTextRun {
+    AzNodeId node_id;
+    size_t glyph_start;
+    size_t glyph_end;
+    float font_size_px;
+};
+typedef struct AzInlineTextRun AzInlineTextRun;
+
 struct AzInlineGlyph {
     AzLogicalRect bounds;
     AzOptionChar unicode_codepoint;
@@ -5390,6 +6008,34 @@ union AzLayoutTopValue {
 };
 typedef union AzLayoutTopValue AzLayoutTopValue;
 
+enum AzLayoutVerticalAlignValueTag {
+   AzLayoutVerticalAlignValueTag_Auto,
+   AzLayoutVerticalAlignValueTag_None,
+   AzLayoutVerticalAlignValueTag_Inherit,
+   AzLayoutVerticalAlignValueTag_Initial,
+   AzLayoutVerticalAlignValueTag_Exact,
+};
+typedef enum AzLayoutVerticalAlignValueTag AzLayoutVerticalAlignValueTag;
+
+struct AzLayoutVerticalAlignValueVariant_Auto { AzLayoutVerticalAlignValueTag tag; };
+typedef struct AzLayoutVerticalAlignValueVariant_Auto AzLayoutVerticalAlignValueVariant_Auto;
+struct AzLayoutVerticalAlignValueVariant_None { AzLayoutVerticalAlignValueTag tag; };
+typedef struct AzLayoutVerticalAlignValueVariant_None AzLayoutVerticalAlignValueVariant_None;
+struct AzLayoutVerticalAlignValueVariant_Inherit { AzLayoutVerticalAlignValueTag tag; };
+typedef struct AzLayoutVerticalAlignValueVariant_Inherit AzLayoutVerticalAlignValueVariant_Inherit;
+struct AzLayoutVerticalAlignValueVariant_Initial { AzLayoutVerticalAlignValueTag tag; };
+typedef struct AzLayoutVerticalAlignValueVariant_Initial AzLayoutVerticalAlignValueVariant_Initial;
+struct AzLayoutVerticalAlignValueVariant_Exact { AzLayoutVerticalAlignValueTag tag; AzLayoutVerticalAlign payload; };
+typedef struct AzLayoutVerticalAlignValueVariant_Exact AzLayoutVerticalAlignValueVariant_Exact;
+union AzLayoutVerticalAlignValue {
+    AzLayoutVerticalAlignValueVariant_Auto Auto;
+    AzLayoutVerticalAlignValueVariant_None None;
+    AzLayoutVerticalAlignValueVariant_Inherit Inherit;
+    AzLayoutVerticalAlignValueVariant_Initial Initial;
+    AzLayoutVerticalAlignValueVariant_Exact Exact;
+};
+typedef union AzLayoutVerticalAlignValue AzLayoutVerticalAlignValue;
+
 enum AzLayoutWidthValueTag {
    AzLayoutWidthValueTag_Auto,
    AzLayoutWidthValueTag_None,
@@ -6090,6 +6736,34 @@ union AzStyleTextAlignValue {
 };
 typedef union AzStyleTextAlignValue AzStyleTextAlignValue;
 
+enum AzStyleTextDecorationValueTag {
+   AzStyleTextDecorationValueTag_Auto,
+   AzStyleTextDecorationValueTag_None,
+   AzStyleTextDecorationValueTag_Inherit,
+   AzStyleTextDecorationValueTag_Initial,
+   AzStyleTextDecorationValueTag_Exact,
+};
+typedef enum AzStyleTextDecorationValueTag AzStyleTextDecorationValueTag;
+
+struct AzStyleTextDecorationValueVariant_Auto { AzStyleTextDecorationValueTag tag; };
+typedef struct AzStyleTextDecorationValueVariant_Auto AzStyleTextDecorationValueVariant_Auto;
+struct AzStyleTextDecorationValueVariant_None { AzStyleTextDecorationValueTag tag; };
+typedef struct AzStyleTextDecorationValueVariant_None AzStyleTextDecorationValueVariant_None;
+struct AzStyleTextDecorationValueVariant_Inherit { AzStyleTextDecorationValueTag tag; };
+typedef struct AzStyleTextDecorationValueVariant_Inherit AzStyleTextDecorationValueVariant_Inherit;
+struct AzStyleTextDecorationValueVariant_Initial { AzStyleTextDecorationValueTag tag; };
+typedef struct AzStyleTextDecorationValueVariant_Initial AzStyleTextDecorationValueVariant_Initial;
+struct AzStyleTextDecorationValueVariant_Exact { AzStyleTextDecorationValueTag tag; AzStyleTextDecoration payload; };
+typedef struct AzStyleTextDecorationValueVariant_Exact AzStyleTextDecorationValueVariant_Exact;
+union AzStyleTextDecorationValue {
+    AzStyleTextDecorationValueVariant_Auto Auto;
+    AzStyleTextDecorationValueVariant_None None;
+    AzStyleTextDecorationValueVariant_Inherit Inherit;
+    AzStyleTextDecorationValueVariant_Initial Initial;
+    AzStyleTextDecorationValueVariant_Exact Exact;
+};
+typedef union AzStyleTextDecorationValue AzStyleTextDecorationValue;
+
 enum AzStyleTextColorValueTag {
    AzStyleTextColorValueTag_Auto,
    AzStyleTextColorValueTag_None,
@@ -6421,6 +7095,24 @@ struct AzNodeGraphOnNodeFieldEdited {
 };
 typedef struct AzNodeGraphOnNodeFieldEdited AzNodeGraphOnNodeFieldEdited;
 
+struct AzNodeGraphOnNodeGraphZoomed {
+    AzRefAny data;
+    AzNodeGraphOnNodeGraphZoomedCallback callback;
+};
+typedef struct AzNodeGraphOnNodeGraphZoomed AzNodeGraphOnNodeGraphZoomed;
+
+struct AzNodeGraphOnSelectionChanged {
+    AzRefAny data;
+    AzNodeGraphOnSelectionChangedCallback callback;
+};
+typedef struct AzNodeGraphOnSelectionChanged AzNodeGraphOnSelectionChanged;
+
+struct AzNodeGraphOnHistoryChanged {
+    AzRefAny data;
+    AzNodeGraphOnHistoryChangedCallback callback;
+};
+typedef struct AzNodeGraphOnHistoryChanged AzNodeGraphOnHistoryChanged;
+
 struct AzOutputNodeAndIndex {
     AzNodeGraphNodeId node_id;
     size_t output_index;
@@ -6433,6 +7125,20 @@ struct AzInputNodeAndIndex {
 };
 typedef struct AzInputNodeAndIndex AzInputNodeAndIndex;
 
+struct AzNodeGraphConnection {
+    AzNodeGraphNodeId output;
+    size_t output_index;
+    AzNodeGraphNodeId input;
+    size_t input_index;
+};
+typedef struct AzNodeGraphConnection AzNodeGraphConnection;
+
+struct AzNodeGraphNodeMove {
+    AzNodeGraphNodeId node_id;
+    AzNodeDragAmount amount;
+};
+typedef struct AzNodeGraphNodeMove AzNodeGraphNodeMove;
+
 struct AzListViewOnLazyLoadScroll {
     AzRefAny data;
     AzListViewOnLazyLoadScrollCallback callback;
@@ -6451,6 +7157,144 @@ struct AzListViewOnRowClick {
 };
 typedef struct AzListViewOnRowClick AzListViewOnRowClick;
 
+struct AzListViewOnRowSelect {
+    AzRefAny data;
+    AzListViewOnRowSelectCallback callback;
+};
+typedef struct AzListViewOnRowSelect AzListViewOnRowSelect;
+
+struct AzListViewOnColumnResize {
+    AzRefAny data;
+    AzListViewOnColumnResizeCallback callback;
+};
+typedef struct AzListViewOnColumnResize AzListViewOnColumnResize;
+
+struct AzListViewRowProvider {
+    AzRefAny data;
+    AzListViewRowProviderCallback callback;
+};
+typedef struct AzListViewRowProvider AzListViewRowProvider;
+
+struct AzTreeViewOnExpand {
+    AzRefAny data;
+    AzTreeViewOnExpandCallback callback;
+};
+typedef struct AzTreeViewOnExpand AzTreeViewOnExpand;
+
+struct AzTreeViewOnSelect {
+    AzRefAny data;
+    AzTreeViewOnSelectCallback callback;
+};
+typedef struct AzTreeViewOnSelect AzTreeViewOnSelect;
+
+struct AzTreeViewOnLazyLoad {
+    AzRefAny data;
+    AzTreeViewOnLazyLoadCallback callback;
+};
+typedef struct AzTreeViewOnLazyLoad AzTreeViewOnLazyLoad;
+
+struct AzTreeViewOnScroll {
+    AzRefAny data;
+    AzTreeViewOnScrollCallback callback;
+};
+typedef struct AzTreeViewOnScroll AzTreeViewOnScroll;
+
+struct AzDataGridRange {
+    AzDataGridCell anchor;
+    AzDataGridCell cursor;
+};
+typedef struct AzDataGridRange AzDataGridRange;
+
+struct AzDataGridCellProvider {
+    AzRefAny data;
+    AzDataGridCellProviderCallback callback;
+};
+typedef struct AzDataGridCellProvider AzDataGridCellProvider;
+
+struct AzDataGridCellStyle {
+    AzRefAny data;
+    AzDataGridCellStyleCallback callback;
+};
+typedef struct AzDataGridCellStyle AzDataGridCellStyle;
+
+struct AzDataGridOnSelect {
+    AzRefAny data;
+    AzDataGridOnSelectCallback callback;
+};
+typedef struct AzDataGridOnSelect AzDataGridOnSelect;
+
+struct AzDataGridOnScroll {
+    AzRefAny data;
+    AzDataGridOnScrollCallback callback;
+};
+typedef struct AzDataGridOnScroll AzDataGridOnScroll;
+
+struct AzDataGridOnCellEdit {
+    AzRefAny data;
+    AzDataGridOnCellEditCallback callback;
+};
+typedef struct AzDataGridOnCellEdit AzDataGridOnCellEdit;
+
+struct AzDataGridOnColumnResize {
+    AzRefAny data;
+    AzDataGridOnColumnResizeCallback callback;
+};
+typedef struct AzDataGridOnColumnResize AzDataGridOnColumnResize;
+
+struct AzDataGridOnRowResize {
+    AzRefAny data;
+    AzDataGridOnRowResizeCallback callback;
+};
+typedef struct AzDataGridOnRowResize AzDataGridOnRowResize;
+
+struct AzDataGridOnCopyRange {
+    AzRefAny data;
+    AzDataGridOnCopyRangeCallback callback;
+};
+typedef struct AzDataGridOnCopyRange AzDataGridOnCopyRange;
+
+struct AzDataGridOnClearRange {
+    AzRefAny data;
+    AzDataGridOnClearRangeCallback callback;
+};
+typedef struct AzDataGridOnClearRange AzDataGridOnClearRange;
+
+struct AzDatePickerOnDateChange {
+    AzRefAny data;
+    AzDatePickerOnDateChangeCallback callback;
+};
+typedef struct AzDatePickerOnDateChange AzDatePickerOnDateChange;
+
+struct AzTimePickerOnTimeChange {
+    AzRefAny data;
+    AzTimePickerOnTimeChangeCallback callback;
+};
+typedef struct AzTimePickerOnTimeChange AzTimePickerOnTimeChange;
+
+struct AzDateTimePickerOnDateTimeChange {
+    AzRefAny data;
+    AzDateTimePickerOnDateTimeChangeCallback callback;
+};
+typedef struct AzDateTimePickerOnDateTimeChange AzDateTimePickerOnDateTimeChange;
+
+struct AzDialogOnClose {
+    AzRefAny data;
+    AzDialogOnCloseCallback callback;
+};
+typedef struct AzDialogOnClose AzDialogOnClose;
+
+struct AzSplitterPaneState {
+    AzOptionF32 size;
+    bool  collapsed;
+};
+typedef struct AzSplitterPaneState AzSplitterPaneState;
+
+struct AzSplitterOnResize {
+    AzRefAny data;
+    AzSplitterOnResizeCallback callback;
+};
+typedef struct AzSplitterOnResize AzSplitterOnResize;
+
 struct AzDropDownOnChoiceChange {
     AzRefAny data;
     AzDropDownOnChoiceChangeCallback callback;
@@ -6581,6 +7425,14 @@ struct AzSvgFillStyle {
 };
 typedef struct AzSvgFillStyle AzSvgFillStyle;
 
+struct AzXmlSpan {
+    size_t file;
+    size_t start;
+    size_t end;
+    AzSvgParseErrorPosition pos;
+};
+typedef struct AzXmlSpan AzXmlSpan;
+
 struct AzInstantPtr {
     void* ptr;
     AzInstantPtrCloneFn clone_fn;
@@ -6631,6 +7483,24 @@ struct AzThreadWriteBackMsg {
 };
 typedef struct AzThreadWriteBackMsg AzThreadWriteBackMsg;
 
+struct AzTreeViewItem;
+typedef struct AzTreeViewItem AzTreeViewItem;
+struct AzTreeViewItemVec {
+    AzTreeViewItem* ptr;
+    size_t len;
+    size_t cap;
+    AzTreeViewItemVecDestructor destructor;
+};
+typedef struct AzTreeViewItemVec AzTreeViewItemVec;
+
+struct AzSplitterPaneStateVec {
+    AzSplitterPaneState* ptr;
+    size_t len;
+    size_t cap;
+    AzSplitterPaneStateVecDestructor destructor;
+};
+typedef struct AzSplitterPaneStateVec AzSplitterPaneStateVec;
+
 struct AzLogicalRectVec {
     AzLogicalRect* ptr;
     size_t len;
@@ -6639,6 +7509,14 @@ struct AzLogicalRectVec {
 };
 typedef struct AzLogicalRectVec AzLogicalRectVec;
 
+struct AzNodeGraphNodeIdVec {
+    AzNodeGraphNodeId* ptr;
+    size_t len;
+    size_t cap;
+    AzNodeGraphNodeIdVecDestructor destructor;
+};
+typedef struct AzNodeGraphNodeIdVec AzNodeGraphNodeIdVec;
+
 struct AzInputOutputTypeIdVec {
     AzInputOutputTypeId* ptr;
     size_t len;
@@ -6699,6 +7577,22 @@ struct AzInlineGlyphVec {
 };
 typedef struct AzInlineGlyphVec AzInlineGlyphVec;
 
+struct AzTextLineMetricsVec {
+    AzTextLineMetrics* ptr;
+    size_t len;
+    size_t cap;
+    AzTextLineMetricsVecDestructor destructor;
+};
+typedef struct AzTextLineMetricsVec AzTextLineMetricsVec;
+
+struct AzInlineTextRunVec {
+    AzInlineTextRun* ptr;
+    size_t len;
+    size_t cap;
+    AzInlineTextRunVecDestructor destructor;
+};
+typedef struct AzInlineTextRunVec AzInlineTextRunVec;
+
 struct AzInlineTextHitVec {
     AzInlineTextHit* ptr;
     size_t len;
@@ -6805,6 +7699,14 @@ struct AzScanCodeVec {
 };
 typedef struct AzScanCodeVec AzScanCodeVec;
 
+struct AzCssUnicodeRangeVec {
+    AzCssUnicodeRange* ptr;
+    size_t len;
+    size_t cap;
+    AzCssUnicodeRangeVecDestructor destructor;
+};
+typedef struct AzCssUnicodeRangeVec AzCssUnicodeRangeVec;
+
 struct AzU16Vec {
     uint16_t* ptr;
     size_t len;
@@ -6901,9 +7803,73 @@ union AzOptionSvgPoint {
 };
 typedef union AzOptionSvgPoint AzOptionSvgPoint;
 
-enum AzOptionListViewOnRowClickTag {
-   AzOptionListViewOnRowClickTag_None,
-   AzOptionListViewOnRowClickTag_Some,
+enum AzOptionTreeViewOnExpandTag {
+   AzOptionTreeViewOnExpandTag_None,
+   AzOptionTreeViewOnExpandTag_Some,
+};
+typedef enum AzOptionTreeViewOnExpandTag AzOptionTreeViewOnExpandTag;
+
+struct AzOptionTreeViewOnExpandVariant_None { AzOptionTreeViewOnExpandTag tag; };
+typedef struct AzOptionTreeViewOnExpandVariant_None AzOptionTreeViewOnExpandVariant_None;
+struct AzOptionTreeViewOnExpandVariant_Some { AzOptionTreeViewOnExpandTag tag; AzTreeViewOnExpand payload; };
+typedef struct AzOptionTreeViewOnExpandVariant_Some AzOptionTreeViewOnExpandVariant_Some;
+union AzOptionTreeViewOnExpand {
+    AzOptionTreeViewOnExpandVariant_None None;
+    AzOptionTreeViewOnExpandVariant_Some Some;
+};
+typedef union AzOptionTreeViewOnExpand AzOptionTreeViewOnExpand;
+
+enum AzOptionTreeViewOnSelectTag {
+   AzOptionTreeViewOnSelectTag_None,
+   AzOptionTreeViewOnSelectTag_Some,
+};
+typedef enum AzOptionTreeViewOnSelectTag AzOptionTreeViewOnSelectTag;
+
+struct AzOptionTreeViewOnSelectVariant_None { AzOptionTreeViewOnSelectTag tag; };
+typedef struct AzOptionTreeViewOnSelectVariant_None AzOptionTreeViewOnSelectVariant_None;
+struct AzOptionTreeViewOnSelectVariant_Some { AzOptionTreeViewOnSelectTag tag; AzTreeViewOnSelect payload; };
+typedef struct AzOptionTreeViewOnSelectVariant_Some AzOptionTreeViewOnSelectVariant_Some;
+union AzOptionTreeViewOnSelect {
+    AzOptionTreeViewOnSelectVariant_None None;
+    AzOptionTreeViewOnSelectVariant_Some Some;
+};
+typedef union AzOptionTreeViewOnSelect AzOptionTreeViewOnSelect;
+
+enum AzOptionTreeViewOnLazyLoadTag {
+   AzOptionTreeViewOnLazyLoadTag_None,
+   AzOptionTreeViewOnLazyLoadTag_Some,
+};
+typedef enum AzOptionTreeViewOnLazyLoadTag AzOptionTreeViewOnLazyLoadTag;
+
+struct AzOptionTreeViewOnLazyLoadVariant_None { AzOptionTreeViewOnLazyLoadTag tag; };
+typedef struct AzOptionTreeViewOnLazyLoadVariant_None AzOptionTreeViewOnLazyLoadVariant_None;
+struct AzOptionTreeViewOnLazyLoadVariant_Some { AzOptionTreeViewOnLazyLoadTag tag; AzTreeViewOnLazyLoad payload; };
+typedef struct AzOptionTreeViewOnLazyLoadVariant_Some AzOptionTreeViewOnLazyLoadVariant_Some;
+union AzOptionTreeViewOnLazyLoad {
+    AzOptionTreeViewOnLazyLoadVariant_None None;
+    AzOptionTreeViewOnLazyLoadVariant_Some Some;
+};
+typedef union AzOptionTreeViewOnLazyLoad AzOptionTreeViewOnLazyLoad;
+
+enum AzOptionTreeViewOnScrollTag {
+   AzOptionTreeViewOnScrollTag_None,
+   AzOptionTreeViewOnScrollTag_Some,
+};
+typedef enum AzOptionTreeViewOnScrollTag AzOptionTreeViewOnScrollTag;
+
+struct AzOptionTreeViewOnScrollVariant_None { AzOptionTreeViewOnScrollTag tag; };
+typedef struct AzOptionTreeViewOnScrollVariant_None AzOptionTreeViewOnScrollVariant_None;
+struct AzOptionTreeViewOnScrollVariant_Some { AzOptionTreeViewOnScrollTag tag; AzTreeViewOnScroll payload; };
+typedef struct AzOptionTreeViewOnScrollVariant_Some AzOptionTreeViewOnScrollVariant_Some;
+union AzOptionTreeViewOnScroll {
+    AzOptionTreeViewOnScrollVariant_None None;
+    AzOptionTreeViewOnScrollVariant_Some Some;
+};
+typedef union AzOptionTreeViewOnScroll AzOptionTreeViewOnScroll;
+
+enum AzOptionListViewOnRowClickTag {
+   AzOptionListViewOnRowClickTag_None,
+   AzOptionListViewOnRowClickTag_Some,
 };
 typedef enum AzOptionListViewOnRowClickTag AzOptionListViewOnRowClickTag;
 
@@ -6917,6 +7883,326 @@ union AzOptionListViewOnRowClick {
 };
 typedef union AzOptionListViewOnRowClick AzOptionListViewOnRowClick;
 
+enum AzOptionListViewOnRowSelectTag {
+   AzOptionListViewOnRowSelectTag_None,
+   AzOptionListViewOnRowSelectTag_Some,
+};
+typedef enum AzOptionListViewOnRowSelectTag AzOptionListViewOnRowSelectTag;
+
+struct AzOptionListViewOnRowSelectVariant_None { AzOptionListViewOnRowSelectTag tag; };
+typedef struct AzOptionListViewOnRowSelectVariant_None AzOptionListViewOnRowSelectVariant_None;
+struct AzOptionListViewOnRowSelectVariant_Some { AzOptionListViewOnRowSelectTag tag; AzListViewOnRowSelect payload; };
+typedef struct AzOptionListViewOnRowSelectVariant_Some AzOptionListViewOnRowSelectVariant_Some;
+union AzOptionListViewOnRowSelect {
+    AzOptionListViewOnRowSelectVariant_None None;
+    AzOptionListViewOnRowSelectVariant_Some Some;
+};
+typedef union AzOptionListViewOnRowSelect AzOptionListViewOnRowSelect;
+
+enum AzOptionListViewOnColumnResizeTag {
+   AzOptionListViewOnColumnResizeTag_None,
+   AzOptionListViewOnColumnResizeTag_Some,
+};
+typedef enum AzOptionListViewOnColumnResizeTag AzOptionListViewOnColumnResizeTag;
+
+struct AzOptionListViewOnColumnResizeVariant_None { AzOptionListViewOnColumnResizeTag tag; };
+typedef struct AzOptionListViewOnColumnResizeVariant_None AzOptionListViewOnColumnResizeVariant_None;
+struct AzOptionListViewOnColumnResizeVariant_Some { AzOptionListViewOnColumnResizeTag tag; AzListViewOnColumnResize payload; };
+typedef struct AzOptionListViewOnColumnResizeVariant_Some AzOptionListViewOnColumnResizeVariant_Some;
+union AzOptionListViewOnColumnResize {
+    AzOptionListViewOnColumnResizeVariant_None None;
+    AzOptionListViewOnColumnResizeVariant_Some Some;
+};
+typedef union AzOptionListViewOnColumnResize AzOptionListViewOnColumnResize;
+
+enum AzOptionListViewRowProviderTag {
+   AzOptionListViewRowProviderTag_None,
+   AzOptionListViewRowProviderTag_Some,
+};
+typedef enum AzOptionListViewRowProviderTag AzOptionListViewRowProviderTag;
+
+struct AzOptionListViewRowProviderVariant_None { AzOptionListViewRowProviderTag tag; };
+typedef struct AzOptionListViewRowProviderVariant_None AzOptionListViewRowProviderVariant_None;
+struct AzOptionListViewRowProviderVariant_Some { AzOptionListViewRowProviderTag tag; AzListViewRowProvider payload; };
+typedef struct AzOptionListViewRowProviderVariant_Some AzOptionListViewRowProviderVariant_Some;
+union AzOptionListViewRowProvider {
+    AzOptionListViewRowProviderVariant_None None;
+    AzOptionListViewRowProviderVariant_Some Some;
+};
+typedef union AzOptionListViewRowProvider AzOptionListViewRowProvider;
+
+enum AzOptionDataGridRangeTag {
+   AzOptionDataGridRangeTag_None,
+   AzOptionDataGridRangeTag_Some,
+};
+typedef enum AzOptionDataGridRangeTag AzOptionDataGridRangeTag;
+
+struct AzOptionDataGridRangeVariant_None { AzOptionDataGridRangeTag tag; };
+typedef struct AzOptionDataGridRangeVariant_None AzOptionDataGridRangeVariant_None;
+struct AzOptionDataGridRangeVariant_Some { AzOptionDataGridRangeTag tag; AzDataGridRange payload; };
+typedef struct AzOptionDataGridRangeVariant_Some AzOptionDataGridRangeVariant_Some;
+union AzOptionDataGridRange {
+    AzOptionDataGridRangeVariant_None None;
+    AzOptionDataGridRangeVariant_Some Some;
+};
+typedef union AzOptionDataGridRange AzOptionDataGridRange;
+
+enum AzOptionDataGridCellProviderTag {
+   AzOptionDataGridCellProviderTag_None,
+   AzOptionDataGridCellProviderTag_Some,
+};
+typedef enum AzOptionDataGridCellProviderTag AzOptionDataGridCellProviderTag;
+
+struct AzOptionDataGridCellProviderVariant_None { AzOptionDataGridCellProviderTag tag; };
+typedef struct AzOptionDataGridCellProviderVariant_None AzOptionDataGridCellProviderVariant_None;
+struct AzOptionDataGridCellProviderVariant_Some { AzOptionDataGridCellProviderTag tag; AzDataGridCellProvider payload; };
+typedef struct AzOptionDataGridCellProviderVariant_Some AzOptionDataGridCellProviderVariant_Some;
+union AzOptionDataGridCellProvider {
+    AzOptionDataGridCellProviderVariant_None None;
+    AzOptionDataGridCellProviderVariant_Some Some;
+};
+typedef union AzOptionDataGridCellProvider AzOptionDataGridCellProvider;
+
+enum AzOptionDataGridCellStyleTag {
+   AzOptionDataGridCellStyleTag_None,
+   AzOptionDataGridCellStyleTag_Some,
+};
+typedef enum AzOptionDataGridCellStyleTag AzOptionDataGridCellStyleTag;
+
+struct AzOptionDataGridCellStyleVariant_None { AzOptionDataGridCellStyleTag tag; };
+typedef struct AzOptionDataGridCellStyleVariant_None AzOptionDataGridCellStyleVariant_None;
+struct AzOptionDataGridCellStyleVariant_Some { AzOptionDataGridCellStyleTag tag; AzDataGridCellStyle payload; };
+typedef struct AzOptionDataGridCellStyleVariant_Some AzOptionDataGridCellStyleVariant_Some;
+union AzOptionDataGridCellStyle {
+    AzOptionDataGridCellStyleVariant_None None;
+    AzOptionDataGridCellStyleVariant_Some Some;
+};
+typedef union AzOptionDataGridCellStyle AzOptionDataGridCellStyle;
+
+enum AzOptionDataGridOnSelectTag {
+   AzOptionDataGridOnSelectTag_None,
+   AzOptionDataGridOnSelectTag_Some,
+};
+typedef enum AzOptionDataGridOnSelectTag AzOptionDataGridOnSelectTag;
+
+struct AzOptionDataGridOnSelectVariant_None { AzOptionDataGridOnSelectTag tag; };
+typedef struct AzOptionDataGridOnSelectVariant_None AzOptionDataGridOnSelectVariant_None;
+struct AzOptionDataGridOnSelectVariant_Some { AzOptionDataGridOnSelectTag tag; AzDataGridOnSelect payload; };
+typedef struct AzOptionDataGridOnSelectVariant_Some AzOptionDataGridOnSelectVariant_Some;
+union AzOptionDataGridOnSelect {
+    AzOptionDataGridOnSelectVariant_None None;
+    AzOptionDataGridOnSelectVariant_Some Some;
+};
+typedef union AzOptionDataGridOnSelect AzOptionDataGridOnSelect;
+
+enum AzOptionDataGridOnScrollTag {
+   AzOptionDataGridOnScrollTag_None,
+   AzOptionDataGridOnScrollTag_Some,
+};
+typedef enum AzOptionDataGridOnScrollTag AzOptionDataGridOnScrollTag;
+
+struct AzOptionDataGridOnScrollVariant_None { AzOptionDataGridOnScrollTag tag; };
+typedef struct AzOptionDataGridOnScrollVariant_None AzOptionDataGridOnScrollVariant_None;
+struct AzOptionDataGridOnScrollVariant_Some { AzOptionDataGridOnScrollTag tag; AzDataGridOnScroll payload; };
+typedef struct AzOptionDataGridOnScrollVariant_Some AzOptionDataGridOnScrollVariant_Some;
+union AzOptionDataGridOnScroll {
+    AzOptionDataGridOnScrollVariant_None None;
+    AzOptionDataGridOnScrollVariant_Some Some;
+};
+typedef union AzOptionDataGridOnScroll AzOptionDataGridOnScroll;
+
+enum AzOptionDataGridOnCellEditTag {
+   AzOptionDataGridOnCellEditTag_None,
+   AzOptionDataGridOnCellEditTag_Some,
+};
+typedef enum AzOptionDataGridOnCellEditTag AzOptionDataGridOnCellEditTag;
+
+struct AzOptionDataGridOnCellEditVariant_None { AzOptionDataGridOnCellEditTag tag; };
+typedef struct AzOptionDataGridOnCellEditVariant_None AzOptionDataGridOnCellEditVariant_None;
+struct AzOptionDataGridOnCellEditVariant_Some { AzOptionDataGridOnCellEditTag tag; AzDataGridOnCellEdit payload; };
+typedef struct AzOptionDataGridOnCellEditVariant_Some AzOptionDataGridOnCellEditVariant_Some;
+union AzOptionDataGridOnCellEdit {
+    AzOptionDataGridOnCellEditVariant_None None;
+    AzOptionDataGridOnCellEditVariant_Some Some;
+};
+typedef union AzOptionDataGridOnCellEdit AzOptionDataGridOnCellEdit;
+
+enum AzOptionDataGridOnColumnResizeTag {
+   AzOptionDataGridOnColumnResizeTag_None,
+   AzOptionDataGridOnColumnResizeTag_Some,
+};
+typedef enum AzOptionDataGridOnColumnResizeTag AzOptionDataGridOnColumnResizeTag;
+
+struct AzOptionDataGridOnColumnResizeVariant_None { AzOptionDataGridOnColumnResizeTag tag; };
+typedef struct AzOptionDataGridOnColumnResizeVariant_None AzOptionDataGridOnColumnResizeVariant_None;
+struct AzOptionDataGridOnColumnResizeVariant_Some { AzOptionDataGridOnColumnResizeTag tag; AzDataGridOnColumnResize payload; };
+typedef struct AzOptionDataGridOnColumnResizeVariant_Some AzOptionDataGridOnColumnResizeVariant_Some;
+union AzOptionDataGridOnColumnResize {
+    AzOptionDataGridOnColumnResizeVariant_None None;
+    AzOptionDataGridOnColumnResizeVariant_Some Some;
+};
+typedef union AzOptionDataGridOnColumnResize AzOptionDataGridOnColumnResize;
+
+enum AzOptionDataGridOnRowResizeTag {
+   AzOptionDataGridOnRowResizeTag_None,
+   AzOptionDataGridOnRowResizeTag_Some,
+};
+typedef enum AzOptionDataGridOnRowResizeTag AzOptionDataGridOnRowResizeTag;
+
+struct AzOptionDataGridOnRowResizeVariant_None { AzOptionDataGridOnRowResizeTag tag; };
+typedef struct AzOptionDataGridOnRowResizeVariant_None AzOptionDataGridOnRowResizeVariant_None;
+struct AzOptionDataGridOnRowResizeVariant_Some { AzOptionDataGridOnRowResizeTag tag; AzDataGridOnRowResize payload; };
+typedef struct AzOptionDataGridOnRowResizeVariant_Some AzOptionDataGridOnRowResizeVariant_Some;
+union AzOptionDataGridOnRowResize {
+    AzOptionDataGridOnRowResizeVariant_None None;
+    AzOptionDataGridOnRowResizeVariant_Some Some;
+};
+typedef union AzOptionDataGridOnRowResize AzOptionDataGridOnRowResize;
+
+enum AzOptionDataGridOnCopyRangeTag {
+   AzOptionDataGridOnCopyRangeTag_None,
+   AzOptionDataGridOnCopyRangeTag_Some,
+};
+typedef enum AzOptionDataGridOnCopyRangeTag AzOptionDataGridOnCopyRangeTag;
+
+struct AzOptionDataGridOnCopyRangeVariant_None { AzOptionDataGridOnCopyRangeTag tag; };
+typedef struct AzOptionDataGridOnCopyRangeVariant_None AzOptionDataGridOnCopyRangeVariant_None;
+struct AzOptionDataGridOnCopyRangeVariant_Some { AzOptionDataGridOnCopyRangeTag tag; AzDataGridOnCopyRange payload; };
+typedef struct AzOptionDataGridOnCopyRangeVariant_Some AzOptionDataGridOnCopyRangeVariant_Some;
+union AzOptionDataGridOnCopyRange {
+    AzOptionDataGridOnCopyRangeVariant_None None;
+    AzOptionDataGridOnCopyRangeVariant_Some Some;
+};
+typedef union AzOptionDataGridOnCopyRange AzOptionDataGridOnCopyRange;
+
+enum AzOptionDataGridOnClearRangeTag {
+   AzOptionDataGridOnClearRangeTag_None,
+   AzOptionDataGridOnClearRangeTag_Some,
+};
+typedef enum AzOptionDataGridOnClearRangeTag AzOptionDataGridOnClearRangeTag;
+
+struct AzOptionDataGridOnClearRangeVariant_None { AzOptionDataGridOnClearRangeTag tag; };
+typedef struct AzOptionDataGridOnClearRangeVariant_None AzOptionDataGridOnClearRangeVariant_None;
+struct AzOptionDataGridOnClearRangeVariant_Some { AzOptionDataGridOnClearRangeTag tag; AzDataGridOnClearRange payload; };
+typedef struct AzOptionDataGridOnClearRangeVariant_Some AzOptionDataGridOnClearRangeVariant_Some;
+union AzOptionDataGridOnClearRange {
+    AzOptionDataGridOnClearRangeVariant_None None;
+    AzOptionDataGridOnClearRangeVariant_Some Some;
+};
+typedef union AzOptionDataGridOnClearRange AzOptionDataGridOnClearRange;
+
+enum AzOptionDateTag {
+   AzOptionDateTag_None,
+   AzOptionDateTag_Some,
+};
+typedef enum AzOptionDateTag AzOptionDateTag;
+
+struct AzOptionDateVariant_None { AzOptionDateTag tag; };
+typedef struct AzOptionDateVariant_None AzOptionDateVariant_None;
+struct AzOptionDateVariant_Some { AzOptionDateTag tag; AzDate payload; };
+typedef struct AzOptionDateVariant_Some AzOptionDateVariant_Some;
+union AzOptionDate {
+    AzOptionDateVariant_None None;
+    AzOptionDateVariant_Some Some;
+};
+typedef union AzOptionDate AzOptionDate;
+
+enum AzOptionTimeTag {
+   AzOptionTimeTag_None,
+   AzOptionTimeTag_Some,
+};
+typedef enum AzOptionTimeTag AzOptionTimeTag;
+
+struct AzOptionTimeVariant_None { AzOptionTimeTag tag; };
+typedef struct AzOptionTimeVariant_None AzOptionTimeVariant_None;
+struct AzOptionTimeVariant_Some { AzOptionTimeTag tag; AzTime payload; };
+typedef struct AzOptionTimeVariant_Some AzOptionTimeVariant_Some;
+union AzOptionTime {
+    AzOptionTimeVariant_None None;
+    AzOptionTimeVariant_Some Some;
+};
+typedef union AzOptionTime AzOptionTime;
+
+enum AzOptionDatePickerOnDateChangeTag {
+   AzOptionDatePickerOnDateChangeTag_None,
+   AzOptionDatePickerOnDateChangeTag_Some,
+};
+typedef enum AzOptionDatePickerOnDateChangeTag AzOptionDatePickerOnDateChangeTag;
+
+struct AzOptionDatePickerOnDateChangeVariant_None { AzOptionDatePickerOnDateChangeTag tag; };
+typedef struct AzOptionDatePickerOnDateChangeVariant_None AzOptionDatePickerOnDateChangeVariant_None;
+struct AzOptionDatePickerOnDateChangeVariant_Some { AzOptionDatePickerOnDateChangeTag tag; AzDatePickerOnDateChange payload; };
+typedef struct AzOptionDatePickerOnDateChangeVariant_Some AzOptionDatePickerOnDateChangeVariant_Some;
+union AzOptionDatePickerOnDateChange {
+    AzOptionDatePickerOnDateChangeVariant_None None;
+    AzOptionDatePickerOnDateChangeVariant_Some Some;
+};
+typedef union AzOptionDatePickerOnDateChange AzOptionDatePickerOnDateChange;
+
+enum AzOptionTimePickerOnTimeChangeTag {
+   AzOptionTimePickerOnTimeChangeTag_None,
+   AzOptionTimePickerOnTimeChangeTag_Some,
+};
+typedef enum AzOptionTimePickerOnTimeChangeTag AzOptionTimePickerOnTimeChangeTag;
+
+struct AzOptionTimePickerOnTimeChangeVariant_None { AzOptionTimePickerOnTimeChangeTag tag; };
+typedef struct AzOptionTimePickerOnTimeChangeVariant_None AzOptionTimePickerOnTimeChangeVariant_None;
+struct AzOptionTimePickerOnTimeChangeVariant_Some { AzOptionTimePickerOnTimeChangeTag tag; AzTimePickerOnTimeChange payload; };
+typedef struct AzOptionTimePickerOnTimeChangeVariant_Some AzOptionTimePickerOnTimeChangeVariant_Some;
+union AzOptionTimePickerOnTimeChange {
+    AzOptionTimePickerOnTimeChangeVariant_None None;
+    AzOptionTimePickerOnTimeChangeVariant_Some Some;
+};
+typedef union AzOptionTimePickerOnTimeChange AzOptionTimePickerOnTimeChange;
+
+enum AzOptionDateTimePickerOnDateTimeChangeTag {
+   AzOptionDateTimePickerOnDateTimeChangeTag_None,
+   AzOptionDateTimePickerOnDateTimeChangeTag_Some,
+};
+typedef enum AzOptionDateTimePickerOnDateTimeChangeTag AzOptionDateTimePickerOnDateTimeChangeTag;
+
+struct AzOptionDateTimePickerOnDateTimeChangeVariant_None { AzOptionDateTimePickerOnDateTimeChangeTag tag; };
+typedef struct AzOptionDateTimePickerOnDateTimeChangeVariant_None AzOptionDateTimePickerOnDateTimeChangeVariant_None;
+struct AzOptionDateTimePickerOnDateTimeChangeVariant_Some { AzOptionDateTimePickerOnDateTimeChangeTag tag; AzDateTimePickerOnDateTimeChange payload; };
+typedef struct AzOptionDateTimePickerOnDateTimeChangeVariant_Some AzOptionDateTimePickerOnDateTimeChangeVariant_Some;
+union AzOptionDateTimePickerOnDateTimeChange {
+    AzOptionDateTimePickerOnDateTimeChangeVariant_None None;
+    AzOptionDateTimePickerOnDateTimeChangeVariant_Some Some;
+};
+typedef union AzOptionDateTimePickerOnDateTimeChange AzOptionDateTimePickerOnDateTimeChange;
+
+enum AzOptionDialogOnCloseTag {
+   AzOptionDialogOnCloseTag_None,
+   AzOptionDialogOnCloseTag_Some,
+};
+typedef enum AzOptionDialogOnCloseTag AzOptionDialogOnCloseTag;
+
+struct AzOptionDialogOnCloseVariant_None { AzOptionDialogOnCloseTag tag; };
+typedef struct AzOptionDialogOnCloseVariant_None AzOptionDialogOnCloseVariant_None;
+struct AzOptionDialogOnCloseVariant_Some { AzOptionDialogOnCloseTag tag; AzDialogOnClose payload; };
+typedef struct AzOptionDialogOnCloseVariant_Some AzOptionDialogOnCloseVariant_Some;
+union AzOptionDialogOnClose {
+    AzOptionDialogOnCloseVariant_None None;
+    AzOptionDialogOnCloseVariant_Some Some;
+};
+typedef union AzOptionDialogOnClose AzOptionDialogOnClose;
+
+enum AzOptionSplitterOnResizeTag {
+   AzOptionSplitterOnResizeTag_None,
+   AzOptionSplitterOnResizeTag_Some,
+};
+typedef enum AzOptionSplitterOnResizeTag AzOptionSplitterOnResizeTag;
+
+struct AzOptionSplitterOnResizeVariant_None { AzOptionSplitterOnResizeTag tag; };
+typedef struct AzOptionSplitterOnResizeVariant_None AzOptionSplitterOnResizeVariant_None;
+struct AzOptionSplitterOnResizeVariant_Some { AzOptionSplitterOnResizeTag tag; AzSplitterOnResize payload; };
+typedef struct AzOptionSplitterOnResizeVariant_Some AzOptionSplitterOnResizeVariant_Some;
+union AzOptionSplitterOnResize {
+    AzOptionSplitterOnResizeVariant_None None;
+    AzOptionSplitterOnResizeVariant_Some Some;
+};
+typedef union AzOptionSplitterOnResize AzOptionSplitterOnResize;
+
 enum AzOptionListViewOnColumnClickTag {
    AzOptionListViewOnColumnClickTag_None,
    AzOptionListViewOnColumnClickTag_Some,
@@ -6981,6 +8267,54 @@ union AzOptionDropDownOnChoiceChange {
 };
 typedef union AzOptionDropDownOnChoiceChange AzOptionDropDownOnChoiceChange;
 
+enum AzOptionNodeGraphOnNodeGraphZoomedTag {
+   AzOptionNodeGraphOnNodeGraphZoomedTag_None,
+   AzOptionNodeGraphOnNodeGraphZoomedTag_Some,
+};
+typedef enum AzOptionNodeGraphOnNodeGraphZoomedTag AzOptionNodeGraphOnNodeGraphZoomedTag;
+
+struct AzOptionNodeGraphOnNodeGraphZoomedVariant_None { AzOptionNodeGraphOnNodeGraphZoomedTag tag; };
+typedef struct AzOptionNodeGraphOnNodeGraphZoomedVariant_None AzOptionNodeGraphOnNodeGraphZoomedVariant_None;
+struct AzOptionNodeGraphOnNodeGraphZoomedVariant_Some { AzOptionNodeGraphOnNodeGraphZoomedTag tag; AzNodeGraphOnNodeGraphZoomed payload; };
+typedef struct AzOptionNodeGraphOnNodeGraphZoomedVariant_Some AzOptionNodeGraphOnNodeGraphZoomedVariant_Some;
+union AzOptionNodeGraphOnNodeGraphZoomed {
+    AzOptionNodeGraphOnNodeGraphZoomedVariant_None None;
+    AzOptionNodeGraphOnNodeGraphZoomedVariant_Some Some;
+};
+typedef union AzOptionNodeGraphOnNodeGraphZoomed AzOptionNodeGraphOnNodeGraphZoomed;
+
+enum AzOptionNodeGraphOnSelectionChangedTag {
+   AzOptionNodeGraphOnSelectionChangedTag_None,
+   AzOptionNodeGraphOnSelectionChangedTag_Some,
+};
+typedef enum AzOptionNodeGraphOnSelectionChangedTag AzOptionNodeGraphOnSelectionChangedTag;
+
+struct AzOptionNodeGraphOnSelectionChangedVariant_None { AzOptionNodeGraphOnSelectionChangedTag tag; };
+typedef struct AzOptionNodeGraphOnSelectionChangedVariant_None AzOptionNodeGraphOnSelectionChangedVariant_None;
+struct AzOptionNodeGraphOnSelectionChangedVariant_Some { AzOptionNodeGraphOnSelectionChangedTag tag; AzNodeGraphOnSelectionChanged payload; };
+typedef struct AzOptionNodeGraphOnSelectionChangedVariant_Some AzOptionNodeGraphOnSelectionChangedVariant_Some;
+union AzOptionNodeGraphOnSelectionChanged {
+    AzOptionNodeGraphOnSelectionChangedVariant_None None;
+    AzOptionNodeGraphOnSelectionChangedVariant_Some Some;
+};
+typedef union AzOptionNodeGraphOnSelectionChanged AzOptionNodeGraphOnSelectionChanged;
+
+enum AzOptionNodeGraphOnHistoryChangedTag {
+   AzOptionNodeGraphOnHistoryChangedTag_None,
+   AzOptionNodeGraphOnHistoryChangedTag_Some,
+};
+typedef enum AzOptionNodeGraphOnHistoryChangedTag AzOptionNodeGraphOnHistoryChangedTag;
+
+struct AzOptionNodeGraphOnHistoryChangedVariant_None { AzOptionNodeGraphOnHistoryChangedTag tag; };
+typedef struct AzOptionNodeGraphOnHistoryChangedVariant_None AzOptionNodeGraphOnHistoryChangedVariant_None;
+struct AzOptionNodeGraphOnHistoryChangedVariant_Some { AzOptionNodeGraphOnHistoryChangedTag tag; AzNodeGraphOnHistoryChanged payload; };
+typedef struct AzOptionNodeGraphOnHistoryChangedVariant_Some AzOptionNodeGraphOnHistoryChangedVariant_Some;
+union AzOptionNodeGraphOnHistoryChanged {
+    AzOptionNodeGraphOnHistoryChangedVariant_None None;
+    AzOptionNodeGraphOnHistoryChangedVariant_Some Some;
+};
+typedef union AzOptionNodeGraphOnHistoryChanged AzOptionNodeGraphOnHistoryChanged;
+
 enum AzOptionNodeGraphOnNodeAddedTag {
    AzOptionNodeGraphOnNodeAddedTag_None,
    AzOptionNodeGraphOnNodeAddedTag_Some,
@@ -7955,6 +9289,14 @@ struct AzMarshaledLayoutCallback {
 };
 typedef struct AzMarshaledLayoutCallback AzMarshaledLayoutCallback;
 
+struct AzTextMetrics {
+    float width;
+    float height;
+    size_t line_count;
+    AzTextLineMetricsVec lines;
+};
+typedef struct AzTextMetrics AzTextMetrics;
+
 struct AzInlineTextContents {
     AzInlineGlyphVec glyphs;
     AzLogicalRect bounds;
@@ -8027,6 +9369,7 @@ struct AzLayoutCallbackInfo {
     void* image_cache;
     AzOptionGl* gl_context;
     void* system_fonts;
+    void* callbacks;
     void* _reserved_ref;
     void* restrict _reserved_mut;
 };
@@ -8076,6 +9419,12 @@ struct AzVirtualKeyCodeCombo {
 };
 typedef struct AzVirtualKeyCodeCombo AzVirtualKeyCodeCombo;
 
+struct AzKeyboardShortcut {
+    AzVirtualKeyCodeCombo keys;
+    AzMenuCallback callback;
+};
+typedef struct AzKeyboardShortcut AzKeyboardShortcut;
+
 enum AzCssPathPseudoSelectorTag {
    AzCssPathPseudoSelectorTag_First,
    AzCssPathPseudoSelectorTag_Last,
@@ -8415,6 +9764,9 @@ struct AzNodeGraphCallbacks {
     AzOptionNodeGraphOnNodeInputDisconnected on_node_input_disconnected;
     AzOptionNodeGraphOnNodeOutputDisconnected on_node_output_disconnected;
     AzOptionNodeGraphOnNodeFieldEdited on_node_field_edited;
+    AzOptionNodeGraphOnNodeGraphZoomed on_node_graph_zoomed;
+    AzOptionNodeGraphOnSelectionChanged on_selection_changed;
+    AzOptionNodeGraphOnHistoryChanged on_history_changed;
 };
 typedef struct AzNodeGraphCallbacks AzNodeGraphCallbacks;
 
@@ -8433,9 +9785,69 @@ typedef struct AzOutputConnection AzOutputConnection;
 struct AzListViewRow {
     AzDomVec cells;
     AzOptionPixelValueNoPercent height;
+    bool  is_selected;
 };
 typedef struct AzListViewRow AzListViewRow;
 
+struct AzTreeView {
+    AzTreeViewItemVec items;
+    AzTreeViewSelectionMode selection_mode;
+    size_t first_visible_row;
+    size_t max_visible_rows;
+    AzOptionUsize focused_item;
+    AzOptionUsize selection_anchor;
+    AzOptionTreeViewOnExpand on_expand;
+    AzOptionTreeViewOnSelect on_select;
+    AzOptionTreeViewOnLazyLoad on_lazy_load;
+    AzOptionTreeViewOnScroll on_scroll;
+};
+typedef struct AzTreeView AzTreeView;
+
+struct AzDatePickerState {
+    AzOptionDate selected;
+    AzDate cursor;
+};
+typedef struct AzDatePickerState AzDatePickerState;
+
+struct AzDatePicker {
+    AzDatePickerState state;
+    AzOptionDate minimum_date;
+    AzOptionDate maximum_date;
+    AzWeekday first_day_of_week;
+    AzOptionDatePickerOnDateChange on_date_change;
+};
+typedef struct AzDatePicker AzDatePicker;
+
+struct AzTimePicker {
+    AzTime time;
+    bool  show_seconds;
+    AzOptionTimePickerOnTimeChange on_time_change;
+};
+typedef struct AzTimePicker AzTimePicker;
+
+struct AzDateTimePickerState {
+    AzDatePickerState date;
+    AzTime time;
+    bool  is_open;
+};
+typedef struct AzDateTimePickerState AzDateTimePickerState;
+
+struct AzDateTimePicker {
+    AzDateTimePickerState state;
+    AzOptionDate minimum_date;
+    AzOptionDate maximum_date;
+    AzWeekday first_day_of_week;
+    bool  show_time;
+    bool  show_seconds;
+    AzOptionDateTimePickerOnDateTimeChange on_date_time_change;
+};
+typedef struct AzDateTimePicker AzDateTimePicker;
+
+struct AzSplitterState {
+    AzSplitterPaneStateVec panes;
+};
+typedef struct AzSplitterState AzSplitterState;
+
 struct AzStyledNode {
     AzStyledNodeState state;
     AzOptionTagId tag_id;
@@ -8636,6 +10048,14 @@ struct AzOutputConnectionVec {
 };
 typedef struct AzOutputConnectionVec AzOutputConnectionVec;
 
+struct AzKeyboardShortcutVec {
+    AzKeyboardShortcut* ptr;
+    size_t len;
+    size_t cap;
+    AzKeyboardShortcutVecDestructor destructor;
+};
+typedef struct AzKeyboardShortcutVec AzKeyboardShortcutVec;
+
 struct AzTessellatedSvgNodeVec {
     AzTessellatedSvgNode* ptr;
     size_t len;
@@ -8804,6 +10224,22 @@ union AzOptionThreadReceiveMsg {
 };
 typedef union AzOptionThreadReceiveMsg AzOptionThreadReceiveMsg;
 
+enum AzOptionTextMetricsTag {
+   AzOptionTextMetricsTag_None,
+   AzOptionTextMetricsTag_Some,
+};
+typedef enum AzOptionTextMetricsTag AzOptionTextMetricsTag;
+
+struct AzOptionTextMetricsVariant_None { AzOptionTextMetricsTag tag; };
+typedef struct AzOptionTextMetricsVariant_None AzOptionTextMetricsVariant_None;
+struct AzOptionTextMetricsVariant_Some { AzOptionTextMetricsTag tag; AzTextMetrics payload; };
+typedef struct AzOptionTextMetricsVariant_Some AzOptionTextMetricsVariant_Some;
+union AzOptionTextMetrics {
+    AzOptionTextMetricsVariant_None None;
+    AzOptionTextMetricsVariant_Some Some;
+};
+typedef union AzOptionTextMetrics AzOptionTextMetrics;
+
 enum AzOptionTaskBarIconTag {
    AzOptionTaskBarIconTag_None,
    AzOptionTaskBarIconTag_Some,
@@ -9081,6 +10517,7 @@ struct AzAccessibilityInfo {
     AzAccessibilityStateVec states;
     AzOptionVirtualKeyCodeCombo accelerator;
     AzOptionString default_action;
+    AzOptionString description;
 };
 typedef struct AzAccessibilityInfo AzAccessibilityInfo;
 
@@ -9146,6 +10583,12 @@ union AzCssPathSelector {
 };
 typedef union AzCssPathSelector AzCssPathSelector;
 
+struct AzCssFontFaceUrl {
+    AzString url;
+    AzOptionString format;
+};
+typedef struct AzCssFontFaceUrl AzCssFontFaceUrl;
+
 enum AzStyleBackgroundContentTag {
    AzStyleBackgroundContentTag_LinearGradient,
    AzStyleBackgroundContentTag_RadialGradient,
@@ -9366,6 +10809,15 @@ struct AzInputOutputInfo {
 };
 typedef struct AzInputOutputInfo AzInputOutputInfo;
 
+struct AzNodeGraphFieldEdit {
+    AzNodeGraphNodeId node_id;
+    AzNodeTypeId node_type;
+    size_t field_idx;
+    AzNodeTypeFieldValue old_value;
+    AzNodeTypeFieldValue new_value;
+};
+typedef struct AzNodeGraphFieldEdit AzNodeGraphFieldEdit;
+
 struct AzListView {
     AzStringVec columns;
     AzListViewRowVec rows;
@@ -9376,6 +10828,16 @@ struct AzListView {
     AzOptionListViewOnLazyLoadScroll on_lazy_load_scroll;
     AzOptionListViewOnColumnClick on_column_click;
     AzOptionListViewOnRowClick on_row_click;
+    size_t row_count;
+    float row_height;
+    size_t first_visible_row;
+    AzF32Vec column_widths;
+    bool  sort_descending;
+    AzOptionUsize focused_row;
+    AzOptionUsize selection_anchor;
+    AzOptionListViewRowProvider row_provider;
+    AzOptionListViewOnRowSelect on_row_select;
+    AzOptionListViewOnColumnResize on_column_resize;
 };
 typedef struct AzListView AzListView;
 
@@ -9386,13 +10848,50 @@ struct AzListViewState {
     AzPixelValueNoPercent scroll_offset;
     AzLogicalPosition current_scroll_position;
     AzLogicalSize current_content_height;
+    size_t row_count;
+    size_t first_visible_row;
+    AzOptionUsize focused_row;
+    AzOptionUsize selection_anchor;
 };
 typedef struct AzListViewState AzListViewState;
 
-struct AzTreeView {
-    AzString root;
-};
-typedef struct AzTreeView AzTreeView;
+struct AzTreeViewItem {
+    size_t id;
+    AzString label;
+    AzOptionImageRef icon;
+    AzTreeViewItemVec children;
+    bool  has_lazy_children;
+    bool  is_expanded;
+    bool  is_selected;
+};
+typedef struct AzTreeViewItem AzTreeViewItem;
+
+struct AzDataGrid {
+    size_t row_count;
+    size_t column_count;
+    AzStringVec column_headers;
+    AzF32Vec column_widths;
+    AzF32Vec row_heights;
+    size_t first_visible_row;
+    size_t first_visible_column;
+    AzOptionDataGridRange selection;
+    AzOptionDataGridCellProvider cell_provider;
+    AzOptionDataGridCellStyle cell_style;
+    AzOptionDataGridOnSelect on_select;
+    AzOptionDataGridOnScroll on_scroll;
+    AzOptionDataGridOnCellEdit on_cell_edit;
+    AzOptionDataGridOnColumnResize on_column_resize;
+    AzOptionDataGridOnRowResize on_row_resize;
+    AzOptionDataGridOnCopyRange on_copy_range;
+    AzOptionDataGridOnClearRange on_clear_range;
+};
+typedef struct AzDataGrid AzDataGrid;
+
+struct AzDialogResult {
+    AzDialogButton button;
+    AzString input;
+};
+typedef struct AzDialogResult AzDialogResult;
 
 struct AzDropDown {
     AzStringVec choices;
@@ -9438,6 +10937,7 @@ struct AzRawImage {
     size_t height;
     bool  alpha_premultiplied;
     AzRawImageFormat data_format;
+    AzU8Vec tag;
 };
 typedef struct AzRawImage AzRawImage;
 
@@ -9859,6 +11359,22 @@ struct AzCssPath {
 };
 typedef struct AzCssPath AzCssPath;
 
+enum AzCssFontFaceSourceTag {
+   AzCssFontFaceSourceTag_Url,
+   AzCssFontFaceSourceTag_Local,
+};
+typedef enum AzCssFontFaceSourceTag AzCssFontFaceSourceTag;
+
+struct AzCssFontFaceSourceVariant_Url { AzCssFontFaceSourceTag tag; AzCssFontFaceUrl payload; };
+typedef struct AzCssFontFaceSourceVariant_Url AzCssFontFaceSourceVariant_Url;
+struct AzCssFontFaceSourceVariant_Local { AzCssFontFaceSourceTag tag; AzString payload; };
+typedef struct AzCssFontFaceSourceVariant_Local AzCssFontFaceSourceVariant_Local;
+union AzCssFontFaceSource {
+    AzCssFontFaceSourceVariant_Url Url;
+    AzCssFontFaceSourceVariant_Local Local;
+};
+typedef union AzCssFontFaceSource AzCssFontFaceSource;
+
 enum AzStyleBackgroundContentVecValueTag {
    AzStyleBackgroundContentVecValueTag_Auto,
    AzStyleBackgroundContentVecValueTag_None,
@@ -9990,6 +11506,8 @@ enum AzCssPropertyTag {
    AzCssPropertyTag_Filter,
    AzCssPropertyTag_BackdropFilter,
    AzCssPropertyTag_TextShadow,
+   AzCssPropertyTag_VerticalAlign,
+   AzCssPropertyTag_TextDecoration,
 };
 typedef enum AzCssPropertyTag AzCssPropertyTag;
 
@@ -10141,6 +11659,10 @@ struct AzCssPropertyVariant_BackdropFilter { AzCssPropertyTag tag; AzStyleFilter
 typedef struct AzCssPropertyVariant_BackdropFilter AzCssPropertyVariant_BackdropFilter;
 struct AzCssPropertyVariant_TextShadow { AzCssPropertyTag tag; AzStyleBoxShadowValue payload; };
 typedef struct AzCssPropertyVariant_TextShadow AzCssPropertyVariant_TextShadow;
+struct AzCssPropertyVariant_VerticalAlign { AzCssPropertyTag tag; AzLayoutVerticalAlignValue payload; };
+typedef struct AzCssPropertyVariant_VerticalAlign AzCssPropertyVariant_VerticalAlign;
+struct AzCssPropertyVariant_TextDecoration { AzCssPropertyTag tag; AzStyleTextDecorationValue payload; };
+typedef struct AzCssPropertyVariant_TextDecoration AzCssPropertyVariant_TextDecoration;
 union AzCssProperty {
     AzCssPropertyVariant_TextColor TextColor;
     AzCssPropertyVariant_FontSize FontSize;
@@ -10216,6 +11738,8 @@ union AzCssProperty {
     AzCssPropertyVariant_Filter Filter;
     AzCssPropertyVariant_BackdropFilter BackdropFilter;
     AzCssPropertyVariant_TextShadow TextShadow;
+    AzCssPropertyVariant_VerticalAlign VerticalAlign;
+    AzCssPropertyVariant_TextDecoration TextDecoration;
 };
 typedef union AzCssProperty AzCssProperty;
 
@@ -10355,6 +11879,7 @@ struct AzXmlNode {
     AzStringPairVec attributes;
     AzXmlNodeVec children;
     AzOptionString text;
+    AzXmlSpan span;
 };
 typedef struct AzXmlNode AzXmlNode;
 
@@ -10414,6 +11939,14 @@ struct AzSvgSimpleNodeVec {
 };
 typedef struct AzSvgSimpleNodeVec AzSvgSimpleNodeVec;
 
+struct AzCssFontFaceSourceVec {
+    AzCssFontFaceSource* ptr;
+    size_t len;
+    size_t cap;
+    AzCssFontFaceSourceVecDestructor destructor;
+};
+typedef struct AzCssFontFaceSourceVec AzCssFontFaceSourceVec;
+
 enum AzOptionCssPropertyTag {
    AzOptionCssPropertyTag_None,
    AzOptionCssPropertyTag_Some,
@@ -10503,6 +12036,7 @@ struct AzInlineText {
     float font_size_px;
     size_t last_word_index;
     float baseline_descender_px;
+    AzInlineTextRunVec runs;
 };
 typedef struct AzInlineText AzInlineText;
 
@@ -10564,6 +12098,15 @@ struct AzDynamicCssProperty {
 };
 typedef struct AzDynamicCssProperty AzDynamicCssProperty;
 
+struct AzCssFontFace {
+    AzString font_family;
+    AzCssFontFaceSourceVec src;
+    uint16_t font_weight;
+    AzCssFontFaceStyle font_style;
+    AzCssUnicodeRangeVec unicode_range;
+};
+typedef struct AzCssFontFace AzCssFontFace;
+
 struct AzNode {
     AzNodeTypeId node_type;
     AzNodePosition position;
@@ -10619,6 +12162,14 @@ struct AzNodeDataInlineCssPropertyVec {
 };
 typedef struct AzNodeDataInlineCssPropertyVec AzNodeDataInlineCssPropertyVec;
 
+struct AzCssFontFaceVec {
+    AzCssFontFace* ptr;
+    size_t len;
+    size_t cap;
+    AzCssFontFaceVecDestructor destructor;
+};
+typedef struct AzCssFontFaceVec AzCssFontFaceVec;
+
 enum AzOptionWindowStateTag {
    AzOptionWindowStateTag_None,
    AzOptionWindowStateTag_Some,
@@ -10831,6 +12382,46 @@ struct AzNodeIdNodeMap {
 };
 typedef struct AzNodeIdNodeMap AzNodeIdNodeMap;
 
+enum AzNodeGraphEditOpTag {
+   AzNodeGraphEditOpTag_AddNode,
+   AzNodeGraphEditOpTag_RemoveNode,
+   AzNodeGraphEditOpTag_MoveNode,
+   AzNodeGraphEditOpTag_Connect,
+   AzNodeGraphEditOpTag_Disconnect,
+   AzNodeGraphEditOpTag_EditField,
+};
+typedef enum AzNodeGraphEditOpTag AzNodeGraphEditOpTag;
+
+struct AzNodeGraphEditOpVariant_AddNode { AzNodeGraphEditOpTag tag; AzNodeIdNodeMap payload; };
+typedef struct AzNodeGraphEditOpVariant_AddNode AzNodeGraphEditOpVariant_AddNode;
+struct AzNodeGraphEditOpVariant_RemoveNode { AzNodeGraphEditOpTag tag; AzNodeIdNodeMap payload; };
+typedef struct AzNodeGraphEditOpVariant_RemoveNode AzNodeGraphEditOpVariant_RemoveNode;
+struct AzNodeGraphEditOpVariant_MoveNode { AzNodeGraphEditOpTag tag; AzNodeGraphNodeMove payload; };
+typedef struct AzNodeGraphEditOpVariant_MoveNode AzNodeGraphEditOpVariant_MoveNode;
+struct AzNodeGraphEditOpVariant_Connect { AzNodeGraphEditOpTag tag; AzNodeGraphConnection payload; };
+typedef struct AzNodeGraphEditOpVariant_Connect AzNodeGraphEditOpVariant_Connect;
+struct AzNodeGraphEditOpVariant_Disconnect { AzNodeGraphEditOpTag tag; AzNodeGraphConnection payload; };
+typedef struct AzNodeGraphEditOpVariant_Disconnect AzNodeGraphEditOpVariant_Disconnect;
+struct AzNodeGraphEditOpVariant_EditField { AzNodeGraphEditOpTag tag; AzNodeGraphFieldEdit payload; };
+typedef struct AzNodeGraphEditOpVariant_EditField AzNodeGraphEditOpVariant_EditField;
+union AzNodeGraphEditOp {
+    AzNodeGraphEditOpVariant_AddNode AddNode;
+    AzNodeGraphEditOpVariant_RemoveNode RemoveNode;
+    AzNodeGraphEditOpVariant_MoveNode MoveNode;
+    AzNodeGraphEditOpVariant_Connect Connect;
+    AzNodeGraphEditOpVariant_Disconnect Disconnect;
+    AzNodeGraphEditOpVariant_EditField EditField;
+};
+typedef union AzNodeGraphEditOp AzNodeGraphEditOp;
+
+struct AzNodeGraphEditOpVec {
+    AzNodeGraphEditOp* ptr;
+    size_t len;
+    size_t cap;
+    AzNodeGraphEditOpVecDestructor destructor;
+};
+typedef struct AzNodeGraphEditOpVec AzNodeGraphEditOpVec;
+
 struct AzNodeIdNodeMapVec {
     AzNodeIdNodeMap* ptr;
     size_t len;
@@ -10938,6 +12529,22 @@ struct AzDom {
 };
 typedef struct AzDom AzDom;
 
+enum AzTooltipContentTag {
+   AzTooltipContentTag_Text,
+   AzTooltipContentTag_Dom,
+};
+typedef enum AzTooltipContentTag AzTooltipContentTag;
+
+struct AzTooltipContentVariant_Text { AzTooltipContentTag tag; AzString payload; };
+typedef struct AzTooltipContentVariant_Text AzTooltipContentVariant_Text;
+struct AzTooltipContentVariant_Dom { AzTooltipContentTag tag; AzDom payload; };
+typedef struct AzTooltipContentVariant_Dom AzTooltipContentVariant_Dom;
+union AzTooltipContent {
+    AzTooltipContentVariant_Text Text;
+    AzTooltipContentVariant_Dom Dom;
+};
+typedef union AzTooltipContent AzTooltipContent;
+
 struct AzCssRuleBlock {
     AzCssPath path;
     AzCssDeclarationVec declarations;
@@ -10957,18 +12564,18 @@ struct AzFrame {
 };
 typedef struct AzFrame AzFrame;
 
-struct AzNodeGraph {
-    AzNodeTypeIdInfoMapVec node_types;
-    AzInputOutputTypeIdInfoMapVec input_output_types;
-    AzNodeIdNodeMapVec nodes;
-    bool  allow_multiple_root_nodes;
-    AzLogicalPosition offset;
-    AzNodeGraphStyle style;
-    AzNodeGraphCallbacks callbacks;
-    AzString add_node_str;
-    float scale_factor;
+struct AzNodeGraphEdit {
+    AzNodeGraphEditOpVec ops;
 };
-typedef struct AzNodeGraph AzNodeGraph;
+typedef struct AzNodeGraphEdit AzNodeGraphEdit;
+
+struct AzSplitterPane {
+    AzDom content;
+    AzSplitterPaneState state;
+    float min_size;
+    AzOptionF32 max_size;
+};
+typedef struct AzSplitterPane AzSplitterPane;
 
 struct AzStyledDom {
     AzNodeId root;
@@ -10985,6 +12592,22 @@ struct AzStyledDom {
 };
 typedef struct AzStyledDom AzStyledDom;
 
+struct AzSplitterPaneVec {
+    AzSplitterPane* ptr;
+    size_t len;
+    size_t cap;
+    AzSplitterPaneVecDestructor destructor;
+};
+typedef struct AzSplitterPaneVec AzSplitterPaneVec;
+
+struct AzNodeGraphEditVec {
+    AzNodeGraphEdit* ptr;
+    size_t len;
+    size_t cap;
+    AzNodeGraphEditVecDestructor destructor;
+};
+typedef struct AzNodeGraphEditVec AzNodeGraphEditVec;
+
 struct AzCssRuleBlockVec {
     AzCssRuleBlock* ptr;
     size_t len;
@@ -11066,11 +12689,42 @@ struct AzIFrameCallbackReturn {
 };
 typedef struct AzIFrameCallbackReturn AzIFrameCallbackReturn;
 
+struct AzTooltip {
+    AzTooltipContent content;
+    AzDuration delay;
+};
+typedef struct AzTooltip AzTooltip;
+
 struct AzStylesheet {
     AzCssRuleBlockVec rules;
 };
 typedef struct AzStylesheet AzStylesheet;
 
+struct AzNodeGraphHistory {
+    AzNodeGraphEditVec undo;
+    AzNodeGraphEditVec redo;
+};
+typedef struct AzNodeGraphHistory AzNodeGraphHistory;
+
+struct AzDialog {
+    AzString title;
+    AzString message;
+    AzDialogIcon icon;
+    AzDialogButtons buttons;
+    AzOptionString input;
+    AzOptionDom content;
+    AzOptionDialogOnClose on_close;
+};
+typedef struct AzDialog AzDialog;
+
+struct AzSplitter {
+    AzSplitterDirection direction;
+    AzSplitterPaneVec panes;
+    float handle_size;
+    AzOptionSplitterOnResize on_resize;
+};
+typedef struct AzSplitter AzSplitter;
+
 struct AzStylesheetVec {
     AzStylesheet* ptr;
     size_t len;
@@ -11113,9 +12767,41 @@ typedef union AzResultSvgSvgParseError AzResultSvgSvgParseError;
 
 struct AzCss {
     AzStylesheetVec stylesheets;
+    AzCssFontFaceVec font_faces;
 };
 typedef struct AzCss AzCss;
 
+struct AzNodeGraph {
+    AzNodeTypeIdInfoMapVec node_types;
+    AzInputOutputTypeIdInfoMapVec input_output_types;
+    AzNodeIdNodeMapVec nodes;
+    bool  allow_multiple_root_nodes;
+    AzLogicalPosition offset;
+    AzNodeGraphStyle style;
+    AzNodeGraphCallbacks callbacks;
+    AzString add_node_str;
+    float scale_factor;
+    AzNodeGraphNodeIdVec selected_nodes;
+    AzNodeGraphHistory history;
+};
+typedef struct AzNodeGraph AzNodeGraph;
+
+enum AzResultNodeGraphNodeGraphErrorTag {
+   AzResultNodeGraphNodeGraphErrorTag_Ok,
+   AzResultNodeGraphNodeGraphErrorTag_Err,
+};
+typedef enum AzResultNodeGraphNodeGraphErrorTag AzResultNodeGraphNodeGraphErrorTag;
+
+struct AzResultNodeGraphNodeGraphErrorVariant_Ok { AzResultNodeGraphNodeGraphErrorTag tag; AzNodeGraph payload; };
+typedef struct AzResultNodeGraphNodeGraphErrorVariant_Ok AzResultNodeGraphNodeGraphErrorVariant_Ok;
+struct AzResultNodeGraphNodeGraphErrorVariant_Err { AzResultNodeGraphNodeGraphErrorTag tag; AzNodeGraphError payload; };
+typedef struct AzResultNodeGraphNodeGraphErrorVariant_Err AzResultNodeGraphNodeGraphErrorVariant_Err;
+union AzResultNodeGraphNodeGraphError {
+    AzResultNodeGraphNodeGraphErrorVariant_Ok Ok;
+    AzResultNodeGraphNodeGraphErrorVariant_Err Err;
+};
+typedef union AzResultNodeGraphNodeGraphError AzResultNodeGraphNodeGraphError;
+
 #define AzAnimationRepeatCount_Times(v) { .Times = { .tag = AzAnimationRepeatCountTag_Times, .payload = v } }
 #define AzAnimationRepeatCount_Infinite { .Infinite = { .tag = AzAnimationRepeatCountTag_Infinite } }
 #define AzTabIndex_Auto { .Auto = { .tag = AzTabIndexTag_Auto } }
@@ -11134,6 +12820,15 @@ typedef struct AzCss AzCss;
 #define AzListViewRowVecDestructor_DefaultRust { .DefaultRust = { .tag = AzListViewRowVecDestructorTag_DefaultRust } }
 #define AzListViewRowVecDestructor_NoDestructor { .NoDestructor = { .tag = AzListViewRowVecDestructorTag_NoDestructor } }
 #define AzListViewRowVecDestructor_External(v) { .External = { .tag = AzListViewRowVecDestructorTag_External, .payload = v } }
+#define AzTreeViewItemVecDestructor_DefaultRust { .DefaultRust = { .tag = AzTreeViewItemVecDestructorTag_DefaultRust } }
+#define AzTreeViewItemVecDestructor_NoDestructor { .NoDestructor = { .tag = AzTreeViewItemVecDestructorTag_NoDestructor } }
+#define AzTreeViewItemVecDestructor_External(v) { .External = { .tag = AzTreeViewItemVecDestructorTag_External, .payload = v } }
+#define AzSplitterPaneVecDestructor_DefaultRust { .DefaultRust = { .tag = AzSplitterPaneVecDestructorTag_DefaultRust } }
+#define AzSplitterPaneVecDestructor_NoDestructor { .NoDestructor = { .tag = AzSplitterPaneVecDestructorTag_NoDestructor } }
+#define AzSplitterPaneVecDestructor_External(v) { .External = { .tag = AzSplitterPaneVecDestructorTag_External, .payload = v } }
+#define AzSplitterPaneStateVecDestructor_DefaultRust { .DefaultRust = { .tag = AzSplitterPaneStateVecDestructorTag_DefaultRust } }
+#define AzSplitterPaneStateVecDestructor_NoDestructor { .NoDestructor = { .tag = AzSplitterPaneStateVecDestructorTag_NoDestructor } }
+#define AzSplitterPaneStateVecDestructor_External(v) { .External = { .tag = AzSplitterPaneStateVecDestructorTag_External, .payload = v } }
 #define AzStyleFilterVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStyleFilterVecDestructorTag_DefaultRust } }
 #define AzStyleFilterVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStyleFilterVecDestructorTag_NoDestructor } }
 #define AzStyleFilterVecDestructor_External(v) { .External = { .tag = AzStyleFilterVecDestructorTag_External, .payload = v } }
@@ -11146,6 +12841,15 @@ typedef struct AzCss AzCss;
 #define AzInputOutputTypeIdInfoMapVecDestructor_DefaultRust { .DefaultRust = { .tag = AzInputOutputTypeIdInfoMapVecDestructorTag_DefaultRust } }
 #define AzInputOutputTypeIdInfoMapVecDestructor_NoDestructor { .NoDestructor = { .tag = AzInputOutputTypeIdInfoMapVecDestructorTag_NoDestructor } }
 #define AzInputOutputTypeIdInfoMapVecDestructor_External(v) { .External = { .tag = AzInputOutputTypeIdInfoMapVecDestructorTag_External, .payload = v } }
+#define AzNodeGraphNodeIdVecDestructor_DefaultRust { .DefaultRust = { .tag = AzNodeGraphNodeIdVecDestructorTag_DefaultRust } }
+#define AzNodeGraphNodeIdVecDestructor_NoDestructor { .NoDestructor = { .tag = AzNodeGraphNodeIdVecDestructorTag_NoDestructor } }
+#define AzNodeGraphNodeIdVecDestructor_External(v) { .External = { .tag = AzNodeGraphNodeIdVecDestructorTag_External, .payload = v } }
+#define AzNodeGraphEditOpVecDestructor_DefaultRust { .DefaultRust = { .tag = AzNodeGraphEditOpVecDestructorTag_DefaultRust } }
+#define AzNodeGraphEditOpVecDestructor_NoDestructor { .NoDestructor = { .tag = AzNodeGraphEditOpVecDestructorTag_NoDestructor } }
+#define AzNodeGraphEditOpVecDestructor_External(v) { .External = { .tag = AzNodeGraphEditOpVecDestructorTag_External, .payload = v } }
+#define AzNodeGraphEditVecDestructor_DefaultRust { .DefaultRust = { .tag = AzNodeGraphEditVecDestructorTag_DefaultRust } }
+#define AzNodeGraphEditVecDestructor_NoDestructor { .NoDestructor = { .tag = AzNodeGraphEditVecDestructorTag_NoDestructor } }
+#define AzNodeGraphEditVecDestructor_External(v) { .External = { .tag = AzNodeGraphEditVecDestructorTag_External, .payload = v } }
 #define AzNodeIdNodeMapVecDestructor_DefaultRust { .DefaultRust = { .tag = AzNodeIdNodeMapVecDestructorTag_DefaultRust } }
 #define AzNodeIdNodeMapVecDestructor_NoDestructor { .NoDestructor = { .tag = AzNodeIdNodeMapVecDestructorTag_NoDestructor } }
 #define AzNodeIdNodeMapVecDestructor_External(v) { .External = { .tag = AzNodeIdNodeMapVecDestructorTag_External, .payload = v } }
@@ -11170,6 +12874,9 @@ typedef struct AzCss AzCss;
 #define AzAccessibilityStateVecDestructor_DefaultRust { .DefaultRust = { .tag = AzAccessibilityStateVecDestructorTag_DefaultRust } }
 #define AzAccessibilityStateVecDestructor_NoDestructor { .NoDestructor = { .tag = AzAccessibilityStateVecDestructorTag_NoDestructor } }
 #define AzAccessibilityStateVecDestructor_External(v) { .External = { .tag = AzAccessibilityStateVecDestructorTag_External, .payload = v } }
+#define AzKeyboardShortcutVecDestructor_DefaultRust { .DefaultRust = { .tag = AzKeyboardShortcutVecDestructorTag_DefaultRust } }
+#define AzKeyboardShortcutVecDestructor_NoDestructor { .NoDestructor = { .tag = AzKeyboardShortcutVecDestructorTag_NoDestructor } }
+#define AzKeyboardShortcutVecDestructor_External(v) { .External = { .tag = AzKeyboardShortcutVecDestructorTag_External, .payload = v } }
 #define AzMenuItemVecDestructor_DefaultRust { .DefaultRust = { .tag = AzMenuItemVecDestructorTag_DefaultRust } }
 #define AzMenuItemVecDestructor_NoDestructor { .NoDestructor = { .tag = AzMenuItemVecDestructorTag_NoDestructor } }
 #define AzMenuItemVecDestructor_External(v) { .External = { .tag = AzMenuItemVecDestructorTag_External, .payload = v } }
@@ -11194,6 +12901,12 @@ typedef struct AzCss AzCss;
 #define AzInlineGlyphVecDestructor_DefaultRust { .DefaultRust = { .tag = AzInlineGlyphVecDestructorTag_DefaultRust } }
 #define AzInlineGlyphVecDestructor_NoDestructor { .NoDestructor = { .tag = AzInlineGlyphVecDestructorTag_NoDestructor } }
 #define AzInlineGlyphVecDestructor_External(v) { .External = { .tag = AzInlineGlyphVecDestructorTag_External, .payload = v } }
+#define AzTextLineMetricsVecDestructor_DefaultRust { .DefaultRust = { .tag = AzTextLineMetricsVecDestructorTag_DefaultRust } }
+#define AzTextLineMetricsVecDestructor_NoDestructor { .NoDestructor = { .tag = AzTextLineMetricsVecDestructorTag_NoDestructor } }
+#define AzTextLineMetricsVecDestructor_External(v) { .External = { .tag = AzTextLineMetricsVecDestructorTag_External, .payload = v } }
+#define AzInlineTextRunVecDestructor_DefaultRust { .DefaultRust = { .tag = AzInlineTextRunVecDestructorTag_DefaultRust } }
+#define AzInlineTextRunVecDestructor_NoDestructor { .NoDestructor = { .tag = AzInlineTextRunVecDestructorTag_NoDestructor } }
+#define AzInlineTextRunVecDestructor_External(v) { .External = { .tag = AzInlineTextRunVecDestructorTag_External, .payload = v } }
 #define AzInlineTextHitVecDestructor_DefaultRust { .DefaultRust = { .tag = AzInlineTextHitVecDestructorTag_DefaultRust } }
 #define AzInlineTextHitVecDestructor_NoDestructor { .NoDestructor = { .tag = AzInlineTextHitVecDestructorTag_NoDestructor } }
 #define AzInlineTextHitVecDestructor_External(v) { .External = { .tag = AzInlineTextHitVecDestructorTag_External, .payload = v } }
@@ -11275,6 +12988,15 @@ typedef struct AzCss AzCss;
 #define AzStylesheetVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStylesheetVecDestructorTag_DefaultRust } }
 #define AzStylesheetVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStylesheetVecDestructorTag_NoDestructor } }
 #define AzStylesheetVecDestructor_External(v) { .External = { .tag = AzStylesheetVecDestructorTag_External, .payload = v } }
+#define AzCssFontFaceVecDestructor_DefaultRust { .DefaultRust = { .tag = AzCssFontFaceVecDestructorTag_DefaultRust } }
+#define AzCssFontFaceVecDestructor_NoDestructor { .NoDestructor = { .tag = AzCssFontFaceVecDestructorTag_NoDestructor } }
+#define AzCssFontFaceVecDestructor_External(v) { .External = { .tag = AzCssFontFaceVecDestructorTag_External, .payload = v } }
+#define AzCssFontFaceSourceVecDestructor_DefaultRust { .DefaultRust = { .tag = AzCssFontFaceSourceVecDestructorTag_DefaultRust } }
+#define AzCssFontFaceSourceVecDestructor_NoDestructor { .NoDestructor = { .tag = AzCssFontFaceSourceVecDestructorTag_NoDestructor } }
+#define AzCssFontFaceSourceVecDestructor_External(v) { .External = { .tag = AzCssFontFaceSourceVecDestructorTag_External, .payload = v } }
+#define AzCssUnicodeRangeVecDestructor_DefaultRust { .DefaultRust = { .tag = AzCssUnicodeRangeVecDestructorTag_DefaultRust } }
+#define AzCssUnicodeRangeVecDestructor_NoDestructor { .NoDestructor = { .tag = AzCssUnicodeRangeVecDestructorTag_NoDestructor } }
+#define AzCssUnicodeRangeVecDestructor_External(v) { .External = { .tag = AzCssUnicodeRangeVecDestructorTag_External, .payload = v } }
 #define AzCssRuleBlockVecDestructor_DefaultRust { .DefaultRust = { .tag = AzCssRuleBlockVecDestructorTag_DefaultRust } }
 #define AzCssRuleBlockVecDestructor_NoDestructor { .NoDestructor = { .tag = AzCssRuleBlockVecDestructorTag_NoDestructor } }
 #define AzCssRuleBlockVecDestructor_External(v) { .External = { .tag = AzCssRuleBlockVecDestructorTag_External, .payload = v } }
@@ -11539,6 +13261,11 @@ typedef struct AzCss AzCss;
 #define AzLayoutTopValue_Inherit { .Inherit = { .tag = AzLayoutTopValueTag_Inherit } }
 #define AzLayoutTopValue_Initial { .Initial = { .tag = AzLayoutTopValueTag_Initial } }
 #define AzLayoutTopValue_Exact(v) { .Exact = { .tag = AzLayoutTopValueTag_Exact, .payload = v } }
+#define AzLayoutVerticalAlignValue_Auto { .Auto = { .tag = AzLayoutVerticalAlignValueTag_Auto } }
+#define AzLayoutVerticalAlignValue_None { .None = { .tag = AzLayoutVerticalAlignValueTag_None } }
+#define AzLayoutVerticalAlignValue_Inherit { .Inherit = { .tag = AzLayoutVerticalAlignValueTag_Inherit } }
+#define AzLayoutVerticalAlignValue_Initial { .Initial = { .tag = AzLayoutVerticalAlignValueTag_Initial } }
+#define AzLayoutVerticalAlignValue_Exact(v) { .Exact = { .tag = AzLayoutVerticalAlignValueTag_Exact, .payload = v } }
 #define AzLayoutWidthValue_Auto { .Auto = { .tag = AzLayoutWidthValueTag_Auto } }
 #define AzLayoutWidthValue_None { .None = { .tag = AzLayoutWidthValueTag_None } }
 #define AzLayoutWidthValue_Inherit { .Inherit = { .tag = AzLayoutWidthValueTag_Inherit } }
@@ -11664,6 +13391,11 @@ typedef struct AzCss AzCss;
 #define AzStyleTextAlignValue_Inherit { .Inherit = { .tag = AzStyleTextAlignValueTag_Inherit } }
 #define AzStyleTextAlignValue_Initial { .Initial = { .tag = AzStyleTextAlignValueTag_Initial } }
 #define AzStyleTextAlignValue_Exact(v) { .Exact = { .tag = AzStyleTextAlignValueTag_Exact, .payload = v } }
+#define AzStyleTextDecorationValue_Auto { .Auto = { .tag = AzStyleTextDecorationValueTag_Auto } }
+#define AzStyleTextDecorationValue_None { .None = { .tag = AzStyleTextDecorationValueTag_None } }
+#define AzStyleTextDecorationValue_Inherit { .Inherit = { .tag = AzStyleTextDecorationValueTag_Inherit } }
+#define AzStyleTextDecorationValue_Initial { .Initial = { .tag = AzStyleTextDecorationValueTag_Initial } }
+#define AzStyleTextDecorationValue_Exact(v) { .Exact = { .tag = AzStyleTextDecorationValueTag_Exact, .payload = v } }
 #define AzStyleTextColorValue_Auto { .Auto = { .tag = AzStyleTextColorValueTag_Auto } }
 #define AzStyleTextColorValue_None { .None = { .tag = AzStyleTextColorValueTag_None } }
 #define AzStyleTextColorValue_Inherit { .Inherit = { .tag = AzStyleTextColorValueTag_Inherit } }
@@ -11708,8 +13440,56 @@ typedef struct AzCss AzCss;
 #define AzThreadSendMsg_Custom(v) { .Custom = { .tag = AzThreadSendMsgTag_Custom, .payload = v } }
 #define AzOptionSvgPoint_None { .None = { .tag = AzOptionSvgPointTag_None } }
 #define AzOptionSvgPoint_Some(v) { .Some = { .tag = AzOptionSvgPointTag_Some, .payload = v } }
+#define AzOptionTreeViewOnExpand_None { .None = { .tag = AzOptionTreeViewOnExpandTag_None } }
+#define AzOptionTreeViewOnExpand_Some(v) { .Some = { .tag = AzOptionTreeViewOnExpandTag_Some, .payload = v } }
+#define AzOptionTreeViewOnSelect_None { .None = { .tag = AzOptionTreeViewOnSelectTag_None } }
+#define AzOptionTreeViewOnSelect_Some(v) { .Some = { .tag = AzOptionTreeViewOnSelectTag_Some, .payload = v } }
+#define AzOptionTreeViewOnLazyLoad_None { .None = { .tag = AzOptionTreeViewOnLazyLoadTag_None } }
+#define AzOptionTreeViewOnLazyLoad_Some(v) { .Some = { .tag = AzOptionTreeViewOnLazyLoadTag_Some, .payload = v } }
+#define AzOptionTreeViewOnScroll_None { .None = { .tag = AzOptionTreeViewOnScrollTag_None } }
+#define AzOptionTreeViewOnScroll_Some(v) { .Some = { .tag = AzOptionTreeViewOnScrollTag_Some, .payload = v } }
 #define AzOptionListViewOnRowClick_None { .None = { .tag = AzOptionListViewOnRowClickTag_None } }
 #define AzOptionListViewOnRowClick_Some(v) { .Some = { .tag = AzOptionListViewOnRowClickTag_Some, .payload = v } }
+#define AzOptionListViewOnRowSelect_None { .None = { .tag = AzOptionListViewOnRowSelectTag_None } }
+#define AzOptionListViewOnRowSelect_Some(v) { .Some = { .tag = AzOptionListViewOnRowSelectTag_Some, .payload = v } }
+#define AzOptionListViewOnColumnResize_None { .None = { .tag = AzOptionListViewOnColumnResizeTag_None } }
+#define AzOptionListViewOnColumnResize_Some(v) { .Some = { .tag = AzOptionListViewOnColumnResizeTag_Some, .payload = v } }
+#define AzOptionListViewRowProvider_None { .None = { .tag = AzOptionListViewRowProviderTag_None } }
+#define AzOptionListViewRowProvider_Some(v) { .Some = { .tag = AzOptionListViewRowProviderTag_Some, .payload = v } }
+#define AzOptionDataGridRange_None { .None = { .tag = AzOptionDataGridRangeTag_None } }
+#define AzOptionDataGridRange_Some(v) { .Some = { .tag = AzOptionDataGridRangeTag_Some, .payload = v } }
+#define AzOptionDataGridCellProvider_None { .None = { .tag = AzOptionDataGridCellProviderTag_None } }
+#define AzOptionDataGridCellProvider_Some(v) { .Some = { .tag = AzOptionDataGridCellProviderTag_Some, .payload = v } }
+#define AzOptionDataGridCellStyle_None { .None = { .tag = AzOptionDataGridCellStyleTag_None } }
+#define AzOptionDataGridCellStyle_Some(v) { .Some = { .tag = AzOptionDataGridCellStyleTag_Some, .payload = v } }
+#define AzOptionDataGridOnSelect_None { .None = { .tag = AzOptionDataGridOnSelectTag_None } }
+#define AzOptionDataGridOnSelect_Some(v) { .Some = { .tag = AzOptionDataGridOnSelectTag_Some, .payload = v } }
+#define AzOptionDataGridOnScroll_None { .None = { .tag = AzOptionDataGridOnScrollTag_None } }
+#define AzOptionDataGridOnScroll_Some(v) { .Some = { .tag = AzOptionDataGridOnScrollTag_Some, .payload = v } }
+#define AzOptionDataGridOnCellEdit_None { .None = { .tag = AzOptionDataGridOnCellEditTag_None } }
+#define AzOptionDataGridOnCellEdit_Some(v) { .Some = { .tag = AzOptionDataGridOnCellEditTag_Some, .payload = v } }
+#define AzOptionDataGridOnColumnResize_None { .None = { .tag = AzOptionDataGridOnColumnResizeTag_None } }
+#define AzOptionDataGridOnColumnResize_Some(v) { .Some = { .tag = AzOptionDataGridOnColumnResizeTag_Some, .payload = v } }
+#define AzOptionDataGridOnRowResize_None { .None = { .tag = AzOptionDataGridOnRowResizeTag_None } }
+#define AzOptionDataGridOnRowResize_Some(v) { .Some = { .tag = AzOptionDataGridOnRowResizeTag_Some, .payload = v } }
+#define AzOptionDataGridOnCopyRange_None { .None = { .tag = AzOptionDataGridOnCopyRangeTag_None } }
+#define AzOptionDataGridOnCopyRange_Some(v) { .Some = { .tag = AzOptionDataGridOnCopyRangeTag_Some, .payload = v } }
+#define AzOptionDataGridOnClearRange_None { .None = { .tag = AzOptionDataGridOnClearRangeTag_None } }
+#define AzOptionDataGridOnClearRange_Some(v) { .Some = { .tag = AzOptionDataGridOnClearRangeTag_Some, .payload = v } }
+#define AzOptionDate_None { .None = { .tag = AzOptionDateTag_None } }
+#define AzOptionDate_Some(v) { .Some = { .tag = AzOptionDateTag_Some, .payload = v } }
+#define AzOptionTime_None { .None = { .tag = AzOptionTimeTag_None } }
+#define AzOptionTime_Some(v) { .Some = { .tag = AzOptionTimeTag_Some, .payload = v } }
+#define AzOptionDatePickerOnDateChange_None { .None = { .tag = AzOptionDatePickerOnDateChangeTag_None } }
+#define AzOptionDatePickerOnDateChange_Some(v) { .Some = { .tag = AzOptionDatePickerOnDateChangeTag_Some, .payload = v } }
+#define AzOptionTimePickerOnTimeChange_None { .None = { .tag = AzOptionTimePickerOnTimeChangeTag_None } }
+#define AzOptionTimePickerOnTimeChange_Some(v) { .Some = { .tag = AzOptionTimePickerOnTimeChangeTag_Some, .payload = v } }
+#define AzOptionDateTimePickerOnDateTimeChange_None { .None = { .tag = AzOptionDateTimePickerOnDateTimeChangeTag_None } }
+#define AzOptionDateTimePickerOnDateTimeChange_Some(v) { .Some = { .tag = AzOptionDateTimePickerOnDateTimeChangeTag_Some, .payload = v } }
+#define AzOptionDialogOnClose_None { .None = { .tag = AzOptionDialogOnCloseTag_None } }
+#define AzOptionDialogOnClose_Some(v) { .Some = { .tag = AzOptionDialogOnCloseTag_Some, .payload = v } }
+#define AzOptionSplitterOnResize_None { .None = { .tag = AzOptionSplitterOnResizeTag_None } }
+#define AzOptionSplitterOnResize_Some(v) { .Some = { .tag = AzOptionSplitterOnResizeTag_Some, .payload = v } }
 #define AzOptionListViewOnColumnClick_None { .None = { .tag = AzOptionListViewOnColumnClickTag_None } }
 #define AzOptionListViewOnColumnClick_Some(v) { .Some = { .tag = AzOptionListViewOnColumnClickTag_Some, .payload = v } }
 #define AzOptionListViewOnLazyLoadScroll_None { .None = { .tag = AzOptionListViewOnLazyLoadScrollTag_None } }
@@ -11718,6 +13498,12 @@ typedef struct AzCss AzCss;
 #define AzOptionPixelValueNoPercent_Some(v) { .Some = { .tag = AzOptionPixelValueNoPercentTag_Some, .payload = v } }
 #define AzOptionDropDownOnChoiceChange_None { .None = { .tag = AzOptionDropDownOnChoiceChangeTag_None } }
 #define AzOptionDropDownOnChoiceChange_Some(v) { .Some = { .tag = AzOptionDropDownOnChoiceChangeTag_Some, .payload = v } }
+#define AzOptionNodeGraphOnNodeGraphZoomed_None { .None = { .tag = AzOptionNodeGraphOnNodeGraphZoomedTag_None } }
+#define AzOptionNodeGraphOnNodeGraphZoomed_Some(v) { .Some = { .tag = AzOptionNodeGraphOnNodeGraphZoomedTag_Some, .payload = v } }
+#define AzOptionNodeGraphOnSelectionChanged_None { .None = { .tag = AzOptionNodeGraphOnSelectionChangedTag_None } }
+#define AzOptionNodeGraphOnSelectionChanged_Some(v) { .Some = { .tag = AzOptionNodeGraphOnSelectionChangedTag_Some, .payload = v } }
+#define AzOptionNodeGraphOnHistoryChanged_None { .None = { .tag = AzOptionNodeGraphOnHistoryChangedTag_None } }
+#define AzOptionNodeGraphOnHistoryChanged_Some(v) { .Some = { .tag = AzOptionNodeGraphOnHistoryChangedTag_Some, .payload = v } }
 #define AzOptionNodeGraphOnNodeAdded_None { .None = { .tag = AzOptionNodeGraphOnNodeAddedTag_None } }
 #define AzOptionNodeGraphOnNodeAdded_Some(v) { .Some = { .tag = AzOptionNodeGraphOnNodeAddedTag_Some, .payload = v } }
 #define AzOptionNodeGraphOnNodeRemoved_None { .None = { .tag = AzOptionNodeGraphOnNodeRemovedTag_None } }
@@ -11921,6 +13707,8 @@ typedef struct AzCss AzCss;
 #define AzOptionStringVec_Some(v) { .Some = { .tag = AzOptionStringVecTag_Some, .payload = v } }
 #define AzOptionThreadReceiveMsg_None { .None = { .tag = AzOptionThreadReceiveMsgTag_None } }
 #define AzOptionThreadReceiveMsg_Some(v) { .Some = { .tag = AzOptionThreadReceiveMsgTag_Some, .payload = v } }
+#define AzOptionTextMetrics_None { .None = { .tag = AzOptionTextMetricsTag_None } }
+#define AzOptionTextMetrics_Some(v) { .Some = { .tag = AzOptionTextMetricsTag_Some, .payload = v } }
 #define AzOptionTaskBarIcon_None { .None = { .tag = AzOptionTaskBarIconTag_None } }
 #define AzOptionTaskBarIcon_Some(v) { .Some = { .tag = AzOptionTaskBarIconTag_Some, .payload = v } }
 #define AzOptionWindowIcon_None { .None = { .tag = AzOptionWindowIconTag_None } }
@@ -12023,6 +13811,8 @@ typedef struct AzCss AzCss;
 #define AzMenuItem_String(v) { .String = { .tag = AzMenuItemTag_String, .payload = v } }
 #define AzMenuItem_Separator { .Separator = { .tag = AzMenuItemTag_Separator } }
 #define AzMenuItem_BreakLine { .BreakLine = { .tag = AzMenuItemTag_BreakLine } }
+#define AzCssFontFaceSource_Url(v) { .Url = { .tag = AzCssFontFaceSourceTag_Url, .payload = v } }
+#define AzCssFontFaceSource_Local(v) { .Local = { .tag = AzCssFontFaceSourceTag_Local, .payload = v } }
 #define AzStyleBackgroundContentVecValue_Auto { .Auto = { .tag = AzStyleBackgroundContentVecValueTag_Auto } }
 #define AzStyleBackgroundContentVecValue_None { .None = { .tag = AzStyleBackgroundContentVecValueTag_None } }
 #define AzStyleBackgroundContentVecValue_Inherit { .Inherit = { .tag = AzStyleBackgroundContentVecValueTag_Inherit } }
@@ -12107,6 +13897,8 @@ typedef struct AzCss AzCss;
 #define AzCssProperty_Filter(v) { .Filter = { .tag = AzCssPropertyTag_Filter, .payload = v } }
 #define AzCssProperty_BackdropFilter(v) { .BackdropFilter = { .tag = AzCssPropertyTag_BackdropFilter, .payload = v } }
 #define AzCssProperty_TextShadow(v) { .TextShadow = { .tag = AzCssPropertyTag_TextShadow, .payload = v } }
+#define AzCssProperty_VerticalAlign(v) { .VerticalAlign = { .tag = AzCssPropertyTag_VerticalAlign, .payload = v } }
+#define AzCssProperty_TextDecoration(v) { .TextDecoration = { .tag = AzCssPropertyTag_TextDecoration, .payload = v } }
 #define AzCssPropertySource_Css(v) { .Css = { .tag = AzCssPropertySourceTag_Css, .payload = v } }
 #define AzCssPropertySource_Inline { .Inline = { .tag = AzCssPropertySourceTag_Inline } }
 #define AzSvgSimpleNode_Path(v) { .Path = { .tag = AzSvgSimpleNodeTag_Path, .payload = v } }
@@ -12149,6 +13941,12 @@ typedef struct AzCss AzCss;
 #define AzFocusTarget_NoFocus { .NoFocus = { .tag = AzFocusTargetTag_NoFocus } }
 #define AzCssDeclaration_Static(v) { .Static = { .tag = AzCssDeclarationTag_Static, .payload = v } }
 #define AzCssDeclaration_Dynamic(v) { .Dynamic = { .tag = AzCssDeclarationTag_Dynamic, .payload = v } }
+#define AzNodeGraphEditOp_AddNode(v) { .AddNode = { .tag = AzNodeGraphEditOpTag_AddNode, .payload = v } }
+#define AzNodeGraphEditOp_RemoveNode(v) { .RemoveNode = { .tag = AzNodeGraphEditOpTag_RemoveNode, .payload = v } }
+#define AzNodeGraphEditOp_MoveNode(v) { .MoveNode = { .tag = AzNodeGraphEditOpTag_MoveNode, .payload = v } }
+#define AzNodeGraphEditOp_Connect(v) { .Connect = { .tag = AzNodeGraphEditOpTag_Connect, .payload = v } }
+#define AzNodeGraphEditOp_Disconnect(v) { .Disconnect = { .tag = AzNodeGraphEditOpTag_Disconnect, .payload = v } }
+#define AzNodeGraphEditOp_EditField(v) { .EditField = { .tag = AzNodeGraphEditOpTag_EditField, .payload = v } }
 #define AzXmlError_NoParserAvailable { .NoParserAvailable = { .tag = AzXmlErrorTag_NoParserAvailable } }
 #define AzXmlError_InvalidXmlPrefixUri(v) { .InvalidXmlPrefixUri = { .tag = AzXmlErrorTag_InvalidXmlPrefixUri, .payload = v } }
 #define AzXmlError_UnexpectedXmlUri(v) { .UnexpectedXmlUri = { .tag = AzXmlErrorTag_UnexpectedXmlUri, .payload = v } }
@@ -12166,6 +13964,8 @@ typedef struct AzCss AzCss;
 #define AzXmlError_NoRootNode { .NoRootNode = { .tag = AzXmlErrorTag_NoRootNode } }
 #define AzXmlError_SizeLimit { .SizeLimit = { .tag = AzXmlErrorTag_SizeLimit } }
 #define AzXmlError_ParserError(v) { .ParserError = { .tag = AzXmlErrorTag_ParserError, .payload = v } }
+#define AzTooltipContent_Text(v) { .Text = { .tag = AzTooltipContentTag_Text, .payload = v } }
+#define AzTooltipContent_Dom(v) { .Dom = { .tag = AzTooltipContentTag_Dom, .payload = v } }
 #define AzOptionDom_None { .None = { .tag = AzOptionDomTag_None } }
 #define AzOptionDom_Some(v) { .Some = { .tag = AzOptionDomTag_Some, .payload = v } }
 #define AzResultXmlXmlError_Ok(v) { .Ok = { .tag = AzResultXmlXmlErrorTag_Ok, .payload = v } }
@@ -12180,10 +13980,24 @@ typedef struct AzCss AzCss;
 #define AzResultSvgXmlNodeSvgParseError_Err(v) { .Err = { .tag = AzResultSvgXmlNodeSvgParseErrorTag_Err, .payload = v } }
 #define AzResultSvgSvgParseError_Ok(v) { .Ok = { .tag = AzResultSvgSvgParseErrorTag_Ok, .payload = v } }
 #define AzResultSvgSvgParseError_Err(v) { .Err = { .tag = AzResultSvgSvgParseErrorTag_Err, .payload = v } }
+#define AzResultNodeGraphNodeGraphError_Ok(v) { .Ok = { .tag = AzResultNodeGraphNodeGraphErrorTag_Ok, .payload = v } }
+#define AzResultNodeGraphNodeGraphError_Err(v) { .Err = { .tag = AzResultNodeGraphNodeGraphErrorTag_Err, .payload = v } }
 AzListViewRow AzListViewRowVecArray[] = {};
 #define AzListViewRowVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzListViewRow), .cap = sizeof(v) / sizeof(AzListViewRow), .destructor = { .NoDestructor = { .tag = AzListViewRowVecDestructorTag_NoDestructor, }, }, }
 #define AzListViewRowVec_empty { .ptr = &AzListViewRowVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzListViewRowVecDestructorTag_NoDestructor, }, }, }
 
+AzTreeViewItem AzTreeViewItemVecArray[] = {};
+#define AzTreeViewItemVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzTreeViewItem), .cap = sizeof(v) / sizeof(AzTreeViewItem), .destructor = { .NoDestructor = { .tag = AzTreeViewItemVecDestructorTag_NoDestructor, }, }, }
+#define AzTreeViewItemVec_empty { .ptr = &AzTreeViewItemVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzTreeViewItemVecDestructorTag_NoDestructor, }, }, }
+
+AzSplitterPane AzSplitterPaneVecArray[] = {};
+#define AzSplitterPaneVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzSplitterPane), .cap = sizeof(v) / sizeof(AzSplitterPane), .destructor = { .NoDestructor = { .tag = AzSplitterPaneVecDestructorTag_NoDestructor, }, }, }
+#define AzSplitterPaneVec_empty { .ptr = &AzSplitterPaneVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzSplitterPaneVecDestructorTag_NoDestructor, }, }, }
+
+AzSplitterPaneState AzSplitterPaneStateVecArray[] = {};
+#define AzSplitterPaneStateVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzSplitterPaneState), .cap = sizeof(v) / sizeof(AzSplitterPaneState), .destructor = { .NoDestructor = { .tag = AzSplitterPaneStateVecDestructorTag_NoDestructor, }, }, }
+#define AzSplitterPaneStateVec_empty { .ptr = &AzSplitterPaneStateVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzSplitterPaneStateVecDestructorTag_NoDestructor, }, }, }
+
 AzStyleFilter AzStyleFilterVecArray[] = {};
 #define AzStyleFilterVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStyleFilter), .cap = sizeof(v) / sizeof(AzStyleFilter), .destructor = { .NoDestructor = { .tag = AzStyleFilterVecDestructorTag_NoDestructor, }, }, }
 #define AzStyleFilterVec_empty { .ptr = &AzStyleFilterVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStyleFilterVecDestructorTag_NoDestructor, }, }, }
@@ -12200,6 +14014,18 @@ AzInputOutputTypeIdInfoMap AzInputOutputTypeIdInfoMapVecArray[] = {};
 #define AzInputOutputTypeIdInfoMapVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzInputOutputTypeIdInfoMap), .cap = sizeof(v) / sizeof(AzInputOutputTypeIdInfoMap), .destructor = { .NoDestructor = { .tag = AzInputOutputTypeIdInfoMapVecDestructorTag_NoDestructor, }, }, }
 #define AzInputOutputTypeIdInfoMapVec_empty { .ptr = &AzInputOutputTypeIdInfoMapVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzInputOutputTypeIdInfoMapVecDestructorTag_NoDestructor, }, }, }
 
+AzNodeGraphNodeId AzNodeGraphNodeIdVecArray[] = {};
+#define AzNodeGraphNodeIdVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzNodeGraphNodeId), .cap = sizeof(v) / sizeof(AzNodeGraphNodeId), .destructor = { .NoDestructor = { .tag = AzNodeGraphNodeIdVecDestructorTag_NoDestructor, }, }, }
+#define AzNodeGraphNodeIdVec_empty { .ptr = &AzNodeGraphNodeIdVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzNodeGraphNodeIdVecDestructorTag_NoDestructor, }, }, }
+
+AzNodeGraphEditOp AzNodeGraphEditOpVecArray[] = {};
+#define AzNodeGraphEditOpVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzNodeGraphEditOp), .cap = sizeof(v) / sizeof(AzNodeGraphEditOp), .destructor = { .NoDestructor = { .tag = AzNodeGraphEditOpVecDestructorTag_NoDestructor, }, }, }
+#define AzNodeGraphEditOpVec_empty { .ptr = &AzNodeGraphEditOpVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzNodeGraphEditOpVecDestructorTag_NoDestructor, }, }, }
+
+AzNodeGraphEdit AzNodeGraphEditVecArray[] = {};
+#define AzNodeGraphEditVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzNodeGraphEdit), .cap = sizeof(v) / sizeof(AzNodeGraphEdit), .destructor = { .NoDestructor = { .tag = AzNodeGraphEditVecDestructorTag_NoDestructor, }, }, }
+#define AzNodeGraphEditVec_empty { .ptr = &AzNodeGraphEditVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzNodeGraphEditVecDestructorTag_NoDestructor, }, }, }
+
 AzNodeIdNodeMap AzNodeIdNodeMapVecArray[] = {};
 #define AzNodeIdNodeMapVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzNodeIdNodeMap), .cap = sizeof(v) / sizeof(AzNodeIdNodeMap), .destructor = { .NoDestructor = { .tag = AzNodeIdNodeMapVecDestructorTag_NoDestructor, }, }, }
 #define AzNodeIdNodeMapVec_empty { .ptr = &AzNodeIdNodeMapVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzNodeIdNodeMapVecDestructorTag_NoDestructor, }, }, }
@@ -12232,6 +14058,10 @@ AzAccessibilityState AzAccessibilityStateVecArray[] = {};
 #define AzAccessibilityStateVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzAccessibilityState), .cap = sizeof(v) / sizeof(AzAccessibilityState), .destructor = { .NoDestructor = { .tag = AzAccessibilityStateVecDestructorTag_NoDestructor, }, }, }
 #define AzAccessibilityStateVec_empty { .ptr = &AzAccessibilityStateVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzAccessibilityStateVecDestructorTag_NoDestructor, }, }, }
 
+AzKeyboardShortcut AzKeyboardShortcutVecArray[] = {};
+#define AzKeyboardShortcutVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzKeyboardShortcut), .cap = sizeof(v) / sizeof(AzKeyboardShortcut), .destructor = { .NoDestructor = { .tag = AzKeyboardShortcutVecDestructorTag_NoDestructor, }, }, }
+#define AzKeyboardShortcutVec_empty { .ptr = &AzKeyboardShortcutVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzKeyboardShortcutVecDestructorTag_NoDestructor, }, }, }
+
 AzMenuItem AzMenuItemVecArray[] = {};
 #define AzMenuItemVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzMenuItem), .cap = sizeof(v) / sizeof(AzMenuItem), .destructor = { .NoDestructor = { .tag = AzMenuItemVecDestructorTag_NoDestructor, }, }, }
 #define AzMenuItemVec_empty { .ptr = &AzMenuItemVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzMenuItemVecDestructorTag_NoDestructor, }, }, }
@@ -12268,6 +14098,14 @@ AzInlineGlyph AzInlineGlyphVecArray[] = {};
 #define AzInlineGlyphVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzInlineGlyph), .cap = sizeof(v) / sizeof(AzInlineGlyph), .destructor = { .NoDestructor = { .tag = AzInlineGlyphVecDestructorTag_NoDestructor, }, }, }
 #define AzInlineGlyphVec_empty { .ptr = &AzInlineGlyphVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzInlineGlyphVecDestructorTag_NoDestructor, }, }, }
 
+AzTextLineMetrics AzTextLineMetricsVecArray[] = {};
+#define AzTextLineMetricsVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzTextLineMetrics), .cap = sizeof(v) / sizeof(AzTextLineMetrics), .destructor = { .NoDestructor = { .tag = AzTextLineMetricsVecDestructorTag_NoDestructor, }, }, }
+#define AzTextLineMetricsVec_empty { .ptr = &AzTextLineMetricsVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzTextLineMetricsVecDestructorTag_NoDestructor, }, }, }
+
+AzInlineTextRun AzInlineTextRunVecArray[] = {};
+#define AzInlineTextRunVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzInlineTextRun), .cap = sizeof(v) / sizeof(AzInlineTextRun), .destructor = { .NoDestructor = { .tag = AzInlineTextRunVecDestructorTag_NoDestructor, }, }, }
+#define AzInlineTextRunVec_empty { .ptr = &AzInlineTextRunVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzInlineTextRunVecDestructorTag_NoDestructor, }, }, }
+
 AzInlineTextHit AzInlineTextHitVecArray[] = {};
 #define AzInlineTextHitVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzInlineTextHit), .cap = sizeof(v) / sizeof(AzInlineTextHit), .destructor = { .NoDestructor = { .tag = AzInlineTextHitVecDestructorTag_NoDestructor, }, }, }
 #define AzInlineTextHitVec_empty { .ptr = &AzInlineTextHitVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzInlineTextHitVecDestructorTag_NoDestructor, }, }, }
@@ -12376,6 +14214,18 @@ AzStylesheet AzStylesheetVecArray[] = {};
 #define AzStylesheetVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStylesheet), .cap = sizeof(v) / sizeof(AzStylesheet), .destructor = { .NoDestructor = { .tag = AzStylesheetVecDestructorTag_NoDestructor, }, }, }
 #define AzStylesheetVec_empty { .ptr = &AzStylesheetVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStylesheetVecDestructorTag_NoDestructor, }, }, }
 
+AzCssFontFace AzCssFontFaceVecArray[] = {};
+#define AzCssFontFaceVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzCssFontFace), .cap = sizeof(v) / sizeof(AzCssFontFace), .destructor = { .NoDestructor = { .tag = AzCssFontFaceVecDestructorTag_NoDestructor, }, }, }
+#define AzCssFontFaceVec_empty { .ptr = &AzCssFontFaceVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzCssFontFaceVecDestructorTag_NoDestructor, }, }, }
+
+AzCssFontFaceSource AzCssFontFaceSourceVecArray[] = {};
+#define AzCssFontFaceSourceVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzCssFontFaceSource), .cap = sizeof(v) / sizeof(AzCssFontFaceSource), .destructor = { .NoDestructor = { .tag = AzCssFontFaceSourceVecDestructorTag_NoDestructor, }, }, }
+#define AzCssFontFaceSourceVec_empty { .ptr = &AzCssFontFaceSourceVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzCssFontFaceSourceVecDestructorTag_NoDestructor, }, }, }
+
+AzCssUnicodeRange AzCssUnicodeRangeVecArray[] = {};
+#define AzCssUnicodeRangeVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzCssUnicodeRange), .cap = sizeof(v) / sizeof(AzCssUnicodeRange), .destructor = { .NoDestructor = { .tag = AzCssUnicodeRangeVecDestructorTag_NoDestructor, }, }, }
+#define AzCssUnicodeRangeVec_empty { .ptr = &AzCssUnicodeRangeVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzCssUnicodeRangeVecDestructorTag_NoDestructor, }, }, }
+
 AzCssRuleBlock AzCssRuleBlockVecArray[] = {};
 #define AzCssRuleBlockVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzCssRuleBlock), .cap = sizeof(v) / sizeof(AzCssRuleBlock), .destructor = { .NoDestructor = { .tag = AzCssRuleBlockVecDestructorTag_NoDestructor, }, }, }
 #define AzCssRuleBlockVec_empty { .ptr = &AzCssRuleBlockVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzCssRuleBlockVecDestructorTag_NoDestructor, }, }, }
@@ -12453,6 +14303,7 @@ AzNodeData AzNodeDataVecArray[] = {};
 extern DLLIMPORT AzApp AzApp_new(AzRefAny  data, AzAppConfig  config);
 extern DLLIMPORT void AzApp_addWindow(AzApp* restrict app, AzWindowCreateOptions  window);
 extern DLLIMPORT void AzApp_addImage(AzApp* restrict app, AzString  id, AzImageRef  image);
+extern DLLIMPORT bool  AzApp_addFont(AzApp* restrict app, AzString  family, AzU8Vec  font_bytes);
 extern DLLIMPORT AzMonitorVec AzApp_getMonitors(const AzApp* app);
 extern DLLIMPORT void AzApp_run(const AzApp* app, AzWindowCreateOptions  window);
 extern DLLIMPORT void AzApp_delete(AzApp* restrict instance);
@@ -12543,7 +14394,9 @@ extern DLLIMPORT AzLogicalSize AzHidpiAdjustedBounds_getLogicalSize(const AzHidp
 extern DLLIMPORT AzPhysicalSizeU32 AzHidpiAdjustedBounds_getPhysicalSize(const AzHidpiAdjustedBounds* hidpiadjustedbounds);
 extern DLLIMPORT float AzHidpiAdjustedBounds_getHidpiFactor(const AzHidpiAdjustedBounds* hidpiadjustedbounds);
 extern DLLIMPORT AzInlineTextHitVec AzInlineText_hitTest(const AzInlineText* inlinetext, AzLogicalPosition  position);
+extern DLLIMPORT AzTextMetrics AzInlineText_getTextMetrics(const AzInlineText* inlinetext);
 extern DLLIMPORT void AzInlineText_delete(AzInlineText* restrict instance);
+extern DLLIMPORT void AzTextMetrics_delete(AzTextMetrics* restrict instance);
 extern DLLIMPORT void AzInlineLine_delete(AzInlineLine* restrict instance);
 extern DLLIMPORT void AzInlineWord_delete(AzInlineWord* restrict instance);
 extern DLLIMPORT void AzInlineTextContents_delete(AzInlineTextContents* restrict instance);
@@ -12581,6 +14434,7 @@ extern DLLIMPORT AzRefAny AzRefAny_deepCopy(AzRefAny* const instance);
 extern DLLIMPORT AzOptionGl AzLayoutCallbackInfo_getGlContext(const AzLayoutCallbackInfo* layoutcallbackinfo);
 extern DLLIMPORT AzStringPairVec AzLayoutCallbackInfo_getSystemFonts(const AzLayoutCallbackInfo* layoutcallbackinfo);
 extern DLLIMPORT AzOptionImageRef AzLayoutCallbackInfo_getImage(const AzLayoutCallbackInfo* layoutcallbackinfo, AzString  id);
+extern DLLIMPORT AzOptionTextMetrics AzLayoutCallbackInfo_measureText(const AzLayoutCallbackInfo* layoutcallbackinfo, AzString  text, AzStyleFontFamilyVec  font_families, AzResolvedTextLayoutOptions  options);
 extern DLLIMPORT void AzLayoutCallbackInfo_delete(AzLayoutCallbackInfo* restrict instance);
 extern DLLIMPORT AzDom AzDom_new(AzNodeType  node_type);
 extern DLLIMPORT AzDom AzDom_body();
@@ -12635,6 +14489,11 @@ extern DLLIMPORT void AzDom_setMenuBar(AzDom* restrict dom, AzMenu  menu_bar);
 extern DLLIMPORT AzDom AzDom_withMenuBar(AzDom* restrict dom, AzMenu  menu_bar);
 extern DLLIMPORT void AzDom_setContextMenu(AzDom* restrict dom, AzMenu  context_menu);
 extern DLLIMPORT AzDom AzDom_withContextMenu(AzDom* restrict dom, AzMenu  context_menu);
+extern DLLIMPORT void AzDom_setTooltip(AzDom* restrict dom, AzTooltip  tooltip);
+extern DLLIMPORT AzDom AzDom_withTooltip(AzDom* restrict dom, AzTooltip  tooltip);
+extern DLLIMPORT void AzDom_setKeyboardShortcuts(AzDom* restrict dom, AzKeyboardShortcutVec  keyboard_shortcuts);
+extern DLLIMPORT AzDom AzDom_withKeyboardShortcuts(AzDom* restrict dom, AzKeyboardShortcutVec  keyboard_shortcuts);
+extern DLLIMPORT void AzDom_makeInert(AzDom* restrict dom);
 extern DLLIMPORT uint64_t AzDom_hash(const AzDom* dom);
 extern DLLIMPORT size_t AzDom_nodeCount(const AzDom* dom);
 extern DLLIMPORT AzString AzDom_getHtmlString(AzDom* restrict dom);
@@ -12675,11 +14534,18 @@ extern DLLIMPORT void AzNodeData_setTabIndex(AzNodeData* restrict nodedata, AzTa
 extern DLLIMPORT void AzNodeData_setAccessibilityInfo(AzNodeData* restrict nodedata, AzAccessibilityInfo  accessibility_info);
 extern DLLIMPORT void AzNodeData_setMenuBar(AzNodeData* restrict nodedata, AzMenu  menu_bar);
 extern DLLIMPORT void AzNodeData_setContextMenu(AzNodeData* restrict nodedata, AzMenu  context_menu);
+extern DLLIMPORT void AzNodeData_setTooltip(AzNodeData* restrict nodedata, AzTooltip  tooltip);
+extern DLLIMPORT void AzNodeData_setKeyboardShortcuts(AzNodeData* restrict nodedata, AzKeyboardShortcutVec  keyboard_shortcuts);
 extern DLLIMPORT uint64_t AzNodeData_hash(const AzNodeData* nodedata);
 extern DLLIMPORT void AzNodeData_delete(AzNodeData* restrict instance);
 extern DLLIMPORT void AzNodeType_delete(AzNodeType* restrict instance);
 extern DLLIMPORT AzEventFilter AzOn_intoEventFilter(const AzOn on);
 extern DLLIMPORT void AzAccessibilityInfo_delete(AzAccessibilityInfo* restrict instance);
+extern DLLIMPORT AzTooltip AzTooltip_text(AzString  text);
+extern DLLIMPORT AzTooltip AzTooltip_dom(AzDom  dom);
+extern DLLIMPORT AzTooltip AzTooltip_withDelay(AzTooltip* restrict tooltip, AzDuration  delay);
+extern DLLIMPORT void AzTooltip_delete(AzTooltip* restrict instance);
+extern DLLIMPORT void AzTooltipContent_delete(AzTooltipContent* restrict instance);
 extern DLLIMPORT void AzIdOrClass_delete(AzIdOrClass* restrict instance);
 extern DLLIMPORT void AzNodeDataInlineCssProperty_delete(AzNodeDataInlineCssProperty* restrict instance);
 extern DLLIMPORT AzMenu AzMenu_new(AzMenuItemVec  items);
@@ -12695,7 +14561,10 @@ extern DLLIMPORT AzStringMenuItem AzStringMenuItem_withChild(AzStringMenuItem* r
 extern DLLIMPORT void AzStringMenuItem_setChildren(AzStringMenuItem* restrict stringmenuitem, AzMenuItemVec  children);
 extern DLLIMPORT AzStringMenuItem AzStringMenuItem_withChildren(AzStringMenuItem* restrict stringmenuitem, AzMenuItemVec  children);
 extern DLLIMPORT void AzStringMenuItem_delete(AzStringMenuItem* restrict instance);
+extern DLLIMPORT bool  AzVirtualKeyCodeCombo_matches(const AzVirtualKeyCodeCombo* virtualkeycodecombo, AzKeyboardState  keyboard_state);
 extern DLLIMPORT void AzVirtualKeyCodeCombo_delete(AzVirtualKeyCodeCombo* restrict instance);
+extern DLLIMPORT AzKeyboardShortcut AzKeyboardShortcut_new(AzVirtualKeyCodeCombo  keys, AzRefAny  data, AzCallbackType  callback);
+extern DLLIMPORT void AzKeyboardShortcut_delete(AzKeyboardShortcut* restrict instance);
 extern DLLIMPORT AzMenuCallback AzMenuCallback_new(AzRefAny  data, AzCallbackType  callback);
 extern DLLIMPORT void AzMenuCallback_delete(AzMenuCallback* restrict instance);
 extern DLLIMPORT void AzMenuItemIcon_delete(AzMenuItemIcon* restrict instance);
@@ -12708,6 +14577,9 @@ extern DLLIMPORT void AzStylesheet_delete(AzStylesheet* restrict instance);
 extern DLLIMPORT AzCss AzCss_empty();
 extern DLLIMPORT AzCss AzCss_fromString(AzString  s);
 extern DLLIMPORT void AzCss_delete(AzCss* restrict instance);
+extern DLLIMPORT void AzCssFontFace_delete(AzCssFontFace* restrict instance);
+extern DLLIMPORT void AzCssFontFaceSource_delete(AzCssFontFaceSource* restrict instance);
+extern DLLIMPORT void AzCssFontFaceUrl_delete(AzCssFontFaceUrl* restrict instance);
 extern DLLIMPORT AzColorU AzColorU_fromStr(AzString  string);
 extern DLLIMPORT AzColorU AzColorU_transparent();
 extern DLLIMPORT AzColorU AzColorU_white();
@@ -12840,6 +14712,8 @@ extern DLLIMPORT void AzFrame_setFlexGrow(AzFrame* restrict frame, float flex_gr
 extern DLLIMPORT AzFrame AzFrame_withFlexGrow(AzFrame* restrict frame, float flex_grow);
 extern DLLIMPORT AzDom AzFrame_dom(AzFrame* restrict frame);
 extern DLLIMPORT void AzFrame_delete(AzFrame* restrict instance);
+extern DLLIMPORT AzNodeGraph AzNodeGraph_fromJson(AzString  json);
+extern DLLIMPORT AzString AzNodeGraph_toJson(const AzNodeGraph* nodegraph);
 extern DLLIMPORT AzDom AzNodeGraph_dom(AzNodeGraph* restrict nodegraph);
 extern DLLIMPORT void AzNodeGraph_delete(AzNodeGraph* restrict instance);
 extern DLLIMPORT void AzNodeTypeIdInfoMap_delete(AzNodeTypeIdInfoMap* restrict instance);
@@ -12854,6 +14728,9 @@ extern DLLIMPORT void AzNodeGraphOnNodeConnected_delete(AzNodeGraphOnNodeConnect
 extern DLLIMPORT void AzNodeGraphOnNodeInputDisconnected_delete(AzNodeGraphOnNodeInputDisconnected* restrict instance);
 extern DLLIMPORT void AzNodeGraphOnNodeOutputDisconnected_delete(AzNodeGraphOnNodeOutputDisconnected* restrict instance);
 extern DLLIMPORT void AzNodeGraphOnNodeFieldEdited_delete(AzNodeGraphOnNodeFieldEdited* restrict instance);
+extern DLLIMPORT void AzNodeGraphOnNodeGraphZoomed_delete(AzNodeGraphOnNodeGraphZoomed* restrict instance);
+extern DLLIMPORT void AzNodeGraphOnSelectionChanged_delete(AzNodeGraphOnSelectionChanged* restrict instance);
+extern DLLIMPORT void AzNodeGraphOnHistoryChanged_delete(AzNodeGraphOnHistoryChanged* restrict instance);
 extern DLLIMPORT void AzNode_delete(AzNode* restrict instance);
 extern DLLIMPORT void AzNodeTypeField_delete(AzNodeTypeField* restrict instance);
 extern DLLIMPORT void AzNodeTypeFieldValue_delete(AzNodeTypeFieldValue* restrict instance);
@@ -12861,8 +14738,50 @@ extern DLLIMPORT void AzInputConnection_delete(AzInputConnection* restrict insta
 extern DLLIMPORT void AzOutputConnection_delete(AzOutputConnection* restrict instance);
 extern DLLIMPORT void AzNodeTypeInfo_delete(AzNodeTypeInfo* restrict instance);
 extern DLLIMPORT void AzInputOutputInfo_delete(AzInputOutputInfo* restrict instance);
+extern DLLIMPORT void AzNodeGraphFieldEdit_delete(AzNodeGraphFieldEdit* restrict instance);
+extern DLLIMPORT AzNodeGraphEditOp AzNodeGraphEditOp_invert(const AzNodeGraphEditOp* nodegrapheditop);
+extern DLLIMPORT void AzNodeGraphEditOp_delete(AzNodeGraphEditOp* restrict instance);
+extern DLLIMPORT void AzNodeGraphEdit_delete(AzNodeGraphEdit* restrict instance);
+extern DLLIMPORT bool  AzNodeGraphHistory_canUndo(const AzNodeGraphHistory* nodegraphhistory);
+extern DLLIMPORT bool  AzNodeGraphHistory_canRedo(const AzNodeGraphHistory* nodegraphhistory);
+extern DLLIMPORT void AzNodeGraphHistory_delete(AzNodeGraphHistory* restrict instance);
 extern DLLIMPORT AzListView AzListView_new(AzStringVec  columns);
+extern DLLIMPORT void AzListView_setColumns(AzListView* restrict listview, AzStringVec  columns);
+extern DLLIMPORT AzListView AzListView_withColumns(AzListView* restrict listview, AzStringVec  columns);
+extern DLLIMPORT void AzListView_setRows(AzListView* restrict listview, AzListViewRowVec  rows);
 extern DLLIMPORT AzListView AzListView_withRows(AzListView* restrict listview, AzListViewRowVec  rows);
+extern DLLIMPORT void AzListView_setSortedBy(AzListView* restrict listview, AzOptionUsize  sorted_by);
+extern DLLIMPORT AzListView AzListView_withSortedBy(AzListView* restrict listview, AzOptionUsize  sorted_by);
+extern DLLIMPORT void AzListView_setSortDescending(AzListView* restrict listview, bool  sort_descending);
+extern DLLIMPORT AzListView AzListView_withSortDescending(AzListView* restrict listview, bool  sort_descending);
+extern DLLIMPORT void AzListView_setScrollOffset(AzListView* restrict listview, AzPixelValueNoPercent  scroll_offset);
+extern DLLIMPORT AzListView AzListView_withScrollOffset(AzListView* restrict listview, AzPixelValueNoPercent  scroll_offset);
+extern DLLIMPORT void AzListView_setContentHeight(AzListView* restrict listview, AzPixelValueNoPercent  content_height);
+extern DLLIMPORT AzListView AzListView_withContentHeight(AzListView* restrict listview, AzPixelValueNoPercent  content_height);
+extern DLLIMPORT void AzListView_setColumnContextMenu(AzListView* restrict listview, AzMenu  context_menu);
+extern DLLIMPORT AzListView AzListView_withColumnContextMenu(AzListView* restrict listview, AzMenu  context_menu);
+extern DLLIMPORT void AzListView_setRowHeight(AzListView* restrict listview, float row_height);
+extern DLLIMPORT AzListView AzListView_withRowHeight(AzListView* restrict listview, float row_height);
+extern DLLIMPORT void AzListView_setFirstVisibleRow(AzListView* restrict listview, size_t first_visible_row);
+extern DLLIMPORT AzListView AzListView_withFirstVisibleRow(AzListView* restrict listview, size_t first_visible_row);
+extern DLLIMPORT void AzListView_setColumnWidths(AzListView* restrict listview, AzF32Vec  column_widths);
+extern DLLIMPORT AzListView AzListView_withColumnWidths(AzListView* restrict listview, AzF32Vec  column_widths);
+extern DLLIMPORT void AzListView_setSelectionState(AzListView* restrict listview, AzOptionUsize  focused_row, AzOptionUsize  selection_anchor);
+extern DLLIMPORT AzListView AzListView_withSelectionState(AzListView* restrict listview, AzOptionUsize  focused_row, AzOptionUsize  selection_anchor);
+extern DLLIMPORT void AzListView_setRowProvider(AzListView* restrict listview, size_t row_count, AzRefAny  data, AzListViewRowProviderCallbackType  callback);
+extern DLLIMPORT AzListView AzListView_withRowProvider(AzListView* restrict listview, size_t row_count, AzRefAny  data, AzListViewRowProviderCallbackType  callback);
+extern DLLIMPORT void AzListView_setOnLazyLoadScroll(AzListView* restrict listview, AzRefAny  data, AzListViewOnLazyLoadScrollCallbackType  callback);
+extern DLLIMPORT AzListView AzListView_withOnLazyLoadScroll(AzListView* restrict listview, AzRefAny  data, AzListViewOnLazyLoadScrollCallbackType  callback);
+extern DLLIMPORT void AzListView_setOnColumnClick(AzListView* restrict listview, AzRefAny  data, AzListViewOnColumnClickCallbackType  callback);
+extern DLLIMPORT AzListView AzListView_withOnColumnClick(AzListView* restrict listview, AzRefAny  data, AzListViewOnColumnClickCallbackType  callback);
+extern DLLIMPORT void AzListView_setOnRowClick(AzListView* restrict listview, AzRefAny  data, AzListViewOnRowClickCallbackType  callback);
+extern DLLIMPORT AzListView AzListView_withOnRowClick(AzListView* restrict listview, AzRefAny  data, AzListViewOnRowClickCallbackType  callback);
+extern DLLIMPORT void AzListView_setOnRowSelect(AzListView* restrict listview, AzRefAny  data, AzListViewOnRowSelectCallbackType  callback);
+extern DLLIMPORT AzListView AzListView_withOnRowSelect(AzListView* restrict listview, AzRefAny  data, AzListViewOnRowSelectCallbackType  callback);
+extern DLLIMPORT void AzListView_setOnColumnResize(AzListView* restrict listview, AzRefAny  data, AzListViewOnColumnResizeCallbackType  callback);
+extern DLLIMPORT AzListView AzListView_withOnColumnResize(AzListView* restrict listview, AzRefAny  data, AzListViewOnColumnResizeCallbackType  callback);
+extern DLLIMPORT size_t AzListView_getTotalRowCount(AzListView* restrict listview);
+extern DLLIMPORT void AzListView_applySelection(AzListView* restrict listview, size_t row_id, AzListViewSelectionChange  change);
 extern DLLIMPORT AzDom AzListView_dom(AzListView* restrict listview);
 extern DLLIMPORT void AzListView_delete(AzListView* restrict instance);
 extern DLLIMPORT void AzListViewRow_delete(AzListViewRow* restrict instance);
@@ -12870,9 +14789,174 @@ extern DLLIMPORT void AzListViewState_delete(AzListViewState* restrict instance)
 extern DLLIMPORT void AzListViewOnLazyLoadScroll_delete(AzListViewOnLazyLoadScroll* restrict instance);
 extern DLLIMPORT void AzListViewOnColumnClick_delete(AzListViewOnColumnClick* restrict instance);
 extern DLLIMPORT void AzListViewOnRowClick_delete(AzListViewOnRowClick* restrict instance);
-extern DLLIMPORT AzTreeView AzTreeView_new(AzString  root);
+extern DLLIMPORT void AzListViewOnRowSelect_delete(AzListViewOnRowSelect* restrict instance);
+extern DLLIMPORT void AzListViewOnColumnResize_delete(AzListViewOnColumnResize* restrict instance);
+extern DLLIMPORT void AzListViewRowProvider_delete(AzListViewRowProvider* restrict instance);
+extern DLLIMPORT AzTreeView AzTreeView_new(AzTreeViewItemVec  items);
+extern DLLIMPORT void AzTreeView_setItems(AzTreeView* restrict treeview, AzTreeViewItemVec  items);
+extern DLLIMPORT AzTreeView AzTreeView_withItems(AzTreeView* restrict treeview, AzTreeViewItemVec  items);
+extern DLLIMPORT void AzTreeView_setSelectionMode(AzTreeView* restrict treeview, AzTreeViewSelectionMode  selection_mode);
+extern DLLIMPORT AzTreeView AzTreeView_withSelectionMode(AzTreeView* restrict treeview, AzTreeViewSelectionMode  selection_mode);
+extern DLLIMPORT void AzTreeView_setFirstVisibleRow(AzTreeView* restrict treeview, size_t first_visible_row);
+extern DLLIMPORT void AzTreeView_setMaxVisibleRows(AzTreeView* restrict treeview, size_t max_visible_rows);
+extern DLLIMPORT void AzTreeView_setOnExpand(AzTreeView* restrict treeview, AzRefAny  data, AzTreeViewOnExpandCallbackType  callback);
+extern DLLIMPORT AzTreeView AzTreeView_withOnExpand(AzTreeView* restrict treeview, AzRefAny  data, AzTreeViewOnExpandCallbackType  callback);
+extern DLLIMPORT void AzTreeView_setOnSelect(AzTreeView* restrict treeview, AzRefAny  data, AzTreeViewOnSelectCallbackType  callback);
+extern DLLIMPORT AzTreeView AzTreeView_withOnSelect(AzTreeView* restrict treeview, AzRefAny  data, AzTreeViewOnSelectCallbackType  callback);
+extern DLLIMPORT void AzTreeView_setOnLazyLoad(AzTreeView* restrict treeview, AzRefAny  data, AzTreeViewOnLazyLoadCallbackType  callback);
+extern DLLIMPORT AzTreeView AzTreeView_withOnLazyLoad(AzTreeView* restrict treeview, AzRefAny  data, AzTreeViewOnLazyLoadCallbackType  callback);
+extern DLLIMPORT void AzTreeView_setOnScroll(AzTreeView* restrict treeview, AzRefAny  data, AzTreeViewOnScrollCallbackType  callback);
+extern DLLIMPORT AzTreeView AzTreeView_withOnScroll(AzTreeView* restrict treeview, AzRefAny  data, AzTreeViewOnScrollCallbackType  callback);
+extern DLLIMPORT size_t AzTreeView_getVisibleRowCount(const AzTreeView* treeview);
+extern DLLIMPORT bool  AzTreeView_setExpanded(AzTreeView* restrict treeview, size_t item_id, bool  expanded);
+extern DLLIMPORT bool  AzTreeView_setChildren(AzTreeView* restrict treeview, size_t item_id, AzTreeViewItemVec  children);
+extern DLLIMPORT void AzTreeView_applySelection(AzTreeView* restrict treeview, size_t item_id, AzTreeViewSelectionChange  change);
 extern DLLIMPORT AzDom AzTreeView_dom(AzTreeView* restrict treeview);
 extern DLLIMPORT void AzTreeView_delete(AzTreeView* restrict instance);
+extern DLLIMPORT AzTreeViewItem AzTreeViewItem_new(size_t id, AzString  label);
+extern DLLIMPORT void AzTreeViewItem_delete(AzTreeViewItem* restrict instance);
+extern DLLIMPORT void AzTreeViewOnExpand_delete(AzTreeViewOnExpand* restrict instance);
+extern DLLIMPORT void AzTreeViewOnSelect_delete(AzTreeViewOnSelect* restrict instance);
+extern DLLIMPORT void AzTreeViewOnLazyLoad_delete(AzTreeViewOnLazyLoad* restrict instance);
+extern DLLIMPORT void AzTreeViewOnScroll_delete(AzTreeViewOnScroll* restrict instance);
+extern DLLIMPORT AzDataGrid AzDataGrid_new(size_t row_count, size_t column_count);
+extern DLLIMPORT void AzDataGrid_setColumnHeaders(AzDataGrid* restrict datagrid, AzStringVec  column_headers);
+extern DLLIMPORT AzDataGrid AzDataGrid_withColumnHeaders(AzDataGrid* restrict datagrid, AzStringVec  column_headers);
+extern DLLIMPORT void AzDataGrid_setColumnWidths(AzDataGrid* restrict datagrid, AzF32Vec  column_widths);
+extern DLLIMPORT AzDataGrid AzDataGrid_withColumnWidths(AzDataGrid* restrict datagrid, AzF32Vec  column_widths);
+extern DLLIMPORT void AzDataGrid_setRowHeights(AzDataGrid* restrict datagrid, AzF32Vec  row_heights);
+extern DLLIMPORT AzDataGrid AzDataGrid_withRowHeights(AzDataGrid* restrict datagrid, AzF32Vec  row_heights);
+extern DLLIMPORT void AzDataGrid_setScrollPosition(AzDataGrid* restrict datagrid, size_t first_visible_row, size_t first_visible_column);
+extern DLLIMPORT AzDataGrid AzDataGrid_withScrollPosition(AzDataGrid* restrict datagrid, size_t first_visible_row, size_t first_visible_column);
+extern DLLIMPORT void AzDataGrid_setSelection(AzDataGrid* restrict datagrid, AzOptionDataGridRange  selection);
+extern DLLIMPORT AzDataGrid AzDataGrid_withSelection(AzDataGrid* restrict datagrid, AzOptionDataGridRange  selection);
+extern DLLIMPORT void AzDataGrid_setCellProvider(AzDataGrid* restrict datagrid, AzRefAny  data, AzDataGridCellProviderCallbackType  callback);
+extern DLLIMPORT AzDataGrid AzDataGrid_withCellProvider(AzDataGrid* restrict datagrid, AzRefAny  data, AzDataGridCellProviderCallbackType  callback);
+extern DLLIMPORT void AzDataGrid_setCellStyle(AzDataGrid* restrict datagrid, AzRefAny  data, AzDataGridCellStyleCallbackType  callback);
+extern DLLIMPORT AzDataGrid AzDataGrid_withCellStyle(AzDataGrid* restrict datagrid, AzRefAny  data, AzDataGridCellStyleCallbackType  callback);
+extern DLLIMPORT void AzDataGrid_setOnSelect(AzDataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnSelectCallbackType  callback);
+extern DLLIMPORT AzDataGrid AzDataGrid_withOnSelect(AzDataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnSelectCallbackType  callback);
+extern DLLIMPORT void AzDataGrid_setOnScroll(AzDataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnScrollCallbackType  callback);
+extern DLLIMPORT AzDataGrid AzDataGrid_withOnScroll(AzDataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnScrollCallbackType  callback);
+extern DLLIMPORT void AzDataGrid_setOnCellEdit(AzDataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnCellEditCallbackType  callback);
+extern DLLIMPORT AzDataGrid AzDataGrid_withOnCellEdit(AzDataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnCellEditCallbackType  callback);
+extern DLLIMPORT void AzDataGrid_setOnColumnResize(AzDataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnColumnResizeCallbackType  callback);
+extern DLLIMPORT AzDataGrid AzDataGrid_withOnColumnResize(AzDataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnColumnResizeCallbackType  callback);
+extern DLLIMPORT void AzDataGrid_setOnRowResize(AzDataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnRowResizeCallbackType  callback);
+extern DLLIMPORT AzDataGrid AzDataGrid_withOnRowResize(AzDataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnRowResizeCallbackType  callback);
+extern DLLIMPORT void AzDataGrid_setOnCopyRange(AzDataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnCopyRangeCallbackType  callback);
+extern DLLIMPORT AzDataGrid AzDataGrid_withOnCopyRange(AzDataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnCopyRangeCallbackType  callback);
+extern DLLIMPORT void AzDataGrid_setOnClearRange(AzDataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnClearRangeCallbackType  callback);
+extern DLLIMPORT AzDataGrid AzDataGrid_withOnClearRange(AzDataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnClearRangeCallbackType  callback);
+extern DLLIMPORT float AzDataGrid_getColumnWidth(const AzDataGrid* datagrid, size_t column);
+extern DLLIMPORT float AzDataGrid_getRowHeight(const AzDataGrid* datagrid, size_t row);
+extern DLLIMPORT AzString AzDataGrid_getColumnName(const AzDataGrid* datagrid, size_t column);
+extern DLLIMPORT AzString AzDataGrid_getCellText(AzDataGrid* restrict datagrid, size_t row, size_t column);
+extern DLLIMPORT AzString AzDataGrid_getSelectionTsv(AzDataGrid* restrict datagrid);
+extern DLLIMPORT AzOptionDataGridRange AzDataGrid_getClippedSelection(const AzDataGrid* datagrid);
+extern DLLIMPORT AzDom AzDataGrid_dom(AzDataGrid* restrict datagrid);
+extern DLLIMPORT void AzDataGrid_delete(AzDataGrid* restrict instance);
+extern DLLIMPORT AzDataGridRange AzDataGridRange_new(size_t row, size_t column);
+extern DLLIMPORT AzDataGridCell AzDataGridRange_getTopLeft(const AzDataGridRange* datagridrange);
+extern DLLIMPORT AzDataGridCell AzDataGridRange_getBottomRight(const AzDataGridRange* datagridrange);
+extern DLLIMPORT bool  AzDataGridRange_contains(const AzDataGridRange* datagridrange, size_t row, size_t column);
+extern DLLIMPORT size_t AzDataGridRange_getCellCount(const AzDataGridRange* datagridrange);
+extern DLLIMPORT void AzDataGridCellProvider_delete(AzDataGridCellProvider* restrict instance);
+extern DLLIMPORT void AzDataGridCellStyle_delete(AzDataGridCellStyle* restrict instance);
+extern DLLIMPORT void AzDataGridOnSelect_delete(AzDataGridOnSelect* restrict instance);
+extern DLLIMPORT void AzDataGridOnScroll_delete(AzDataGridOnScroll* restrict instance);
+extern DLLIMPORT void AzDataGridOnCellEdit_delete(AzDataGridOnCellEdit* restrict instance);
+extern DLLIMPORT void AzDataGridOnColumnResize_delete(AzDataGridOnColumnResize* restrict instance);
+extern DLLIMPORT void AzDataGridOnRowResize_delete(AzDataGridOnRowResize* restrict instance);
+extern DLLIMPORT void AzDataGridOnCopyRange_delete(AzDataGridOnCopyRange* restrict instance);
+extern DLLIMPORT void AzDataGridOnClearRange_delete(AzDataGridOnClearRange* restrict instance);
+extern DLLIMPORT AzDate AzDate_new(int32_t year, uint8_t month, uint8_t day);
+extern DLLIMPORT AzDate AzDate_today();
+extern DLLIMPORT bool  AzDate_isValid(const AzDate* date);
+extern DLLIMPORT AzWeekday AzDate_weekday(const AzDate* date);
+extern DLLIMPORT AzDate AzDate_addDays(const AzDate* date, int64_t days);
+extern DLLIMPORT AzDate AzDate_addMonths(const AzDate* date, int64_t months);
+extern DLLIMPORT AzDate AzDate_clampTo(const AzDate* date, AzOptionDate  minimum, AzOptionDate  maximum);
+extern DLLIMPORT AzTime AzTime_new(uint8_t hour, uint8_t minute, uint8_t second);
+extern DLLIMPORT bool  AzTime_isValid(const AzTime* time);
+extern DLLIMPORT void AzDatePickerOnDateChange_delete(AzDatePickerOnDateChange* restrict instance);
+extern DLLIMPORT AzDatePicker AzDatePicker_new(AzDate  date);
+extern DLLIMPORT void AzDatePicker_setState(AzDatePicker* restrict datepicker, AzDatePickerState  state);
+extern DLLIMPORT AzDatePicker AzDatePicker_withState(AzDatePicker* restrict datepicker, AzDatePickerState  state);
+extern DLLIMPORT void AzDatePicker_setMinimumDate(AzDatePicker* restrict datepicker, AzOptionDate  minimum_date);
+extern DLLIMPORT AzDatePicker AzDatePicker_withMinimumDate(AzDatePicker* restrict datepicker, AzOptionDate  minimum_date);
+extern DLLIMPORT void AzDatePicker_setMaximumDate(AzDatePicker* restrict datepicker, AzOptionDate  maximum_date);
+extern DLLIMPORT AzDatePicker AzDatePicker_withMaximumDate(AzDatePicker* restrict datepicker, AzOptionDate  maximum_date);
+extern DLLIMPORT void AzDatePicker_setFirstDayOfWeek(AzDatePicker* restrict datepicker, AzWeekday  first_day_of_week);
+extern DLLIMPORT AzDatePicker AzDatePicker_withFirstDayOfWeek(AzDatePicker* restrict datepicker, AzWeekday  first_day_of_week);
+extern DLLIMPORT void AzDatePicker_setOnDateChange(AzDatePicker* restrict datepicker, AzRefAny  data, AzDatePickerOnDateChangeCallbackType  callback);
+extern DLLIMPORT AzDatePicker AzDatePicker_withOnDateChange(AzDatePicker* restrict datepicker, AzRefAny  data, AzDatePickerOnDateChangeCallbackType  callback);
+extern DLLIMPORT AzDom AzDatePicker_dom(AzDatePicker* restrict datepicker);
+extern DLLIMPORT void AzDatePicker_delete(AzDatePicker* restrict instance);
+extern DLLIMPORT void AzTimePickerOnTimeChange_delete(AzTimePickerOnTimeChange* restrict instance);
+extern DLLIMPORT AzTimePicker AzTimePicker_new(AzTime  time);
+extern DLLIMPORT void AzTimePicker_setShowSeconds(AzTimePicker* restrict timepicker, bool  show_seconds);
+extern DLLIMPORT AzTimePicker AzTimePicker_withShowSeconds(AzTimePicker* restrict timepicker, bool  show_seconds);
+extern DLLIMPORT void AzTimePicker_setOnTimeChange(AzTimePicker* restrict timepicker, AzRefAny  data, AzTimePickerOnTimeChangeCallbackType  callback);
+extern DLLIMPORT AzTimePicker AzTimePicker_withOnTimeChange(AzTimePicker* restrict timepicker, AzRefAny  data, AzTimePickerOnTimeChangeCallbackType  callback);
+extern DLLIMPORT AzDom AzTimePicker_dom(AzTimePicker* restrict timepicker);
+extern DLLIMPORT void AzTimePicker_delete(AzTimePicker* restrict instance);
+extern DLLIMPORT void AzDateTimePickerOnDateTimeChange_delete(AzDateTimePickerOnDateTimeChange* restrict instance);
+extern DLLIMPORT AzDateTimePicker AzDateTimePicker_new(AzDate  date);
+extern DLLIMPORT void AzDateTimePicker_setState(AzDateTimePicker* restrict datetimepicker, AzDateTimePickerState  state);
+extern DLLIMPORT AzDateTimePicker AzDateTimePicker_withState(AzDateTimePicker* restrict datetimepicker, AzDateTimePickerState  state);
+extern DLLIMPORT void AzDateTimePicker_setMinimumDate(AzDateTimePicker* restrict datetimepicker, AzOptionDate  minimum_date);
+extern DLLIMPORT AzDateTimePicker AzDateTimePicker_withMinimumDate(AzDateTimePicker* restrict datetimepicker, AzOptionDate  minimum_date);
+extern DLLIMPORT void AzDateTimePicker_setMaximumDate(AzDateTimePicker* restrict datetimepicker, AzOptionDate  maximum_date);
+extern DLLIMPORT AzDateTimePicker AzDateTimePicker_withMaximumDate(AzDateTimePicker* restrict datetimepicker, AzOptionDate  maximum_date);
+extern DLLIMPORT void AzDateTimePicker_setFirstDayOfWeek(AzDateTimePicker* restrict datetimepicker, AzWeekday  first_day_of_week);
+extern DLLIMPORT AzDateTimePicker AzDateTimePicker_withFirstDayOfWeek(AzDateTimePicker* restrict datetimepicker, AzWeekday  first_day_of_week);
+extern DLLIMPORT void AzDateTimePicker_setShowTime(AzDateTimePicker* restrict datetimepicker, bool  show_time, bool  show_seconds);
+extern DLLIMPORT AzDateTimePicker AzDateTimePicker_withShowTime(AzDateTimePicker* restrict datetimepicker, bool  show_time, bool  show_seconds);
+extern DLLIMPORT void AzDateTimePicker_setOnDateTimeChange(AzDateTimePicker* restrict datetimepicker, AzRefAny  data, AzDateTimePickerOnDateTimeChangeCallbackType  callback);
+extern DLLIMPORT AzDateTimePicker AzDateTimePicker_withOnDateTimeChange(AzDateTimePicker* restrict datetimepicker, AzRefAny  data, AzDateTimePickerOnDateTimeChangeCallbackType  callback);
+extern DLLIMPORT AzDom AzDateTimePicker_dom(AzDateTimePicker* restrict datetimepicker);
+extern DLLIMPORT void AzDateTimePicker_delete(AzDateTimePicker* restrict instance);
+extern DLLIMPORT bool  AzDialogResult_isAccepted(const AzDialogResult* dialogresult);
+extern DLLIMPORT void AzDialogResult_delete(AzDialogResult* restrict instance);
+extern DLLIMPORT void AzDialogOnClose_delete(AzDialogOnClose* restrict instance);
+extern DLLIMPORT AzDialog AzDialog_msgBox(AzString  title, AzString  message);
+extern DLLIMPORT AzDialog AzDialog_confirm(AzString  title, AzString  message);
+extern DLLIMPORT AzDialog AzDialog_prompt(AzString  title, AzString  message, AzString  default_value);
+extern DLLIMPORT AzDialog AzDialog_custom(AzString  title, AzDom  content);
+extern DLLIMPORT void AzDialog_setIcon(AzDialog* restrict dialog, AzDialogIcon  icon);
+extern DLLIMPORT AzDialog AzDialog_withIcon(AzDialog* restrict dialog, AzDialogIcon  icon);
+extern DLLIMPORT void AzDialog_setButtons(AzDialog* restrict dialog, AzDialogButtons  buttons);
+extern DLLIMPORT AzDialog AzDialog_withButtons(AzDialog* restrict dialog, AzDialogButtons  buttons);
+extern DLLIMPORT void AzDialog_setOnClose(AzDialog* restrict dialog, AzRefAny  data, AzDialogOnCloseCallbackType  callback);
+extern DLLIMPORT AzDialog AzDialog_withOnClose(AzDialog* restrict dialog, AzRefAny  data, AzDialogOnCloseCallbackType  callback);
+extern DLLIMPORT AzDom AzDialog_dom(AzDialog* restrict dialog);
+extern DLLIMPORT AzDom AzDialog_domOver(AzDialog* restrict dialog, AzDom  content);
+extern DLLIMPORT void AzDialog_delete(AzDialog* restrict instance);
+extern DLLIMPORT void AzSplitterState_delete(AzSplitterState* restrict instance);
+extern DLLIMPORT AzSplitterPane AzSplitterPane_new(AzDom  content);
+extern DLLIMPORT void AzSplitterPane_setSize(AzSplitterPane* restrict splitterpane, float size);
+extern DLLIMPORT AzSplitterPane AzSplitterPane_withSize(AzSplitterPane* restrict splitterpane, float size);
+extern DLLIMPORT void AzSplitterPane_setMinSize(AzSplitterPane* restrict splitterpane, float min_size);
+extern DLLIMPORT AzSplitterPane AzSplitterPane_withMinSize(AzSplitterPane* restrict splitterpane, float min_size);
+extern DLLIMPORT void AzSplitterPane_setMaxSize(AzSplitterPane* restrict splitterpane, float max_size);
+extern DLLIMPORT AzSplitterPane AzSplitterPane_withMaxSize(AzSplitterPane* restrict splitterpane, float max_size);
+extern DLLIMPORT void AzSplitterPane_setCollapsed(AzSplitterPane* restrict splitterpane, bool  collapsed);
+extern DLLIMPORT AzSplitterPane AzSplitterPane_withCollapsed(AzSplitterPane* restrict splitterpane, bool  collapsed);
+extern DLLIMPORT void AzSplitterPane_delete(AzSplitterPane* restrict instance);
+extern DLLIMPORT void AzSplitterOnResize_delete(AzSplitterOnResize* restrict instance);
+extern DLLIMPORT AzSplitter AzSplitter_new(AzSplitterDirection  direction);
+extern DLLIMPORT AzSplitter AzSplitter_withPane(AzSplitter* restrict splitter, AzSplitterPane  pane);
+extern DLLIMPORT void AzSplitter_addPane(AzSplitter* restrict splitter, AzSplitterPane  pane);
+extern DLLIMPORT void AzSplitter_setHandleSize(AzSplitter* restrict splitter, float handle_size);
+extern DLLIMPORT AzSplitter AzSplitter_withHandleSize(AzSplitter* restrict splitter, float handle_size);
+extern DLLIMPORT AzSplitterState AzSplitter_getState(const AzSplitter* splitter);
+extern DLLIMPORT void AzSplitter_setState(AzSplitter* restrict splitter, AzSplitterState  state);
+extern DLLIMPORT AzSplitter AzSplitter_withState(AzSplitter* restrict splitter, AzSplitterState  state);
+extern DLLIMPORT void AzSplitter_setOnResize(AzSplitter* restrict splitter, AzRefAny  data, AzSplitterOnResizeCallbackType  callback);
+extern DLLIMPORT AzSplitter AzSplitter_withOnResize(AzSplitter* restrict splitter, AzRefAny  data, AzSplitterOnResizeCallbackType  callback);
+extern DLLIMPORT AzDom AzSplitter_dom(AzSplitter* restrict splitter);
+extern DLLIMPORT void AzSplitter_delete(AzSplitter* restrict instance);
 extern DLLIMPORT AzDropDown AzDropDown_new(AzStringVec  choices);
 extern DLLIMPORT AzDom AzDropDown_dom(AzDropDown* restrict dropdown);
 extern DLLIMPORT void AzDropDown_delete(AzDropDown* restrict instance);
@@ -13202,6 +15286,8 @@ extern DLLIMPORT AzFontRef AzFontRef_parse(AzFontSource  source);
 extern DLLIMPORT AzU8Vec AzFontRef_getBytes(const AzFontRef* fontref);
 extern DLLIMPORT AzFontMetrics AzFontRef_getFontMetrics(const AzFontRef* fontref);
 extern DLLIMPORT AzInlineText AzFontRef_shapeText(const AzFontRef* fontref, AzRefstr  text, AzResolvedTextLayoutOptions  options);
+extern DLLIMPORT AzTextMetrics AzFontRef_measureText(const AzFontRef* fontref, AzRefstr  text, AzResolvedTextLayoutOptions  options);
+extern DLLIMPORT AzSvgMultiPolygon AzFontRef_textToPath(const AzFontRef* fontref, AzRefstr  text, AzResolvedTextLayoutOptions  options);
 extern DLLIMPORT uint64_t AzFontRef_getHash(const AzFontRef* fontref);
 extern DLLIMPORT void AzFontRef_delete(AzFontRef* restrict instance);
 extern DLLIMPORT AzFontRef AzFontRef_deepCopy(AzFontRef* const instance);
@@ -13374,10 +15460,16 @@ extern DLLIMPORT AzString AzString_trim(const AzString* string);
 extern DLLIMPORT AzRefstr AzString_asRefstr(const AzString* string);
 extern DLLIMPORT void AzString_delete(AzString* restrict instance);
 extern DLLIMPORT void AzListViewRowVec_delete(AzListViewRowVec* restrict instance);
+extern DLLIMPORT void AzTreeViewItemVec_delete(AzTreeViewItemVec* restrict instance);
+extern DLLIMPORT void AzSplitterPaneVec_delete(AzSplitterPaneVec* restrict instance);
+extern DLLIMPORT void AzSplitterPaneStateVec_delete(AzSplitterPaneStateVec* restrict instance);
 extern DLLIMPORT void AzStyleFilterVec_delete(AzStyleFilterVec* restrict instance);
 extern DLLIMPORT void AzLogicalRectVec_delete(AzLogicalRectVec* restrict instance);
 extern DLLIMPORT void AzNodeTypeIdInfoMapVec_delete(AzNodeTypeIdInfoMapVec* restrict instance);
 extern DLLIMPORT void AzInputOutputTypeIdInfoMapVec_delete(AzInputOutputTypeIdInfoMapVec* restrict instance);
+extern DLLIMPORT void AzNodeGraphNodeIdVec_delete(AzNodeGraphNodeIdVec* restrict instance);
+extern DLLIMPORT void AzNodeGraphEditOpVec_delete(AzNodeGraphEditOpVec* restrict instance);
+extern DLLIMPORT void AzNodeGraphEditVec_delete(AzNodeGraphEditVec* restrict instance);
 extern DLLIMPORT void AzNodeIdNodeMapVec_delete(AzNodeIdNodeMapVec* restrict instance);
 extern DLLIMPORT void AzInputOutputTypeIdVec_delete(AzInputOutputTypeIdVec* restrict instance);
 extern DLLIMPORT void AzNodeTypeFieldVec_delete(AzNodeTypeFieldVec* restrict instance);
@@ -13386,6 +15478,7 @@ extern DLLIMPORT void AzOutputNodeAndIndexVec_delete(AzOutputNodeAndIndexVec* re
 extern DLLIMPORT void AzOutputConnectionVec_delete(AzOutputConnectionVec* restrict instance);
 extern DLLIMPORT void AzInputNodeAndIndexVec_delete(AzInputNodeAndIndexVec* restrict instance);
 extern DLLIMPORT void AzAccessibilityStateVec_delete(AzAccessibilityStateVec* restrict instance);
+extern DLLIMPORT void AzKeyboardShortcutVec_delete(AzKeyboardShortcutVec* restrict instance);
 extern DLLIMPORT void AzMenuItemVec_delete(AzMenuItemVec* restrict instance);
 extern DLLIMPORT AzTessellatedSvgNodeVecRef AzTessellatedSvgNodeVec_asRefVec(const AzTessellatedSvgNodeVec* tessellatedsvgnodevec);
 extern DLLIMPORT void AzTessellatedSvgNodeVec_delete(AzTessellatedSvgNodeVec* restrict instance);
@@ -13397,6 +15490,8 @@ extern DLLIMPORT void AzFmtArgVec_delete(AzFmtArgVec* restrict instance);
 extern DLLIMPORT void AzInlineLineVec_delete(AzInlineLineVec* restrict instance);
 extern DLLIMPORT void AzInlineWordVec_delete(AzInlineWordVec* restrict instance);
 extern DLLIMPORT void AzInlineGlyphVec_delete(AzInlineGlyphVec* restrict instance);
+extern DLLIMPORT void AzTextLineMetricsVec_delete(AzTextLineMetricsVec* restrict instance);
+extern DLLIMPORT void AzInlineTextRunVec_delete(AzInlineTextRunVec* restrict instance);
 extern DLLIMPORT void AzInlineTextHitVec_delete(AzInlineTextHitVec* restrict instance);
 extern DLLIMPORT void AzMonitorVec_delete(AzMonitorVec* restrict instance);
 extern DLLIMPORT void AzVideoModeVec_delete(AzVideoModeVec* restrict instance);
@@ -13424,6 +15519,9 @@ extern DLLIMPORT void AzScanCodeVec_delete(AzScanCodeVec* restrict instance);
 extern DLLIMPORT void AzCssDeclarationVec_delete(AzCssDeclarationVec* restrict instance);
 extern DLLIMPORT void AzCssPathSelectorVec_delete(AzCssPathSelectorVec* restrict instance);
 extern DLLIMPORT void AzStylesheetVec_delete(AzStylesheetVec* restrict instance);
+extern DLLIMPORT void AzCssFontFaceVec_delete(AzCssFontFaceVec* restrict instance);
+extern DLLIMPORT void AzCssFontFaceSourceVec_delete(AzCssFontFaceSourceVec* restrict instance);
+extern DLLIMPORT void AzCssUnicodeRangeVec_delete(AzCssUnicodeRangeVec* restrict instance);
 extern DLLIMPORT void AzCssRuleBlockVec_delete(AzCssRuleBlockVec* restrict instance);
 extern DLLIMPORT void AzU16Vec_delete(AzU16Vec* restrict instance);
 extern DLLIMPORT void AzF32Vec_delete(AzF32Vec* restrict instance);
@@ -13444,12 +15542,36 @@ extern DLLIMPORT void AzStyledNodeVec_delete(AzStyledNodeVec* restrict instance)
 extern DLLIMPORT void AzTagIdToNodeIdMappingVec_delete(AzTagIdToNodeIdMappingVec* restrict instance);
 extern DLLIMPORT void AzParentWithNodeDepthVec_delete(AzParentWithNodeDepthVec* restrict instance);
 extern DLLIMPORT void AzNodeDataVec_delete(AzNodeDataVec* restrict instance);
+extern DLLIMPORT void AzOptionTreeViewOnExpand_delete(AzOptionTreeViewOnExpand* restrict instance);
+extern DLLIMPORT void AzOptionTreeViewOnSelect_delete(AzOptionTreeViewOnSelect* restrict instance);
+extern DLLIMPORT void AzOptionTreeViewOnLazyLoad_delete(AzOptionTreeViewOnLazyLoad* restrict instance);
+extern DLLIMPORT void AzOptionTreeViewOnScroll_delete(AzOptionTreeViewOnScroll* restrict instance);
 extern DLLIMPORT void AzOptionListViewOnRowClick_delete(AzOptionListViewOnRowClick* restrict instance);
+extern DLLIMPORT void AzOptionListViewOnRowSelect_delete(AzOptionListViewOnRowSelect* restrict instance);
+extern DLLIMPORT void AzOptionListViewOnColumnResize_delete(AzOptionListViewOnColumnResize* restrict instance);
+extern DLLIMPORT void AzOptionListViewRowProvider_delete(AzOptionListViewRowProvider* restrict instance);
+extern DLLIMPORT void AzOptionDataGridCellProvider_delete(AzOptionDataGridCellProvider* restrict instance);
+extern DLLIMPORT void AzOptionDataGridCellStyle_delete(AzOptionDataGridCellStyle* restrict instance);
+extern DLLIMPORT void AzOptionDataGridOnSelect_delete(AzOptionDataGridOnSelect* restrict instance);
+extern DLLIMPORT void AzOptionDataGridOnScroll_delete(AzOptionDataGridOnScroll* restrict instance);
+extern DLLIMPORT void AzOptionDataGridOnCellEdit_delete(AzOptionDataGridOnCellEdit* restrict instance);
+extern DLLIMPORT void AzOptionDataGridOnColumnResize_delete(AzOptionDataGridOnColumnResize* restrict instance);
+extern DLLIMPORT void AzOptionDataGridOnRowResize_delete(AzOptionDataGridOnRowResize* restrict instance);
+extern DLLIMPORT void AzOptionDataGridOnCopyRange_delete(AzOptionDataGridOnCopyRange* restrict instance);
+extern DLLIMPORT void AzOptionDataGridOnClearRange_delete(AzOptionDataGridOnClearRange* restrict instance);
+extern DLLIMPORT void AzOptionDatePickerOnDateChange_delete(AzOptionDatePickerOnDateChange* restrict instance);
+extern DLLIMPORT void AzOptionTimePickerOnTimeChange_delete(AzOptionTimePickerOnTimeChange* restrict instance);
+extern DLLIMPORT void AzOptionDateTimePickerOnDateTimeChange_delete(AzOptionDateTimePickerOnDateTimeChange* restrict instance);
+extern DLLIMPORT void AzOptionDialogOnClose_delete(AzOptionDialogOnClose* restrict instance);
+extern DLLIMPORT void AzOptionSplitterOnResize_delete(AzOptionSplitterOnResize* restrict instance);
 extern DLLIMPORT void AzOptionListViewOnColumnClick_delete(AzOptionListViewOnColumnClick* restrict instance);
 extern DLLIMPORT void AzOptionListViewOnLazyLoadScroll_delete(AzOptionListViewOnLazyLoadScroll* restrict instance);
 extern DLLIMPORT void AzOptionMenu_delete(AzOptionMenu* restrict instance);
 extern DLLIMPORT void AzOptionDropDownOnChoiceChange_delete(AzOptionDropDownOnChoiceChange* restrict instance);
 extern DLLIMPORT void AzOptionResolvedTextLayoutOptions_delete(AzOptionResolvedTextLayoutOptions* restrict instance);
+extern DLLIMPORT void AzOptionNodeGraphOnNodeGraphZoomed_delete(AzOptionNodeGraphOnNodeGraphZoomed* restrict instance);
+extern DLLIMPORT void AzOptionNodeGraphOnSelectionChanged_delete(AzOptionNodeGraphOnSelectionChanged* restrict instance);
+extern DLLIMPORT void AzOptionNodeGraphOnHistoryChanged_delete(AzOptionNodeGraphOnHistoryChanged* restrict instance);
 extern DLLIMPORT void AzOptionNodeGraphOnNodeAdded_delete(AzOptionNodeGraphOnNodeAdded* restrict instance);
 extern DLLIMPORT void AzOptionNodeGraphOnNodeRemoved_delete(AzOptionNodeGraphOnNodeRemoved* restrict instance);
 extern DLLIMPORT void AzOptionNodeGraphOnNodeGraphDragged_delete(AzOptionNodeGraphOnNodeGraphDragged* restrict instance);
@@ -13485,6 +15607,7 @@ extern DLLIMPORT void AzOptionThreadReceiveMsg_delete(AzOptionThreadReceiveMsg*
 extern DLLIMPORT void AzOptionThreadSendMsg_delete(AzOptionThreadSendMsg* restrict instance);
 extern DLLIMPORT void AzOptionRefAny_delete(AzOptionRefAny* restrict instance);
 extern DLLIMPORT void AzOptionInlineText_delete(AzOptionInlineText* restrict instance);
+extern DLLIMPORT void AzOptionTextMetrics_delete(AzOptionTextMetrics* restrict instance);
 extern DLLIMPORT void AzOptionRawImage_delete(AzOptionRawImage* restrict instance);
 extern DLLIMPORT void AzOptionWaylandTheme_delete(AzOptionWaylandTheme* restrict instance);
 extern DLLIMPORT void AzOptionTaskBarIcon_delete(AzOptionTaskBarIcon* restrict instance);
@@ -13499,6 +15622,7 @@ extern DLLIMPORT void AzResultXmlXmlError_delete(AzResultXmlXmlError* restrict i
 extern DLLIMPORT void AzResultRawImageDecodeImageError_delete(AzResultRawImageDecodeImageError* restrict instance);
 extern DLLIMPORT void AzResultU8VecEncodeImageError_delete(AzResultU8VecEncodeImageError* restrict instance);
 extern DLLIMPORT void AzResultSvgXmlNodeSvgParseError_delete(AzResultSvgXmlNodeSvgParseError* restrict instance);
+extern DLLIMPORT void AzResultNodeGraphNodeGraphError_delete(AzResultNodeGraphNodeGraphError* restrict instance);
 extern DLLIMPORT void AzResultSvgSvgParseError_delete(AzResultSvgSvgParseError* restrict instance);
 extern DLLIMPORT void AzSvgParseError_delete(AzSvgParseError* restrict instance);
 extern DLLIMPORT void AzXmlError_delete(AzXmlError* restrict instance);
@@ -15470,6 +17594,34 @@ bool AzNotEventFilter_matchMutFocus(AzNotEventFilter* restrict value, AzFocusEve
     return valid;
 }
 
+bool AzTooltipContent_matchRefText(const AzTooltipContent* value, const AzString** restrict out) {
+    const AzTooltipContentVariant_Text* casted = (const AzTooltipContentVariant_Text*)value;
+    bool valid = casted->tag == AzTooltipContentTag_Text;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTooltipContent_matchMutText(AzTooltipContent* restrict value, AzString* restrict * restrict out) {
+    AzTooltipContentVariant_Text* restrict casted = (AzTooltipContentVariant_Text* restrict)value;
+    bool valid = casted->tag == AzTooltipContentTag_Text;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTooltipContent_matchRefDom(const AzTooltipContent* value, const AzDom** restrict out) {
+    const AzTooltipContentVariant_Dom* casted = (const AzTooltipContentVariant_Dom*)value;
+    bool valid = casted->tag == AzTooltipContentTag_Dom;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTooltipContent_matchMutDom(AzTooltipContent* restrict value, AzDom* restrict * restrict out) {
+    AzTooltipContentVariant_Dom* restrict casted = (AzTooltipContentVariant_Dom* restrict)value;
+    bool valid = casted->tag == AzTooltipContentTag_Dom;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzTabIndex_matchRefOverrideInParent(const AzTabIndex* value, const Azu32** restrict out) {
     const AzTabIndexVariant_OverrideInParent* casted = (const AzTabIndexVariant_OverrideInParent*)value;
     bool valid = casted->tag == AzTabIndexTag_OverrideInParent;
@@ -15736,15 +17888,43 @@ bool AzCssNthChildSelector_matchMutPattern(AzCssNthChildSelector* restrict value
     return valid;
 }
 
-bool AzAnimationInterpolationFunction_matchRefCubicBezier(const AzAnimationInterpolationFunction* value, const AzSvgCubicCurve** restrict out) {
-    const AzAnimationInterpolationFunctionVariant_CubicBezier* casted = (const AzAnimationInterpolationFunctionVariant_CubicBezier*)value;
-    bool valid = casted->tag == AzAnimationInterpolationFunctionTag_CubicBezier;
+bool AzCssFontFaceSource_matchRefUrl(const AzCssFontFaceSource* value, const AzCssFontFaceUrl** restrict out) {
+    const AzCssFontFaceSourceVariant_Url* casted = (const AzCssFontFaceSourceVariant_Url*)value;
+    bool valid = casted->tag == AzCssFontFaceSourceTag_Url;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzAnimationInterpolationFunction_matchMutCubicBezier(AzAnimationInterpolationFunction* restrict value, AzSvgCubicCurve* restrict * restrict out) {
-    AzAnimationInterpolationFunctionVariant_CubicBezier* restrict casted = (AzAnimationInterpolationFunctionVariant_CubicBezier* restrict)value;
+bool AzCssFontFaceSource_matchMutUrl(AzCssFontFaceSource* restrict value, AzCssFontFaceUrl* restrict * restrict out) {
+    AzCssFontFaceSourceVariant_Url* restrict casted = (AzCssFontFaceSourceVariant_Url* restrict)value;
+    bool valid = casted->tag == AzCssFontFaceSourceTag_Url;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssFontFaceSource_matchRefLocal(const AzCssFontFaceSource* value, const AzString** restrict out) {
+    const AzCssFontFaceSourceVariant_Local* casted = (const AzCssFontFaceSourceVariant_Local*)value;
+    bool valid = casted->tag == AzCssFontFaceSourceTag_Local;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssFontFaceSource_matchMutLocal(AzCssFontFaceSource* restrict value, AzString* restrict * restrict out) {
+    AzCssFontFaceSourceVariant_Local* restrict casted = (AzCssFontFaceSourceVariant_Local* restrict)value;
+    bool valid = casted->tag == AzCssFontFaceSourceTag_Local;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzAnimationInterpolationFunction_matchRefCubicBezier(const AzAnimationInterpolationFunction* value, const AzSvgCubicCurve** restrict out) {
+    const AzAnimationInterpolationFunctionVariant_CubicBezier* casted = (const AzAnimationInterpolationFunctionVariant_CubicBezier*)value;
+    bool valid = casted->tag == AzAnimationInterpolationFunctionTag_CubicBezier;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzAnimationInterpolationFunction_matchMutCubicBezier(AzAnimationInterpolationFunction* restrict value, AzSvgCubicCurve* restrict * restrict out) {
+    AzAnimationInterpolationFunctionVariant_CubicBezier* restrict casted = (AzAnimationInterpolationFunctionVariant_CubicBezier* restrict)value;
     bool valid = casted->tag == AzAnimationInterpolationFunctionTag_CubicBezier;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
@@ -16744,6 +18924,20 @@ bool AzLayoutTopValue_matchMutExact(AzLayoutTopValue* restrict value, AzLayoutTo
     return valid;
 }
 
+bool AzLayoutVerticalAlignValue_matchRefExact(const AzLayoutVerticalAlignValue* value, const AzLayoutVerticalAlign** restrict out) {
+    const AzLayoutVerticalAlignValueVariant_Exact* casted = (const AzLayoutVerticalAlignValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutVerticalAlignValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutVerticalAlignValue_matchMutExact(AzLayoutVerticalAlignValue* restrict value, AzLayoutVerticalAlign* restrict * restrict out) {
+    AzLayoutVerticalAlignValueVariant_Exact* restrict casted = (AzLayoutVerticalAlignValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutVerticalAlignValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzLayoutWidthValue_matchRefExact(const AzLayoutWidthValue* value, const AzLayoutWidth** restrict out) {
     const AzLayoutWidthValueVariant_Exact* casted = (const AzLayoutWidthValueVariant_Exact*)value;
     bool valid = casted->tag == AzLayoutWidthValueTag_Exact;
@@ -17178,6 +19372,20 @@ bool AzStyleTextAlignValue_matchMutExact(AzStyleTextAlignValue* restrict value,
     return valid;
 }
 
+bool AzStyleTextDecorationValue_matchRefExact(const AzStyleTextDecorationValue* value, const AzStyleTextDecoration** restrict out) {
+    const AzStyleTextDecorationValueVariant_Exact* casted = (const AzStyleTextDecorationValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleTextDecorationValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTextDecorationValue_matchMutExact(AzStyleTextDecorationValue* restrict value, AzStyleTextDecoration* restrict * restrict out) {
+    AzStyleTextDecorationValueVariant_Exact* restrict casted = (AzStyleTextDecorationValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleTextDecorationValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzStyleTextColorValue_matchRefExact(const AzStyleTextColorValue* value, const AzStyleTextColor** restrict out) {
     const AzStyleTextColorValueVariant_Exact* casted = (const AzStyleTextColorValueVariant_Exact*)value;
     bool valid = casted->tag == AzStyleTextColorValueTag_Exact;
@@ -18340,6 +20548,34 @@ bool AzCssProperty_matchMutTextShadow(AzCssProperty* restrict value, AzStyleBoxS
     return valid;
 }
 
+bool AzCssProperty_matchRefVerticalAlign(const AzCssProperty* value, const AzLayoutVerticalAlignValue** restrict out) {
+    const AzCssPropertyVariant_VerticalAlign* casted = (const AzCssPropertyVariant_VerticalAlign*)value;
+    bool valid = casted->tag == AzCssPropertyTag_VerticalAlign;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutVerticalAlign(AzCssProperty* restrict value, AzLayoutVerticalAlignValue* restrict * restrict out) {
+    AzCssPropertyVariant_VerticalAlign* restrict casted = (AzCssPropertyVariant_VerticalAlign* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_VerticalAlign;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefTextDecoration(const AzCssProperty* value, const AzStyleTextDecorationValue** restrict out) {
+    const AzCssPropertyVariant_TextDecoration* casted = (const AzCssPropertyVariant_TextDecoration*)value;
+    bool valid = casted->tag == AzCssPropertyTag_TextDecoration;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutTextDecoration(AzCssProperty* restrict value, AzStyleTextDecorationValue* restrict * restrict out) {
+    AzCssPropertyVariant_TextDecoration* restrict casted = (AzCssPropertyVariant_TextDecoration* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_TextDecoration;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzTextInputSelection_matchRefFromTo(const AzTextInputSelection* value, const AzTextInputSelectionRange** restrict out) {
     const AzTextInputSelectionVariant_FromTo* casted = (const AzTextInputSelectionVariant_FromTo*)value;
     bool valid = casted->tag == AzTextInputSelectionTag_FromTo;
@@ -18424,6 +20660,90 @@ bool AzNodeTypeFieldValue_matchMutFileInput(AzNodeTypeFieldValue* restrict value
     return valid;
 }
 
+bool AzNodeGraphEditOp_matchRefAddNode(const AzNodeGraphEditOp* value, const AzNodeIdNodeMap** restrict out) {
+    const AzNodeGraphEditOpVariant_AddNode* casted = (const AzNodeGraphEditOpVariant_AddNode*)value;
+    bool valid = casted->tag == AzNodeGraphEditOpTag_AddNode;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzNodeGraphEditOp_matchMutAddNode(AzNodeGraphEditOp* restrict value, AzNodeIdNodeMap* restrict * restrict out) {
+    AzNodeGraphEditOpVariant_AddNode* restrict casted = (AzNodeGraphEditOpVariant_AddNode* restrict)value;
+    bool valid = casted->tag == AzNodeGraphEditOpTag_AddNode;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzNodeGraphEditOp_matchRefRemoveNode(const AzNodeGraphEditOp* value, const AzNodeIdNodeMap** restrict out) {
+    const AzNodeGraphEditOpVariant_RemoveNode* casted = (const AzNodeGraphEditOpVariant_RemoveNode*)value;
+    bool valid = casted->tag == AzNodeGraphEditOpTag_RemoveNode;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzNodeGraphEditOp_matchMutRemoveNode(AzNodeGraphEditOp* restrict value, AzNodeIdNodeMap* restrict * restrict out) {
+    AzNodeGraphEditOpVariant_RemoveNode* restrict casted = (AzNodeGraphEditOpVariant_RemoveNode* restrict)value;
+    bool valid = casted->tag == AzNodeGraphEditOpTag_RemoveNode;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzNodeGraphEditOp_matchRefMoveNode(const AzNodeGraphEditOp* value, const AzNodeGraphNodeMove** restrict out) {
+    const AzNodeGraphEditOpVariant_MoveNode* casted = (const AzNodeGraphEditOpVariant_MoveNode*)value;
+    bool valid = casted->tag == AzNodeGraphEditOpTag_MoveNode;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzNodeGraphEditOp_matchMutMoveNode(AzNodeGraphEditOp* restrict value, AzNodeGraphNodeMove* restrict * restrict out) {
+    AzNodeGraphEditOpVariant_MoveNode* restrict casted = (AzNodeGraphEditOpVariant_MoveNode* restrict)value;
+    bool valid = casted->tag == AzNodeGraphEditOpTag_MoveNode;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzNodeGraphEditOp_matchRefConnect(const AzNodeGraphEditOp* value, const AzNodeGraphConnection** restrict out) {
+    const AzNodeGraphEditOpVariant_Connect* casted = (const AzNodeGraphEditOpVariant_Connect*)value;
+    bool valid = casted->tag == AzNodeGraphEditOpTag_Connect;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzNodeGraphEditOp_matchMutConnect(AzNodeGraphEditOp* restrict value, AzNodeGraphConnection* restrict * restrict out) {
+    AzNodeGraphEditOpVariant_Connect* restrict casted = (AzNodeGraphEditOpVariant_Connect* restrict)value;
+    bool valid = casted->tag == AzNodeGraphEditOpTag_Connect;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzNodeGraphEditOp_matchRefDisconnect(const AzNodeGraphEditOp* value, const AzNodeGraphConnection** restrict out) {
+    const AzNodeGraphEditOpVariant_Disconnect* casted = (const AzNodeGraphEditOpVariant_Disconnect*)value;
+    bool valid = casted->tag == AzNodeGraphEditOpTag_Disconnect;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzNodeGraphEditOp_matchMutDisconnect(AzNodeGraphEditOp* restrict value, AzNodeGraphConnection* restrict * restrict out) {
+    AzNodeGraphEditOpVariant_Disconnect* restrict casted = (AzNodeGraphEditOpVariant_Disconnect* restrict)value;
+    bool valid = casted->tag == AzNodeGraphEditOpTag_Disconnect;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzNodeGraphEditOp_matchRefEditField(const AzNodeGraphEditOp* value, const AzNodeGraphFieldEdit** restrict out) {
+    const AzNodeGraphEditOpVariant_EditField* casted = (const AzNodeGraphEditOpVariant_EditField*)value;
+    bool valid = casted->tag == AzNodeGraphEditOpTag_EditField;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzNodeGraphEditOp_matchMutEditField(AzNodeGraphEditOp* restrict value, AzNodeGraphFieldEdit* restrict * restrict out) {
+    AzNodeGraphEditOpVariant_EditField* restrict casted = (AzNodeGraphEditOpVariant_EditField* restrict)value;
+    bool valid = casted->tag == AzNodeGraphEditOpTag_EditField;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCssPropertySource_matchRefCss(const AzCssPropertySource* value, const AzCssPath** restrict out) {
     const AzCssPropertySourceVariant_Css* casted = (const AzCssPropertySourceVariant_Css*)value;
     bool valid = casted->tag == AzCssPropertySourceTag_Css;
@@ -19096,6 +21416,48 @@ bool AzListViewRowVecDestructor_matchMutExternal(AzListViewRowVecDestructor* res
     return valid;
 }
 
+bool AzTreeViewItemVecDestructor_matchRefExternal(const AzTreeViewItemVecDestructor* value, const AzTreeViewItemVecDestructorType** restrict out) {
+    const AzTreeViewItemVecDestructorVariant_External* casted = (const AzTreeViewItemVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzTreeViewItemVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTreeViewItemVecDestructor_matchMutExternal(AzTreeViewItemVecDestructor* restrict value, AzTreeViewItemVecDestructorType* restrict * restrict out) {
+    AzTreeViewItemVecDestructorVariant_External* restrict casted = (AzTreeViewItemVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzTreeViewItemVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzSplitterPaneVecDestructor_matchRefExternal(const AzSplitterPaneVecDestructor* value, const AzSplitterPaneVecDestructorType** restrict out) {
+    const AzSplitterPaneVecDestructorVariant_External* casted = (const AzSplitterPaneVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzSplitterPaneVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzSplitterPaneVecDestructor_matchMutExternal(AzSplitterPaneVecDestructor* restrict value, AzSplitterPaneVecDestructorType* restrict * restrict out) {
+    AzSplitterPaneVecDestructorVariant_External* restrict casted = (AzSplitterPaneVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzSplitterPaneVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzSplitterPaneStateVecDestructor_matchRefExternal(const AzSplitterPaneStateVecDestructor* value, const AzSplitterPaneStateVecDestructorType** restrict out) {
+    const AzSplitterPaneStateVecDestructorVariant_External* casted = (const AzSplitterPaneStateVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzSplitterPaneStateVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzSplitterPaneStateVecDestructor_matchMutExternal(AzSplitterPaneStateVecDestructor* restrict value, AzSplitterPaneStateVecDestructorType* restrict * restrict out) {
+    AzSplitterPaneStateVecDestructorVariant_External* restrict casted = (AzSplitterPaneStateVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzSplitterPaneStateVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzStyleFilterVecDestructor_matchRefExternal(const AzStyleFilterVecDestructor* value, const AzStyleFilterVecDestructorType** restrict out) {
     const AzStyleFilterVecDestructorVariant_External* casted = (const AzStyleFilterVecDestructorVariant_External*)value;
     bool valid = casted->tag == AzStyleFilterVecDestructorTag_External;
@@ -19152,6 +21514,48 @@ bool AzInputOutputTypeIdInfoMapVecDestructor_matchMutExternal(AzInputOutputTypeI
     return valid;
 }
 
+bool AzNodeGraphNodeIdVecDestructor_matchRefExternal(const AzNodeGraphNodeIdVecDestructor* value, const AzNodeGraphNodeIdVecDestructorType** restrict out) {
+    const AzNodeGraphNodeIdVecDestructorVariant_External* casted = (const AzNodeGraphNodeIdVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzNodeGraphNodeIdVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzNodeGraphNodeIdVecDestructor_matchMutExternal(AzNodeGraphNodeIdVecDestructor* restrict value, AzNodeGraphNodeIdVecDestructorType* restrict * restrict out) {
+    AzNodeGraphNodeIdVecDestructorVariant_External* restrict casted = (AzNodeGraphNodeIdVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzNodeGraphNodeIdVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzNodeGraphEditOpVecDestructor_matchRefExternal(const AzNodeGraphEditOpVecDestructor* value, const AzNodeGraphEditOpVecDestructorType** restrict out) {
+    const AzNodeGraphEditOpVecDestructorVariant_External* casted = (const AzNodeGraphEditOpVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzNodeGraphEditOpVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzNodeGraphEditOpVecDestructor_matchMutExternal(AzNodeGraphEditOpVecDestructor* restrict value, AzNodeGraphEditOpVecDestructorType* restrict * restrict out) {
+    AzNodeGraphEditOpVecDestructorVariant_External* restrict casted = (AzNodeGraphEditOpVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzNodeGraphEditOpVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzNodeGraphEditVecDestructor_matchRefExternal(const AzNodeGraphEditVecDestructor* value, const AzNodeGraphEditVecDestructorType** restrict out) {
+    const AzNodeGraphEditVecDestructorVariant_External* casted = (const AzNodeGraphEditVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzNodeGraphEditVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzNodeGraphEditVecDestructor_matchMutExternal(AzNodeGraphEditVecDestructor* restrict value, AzNodeGraphEditVecDestructorType* restrict * restrict out) {
+    AzNodeGraphEditVecDestructorVariant_External* restrict casted = (AzNodeGraphEditVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzNodeGraphEditVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzNodeIdNodeMapVecDestructor_matchRefExternal(const AzNodeIdNodeMapVecDestructor* value, const AzNodeIdNodeMapVecDestructorType** restrict out) {
     const AzNodeIdNodeMapVecDestructorVariant_External* casted = (const AzNodeIdNodeMapVecDestructorVariant_External*)value;
     bool valid = casted->tag == AzNodeIdNodeMapVecDestructorTag_External;
@@ -19264,6 +21668,20 @@ bool AzAccessibilityStateVecDestructor_matchMutExternal(AzAccessibilityStateVecD
     return valid;
 }
 
+bool AzKeyboardShortcutVecDestructor_matchRefExternal(const AzKeyboardShortcutVecDestructor* value, const AzKeyboardShortcutVecDestructorType** restrict out) {
+    const AzKeyboardShortcutVecDestructorVariant_External* casted = (const AzKeyboardShortcutVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzKeyboardShortcutVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzKeyboardShortcutVecDestructor_matchMutExternal(AzKeyboardShortcutVecDestructor* restrict value, AzKeyboardShortcutVecDestructorType* restrict * restrict out) {
+    AzKeyboardShortcutVecDestructorVariant_External* restrict casted = (AzKeyboardShortcutVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzKeyboardShortcutVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzMenuItemVecDestructor_matchRefExternal(const AzMenuItemVecDestructor* value, const AzMenuItemVecDestructorType** restrict out) {
     const AzMenuItemVecDestructorVariant_External* casted = (const AzMenuItemVecDestructorVariant_External*)value;
     bool valid = casted->tag == AzMenuItemVecDestructorTag_External;
@@ -19376,6 +21794,34 @@ bool AzInlineGlyphVecDestructor_matchMutExternal(AzInlineGlyphVecDestructor* res
     return valid;
 }
 
+bool AzTextLineMetricsVecDestructor_matchRefExternal(const AzTextLineMetricsVecDestructor* value, const AzTextLineMetricsVecDestructorType** restrict out) {
+    const AzTextLineMetricsVecDestructorVariant_External* casted = (const AzTextLineMetricsVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzTextLineMetricsVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTextLineMetricsVecDestructor_matchMutExternal(AzTextLineMetricsVecDestructor* restrict value, AzTextLineMetricsVecDestructorType* restrict * restrict out) {
+    AzTextLineMetricsVecDestructorVariant_External* restrict casted = (AzTextLineMetricsVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzTextLineMetricsVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzInlineTextRunVecDestructor_matchRefExternal(const AzInlineTextRunVecDestructor* value, const AzInlineTextRunVecDestructorType** restrict out) {
+    const AzInlineTextRunVecDestructorVariant_External* casted = (const AzInlineTextRunVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzInlineTextRunVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzInlineTextRunVecDestructor_matchMutExternal(AzInlineTextRunVecDestructor* restrict value, AzInlineTextRunVecDestructorType* restrict * restrict out) {
+    AzInlineTextRunVecDestructorVariant_External* restrict casted = (AzInlineTextRunVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzInlineTextRunVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzInlineTextHitVecDestructor_matchRefExternal(const AzInlineTextHitVecDestructor* value, const AzInlineTextHitVecDestructorType** restrict out) {
     const AzInlineTextHitVecDestructorVariant_External* casted = (const AzInlineTextHitVecDestructorVariant_External*)value;
     bool valid = casted->tag == AzInlineTextHitVecDestructorTag_External;
@@ -19754,6 +22200,48 @@ bool AzStylesheetVecDestructor_matchMutExternal(AzStylesheetVecDestructor* restr
     return valid;
 }
 
+bool AzCssFontFaceVecDestructor_matchRefExternal(const AzCssFontFaceVecDestructor* value, const AzCssFontFaceVecDestructorType** restrict out) {
+    const AzCssFontFaceVecDestructorVariant_External* casted = (const AzCssFontFaceVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzCssFontFaceVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssFontFaceVecDestructor_matchMutExternal(AzCssFontFaceVecDestructor* restrict value, AzCssFontFaceVecDestructorType* restrict * restrict out) {
+    AzCssFontFaceVecDestructorVariant_External* restrict casted = (AzCssFontFaceVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzCssFontFaceVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssFontFaceSourceVecDestructor_matchRefExternal(const AzCssFontFaceSourceVecDestructor* value, const AzCssFontFaceSourceVecDestructorType** restrict out) {
+    const AzCssFontFaceSourceVecDestructorVariant_External* casted = (const AzCssFontFaceSourceVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzCssFontFaceSourceVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssFontFaceSourceVecDestructor_matchMutExternal(AzCssFontFaceSourceVecDestructor* restrict value, AzCssFontFaceSourceVecDestructorType* restrict * restrict out) {
+    AzCssFontFaceSourceVecDestructorVariant_External* restrict casted = (AzCssFontFaceSourceVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzCssFontFaceSourceVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssUnicodeRangeVecDestructor_matchRefExternal(const AzCssUnicodeRangeVecDestructor* value, const AzCssUnicodeRangeVecDestructorType** restrict out) {
+    const AzCssUnicodeRangeVecDestructorVariant_External* casted = (const AzCssUnicodeRangeVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzCssUnicodeRangeVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssUnicodeRangeVecDestructor_matchMutExternal(AzCssUnicodeRangeVecDestructor* restrict value, AzCssUnicodeRangeVecDestructorType* restrict * restrict out) {
+    AzCssUnicodeRangeVecDestructorVariant_External* restrict casted = (AzCssUnicodeRangeVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzCssUnicodeRangeVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCssRuleBlockVecDestructor_matchRefExternal(const AzCssRuleBlockVecDestructor* value, const AzCssRuleBlockVecDestructorType** restrict out) {
     const AzCssRuleBlockVecDestructorVariant_External* casted = (const AzCssRuleBlockVecDestructorVariant_External*)value;
     bool valid = casted->tag == AzCssRuleBlockVecDestructorTag_External;
@@ -20020,6 +22508,62 @@ bool AzOptionSvgPoint_matchMutSome(AzOptionSvgPoint* restrict value, AzSvgPoint*
     return valid;
 }
 
+bool AzOptionTreeViewOnExpand_matchRefSome(const AzOptionTreeViewOnExpand* value, const AzTreeViewOnExpand** restrict out) {
+    const AzOptionTreeViewOnExpandVariant_Some* casted = (const AzOptionTreeViewOnExpandVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTreeViewOnExpandTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTreeViewOnExpand_matchMutSome(AzOptionTreeViewOnExpand* restrict value, AzTreeViewOnExpand* restrict * restrict out) {
+    AzOptionTreeViewOnExpandVariant_Some* restrict casted = (AzOptionTreeViewOnExpandVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTreeViewOnExpandTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTreeViewOnSelect_matchRefSome(const AzOptionTreeViewOnSelect* value, const AzTreeViewOnSelect** restrict out) {
+    const AzOptionTreeViewOnSelectVariant_Some* casted = (const AzOptionTreeViewOnSelectVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTreeViewOnSelectTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTreeViewOnSelect_matchMutSome(AzOptionTreeViewOnSelect* restrict value, AzTreeViewOnSelect* restrict * restrict out) {
+    AzOptionTreeViewOnSelectVariant_Some* restrict casted = (AzOptionTreeViewOnSelectVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTreeViewOnSelectTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTreeViewOnLazyLoad_matchRefSome(const AzOptionTreeViewOnLazyLoad* value, const AzTreeViewOnLazyLoad** restrict out) {
+    const AzOptionTreeViewOnLazyLoadVariant_Some* casted = (const AzOptionTreeViewOnLazyLoadVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTreeViewOnLazyLoadTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTreeViewOnLazyLoad_matchMutSome(AzOptionTreeViewOnLazyLoad* restrict value, AzTreeViewOnLazyLoad* restrict * restrict out) {
+    AzOptionTreeViewOnLazyLoadVariant_Some* restrict casted = (AzOptionTreeViewOnLazyLoadVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTreeViewOnLazyLoadTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTreeViewOnScroll_matchRefSome(const AzOptionTreeViewOnScroll* value, const AzTreeViewOnScroll** restrict out) {
+    const AzOptionTreeViewOnScrollVariant_Some* casted = (const AzOptionTreeViewOnScrollVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTreeViewOnScrollTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTreeViewOnScroll_matchMutSome(AzOptionTreeViewOnScroll* restrict value, AzTreeViewOnScroll* restrict * restrict out) {
+    AzOptionTreeViewOnScrollVariant_Some* restrict casted = (AzOptionTreeViewOnScrollVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTreeViewOnScrollTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionListViewOnRowClick_matchRefSome(const AzOptionListViewOnRowClick* value, const AzListViewOnRowClick** restrict out) {
     const AzOptionListViewOnRowClickVariant_Some* casted = (const AzOptionListViewOnRowClickVariant_Some*)value;
     bool valid = casted->tag == AzOptionListViewOnRowClickTag_Some;
@@ -20034,6 +22578,286 @@ bool AzOptionListViewOnRowClick_matchMutSome(AzOptionListViewOnRowClick* restric
     return valid;
 }
 
+bool AzOptionListViewOnRowSelect_matchRefSome(const AzOptionListViewOnRowSelect* value, const AzListViewOnRowSelect** restrict out) {
+    const AzOptionListViewOnRowSelectVariant_Some* casted = (const AzOptionListViewOnRowSelectVariant_Some*)value;
+    bool valid = casted->tag == AzOptionListViewOnRowSelectTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionListViewOnRowSelect_matchMutSome(AzOptionListViewOnRowSelect* restrict value, AzListViewOnRowSelect* restrict * restrict out) {
+    AzOptionListViewOnRowSelectVariant_Some* restrict casted = (AzOptionListViewOnRowSelectVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionListViewOnRowSelectTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionListViewOnColumnResize_matchRefSome(const AzOptionListViewOnColumnResize* value, const AzListViewOnColumnResize** restrict out) {
+    const AzOptionListViewOnColumnResizeVariant_Some* casted = (const AzOptionListViewOnColumnResizeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionListViewOnColumnResizeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionListViewOnColumnResize_matchMutSome(AzOptionListViewOnColumnResize* restrict value, AzListViewOnColumnResize* restrict * restrict out) {
+    AzOptionListViewOnColumnResizeVariant_Some* restrict casted = (AzOptionListViewOnColumnResizeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionListViewOnColumnResizeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionListViewRowProvider_matchRefSome(const AzOptionListViewRowProvider* value, const AzListViewRowProvider** restrict out) {
+    const AzOptionListViewRowProviderVariant_Some* casted = (const AzOptionListViewRowProviderVariant_Some*)value;
+    bool valid = casted->tag == AzOptionListViewRowProviderTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionListViewRowProvider_matchMutSome(AzOptionListViewRowProvider* restrict value, AzListViewRowProvider* restrict * restrict out) {
+    AzOptionListViewRowProviderVariant_Some* restrict casted = (AzOptionListViewRowProviderVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionListViewRowProviderTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDataGridRange_matchRefSome(const AzOptionDataGridRange* value, const AzDataGridRange** restrict out) {
+    const AzOptionDataGridRangeVariant_Some* casted = (const AzOptionDataGridRangeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionDataGridRangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDataGridRange_matchMutSome(AzOptionDataGridRange* restrict value, AzDataGridRange* restrict * restrict out) {
+    AzOptionDataGridRangeVariant_Some* restrict casted = (AzOptionDataGridRangeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionDataGridRangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDataGridCellProvider_matchRefSome(const AzOptionDataGridCellProvider* value, const AzDataGridCellProvider** restrict out) {
+    const AzOptionDataGridCellProviderVariant_Some* casted = (const AzOptionDataGridCellProviderVariant_Some*)value;
+    bool valid = casted->tag == AzOptionDataGridCellProviderTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDataGridCellProvider_matchMutSome(AzOptionDataGridCellProvider* restrict value, AzDataGridCellProvider* restrict * restrict out) {
+    AzOptionDataGridCellProviderVariant_Some* restrict casted = (AzOptionDataGridCellProviderVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionDataGridCellProviderTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDataGridCellStyle_matchRefSome(const AzOptionDataGridCellStyle* value, const AzDataGridCellStyle** restrict out) {
+    const AzOptionDataGridCellStyleVariant_Some* casted = (const AzOptionDataGridCellStyleVariant_Some*)value;
+    bool valid = casted->tag == AzOptionDataGridCellStyleTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDataGridCellStyle_matchMutSome(AzOptionDataGridCellStyle* restrict value, AzDataGridCellStyle* restrict * restrict out) {
+    AzOptionDataGridCellStyleVariant_Some* restrict casted = (AzOptionDataGridCellStyleVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionDataGridCellStyleTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDataGridOnSelect_matchRefSome(const AzOptionDataGridOnSelect* value, const AzDataGridOnSelect** restrict out) {
+    const AzOptionDataGridOnSelectVariant_Some* casted = (const AzOptionDataGridOnSelectVariant_Some*)value;
+    bool valid = casted->tag == AzOptionDataGridOnSelectTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDataGridOnSelect_matchMutSome(AzOptionDataGridOnSelect* restrict value, AzDataGridOnSelect* restrict * restrict out) {
+    AzOptionDataGridOnSelectVariant_Some* restrict casted = (AzOptionDataGridOnSelectVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionDataGridOnSelectTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDataGridOnScroll_matchRefSome(const AzOptionDataGridOnScroll* value, const AzDataGridOnScroll** restrict out) {
+    const AzOptionDataGridOnScrollVariant_Some* casted = (const AzOptionDataGridOnScrollVariant_Some*)value;
+    bool valid = casted->tag == AzOptionDataGridOnScrollTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDataGridOnScroll_matchMutSome(AzOptionDataGridOnScroll* restrict value, AzDataGridOnScroll* restrict * restrict out) {
+    AzOptionDataGridOnScrollVariant_Some* restrict casted = (AzOptionDataGridOnScrollVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionDataGridOnScrollTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDataGridOnCellEdit_matchRefSome(const AzOptionDataGridOnCellEdit* value, const AzDataGridOnCellEdit** restrict out) {
+    const AzOptionDataGridOnCellEditVariant_Some* casted = (const AzOptionDataGridOnCellEditVariant_Some*)value;
+    bool valid = casted->tag == AzOptionDataGridOnCellEditTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDataGridOnCellEdit_matchMutSome(AzOptionDataGridOnCellEdit* restrict value, AzDataGridOnCellEdit* restrict * restrict out) {
+    AzOptionDataGridOnCellEditVariant_Some* restrict casted = (AzOptionDataGridOnCellEditVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionDataGridOnCellEditTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDataGridOnColumnResize_matchRefSome(const AzOptionDataGridOnColumnResize* value, const AzDataGridOnColumnResize** restrict out) {
+    const AzOptionDataGridOnColumnResizeVariant_Some* casted = (const AzOptionDataGridOnColumnResizeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionDataGridOnColumnResizeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDataGridOnColumnResize_matchMutSome(AzOptionDataGridOnColumnResize* restrict value, AzDataGridOnColumnResize* restrict * restrict out) {
+    AzOptionDataGridOnColumnResizeVariant_Some* restrict casted = (AzOptionDataGridOnColumnResizeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionDataGridOnColumnResizeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDataGridOnRowResize_matchRefSome(const AzOptionDataGridOnRowResize* value, const AzDataGridOnRowResize** restrict out) {
+    const AzOptionDataGridOnRowResizeVariant_Some* casted = (const AzOptionDataGridOnRowResizeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionDataGridOnRowResizeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDataGridOnRowResize_matchMutSome(AzOptionDataGridOnRowResize* restrict value, AzDataGridOnRowResize* restrict * restrict out) {
+    AzOptionDataGridOnRowResizeVariant_Some* restrict casted = (AzOptionDataGridOnRowResizeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionDataGridOnRowResizeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDataGridOnCopyRange_matchRefSome(const AzOptionDataGridOnCopyRange* value, const AzDataGridOnCopyRange** restrict out) {
+    const AzOptionDataGridOnCopyRangeVariant_Some* casted = (const AzOptionDataGridOnCopyRangeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionDataGridOnCopyRangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDataGridOnCopyRange_matchMutSome(AzOptionDataGridOnCopyRange* restrict value, AzDataGridOnCopyRange* restrict * restrict out) {
+    AzOptionDataGridOnCopyRangeVariant_Some* restrict casted = (AzOptionDataGridOnCopyRangeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionDataGridOnCopyRangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDataGridOnClearRange_matchRefSome(const AzOptionDataGridOnClearRange* value, const AzDataGridOnClearRange** restrict out) {
+    const AzOptionDataGridOnClearRangeVariant_Some* casted = (const AzOptionDataGridOnClearRangeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionDataGridOnClearRangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDataGridOnClearRange_matchMutSome(AzOptionDataGridOnClearRange* restrict value, AzDataGridOnClearRange* restrict * restrict out) {
+    AzOptionDataGridOnClearRangeVariant_Some* restrict casted = (AzOptionDataGridOnClearRangeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionDataGridOnClearRangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDate_matchRefSome(const AzOptionDate* value, const AzDate** restrict out) {
+    const AzOptionDateVariant_Some* casted = (const AzOptionDateVariant_Some*)value;
+    bool valid = casted->tag == AzOptionDateTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDate_matchMutSome(AzOptionDate* restrict value, AzDate* restrict * restrict out) {
+    AzOptionDateVariant_Some* restrict casted = (AzOptionDateVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionDateTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTime_matchRefSome(const AzOptionTime* value, const AzTime** restrict out) {
+    const AzOptionTimeVariant_Some* casted = (const AzOptionTimeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTimeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTime_matchMutSome(AzOptionTime* restrict value, AzTime* restrict * restrict out) {
+    AzOptionTimeVariant_Some* restrict casted = (AzOptionTimeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTimeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDatePickerOnDateChange_matchRefSome(const AzOptionDatePickerOnDateChange* value, const AzDatePickerOnDateChange** restrict out) {
+    const AzOptionDatePickerOnDateChangeVariant_Some* casted = (const AzOptionDatePickerOnDateChangeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionDatePickerOnDateChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDatePickerOnDateChange_matchMutSome(AzOptionDatePickerOnDateChange* restrict value, AzDatePickerOnDateChange* restrict * restrict out) {
+    AzOptionDatePickerOnDateChangeVariant_Some* restrict casted = (AzOptionDatePickerOnDateChangeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionDatePickerOnDateChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTimePickerOnTimeChange_matchRefSome(const AzOptionTimePickerOnTimeChange* value, const AzTimePickerOnTimeChange** restrict out) {
+    const AzOptionTimePickerOnTimeChangeVariant_Some* casted = (const AzOptionTimePickerOnTimeChangeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTimePickerOnTimeChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTimePickerOnTimeChange_matchMutSome(AzOptionTimePickerOnTimeChange* restrict value, AzTimePickerOnTimeChange* restrict * restrict out) {
+    AzOptionTimePickerOnTimeChangeVariant_Some* restrict casted = (AzOptionTimePickerOnTimeChangeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTimePickerOnTimeChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDateTimePickerOnDateTimeChange_matchRefSome(const AzOptionDateTimePickerOnDateTimeChange* value, const AzDateTimePickerOnDateTimeChange** restrict out) {
+    const AzOptionDateTimePickerOnDateTimeChangeVariant_Some* casted = (const AzOptionDateTimePickerOnDateTimeChangeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionDateTimePickerOnDateTimeChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDateTimePickerOnDateTimeChange_matchMutSome(AzOptionDateTimePickerOnDateTimeChange* restrict value, AzDateTimePickerOnDateTimeChange* restrict * restrict out) {
+    AzOptionDateTimePickerOnDateTimeChangeVariant_Some* restrict casted = (AzOptionDateTimePickerOnDateTimeChangeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionDateTimePickerOnDateTimeChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDialogOnClose_matchRefSome(const AzOptionDialogOnClose* value, const AzDialogOnClose** restrict out) {
+    const AzOptionDialogOnCloseVariant_Some* casted = (const AzOptionDialogOnCloseVariant_Some*)value;
+    bool valid = casted->tag == AzOptionDialogOnCloseTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDialogOnClose_matchMutSome(AzOptionDialogOnClose* restrict value, AzDialogOnClose* restrict * restrict out) {
+    AzOptionDialogOnCloseVariant_Some* restrict casted = (AzOptionDialogOnCloseVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionDialogOnCloseTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionSplitterOnResize_matchRefSome(const AzOptionSplitterOnResize* value, const AzSplitterOnResize** restrict out) {
+    const AzOptionSplitterOnResizeVariant_Some* casted = (const AzOptionSplitterOnResizeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionSplitterOnResizeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionSplitterOnResize_matchMutSome(AzOptionSplitterOnResize* restrict value, AzSplitterOnResize* restrict * restrict out) {
+    AzOptionSplitterOnResizeVariant_Some* restrict casted = (AzOptionSplitterOnResizeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionSplitterOnResizeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionListViewOnColumnClick_matchRefSome(const AzOptionListViewOnColumnClick* value, const AzListViewOnColumnClick** restrict out) {
     const AzOptionListViewOnColumnClickVariant_Some* casted = (const AzOptionListViewOnColumnClickVariant_Some*)value;
     bool valid = casted->tag == AzOptionListViewOnColumnClickTag_Some;
@@ -20118,6 +22942,48 @@ bool AzOptionResolvedTextLayoutOptions_matchMutSome(AzOptionResolvedTextLayoutOp
     return valid;
 }
 
+bool AzOptionNodeGraphOnNodeGraphZoomed_matchRefSome(const AzOptionNodeGraphOnNodeGraphZoomed* value, const AzNodeGraphOnNodeGraphZoomed** restrict out) {
+    const AzOptionNodeGraphOnNodeGraphZoomedVariant_Some* casted = (const AzOptionNodeGraphOnNodeGraphZoomedVariant_Some*)value;
+    bool valid = casted->tag == AzOptionNodeGraphOnNodeGraphZoomedTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionNodeGraphOnNodeGraphZoomed_matchMutSome(AzOptionNodeGraphOnNodeGraphZoomed* restrict value, AzNodeGraphOnNodeGraphZoomed* restrict * restrict out) {
+    AzOptionNodeGraphOnNodeGraphZoomedVariant_Some* restrict casted = (AzOptionNodeGraphOnNodeGraphZoomedVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionNodeGraphOnNodeGraphZoomedTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionNodeGraphOnSelectionChanged_matchRefSome(const AzOptionNodeGraphOnSelectionChanged* value, const AzNodeGraphOnSelectionChanged** restrict out) {
+    const AzOptionNodeGraphOnSelectionChangedVariant_Some* casted = (const AzOptionNodeGraphOnSelectionChangedVariant_Some*)value;
+    bool valid = casted->tag == AzOptionNodeGraphOnSelectionChangedTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionNodeGraphOnSelectionChanged_matchMutSome(AzOptionNodeGraphOnSelectionChanged* restrict value, AzNodeGraphOnSelectionChanged* restrict * restrict out) {
+    AzOptionNodeGraphOnSelectionChangedVariant_Some* restrict casted = (AzOptionNodeGraphOnSelectionChangedVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionNodeGraphOnSelectionChangedTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionNodeGraphOnHistoryChanged_matchRefSome(const AzOptionNodeGraphOnHistoryChanged* value, const AzNodeGraphOnHistoryChanged** restrict out) {
+    const AzOptionNodeGraphOnHistoryChangedVariant_Some* casted = (const AzOptionNodeGraphOnHistoryChangedVariant_Some*)value;
+    bool valid = casted->tag == AzOptionNodeGraphOnHistoryChangedTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionNodeGraphOnHistoryChanged_matchMutSome(AzOptionNodeGraphOnHistoryChanged* restrict value, AzNodeGraphOnHistoryChanged* restrict * restrict out) {
+    AzOptionNodeGraphOnHistoryChangedVariant_Some* restrict casted = (AzOptionNodeGraphOnHistoryChangedVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionNodeGraphOnHistoryChangedTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionNodeGraphOnNodeAdded_matchRefSome(const AzOptionNodeGraphOnNodeAdded* value, const AzNodeGraphOnNodeAdded** restrict out) {
     const AzOptionNodeGraphOnNodeAddedVariant_Some* casted = (const AzOptionNodeGraphOnNodeAddedVariant_Some*)value;
     bool valid = casted->tag == AzOptionNodeGraphOnNodeAddedTag_Some;
@@ -20790,6 +23656,20 @@ bool AzOptionInlineText_matchMutSome(AzOptionInlineText* restrict value, AzInlin
     return valid;
 }
 
+bool AzOptionTextMetrics_matchRefSome(const AzOptionTextMetrics* value, const AzTextMetrics** restrict out) {
+    const AzOptionTextMetricsVariant_Some* casted = (const AzOptionTextMetricsVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTextMetricsTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTextMetrics_matchMutSome(AzOptionTextMetrics* restrict value, AzTextMetrics* restrict * restrict out) {
+    AzOptionTextMetricsVariant_Some* restrict casted = (AzOptionTextMetricsVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTextMetricsTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionLayoutPoint_matchRefSome(const AzOptionLayoutPoint* value, const AzLayoutPoint** restrict out) {
     const AzOptionLayoutPointVariant_Some* casted = (const AzOptionLayoutPointVariant_Some*)value;
     bool valid = casted->tag == AzOptionLayoutPointTag_Some;
@@ -21350,6 +24230,34 @@ bool AzResultSvgXmlNodeSvgParseError_matchMutErr(AzResultSvgXmlNodeSvgParseError
     return valid;
 }
 
+bool AzResultNodeGraphNodeGraphError_matchRefOk(const AzResultNodeGraphNodeGraphError* value, const AzNodeGraph** restrict out) {
+    const AzResultNodeGraphNodeGraphErrorVariant_Ok* casted = (const AzResultNodeGraphNodeGraphErrorVariant_Ok*)value;
+    bool valid = casted->tag == AzResultNodeGraphNodeGraphErrorTag_Ok;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzResultNodeGraphNodeGraphError_matchMutOk(AzResultNodeGraphNodeGraphError* restrict value, AzNodeGraph* restrict * restrict out) {
+    AzResultNodeGraphNodeGraphErrorVariant_Ok* restrict casted = (AzResultNodeGraphNodeGraphErrorVariant_Ok* restrict)value;
+    bool valid = casted->tag == AzResultNodeGraphNodeGraphErrorTag_Ok;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzResultNodeGraphNodeGraphError_matchRefErr(const AzResultNodeGraphNodeGraphError* value, const AzNodeGraphError** restrict out) {
+    const AzResultNodeGraphNodeGraphErrorVariant_Err* casted = (const AzResultNodeGraphNodeGraphErrorVariant_Err*)value;
+    bool valid = casted->tag == AzResultNodeGraphNodeGraphErrorTag_Err;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzResultNodeGraphNodeGraphError_matchMutErr(AzResultNodeGraphNodeGraphError* restrict value, AzNodeGraphError* restrict * restrict out) {
+    AzResultNodeGraphNodeGraphErrorVariant_Err* restrict casted = (AzResultNodeGraphNodeGraphErrorVariant_Err* restrict)value;
+    bool valid = casted->tag == AzResultNodeGraphNodeGraphErrorTag_Err;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzResultSvgSvgParseError_matchRefOk(const AzResultSvgSvgParseError* value, const AzSvg** restrict out) {
     const AzResultSvgSvgParseErrorVariant_Ok* casted = (const AzResultSvgSvgParseErrorVariant_Ok*)value;
     bool valid = casted->tag == AzResultSvgSvgParseErrorTag_Ok;
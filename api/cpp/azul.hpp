@@ -86,6 +86,15 @@ namespace dll {
     union NodeTypeFieldValue;
     using NodeGraphOnNodeFieldEditedCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, NodeGraphNodeId, size_t, NodeTypeId, NodeTypeFieldValue);
     
+    struct LogicalPosition;
+    using NodeGraphOnNodeGraphZoomedCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, float, LogicalPosition);
+    
+    struct NodeGraphNodeIdVec;
+    using NodeGraphOnSelectionChangedCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, NodeGraphNodeIdVec);
+    
+    struct NodeGraphHistory;
+    using NodeGraphOnHistoryChangedCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, NodeGraphHistory);
+    
     struct ListViewState;
     using ListViewOnLazyLoadScrollCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, ListViewState* const);
     
@@ -93,6 +102,59 @@ namespace dll {
     
     using ListViewOnRowClickCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, ListViewState* const, size_t);
     
+    enum ListViewSelectionChange;
+    using ListViewOnRowSelectCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, ListViewState* const, size_t, ListViewSelectionChange);
+    
+    using ListViewOnColumnResizeCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, ListViewState* const, size_t, float);
+    
+    struct ListViewRowVec;
+    using ListViewRowProviderCallbackType = ListViewRowVec(*)(RefAny* restrict, size_t, size_t);
+    
+    using TreeViewOnExpandCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, size_t, bool );
+    
+    enum TreeViewSelectionChange;
+    using TreeViewOnSelectCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, size_t, TreeViewSelectionChange);
+    
+    using TreeViewOnLazyLoadCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, size_t);
+    
+    using TreeViewOnScrollCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, size_t);
+    
+    struct String;
+    using DataGridCellProviderCallbackType = String(*)(RefAny* restrict, size_t, size_t);
+    
+    struct NodeDataInlineCssPropertyVec;
+    using DataGridCellStyleCallbackType = NodeDataInlineCssPropertyVec(*)(RefAny* restrict, size_t, size_t, bool );
+    
+    struct DataGridRange;
+    using DataGridOnSelectCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, DataGridRange);
+    
+    using DataGridOnScrollCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, size_t, size_t);
+    
+    using DataGridOnCellEditCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, size_t, size_t, String);
+    
+    using DataGridOnColumnResizeCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, size_t, float);
+    
+    using DataGridOnRowResizeCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, size_t, float);
+    
+    using DataGridOnCopyRangeCallbackType = String(*)(RefAny* restrict, DataGridRange);
+    
+    using DataGridOnClearRangeCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, DataGridRange);
+    
+    struct DatePickerState;
+    using DatePickerOnDateChangeCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, DatePickerState* const);
+    
+    struct Time;
+    using TimePickerOnTimeChangeCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, Time* const);
+    
+    struct DateTimePickerState;
+    using DateTimePickerOnDateTimeChangeCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, DateTimePickerState* const);
+    
+    struct DialogResult;
+    using DialogOnCloseCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, DialogResult* const);
+    
+    struct SplitterState;
+    using SplitterOnResizeCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, SplitterState* const);
+    
     using DropDownOnChoiceChangeCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, size_t);
     
     using ParsedFontDestructorFnType = void(*)(void* restrict);
@@ -132,9 +194,17 @@ namespace dll {
     struct StyleFontFamilyVec;
     using StyleFontFamilyVecDestructorType = void(*)(StyleFontFamilyVec* restrict);
     
-    struct ListViewRowVec;
     using ListViewRowVecDestructorType = void(*)(ListViewRowVec* restrict);
     
+    struct TreeViewItemVec;
+    using TreeViewItemVecDestructorType = void(*)(TreeViewItemVec* restrict);
+    
+    struct SplitterPaneVec;
+    using SplitterPaneVecDestructorType = void(*)(SplitterPaneVec* restrict);
+    
+    struct SplitterPaneStateVec;
+    using SplitterPaneStateVecDestructorType = void(*)(SplitterPaneStateVec* restrict);
+    
     struct StyleFilterVec;
     using StyleFilterVecDestructorType = void(*)(StyleFilterVec* restrict);
     
@@ -147,6 +217,14 @@ namespace dll {
     struct InputOutputTypeIdInfoMapVec;
     using InputOutputTypeIdInfoMapVecDestructorType = void(*)(InputOutputTypeIdInfoMapVec* restrict);
     
+    using NodeGraphNodeIdVecDestructorType = void(*)(NodeGraphNodeIdVec* restrict);
+    
+    struct NodeGraphEditOpVec;
+    using NodeGraphEditOpVecDestructorType = void(*)(NodeGraphEditOpVec* restrict);
+    
+    struct NodeGraphEditVec;
+    using NodeGraphEditVecDestructorType = void(*)(NodeGraphEditVec* restrict);
+    
     struct NodeIdNodeMapVec;
     using NodeIdNodeMapVecDestructorType = void(*)(NodeIdNodeMapVec* restrict);
     
@@ -171,6 +249,9 @@ namespace dll {
     struct AccessibilityStateVec;
     using AccessibilityStateVecDestructorType = void(*)(AccessibilityStateVec* restrict);
     
+    struct KeyboardShortcutVec;
+    using KeyboardShortcutVecDestructorType = void(*)(KeyboardShortcutVec* restrict);
+    
     struct MenuItemVec;
     using MenuItemVecDestructorType = void(*)(MenuItemVec* restrict);
     
@@ -195,6 +276,12 @@ namespace dll {
     struct InlineGlyphVec;
     using InlineGlyphVecDestructorType = void(*)(InlineGlyphVec* restrict);
     
+    struct TextLineMetricsVec;
+    using TextLineMetricsVecDestructorType = void(*)(TextLineMetricsVec* restrict);
+    
+    struct InlineTextRunVec;
+    using InlineTextRunVecDestructorType = void(*)(InlineTextRunVec* restrict);
+    
     struct InlineTextHitVec;
     using InlineTextHitVecDestructorType = void(*)(InlineTextHitVec* restrict);
     
@@ -210,7 +297,6 @@ namespace dll {
     struct IdOrClassVec;
     using IdOrClassVecDestructorType = void(*)(IdOrClassVec* restrict);
     
-    struct NodeDataInlineCssPropertyVec;
     using NodeDataInlineCssPropertyVecDestructorType = void(*)(NodeDataInlineCssPropertyVec* restrict);
     
     struct StyleBackgroundContentVec;
@@ -276,6 +362,15 @@ namespace dll {
     struct StylesheetVec;
     using StylesheetVecDestructorType = void(*)(StylesheetVec* restrict);
     
+    struct CssFontFaceVec;
+    using CssFontFaceVecDestructorType = void(*)(CssFontFaceVec* restrict);
+    
+    struct CssFontFaceSourceVec;
+    using CssFontFaceSourceVecDestructorType = void(*)(CssFontFaceSourceVec* restrict);
+    
+    struct CssUnicodeRangeVec;
+    using CssUnicodeRangeVecDestructorType = void(*)(CssUnicodeRangeVec* restrict);
+    
     struct CssRuleBlockVec;
     using CssRuleBlockVecDestructorType = void(*)(CssRuleBlockVec* restrict);
     
@@ -1139,6 +1234,19 @@ namespace dll {
         CssNthChildPattern() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class CssFontFaceStyle {
+       Normal,
+       Italic,
+       Oblique,
+    };
+    
+    struct CssUnicodeRange {
+        uint32_t start;
+        uint32_t end;
+        CssUnicodeRange& operator=(const CssUnicodeRange&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssUnicodeRange() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class CssPropertyType {
        TextColor,
        FontSize,
@@ -1214,6 +1322,8 @@ namespace dll {
        Filter,
        BackdropFilter,
        TextShadow,
+       VerticalAlign,
+       TextDecoration,
     };
     
     struct ColorU {
@@ -1295,6 +1405,7 @@ namespace dll {
        Flex,
        Block,
        InlineBlock,
+       Inline,
     };
     
     enum class LayoutFloat {
@@ -1318,6 +1429,15 @@ namespace dll {
        Fixed,
     };
     
+    enum class LayoutVerticalAlign {
+       Baseline,
+       Top,
+       Middle,
+       Bottom,
+       Sub,
+       Super,
+    };
+    
     enum class LayoutFlexWrap {
        Wrap,
        NoWrap,
@@ -1430,6 +1550,13 @@ namespace dll {
        Right,
     };
     
+    enum class StyleTextDecoration {
+       None,
+       Underline,
+       Overline,
+       LineThrough,
+    };
+    
     struct Ribbon {
         int32_t tab_active;
         Ribbon& operator=(const Ribbon&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -1611,6 +1738,27 @@ namespace dll {
         NodeGraphOnNodeFieldEditedCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct NodeGraphOnNodeGraphZoomedCallback {
+        NodeGraphOnNodeGraphZoomedCallbackType cb;
+        NodeGraphOnNodeGraphZoomedCallback& operator=(const NodeGraphOnNodeGraphZoomedCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        NodeGraphOnNodeGraphZoomedCallback(const NodeGraphOnNodeGraphZoomedCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        NodeGraphOnNodeGraphZoomedCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct NodeGraphOnSelectionChangedCallback {
+        NodeGraphOnSelectionChangedCallbackType cb;
+        NodeGraphOnSelectionChangedCallback& operator=(const NodeGraphOnSelectionChangedCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        NodeGraphOnSelectionChangedCallback(const NodeGraphOnSelectionChangedCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        NodeGraphOnSelectionChangedCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct NodeGraphOnHistoryChangedCallback {
+        NodeGraphOnHistoryChangedCallbackType cb;
+        NodeGraphOnHistoryChangedCallback& operator=(const NodeGraphOnHistoryChangedCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        NodeGraphOnHistoryChangedCallback(const NodeGraphOnHistoryChangedCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        NodeGraphOnHistoryChangedCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct InputOutputTypeId {
         uint64_t inner;
         InputOutputTypeId& operator=(const InputOutputTypeId&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -1650,6 +1798,20 @@ namespace dll {
         NodeDragAmount() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class NodeGraphError {
+       NodeMimeTypeMismatch,
+       NodeInvalidIndex,
+       NodeInvalidNode,
+       NoRootNode,
+       InvalidJson,
+    };
+    
+    enum class ListViewSelectionChange {
+       Replace,
+       Toggle,
+       Extend,
+    };
+    
     struct ListViewOnLazyLoadScrollCallback {
         ListViewOnLazyLoadScrollCallbackType cb;
         ListViewOnLazyLoadScrollCallback& operator=(const ListViewOnLazyLoadScrollCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -1671,6 +1833,224 @@ namespace dll {
         ListViewOnRowClickCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct ListViewOnRowSelectCallback {
+        ListViewOnRowSelectCallbackType cb;
+        ListViewOnRowSelectCallback& operator=(const ListViewOnRowSelectCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ListViewOnRowSelectCallback(const ListViewOnRowSelectCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        ListViewOnRowSelectCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct ListViewOnColumnResizeCallback {
+        ListViewOnColumnResizeCallbackType cb;
+        ListViewOnColumnResizeCallback& operator=(const ListViewOnColumnResizeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ListViewOnColumnResizeCallback(const ListViewOnColumnResizeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        ListViewOnColumnResizeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct ListViewRowProviderCallback {
+        ListViewRowProviderCallbackType cb;
+        ListViewRowProviderCallback& operator=(const ListViewRowProviderCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ListViewRowProviderCallback(const ListViewRowProviderCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        ListViewRowProviderCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class TreeViewSelectionMode {
+       Single,
+       Multiple,
+    };
+    
+    enum class TreeViewSelectionChange {
+       Replace,
+       Toggle,
+       Extend,
+    };
+    
+    struct TreeViewOnExpandCallback {
+        TreeViewOnExpandCallbackType cb;
+        TreeViewOnExpandCallback& operator=(const TreeViewOnExpandCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewOnExpandCallback(const TreeViewOnExpandCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewOnExpandCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewOnSelectCallback {
+        TreeViewOnSelectCallbackType cb;
+        TreeViewOnSelectCallback& operator=(const TreeViewOnSelectCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewOnSelectCallback(const TreeViewOnSelectCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewOnSelectCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewOnLazyLoadCallback {
+        TreeViewOnLazyLoadCallbackType cb;
+        TreeViewOnLazyLoadCallback& operator=(const TreeViewOnLazyLoadCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewOnLazyLoadCallback(const TreeViewOnLazyLoadCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewOnLazyLoadCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewOnScrollCallback {
+        TreeViewOnScrollCallbackType cb;
+        TreeViewOnScrollCallback& operator=(const TreeViewOnScrollCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewOnScrollCallback(const TreeViewOnScrollCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewOnScrollCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DataGridCell {
+        size_t row;
+        size_t column;
+        DataGridCell& operator=(const DataGridCell&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DataGridCell() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DataGridCellProviderCallback {
+        DataGridCellProviderCallbackType cb;
+        DataGridCellProviderCallback& operator=(const DataGridCellProviderCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DataGridCellProviderCallback(const DataGridCellProviderCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        DataGridCellProviderCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DataGridCellStyleCallback {
+        DataGridCellStyleCallbackType cb;
+        DataGridCellStyleCallback& operator=(const DataGridCellStyleCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DataGridCellStyleCallback(const DataGridCellStyleCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        DataGridCellStyleCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DataGridOnSelectCallback {
+        DataGridOnSelectCallbackType cb;
+        DataGridOnSelectCallback& operator=(const DataGridOnSelectCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DataGridOnSelectCallback(const DataGridOnSelectCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        DataGridOnSelectCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DataGridOnScrollCallback {
+        DataGridOnScrollCallbackType cb;
+        DataGridOnScrollCallback& operator=(const DataGridOnScrollCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DataGridOnScrollCallback(const DataGridOnScrollCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        DataGridOnScrollCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DataGridOnCellEditCallback {
+        DataGridOnCellEditCallbackType cb;
+        DataGridOnCellEditCallback& operator=(const DataGridOnCellEditCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DataGridOnCellEditCallback(const DataGridOnCellEditCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        DataGridOnCellEditCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DataGridOnColumnResizeCallback {
+        DataGridOnColumnResizeCallbackType cb;
+        DataGridOnColumnResizeCallback& operator=(const DataGridOnColumnResizeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DataGridOnColumnResizeCallback(const DataGridOnColumnResizeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        DataGridOnColumnResizeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DataGridOnRowResizeCallback {
+        DataGridOnRowResizeCallbackType cb;
+        DataGridOnRowResizeCallback& operator=(const DataGridOnRowResizeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DataGridOnRowResizeCallback(const DataGridOnRowResizeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        DataGridOnRowResizeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DataGridOnCopyRangeCallback {
+        DataGridOnCopyRangeCallbackType cb;
+        DataGridOnCopyRangeCallback& operator=(const DataGridOnCopyRangeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DataGridOnCopyRangeCallback(const DataGridOnCopyRangeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        DataGridOnCopyRangeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DataGridOnClearRangeCallback {
+        DataGridOnClearRangeCallbackType cb;
+        DataGridOnClearRangeCallback& operator=(const DataGridOnClearRangeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DataGridOnClearRangeCallback(const DataGridOnClearRangeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        DataGridOnClearRangeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct Date {
+        int32_t year;
+        uint8_t month;
+        uint8_t day;
+        Date& operator=(const Date&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        Date() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class Weekday {
+       Monday,
+       Tuesday,
+       Wednesday,
+       Thursday,
+       Friday,
+       Saturday,
+       Sunday,
+    };
+    
+    struct Time {
+        uint8_t hour;
+        uint8_t minute;
+        uint8_t second;
+        Time& operator=(const Time&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        Time() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DatePickerOnDateChangeCallback {
+        DatePickerOnDateChangeCallbackType cb;
+        DatePickerOnDateChangeCallback& operator=(const DatePickerOnDateChangeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DatePickerOnDateChangeCallback(const DatePickerOnDateChangeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        DatePickerOnDateChangeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TimePickerOnTimeChangeCallback {
+        TimePickerOnTimeChangeCallbackType cb;
+        TimePickerOnTimeChangeCallback& operator=(const TimePickerOnTimeChangeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TimePickerOnTimeChangeCallback(const TimePickerOnTimeChangeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TimePickerOnTimeChangeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DateTimePickerOnDateTimeChangeCallback {
+        DateTimePickerOnDateTimeChangeCallbackType cb;
+        DateTimePickerOnDateTimeChangeCallback& operator=(const DateTimePickerOnDateTimeChangeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DateTimePickerOnDateTimeChangeCallback(const DateTimePickerOnDateTimeChangeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        DateTimePickerOnDateTimeChangeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class DialogIcon {
+       None,
+       Info,
+       Warning,
+       Error,
+       Question,
+    };
+    
+    enum class DialogButtons {
+       Ok,
+       OkCancel,
+       YesNo,
+       YesNoCancel,
+    };
+    
+    enum class DialogButton {
+       Ok,
+       Cancel,
+       Yes,
+       No,
+    };
+    
+    struct DialogOnCloseCallback {
+        DialogOnCloseCallbackType cb;
+        DialogOnCloseCallback& operator=(const DialogOnCloseCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DialogOnCloseCallback(const DialogOnCloseCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        DialogOnCloseCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class SplitterDirection {
+       Horizontal,
+       Vertical,
+    };
+    
+    struct SplitterOnResizeCallback {
+        SplitterOnResizeCallbackType cb;
+        SplitterOnResizeCallback& operator=(const SplitterOnResizeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SplitterOnResizeCallback(const SplitterOnResizeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        SplitterOnResizeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct DropDownOnChoiceChangeCallback {
         DropDownOnChoiceChangeCallbackType cb;
         DropDownOnChoiceChangeCallback& operator=(const DropDownOnChoiceChangeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -2310,6 +2690,54 @@ namespace dll {
     };
     
     
+    enum class TreeViewItemVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct TreeViewItemVecDestructorVariant_DefaultRust { TreeViewItemVecDestructorTag tag; };
+    struct TreeViewItemVecDestructorVariant_NoDestructor { TreeViewItemVecDestructorTag tag; };
+    struct TreeViewItemVecDestructorVariant_External { TreeViewItemVecDestructorTag tag; TreeViewItemVecDestructorType payload; };
+    union TreeViewItemVecDestructor {
+        TreeViewItemVecDestructorVariant_DefaultRust DefaultRust;
+        TreeViewItemVecDestructorVariant_NoDestructor NoDestructor;
+        TreeViewItemVecDestructorVariant_External External;
+    };
+    
+    
+    enum class SplitterPaneVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct SplitterPaneVecDestructorVariant_DefaultRust { SplitterPaneVecDestructorTag tag; };
+    struct SplitterPaneVecDestructorVariant_NoDestructor { SplitterPaneVecDestructorTag tag; };
+    struct SplitterPaneVecDestructorVariant_External { SplitterPaneVecDestructorTag tag; SplitterPaneVecDestructorType payload; };
+    union SplitterPaneVecDestructor {
+        SplitterPaneVecDestructorVariant_DefaultRust DefaultRust;
+        SplitterPaneVecDestructorVariant_NoDestructor NoDestructor;
+        SplitterPaneVecDestructorVariant_External External;
+    };
+    
+    
+    enum class SplitterPaneStateVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct SplitterPaneStateVecDestructorVariant_DefaultRust { SplitterPaneStateVecDestructorTag tag; };
+    struct SplitterPaneStateVecDestructorVariant_NoDestructor { SplitterPaneStateVecDestructorTag tag; };
+    struct SplitterPaneStateVecDestructorVariant_External { SplitterPaneStateVecDestructorTag tag; SplitterPaneStateVecDestructorType payload; };
+    union SplitterPaneStateVecDestructor {
+        SplitterPaneStateVecDestructorVariant_DefaultRust DefaultRust;
+        SplitterPaneStateVecDestructorVariant_NoDestructor NoDestructor;
+        SplitterPaneStateVecDestructorVariant_External External;
+    };
+    
+    
     enum class StyleFilterVecDestructorTag {
        DefaultRust,
        NoDestructor,
@@ -2374,6 +2802,54 @@ namespace dll {
     };
     
     
+    enum class NodeGraphNodeIdVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct NodeGraphNodeIdVecDestructorVariant_DefaultRust { NodeGraphNodeIdVecDestructorTag tag; };
+    struct NodeGraphNodeIdVecDestructorVariant_NoDestructor { NodeGraphNodeIdVecDestructorTag tag; };
+    struct NodeGraphNodeIdVecDestructorVariant_External { NodeGraphNodeIdVecDestructorTag tag; NodeGraphNodeIdVecDestructorType payload; };
+    union NodeGraphNodeIdVecDestructor {
+        NodeGraphNodeIdVecDestructorVariant_DefaultRust DefaultRust;
+        NodeGraphNodeIdVecDestructorVariant_NoDestructor NoDestructor;
+        NodeGraphNodeIdVecDestructorVariant_External External;
+    };
+    
+    
+    enum class NodeGraphEditOpVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct NodeGraphEditOpVecDestructorVariant_DefaultRust { NodeGraphEditOpVecDestructorTag tag; };
+    struct NodeGraphEditOpVecDestructorVariant_NoDestructor { NodeGraphEditOpVecDestructorTag tag; };
+    struct NodeGraphEditOpVecDestructorVariant_External { NodeGraphEditOpVecDestructorTag tag; NodeGraphEditOpVecDestructorType payload; };
+    union NodeGraphEditOpVecDestructor {
+        NodeGraphEditOpVecDestructorVariant_DefaultRust DefaultRust;
+        NodeGraphEditOpVecDestructorVariant_NoDestructor NoDestructor;
+        NodeGraphEditOpVecDestructorVariant_External External;
+    };
+    
+    
+    enum class NodeGraphEditVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct NodeGraphEditVecDestructorVariant_DefaultRust { NodeGraphEditVecDestructorTag tag; };
+    struct NodeGraphEditVecDestructorVariant_NoDestructor { NodeGraphEditVecDestructorTag tag; };
+    struct NodeGraphEditVecDestructorVariant_External { NodeGraphEditVecDestructorTag tag; NodeGraphEditVecDestructorType payload; };
+    union NodeGraphEditVecDestructor {
+        NodeGraphEditVecDestructorVariant_DefaultRust DefaultRust;
+        NodeGraphEditVecDestructorVariant_NoDestructor NoDestructor;
+        NodeGraphEditVecDestructorVariant_External External;
+    };
+    
+    
     enum class NodeIdNodeMapVecDestructorTag {
        DefaultRust,
        NoDestructor,
@@ -2502,6 +2978,22 @@ namespace dll {
     };
     
     
+    enum class KeyboardShortcutVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct KeyboardShortcutVecDestructorVariant_DefaultRust { KeyboardShortcutVecDestructorTag tag; };
+    struct KeyboardShortcutVecDestructorVariant_NoDestructor { KeyboardShortcutVecDestructorTag tag; };
+    struct KeyboardShortcutVecDestructorVariant_External { KeyboardShortcutVecDestructorTag tag; KeyboardShortcutVecDestructorType payload; };
+    union KeyboardShortcutVecDestructor {
+        KeyboardShortcutVecDestructorVariant_DefaultRust DefaultRust;
+        KeyboardShortcutVecDestructorVariant_NoDestructor NoDestructor;
+        KeyboardShortcutVecDestructorVariant_External External;
+    };
+    
+    
     enum class MenuItemVecDestructorTag {
        DefaultRust,
        NoDestructor,
@@ -2630,6 +3122,38 @@ namespace dll {
     };
     
     
+    enum class TextLineMetricsVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct TextLineMetricsVecDestructorVariant_DefaultRust { TextLineMetricsVecDestructorTag tag; };
+    struct TextLineMetricsVecDestructorVariant_NoDestructor { TextLineMetricsVecDestructorTag tag; };
+    struct TextLineMetricsVecDestructorVariant_External { TextLineMetricsVecDestructorTag tag; TextLineMetricsVecDestructorType payload; };
+    union TextLineMetricsVecDestructor {
+        TextLineMetricsVecDestructorVariant_DefaultRust DefaultRust;
+        TextLineMetricsVecDestructorVariant_NoDestructor NoDestructor;
+        TextLineMetricsVecDestructorVariant_External External;
+    };
+    
+    
+    enum class InlineTextRunVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct InlineTextRunVecDestructorVariant_DefaultRust { InlineTextRunVecDestructorTag tag; };
+    struct InlineTextRunVecDestructorVariant_NoDestructor { InlineTextRunVecDestructorTag tag; };
+    struct InlineTextRunVecDestructorVariant_External { InlineTextRunVecDestructorTag tag; InlineTextRunVecDestructorType payload; };
+    union InlineTextRunVecDestructor {
+        InlineTextRunVecDestructorVariant_DefaultRust DefaultRust;
+        InlineTextRunVecDestructorVariant_NoDestructor NoDestructor;
+        InlineTextRunVecDestructorVariant_External External;
+    };
+    
+    
     enum class InlineTextHitVecDestructorTag {
        DefaultRust,
        NoDestructor,
@@ -3062,6 +3586,54 @@ namespace dll {
     };
     
     
+    enum class CssFontFaceVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct CssFontFaceVecDestructorVariant_DefaultRust { CssFontFaceVecDestructorTag tag; };
+    struct CssFontFaceVecDestructorVariant_NoDestructor { CssFontFaceVecDestructorTag tag; };
+    struct CssFontFaceVecDestructorVariant_External { CssFontFaceVecDestructorTag tag; CssFontFaceVecDestructorType payload; };
+    union CssFontFaceVecDestructor {
+        CssFontFaceVecDestructorVariant_DefaultRust DefaultRust;
+        CssFontFaceVecDestructorVariant_NoDestructor NoDestructor;
+        CssFontFaceVecDestructorVariant_External External;
+    };
+    
+    
+    enum class CssFontFaceSourceVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct CssFontFaceSourceVecDestructorVariant_DefaultRust { CssFontFaceSourceVecDestructorTag tag; };
+    struct CssFontFaceSourceVecDestructorVariant_NoDestructor { CssFontFaceSourceVecDestructorTag tag; };
+    struct CssFontFaceSourceVecDestructorVariant_External { CssFontFaceSourceVecDestructorTag tag; CssFontFaceSourceVecDestructorType payload; };
+    union CssFontFaceSourceVecDestructor {
+        CssFontFaceSourceVecDestructorVariant_DefaultRust DefaultRust;
+        CssFontFaceSourceVecDestructorVariant_NoDestructor NoDestructor;
+        CssFontFaceSourceVecDestructorVariant_External External;
+    };
+    
+    
+    enum class CssUnicodeRangeVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct CssUnicodeRangeVecDestructorVariant_DefaultRust { CssUnicodeRangeVecDestructorTag tag; };
+    struct CssUnicodeRangeVecDestructorVariant_NoDestructor { CssUnicodeRangeVecDestructorTag tag; };
+    struct CssUnicodeRangeVecDestructorVariant_External { CssUnicodeRangeVecDestructorTag tag; CssUnicodeRangeVecDestructorType payload; };
+    union CssUnicodeRangeVecDestructor {
+        CssUnicodeRangeVecDestructorVariant_DefaultRust DefaultRust;
+        CssUnicodeRangeVecDestructorVariant_NoDestructor NoDestructor;
+        CssUnicodeRangeVecDestructorVariant_External External;
+    };
+    
+    
     enum class CssRuleBlockVecDestructorTag {
        DefaultRust,
        NoDestructor,
@@ -3656,6 +4228,23 @@ namespace dll {
         HidpiAdjustedBounds() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextLineMetrics {
+        LogicalRect bounds;
+        float baseline_px;
+        size_t glyph_count;
+        TextLineMetrics& operator=(const TextLineMetrics&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextLineMetrics() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct InlineTextRun {
+        NodeId node_id;
+        size_t glyph_start;
+        size_t glyph_end;
+        float font_size_px;
+        InlineTextRun& operator=(const InlineTextRun&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        InlineTextRun() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct InlineGlyph {
         LogicalRect bounds;
         OptionChar unicode_codepoint;
@@ -4927,6 +5516,28 @@ namespace dll {
     };
     
     
+    enum class LayoutVerticalAlignValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct LayoutVerticalAlignValueVariant_Auto { LayoutVerticalAlignValueTag tag; };
+    struct LayoutVerticalAlignValueVariant_None { LayoutVerticalAlignValueTag tag; };
+    struct LayoutVerticalAlignValueVariant_Inherit { LayoutVerticalAlignValueTag tag; };
+    struct LayoutVerticalAlignValueVariant_Initial { LayoutVerticalAlignValueTag tag; };
+    struct LayoutVerticalAlignValueVariant_Exact { LayoutVerticalAlignValueTag tag; LayoutVerticalAlign payload; };
+    union LayoutVerticalAlignValue {
+        LayoutVerticalAlignValueVariant_Auto Auto;
+        LayoutVerticalAlignValueVariant_None None;
+        LayoutVerticalAlignValueVariant_Inherit Inherit;
+        LayoutVerticalAlignValueVariant_Initial Initial;
+        LayoutVerticalAlignValueVariant_Exact Exact;
+    };
+    
+    
     enum class LayoutWidthValueTag {
        Auto,
        None,
@@ -5477,7 +6088,7 @@ namespace dll {
     };
     
     
-    enum class StyleTextColorValueTag {
+    enum class StyleTextDecorationValueTag {
        Auto,
        None,
        Inherit,
@@ -5485,10 +6096,32 @@ namespace dll {
        Exact,
     };
     
-    struct StyleTextColorValueVariant_Auto { StyleTextColorValueTag tag; };
-    struct StyleTextColorValueVariant_None { StyleTextColorValueTag tag; };
-    struct StyleTextColorValueVariant_Inherit { StyleTextColorValueTag tag; };
-    struct StyleTextColorValueVariant_Initial { StyleTextColorValueTag tag; };
+    struct StyleTextDecorationValueVariant_Auto { StyleTextDecorationValueTag tag; };
+    struct StyleTextDecorationValueVariant_None { StyleTextDecorationValueTag tag; };
+    struct StyleTextDecorationValueVariant_Inherit { StyleTextDecorationValueTag tag; };
+    struct StyleTextDecorationValueVariant_Initial { StyleTextDecorationValueTag tag; };
+    struct StyleTextDecorationValueVariant_Exact { StyleTextDecorationValueTag tag; StyleTextDecoration payload; };
+    union StyleTextDecorationValue {
+        StyleTextDecorationValueVariant_Auto Auto;
+        StyleTextDecorationValueVariant_None None;
+        StyleTextDecorationValueVariant_Inherit Inherit;
+        StyleTextDecorationValueVariant_Initial Initial;
+        StyleTextDecorationValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleTextColorValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleTextColorValueVariant_Auto { StyleTextColorValueTag tag; };
+    struct StyleTextColorValueVariant_None { StyleTextColorValueTag tag; };
+    struct StyleTextColorValueVariant_Inherit { StyleTextColorValueTag tag; };
+    struct StyleTextColorValueVariant_Initial { StyleTextColorValueTag tag; };
     struct StyleTextColorValueVariant_Exact { StyleTextColorValueTag tag; StyleTextColor payload; };
     union StyleTextColorValue {
         StyleTextColorValueVariant_Auto Auto;
@@ -5803,6 +6436,30 @@ namespace dll {
         NodeGraphOnNodeFieldEdited() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct NodeGraphOnNodeGraphZoomed {
+        RefAny data;
+        NodeGraphOnNodeGraphZoomedCallback callback;
+        NodeGraphOnNodeGraphZoomed& operator=(const NodeGraphOnNodeGraphZoomed&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        NodeGraphOnNodeGraphZoomed(const NodeGraphOnNodeGraphZoomed&) = delete; /* disable copy constructor, use explicit .clone() */
+        NodeGraphOnNodeGraphZoomed() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct NodeGraphOnSelectionChanged {
+        RefAny data;
+        NodeGraphOnSelectionChangedCallback callback;
+        NodeGraphOnSelectionChanged& operator=(const NodeGraphOnSelectionChanged&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        NodeGraphOnSelectionChanged(const NodeGraphOnSelectionChanged&) = delete; /* disable copy constructor, use explicit .clone() */
+        NodeGraphOnSelectionChanged() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct NodeGraphOnHistoryChanged {
+        RefAny data;
+        NodeGraphOnHistoryChangedCallback callback;
+        NodeGraphOnHistoryChanged& operator=(const NodeGraphOnHistoryChanged&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        NodeGraphOnHistoryChanged(const NodeGraphOnHistoryChanged&) = delete; /* disable copy constructor, use explicit .clone() */
+        NodeGraphOnHistoryChanged() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct OutputNodeAndIndex {
         NodeGraphNodeId node_id;
         size_t output_index;
@@ -5819,6 +6476,22 @@ namespace dll {
         InputNodeAndIndex() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct NodeGraphConnection {
+        NodeGraphNodeId output;
+        size_t output_index;
+        NodeGraphNodeId input;
+        size_t input_index;
+        NodeGraphConnection& operator=(const NodeGraphConnection&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        NodeGraphConnection() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct NodeGraphNodeMove {
+        NodeGraphNodeId node_id;
+        NodeDragAmount amount;
+        NodeGraphNodeMove& operator=(const NodeGraphNodeMove&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        NodeGraphNodeMove() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct ListViewOnLazyLoadScroll {
         RefAny data;
         ListViewOnLazyLoadScrollCallback callback;
@@ -5843,6 +6516,188 @@ namespace dll {
         ListViewOnRowClick() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct ListViewOnRowSelect {
+        RefAny data;
+        ListViewOnRowSelectCallback callback;
+        ListViewOnRowSelect& operator=(const ListViewOnRowSelect&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ListViewOnRowSelect(const ListViewOnRowSelect&) = delete; /* disable copy constructor, use explicit .clone() */
+        ListViewOnRowSelect() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct ListViewOnColumnResize {
+        RefAny data;
+        ListViewOnColumnResizeCallback callback;
+        ListViewOnColumnResize& operator=(const ListViewOnColumnResize&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ListViewOnColumnResize(const ListViewOnColumnResize&) = delete; /* disable copy constructor, use explicit .clone() */
+        ListViewOnColumnResize() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct ListViewRowProvider {
+        RefAny data;
+        ListViewRowProviderCallback callback;
+        ListViewRowProvider& operator=(const ListViewRowProvider&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ListViewRowProvider(const ListViewRowProvider&) = delete; /* disable copy constructor, use explicit .clone() */
+        ListViewRowProvider() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewOnExpand {
+        RefAny data;
+        TreeViewOnExpandCallback callback;
+        TreeViewOnExpand& operator=(const TreeViewOnExpand&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewOnExpand(const TreeViewOnExpand&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewOnExpand() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewOnSelect {
+        RefAny data;
+        TreeViewOnSelectCallback callback;
+        TreeViewOnSelect& operator=(const TreeViewOnSelect&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewOnSelect(const TreeViewOnSelect&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewOnSelect() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewOnLazyLoad {
+        RefAny data;
+        TreeViewOnLazyLoadCallback callback;
+        TreeViewOnLazyLoad& operator=(const TreeViewOnLazyLoad&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewOnLazyLoad(const TreeViewOnLazyLoad&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewOnLazyLoad() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewOnScroll {
+        RefAny data;
+        TreeViewOnScrollCallback callback;
+        TreeViewOnScroll& operator=(const TreeViewOnScroll&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewOnScroll(const TreeViewOnScroll&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewOnScroll() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DataGridRange {
+        DataGridCell anchor;
+        DataGridCell cursor;
+        DataGridRange& operator=(const DataGridRange&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DataGridRange() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DataGridCellProvider {
+        RefAny data;
+        DataGridCellProviderCallback callback;
+        DataGridCellProvider& operator=(const DataGridCellProvider&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DataGridCellProvider(const DataGridCellProvider&) = delete; /* disable copy constructor, use explicit .clone() */
+        DataGridCellProvider() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DataGridCellStyle {
+        RefAny data;
+        DataGridCellStyleCallback callback;
+        DataGridCellStyle& operator=(const DataGridCellStyle&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DataGridCellStyle(const DataGridCellStyle&) = delete; /* disable copy constructor, use explicit .clone() */
+        DataGridCellStyle() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DataGridOnSelect {
+        RefAny data;
+        DataGridOnSelectCallback callback;
+        DataGridOnSelect& operator=(const DataGridOnSelect&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DataGridOnSelect(const DataGridOnSelect&) = delete; /* disable copy constructor, use explicit .clone() */
+        DataGridOnSelect() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DataGridOnScroll {
+        RefAny data;
+        DataGridOnScrollCallback callback;
+        DataGridOnScroll& operator=(const DataGridOnScroll&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DataGridOnScroll(const DataGridOnScroll&) = delete; /* disable copy constructor, use explicit .clone() */
+        DataGridOnScroll() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DataGridOnCellEdit {
+        RefAny data;
+        DataGridOnCellEditCallback callback;
+        DataGridOnCellEdit& operator=(const DataGridOnCellEdit&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DataGridOnCellEdit(const DataGridOnCellEdit&) = delete; /* disable copy constructor, use explicit .clone() */
+        DataGridOnCellEdit() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DataGridOnColumnResize {
+        RefAny data;
+        DataGridOnColumnResizeCallback callback;
+        DataGridOnColumnResize& operator=(const DataGridOnColumnResize&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DataGridOnColumnResize(const DataGridOnColumnResize&) = delete; /* disable copy constructor, use explicit .clone() */
+        DataGridOnColumnResize() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DataGridOnRowResize {
+        RefAny data;
+        DataGridOnRowResizeCallback callback;
+        DataGridOnRowResize& operator=(const DataGridOnRowResize&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DataGridOnRowResize(const DataGridOnRowResize&) = delete; /* disable copy constructor, use explicit .clone() */
+        DataGridOnRowResize() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DataGridOnCopyRange {
+        RefAny data;
+        DataGridOnCopyRangeCallback callback;
+        DataGridOnCopyRange& operator=(const DataGridOnCopyRange&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DataGridOnCopyRange(const DataGridOnCopyRange&) = delete; /* disable copy constructor, use explicit .clone() */
+        DataGridOnCopyRange() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DataGridOnClearRange {
+        RefAny data;
+        DataGridOnClearRangeCallback callback;
+        DataGridOnClearRange& operator=(const DataGridOnClearRange&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DataGridOnClearRange(const DataGridOnClearRange&) = delete; /* disable copy constructor, use explicit .clone() */
+        DataGridOnClearRange() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DatePickerOnDateChange {
+        RefAny data;
+        DatePickerOnDateChangeCallback callback;
+        DatePickerOnDateChange& operator=(const DatePickerOnDateChange&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DatePickerOnDateChange(const DatePickerOnDateChange&) = delete; /* disable copy constructor, use explicit .clone() */
+        DatePickerOnDateChange() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TimePickerOnTimeChange {
+        RefAny data;
+        TimePickerOnTimeChangeCallback callback;
+        TimePickerOnTimeChange& operator=(const TimePickerOnTimeChange&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TimePickerOnTimeChange(const TimePickerOnTimeChange&) = delete; /* disable copy constructor, use explicit .clone() */
+        TimePickerOnTimeChange() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DateTimePickerOnDateTimeChange {
+        RefAny data;
+        DateTimePickerOnDateTimeChangeCallback callback;
+        DateTimePickerOnDateTimeChange& operator=(const DateTimePickerOnDateTimeChange&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DateTimePickerOnDateTimeChange(const DateTimePickerOnDateTimeChange&) = delete; /* disable copy constructor, use explicit .clone() */
+        DateTimePickerOnDateTimeChange() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DialogOnClose {
+        RefAny data;
+        DialogOnCloseCallback callback;
+        DialogOnClose& operator=(const DialogOnClose&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DialogOnClose(const DialogOnClose&) = delete; /* disable copy constructor, use explicit .clone() */
+        DialogOnClose() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SplitterPaneState {
+        OptionF32 size;
+        bool  collapsed;
+        SplitterPaneState& operator=(const SplitterPaneState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SplitterPaneState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SplitterOnResize {
+        RefAny data;
+        SplitterOnResizeCallback callback;
+        SplitterOnResize& operator=(const SplitterOnResize&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SplitterOnResize(const SplitterOnResize&) = delete; /* disable copy constructor, use explicit .clone() */
+        SplitterOnResize() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct DropDownOnChoiceChange {
         RefAny data;
         DropDownOnChoiceChangeCallback callback;
@@ -5990,6 +6845,15 @@ namespace dll {
         SvgFillStyle() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct XmlSpan {
+        size_t file;
+        size_t start;
+        size_t end;
+        SvgParseErrorPosition pos;
+        XmlSpan& operator=(const XmlSpan&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        XmlSpan() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct InstantPtr {
         void* ptr;
         InstantPtrCloneFn clone_fn;
@@ -6037,6 +6901,27 @@ namespace dll {
         ThreadWriteBackMsg() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct AzTreeViewItem;
+    struct TreeViewItemVec {
+        TreeViewItem* ptr;
+        size_t len;
+        size_t cap;
+        TreeViewItemVecDestructor destructor;
+        TreeViewItemVec& operator=(const TreeViewItemVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewItemVec(const TreeViewItemVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewItemVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SplitterPaneStateVec {
+        SplitterPaneState* ptr;
+        size_t len;
+        size_t cap;
+        SplitterPaneStateVecDestructor destructor;
+        SplitterPaneStateVec& operator=(const SplitterPaneStateVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SplitterPaneStateVec(const SplitterPaneStateVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        SplitterPaneStateVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct LogicalRectVec {
         LogicalRect* ptr;
         size_t len;
@@ -6047,6 +6932,16 @@ namespace dll {
         LogicalRectVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct NodeGraphNodeIdVec {
+        NodeGraphNodeId* ptr;
+        size_t len;
+        size_t cap;
+        NodeGraphNodeIdVecDestructor destructor;
+        NodeGraphNodeIdVec& operator=(const NodeGraphNodeIdVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        NodeGraphNodeIdVec(const NodeGraphNodeIdVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        NodeGraphNodeIdVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct InputOutputTypeIdVec {
         InputOutputTypeId* ptr;
         size_t len;
@@ -6119,6 +7014,26 @@ namespace dll {
         InlineGlyphVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextLineMetricsVec {
+        TextLineMetrics* ptr;
+        size_t len;
+        size_t cap;
+        TextLineMetricsVecDestructor destructor;
+        TextLineMetricsVec& operator=(const TextLineMetricsVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextLineMetricsVec(const TextLineMetricsVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextLineMetricsVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct InlineTextRunVec {
+        InlineTextRun* ptr;
+        size_t len;
+        size_t cap;
+        InlineTextRunVecDestructor destructor;
+        InlineTextRunVec& operator=(const InlineTextRunVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        InlineTextRunVec(const InlineTextRunVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        InlineTextRunVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct InlineTextHitVec {
         InlineTextHit* ptr;
         size_t len;
@@ -6250,6 +7165,16 @@ namespace dll {
         ScanCodeVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct CssUnicodeRangeVec {
+        CssUnicodeRange* ptr;
+        size_t len;
+        size_t cap;
+        CssUnicodeRangeVecDestructor destructor;
+        CssUnicodeRangeVec& operator=(const CssUnicodeRangeVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssUnicodeRangeVec(const CssUnicodeRangeVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssUnicodeRangeVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct U16Vec {
         uint16_t* ptr;
         size_t len;
@@ -6363,6 +7288,58 @@ namespace dll {
     };
     
     
+    enum class OptionTreeViewOnExpandTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTreeViewOnExpandVariant_None { OptionTreeViewOnExpandTag tag; };
+    struct OptionTreeViewOnExpandVariant_Some { OptionTreeViewOnExpandTag tag; TreeViewOnExpand payload; };
+    union OptionTreeViewOnExpand {
+        OptionTreeViewOnExpandVariant_None None;
+        OptionTreeViewOnExpandVariant_Some Some;
+    };
+    
+    
+    enum class OptionTreeViewOnSelectTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTreeViewOnSelectVariant_None { OptionTreeViewOnSelectTag tag; };
+    struct OptionTreeViewOnSelectVariant_Some { OptionTreeViewOnSelectTag tag; TreeViewOnSelect payload; };
+    union OptionTreeViewOnSelect {
+        OptionTreeViewOnSelectVariant_None None;
+        OptionTreeViewOnSelectVariant_Some Some;
+    };
+    
+    
+    enum class OptionTreeViewOnLazyLoadTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTreeViewOnLazyLoadVariant_None { OptionTreeViewOnLazyLoadTag tag; };
+    struct OptionTreeViewOnLazyLoadVariant_Some { OptionTreeViewOnLazyLoadTag tag; TreeViewOnLazyLoad payload; };
+    union OptionTreeViewOnLazyLoad {
+        OptionTreeViewOnLazyLoadVariant_None None;
+        OptionTreeViewOnLazyLoadVariant_Some Some;
+    };
+    
+    
+    enum class OptionTreeViewOnScrollTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTreeViewOnScrollVariant_None { OptionTreeViewOnScrollTag tag; };
+    struct OptionTreeViewOnScrollVariant_Some { OptionTreeViewOnScrollTag tag; TreeViewOnScroll payload; };
+    union OptionTreeViewOnScroll {
+        OptionTreeViewOnScrollVariant_None None;
+        OptionTreeViewOnScrollVariant_Some Some;
+    };
+    
+    
     enum class OptionListViewOnRowClickTag {
        None,
        Some,
@@ -6376,6 +7353,266 @@ namespace dll {
     };
     
     
+    enum class OptionListViewOnRowSelectTag {
+       None,
+       Some,
+    };
+    
+    struct OptionListViewOnRowSelectVariant_None { OptionListViewOnRowSelectTag tag; };
+    struct OptionListViewOnRowSelectVariant_Some { OptionListViewOnRowSelectTag tag; ListViewOnRowSelect payload; };
+    union OptionListViewOnRowSelect {
+        OptionListViewOnRowSelectVariant_None None;
+        OptionListViewOnRowSelectVariant_Some Some;
+    };
+    
+    
+    enum class OptionListViewOnColumnResizeTag {
+       None,
+       Some,
+    };
+    
+    struct OptionListViewOnColumnResizeVariant_None { OptionListViewOnColumnResizeTag tag; };
+    struct OptionListViewOnColumnResizeVariant_Some { OptionListViewOnColumnResizeTag tag; ListViewOnColumnResize payload; };
+    union OptionListViewOnColumnResize {
+        OptionListViewOnColumnResizeVariant_None None;
+        OptionListViewOnColumnResizeVariant_Some Some;
+    };
+    
+    
+    enum class OptionListViewRowProviderTag {
+       None,
+       Some,
+    };
+    
+    struct OptionListViewRowProviderVariant_None { OptionListViewRowProviderTag tag; };
+    struct OptionListViewRowProviderVariant_Some { OptionListViewRowProviderTag tag; ListViewRowProvider payload; };
+    union OptionListViewRowProvider {
+        OptionListViewRowProviderVariant_None None;
+        OptionListViewRowProviderVariant_Some Some;
+    };
+    
+    
+    enum class OptionDataGridRangeTag {
+       None,
+       Some,
+    };
+    
+    struct OptionDataGridRangeVariant_None { OptionDataGridRangeTag tag; };
+    struct OptionDataGridRangeVariant_Some { OptionDataGridRangeTag tag; DataGridRange payload; };
+    union OptionDataGridRange {
+        OptionDataGridRangeVariant_None None;
+        OptionDataGridRangeVariant_Some Some;
+    };
+    
+    
+    enum class OptionDataGridCellProviderTag {
+       None,
+       Some,
+    };
+    
+    struct OptionDataGridCellProviderVariant_None { OptionDataGridCellProviderTag tag; };
+    struct OptionDataGridCellProviderVariant_Some { OptionDataGridCellProviderTag tag; DataGridCellProvider payload; };
+    union OptionDataGridCellProvider {
+        OptionDataGridCellProviderVariant_None None;
+        OptionDataGridCellProviderVariant_Some Some;
+    };
+    
+    
+    enum class OptionDataGridCellStyleTag {
+       None,
+       Some,
+    };
+    
+    struct OptionDataGridCellStyleVariant_None { OptionDataGridCellStyleTag tag; };
+    struct OptionDataGridCellStyleVariant_Some { OptionDataGridCellStyleTag tag; DataGridCellStyle payload; };
+    union OptionDataGridCellStyle {
+        OptionDataGridCellStyleVariant_None None;
+        OptionDataGridCellStyleVariant_Some Some;
+    };
+    
+    
+    enum class OptionDataGridOnSelectTag {
+       None,
+       Some,
+    };
+    
+    struct OptionDataGridOnSelectVariant_None { OptionDataGridOnSelectTag tag; };
+    struct OptionDataGridOnSelectVariant_Some { OptionDataGridOnSelectTag tag; DataGridOnSelect payload; };
+    union OptionDataGridOnSelect {
+        OptionDataGridOnSelectVariant_None None;
+        OptionDataGridOnSelectVariant_Some Some;
+    };
+    
+    
+    enum class OptionDataGridOnScrollTag {
+       None,
+       Some,
+    };
+    
+    struct OptionDataGridOnScrollVariant_None { OptionDataGridOnScrollTag tag; };
+    struct OptionDataGridOnScrollVariant_Some { OptionDataGridOnScrollTag tag; DataGridOnScroll payload; };
+    union OptionDataGridOnScroll {
+        OptionDataGridOnScrollVariant_None None;
+        OptionDataGridOnScrollVariant_Some Some;
+    };
+    
+    
+    enum class OptionDataGridOnCellEditTag {
+       None,
+       Some,
+    };
+    
+    struct OptionDataGridOnCellEditVariant_None { OptionDataGridOnCellEditTag tag; };
+    struct OptionDataGridOnCellEditVariant_Some { OptionDataGridOnCellEditTag tag; DataGridOnCellEdit payload; };
+    union OptionDataGridOnCellEdit {
+        OptionDataGridOnCellEditVariant_None None;
+        OptionDataGridOnCellEditVariant_Some Some;
+    };
+    
+    
+    enum class OptionDataGridOnColumnResizeTag {
+       None,
+       Some,
+    };
+    
+    struct OptionDataGridOnColumnResizeVariant_None { OptionDataGridOnColumnResizeTag tag; };
+    struct OptionDataGridOnColumnResizeVariant_Some { OptionDataGridOnColumnResizeTag tag; DataGridOnColumnResize payload; };
+    union OptionDataGridOnColumnResize {
+        OptionDataGridOnColumnResizeVariant_None None;
+        OptionDataGridOnColumnResizeVariant_Some Some;
+    };
+    
+    
+    enum class OptionDataGridOnRowResizeTag {
+       None,
+       Some,
+    };
+    
+    struct OptionDataGridOnRowResizeVariant_None { OptionDataGridOnRowResizeTag tag; };
+    struct OptionDataGridOnRowResizeVariant_Some { OptionDataGridOnRowResizeTag tag; DataGridOnRowResize payload; };
+    union OptionDataGridOnRowResize {
+        OptionDataGridOnRowResizeVariant_None None;
+        OptionDataGridOnRowResizeVariant_Some Some;
+    };
+    
+    
+    enum class OptionDataGridOnCopyRangeTag {
+       None,
+       Some,
+    };
+    
+    struct OptionDataGridOnCopyRangeVariant_None { OptionDataGridOnCopyRangeTag tag; };
+    struct OptionDataGridOnCopyRangeVariant_Some { OptionDataGridOnCopyRangeTag tag; DataGridOnCopyRange payload; };
+    union OptionDataGridOnCopyRange {
+        OptionDataGridOnCopyRangeVariant_None None;
+        OptionDataGridOnCopyRangeVariant_Some Some;
+    };
+    
+    
+    enum class OptionDataGridOnClearRangeTag {
+       None,
+       Some,
+    };
+    
+    struct OptionDataGridOnClearRangeVariant_None { OptionDataGridOnClearRangeTag tag; };
+    struct OptionDataGridOnClearRangeVariant_Some { OptionDataGridOnClearRangeTag tag; DataGridOnClearRange payload; };
+    union OptionDataGridOnClearRange {
+        OptionDataGridOnClearRangeVariant_None None;
+        OptionDataGridOnClearRangeVariant_Some Some;
+    };
+    
+    
+    enum class OptionDateTag {
+       None,
+       Some,
+    };
+    
+    struct OptionDateVariant_None { OptionDateTag tag; };
+    struct OptionDateVariant_Some { OptionDateTag tag; Date payload; };
+    union OptionDate {
+        OptionDateVariant_None None;
+        OptionDateVariant_Some Some;
+    };
+    
+    
+    enum class OptionTimeTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTimeVariant_None { OptionTimeTag tag; };
+    struct OptionTimeVariant_Some { OptionTimeTag tag; Time payload; };
+    union OptionTime {
+        OptionTimeVariant_None None;
+        OptionTimeVariant_Some Some;
+    };
+    
+    
+    enum class OptionDatePickerOnDateChangeTag {
+       None,
+       Some,
+    };
+    
+    struct OptionDatePickerOnDateChangeVariant_None { OptionDatePickerOnDateChangeTag tag; };
+    struct OptionDatePickerOnDateChangeVariant_Some { OptionDatePickerOnDateChangeTag tag; DatePickerOnDateChange payload; };
+    union OptionDatePickerOnDateChange {
+        OptionDatePickerOnDateChangeVariant_None None;
+        OptionDatePickerOnDateChangeVariant_Some Some;
+    };
+    
+    
+    enum class OptionTimePickerOnTimeChangeTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTimePickerOnTimeChangeVariant_None { OptionTimePickerOnTimeChangeTag tag; };
+    struct OptionTimePickerOnTimeChangeVariant_Some { OptionTimePickerOnTimeChangeTag tag; TimePickerOnTimeChange payload; };
+    union OptionTimePickerOnTimeChange {
+        OptionTimePickerOnTimeChangeVariant_None None;
+        OptionTimePickerOnTimeChangeVariant_Some Some;
+    };
+    
+    
+    enum class OptionDateTimePickerOnDateTimeChangeTag {
+       None,
+       Some,
+    };
+    
+    struct OptionDateTimePickerOnDateTimeChangeVariant_None { OptionDateTimePickerOnDateTimeChangeTag tag; };
+    struct OptionDateTimePickerOnDateTimeChangeVariant_Some { OptionDateTimePickerOnDateTimeChangeTag tag; DateTimePickerOnDateTimeChange payload; };
+    union OptionDateTimePickerOnDateTimeChange {
+        OptionDateTimePickerOnDateTimeChangeVariant_None None;
+        OptionDateTimePickerOnDateTimeChangeVariant_Some Some;
+    };
+    
+    
+    enum class OptionDialogOnCloseTag {
+       None,
+       Some,
+    };
+    
+    struct OptionDialogOnCloseVariant_None { OptionDialogOnCloseTag tag; };
+    struct OptionDialogOnCloseVariant_Some { OptionDialogOnCloseTag tag; DialogOnClose payload; };
+    union OptionDialogOnClose {
+        OptionDialogOnCloseVariant_None None;
+        OptionDialogOnCloseVariant_Some Some;
+    };
+    
+    
+    enum class OptionSplitterOnResizeTag {
+       None,
+       Some,
+    };
+    
+    struct OptionSplitterOnResizeVariant_None { OptionSplitterOnResizeTag tag; };
+    struct OptionSplitterOnResizeVariant_Some { OptionSplitterOnResizeTag tag; SplitterOnResize payload; };
+    union OptionSplitterOnResize {
+        OptionSplitterOnResizeVariant_None None;
+        OptionSplitterOnResizeVariant_Some Some;
+    };
+    
+    
     enum class OptionListViewOnColumnClickTag {
        None,
        Some,
@@ -6428,6 +7665,45 @@ namespace dll {
     };
     
     
+    enum class OptionNodeGraphOnNodeGraphZoomedTag {
+       None,
+       Some,
+    };
+    
+    struct OptionNodeGraphOnNodeGraphZoomedVariant_None { OptionNodeGraphOnNodeGraphZoomedTag tag; };
+    struct OptionNodeGraphOnNodeGraphZoomedVariant_Some { OptionNodeGraphOnNodeGraphZoomedTag tag; NodeGraphOnNodeGraphZoomed payload; };
+    union OptionNodeGraphOnNodeGraphZoomed {
+        OptionNodeGraphOnNodeGraphZoomedVariant_None None;
+        OptionNodeGraphOnNodeGraphZoomedVariant_Some Some;
+    };
+    
+    
+    enum class OptionNodeGraphOnSelectionChangedTag {
+       None,
+       Some,
+    };
+    
+    struct OptionNodeGraphOnSelectionChangedVariant_None { OptionNodeGraphOnSelectionChangedTag tag; };
+    struct OptionNodeGraphOnSelectionChangedVariant_Some { OptionNodeGraphOnSelectionChangedTag tag; NodeGraphOnSelectionChanged payload; };
+    union OptionNodeGraphOnSelectionChanged {
+        OptionNodeGraphOnSelectionChangedVariant_None None;
+        OptionNodeGraphOnSelectionChangedVariant_Some Some;
+    };
+    
+    
+    enum class OptionNodeGraphOnHistoryChangedTag {
+       None,
+       Some,
+    };
+    
+    struct OptionNodeGraphOnHistoryChangedVariant_None { OptionNodeGraphOnHistoryChangedTag tag; };
+    struct OptionNodeGraphOnHistoryChangedVariant_Some { OptionNodeGraphOnHistoryChangedTag tag; NodeGraphOnHistoryChanged payload; };
+    union OptionNodeGraphOnHistoryChanged {
+        OptionNodeGraphOnHistoryChangedVariant_None None;
+        OptionNodeGraphOnHistoryChangedVariant_Some Some;
+    };
+    
+    
     enum class OptionNodeGraphOnNodeAddedTag {
        None,
        Some,
@@ -7258,6 +8534,16 @@ namespace dll {
         MarshaledLayoutCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextMetrics {
+        float width;
+        float height;
+        size_t line_count;
+        TextLineMetricsVec lines;
+        TextMetrics& operator=(const TextMetrics&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextMetrics(const TextMetrics&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextMetrics() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct InlineTextContents {
         InlineGlyphVec glyphs;
         LogicalRect bounds;
@@ -7329,6 +8615,7 @@ namespace dll {
         void* image_cache;
         OptionGl* gl_context;
         void* system_fonts;
+        void* callbacks;
         void* _reserved_ref;
         void* restrict _reserved_mut;
         LayoutCallbackInfo& operator=(const LayoutCallbackInfo&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -7377,6 +8664,14 @@ namespace dll {
         VirtualKeyCodeCombo() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct KeyboardShortcut {
+        VirtualKeyCodeCombo keys;
+        MenuCallback callback;
+        KeyboardShortcut& operator=(const KeyboardShortcut&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        KeyboardShortcut(const KeyboardShortcut&) = delete; /* disable copy constructor, use explicit .clone() */
+        KeyboardShortcut() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class CssPathPseudoSelectorTag {
        First,
        Last,
@@ -7663,6 +8958,9 @@ namespace dll {
         OptionNodeGraphOnNodeInputDisconnected on_node_input_disconnected;
         OptionNodeGraphOnNodeOutputDisconnected on_node_output_disconnected;
         OptionNodeGraphOnNodeFieldEdited on_node_field_edited;
+        OptionNodeGraphOnNodeGraphZoomed on_node_graph_zoomed;
+        OptionNodeGraphOnSelectionChanged on_selection_changed;
+        OptionNodeGraphOnHistoryChanged on_history_changed;
         NodeGraphCallbacks& operator=(const NodeGraphCallbacks&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         NodeGraphCallbacks(const NodeGraphCallbacks&) = delete; /* disable copy constructor, use explicit .clone() */
         NodeGraphCallbacks() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -7687,11 +8985,83 @@ namespace dll {
     struct ListViewRow {
         DomVec cells;
         OptionPixelValueNoPercent height;
+        bool  is_selected;
         ListViewRow& operator=(const ListViewRow&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         ListViewRow(const ListViewRow&) = delete; /* disable copy constructor, use explicit .clone() */
         ListViewRow() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TreeView {
+        TreeViewItemVec items;
+        TreeViewSelectionMode selection_mode;
+        size_t first_visible_row;
+        size_t max_visible_rows;
+        OptionUsize focused_item;
+        OptionUsize selection_anchor;
+        OptionTreeViewOnExpand on_expand;
+        OptionTreeViewOnSelect on_select;
+        OptionTreeViewOnLazyLoad on_lazy_load;
+        OptionTreeViewOnScroll on_scroll;
+        TreeView& operator=(const TreeView&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeView(const TreeView&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeView() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DatePickerState {
+        OptionDate selected;
+        Date cursor;
+        DatePickerState& operator=(const DatePickerState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DatePickerState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DatePicker {
+        DatePickerState state;
+        OptionDate minimum_date;
+        OptionDate maximum_date;
+        Weekday first_day_of_week;
+        OptionDatePickerOnDateChange on_date_change;
+        DatePicker& operator=(const DatePicker&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DatePicker(const DatePicker&) = delete; /* disable copy constructor, use explicit .clone() */
+        DatePicker() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TimePicker {
+        Time time;
+        bool  show_seconds;
+        OptionTimePickerOnTimeChange on_time_change;
+        TimePicker& operator=(const TimePicker&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TimePicker(const TimePicker&) = delete; /* disable copy constructor, use explicit .clone() */
+        TimePicker() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DateTimePickerState {
+        DatePickerState date;
+        Time time;
+        bool  is_open;
+        DateTimePickerState& operator=(const DateTimePickerState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DateTimePickerState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DateTimePicker {
+        DateTimePickerState state;
+        OptionDate minimum_date;
+        OptionDate maximum_date;
+        Weekday first_day_of_week;
+        bool  show_time;
+        bool  show_seconds;
+        OptionDateTimePickerOnDateTimeChange on_date_time_change;
+        DateTimePicker& operator=(const DateTimePicker&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DateTimePicker(const DateTimePicker&) = delete; /* disable copy constructor, use explicit .clone() */
+        DateTimePicker() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SplitterState {
+        SplitterPaneStateVec panes;
+        SplitterState& operator=(const SplitterState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SplitterState(const SplitterState&) = delete; /* disable copy constructor, use explicit .clone() */
+        SplitterState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct StyledNode {
         StyledNodeState state;
         OptionTagId tag_id;
@@ -7911,6 +9281,16 @@ namespace dll {
         OutputConnectionVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct KeyboardShortcutVec {
+        KeyboardShortcut* ptr;
+        size_t len;
+        size_t cap;
+        KeyboardShortcutVecDestructor destructor;
+        KeyboardShortcutVec& operator=(const KeyboardShortcutVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        KeyboardShortcutVec(const KeyboardShortcutVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        KeyboardShortcutVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct TessellatedSvgNodeVec {
         TessellatedSvgNode* ptr;
         size_t len;
@@ -8072,6 +9452,19 @@ namespace dll {
     };
     
     
+    enum class OptionTextMetricsTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTextMetricsVariant_None { OptionTextMetricsTag tag; };
+    struct OptionTextMetricsVariant_Some { OptionTextMetricsTag tag; TextMetrics payload; };
+    union OptionTextMetrics {
+        OptionTextMetricsVariant_None None;
+        OptionTextMetricsVariant_Some Some;
+    };
+    
+    
     enum class OptionTaskBarIconTag {
        None,
        Some,
@@ -8341,6 +9734,7 @@ namespace dll {
         AccessibilityStateVec states;
         OptionVirtualKeyCodeCombo accelerator;
         OptionString default_action;
+        OptionString description;
         AccessibilityInfo& operator=(const AccessibilityInfo&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         AccessibilityInfo(const AccessibilityInfo&) = delete; /* disable copy constructor, use explicit .clone() */
         AccessibilityInfo() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -8399,6 +9793,14 @@ namespace dll {
     };
     
     
+    struct CssFontFaceUrl {
+        String url;
+        OptionString format;
+        CssFontFaceUrl& operator=(const CssFontFaceUrl&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssFontFaceUrl(const CssFontFaceUrl&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssFontFaceUrl() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class StyleBackgroundContentTag {
        LinearGradient,
        RadialGradient,
@@ -8601,6 +10003,17 @@ namespace dll {
         InputOutputInfo() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct NodeGraphFieldEdit {
+        NodeGraphNodeId node_id;
+        NodeTypeId node_type;
+        size_t field_idx;
+        NodeTypeFieldValue old_value;
+        NodeTypeFieldValue new_value;
+        NodeGraphFieldEdit& operator=(const NodeGraphFieldEdit&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        NodeGraphFieldEdit(const NodeGraphFieldEdit&) = delete; /* disable copy constructor, use explicit .clone() */
+        NodeGraphFieldEdit() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct ListView {
         StringVec columns;
         ListViewRowVec rows;
@@ -8611,6 +10024,16 @@ namespace dll {
         OptionListViewOnLazyLoadScroll on_lazy_load_scroll;
         OptionListViewOnColumnClick on_column_click;
         OptionListViewOnRowClick on_row_click;
+        size_t row_count;
+        float row_height;
+        size_t first_visible_row;
+        F32Vec column_widths;
+        bool  sort_descending;
+        OptionUsize focused_row;
+        OptionUsize selection_anchor;
+        OptionListViewRowProvider row_provider;
+        OptionListViewOnRowSelect on_row_select;
+        OptionListViewOnColumnResize on_column_resize;
         ListView& operator=(const ListView&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         ListView(const ListView&) = delete; /* disable copy constructor, use explicit .clone() */
         ListView() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -8623,16 +10046,57 @@ namespace dll {
         PixelValueNoPercent scroll_offset;
         LogicalPosition current_scroll_position;
         LogicalSize current_content_height;
+        size_t row_count;
+        size_t first_visible_row;
+        OptionUsize focused_row;
+        OptionUsize selection_anchor;
         ListViewState& operator=(const ListViewState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         ListViewState(const ListViewState&) = delete; /* disable copy constructor, use explicit .clone() */
         ListViewState() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
-    struct TreeView {
-        String root;
-        TreeView& operator=(const TreeView&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
-        TreeView(const TreeView&) = delete; /* disable copy constructor, use explicit .clone() */
-        TreeView() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    struct TreeViewItem {
+        size_t id;
+        String label;
+        OptionImageRef icon;
+        TreeViewItemVec children;
+        bool  has_lazy_children;
+        bool  is_expanded;
+        bool  is_selected;
+        TreeViewItem& operator=(const TreeViewItem&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewItem(const TreeViewItem&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewItem() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DataGrid {
+        size_t row_count;
+        size_t column_count;
+        StringVec column_headers;
+        F32Vec column_widths;
+        F32Vec row_heights;
+        size_t first_visible_row;
+        size_t first_visible_column;
+        OptionDataGridRange selection;
+        OptionDataGridCellProvider cell_provider;
+        OptionDataGridCellStyle cell_style;
+        OptionDataGridOnSelect on_select;
+        OptionDataGridOnScroll on_scroll;
+        OptionDataGridOnCellEdit on_cell_edit;
+        OptionDataGridOnColumnResize on_column_resize;
+        OptionDataGridOnRowResize on_row_resize;
+        OptionDataGridOnCopyRange on_copy_range;
+        OptionDataGridOnClearRange on_clear_range;
+        DataGrid& operator=(const DataGrid&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DataGrid(const DataGrid&) = delete; /* disable copy constructor, use explicit .clone() */
+        DataGrid() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DialogResult {
+        DialogButton button;
+        String input;
+        DialogResult& operator=(const DialogResult&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DialogResult(const DialogResult&) = delete; /* disable copy constructor, use explicit .clone() */
+        DialogResult() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
     struct DropDown {
@@ -8689,6 +10153,7 @@ namespace dll {
         size_t height;
         bool  alpha_premultiplied;
         RawImageFormat data_format;
+        U8Vec tag;
         RawImage& operator=(const RawImage&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         RawImage(const RawImage&) = delete; /* disable copy constructor, use explicit .clone() */
         RawImage() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -9102,6 +10567,19 @@ namespace dll {
         CssPath() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class CssFontFaceSourceTag {
+       Url,
+       Local,
+    };
+    
+    struct CssFontFaceSourceVariant_Url { CssFontFaceSourceTag tag; CssFontFaceUrl payload; };
+    struct CssFontFaceSourceVariant_Local { CssFontFaceSourceTag tag; String payload; };
+    union CssFontFaceSource {
+        CssFontFaceSourceVariant_Url Url;
+        CssFontFaceSourceVariant_Local Local;
+    };
+    
+    
     enum class StyleBackgroundContentVecValueTag {
        Auto,
        None,
@@ -9221,6 +10699,8 @@ namespace dll {
        Filter,
        BackdropFilter,
        TextShadow,
+       VerticalAlign,
+       TextDecoration,
     };
     
     struct CssPropertyVariant_TextColor { CssPropertyTag tag; StyleTextColorValue payload; };
@@ -9297,6 +10777,8 @@ namespace dll {
     struct CssPropertyVariant_Filter { CssPropertyTag tag; StyleFilterVecValue payload; };
     struct CssPropertyVariant_BackdropFilter { CssPropertyTag tag; StyleFilterVecValue payload; };
     struct CssPropertyVariant_TextShadow { CssPropertyTag tag; StyleBoxShadowValue payload; };
+    struct CssPropertyVariant_VerticalAlign { CssPropertyTag tag; LayoutVerticalAlignValue payload; };
+    struct CssPropertyVariant_TextDecoration { CssPropertyTag tag; StyleTextDecorationValue payload; };
     union CssProperty {
         CssPropertyVariant_TextColor TextColor;
         CssPropertyVariant_FontSize FontSize;
@@ -9372,6 +10854,8 @@ namespace dll {
         CssPropertyVariant_Filter Filter;
         CssPropertyVariant_BackdropFilter BackdropFilter;
         CssPropertyVariant_TextShadow TextShadow;
+        CssPropertyVariant_VerticalAlign VerticalAlign;
+        CssPropertyVariant_TextDecoration TextDecoration;
     };
     
     
@@ -9526,6 +11010,7 @@ namespace dll {
         StringPairVec attributes;
         XmlNodeVec children;
         OptionString text;
+        XmlSpan span;
         XmlNode& operator=(const XmlNode&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         XmlNode(const XmlNode&) = delete; /* disable copy constructor, use explicit .clone() */
         XmlNode() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -9601,6 +11086,16 @@ namespace dll {
         SvgSimpleNodeVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct CssFontFaceSourceVec {
+        CssFontFaceSource* ptr;
+        size_t len;
+        size_t cap;
+        CssFontFaceSourceVecDestructor destructor;
+        CssFontFaceSourceVec& operator=(const CssFontFaceSourceVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssFontFaceSourceVec(const CssFontFaceSourceVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssFontFaceSourceVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class OptionCssPropertyTag {
        None,
        Some,
@@ -9695,6 +11190,7 @@ namespace dll {
         float font_size_px;
         size_t last_word_index;
         float baseline_descender_px;
+        InlineTextRunVec runs;
         InlineText& operator=(const InlineText&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         InlineText(const InlineText&) = delete; /* disable copy constructor, use explicit .clone() */
         InlineText() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -9761,6 +11257,17 @@ namespace dll {
         DynamicCssProperty() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct CssFontFace {
+        String font_family;
+        CssFontFaceSourceVec src;
+        uint16_t font_weight;
+        CssFontFaceStyle font_style;
+        CssUnicodeRangeVec unicode_range;
+        CssFontFace& operator=(const CssFontFace&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssFontFace(const CssFontFace&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssFontFace() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct Node {
         NodeTypeId node_type;
         NodePosition position;
@@ -9815,6 +11322,16 @@ namespace dll {
         NodeDataInlineCssPropertyVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct CssFontFaceVec {
+        CssFontFace* ptr;
+        size_t len;
+        size_t cap;
+        CssFontFaceVecDestructor destructor;
+        CssFontFaceVec& operator=(const CssFontFaceVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssFontFaceVec(const CssFontFaceVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssFontFaceVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class OptionWindowStateTag {
        None,
        Some,
@@ -10019,6 +11536,41 @@ namespace dll {
         NodeIdNodeMap() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class NodeGraphEditOpTag {
+       AddNode,
+       RemoveNode,
+       MoveNode,
+       Connect,
+       Disconnect,
+       EditField,
+    };
+    
+    struct NodeGraphEditOpVariant_AddNode { NodeGraphEditOpTag tag; NodeIdNodeMap payload; };
+    struct NodeGraphEditOpVariant_RemoveNode { NodeGraphEditOpTag tag; NodeIdNodeMap payload; };
+    struct NodeGraphEditOpVariant_MoveNode { NodeGraphEditOpTag tag; NodeGraphNodeMove payload; };
+    struct NodeGraphEditOpVariant_Connect { NodeGraphEditOpTag tag; NodeGraphConnection payload; };
+    struct NodeGraphEditOpVariant_Disconnect { NodeGraphEditOpTag tag; NodeGraphConnection payload; };
+    struct NodeGraphEditOpVariant_EditField { NodeGraphEditOpTag tag; NodeGraphFieldEdit payload; };
+    union NodeGraphEditOp {
+        NodeGraphEditOpVariant_AddNode AddNode;
+        NodeGraphEditOpVariant_RemoveNode RemoveNode;
+        NodeGraphEditOpVariant_MoveNode MoveNode;
+        NodeGraphEditOpVariant_Connect Connect;
+        NodeGraphEditOpVariant_Disconnect Disconnect;
+        NodeGraphEditOpVariant_EditField EditField;
+    };
+    
+    
+    struct NodeGraphEditOpVec {
+        NodeGraphEditOp* ptr;
+        size_t len;
+        size_t cap;
+        NodeGraphEditOpVecDestructor destructor;
+        NodeGraphEditOpVec& operator=(const NodeGraphEditOpVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        NodeGraphEditOpVec(const NodeGraphEditOpVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        NodeGraphEditOpVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct NodeIdNodeMapVec {
         NodeIdNodeMap* ptr;
         size_t len;
@@ -10116,6 +11668,19 @@ namespace dll {
         Dom() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class TooltipContentTag {
+       Text,
+       Dom,
+    };
+    
+    struct TooltipContentVariant_Text { TooltipContentTag tag; String payload; };
+    struct TooltipContentVariant_Dom { TooltipContentTag tag; Dom payload; };
+    union TooltipContent {
+        TooltipContentVariant_Text Text;
+        TooltipContentVariant_Dom Dom;
+    };
+    
+    
     struct CssRuleBlock {
         CssPath path;
         CssDeclarationVec declarations;
@@ -10141,19 +11706,21 @@ namespace dll {
         Frame() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
-    struct NodeGraph {
-        NodeTypeIdInfoMapVec node_types;
-        InputOutputTypeIdInfoMapVec input_output_types;
-        NodeIdNodeMapVec nodes;
-        bool  allow_multiple_root_nodes;
-        LogicalPosition offset;
-        NodeGraphStyle style;
-        NodeGraphCallbacks callbacks;
-        String add_node_str;
-        float scale_factor;
-        NodeGraph& operator=(const NodeGraph&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
-        NodeGraph(const NodeGraph&) = delete; /* disable copy constructor, use explicit .clone() */
-        NodeGraph() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    struct NodeGraphEdit {
+        NodeGraphEditOpVec ops;
+        NodeGraphEdit& operator=(const NodeGraphEdit&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        NodeGraphEdit(const NodeGraphEdit&) = delete; /* disable copy constructor, use explicit .clone() */
+        NodeGraphEdit() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SplitterPane {
+        Dom content;
+        SplitterPaneState state;
+        float min_size;
+        OptionF32 max_size;
+        SplitterPane& operator=(const SplitterPane&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SplitterPane(const SplitterPane&) = delete; /* disable copy constructor, use explicit .clone() */
+        SplitterPane() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
     struct StyledDom {
@@ -10173,6 +11740,26 @@ namespace dll {
         StyledDom() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct SplitterPaneVec {
+        SplitterPane* ptr;
+        size_t len;
+        size_t cap;
+        SplitterPaneVecDestructor destructor;
+        SplitterPaneVec& operator=(const SplitterPaneVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SplitterPaneVec(const SplitterPaneVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        SplitterPaneVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct NodeGraphEditVec {
+        NodeGraphEdit* ptr;
+        size_t len;
+        size_t cap;
+        NodeGraphEditVecDestructor destructor;
+        NodeGraphEditVec& operator=(const NodeGraphEditVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        NodeGraphEditVec(const NodeGraphEditVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        NodeGraphEditVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct CssRuleBlockVec {
         CssRuleBlock* ptr;
         size_t len;
@@ -10245,6 +11832,14 @@ namespace dll {
         IFrameCallbackReturn() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct Tooltip {
+        TooltipContent content;
+        Duration delay;
+        Tooltip& operator=(const Tooltip&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        Tooltip(const Tooltip&) = delete; /* disable copy constructor, use explicit .clone() */
+        Tooltip() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct Stylesheet {
         CssRuleBlockVec rules;
         Stylesheet& operator=(const Stylesheet&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -10252,6 +11847,37 @@ namespace dll {
         Stylesheet() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct NodeGraphHistory {
+        NodeGraphEditVec undo;
+        NodeGraphEditVec redo;
+        NodeGraphHistory& operator=(const NodeGraphHistory&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        NodeGraphHistory(const NodeGraphHistory&) = delete; /* disable copy constructor, use explicit .clone() */
+        NodeGraphHistory() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct Dialog {
+        String title;
+        String message;
+        DialogIcon icon;
+        DialogButtons buttons;
+        OptionString input;
+        OptionDom content;
+        OptionDialogOnClose on_close;
+        Dialog& operator=(const Dialog&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        Dialog(const Dialog&) = delete; /* disable copy constructor, use explicit .clone() */
+        Dialog() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct Splitter {
+        SplitterDirection direction;
+        SplitterPaneVec panes;
+        float handle_size;
+        OptionSplitterOnResize on_resize;
+        Splitter& operator=(const Splitter&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        Splitter(const Splitter&) = delete; /* disable copy constructor, use explicit .clone() */
+        Splitter() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct StylesheetVec {
         Stylesheet* ptr;
         size_t len;
@@ -10290,10 +11916,41 @@ namespace dll {
     
     struct Css {
         StylesheetVec stylesheets;
+        CssFontFaceVec font_faces;
         Css& operator=(const Css&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         Css(const Css&) = delete; /* disable copy constructor, use explicit .clone() */
         Css() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
+    
+    struct NodeGraph {
+        NodeTypeIdInfoMapVec node_types;
+        InputOutputTypeIdInfoMapVec input_output_types;
+        NodeIdNodeMapVec nodes;
+        bool  allow_multiple_root_nodes;
+        LogicalPosition offset;
+        NodeGraphStyle style;
+        NodeGraphCallbacks callbacks;
+        String add_node_str;
+        float scale_factor;
+        NodeGraphNodeIdVec selected_nodes;
+        NodeGraphHistory history;
+        NodeGraph& operator=(const NodeGraph&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        NodeGraph(const NodeGraph&) = delete; /* disable copy constructor, use explicit .clone() */
+        NodeGraph() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class ResultNodeGraphNodeGraphErrorTag {
+       Ok,
+       Err,
+    };
+    
+    struct ResultNodeGraphNodeGraphErrorVariant_Ok { ResultNodeGraphNodeGraphErrorTag tag; NodeGraph payload; };
+    struct ResultNodeGraphNodeGraphErrorVariant_Err { ResultNodeGraphNodeGraphErrorTag tag; NodeGraphError payload; };
+    union ResultNodeGraphNodeGraphError {
+        ResultNodeGraphNodeGraphErrorVariant_Ok Ok;
+        ResultNodeGraphNodeGraphErrorVariant_Err Err;
+    };
+    
 
     extern "C" {        
         
@@ -10301,6 +11958,7 @@ namespace dll {
         App App_new(AzRefAny  data, AzAppConfig  config);
         void App_addWindow(App* restrict app, AzWindowCreateOptions  window);
         void App_addImage(App* restrict app, AzString  id, AzImageRef  image);
+        bool  App_addFont(App* restrict app, AzString  family, AzU8Vec  font_bytes);
         MonitorVec App_getMonitors(const App* app);
         void App_run(const App* app, AzWindowCreateOptions  window);
         void App_delete(App* restrict instance);
@@ -10391,7 +12049,9 @@ namespace dll {
         PhysicalSizeU32 HidpiAdjustedBounds_getPhysicalSize(const HidpiAdjustedBounds* hidpiadjustedbounds);
         float HidpiAdjustedBounds_getHidpiFactor(const HidpiAdjustedBounds* hidpiadjustedbounds);
         InlineTextHitVec InlineText_hitTest(const InlineText* inlinetext, AzLogicalPosition  position);
+        TextMetrics InlineText_getTextMetrics(const InlineText* inlinetext);
         void InlineText_delete(InlineText* restrict instance);
+        void TextMetrics_delete(TextMetrics* restrict instance);
         void InlineLine_delete(InlineLine* restrict instance);
         void InlineWord_delete(InlineWord* restrict instance);
         void InlineTextContents_delete(InlineTextContents* restrict instance);
@@ -10429,6 +12089,7 @@ namespace dll {
         OptionGl LayoutCallbackInfo_getGlContext(const LayoutCallbackInfo* layoutcallbackinfo);
         StringPairVec LayoutCallbackInfo_getSystemFonts(const LayoutCallbackInfo* layoutcallbackinfo);
         OptionImageRef LayoutCallbackInfo_getImage(const LayoutCallbackInfo* layoutcallbackinfo, AzString  id);
+        OptionTextMetrics LayoutCallbackInfo_measureText(const LayoutCallbackInfo* layoutcallbackinfo, AzString  text, AzStyleFontFamilyVec  font_families, AzResolvedTextLayoutOptions  options);
         void LayoutCallbackInfo_delete(LayoutCallbackInfo* restrict instance);
         Dom Dom_new(AzNodeType  node_type);
         Dom Dom_body();
@@ -10483,6 +12144,11 @@ namespace dll {
         Dom Dom_withMenuBar(Dom* restrict dom, AzMenu  menu_bar);
         void Dom_setContextMenu(Dom* restrict dom, AzMenu  context_menu);
         Dom Dom_withContextMenu(Dom* restrict dom, AzMenu  context_menu);
+        void Dom_setTooltip(Dom* restrict dom, AzTooltip  tooltip);
+        Dom Dom_withTooltip(Dom* restrict dom, AzTooltip  tooltip);
+        void Dom_setKeyboardShortcuts(Dom* restrict dom, AzKeyboardShortcutVec  keyboard_shortcuts);
+        Dom Dom_withKeyboardShortcuts(Dom* restrict dom, AzKeyboardShortcutVec  keyboard_shortcuts);
+        void Dom_makeInert(Dom* restrict dom);
         uint64_t Dom_hash(const Dom* dom);
         size_t Dom_nodeCount(const Dom* dom);
         String Dom_getHtmlString(Dom* restrict dom);
@@ -10523,11 +12189,18 @@ namespace dll {
         void NodeData_setAccessibilityInfo(NodeData* restrict nodedata, AzAccessibilityInfo  accessibility_info);
         void NodeData_setMenuBar(NodeData* restrict nodedata, AzMenu  menu_bar);
         void NodeData_setContextMenu(NodeData* restrict nodedata, AzMenu  context_menu);
+        void NodeData_setTooltip(NodeData* restrict nodedata, AzTooltip  tooltip);
+        void NodeData_setKeyboardShortcuts(NodeData* restrict nodedata, AzKeyboardShortcutVec  keyboard_shortcuts);
         uint64_t NodeData_hash(const NodeData* nodedata);
         void NodeData_delete(NodeData* restrict instance);
         void NodeType_delete(NodeType* restrict instance);
         EventFilter On_intoEventFilter(const On on);
         void AccessibilityInfo_delete(AccessibilityInfo* restrict instance);
+        Tooltip Tooltip_text(AzString  text);
+        Tooltip Tooltip_dom(AzDom  dom);
+        Tooltip Tooltip_withDelay(Tooltip* restrict tooltip, AzDuration  delay);
+        void Tooltip_delete(Tooltip* restrict instance);
+        void TooltipContent_delete(TooltipContent* restrict instance);
         void IdOrClass_delete(IdOrClass* restrict instance);
         void NodeDataInlineCssProperty_delete(NodeDataInlineCssProperty* restrict instance);
         Menu Menu_new(AzMenuItemVec  items);
@@ -10543,7 +12216,10 @@ namespace dll {
         void StringMenuItem_setChildren(StringMenuItem* restrict stringmenuitem, AzMenuItemVec  children);
         StringMenuItem StringMenuItem_withChildren(StringMenuItem* restrict stringmenuitem, AzMenuItemVec  children);
         void StringMenuItem_delete(StringMenuItem* restrict instance);
+        bool  VirtualKeyCodeCombo_matches(const VirtualKeyCodeCombo* virtualkeycodecombo, AzKeyboardState  keyboard_state);
         void VirtualKeyCodeCombo_delete(VirtualKeyCodeCombo* restrict instance);
+        KeyboardShortcut KeyboardShortcut_new(AzVirtualKeyCodeCombo  keys, AzRefAny  data, AzCallbackType  callback);
+        void KeyboardShortcut_delete(KeyboardShortcut* restrict instance);
         MenuCallback MenuCallback_new(AzRefAny  data, AzCallbackType  callback);
         void MenuCallback_delete(MenuCallback* restrict instance);
         void MenuItemIcon_delete(MenuItemIcon* restrict instance);
@@ -10556,6 +12232,9 @@ namespace dll {
         Css Css_empty();
         Css Css_fromString(AzString  s);
         void Css_delete(Css* restrict instance);
+        void CssFontFace_delete(CssFontFace* restrict instance);
+        void CssFontFaceSource_delete(CssFontFaceSource* restrict instance);
+        void CssFontFaceUrl_delete(CssFontFaceUrl* restrict instance);
         ColorU ColorU_fromStr(AzString  string);
         ColorU ColorU_transparent();
         ColorU ColorU_white();
@@ -10688,6 +12367,8 @@ namespace dll {
         Frame Frame_withFlexGrow(Frame* restrict frame, float flex_grow);
         Dom Frame_dom(Frame* restrict frame);
         void Frame_delete(Frame* restrict instance);
+        NodeGraph NodeGraph_fromJson(AzString  json);
+        String NodeGraph_toJson(const NodeGraph* nodegraph);
         Dom NodeGraph_dom(NodeGraph* restrict nodegraph);
         void NodeGraph_delete(NodeGraph* restrict instance);
         void NodeTypeIdInfoMap_delete(NodeTypeIdInfoMap* restrict instance);
@@ -10702,6 +12383,9 @@ namespace dll {
         void NodeGraphOnNodeInputDisconnected_delete(NodeGraphOnNodeInputDisconnected* restrict instance);
         void NodeGraphOnNodeOutputDisconnected_delete(NodeGraphOnNodeOutputDisconnected* restrict instance);
         void NodeGraphOnNodeFieldEdited_delete(NodeGraphOnNodeFieldEdited* restrict instance);
+        void NodeGraphOnNodeGraphZoomed_delete(NodeGraphOnNodeGraphZoomed* restrict instance);
+        void NodeGraphOnSelectionChanged_delete(NodeGraphOnSelectionChanged* restrict instance);
+        void NodeGraphOnHistoryChanged_delete(NodeGraphOnHistoryChanged* restrict instance);
         void Node_delete(Node* restrict instance);
         void NodeTypeField_delete(NodeTypeField* restrict instance);
         void NodeTypeFieldValue_delete(NodeTypeFieldValue* restrict instance);
@@ -10709,8 +12393,50 @@ namespace dll {
         void OutputConnection_delete(OutputConnection* restrict instance);
         void NodeTypeInfo_delete(NodeTypeInfo* restrict instance);
         void InputOutputInfo_delete(InputOutputInfo* restrict instance);
+        void NodeGraphFieldEdit_delete(NodeGraphFieldEdit* restrict instance);
+        NodeGraphEditOp NodeGraphEditOp_invert(const NodeGraphEditOp* nodegrapheditop);
+        void NodeGraphEditOp_delete(NodeGraphEditOp* restrict instance);
+        void NodeGraphEdit_delete(NodeGraphEdit* restrict instance);
+        bool  NodeGraphHistory_canUndo(const NodeGraphHistory* nodegraphhistory);
+        bool  NodeGraphHistory_canRedo(const NodeGraphHistory* nodegraphhistory);
+        void NodeGraphHistory_delete(NodeGraphHistory* restrict instance);
         ListView ListView_new(AzStringVec  columns);
+        void ListView_setColumns(ListView* restrict listview, AzStringVec  columns);
+        ListView ListView_withColumns(ListView* restrict listview, AzStringVec  columns);
+        void ListView_setRows(ListView* restrict listview, AzListViewRowVec  rows);
         ListView ListView_withRows(ListView* restrict listview, AzListViewRowVec  rows);
+        void ListView_setSortedBy(ListView* restrict listview, AzOptionUsize  sorted_by);
+        ListView ListView_withSortedBy(ListView* restrict listview, AzOptionUsize  sorted_by);
+        void ListView_setSortDescending(ListView* restrict listview, bool  sort_descending);
+        ListView ListView_withSortDescending(ListView* restrict listview, bool  sort_descending);
+        void ListView_setScrollOffset(ListView* restrict listview, AzPixelValueNoPercent  scroll_offset);
+        ListView ListView_withScrollOffset(ListView* restrict listview, AzPixelValueNoPercent  scroll_offset);
+        void ListView_setContentHeight(ListView* restrict listview, AzPixelValueNoPercent  content_height);
+        ListView ListView_withContentHeight(ListView* restrict listview, AzPixelValueNoPercent  content_height);
+        void ListView_setColumnContextMenu(ListView* restrict listview, AzMenu  context_menu);
+        ListView ListView_withColumnContextMenu(ListView* restrict listview, AzMenu  context_menu);
+        void ListView_setRowHeight(ListView* restrict listview, float row_height);
+        ListView ListView_withRowHeight(ListView* restrict listview, float row_height);
+        void ListView_setFirstVisibleRow(ListView* restrict listview, size_t first_visible_row);
+        ListView ListView_withFirstVisibleRow(ListView* restrict listview, size_t first_visible_row);
+        void ListView_setColumnWidths(ListView* restrict listview, AzF32Vec  column_widths);
+        ListView ListView_withColumnWidths(ListView* restrict listview, AzF32Vec  column_widths);
+        void ListView_setSelectionState(ListView* restrict listview, AzOptionUsize  focused_row, AzOptionUsize  selection_anchor);
+        ListView ListView_withSelectionState(ListView* restrict listview, AzOptionUsize  focused_row, AzOptionUsize  selection_anchor);
+        void ListView_setRowProvider(ListView* restrict listview, size_t row_count, AzRefAny  data, AzListViewRowProviderCallbackType  callback);
+        ListView ListView_withRowProvider(ListView* restrict listview, size_t row_count, AzRefAny  data, AzListViewRowProviderCallbackType  callback);
+        void ListView_setOnLazyLoadScroll(ListView* restrict listview, AzRefAny  data, AzListViewOnLazyLoadScrollCallbackType  callback);
+        ListView ListView_withOnLazyLoadScroll(ListView* restrict listview, AzRefAny  data, AzListViewOnLazyLoadScrollCallbackType  callback);
+        void ListView_setOnColumnClick(ListView* restrict listview, AzRefAny  data, AzListViewOnColumnClickCallbackType  callback);
+        ListView ListView_withOnColumnClick(ListView* restrict listview, AzRefAny  data, AzListViewOnColumnClickCallbackType  callback);
+        void ListView_setOnRowClick(ListView* restrict listview, AzRefAny  data, AzListViewOnRowClickCallbackType  callback);
+        ListView ListView_withOnRowClick(ListView* restrict listview, AzRefAny  data, AzListViewOnRowClickCallbackType  callback);
+        void ListView_setOnRowSelect(ListView* restrict listview, AzRefAny  data, AzListViewOnRowSelectCallbackType  callback);
+        ListView ListView_withOnRowSelect(ListView* restrict listview, AzRefAny  data, AzListViewOnRowSelectCallbackType  callback);
+        void ListView_setOnColumnResize(ListView* restrict listview, AzRefAny  data, AzListViewOnColumnResizeCallbackType  callback);
+        ListView ListView_withOnColumnResize(ListView* restrict listview, AzRefAny  data, AzListViewOnColumnResizeCallbackType  callback);
+        size_t ListView_getTotalRowCount(ListView* restrict listview);
+        void ListView_applySelection(ListView* restrict listview, size_t row_id, AzListViewSelectionChange  change);
         Dom ListView_dom(ListView* restrict listview);
         void ListView_delete(ListView* restrict instance);
         void ListViewRow_delete(ListViewRow* restrict instance);
@@ -10718,9 +12444,174 @@ namespace dll {
         void ListViewOnLazyLoadScroll_delete(ListViewOnLazyLoadScroll* restrict instance);
         void ListViewOnColumnClick_delete(ListViewOnColumnClick* restrict instance);
         void ListViewOnRowClick_delete(ListViewOnRowClick* restrict instance);
-        TreeView TreeView_new(AzString  root);
+        void ListViewOnRowSelect_delete(ListViewOnRowSelect* restrict instance);
+        void ListViewOnColumnResize_delete(ListViewOnColumnResize* restrict instance);
+        void ListViewRowProvider_delete(ListViewRowProvider* restrict instance);
+        TreeView TreeView_new(AzTreeViewItemVec  items);
+        void TreeView_setItems(TreeView* restrict treeview, AzTreeViewItemVec  items);
+        TreeView TreeView_withItems(TreeView* restrict treeview, AzTreeViewItemVec  items);
+        void TreeView_setSelectionMode(TreeView* restrict treeview, AzTreeViewSelectionMode  selection_mode);
+        TreeView TreeView_withSelectionMode(TreeView* restrict treeview, AzTreeViewSelectionMode  selection_mode);
+        void TreeView_setFirstVisibleRow(TreeView* restrict treeview, size_t first_visible_row);
+        void TreeView_setMaxVisibleRows(TreeView* restrict treeview, size_t max_visible_rows);
+        void TreeView_setOnExpand(TreeView* restrict treeview, AzRefAny  data, AzTreeViewOnExpandCallbackType  callback);
+        TreeView TreeView_withOnExpand(TreeView* restrict treeview, AzRefAny  data, AzTreeViewOnExpandCallbackType  callback);
+        void TreeView_setOnSelect(TreeView* restrict treeview, AzRefAny  data, AzTreeViewOnSelectCallbackType  callback);
+        TreeView TreeView_withOnSelect(TreeView* restrict treeview, AzRefAny  data, AzTreeViewOnSelectCallbackType  callback);
+        void TreeView_setOnLazyLoad(TreeView* restrict treeview, AzRefAny  data, AzTreeViewOnLazyLoadCallbackType  callback);
+        TreeView TreeView_withOnLazyLoad(TreeView* restrict treeview, AzRefAny  data, AzTreeViewOnLazyLoadCallbackType  callback);
+        void TreeView_setOnScroll(TreeView* restrict treeview, AzRefAny  data, AzTreeViewOnScrollCallbackType  callback);
+        TreeView TreeView_withOnScroll(TreeView* restrict treeview, AzRefAny  data, AzTreeViewOnScrollCallbackType  callback);
+        size_t TreeView_getVisibleRowCount(const TreeView* treeview);
+        bool  TreeView_setExpanded(TreeView* restrict treeview, size_t item_id, bool  expanded);
+        bool  TreeView_setChildren(TreeView* restrict treeview, size_t item_id, AzTreeViewItemVec  children);
+        void TreeView_applySelection(TreeView* restrict treeview, size_t item_id, AzTreeViewSelectionChange  change);
         Dom TreeView_dom(TreeView* restrict treeview);
         void TreeView_delete(TreeView* restrict instance);
+        TreeViewItem TreeViewItem_new(size_t id, AzString  label);
+        void TreeViewItem_delete(TreeViewItem* restrict instance);
+        void TreeViewOnExpand_delete(TreeViewOnExpand* restrict instance);
+        void TreeViewOnSelect_delete(TreeViewOnSelect* restrict instance);
+        void TreeViewOnLazyLoad_delete(TreeViewOnLazyLoad* restrict instance);
+        void TreeViewOnScroll_delete(TreeViewOnScroll* restrict instance);
+        DataGrid DataGrid_new(size_t row_count, size_t column_count);
+        void DataGrid_setColumnHeaders(DataGrid* restrict datagrid, AzStringVec  column_headers);
+        DataGrid DataGrid_withColumnHeaders(DataGrid* restrict datagrid, AzStringVec  column_headers);
+        void DataGrid_setColumnWidths(DataGrid* restrict datagrid, AzF32Vec  column_widths);
+        DataGrid DataGrid_withColumnWidths(DataGrid* restrict datagrid, AzF32Vec  column_widths);
+        void DataGrid_setRowHeights(DataGrid* restrict datagrid, AzF32Vec  row_heights);
+        DataGrid DataGrid_withRowHeights(DataGrid* restrict datagrid, AzF32Vec  row_heights);
+        void DataGrid_setScrollPosition(DataGrid* restrict datagrid, size_t first_visible_row, size_t first_visible_column);
+        DataGrid DataGrid_withScrollPosition(DataGrid* restrict datagrid, size_t first_visible_row, size_t first_visible_column);
+        void DataGrid_setSelection(DataGrid* restrict datagrid, AzOptionDataGridRange  selection);
+        DataGrid DataGrid_withSelection(DataGrid* restrict datagrid, AzOptionDataGridRange  selection);
+        void DataGrid_setCellProvider(DataGrid* restrict datagrid, AzRefAny  data, AzDataGridCellProviderCallbackType  callback);
+        DataGrid DataGrid_withCellProvider(DataGrid* restrict datagrid, AzRefAny  data, AzDataGridCellProviderCallbackType  callback);
+        void DataGrid_setCellStyle(DataGrid* restrict datagrid, AzRefAny  data, AzDataGridCellStyleCallbackType  callback);
+        DataGrid DataGrid_withCellStyle(DataGrid* restrict datagrid, AzRefAny  data, AzDataGridCellStyleCallbackType  callback);
+        void DataGrid_setOnSelect(DataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnSelectCallbackType  callback);
+        DataGrid DataGrid_withOnSelect(DataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnSelectCallbackType  callback);
+        void DataGrid_setOnScroll(DataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnScrollCallbackType  callback);
+        DataGrid DataGrid_withOnScroll(DataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnScrollCallbackType  callback);
+        void DataGrid_setOnCellEdit(DataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnCellEditCallbackType  callback);
+        DataGrid DataGrid_withOnCellEdit(DataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnCellEditCallbackType  callback);
+        void DataGrid_setOnColumnResize(DataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnColumnResizeCallbackType  callback);
+        DataGrid DataGrid_withOnColumnResize(DataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnColumnResizeCallbackType  callback);
+        void DataGrid_setOnRowResize(DataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnRowResizeCallbackType  callback);
+        DataGrid DataGrid_withOnRowResize(DataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnRowResizeCallbackType  callback);
+        void DataGrid_setOnCopyRange(DataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnCopyRangeCallbackType  callback);
+        DataGrid DataGrid_withOnCopyRange(DataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnCopyRangeCallbackType  callback);
+        void DataGrid_setOnClearRange(DataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnClearRangeCallbackType  callback);
+        DataGrid DataGrid_withOnClearRange(DataGrid* restrict datagrid, AzRefAny  data, AzDataGridOnClearRangeCallbackType  callback);
+        float DataGrid_getColumnWidth(const DataGrid* datagrid, size_t column);
+        float DataGrid_getRowHeight(const DataGrid* datagrid, size_t row);
+        String DataGrid_getColumnName(const DataGrid* datagrid, size_t column);
+        String DataGrid_getCellText(DataGrid* restrict datagrid, size_t row, size_t column);
+        String DataGrid_getSelectionTsv(DataGrid* restrict datagrid);
+        OptionDataGridRange DataGrid_getClippedSelection(const DataGrid* datagrid);
+        Dom DataGrid_dom(DataGrid* restrict datagrid);
+        void DataGrid_delete(DataGrid* restrict instance);
+        DataGridRange DataGridRange_new(size_t row, size_t column);
+        DataGridCell DataGridRange_getTopLeft(const DataGridRange* datagridrange);
+        DataGridCell DataGridRange_getBottomRight(const DataGridRange* datagridrange);
+        bool  DataGridRange_contains(const DataGridRange* datagridrange, size_t row, size_t column);
+        size_t DataGridRange_getCellCount(const DataGridRange* datagridrange);
+        void DataGridCellProvider_delete(DataGridCellProvider* restrict instance);
+        void DataGridCellStyle_delete(DataGridCellStyle* restrict instance);
+        void DataGridOnSelect_delete(DataGridOnSelect* restrict instance);
+        void DataGridOnScroll_delete(DataGridOnScroll* restrict instance);
+        void DataGridOnCellEdit_delete(DataGridOnCellEdit* restrict instance);
+        void DataGridOnColumnResize_delete(DataGridOnColumnResize* restrict instance);
+        void DataGridOnRowResize_delete(DataGridOnRowResize* restrict instance);
+        void DataGridOnCopyRange_delete(DataGridOnCopyRange* restrict instance);
+        void DataGridOnClearRange_delete(DataGridOnClearRange* restrict instance);
+        Date Date_new(int32_t year, uint8_t month, uint8_t day);
+        Date Date_today();
+        bool  Date_isValid(const Date* date);
+        Weekday Date_weekday(const Date* date);
+        Date Date_addDays(const Date* date, int64_t days);
+        Date Date_addMonths(const Date* date, int64_t months);
+        Date Date_clampTo(const Date* date, AzOptionDate  minimum, AzOptionDate  maximum);
+        Time Time_new(uint8_t hour, uint8_t minute, uint8_t second);
+        bool  Time_isValid(const Time* time);
+        void DatePickerOnDateChange_delete(DatePickerOnDateChange* restrict instance);
+        DatePicker DatePicker_new(AzDate  date);
+        void DatePicker_setState(DatePicker* restrict datepicker, AzDatePickerState  state);
+        DatePicker DatePicker_withState(DatePicker* restrict datepicker, AzDatePickerState  state);
+        void DatePicker_setMinimumDate(DatePicker* restrict datepicker, AzOptionDate  minimum_date);
+        DatePicker DatePicker_withMinimumDate(DatePicker* restrict datepicker, AzOptionDate  minimum_date);
+        void DatePicker_setMaximumDate(DatePicker* restrict datepicker, AzOptionDate  maximum_date);
+        DatePicker DatePicker_withMaximumDate(DatePicker* restrict datepicker, AzOptionDate  maximum_date);
+        void DatePicker_setFirstDayOfWeek(DatePicker* restrict datepicker, AzWeekday  first_day_of_week);
+        DatePicker DatePicker_withFirstDayOfWeek(DatePicker* restrict datepicker, AzWeekday  first_day_of_week);
+        void DatePicker_setOnDateChange(DatePicker* restrict datepicker, AzRefAny  data, AzDatePickerOnDateChangeCallbackType  callback);
+        DatePicker DatePicker_withOnDateChange(DatePicker* restrict datepicker, AzRefAny  data, AzDatePickerOnDateChangeCallbackType  callback);
+        Dom DatePicker_dom(DatePicker* restrict datepicker);
+        void DatePicker_delete(DatePicker* restrict instance);
+        void TimePickerOnTimeChange_delete(TimePickerOnTimeChange* restrict instance);
+        TimePicker TimePicker_new(AzTime  time);
+        void TimePicker_setShowSeconds(TimePicker* restrict timepicker, bool  show_seconds);
+        TimePicker TimePicker_withShowSeconds(TimePicker* restrict timepicker, bool  show_seconds);
+        void TimePicker_setOnTimeChange(TimePicker* restrict timepicker, AzRefAny  data, AzTimePickerOnTimeChangeCallbackType  callback);
+        TimePicker TimePicker_withOnTimeChange(TimePicker* restrict timepicker, AzRefAny  data, AzTimePickerOnTimeChangeCallbackType  callback);
+        Dom TimePicker_dom(TimePicker* restrict timepicker);
+        void TimePicker_delete(TimePicker* restrict instance);
+        void DateTimePickerOnDateTimeChange_delete(DateTimePickerOnDateTimeChange* restrict instance);
+        DateTimePicker DateTimePicker_new(AzDate  date);
+        void DateTimePicker_setState(DateTimePicker* restrict datetimepicker, AzDateTimePickerState  state);
+        DateTimePicker DateTimePicker_withState(DateTimePicker* restrict datetimepicker, AzDateTimePickerState  state);
+        void DateTimePicker_setMinimumDate(DateTimePicker* restrict datetimepicker, AzOptionDate  minimum_date);
+        DateTimePicker DateTimePicker_withMinimumDate(DateTimePicker* restrict datetimepicker, AzOptionDate  minimum_date);
+        void DateTimePicker_setMaximumDate(DateTimePicker* restrict datetimepicker, AzOptionDate  maximum_date);
+        DateTimePicker DateTimePicker_withMaximumDate(DateTimePicker* restrict datetimepicker, AzOptionDate  maximum_date);
+        void DateTimePicker_setFirstDayOfWeek(DateTimePicker* restrict datetimepicker, AzWeekday  first_day_of_week);
+        DateTimePicker DateTimePicker_withFirstDayOfWeek(DateTimePicker* restrict datetimepicker, AzWeekday  first_day_of_week);
+        void DateTimePicker_setShowTime(DateTimePicker* restrict datetimepicker, bool  show_time, bool  show_seconds);
+        DateTimePicker DateTimePicker_withShowTime(DateTimePicker* restrict datetimepicker, bool  show_time, bool  show_seconds);
+        void DateTimePicker_setOnDateTimeChange(DateTimePicker* restrict datetimepicker, AzRefAny  data, AzDateTimePickerOnDateTimeChangeCallbackType  callback);
+        DateTimePicker DateTimePicker_withOnDateTimeChange(DateTimePicker* restrict datetimepicker, AzRefAny  data, AzDateTimePickerOnDateTimeChangeCallbackType  callback);
+        Dom DateTimePicker_dom(DateTimePicker* restrict datetimepicker);
+        void DateTimePicker_delete(DateTimePicker* restrict instance);
+        bool  DialogResult_isAccepted(const DialogResult* dialogresult);
+        void DialogResult_delete(DialogResult* restrict instance);
+        void DialogOnClose_delete(DialogOnClose* restrict instance);
+        Dialog Dialog_msgBox(AzString  title, AzString  message);
+        Dialog Dialog_confirm(AzString  title, AzString  message);
+        Dialog Dialog_prompt(AzString  title, AzString  message, AzString  default_value);
+        Dialog Dialog_custom(AzString  title, AzDom  content);
+        void Dialog_setIcon(Dialog* restrict dialog, AzDialogIcon  icon);
+        Dialog Dialog_withIcon(Dialog* restrict dialog, AzDialogIcon  icon);
+        void Dialog_setButtons(Dialog* restrict dialog, AzDialogButtons  buttons);
+        Dialog Dialog_withButtons(Dialog* restrict dialog, AzDialogButtons  buttons);
+        void Dialog_setOnClose(Dialog* restrict dialog, AzRefAny  data, AzDialogOnCloseCallbackType  callback);
+        Dialog Dialog_withOnClose(Dialog* restrict dialog, AzRefAny  data, AzDialogOnCloseCallbackType  callback);
+        Dom Dialog_dom(Dialog* restrict dialog);
+        Dom Dialog_domOver(Dialog* restrict dialog, AzDom  content);
+        void Dialog_delete(Dialog* restrict instance);
+        void SplitterState_delete(SplitterState* restrict instance);
+        SplitterPane SplitterPane_new(AzDom  content);
+        void SplitterPane_setSize(SplitterPane* restrict splitterpane, float size);
+        SplitterPane SplitterPane_withSize(SplitterPane* restrict splitterpane, float size);
+        void SplitterPane_setMinSize(SplitterPane* restrict splitterpane, float min_size);
+        SplitterPane SplitterPane_withMinSize(SplitterPane* restrict splitterpane, float min_size);
+        void SplitterPane_setMaxSize(SplitterPane* restrict splitterpane, float max_size);
+        SplitterPane SplitterPane_withMaxSize(SplitterPane* restrict splitterpane, float max_size);
+        void SplitterPane_setCollapsed(SplitterPane* restrict splitterpane, bool  collapsed);
+        SplitterPane SplitterPane_withCollapsed(SplitterPane* restrict splitterpane, bool  collapsed);
+        void SplitterPane_delete(SplitterPane* restrict instance);
+        void SplitterOnResize_delete(SplitterOnResize* restrict instance);
+        Splitter Splitter_new(AzSplitterDirection  direction);
+        Splitter Splitter_withPane(Splitter* restrict splitter, AzSplitterPane  pane);
+        void Splitter_addPane(Splitter* restrict splitter, AzSplitterPane  pane);
+        void Splitter_setHandleSize(Splitter* restrict splitter, float handle_size);
+        Splitter Splitter_withHandleSize(Splitter* restrict splitter, float handle_size);
+        SplitterState Splitter_getState(const Splitter* splitter);
+        void Splitter_setState(Splitter* restrict splitter, AzSplitterState  state);
+        Splitter Splitter_withState(Splitter* restrict splitter, AzSplitterState  state);
+        void Splitter_setOnResize(Splitter* restrict splitter, AzRefAny  data, AzSplitterOnResizeCallbackType  callback);
+        Splitter Splitter_withOnResize(Splitter* restrict splitter, AzRefAny  data, AzSplitterOnResizeCallbackType  callback);
+        Dom Splitter_dom(Splitter* restrict splitter);
+        void Splitter_delete(Splitter* restrict instance);
         DropDown DropDown_new(AzStringVec  choices);
         Dom DropDown_dom(DropDown* restrict dropdown);
         void DropDown_delete(DropDown* restrict instance);
@@ -11050,6 +12941,8 @@ namespace dll {
         U8Vec FontRef_getBytes(const FontRef* fontref);
         FontMetrics FontRef_getFontMetrics(const FontRef* fontref);
         InlineText FontRef_shapeText(const FontRef* fontref, AzRefstr  text, AzResolvedTextLayoutOptions  options);
+        TextMetrics FontRef_measureText(const FontRef* fontref, AzRefstr  text, AzResolvedTextLayoutOptions  options);
+        SvgMultiPolygon FontRef_textToPath(const FontRef* fontref, AzRefstr  text, AzResolvedTextLayoutOptions  options);
         uint64_t FontRef_getHash(const FontRef* fontref);
         void FontRef_delete(FontRef* restrict instance);
         FontRef FontRef_deepCopy(FontRef* const instance);
@@ -11222,10 +13115,16 @@ namespace dll {
         Refstr String_asRefstr(const String* string);
         void String_delete(String* restrict instance);
         void ListViewRowVec_delete(ListViewRowVec* restrict instance);
+        void TreeViewItemVec_delete(TreeViewItemVec* restrict instance);
+        void SplitterPaneVec_delete(SplitterPaneVec* restrict instance);
+        void SplitterPaneStateVec_delete(SplitterPaneStateVec* restrict instance);
         void StyleFilterVec_delete(StyleFilterVec* restrict instance);
         void LogicalRectVec_delete(LogicalRectVec* restrict instance);
         void NodeTypeIdInfoMapVec_delete(NodeTypeIdInfoMapVec* restrict instance);
         void InputOutputTypeIdInfoMapVec_delete(InputOutputTypeIdInfoMapVec* restrict instance);
+        void NodeGraphNodeIdVec_delete(NodeGraphNodeIdVec* restrict instance);
+        void NodeGraphEditOpVec_delete(NodeGraphEditOpVec* restrict instance);
+        void NodeGraphEditVec_delete(NodeGraphEditVec* restrict instance);
         void NodeIdNodeMapVec_delete(NodeIdNodeMapVec* restrict instance);
         void InputOutputTypeIdVec_delete(InputOutputTypeIdVec* restrict instance);
         void NodeTypeFieldVec_delete(NodeTypeFieldVec* restrict instance);
@@ -11234,6 +13133,7 @@ namespace dll {
         void OutputConnectionVec_delete(OutputConnectionVec* restrict instance);
         void InputNodeAndIndexVec_delete(InputNodeAndIndexVec* restrict instance);
         void AccessibilityStateVec_delete(AccessibilityStateVec* restrict instance);
+        void KeyboardShortcutVec_delete(KeyboardShortcutVec* restrict instance);
         void MenuItemVec_delete(MenuItemVec* restrict instance);
         TessellatedSvgNodeVecRef TessellatedSvgNodeVec_asRefVec(const TessellatedSvgNodeVec* tessellatedsvgnodevec);
         void TessellatedSvgNodeVec_delete(TessellatedSvgNodeVec* restrict instance);
@@ -11245,6 +13145,8 @@ namespace dll {
         void InlineLineVec_delete(InlineLineVec* restrict instance);
         void InlineWordVec_delete(InlineWordVec* restrict instance);
         void InlineGlyphVec_delete(InlineGlyphVec* restrict instance);
+        void TextLineMetricsVec_delete(TextLineMetricsVec* restrict instance);
+        void InlineTextRunVec_delete(InlineTextRunVec* restrict instance);
         void InlineTextHitVec_delete(InlineTextHitVec* restrict instance);
         void MonitorVec_delete(MonitorVec* restrict instance);
         void VideoModeVec_delete(VideoModeVec* restrict instance);
@@ -11272,6 +13174,9 @@ namespace dll {
         void CssDeclarationVec_delete(CssDeclarationVec* restrict instance);
         void CssPathSelectorVec_delete(CssPathSelectorVec* restrict instance);
         void StylesheetVec_delete(StylesheetVec* restrict instance);
+        void CssFontFaceVec_delete(CssFontFaceVec* restrict instance);
+        void CssFontFaceSourceVec_delete(CssFontFaceSourceVec* restrict instance);
+        void CssUnicodeRangeVec_delete(CssUnicodeRangeVec* restrict instance);
         void CssRuleBlockVec_delete(CssRuleBlockVec* restrict instance);
         void U16Vec_delete(U16Vec* restrict instance);
         void F32Vec_delete(F32Vec* restrict instance);
@@ -11292,12 +13197,36 @@ namespace dll {
         void TagIdToNodeIdMappingVec_delete(TagIdToNodeIdMappingVec* restrict instance);
         void ParentWithNodeDepthVec_delete(ParentWithNodeDepthVec* restrict instance);
         void NodeDataVec_delete(NodeDataVec* restrict instance);
+        void OptionTreeViewOnExpand_delete(OptionTreeViewOnExpand* restrict instance);
+        void OptionTreeViewOnSelect_delete(OptionTreeViewOnSelect* restrict instance);
+        void OptionTreeViewOnLazyLoad_delete(OptionTreeViewOnLazyLoad* restrict instance);
+        void OptionTreeViewOnScroll_delete(OptionTreeViewOnScroll* restrict instance);
         void OptionListViewOnRowClick_delete(OptionListViewOnRowClick* restrict instance);
+        void OptionListViewOnRowSelect_delete(OptionListViewOnRowSelect* restrict instance);
+        void OptionListViewOnColumnResize_delete(OptionListViewOnColumnResize* restrict instance);
+        void OptionListViewRowProvider_delete(OptionListViewRowProvider* restrict instance);
+        void OptionDataGridCellProvider_delete(OptionDataGridCellProvider* restrict instance);
+        void OptionDataGridCellStyle_delete(OptionDataGridCellStyle* restrict instance);
+        void OptionDataGridOnSelect_delete(OptionDataGridOnSelect* restrict instance);
+        void OptionDataGridOnScroll_delete(OptionDataGridOnScroll* restrict instance);
+        void OptionDataGridOnCellEdit_delete(OptionDataGridOnCellEdit* restrict instance);
+        void OptionDataGridOnColumnResize_delete(OptionDataGridOnColumnResize* restrict instance);
+        void OptionDataGridOnRowResize_delete(OptionDataGridOnRowResize* restrict instance);
+        void OptionDataGridOnCopyRange_delete(OptionDataGridOnCopyRange* restrict instance);
+        void OptionDataGridOnClearRange_delete(OptionDataGridOnClearRange* restrict instance);
+        void OptionDatePickerOnDateChange_delete(OptionDatePickerOnDateChange* restrict instance);
+        void OptionTimePickerOnTimeChange_delete(OptionTimePickerOnTimeChange* restrict instance);
+        void OptionDateTimePickerOnDateTimeChange_delete(OptionDateTimePickerOnDateTimeChange* restrict instance);
+        void OptionDialogOnClose_delete(OptionDialogOnClose* restrict instance);
+        void OptionSplitterOnResize_delete(OptionSplitterOnResize* restrict instance);
         void OptionListViewOnColumnClick_delete(OptionListViewOnColumnClick* restrict instance);
         void OptionListViewOnLazyLoadScroll_delete(OptionListViewOnLazyLoadScroll* restrict instance);
         void OptionMenu_delete(OptionMenu* restrict instance);
         void OptionDropDownOnChoiceChange_delete(OptionDropDownOnChoiceChange* restrict instance);
         void OptionResolvedTextLayoutOptions_delete(OptionResolvedTextLayoutOptions* restrict instance);
+        void OptionNodeGraphOnNodeGraphZoomed_delete(OptionNodeGraphOnNodeGraphZoomed* restrict instance);
+        void OptionNodeGraphOnSelectionChanged_delete(OptionNodeGraphOnSelectionChanged* restrict instance);
+        void OptionNodeGraphOnHistoryChanged_delete(OptionNodeGraphOnHistoryChanged* restrict instance);
         void OptionNodeGraphOnNodeAdded_delete(OptionNodeGraphOnNodeAdded* restrict instance);
         void OptionNodeGraphOnNodeRemoved_delete(OptionNodeGraphOnNodeRemoved* restrict instance);
         void OptionNodeGraphOnNodeGraphDragged_delete(OptionNodeGraphOnNodeGraphDragged* restrict instance);
@@ -11333,6 +13262,7 @@ namespace dll {
         void OptionThreadSendMsg_delete(OptionThreadSendMsg* restrict instance);
         void OptionRefAny_delete(OptionRefAny* restrict instance);
         void OptionInlineText_delete(OptionInlineText* restrict instance);
+        void OptionTextMetrics_delete(OptionTextMetrics* restrict instance);
         void OptionRawImage_delete(OptionRawImage* restrict instance);
         void OptionWaylandTheme_delete(OptionWaylandTheme* restrict instance);
         void OptionTaskBarIcon_delete(OptionTaskBarIcon* restrict instance);
@@ -11347,6 +13277,7 @@ namespace dll {
         void ResultRawImageDecodeImageError_delete(ResultRawImageDecodeImageError* restrict instance);
         void ResultU8VecEncodeImageError_delete(ResultU8VecEncodeImageError* restrict instance);
         void ResultSvgXmlNodeSvgParseError_delete(ResultSvgXmlNodeSvgParseError* restrict instance);
+        void ResultNodeGraphNodeGraphError_delete(ResultNodeGraphNodeGraphError* restrict instance);
         void ResultSvgSvgParseError_delete(ResultSvgSvgParseError* restrict instance);
         void SvgParseError_delete(SvgParseError* restrict instance);
         void XmlError_delete(XmlError* restrict instance);
@@ -508,6 +508,52 @@ mod dll {
     impl ::core::fmt::Debug for AzListViewOnColumnClickCallback             { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
     
     impl ::core::fmt::Debug for AzListViewOnLazyLoadScrollCallback          { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    
+    impl ::core::fmt::Debug for AzSplitterOnResizeCallback                { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    
+    impl ::core::fmt::Debug for AzDialogOnCloseCallback                   { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    
+    impl ::core::fmt::Debug for AzDatePickerOnDateChangeCallback          { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    
+    impl ::core::fmt::Debug for AzTimePickerOnTimeChangeCallback          { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    
+    impl ::core::fmt::Debug for AzDateTimePickerOnDateTimeChangeCallback  { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    
+    impl ::core::fmt::Debug for AzNodeGraphOnNodeGraphZoomedCallback      { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    
+    impl ::core::fmt::Debug for AzNodeGraphOnSelectionChangedCallback     { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    
+    impl ::core::fmt::Debug for AzNodeGraphOnHistoryChangedCallback       { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    
+    impl ::core::fmt::Debug for AzDataGridCellProviderCallback            { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    
+    impl ::core::fmt::Debug for AzDataGridCellStyleCallback               { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    
+    impl ::core::fmt::Debug for AzDataGridOnSelectCallback                { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    
+    impl ::core::fmt::Debug for AzDataGridOnScrollCallback                { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    
+    impl ::core::fmt::Debug for AzDataGridOnCellEditCallback              { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    
+    impl ::core::fmt::Debug for AzDataGridOnColumnResizeCallback          { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    
+    impl ::core::fmt::Debug for AzDataGridOnRowResizeCallback             { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    impl ::core::fmt::Debug for AzDataGridOnCopyRangeCallback             { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    impl ::core::fmt::Debug for AzDataGridOnClearRangeCallback             { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    
+    impl ::core::fmt::Debug for AzListViewOnRowSelectCallback             { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    
+    impl ::core::fmt::Debug for AzListViewOnColumnResizeCallback          { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    
+    impl ::core::fmt::Debug for AzListViewRowProviderCallback             { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    
+    impl ::core::fmt::Debug for AzTreeViewOnExpandCallback                { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    
+    impl ::core::fmt::Debug for AzTreeViewOnSelectCallback                { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    
+    impl ::core::fmt::Debug for AzTreeViewOnLazyLoadCallback              { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    
+    impl ::core::fmt::Debug for AzTreeViewOnScrollCallback                { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
 
     
     impl PartialEq for AzCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
@@ -590,6 +636,52 @@ mod dll {
     impl PartialEq for AzListViewOnColumnClickCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
     
     impl PartialEq for AzListViewOnRowClickCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    
+    impl PartialEq for AzSplitterOnResizeCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    
+    impl PartialEq for AzDialogOnCloseCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    
+    impl PartialEq for AzDatePickerOnDateChangeCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    
+    impl PartialEq for AzTimePickerOnTimeChangeCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    
+    impl PartialEq for AzDateTimePickerOnDateTimeChangeCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    
+    impl PartialEq for AzNodeGraphOnNodeGraphZoomedCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    
+    impl PartialEq for AzNodeGraphOnSelectionChangedCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    
+    impl PartialEq for AzNodeGraphOnHistoryChangedCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    
+    impl PartialEq for AzDataGridCellProviderCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    
+    impl PartialEq for AzDataGridCellStyleCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    
+    impl PartialEq for AzDataGridOnSelectCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    
+    impl PartialEq for AzDataGridOnScrollCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    
+    impl PartialEq for AzDataGridOnCellEditCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    
+    impl PartialEq for AzDataGridOnColumnResizeCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    
+    impl PartialEq for AzDataGridOnRowResizeCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    impl PartialEq for AzDataGridOnCopyRangeCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    impl PartialEq for AzDataGridOnClearRangeCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    
+    impl PartialEq for AzListViewOnRowSelectCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    
+    impl PartialEq for AzListViewOnColumnResizeCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    
+    impl PartialEq for AzListViewRowProviderCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    
+    impl PartialEq for AzTreeViewOnExpandCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    
+    impl PartialEq for AzTreeViewOnSelectCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    
+    impl PartialEq for AzTreeViewOnLazyLoadCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    
+    impl PartialEq for AzTreeViewOnScrollCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
 
     
     impl PartialOrd for AzCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
@@ -672,6 +764,52 @@ mod dll {
     impl PartialOrd for AzListViewOnColumnClickCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
     
     impl PartialOrd for AzListViewOnRowClickCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
+    
+    impl PartialOrd for AzSplitterOnResizeCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
+    
+    impl PartialOrd for AzDialogOnCloseCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
+    
+    impl PartialOrd for AzDatePickerOnDateChangeCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
+    
+    impl PartialOrd for AzTimePickerOnTimeChangeCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
+    
+    impl PartialOrd for AzDateTimePickerOnDateTimeChangeCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
+    
+    impl PartialOrd for AzNodeGraphOnNodeGraphZoomedCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
+    
+    impl PartialOrd for AzNodeGraphOnSelectionChangedCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
+    
+    impl PartialOrd for AzNodeGraphOnHistoryChangedCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
+    
+    impl PartialOrd for AzDataGridCellProviderCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
+    
+    impl PartialOrd for AzDataGridCellStyleCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
+    
+    impl PartialOrd for AzDataGridOnSelectCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
+    
+    impl PartialOrd for AzDataGridOnScrollCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
+    
+    impl PartialOrd for AzDataGridOnCellEditCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
+    
+    impl PartialOrd for AzDataGridOnColumnResizeCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
+    
+    impl PartialOrd for AzDataGridOnRowResizeCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
+    impl PartialOrd for AzDataGridOnCopyRangeCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
+    impl PartialOrd for AzDataGridOnClearRangeCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
+    
+    impl PartialOrd for AzListViewOnRowSelectCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
+    
+    impl PartialOrd for AzListViewOnColumnResizeCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
+    
+    impl PartialOrd for AzListViewRowProviderCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
+    
+    impl PartialOrd for AzTreeViewOnExpandCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
+    
+    impl PartialOrd for AzTreeViewOnSelectCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
+    
+    impl PartialOrd for AzTreeViewOnLazyLoadCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
+    
+    impl PartialOrd for AzTreeViewOnScrollCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
 
     #[cfg(not(feature = "link-static"))]
     pub use self::dynamic_link::*;
@@ -1796,6 +1934,29 @@ mod dll {
             pub offset: u32,
         }
 
+        /// Re-export of rust-allocated (stack based) `CssFontFaceStyle` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzCssFontFaceStyle {
+            Normal,
+            Italic,
+            Oblique,
+        }
+
+        /// `U+0000-00FF`: inclusive range of unicode code points
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzCssUnicodeRange {
+            pub start: u32,
+            pub end: u32,
+        }
+
         /// Re-export of rust-allocated (stack based) `CssPropertyType` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -1877,6 +2038,8 @@ mod dll {
             Filter,
             BackdropFilter,
             TextShadow,
+            VerticalAlign,
+            TextDecoration,
         }
 
         /// Re-export of rust-allocated (stack based) `ColorU` struct
@@ -2018,6 +2181,7 @@ mod dll {
             Flex,
             Block,
             InlineBlock,
+            Inline,
         }
 
         /// Re-export of rust-allocated (stack based) `LayoutFloat` struct
@@ -2059,6 +2223,21 @@ mod dll {
             Fixed,
         }
 
+        /// Re-export of rust-allocated (stack based) `LayoutVerticalAlign` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzLayoutVerticalAlign {
+            Baseline,
+            Top,
+            Middle,
+            Bottom,
+            Sub,
+            Super,
+        }
+
         /// Re-export of rust-allocated (stack based) `LayoutFlexWrap` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -2248,6 +2427,19 @@ mod dll {
             Right,
         }
 
+        /// Re-export of rust-allocated (stack based) `StyleTextDecoration` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleTextDecoration {
+            None,
+            Underline,
+            Overline,
+            LineThrough,
+        }
+
         /// Re-export of rust-allocated (stack based) `Ribbon` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -2508,6 +2700,36 @@ mod dll {
             pub cb: AzNodeGraphOnNodeFieldEditedCallbackType,
         }
 
+        /// `AzNodeGraphOnNodeGraphZoomedCallbackType` struct
+        pub type AzNodeGraphOnNodeGraphZoomedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, f32, AzLogicalPosition) -> AzUpdate;
+
+        /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeGraphZoomedCallback` struct
+        #[repr(C)]
+        #[derive(Clone)]
+        pub struct AzNodeGraphOnNodeGraphZoomedCallback {
+            pub cb: AzNodeGraphOnNodeGraphZoomedCallbackType,
+        }
+
+        /// `AzNodeGraphOnSelectionChangedCallbackType` struct
+        pub type AzNodeGraphOnSelectionChangedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphNodeIdVec) -> AzUpdate;
+
+        /// Re-export of rust-allocated (stack based) `NodeGraphOnSelectionChangedCallback` struct
+        #[repr(C)]
+        #[derive(Clone)]
+        pub struct AzNodeGraphOnSelectionChangedCallback {
+            pub cb: AzNodeGraphOnSelectionChangedCallbackType,
+        }
+
+        /// `AzNodeGraphOnHistoryChangedCallbackType` struct
+        pub type AzNodeGraphOnHistoryChangedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphHistory) -> AzUpdate;
+
+        /// Re-export of rust-allocated (stack based) `NodeGraphOnHistoryChangedCallback` struct
+        #[repr(C)]
+        #[derive(Clone)]
+        pub struct AzNodeGraphOnHistoryChangedCallback {
+            pub cb: AzNodeGraphOnHistoryChangedCallbackType,
+        }
+
         /// Re-export of rust-allocated (stack based) `InputOutputTypeId` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -2589,6 +2811,32 @@ mod dll {
             pub y: f32,
         }
 
+        /// Re-export of rust-allocated (stack based) `NodeGraphError` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzNodeGraphError {
+            NodeMimeTypeMismatch,
+            NodeInvalidIndex,
+            NodeInvalidNode,
+            NoRootNode,
+            InvalidJson,
+        }
+
+        /// How the selection should change in response to a user action
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzListViewSelectionChange {
+            Replace,
+            Toggle,
+            Extend,
+        }
+
         /// `AzListViewOnLazyLoadScrollCallbackType` struct
         pub type AzListViewOnLazyLoadScrollCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzListViewState) -> AzUpdate;
 
@@ -2619,190 +2867,525 @@ mod dll {
             pub cb: AzListViewOnRowClickCallbackType,
         }
 
-        /// `AzDropDownOnChoiceChangeCallbackType` struct
-        pub type AzDropDownOnChoiceChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize) -> AzUpdate;
+        /// `AzListViewOnRowSelectCallbackType` struct
+        pub type AzListViewOnRowSelectCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzListViewState, usize, AzListViewSelectionChange) -> AzUpdate;
 
-        /// Re-export of rust-allocated (stack based) `DropDownOnChoiceChangeCallback` struct
+        /// Re-export of rust-allocated (stack based) `ListViewOnRowSelectCallback` struct
         #[repr(C)]
         #[derive(Clone)]
-        pub struct AzDropDownOnChoiceChangeCallback {
-            pub cb: AzDropDownOnChoiceChangeCallbackType,
+        pub struct AzListViewOnRowSelectCallback {
+            pub cb: AzListViewOnRowSelectCallbackType,
         }
 
-        /// Re-export of rust-allocated (stack based) `NodeHierarchyItem` struct
+        /// `AzListViewOnColumnResizeCallbackType` struct
+        pub type AzListViewOnColumnResizeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzListViewState, usize, f32) -> AzUpdate;
+
+        /// Re-export of rust-allocated (stack based) `ListViewOnColumnResizeCallback` struct
         #[repr(C)]
-        #[derive(Debug)]
         #[derive(Clone)]
-        #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
-        pub struct AzNodeHierarchyItem {
-            pub parent: usize,
-            pub previous_sibling: usize,
-            pub next_sibling: usize,
-            pub last_child: usize,
+        pub struct AzListViewOnColumnResizeCallback {
+            pub cb: AzListViewOnColumnResizeCallbackType,
         }
 
-        /// Re-export of rust-allocated (stack based) `CascadeInfo` struct
+        /// `AzListViewRowProviderCallbackType` struct
+        pub type AzListViewRowProviderCallbackType = extern "C" fn(&mut AzRefAny, usize, usize) -> AzListViewRowVec;
+
+        /// Re-export of rust-allocated (stack based) `ListViewRowProviderCallback` struct
         #[repr(C)]
-        #[derive(Debug)]
         #[derive(Clone)]
-        #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
-        pub struct AzCascadeInfo {
-            pub index_in_parent: u32,
-            pub is_last_child: bool,
+        pub struct AzListViewRowProviderCallback {
+            pub cb: AzListViewRowProviderCallbackType,
         }
 
-        /// Re-export of rust-allocated (stack based) `StyledNodeState` struct
+        /// Re-export of rust-allocated (stack based) `TreeViewSelectionMode` struct
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub struct AzStyledNodeState {
-            pub normal: bool,
-            pub hover: bool,
-            pub active: bool,
-            pub focused: bool,
+        pub enum AzTreeViewSelectionMode {
+            Single,
+            Multiple,
         }
 
-        /// Re-export of rust-allocated (stack based) `TagId` struct
+        /// Re-export of rust-allocated (stack based) `TreeViewSelectionChange` struct
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub struct AzTagId {
-            pub inner: u64,
+        pub enum AzTreeViewSelectionChange {
+            Replace,
+            Toggle,
+            Extend,
         }
 
-        /// Re-export of rust-allocated (stack based) `CssPropertyCache` struct
+        /// `AzTreeViewOnExpandCallbackType` struct
+        pub type AzTreeViewOnExpandCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, bool) -> AzUpdate;
+
+        /// Re-export of rust-allocated (stack based) `TreeViewOnExpandCallback` struct
         #[repr(C)]
-        #[derive(Debug)]
-        #[derive(PartialEq, PartialOrd)]
-        pub struct AzCssPropertyCache {
-            pub(crate) ptr: *mut c_void,
-            pub run_destructor: bool,
+        #[derive(Clone)]
+        pub struct AzTreeViewOnExpandCallback {
+            pub cb: AzTreeViewOnExpandCallbackType,
         }
 
-        /// Re-export of rust-allocated (stack based) `GlVoidPtrConst` struct
+        /// `AzTreeViewOnSelectCallbackType` struct
+        pub type AzTreeViewOnSelectCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, AzTreeViewSelectionChange) -> AzUpdate;
+
+        /// Re-export of rust-allocated (stack based) `TreeViewOnSelectCallback` struct
         #[repr(C)]
-        #[derive(Debug)]
-        #[derive(PartialEq, PartialOrd)]
-        pub struct AzGlVoidPtrConst {
-            pub(crate) ptr: *const c_void,
-            pub run_destructor: bool,
+        #[derive(Clone)]
+        pub struct AzTreeViewOnSelectCallback {
+            pub cb: AzTreeViewOnSelectCallbackType,
         }
 
-        /// Re-export of rust-allocated (stack based) `GlVoidPtrMut` struct
+        /// `AzTreeViewOnLazyLoadCallbackType` struct
+        pub type AzTreeViewOnLazyLoadCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize) -> AzUpdate;
+
+        /// Re-export of rust-allocated (stack based) `TreeViewOnLazyLoadCallback` struct
         #[repr(C)]
-        #[derive(Debug)]
         #[derive(Clone)]
-        #[derive(PartialEq, PartialOrd)]
-        pub struct AzGlVoidPtrMut {
-            pub(crate) ptr: *mut c_void,
+        pub struct AzTreeViewOnLazyLoadCallback {
+            pub cb: AzTreeViewOnLazyLoadCallbackType,
         }
 
-        /// Re-export of rust-allocated (stack based) `GlShaderPrecisionFormatReturn` struct
+        /// `AzTreeViewOnScrollCallbackType` struct
+        pub type AzTreeViewOnScrollCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize) -> AzUpdate;
+
+        /// Re-export of rust-allocated (stack based) `TreeViewOnScrollCallback` struct
         #[repr(C)]
-        #[derive(Debug)]
         #[derive(Clone)]
-        #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
-        pub struct AzGlShaderPrecisionFormatReturn {
-            pub _0: i32,
-            pub _1: i32,
-            pub _2: i32,
+        pub struct AzTreeViewOnScrollCallback {
+            pub cb: AzTreeViewOnScrollCallbackType,
         }
 
-        /// Re-export of rust-allocated (stack based) `VertexAttributeType` struct
+        /// Position of a cell in the DataGrid
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzVertexAttributeType {
-            Float,
-            Double,
-            UnsignedByte,
-            UnsignedShort,
-            UnsignedInt,
+        pub struct AzDataGridCell {
+            pub row: usize,
+            pub column: usize,
         }
 
-        /// Re-export of rust-allocated (stack based) `IndexBufferFormat` struct
+        /// `AzDataGridCellProviderCallbackType` struct
+        pub type AzDataGridCellProviderCallbackType = extern "C" fn(&mut AzRefAny, usize, usize) -> AzString;
+
+        /// Re-export of rust-allocated (stack based) `DataGridCellProviderCallback` struct
         #[repr(C)]
-        #[derive(Debug)]
         #[derive(Clone)]
-        #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
-        pub enum AzIndexBufferFormat {
-            Points,
-            Lines,
-            LineStrip,
-            Triangles,
-            TriangleStrip,
-            TriangleFan,
+        pub struct AzDataGridCellProviderCallback {
+            pub cb: AzDataGridCellProviderCallbackType,
         }
 
-        /// Re-export of rust-allocated (stack based) `GlType` struct
+        /// `AzDataGridCellStyleCallbackType` struct
+        pub type AzDataGridCellStyleCallbackType = extern "C" fn(&mut AzRefAny, usize, usize, bool) -> AzNodeDataInlineCssPropertyVec;
+
+        /// Re-export of rust-allocated (stack based) `DataGridCellStyleCallback` struct
         #[repr(C)]
-        #[derive(Debug)]
         #[derive(Clone)]
-        #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
-        pub enum AzGlType {
-            Gl,
-            Gles,
+        pub struct AzDataGridCellStyleCallback {
+            pub cb: AzDataGridCellStyleCallbackType,
         }
 
-        /// C-ABI stable reexport of `&[u8]`
+        /// `AzDataGridOnSelectCallbackType` struct
+        pub type AzDataGridOnSelectCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzDataGridRange) -> AzUpdate;
+
+        /// Re-export of rust-allocated (stack based) `DataGridOnSelectCallback` struct
         #[repr(C)]
-        pub struct AzU8VecRef {
-            pub(crate) ptr: *const u8,
-            pub len: usize,
+        #[derive(Clone)]
+        pub struct AzDataGridOnSelectCallback {
+            pub cb: AzDataGridOnSelectCallbackType,
         }
 
-        /// C-ABI stable reexport of `&mut [u8]`
+        /// `AzDataGridOnScrollCallbackType` struct
+        pub type AzDataGridOnScrollCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, usize) -> AzUpdate;
+
+        /// Re-export of rust-allocated (stack based) `DataGridOnScrollCallback` struct
         #[repr(C)]
-        #[derive(Debug)]
         #[derive(Clone)]
-        #[derive(PartialEq, PartialOrd)]
-        pub struct AzU8VecRefMut {
-            pub(crate) ptr: *mut u8,
-            pub len: usize,
+        pub struct AzDataGridOnScrollCallback {
+            pub cb: AzDataGridOnScrollCallbackType,
         }
 
-        /// C-ABI stable reexport of `&[f32]`
+        /// `AzDataGridOnCellEditCallbackType` struct
+        pub type AzDataGridOnCellEditCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, usize, AzString) -> AzUpdate;
+
+        /// Re-export of rust-allocated (stack based) `DataGridOnCellEditCallback` struct
         #[repr(C)]
-        #[derive(Debug)]
         #[derive(Clone)]
-        #[derive(PartialEq, PartialOrd)]
-        pub struct AzF32VecRef {
-            pub(crate) ptr: *const f32,
-            pub len: usize,
+        pub struct AzDataGridOnCellEditCallback {
+            pub cb: AzDataGridOnCellEditCallbackType,
         }
 
-        /// C-ABI stable reexport of `&[i32]`
+        /// `AzDataGridOnColumnResizeCallbackType` struct
+        pub type AzDataGridOnColumnResizeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, f32) -> AzUpdate;
+
+        /// Re-export of rust-allocated (stack based) `DataGridOnColumnResizeCallback` struct
         #[repr(C)]
-        #[derive(Debug)]
         #[derive(Clone)]
-        #[derive(PartialEq, PartialOrd)]
-        pub struct AzI32VecRef {
-            pub(crate) ptr: *const i32,
-            pub len: usize,
+        pub struct AzDataGridOnColumnResizeCallback {
+            pub cb: AzDataGridOnColumnResizeCallbackType,
         }
 
-        /// C-ABI stable reexport of `&[GLuint]` aka `&[u32]`
+        /// `AzDataGridOnRowResizeCallbackType` struct
+        pub type AzDataGridOnRowResizeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, f32) -> AzUpdate;
+
+        /// Re-export of rust-allocated (stack based) `DataGridOnRowResizeCallback` struct
         #[repr(C)]
-        #[derive(Debug)]
         #[derive(Clone)]
-        #[derive(PartialEq, PartialOrd)]
-        pub struct AzGLuintVecRef {
-            pub(crate) ptr: *const u32,
-            pub len: usize,
+        pub struct AzDataGridOnRowResizeCallback {
+            pub cb: AzDataGridOnRowResizeCallbackType,
         }
 
-        /// C-ABI stable reexport of `&[GLenum]` aka `&[u32]`
+        /// `AzDataGridOnCopyRangeCallbackType` struct
+        pub type AzDataGridOnCopyRangeCallbackType = extern "C" fn(&mut AzRefAny, AzDataGridRange) -> AzString;
+
+        /// Re-export of rust-allocated (stack based) `DataGridOnCopyRangeCallback` struct
+        #[repr(C)]
+        #[derive(Clone)]
+        pub struct AzDataGridOnCopyRangeCallback {
+            pub cb: AzDataGridOnCopyRangeCallbackType,
+        }
+
+        /// `AzDataGridOnClearRangeCallbackType` struct
+        pub type AzDataGridOnClearRangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzDataGridRange) -> AzUpdate;
+
+        /// Re-export of rust-allocated (stack based) `DataGridOnClearRangeCallback` struct
+        #[repr(C)]
+        #[derive(Clone)]
+        pub struct AzDataGridOnClearRangeCallback {
+            pub cb: AzDataGridOnClearRangeCallbackType,
+        }
+
+        /// Calendar date (proleptic Gregorian calendar), not validated on construction
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzDate {
+            pub year: i32,
+            pub month: u8,
+            pub day: u8,
+        }
+
+        /// Day of the week
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzWeekday {
+            Monday,
+            Tuesday,
+            Wednesday,
+            Thursday,
+            Friday,
+            Saturday,
+            Sunday,
+        }
+
+        /// Time of day, not validated on construction
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzTime {
+            pub hour: u8,
+            pub minute: u8,
+            pub second: u8,
+        }
+
+        /// `AzDatePickerOnDateChangeCallbackType` struct
+        pub type AzDatePickerOnDateChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzDatePickerState) -> AzUpdate;
+
+        /// Re-export of rust-allocated (stack based) `DatePickerOnDateChangeCallback` struct
+        #[repr(C)]
+        #[derive(Clone)]
+        pub struct AzDatePickerOnDateChangeCallback {
+            pub cb: AzDatePickerOnDateChangeCallbackType,
+        }
+
+        /// `AzTimePickerOnTimeChangeCallbackType` struct
+        pub type AzTimePickerOnTimeChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTime) -> AzUpdate;
+
+        /// Re-export of rust-allocated (stack based) `TimePickerOnTimeChangeCallback` struct
+        #[repr(C)]
+        #[derive(Clone)]
+        pub struct AzTimePickerOnTimeChangeCallback {
+            pub cb: AzTimePickerOnTimeChangeCallbackType,
+        }
+
+        /// `AzDateTimePickerOnDateTimeChangeCallbackType` struct
+        pub type AzDateTimePickerOnDateTimeChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzDateTimePickerState) -> AzUpdate;
+
+        /// Re-export of rust-allocated (stack based) `DateTimePickerOnDateTimeChangeCallback` struct
+        #[repr(C)]
+        #[derive(Clone)]
+        pub struct AzDateTimePickerOnDateTimeChangeCallback {
+            pub cb: AzDateTimePickerOnDateTimeChangeCallbackType,
+        }
+
+        /// Icon shown left of the message of a `Dialog`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzDialogIcon {
+            None,
+            Info,
+            Warning,
+            Error,
+            Question,
+        }
+
+        /// Set of buttons shown at the bottom of a `Dialog`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzDialogButtons {
+            Ok,
+            OkCancel,
+            YesNo,
+            YesNoCancel,
+        }
+
+        /// Button that closed a `Dialog`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzDialogButton {
+            Ok,
+            Cancel,
+            Yes,
+            No,
+        }
+
+        /// `AzDialogOnCloseCallbackType` struct
+        pub type AzDialogOnCloseCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzDialogResult) -> AzUpdate;
+
+        /// Re-export of rust-allocated (stack based) `DialogOnCloseCallback` struct
+        #[repr(C)]
+        #[derive(Clone)]
+        pub struct AzDialogOnCloseCallback {
+            pub cb: AzDialogOnCloseCallbackType,
+        }
+
+        /// Direction in which the panes of a `Splitter` are laid out
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzSplitterDirection {
+            Horizontal,
+            Vertical,
+        }
+
+        /// `AzSplitterOnResizeCallbackType` struct
+        pub type AzSplitterOnResizeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzSplitterState) -> AzUpdate;
+
+        /// Re-export of rust-allocated (stack based) `SplitterOnResizeCallback` struct
+        #[repr(C)]
+        #[derive(Clone)]
+        pub struct AzSplitterOnResizeCallback {
+            pub cb: AzSplitterOnResizeCallbackType,
+        }
+
+        /// `AzDropDownOnChoiceChangeCallbackType` struct
+        pub type AzDropDownOnChoiceChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize) -> AzUpdate;
+
+        /// Re-export of rust-allocated (stack based) `DropDownOnChoiceChangeCallback` struct
+        #[repr(C)]
+        #[derive(Clone)]
+        pub struct AzDropDownOnChoiceChangeCallback {
+            pub cb: AzDropDownOnChoiceChangeCallbackType,
+        }
+
+        /// Re-export of rust-allocated (stack based) `NodeHierarchyItem` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzNodeHierarchyItem {
+            pub parent: usize,
+            pub previous_sibling: usize,
+            pub next_sibling: usize,
+            pub last_child: usize,
+        }
+
+        /// Re-export of rust-allocated (stack based) `CascadeInfo` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzCascadeInfo {
+            pub index_in_parent: u32,
+            pub is_last_child: bool,
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyledNodeState` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzStyledNodeState {
+            pub normal: bool,
+            pub hover: bool,
+            pub active: bool,
+            pub focused: bool,
+        }
+
+        /// Re-export of rust-allocated (stack based) `TagId` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzTagId {
+            pub inner: u64,
+        }
+
+        /// Re-export of rust-allocated (stack based) `CssPropertyCache` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzCssPropertyCache {
+            pub(crate) ptr: *mut c_void,
+            pub run_destructor: bool,
+        }
+
+        /// Re-export of rust-allocated (stack based) `GlVoidPtrConst` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzGlVoidPtrConst {
+            pub(crate) ptr: *const c_void,
+            pub run_destructor: bool,
+        }
+
+        /// Re-export of rust-allocated (stack based) `GlVoidPtrMut` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzGlVoidPtrMut {
+            pub(crate) ptr: *mut c_void,
+        }
+
+        /// Re-export of rust-allocated (stack based) `GlShaderPrecisionFormatReturn` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzGlShaderPrecisionFormatReturn {
+            pub _0: i32,
+            pub _1: i32,
+            pub _2: i32,
+        }
+
+        /// Re-export of rust-allocated (stack based) `VertexAttributeType` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzVertexAttributeType {
+            Float,
+            Double,
+            UnsignedByte,
+            UnsignedShort,
+            UnsignedInt,
+        }
+
+        /// Re-export of rust-allocated (stack based) `IndexBufferFormat` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzIndexBufferFormat {
+            Points,
+            Lines,
+            LineStrip,
+            Triangles,
+            TriangleStrip,
+            TriangleFan,
+        }
+
+        /// Re-export of rust-allocated (stack based) `GlType` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzGlType {
+            Gl,
+            Gles,
+        }
+
+        /// C-ABI stable reexport of `&[u8]`
+        #[repr(C)]
+        pub struct AzU8VecRef {
+            pub(crate) ptr: *const u8,
+            pub len: usize,
+        }
+
+        /// C-ABI stable reexport of `&mut [u8]`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzU8VecRefMut {
+            pub(crate) ptr: *mut u8,
+            pub len: usize,
+        }
+
+        /// C-ABI stable reexport of `&[f32]`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzF32VecRef {
+            pub(crate) ptr: *const f32,
+            pub len: usize,
+        }
+
+        /// C-ABI stable reexport of `&[i32]`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzI32VecRef {
+            pub(crate) ptr: *const i32,
+            pub len: usize,
+        }
+
+        /// C-ABI stable reexport of `&[GLuint]` aka `&[u32]`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzGLuintVecRef {
+            pub(crate) ptr: *const u32,
+            pub len: usize,
+        }
+
+        /// C-ABI stable reexport of `&[GLenum]` aka `&[u32]`
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
@@ -3511,6 +4094,45 @@ mod dll {
         /// `AzListViewRowVecDestructorType` struct
         pub type AzListViewRowVecDestructorType = extern "C" fn(&mut AzListViewRowVec);
 
+        /// Re-export of rust-allocated (stack based) `TreeViewItemVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzTreeViewItemVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzTreeViewItemVecDestructorType),
+        }
+
+        /// `AzTreeViewItemVecDestructorType` struct
+        pub type AzTreeViewItemVecDestructorType = extern "C" fn(&mut AzTreeViewItemVec);
+
+        /// Re-export of rust-allocated (stack based) `SplitterPaneVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzSplitterPaneVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzSplitterPaneVecDestructorType),
+        }
+
+        /// `AzSplitterPaneVecDestructorType` struct
+        pub type AzSplitterPaneVecDestructorType = extern "C" fn(&mut AzSplitterPaneVec);
+
+        /// Re-export of rust-allocated (stack based) `SplitterPaneStateVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzSplitterPaneStateVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzSplitterPaneStateVecDestructorType),
+        }
+
+        /// `AzSplitterPaneStateVecDestructorType` struct
+        pub type AzSplitterPaneStateVecDestructorType = extern "C" fn(&mut AzSplitterPaneStateVec);
+
         /// Re-export of rust-allocated (stack based) `StyleFilterVecDestructor` struct
         #[repr(C, u8)]
         #[derive(Clone)]
@@ -3563,6 +4185,45 @@ mod dll {
         /// `AzInputOutputTypeIdInfoMapVecDestructorType` struct
         pub type AzInputOutputTypeIdInfoMapVecDestructorType = extern "C" fn(&mut AzInputOutputTypeIdInfoMapVec);
 
+        /// Re-export of rust-allocated (stack based) `NodeGraphNodeIdVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzNodeGraphNodeIdVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzNodeGraphNodeIdVecDestructorType),
+        }
+
+        /// `AzNodeGraphNodeIdVecDestructorType` struct
+        pub type AzNodeGraphNodeIdVecDestructorType = extern "C" fn(&mut AzNodeGraphNodeIdVec);
+
+        /// Re-export of rust-allocated (stack based) `NodeGraphEditOpVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzNodeGraphEditOpVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzNodeGraphEditOpVecDestructorType),
+        }
+
+        /// `AzNodeGraphEditOpVecDestructorType` struct
+        pub type AzNodeGraphEditOpVecDestructorType = extern "C" fn(&mut AzNodeGraphEditOpVec);
+
+        /// Re-export of rust-allocated (stack based) `NodeGraphEditVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzNodeGraphEditVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzNodeGraphEditVecDestructorType),
+        }
+
+        /// `AzNodeGraphEditVecDestructorType` struct
+        pub type AzNodeGraphEditVecDestructorType = extern "C" fn(&mut AzNodeGraphEditVec);
+
         /// Re-export of rust-allocated (stack based) `NodeIdNodeMapVecDestructor` struct
         #[repr(C, u8)]
         #[derive(Clone)]
@@ -3667,6 +4328,19 @@ mod dll {
         /// `AzAccessibilityStateVecDestructorType` struct
         pub type AzAccessibilityStateVecDestructorType = extern "C" fn(&mut AzAccessibilityStateVec);
 
+        /// Re-export of rust-allocated (stack based) `KeyboardShortcutVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzKeyboardShortcutVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzKeyboardShortcutVecDestructorType),
+        }
+
+        /// `AzKeyboardShortcutVecDestructorType` struct
+        pub type AzKeyboardShortcutVecDestructorType = extern "C" fn(&mut AzKeyboardShortcutVec);
+
         /// Re-export of rust-allocated (stack based) `MenuItemVecDestructor` struct
         #[repr(C, u8)]
         #[derive(Clone)]
@@ -3771,6 +4445,32 @@ mod dll {
         /// `AzInlineGlyphVecDestructorType` struct
         pub type AzInlineGlyphVecDestructorType = extern "C" fn(&mut AzInlineGlyphVec);
 
+        /// Re-export of rust-allocated (stack based) `TextLineMetricsVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzTextLineMetricsVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzTextLineMetricsVecDestructorType),
+        }
+
+        /// `AzTextLineMetricsVecDestructorType` struct
+        pub type AzTextLineMetricsVecDestructorType = extern "C" fn(&mut AzTextLineMetricsVec);
+
+        /// Re-export of rust-allocated (stack based) `InlineTextRunVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzInlineTextRunVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzInlineTextRunVecDestructorType),
+        }
+
+        /// `AzInlineTextRunVecDestructorType` struct
+        pub type AzInlineTextRunVecDestructorType = extern "C" fn(&mut AzInlineTextRunVec);
+
         /// Re-export of rust-allocated (stack based) `InlineTextHitVecDestructor` struct
         #[repr(C, u8)]
         #[derive(Clone)]
@@ -4122,6 +4822,45 @@ mod dll {
         /// `AzStylesheetVecDestructorType` struct
         pub type AzStylesheetVecDestructorType = extern "C" fn(&mut AzStylesheetVec);
 
+        /// Re-export of rust-allocated (stack based) `CssFontFaceVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzCssFontFaceVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzCssFontFaceVecDestructorType),
+        }
+
+        /// `AzCssFontFaceVecDestructorType` struct
+        pub type AzCssFontFaceVecDestructorType = extern "C" fn(&mut AzCssFontFaceVec);
+
+        /// Re-export of rust-allocated (stack based) `CssFontFaceSourceVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzCssFontFaceSourceVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzCssFontFaceSourceVecDestructorType),
+        }
+
+        /// `AzCssFontFaceSourceVecDestructorType` struct
+        pub type AzCssFontFaceSourceVecDestructorType = extern "C" fn(&mut AzCssFontFaceSourceVec);
+
+        /// Re-export of rust-allocated (stack based) `CssUnicodeRangeVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzCssUnicodeRangeVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzCssUnicodeRangeVecDestructorType),
+        }
+
+        /// `AzCssUnicodeRangeVecDestructorType` struct
+        pub type AzCssUnicodeRangeVecDestructorType = extern "C" fn(&mut AzCssUnicodeRangeVec);
+
         /// Re-export of rust-allocated (stack based) `CssRuleBlockVecDestructor` struct
         #[repr(C, u8)]
         #[derive(Clone)]
@@ -4648,6 +5387,31 @@ mod dll {
             pub hidpi_factor: f32,
         }
 
+        /// Metrics of a single line of a measured text
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzTextLineMetrics {
+            pub bounds: AzLogicalRect,
+            pub baseline_px: f32,
+            pub glyph_count: usize,
+        }
+
+        /// Re-export of rust-allocated (stack based) `InlineTextRun` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzInlineTextRun {
+            pub node_id: AzNodeId,
+            pub glyph_start: usize,
+            pub glyph_end: usize,
+            pub font_size_px: f32,
+        }
+
         /// Re-export of rust-allocated (stack based) `InlineGlyph` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -5925,21 +6689,35 @@ mod dll {
             None,
             Inherit,
             Initial,
-            Exact(AzLayoutRight),
+            Exact(AzLayoutRight),
+        }
+
+        /// Re-export of rust-allocated (stack based) `LayoutTopValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzLayoutTopValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzLayoutTop),
         }
 
-        /// Re-export of rust-allocated (stack based) `LayoutTopValue` struct
+        /// Re-export of rust-allocated (stack based) `LayoutVerticalAlignValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzLayoutTopValue {
+        pub enum AzLayoutVerticalAlignValue {
             Auto,
             None,
             Inherit,
             Initial,
-            Exact(AzLayoutTop),
+            Exact(AzLayoutVerticalAlign),
         }
 
         /// Re-export of rust-allocated (stack based) `LayoutWidthValue` struct
@@ -6292,6 +7070,20 @@ mod dll {
             Exact(AzStyleTextAlign),
         }
 
+        /// Re-export of rust-allocated (stack based) `StyleTextDecorationValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleTextDecorationValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStyleTextDecoration),
+        }
+
         /// Re-export of rust-allocated (stack based) `StyleTextColorValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -6524,129 +7316,418 @@ mod dll {
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        pub struct AzNodeGraphOnNodeAdded {
+        pub struct AzNodeGraphOnNodeAdded {
+            pub data: AzRefAny,
+            pub callback: AzNodeGraphOnNodeAddedCallback,
+        }
+
+        /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeRemoved` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzNodeGraphOnNodeRemoved {
+            pub data: AzRefAny,
+            pub callback: AzNodeGraphOnNodeRemovedCallback,
+        }
+
+        /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeGraphDragged` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzNodeGraphOnNodeGraphDragged {
+            pub data: AzRefAny,
+            pub callback: AzNodeGraphOnNodeGraphDraggedCallback,
+        }
+
+        /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeDragged` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzNodeGraphOnNodeDragged {
+            pub data: AzRefAny,
+            pub callback: AzNodeGraphOnNodeDraggedCallback,
+        }
+
+        /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeConnected` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzNodeGraphOnNodeConnected {
+            pub data: AzRefAny,
+            pub callback: AzNodeGraphOnNodeConnectedCallback,
+        }
+
+        /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeInputDisconnected` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzNodeGraphOnNodeInputDisconnected {
+            pub data: AzRefAny,
+            pub callback: AzNodeGraphOnNodeInputDisconnectedCallback,
+        }
+
+        /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeOutputDisconnected` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzNodeGraphOnNodeOutputDisconnected {
+            pub data: AzRefAny,
+            pub callback: AzNodeGraphOnNodeOutputDisconnectedCallback,
+        }
+
+        /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeFieldEdited` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzNodeGraphOnNodeFieldEdited {
+            pub data: AzRefAny,
+            pub callback: AzNodeGraphOnNodeFieldEditedCallback,
+        }
+
+        /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeGraphZoomed` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzNodeGraphOnNodeGraphZoomed {
+            pub data: AzRefAny,
+            pub callback: AzNodeGraphOnNodeGraphZoomedCallback,
+        }
+
+        /// Re-export of rust-allocated (stack based) `NodeGraphOnSelectionChanged` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzNodeGraphOnSelectionChanged {
+            pub data: AzRefAny,
+            pub callback: AzNodeGraphOnSelectionChangedCallback,
+        }
+
+        /// Re-export of rust-allocated (stack based) `NodeGraphOnHistoryChanged` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzNodeGraphOnHistoryChanged {
+            pub data: AzRefAny,
+            pub callback: AzNodeGraphOnHistoryChangedCallback,
+        }
+
+        /// Re-export of rust-allocated (stack based) `OutputNodeAndIndex` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzOutputNodeAndIndex {
+            pub node_id: AzNodeGraphNodeId,
+            pub output_index: usize,
+        }
+
+        /// Re-export of rust-allocated (stack based) `InputNodeAndIndex` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzInputNodeAndIndex {
+            pub node_id: AzNodeGraphNodeId,
+            pub input_index: usize,
+        }
+
+        /// Connection from the output of one node to the input of another node
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        #[derive(Eq)]
+        #[derive(Ord)]
+        #[derive(Hash)]
+        pub struct AzNodeGraphConnection {
+            pub output: AzNodeGraphNodeId,
+            pub output_index: usize,
+            pub input: AzNodeGraphNodeId,
+            pub input_index: usize,
+        }
+
+        /// Node that was moved by `amount` (in graph coordinates)
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzNodeGraphNodeMove {
+            pub node_id: AzNodeGraphNodeId,
+            pub amount: AzNodeDragAmount,
+        }
+
+        /// Re-export of rust-allocated (stack based) `ListViewOnLazyLoadScroll` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzListViewOnLazyLoadScroll {
+            pub data: AzRefAny,
+            pub callback: AzListViewOnLazyLoadScrollCallback,
+        }
+
+        /// Re-export of rust-allocated (stack based) `ListViewOnColumnClick` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzListViewOnColumnClick {
+            pub data: AzRefAny,
+            pub callback: AzListViewOnColumnClickCallback,
+        }
+
+        /// Re-export of rust-allocated (stack based) `ListViewOnRowClick` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzListViewOnRowClick {
+            pub data: AzRefAny,
+            pub callback: AzListViewOnRowClickCallback,
+        }
+
+        /// Re-export of rust-allocated (stack based) `ListViewOnRowSelect` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzListViewOnRowSelect {
+            pub data: AzRefAny,
+            pub callback: AzListViewOnRowSelectCallback,
+        }
+
+        /// Re-export of rust-allocated (stack based) `ListViewOnColumnResize` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzListViewOnColumnResize {
+            pub data: AzRefAny,
+            pub callback: AzListViewOnColumnResizeCallback,
+        }
+
+        /// Re-export of rust-allocated (stack based) `ListViewRowProvider` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzListViewRowProvider {
+            pub data: AzRefAny,
+            pub callback: AzListViewRowProviderCallback,
+        }
+
+        /// Re-export of rust-allocated (stack based) `TreeViewOnExpand` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzTreeViewOnExpand {
+            pub data: AzRefAny,
+            pub callback: AzTreeViewOnExpandCallback,
+        }
+
+        /// Re-export of rust-allocated (stack based) `TreeViewOnSelect` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzTreeViewOnSelect {
+            pub data: AzRefAny,
+            pub callback: AzTreeViewOnSelectCallback,
+        }
+
+        /// Re-export of rust-allocated (stack based) `TreeViewOnLazyLoad` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzTreeViewOnLazyLoad {
+            pub data: AzRefAny,
+            pub callback: AzTreeViewOnLazyLoadCallback,
+        }
+
+        /// Re-export of rust-allocated (stack based) `TreeViewOnScroll` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzTreeViewOnScroll {
+            pub data: AzRefAny,
+            pub callback: AzTreeViewOnScrollCallback,
+        }
+
+        /// Rectangular range of selected cells
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzDataGridRange {
+            pub anchor: AzDataGridCell,
+            pub cursor: AzDataGridCell,
+        }
+
+        /// Re-export of rust-allocated (stack based) `DataGridCellProvider` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzDataGridCellProvider {
+            pub data: AzRefAny,
+            pub callback: AzDataGridCellProviderCallback,
+        }
+
+        /// Re-export of rust-allocated (stack based) `DataGridCellStyle` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzDataGridCellStyle {
+            pub data: AzRefAny,
+            pub callback: AzDataGridCellStyleCallback,
+        }
+
+        /// Re-export of rust-allocated (stack based) `DataGridOnSelect` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzDataGridOnSelect {
             pub data: AzRefAny,
-            pub callback: AzNodeGraphOnNodeAddedCallback,
+            pub callback: AzDataGridOnSelectCallback,
         }
 
-        /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeRemoved` struct
+        /// Re-export of rust-allocated (stack based) `DataGridOnScroll` struct
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        pub struct AzNodeGraphOnNodeRemoved {
+        pub struct AzDataGridOnScroll {
             pub data: AzRefAny,
-            pub callback: AzNodeGraphOnNodeRemovedCallback,
+            pub callback: AzDataGridOnScrollCallback,
         }
 
-        /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeGraphDragged` struct
+        /// Re-export of rust-allocated (stack based) `DataGridOnCellEdit` struct
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        pub struct AzNodeGraphOnNodeGraphDragged {
+        pub struct AzDataGridOnCellEdit {
             pub data: AzRefAny,
-            pub callback: AzNodeGraphOnNodeGraphDraggedCallback,
+            pub callback: AzDataGridOnCellEditCallback,
         }
 
-        /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeDragged` struct
+        /// Re-export of rust-allocated (stack based) `DataGridOnColumnResize` struct
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        pub struct AzNodeGraphOnNodeDragged {
+        pub struct AzDataGridOnColumnResize {
             pub data: AzRefAny,
-            pub callback: AzNodeGraphOnNodeDraggedCallback,
+            pub callback: AzDataGridOnColumnResizeCallback,
         }
 
-        /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeConnected` struct
+        /// Re-export of rust-allocated (stack based) `DataGridOnRowResize` struct
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        pub struct AzNodeGraphOnNodeConnected {
+        pub struct AzDataGridOnRowResize {
             pub data: AzRefAny,
-            pub callback: AzNodeGraphOnNodeConnectedCallback,
+            pub callback: AzDataGridOnRowResizeCallback,
         }
 
-        /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeInputDisconnected` struct
+        /// Re-export of rust-allocated (stack based) `DataGridOnCopyRange` struct
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        pub struct AzNodeGraphOnNodeInputDisconnected {
+        pub struct AzDataGridOnCopyRange {
             pub data: AzRefAny,
-            pub callback: AzNodeGraphOnNodeInputDisconnectedCallback,
+            pub callback: AzDataGridOnCopyRangeCallback,
         }
 
-        /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeOutputDisconnected` struct
+        /// Re-export of rust-allocated (stack based) `DataGridOnClearRange` struct
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        pub struct AzNodeGraphOnNodeOutputDisconnected {
+        pub struct AzDataGridOnClearRange {
             pub data: AzRefAny,
-            pub callback: AzNodeGraphOnNodeOutputDisconnectedCallback,
+            pub callback: AzDataGridOnClearRangeCallback,
         }
 
-        /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeFieldEdited` struct
+        /// Re-export of rust-allocated (stack based) `DatePickerOnDateChange` struct
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        pub struct AzNodeGraphOnNodeFieldEdited {
+        pub struct AzDatePickerOnDateChange {
             pub data: AzRefAny,
-            pub callback: AzNodeGraphOnNodeFieldEditedCallback,
+            pub callback: AzDatePickerOnDateChangeCallback,
         }
 
-        /// Re-export of rust-allocated (stack based) `OutputNodeAndIndex` struct
+        /// Re-export of rust-allocated (stack based) `TimePickerOnTimeChange` struct
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        pub struct AzOutputNodeAndIndex {
-            pub node_id: AzNodeGraphNodeId,
-            pub output_index: usize,
+        pub struct AzTimePickerOnTimeChange {
+            pub data: AzRefAny,
+            pub callback: AzTimePickerOnTimeChangeCallback,
         }
 
-        /// Re-export of rust-allocated (stack based) `InputNodeAndIndex` struct
+        /// Re-export of rust-allocated (stack based) `DateTimePickerOnDateTimeChange` struct
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        pub struct AzInputNodeAndIndex {
-            pub node_id: AzNodeGraphNodeId,
-            pub input_index: usize,
+        pub struct AzDateTimePickerOnDateTimeChange {
+            pub data: AzRefAny,
+            pub callback: AzDateTimePickerOnDateTimeChangeCallback,
         }
 
-        /// Re-export of rust-allocated (stack based) `ListViewOnLazyLoadScroll` struct
+        /// Re-export of rust-allocated (stack based) `DialogOnClose` struct
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        pub struct AzListViewOnLazyLoadScroll {
+        pub struct AzDialogOnClose {
             pub data: AzRefAny,
-            pub callback: AzListViewOnLazyLoadScrollCallback,
+            pub callback: AzDialogOnCloseCallback,
         }
 
-        /// Re-export of rust-allocated (stack based) `ListViewOnColumnClick` struct
+        /// Size of a single pane, as reported by `on_resize`
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        pub struct AzListViewOnColumnClick {
-            pub data: AzRefAny,
-            pub callback: AzListViewOnColumnClickCallback,
+        #[derive(Copy)]
+        pub struct AzSplitterPaneState {
+            pub size: AzOptionF32,
+            pub collapsed: bool,
         }
 
-        /// Re-export of rust-allocated (stack based) `ListViewOnRowClick` struct
+        /// Re-export of rust-allocated (stack based) `SplitterOnResize` struct
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        pub struct AzListViewOnRowClick {
+        pub struct AzSplitterOnResize {
             pub data: AzRefAny,
-            pub callback: AzListViewOnRowClickCallback,
+            pub callback: AzSplitterOnResizeCallback,
         }
 
         /// Re-export of rust-allocated (stack based) `DropDownOnChoiceChange` struct
@@ -6830,6 +7911,19 @@ mod dll {
             pub high_quality_aa: bool,
         }
 
+        /// Location of the start tag of an `XmlNode` in the XML source
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzXmlSpan {
+            pub file: usize,
+            pub start: usize,
+            pub end: usize,
+            pub pos: AzSvgParseErrorPosition,
+        }
+
         /// Re-export of rust-allocated (stack based) `InstantPtr` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -6873,6 +7967,24 @@ mod dll {
             pub callback: AzWriteBackCallback,
         }
 
+        /// Wrapper over a Rust-allocated `Vec<TreeViewItem>`
+        #[repr(C)]
+        pub struct AzTreeViewItemVec {
+            pub(crate) ptr: *const AzTreeViewItem,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzTreeViewItemVecDestructor,
+        }
+
+        /// Wrapper over a Rust-allocated `Vec<SplitterPaneState>`
+        #[repr(C)]
+        pub struct AzSplitterPaneStateVec {
+            pub(crate) ptr: *const AzSplitterPaneState,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzSplitterPaneStateVecDestructor,
+        }
+
         /// Wrapper over a Rust-allocated `Vec<LogicalRect>`
         #[repr(C)]
         pub struct AzLogicalRectVec {
@@ -6882,6 +7994,15 @@ mod dll {
             pub destructor: AzLogicalRectVecDestructor,
         }
 
+        /// Wrapper over a Rust-allocated `Vec<NodeGraphNodeId>`
+        #[repr(C)]
+        pub struct AzNodeGraphNodeIdVec {
+            pub(crate) ptr: *const AzNodeGraphNodeId,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzNodeGraphNodeIdVecDestructor,
+        }
+
         /// Wrapper over a Rust-allocated `Vec<InputOutputTypeId>`
         #[repr(C)]
         pub struct AzInputOutputTypeIdVec {
@@ -6945,6 +8066,24 @@ mod dll {
             pub destructor: AzInlineGlyphVecDestructor,
         }
 
+        /// Wrapper over a Rust-allocated `Vec<TextLineMetrics>`
+        #[repr(C)]
+        pub struct AzTextLineMetricsVec {
+            pub(crate) ptr: *const AzTextLineMetrics,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzTextLineMetricsVecDestructor,
+        }
+
+        /// Wrapper over a Rust-allocated `Vec<InlineTextRun>`
+        #[repr(C)]
+        pub struct AzInlineTextRunVec {
+            pub(crate) ptr: *const AzInlineTextRun,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzInlineTextRunVecDestructor,
+        }
+
         /// Wrapper over a Rust-allocated `Vec<InlineTextHit>`
         #[repr(C)]
         pub struct AzInlineTextHitVec {
@@ -7062,6 +8201,15 @@ mod dll {
             pub destructor: AzScanCodeVecDestructor,
         }
 
+        /// Wrapper over a Rust-allocated `Vec<CssUnicodeRange>`
+        #[repr(C)]
+        pub struct AzCssUnicodeRangeVec {
+            pub(crate) ptr: *const AzCssUnicodeRange,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzCssUnicodeRangeVecDestructor,
+        }
+
         /// Wrapper over a Rust-allocated `Vec<u16>`
         #[repr(C)]
         pub struct AzU16Vec {
@@ -7107,69 +8255,312 @@ mod dll {
             pub destructor: AzGLintVecDestructor,
         }
 
-        /// Wrapper over a Rust-allocated `NormalizedLinearColorStopVec`
-        #[repr(C)]
-        pub struct AzNormalizedLinearColorStopVec {
-            pub(crate) ptr: *const AzNormalizedLinearColorStop,
-            pub len: usize,
-            pub cap: usize,
-            pub destructor: AzNormalizedLinearColorStopVecDestructor,
+        /// Wrapper over a Rust-allocated `NormalizedLinearColorStopVec`
+        #[repr(C)]
+        pub struct AzNormalizedLinearColorStopVec {
+            pub(crate) ptr: *const AzNormalizedLinearColorStop,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzNormalizedLinearColorStopVecDestructor,
+        }
+
+        /// Wrapper over a Rust-allocated `NormalizedRadialColorStopVec`
+        #[repr(C)]
+        pub struct AzNormalizedRadialColorStopVec {
+            pub(crate) ptr: *const AzNormalizedRadialColorStop,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzNormalizedRadialColorStopVecDestructor,
+        }
+
+        /// Wrapper over a Rust-allocated `NodeIdVec`
+        #[repr(C)]
+        pub struct AzNodeIdVec {
+            pub(crate) ptr: *const AzNodeId,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzNodeIdVecDestructor,
+        }
+
+        /// Wrapper over a Rust-allocated `Vec<NodeHierarchyItem>`
+        #[repr(C)]
+        pub struct AzNodeHierarchyItemVec {
+            pub(crate) ptr: *const AzNodeHierarchyItem,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzNodeHierarchyItemVecDestructor,
+        }
+
+        /// Wrapper over a Rust-allocated `ParentWithNodeDepthVec`
+        #[repr(C)]
+        pub struct AzParentWithNodeDepthVec {
+            pub(crate) ptr: *const AzParentWithNodeDepth,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzParentWithNodeDepthVecDestructor,
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionSvgPoint` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionSvgPoint {
+            None,
+            Some(AzSvgPoint),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionTreeViewOnExpand` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionTreeViewOnExpand {
+            None,
+            Some(AzTreeViewOnExpand),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionTreeViewOnSelect` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionTreeViewOnSelect {
+            None,
+            Some(AzTreeViewOnSelect),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionTreeViewOnLazyLoad` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionTreeViewOnLazyLoad {
+            None,
+            Some(AzTreeViewOnLazyLoad),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionTreeViewOnScroll` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionTreeViewOnScroll {
+            None,
+            Some(AzTreeViewOnScroll),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionListViewOnRowClick` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionListViewOnRowClick {
+            None,
+            Some(AzListViewOnRowClick),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionListViewOnRowSelect` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionListViewOnRowSelect {
+            None,
+            Some(AzListViewOnRowSelect),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionListViewOnColumnResize` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionListViewOnColumnResize {
+            None,
+            Some(AzListViewOnColumnResize),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionListViewRowProvider` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionListViewRowProvider {
+            None,
+            Some(AzListViewRowProvider),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionDataGridRange` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzOptionDataGridRange {
+            None,
+            Some(AzDataGridRange),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionDataGridCellProvider` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionDataGridCellProvider {
+            None,
+            Some(AzDataGridCellProvider),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionDataGridCellStyle` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionDataGridCellStyle {
+            None,
+            Some(AzDataGridCellStyle),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionDataGridOnSelect` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionDataGridOnSelect {
+            None,
+            Some(AzDataGridOnSelect),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionDataGridOnScroll` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionDataGridOnScroll {
+            None,
+            Some(AzDataGridOnScroll),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionDataGridOnCellEdit` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionDataGridOnCellEdit {
+            None,
+            Some(AzDataGridOnCellEdit),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionDataGridOnColumnResize` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionDataGridOnColumnResize {
+            None,
+            Some(AzDataGridOnColumnResize),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionDataGridOnRowResize` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionDataGridOnRowResize {
+            None,
+            Some(AzDataGridOnRowResize),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionDataGridOnCopyRange` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionDataGridOnCopyRange {
+            None,
+            Some(AzDataGridOnCopyRange),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionDataGridOnClearRange` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionDataGridOnClearRange {
+            None,
+            Some(AzDataGridOnClearRange),
         }
 
-        /// Wrapper over a Rust-allocated `NormalizedRadialColorStopVec`
-        #[repr(C)]
-        pub struct AzNormalizedRadialColorStopVec {
-            pub(crate) ptr: *const AzNormalizedRadialColorStop,
-            pub len: usize,
-            pub cap: usize,
-            pub destructor: AzNormalizedRadialColorStopVecDestructor,
+        /// Re-export of rust-allocated (stack based) `OptionDate` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzOptionDate {
+            None,
+            Some(AzDate),
         }
 
-        /// Wrapper over a Rust-allocated `NodeIdVec`
-        #[repr(C)]
-        pub struct AzNodeIdVec {
-            pub(crate) ptr: *const AzNodeId,
-            pub len: usize,
-            pub cap: usize,
-            pub destructor: AzNodeIdVecDestructor,
+        /// Re-export of rust-allocated (stack based) `OptionTime` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzOptionTime {
+            None,
+            Some(AzTime),
         }
 
-        /// Wrapper over a Rust-allocated `Vec<NodeHierarchyItem>`
-        #[repr(C)]
-        pub struct AzNodeHierarchyItemVec {
-            pub(crate) ptr: *const AzNodeHierarchyItem,
-            pub len: usize,
-            pub cap: usize,
-            pub destructor: AzNodeHierarchyItemVecDestructor,
+        /// Re-export of rust-allocated (stack based) `OptionDatePickerOnDateChange` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionDatePickerOnDateChange {
+            None,
+            Some(AzDatePickerOnDateChange),
         }
 
-        /// Wrapper over a Rust-allocated `ParentWithNodeDepthVec`
-        #[repr(C)]
-        pub struct AzParentWithNodeDepthVec {
-            pub(crate) ptr: *const AzParentWithNodeDepth,
-            pub len: usize,
-            pub cap: usize,
-            pub destructor: AzParentWithNodeDepthVecDestructor,
+        /// Re-export of rust-allocated (stack based) `OptionTimePickerOnTimeChange` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionTimePickerOnTimeChange {
+            None,
+            Some(AzTimePickerOnTimeChange),
         }
 
-        /// Re-export of rust-allocated (stack based) `OptionSvgPoint` struct
+        /// Re-export of rust-allocated (stack based) `OptionDateTimePickerOnDateTimeChange` struct
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        pub enum AzOptionSvgPoint {
+        pub enum AzOptionDateTimePickerOnDateTimeChange {
             None,
-            Some(AzSvgPoint),
+            Some(AzDateTimePickerOnDateTimeChange),
         }
 
-        /// Re-export of rust-allocated (stack based) `OptionListViewOnRowClick` struct
+        /// Re-export of rust-allocated (stack based) `OptionDialogOnClose` struct
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        pub enum AzOptionListViewOnRowClick {
+        pub enum AzOptionDialogOnClose {
             None,
-            Some(AzListViewOnRowClick),
+            Some(AzDialogOnClose),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionSplitterOnResize` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionSplitterOnResize {
+            None,
+            Some(AzSplitterOnResize),
         }
 
         /// Re-export of rust-allocated (stack based) `OptionListViewOnColumnClick` struct
@@ -7212,6 +8603,36 @@ mod dll {
             Some(AzDropDownOnChoiceChange),
         }
 
+        /// Re-export of rust-allocated (stack based) `OptionNodeGraphOnNodeGraphZoomed` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionNodeGraphOnNodeGraphZoomed {
+            None,
+            Some(AzNodeGraphOnNodeGraphZoomed),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionNodeGraphOnSelectionChanged` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionNodeGraphOnSelectionChanged {
+            None,
+            Some(AzNodeGraphOnSelectionChanged),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionNodeGraphOnHistoryChanged` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionNodeGraphOnHistoryChanged {
+            None,
+            Some(AzNodeGraphOnHistoryChanged),
+        }
+
         /// Re-export of rust-allocated (stack based) `OptionNodeGraphOnNodeAdded` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -7936,6 +9357,18 @@ mod dll {
             pub cb: AzMarshaledLayoutCallbackInner,
         }
 
+        /// Size of a string measured without laying out a DOM
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzTextMetrics {
+            pub width: f32,
+            pub height: f32,
+            pub line_count: usize,
+            pub lines: AzTextLineMetricsVec,
+        }
+
         /// Re-export of rust-allocated (stack based) `InlineTextContents` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -8009,6 +9442,7 @@ mod dll {
             pub image_cache: *const c_void,
             pub gl_context: *const AzOptionGl,
             pub system_fonts: *const c_void,
+            pub callbacks: *const c_void,
             pub _reserved_ref: *const c_void,
             pub _reserved_mut: *mut c_void,
         }
@@ -8048,6 +9482,16 @@ mod dll {
             pub keys: AzVirtualKeyCodeVec,
         }
 
+        /// Keyboard shortcut that is not attached to a menu item: when the key combination is pressed, the callback is invoked. See `Dom::with_keyboard_shortcuts()`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzKeyboardShortcut {
+            pub keys: AzVirtualKeyCodeCombo,
+            pub callback: AzMenuCallback,
+        }
+
         /// Re-export of rust-allocated (stack based) `CssPathPseudoSelector` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -8250,6 +9694,9 @@ mod dll {
             pub on_node_input_disconnected: AzOptionNodeGraphOnNodeInputDisconnected,
             pub on_node_output_disconnected: AzOptionNodeGraphOnNodeOutputDisconnected,
             pub on_node_field_edited: AzOptionNodeGraphOnNodeFieldEdited,
+            pub on_node_graph_zoomed: AzOptionNodeGraphOnNodeGraphZoomed,
+            pub on_selection_changed: AzOptionNodeGraphOnSelectionChanged,
+            pub on_history_changed: AzOptionNodeGraphOnHistoryChanged,
         }
 
         /// Re-export of rust-allocated (stack based) `InputConnection` struct
@@ -8280,6 +9727,96 @@ mod dll {
         pub struct AzListViewRow {
             pub cells: AzDomVec,
             pub height: AzOptionPixelValueNoPercent,
+            pub is_selected: bool,
+        }
+
+        /// Hierarchical tree of items with expand / collapse and selection. Only the rows between `first_visible_row` and `first_visible_row + max_visible_rows` are rendered. The callbacks only report what the user did, the application applies the change to its own copy of the TreeView (`set_expanded`, `apply_selection`, `set_children`, `set_first_visible_row`)
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzTreeView {
+            pub items: AzTreeViewItemVec,
+            pub selection_mode: AzTreeViewSelectionMode,
+            pub first_visible_row: usize,
+            pub max_visible_rows: usize,
+            pub focused_item: AzOptionUsize,
+            pub selection_anchor: AzOptionUsize,
+            pub on_expand: AzOptionTreeViewOnExpand,
+            pub on_select: AzOptionTreeViewOnSelect,
+            pub on_lazy_load: AzOptionTreeViewOnLazyLoad,
+            pub on_scroll: AzOptionTreeViewOnScroll,
+        }
+
+        /// State of a `DatePicker`, reported by `on_date_change`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzDatePickerState {
+            pub selected: AzOptionDate,
+            pub cursor: AzDate,
+        }
+
+        /// Month calendar to select a single date. Selecting a date and navigating between months is reported through `on_date_change`, the application stores the new `DatePickerState` for the next frame. Arrow keys, PageUp / PageDown and Home / End move the cursor, Enter / Space select the date under the cursor
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzDatePicker {
+            pub state: AzDatePickerState,
+            pub minimum_date: AzOptionDate,
+            pub maximum_date: AzOptionDate,
+            pub first_day_of_week: AzWeekday,
+            pub on_date_change: AzOptionDatePickerOnDateChange,
+        }
+
+        /// Hour / minute / second input with spin buttons, changes are reported through `on_time_change`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzTimePicker {
+            pub time: AzTime,
+            pub show_seconds: bool,
+            pub on_time_change: AzOptionTimePickerOnTimeChange,
+        }
+
+        /// State of a `DateTimePicker`, reported by `on_date_time_change`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzDateTimePickerState {
+            pub date: AzDatePickerState,
+            pub time: AzTime,
+            pub is_open: bool,
+        }
+
+        /// Drop-down field showing the selected date (and time), which opens a `DatePicker` (and `TimePicker`) below the field when clicked. Opening / closing the popup and changing the date or time is reported through `on_date_time_change`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzDateTimePicker {
+            pub state: AzDateTimePickerState,
+            pub minimum_date: AzOptionDate,
+            pub maximum_date: AzOptionDate,
+            pub first_day_of_week: AzWeekday,
+            pub show_time: bool,
+            pub show_seconds: bool,
+            pub on_date_time_change: AzOptionDateTimePickerOnDateTimeChange,
+        }
+
+        /// Sizes of all panes of a `Splitter`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzSplitterState {
+            pub panes: AzSplitterPaneStateVec,
         }
 
         /// Re-export of rust-allocated (stack based) `StyledNode` struct
@@ -8509,6 +10046,15 @@ mod dll {
             pub destructor: AzOutputConnectionVecDestructor,
         }
 
+        /// Wrapper over a Rust-allocated `Vec<KeyboardShortcut>`
+        #[repr(C)]
+        pub struct AzKeyboardShortcutVec {
+            pub(crate) ptr: *const AzKeyboardShortcut,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzKeyboardShortcutVecDestructor,
+        }
+
         /// Wrapper over a Rust-allocated `Vec<TessellatedSvgNode>`
         #[repr(C)]
         pub struct AzTessellatedSvgNodeVec {
@@ -8642,6 +10188,16 @@ mod dll {
             Some(AzThreadReceiveMsg),
         }
 
+        /// Re-export of rust-allocated (stack based) `OptionTextMetrics` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionTextMetrics {
+            None,
+            Some(AzTextMetrics),
+        }
+
         /// Re-export of rust-allocated (stack based) `OptionTaskBarIcon` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -8904,6 +10460,7 @@ mod dll {
             pub states: AzAccessibilityStateVec,
             pub accelerator: AzOptionVirtualKeyCodeCombo,
             pub default_action: AzOptionString,
+            pub description: AzOptionString,
         }
 
         /// Re-export of rust-allocated (stack based) `IdOrClass` struct
@@ -8945,6 +10502,16 @@ mod dll {
             Children,
         }
 
+        /// `url("fonts/Roboto.ttf") format("truetype")`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzCssFontFaceUrl {
+            pub url: AzString,
+            pub format: AzOptionString,
+        }
+
         /// Re-export of rust-allocated (stack based) `StyleBackgroundContent` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -9116,7 +10683,20 @@ mod dll {
             pub color: AzColorU,
         }
 
-        /// Re-export of rust-allocated (stack based) `ListView` struct
+        /// Field of a node that was changed from `old_value` to `new_value`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzNodeGraphFieldEdit {
+            pub node_id: AzNodeGraphNodeId,
+            pub node_type: AzNodeTypeId,
+            pub field_idx: usize,
+            pub old_value: AzNodeTypeFieldValue,
+            pub new_value: AzNodeTypeFieldValue,
+        }
+
+        /// Table with a header row. If a `row_provider` is set, the ListView is virtualized: only the rows that fit into the visible area are requested from the row provider and rendered. The callbacks report scrolling, selection and column resizing, the application stores the new `first_visible_row`, selection state and `column_widths` for the next frame
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
@@ -9131,6 +10711,16 @@ mod dll {
             pub on_lazy_load_scroll: AzOptionListViewOnLazyLoadScroll,
             pub on_column_click: AzOptionListViewOnColumnClick,
             pub on_row_click: AzOptionListViewOnRowClick,
+            pub row_count: usize,
+            pub row_height: f32,
+            pub first_visible_row: usize,
+            pub column_widths: AzF32Vec,
+            pub sort_descending: bool,
+            pub focused_row: AzOptionUsize,
+            pub selection_anchor: AzOptionUsize,
+            pub row_provider: AzOptionListViewRowProvider,
+            pub on_row_select: AzOptionListViewOnRowSelect,
+            pub on_column_resize: AzOptionListViewOnColumnResize,
         }
 
         /// Re-export of rust-allocated (stack based) `ListViewState` struct
@@ -9145,15 +10735,60 @@ mod dll {
             pub scroll_offset: AzPixelValueNoPercent,
             pub current_scroll_position: AzLogicalPosition,
             pub current_content_height: AzLogicalSize,
+            pub row_count: usize,
+            pub first_visible_row: usize,
+            pub focused_row: AzOptionUsize,
+            pub selection_anchor: AzOptionUsize,
         }
 
-        /// Re-export of rust-allocated (stack based) `TreeView` struct
+        /// Re-export of rust-allocated (stack based) `TreeViewItem` struct
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        pub struct AzTreeView {
-            pub root: AzString,
+        pub struct AzTreeViewItem {
+            pub id: usize,
+            pub label: AzString,
+            pub icon: AzOptionImageRef,
+            pub children: AzTreeViewItemVec,
+            pub has_lazy_children: bool,
+            pub is_expanded: bool,
+            pub is_selected: bool,
+        }
+
+        /// Spreadsheet-like grid of text cells. The DataGrid does not store any cell data: the text of each visible cell is requested from the `cell_provider`, only the cells that fit into the visible area are rendered. Scrolling, selection, resizing and editing are reported through the callbacks, the application stores the new state for the next frame
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzDataGrid {
+            pub row_count: usize,
+            pub column_count: usize,
+            pub column_headers: AzStringVec,
+            pub column_widths: AzF32Vec,
+            pub row_heights: AzF32Vec,
+            pub first_visible_row: usize,
+            pub first_visible_column: usize,
+            pub selection: AzOptionDataGridRange,
+            pub cell_provider: AzOptionDataGridCellProvider,
+            pub cell_style: AzOptionDataGridCellStyle,
+            pub on_select: AzOptionDataGridOnSelect,
+            pub on_scroll: AzOptionDataGridOnScroll,
+            pub on_cell_edit: AzOptionDataGridOnCellEdit,
+            pub on_column_resize: AzOptionDataGridOnColumnResize,
+            pub on_row_resize: AzOptionDataGridOnRowResize,
+            pub on_copy_range: AzOptionDataGridOnCopyRange,
+            pub on_clear_range: AzOptionDataGridOnClearRange,
+        }
+
+        /// Result of a `Dialog`, reported by `on_close`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzDialogResult {
+            pub button: AzDialogButton,
+            pub input: AzString,
         }
 
         /// Re-export of rust-allocated (stack based) `DropDown` struct
@@ -9565,6 +11200,16 @@ mod dll {
             pub selectors: AzCssPathSelectorVec,
         }
 
+        /// Single entry of the `src: ...` list in a `@font-face` rule
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzCssFontFaceSource {
+            Url(AzCssFontFaceUrl),
+            Local(AzString),
+        }
+
         /// Re-export of rust-allocated (stack based) `StyleBackgroundContentVecValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -9671,6 +11316,8 @@ mod dll {
             Filter(AzStyleFilterVecValue),
             BackdropFilter(AzStyleFilterVecValue),
             TextShadow(AzStyleBoxShadowValue),
+            VerticalAlign(AzLayoutVerticalAlignValue),
+            TextDecoration(AzStyleTextDecorationValue),
         }
 
         /// Re-export of rust-allocated (stack based) `FileInputStateWrapper` struct
@@ -9842,6 +11489,7 @@ mod dll {
             pub attributes: AzStringPairVec,
             pub children: AzXmlNodeVec,
             pub text: AzOptionString,
+            pub span: AzXmlSpan,
         }
 
         /// Wrapper over a Rust-allocated `Vec<NodeTypeIdInfoMap>`
@@ -9907,6 +11555,15 @@ mod dll {
             pub destructor: AzSvgSimpleNodeVecDestructor,
         }
 
+        /// Wrapper over a Rust-allocated `Vec<CssFontFaceSource>`
+        #[repr(C)]
+        pub struct AzCssFontFaceSourceVec {
+            pub(crate) ptr: *const AzCssFontFaceSource,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzCssFontFaceSourceVecDestructor,
+        }
+
         /// Re-export of rust-allocated (stack based) `OptionCssProperty` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -10011,6 +11668,7 @@ mod dll {
             pub font_size_px: f32,
             pub last_word_index: usize,
             pub baseline_descender_px: f32,
+            pub runs: AzInlineTextRunVec,
         }
 
         /// CSS path to set the keyboard input focus
@@ -10075,6 +11733,19 @@ mod dll {
             pub default_value: AzCssProperty,
         }
 
+        /// Parsed contents of an `@font-face { ... }` block
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzCssFontFace {
+            pub font_family: AzString,
+            pub src: AzCssFontFaceSourceVec,
+            pub font_weight: u16,
+            pub font_style: AzCssFontFaceStyle,
+            pub unicode_range: AzCssUnicodeRangeVec,
+        }
+
         /// Re-export of rust-allocated (stack based) `Node` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -10121,6 +11792,15 @@ mod dll {
             pub destructor: AzNodeDataInlineCssPropertyVecDestructor,
         }
 
+        /// Wrapper over a Rust-allocated `Vec<CssFontFace>`
+        #[repr(C)]
+        pub struct AzCssFontFaceVec {
+            pub(crate) ptr: *const AzCssFontFace,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzCssFontFaceVecDestructor,
+        }
+
         /// Re-export of rust-allocated (stack based) `OptionWindowState` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -10304,6 +11984,29 @@ mod dll {
             pub node: AzNode,
         }
 
+        /// Single modification of the node graph, recorded in the undo history
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzNodeGraphEditOp {
+            AddNode(AzNodeIdNodeMap),
+            RemoveNode(AzNodeIdNodeMap),
+            MoveNode(AzNodeGraphNodeMove),
+            Connect(AzNodeGraphConnection),
+            Disconnect(AzNodeGraphConnection),
+            EditField(AzNodeGraphFieldEdit),
+        }
+
+        /// Wrapper over a Rust-allocated `Vec<NodeGraphEditOp>`
+        #[repr(C)]
+        pub struct AzNodeGraphEditOpVec {
+            pub(crate) ptr: *const AzNodeGraphEditOp,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzNodeGraphEditOpVecDestructor,
+        }
+
         /// Wrapper over a Rust-allocated `Vec<NodeIdNodeMap>`
         #[repr(C)]
         pub struct AzNodeIdNodeMapVec {
@@ -10367,6 +12070,16 @@ mod dll {
             pub total_children: usize,
         }
 
+        /// Content of a `Tooltip`: either a plain text or a custom DOM
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzTooltipContent {
+            Text(AzString),
+            Dom(AzDom),
+        }
+
         /// Re-export of rust-allocated (stack based) `CssRuleBlock` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -10398,21 +12111,25 @@ mod dll {
             pub content: AzDom,
         }
 
-        /// Re-export of rust-allocated (stack based) `NodeGraph` struct
+        /// One undoable user action (for example "paste three nodes"), consisting of one or more operations
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        pub struct AzNodeGraph {
-            pub node_types: AzNodeTypeIdInfoMapVec,
-            pub input_output_types: AzInputOutputTypeIdInfoMapVec,
-            pub nodes: AzNodeIdNodeMapVec,
-            pub allow_multiple_root_nodes: bool,
-            pub offset: AzLogicalPosition,
-            pub style: AzNodeGraphStyle,
-            pub callbacks: AzNodeGraphCallbacks,
-            pub add_node_str: AzString,
-            pub scale_factor: f32,
+        pub struct AzNodeGraphEdit {
+            pub ops: AzNodeGraphEditOpVec,
+        }
+
+        /// Pane of a `Splitter`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzSplitterPane {
+            pub content: AzDom,
+            pub state: AzSplitterPaneState,
+            pub min_size: f32,
+            pub max_size: AzOptionF32,
         }
 
         /// Re-export of rust-allocated (stack based) `StyledDom` struct
@@ -10434,6 +12151,24 @@ mod dll {
             pub css_property_cache: AzCssPropertyCache,
         }
 
+        /// Wrapper over a Rust-allocated `Vec<SplitterPane>`
+        #[repr(C)]
+        pub struct AzSplitterPaneVec {
+            pub(crate) ptr: *const AzSplitterPane,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzSplitterPaneVecDestructor,
+        }
+
+        /// Wrapper over a Rust-allocated `Vec<NodeGraphEdit>`
+        #[repr(C)]
+        pub struct AzNodeGraphEditVec {
+            pub(crate) ptr: *const AzNodeGraphEdit,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzNodeGraphEditVecDestructor,
+        }
+
         /// Wrapper over a Rust-allocated `CssRuleBlock`
         #[repr(C)]
         pub struct AzCssRuleBlockVec {
@@ -10490,6 +12225,16 @@ mod dll {
             pub virtual_scroll_offset: AzLogicalPosition,
         }
 
+        /// Hover hint of a DOM node, see `Dom::with_tooltip()`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzTooltip {
+            pub content: AzTooltipContent,
+            pub delay: AzDuration,
+        }
+
         /// Re-export of rust-allocated (stack based) `Stylesheet` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -10499,6 +12244,43 @@ mod dll {
             pub rules: AzCssRuleBlockVec,
         }
 
+        /// Undo / redo stacks of the node graph: the last edit is at the end of each stack
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzNodeGraphHistory {
+            pub undo: AzNodeGraphEditVec,
+            pub redo: AzNodeGraphEditVec,
+        }
+
+        /// Modal dialog rendered inside of the window. Dialogs are stateless: the application stores the `Dialog` while it is open and removes it again in `on_close`. Use `dom_over()` to render the dialog on top of the rest of the UI: the content below the dialog is made inert, so the keyboard focus can't leave the dialog and clicks don't reach the content anymore
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzDialog {
+            pub title: AzString,
+            pub message: AzString,
+            pub icon: AzDialogIcon,
+            pub buttons: AzDialogButtons,
+            pub input: AzOptionString,
+            pub content: AzOptionDom,
+            pub on_close: AzOptionDialogOnClose,
+        }
+
+        /// Container which splits its area into resizable panes. Panes with a fixed size keep their size when the splitter is resized, the remaining space is distributed between the flexible panes. Dragging a handle resizes the panes on both sides of it within their `min_size` / `max_size`; if both panes are flexible, the first one is given a fixed size. Double-clicking a handle (or pressing Enter while it is focused) collapses the fixed pane next to it
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzSplitter {
+            pub direction: AzSplitterDirection,
+            pub panes: AzSplitterPaneVec,
+            pub handle_size: f32,
+            pub on_resize: AzOptionSplitterOnResize,
+        }
+
         /// Wrapper over a Rust-allocated `Stylesheet`
         #[repr(C)]
         pub struct AzStylesheetVec {
@@ -10535,6 +12317,36 @@ mod dll {
         #[derive(PartialEq, PartialOrd)]
         pub struct AzCss {
             pub stylesheets: AzStylesheetVec,
+            pub font_faces: AzCssFontFaceVec,
+        }
+
+        /// Re-export of rust-allocated (stack based) `NodeGraph` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzNodeGraph {
+            pub node_types: AzNodeTypeIdInfoMapVec,
+            pub input_output_types: AzInputOutputTypeIdInfoMapVec,
+            pub nodes: AzNodeIdNodeMapVec,
+            pub allow_multiple_root_nodes: bool,
+            pub offset: AzLogicalPosition,
+            pub style: AzNodeGraphStyle,
+            pub callbacks: AzNodeGraphCallbacks,
+            pub add_node_str: AzString,
+            pub scale_factor: f32,
+            pub selected_nodes: AzNodeGraphNodeIdVec,
+            pub history: AzNodeGraphHistory,
+        }
+
+        /// Re-export of rust-allocated (stack based) `ResultNodeGraphNodeGraphError` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzResultNodeGraphNodeGraphError {
+            Ok(AzNodeGraph),
+            Err(AzNodeGraphError),
         }
 
     }
@@ -10549,6 +12361,7 @@ mod dll {
         pub(crate) fn AzApp_new(data: AzRefAny, config: AzAppConfig) -> AzApp { unsafe { transmute(azul::AzApp_new(transmute(data), transmute(config))) } }
         pub(crate) fn AzApp_addWindow(app: &mut AzApp, window: AzWindowCreateOptions) { unsafe { transmute(azul::AzApp_addWindow(transmute(app), transmute(window))) } }
         pub(crate) fn AzApp_addImage(app: &mut AzApp, id: AzString, image: AzImageRef) { unsafe { transmute(azul::AzApp_addImage(transmute(app), transmute(id), transmute(image))) } }
+        pub(crate) fn AzApp_addFont(app: &mut AzApp, family: AzString, font_bytes: AzU8Vec) -> bool { unsafe { transmute(azul::AzApp_addFont(transmute(app), transmute(family), transmute(font_bytes))) } }
         pub(crate) fn AzApp_getMonitors(app: &AzApp) -> AzMonitorVec { unsafe { transmute(azul::AzApp_getMonitors(transmute(app))) } }
         pub(crate) fn AzApp_run(app: &AzApp, window: AzWindowCreateOptions) { unsafe { transmute(azul::AzApp_run(transmute(app), transmute(window))) } }
         pub(crate) fn AzApp_delete(object: &mut AzApp) { unsafe { transmute(azul::AzApp_delete(transmute(object))) } }
@@ -10623,6 +12436,7 @@ mod dll {
         pub(crate) fn AzHidpiAdjustedBounds_getPhysicalSize(hidpiadjustedbounds: &AzHidpiAdjustedBounds) -> AzPhysicalSizeU32 { unsafe { transmute(azul::AzHidpiAdjustedBounds_getPhysicalSize(transmute(hidpiadjustedbounds))) } }
         pub(crate) fn AzHidpiAdjustedBounds_getHidpiFactor(hidpiadjustedbounds: &AzHidpiAdjustedBounds) -> f32 { unsafe { transmute(azul::AzHidpiAdjustedBounds_getHidpiFactor(transmute(hidpiadjustedbounds))) } }
         pub(crate) fn AzInlineText_hitTest(inlinetext: &AzInlineText, position: AzLogicalPosition) -> AzInlineTextHitVec { unsafe { transmute(azul::AzInlineText_hitTest(transmute(inlinetext), transmute(position))) } }
+        pub(crate) fn AzInlineText_getTextMetrics(inlinetext: &AzInlineText) -> AzTextMetrics { unsafe { transmute(azul::AzInlineText_getTextMetrics(transmute(inlinetext))) } }
         pub(crate) fn AzResolvedTextLayoutOptions_default() -> AzResolvedTextLayoutOptions { unsafe { transmute(azul::AzResolvedTextLayoutOptions_default()) } }
         pub(crate) fn AzRenderImageCallbackInfo_getGlContext(renderimagecallbackinfo: &AzRenderImageCallbackInfo) -> AzOptionGl { unsafe { transmute(azul::AzRenderImageCallbackInfo_getGlContext(transmute(renderimagecallbackinfo))) } }
         pub(crate) fn AzRenderImageCallbackInfo_getBounds(renderimagecallbackinfo: &AzRenderImageCallbackInfo) -> AzHidpiAdjustedBounds { unsafe { transmute(azul::AzRenderImageCallbackInfo_getBounds(transmute(renderimagecallbackinfo))) } }
@@ -10650,6 +12464,7 @@ mod dll {
         pub(crate) fn AzLayoutCallbackInfo_getGlContext(layoutcallbackinfo: &AzLayoutCallbackInfo) -> AzOptionGl { unsafe { transmute(azul::AzLayoutCallbackInfo_getGlContext(transmute(layoutcallbackinfo))) } }
         pub(crate) fn AzLayoutCallbackInfo_getSystemFonts(layoutcallbackinfo: &AzLayoutCallbackInfo) -> AzStringPairVec { unsafe { transmute(azul::AzLayoutCallbackInfo_getSystemFonts(transmute(layoutcallbackinfo))) } }
         pub(crate) fn AzLayoutCallbackInfo_getImage(layoutcallbackinfo: &AzLayoutCallbackInfo, id: AzString) -> AzOptionImageRef { unsafe { transmute(azul::AzLayoutCallbackInfo_getImage(transmute(layoutcallbackinfo), transmute(id))) } }
+        pub(crate) fn AzLayoutCallbackInfo_measureText(layoutcallbackinfo: &AzLayoutCallbackInfo, text: AzString, font_families: AzStyleFontFamilyVec, options: AzResolvedTextLayoutOptions) -> AzOptionTextMetrics { unsafe { transmute(azul::AzLayoutCallbackInfo_measureText(transmute(layoutcallbackinfo), transmute(text), transmute(font_families), transmute(options))) } }
         pub(crate) fn AzDom_new(node_type: AzNodeType) -> AzDom { unsafe { transmute(azul::AzDom_new(transmute(node_type))) } }
         pub(crate) fn AzDom_body() -> AzDom { unsafe { transmute(azul::AzDom_body()) } }
         pub(crate) fn AzDom_div() -> AzDom { unsafe { transmute(azul::AzDom_div()) } }
@@ -10703,6 +12518,11 @@ mod dll {
         pub(crate) fn AzDom_withMenuBar(dom: &mut AzDom, menu_bar: AzMenu) -> AzDom { unsafe { transmute(azul::AzDom_withMenuBar(transmute(dom), transmute(menu_bar))) } }
         pub(crate) fn AzDom_setContextMenu(dom: &mut AzDom, context_menu: AzMenu) { unsafe { transmute(azul::AzDom_setContextMenu(transmute(dom), transmute(context_menu))) } }
         pub(crate) fn AzDom_withContextMenu(dom: &mut AzDom, context_menu: AzMenu) -> AzDom { unsafe { transmute(azul::AzDom_withContextMenu(transmute(dom), transmute(context_menu))) } }
+        pub(crate) fn AzDom_setTooltip(dom: &mut AzDom, tooltip: AzTooltip) { unsafe { transmute(azul::AzDom_setTooltip(transmute(dom), transmute(tooltip))) } }
+        pub(crate) fn AzDom_withTooltip(dom: &mut AzDom, tooltip: AzTooltip) -> AzDom { unsafe { transmute(azul::AzDom_withTooltip(transmute(dom), transmute(tooltip))) } }
+        pub(crate) fn AzDom_setKeyboardShortcuts(dom: &mut AzDom, keyboard_shortcuts: AzKeyboardShortcutVec) { unsafe { transmute(azul::AzDom_setKeyboardShortcuts(transmute(dom), transmute(keyboard_shortcuts))) } }
+        pub(crate) fn AzDom_withKeyboardShortcuts(dom: &mut AzDom, keyboard_shortcuts: AzKeyboardShortcutVec) -> AzDom { unsafe { transmute(azul::AzDom_withKeyboardShortcuts(transmute(dom), transmute(keyboard_shortcuts))) } }
+        pub(crate) fn AzDom_makeInert(dom: &mut AzDom) { unsafe { transmute(azul::AzDom_makeInert(transmute(dom))) } }
         pub(crate) fn AzDom_hash(dom: &AzDom) -> u64 { unsafe { transmute(azul::AzDom_hash(transmute(dom))) } }
         pub(crate) fn AzDom_nodeCount(dom: &AzDom) -> usize { unsafe { transmute(azul::AzDom_nodeCount(transmute(dom))) } }
         pub(crate) fn AzDom_getHtmlString(dom: &mut AzDom) -> AzString { unsafe { transmute(azul::AzDom_getHtmlString(transmute(dom))) } }
@@ -10740,8 +12560,13 @@ mod dll {
         pub(crate) fn AzNodeData_setAccessibilityInfo(nodedata: &mut AzNodeData, accessibility_info: AzAccessibilityInfo) { unsafe { transmute(azul::AzNodeData_setAccessibilityInfo(transmute(nodedata), transmute(accessibility_info))) } }
         pub(crate) fn AzNodeData_setMenuBar(nodedata: &mut AzNodeData, menu_bar: AzMenu) { unsafe { transmute(azul::AzNodeData_setMenuBar(transmute(nodedata), transmute(menu_bar))) } }
         pub(crate) fn AzNodeData_setContextMenu(nodedata: &mut AzNodeData, context_menu: AzMenu) { unsafe { transmute(azul::AzNodeData_setContextMenu(transmute(nodedata), transmute(context_menu))) } }
+        pub(crate) fn AzNodeData_setTooltip(nodedata: &mut AzNodeData, tooltip: AzTooltip) { unsafe { transmute(azul::AzNodeData_setTooltip(transmute(nodedata), transmute(tooltip))) } }
+        pub(crate) fn AzNodeData_setKeyboardShortcuts(nodedata: &mut AzNodeData, keyboard_shortcuts: AzKeyboardShortcutVec) { unsafe { transmute(azul::AzNodeData_setKeyboardShortcuts(transmute(nodedata), transmute(keyboard_shortcuts))) } }
         pub(crate) fn AzNodeData_hash(nodedata: &AzNodeData) -> u64 { unsafe { transmute(azul::AzNodeData_hash(transmute(nodedata))) } }
         pub(crate) fn AzOn_intoEventFilter(on: AzOn) -> AzEventFilter { unsafe { transmute(azul::AzOn_intoEventFilter(transmute(on))) } }
+        pub(crate) fn AzTooltip_text(text: AzString) -> AzTooltip { unsafe { transmute(azul::AzTooltip_text(transmute(text))) } }
+        pub(crate) fn AzTooltip_dom(dom: AzDom) -> AzTooltip { unsafe { transmute(azul::AzTooltip_dom(transmute(dom))) } }
+        pub(crate) fn AzTooltip_withDelay(tooltip: &mut AzTooltip, delay: AzDuration) -> AzTooltip { unsafe { transmute(azul::AzTooltip_withDelay(transmute(tooltip), transmute(delay))) } }
         pub(crate) fn AzMenu_new(items: AzMenuItemVec) -> AzMenu { unsafe { transmute(azul::AzMenu_new(transmute(items))) } }
         pub(crate) fn AzMenu_setPopupPosition(menu: &mut AzMenu, position: AzMenuPopupPosition) { unsafe { transmute(azul::AzMenu_setPopupPosition(transmute(menu), transmute(position))) } }
         pub(crate) fn AzMenu_withPopupPosition(menu: &mut AzMenu, position: AzMenuPopupPosition) -> AzMenu { unsafe { transmute(azul::AzMenu_withPopupPosition(transmute(menu), transmute(position))) } }
@@ -10752,6 +12577,8 @@ mod dll {
         pub(crate) fn AzStringMenuItem_withChild(stringmenuitem: &mut AzStringMenuItem, child: AzMenuItem) -> AzStringMenuItem { unsafe { transmute(azul::AzStringMenuItem_withChild(transmute(stringmenuitem), transmute(child))) } }
         pub(crate) fn AzStringMenuItem_setChildren(stringmenuitem: &mut AzStringMenuItem, children: AzMenuItemVec) { unsafe { transmute(azul::AzStringMenuItem_setChildren(transmute(stringmenuitem), transmute(children))) } }
         pub(crate) fn AzStringMenuItem_withChildren(stringmenuitem: &mut AzStringMenuItem, children: AzMenuItemVec) -> AzStringMenuItem { unsafe { transmute(azul::AzStringMenuItem_withChildren(transmute(stringmenuitem), transmute(children))) } }
+        pub(crate) fn AzVirtualKeyCodeCombo_matches(virtualkeycodecombo: &AzVirtualKeyCodeCombo, keyboard_state: AzKeyboardState) -> bool { unsafe { transmute(azul::AzVirtualKeyCodeCombo_matches(transmute(virtualkeycodecombo), transmute(keyboard_state))) } }
+        pub(crate) fn AzKeyboardShortcut_new(keys: AzVirtualKeyCodeCombo, data: AzRefAny, callback: AzCallbackType) -> AzKeyboardShortcut { unsafe { transmute(azul::AzKeyboardShortcut_new(transmute(keys), transmute(data), transmute(callback))) } }
         pub(crate) fn AzMenuCallback_new(data: AzRefAny, callback: AzCallbackType) -> AzMenuCallback { unsafe { transmute(azul::AzMenuCallback_new(transmute(data), transmute(callback))) } }
         pub(crate) fn AzCss_empty() -> AzCss { unsafe { transmute(azul::AzCss_empty()) } }
         pub(crate) fn AzCss_fromString(s: AzString) -> AzCss { unsafe { transmute(azul::AzCss_fromString(transmute(s))) } }
@@ -10843,12 +12670,186 @@ mod dll {
         pub(crate) fn AzFrame_setFlexGrow(frame: &mut AzFrame, flex_grow: f32) { unsafe { transmute(azul::AzFrame_setFlexGrow(transmute(frame), transmute(flex_grow))) } }
         pub(crate) fn AzFrame_withFlexGrow(frame: &mut AzFrame, flex_grow: f32) -> AzFrame { unsafe { transmute(azul::AzFrame_withFlexGrow(transmute(frame), transmute(flex_grow))) } }
         pub(crate) fn AzFrame_dom(frame: &mut AzFrame) -> AzDom { unsafe { transmute(azul::AzFrame_dom(transmute(frame))) } }
+        pub(crate) fn AzNodeGraph_fromJson(json: AzString) -> AzResultNodeGraphNodeGraphError { unsafe { transmute(azul::AzNodeGraph_fromJson(transmute(json))) } }
+        pub(crate) fn AzNodeGraph_toJson(nodegraph: &AzNodeGraph) -> AzString { unsafe { transmute(azul::AzNodeGraph_toJson(transmute(nodegraph))) } }
         pub(crate) fn AzNodeGraph_dom(nodegraph: &mut AzNodeGraph) -> AzDom { unsafe { transmute(azul::AzNodeGraph_dom(transmute(nodegraph))) } }
+        pub(crate) fn AzNodeGraphEditOp_invert(nodegrapheditop: &AzNodeGraphEditOp) -> AzNodeGraphEditOp { unsafe { transmute(azul::AzNodeGraphEditOp_invert(transmute(nodegrapheditop))) } }
+        pub(crate) fn AzNodeGraphHistory_canUndo(nodegraphhistory: &AzNodeGraphHistory) -> bool { unsafe { transmute(azul::AzNodeGraphHistory_canUndo(transmute(nodegraphhistory))) } }
+        pub(crate) fn AzNodeGraphHistory_canRedo(nodegraphhistory: &AzNodeGraphHistory) -> bool { unsafe { transmute(azul::AzNodeGraphHistory_canRedo(transmute(nodegraphhistory))) } }
         pub(crate) fn AzListView_new(columns: AzStringVec) -> AzListView { unsafe { transmute(azul::AzListView_new(transmute(columns))) } }
+        pub(crate) fn AzListView_setColumns(listview: &mut AzListView, columns: AzStringVec) { unsafe { transmute(azul::AzListView_setColumns(transmute(listview), transmute(columns))) } }
+        pub(crate) fn AzListView_withColumns(listview: &mut AzListView, columns: AzStringVec) -> AzListView { unsafe { transmute(azul::AzListView_withColumns(transmute(listview), transmute(columns))) } }
+        pub(crate) fn AzListView_setRows(listview: &mut AzListView, rows: AzListViewRowVec) { unsafe { transmute(azul::AzListView_setRows(transmute(listview), transmute(rows))) } }
         pub(crate) fn AzListView_withRows(listview: &mut AzListView, rows: AzListViewRowVec) -> AzListView { unsafe { transmute(azul::AzListView_withRows(transmute(listview), transmute(rows))) } }
+        pub(crate) fn AzListView_setSortedBy(listview: &mut AzListView, sorted_by: AzOptionUsize) { unsafe { transmute(azul::AzListView_setSortedBy(transmute(listview), transmute(sorted_by))) } }
+        pub(crate) fn AzListView_withSortedBy(listview: &mut AzListView, sorted_by: AzOptionUsize) -> AzListView { unsafe { transmute(azul::AzListView_withSortedBy(transmute(listview), transmute(sorted_by))) } }
+        pub(crate) fn AzListView_setSortDescending(listview: &mut AzListView, sort_descending: bool) { unsafe { transmute(azul::AzListView_setSortDescending(transmute(listview), transmute(sort_descending))) } }
+        pub(crate) fn AzListView_withSortDescending(listview: &mut AzListView, sort_descending: bool) -> AzListView { unsafe { transmute(azul::AzListView_withSortDescending(transmute(listview), transmute(sort_descending))) } }
+        pub(crate) fn AzListView_setScrollOffset(listview: &mut AzListView, scroll_offset: AzPixelValueNoPercent) { unsafe { transmute(azul::AzListView_setScrollOffset(transmute(listview), transmute(scroll_offset))) } }
+        pub(crate) fn AzListView_withScrollOffset(listview: &mut AzListView, scroll_offset: AzPixelValueNoPercent) -> AzListView { unsafe { transmute(azul::AzListView_withScrollOffset(transmute(listview), transmute(scroll_offset))) } }
+        pub(crate) fn AzListView_setContentHeight(listview: &mut AzListView, content_height: AzPixelValueNoPercent) { unsafe { transmute(azul::AzListView_setContentHeight(transmute(listview), transmute(content_height))) } }
+        pub(crate) fn AzListView_withContentHeight(listview: &mut AzListView, content_height: AzPixelValueNoPercent) -> AzListView { unsafe { transmute(azul::AzListView_withContentHeight(transmute(listview), transmute(content_height))) } }
+        pub(crate) fn AzListView_setColumnContextMenu(listview: &mut AzListView, context_menu: AzMenu) { unsafe { transmute(azul::AzListView_setColumnContextMenu(transmute(listview), transmute(context_menu))) } }
+        pub(crate) fn AzListView_withColumnContextMenu(listview: &mut AzListView, context_menu: AzMenu) -> AzListView { unsafe { transmute(azul::AzListView_withColumnContextMenu(transmute(listview), transmute(context_menu))) } }
+        pub(crate) fn AzListView_setRowHeight(listview: &mut AzListView, row_height: f32) { unsafe { transmute(azul::AzListView_setRowHeight(transmute(listview), transmute(row_height))) } }
+        pub(crate) fn AzListView_withRowHeight(listview: &mut AzListView, row_height: f32) -> AzListView { unsafe { transmute(azul::AzListView_withRowHeight(transmute(listview), transmute(row_height))) } }
+        pub(crate) fn AzListView_setFirstVisibleRow(listview: &mut AzListView, first_visible_row: usize) { unsafe { transmute(azul::AzListView_setFirstVisibleRow(transmute(listview), transmute(first_visible_row))) } }
+        pub(crate) fn AzListView_withFirstVisibleRow(listview: &mut AzListView, first_visible_row: usize) -> AzListView { unsafe { transmute(azul::AzListView_withFirstVisibleRow(transmute(listview), transmute(first_visible_row))) } }
+        pub(crate) fn AzListView_setColumnWidths(listview: &mut AzListView, column_widths: AzF32Vec) { unsafe { transmute(azul::AzListView_setColumnWidths(transmute(listview), transmute(column_widths))) } }
+        pub(crate) fn AzListView_withColumnWidths(listview: &mut AzListView, column_widths: AzF32Vec) -> AzListView { unsafe { transmute(azul::AzListView_withColumnWidths(transmute(listview), transmute(column_widths))) } }
+        pub(crate) fn AzListView_setSelectionState(listview: &mut AzListView, focused_row: AzOptionUsize, selection_anchor: AzOptionUsize) { unsafe { transmute(azul::AzListView_setSelectionState(transmute(listview), transmute(focused_row), transmute(selection_anchor))) } }
+        pub(crate) fn AzListView_withSelectionState(listview: &mut AzListView, focused_row: AzOptionUsize, selection_anchor: AzOptionUsize) -> AzListView { unsafe { transmute(azul::AzListView_withSelectionState(transmute(listview), transmute(focused_row), transmute(selection_anchor))) } }
+        pub(crate) fn AzListView_setRowProvider(listview: &mut AzListView, row_count: usize, data: AzRefAny, callback: AzListViewRowProviderCallbackType) { unsafe { transmute(azul::AzListView_setRowProvider(transmute(listview), transmute(row_count), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzListView_withRowProvider(listview: &mut AzListView, row_count: usize, data: AzRefAny, callback: AzListViewRowProviderCallbackType) -> AzListView { unsafe { transmute(azul::AzListView_withRowProvider(transmute(listview), transmute(row_count), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzListView_setOnLazyLoadScroll(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnLazyLoadScrollCallbackType) { unsafe { transmute(azul::AzListView_setOnLazyLoadScroll(transmute(listview), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzListView_withOnLazyLoadScroll(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnLazyLoadScrollCallbackType) -> AzListView { unsafe { transmute(azul::AzListView_withOnLazyLoadScroll(transmute(listview), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzListView_setOnColumnClick(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnColumnClickCallbackType) { unsafe { transmute(azul::AzListView_setOnColumnClick(transmute(listview), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzListView_withOnColumnClick(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnColumnClickCallbackType) -> AzListView { unsafe { transmute(azul::AzListView_withOnColumnClick(transmute(listview), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzListView_setOnRowClick(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnRowClickCallbackType) { unsafe { transmute(azul::AzListView_setOnRowClick(transmute(listview), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzListView_withOnRowClick(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnRowClickCallbackType) -> AzListView { unsafe { transmute(azul::AzListView_withOnRowClick(transmute(listview), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzListView_setOnRowSelect(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnRowSelectCallbackType) { unsafe { transmute(azul::AzListView_setOnRowSelect(transmute(listview), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzListView_withOnRowSelect(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnRowSelectCallbackType) -> AzListView { unsafe { transmute(azul::AzListView_withOnRowSelect(transmute(listview), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzListView_setOnColumnResize(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnColumnResizeCallbackType) { unsafe { transmute(azul::AzListView_setOnColumnResize(transmute(listview), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzListView_withOnColumnResize(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnColumnResizeCallbackType) -> AzListView { unsafe { transmute(azul::AzListView_withOnColumnResize(transmute(listview), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzListView_getTotalRowCount(listview: &mut AzListView) -> usize { unsafe { transmute(azul::AzListView_getTotalRowCount(transmute(listview))) } }
+        pub(crate) fn AzListView_applySelection(listview: &mut AzListView, row_id: usize, change: AzListViewSelectionChange) { unsafe { transmute(azul::AzListView_applySelection(transmute(listview), transmute(row_id), transmute(change))) } }
         pub(crate) fn AzListView_dom(listview: &mut AzListView) -> AzDom { unsafe { transmute(azul::AzListView_dom(transmute(listview))) } }
-        pub(crate) fn AzTreeView_new(root: AzString) -> AzTreeView { unsafe { transmute(azul::AzTreeView_new(transmute(root))) } }
+        pub(crate) fn AzTreeView_new(items: AzTreeViewItemVec) -> AzTreeView { unsafe { transmute(azul::AzTreeView_new(transmute(items))) } }
+        pub(crate) fn AzTreeView_setItems(treeview: &mut AzTreeView, items: AzTreeViewItemVec) { unsafe { transmute(azul::AzTreeView_setItems(transmute(treeview), transmute(items))) } }
+        pub(crate) fn AzTreeView_withItems(treeview: &mut AzTreeView, items: AzTreeViewItemVec) -> AzTreeView { unsafe { transmute(azul::AzTreeView_withItems(transmute(treeview), transmute(items))) } }
+        pub(crate) fn AzTreeView_setSelectionMode(treeview: &mut AzTreeView, selection_mode: AzTreeViewSelectionMode) { unsafe { transmute(azul::AzTreeView_setSelectionMode(transmute(treeview), transmute(selection_mode))) } }
+        pub(crate) fn AzTreeView_withSelectionMode(treeview: &mut AzTreeView, selection_mode: AzTreeViewSelectionMode) -> AzTreeView { unsafe { transmute(azul::AzTreeView_withSelectionMode(transmute(treeview), transmute(selection_mode))) } }
+        pub(crate) fn AzTreeView_setFirstVisibleRow(treeview: &mut AzTreeView, first_visible_row: usize) { unsafe { transmute(azul::AzTreeView_setFirstVisibleRow(transmute(treeview), transmute(first_visible_row))) } }
+        pub(crate) fn AzTreeView_setMaxVisibleRows(treeview: &mut AzTreeView, max_visible_rows: usize) { unsafe { transmute(azul::AzTreeView_setMaxVisibleRows(transmute(treeview), transmute(max_visible_rows))) } }
+        pub(crate) fn AzTreeView_setOnExpand(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnExpandCallbackType) { unsafe { transmute(azul::AzTreeView_setOnExpand(transmute(treeview), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzTreeView_withOnExpand(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnExpandCallbackType) -> AzTreeView { unsafe { transmute(azul::AzTreeView_withOnExpand(transmute(treeview), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzTreeView_setOnSelect(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnSelectCallbackType) { unsafe { transmute(azul::AzTreeView_setOnSelect(transmute(treeview), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzTreeView_withOnSelect(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnSelectCallbackType) -> AzTreeView { unsafe { transmute(azul::AzTreeView_withOnSelect(transmute(treeview), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzTreeView_setOnLazyLoad(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnLazyLoadCallbackType) { unsafe { transmute(azul::AzTreeView_setOnLazyLoad(transmute(treeview), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzTreeView_withOnLazyLoad(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnLazyLoadCallbackType) -> AzTreeView { unsafe { transmute(azul::AzTreeView_withOnLazyLoad(transmute(treeview), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzTreeView_setOnScroll(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnScrollCallbackType) { unsafe { transmute(azul::AzTreeView_setOnScroll(transmute(treeview), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzTreeView_withOnScroll(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnScrollCallbackType) -> AzTreeView { unsafe { transmute(azul::AzTreeView_withOnScroll(transmute(treeview), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzTreeView_getVisibleRowCount(treeview: &AzTreeView) -> usize { unsafe { transmute(azul::AzTreeView_getVisibleRowCount(transmute(treeview))) } }
+        pub(crate) fn AzTreeView_setExpanded(treeview: &mut AzTreeView, item_id: usize, expanded: bool) -> bool { unsafe { transmute(azul::AzTreeView_setExpanded(transmute(treeview), transmute(item_id), transmute(expanded))) } }
+        pub(crate) fn AzTreeView_setChildren(treeview: &mut AzTreeView, item_id: usize, children: AzTreeViewItemVec) -> bool { unsafe { transmute(azul::AzTreeView_setChildren(transmute(treeview), transmute(item_id), transmute(children))) } }
+        pub(crate) fn AzTreeView_applySelection(treeview: &mut AzTreeView, item_id: usize, change: AzTreeViewSelectionChange) { unsafe { transmute(azul::AzTreeView_applySelection(transmute(treeview), transmute(item_id), transmute(change))) } }
         pub(crate) fn AzTreeView_dom(treeview: &mut AzTreeView) -> AzDom { unsafe { transmute(azul::AzTreeView_dom(transmute(treeview))) } }
+        pub(crate) fn AzTreeViewItem_new(id: usize, label: AzString) -> AzTreeViewItem { unsafe { transmute(azul::AzTreeViewItem_new(transmute(id), transmute(label))) } }
+        pub(crate) fn AzDataGrid_new(row_count: usize, column_count: usize) -> AzDataGrid { unsafe { transmute(azul::AzDataGrid_new(transmute(row_count), transmute(column_count))) } }
+        pub(crate) fn AzDataGrid_setColumnHeaders(datagrid: &mut AzDataGrid, column_headers: AzStringVec) { unsafe { transmute(azul::AzDataGrid_setColumnHeaders(transmute(datagrid), transmute(column_headers))) } }
+        pub(crate) fn AzDataGrid_withColumnHeaders(datagrid: &mut AzDataGrid, column_headers: AzStringVec) -> AzDataGrid { unsafe { transmute(azul::AzDataGrid_withColumnHeaders(transmute(datagrid), transmute(column_headers))) } }
+        pub(crate) fn AzDataGrid_setColumnWidths(datagrid: &mut AzDataGrid, column_widths: AzF32Vec) { unsafe { transmute(azul::AzDataGrid_setColumnWidths(transmute(datagrid), transmute(column_widths))) } }
+        pub(crate) fn AzDataGrid_withColumnWidths(datagrid: &mut AzDataGrid, column_widths: AzF32Vec) -> AzDataGrid { unsafe { transmute(azul::AzDataGrid_withColumnWidths(transmute(datagrid), transmute(column_widths))) } }
+        pub(crate) fn AzDataGrid_setRowHeights(datagrid: &mut AzDataGrid, row_heights: AzF32Vec) { unsafe { transmute(azul::AzDataGrid_setRowHeights(transmute(datagrid), transmute(row_heights))) } }
+        pub(crate) fn AzDataGrid_withRowHeights(datagrid: &mut AzDataGrid, row_heights: AzF32Vec) -> AzDataGrid { unsafe { transmute(azul::AzDataGrid_withRowHeights(transmute(datagrid), transmute(row_heights))) } }
+        pub(crate) fn AzDataGrid_setScrollPosition(datagrid: &mut AzDataGrid, first_visible_row: usize, first_visible_column: usize) { unsafe { transmute(azul::AzDataGrid_setScrollPosition(transmute(datagrid), transmute(first_visible_row), transmute(first_visible_column))) } }
+        pub(crate) fn AzDataGrid_withScrollPosition(datagrid: &mut AzDataGrid, first_visible_row: usize, first_visible_column: usize) -> AzDataGrid { unsafe { transmute(azul::AzDataGrid_withScrollPosition(transmute(datagrid), transmute(first_visible_row), transmute(first_visible_column))) } }
+        pub(crate) fn AzDataGrid_setSelection(datagrid: &mut AzDataGrid, selection: AzOptionDataGridRange) { unsafe { transmute(azul::AzDataGrid_setSelection(transmute(datagrid), transmute(selection))) } }
+        pub(crate) fn AzDataGrid_withSelection(datagrid: &mut AzDataGrid, selection: AzOptionDataGridRange) -> AzDataGrid { unsafe { transmute(azul::AzDataGrid_withSelection(transmute(datagrid), transmute(selection))) } }
+        pub(crate) fn AzDataGrid_setCellProvider(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridCellProviderCallbackType) { unsafe { transmute(azul::AzDataGrid_setCellProvider(transmute(datagrid), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDataGrid_withCellProvider(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridCellProviderCallbackType) -> AzDataGrid { unsafe { transmute(azul::AzDataGrid_withCellProvider(transmute(datagrid), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDataGrid_setCellStyle(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridCellStyleCallbackType) { unsafe { transmute(azul::AzDataGrid_setCellStyle(transmute(datagrid), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDataGrid_withCellStyle(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridCellStyleCallbackType) -> AzDataGrid { unsafe { transmute(azul::AzDataGrid_withCellStyle(transmute(datagrid), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDataGrid_setOnSelect(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnSelectCallbackType) { unsafe { transmute(azul::AzDataGrid_setOnSelect(transmute(datagrid), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDataGrid_withOnSelect(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnSelectCallbackType) -> AzDataGrid { unsafe { transmute(azul::AzDataGrid_withOnSelect(transmute(datagrid), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDataGrid_setOnScroll(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnScrollCallbackType) { unsafe { transmute(azul::AzDataGrid_setOnScroll(transmute(datagrid), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDataGrid_withOnScroll(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnScrollCallbackType) -> AzDataGrid { unsafe { transmute(azul::AzDataGrid_withOnScroll(transmute(datagrid), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDataGrid_setOnCellEdit(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnCellEditCallbackType) { unsafe { transmute(azul::AzDataGrid_setOnCellEdit(transmute(datagrid), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDataGrid_withOnCellEdit(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnCellEditCallbackType) -> AzDataGrid { unsafe { transmute(azul::AzDataGrid_withOnCellEdit(transmute(datagrid), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDataGrid_setOnColumnResize(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnColumnResizeCallbackType) { unsafe { transmute(azul::AzDataGrid_setOnColumnResize(transmute(datagrid), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDataGrid_withOnColumnResize(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnColumnResizeCallbackType) -> AzDataGrid { unsafe { transmute(azul::AzDataGrid_withOnColumnResize(transmute(datagrid), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDataGrid_setOnRowResize(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnRowResizeCallbackType) { unsafe { transmute(azul::AzDataGrid_setOnRowResize(transmute(datagrid), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDataGrid_withOnRowResize(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnRowResizeCallbackType) -> AzDataGrid { unsafe { transmute(azul::AzDataGrid_withOnRowResize(transmute(datagrid), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDataGrid_setOnCopyRange(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnCopyRangeCallbackType) { unsafe { transmute(azul::AzDataGrid_setOnCopyRange(transmute(datagrid), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDataGrid_withOnCopyRange(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnCopyRangeCallbackType) -> AzDataGrid { unsafe { transmute(azul::AzDataGrid_withOnCopyRange(transmute(datagrid), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDataGrid_setOnClearRange(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnClearRangeCallbackType) { unsafe { transmute(azul::AzDataGrid_setOnClearRange(transmute(datagrid), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDataGrid_withOnClearRange(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnClearRangeCallbackType) -> AzDataGrid { unsafe { transmute(azul::AzDataGrid_withOnClearRange(transmute(datagrid), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDataGrid_getColumnWidth(datagrid: &AzDataGrid, column: usize) -> f32 { unsafe { transmute(azul::AzDataGrid_getColumnWidth(transmute(datagrid), transmute(column))) } }
+        pub(crate) fn AzDataGrid_getRowHeight(datagrid: &AzDataGrid, row: usize) -> f32 { unsafe { transmute(azul::AzDataGrid_getRowHeight(transmute(datagrid), transmute(row))) } }
+        pub(crate) fn AzDataGrid_getColumnName(datagrid: &AzDataGrid, column: usize) -> AzString { unsafe { transmute(azul::AzDataGrid_getColumnName(transmute(datagrid), transmute(column))) } }
+        pub(crate) fn AzDataGrid_getCellText(datagrid: &mut AzDataGrid, row: usize, column: usize) -> AzString { unsafe { transmute(azul::AzDataGrid_getCellText(transmute(datagrid), transmute(row), transmute(column))) } }
+        pub(crate) fn AzDataGrid_getSelectionTsv(datagrid: &mut AzDataGrid) -> AzString { unsafe { transmute(azul::AzDataGrid_getSelectionTsv(transmute(datagrid))) } }
+        pub(crate) fn AzDataGrid_getClippedSelection(datagrid: &AzDataGrid) -> AzOptionDataGridRange { unsafe { transmute(azul::AzDataGrid_getClippedSelection(transmute(datagrid))) } }
+        pub(crate) fn AzDataGrid_dom(datagrid: &mut AzDataGrid) -> AzDom { unsafe { transmute(azul::AzDataGrid_dom(transmute(datagrid))) } }
+        pub(crate) fn AzDataGridRange_new(row: usize, column: usize) -> AzDataGridRange { unsafe { transmute(azul::AzDataGridRange_new(transmute(row), transmute(column))) } }
+        pub(crate) fn AzDataGridRange_getTopLeft(datagridrange: &AzDataGridRange) -> AzDataGridCell { unsafe { transmute(azul::AzDataGridRange_getTopLeft(transmute(datagridrange))) } }
+        pub(crate) fn AzDataGridRange_getBottomRight(datagridrange: &AzDataGridRange) -> AzDataGridCell { unsafe { transmute(azul::AzDataGridRange_getBottomRight(transmute(datagridrange))) } }
+        pub(crate) fn AzDataGridRange_contains(datagridrange: &AzDataGridRange, row: usize, column: usize) -> bool { unsafe { transmute(azul::AzDataGridRange_contains(transmute(datagridrange), transmute(row), transmute(column))) } }
+        pub(crate) fn AzDataGridRange_getCellCount(datagridrange: &AzDataGridRange) -> usize { unsafe { transmute(azul::AzDataGridRange_getCellCount(transmute(datagridrange))) } }
+        pub(crate) fn AzDate_new(year: i32, month: u8, day: u8) -> AzDate { unsafe { transmute(azul::AzDate_new(transmute(year), transmute(month), transmute(day))) } }
+        pub(crate) fn AzDate_today() -> AzDate { unsafe { transmute(azul::AzDate_today()) } }
+        pub(crate) fn AzDate_isValid(date: &AzDate) -> bool { unsafe { transmute(azul::AzDate_isValid(transmute(date))) } }
+        pub(crate) fn AzDate_weekday(date: &AzDate) -> AzWeekday { unsafe { transmute(azul::AzDate_weekday(transmute(date))) } }
+        pub(crate) fn AzDate_addDays(date: &AzDate, days: i64) -> AzDate { unsafe { transmute(azul::AzDate_addDays(transmute(date), transmute(days))) } }
+        pub(crate) fn AzDate_addMonths(date: &AzDate, months: i64) -> AzDate { unsafe { transmute(azul::AzDate_addMonths(transmute(date), transmute(months))) } }
+        pub(crate) fn AzDate_clampTo(date: &AzDate, minimum: AzOptionDate, maximum: AzOptionDate) -> AzDate { unsafe { transmute(azul::AzDate_clampTo(transmute(date), transmute(minimum), transmute(maximum))) } }
+        pub(crate) fn AzTime_new(hour: u8, minute: u8, second: u8) -> AzTime { unsafe { transmute(azul::AzTime_new(transmute(hour), transmute(minute), transmute(second))) } }
+        pub(crate) fn AzTime_isValid(time: &AzTime) -> bool { unsafe { transmute(azul::AzTime_isValid(transmute(time))) } }
+        pub(crate) fn AzDatePicker_new(date: AzDate) -> AzDatePicker { unsafe { transmute(azul::AzDatePicker_new(transmute(date))) } }
+        pub(crate) fn AzDatePicker_setState(datepicker: &mut AzDatePicker, state: AzDatePickerState) { unsafe { transmute(azul::AzDatePicker_setState(transmute(datepicker), transmute(state))) } }
+        pub(crate) fn AzDatePicker_withState(datepicker: &mut AzDatePicker, state: AzDatePickerState) -> AzDatePicker { unsafe { transmute(azul::AzDatePicker_withState(transmute(datepicker), transmute(state))) } }
+        pub(crate) fn AzDatePicker_setMinimumDate(datepicker: &mut AzDatePicker, minimum_date: AzOptionDate) { unsafe { transmute(azul::AzDatePicker_setMinimumDate(transmute(datepicker), transmute(minimum_date))) } }
+        pub(crate) fn AzDatePicker_withMinimumDate(datepicker: &mut AzDatePicker, minimum_date: AzOptionDate) -> AzDatePicker { unsafe { transmute(azul::AzDatePicker_withMinimumDate(transmute(datepicker), transmute(minimum_date))) } }
+        pub(crate) fn AzDatePicker_setMaximumDate(datepicker: &mut AzDatePicker, maximum_date: AzOptionDate) { unsafe { transmute(azul::AzDatePicker_setMaximumDate(transmute(datepicker), transmute(maximum_date))) } }
+        pub(crate) fn AzDatePicker_withMaximumDate(datepicker: &mut AzDatePicker, maximum_date: AzOptionDate) -> AzDatePicker { unsafe { transmute(azul::AzDatePicker_withMaximumDate(transmute(datepicker), transmute(maximum_date))) } }
+        pub(crate) fn AzDatePicker_setFirstDayOfWeek(datepicker: &mut AzDatePicker, first_day_of_week: AzWeekday) { unsafe { transmute(azul::AzDatePicker_setFirstDayOfWeek(transmute(datepicker), transmute(first_day_of_week))) } }
+        pub(crate) fn AzDatePicker_withFirstDayOfWeek(datepicker: &mut AzDatePicker, first_day_of_week: AzWeekday) -> AzDatePicker { unsafe { transmute(azul::AzDatePicker_withFirstDayOfWeek(transmute(datepicker), transmute(first_day_of_week))) } }
+        pub(crate) fn AzDatePicker_setOnDateChange(datepicker: &mut AzDatePicker, data: AzRefAny, callback: AzDatePickerOnDateChangeCallbackType) { unsafe { transmute(azul::AzDatePicker_setOnDateChange(transmute(datepicker), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDatePicker_withOnDateChange(datepicker: &mut AzDatePicker, data: AzRefAny, callback: AzDatePickerOnDateChangeCallbackType) -> AzDatePicker { unsafe { transmute(azul::AzDatePicker_withOnDateChange(transmute(datepicker), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDatePicker_dom(datepicker: &mut AzDatePicker) -> AzDom { unsafe { transmute(azul::AzDatePicker_dom(transmute(datepicker))) } }
+        pub(crate) fn AzTimePicker_new(time: AzTime) -> AzTimePicker { unsafe { transmute(azul::AzTimePicker_new(transmute(time))) } }
+        pub(crate) fn AzTimePicker_setShowSeconds(timepicker: &mut AzTimePicker, show_seconds: bool) { unsafe { transmute(azul::AzTimePicker_setShowSeconds(transmute(timepicker), transmute(show_seconds))) } }
+        pub(crate) fn AzTimePicker_withShowSeconds(timepicker: &mut AzTimePicker, show_seconds: bool) -> AzTimePicker { unsafe { transmute(azul::AzTimePicker_withShowSeconds(transmute(timepicker), transmute(show_seconds))) } }
+        pub(crate) fn AzTimePicker_setOnTimeChange(timepicker: &mut AzTimePicker, data: AzRefAny, callback: AzTimePickerOnTimeChangeCallbackType) { unsafe { transmute(azul::AzTimePicker_setOnTimeChange(transmute(timepicker), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzTimePicker_withOnTimeChange(timepicker: &mut AzTimePicker, data: AzRefAny, callback: AzTimePickerOnTimeChangeCallbackType) -> AzTimePicker { unsafe { transmute(azul::AzTimePicker_withOnTimeChange(transmute(timepicker), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzTimePicker_dom(timepicker: &mut AzTimePicker) -> AzDom { unsafe { transmute(azul::AzTimePicker_dom(transmute(timepicker))) } }
+        pub(crate) fn AzDateTimePicker_new(date: AzDate) -> AzDateTimePicker { unsafe { transmute(azul::AzDateTimePicker_new(transmute(date))) } }
+        pub(crate) fn AzDateTimePicker_setState(datetimepicker: &mut AzDateTimePicker, state: AzDateTimePickerState) { unsafe { transmute(azul::AzDateTimePicker_setState(transmute(datetimepicker), transmute(state))) } }
+        pub(crate) fn AzDateTimePicker_withState(datetimepicker: &mut AzDateTimePicker, state: AzDateTimePickerState) -> AzDateTimePicker { unsafe { transmute(azul::AzDateTimePicker_withState(transmute(datetimepicker), transmute(state))) } }
+        pub(crate) fn AzDateTimePicker_setMinimumDate(datetimepicker: &mut AzDateTimePicker, minimum_date: AzOptionDate) { unsafe { transmute(azul::AzDateTimePicker_setMinimumDate(transmute(datetimepicker), transmute(minimum_date))) } }
+        pub(crate) fn AzDateTimePicker_withMinimumDate(datetimepicker: &mut AzDateTimePicker, minimum_date: AzOptionDate) -> AzDateTimePicker { unsafe { transmute(azul::AzDateTimePicker_withMinimumDate(transmute(datetimepicker), transmute(minimum_date))) } }
+        pub(crate) fn AzDateTimePicker_setMaximumDate(datetimepicker: &mut AzDateTimePicker, maximum_date: AzOptionDate) { unsafe { transmute(azul::AzDateTimePicker_setMaximumDate(transmute(datetimepicker), transmute(maximum_date))) } }
+        pub(crate) fn AzDateTimePicker_withMaximumDate(datetimepicker: &mut AzDateTimePicker, maximum_date: AzOptionDate) -> AzDateTimePicker { unsafe { transmute(azul::AzDateTimePicker_withMaximumDate(transmute(datetimepicker), transmute(maximum_date))) } }
+        pub(crate) fn AzDateTimePicker_setFirstDayOfWeek(datetimepicker: &mut AzDateTimePicker, first_day_of_week: AzWeekday) { unsafe { transmute(azul::AzDateTimePicker_setFirstDayOfWeek(transmute(datetimepicker), transmute(first_day_of_week))) } }
+        pub(crate) fn AzDateTimePicker_withFirstDayOfWeek(datetimepicker: &mut AzDateTimePicker, first_day_of_week: AzWeekday) -> AzDateTimePicker { unsafe { transmute(azul::AzDateTimePicker_withFirstDayOfWeek(transmute(datetimepicker), transmute(first_day_of_week))) } }
+        pub(crate) fn AzDateTimePicker_setShowTime(datetimepicker: &mut AzDateTimePicker, show_time: bool, show_seconds: bool) { unsafe { transmute(azul::AzDateTimePicker_setShowTime(transmute(datetimepicker), transmute(show_time), transmute(show_seconds))) } }
+        pub(crate) fn AzDateTimePicker_withShowTime(datetimepicker: &mut AzDateTimePicker, show_time: bool, show_seconds: bool) -> AzDateTimePicker { unsafe { transmute(azul::AzDateTimePicker_withShowTime(transmute(datetimepicker), transmute(show_time), transmute(show_seconds))) } }
+        pub(crate) fn AzDateTimePicker_setOnDateTimeChange(datetimepicker: &mut AzDateTimePicker, data: AzRefAny, callback: AzDateTimePickerOnDateTimeChangeCallbackType) { unsafe { transmute(azul::AzDateTimePicker_setOnDateTimeChange(transmute(datetimepicker), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDateTimePicker_withOnDateTimeChange(datetimepicker: &mut AzDateTimePicker, data: AzRefAny, callback: AzDateTimePickerOnDateTimeChangeCallbackType) -> AzDateTimePicker { unsafe { transmute(azul::AzDateTimePicker_withOnDateTimeChange(transmute(datetimepicker), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDateTimePicker_dom(datetimepicker: &mut AzDateTimePicker) -> AzDom { unsafe { transmute(azul::AzDateTimePicker_dom(transmute(datetimepicker))) } }
+        pub(crate) fn AzDialogResult_isAccepted(dialogresult: &AzDialogResult) -> bool { unsafe { transmute(azul::AzDialogResult_isAccepted(transmute(dialogresult))) } }
+        pub(crate) fn AzDialog_msgBox(title: AzString, message: AzString) -> AzDialog { unsafe { transmute(azul::AzDialog_msgBox(transmute(title), transmute(message))) } }
+        pub(crate) fn AzDialog_confirm(title: AzString, message: AzString) -> AzDialog { unsafe { transmute(azul::AzDialog_confirm(transmute(title), transmute(message))) } }
+        pub(crate) fn AzDialog_prompt(title: AzString, message: AzString, default_value: AzString) -> AzDialog { unsafe { transmute(azul::AzDialog_prompt(transmute(title), transmute(message), transmute(default_value))) } }
+        pub(crate) fn AzDialog_custom(title: AzString, content: AzDom) -> AzDialog { unsafe { transmute(azul::AzDialog_custom(transmute(title), transmute(content))) } }
+        pub(crate) fn AzDialog_setIcon(dialog: &mut AzDialog, icon: AzDialogIcon) { unsafe { transmute(azul::AzDialog_setIcon(transmute(dialog), transmute(icon))) } }
+        pub(crate) fn AzDialog_withIcon(dialog: &mut AzDialog, icon: AzDialogIcon) -> AzDialog { unsafe { transmute(azul::AzDialog_withIcon(transmute(dialog), transmute(icon))) } }
+        pub(crate) fn AzDialog_setButtons(dialog: &mut AzDialog, buttons: AzDialogButtons) { unsafe { transmute(azul::AzDialog_setButtons(transmute(dialog), transmute(buttons))) } }
+        pub(crate) fn AzDialog_withButtons(dialog: &mut AzDialog, buttons: AzDialogButtons) -> AzDialog { unsafe { transmute(azul::AzDialog_withButtons(transmute(dialog), transmute(buttons))) } }
+        pub(crate) fn AzDialog_setOnClose(dialog: &mut AzDialog, data: AzRefAny, callback: AzDialogOnCloseCallbackType) { unsafe { transmute(azul::AzDialog_setOnClose(transmute(dialog), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDialog_withOnClose(dialog: &mut AzDialog, data: AzRefAny, callback: AzDialogOnCloseCallbackType) -> AzDialog { unsafe { transmute(azul::AzDialog_withOnClose(transmute(dialog), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDialog_dom(dialog: &mut AzDialog) -> AzDom { unsafe { transmute(azul::AzDialog_dom(transmute(dialog))) } }
+        pub(crate) fn AzDialog_domOver(dialog: &mut AzDialog, content: AzDom) -> AzDom { unsafe { transmute(azul::AzDialog_domOver(transmute(dialog), transmute(content))) } }
+        pub(crate) fn AzSplitterPane_new(content: AzDom) -> AzSplitterPane { unsafe { transmute(azul::AzSplitterPane_new(transmute(content))) } }
+        pub(crate) fn AzSplitterPane_setSize(splitterpane: &mut AzSplitterPane, size: f32) { unsafe { transmute(azul::AzSplitterPane_setSize(transmute(splitterpane), transmute(size))) } }
+        pub(crate) fn AzSplitterPane_withSize(splitterpane: &mut AzSplitterPane, size: f32) -> AzSplitterPane { unsafe { transmute(azul::AzSplitterPane_withSize(transmute(splitterpane), transmute(size))) } }
+        pub(crate) fn AzSplitterPane_setMinSize(splitterpane: &mut AzSplitterPane, min_size: f32) { unsafe { transmute(azul::AzSplitterPane_setMinSize(transmute(splitterpane), transmute(min_size))) } }
+        pub(crate) fn AzSplitterPane_withMinSize(splitterpane: &mut AzSplitterPane, min_size: f32) -> AzSplitterPane { unsafe { transmute(azul::AzSplitterPane_withMinSize(transmute(splitterpane), transmute(min_size))) } }
+        pub(crate) fn AzSplitterPane_setMaxSize(splitterpane: &mut AzSplitterPane, max_size: f32) { unsafe { transmute(azul::AzSplitterPane_setMaxSize(transmute(splitterpane), transmute(max_size))) } }
+        pub(crate) fn AzSplitterPane_withMaxSize(splitterpane: &mut AzSplitterPane, max_size: f32) -> AzSplitterPane { unsafe { transmute(azul::AzSplitterPane_withMaxSize(transmute(splitterpane), transmute(max_size))) } }
+        pub(crate) fn AzSplitterPane_setCollapsed(splitterpane: &mut AzSplitterPane, collapsed: bool) { unsafe { transmute(azul::AzSplitterPane_setCollapsed(transmute(splitterpane), transmute(collapsed))) } }
+        pub(crate) fn AzSplitterPane_withCollapsed(splitterpane: &mut AzSplitterPane, collapsed: bool) -> AzSplitterPane { unsafe { transmute(azul::AzSplitterPane_withCollapsed(transmute(splitterpane), transmute(collapsed))) } }
+        pub(crate) fn AzSplitter_new(direction: AzSplitterDirection) -> AzSplitter { unsafe { transmute(azul::AzSplitter_new(transmute(direction))) } }
+        pub(crate) fn AzSplitter_withPane(splitter: &mut AzSplitter, pane: AzSplitterPane) -> AzSplitter { unsafe { transmute(azul::AzSplitter_withPane(transmute(splitter), transmute(pane))) } }
+        pub(crate) fn AzSplitter_addPane(splitter: &mut AzSplitter, pane: AzSplitterPane) { unsafe { transmute(azul::AzSplitter_addPane(transmute(splitter), transmute(pane))) } }
+        pub(crate) fn AzSplitter_setHandleSize(splitter: &mut AzSplitter, handle_size: f32) { unsafe { transmute(azul::AzSplitter_setHandleSize(transmute(splitter), transmute(handle_size))) } }
+        pub(crate) fn AzSplitter_withHandleSize(splitter: &mut AzSplitter, handle_size: f32) -> AzSplitter { unsafe { transmute(azul::AzSplitter_withHandleSize(transmute(splitter), transmute(handle_size))) } }
+        pub(crate) fn AzSplitter_getState(splitter: &AzSplitter) -> AzSplitterState { unsafe { transmute(azul::AzSplitter_getState(transmute(splitter))) } }
+        pub(crate) fn AzSplitter_setState(splitter: &mut AzSplitter, state: AzSplitterState) { unsafe { transmute(azul::AzSplitter_setState(transmute(splitter), transmute(state))) } }
+        pub(crate) fn AzSplitter_withState(splitter: &mut AzSplitter, state: AzSplitterState) -> AzSplitter { unsafe { transmute(azul::AzSplitter_withState(transmute(splitter), transmute(state))) } }
+        pub(crate) fn AzSplitter_setOnResize(splitter: &mut AzSplitter, data: AzRefAny, callback: AzSplitterOnResizeCallbackType) { unsafe { transmute(azul::AzSplitter_setOnResize(transmute(splitter), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzSplitter_withOnResize(splitter: &mut AzSplitter, data: AzRefAny, callback: AzSplitterOnResizeCallbackType) -> AzSplitter { unsafe { transmute(azul::AzSplitter_withOnResize(transmute(splitter), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzSplitter_dom(splitter: &mut AzSplitter) -> AzDom { unsafe { transmute(azul::AzSplitter_dom(transmute(splitter))) } }
         pub(crate) fn AzDropDown_new(choices: AzStringVec) -> AzDropDown { unsafe { transmute(azul::AzDropDown_new(transmute(choices))) } }
         pub(crate) fn AzDropDown_dom(dropdown: &mut AzDropDown) -> AzDom { unsafe { transmute(azul::AzDropDown_dom(transmute(dropdown))) } }
         pub(crate) fn AzCssPropertyCache_delete(object: &mut AzCssPropertyCache) { unsafe { transmute(azul::AzCssPropertyCache_delete(transmute(object))) } }
@@ -11163,6 +13164,8 @@ mod dll {
         pub(crate) fn AzFontRef_getBytes(fontref: &AzFontRef) -> AzU8Vec { unsafe { transmute(azul::AzFontRef_getBytes(transmute(fontref))) } }
         pub(crate) fn AzFontRef_getFontMetrics(fontref: &AzFontRef) -> AzFontMetrics { unsafe { transmute(azul::AzFontRef_getFontMetrics(transmute(fontref))) } }
         pub(crate) fn AzFontRef_shapeText(fontref: &AzFontRef, text: AzRefstr, options: AzResolvedTextLayoutOptions) -> AzInlineText { unsafe { transmute(azul::AzFontRef_shapeText(transmute(fontref), transmute(text), transmute(options))) } }
+        pub(crate) fn AzFontRef_measureText(fontref: &AzFontRef, text: AzRefstr, options: AzResolvedTextLayoutOptions) -> AzTextMetrics { unsafe { transmute(azul::AzFontRef_measureText(transmute(fontref), transmute(text), transmute(options))) } }
+        pub(crate) fn AzFontRef_textToPath(fontref: &AzFontRef, text: AzRefstr, options: AzResolvedTextLayoutOptions) -> AzSvgMultiPolygon { unsafe { transmute(azul::AzFontRef_textToPath(transmute(fontref), transmute(text), transmute(options))) } }
         pub(crate) fn AzFontRef_getHash(fontref: &AzFontRef) -> u64 { unsafe { transmute(azul::AzFontRef_getHash(transmute(fontref))) } }
         pub(crate) fn AzFontRef_delete(object: &mut AzFontRef) { unsafe { transmute(azul::AzFontRef_delete(transmute(object))) } }
         pub(crate) fn AzFontRef_deepCopy(object: &AzFontRef) -> AzFontRef { unsafe { transmute(azul::AzFontRef_deepCopy(transmute(object))) } }
@@ -11312,10 +13315,16 @@ mod dll {
         pub(crate) fn AzString_trim(string: &AzString) -> AzString { unsafe { transmute(azul::AzString_trim(transmute(string))) } }
         pub(crate) fn AzString_asRefstr(string: &AzString) -> AzRefstr { unsafe { transmute(azul::AzString_asRefstr(transmute(string))) } }
         pub(crate) fn AzListViewRowVec_delete(object: &mut AzListViewRowVec) { unsafe { transmute(azul::AzListViewRowVec_delete(transmute(object))) } }
+        pub(crate) fn AzTreeViewItemVec_delete(object: &mut AzTreeViewItemVec) { unsafe { transmute(azul::AzTreeViewItemVec_delete(transmute(object))) } }
+        pub(crate) fn AzSplitterPaneVec_delete(object: &mut AzSplitterPaneVec) { unsafe { transmute(azul::AzSplitterPaneVec_delete(transmute(object))) } }
+        pub(crate) fn AzSplitterPaneStateVec_delete(object: &mut AzSplitterPaneStateVec) { unsafe { transmute(azul::AzSplitterPaneStateVec_delete(transmute(object))) } }
         pub(crate) fn AzStyleFilterVec_delete(object: &mut AzStyleFilterVec) { unsafe { transmute(azul::AzStyleFilterVec_delete(transmute(object))) } }
         pub(crate) fn AzLogicalRectVec_delete(object: &mut AzLogicalRectVec) { unsafe { transmute(azul::AzLogicalRectVec_delete(transmute(object))) } }
         pub(crate) fn AzNodeTypeIdInfoMapVec_delete(object: &mut AzNodeTypeIdInfoMapVec) { unsafe { transmute(azul::AzNodeTypeIdInfoMapVec_delete(transmute(object))) } }
         pub(crate) fn AzInputOutputTypeIdInfoMapVec_delete(object: &mut AzInputOutputTypeIdInfoMapVec) { unsafe { transmute(azul::AzInputOutputTypeIdInfoMapVec_delete(transmute(object))) } }
+        pub(crate) fn AzNodeGraphNodeIdVec_delete(object: &mut AzNodeGraphNodeIdVec) { unsafe { transmute(azul::AzNodeGraphNodeIdVec_delete(transmute(object))) } }
+        pub(crate) fn AzNodeGraphEditOpVec_delete(object: &mut AzNodeGraphEditOpVec) { unsafe { transmute(azul::AzNodeGraphEditOpVec_delete(transmute(object))) } }
+        pub(crate) fn AzNodeGraphEditVec_delete(object: &mut AzNodeGraphEditVec) { unsafe { transmute(azul::AzNodeGraphEditVec_delete(transmute(object))) } }
         pub(crate) fn AzNodeIdNodeMapVec_delete(object: &mut AzNodeIdNodeMapVec) { unsafe { transmute(azul::AzNodeIdNodeMapVec_delete(transmute(object))) } }
         pub(crate) fn AzInputOutputTypeIdVec_delete(object: &mut AzInputOutputTypeIdVec) { unsafe { transmute(azul::AzInputOutputTypeIdVec_delete(transmute(object))) } }
         pub(crate) fn AzNodeTypeFieldVec_delete(object: &mut AzNodeTypeFieldVec) { unsafe { transmute(azul::AzNodeTypeFieldVec_delete(transmute(object))) } }
@@ -11324,6 +13333,7 @@ mod dll {
         pub(crate) fn AzOutputConnectionVec_delete(object: &mut AzOutputConnectionVec) { unsafe { transmute(azul::AzOutputConnectionVec_delete(transmute(object))) } }
         pub(crate) fn AzInputNodeAndIndexVec_delete(object: &mut AzInputNodeAndIndexVec) { unsafe { transmute(azul::AzInputNodeAndIndexVec_delete(transmute(object))) } }
         pub(crate) fn AzAccessibilityStateVec_delete(object: &mut AzAccessibilityStateVec) { unsafe { transmute(azul::AzAccessibilityStateVec_delete(transmute(object))) } }
+        pub(crate) fn AzKeyboardShortcutVec_delete(object: &mut AzKeyboardShortcutVec) { unsafe { transmute(azul::AzKeyboardShortcutVec_delete(transmute(object))) } }
         pub(crate) fn AzMenuItemVec_delete(object: &mut AzMenuItemVec) { unsafe { transmute(azul::AzMenuItemVec_delete(transmute(object))) } }
         pub(crate) fn AzTessellatedSvgNodeVec_asRefVec(tessellatedsvgnodevec: &AzTessellatedSvgNodeVec) -> AzTessellatedSvgNodeVecRef { unsafe { transmute(azul::AzTessellatedSvgNodeVec_asRefVec(transmute(tessellatedsvgnodevec))) } }
         pub(crate) fn AzTessellatedSvgNodeVec_delete(object: &mut AzTessellatedSvgNodeVec) { unsafe { transmute(azul::AzTessellatedSvgNodeVec_delete(transmute(object))) } }
@@ -11335,6 +13345,8 @@ mod dll {
         pub(crate) fn AzInlineLineVec_delete(object: &mut AzInlineLineVec) { unsafe { transmute(azul::AzInlineLineVec_delete(transmute(object))) } }
         pub(crate) fn AzInlineWordVec_delete(object: &mut AzInlineWordVec) { unsafe { transmute(azul::AzInlineWordVec_delete(transmute(object))) } }
         pub(crate) fn AzInlineGlyphVec_delete(object: &mut AzInlineGlyphVec) { unsafe { transmute(azul::AzInlineGlyphVec_delete(transmute(object))) } }
+        pub(crate) fn AzTextLineMetricsVec_delete(object: &mut AzTextLineMetricsVec) { unsafe { transmute(azul::AzTextLineMetricsVec_delete(transmute(object))) } }
+        pub(crate) fn AzInlineTextRunVec_delete(object: &mut AzInlineTextRunVec) { unsafe { transmute(azul::AzInlineTextRunVec_delete(transmute(object))) } }
         pub(crate) fn AzInlineTextHitVec_delete(object: &mut AzInlineTextHitVec) { unsafe { transmute(azul::AzInlineTextHitVec_delete(transmute(object))) } }
         pub(crate) fn AzMonitorVec_delete(object: &mut AzMonitorVec) { unsafe { transmute(azul::AzMonitorVec_delete(transmute(object))) } }
         pub(crate) fn AzVideoModeVec_delete(object: &mut AzVideoModeVec) { unsafe { transmute(azul::AzVideoModeVec_delete(transmute(object))) } }
@@ -11362,6 +13374,9 @@ mod dll {
         pub(crate) fn AzCssDeclarationVec_delete(object: &mut AzCssDeclarationVec) { unsafe { transmute(azul::AzCssDeclarationVec_delete(transmute(object))) } }
         pub(crate) fn AzCssPathSelectorVec_delete(object: &mut AzCssPathSelectorVec) { unsafe { transmute(azul::AzCssPathSelectorVec_delete(transmute(object))) } }
         pub(crate) fn AzStylesheetVec_delete(object: &mut AzStylesheetVec) { unsafe { transmute(azul::AzStylesheetVec_delete(transmute(object))) } }
+        pub(crate) fn AzCssFontFaceVec_delete(object: &mut AzCssFontFaceVec) { unsafe { transmute(azul::AzCssFontFaceVec_delete(transmute(object))) } }
+        pub(crate) fn AzCssFontFaceSourceVec_delete(object: &mut AzCssFontFaceSourceVec) { unsafe { transmute(azul::AzCssFontFaceSourceVec_delete(transmute(object))) } }
+        pub(crate) fn AzCssUnicodeRangeVec_delete(object: &mut AzCssUnicodeRangeVec) { unsafe { transmute(azul::AzCssUnicodeRangeVec_delete(transmute(object))) } }
         pub(crate) fn AzCssRuleBlockVec_delete(object: &mut AzCssRuleBlockVec) { unsafe { transmute(azul::AzCssRuleBlockVec_delete(transmute(object))) } }
         pub(crate) fn AzU16Vec_delete(object: &mut AzU16Vec) { unsafe { transmute(azul::AzU16Vec_delete(transmute(object))) } }
         pub(crate) fn AzF32Vec_delete(object: &mut AzF32Vec) { unsafe { transmute(azul::AzF32Vec_delete(transmute(object))) } }
@@ -11396,6 +13411,7 @@ mod dll {
             pub(crate) fn AzApp_new(_:  AzRefAny, _:  AzAppConfig) -> AzApp;
             pub(crate) fn AzApp_addWindow(_:  &mut AzApp, _:  AzWindowCreateOptions);
             pub(crate) fn AzApp_addImage(_:  &mut AzApp, _:  AzString, _:  AzImageRef);
+            pub(crate) fn AzApp_addFont(_:  &mut AzApp, _:  AzString, _:  AzU8Vec) -> bool;
             pub(crate) fn AzApp_getMonitors(_:  &AzApp) -> AzMonitorVec;
             pub(crate) fn AzApp_run(_:  &AzApp, _:  AzWindowCreateOptions);
             pub(crate) fn AzApp_delete(_:  &mut AzApp);
@@ -11470,6 +13486,7 @@ mod dll {
             pub(crate) fn AzHidpiAdjustedBounds_getPhysicalSize(_:  &AzHidpiAdjustedBounds) -> AzPhysicalSizeU32;
             pub(crate) fn AzHidpiAdjustedBounds_getHidpiFactor(_:  &AzHidpiAdjustedBounds) -> f32;
             pub(crate) fn AzInlineText_hitTest(_:  &AzInlineText, _:  AzLogicalPosition) -> AzInlineTextHitVec;
+            pub(crate) fn AzInlineText_getTextMetrics(_:  &AzInlineText) -> AzTextMetrics;
             pub(crate) fn AzResolvedTextLayoutOptions_default() -> AzResolvedTextLayoutOptions;
             pub(crate) fn AzRenderImageCallbackInfo_getGlContext(_:  &AzRenderImageCallbackInfo) -> AzOptionGl;
             pub(crate) fn AzRenderImageCallbackInfo_getBounds(_:  &AzRenderImageCallbackInfo) -> AzHidpiAdjustedBounds;
@@ -11497,6 +13514,7 @@ mod dll {
             pub(crate) fn AzLayoutCallbackInfo_getGlContext(_:  &AzLayoutCallbackInfo) -> AzOptionGl;
             pub(crate) fn AzLayoutCallbackInfo_getSystemFonts(_:  &AzLayoutCallbackInfo) -> AzStringPairVec;
             pub(crate) fn AzLayoutCallbackInfo_getImage(_:  &AzLayoutCallbackInfo, _:  AzString) -> AzOptionImageRef;
+            pub(crate) fn AzLayoutCallbackInfo_measureText(_:  &AzLayoutCallbackInfo, _:  AzString, _:  AzStyleFontFamilyVec, _:  AzResolvedTextLayoutOptions) -> AzOptionTextMetrics;
             pub(crate) fn AzDom_new(_:  AzNodeType) -> AzDom;
             pub(crate) fn AzDom_body() -> AzDom;
             pub(crate) fn AzDom_div() -> AzDom;
@@ -11550,6 +13568,11 @@ mod dll {
             pub(crate) fn AzDom_withMenuBar(_:  &mut AzDom, _:  AzMenu) -> AzDom;
             pub(crate) fn AzDom_setContextMenu(_:  &mut AzDom, _:  AzMenu);
             pub(crate) fn AzDom_withContextMenu(_:  &mut AzDom, _:  AzMenu) -> AzDom;
+            pub(crate) fn AzDom_setTooltip(_:  &mut AzDom, _:  AzTooltip);
+            pub(crate) fn AzDom_withTooltip(_:  &mut AzDom, _:  AzTooltip) -> AzDom;
+            pub(crate) fn AzDom_setKeyboardShortcuts(_:  &mut AzDom, _:  AzKeyboardShortcutVec);
+            pub(crate) fn AzDom_withKeyboardShortcuts(_:  &mut AzDom, _:  AzKeyboardShortcutVec) -> AzDom;
+            pub(crate) fn AzDom_makeInert(_:  &mut AzDom);
             pub(crate) fn AzDom_hash(_:  &AzDom) -> u64;
             pub(crate) fn AzDom_nodeCount(_:  &AzDom) -> usize;
             pub(crate) fn AzDom_getHtmlString(_:  &mut AzDom) -> AzString;
@@ -11587,8 +13610,13 @@ mod dll {
             pub(crate) fn AzNodeData_setAccessibilityInfo(_:  &mut AzNodeData, _:  AzAccessibilityInfo);
             pub(crate) fn AzNodeData_setMenuBar(_:  &mut AzNodeData, _:  AzMenu);
             pub(crate) fn AzNodeData_setContextMenu(_:  &mut AzNodeData, _:  AzMenu);
+            pub(crate) fn AzNodeData_setTooltip(_:  &mut AzNodeData, _:  AzTooltip);
+            pub(crate) fn AzNodeData_setKeyboardShortcuts(_:  &mut AzNodeData, _:  AzKeyboardShortcutVec);
             pub(crate) fn AzNodeData_hash(_:  &AzNodeData) -> u64;
             pub(crate) fn AzOn_intoEventFilter(_:  AzOn) -> AzEventFilter;
+            pub(crate) fn AzTooltip_text(_:  AzString) -> AzTooltip;
+            pub(crate) fn AzTooltip_dom(_:  AzDom) -> AzTooltip;
+            pub(crate) fn AzTooltip_withDelay(_:  &mut AzTooltip, _:  AzDuration) -> AzTooltip;
             pub(crate) fn AzMenu_new(_:  AzMenuItemVec) -> AzMenu;
             pub(crate) fn AzMenu_setPopupPosition(_:  &mut AzMenu, _:  AzMenuPopupPosition);
             pub(crate) fn AzMenu_withPopupPosition(_:  &mut AzMenu, _:  AzMenuPopupPosition) -> AzMenu;
@@ -11599,6 +13627,8 @@ mod dll {
             pub(crate) fn AzStringMenuItem_withChild(_:  &mut AzStringMenuItem, _:  AzMenuItem) -> AzStringMenuItem;
             pub(crate) fn AzStringMenuItem_setChildren(_:  &mut AzStringMenuItem, _:  AzMenuItemVec);
             pub(crate) fn AzStringMenuItem_withChildren(_:  &mut AzStringMenuItem, _:  AzMenuItemVec) -> AzStringMenuItem;
+            pub(crate) fn AzVirtualKeyCodeCombo_matches(_:  &AzVirtualKeyCodeCombo, _:  AzKeyboardState) -> bool;
+            pub(crate) fn AzKeyboardShortcut_new(_:  AzVirtualKeyCodeCombo, _:  AzRefAny, _:  AzCallbackType) -> AzKeyboardShortcut;
             pub(crate) fn AzMenuCallback_new(_:  AzRefAny, _:  AzCallbackType) -> AzMenuCallback;
             pub(crate) fn AzCss_empty() -> AzCss;
             pub(crate) fn AzCss_fromString(_:  AzString) -> AzCss;
@@ -11690,12 +13720,186 @@ mod dll {
             pub(crate) fn AzFrame_setFlexGrow(_:  &mut AzFrame, _:  f32);
             pub(crate) fn AzFrame_withFlexGrow(_:  &mut AzFrame, _:  f32) -> AzFrame;
             pub(crate) fn AzFrame_dom(_:  &mut AzFrame) -> AzDom;
+            pub(crate) fn AzNodeGraph_fromJson(_:  AzString) -> AzResultNodeGraphNodeGraphError;
+            pub(crate) fn AzNodeGraph_toJson(_:  &AzNodeGraph) -> AzString;
             pub(crate) fn AzNodeGraph_dom(_:  &mut AzNodeGraph) -> AzDom;
+            pub(crate) fn AzNodeGraphEditOp_invert(_:  &AzNodeGraphEditOp) -> AzNodeGraphEditOp;
+            pub(crate) fn AzNodeGraphHistory_canUndo(_:  &AzNodeGraphHistory) -> bool;
+            pub(crate) fn AzNodeGraphHistory_canRedo(_:  &AzNodeGraphHistory) -> bool;
             pub(crate) fn AzListView_new(_:  AzStringVec) -> AzListView;
+            pub(crate) fn AzListView_setColumns(_:  &mut AzListView, _:  AzStringVec);
+            pub(crate) fn AzListView_withColumns(_:  &mut AzListView, _:  AzStringVec) -> AzListView;
+            pub(crate) fn AzListView_setRows(_:  &mut AzListView, _:  AzListViewRowVec);
             pub(crate) fn AzListView_withRows(_:  &mut AzListView, _:  AzListViewRowVec) -> AzListView;
+            pub(crate) fn AzListView_setSortedBy(_:  &mut AzListView, _:  AzOptionUsize);
+            pub(crate) fn AzListView_withSortedBy(_:  &mut AzListView, _:  AzOptionUsize) -> AzListView;
+            pub(crate) fn AzListView_setSortDescending(_:  &mut AzListView, _:  bool);
+            pub(crate) fn AzListView_withSortDescending(_:  &mut AzListView, _:  bool) -> AzListView;
+            pub(crate) fn AzListView_setScrollOffset(_:  &mut AzListView, _:  AzPixelValueNoPercent);
+            pub(crate) fn AzListView_withScrollOffset(_:  &mut AzListView, _:  AzPixelValueNoPercent) -> AzListView;
+            pub(crate) fn AzListView_setContentHeight(_:  &mut AzListView, _:  AzPixelValueNoPercent);
+            pub(crate) fn AzListView_withContentHeight(_:  &mut AzListView, _:  AzPixelValueNoPercent) -> AzListView;
+            pub(crate) fn AzListView_setColumnContextMenu(_:  &mut AzListView, _:  AzMenu);
+            pub(crate) fn AzListView_withColumnContextMenu(_:  &mut AzListView, _:  AzMenu) -> AzListView;
+            pub(crate) fn AzListView_setRowHeight(_:  &mut AzListView, _:  f32);
+            pub(crate) fn AzListView_withRowHeight(_:  &mut AzListView, _:  f32) -> AzListView;
+            pub(crate) fn AzListView_setFirstVisibleRow(_:  &mut AzListView, _:  usize);
+            pub(crate) fn AzListView_withFirstVisibleRow(_:  &mut AzListView, _:  usize) -> AzListView;
+            pub(crate) fn AzListView_setColumnWidths(_:  &mut AzListView, _:  AzF32Vec);
+            pub(crate) fn AzListView_withColumnWidths(_:  &mut AzListView, _:  AzF32Vec) -> AzListView;
+            pub(crate) fn AzListView_setSelectionState(_:  &mut AzListView, _:  AzOptionUsize, _:  AzOptionUsize);
+            pub(crate) fn AzListView_withSelectionState(_:  &mut AzListView, _:  AzOptionUsize, _:  AzOptionUsize) -> AzListView;
+            pub(crate) fn AzListView_setRowProvider(_:  &mut AzListView, _:  usize, _:  AzRefAny, _:  AzListViewRowProviderCallbackType);
+            pub(crate) fn AzListView_withRowProvider(_:  &mut AzListView, _:  usize, _:  AzRefAny, _:  AzListViewRowProviderCallbackType) -> AzListView;
+            pub(crate) fn AzListView_setOnLazyLoadScroll(_:  &mut AzListView, _:  AzRefAny, _:  AzListViewOnLazyLoadScrollCallbackType);
+            pub(crate) fn AzListView_withOnLazyLoadScroll(_:  &mut AzListView, _:  AzRefAny, _:  AzListViewOnLazyLoadScrollCallbackType) -> AzListView;
+            pub(crate) fn AzListView_setOnColumnClick(_:  &mut AzListView, _:  AzRefAny, _:  AzListViewOnColumnClickCallbackType);
+            pub(crate) fn AzListView_withOnColumnClick(_:  &mut AzListView, _:  AzRefAny, _:  AzListViewOnColumnClickCallbackType) -> AzListView;
+            pub(crate) fn AzListView_setOnRowClick(_:  &mut AzListView, _:  AzRefAny, _:  AzListViewOnRowClickCallbackType);
+            pub(crate) fn AzListView_withOnRowClick(_:  &mut AzListView, _:  AzRefAny, _:  AzListViewOnRowClickCallbackType) -> AzListView;
+            pub(crate) fn AzListView_setOnRowSelect(_:  &mut AzListView, _:  AzRefAny, _:  AzListViewOnRowSelectCallbackType);
+            pub(crate) fn AzListView_withOnRowSelect(_:  &mut AzListView, _:  AzRefAny, _:  AzListViewOnRowSelectCallbackType) -> AzListView;
+            pub(crate) fn AzListView_setOnColumnResize(_:  &mut AzListView, _:  AzRefAny, _:  AzListViewOnColumnResizeCallbackType);
+            pub(crate) fn AzListView_withOnColumnResize(_:  &mut AzListView, _:  AzRefAny, _:  AzListViewOnColumnResizeCallbackType) -> AzListView;
+            pub(crate) fn AzListView_getTotalRowCount(_:  &mut AzListView) -> usize;
+            pub(crate) fn AzListView_applySelection(_:  &mut AzListView, _:  usize, _:  AzListViewSelectionChange);
             pub(crate) fn AzListView_dom(_:  &mut AzListView) -> AzDom;
-            pub(crate) fn AzTreeView_new(_:  AzString) -> AzTreeView;
+            pub(crate) fn AzTreeView_new(_:  AzTreeViewItemVec) -> AzTreeView;
+            pub(crate) fn AzTreeView_setItems(_:  &mut AzTreeView, _:  AzTreeViewItemVec);
+            pub(crate) fn AzTreeView_withItems(_:  &mut AzTreeView, _:  AzTreeViewItemVec) -> AzTreeView;
+            pub(crate) fn AzTreeView_setSelectionMode(_:  &mut AzTreeView, _:  AzTreeViewSelectionMode);
+            pub(crate) fn AzTreeView_withSelectionMode(_:  &mut AzTreeView, _:  AzTreeViewSelectionMode) -> AzTreeView;
+            pub(crate) fn AzTreeView_setFirstVisibleRow(_:  &mut AzTreeView, _:  usize);
+            pub(crate) fn AzTreeView_setMaxVisibleRows(_:  &mut AzTreeView, _:  usize);
+            pub(crate) fn AzTreeView_setOnExpand(_:  &mut AzTreeView, _:  AzRefAny, _:  AzTreeViewOnExpandCallbackType);
+            pub(crate) fn AzTreeView_withOnExpand(_:  &mut AzTreeView, _:  AzRefAny, _:  AzTreeViewOnExpandCallbackType) -> AzTreeView;
+            pub(crate) fn AzTreeView_setOnSelect(_:  &mut AzTreeView, _:  AzRefAny, _:  AzTreeViewOnSelectCallbackType);
+            pub(crate) fn AzTreeView_withOnSelect(_:  &mut AzTreeView, _:  AzRefAny, _:  AzTreeViewOnSelectCallbackType) -> AzTreeView;
+            pub(crate) fn AzTreeView_setOnLazyLoad(_:  &mut AzTreeView, _:  AzRefAny, _:  AzTreeViewOnLazyLoadCallbackType);
+            pub(crate) fn AzTreeView_withOnLazyLoad(_:  &mut AzTreeView, _:  AzRefAny, _:  AzTreeViewOnLazyLoadCallbackType) -> AzTreeView;
+            pub(crate) fn AzTreeView_setOnScroll(_:  &mut AzTreeView, _:  AzRefAny, _:  AzTreeViewOnScrollCallbackType);
+            pub(crate) fn AzTreeView_withOnScroll(_:  &mut AzTreeView, _:  AzRefAny, _:  AzTreeViewOnScrollCallbackType) -> AzTreeView;
+            pub(crate) fn AzTreeView_getVisibleRowCount(_:  &AzTreeView) -> usize;
+            pub(crate) fn AzTreeView_setExpanded(_:  &mut AzTreeView, _:  usize, _:  bool) -> bool;
+            pub(crate) fn AzTreeView_setChildren(_:  &mut AzTreeView, _:  usize, _:  AzTreeViewItemVec) -> bool;
+            pub(crate) fn AzTreeView_applySelection(_:  &mut AzTreeView, _:  usize, _:  AzTreeViewSelectionChange);
             pub(crate) fn AzTreeView_dom(_:  &mut AzTreeView) -> AzDom;
+            pub(crate) fn AzTreeViewItem_new(_:  usize, _:  AzString) -> AzTreeViewItem;
+            pub(crate) fn AzDataGrid_new(_:  usize, _:  usize) -> AzDataGrid;
+            pub(crate) fn AzDataGrid_setColumnHeaders(_:  &mut AzDataGrid, _:  AzStringVec);
+            pub(crate) fn AzDataGrid_withColumnHeaders(_:  &mut AzDataGrid, _:  AzStringVec) -> AzDataGrid;
+            pub(crate) fn AzDataGrid_setColumnWidths(_:  &mut AzDataGrid, _:  AzF32Vec);
+            pub(crate) fn AzDataGrid_withColumnWidths(_:  &mut AzDataGrid, _:  AzF32Vec) -> AzDataGrid;
+            pub(crate) fn AzDataGrid_setRowHeights(_:  &mut AzDataGrid, _:  AzF32Vec);
+            pub(crate) fn AzDataGrid_withRowHeights(_:  &mut AzDataGrid, _:  AzF32Vec) -> AzDataGrid;
+            pub(crate) fn AzDataGrid_setScrollPosition(_:  &mut AzDataGrid, _:  usize, _:  usize);
+            pub(crate) fn AzDataGrid_withScrollPosition(_:  &mut AzDataGrid, _:  usize, _:  usize) -> AzDataGrid;
+            pub(crate) fn AzDataGrid_setSelection(_:  &mut AzDataGrid, _:  AzOptionDataGridRange);
+            pub(crate) fn AzDataGrid_withSelection(_:  &mut AzDataGrid, _:  AzOptionDataGridRange) -> AzDataGrid;
+            pub(crate) fn AzDataGrid_setCellProvider(_:  &mut AzDataGrid, _:  AzRefAny, _:  AzDataGridCellProviderCallbackType);
+            pub(crate) fn AzDataGrid_withCellProvider(_:  &mut AzDataGrid, _:  AzRefAny, _:  AzDataGridCellProviderCallbackType) -> AzDataGrid;
+            pub(crate) fn AzDataGrid_setCellStyle(_:  &mut AzDataGrid, _:  AzRefAny, _:  AzDataGridCellStyleCallbackType);
+            pub(crate) fn AzDataGrid_withCellStyle(_:  &mut AzDataGrid, _:  AzRefAny, _:  AzDataGridCellStyleCallbackType) -> AzDataGrid;
+            pub(crate) fn AzDataGrid_setOnSelect(_:  &mut AzDataGrid, _:  AzRefAny, _:  AzDataGridOnSelectCallbackType);
+            pub(crate) fn AzDataGrid_withOnSelect(_:  &mut AzDataGrid, _:  AzRefAny, _:  AzDataGridOnSelectCallbackType) -> AzDataGrid;
+            pub(crate) fn AzDataGrid_setOnScroll(_:  &mut AzDataGrid, _:  AzRefAny, _:  AzDataGridOnScrollCallbackType);
+            pub(crate) fn AzDataGrid_withOnScroll(_:  &mut AzDataGrid, _:  AzRefAny, _:  AzDataGridOnScrollCallbackType) -> AzDataGrid;
+            pub(crate) fn AzDataGrid_setOnCellEdit(_:  &mut AzDataGrid, _:  AzRefAny, _:  AzDataGridOnCellEditCallbackType);
+            pub(crate) fn AzDataGrid_withOnCellEdit(_:  &mut AzDataGrid, _:  AzRefAny, _:  AzDataGridOnCellEditCallbackType) -> AzDataGrid;
+            pub(crate) fn AzDataGrid_setOnColumnResize(_:  &mut AzDataGrid, _:  AzRefAny, _:  AzDataGridOnColumnResizeCallbackType);
+            pub(crate) fn AzDataGrid_withOnColumnResize(_:  &mut AzDataGrid, _:  AzRefAny, _:  AzDataGridOnColumnResizeCallbackType) -> AzDataGrid;
+            pub(crate) fn AzDataGrid_setOnRowResize(_:  &mut AzDataGrid, _:  AzRefAny, _:  AzDataGridOnRowResizeCallbackType);
+            pub(crate) fn AzDataGrid_withOnRowResize(_:  &mut AzDataGrid, _:  AzRefAny, _:  AzDataGridOnRowResizeCallbackType) -> AzDataGrid;
+            pub(crate) fn AzDataGrid_setOnCopyRange(_:  &mut AzDataGrid, _:  AzRefAny, _:  AzDataGridOnCopyRangeCallbackType);
+            pub(crate) fn AzDataGrid_withOnCopyRange(_:  &mut AzDataGrid, _:  AzRefAny, _:  AzDataGridOnCopyRangeCallbackType) -> AzDataGrid;
+            pub(crate) fn AzDataGrid_setOnClearRange(_:  &mut AzDataGrid, _:  AzRefAny, _:  AzDataGridOnClearRangeCallbackType);
+            pub(crate) fn AzDataGrid_withOnClearRange(_:  &mut AzDataGrid, _:  AzRefAny, _:  AzDataGridOnClearRangeCallbackType) -> AzDataGrid;
+            pub(crate) fn AzDataGrid_getColumnWidth(_:  &AzDataGrid, _:  usize) -> f32;
+            pub(crate) fn AzDataGrid_getRowHeight(_:  &AzDataGrid, _:  usize) -> f32;
+            pub(crate) fn AzDataGrid_getColumnName(_:  &AzDataGrid, _:  usize) -> AzString;
+            pub(crate) fn AzDataGrid_getCellText(_:  &mut AzDataGrid, _:  usize, _:  usize) -> AzString;
+            pub(crate) fn AzDataGrid_getSelectionTsv(_:  &mut AzDataGrid) -> AzString;
+            pub(crate) fn AzDataGrid_getClippedSelection(_:  &AzDataGrid) -> AzOptionDataGridRange;
+            pub(crate) fn AzDataGrid_dom(_:  &mut AzDataGrid) -> AzDom;
+            pub(crate) fn AzDataGridRange_new(_:  usize, _:  usize) -> AzDataGridRange;
+            pub(crate) fn AzDataGridRange_getTopLeft(_:  &AzDataGridRange) -> AzDataGridCell;
+            pub(crate) fn AzDataGridRange_getBottomRight(_:  &AzDataGridRange) -> AzDataGridCell;
+            pub(crate) fn AzDataGridRange_contains(_:  &AzDataGridRange, _:  usize, _:  usize) -> bool;
+            pub(crate) fn AzDataGridRange_getCellCount(_:  &AzDataGridRange) -> usize;
+            pub(crate) fn AzDate_new(_:  i32, _:  u8, _:  u8) -> AzDate;
+            pub(crate) fn AzDate_today() -> AzDate;
+            pub(crate) fn AzDate_isValid(_:  &AzDate) -> bool;
+            pub(crate) fn AzDate_weekday(_:  &AzDate) -> AzWeekday;
+            pub(crate) fn AzDate_addDays(_:  &AzDate, _:  i64) -> AzDate;
+            pub(crate) fn AzDate_addMonths(_:  &AzDate, _:  i64) -> AzDate;
+            pub(crate) fn AzDate_clampTo(_:  &AzDate, _:  AzOptionDate, _:  AzOptionDate) -> AzDate;
+            pub(crate) fn AzTime_new(_:  u8, _:  u8, _:  u8) -> AzTime;
+            pub(crate) fn AzTime_isValid(_:  &AzTime) -> bool;
+            pub(crate) fn AzDatePicker_new(_:  AzDate) -> AzDatePicker;
+            pub(crate) fn AzDatePicker_setState(_:  &mut AzDatePicker, _:  AzDatePickerState);
+            pub(crate) fn AzDatePicker_withState(_:  &mut AzDatePicker, _:  AzDatePickerState) -> AzDatePicker;
+            pub(crate) fn AzDatePicker_setMinimumDate(_:  &mut AzDatePicker, _:  AzOptionDate);
+            pub(crate) fn AzDatePicker_withMinimumDate(_:  &mut AzDatePicker, _:  AzOptionDate) -> AzDatePicker;
+            pub(crate) fn AzDatePicker_setMaximumDate(_:  &mut AzDatePicker, _:  AzOptionDate);
+            pub(crate) fn AzDatePicker_withMaximumDate(_:  &mut AzDatePicker, _:  AzOptionDate) -> AzDatePicker;
+            pub(crate) fn AzDatePicker_setFirstDayOfWeek(_:  &mut AzDatePicker, _:  AzWeekday);
+            pub(crate) fn AzDatePicker_withFirstDayOfWeek(_:  &mut AzDatePicker, _:  AzWeekday) -> AzDatePicker;
+            pub(crate) fn AzDatePicker_setOnDateChange(_:  &mut AzDatePicker, _:  AzRefAny, _:  AzDatePickerOnDateChangeCallbackType);
+            pub(crate) fn AzDatePicker_withOnDateChange(_:  &mut AzDatePicker, _:  AzRefAny, _:  AzDatePickerOnDateChangeCallbackType) -> AzDatePicker;
+            pub(crate) fn AzDatePicker_dom(_:  &mut AzDatePicker) -> AzDom;
+            pub(crate) fn AzTimePicker_new(_:  AzTime) -> AzTimePicker;
+            pub(crate) fn AzTimePicker_setShowSeconds(_:  &mut AzTimePicker, _:  bool);
+            pub(crate) fn AzTimePicker_withShowSeconds(_:  &mut AzTimePicker, _:  bool) -> AzTimePicker;
+            pub(crate) fn AzTimePicker_setOnTimeChange(_:  &mut AzTimePicker, _:  AzRefAny, _:  AzTimePickerOnTimeChangeCallbackType);
+            pub(crate) fn AzTimePicker_withOnTimeChange(_:  &mut AzTimePicker, _:  AzRefAny, _:  AzTimePickerOnTimeChangeCallbackType) -> AzTimePicker;
+            pub(crate) fn AzTimePicker_dom(_:  &mut AzTimePicker) -> AzDom;
+            pub(crate) fn AzDateTimePicker_new(_:  AzDate) -> AzDateTimePicker;
+            pub(crate) fn AzDateTimePicker_setState(_:  &mut AzDateTimePicker, _:  AzDateTimePickerState);
+            pub(crate) fn AzDateTimePicker_withState(_:  &mut AzDateTimePicker, _:  AzDateTimePickerState) -> AzDateTimePicker;
+            pub(crate) fn AzDateTimePicker_setMinimumDate(_:  &mut AzDateTimePicker, _:  AzOptionDate);
+            pub(crate) fn AzDateTimePicker_withMinimumDate(_:  &mut AzDateTimePicker, _:  AzOptionDate) -> AzDateTimePicker;
+            pub(crate) fn AzDateTimePicker_setMaximumDate(_:  &mut AzDateTimePicker, _:  AzOptionDate);
+            pub(crate) fn AzDateTimePicker_withMaximumDate(_:  &mut AzDateTimePicker, _:  AzOptionDate) -> AzDateTimePicker;
+            pub(crate) fn AzDateTimePicker_setFirstDayOfWeek(_:  &mut AzDateTimePicker, _:  AzWeekday);
+            pub(crate) fn AzDateTimePicker_withFirstDayOfWeek(_:  &mut AzDateTimePicker, _:  AzWeekday) -> AzDateTimePicker;
+            pub(crate) fn AzDateTimePicker_setShowTime(_:  &mut AzDateTimePicker, _:  bool, _:  bool);
+            pub(crate) fn AzDateTimePicker_withShowTime(_:  &mut AzDateTimePicker, _:  bool, _:  bool) -> AzDateTimePicker;
+            pub(crate) fn AzDateTimePicker_setOnDateTimeChange(_:  &mut AzDateTimePicker, _:  AzRefAny, _:  AzDateTimePickerOnDateTimeChangeCallbackType);
+            pub(crate) fn AzDateTimePicker_withOnDateTimeChange(_:  &mut AzDateTimePicker, _:  AzRefAny, _:  AzDateTimePickerOnDateTimeChangeCallbackType) -> AzDateTimePicker;
+            pub(crate) fn AzDateTimePicker_dom(_:  &mut AzDateTimePicker) -> AzDom;
+            pub(crate) fn AzDialogResult_isAccepted(_:  &AzDialogResult) -> bool;
+            pub(crate) fn AzDialog_msgBox(_:  AzString, _:  AzString) -> AzDialog;
+            pub(crate) fn AzDialog_confirm(_:  AzString, _:  AzString) -> AzDialog;
+            pub(crate) fn AzDialog_prompt(_:  AzString, _:  AzString, _:  AzString) -> AzDialog;
+            pub(crate) fn AzDialog_custom(_:  AzString, _:  AzDom) -> AzDialog;
+            pub(crate) fn AzDialog_setIcon(_:  &mut AzDialog, _:  AzDialogIcon);
+            pub(crate) fn AzDialog_withIcon(_:  &mut AzDialog, _:  AzDialogIcon) -> AzDialog;
+            pub(crate) fn AzDialog_setButtons(_:  &mut AzDialog, _:  AzDialogButtons);
+            pub(crate) fn AzDialog_withButtons(_:  &mut AzDialog, _:  AzDialogButtons) -> AzDialog;
+            pub(crate) fn AzDialog_setOnClose(_:  &mut AzDialog, _:  AzRefAny, _:  AzDialogOnCloseCallbackType);
+            pub(crate) fn AzDialog_withOnClose(_:  &mut AzDialog, _:  AzRefAny, _:  AzDialogOnCloseCallbackType) -> AzDialog;
+            pub(crate) fn AzDialog_dom(_:  &mut AzDialog) -> AzDom;
+            pub(crate) fn AzDialog_domOver(_:  &mut AzDialog, _:  AzDom) -> AzDom;
+            pub(crate) fn AzSplitterPane_new(_:  AzDom) -> AzSplitterPane;
+            pub(crate) fn AzSplitterPane_setSize(_:  &mut AzSplitterPane, _:  f32);
+            pub(crate) fn AzSplitterPane_withSize(_:  &mut AzSplitterPane, _:  f32) -> AzSplitterPane;
+            pub(crate) fn AzSplitterPane_setMinSize(_:  &mut AzSplitterPane, _:  f32);
+            pub(crate) fn AzSplitterPane_withMinSize(_:  &mut AzSplitterPane, _:  f32) -> AzSplitterPane;
+            pub(crate) fn AzSplitterPane_setMaxSize(_:  &mut AzSplitterPane, _:  f32);
+            pub(crate) fn AzSplitterPane_withMaxSize(_:  &mut AzSplitterPane, _:  f32) -> AzSplitterPane;
+            pub(crate) fn AzSplitterPane_setCollapsed(_:  &mut AzSplitterPane, _:  bool);
+            pub(crate) fn AzSplitterPane_withCollapsed(_:  &mut AzSplitterPane, _:  bool) -> AzSplitterPane;
+            pub(crate) fn AzSplitter_new(_:  AzSplitterDirection) -> AzSplitter;
+            pub(crate) fn AzSplitter_withPane(_:  &mut AzSplitter, _:  AzSplitterPane) -> AzSplitter;
+            pub(crate) fn AzSplitter_addPane(_:  &mut AzSplitter, _:  AzSplitterPane);
+            pub(crate) fn AzSplitter_setHandleSize(_:  &mut AzSplitter, _:  f32);
+            pub(crate) fn AzSplitter_withHandleSize(_:  &mut AzSplitter, _:  f32) -> AzSplitter;
+            pub(crate) fn AzSplitter_getState(_:  &AzSplitter) -> AzSplitterState;
+            pub(crate) fn AzSplitter_setState(_:  &mut AzSplitter, _:  AzSplitterState);
+            pub(crate) fn AzSplitter_withState(_:  &mut AzSplitter, _:  AzSplitterState) -> AzSplitter;
+            pub(crate) fn AzSplitter_setOnResize(_:  &mut AzSplitter, _:  AzRefAny, _:  AzSplitterOnResizeCallbackType);
+            pub(crate) fn AzSplitter_withOnResize(_:  &mut AzSplitter, _:  AzRefAny, _:  AzSplitterOnResizeCallbackType) -> AzSplitter;
+            pub(crate) fn AzSplitter_dom(_:  &mut AzSplitter) -> AzDom;
             pub(crate) fn AzDropDown_new(_:  AzStringVec) -> AzDropDown;
             pub(crate) fn AzDropDown_dom(_:  &mut AzDropDown) -> AzDom;
             pub(crate) fn AzCssPropertyCache_delete(_:  &mut AzCssPropertyCache);
@@ -12010,6 +14214,8 @@ mod dll {
             pub(crate) fn AzFontRef_getBytes(_:  &AzFontRef) -> AzU8Vec;
             pub(crate) fn AzFontRef_getFontMetrics(_:  &AzFontRef) -> AzFontMetrics;
             pub(crate) fn AzFontRef_shapeText(_:  &AzFontRef, _:  AzRefstr, _:  AzResolvedTextLayoutOptions) -> AzInlineText;
+            pub(crate) fn AzFontRef_measureText(_:  &AzFontRef, _:  AzRefstr, _:  AzResolvedTextLayoutOptions) -> AzTextMetrics;
+            pub(crate) fn AzFontRef_textToPath(_:  &AzFontRef, _:  AzRefstr, _:  AzResolvedTextLayoutOptions) -> AzSvgMultiPolygon;
             pub(crate) fn AzFontRef_getHash(_:  &AzFontRef) -> u64;
             pub(crate) fn AzFontRef_delete(_:  &mut AzFontRef);
             pub(crate) fn AzFontRef_deepCopy(_:  &AzFontRef) -> AzFontRef;
@@ -12159,10 +14365,16 @@ mod dll {
             pub(crate) fn AzString_trim(_:  &AzString) -> AzString;
             pub(crate) fn AzString_asRefstr(_:  &AzString) -> AzRefstr;
             pub(crate) fn AzListViewRowVec_delete(_:  &mut AzListViewRowVec);
+            pub(crate) fn AzTreeViewItemVec_delete(_:  &mut AzTreeViewItemVec);
+            pub(crate) fn AzSplitterPaneVec_delete(_:  &mut AzSplitterPaneVec);
+            pub(crate) fn AzSplitterPaneStateVec_delete(_:  &mut AzSplitterPaneStateVec);
             pub(crate) fn AzStyleFilterVec_delete(_:  &mut AzStyleFilterVec);
             pub(crate) fn AzLogicalRectVec_delete(_:  &mut AzLogicalRectVec);
             pub(crate) fn AzNodeTypeIdInfoMapVec_delete(_:  &mut AzNodeTypeIdInfoMapVec);
             pub(crate) fn AzInputOutputTypeIdInfoMapVec_delete(_:  &mut AzInputOutputTypeIdInfoMapVec);
+            pub(crate) fn AzNodeGraphNodeIdVec_delete(_:  &mut AzNodeGraphNodeIdVec);
+            pub(crate) fn AzNodeGraphEditOpVec_delete(_:  &mut AzNodeGraphEditOpVec);
+            pub(crate) fn AzNodeGraphEditVec_delete(_:  &mut AzNodeGraphEditVec);
             pub(crate) fn AzNodeIdNodeMapVec_delete(_:  &mut AzNodeIdNodeMapVec);
             pub(crate) fn AzInputOutputTypeIdVec_delete(_:  &mut AzInputOutputTypeIdVec);
             pub(crate) fn AzNodeTypeFieldVec_delete(_:  &mut AzNodeTypeFieldVec);
@@ -12171,6 +14383,7 @@ mod dll {
             pub(crate) fn AzOutputConnectionVec_delete(_:  &mut AzOutputConnectionVec);
             pub(crate) fn AzInputNodeAndIndexVec_delete(_:  &mut AzInputNodeAndIndexVec);
             pub(crate) fn AzAccessibilityStateVec_delete(_:  &mut AzAccessibilityStateVec);
+            pub(crate) fn AzKeyboardShortcutVec_delete(_:  &mut AzKeyboardShortcutVec);
             pub(crate) fn AzMenuItemVec_delete(_:  &mut AzMenuItemVec);
             pub(crate) fn AzTessellatedSvgNodeVec_asRefVec(_:  &AzTessellatedSvgNodeVec) -> AzTessellatedSvgNodeVecRef;
             pub(crate) fn AzTessellatedSvgNodeVec_delete(_:  &mut AzTessellatedSvgNodeVec);
@@ -12182,6 +14395,8 @@ mod dll {
             pub(crate) fn AzInlineLineVec_delete(_:  &mut AzInlineLineVec);
             pub(crate) fn AzInlineWordVec_delete(_:  &mut AzInlineWordVec);
             pub(crate) fn AzInlineGlyphVec_delete(_:  &mut AzInlineGlyphVec);
+            pub(crate) fn AzTextLineMetricsVec_delete(_:  &mut AzTextLineMetricsVec);
+            pub(crate) fn AzInlineTextRunVec_delete(_:  &mut AzInlineTextRunVec);
             pub(crate) fn AzInlineTextHitVec_delete(_:  &mut AzInlineTextHitVec);
             pub(crate) fn AzMonitorVec_delete(_:  &mut AzMonitorVec);
             pub(crate) fn AzVideoModeVec_delete(_:  &mut AzVideoModeVec);
@@ -12209,6 +14424,9 @@ mod dll {
             pub(crate) fn AzCssDeclarationVec_delete(_:  &mut AzCssDeclarationVec);
             pub(crate) fn AzCssPathSelectorVec_delete(_:  &mut AzCssPathSelectorVec);
             pub(crate) fn AzStylesheetVec_delete(_:  &mut AzStylesheetVec);
+            pub(crate) fn AzCssFontFaceVec_delete(_:  &mut AzCssFontFaceVec);
+            pub(crate) fn AzCssFontFaceSourceVec_delete(_:  &mut AzCssFontFaceSourceVec);
+            pub(crate) fn AzCssUnicodeRangeVec_delete(_:  &mut AzCssUnicodeRangeVec);
             pub(crate) fn AzCssRuleBlockVec_delete(_:  &mut AzCssRuleBlockVec);
             pub(crate) fn AzU16Vec_delete(_:  &mut AzU16Vec);
             pub(crate) fn AzF32Vec_delete(_:  &mut AzF32Vec);
@@ -12246,6 +14464,7 @@ pub mod app {
     use crate::window::WindowCreateOptions;
     use crate::str::String;
     use crate::image::ImageRef;
+    use crate::vec::U8Vec;
     /// Main application class
     
     #[doc(inline)] pub use crate::dll::AzApp as App;
@@ -12257,6 +14476,8 @@ pub mod app {
         pub fn add_window<_1: Into<WindowCreateOptions>>(&mut self, window: _1)  { unsafe { crate::dll::AzApp_addWindow(self, window.into()) } }
         /// Adds a new image identified by an ID to the image cache
         pub fn add_image<_1: Into<String>, _2: Into<ImageRef>>(&mut self, id: _1, image: _2)  { unsafe { crate::dll::AzApp_addImage(self, id.into(), image.into()) } }
+        /// Registers a font (TTF / OTF bytes) under a font family name, so that it can be used via `font-family` without being installed. Returns false if the font could not be parsed.
+        pub fn add_font<_1: Into<String>, _2: Into<U8Vec>>(&mut self, family: _1, font_bytes: _2)  -> bool { unsafe { crate::dll::AzApp_addFont(self, family.into(), font_bytes.into()) } }
         /// Returns a list of monitors - useful for setting the monitor that a window should spawn on.
         pub fn get_monitors(&self)  -> crate::vec::MonitorVec { unsafe { crate::dll::AzApp_getMonitors(self) } }
         /// Runs the application. Due to platform restrictions (specifically `WinMain` on Windows), this function never returns.
@@ -12744,6 +14965,7 @@ pub mod callbacks {
     use crate::window::{LogicalPosition, WindowCreateOptions, WindowState};
     use crate::image::{ImageMask, ImageRef};
     use crate::task::{ThreadId, ThreadSendMsg, Timer, TimerId};
+    use crate::vec::StyleFontFamilyVec;
     /// `LayoutCallback` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutCallback as LayoutCallback;
@@ -12922,8 +15144,19 @@ pub mod callbacks {
 
         /// Hit-tests the inline text, returns detailed information about which glyph / word / line, etc. the position (usually the mouse cursor) is currently over. Result may be empty (no hits) or contain more than one result (cursor is hovering over multiple overlapping glyphs at once).
         pub fn hit_test<_1: Into<LogicalPosition>>(&self, position: _1)  -> crate::vec::InlineTextHitVec { unsafe { crate::dll::AzInlineText_hitTest(self, position.into()) } }
+        /// Returns the size of the text and of its individual lines
+        pub fn get_text_metrics(&self)  -> crate::callbacks::TextMetrics { unsafe { crate::dll::AzInlineText_getTextMetrics(self) } }
     }
 
+    /// Size of a string measured without laying out a DOM
+    
+    #[doc(inline)] pub use crate::dll::AzTextMetrics as TextMetrics;
+    /// Metrics of a single line of a measured text
+    
+    #[doc(inline)] pub use crate::dll::AzTextLineMetrics as TextLineMetrics;
+    /// `InlineTextRun` struct
+    
+    #[doc(inline)] pub use crate::dll::AzInlineTextRun as InlineTextRun;
     /// `InlineLine` struct
     
     #[doc(inline)] pub use crate::dll::AzInlineLine as InlineLine;
@@ -13085,6 +15318,8 @@ pub mod callbacks {
         pub fn get_system_fonts(&self)  -> crate::vec::StringPairVec { unsafe { crate::dll::AzLayoutCallbackInfo_getSystemFonts(self) } }
         /// Returns an `ImageRef` referenced by a CSS ID
         pub fn get_image<_1: Into<String>>(&self, id: _1)  -> crate::option::OptionImageRef { unsafe { crate::dll::AzLayoutCallbackInfo_getImage(self, id.into()) } }
+        /// Measures the size of a text before the layout (i.e. to size table columns to their content), using the first loadable font of the font families. Returns `None` if none of the fonts could be loaded
+        pub fn measure_text<_1: Into<String>, _2: Into<StyleFontFamilyVec>, _3: Into<ResolvedTextLayoutOptions>>(&self, text: _1, font_families: _2, options: _3)  -> crate::option::OptionTextMetrics { unsafe { crate::dll::AzLayoutCallbackInfo_measureText(self, text.into(), font_families.into(), options.into()) } }
     }
 
 }
@@ -13224,9 +15459,10 @@ pub mod dom {
     }    use crate::str::String;
     use crate::image::{ImageMask, ImageRef};
     use crate::callbacks::{CallbackType, IFrameCallbackType, RefAny};
-    use crate::vec::{CallbackDataVec, DomVec, IdOrClassVec, NodeDataInlineCssPropertyVec};
+    use crate::vec::{CallbackDataVec, DomVec, IdOrClassVec, KeyboardShortcutVec, NodeDataInlineCssPropertyVec};
     use crate::css::{Css, CssProperty};
     use crate::menu::Menu;
+    use crate::time::Duration;
     /// `Dom` struct
     
     #[doc(inline)] pub use crate::dll::AzDom as Dom;
@@ -13338,6 +15574,16 @@ pub mod dom {
         pub fn set_context_menu<_1: Into<Menu>>(&mut self, context_menu: _1)  { unsafe { crate::dll::AzDom_setContextMenu(self, context_menu.into()) } }
         /// Same as set_context_menu, but as a builder method
         pub fn with_context_menu<_1: Into<Menu>>(&mut self, context_menu: _1)  -> crate::dom::Dom { unsafe { crate::dll::AzDom_withContextMenu(self, context_menu.into()) } }
+        /// Sets the tooltip for the DOM root node, shown after the mouse rested on the node for `tooltip.delay`
+        pub fn set_tooltip<_1: Into<Tooltip>>(&mut self, tooltip: _1)  { unsafe { crate::dll::AzDom_setTooltip(self, tooltip.into()) } }
+        /// Same as set_tooltip, but as a builder method
+        pub fn with_tooltip<_1: Into<Tooltip>>(&mut self, tooltip: _1)  -> crate::dom::Dom { unsafe { crate::dll::AzDom_withTooltip(self, tooltip.into()) } }
+        /// Sets keyboard shortcuts that are active while the DOM root node or one of its children is focused. Set them on the root of the window for window-wide shortcuts
+        pub fn set_keyboard_shortcuts<_1: Into<KeyboardShortcutVec>>(&mut self, keyboard_shortcuts: _1)  { unsafe { crate::dll::AzDom_setKeyboardShortcuts(self, keyboard_shortcuts.into()) } }
+        /// Same as set_keyboard_shortcuts, but as a builder method
+        pub fn with_keyboard_shortcuts<_1: Into<KeyboardShortcutVec>>(&mut self, keyboard_shortcuts: _1)  -> crate::dom::Dom { unsafe { crate::dll::AzDom_withKeyboardShortcuts(self, keyboard_shortcuts.into()) } }
+        /// Makes this node and all of its children inert: they are still rendered, but can't receive events or the keyboard focus anymore - used to block the content behind modal dialogs
+        pub fn make_inert(&mut self)  { unsafe { crate::dll::AzDom_makeInert(self) } }
         /// Calculates the hash of this node (note: in order to be truly unique, you also have to hash the DOM and Node ID).
         pub fn hash(&self)  -> u64 { unsafe { crate::dll::AzDom_hash(self) } }
         /// Returns the number of nodes in the DOM, including all child DOM trees. Result is equal to `self.total_children + 1` (count of all child trees + the root node)
@@ -13425,6 +15671,10 @@ pub mod dom {
         pub fn set_menu_bar<_1: Into<Menu>>(&mut self, menu_bar: _1)  { unsafe { crate::dll::AzNodeData_setMenuBar(self, menu_bar.into()) } }
         /// Signalizes that this node has a (native) context-aware menu. If set, the user can left-click the node to open the menu
         pub fn set_context_menu<_1: Into<Menu>>(&mut self, context_menu: _1)  { unsafe { crate::dll::AzNodeData_setContextMenu(self, context_menu.into()) } }
+        /// Sets a tooltip that is shown when the mouse rests over this node. The tooltip text is also used as the accessible description of the node
+        pub fn set_tooltip<_1: Into<Tooltip>>(&mut self, tooltip: _1)  { unsafe { crate::dll::AzNodeData_setTooltip(self, tooltip.into()) } }
+        /// Sets keyboard shortcuts that are active while this node or one of its children is focused
+        pub fn set_keyboard_shortcuts<_1: Into<KeyboardShortcutVec>>(&mut self, keyboard_shortcuts: _1)  { unsafe { crate::dll::AzNodeData_setKeyboardShortcuts(self, keyboard_shortcuts.into()) } }
         /// Calculates the hash of this node (note: in order to be truly unique, you also have to hash the DOM and Node ID).
         pub fn hash(&self)  -> u64 { unsafe { crate::dll::AzNodeData_hash(self) } }
     }
@@ -13465,6 +15715,22 @@ pub mod dom {
     /// Accessibility information (MSAA wrapper). See `NodeData.set_accessibility_info()`
     
     #[doc(inline)] pub use crate::dll::AzAccessibilityInfo as AccessibilityInfo;
+    /// Hover hint of a DOM node, see `Dom::with_tooltip()`
+    
+    #[doc(inline)] pub use crate::dll::AzTooltip as Tooltip;
+    impl Tooltip {
+
+        /// Creates a new text tooltip with the default hover delay (500ms)
+        pub fn text<_1: Into<String>>(text: _1) -> Self { unsafe { crate::dll::AzTooltip_text(text.into()) } }
+        /// Creates a new tooltip rendering a custom DOM, with the default hover delay (500ms)
+        pub fn dom<_1: Into<Dom>>(dom: _1) -> Self { unsafe { crate::dll::AzTooltip_dom(dom.into()) } }
+        /// Sets the hover delay before the tooltip is shown (builder method)
+        pub fn with_delay<_1: Into<Duration>>(&mut self, delay: _1)  -> crate::dom::Tooltip { unsafe { crate::dll::AzTooltip_withDelay(self, delay.into()) } }
+    }
+
+    /// Content of a `Tooltip`: either a plain text or a custom DOM
+    
+    #[doc(inline)] pub use crate::dll::AzTooltipContent as TooltipContent;
     /// MSAA Accessibility role constants. For information on what each role does, see the <a href="https://docs.microsoft.com/en-us/windows/win32/winauto/object-roles">MSDN Role Constants page</a>
     
     #[doc(inline)] pub use crate::dll::AzAccessibilityRole as AccessibilityRole;
@@ -13489,6 +15755,7 @@ pub mod menu {
     use crate::vec::MenuItemVec;
     use crate::str::String;
     use crate::callbacks::{CallbackType, RefAny};
+    use crate::window::KeyboardState;
     /// Menu struct (application / window menu, dropdown menu, context menu). Modeled after the Windows API
     
     #[doc(inline)] pub use crate::dll::AzMenu as Menu;
@@ -13535,6 +15802,21 @@ pub mod menu {
     /// Combination of virtual key codes that have to be pressed together
     
     #[doc(inline)] pub use crate::dll::AzVirtualKeyCodeCombo as VirtualKeyCodeCombo;
+    impl VirtualKeyCodeCombo {
+
+        /// Returns whether the key combination was just completed: the last pressed key is a non-modifier key of the combination, all other keys of the combination are held down and no additional modifier is pressed
+        pub fn matches<_1: Into<KeyboardState>>(&self, keyboard_state: _1)  -> bool { unsafe { crate::dll::AzVirtualKeyCodeCombo_matches(self, keyboard_state.into()) } }
+    }
+
+    /// Keyboard shortcut that is not attached to a menu item: when the key combination is pressed, the callback is invoked. See `Dom::with_keyboard_shortcuts()`
+    
+    #[doc(inline)] pub use crate::dll::AzKeyboardShortcut as KeyboardShortcut;
+    impl KeyboardShortcut {
+
+        /// Creates a new `KeyboardShortcut` instance.
+        pub fn new<_1: Into<VirtualKeyCodeCombo>, _2: Into<RefAny>>(keys: _1, data: _2, callback: CallbackType) -> Self { unsafe { crate::dll::AzKeyboardShortcut_new(keys.into(), data.into(), callback) } }
+    }
+
     /// Similar to `dom.CallbackData`, stores some data + a callback to call when the menu is activated
     
     #[doc(inline)] pub use crate::dll::AzMenuCallback as MenuCallback;
@@ -13644,6 +15926,8 @@ pub mod css {
             CssPropertyType::Filter => CssProperty::Filter(StyleFilterVecValue::$content_type),
             CssPropertyType::BackdropFilter => CssProperty::BackdropFilter(StyleFilterVecValue::$content_type),
             CssPropertyType::TextShadow => CssProperty::TextShadow(StyleBoxShadowValue::$content_type),
+            CssPropertyType::VerticalAlign => CssProperty::VerticalAlign(LayoutVerticalAlignValue::$content_type),
+            CssPropertyType::TextDecoration => CssProperty::TextDecoration(StyleTextDecorationValue::$content_type),
         }
     })}
 
@@ -13726,6 +16010,8 @@ pub mod css {
                 CssProperty::Filter(_) => CssPropertyType::Filter,
                 CssProperty::BackdropFilter(_) => CssPropertyType::BackdropFilter,
                 CssProperty::TextShadow(_) => CssPropertyType::TextShadow,
+                CssProperty::VerticalAlign(_) => CssPropertyType::VerticalAlign,
+                CssProperty::TextDecoration(_) => CssPropertyType::TextDecoration,
             }
         }
 
@@ -13809,6 +16095,8 @@ pub mod css {
         pub const fn filter(input: StyleFilterVec) -> Self { CssProperty::Filter(StyleFilterVecValue::Exact(input)) }
         pub const fn backdrop_filter(input: StyleFilterVec) -> Self { CssProperty::BackdropFilter(StyleFilterVecValue::Exact(input)) }
         pub const fn text_shadow(input: StyleBoxShadow) -> Self { CssProperty::TextShadow(StyleBoxShadowValue::Exact(input)) }
+        pub const fn vertical_align(input: LayoutVerticalAlign) -> Self { CssProperty::VerticalAlign(LayoutVerticalAlignValue::Exact(input)) }
+        pub const fn text_decoration(input: StyleTextDecoration) -> Self { CssProperty::TextDecoration(StyleTextDecorationValue::Exact(input)) }
     }
 
     const FP_PRECISION_MULTIPLIER: f32 = 1000.0;
@@ -14250,6 +16538,21 @@ pub mod css {
         pub fn from_string<_1: Into<String>>(s: _1) -> Self { unsafe { crate::dll::AzCss_fromString(s.into()) } }
     }
 
+    /// Parsed contents of an `@font-face { ... }` block
+    
+    #[doc(inline)] pub use crate::dll::AzCssFontFace as CssFontFace;
+    /// Single entry of the `src: ...` list in a `@font-face` rule
+    
+    #[doc(inline)] pub use crate::dll::AzCssFontFaceSource as CssFontFaceSource;
+    /// `url("fonts/Roboto.ttf") format("truetype")`
+    
+    #[doc(inline)] pub use crate::dll::AzCssFontFaceUrl as CssFontFaceUrl;
+    /// `CssFontFaceStyle` struct
+    
+    #[doc(inline)] pub use crate::dll::AzCssFontFaceStyle as CssFontFaceStyle;
+    /// `U+0000-00FF`: inclusive range of unicode code points
+    
+    #[doc(inline)] pub use crate::dll::AzCssUnicodeRange as CssUnicodeRange;
     /// `CssPropertyType` struct
     
     #[doc(inline)] pub use crate::dll::AzCssPropertyType as CssPropertyType;
@@ -14393,6 +16696,9 @@ pub mod css {
     /// `LayoutTop` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutTop as LayoutTop;
+    /// `LayoutVerticalAlign` struct
+    
+    #[doc(inline)] pub use crate::dll::AzLayoutVerticalAlign as LayoutVerticalAlign;
     /// `LayoutWidth` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutWidth as LayoutWidth;
@@ -14585,6 +16891,9 @@ pub mod css {
     /// `StyleTextAlign` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleTextAlign as StyleTextAlign;
+    /// `StyleTextDecoration` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleTextDecoration as StyleTextDecoration;
     /// `StyleTextColor` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleTextColor as StyleTextColor;
@@ -14675,6 +16984,9 @@ pub mod css {
     /// `LayoutTopValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutTopValue as LayoutTopValue;
+    /// `LayoutVerticalAlignValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzLayoutVerticalAlignValue as LayoutVerticalAlignValue;
     /// `LayoutWidthValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutWidthValue as LayoutWidthValue;
@@ -14768,6 +17080,9 @@ pub mod css {
     /// `StyleTextAlignValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleTextAlignValue as StyleTextAlignValue;
+    /// `StyleTextDecorationValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleTextDecorationValue as StyleTextDecorationValue;
     /// `StyleTextColorValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleTextColorValue as StyleTextColorValue;
@@ -14819,10 +17134,11 @@ pub mod widgets {
     use core::ffi::c_void;
     use crate::callbacks::{CallbackType, RefAny};
     use crate::str::String;
-    use crate::option::OptionString;
-    use crate::css::{ColorU, PixelValue};
-    use crate::vec::{ListViewRowVec, NodeDataInlineCssPropertyVec, StringVec, StyleBackgroundContentVec};
+    use crate::option::{OptionDataGridRange, OptionDate, OptionString, OptionUsize};
+    use crate::css::{ColorU, PixelValue, PixelValueNoPercent};
+    use crate::vec::{F32Vec, ListViewRowVec, NodeDataInlineCssPropertyVec, StringVec, StyleBackgroundContentVec, TreeViewItemVec};
     use crate::dom::Dom;
+    use crate::menu::Menu;
     /// `Ribbon` struct
     
     #[doc(inline)] pub use crate::dll::AzRibbon as Ribbon;
@@ -15210,6 +17526,10 @@ pub mod widgets {
     #[doc(inline)] pub use crate::dll::AzNodeGraph as NodeGraph;
     impl NodeGraph {
 
+        /// Loads a graph saved with `to_json`: returns a default `NodeGraph` with only the `nodes`, `offset` and `scale_factor` set, the node types and callbacks have to be set again by the application
+        pub fn from_json<_1: Into<String>>(json: _1) ->  crate::error::ResultNodeGraphNodeGraphError { unsafe { crate::dll::AzNodeGraph_fromJson(json.into()) } }
+        /// Serializes the nodes (including positions, field values and connections), the offset and the zoom level of the graph to JSON
+        pub fn to_json(&self)  -> crate::str::String { unsafe { crate::dll::AzNodeGraph_toJson(self) } }
         /// Calls the `NodeGraph::dom` function.
         pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzNodeGraph_dom(self) } }
     }
@@ -15301,6 +17621,33 @@ pub mod widgets {
     /// `NodeGraphOnNodeFieldEdited` struct
     
     #[doc(inline)] pub use crate::dll::AzNodeGraphOnNodeFieldEdited as NodeGraphOnNodeFieldEdited;
+    /// `NodeGraphOnNodeGraphZoomedCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphOnNodeGraphZoomedCallbackType as NodeGraphOnNodeGraphZoomedCallbackType;
+    /// `NodeGraphOnNodeGraphZoomedCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphOnNodeGraphZoomedCallback as NodeGraphOnNodeGraphZoomedCallback;
+    /// `NodeGraphOnNodeGraphZoomed` struct
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphOnNodeGraphZoomed as NodeGraphOnNodeGraphZoomed;
+    /// `NodeGraphOnSelectionChangedCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphOnSelectionChangedCallbackType as NodeGraphOnSelectionChangedCallbackType;
+    /// `NodeGraphOnSelectionChangedCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphOnSelectionChangedCallback as NodeGraphOnSelectionChangedCallback;
+    /// `NodeGraphOnSelectionChanged` struct
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphOnSelectionChanged as NodeGraphOnSelectionChanged;
+    /// `NodeGraphOnHistoryChangedCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphOnHistoryChangedCallbackType as NodeGraphOnHistoryChangedCallbackType;
+    /// `NodeGraphOnHistoryChangedCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphOnHistoryChangedCallback as NodeGraphOnHistoryChangedCallback;
+    /// `NodeGraphOnHistoryChanged` struct
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphOnHistoryChanged as NodeGraphOnHistoryChanged;
     /// `InputOutputTypeId` struct
     
     #[doc(inline)] pub use crate::dll::AzInputOutputTypeId as InputOutputTypeId;
@@ -15346,15 +17693,120 @@ pub mod widgets {
     /// `NodeDragAmount` struct
     
     #[doc(inline)] pub use crate::dll::AzNodeDragAmount as NodeDragAmount;
-    /// `ListView` struct
+    /// `NodeGraphError` struct
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphError as NodeGraphError;
+    /// Connection from the output of one node to the input of another node
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphConnection as NodeGraphConnection;
+    /// Node that was moved by `amount` (in graph coordinates)
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphNodeMove as NodeGraphNodeMove;
+    /// Field of a node that was changed from `old_value` to `new_value`
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphFieldEdit as NodeGraphFieldEdit;
+    /// Single modification of the node graph, recorded in the undo history
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphEditOp as NodeGraphEditOp;
+    impl NodeGraphEditOp {
+
+        /// Returns the operation that reverts this operation
+        pub fn invert(&self)  -> crate::widgets::NodeGraphEditOp { unsafe { crate::dll::AzNodeGraphEditOp_invert(self) } }
+    }
+
+    /// One undoable user action (for example "paste three nodes"), consisting of one or more operations
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphEdit as NodeGraphEdit;
+    /// Undo / redo stacks of the node graph: the last edit is at the end of each stack
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphHistory as NodeGraphHistory;
+    impl NodeGraphHistory {
+
+        /// Calls the `NodeGraphHistory::can_undo` function.
+        pub fn can_undo(&self)  -> bool { unsafe { crate::dll::AzNodeGraphHistory_canUndo(self) } }
+        /// Calls the `NodeGraphHistory::can_redo` function.
+        pub fn can_redo(&self)  -> bool { unsafe { crate::dll::AzNodeGraphHistory_canRedo(self) } }
+    }
+
+    /// Table with a header row. If a `row_provider` is set, the ListView is virtualized: only the rows that fit into the visible area are requested from the row provider and rendered. The callbacks report scrolling, selection and column resizing, the application stores the new `first_visible_row`, selection state and `column_widths` for the next frame
     
     #[doc(inline)] pub use crate::dll::AzListView as ListView;
     impl ListView {
 
         /// Creates a new `ListView` instance.
         pub fn new<_1: Into<StringVec>>(columns: _1) -> Self { unsafe { crate::dll::AzListView_new(columns.into()) } }
+        /// Calls the `ListView::set_columns` function.
+        pub fn set_columns<_1: Into<StringVec>>(&mut self, columns: _1)  { unsafe { crate::dll::AzListView_setColumns(self, columns.into()) } }
+        /// Calls the `ListView::with_columns` function.
+        pub fn with_columns<_1: Into<StringVec>>(&mut self, columns: _1)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withColumns(self, columns.into()) } }
+        /// Calls the `ListView::set_rows` function.
+        pub fn set_rows<_1: Into<ListViewRowVec>>(&mut self, rows: _1)  { unsafe { crate::dll::AzListView_setRows(self, rows.into()) } }
         /// Calls the `ListView::with_rows` function.
         pub fn with_rows<_1: Into<ListViewRowVec>>(&mut self, rows: _1)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withRows(self, rows.into()) } }
+        /// Calls the `ListView::set_sorted_by` function.
+        pub fn set_sorted_by<_1: Into<OptionUsize>>(&mut self, sorted_by: _1)  { unsafe { crate::dll::AzListView_setSortedBy(self, sorted_by.into()) } }
+        /// Calls the `ListView::with_sorted_by` function.
+        pub fn with_sorted_by<_1: Into<OptionUsize>>(&mut self, sorted_by: _1)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withSortedBy(self, sorted_by.into()) } }
+        /// Calls the `ListView::set_sort_descending` function.
+        pub fn set_sort_descending(&mut self, sort_descending: bool)  { unsafe { crate::dll::AzListView_setSortDescending(self, sort_descending) } }
+        /// Calls the `ListView::with_sort_descending` function.
+        pub fn with_sort_descending(&mut self, sort_descending: bool)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withSortDescending(self, sort_descending) } }
+        /// Calls the `ListView::set_scroll_offset` function.
+        pub fn set_scroll_offset<_1: Into<PixelValueNoPercent>>(&mut self, scroll_offset: _1)  { unsafe { crate::dll::AzListView_setScrollOffset(self, scroll_offset.into()) } }
+        /// Calls the `ListView::with_scroll_offset` function.
+        pub fn with_scroll_offset<_1: Into<PixelValueNoPercent>>(&mut self, scroll_offset: _1)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withScrollOffset(self, scroll_offset.into()) } }
+        /// Calls the `ListView::set_content_height` function.
+        pub fn set_content_height<_1: Into<PixelValueNoPercent>>(&mut self, content_height: _1)  { unsafe { crate::dll::AzListView_setContentHeight(self, content_height.into()) } }
+        /// Calls the `ListView::with_content_height` function.
+        pub fn with_content_height<_1: Into<PixelValueNoPercent>>(&mut self, content_height: _1)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withContentHeight(self, content_height.into()) } }
+        /// Calls the `ListView::set_column_context_menu` function.
+        pub fn set_column_context_menu<_1: Into<Menu>>(&mut self, context_menu: _1)  { unsafe { crate::dll::AzListView_setColumnContextMenu(self, context_menu.into()) } }
+        /// Calls the `ListView::with_column_context_menu` function.
+        pub fn with_column_context_menu<_1: Into<Menu>>(&mut self, context_menu: _1)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withColumnContextMenu(self, context_menu.into()) } }
+        /// Calls the `ListView::set_row_height` function.
+        pub fn set_row_height(&mut self, row_height: f32)  { unsafe { crate::dll::AzListView_setRowHeight(self, row_height) } }
+        /// Calls the `ListView::with_row_height` function.
+        pub fn with_row_height(&mut self, row_height: f32)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withRowHeight(self, row_height) } }
+        /// Calls the `ListView::set_first_visible_row` function.
+        pub fn set_first_visible_row(&mut self, first_visible_row: usize)  { unsafe { crate::dll::AzListView_setFirstVisibleRow(self, first_visible_row) } }
+        /// Calls the `ListView::with_first_visible_row` function.
+        pub fn with_first_visible_row(&mut self, first_visible_row: usize)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withFirstVisibleRow(self, first_visible_row) } }
+        /// Calls the `ListView::set_column_widths` function.
+        pub fn set_column_widths<_1: Into<F32Vec>>(&mut self, column_widths: _1)  { unsafe { crate::dll::AzListView_setColumnWidths(self, column_widths.into()) } }
+        /// Calls the `ListView::with_column_widths` function.
+        pub fn with_column_widths<_1: Into<F32Vec>>(&mut self, column_widths: _1)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withColumnWidths(self, column_widths.into()) } }
+        /// Calls the `ListView::set_selection_state` function.
+        pub fn set_selection_state<_1: Into<OptionUsize>, _2: Into<OptionUsize>>(&mut self, focused_row: _1, selection_anchor: _2)  { unsafe { crate::dll::AzListView_setSelectionState(self, focused_row.into(), selection_anchor.into()) } }
+        /// Calls the `ListView::with_selection_state` function.
+        pub fn with_selection_state<_1: Into<OptionUsize>, _2: Into<OptionUsize>>(&mut self, focused_row: _1, selection_anchor: _2)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withSelectionState(self, focused_row.into(), selection_anchor.into()) } }
+        /// Calls the `ListView::set_row_provider` function.
+        pub fn set_row_provider<_2: Into<RefAny>>(&mut self, row_count: usize, data: _2, callback: ListViewRowProviderCallbackType)  { unsafe { crate::dll::AzListView_setRowProvider(self, row_count, data.into(), callback) } }
+        /// Calls the `ListView::with_row_provider` function.
+        pub fn with_row_provider<_2: Into<RefAny>>(&mut self, row_count: usize, data: _2, callback: ListViewRowProviderCallbackType)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withRowProvider(self, row_count, data.into(), callback) } }
+        /// Calls the `ListView::set_on_lazy_load_scroll` function.
+        pub fn set_on_lazy_load_scroll<_1: Into<RefAny>>(&mut self, data: _1, callback: ListViewOnLazyLoadScrollCallbackType)  { unsafe { crate::dll::AzListView_setOnLazyLoadScroll(self, data.into(), callback) } }
+        /// Calls the `ListView::with_on_lazy_load_scroll` function.
+        pub fn with_on_lazy_load_scroll<_1: Into<RefAny>>(&mut self, data: _1, callback: ListViewOnLazyLoadScrollCallbackType)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withOnLazyLoadScroll(self, data.into(), callback) } }
+        /// Calls the `ListView::set_on_column_click` function.
+        pub fn set_on_column_click<_1: Into<RefAny>>(&mut self, data: _1, callback: ListViewOnColumnClickCallbackType)  { unsafe { crate::dll::AzListView_setOnColumnClick(self, data.into(), callback) } }
+        /// Calls the `ListView::with_on_column_click` function.
+        pub fn with_on_column_click<_1: Into<RefAny>>(&mut self, data: _1, callback: ListViewOnColumnClickCallbackType)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withOnColumnClick(self, data.into(), callback) } }
+        /// Calls the `ListView::set_on_row_click` function.
+        pub fn set_on_row_click<_1: Into<RefAny>>(&mut self, data: _1, callback: ListViewOnRowClickCallbackType)  { unsafe { crate::dll::AzListView_setOnRowClick(self, data.into(), callback) } }
+        /// Calls the `ListView::with_on_row_click` function.
+        pub fn with_on_row_click<_1: Into<RefAny>>(&mut self, data: _1, callback: ListViewOnRowClickCallbackType)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withOnRowClick(self, data.into(), callback) } }
+        /// Calls the `ListView::set_on_row_select` function.
+        pub fn set_on_row_select<_1: Into<RefAny>>(&mut self, data: _1, callback: ListViewOnRowSelectCallbackType)  { unsafe { crate::dll::AzListView_setOnRowSelect(self, data.into(), callback) } }
+        /// Calls the `ListView::with_on_row_select` function.
+        pub fn with_on_row_select<_1: Into<RefAny>>(&mut self, data: _1, callback: ListViewOnRowSelectCallbackType)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withOnRowSelect(self, data.into(), callback) } }
+        /// Calls the `ListView::set_on_column_resize` function.
+        pub fn set_on_column_resize<_1: Into<RefAny>>(&mut self, data: _1, callback: ListViewOnColumnResizeCallbackType)  { unsafe { crate::dll::AzListView_setOnColumnResize(self, data.into(), callback) } }
+        /// Calls the `ListView::with_on_column_resize` function.
+        pub fn with_on_column_resize<_1: Into<RefAny>>(&mut self, data: _1, callback: ListViewOnColumnResizeCallbackType)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withOnColumnResize(self, data.into(), callback) } }
+        /// Calls the `ListView::get_total_row_count` function.
+        pub fn get_total_row_count(&mut self)  -> usize { unsafe { crate::dll::AzListView_getTotalRowCount(self) } }
+        /// Updates the `is_selected` flags of the rows the same way the ListView does it internally
+        pub fn apply_selection<_2: Into<ListViewSelectionChange>>(&mut self, row_id: usize, change: _2)  { unsafe { crate::dll::AzListView_applySelection(self, row_id, change.into()) } }
         /// Calls the `ListView::dom` function.
         pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzListView_dom(self) } }
     }
@@ -15365,6 +17817,9 @@ pub mod widgets {
     /// `ListViewState` struct
     
     #[doc(inline)] pub use crate::dll::AzListViewState as ListViewState;
+    /// How the selection should change in response to a user action
+    
+    #[doc(inline)] pub use crate::dll::AzListViewSelectionChange as ListViewSelectionChange;
     /// `ListViewOnLazyLoadScrollCallbackType` struct
     
     #[doc(inline)] pub use crate::dll::AzListViewOnLazyLoadScrollCallbackType as ListViewOnLazyLoadScrollCallbackType;
@@ -15392,17 +17847,594 @@ pub mod widgets {
     /// `ListViewOnRowClick` struct
     
     #[doc(inline)] pub use crate::dll::AzListViewOnRowClick as ListViewOnRowClick;
-    /// `TreeView` struct
+    /// `ListViewOnRowSelectCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzListViewOnRowSelectCallbackType as ListViewOnRowSelectCallbackType;
+    /// `ListViewOnRowSelectCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzListViewOnRowSelectCallback as ListViewOnRowSelectCallback;
+    /// `ListViewOnRowSelect` struct
+    
+    #[doc(inline)] pub use crate::dll::AzListViewOnRowSelect as ListViewOnRowSelect;
+    /// `ListViewOnColumnResizeCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzListViewOnColumnResizeCallbackType as ListViewOnColumnResizeCallbackType;
+    /// `ListViewOnColumnResizeCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzListViewOnColumnResizeCallback as ListViewOnColumnResizeCallback;
+    /// `ListViewOnColumnResize` struct
+    
+    #[doc(inline)] pub use crate::dll::AzListViewOnColumnResize as ListViewOnColumnResize;
+    /// `ListViewRowProviderCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzListViewRowProviderCallbackType as ListViewRowProviderCallbackType;
+    /// `ListViewRowProviderCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzListViewRowProviderCallback as ListViewRowProviderCallback;
+    /// `ListViewRowProvider` struct
+    
+    #[doc(inline)] pub use crate::dll::AzListViewRowProvider as ListViewRowProvider;
+    /// Hierarchical tree of items with expand / collapse and selection. Only the rows between `first_visible_row` and `first_visible_row + max_visible_rows` are rendered. The callbacks only report what the user did, the application applies the change to its own copy of the TreeView (`set_expanded`, `apply_selection`, `set_children`, `set_first_visible_row`)
     
     #[doc(inline)] pub use crate::dll::AzTreeView as TreeView;
     impl TreeView {
 
         /// Creates a new `TreeView` instance.
-        pub fn new<_1: Into<String>>(root: _1) -> Self { unsafe { crate::dll::AzTreeView_new(root.into()) } }
+        pub fn new<_1: Into<TreeViewItemVec>>(items: _1) -> Self { unsafe { crate::dll::AzTreeView_new(items.into()) } }
+        /// Calls the `TreeView::set_items` function.
+        pub fn set_items<_1: Into<TreeViewItemVec>>(&mut self, items: _1)  { unsafe { crate::dll::AzTreeView_setItems(self, items.into()) } }
+        /// Calls the `TreeView::with_items` function.
+        pub fn with_items<_1: Into<TreeViewItemVec>>(&mut self, items: _1)  -> crate::widgets::TreeView { unsafe { crate::dll::AzTreeView_withItems(self, items.into()) } }
+        /// Calls the `TreeView::set_selection_mode` function.
+        pub fn set_selection_mode<_1: Into<TreeViewSelectionMode>>(&mut self, selection_mode: _1)  { unsafe { crate::dll::AzTreeView_setSelectionMode(self, selection_mode.into()) } }
+        /// Calls the `TreeView::with_selection_mode` function.
+        pub fn with_selection_mode<_1: Into<TreeViewSelectionMode>>(&mut self, selection_mode: _1)  -> crate::widgets::TreeView { unsafe { crate::dll::AzTreeView_withSelectionMode(self, selection_mode.into()) } }
+        /// Calls the `TreeView::set_first_visible_row` function.
+        pub fn set_first_visible_row(&mut self, first_visible_row: usize)  { unsafe { crate::dll::AzTreeView_setFirstVisibleRow(self, first_visible_row) } }
+        /// Calls the `TreeView::set_max_visible_rows` function.
+        pub fn set_max_visible_rows(&mut self, max_visible_rows: usize)  { unsafe { crate::dll::AzTreeView_setMaxVisibleRows(self, max_visible_rows) } }
+        /// Calls the `TreeView::set_on_expand` function.
+        pub fn set_on_expand<_1: Into<RefAny>>(&mut self, data: _1, callback: TreeViewOnExpandCallbackType)  { unsafe { crate::dll::AzTreeView_setOnExpand(self, data.into(), callback) } }
+        /// Calls the `TreeView::with_on_expand` function.
+        pub fn with_on_expand<_1: Into<RefAny>>(&mut self, data: _1, callback: TreeViewOnExpandCallbackType)  -> crate::widgets::TreeView { unsafe { crate::dll::AzTreeView_withOnExpand(self, data.into(), callback) } }
+        /// Calls the `TreeView::set_on_select` function.
+        pub fn set_on_select<_1: Into<RefAny>>(&mut self, data: _1, callback: TreeViewOnSelectCallbackType)  { unsafe { crate::dll::AzTreeView_setOnSelect(self, data.into(), callback) } }
+        /// Calls the `TreeView::with_on_select` function.
+        pub fn with_on_select<_1: Into<RefAny>>(&mut self, data: _1, callback: TreeViewOnSelectCallbackType)  -> crate::widgets::TreeView { unsafe { crate::dll::AzTreeView_withOnSelect(self, data.into(), callback) } }
+        /// Calls the `TreeView::set_on_lazy_load` function.
+        pub fn set_on_lazy_load<_1: Into<RefAny>>(&mut self, data: _1, callback: TreeViewOnLazyLoadCallbackType)  { unsafe { crate::dll::AzTreeView_setOnLazyLoad(self, data.into(), callback) } }
+        /// Calls the `TreeView::with_on_lazy_load` function.
+        pub fn with_on_lazy_load<_1: Into<RefAny>>(&mut self, data: _1, callback: TreeViewOnLazyLoadCallbackType)  -> crate::widgets::TreeView { unsafe { crate::dll::AzTreeView_withOnLazyLoad(self, data.into(), callback) } }
+        /// Calls the `TreeView::set_on_scroll` function.
+        pub fn set_on_scroll<_1: Into<RefAny>>(&mut self, data: _1, callback: TreeViewOnScrollCallbackType)  { unsafe { crate::dll::AzTreeView_setOnScroll(self, data.into(), callback) } }
+        /// Calls the `TreeView::with_on_scroll` function.
+        pub fn with_on_scroll<_1: Into<RefAny>>(&mut self, data: _1, callback: TreeViewOnScrollCallbackType)  -> crate::widgets::TreeView { unsafe { crate::dll::AzTreeView_withOnScroll(self, data.into(), callback) } }
+        /// Returns the number of rows that are visible when all collapsed items are skipped
+        pub fn get_visible_row_count(&self)  -> usize { unsafe { crate::dll::AzTreeView_getVisibleRowCount(self) } }
+        /// Expands or collapses the item with the given ID, returns false if the item could not be found
+        pub fn set_expanded(&mut self, item_id: usize, expanded: bool)  -> bool { unsafe { crate::dll::AzTreeView_setExpanded(self, item_id, expanded) } }
+        /// Replaces the children of the item with the given ID and clears the `has_lazy_children` flag
+        pub fn set_children<_2: Into<TreeViewItemVec>>(&mut self, item_id: usize, children: _2)  -> bool { unsafe { crate::dll::AzTreeView_setChildren(self, item_id, children.into()) } }
+        /// Updates the `is_selected` flags of the items the same way the TreeView does it internally
+        pub fn apply_selection<_2: Into<TreeViewSelectionChange>>(&mut self, item_id: usize, change: _2)  { unsafe { crate::dll::AzTreeView_applySelection(self, item_id, change.into()) } }
         /// Calls the `TreeView::dom` function.
         pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzTreeView_dom(self) } }
     }
 
+    /// `TreeViewItem` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTreeViewItem as TreeViewItem;
+    impl TreeViewItem {
+
+        /// Creates a new `TreeViewItem` instance.
+        pub fn new<_2: Into<String>>(id: usize, label: _2) -> Self { unsafe { crate::dll::AzTreeViewItem_new(id, label.into()) } }
+    }
+
+    /// `TreeViewSelectionMode` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTreeViewSelectionMode as TreeViewSelectionMode;
+    /// `TreeViewSelectionChange` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTreeViewSelectionChange as TreeViewSelectionChange;
+    /// `TreeViewOnExpandCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTreeViewOnExpandCallbackType as TreeViewOnExpandCallbackType;
+    /// `TreeViewOnExpandCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTreeViewOnExpandCallback as TreeViewOnExpandCallback;
+    /// `TreeViewOnExpand` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTreeViewOnExpand as TreeViewOnExpand;
+    /// `TreeViewOnSelectCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTreeViewOnSelectCallbackType as TreeViewOnSelectCallbackType;
+    /// `TreeViewOnSelectCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTreeViewOnSelectCallback as TreeViewOnSelectCallback;
+    /// `TreeViewOnSelect` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTreeViewOnSelect as TreeViewOnSelect;
+    /// `TreeViewOnLazyLoadCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTreeViewOnLazyLoadCallbackType as TreeViewOnLazyLoadCallbackType;
+    /// `TreeViewOnLazyLoadCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTreeViewOnLazyLoadCallback as TreeViewOnLazyLoadCallback;
+    /// `TreeViewOnLazyLoad` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTreeViewOnLazyLoad as TreeViewOnLazyLoad;
+    /// `TreeViewOnScrollCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTreeViewOnScrollCallbackType as TreeViewOnScrollCallbackType;
+    /// `TreeViewOnScrollCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTreeViewOnScrollCallback as TreeViewOnScrollCallback;
+    /// `TreeViewOnScroll` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTreeViewOnScroll as TreeViewOnScroll;
+    /// Spreadsheet-like grid of text cells. The DataGrid does not store any cell data: the text of each visible cell is requested from the `cell_provider`, only the cells that fit into the visible area are rendered. Scrolling, selection, resizing and editing are reported through the callbacks, the application stores the new state for the next frame
+    
+    #[doc(inline)] pub use crate::dll::AzDataGrid as DataGrid;
+    impl DataGrid {
+
+        /// Creates a new `DataGrid` instance.
+        pub fn new(row_count: usize, column_count: usize) -> Self { unsafe { crate::dll::AzDataGrid_new(row_count, column_count) } }
+        /// Calls the `DataGrid::set_column_headers` function.
+        pub fn set_column_headers<_1: Into<StringVec>>(&mut self, column_headers: _1)  { unsafe { crate::dll::AzDataGrid_setColumnHeaders(self, column_headers.into()) } }
+        /// Calls the `DataGrid::with_column_headers` function.
+        pub fn with_column_headers<_1: Into<StringVec>>(&mut self, column_headers: _1)  -> crate::widgets::DataGrid { unsafe { crate::dll::AzDataGrid_withColumnHeaders(self, column_headers.into()) } }
+        /// Calls the `DataGrid::set_column_widths` function.
+        pub fn set_column_widths<_1: Into<F32Vec>>(&mut self, column_widths: _1)  { unsafe { crate::dll::AzDataGrid_setColumnWidths(self, column_widths.into()) } }
+        /// Calls the `DataGrid::with_column_widths` function.
+        pub fn with_column_widths<_1: Into<F32Vec>>(&mut self, column_widths: _1)  -> crate::widgets::DataGrid { unsafe { crate::dll::AzDataGrid_withColumnWidths(self, column_widths.into()) } }
+        /// Calls the `DataGrid::set_row_heights` function.
+        pub fn set_row_heights<_1: Into<F32Vec>>(&mut self, row_heights: _1)  { unsafe { crate::dll::AzDataGrid_setRowHeights(self, row_heights.into()) } }
+        /// Calls the `DataGrid::with_row_heights` function.
+        pub fn with_row_heights<_1: Into<F32Vec>>(&mut self, row_heights: _1)  -> crate::widgets::DataGrid { unsafe { crate::dll::AzDataGrid_withRowHeights(self, row_heights.into()) } }
+        /// Calls the `DataGrid::set_scroll_position` function.
+        pub fn set_scroll_position(&mut self, first_visible_row: usize, first_visible_column: usize)  { unsafe { crate::dll::AzDataGrid_setScrollPosition(self, first_visible_row, first_visible_column) } }
+        /// Calls the `DataGrid::with_scroll_position` function.
+        pub fn with_scroll_position(&mut self, first_visible_row: usize, first_visible_column: usize)  -> crate::widgets::DataGrid { unsafe { crate::dll::AzDataGrid_withScrollPosition(self, first_visible_row, first_visible_column) } }
+        /// Calls the `DataGrid::set_selection` function.
+        pub fn set_selection<_1: Into<OptionDataGridRange>>(&mut self, selection: _1)  { unsafe { crate::dll::AzDataGrid_setSelection(self, selection.into()) } }
+        /// Calls the `DataGrid::with_selection` function.
+        pub fn with_selection<_1: Into<OptionDataGridRange>>(&mut self, selection: _1)  -> crate::widgets::DataGrid { unsafe { crate::dll::AzDataGrid_withSelection(self, selection.into()) } }
+        /// Calls the `DataGrid::set_cell_provider` function.
+        pub fn set_cell_provider<_1: Into<RefAny>>(&mut self, data: _1, callback: DataGridCellProviderCallbackType)  { unsafe { crate::dll::AzDataGrid_setCellProvider(self, data.into(), callback) } }
+        /// Calls the `DataGrid::with_cell_provider` function.
+        pub fn with_cell_provider<_1: Into<RefAny>>(&mut self, data: _1, callback: DataGridCellProviderCallbackType)  -> crate::widgets::DataGrid { unsafe { crate::dll::AzDataGrid_withCellProvider(self, data.into(), callback) } }
+        /// Calls the `DataGrid::set_cell_style` function.
+        pub fn set_cell_style<_1: Into<RefAny>>(&mut self, data: _1, callback: DataGridCellStyleCallbackType)  { unsafe { crate::dll::AzDataGrid_setCellStyle(self, data.into(), callback) } }
+        /// Calls the `DataGrid::with_cell_style` function.
+        pub fn with_cell_style<_1: Into<RefAny>>(&mut self, data: _1, callback: DataGridCellStyleCallbackType)  -> crate::widgets::DataGrid { unsafe { crate::dll::AzDataGrid_withCellStyle(self, data.into(), callback) } }
+        /// Calls the `DataGrid::set_on_select` function.
+        pub fn set_on_select<_1: Into<RefAny>>(&mut self, data: _1, callback: DataGridOnSelectCallbackType)  { unsafe { crate::dll::AzDataGrid_setOnSelect(self, data.into(), callback) } }
+        /// Calls the `DataGrid::with_on_select` function.
+        pub fn with_on_select<_1: Into<RefAny>>(&mut self, data: _1, callback: DataGridOnSelectCallbackType)  -> crate::widgets::DataGrid { unsafe { crate::dll::AzDataGrid_withOnSelect(self, data.into(), callback) } }
+        /// Calls the `DataGrid::set_on_scroll` function.
+        pub fn set_on_scroll<_1: Into<RefAny>>(&mut self, data: _1, callback: DataGridOnScrollCallbackType)  { unsafe { crate::dll::AzDataGrid_setOnScroll(self, data.into(), callback) } }
+        /// Calls the `DataGrid::with_on_scroll` function.
+        pub fn with_on_scroll<_1: Into<RefAny>>(&mut self, data: _1, callback: DataGridOnScrollCallbackType)  -> crate::widgets::DataGrid { unsafe { crate::dll::AzDataGrid_withOnScroll(self, data.into(), callback) } }
+        /// Calls the `DataGrid::set_on_cell_edit` function.
+        pub fn set_on_cell_edit<_1: Into<RefAny>>(&mut self, data: _1, callback: DataGridOnCellEditCallbackType)  { unsafe { crate::dll::AzDataGrid_setOnCellEdit(self, data.into(), callback) } }
+        /// Calls the `DataGrid::with_on_cell_edit` function.
+        pub fn with_on_cell_edit<_1: Into<RefAny>>(&mut self, data: _1, callback: DataGridOnCellEditCallbackType)  -> crate::widgets::DataGrid { unsafe { crate::dll::AzDataGrid_withOnCellEdit(self, data.into(), callback) } }
+        /// Calls the `DataGrid::set_on_column_resize` function.
+        pub fn set_on_column_resize<_1: Into<RefAny>>(&mut self, data: _1, callback: DataGridOnColumnResizeCallbackType)  { unsafe { crate::dll::AzDataGrid_setOnColumnResize(self, data.into(), callback) } }
+        /// Calls the `DataGrid::with_on_column_resize` function.
+        pub fn with_on_column_resize<_1: Into<RefAny>>(&mut self, data: _1, callback: DataGridOnColumnResizeCallbackType)  -> crate::widgets::DataGrid { unsafe { crate::dll::AzDataGrid_withOnColumnResize(self, data.into(), callback) } }
+        /// Calls the `DataGrid::set_on_row_resize` function.
+        pub fn set_on_row_resize<_1: Into<RefAny>>(&mut self, data: _1, callback: DataGridOnRowResizeCallbackType)  { unsafe { crate::dll::AzDataGrid_setOnRowResize(self, data.into(), callback) } }
+        /// Calls the `DataGrid::with_on_row_resize` function.
+        pub fn with_on_row_resize<_1: Into<RefAny>>(&mut self, data: _1, callback: DataGridOnRowResizeCallbackType)  -> crate::widgets::DataGrid { unsafe { crate::dll::AzDataGrid_withOnRowResize(self, data.into(), callback) } }
+        /// Calls the `DataGrid::set_on_copy_range` function.
+        pub fn set_on_copy_range<_1: Into<RefAny>>(&mut self, data: _1, callback: DataGridOnCopyRangeCallbackType)  { unsafe { crate::dll::AzDataGrid_setOnCopyRange(self, data.into(), callback) } }
+        /// Calls the `DataGrid::with_on_copy_range` function.
+        pub fn with_on_copy_range<_1: Into<RefAny>>(&mut self, data: _1, callback: DataGridOnCopyRangeCallbackType)  -> crate::widgets::DataGrid { unsafe { crate::dll::AzDataGrid_withOnCopyRange(self, data.into(), callback) } }
+        /// Calls the `DataGrid::set_on_clear_range` function.
+        pub fn set_on_clear_range<_1: Into<RefAny>>(&mut self, data: _1, callback: DataGridOnClearRangeCallbackType)  { unsafe { crate::dll::AzDataGrid_setOnClearRange(self, data.into(), callback) } }
+        /// Calls the `DataGrid::with_on_clear_range` function.
+        pub fn with_on_clear_range<_1: Into<RefAny>>(&mut self, data: _1, callback: DataGridOnClearRangeCallbackType)  -> crate::widgets::DataGrid { unsafe { crate::dll::AzDataGrid_withOnClearRange(self, data.into(), callback) } }
+        /// Calls the `DataGrid::get_column_width` function.
+        pub fn get_column_width(&self, column: usize)  -> f32 { unsafe { crate::dll::AzDataGrid_getColumnWidth(self, column) } }
+        /// Calls the `DataGrid::get_row_height` function.
+        pub fn get_row_height(&self, row: usize)  -> f32 { unsafe { crate::dll::AzDataGrid_getRowHeight(self, row) } }
+        /// Calls the `DataGrid::get_column_name` function.
+        pub fn get_column_name(&self, column: usize)  -> crate::str::String { unsafe { crate::dll::AzDataGrid_getColumnName(self, column) } }
+        /// Calls the `DataGrid::get_cell_text` function.
+        pub fn get_cell_text(&mut self, row: usize, column: usize)  -> crate::str::String { unsafe { crate::dll::AzDataGrid_getCellText(self, row, column) } }
+        /// Calls the `DataGrid::get_selection_tsv` function.
+        pub fn get_selection_tsv(&mut self)  -> crate::str::String { unsafe { crate::dll::AzDataGrid_getSelectionTsv(self) } }
+        /// Returns the selection, clipped to the size of the grid (`None` if nothing is selected or the selection is out of bounds)
+        pub fn get_clipped_selection(&self)  -> crate::option::OptionDataGridRange { unsafe { crate::dll::AzDataGrid_getClippedSelection(self) } }
+        /// Calls the `DataGrid::dom` function.
+        pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzDataGrid_dom(self) } }
+    }
+
+    /// Position of a cell in the DataGrid
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridCell as DataGridCell;
+    /// Rectangular range of selected cells
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridRange as DataGridRange;
+    impl DataGridRange {
+
+        /// Selection of a single cell
+        pub fn new(row: usize, column: usize) -> Self { unsafe { crate::dll::AzDataGridRange_new(row, column) } }
+        /// Calls the `DataGridRange::get_top_left` function.
+        pub fn get_top_left(&self)  -> crate::widgets::DataGridCell { unsafe { crate::dll::AzDataGridRange_getTopLeft(self) } }
+        /// Calls the `DataGridRange::get_bottom_right` function.
+        pub fn get_bottom_right(&self)  -> crate::widgets::DataGridCell { unsafe { crate::dll::AzDataGridRange_getBottomRight(self) } }
+        /// Calls the `DataGridRange::contains` function.
+        pub fn contains(&self, row: usize, column: usize)  -> bool { unsafe { crate::dll::AzDataGridRange_contains(self, row, column) } }
+        /// Number of cells in the range (saturates at `usize::MAX`)
+        pub fn get_cell_count(&self)  -> usize { unsafe { crate::dll::AzDataGridRange_getCellCount(self) } }
+    }
+
+    /// `DataGridCellProviderCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridCellProviderCallbackType as DataGridCellProviderCallbackType;
+    /// `DataGridCellProviderCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridCellProviderCallback as DataGridCellProviderCallback;
+    /// `DataGridCellProvider` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridCellProvider as DataGridCellProvider;
+    /// `DataGridCellStyleCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridCellStyleCallbackType as DataGridCellStyleCallbackType;
+    /// `DataGridCellStyleCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridCellStyleCallback as DataGridCellStyleCallback;
+    /// `DataGridCellStyle` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridCellStyle as DataGridCellStyle;
+    /// `DataGridOnSelectCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridOnSelectCallbackType as DataGridOnSelectCallbackType;
+    /// `DataGridOnSelectCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridOnSelectCallback as DataGridOnSelectCallback;
+    /// `DataGridOnSelect` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridOnSelect as DataGridOnSelect;
+    /// `DataGridOnScrollCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridOnScrollCallbackType as DataGridOnScrollCallbackType;
+    /// `DataGridOnScrollCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridOnScrollCallback as DataGridOnScrollCallback;
+    /// `DataGridOnScroll` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridOnScroll as DataGridOnScroll;
+    /// `DataGridOnCellEditCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridOnCellEditCallbackType as DataGridOnCellEditCallbackType;
+    /// `DataGridOnCellEditCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridOnCellEditCallback as DataGridOnCellEditCallback;
+    /// `DataGridOnCellEdit` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridOnCellEdit as DataGridOnCellEdit;
+    /// `DataGridOnColumnResizeCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridOnColumnResizeCallbackType as DataGridOnColumnResizeCallbackType;
+    /// `DataGridOnColumnResizeCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridOnColumnResizeCallback as DataGridOnColumnResizeCallback;
+    /// `DataGridOnColumnResize` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridOnColumnResize as DataGridOnColumnResize;
+    /// `DataGridOnRowResizeCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridOnRowResizeCallbackType as DataGridOnRowResizeCallbackType;
+    /// `DataGridOnRowResizeCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridOnRowResizeCallback as DataGridOnRowResizeCallback;
+    /// `DataGridOnRowResize` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridOnRowResize as DataGridOnRowResize;
+    /// `DataGridOnCopyRangeCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridOnCopyRangeCallbackType as DataGridOnCopyRangeCallbackType;
+    /// `DataGridOnCopyRangeCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridOnCopyRangeCallback as DataGridOnCopyRangeCallback;
+    /// `DataGridOnCopyRange` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridOnCopyRange as DataGridOnCopyRange;
+    /// `DataGridOnClearRangeCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridOnClearRangeCallbackType as DataGridOnClearRangeCallbackType;
+    /// `DataGridOnClearRangeCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridOnClearRangeCallback as DataGridOnClearRangeCallback;
+    /// `DataGridOnClearRange` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDataGridOnClearRange as DataGridOnClearRange;
+    /// Calendar date (proleptic Gregorian calendar), not validated on construction
+    
+    #[doc(inline)] pub use crate::dll::AzDate as Date;
+    impl Date {
+
+        /// Creates a new `Date` instance.
+        pub fn new(year: i32, month: u8, day: u8) -> Self { unsafe { crate::dll::AzDate_new(year, month, day) } }
+        /// Returns the current date (UTC)
+        pub fn today() -> Self { unsafe { crate::dll::AzDate_today() } }
+        /// Calls the `Date::is_valid` function.
+        pub fn is_valid(&self)  -> bool { unsafe { crate::dll::AzDate_isValid(self) } }
+        /// Calls the `Date::weekday` function.
+        pub fn weekday(&self)  -> crate::widgets::Weekday { unsafe { crate::dll::AzDate_weekday(self) } }
+        /// Calls the `Date::add_days` function.
+        pub fn add_days(&self, days: i64)  -> crate::widgets::Date { unsafe { crate::dll::AzDate_addDays(self, days) } }
+        /// Adds (or subtracts) months, the day is clamped to the length of the target month
+        pub fn add_months(&self, months: i64)  -> crate::widgets::Date { unsafe { crate::dll::AzDate_addMonths(self, months) } }
+        /// Clamps the date to the (optional) minimum and maximum date
+        pub fn clamp_to<_1: Into<OptionDate>, _2: Into<OptionDate>>(&self, minimum: _1, maximum: _2)  -> crate::widgets::Date { unsafe { crate::dll::AzDate_clampTo(self, minimum.into(), maximum.into()) } }
+    }
+
+    /// Day of the week
+    
+    #[doc(inline)] pub use crate::dll::AzWeekday as Weekday;
+    /// Time of day, not validated on construction
+    
+    #[doc(inline)] pub use crate::dll::AzTime as Time;
+    impl Time {
+
+        /// Creates a new `Time` instance.
+        pub fn new(hour: u8, minute: u8, second: u8) -> Self { unsafe { crate::dll::AzTime_new(hour, minute, second) } }
+        /// Calls the `Time::is_valid` function.
+        pub fn is_valid(&self)  -> bool { unsafe { crate::dll::AzTime_isValid(self) } }
+    }
+
+    /// State of a `DatePicker`, reported by `on_date_change`
+    
+    #[doc(inline)] pub use crate::dll::AzDatePickerState as DatePickerState;
+    /// `DatePickerOnDateChangeCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDatePickerOnDateChangeCallbackType as DatePickerOnDateChangeCallbackType;
+    /// `DatePickerOnDateChangeCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDatePickerOnDateChangeCallback as DatePickerOnDateChangeCallback;
+    /// `DatePickerOnDateChange` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDatePickerOnDateChange as DatePickerOnDateChange;
+    /// Month calendar to select a single date. Selecting a date and navigating between months is reported through `on_date_change`, the application stores the new `DatePickerState` for the next frame. Arrow keys, PageUp / PageDown and Home / End move the cursor, Enter / Space select the date under the cursor
+    
+    #[doc(inline)] pub use crate::dll::AzDatePicker as DatePicker;
+    impl DatePicker {
+
+        /// Creates a DatePicker with the `date` selected and the cursor on it
+        pub fn new<_1: Into<Date>>(date: _1) -> Self { unsafe { crate::dll::AzDatePicker_new(date.into()) } }
+        /// Calls the `DatePicker::set_state` function.
+        pub fn set_state<_1: Into<DatePickerState>>(&mut self, state: _1)  { unsafe { crate::dll::AzDatePicker_setState(self, state.into()) } }
+        /// Calls the `DatePicker::with_state` function.
+        pub fn with_state<_1: Into<DatePickerState>>(&mut self, state: _1)  -> crate::widgets::DatePicker { unsafe { crate::dll::AzDatePicker_withState(self, state.into()) } }
+        /// Calls the `DatePicker::set_minimum_date` function.
+        pub fn set_minimum_date<_1: Into<OptionDate>>(&mut self, minimum_date: _1)  { unsafe { crate::dll::AzDatePicker_setMinimumDate(self, minimum_date.into()) } }
+        /// Calls the `DatePicker::with_minimum_date` function.
+        pub fn with_minimum_date<_1: Into<OptionDate>>(&mut self, minimum_date: _1)  -> crate::widgets::DatePicker { unsafe { crate::dll::AzDatePicker_withMinimumDate(self, minimum_date.into()) } }
+        /// Calls the `DatePicker::set_maximum_date` function.
+        pub fn set_maximum_date<_1: Into<OptionDate>>(&mut self, maximum_date: _1)  { unsafe { crate::dll::AzDatePicker_setMaximumDate(self, maximum_date.into()) } }
+        /// Calls the `DatePicker::with_maximum_date` function.
+        pub fn with_maximum_date<_1: Into<OptionDate>>(&mut self, maximum_date: _1)  -> crate::widgets::DatePicker { unsafe { crate::dll::AzDatePicker_withMaximumDate(self, maximum_date.into()) } }
+        /// Calls the `DatePicker::set_first_day_of_week` function.
+        pub fn set_first_day_of_week<_1: Into<Weekday>>(&mut self, first_day_of_week: _1)  { unsafe { crate::dll::AzDatePicker_setFirstDayOfWeek(self, first_day_of_week.into()) } }
+        /// Calls the `DatePicker::with_first_day_of_week` function.
+        pub fn with_first_day_of_week<_1: Into<Weekday>>(&mut self, first_day_of_week: _1)  -> crate::widgets::DatePicker { unsafe { crate::dll::AzDatePicker_withFirstDayOfWeek(self, first_day_of_week.into()) } }
+        /// Calls the `DatePicker::set_on_date_change` function.
+        pub fn set_on_date_change<_1: Into<RefAny>>(&mut self, data: _1, callback: DatePickerOnDateChangeCallbackType)  { unsafe { crate::dll::AzDatePicker_setOnDateChange(self, data.into(), callback) } }
+        /// Calls the `DatePicker::with_on_date_change` function.
+        pub fn with_on_date_change<_1: Into<RefAny>>(&mut self, data: _1, callback: DatePickerOnDateChangeCallbackType)  -> crate::widgets::DatePicker { unsafe { crate::dll::AzDatePicker_withOnDateChange(self, data.into(), callback) } }
+        /// Calls the `DatePicker::dom` function.
+        pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzDatePicker_dom(self) } }
+    }
+
+    /// `TimePickerOnTimeChangeCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTimePickerOnTimeChangeCallbackType as TimePickerOnTimeChangeCallbackType;
+    /// `TimePickerOnTimeChangeCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTimePickerOnTimeChangeCallback as TimePickerOnTimeChangeCallback;
+    /// `TimePickerOnTimeChange` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTimePickerOnTimeChange as TimePickerOnTimeChange;
+    /// Hour / minute / second input with spin buttons, changes are reported through `on_time_change`
+    
+    #[doc(inline)] pub use crate::dll::AzTimePicker as TimePicker;
+    impl TimePicker {
+
+        /// Creates a new `TimePicker` instance.
+        pub fn new<_1: Into<Time>>(time: _1) -> Self { unsafe { crate::dll::AzTimePicker_new(time.into()) } }
+        /// Calls the `TimePicker::set_show_seconds` function.
+        pub fn set_show_seconds(&mut self, show_seconds: bool)  { unsafe { crate::dll::AzTimePicker_setShowSeconds(self, show_seconds) } }
+        /// Calls the `TimePicker::with_show_seconds` function.
+        pub fn with_show_seconds(&mut self, show_seconds: bool)  -> crate::widgets::TimePicker { unsafe { crate::dll::AzTimePicker_withShowSeconds(self, show_seconds) } }
+        /// Calls the `TimePicker::set_on_time_change` function.
+        pub fn set_on_time_change<_1: Into<RefAny>>(&mut self, data: _1, callback: TimePickerOnTimeChangeCallbackType)  { unsafe { crate::dll::AzTimePicker_setOnTimeChange(self, data.into(), callback) } }
+        /// Calls the `TimePicker::with_on_time_change` function.
+        pub fn with_on_time_change<_1: Into<RefAny>>(&mut self, data: _1, callback: TimePickerOnTimeChangeCallbackType)  -> crate::widgets::TimePicker { unsafe { crate::dll::AzTimePicker_withOnTimeChange(self, data.into(), callback) } }
+        /// Calls the `TimePicker::dom` function.
+        pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzTimePicker_dom(self) } }
+    }
+
+    /// State of a `DateTimePicker`, reported by `on_date_time_change`
+    
+    #[doc(inline)] pub use crate::dll::AzDateTimePickerState as DateTimePickerState;
+    /// `DateTimePickerOnDateTimeChangeCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDateTimePickerOnDateTimeChangeCallbackType as DateTimePickerOnDateTimeChangeCallbackType;
+    /// `DateTimePickerOnDateTimeChangeCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDateTimePickerOnDateTimeChangeCallback as DateTimePickerOnDateTimeChangeCallback;
+    /// `DateTimePickerOnDateTimeChange` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDateTimePickerOnDateTimeChange as DateTimePickerOnDateTimeChange;
+    /// Drop-down field showing the selected date (and time), which opens a `DatePicker` (and `TimePicker`) below the field when clicked. Opening / closing the popup and changing the date or time is reported through `on_date_time_change`
+    
+    #[doc(inline)] pub use crate::dll::AzDateTimePicker as DateTimePicker;
+    impl DateTimePicker {
+
+        /// Creates a closed DateTimePicker with the `date` selected
+        pub fn new<_1: Into<Date>>(date: _1) -> Self { unsafe { crate::dll::AzDateTimePicker_new(date.into()) } }
+        /// Calls the `DateTimePicker::set_state` function.
+        pub fn set_state<_1: Into<DateTimePickerState>>(&mut self, state: _1)  { unsafe { crate::dll::AzDateTimePicker_setState(self, state.into()) } }
+        /// Calls the `DateTimePicker::with_state` function.
+        pub fn with_state<_1: Into<DateTimePickerState>>(&mut self, state: _1)  -> crate::widgets::DateTimePicker { unsafe { crate::dll::AzDateTimePicker_withState(self, state.into()) } }
+        /// Calls the `DateTimePicker::set_minimum_date` function.
+        pub fn set_minimum_date<_1: Into<OptionDate>>(&mut self, minimum_date: _1)  { unsafe { crate::dll::AzDateTimePicker_setMinimumDate(self, minimum_date.into()) } }
+        /// Calls the `DateTimePicker::with_minimum_date` function.
+        pub fn with_minimum_date<_1: Into<OptionDate>>(&mut self, minimum_date: _1)  -> crate::widgets::DateTimePicker { unsafe { crate::dll::AzDateTimePicker_withMinimumDate(self, minimum_date.into()) } }
+        /// Calls the `DateTimePicker::set_maximum_date` function.
+        pub fn set_maximum_date<_1: Into<OptionDate>>(&mut self, maximum_date: _1)  { unsafe { crate::dll::AzDateTimePicker_setMaximumDate(self, maximum_date.into()) } }
+        /// Calls the `DateTimePicker::with_maximum_date` function.
+        pub fn with_maximum_date<_1: Into<OptionDate>>(&mut self, maximum_date: _1)  -> crate::widgets::DateTimePicker { unsafe { crate::dll::AzDateTimePicker_withMaximumDate(self, maximum_date.into()) } }
+        /// Calls the `DateTimePicker::set_first_day_of_week` function.
+        pub fn set_first_day_of_week<_1: Into<Weekday>>(&mut self, first_day_of_week: _1)  { unsafe { crate::dll::AzDateTimePicker_setFirstDayOfWeek(self, first_day_of_week.into()) } }
+        /// Calls the `DateTimePicker::with_first_day_of_week` function.
+        pub fn with_first_day_of_week<_1: Into<Weekday>>(&mut self, first_day_of_week: _1)  -> crate::widgets::DateTimePicker { unsafe { crate::dll::AzDateTimePicker_withFirstDayOfWeek(self, first_day_of_week.into()) } }
+        /// Calls the `DateTimePicker::set_show_time` function.
+        pub fn set_show_time(&mut self, show_time: bool, show_seconds: bool)  { unsafe { crate::dll::AzDateTimePicker_setShowTime(self, show_time, show_seconds) } }
+        /// Calls the `DateTimePicker::with_show_time` function.
+        pub fn with_show_time(&mut self, show_time: bool, show_seconds: bool)  -> crate::widgets::DateTimePicker { unsafe { crate::dll::AzDateTimePicker_withShowTime(self, show_time, show_seconds) } }
+        /// Calls the `DateTimePicker::set_on_date_time_change` function.
+        pub fn set_on_date_time_change<_1: Into<RefAny>>(&mut self, data: _1, callback: DateTimePickerOnDateTimeChangeCallbackType)  { unsafe { crate::dll::AzDateTimePicker_setOnDateTimeChange(self, data.into(), callback) } }
+        /// Calls the `DateTimePicker::with_on_date_time_change` function.
+        pub fn with_on_date_time_change<_1: Into<RefAny>>(&mut self, data: _1, callback: DateTimePickerOnDateTimeChangeCallbackType)  -> crate::widgets::DateTimePicker { unsafe { crate::dll::AzDateTimePicker_withOnDateTimeChange(self, data.into(), callback) } }
+        /// Calls the `DateTimePicker::dom` function.
+        pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzDateTimePicker_dom(self) } }
+    }
+
+    /// Icon shown left of the message of a `Dialog`
+    
+    #[doc(inline)] pub use crate::dll::AzDialogIcon as DialogIcon;
+    /// Set of buttons shown at the bottom of a `Dialog`
+    
+    #[doc(inline)] pub use crate::dll::AzDialogButtons as DialogButtons;
+    /// Button that closed a `Dialog`
+    
+    #[doc(inline)] pub use crate::dll::AzDialogButton as DialogButton;
+    /// Result of a `Dialog`, reported by `on_close`
+    
+    #[doc(inline)] pub use crate::dll::AzDialogResult as DialogResult;
+    impl DialogResult {
+
+        /// Returns whether the dialog was closed with "Ok" or "Yes"
+        pub fn is_accepted(&self)  -> bool { unsafe { crate::dll::AzDialogResult_isAccepted(self) } }
+    }
+
+    /// `DialogOnCloseCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDialogOnCloseCallbackType as DialogOnCloseCallbackType;
+    /// `DialogOnCloseCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDialogOnCloseCallback as DialogOnCloseCallback;
+    /// `DialogOnClose` struct
+    
+    #[doc(inline)] pub use crate::dll::AzDialogOnClose as DialogOnClose;
+    /// Modal dialog rendered inside of the window. Dialogs are stateless: the application stores the `Dialog` while it is open and removes it again in `on_close`. Use `dom_over()` to render the dialog on top of the rest of the UI: the content below the dialog is made inert, so the keyboard focus can't leave the dialog and clicks don't reach the content anymore
+    
+    #[doc(inline)] pub use crate::dll::AzDialog as Dialog;
+    impl Dialog {
+
+        /// Creates a message box with an "OK" button
+        pub fn msg_box<_1: Into<String>, _2: Into<String>>(title: _1, message: _2) -> Self { unsafe { crate::dll::AzDialog_msgBox(title.into(), message.into()) } }
+        /// Creates a question with "OK" / "Cancel" buttons
+        pub fn confirm<_1: Into<String>, _2: Into<String>>(title: _1, message: _2) -> Self { unsafe { crate::dll::AzDialog_confirm(title.into(), message.into()) } }
+        /// Creates a dialog asking for a line of text, pre-filled with `default_value`
+        pub fn prompt<_1: Into<String>, _2: Into<String>, _3: Into<String>>(title: _1, message: _2, default_value: _3) -> Self { unsafe { crate::dll::AzDialog_prompt(title.into(), message.into(), default_value.into()) } }
+        /// Creates a dialog displaying a custom DOM with "OK" / "Cancel" buttons
+        pub fn custom<_1: Into<String>, _2: Into<Dom>>(title: _1, content: _2) -> Self { unsafe { crate::dll::AzDialog_custom(title.into(), content.into()) } }
+        /// Calls the `Dialog::set_icon` function.
+        pub fn set_icon<_1: Into<DialogIcon>>(&mut self, icon: _1)  { unsafe { crate::dll::AzDialog_setIcon(self, icon.into()) } }
+        /// Calls the `Dialog::with_icon` function.
+        pub fn with_icon<_1: Into<DialogIcon>>(&mut self, icon: _1)  -> crate::widgets::Dialog { unsafe { crate::dll::AzDialog_withIcon(self, icon.into()) } }
+        /// Calls the `Dialog::set_buttons` function.
+        pub fn set_buttons<_1: Into<DialogButtons>>(&mut self, buttons: _1)  { unsafe { crate::dll::AzDialog_setButtons(self, buttons.into()) } }
+        /// Calls the `Dialog::with_buttons` function.
+        pub fn with_buttons<_1: Into<DialogButtons>>(&mut self, buttons: _1)  -> crate::widgets::Dialog { unsafe { crate::dll::AzDialog_withButtons(self, buttons.into()) } }
+        /// Calls the `Dialog::set_on_close` function.
+        pub fn set_on_close<_1: Into<RefAny>>(&mut self, data: _1, callback: DialogOnCloseCallbackType)  { unsafe { crate::dll::AzDialog_setOnClose(self, data.into(), callback) } }
+        /// Calls the `Dialog::with_on_close` function.
+        pub fn with_on_close<_1: Into<RefAny>>(&mut self, data: _1, callback: DialogOnCloseCallbackType)  -> crate::widgets::Dialog { unsafe { crate::dll::AzDialog_withOnClose(self, data.into(), callback) } }
+        /// Renders the backdrop with the dialog centered in it. The backdrop is positioned absolutely, so it has to be the last child of a container spanning the window - prefer `dom_over()`, which also blocks the input to the rest of the UI
+        pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzDialog_dom(self) } }
+        /// Renders the dialog on top of the `content`, which is made inert (see `Dom::make_inert`) while the dialog is open
+        pub fn dom_over<_1: Into<Dom>>(&mut self, content: _1)  -> crate::dom::Dom { unsafe { crate::dll::AzDialog_domOver(self, content.into()) } }
+    }
+
+    /// Direction in which the panes of a `Splitter` are laid out
+    
+    #[doc(inline)] pub use crate::dll::AzSplitterDirection as SplitterDirection;
+    /// Size of a single pane, as reported by `on_resize`
+    
+    #[doc(inline)] pub use crate::dll::AzSplitterPaneState as SplitterPaneState;
+    /// Sizes of all panes of a `Splitter`
+    
+    #[doc(inline)] pub use crate::dll::AzSplitterState as SplitterState;
+    /// Pane of a `Splitter`
+    
+    #[doc(inline)] pub use crate::dll::AzSplitterPane as SplitterPane;
+    impl SplitterPane {
+
+        /// Creates a flexible pane, which takes up the remaining space
+        pub fn new<_1: Into<Dom>>(content: _1) -> Self { unsafe { crate::dll::AzSplitterPane_new(content.into()) } }
+        /// Gives the pane a fixed size (in pixels) instead of taking up the remaining space
+        pub fn set_size(&mut self, size: f32)  { unsafe { crate::dll::AzSplitterPane_setSize(self, size) } }
+        /// Gives the pane a fixed size (in pixels) instead of taking up the remaining space
+        pub fn with_size(&mut self, size: f32)  -> crate::widgets::SplitterPane { unsafe { crate::dll::AzSplitterPane_withSize(self, size) } }
+        /// Calls the `SplitterPane::set_min_size` function.
+        pub fn set_min_size(&mut self, min_size: f32)  { unsafe { crate::dll::AzSplitterPane_setMinSize(self, min_size) } }
+        /// Calls the `SplitterPane::with_min_size` function.
+        pub fn with_min_size(&mut self, min_size: f32)  -> crate::widgets::SplitterPane { unsafe { crate::dll::AzSplitterPane_withMinSize(self, min_size) } }
+        /// Calls the `SplitterPane::set_max_size` function.
+        pub fn set_max_size(&mut self, max_size: f32)  { unsafe { crate::dll::AzSplitterPane_setMaxSize(self, max_size) } }
+        /// Calls the `SplitterPane::with_max_size` function.
+        pub fn with_max_size(&mut self, max_size: f32)  -> crate::widgets::SplitterPane { unsafe { crate::dll::AzSplitterPane_withMaxSize(self, max_size) } }
+        /// Calls the `SplitterPane::set_collapsed` function.
+        pub fn set_collapsed(&mut self, collapsed: bool)  { unsafe { crate::dll::AzSplitterPane_setCollapsed(self, collapsed) } }
+        /// Calls the `SplitterPane::with_collapsed` function.
+        pub fn with_collapsed(&mut self, collapsed: bool)  -> crate::widgets::SplitterPane { unsafe { crate::dll::AzSplitterPane_withCollapsed(self, collapsed) } }
+    }
+
+    /// `SplitterOnResizeCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzSplitterOnResizeCallbackType as SplitterOnResizeCallbackType;
+    /// `SplitterOnResizeCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzSplitterOnResizeCallback as SplitterOnResizeCallback;
+    /// `SplitterOnResize` struct
+    
+    #[doc(inline)] pub use crate::dll::AzSplitterOnResize as SplitterOnResize;
+    /// Container which splits its area into resizable panes. Panes with a fixed size keep their size when the splitter is resized, the remaining space is distributed between the flexible panes. Dragging a handle resizes the panes on both sides of it within their `min_size` / `max_size`; if both panes are flexible, the first one is given a fixed size. Double-clicking a handle (or pressing Enter while it is focused) collapses the fixed pane next to it
+    
+    #[doc(inline)] pub use crate::dll::AzSplitter as Splitter;
+    impl Splitter {
+
+        /// Creates a new `Splitter` instance.
+        pub fn new<_1: Into<SplitterDirection>>(direction: _1) -> Self { unsafe { crate::dll::AzSplitter_new(direction.into()) } }
+        /// Calls the `Splitter::with_pane` function.
+        pub fn with_pane<_1: Into<SplitterPane>>(&mut self, pane: _1)  -> crate::widgets::Splitter { unsafe { crate::dll::AzSplitter_withPane(self, pane.into()) } }
+        /// Calls the `Splitter::add_pane` function.
+        pub fn add_pane<_1: Into<SplitterPane>>(&mut self, pane: _1)  { unsafe { crate::dll::AzSplitter_addPane(self, pane.into()) } }
+        /// Calls the `Splitter::set_handle_size` function.
+        pub fn set_handle_size(&mut self, handle_size: f32)  { unsafe { crate::dll::AzSplitter_setHandleSize(self, handle_size) } }
+        /// Calls the `Splitter::with_handle_size` function.
+        pub fn with_handle_size(&mut self, handle_size: f32)  -> crate::widgets::Splitter { unsafe { crate::dll::AzSplitter_withHandleSize(self, handle_size) } }
+        /// Returns the current sizes of all panes
+        pub fn get_state(&self)  -> crate::widgets::SplitterState { unsafe { crate::dll::AzSplitter_getState(self) } }
+        /// Restores the pane sizes previously reported by `on_resize`
+        pub fn set_state<_1: Into<SplitterState>>(&mut self, state: _1)  { unsafe { crate::dll::AzSplitter_setState(self, state.into()) } }
+        /// Restores the pane sizes previously reported by `on_resize`
+        pub fn with_state<_1: Into<SplitterState>>(&mut self, state: _1)  -> crate::widgets::Splitter { unsafe { crate::dll::AzSplitter_withState(self, state.into()) } }
+        /// Calls the `Splitter::set_on_resize` function.
+        pub fn set_on_resize<_1: Into<RefAny>>(&mut self, data: _1, callback: SplitterOnResizeCallbackType)  { unsafe { crate::dll::AzSplitter_setOnResize(self, data.into(), callback) } }
+        /// Calls the `Splitter::with_on_resize` function.
+        pub fn with_on_resize<_1: Into<RefAny>>(&mut self, data: _1, callback: SplitterOnResizeCallbackType)  -> crate::widgets::Splitter { unsafe { crate::dll::AzSplitter_withOnResize(self, data.into(), callback) } }
+        /// Calls the `Splitter::dom` function.
+        pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzSplitter_dom(self) } }
+    }
+
     /// `DropDown` struct
     
     #[doc(inline)] pub use crate::dll::AzDropDown as DropDown;
@@ -17973,6 +21005,10 @@ pub mod font {
         pub fn get_font_metrics(&self)  -> crate::font::FontMetrics { unsafe { crate::dll::AzFontRef_getFontMetrics(self) } }
         /// Returns the text layout of the shaped text
         pub fn shape_text<_1: Into<Refstr>, _2: Into<ResolvedTextLayoutOptions>>(&self, text: _1, options: _2)  -> crate::callbacks::InlineText { unsafe { crate::dll::AzFontRef_shapeText(self, text.into(), options.into()) } }
+        /// Measures the size of the text (width, height and line metrics) without laying out a DOM
+        pub fn measure_text<_1: Into<Refstr>, _2: Into<ResolvedTextLayoutOptions>>(&self, text: _1, options: _2)  -> crate::callbacks::TextMetrics { unsafe { crate::dll::AzFontRef_measureText(self, text.into(), options.into()) } }
+        /// Shapes the text and returns the outlines of the positioned glyphs (relative to the top left corner of the text), so that the text can be tessellated, stroked or used in boolean operations
+        pub fn text_to_path<_1: Into<Refstr>, _2: Into<ResolvedTextLayoutOptions>>(&self, text: _1, options: _2)  -> crate::svg::SvgMultiPolygon { unsafe { crate::dll::AzFontRef_textToPath(self, text.into(), options.into()) } }
         /// Returns the hash of the FontRef (fast)
         pub fn get_hash(&self)  -> u64 { unsafe { crate::dll::AzFontRef_getHash(self) } }
     }
@@ -18413,6 +21449,9 @@ pub mod xml {
     /// `XmlNode` struct
     
     #[doc(inline)] pub use crate::dll::AzXmlNode as XmlNode;
+    /// Location of the start tag of an `XmlNode` in the XML source
+    
+    #[doc(inline)] pub use crate::dll::AzXmlSpan as XmlSpan;
 }
 
 pub mod fs {
@@ -19206,6 +22245,24 @@ pub mod vec {
     impl_vec_clone!(AzStyleFilter, AzStyleFilterVec, AzStyleFilterVecDestructor);
     impl_vec!(AzListViewRow, AzListViewRowVec, AzListViewRowVecDestructor, az_list_view_vec_destructor, AzListViewRowVec_delete);
     impl_vec_clone!(AzListViewRow, AzListViewRowVec, AzListViewRowVecDestructor);
+    impl_vec!(AzSplitterPane, AzSplitterPaneVec, AzSplitterPaneVecDestructor, az_splitter_pane_vec_destructor, AzSplitterPaneVec_delete);
+    impl_vec_clone!(AzSplitterPane, AzSplitterPaneVec, AzSplitterPaneVecDestructor);
+    impl_vec!(AzSplitterPaneState, AzSplitterPaneStateVec, AzSplitterPaneStateVecDestructor, az_splitter_pane_state_vec_destructor, AzSplitterPaneStateVec_delete);
+    impl_vec_clone!(AzSplitterPaneState, AzSplitterPaneStateVec, AzSplitterPaneStateVecDestructor);
+    impl_vec!(AzKeyboardShortcut, AzKeyboardShortcutVec, AzKeyboardShortcutVecDestructor, az_keyboard_shortcut_vec_destructor, AzKeyboardShortcutVec_delete);
+    impl_vec_clone!(AzKeyboardShortcut, AzKeyboardShortcutVec, AzKeyboardShortcutVecDestructor);
+    impl_vec!(AzNodeGraphNodeId, AzNodeGraphNodeIdVec, AzNodeGraphNodeIdVecDestructor, az_node_graph_node_id_vec_destructor, AzNodeGraphNodeIdVec_delete);
+    impl_vec_clone!(AzNodeGraphNodeId, AzNodeGraphNodeIdVec, AzNodeGraphNodeIdVecDestructor);
+    impl_vec!(AzNodeGraphEditOp, AzNodeGraphEditOpVec, AzNodeGraphEditOpVecDestructor, az_node_graph_edit_op_vec_destructor, AzNodeGraphEditOpVec_delete);
+    impl_vec_clone!(AzNodeGraphEditOp, AzNodeGraphEditOpVec, AzNodeGraphEditOpVecDestructor);
+    impl_vec!(AzNodeGraphEdit, AzNodeGraphEditVec, AzNodeGraphEditVecDestructor, az_node_graph_edit_vec_destructor, AzNodeGraphEditVec_delete);
+    impl_vec_clone!(AzNodeGraphEdit, AzNodeGraphEditVec, AzNodeGraphEditVecDestructor);
+    impl_vec!(AzTreeViewItem, AzTreeViewItemVec, AzTreeViewItemVecDestructor, az_tree_view_item_vec_destructor, AzTreeViewItemVec_delete);
+    impl_vec_clone!(AzTreeViewItem, AzTreeViewItemVec, AzTreeViewItemVecDestructor);
+    impl_vec!(AzTextLineMetrics, AzTextLineMetricsVec, AzTextLineMetricsVecDestructor, az_text_line_metrics_vec_destructor, AzTextLineMetricsVec_delete);
+    impl_vec_clone!(AzTextLineMetrics, AzTextLineMetricsVec, AzTextLineMetricsVecDestructor);
+    impl_vec!(AzInlineTextRun, AzInlineTextRunVec, AzInlineTextRunVecDestructor, az_inline_text_run_vec_destructor, AzInlineTextRunVec_delete);
+    impl_vec_clone!(AzInlineTextRun, AzInlineTextRunVec, AzInlineTextRunVecDestructor);
     impl_vec!(AzAccessibilityState,  AzAccessibilityStateVec,  AzAccessibilityStateVecDestructor, az_accessibility_state_vec_destructor, AzAccessibilityStateVec_delete);
     impl_vec_clone!(AzAccessibilityState,  AzAccessibilityStateVec,  AzAccessibilityStateVecDestructor);
     impl_vec!(AzMenuItem,  AzMenuItemVec,  AzMenuItemVecDestructor, az_menu_item_vec_destructor, AzMenuItemVec_delete);
@@ -19245,6 +22302,15 @@ pub mod vec {
     }    /// Wrapper over a Rust-allocated `Vec<ListViewRow>`
     
     #[doc(inline)] pub use crate::dll::AzListViewRowVec as ListViewRowVec;
+    /// Wrapper over a Rust-allocated `Vec<TreeViewItem>`
+    
+    #[doc(inline)] pub use crate::dll::AzTreeViewItemVec as TreeViewItemVec;
+    /// Wrapper over a Rust-allocated `Vec<SplitterPane>`
+    
+    #[doc(inline)] pub use crate::dll::AzSplitterPaneVec as SplitterPaneVec;
+    /// Wrapper over a Rust-allocated `Vec<SplitterPaneState>`
+    
+    #[doc(inline)] pub use crate::dll::AzSplitterPaneStateVec as SplitterPaneStateVec;
     /// Wrapper over a Rust-allocated `Vec<StyleFilter>`
     
     #[doc(inline)] pub use crate::dll::AzStyleFilterVec as StyleFilterVec;
@@ -19257,6 +22323,15 @@ pub mod vec {
     /// Wrapper over a Rust-allocated `Vec<InputOutputTypeIdInfoMap>`
     
     #[doc(inline)] pub use crate::dll::AzInputOutputTypeIdInfoMapVec as InputOutputTypeIdInfoMapVec;
+    /// Wrapper over a Rust-allocated `Vec<NodeGraphNodeId>`
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphNodeIdVec as NodeGraphNodeIdVec;
+    /// Wrapper over a Rust-allocated `Vec<NodeGraphEditOp>`
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphEditOpVec as NodeGraphEditOpVec;
+    /// Wrapper over a Rust-allocated `Vec<NodeGraphEdit>`
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphEditVec as NodeGraphEditVec;
     /// Wrapper over a Rust-allocated `Vec<NodeIdNodeMap>`
     
     #[doc(inline)] pub use crate::dll::AzNodeIdNodeMapVec as NodeIdNodeMapVec;
@@ -19281,6 +22356,9 @@ pub mod vec {
     /// Wrapper over a Rust-allocated `Vec<AccessibilityState>`
     
     #[doc(inline)] pub use crate::dll::AzAccessibilityStateVec as AccessibilityStateVec;
+    /// Wrapper over a Rust-allocated `Vec<KeyboardShortcut>`
+    
+    #[doc(inline)] pub use crate::dll::AzKeyboardShortcutVec as KeyboardShortcutVec;
     /// Wrapper over a Rust-allocated `Vec<MenuItem>`
     
     #[doc(inline)] pub use crate::dll::AzMenuItemVec as MenuItemVec;
@@ -19320,6 +22398,12 @@ pub mod vec {
     /// Wrapper over a Rust-allocated `Vec<InlineGlyph>`
     
     #[doc(inline)] pub use crate::dll::AzInlineGlyphVec as InlineGlyphVec;
+    /// Wrapper over a Rust-allocated `Vec<TextLineMetrics>`
+    
+    #[doc(inline)] pub use crate::dll::AzTextLineMetricsVec as TextLineMetricsVec;
+    /// Wrapper over a Rust-allocated `Vec<InlineTextRun>`
+    
+    #[doc(inline)] pub use crate::dll::AzInlineTextRunVec as InlineTextRunVec;
     /// Wrapper over a Rust-allocated `Vec<InlineTextHit>`
     
     #[doc(inline)] pub use crate::dll::AzInlineTextHitVec as InlineTextHitVec;
@@ -19401,6 +22485,15 @@ pub mod vec {
     /// Wrapper over a Rust-allocated `Stylesheet`
     
     #[doc(inline)] pub use crate::dll::AzStylesheetVec as StylesheetVec;
+    /// Wrapper over a Rust-allocated `Vec<CssFontFace>`
+    
+    #[doc(inline)] pub use crate::dll::AzCssFontFaceVec as CssFontFaceVec;
+    /// Wrapper over a Rust-allocated `Vec<CssFontFaceSource>`
+    
+    #[doc(inline)] pub use crate::dll::AzCssFontFaceSourceVec as CssFontFaceSourceVec;
+    /// Wrapper over a Rust-allocated `Vec<CssUnicodeRange>`
+    
+    #[doc(inline)] pub use crate::dll::AzCssUnicodeRangeVec as CssUnicodeRangeVec;
     /// Wrapper over a Rust-allocated `CssRuleBlock`
     
     #[doc(inline)] pub use crate::dll::AzCssRuleBlockVec as CssRuleBlockVec;
@@ -19475,6 +22568,24 @@ pub mod vec {
     /// `ListViewRowVecDestructorType` struct
     
     #[doc(inline)] pub use crate::dll::AzListViewRowVecDestructorType as ListViewRowVecDestructorType;
+    /// `TreeViewItemVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTreeViewItemVecDestructor as TreeViewItemVecDestructor;
+    /// `TreeViewItemVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTreeViewItemVecDestructorType as TreeViewItemVecDestructorType;
+    /// `SplitterPaneVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzSplitterPaneVecDestructor as SplitterPaneVecDestructor;
+    /// `SplitterPaneVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzSplitterPaneVecDestructorType as SplitterPaneVecDestructorType;
+    /// `SplitterPaneStateVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzSplitterPaneStateVecDestructor as SplitterPaneStateVecDestructor;
+    /// `SplitterPaneStateVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzSplitterPaneStateVecDestructorType as SplitterPaneStateVecDestructorType;
     /// `StyleFilterVecDestructor` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleFilterVecDestructor as StyleFilterVecDestructor;
@@ -19499,6 +22610,24 @@ pub mod vec {
     /// `InputOutputTypeIdInfoMapVecDestructorType` struct
     
     #[doc(inline)] pub use crate::dll::AzInputOutputTypeIdInfoMapVecDestructorType as InputOutputTypeIdInfoMapVecDestructorType;
+    /// `NodeGraphNodeIdVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphNodeIdVecDestructor as NodeGraphNodeIdVecDestructor;
+    /// `NodeGraphNodeIdVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphNodeIdVecDestructorType as NodeGraphNodeIdVecDestructorType;
+    /// `NodeGraphEditOpVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphEditOpVecDestructor as NodeGraphEditOpVecDestructor;
+    /// `NodeGraphEditOpVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphEditOpVecDestructorType as NodeGraphEditOpVecDestructorType;
+    /// `NodeGraphEditVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphEditVecDestructor as NodeGraphEditVecDestructor;
+    /// `NodeGraphEditVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzNodeGraphEditVecDestructorType as NodeGraphEditVecDestructorType;
     /// `NodeIdNodeMapVecDestructor` struct
     
     #[doc(inline)] pub use crate::dll::AzNodeIdNodeMapVecDestructor as NodeIdNodeMapVecDestructor;
@@ -19547,6 +22676,12 @@ pub mod vec {
     /// `AccessibilityStateVecDestructorType` struct
     
     #[doc(inline)] pub use crate::dll::AzAccessibilityStateVecDestructorType as AccessibilityStateVecDestructorType;
+    /// `KeyboardShortcutVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzKeyboardShortcutVecDestructor as KeyboardShortcutVecDestructor;
+    /// `KeyboardShortcutVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzKeyboardShortcutVecDestructorType as KeyboardShortcutVecDestructorType;
     /// `MenuItemVecDestructor` struct
     
     #[doc(inline)] pub use crate::dll::AzMenuItemVecDestructor as MenuItemVecDestructor;
@@ -19595,6 +22730,18 @@ pub mod vec {
     /// `InlineGlyphVecDestructorType` struct
     
     #[doc(inline)] pub use crate::dll::AzInlineGlyphVecDestructorType as InlineGlyphVecDestructorType;
+    /// `TextLineMetricsVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTextLineMetricsVecDestructor as TextLineMetricsVecDestructor;
+    /// `TextLineMetricsVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTextLineMetricsVecDestructorType as TextLineMetricsVecDestructorType;
+    /// `InlineTextRunVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzInlineTextRunVecDestructor as InlineTextRunVecDestructor;
+    /// `InlineTextRunVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzInlineTextRunVecDestructorType as InlineTextRunVecDestructorType;
     /// `InlineTextHitVecDestructor` struct
     
     #[doc(inline)] pub use crate::dll::AzInlineTextHitVecDestructor as InlineTextHitVecDestructor;
@@ -19757,6 +22904,24 @@ pub mod vec {
     /// `StylesheetVecDestructorType` struct
     
     #[doc(inline)] pub use crate::dll::AzStylesheetVecDestructorType as StylesheetVecDestructorType;
+    /// `CssFontFaceVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzCssFontFaceVecDestructor as CssFontFaceVecDestructor;
+    /// `CssFontFaceVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzCssFontFaceVecDestructorType as CssFontFaceVecDestructorType;
+    /// `CssFontFaceSourceVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzCssFontFaceSourceVecDestructor as CssFontFaceSourceVecDestructor;
+    /// `CssFontFaceSourceVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzCssFontFaceSourceVecDestructorType as CssFontFaceSourceVecDestructorType;
+    /// `CssUnicodeRangeVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzCssUnicodeRangeVecDestructor as CssUnicodeRangeVecDestructor;
+    /// `CssUnicodeRangeVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzCssUnicodeRangeVecDestructorType as CssUnicodeRangeVecDestructorType;
     /// `CssRuleBlockVecDestructor` struct
     
     #[doc(inline)] pub use crate::dll::AzCssRuleBlockVecDestructor as CssRuleBlockVecDestructor;
@@ -20047,6 +23212,9 @@ pub mod option {
     impl_option!(AzSystemClipboard, AzOptionSystemClipboard, copy = false,  clone = false, [Debug]);
     impl_option!(AzFileTypeList, AzOptionFileTypeList, copy = false, [Debug, Clone]);
     impl_option!(AzWindowState, AzOptionWindowState, copy = false, [Debug, Clone]);
+    impl_option!(AzDataGridRange, AzOptionDataGridRange, [Debug, Copy, Clone]);
+    impl_option!(AzDate, AzOptionDate, [Debug, Copy, Clone]);
+    impl_option!(AzTime, AzOptionTime, [Debug, Copy, Clone]);
     impl_option!(AzKeyboardState, AzOptionKeyboardState, copy = false, [Debug, Clone]);
     impl_option!(AzMouseState, AzOptionMouseState, [Debug, Clone]);
     impl_option!(AzNodeGraphOnNodeAdded, AzOptionNodeGraphOnNodeAdded, [Debug, Copy, Clone]);
@@ -20057,15 +23225,90 @@ pub mod option {
     impl_option!(AzNodeGraphOnNodeInputDisconnected, AzOptionNodeGraphOnNodeInputDisconnected, [Debug, Copy, Clone]);
     impl_option!(AzNodeGraphOnNodeOutputDisconnected, AzOptionNodeGraphOnNodeOutputDisconnected, [Debug, Copy, Clone]);
     impl_option!(AzNodeGraphOnNodeFieldEdited, AzOptionNodeGraphOnNodeFieldEdited, [Debug, Copy, Clone]);
+    impl_option!(AzNodeGraphOnNodeGraphZoomed, AzOptionNodeGraphOnNodeGraphZoomed, [Debug, Copy, Clone]);
+    impl_option!(AzNodeGraphOnSelectionChanged, AzOptionNodeGraphOnSelectionChanged, [Debug, Copy, Clone]);
+    impl_option!(AzNodeGraphOnHistoryChanged, AzOptionNodeGraphOnHistoryChanged, [Debug, Copy, Clone]);
     impl_option!(AzGl, AzOptionGl, copy = false, [Debug, Clone]);
     impl_option!(AzPixelValueNoPercent, AzOptionPixelValueNoPercent, copy = false, [Debug, Copy, Clone]);
     impl_option!(AzSvgPoint, AzOptionSvgPoint, [Debug, Copy, Clone]);
     /// `OptionSvgPoint` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionSvgPoint as OptionSvgPoint;
+    /// `OptionTreeViewOnExpand` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionTreeViewOnExpand as OptionTreeViewOnExpand;
+    /// `OptionTreeViewOnSelect` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionTreeViewOnSelect as OptionTreeViewOnSelect;
+    /// `OptionTreeViewOnLazyLoad` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionTreeViewOnLazyLoad as OptionTreeViewOnLazyLoad;
+    /// `OptionTreeViewOnScroll` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionTreeViewOnScroll as OptionTreeViewOnScroll;
     /// `OptionListViewOnRowClick` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionListViewOnRowClick as OptionListViewOnRowClick;
+    /// `OptionListViewOnRowSelect` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionListViewOnRowSelect as OptionListViewOnRowSelect;
+    /// `OptionListViewOnColumnResize` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionListViewOnColumnResize as OptionListViewOnColumnResize;
+    /// `OptionListViewRowProvider` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionListViewRowProvider as OptionListViewRowProvider;
+    /// `OptionDataGridRange` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionDataGridRange as OptionDataGridRange;
+    /// `OptionDataGridCellProvider` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionDataGridCellProvider as OptionDataGridCellProvider;
+    /// `OptionDataGridCellStyle` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionDataGridCellStyle as OptionDataGridCellStyle;
+    /// `OptionDataGridOnSelect` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionDataGridOnSelect as OptionDataGridOnSelect;
+    /// `OptionDataGridOnScroll` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionDataGridOnScroll as OptionDataGridOnScroll;
+    /// `OptionDataGridOnCellEdit` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionDataGridOnCellEdit as OptionDataGridOnCellEdit;
+    /// `OptionDataGridOnColumnResize` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionDataGridOnColumnResize as OptionDataGridOnColumnResize;
+    /// `OptionDataGridOnRowResize` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionDataGridOnRowResize as OptionDataGridOnRowResize;
+    /// `OptionDataGridOnCopyRange` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionDataGridOnCopyRange as OptionDataGridOnCopyRange;
+    /// `OptionDataGridOnClearRange` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionDataGridOnClearRange as OptionDataGridOnClearRange;
+    /// `OptionDate` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionDate as OptionDate;
+    /// `OptionTime` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionTime as OptionTime;
+    /// `OptionDatePickerOnDateChange` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionDatePickerOnDateChange as OptionDatePickerOnDateChange;
+    /// `OptionTimePickerOnTimeChange` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionTimePickerOnTimeChange as OptionTimePickerOnTimeChange;
+    /// `OptionDateTimePickerOnDateTimeChange` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionDateTimePickerOnDateTimeChange as OptionDateTimePickerOnDateTimeChange;
+    /// `OptionDialogOnClose` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionDialogOnClose as OptionDialogOnClose;
+    /// `OptionSplitterOnResize` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionSplitterOnResize as OptionSplitterOnResize;
     /// `OptionListViewOnColumnClick` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionListViewOnColumnClick as OptionListViewOnColumnClick;
@@ -20084,6 +23327,15 @@ pub mod option {
     /// `OptionResolvedTextLayoutOptions` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionResolvedTextLayoutOptions as OptionResolvedTextLayoutOptions;
+    /// `OptionNodeGraphOnNodeGraphZoomed` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionNodeGraphOnNodeGraphZoomed as OptionNodeGraphOnNodeGraphZoomed;
+    /// `OptionNodeGraphOnSelectionChanged` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionNodeGraphOnSelectionChanged as OptionNodeGraphOnSelectionChanged;
+    /// `OptionNodeGraphOnHistoryChanged` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionNodeGraphOnHistoryChanged as OptionNodeGraphOnHistoryChanged;
     /// `OptionNodeGraphOnNodeAdded` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionNodeGraphOnNodeAdded as OptionNodeGraphOnNodeAdded;
@@ -20228,6 +23480,9 @@ pub mod option {
     /// `OptionInlineText` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionInlineText as OptionInlineText;
+    /// `OptionTextMetrics` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionTextMetrics as OptionTextMetrics;
     /// `OptionLayoutPoint` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionLayoutPoint as OptionLayoutPoint;
@@ -20343,6 +23598,9 @@ pub mod error {
     /// `ResultSvgXmlNodeSvgParseError` struct
     
     #[doc(inline)] pub use crate::dll::AzResultSvgXmlNodeSvgParseError as ResultSvgXmlNodeSvgParseError;
+    /// `ResultNodeGraphNodeGraphError` struct
+    
+    #[doc(inline)] pub use crate::dll::AzResultNodeGraphNodeGraphError as ResultNodeGraphNodeGraphError;
     /// `ResultSvgSvgParseError` struct
     
     #[doc(inline)] pub use crate::dll::AzResultSvgSvgParseError as ResultSvgSvgParseError;
@@ -22,7 +22,7 @@ use alloc::string::String;
 use alloc::vec::Vec;
 pub use azul_css::FontMetrics;
 use azul_css::{
-    AzString, ColorU, F32Vec, FontRef, LayoutRect, LayoutSize, OptionI32, StyleFontFamily,
+    AzString, ColorU, F32Vec, FontRef, LayoutRect, LayoutSize, OptionColorU, OptionI32, StyleFontFamily,
     StyleFontFamilyVec, StyleFontSize, U16Vec, U32Vec, U8Vec, FloatValue,
 };
 use core::{
@@ -553,12 +553,18 @@ pub struct ImageCache {
     /// all other maps are library-internal only and automatically delete their resources once they
     /// aren't needed anymore
     pub image_id_map: FastHashMap<AzString, ImageRef>,
+    /// Fonts registered by the user under a font family name, i.e. `font-family: "MyFont"`
+    /// will resolve to the registered font before trying to load a system font
+    ///
+    /// Family names are case-insensitive like in CSS, the keys are stored in lowercase
+    pub font_family_map: FastHashMap<AzString, FontRef>,
 }
 
 impl Default for ImageCache {
     fn default() -> Self {
         Self {
             image_id_map: FastHashMap::default(),
+            font_family_map: FastHashMap::default(),
         }
     }
 }
@@ -581,6 +587,24 @@ impl ImageCache {
     pub fn delete_css_image_id(&mut self, css_id: &AzString) {
         self.image_id_map.remove(css_id);
     }
+
+    // -- font family cache
+
+    pub fn add_css_font_family(&mut self, family: AzString, font: FontRef) {
+        self.font_family_map.insert(Self::font_family_key(&family), font);
+    }
+
+    pub fn get_css_font_family(&self, family: &AzString) -> Option<&FontRef> {
+        self.font_family_map.get(&Self::font_family_key(family))
+    }
+
+    pub fn delete_css_font_family(&mut self, family: &AzString) {
+        self.font_family_map.remove(&Self::font_family_key(family));
+    }
+
+    fn font_family_key(family: &AzString) -> AzString {
+        family.as_str().to_ascii_lowercase().into()
+    }
 }
 
 /// What type of image is this?
@@ -720,6 +744,15 @@ impl RendererResources {
             }
         }
 
+        // images of color bitmap glyphs are only known after the text is shaped
+        for layout_result in new_layout_results {
+            for shaped_words in layout_result.shaped_words_cache.values() {
+                for bitmap_glyph in shaped_words.bitmap_glyphs.iter() {
+                    next_frame_image_keys.insert(bitmap_glyph.image.get_hash());
+                }
+            }
+        }
+
         for ((_dom_id, _node_id, _callback_imageref_hash), image_ref_hash) in
             gl_texture_cache.hashes.iter()
         {
@@ -2040,9 +2073,33 @@ pub struct ShapedWords {
     pub font_metrics_ascender: i16,
     pub font_metrics_descender: i16,
     pub font_metrics_line_gap: i16,
+    /// Layered color glyphs (`COLR` / `CPAL` tables) used in the words,
+    /// sorted by glyph index. Empty if the font has no color layers.
+    pub color_glyphs: ColorGlyphVec,
+    /// Color bitmap glyphs (`CBDT` / `sbix` tables) used in the words,
+    /// sorted by glyph index. Empty if the font has no bitmap glyphs.
+    pub bitmap_glyphs: BitmapGlyphVec,
 }
 
 impl ShapedWords {
+    /// Returns the color layers of a glyph, if the glyph is a layered color glyph
+    pub fn get_color_glyph(&self, glyph_index: u16) -> Option<&ColorGlyph> {
+        let color_glyphs = self.color_glyphs.as_ref();
+        color_glyphs
+            .binary_search_by_key(&glyph_index, |g| g.glyph_index)
+            .ok()
+            .map(|i| &color_glyphs[i])
+    }
+
+    /// Returns the image of a glyph, if the glyph is a color bitmap glyph
+    pub fn get_bitmap_glyph(&self, glyph_index: u16) -> Option<&BitmapGlyph> {
+        let bitmap_glyphs = self.bitmap_glyphs.as_ref();
+        bitmap_glyphs
+            .binary_search_by_key(&glyph_index, |g| g.glyph_index)
+            .ok()
+            .map(|i| &bitmap_glyphs[i])
+    }
+
     pub fn get_longest_word_width_px(&self, target_font_size: f32) -> f32 {
         self.longest_word_width as f32 / self.font_metrics_units_per_em as f32 * target_font_size
     }
@@ -2206,7 +2263,9 @@ pub fn get_inline_text(
     word_positions: &WordPositions,
     inline_text_layout: &InlineTextLayout,
 ) -> InlineText {
-    use crate::callbacks::{InlineGlyph, InlineLine, InlineTextContents, InlineWord};
+    use crate::callbacks::{
+        InlineGlyph, InlineLine, InlineTextContents, InlineTextRun, InlineWord,
+    };
 
     // check the range so that in the worst case there isn't a random crash here
     fn get_range_checked_inclusive_end(
@@ -2361,13 +2420,25 @@ pub fn get_inline_text(
         })
         .collect::<Vec<InlineLine>>();
 
-    InlineText {
+    let mut inline_text = InlineText {
         lines: inline_lines.into(), // relative to 0, 0
         content_size: word_positions.content_size,
         font_size_px,
         last_word_index: word_positions.number_of_shaped_words,
         baseline_descender_px: *descender_px,
-    }
+        runs: Vec::new().into(),
+    };
+
+    // the node ID is not known here, set by the caller if necessary
+    inline_text.runs = vec![InlineTextRun {
+        node_id: NodeHierarchyItemId::NONE,
+        glyph_start: 0,
+        glyph_end: inline_text.get_glyph_count(),
+        font_size_px,
+    }]
+    .into();
+
+    inline_text
 }
 
 impl_vec!(GlyphInfo, GlyphInfoVec, GlyphInfoVecDestructor);
@@ -2451,6 +2522,56 @@ impl fmt::Debug for ShapedWord {
     }
 }
 
+/// Single layer of a layered color glyph
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct ColorGlyphLayer {
+    /// Glyph that is drawn for this layer
+    pub glyph_index: u16,
+    /// Color of the layer, `None` means that the current text color is used
+    pub color: OptionColorU,
+}
+
+impl_vec!(ColorGlyphLayer, ColorGlyphLayerVec, ColorGlyphLayerVecDestructor);
+impl_vec_clone!(ColorGlyphLayer, ColorGlyphLayerVec, ColorGlyphLayerVecDestructor);
+impl_vec_debug!(ColorGlyphLayer, ColorGlyphLayerVec);
+impl_vec_partialeq!(ColorGlyphLayer, ColorGlyphLayerVec);
+impl_vec_partialord!(ColorGlyphLayer, ColorGlyphLayerVec);
+
+/// Color glyph, composed of multiple glyphs drawn on top of each other (bottom layer first)
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct ColorGlyph {
+    pub glyph_index: u16,
+    pub layers: ColorGlyphLayerVec,
+}
+
+impl_vec!(ColorGlyph, ColorGlyphVec, ColorGlyphVecDestructor);
+impl_vec_clone!(ColorGlyph, ColorGlyphVec, ColorGlyphVecDestructor);
+impl_vec_debug!(ColorGlyph, ColorGlyphVec);
+impl_vec_partialeq!(ColorGlyph, ColorGlyphVec);
+impl_vec_partialord!(ColorGlyph, ColorGlyphVec);
+
+/// Color bitmap glyph (`CBDT` / `sbix` tables), drawn as an image instead of a glyph
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct BitmapGlyph {
+    pub glyph_index: u16,
+    /// Decoded bitmap of the largest strike of the glyph
+    pub image: ImageRef,
+    /// Bounds of the bitmap in font units, relative to the glyph origin (y-axis pointing up)
+    pub min_x: i16,
+    pub min_y: i16,
+    pub max_x: i16,
+    pub max_y: i16,
+}
+
+impl_vec!(BitmapGlyph, BitmapGlyphVec, BitmapGlyphVecDestructor);
+impl_vec_clone!(BitmapGlyph, BitmapGlyphVec, BitmapGlyphVecDestructor);
+impl_vec_debug!(BitmapGlyph, BitmapGlyphVec);
+impl_vec_partialeq!(BitmapGlyph, BitmapGlyphVec);
+impl_vec_partialord!(BitmapGlyph, BitmapGlyphVec);
+
 impl_vec!(ShapedWord, ShapedWordVec, ShapedWordVecDestructor);
 impl_vec_clone!(ShapedWord, ShapedWordVec, ShapedWordVecDestructor);
 impl_vec_partialeq!(ShapedWord, ShapedWordVec);
@@ -2541,6 +2662,7 @@ pub fn add_fonts_and_images(
     );
 
     let add_font_resource_updates = build_add_font_resource_updates(
+        image_cache,
         renderer_resources,
         current_window_dpi,
         fc_cache,
@@ -2558,6 +2680,45 @@ pub fn add_fonts_and_images(
     );
 }
 
+/// Registers the images of the color bitmap glyphs (`CBDT` / `sbix` tables) in the shaped
+/// text of a layouted DOM. Unlike other images, the glyphs are only known after the text
+/// has been shaped, so this has to be called after the layout.
+pub fn add_bitmap_glyph_images(
+    renderer_resources: &mut RendererResources,
+    render_api_namespace: IdNamespace,
+    epoch: Epoch,
+    document_id: &DocumentId,
+    all_resource_updates: &mut Vec<ResourceUpdate>,
+    shaped_words_cache: &BTreeMap<NodeId, ShapedWords>,
+    insert_into_active_gl_textures: GlStoreImageFn,
+) {
+    let bitmap_glyph_images = shaped_words_cache
+        .values()
+        .flat_map(|shaped_words| shaped_words.bitmap_glyphs.iter())
+        .map(|bitmap_glyph| bitmap_glyph.image.clone())
+        .collect::<FastBTreeSet<_>>();
+
+    if bitmap_glyph_images.is_empty() {
+        return;
+    }
+
+    let add_image_resource_updates = build_add_image_resource_updates(
+        renderer_resources,
+        render_api_namespace,
+        epoch,
+        document_id,
+        &bitmap_glyph_images,
+        insert_into_active_gl_textures,
+    );
+
+    add_resources(
+        renderer_resources,
+        all_resource_updates,
+        Vec::new(),
+        add_image_resource_updates,
+    );
+}
+
 pub fn font_size_to_au(font_size: StyleFontSize) -> Au {
     use crate::ui_solver::DEFAULT_FONT_SIZE_PX;
     Au::from_px(font_size.inner.to_pixels(DEFAULT_FONT_SIZE_PX as f32))
@@ -2951,6 +3112,42 @@ pub struct LoadedFontSource {
     pub load_outlines: bool,
 }
 
+/// Returns whether the font contains color bitmap glyphs (`CBDT` or `sbix` table),
+/// which are only rendered if the font instance is created with embedded bitmaps enabled
+pub fn font_has_bitmap_glyphs(font_bytes: &[u8], font_index: u32) -> bool {
+
+    fn read_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+        let b = bytes.get(offset..offset + 2)?;
+        Some(u16::from_be_bytes([b[0], b[1]]))
+    }
+
+    fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+        let b = bytes.get(offset..offset + 4)?;
+        Some(u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    // font collections store the offsets to the table directories after the header
+    let table_directory = if font_bytes.get(0..4) == Some(b"ttcf") {
+        match read_u32(font_bytes, 12 + font_index as usize * 4) {
+            Some(s) => s as usize,
+            None => return false,
+        }
+    } else {
+        0
+    };
+
+    let num_tables = match read_u16(font_bytes, table_directory + 4) {
+        Some(s) => s as usize,
+        None => return false,
+    };
+
+    (0..num_tables).any(|table_index| {
+        let record_start = table_directory + 12 + table_index * 16;
+        let tag = font_bytes.get(record_start..record_start + 4);
+        tag == Some(b"CBDT") || tag == Some(b"sbix")
+    })
+}
+
 // function to load the font source from a file
 pub type LoadFontFn = fn(&StyleFontFamily, &FcFontCache) -> Option<LoadedFontSource>;
 
@@ -2966,6 +3163,7 @@ pub type ParseFontFn = fn(LoadedFontSource) -> Option<FontRef>; // = Option<Box<
 /// add-and-remove fonts after every IFrameCallback, which would cause a lot of
 /// I/O waiting.
 pub fn build_add_font_resource_updates(
+    image_cache: &ImageCache,
     renderer_resources: &mut RendererResources,
     dpi: DpiScaleFactor,
     fc_cache: &FcFontCache,
@@ -2976,8 +3174,21 @@ pub fn build_add_font_resource_updates(
 ) -> Vec<(StyleFontFamilyHash, AddFontMsg)> {
     let mut resource_updates = alloc::vec::Vec::new();
     let mut font_instances_added_this_frame = FastBTreeSet::new();
+    let mut bitmap_fonts_added_this_frame = FastHashMap::<FontKey, bool>::default();
 
     'outer: for (im_font_id, font_sizes) in fonts_in_dom {
+        macro_rules! font_key_has_bitmap_glyphs {
+            ($font_key:expr) => {{
+                match renderer_resources.currently_registered_fonts.get(&$font_key) {
+                    Some((font_ref, _)) => {
+                        let font_data = font_ref.get_data();
+                        font_has_bitmap_glyphs(font_data.bytes.as_ref(), font_data.font_index)
+                    }
+                    None => bitmap_fonts_added_this_frame.get(&$font_key).copied().unwrap_or(false),
+                }
+            }};
+        }
+
         macro_rules! insert_font_instances {
             ($font_family_hash:expr, $font_key:expr, $font_size:expr) => {{
                 let font_instance_key_exists = renderer_resources
@@ -3010,9 +3221,16 @@ pub fn build_add_font_resource_updates(
                     #[cfg(target_arch = "wasm32")]
                     let platform_options = FontInstancePlatformOptions::default();
 
+                    // color emoji fonts (CBDT / sbix) store their glyphs as bitmaps
+                    let embedded_bitmaps = if font_key_has_bitmap_glyphs!($font_key) {
+                        FONT_INSTANCE_FLAG_EMBEDDED_BITMAPS
+                    } else {
+                        0
+                    };
+
                     let options = FontInstanceOptions {
                         render_mode: FontRenderMode::Subpixel,
-                        flags: 0 | FONT_INSTANCE_FLAG_NO_AUTOHINT,
+                        flags: 0 | FONT_INSTANCE_FLAG_NO_AUTOHINT | embedded_bitmaps,
                         ..Default::default()
                     };
 
@@ -3072,18 +3290,28 @@ pub fn build_add_font_resource_updates(
                     let font_ref = match family {
                         StyleFontFamily::Ref(r) => r.clone(), // Clone the FontRef
                         other => {
-                            // Load and parse the font
-                            let font_data = match (font_source_load_fn)(&other, fc_cache) {
-                                Some(s) => s,
-                                None => continue 'inner,
+                            let registered_font = match other {
+                                StyleFontFamily::System(name) => image_cache.get_css_font_family(name),
+                                _ => None,
                             };
 
-                            let font_ref = match (parse_font_fn)(font_data) {
-                                Some(s) => s,
-                                None => continue 'inner,
-                            };
+                            // fonts registered via App::add_font take precedence over system fonts
+                            if let Some(font_ref) = registered_font {
+                                font_ref.clone()
+                            } else {
+                                // Load and parse the font
+                                let font_data = match (font_source_load_fn)(&other, fc_cache) {
+                                    Some(s) => s,
+                                    None => continue 'inner,
+                                };
+
+                                let font_ref = match (parse_font_fn)(font_data) {
+                                    Some(s) => s,
+                                    None => continue 'inner,
+                                };
 
-                            font_ref
+                                font_ref
+                            }
                         }
                     };
 
@@ -3099,6 +3327,11 @@ pub fn build_add_font_resource_updates(
 
                 // Generate a new font key, store the mapping between hash and font key
                 let font_key = FontKey::unique(id_namespace);
+                let font_data = font_ref.get_data();
+                bitmap_fonts_added_this_frame.insert(
+                    font_key,
+                    font_has_bitmap_glyphs(font_data.bytes.as_ref(), font_data.font_index),
+                );
                 let add_font_msg = AddFontMsg::Font(font_key, font_family_hash, font_ref);
 
                 renderer_resources
@@ -3269,3 +3502,31 @@ pub fn add_resources(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use azul_css::FontData;
+    use core::ffi::c_void;
+
+    #[test]
+    fn test_css_font_family_is_case_insensitive() {
+        fn parsed_destructor(_: *mut c_void) { }
+        let font = FontRef::new(FontData {
+            bytes: Vec::new().into(),
+            font_index: 0,
+            parsed: core::ptr::null(),
+            parsed_destructor,
+        });
+
+        let mut image_cache = ImageCache::new();
+        image_cache.add_css_font_family("My Font".into(), font.clone());
+        assert_eq!(image_cache.get_css_font_family(&"my font".into()), Some(&font));
+        assert_eq!(image_cache.get_css_font_family(&"MY FONT".into()), Some(&font));
+        assert_eq!(image_cache.get_css_font_family(&"MyFont".into()), None);
+
+        image_cache.delete_css_font_family(&"MY FONT".into());
+        assert_eq!(image_cache.get_css_font_family(&"My Font".into()), None);
+    }
+}
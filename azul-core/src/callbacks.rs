@@ -2,6 +2,7 @@
 
 use crate::gl::OptionGlContextPtr;
 use crate::{
+    display_list::RenderCallbacks,
     app_resources::{
         FontInstanceKey, IdNamespace, ImageCache, ImageMask, ImageRef, LayoutedGlyphs,
         RendererResources, ShapedWords, WordPositions, Words,
@@ -32,7 +33,7 @@ use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
 use azul_css::{
     AnimationInterpolationFunction, AzString, CssPath, CssProperty, CssPropertyType, FontRef,
-    InterpolateResolver, LayoutRect, LayoutSize,
+    InterpolateResolver, LayoutRect, LayoutSize, StyleFontFamily,
 };
 use core::{
     ffi::c_void,
@@ -275,10 +276,13 @@ impl RefAny {
             unsafe {
                 // copy the struct from the heap to the stack and
                 // call mem::drop on U to run the destructor
+                //
+                // note: copied bytewise, since the heap allocation
+                // in new_c() is not necessarily aligned for U
                 let mut stack_mem = mem::MaybeUninit::<U>::uninit();
                 ptr::copy_nonoverlapping(
-                    (ptr as *mut c_void) as *const U,
-                    stack_mem.as_mut_ptr(),
+                    (ptr as *mut c_void) as *const u8,
+                    stack_mem.as_mut_ptr() as *mut u8,
                     mem::size_of::<U>(),
                 );
                 let stack_mem = stack_mem.assume_init();
@@ -859,8 +863,32 @@ pub struct InlineText {
     pub last_word_index: usize,
     /// NOTE: descender is NEGATIVE (pixels from baseline to font size)
     pub baseline_descender_px: f32,
+    /// Styled text runs (text nodes) that this text was laid out from -
+    /// contains more than one run if the text is an inline formatting
+    /// context (`display: inline` children with different fonts / sizes)
+    pub runs: InlineTextRunVec,
 }
 
+/// Range of glyphs in an `InlineText` that belong to the same text run (text node)
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct InlineTextRun {
+    /// Text node that this run was laid out from
+    pub node_id: NodeHierarchyItemId,
+    /// Index of the first glyph of the run (same as `InlineTextHit.glyph_index_relative_to_text`)
+    pub glyph_start: usize,
+    /// Index of the glyph after the last glyph of the run
+    pub glyph_end: usize,
+    /// Font size in pixels used for layouting this run
+    pub font_size_px: f32,
+}
+
+impl_vec!(InlineTextRun, InlineTextRunVec, InlineTextRunVecDestructor);
+impl_vec_clone!(InlineTextRun, InlineTextRunVec, InlineTextRunVecDestructor);
+impl_vec_debug!(InlineTextRun, InlineTextRunVec);
+impl_vec_partialeq!(InlineTextRun, InlineTextRunVec);
+impl_vec_partialord!(InlineTextRun, InlineTextRunVec);
+
 impl_option!(
     InlineText,
     OptionInlineText,
@@ -868,7 +896,105 @@ impl_option!(
     [Debug, Clone, PartialEq, PartialOrd]
 );
 
+/// Size of a string measured without laying out a DOM, i.e. to size
+/// table columns to their content in the `layout()` callback
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct TextMetrics {
+    /// Width of the longest line (in pixels)
+    pub width: f32,
+    /// Height of all lines (in pixels)
+    pub height: f32,
+    /// Number of lines after breaking the text at `max_horizontal_width`
+    pub line_count: usize,
+    /// Metrics of the individual lines, from top to bottom
+    pub lines: TextLineMetricsVec,
+}
+
+impl_option!(
+    TextMetrics,
+    OptionTextMetrics,
+    copy = false,
+    [Debug, Clone, PartialEq, PartialOrd]
+);
+
+/// Metrics of a single line of a measured text
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct TextLineMetrics {
+    /// Bounds of the line, relative to the TOP left corner of the text
+    pub bounds: LogicalRect,
+    /// Distance from the top of the text to the baseline of this line
+    pub baseline_px: f32,
+    /// Number of glyphs on this line
+    pub glyph_count: usize,
+}
+
+impl_vec!(TextLineMetrics, TextLineMetricsVec, TextLineMetricsVecDestructor);
+impl_vec_clone!(TextLineMetrics, TextLineMetricsVec, TextLineMetricsVecDestructor);
+impl_vec_debug!(TextLineMetrics, TextLineMetricsVec);
+impl_vec_partialeq!(TextLineMetrics, TextLineMetricsVec);
+impl_vec_partialord!(TextLineMetrics, TextLineMetricsVec);
+
 impl InlineText {
+    /// Returns the index of the run that contains the given glyph
+    /// (i.e. `InlineTextHit.glyph_index_relative_to_text`)
+    pub fn get_run_index(&self, glyph_index: usize) -> Option<usize> {
+        self.runs
+            .iter()
+            .position(|run| glyph_index >= run.glyph_start && glyph_index < run.glyph_end)
+    }
+
+    /// Returns the number of glyphs in this text
+    pub fn get_glyph_count(&self) -> usize {
+        self.lines
+            .iter()
+            .flat_map(|line| line.words.iter())
+            .filter_map(|word| word.get_text_content())
+            .map(|text_content| text_content.glyphs.len())
+            .sum()
+    }
+
+    /// Returns the size of the text and of its individual lines
+    pub fn get_text_metrics(&self) -> TextMetrics {
+        let lines = self
+            .lines
+            .iter()
+            .map(|line| {
+                // line bounds are relative to the BOTTOM left corner of the line
+                let mut bounds = line.bounds;
+                bounds.origin.y -= bounds.size.height;
+                TextLineMetrics {
+                    bounds,
+                    // descender is NEGATIVE
+                    baseline_px: line.bounds.origin.y + self.baseline_descender_px,
+                    glyph_count: line
+                        .words
+                        .iter()
+                        .filter_map(|word| word.get_text_content())
+                        .map(|text_content| text_content.glyphs.len())
+                        .sum(),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let width = lines
+            .iter()
+            .map(|line| line.bounds.size.width)
+            .fold(0.0_f32, f32::max);
+        let height = lines
+            .iter()
+            .map(|line| line.bounds.origin.y + line.bounds.size.height)
+            .fold(0.0_f32, f32::max);
+
+        TextMetrics {
+            width,
+            height,
+            line_count: lines.len(),
+            lines: lines.into(),
+        }
+    }
+
     /// Returns the final, positioned glyphs from an inline text
     ///
     /// NOTE: It seems that at least in webrender, the glyphs have to be
@@ -1605,21 +1731,12 @@ impl CallbackInfo {
         }
     }
 
+    /// Returns the layouted text of a text node - or, if the node is the root
+    /// of an inline formatting context, the combined text of all its inline runs
     pub fn get_inline_text(&self, node_id: DomNodeId) -> Option<InlineText> {
         let nid = node_id.node.into_crate_internal()?;
         let layout_result = self.internal_get_layout_results().get(node_id.dom.inner)?;
-        let words = layout_result.words_cache.get(&nid)?;
-        let shaped_words = layout_result.shaped_words_cache.get(&nid)?;
-        let word_positions = layout_result.positioned_words_cache.get(&nid)?;
-        let positioned_rectangles = layout_result.rects.as_ref();
-        let positioned_rectangle = positioned_rectangles.get(nid)?;
-        let (_, inline_text_layout) = positioned_rectangle.resolved_text_layout_options.as_ref()?;
-        Some(crate::app_resources::get_inline_text(
-            &words,
-            &shaped_words,
-            &word_positions.0,
-            &inline_text_layout,
-        ))
+        layout_result.get_inline_text(nid)
     }
 
     /// Returns the FontRef for the given NodeId
@@ -2499,6 +2616,8 @@ pub struct LayoutCallbackInfo {
     pub gl_context: *const OptionGlContextPtr,
     /// Reference to the system font cache
     system_fonts: *const FcFontCache,
+    /// Font loading / parsing functions, necessary to measure text before layout
+    callbacks: *const RenderCallbacks,
     /// Extension for future ABI stability (referenced data)
     _abi_ref: *const c_void,
     /// Extension for future ABI stability (mutable data)
@@ -2513,6 +2632,7 @@ impl Clone for LayoutCallbackInfo {
             image_cache: self.image_cache,
             gl_context: self.gl_context,
             system_fonts: self.system_fonts,
+            callbacks: self.callbacks,
             _abi_ref: self._abi_ref,
             _abi_mut: self._abi_mut,
         }
@@ -2526,6 +2646,7 @@ impl LayoutCallbackInfo {
         image_cache: &'a ImageCache,
         gl_context: &'a OptionGlContextPtr,
         fc_cache: &'a FcFontCache,
+        callbacks: &'a RenderCallbacks,
     ) -> Self {
         Self {
             window_size: window_size,
@@ -2533,6 +2654,7 @@ impl LayoutCallbackInfo {
             image_cache: image_cache as *const ImageCache,
             gl_context: gl_context as *const OptionGlContextPtr,
             system_fonts: fc_cache as *const FcFontCache,
+            callbacks: callbacks as *const RenderCallbacks,
             _abi_ref: core::ptr::null(),
             _abi_mut: core::ptr::null_mut(),
         }
@@ -2547,6 +2669,9 @@ impl LayoutCallbackInfo {
     fn internal_get_gl_context<'a>(&'a self) -> &'a OptionGlContextPtr {
         unsafe { &*self.gl_context }
     }
+    fn internal_get_callbacks<'a>(&'a self) -> &'a RenderCallbacks {
+        unsafe { &*self.callbacks }
+    }
 
     pub fn get_gl_context(&self) -> OptionGlContextPtr {
         self.internal_get_gl_context().clone()
@@ -2570,6 +2695,29 @@ impl LayoutCallbackInfo {
             .get_css_image_id(image_id)
             .cloned()
     }
+
+    /// Resolves the first loadable font of a `font-family` list, fonts
+    /// registered via `App::add_font` take precedence over system fonts
+    /// (family names are matched case-insensitively, same as in the CSS)
+    ///
+    /// NOTE: EXPENSIVE for system fonts, since the font has to be loaded
+    /// and parsed again on every call
+    pub fn get_font_ref(&self, font_families: &[StyleFontFamily]) -> Option<FontRef> {
+        let image_cache = self.internal_get_image_cache();
+        let callbacks = self.internal_get_callbacks();
+        let load_font = |family: &StyleFontFamily| {
+            let font_source = (callbacks.load_font_fn)(family, self.internal_get_system_fonts())?;
+            (callbacks.parse_font_fn)(font_source)
+        };
+        font_families.iter().find_map(|family| match family {
+            StyleFontFamily::Ref(r) => Some(r.clone()),
+            StyleFontFamily::System(name) => image_cache
+                .get_css_font_family(name)
+                .cloned()
+                .or_else(|| load_font(family)),
+            other => load_font(other),
+        })
+    }
 }
 
 /// Information about the bounds of a laid-out div rectangle.
@@ -2891,3 +3039,98 @@ impl FocusTarget {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::app_resources::{
+        DpiScaleFactor, Epoch, ExternalImageId, LoadedFontSource, ResourceUpdate,
+    };
+    use crate::gl::Texture;
+    use azul_css::FontData;
+
+    fn parsed_destructor(_: *mut c_void) {}
+
+    fn test_font() -> FontRef {
+        FontRef::new(FontData {
+            bytes: Vec::new().into(),
+            font_index: 0,
+            parsed: core::ptr::null(),
+            parsed_destructor,
+        })
+    }
+
+    fn insert_into_active_gl_textures(_: DocumentId, _: Epoch, _: Texture) -> ExternalImageId {
+        ExternalImageId { inner: 0 }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn layout(
+        _: StyledDom,
+        _: &ImageCache,
+        _: &FcFontCache,
+        _: &mut RendererResources,
+        _: DpiScaleFactor,
+        _: &mut Vec<ResourceUpdate>,
+        _: IdNamespace,
+        _: &DocumentId,
+        _: Epoch,
+        _: &RenderCallbacks,
+        _: &FullWindowState,
+    ) -> Vec<LayoutResult> {
+        Vec::new()
+    }
+
+    // no system fonts available: only user-registered fonts can resolve
+    fn load_font(_: &StyleFontFamily, _: &FcFontCache) -> Option<LoadedFontSource> {
+        None
+    }
+
+    fn parse_font(_: LoadedFontSource) -> Option<FontRef> {
+        None
+    }
+
+    #[test]
+    fn test_layout_callback_get_font_ref() {
+        let font = test_font();
+        let mut image_cache = ImageCache::new();
+        image_cache.add_css_font_family("Roboto".into(), font.clone());
+
+        let gl_context = OptionGlContextPtr::None;
+        let fc_cache = FcFontCache::default();
+        let callbacks = RenderCallbacks {
+            insert_into_active_gl_textures_fn: insert_into_active_gl_textures,
+            layout_fn: layout,
+            load_font_fn: load_font,
+            parse_font_fn: parse_font,
+        };
+        let info = LayoutCallbackInfo::new(
+            WindowSize::default(),
+            WindowTheme::default(),
+            &image_cache,
+            &gl_context,
+            &fc_cache,
+            &callbacks,
+        );
+
+        let system = |name: &str| StyleFontFamily::System(name.into());
+
+        // registered families are matched case-insensitively
+        assert_eq!(info.get_font_ref(&[system("Roboto")]), Some(font.clone()));
+        assert_eq!(info.get_font_ref(&[system("ROBOTO")]), Some(font.clone()));
+        // unresolvable families are skipped
+        assert_eq!(
+            info.get_font_ref(&[system("Unknown"), system("roboto")]),
+            Some(font.clone())
+        );
+        assert_eq!(info.get_font_ref(&[system("Unknown")]), None);
+        assert_eq!(info.get_font_ref(&[]), None);
+
+        let other = test_font();
+        assert_eq!(
+            info.get_font_ref(&[StyleFontFamily::Ref(other.clone()), system("Roboto")]),
+            Some(other)
+        );
+    }
+}
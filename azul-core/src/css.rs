@@ -675,6 +675,14 @@ pub(crate) fn format_static_css_prop(prop: &CssProperty, tabs: usize) -> String
             "CssProperty::TextShadow({})",
             print_css_property_value(p, tabs, "StyleBoxShadow")
         ),
+        CssProperty::VerticalAlign(p) => format!(
+            "CssProperty::VerticalAlign({})",
+            print_css_property_value(p, tabs, "LayoutVerticalAlign")
+        ),
+        CssProperty::TextDecoration(p) => format!(
+            "CssProperty::TextDecoration({})",
+            print_css_property_value(p, tabs, "StyleTextDecoration")
+        ),
     }
 }
 
@@ -1017,9 +1025,10 @@ impl FormatAsRustCode for StyleBackgroundRepeatVec {
     }
 }
 
-impl_enum_fmt!(LayoutDisplay, None, Flex, Block, InlineBlock);
+impl_enum_fmt!(LayoutDisplay, None, Flex, Block, InlineBlock, Inline);
 
 impl_enum_fmt!(LayoutFloat, Left, Right);
+impl_enum_fmt!(LayoutVerticalAlign, Baseline, Top, Middle, Bottom, Sub, Super);
 
 impl_enum_fmt!(LayoutBoxSizing, ContentBox, BorderBox);
 
@@ -1057,6 +1066,8 @@ impl_enum_fmt!(LayoutOverflow, Auto, Scroll, Visible, Hidden);
 
 impl_enum_fmt!(StyleTextAlign, Center, Left, Right);
 
+impl_enum_fmt!(StyleTextDecoration, None, Underline, Overline, LineThrough);
+
 impl_enum_fmt!(
     DirectionCorner,
     Right,
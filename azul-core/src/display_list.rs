@@ -4,8 +4,9 @@ use crate::{
         AddImageMsg, Epoch, ExternalImageId, FontInstanceKey, GlTextureCache, GlyphOptions,
         IdNamespace, ImageCache, ImageDescriptor, ImageKey, LoadFontFn, OpacityKey, ParseFontFn,
         PrimitiveFlags, RendererResources, ResourceUpdate, TransformKey, DpiScaleFactor,
+        ShapedWords,
     },
-    callbacks::{DocumentId, DomNodeId, PipelineId},
+    callbacks::{DocumentId, DomNodeId, InlineText, PipelineId},
     dom::{ScrollTagId, TagId},
     id_tree::NodeId,
     styled_dom::{ContentGroup, DomId, NodeHierarchyItemId, StyledDom},
@@ -22,7 +23,7 @@ use azul_css::{
     StyleBorderBottomRightRadius, StyleBorderBottomStyle, StyleBorderLeftColor,
     StyleBorderLeftStyle, StyleBorderRightColor, StyleBorderRightStyle, StyleBorderTopColor,
     StyleBorderTopLeftRadius, StyleBorderTopRightRadius, StyleBorderTopStyle, StyleBoxShadow,
-    StyleMixBlendMode,
+    StyleMixBlendMode, StyleTextDecoration,
 };
 use core::fmt;
 use rust_fontconfig::FcFontCache;
@@ -850,6 +851,229 @@ impl SolvedLayout {
     }
 }
 
+/// Replaces the layered color glyphs (`COLR` table) in a glyph run with their layers.
+///
+/// Returns the glyphs without color layers and the glyph runs for the layers, in the
+/// order they have to be drawn in (bottom layer first). Glyph runs are grouped by
+/// layer depth and color, so that each run can be drawn with a single color.
+pub fn split_color_glyph_layers(
+    glyphs: Vec<GlyphInstance>,
+    shaped_words: &ShapedWords,
+    text_color: ColorU,
+) -> (Vec<GlyphInstance>, Vec<(ColorU, Vec<GlyphInstance>)>) {
+
+    use core::convert::TryFrom;
+
+    if shaped_words.color_glyphs.is_empty() {
+        return (glyphs, Vec::new());
+    }
+
+    let mut regular_glyphs = Vec::with_capacity(glyphs.len());
+    // (layer depth, color) -> glyphs, ColorU isn't Ord, so sort by the u32 representation
+    let mut layer_runs = BTreeMap::<(usize, u32), (ColorU, Vec<GlyphInstance>)>::new();
+
+    for glyph in glyphs {
+        let color_glyph = match u16::try_from(glyph.index).ok().and_then(|i| shaped_words.get_color_glyph(i)) {
+            Some(s) => s,
+            None => {
+                regular_glyphs.push(glyph);
+                continue;
+            }
+        };
+
+        for (layer_depth, layer) in color_glyph.layers.iter().enumerate() {
+            let color = layer.color.into_option().unwrap_or(text_color);
+            let color_key = u32::from_be_bytes([color.r, color.g, color.b, color.a]);
+            layer_runs
+                .entry((layer_depth, color_key))
+                .or_insert_with(|| (color, Vec::new()))
+                .1
+                .push(GlyphInstance {
+                    index: layer.glyph_index as GlyphIndex,
+                    point: glyph.point,
+                    size: glyph.size,
+                });
+        }
+    }
+
+    (regular_glyphs, layer_runs.into_iter().map(|(_, v)| v).collect())
+}
+
+/// Removes the color bitmap glyphs (`CBDT` / `sbix` tables) from a glyph run.
+///
+/// Returns the remaining glyphs and the image key + bounds (relative to the text node) of
+/// each bitmap glyph. Glyphs whose image isn't registered in the `renderer_resources`
+/// stay in the glyph run.
+pub fn split_bitmap_glyphs(
+    glyphs: Vec<GlyphInstance>,
+    shaped_words: &ShapedWords,
+    font_size_px: f32,
+    renderer_resources: &RendererResources,
+) -> (Vec<GlyphInstance>, Vec<(ImageKey, LogicalRect)>) {
+
+    use crate::app_resources::ResolvedImage;
+    use core::convert::TryFrom;
+
+    if shaped_words.bitmap_glyphs.is_empty() {
+        return (glyphs, Vec::new());
+    }
+
+    let scale = font_size_px / shaped_words.font_metrics_units_per_em as f32;
+    let mut regular_glyphs = Vec::with_capacity(glyphs.len());
+    let mut bitmap_glyphs = Vec::new();
+
+    for glyph in glyphs {
+        let bitmap_glyph = match u16::try_from(glyph.index).ok().and_then(|i| shaped_words.get_bitmap_glyph(i)) {
+            Some(s) => s,
+            None => {
+                regular_glyphs.push(glyph);
+                continue;
+            }
+        };
+
+        let image_key = match renderer_resources.get_image(&bitmap_glyph.image.get_hash()) {
+            Some(ResolvedImage { key, .. }) => *key,
+            None => {
+                regular_glyphs.push(glyph);
+                continue;
+            }
+        };
+
+        // glyph.point is the origin of the glyph on the baseline (y-axis pointing down)
+        let bounds = LogicalRect::new(
+            LogicalPosition::new(
+                glyph.point.x + bitmap_glyph.min_x as f32 * scale,
+                glyph.point.y - bitmap_glyph.max_y as f32 * scale,
+            ),
+            LogicalSize::new(
+                (bitmap_glyph.max_x as f32 - bitmap_glyph.min_x as f32) * scale,
+                (bitmap_glyph.max_y as f32 - bitmap_glyph.min_y as f32) * scale,
+            ),
+        );
+
+        bitmap_glyphs.push((image_key, bounds));
+    }
+
+    (regular_glyphs, bitmap_glyphs)
+}
+
+/// Returns one frame per bitmap glyph (see `split_bitmap_glyphs`) that draws the image
+/// of the glyph, `text_position` is the position of the text node that the frames are appended to.
+pub fn get_bitmap_glyph_frames(
+    bitmap_glyphs: Vec<(ImageKey, LogicalRect)>,
+    text_position: &PositionInfo,
+) -> Vec<DisplayListMsg> {
+    bitmap_glyphs.into_iter().map(|(image_key, bounds)| {
+        get_text_child_frame(bounds, text_position, LayoutRectContent::Image {
+            size: bounds.size,
+            offset: LogicalPosition::zero(),
+            image_rendering: ImageRendering::Auto,
+            alpha_type: AlphaType::PremultipliedAlpha,
+            image_key,
+            background_color: ColorU::WHITE,
+        })
+    }).collect()
+}
+
+/// Frame at `bounds` (relative to the text node) that draws additional content of a text node
+fn get_text_child_frame(
+    bounds: LogicalRect,
+    text_position: &PositionInfo,
+    content: LayoutRectContent,
+) -> DisplayListMsg {
+
+    use crate::ui_solver::PositionInfoInner;
+
+    let static_offset = text_position.get_static_offset();
+
+    DisplayListMsg::Frame(DisplayListFrame {
+        size: bounds.size,
+        position: PositionInfo::Static(PositionInfoInner {
+            x_offset: bounds.origin.x,
+            y_offset: bounds.origin.y,
+            static_x_offset: static_offset.x + bounds.origin.x,
+            static_y_offset: static_offset.y + bounds.origin.y,
+        }),
+        flags: PrimitiveFlags {
+            is_backface_visible: false,
+            is_scrollbar_container: false,
+            is_scrollbar_thumb: false,
+            prefer_compositor_surface: false,
+            supports_external_compositor_surface: false,
+        },
+        mix_blend_mode: None,
+        clip_children: None,
+        clip_mask: None,
+        border_radius: StyleBorderRadius::default(),
+        tag: None,
+        box_shadow: None,
+        transform: None,
+        opacity: None,
+        content: vec![content],
+        children: Vec::new(),
+    })
+}
+
+/// Thickness of a `text-decoration` line, relative to the font size
+const TEXT_DECORATION_THICKNESS: f32 = 1.0 / 16.0;
+/// Distance from the baseline to a `text-decoration: line-through` line, relative to the font size
+const LINE_THROUGH_OFFSET: f32 = 0.3;
+
+/// Returns one frame per line of text that draws the `text-decoration` line of the line,
+/// `text_position` is the position of the text node that the frames are appended to.
+///
+/// The line spans from the start of the first word to the end of the last word of each line.
+pub fn get_text_decoration_frames(
+    inline_text: &InlineText,
+    shaped_words: &ShapedWords,
+    text_decoration: StyleTextDecoration,
+    color: ColorU,
+    text_position: &PositionInfo,
+) -> Vec<DisplayListMsg> {
+
+    use crate::callbacks::InlineWord;
+
+    let font_size_px = inline_text.font_size_px;
+    let thickness = (font_size_px * TEXT_DECORATION_THICKNESS).max(1.0);
+
+    // distance from the baseline to the center of the line (y-axis pointing down)
+    let offset_y = match text_decoration {
+        StyleTextDecoration::None => return Vec::new(),
+        // descender is NEGATIVE
+        StyleTextDecoration::Underline => -shaped_words.get_descender(font_size_px) / 2.0,
+        StyleTextDecoration::Overline => -shaped_words.get_ascender(font_size_px) + thickness / 2.0,
+        StyleTextDecoration::LineThrough => -font_size_px * LINE_THROUGH_OFFSET,
+    };
+
+    inline_text.lines.iter().filter_map(|line| {
+
+        let (start_x, end_x) = line.words.iter().filter_map(|word| match word {
+            InlineWord::Word(w) => Some((w.bounds.origin.x, w.bounds.origin.x + w.bounds.size.width)),
+            _ => None,
+        }).fold(None, |acc, (start, end)| Some(match acc {
+            None => (start, end),
+            Some((s, e)) => (f32::min(s, start), f32::max(e, end)),
+        }))?;
+
+        // line.bounds.origin is the bottom left corner of the line
+        let baseline_y = line.bounds.origin.y + inline_text.baseline_descender_px;
+        let x = line.bounds.origin.x + start_x;
+        let y = baseline_y + offset_y - thickness / 2.0;
+
+        let bounds = LogicalRect::new(
+            LogicalPosition::new(x, y),
+            LogicalSize::new(end_x - start_x, thickness),
+        );
+
+        Some(get_text_child_frame(bounds, text_position, LayoutRectContent::Background {
+            content: RectBackground::Color(color),
+            size: None,
+            offset: None,
+            repeat: None,
+        }))
+    }).collect()
+}
+
 pub fn push_rectangles_into_displaylist<'a>(
     root_content_group: &ContentGroup,
     referenced_content: &DisplayListParametersRef<'a>,
@@ -1193,7 +1417,7 @@ pub fn displaylist_handle_rect<'a>(
                 Some(words),
                 Some(shaped_words),
                 Some(word_positions),
-                Some((_, inline_text_layout)),
+                Some((text_layout_options, inline_text_layout)),
             ) = (
                 layout_result.words_cache.get(&rect_idx),
                 layout_result.shaped_words_cache.get(&rect_idx),
@@ -1222,14 +1446,60 @@ pub fn displaylist_handle_rect<'a>(
                         .and_then(|p| p.get_property())
                         .cloned();
 
+                    // color bitmap glyphs are drawn as images on top of the text
+                    let (glyphs, bitmap_glyphs) = split_bitmap_glyphs(
+                        layouted_glyphs.glyphs,
+                        shaped_words,
+                        text_layout_options.font_size_px,
+                        renderer_resources,
+                    );
+
+                    let (glyphs, color_glyph_layers) = split_color_glyph_layers(
+                        glyphs,
+                        shaped_words,
+                        text_color.inner,
+                    );
+
                     frame.content.push(LayoutRectContent::Text {
                         text_shadow,
-                        glyphs: layouted_glyphs.glyphs,
+                        glyphs,
                         font_instance_key,
                         color: text_color.inner,
                         glyph_options: None,
                         overflow: (overflow_horizontal_visible, overflow_vertical_visible),
                     });
+
+                    // layered color glyphs are drawn as one glyph run per layer + color
+                    for (layer_color, layer_glyphs) in color_glyph_layers {
+                        frame.content.push(LayoutRectContent::Text {
+                            text_shadow: None,
+                            glyphs: layer_glyphs,
+                            font_instance_key,
+                            color: layer_color,
+                            glyph_options: None,
+                            overflow: (overflow_horizontal_visible, overflow_vertical_visible),
+                        });
+                    }
+
+                    let text_decoration = layout_result
+                        .styled_dom
+                        .get_css_property_cache()
+                        .get_text_decoration(&html_node, &rect_idx, &styled_node.state)
+                        .and_then(|p| p.get_property().copied())
+                        .unwrap_or_default();
+
+                    frame.children.extend(get_bitmap_glyph_frames(
+                        bitmap_glyphs,
+                        &positioned_rect.position,
+                    ));
+
+                    frame.children.extend(get_text_decoration_frames(
+                        &inline_text,
+                        shaped_words,
+                        text_decoration,
+                        text_color.inner,
+                        &positioned_rect.position,
+                    ));
                 }
             }
         }
@@ -1,13 +1,17 @@
 use crate::{
     app_resources::{ImageCallback, ImageMask, RendererResources},
     app_resources::{ImageRef, ImageRefHash},
-    callbacks::{Callback, CallbackType, IFrameCallback, IFrameCallbackType, OptionRefAny, RefAny},
+    callbacks::{
+        Callback, CallbackType, IFrameCallback, IFrameCallbackInfo, IFrameCallbackReturn,
+        IFrameCallbackType, OptionRefAny, RefAny,
+    },
     id_tree::{NodeDataContainer, NodeDataContainerRef, NodeDataContainerRefMut},
     styled_dom::{
         CssPropertyCache, CssPropertyCachePtr, StyleFontFamilyHash, StyledNode, StyledNodeState,
     },
     styled_dom::{NodeHierarchyItemId, StyledDom},
-    window::{Menu, OptionVirtualKeyCodeCombo},
+    task::{Duration, SystemTimeDiff},
+    window::{KeyboardShortcutVec, Menu, OptionVirtualKeyCodeCombo},
 };
 use alloc::boxed::Box;
 use alloc::collections::btree_map::BTreeMap;
@@ -544,6 +548,19 @@ pub struct IFrameNode {
     pub data: RefAny,
 }
 
+// Invokes the wrapped IFrame callback of an inert node (see `NodeData::make_inert`)
+// and makes the returned DOM inert, including the DOMs of nested IFrames
+extern "C" fn inert_iframe_callback(data: &mut RefAny, info: &mut IFrameCallbackInfo) -> IFrameCallbackReturn {
+    let mut iframe = match data.downcast_mut::<IFrameNode>() {
+        Some(s) => s,
+        None => return IFrameCallbackReturn::default(),
+    };
+    let iframe = &mut *iframe;
+    let mut iframe_return = (iframe.callback.cb)(&mut iframe.data, info);
+    iframe_return.dom.make_inert();
+    iframe_return
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub struct CallbackData {
@@ -781,6 +798,12 @@ impl Hash for NodeData {
             if let Some(c) = ext.context_menu.as_ref() {
                 c.hash(state);
             }
+            if let Some(c) = ext.tooltip.as_ref() {
+                c.hash(state);
+            }
+            if let Some(c) = ext.keyboard_shortcuts.as_ref() {
+                c.hash(state);
+            }
         }
     }
 }
@@ -798,6 +821,10 @@ pub struct NodeDataExt {
     pub(crate) menu_bar: Option<Box<Menu>>,
     /// Context menu that should be opened when the item is left-clicked
     pub(crate) context_menu: Option<Box<Menu>>,
+    /// Tooltip that should be shown when the mouse rests over this node
+    pub(crate) tooltip: Option<Box<Tooltip>>,
+    /// Keyboard shortcuts that are active while this node or one of its children is focused
+    pub(crate) keyboard_shortcuts: Option<Box<KeyboardShortcutVec>>,
     // ... insert further API extensions here...
 }
 
@@ -824,6 +851,90 @@ pub struct AccessibilityInfo {
     /// Optional "default action" description. Only used when there is at least
     /// one `ComponentEventFilter::DefaultAction` callback present on this node
     pub default_action: OptionAzString,
+    /// Optional longer "description" of the `IAccessible`. If not set,
+    /// the text of the nodes tooltip is used instead
+    ///
+    /// NOTE: Adding this field changed the size of the `#[repr(C)]` struct,
+    /// C / C++ / Python code compiled against an older `azul.h` has to be recompiled.
+    pub description: OptionAzString,
+}
+
+/// Hover hint of a DOM node, see `Dom::with_tooltip()`
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[repr(C)]
+pub struct Tooltip {
+    /// What to display inside of the tooltip
+    pub content: TooltipContent,
+    /// How long the mouse has to rest over the node before the tooltip is shown
+    pub delay: Duration,
+}
+
+/// Content of a `Tooltip`: either a plain text or a custom DOM
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[repr(C, u8)]
+pub enum TooltipContent {
+    Text(AzString),
+    Dom(Dom),
+}
+
+impl Tooltip {
+    /// Default hover delay before a tooltip is shown (500ms)
+    pub const DEFAULT_DELAY: Duration = Duration::System(SystemTimeDiff::from_millis(500));
+
+    /// Creates a new text tooltip with the default hover delay
+    #[inline]
+    pub fn text(text: AzString) -> Self {
+        Self {
+            content: TooltipContent::Text(text),
+            delay: Self::DEFAULT_DELAY,
+        }
+    }
+
+    /// Creates a new tooltip rendering a custom DOM, with the default hover delay
+    #[inline]
+    pub fn dom(dom: Dom) -> Self {
+        Self {
+            content: TooltipContent::Dom(dom),
+            delay: Self::DEFAULT_DELAY,
+        }
+    }
+
+    #[inline]
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut default = Self::text(AzString::from_const_str(""));
+        mem::swap(&mut default, self);
+        default
+    }
+
+    /// Returns the plain-text representation of the tooltip,
+    /// i.e. the concatenated text nodes of a custom DOM tooltip
+    pub fn get_text(&self) -> String {
+        fn collect_text(dom: &Dom, out: &mut String) {
+            if let NodeType::Text(t) = &dom.root.node_type {
+                if !out.is_empty() {
+                    out.push(' ');
+                }
+                out.push_str(t.as_str());
+            }
+            for child in dom.children.as_ref().iter() {
+                collect_text(child, out);
+            }
+        }
+
+        match &self.content {
+            TooltipContent::Text(t) => t.as_str().to_string(),
+            TooltipContent::Dom(d) => {
+                let mut s = String::new();
+                collect_text(d, &mut s);
+                s
+            }
+        }
+    }
 }
 
 /// MSAA Accessibility role constants. For information on what each role does,
@@ -1102,7 +1213,17 @@ fn node_data_to_string(node_data: &NodeData) -> String {
         tabindex_string = format!(" tabindex=\"{}\" ", tab_index.get_index());
     };
 
-    format!("{}{}{}", id_string, class_string, tabindex_string)
+    // explicit accessibility description or the tooltip text
+    let mut description_string = String::new();
+    if let Some(description) = node_data.get_accessible_description() {
+        let escaped = description.as_str()
+            .replace('&', "&amp;")
+            .replace('"', "&quot;")
+            .replace('<', "&lt;");
+        description_string = format!(" aria-description=\"{}\" ", escaped);
+    }
+
+    format!("{}{}{}{}", id_string, class_string, tabindex_string, description_string)
 }
 
 impl NodeData {
@@ -1240,6 +1361,23 @@ impl NodeData {
     pub fn get_context_menu(&self) -> Option<&Box<Menu>> {
         self.extra.as_ref().and_then(|e| e.context_menu.as_ref())
     }
+    #[inline]
+    pub fn get_tooltip(&self) -> Option<&Box<Tooltip>> {
+        self.extra.as_ref().and_then(|e| e.tooltip.as_ref())
+    }
+    #[inline]
+    pub fn get_keyboard_shortcuts(&self) -> Option<&Box<KeyboardShortcutVec>> {
+        self.extra.as_ref().and_then(|e| e.keyboard_shortcuts.as_ref())
+    }
+
+    /// Returns the accessible description of this node: either the explicit
+    /// `AccessibilityInfo.description` or, as a fallback, the text of the tooltip
+    pub fn get_accessible_description(&self) -> Option<AzString> {
+        let explicit = self
+            .get_accessibility_info()
+            .and_then(|a| a.description.as_ref().cloned());
+        explicit.or_else(|| self.get_tooltip().map(|t| t.get_text().into()))
+    }
 
     #[inline(always)]
     pub fn set_node_type(&mut self, node_type: NodeType) {
@@ -1290,12 +1428,61 @@ impl NodeData {
             .context_menu = Some(Box::new(context_menu));
     }
 
+    #[inline]
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.extra
+            .get_or_insert_with(|| Box::new(NodeDataExt::default()))
+            .tooltip = Some(Box::new(tooltip));
+    }
+
+    #[inline]
+    pub fn set_keyboard_shortcuts(&mut self, keyboard_shortcuts: KeyboardShortcutVec) {
+        self.extra
+            .get_or_insert_with(|| Box::new(NodeDataExt::default()))
+            .keyboard_shortcuts = Some(Box::new(keyboard_shortcuts));
+    }
+
+    /// Removes everything that makes this node interactive: callbacks, tab index,
+    /// context menu, tooltip and keyboard shortcuts (see `Dom::make_inert`)
+    ///
+    /// The DOM of an IFrame is only created during layout, so the IFrame callback
+    /// is wrapped in a callback that makes the returned DOM inert as well.
+    pub fn make_inert(&mut self) {
+        self.callbacks = CallbackDataVec::from_const_slice(&[]);
+        self.tab_index = None.into();
+        if let Some(ext) = self.extra.as_mut() {
+            ext.context_menu = None;
+            ext.tooltip = None;
+            ext.keyboard_shortcuts = None;
+        }
+        if matches!(self.node_type, NodeType::IFrame(_)) {
+            if let NodeType::IFrame(iframe) = mem::replace(&mut self.node_type, NodeType::Div) {
+                self.node_type = NodeType::IFrame(IFrameNode {
+                    callback: IFrameCallback { cb: inert_iframe_callback },
+                    data: RefAny::new(iframe),
+                });
+            }
+        }
+    }
+
     #[inline]
     pub fn with_context_menu(mut self, context_menu: Menu) -> Self {
         self.set_context_menu(context_menu);
         self
     }
 
+    #[inline]
+    pub fn with_keyboard_shortcuts(mut self, keyboard_shortcuts: KeyboardShortcutVec) -> Self {
+        self.set_keyboard_shortcuts(keyboard_shortcuts);
+        self
+    }
+
+    #[inline]
+    pub fn with_tooltip(mut self, tooltip: Tooltip) -> Self {
+        self.set_tooltip(tooltip);
+        self
+    }
+
     #[inline]
     pub fn add_callback(&mut self, event: EventFilter, data: RefAny, callback: CallbackType) {
         let mut v: CallbackDataVec = Vec::new().into();
@@ -1562,6 +1749,16 @@ impl Dom {
         self.estimated_total_children = children_estimated;
     }
 
+    /// Makes this node and all of its children inert: they are still rendered,
+    /// but can't receive events or the keyboard focus anymore - used to block
+    /// the content behind modal dialogs
+    pub fn make_inert(&mut self) {
+        self.root.make_inert();
+        for child in self.children.as_mut().iter_mut() {
+            child.make_inert();
+        }
+    }
+
     pub fn copy_except_for_root(&mut self) -> Self {
         Self {
             root: self.root.copy_special(),
@@ -1633,6 +1830,36 @@ impl Dom {
         self
     }
 
+    #[inline]
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.root.set_tooltip(tooltip);
+    }
+
+    #[inline]
+    pub fn with_tooltip(mut self, tooltip: Tooltip) -> Self {
+        self.root.set_tooltip(tooltip);
+        self
+    }
+
+    /// Shorthand for `with_tooltip(Tooltip::text(text))`
+    #[inline]
+    pub fn with_tooltip_text(self, text: AzString) -> Self {
+        self.with_tooltip(Tooltip::text(text))
+    }
+
+    /// Sets keyboard shortcuts that are active while this node or one of its
+    /// children is focused. Set them on the root node for window-wide shortcuts
+    #[inline]
+    pub fn set_keyboard_shortcuts(&mut self, keyboard_shortcuts: KeyboardShortcutVec) {
+        self.root.set_keyboard_shortcuts(keyboard_shortcuts);
+    }
+
+    #[inline]
+    pub fn with_keyboard_shortcuts(mut self, keyboard_shortcuts: KeyboardShortcutVec) -> Self {
+        self.root.set_keyboard_shortcuts(keyboard_shortcuts);
+        self
+    }
+
     fn fixup_children_estimated(&mut self) -> usize {
         if self.children.is_empty() {
             self.estimated_total_children = 0;
@@ -1711,6 +1938,13 @@ impl From<Dom> for CompactDom {
     }
 }
 
+// The last child comes after the subtrees of all other children, so it is not
+// necessarily the last node in the subtree of the parent (if it has children itself)
+fn get_last_child_id(node_id: NodeId, dom: &Dom) -> Option<NodeId> {
+    let last_child = dom.children.as_ref().last()?;
+    Some(node_id + (dom.estimated_total_children - last_child.estimated_total_children))
+}
+
 fn convert_dom_into_compact_dom(mut dom: Dom) -> CompactDom {
     // note: somehow convert this into a non-recursive form later on!
     fn convert_dom_into_compact_dom_internal(
@@ -1744,7 +1978,6 @@ fn convert_dom_into_compact_dom(mut dom: Dom) -> CompactDom {
         for (child_index, child_dom) in dom.children.as_mut().iter_mut().enumerate() {
             let child_node_id = NodeId::new(*cur_node_id);
             let is_last_child = (child_index + 1) == children_len;
-            let child_dom_is_empty = child_dom.children.is_empty();
             let child_node = Node {
                 parent: Some(parent_node_id),
                 previous_sibling: previous_sibling_id,
@@ -1753,11 +1986,7 @@ fn convert_dom_into_compact_dom(mut dom: Dom) -> CompactDom {
                 } else {
                     Some(child_node_id + child_dom.estimated_total_children + 1)
                 },
-                last_child: if child_dom_is_empty {
-                    None
-                } else {
-                    Some(child_node_id + child_dom.estimated_total_children)
-                },
+                last_child: get_last_child_id(child_node_id, child_dom),
             };
             previous_sibling_id = Some(child_node_id);
             // recurse BEFORE adding the next child
@@ -1786,11 +2015,7 @@ fn convert_dom_into_compact_dom(mut dom: Dom) -> CompactDom {
         parent: None,
         previous_sibling: None,
         next_sibling: None,
-        last_child: if dom.children.is_empty() {
-            None
-        } else {
-            Some(root_node_id + dom.estimated_total_children)
-        },
+        last_child: get_last_child_id(root_node_id, &dom),
     };
 
     convert_dom_into_compact_dom_internal(
@@ -30,6 +30,8 @@ pub mod css;
 pub mod display_list;
 /// `Dom` construction, `NodeData` and `NodeType` management functions
 pub mod dom;
+/// In-window rendering of context menus and menu bars (for platforms without native menus)
+pub mod menu_overlay;
 // Algorithms to create git-like diffs between two doms in linear time
 // pub mod diff;
 /// Contains OpenGL helper functions (to compile / link shaders), `VirtualGlDriver` for unit testing
@@ -0,0 +1,815 @@
+//! In-window menus for platforms without native menus (X11)
+//!
+//! Context menus and the drop-downs of the window menu bar are rendered
+//! as absolutely positioned popups on top of the regular DOM. The popups
+//! are laid out with fixed item sizes, so hit-testing and keyboard
+//! navigation can be done here without going through the layout solver.
+
+use crate::{
+    callbacks::DomNodeId,
+    dom::Dom,
+    id_tree::NodeId,
+    styled_dom::{DomId, NodeHierarchyItemId, StyledDom},
+    window::{
+        LogicalPosition, LogicalRect, LogicalSize, Menu, MenuCallback, MenuItem, MenuItemIcon,
+        MenuItemState, MenuItemVec, MenuPopupPosition, StringMenuItem, VirtualKeyCode,
+    },
+};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Height of a regular menu item
+pub const MENU_ITEM_HEIGHT: f32 = 22.0;
+/// Height of a separator line (including the padding)
+pub const MENU_SEPARATOR_HEIGHT: f32 = 7.0;
+/// Height of the in-window menu bar
+pub const MENU_BAR_HEIGHT: f32 = 20.0;
+
+/// Approximate advance of one character of the 12px menu font,
+/// used to size the popups without running the text layout
+const MENU_CHAR_WIDTH: f32 = 7.0;
+const MENU_MIN_WIDTH: f32 = 120.0;
+const MENU_ICON_COLUMN: f32 = 24.0;
+const MENU_ARROW_COLUMN: f32 = 20.0;
+const MENU_ACCELERATOR_GAP: f32 = 24.0;
+const MENU_BAR_ITEM_PADDING: f32 = 8.0;
+const MENU_BORDER: f32 = 1.0;
+
+const MENU_FONT: &str = "font-family:sans-serif;font-size:12px;";
+const MENU_SELECTED_BACKGROUND: &str = "#0078d7";
+const MENU_GREYED_COLOR: &str = "#a0a0a0";
+
+/// Splits a label such as `"&File"` into the display text (`"File"`)
+/// and the lowercase mnemonic character (`'f'`). `"&&"` is an escaped `&`.
+pub fn parse_mnemonic(label: &str) -> (String, Option<char>) {
+    let mut text = String::with_capacity(label.len());
+    let mut mnemonic = None;
+    let mut chars = label.chars();
+    while let Some(c) = chars.next() {
+        if c != '&' {
+            text.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('&') => text.push('&'),
+            Some(m) => {
+                if mnemonic.is_none() {
+                    mnemonic = m.to_lowercase().next();
+                }
+                text.push(m);
+            }
+            None => {}
+        }
+    }
+    (text, mnemonic)
+}
+
+/// Mnemonic of the item: either the explicit `&` marker
+/// or the first alphanumeric character of the label
+fn get_mnemonic(item: &StringMenuItem) -> Option<char> {
+    let (text, mnemonic) = parse_mnemonic(item.label.as_str());
+    mnemonic.or_else(|| {
+        text.chars()
+            .find(|c| c.is_alphanumeric())
+            .and_then(|c| c.to_lowercase().next())
+    })
+}
+
+fn text_width(text: &str) -> f32 {
+    text.chars().count() as f32 * MENU_CHAR_WIDTH
+}
+
+fn item_height(item: &MenuItem) -> f32 {
+    match item {
+        MenuItem::String(_) => MENU_ITEM_HEIGHT,
+        MenuItem::Separator => MENU_SEPARATOR_HEIGHT,
+        MenuItem::BreakLine => 0.0,
+    }
+}
+
+/// Only normal (not greyed or disabled) string items can be selected and activated
+fn is_selectable(item: &MenuItem) -> bool {
+    match item {
+        MenuItem::String(s) => s.state == MenuItemState::Normal,
+        _ => false,
+    }
+}
+
+fn has_submenu(item: &MenuItem) -> bool {
+    match item {
+        MenuItem::String(s) => !s.children.as_ref().is_empty(),
+        _ => false,
+    }
+}
+
+fn popup_size(items: &MenuItemVec) -> LogicalSize {
+    let mut label_width = 0.0_f32;
+    let mut accelerator_width = 0.0_f32;
+    let mut height = 2.0 * MENU_BORDER;
+
+    for item in items.as_ref().iter() {
+        height += item_height(item);
+        if let MenuItem::String(s) = item {
+            let (text, _) = parse_mnemonic(s.label.as_str());
+            label_width = label_width.max(text_width(&text));
+            if let Some(a) = s.accelerator.as_ref() {
+                accelerator_width = accelerator_width
+                    .max(text_width(&a.to_display_string()) + MENU_ACCELERATOR_GAP);
+            }
+        }
+    }
+
+    let width = (MENU_ICON_COLUMN + label_width + accelerator_width + MENU_ARROW_COLUMN)
+        .max(MENU_MIN_WIDTH)
+        + 2.0 * MENU_BORDER;
+
+    LogicalSize::new(width, height)
+}
+
+/// Returns the (item index, rect) of every string item in the in-window menu bar
+pub fn menu_bar_item_rects(menu_bar: &Menu) -> Vec<(usize, LogicalRect)> {
+    let mut x = 0.0;
+    let mut rects = Vec::new();
+    for (index, item) in menu_bar.items.as_ref().iter().enumerate() {
+        match item {
+            MenuItem::String(s) => {
+                let (text, _) = parse_mnemonic(s.label.as_str());
+                let width = text_width(&text) + 2.0 * MENU_BAR_ITEM_PADDING;
+                rects.push((
+                    index,
+                    LogicalRect::new(
+                        LogicalPosition::new(x, 0.0),
+                        LogicalSize::new(width, MENU_BAR_HEIGHT),
+                    ),
+                ));
+                x += width;
+            }
+            MenuItem::Separator => x += 1.0,
+            MenuItem::BreakLine => {}
+        }
+    }
+    rects
+}
+
+/// Wraps the `StyledDom` in a container with the menu bar
+/// of the root node on top (if the root node has a menu bar)
+pub fn inject_window_menu_bar(styled_dom: StyledDom, active_item: Option<usize>) -> StyledDom {
+    let menu_bar = styled_dom
+        .node_data
+        .as_container()
+        .get_extended_lifetime(NodeId::ZERO)
+        .and_then(|node| node.get_menu_bar())
+        .map(|mb| (**mb).clone());
+
+    match menu_bar {
+        Some(mb) => styled_dom.inject_menu_bar(&mb, active_item),
+        None => styled_dom,
+    }
+}
+
+/// Returns the index of the first menu bar item with a sub-menu
+/// and the given mnemonic (or any mnemonic if `character` is None)
+pub fn menu_bar_index_for_mnemonic(menu_bar: &Menu, character: Option<char>) -> Option<usize> {
+    let character = character.and_then(|c| c.to_lowercase().next());
+    menu_bar
+        .items
+        .as_ref()
+        .iter()
+        .enumerate()
+        .find(|(_, item)| match item {
+            MenuItem::String(s) => {
+                is_selectable(item)
+                    && has_submenu(item)
+                    && character.map(|c| get_mnemonic(s) == Some(c)).unwrap_or(true)
+            }
+            _ => false,
+        })
+        .map(|(index, _)| index)
+}
+
+/// Returns the index of the menu bar item under the cursor
+pub fn menu_bar_index_at(menu_bar: &Menu, position: LogicalPosition) -> Option<usize> {
+    menu_bar_item_rects(menu_bar)
+        .into_iter()
+        .find(|(_, rect)| rect.hit_test(&position).is_some())
+        .map(|(index, _)| index)
+}
+
+/// Renders the in-window menu bar, highlighting the item whose drop-down is open
+pub fn menu_bar_dom(menu_bar: &Menu, active_item: Option<usize>) -> Dom {
+    let rects = menu_bar_item_rects(menu_bar);
+
+    menu_bar
+        .items
+        .as_ref()
+        .iter()
+        .enumerate()
+        .filter_map(|(index, item)| match item {
+            MenuItem::String(s) => {
+                let (text, _) = parse_mnemonic(s.label.as_str());
+                let width = rects
+                    .iter()
+                    .find(|(i, _)| *i == index)
+                    .map(|(_, r)| r.size.width)
+                    .unwrap_or(0.0);
+                let (background, color) = if active_item == Some(index) {
+                    (MENU_SELECTED_BACKGROUND, "white")
+                } else if s.state == MenuItemState::Greyed {
+                    ("transparent", MENU_GREYED_COLOR)
+                } else {
+                    ("transparent", "black")
+                };
+                let item_style = format!(
+                    "width:{}px;height:{}px;padding-left:{}px;display:flex;flex-direction:row;align-items:center;background:{};",
+                    width, MENU_BAR_HEIGHT, MENU_BAR_ITEM_PADDING, background
+                );
+                let text_style = format!("{}color:{};", MENU_FONT, color);
+                Some(
+                    Dom::div()
+                        .with_inline_style(&item_style)
+                        .with_child(Dom::text(text).with_inline_style(&text_style)),
+                )
+            }
+            MenuItem::Separator => {
+                Some(Dom::div().with_inline_style("width:1px;background:grey;"))
+            }
+            MenuItem::BreakLine => None,
+        })
+        .collect::<Dom>()
+        .with_inline_style(&format!(
+            "height:{}px;display:flex;flex-direction:row;background:#f0f0f0;",
+            MENU_BAR_HEIGHT
+        ))
+}
+
+/// A single open (sub-)menu popup
+#[derive(Debug, Clone, PartialEq)]
+pub struct MenuPopup {
+    /// Items of this popup (cloned from the `Menu`)
+    pub items: MenuItemVec,
+    /// Position and size of the popup, relative to the window
+    pub rect: LogicalRect,
+    /// Currently highlighted item
+    pub selected: Option<usize>,
+}
+
+impl MenuPopup {
+    /// Creates a popup at `origin`, moved so that it doesn't overflow the window
+    fn new(items: MenuItemVec, origin: LogicalPosition, window_size: LogicalSize) -> Self {
+        let size = popup_size(&items);
+        let x = origin.x.min(window_size.width - size.width).max(0.0);
+        let y = origin.y.min(window_size.height - size.height).max(0.0);
+        Self {
+            items,
+            rect: LogicalRect::new(LogicalPosition::new(x, y), size),
+            selected: None,
+        }
+    }
+
+    /// Returns the rect of the item at `index`
+    pub fn item_rect(&self, index: usize) -> Option<LogicalRect> {
+        let mut y = self.rect.origin.y + MENU_BORDER;
+        for (i, item) in self.items.as_ref().iter().enumerate() {
+            let height = item_height(item);
+            if i == index {
+                return Some(LogicalRect::new(
+                    LogicalPosition::new(self.rect.origin.x + MENU_BORDER, y),
+                    LogicalSize::new(self.rect.size.width - 2.0 * MENU_BORDER, height),
+                ));
+            }
+            y += height;
+        }
+        None
+    }
+
+    /// Returns the index of the item under the cursor
+    pub fn item_at(&self, position: LogicalPosition) -> Option<usize> {
+        (0..self.items.len()).find(|i| {
+            self.item_rect(*i)
+                .map(|r| r.hit_test(&position).is_some())
+                .unwrap_or(false)
+        })
+    }
+
+    /// Next (or previous) selectable item after the current selection, wrapping around
+    fn next_selectable(&self, forward: bool) -> Option<usize> {
+        let items = self.items.as_ref();
+        let len = items.len();
+        for step in 1..=len {
+            let index = match (self.selected, forward) {
+                (Some(s), true) => (s + step) % len,
+                (Some(s), false) => (s + len - (step % len)) % len,
+                (None, true) => step - 1,
+                (None, false) => len - step,
+            };
+            if is_selectable(&items[index]) {
+                return Some(index);
+            }
+        }
+        None
+    }
+
+    fn dom(&self) -> Dom {
+        let items = self.items.as_ref().iter().enumerate().filter_map(|(index, item)| {
+            match item {
+                MenuItem::String(s) => Some(self.string_item_dom(index, s)),
+                MenuItem::Separator => Some(
+                    Dom::div()
+                        .with_inline_style(&format!("height:{}px;", MENU_SEPARATOR_HEIGHT))
+                        .with_child(
+                            Dom::div()
+                                .with_inline_style("height:1px;margin-top:3px;background:#d0d0d0;"),
+                        ),
+                ),
+                MenuItem::BreakLine => None,
+            }
+        });
+
+        items.collect::<Dom>().with_inline_style(&format!(
+            "position:absolute;top:{}px;left:{}px;width:{}px;height:{}px;box-sizing:border-box;\
+             display:flex;flex-direction:column;background:white;border:{}px solid #a0a0a0;",
+            self.rect.origin.y,
+            self.rect.origin.x,
+            self.rect.size.width,
+            self.rect.size.height,
+            MENU_BORDER,
+        ))
+    }
+
+    fn string_item_dom(&self, index: usize, item: &StringMenuItem) -> Dom {
+        let (text, _) = parse_mnemonic(item.label.as_str());
+        let selected = self.selected == Some(index);
+        let color = if selected {
+            "white"
+        } else if item.state == MenuItemState::Greyed {
+            MENU_GREYED_COLOR
+        } else {
+            "black"
+        };
+        let text_style = format!("{}color:{};", MENU_FONT, color);
+
+        let icon = match item.icon.as_ref() {
+            Some(MenuItemIcon::Checkbox(true)) => Dom::text("\u{2713}").with_inline_style(&text_style),
+            Some(MenuItemIcon::Image(image)) => {
+                Dom::image(image.clone()).with_inline_style("width:16px;height:16px;")
+            }
+            Some(MenuItemIcon::Checkbox(false)) | None => Dom::div(),
+        };
+
+        let accelerator = match item.accelerator.as_ref() {
+            Some(a) => Dom::text(a.to_display_string()).with_inline_style(&text_style),
+            None => Dom::div(),
+        };
+
+        let arrow = if item.children.as_ref().is_empty() {
+            Dom::div()
+        } else {
+            Dom::text("\u{25B8}").with_inline_style(&text_style)
+        };
+
+        let background = if selected {
+            MENU_SELECTED_BACKGROUND
+        } else {
+            "transparent"
+        };
+
+        Dom::div()
+            .with_inline_style(&format!(
+                "height:{}px;display:flex;flex-direction:row;align-items:center;background:{};",
+                MENU_ITEM_HEIGHT, background
+            ))
+            .with_children(
+                vec![
+                    Dom::div()
+                        .with_inline_style(&format!(
+                            "width:{}px;display:flex;align-items:center;justify-content:center;",
+                            MENU_ICON_COLUMN
+                        ))
+                        .with_child(icon),
+                    Dom::text(text).with_inline_style(&format!("{}flex-grow:1;", text_style)),
+                    accelerator,
+                    Dom::div()
+                        .with_inline_style(&format!(
+                            "width:{}px;display:flex;align-items:center;justify-content:center;",
+                            MENU_ARROW_COLUMN
+                        ))
+                        .with_child(arrow),
+                ]
+                .into(),
+            )
+    }
+}
+
+/// Where the menu overlay was opened from
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum MenuOverlaySource {
+    /// Context menu of a DOM node
+    ContextMenu,
+    /// Drop-down of the menu bar item at the given index
+    MenuBar(usize),
+}
+
+/// What the windowing system has to do after an event was sent to the `MenuOverlay`
+#[derive(Debug, Clone, PartialEq)]
+pub enum MenuOverlayEvent {
+    /// Event was consumed, nothing changed
+    DoNothing,
+    /// Selection changed or a sub-menu was opened / closed: regenerate the DOM
+    Redraw,
+    /// The menu was dismissed without activating an item
+    Close,
+    /// An item was activated: close the menu, then invoke the
+    /// callback via `WindowInternal::invoke_menu_callback()`
+    Invoke(MenuCallback, DomNodeId),
+}
+
+/// Open in-window context menu or menu bar drop-down, including all open sub-menus
+#[derive(Debug, Clone, PartialEq)]
+pub struct MenuOverlay {
+    /// Where the menu was opened from
+    pub source: MenuOverlaySource,
+    /// Node that the menu callbacks are invoked on
+    pub hit_node: DomNodeId,
+    /// Open popups, from the root menu to the deepest sub-menu
+    pub popups: Vec<MenuPopup>,
+    /// Window menu bar, used to switch between the drop-downs
+    menu_bar: Option<Menu>,
+    window_size: LogicalSize,
+}
+
+impl MenuOverlay {
+    /// Opens a context menu, positioned according to `menu.position`
+    pub fn context_menu(
+        menu: &Menu,
+        hit_node: DomNodeId,
+        cursor: LogicalPosition,
+        hit_rect: LogicalRect,
+        window_size: LogicalSize,
+    ) -> Self {
+        use self::MenuPopupPosition::*;
+
+        let size = popup_size(&menu.items);
+        let (w, h) = (size.width, size.height);
+
+        let (x, y) = match menu.position {
+            BottomRightOfCursor => (cursor.x, cursor.y),
+            BottomLeftOfCursor => (cursor.x - w, cursor.y),
+            TopLeftOfCursor => (cursor.x - w, cursor.y - h),
+            TopRightOfCursor => (cursor.x, cursor.y - h),
+            BottomOfHitRect => (hit_rect.min_x(), hit_rect.max_y()),
+            LeftOfHitRect => (hit_rect.min_x() - w, hit_rect.min_y()),
+            TopOfHitRect => (hit_rect.min_x(), hit_rect.min_y() - h),
+            RightOfHitRect => (hit_rect.max_x(), hit_rect.min_y()),
+            AutoCursor => {
+                let x = if cursor.x + w > window_size.width {
+                    cursor.x - w
+                } else {
+                    cursor.x
+                };
+                let y = if cursor.y + h > window_size.height {
+                    cursor.y - h
+                } else {
+                    cursor.y
+                };
+                (x, y)
+            }
+            AutoHitRect => {
+                let y = if hit_rect.max_y() + h > window_size.height {
+                    hit_rect.min_y() - h
+                } else {
+                    hit_rect.max_y()
+                };
+                (hit_rect.min_x(), y)
+            }
+        };
+
+        Self {
+            source: MenuOverlaySource::ContextMenu,
+            hit_node,
+            popups: vec![MenuPopup::new(
+                menu.items.clone(),
+                LogicalPosition::new(x, y),
+                window_size,
+            )],
+            menu_bar: None,
+            window_size,
+        }
+    }
+
+    /// Opens the drop-down of the menu bar item at `index`,
+    /// returns None if the item has no sub-menu
+    pub fn menu_bar(menu_bar: &Menu, index: usize, window_size: LogicalSize) -> Option<Self> {
+        let item = match menu_bar.items.as_ref().get(index)? {
+            MenuItem::String(s) if !s.children.as_ref().is_empty() => s,
+            _ => return None,
+        };
+
+        let (_, rect) = menu_bar_item_rects(menu_bar)
+            .into_iter()
+            .find(|(i, _)| *i == index)?;
+
+        Some(Self {
+            source: MenuOverlaySource::MenuBar(index),
+            hit_node: DomNodeId {
+                dom: DomId::ROOT_ID,
+                node: NodeHierarchyItemId::from_crate_internal(None),
+            },
+            popups: vec![MenuPopup::new(
+                item.children.clone(),
+                LogicalPosition::new(rect.min_x(), rect.max_y()),
+                window_size,
+            )],
+            menu_bar: Some(menu_bar.clone()),
+            window_size,
+        })
+    }
+
+    /// Highlights the first selectable item of the root popup (used
+    /// when the menu was opened with the keyboard)
+    pub fn select_first_item(&mut self) {
+        if let Some(root) = self.popups.first_mut() {
+            root.selected = root.next_selectable(true);
+        }
+    }
+
+    /// Index of the menu bar item whose drop-down is open
+    pub fn get_menu_bar_index(&self) -> Option<usize> {
+        match self.source {
+            MenuOverlaySource::MenuBar(i) => Some(i),
+            MenuOverlaySource::ContextMenu => None,
+        }
+    }
+
+    /// Renders all open popups
+    pub fn dom(&self) -> Dom {
+        self.popups
+            .iter()
+            .map(|p| p.dom())
+            .collect::<Dom>()
+            .with_inline_style("position:absolute;top:0px;left:0px;")
+    }
+
+    pub fn on_mouse_move(&mut self, position: LogicalPosition) -> MenuOverlayEvent {
+        for depth in (0..self.popups.len()).rev() {
+            if let Some(index) = self.popups[depth].item_at(position) {
+                let selected = if is_selectable(&self.popups[depth].items.as_ref()[index]) {
+                    Some(index)
+                } else {
+                    None
+                };
+                if self.popups[depth].selected == selected {
+                    return MenuOverlayEvent::DoNothing;
+                }
+                self.popups[depth].selected = selected;
+                self.popups.truncate(depth + 1);
+                self.open_submenu(depth);
+                return MenuOverlayEvent::Redraw;
+            }
+            if self.popups[depth].rect.hit_test(&position).is_some() {
+                return MenuOverlayEvent::DoNothing;
+            }
+        }
+
+        // hovering over another menu bar item switches the drop-down
+        if let MenuOverlaySource::MenuBar(current) = self.source {
+            let hovered = self
+                .menu_bar
+                .as_ref()
+                .and_then(|mb| menu_bar_index_at(mb, position));
+            if let Some(index) = hovered {
+                if index != current && self.switch_menu_bar_item(index, false) {
+                    return MenuOverlayEvent::Redraw;
+                }
+            }
+        }
+
+        MenuOverlayEvent::DoNothing
+    }
+
+    pub fn on_mouse_down(&mut self, position: LogicalPosition) -> MenuOverlayEvent {
+        for depth in (0..self.popups.len()).rev() {
+            if let Some(index) = self.popups[depth].item_at(position) {
+                return self.activate(depth, index);
+            }
+            if self.popups[depth].rect.hit_test(&position).is_some() {
+                return MenuOverlayEvent::DoNothing;
+            }
+        }
+
+        if let MenuOverlaySource::MenuBar(current) = self.source {
+            let clicked = self
+                .menu_bar
+                .as_ref()
+                .and_then(|mb| menu_bar_index_at(mb, position));
+            if let Some(index) = clicked {
+                if index != current && self.switch_menu_bar_item(index, false) {
+                    return MenuOverlayEvent::Redraw;
+                }
+            }
+        }
+
+        MenuOverlayEvent::Close
+    }
+
+    /// Keyboard navigation: arrow keys, Home / End, Enter / Space,
+    /// Escape and mnemonics (`character` = the typed character)
+    pub fn on_key_down(
+        &mut self,
+        key: Option<VirtualKeyCode>,
+        character: Option<char>,
+    ) -> MenuOverlayEvent {
+        use self::VirtualKeyCode::*;
+
+        let depth = match self.popups.len().checked_sub(1) {
+            Some(s) => s,
+            None => return MenuOverlayEvent::Close,
+        };
+
+        match key {
+            Some(Escape) => {
+                if depth > 0 {
+                    self.popups.pop();
+                    MenuOverlayEvent::Redraw
+                } else {
+                    MenuOverlayEvent::Close
+                }
+            }
+            Some(Up) | Some(Down) => {
+                let next = self.popups[depth].next_selectable(key == Some(Down));
+                self.select(depth, next)
+            }
+            Some(Home) | Some(End) => {
+                let popup = &self.popups[depth];
+                let next = MenuPopup {
+                    selected: None,
+                    ..popup.clone()
+                }
+                .next_selectable(key == Some(Home));
+                self.select(depth, next)
+            }
+            Some(Right) => {
+                let selected = self.popups[depth].selected;
+                match selected {
+                    Some(s) if has_submenu(&self.popups[depth].items.as_ref()[s]) => {
+                        self.activate(depth, s)
+                    }
+                    _ => self.switch_menu_bar_neighbour(true),
+                }
+            }
+            Some(Left) => {
+                if depth > 0 {
+                    self.popups.pop();
+                    MenuOverlayEvent::Redraw
+                } else {
+                    self.switch_menu_bar_neighbour(false)
+                }
+            }
+            Some(Return) | Some(NumpadEnter) | Some(Space) => match self.popups[depth].selected {
+                Some(s) => self.activate(depth, s),
+                None => MenuOverlayEvent::DoNothing,
+            },
+            _ => match character {
+                Some(c) => self.on_mnemonic(depth, c),
+                None => MenuOverlayEvent::DoNothing,
+            },
+        }
+    }
+
+    fn select(&mut self, depth: usize, index: Option<usize>) -> MenuOverlayEvent {
+        if index.is_none() || self.popups[depth].selected == index {
+            return MenuOverlayEvent::DoNothing;
+        }
+        self.popups[depth].selected = index;
+        self.popups.truncate(depth + 1);
+        MenuOverlayEvent::Redraw
+    }
+
+    fn on_mnemonic(&mut self, depth: usize, character: char) -> MenuOverlayEvent {
+        let character = match character.to_lowercase().next() {
+            Some(c) => c,
+            None => return MenuOverlayEvent::DoNothing,
+        };
+
+        let selected = self.popups[depth].selected;
+        let matches = self.popups[depth]
+            .items
+            .as_ref()
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| match item {
+                MenuItem::String(s)
+                    if is_selectable(item) && get_mnemonic(s) == Some(character) =>
+                {
+                    Some(i)
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        match matches.as_slice() {
+            [] => MenuOverlayEvent::DoNothing,
+            [single] => self.activate(depth, *single),
+            multiple => {
+                // several items share the mnemonic: cycle through them
+                let next = multiple
+                    .iter()
+                    .copied()
+                    .find(|i| selected.map(|s| *i > s).unwrap_or(true))
+                    .unwrap_or(multiple[0]);
+                self.select(depth, Some(next))
+            }
+        }
+    }
+
+    /// Activates the item: opens its sub-menu or returns its callback
+    fn activate(&mut self, depth: usize, index: usize) -> MenuOverlayEvent {
+        let item = match self.popups[depth].items.as_ref().get(index) {
+            Some(MenuItem::String(s)) if s.state == MenuItemState::Normal => s.clone(),
+            _ => return MenuOverlayEvent::DoNothing,
+        };
+
+        self.popups[depth].selected = Some(index);
+        self.popups.truncate(depth + 1);
+
+        if !item.children.as_ref().is_empty() {
+            self.open_submenu(depth);
+            if let Some(submenu) = self.popups.get_mut(depth + 1) {
+                submenu.selected = submenu.next_selectable(true);
+            }
+            return MenuOverlayEvent::Redraw;
+        }
+
+        match item.callback.into_option() {
+            Some(cb) => MenuOverlayEvent::Invoke(cb, self.hit_node),
+            None => MenuOverlayEvent::Close,
+        }
+    }
+
+    /// Opens the sub-menu of the selected item of the popup at `depth`
+    /// to the right of the item (or to the left if there isn't enough space)
+    fn open_submenu(&mut self, depth: usize) {
+        let popup = &self.popups[depth];
+        let index = match popup.selected {
+            Some(s) => s,
+            None => return,
+        };
+        let children = match popup.items.as_ref().get(index) {
+            Some(MenuItem::String(s)) if !s.children.as_ref().is_empty() => s.children.clone(),
+            _ => return,
+        };
+        let item_rect = match popup.item_rect(index) {
+            Some(s) => s,
+            None => return,
+        };
+
+        let width = popup_size(&children).width;
+        let x = if popup.rect.max_x() + width > self.window_size.width {
+            popup.rect.min_x() - width
+        } else {
+            popup.rect.max_x()
+        };
+        let origin = LogicalPosition::new(x, item_rect.min_y() - MENU_BORDER);
+        let submenu = MenuPopup::new(children, origin, self.window_size);
+        self.popups.push(submenu);
+    }
+
+    fn switch_menu_bar_item(&mut self, index: usize, select_first: bool) -> bool {
+        let new = match self.menu_bar.as_ref() {
+            Some(mb) => MenuOverlay::menu_bar(mb, index, self.window_size),
+            None => None,
+        };
+        match new {
+            Some(mut new) => {
+                if select_first {
+                    new.select_first_item();
+                }
+                *self = new;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Left / Right on the root drop-down of a menu bar switches to the neighbouring menu
+    fn switch_menu_bar_neighbour(&mut self, forward: bool) -> MenuOverlayEvent {
+        let current = match self.source {
+            MenuOverlaySource::MenuBar(i) => i,
+            MenuOverlaySource::ContextMenu => return MenuOverlayEvent::DoNothing,
+        };
+        let len = match self.menu_bar.as_ref() {
+            Some(mb) => mb.items.len(),
+            None => return MenuOverlayEvent::DoNothing,
+        };
+        for step in 1..len {
+            let index = if forward {
+                (current + step) % len
+            } else {
+                (current + len - step) % len
+            };
+            if self.switch_menu_bar_item(index, true) {
+                return MenuOverlayEvent::Redraw;
+            }
+        }
+        MenuOverlayEvent::DoNothing
+    }
+}
@@ -2,15 +2,16 @@ use crate::{
     app_resources::{Au, ImageCache, ImageRef, ImmediateFontId, RendererResources},
     callbacks::{CallbackInfo, RefAny, Update},
     dom::{
-        CompactDom, Dom, NodeData, NodeDataInlineCssProperty, NodeDataVec, OptionTabIndex,
-        TabIndex, TagId,
+        CompactDom, Dom, NodeData, NodeDataInlineCssProperty, NodeDataVec, NodeType,
+        OptionTabIndex, TabIndex, TagId, Tooltip,
     },
+    menu_overlay::MenuOverlay,
     id_tree::{Node, NodeDataContainer, NodeDataContainerRef, NodeDataContainerRefMut, NodeId},
     style::{
         construct_html_cascade_tree, matches_html_element, rule_ends_with, CascadeInfo,
         CascadeInfoVec,
     },
-    window::Menu,
+    window::{LogicalPosition, Menu},
     FastBTreeSet, FastHashMap,
 };
 use alloc::boxed::Box;
@@ -18,7 +19,7 @@ use alloc::collections::btree_map::BTreeMap;
 use alloc::string::String;
 use alloc::vec::Vec;
 use azul_css::{
-    AzString, Css, CssPath, CssProperty, CssPropertyType, LayoutAlignContentValue,
+    AzString, Css, CssFontFace, CssPath, CssProperty, CssPropertyType, LayoutAlignContentValue,
     LayoutAlignItemsValue, LayoutBorderBottomWidthValue, LayoutBorderLeftWidthValue,
     LayoutBorderRightWidthValue, LayoutBorderTopWidthValue, LayoutBottomValue,
     LayoutBoxSizingValue, LayoutDisplayValue, LayoutFlexDirectionValue, LayoutFlexGrowValue,
@@ -27,7 +28,8 @@ use azul_css::{
     LayoutMarginRightValue, LayoutMarginTopValue, LayoutMaxHeightValue, LayoutMaxWidthValue,
     LayoutMinHeightValue, LayoutMinWidthValue, LayoutOverflowValue, LayoutPaddingBottomValue,
     LayoutPaddingLeftValue, LayoutPaddingRightValue, LayoutPaddingTopValue, LayoutPositionValue,
-    LayoutRightValue, LayoutTopValue, LayoutWidthValue, StyleBackfaceVisibilityValue,
+    LayoutRightValue, LayoutTopValue, LayoutVerticalAlignValue, LayoutWidthValue,
+    StyleBackfaceVisibilityValue,
     StyleBackgroundContentVecValue, StyleBackgroundPositionVecValue, StyleBackgroundRepeatVecValue,
     StyleBackgroundSizeVecValue, StyleBorderBottomColorValue, StyleBorderBottomLeftRadiusValue,
     StyleBorderBottomRightRadiusValue, StyleBorderBottomStyleValue, StyleBorderLeftColorValue,
@@ -37,7 +39,7 @@ use azul_css::{
     StyleFontFamily, StyleFontFamilyVec, StyleFontFamilyVecValue, StyleFontSize,
     StyleFontSizeValue, StyleLetterSpacingValue, StyleLineHeightValue, StyleMixBlendModeValue,
     StyleOpacityValue, StylePerspectiveOriginValue, StyleTabWidthValue, StyleTextAlignValue,
-    StyleTextColor, StyleTextColorValue, StyleTransformOriginValue, StyleTransformVecValue,
+    StyleTextColor, StyleTextColorValue, StyleTextDecorationValue, StyleTransformOriginValue, StyleTransformVecValue,
     StyleWordSpacingValue,
 };
 use azul_css_parser::CssApiWrapper;
@@ -211,6 +213,9 @@ pub struct CssPropertyCache {
     pub css_hover_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
     pub css_active_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
     pub css_focus_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
+
+    // @font-face rules of the CSS, used to select the faces of a text by their unicode-range
+    pub font_faces: Vec<CssFontFace>,
 }
 
 impl CssPropertyCache {
@@ -229,6 +234,7 @@ impl CssPropertyCache {
         use azul_css::LayoutDisplay;
 
         let css_is_empty = css.is_empty();
+        self.font_faces = css.font_faces.as_ref().to_vec();
 
         if !css_is_empty {
             css.sort_by_specificity();
@@ -638,6 +644,9 @@ impl CssPropertyCache {
         if let Some(p) = self.get_text_align(&node_data, node_id, node_state) {
             s.push_str(&format!("text-align: {};", p.get_css_value_fmt()));
         }
+        if let Some(p) = self.get_text_decoration(&node_data, node_id, node_state) {
+            s.push_str(&format!("text-decoration: {};", p.get_css_value_fmt()));
+        }
         if let Some(p) = self.get_line_height(&node_data, node_id, node_state) {
             s.push_str(&format!("line-height: {};", p.get_css_value_fmt()));
         }
@@ -743,6 +752,9 @@ impl CssPropertyCache {
         if let Some(p) = self.get_float(&node_data, node_id, node_state) {
             s.push_str(&format!("float: {};", p.get_css_value_fmt()));
         }
+        if let Some(p) = self.get_vertical_align(&node_data, node_id, node_state) {
+            s.push_str(&format!("vertical-align: {};", p.get_css_value_fmt()));
+        }
         if let Some(p) = self.get_box_sizing(&node_data, node_id, node_state) {
             s.push_str(&format!("box-sizing: {};", p.get_css_value_fmt()));
         }
@@ -901,6 +913,8 @@ impl CssPropertyCache {
             css_hover_props: BTreeMap::new(),
             css_active_props: BTreeMap::new(),
             css_focus_props: BTreeMap::new(),
+
+            font_faces: Vec::new(),
         }
     }
 
@@ -926,6 +940,12 @@ impl CssPropertyCache {
         append_css_property_vec!(css_active_props);
         append_css_property_vec!(css_focus_props);
 
+        for font_face in other.font_faces.drain(..) {
+            if !self.font_faces.contains(&font_face) {
+                self.font_faces.push(font_face);
+            }
+        }
+
         self.node_count += other.node_count;
     }
 
@@ -1003,10 +1023,55 @@ impl CssPropertyCache {
         .into();
         let font_family_opt = self.get_font_family(node_data, node_id, node_state);
 
-        font_family_opt
+        let font_families = font_family_opt
             .as_ref()
             .and_then(|family| Some(family.get_property()?.clone()))
-            .unwrap_or(default_font_id)
+            .unwrap_or(default_font_id);
+
+        match node_data.get_node_type() {
+            NodeType::Text(text) if !self.font_faces.is_empty() => {
+                self.filter_font_faces_by_unicode_range(font_families, text.as_str())
+            }
+            _ => font_families,
+        }
+    }
+
+    /// Removes the sources of `@font-face` rules whose `unicode-range` doesn't cover any
+    /// character of the text, so that i.e. a cyrillic subset isn't loaded for a latin text
+    fn filter_font_faces_by_unicode_range(
+        &self,
+        font_families: StyleFontFamilyVec,
+        text: &str,
+    ) -> StyleFontFamilyVec {
+        use azul_css::CssFontFaceSource;
+
+        let covers_text = |family: &StyleFontFamily| {
+            let mut faces = self
+                .font_faces
+                .iter()
+                .filter(|face| {
+                    face.src.iter().any(|source| match (source, family) {
+                        (CssFontFaceSource::Url(u), StyleFontFamily::File(f)) => u.url == *f,
+                        (CssFontFaceSource::Local(l), StyleFontFamily::System(f)) => l == f,
+                        _ => false,
+                    })
+                })
+                .peekable();
+
+            // family is not the source of any @font-face rule
+            if faces.peek().is_none() {
+                return true;
+            }
+
+            faces.any(|face| text.chars().any(|c| face.covers(c)))
+        };
+
+        font_families
+            .iter()
+            .filter(|f| covers_text(f))
+            .cloned()
+            .collect::<Vec<_>>()
+            .into()
     }
 
     pub fn get_font_size_or_default(
@@ -1316,6 +1381,15 @@ impl CssPropertyCache {
         self.get_property(node_data, node_id, node_state, &CssPropertyType::TextAlign)
             .and_then(|p| p.as_text_align())
     }
+    pub fn get_text_decoration<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleTextDecorationValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::TextDecoration)
+            .and_then(|p| p.as_text_decoration())
+    }
     pub fn get_line_height<'a>(
         &'a self,
         node_data: &'a NodeData,
@@ -1673,6 +1747,15 @@ impl CssPropertyCache {
         self.get_property(node_data, node_id, node_state, &CssPropertyType::Float)
             .and_then(|p| p.as_float())
     }
+    pub fn get_vertical_align<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a LayoutVerticalAlignValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::VerticalAlign)
+            .and_then(|p| p.as_vertical_align())
+    }
     pub fn get_box_sizing<'a>(
         &'a self,
         node_data: &'a NodeData,
@@ -2757,39 +2840,64 @@ impl StyledDom {
         // *self = new_styled_dom;
     }
 
-    /// Inject a menu bar into the root component
-    pub fn inject_menu_bar(mut self, menu_bar: &Menu) -> Self {
-        use crate::window::MenuItem;
+    /// Inject a menu bar into the root component, `active_item` is
+    /// the menu bar item whose drop-down is currently open
+    pub fn inject_menu_bar(self, menu_bar: &Menu, active_item: Option<usize>) -> Self {
+        use crate::menu_overlay::menu_bar_dom;
         use azul_css_parser::CssApiWrapper;
 
-        let menu_dom = menu_bar
-            .items
-            .as_ref()
-            .iter()
-            .map(|mi| match mi {
-                MenuItem::String(smi) => Dom::text(smi.label.clone().into_library_owned_string())
-                    .with_inline_style("font-family:sans-serif;".into()),
-                MenuItem::Separator => {
-                    Dom::div().with_inline_style("padding:1px;background:grey;".into())
-                }
-                MenuItem::BreakLine => Dom::div(),
-            })
-            .collect::<Dom>()
-            .with_inline_style(
-                "
-            height:20px;
-            display:flex;
-            flex-direction:row;"
-                    .into(),
-            )
-            .style(CssApiWrapper::empty());
+        let menu_dom = menu_bar_dom(menu_bar, active_item).style(CssApiWrapper::empty());
 
-        let mut core_container = Dom::body().style(CssApiWrapper::empty());
+        // keep the menu bar on the new root, so that
+        // WindowInternal::get_menu_bar() still finds it
+        let mut body = Dom::body();
+        body.root.set_menu_bar(menu_bar.clone());
+
+        let mut core_container = body.style(CssApiWrapper::empty());
         core_container.append_child(menu_dom);
         core_container.append_child(self);
         core_container
     }
 
+    /// Appends the popups of an open in-window context menu / menu bar drop-down
+    pub fn inject_menu_overlay(&mut self, menu_overlay: &MenuOverlay) {
+        self.append_child(menu_overlay.dom().style(CssApiWrapper::empty()));
+    }
+
+    /// Appends the tooltip as an absolutely positioned overlay
+    /// next to the cursor position
+    pub fn inject_tooltip(&mut self, tooltip: &Tooltip, cursor_position: LogicalPosition) {
+        use crate::dom::TooltipContent;
+
+        const TOOLTIP_OFFSET_X: f32 = 12.0;
+        const TOOLTIP_OFFSET_Y: f32 = 20.0;
+
+        let content = match &tooltip.content {
+            TooltipContent::Text(t) => Dom::text(t.clone())
+                .with_inline_style("font-family:sans-serif;font-size:12px;color:black;"),
+            TooltipContent::Dom(d) => d.clone(),
+        };
+
+        let tooltip_style = format!(
+            "
+            position:absolute;
+            top:{}px;
+            left:{}px;
+            padding:2px 4px;
+            background:#ffffe1;
+            border:1px solid #767676;",
+            cursor_position.y + TOOLTIP_OFFSET_Y,
+            cursor_position.x + TOOLTIP_OFFSET_X,
+        );
+
+        let tooltip_dom = Dom::div()
+            .with_inline_style(&tooltip_style)
+            .with_child(content)
+            .style(CssApiWrapper::empty());
+
+        self.append_child(tooltip_dom);
+    }
+
     /// Same as `append_child()`, but as a builder method
     pub fn with_child(&mut self, other: Self) -> Self {
         let mut s = self.swap_with_default();
@@ -2797,6 +2905,18 @@ impl StyledDom {
         s
     }
 
+    /// Same as `Dom::make_inert`, but for an already styled DOM (i.e. the DOM
+    /// returned by an IFrame callback): the nodes keep their hit-testing tags
+    /// (so that :hover styles still apply), but can't be focused anymore
+    pub fn make_inert(&mut self) {
+        for node_data in self.node_data.as_mut().iter_mut() {
+            node_data.make_inert();
+        }
+        for tag_id_node_id in self.tag_ids_to_node_ids.as_mut().iter_mut() {
+            tag_id_node_id.tab_index = None.into();
+        }
+    }
+
     pub fn restyle(&mut self, mut css: CssApiWrapper) {
 
         let new_tag_ids = self.css_property_cache.downcast_mut().restyle(
@@ -3882,3 +4002,50 @@ fn recursive_get_last_child(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::dom::{Dom, IdOrClass};
+
+    #[test]
+    fn test_font_face_unicode_range() {
+        let css = azul_css_parser::new_from_str("
+            @font-face { font-family: F; src: url(latin.ttf); unicode-range: U+0000-00FF; }
+            @font-face { font-family: F; src: url(cyrillic.ttf); unicode-range: U+0400-04FF; }
+            @font-face { font-family: G; src: local(G); }
+            .t { font-family: f, G; }
+        ").unwrap();
+
+        let text = |s: &str| Dom::text(s).with_ids_and_classes(vec![IdOrClass::Class("t".into())].into());
+        let mut dom = Dom::body().with_children(vec![
+            text("hello"),
+            text("привет"),
+            text("hello привет"),
+            text("日本"),
+        ].into());
+
+        let styled_dom = StyledDom::new(&mut dom, CssApiWrapper { css });
+        let node_data = styled_dom.node_data.as_container();
+        let styled_nodes = styled_dom.styled_nodes.as_container();
+        let families = |i: usize| {
+            let node_id = NodeId::new(i);
+            styled_dom
+                .get_css_property_cache()
+                .get_font_id_or_default(&node_data[node_id], &node_id, &styled_nodes[node_id].state)
+                .iter()
+                .map(|f| match f {
+                    StyleFontFamily::System(s) | StyleFontFamily::File(s) => s.as_str().to_string(),
+                    StyleFontFamily::Ref(_) => String::new(),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(families(1), vec!["latin.ttf", "f", "G"]);
+        assert_eq!(families(2), vec!["cyrillic.ttf", "f", "G"]);
+        assert_eq!(families(3), vec!["latin.ttf", "cyrillic.ttf", "f", "G"]);
+        // no face covers the text: fall back to the family name
+        assert_eq!(families(4), vec!["f", "G"]);
+    }
+}
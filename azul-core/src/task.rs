@@ -423,6 +423,14 @@ impl Duration {
         }
     }
 
+    /// Returns the duration in milliseconds (ticks are treated as milliseconds)
+    pub fn millis(&self) -> u64 {
+        match self {
+            Duration::System(s) => s.millis(),
+            Duration::Tick(t) => t.tick_diff,
+        }
+    }
+
     pub fn div(&self, other: &Self) -> f32 {
         use self::Duration::*;
         match (self, other) {
@@ -655,8 +663,7 @@ impl Timer {
 
     pub fn tick_millis(&self) -> u64 {
         match self.interval.as_ref() {
-            Some(Duration::System(s)) => s.millis(),
-            Some(Duration::Tick(s)) => s.tick_diff,
+            Some(d) => d.millis(),
             None => 10, // ms
         }
     }
@@ -5,7 +5,7 @@ use crate::{
     },
     callbacks::{
         DocumentId, HidpiAdjustedBounds, HitTestItem, IFrameCallbackInfo, IFrameCallbackReturn,
-        PipelineId, ScrollHitTestItem,
+        InlineText, InlineTextRun, PipelineId, ScrollHitTestItem,
     },
     display_list::{CachedDisplayList, RenderCallbacks},
     dom::{DomNodeHash, ScrollTagId, TagId},
@@ -598,6 +598,16 @@ pub struct HorizontalSolvedPosition(pub f32);
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
 pub struct VerticalSolvedPosition(pub f32);
 
+/// Block that lays out its `display: inline` children (and their text)
+/// in shared line boxes instead of as separate flex items
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InlineFormattingContext {
+    /// Text nodes of the context, in DOM order
+    pub runs: Vec<NodeId>,
+    /// All nodes inside of the context (inline elements + text nodes)
+    pub inline_nodes: Vec<NodeId>,
+}
+
 pub struct LayoutResult {
     pub dom_id: DomId,
     pub parent_dom_id: Option<DomId>,
@@ -619,6 +629,8 @@ pub struct LayoutResult {
     pub words_cache: BTreeMap<NodeId, Words>,
     pub shaped_words_cache: BTreeMap<NodeId, ShapedWords>,
     pub positioned_words_cache: BTreeMap<NodeId, (WordPositions, FontInstanceKey)>,
+    /// Inline formatting contexts, keyed by the block node containing the inline content
+    pub inline_formatting_contexts: BTreeMap<NodeId, InlineFormattingContext>,
     pub scrollable_nodes: ScrolledNodes,
     pub iframe_mapping: BTreeMap<NodeId, DomId>,
     pub gpu_value_cache: GpuValueCache,
@@ -647,6 +659,7 @@ impl fmt::Debug for LayoutResult {
             words_cache(len = {}),
             shaped_words_cache(len = {}),
             positioned_words_cache(len = {}),
+            inline_formatting_contexts(len = {}),
             scrollable_nodes: {:#?},
             iframe_mapping(len = {}): {:#?},
             gpu_value_cache: {:#?},
@@ -671,6 +684,7 @@ impl fmt::Debug for LayoutResult {
             self.words_cache.len(),
             self.shaped_words_cache.len(),
             self.positioned_words_cache.len(),
+            self.inline_formatting_contexts.len(),
             self.scrollable_nodes,
             self.iframe_mapping.len(),
             self.iframe_mapping,
@@ -690,6 +704,107 @@ impl LayoutResult {
         LayoutRect::new(self.root_position, self.root_size)
     }
 
+    /// Returns the layouted text of a text node, relative to the node origin.
+    ///
+    /// If the node is the root of an inline formatting context, returns the
+    /// lines of all inline runs (relative to the root node), with one
+    /// `InlineTextRun` per text node.
+    pub fn get_inline_text(&self, node_id: NodeId) -> Option<InlineText> {
+        match self.inline_formatting_contexts.get(&node_id) {
+            Some(inline_formatting_context) => {
+                self.get_inline_formatting_context_text(node_id, inline_formatting_context)
+            }
+            None => {
+                let mut inline_text = self.get_text_node_inline_text(node_id)?;
+                let run_node_id = NodeHierarchyItemId::from_crate_internal(Some(node_id));
+                inline_text.runs = inline_text
+                    .runs
+                    .iter()
+                    .map(|run| InlineTextRun {
+                        node_id: run_node_id,
+                        ..*run
+                    })
+                    .collect::<Vec<_>>()
+                    .into();
+                Some(inline_text)
+            }
+        }
+    }
+
+    fn get_text_node_inline_text(&self, node_id: NodeId) -> Option<InlineText> {
+        let words = self.words_cache.get(&node_id)?;
+        let shaped_words = self.shaped_words_cache.get(&node_id)?;
+        let (word_positions, _) = self.positioned_words_cache.get(&node_id)?;
+        let positioned_rectangle = self.rects.internal.get(node_id.index())?;
+        let (_, inline_text_layout) = positioned_rectangle.resolved_text_layout_options.as_ref()?;
+        Some(crate::app_resources::get_inline_text(
+            words,
+            shaped_words,
+            word_positions,
+            inline_text_layout,
+        ))
+    }
+
+    fn get_inline_formatting_context_text(
+        &self,
+        root_id: NodeId,
+        inline_formatting_context: &InlineFormattingContext,
+    ) -> Option<InlineText> {
+        let root_rect = self.rects.internal.get(root_id.index())?;
+        let root_origin = root_rect.position.get_static_offset();
+
+        let mut lines = Vec::new();
+        let mut runs = Vec::new();
+        let mut last_word_index = 0;
+        let mut glyph_start = 0;
+        let mut font_size_px = None;
+
+        for run_id in inline_formatting_context.runs.iter() {
+            let run_text = match self.get_text_node_inline_text(*run_id) {
+                Some(s) => s,
+                None => continue,
+            };
+            let run_rect = match self.rects.internal.get(run_id.index()) {
+                Some(s) => s,
+                None => continue,
+            };
+
+            // the combined text has no common baseline, so the descender
+            // of each run is applied to its lines instead
+            let run_origin = run_rect.position.get_static_offset();
+            let line_offset = LogicalPosition::new(
+                run_origin.x - root_origin.x,
+                run_origin.y - root_origin.y + run_text.baseline_descender_px,
+            );
+
+            let glyph_end = glyph_start + run_text.get_glyph_count();
+            runs.push(InlineTextRun {
+                node_id: NodeHierarchyItemId::from_crate_internal(Some(*run_id)),
+                glyph_start,
+                glyph_end,
+                font_size_px: run_text.font_size_px,
+            });
+            glyph_start = glyph_end;
+            last_word_index += run_text.last_word_index;
+            font_size_px.get_or_insert(run_text.font_size_px);
+
+            let InlineText { lines: run_lines, .. } = run_text;
+            lines.extend(run_lines.into_library_owned_vec().into_iter().map(|mut line| {
+                line.bounds.origin = line.bounds.origin + line_offset;
+                line
+            }));
+        }
+
+        Some(InlineText {
+            lines: lines.into(),
+            content_size: root_rect.size,
+            font_size_px: font_size_px?,
+            last_word_index,
+            baseline_descender_px: 0.0,
+            runs: runs.into(),
+        })
+    }
+
     pub fn get_cached_display_list(
         document_id: &DocumentId,
         dom_id: DomId,
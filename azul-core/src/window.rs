@@ -10,10 +10,11 @@ use crate::{
         PipelineId, RefAny, ScrollPosition, Update,
     },
     display_list::RenderCallbacks,
-    dom::NodeHierarchy,
+    dom::{NodeHierarchy, Tooltip},
+    menu_overlay::{MenuOverlay, MenuOverlayEvent},
     id_tree::NodeId,
     styled_dom::{DomId, NodeHierarchyItemId},
-    task::{ExternalSystemCallbacks, Instant, Thread, ThreadId, Timer, TimerId},
+    task::{Duration, ExternalSystemCallbacks, Instant, Thread, ThreadId, Timer, TimerId},
     ui_solver::{
         ExternalScrollId, HitTest, LayoutResult, OverflowingScrollNode, QuickResizeResult,
     },
@@ -23,6 +24,7 @@ use crate::{
 use alloc::boxed::Box;
 use alloc::collections::btree_map::BTreeMap;
 use alloc::collections::btree_set::BTreeSet;
+use alloc::string::String;
 use alloc::vec::Vec;
 use azul_css::{
     AzString, ColorU, CssPath, CssProperty, LayoutPoint, LayoutRect, LayoutSize, OptionAzString,
@@ -696,6 +698,13 @@ pub struct WindowInternal {
     pub timers: BTreeMap<TimerId, Timer>,
     /// List of threads running in the background
     pub threads: BTreeMap<ThreadId, Thread>,
+    /// Hover state of the tooltip of the currently hovered node
+    pub tooltip: TooltipState,
+    /// Whether the menu bar and context menus are rendered in-window
+    /// (see `WindowInternalInit::in_window_menus`)
+    pub in_window_menus: bool,
+    /// Currently open in-window context menu or menu bar drop-down
+    pub menu_overlay: Option<MenuOverlay>,
 }
 
 impl WindowInternal {
@@ -704,6 +713,68 @@ impl WindowInternal {
     }
 }
 
+/// Tracks which node the mouse is resting on and whether its tooltip is visible
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TooltipState {
+    /// Node with a tooltip that the mouse is currently hovering over
+    pub hovered_node: Option<DomNodeId>,
+    /// Set when the mouse was pressed on the hovered node: the tooltip
+    /// stays hidden until the mouse leaves the node
+    pub dismissed: bool,
+    /// Tooltip (and the cursor position it was opened at) that is
+    /// injected into the DOM on the next `regenerate_styled_dom()`
+    pub visible: Option<(Tooltip, LogicalPosition)>,
+}
+
+impl TooltipState {
+    /// Updates the state with the node (and its tooltip delay) that is
+    /// hovered after the latest hit test, see `WindowInternal::update_tooltip`
+    pub fn update(&mut self, hovered: Option<(Duration, DomNodeId)>, mouse_down: bool) -> TooltipUpdate {
+        let was_visible = self.visible.is_some();
+
+        let start_timer = match hovered {
+            None => {
+                *self = TooltipState::default();
+                None
+            }
+            Some((_, node)) if self.hovered_node == Some(node) => {
+                if mouse_down {
+                    self.dismissed = true;
+                    self.visible = None;
+                }
+                None
+            }
+            Some((delay, node)) => {
+                *self = TooltipState {
+                    hovered_node: Some(node),
+                    dismissed: mouse_down,
+                    visible: None,
+                };
+                if mouse_down {
+                    None
+                } else {
+                    Some(delay)
+                }
+            }
+        };
+
+        TooltipUpdate {
+            should_regenerate_dom: was_visible && self.visible.is_none(),
+            start_timer,
+        }
+    }
+}
+
+/// What the windowing system has to do after the tooltip state has been updated
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TooltipUpdate {
+    /// A previously visible tooltip was hidden, the DOM has to be regenerated
+    pub should_regenerate_dom: bool,
+    /// A new node is hovered: (re-)start the tooltip timer with the given delay,
+    /// then call `show_tooltip_if_ready()` once it fires
+    pub start_timer: Option<Duration>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct FullHitTest {
     pub hovered_nodes: BTreeMap<DomId, HitTest>,
@@ -793,6 +864,9 @@ pub struct WindowInternalInit {
     pub window_create_options: WindowCreateOptions,
     pub document_id: DocumentId,
     pub id_namespace: IdNamespace,
+    /// Whether the menu bar and context menus have to be rendered
+    /// in-window, because the platform has no native menus
+    pub in_window_menus: bool,
 }
 
 impl WindowInternal {
@@ -828,6 +902,7 @@ impl WindowInternal {
                 image_cache,
                 gl_context,
                 &fc_cache_real,
+                callbacks,
             );
 
             match layout_callback {
@@ -839,6 +914,12 @@ impl WindowInternal {
             }
         };
 
+        let styled_dom = if init.in_window_menus {
+            crate::menu_overlay::inject_window_menu_bar(styled_dom, None)
+        } else {
+            styled_dom
+        };
+
         let mut current_window_state = FullWindowState::from_window_state(
             /*window_state: */ &init.window_create_options.state,
             /*dropped_file: */ None,
@@ -914,6 +995,9 @@ impl WindowInternal {
             timers: BTreeMap::new(),
             threads: BTreeMap::new(),
             scroll_states,
+            tooltip: TooltipState::default(),
+            in_window_menus: init.in_window_menus,
+            menu_overlay: None,
         }
     }
 
@@ -948,6 +1032,7 @@ impl WindowInternal {
                 image_cache,
                 gl_context,
                 &fc_cache_real,
+                callbacks,
             );
 
             match layout_callback {
@@ -959,6 +1044,22 @@ impl WindowInternal {
             }
         };
 
+        if self.in_window_menus {
+            let active_item = self
+                .menu_overlay
+                .as_ref()
+                .and_then(|m| m.get_menu_bar_index());
+            styled_dom = crate::menu_overlay::inject_window_menu_bar(styled_dom, active_item);
+        }
+
+        if let Some((tooltip, cursor_position)) = self.tooltip.visible.as_ref() {
+            styled_dom.inject_tooltip(tooltip, *cursor_position);
+        }
+
+        if let Some(menu_overlay) = self.menu_overlay.as_ref() {
+            styled_dom.inject_menu_overlay(menu_overlay);
+        }
+
         styled_dom.insert_default_system_callbacks(DefaultCallbacksCfg {
             smooth_scroll: self.current_window_state.flags.smooth_scroll_enabled,
             enable_autotab: self.current_window_state.flags.autotab_enabled,
@@ -1172,6 +1273,286 @@ impl WindowInternal {
         context_menu
     }
 
+    /// Returns the callback of the keyboard accelerator that matches the current
+    /// keyboard state (see `VirtualKeyCodeCombo::matches`) or None.
+    ///
+    /// Accelerators are searched from the focused node up to the root node (so that
+    /// the innermost accelerator wins), then in the menu bar of the window. On each node,
+    /// the keyboard shortcut table, the context menu items and the
+    /// `AccessibilityInfo::accelerator` (which invokes the `DefaultAction` callback
+    /// of the node) are checked. Disabled or greyed menu items are ignored.
+    pub fn get_accelerator_callback(&self) -> Option<(MenuCallback, DomNodeId)> {
+        use crate::dom::{ComponentEventFilter, EventFilter};
+
+        let keyboard_state = &self.current_window_state.keyboard_state;
+
+        let (dom_id, start_node) = match self.current_window_state.focused_node {
+            Some(DomNodeId { dom, node }) => (dom, node.into_crate_internal().unwrap_or(NodeId::ZERO)),
+            None => (DomId::ROOT_ID, NodeId::ZERO),
+        };
+
+        let mut nodes_to_check = Vec::new();
+        if let Some(lr) = self.layout_results.get(dom_id.inner) {
+            let node_hierarchy = lr.styled_dom.node_hierarchy.as_container();
+            let mut current = Some(start_node);
+            while let Some(node_id) = current {
+                if node_hierarchy.get(node_id).is_none() {
+                    break;
+                }
+                nodes_to_check.push((dom_id, node_id));
+                current = node_hierarchy[node_id].parent_id();
+            }
+        }
+        if dom_id != DomId::ROOT_ID {
+            nodes_to_check.push((DomId::ROOT_ID, NodeId::ZERO));
+        }
+
+        for (dom_id, node_id) in nodes_to_check {
+            let node_data = match self
+                .layout_results
+                .get(dom_id.inner)
+                .and_then(|lr| lr.styled_dom.node_data.as_container().get_extended_lifetime(node_id))
+            {
+                Some(s) => s,
+                None => continue,
+            };
+
+            let hit_dom_node = DomNodeId {
+                dom: dom_id,
+                node: NodeHierarchyItemId::from_crate_internal(Some(node_id)),
+            };
+
+            if let Some(shortcuts) = node_data.get_keyboard_shortcuts() {
+                if let Some(s) = shortcuts.iter().find(|s| s.keys.matches(keyboard_state)) {
+                    return Some((s.callback.clone(), hit_dom_node));
+                }
+            }
+
+            if let Some(cm) = node_data.get_context_menu() {
+                if let Some(cb) = find_menu_accelerator(cm.items.as_ref(), keyboard_state) {
+                    return Some((cb, hit_dom_node));
+                }
+            }
+
+            let default_action = node_data
+                .get_accessibility_info()
+                .and_then(|a| a.accelerator.as_ref())
+                .filter(|a| a.matches(keyboard_state))
+                .and_then(|_| {
+                    node_data
+                        .get_callbacks()
+                        .iter()
+                        .find(|c| c.event == EventFilter::Component(ComponentEventFilter::DefaultAction))
+                });
+
+            if let Some(cb) = default_action {
+                let menu_callback = MenuCallback {
+                    callback: cb.callback.clone(),
+                    data: cb.data.clone(),
+                };
+                return Some((menu_callback, hit_dom_node));
+            }
+        }
+
+        let menu_bar = self.get_menu_bar()?;
+        let cb = find_menu_accelerator(menu_bar.items.as_ref(), keyboard_state)?;
+        Some((
+            cb,
+            DomNodeId {
+                dom: DomId::ROOT_ID,
+                node: NodeHierarchyItemId::from_crate_internal(None),
+            },
+        ))
+    }
+
+    /// Opens the context menu of the hit node as an in-window overlay
+    /// (see `in_window_menus`). Returns true if a menu was opened.
+    pub fn open_context_menu_overlay(&mut self) -> bool {
+        let cursor = match self
+            .current_window_state
+            .mouse_state
+            .cursor_position
+            .get_position()
+        {
+            Some(s) => s,
+            None => return false,
+        };
+        let window_size = self.current_window_state.size.get_logical_size();
+
+        let menu_overlay = {
+            let (menu, hit, hit_node) = match self.get_context_menu() {
+                Some(s) => s,
+                None => return false,
+            };
+
+            let hit_size = hit_node
+                .node
+                .into_crate_internal()
+                .and_then(|node_id| {
+                    let lr = self.layout_results.get(hit_node.dom.inner)?;
+                    lr.rects.as_ref().get_extended_lifetime(node_id).map(|r| r.size)
+                })
+                .unwrap_or_default();
+
+            let hit_origin = LogicalPosition::new(
+                hit.point_in_viewport.x - hit.point_relative_to_item.x,
+                hit.point_in_viewport.y - hit.point_relative_to_item.y,
+            );
+
+            MenuOverlay::context_menu(
+                menu,
+                hit_node,
+                cursor,
+                LogicalRect::new(hit_origin, hit_size),
+                window_size,
+            )
+        };
+
+        self.tooltip.visible = None;
+        self.menu_overlay = Some(menu_overlay);
+        true
+    }
+
+    /// Handles a click on the in-window menu bar: opens the drop-down of the
+    /// clicked item or returns the callback of an item without a sub-menu
+    pub fn click_menu_bar(&mut self, position: LogicalPosition) -> MenuOverlayEvent {
+        use crate::menu_overlay::menu_bar_index_at;
+
+        if !self.in_window_menus {
+            return MenuOverlayEvent::DoNothing;
+        }
+
+        let menu_bar = match self.get_menu_bar() {
+            Some(s) => (**s).clone(),
+            None => return MenuOverlayEvent::DoNothing,
+        };
+
+        let index = match menu_bar_index_at(&menu_bar, position) {
+            Some(s) => s,
+            None => return MenuOverlayEvent::DoNothing,
+        };
+
+        let window_size = self.current_window_state.size.get_logical_size();
+        if let Some(menu_overlay) = MenuOverlay::menu_bar(&menu_bar, index, window_size) {
+            self.tooltip.visible = None;
+            self.menu_overlay = Some(menu_overlay);
+            return MenuOverlayEvent::Redraw;
+        }
+
+        match menu_bar.items.as_ref().get(index) {
+            Some(MenuItem::String(s)) if s.state == MenuItemState::Normal => {
+                match s.callback.as_ref() {
+                    Some(cb) => MenuOverlayEvent::Invoke(
+                        cb.clone(),
+                        DomNodeId {
+                            dom: DomId::ROOT_ID,
+                            node: NodeHierarchyItemId::from_crate_internal(None),
+                        },
+                    ),
+                    None => MenuOverlayEvent::DoNothing,
+                }
+            }
+            _ => MenuOverlayEvent::DoNothing,
+        }
+    }
+
+    /// Opens a menu bar drop-down via the keyboard (F10 or Alt + mnemonic)
+    /// and highlights its first item. Returns true if a menu was opened.
+    pub fn open_menu_bar_with_keyboard(&mut self, mnemonic: Option<char>) -> bool {
+        use crate::menu_overlay::menu_bar_index_for_mnemonic;
+
+        if !self.in_window_menus {
+            return false;
+        }
+
+        let window_size = self.current_window_state.size.get_logical_size();
+        let menu_overlay = self.get_menu_bar().and_then(|menu_bar| {
+            let index = menu_bar_index_for_mnemonic(menu_bar, mnemonic)?;
+            MenuOverlay::menu_bar(menu_bar, index, window_size)
+        });
+
+        match menu_overlay {
+            Some(mut menu_overlay) => {
+                menu_overlay.select_first_item();
+                self.tooltip.visible = None;
+                self.menu_overlay = Some(menu_overlay);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the tooltip of the nearest hit node
+    /// or None if no tooltip was found
+    pub fn get_tooltip<'a>(&'a self) -> Option<(&'a Box<Tooltip>, DomNodeId)> {
+        let mut tooltip = None;
+        let hit_test = &self.current_window_state.last_hit_test;
+
+        for (dom_id, hit_test) in hit_test.hovered_nodes.iter() {
+            let layout_result = self.layout_results.get(dom_id.inner)?;
+            for node_id in hit_test.regular_hit_test_nodes.keys() {
+                let ndc = layout_result.styled_dom.node_data.as_container();
+                if let Some(tt) = ndc
+                    .get_extended_lifetime(*node_id)
+                    .and_then(|node| node.get_tooltip())
+                {
+                    let domnode = DomNodeId {
+                        dom: *dom_id,
+                        node: NodeHierarchyItemId::from_crate_internal(Some(*node_id)),
+                    };
+                    tooltip = Some((tt, domnode));
+                }
+            }
+        }
+        tooltip
+    }
+
+    /// Updates the tooltip state after a new hit test: hides the tooltip
+    /// when the mouse left the node or a mouse button was pressed and
+    /// tells the caller to start the hover timer when a new node is hovered
+    pub fn update_tooltip(&mut self) -> TooltipUpdate {
+        let mouse_down = self.current_window_state.mouse_state.mouse_down();
+        let cursor_in_window = self
+            .current_window_state
+            .mouse_state
+            .cursor_position
+            .is_inside_window();
+
+        let hovered = if cursor_in_window {
+            self.get_tooltip().map(|(tt, node)| (tt.delay, node))
+        } else {
+            None
+        };
+
+        self.tooltip.update(hovered, mouse_down)
+    }
+
+    /// Called when the tooltip timer fires: marks the tooltip of the hovered
+    /// node as visible. Returns true if the DOM has to be regenerated.
+    pub fn show_tooltip_if_ready(&mut self) -> bool {
+        if self.tooltip.dismissed || self.tooltip.visible.is_some() {
+            return false;
+        }
+
+        let cursor_position = match self
+            .current_window_state
+            .mouse_state
+            .cursor_position
+            .get_position()
+        {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let tooltip = match self.get_tooltip() {
+            Some((tt, node)) if self.tooltip.hovered_node == Some(node) => (**tt).clone(),
+            _ => return false,
+        };
+
+        self.tooltip.visible = Some((tooltip, cursor_position));
+        true
+    }
+
     /// Runs a single timer, similar to CallbacksOfHitTest.call()
     ///
     /// NOTE: The timer has to be selected first by the calling code and verified
@@ -1737,6 +2118,42 @@ impl WindowInternal {
 
         return ret;
     }
+
+    /// Invokes the callback of the keyboard accelerator matching the current keyboard
+    /// state (see `get_accelerator_callback`). Returns None if no accelerator matched,
+    /// in which case the key event should be processed regularly.
+    pub fn invoke_accelerator(
+        &mut self,
+        current_window_handle: &RawWindowHandle,
+        gl_context: &OptionGlContextPtr,
+        image_cache: &mut ImageCache,
+        system_fonts: &mut FcFontCache,
+        system_callbacks: &ExternalSystemCallbacks,
+    ) -> Option<CallCallbacksResult> {
+        let (mut menu_callback, hit_dom_node) = self.get_accelerator_callback()?;
+        Some(self.invoke_menu_callback(
+            &mut menu_callback,
+            hit_dom_node,
+            current_window_handle,
+            gl_context,
+            image_cache,
+            system_fonts,
+            system_callbacks,
+        ))
+    }
+}
+
+/// Recursively searches the (enabled) menu items for an accelerator matching the keyboard state
+fn find_menu_accelerator(items: &[MenuItem], keyboard_state: &KeyboardState) -> Option<MenuCallback> {
+    items.iter().find_map(|item| match item {
+        MenuItem::String(s) if s.state == MenuItemState::Normal => {
+            match (s.accelerator.as_ref(), s.callback.as_ref()) {
+                (Some(a), Some(cb)) if a.matches(keyboard_state) => Some(cb.clone()),
+                _ => find_menu_accelerator(s.children.as_ref(), keyboard_state),
+            }
+        }
+        _ => None,
+    })
 }
 
 #[derive(Debug, Default, Copy, Clone, PartialEq, PartialOrd, Hash, Ord, Eq)]
@@ -3282,6 +3699,43 @@ pub enum VirtualKeyCode {
     Cut,
 }
 
+impl VirtualKeyCode {
+    /// Returns whether the key is a Ctrl, Shift, Alt or Super (Windows / Command) key
+    pub fn is_modifier(&self) -> bool {
+        use self::VirtualKeyCode::*;
+        match self {
+            LControl | RControl | LShift | RShift | LAlt | RAlt | LWin | RWin => true,
+            _ => false,
+        }
+    }
+
+    /// Returns the name of the key as it is usually printed in menus
+    pub fn get_display_name(&self) -> String {
+        use self::VirtualKeyCode::*;
+        match self {
+            Key1 => "1".into(),
+            Key2 => "2".into(),
+            Key3 => "3".into(),
+            Key4 => "4".into(),
+            Key5 => "5".into(),
+            Key6 => "6".into(),
+            Key7 => "7".into(),
+            Key8 => "8".into(),
+            Key9 => "9".into(),
+            Key0 => "0".into(),
+            LControl | RControl => "Ctrl".into(),
+            LShift | RShift => "Shift".into(),
+            LAlt | RAlt => "Alt".into(),
+            LWin | RWin => "Super".into(),
+            Return => "Enter".into(),
+            Escape => "Esc".into(),
+            Back => "Backspace".into(),
+            Delete => "Del".into(),
+            other => format!("{:?}", other),
+        }
+    }
+}
+
 /// 16x16x4 bytes icon
 #[derive(Debug, Clone)]
 #[repr(C)]
@@ -3578,6 +4032,81 @@ impl_option!(
     [Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord]
 );
 
+impl VirtualKeyCodeCombo {
+    /// Formats the key combination for display, e.g. `"Ctrl+Shift+S"`
+    pub fn to_display_string(&self) -> String {
+        self.keys
+            .as_ref()
+            .iter()
+            .map(|k| k.get_display_name())
+            .collect::<Vec<_>>()
+            .join("+")
+    }
+
+    /// Returns whether the key combination was just completed: the last pressed key
+    /// is a non-modifier key of the combination, all other keys of the combination are held down
+    /// and no additional modifier (Ctrl / Shift / Alt / Super) is pressed.
+    ///
+    /// Left and right modifier keys are treated as equivalent.
+    pub fn matches(&self, keyboard_state: &KeyboardState) -> bool {
+        use self::VirtualKeyCode::*;
+
+        let keys = self.keys.as_ref();
+        let current_key = match keyboard_state.current_virtual_keycode.into_option() {
+            Some(s) => s,
+            None => return false,
+        };
+
+        if current_key.is_modifier() || !keys.contains(&current_key) {
+            return false;
+        }
+
+        let combo_has = |a: VirtualKeyCode, b: VirtualKeyCode| keys.iter().any(|k| *k == a || *k == b);
+        if keyboard_state.ctrl_down() != combo_has(LControl, RControl)
+            || keyboard_state.shift_down() != combo_has(LShift, RShift)
+            || keyboard_state.alt_down() != combo_has(LAlt, RAlt)
+            || keyboard_state.super_down() != combo_has(LWin, RWin)
+        {
+            return false;
+        }
+
+        keys.iter()
+            .all(|k| k.is_modifier() || *k == current_key || keyboard_state.is_key_down(*k))
+    }
+}
+
+/// Keyboard shortcut that is not attached to a menu item: when the key
+/// combination is pressed, the callback is invoked. See `Dom::with_keyboard_shortcuts()`
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+#[repr(C)]
+pub struct KeyboardShortcut {
+    /// Key combination that triggers the shortcut
+    pub keys: VirtualKeyCodeCombo,
+    /// Callback to invoke when the key combination is pressed
+    pub callback: MenuCallback,
+}
+
+impl KeyboardShortcut {
+    pub fn new(keys: VirtualKeyCodeCombo, data: RefAny, callback: CallbackType) -> Self {
+        Self {
+            keys,
+            callback: MenuCallback {
+                data,
+                callback: Callback { cb: callback },
+            },
+        }
+    }
+}
+
+impl_vec!(KeyboardShortcut, KeyboardShortcutVec, KeyboardShortcutVecDestructor);
+impl_vec_clone!(KeyboardShortcut, KeyboardShortcutVec, KeyboardShortcutVecDestructor);
+impl_vec_debug!(KeyboardShortcut, KeyboardShortcutVec);
+impl_vec_partialeq!(KeyboardShortcut, KeyboardShortcutVec);
+impl_vec_partialord!(KeyboardShortcut, KeyboardShortcutVec);
+impl_vec_hash!(KeyboardShortcut, KeyboardShortcutVec);
+impl_vec_eq!(KeyboardShortcut, KeyboardShortcutVec);
+impl_vec_ord!(KeyboardShortcut, KeyboardShortcutVec);
+
 /// Menu callback: What data / function pointer should
 /// be called when the menu item is clicked?
 #[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
@@ -3621,3 +4150,38 @@ pub enum MenuItemState {
     /// Menu item is disabled, but NOT greyed out
     Disabled,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keyboard_state(pressed: &[VirtualKeyCode]) -> KeyboardState {
+        KeyboardState {
+            current_virtual_keycode: pressed.last().copied().into(),
+            pressed_virtual_keycodes: pressed.to_vec().into(),
+            ..Default::default()
+        }
+    }
+
+    fn combo(keys: &[VirtualKeyCode]) -> VirtualKeyCodeCombo {
+        VirtualKeyCodeCombo { keys: keys.to_vec().into() }
+    }
+
+    #[test]
+    fn accelerator_matching() {
+        use self::VirtualKeyCode::*;
+
+        let ctrl_s = combo(&[LControl, S]);
+        assert!(ctrl_s.matches(&keyboard_state(&[LControl, S])));
+        assert!(ctrl_s.matches(&keyboard_state(&[RControl, S])));
+        // extra modifier
+        assert!(!ctrl_s.matches(&keyboard_state(&[LControl, LShift, S])));
+        // missing modifier
+        assert!(!ctrl_s.matches(&keyboard_state(&[S])));
+        // the last pressed key has to complete the combination
+        assert!(!ctrl_s.matches(&keyboard_state(&[S, LControl])));
+
+        assert!(combo(&[F5]).matches(&keyboard_state(&[F5])));
+        assert!(!combo(&[]).matches(&keyboard_state(&[F5])));
+    }
+}
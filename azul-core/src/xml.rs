@@ -1,7 +1,11 @@
 //! XML structure definitions
 
 use crate::css::VecContents;
-use crate::dom::Dom;
+use crate::callbacks::{Callback, CallbackType, RefAny};
+use crate::dom::{
+    Dom, EventFilter, FocusEventFilter, HoverEventFilter,
+    NotEventFilter, TabIndex, WindowEventFilter,
+};
 use crate::styled_dom::StyledDom;
 use crate::window::{AzStringPair, StringPairVec};
 use alloc::collections::BTreeMap;
@@ -33,6 +37,8 @@ pub type ComponentArgumentOrder = usize;
 pub type ComponentArgumentTypes = Vec<(ComponentArgumentName, ComponentArgumentType)>;
 pub type ComponentName = String;
 pub type CompiledComponent = String;
+/// Whether the compiled component binds event handlers and therefore takes the callback `data: &RefAny`
+pub type UsesCallbackData = bool;
 
 pub const DEFAULT_ARGS: [&str; 8] = [
     "id",
@@ -45,6 +51,14 @@ pub const DEFAULT_ARGS: [&str; 8] = [
     "args",
 ];
 
+/// Argument type of a path to a file, i.e. the `src` of an `<img>`: the Rust
+/// code embeds the file via `include_bytes!("path")`, so the path is relative
+/// to the generated source file
+pub const FILE_ARGUMENT_TYPE: &str = "File";
+/// Argument type of inline file contents, i.e. the source of an inline `<svg>`:
+/// the Rust code embeds the contents as a byte string
+pub const BYTES_ARGUMENT_TYPE: &str = "Bytes";
+
 #[allow(non_camel_case_types)]
 pub enum c_void {}
 
@@ -172,7 +186,7 @@ impl fmt::Display for XmlStreamError {
     }
 }
 
-#[derive(Debug, PartialEq, PartialOrd, Clone, Ord, Hash, Eq)]
+#[derive(Debug, Default, Copy, PartialEq, PartialOrd, Clone, Ord, Hash, Eq)]
 #[repr(C)]
 pub struct XmlTextPos {
     pub row: u32,
@@ -185,6 +199,22 @@ impl fmt::Display for XmlTextPos {
     }
 }
 
+/// Location of the start tag of an `XmlNode` in the XML source
+#[derive(Debug, Default, Copy, PartialEq, PartialOrd, Clone, Ord, Hash, Eq)]
+#[repr(C)]
+pub struct XmlSpan {
+    /// Index of the file that the node was parsed from in the list of source
+    /// files (see `XmlFile::files` in azulc) - `0` if the node was parsed from a string
+    pub file: usize,
+    /// Byte offset of the `<` of the start tag in the source
+    pub start: usize,
+    /// Byte offset after the `>` of the start tag in the source
+    pub end: usize,
+    /// Line and column of the start tag (starting at 1),
+    /// `0:0` if the node was not parsed from a string
+    pub pos: XmlTextPos,
+}
+
 #[derive(Debug, PartialEq, PartialOrd, Clone)]
 #[repr(C)]
 pub struct XmlTextError {
@@ -207,6 +237,19 @@ pub enum XmlParseError {
     UnknownToken(XmlTextPos),
 }
 
+impl XmlParseError {
+    /// Returns the position of the error in the XML source
+    pub fn get_pos(&self) -> XmlTextPos {
+        use self::XmlParseError::*;
+        match self {
+            InvalidDeclaration(e) | InvalidComment(e) | InvalidPI(e) |
+            InvalidDoctype(e) | InvalidEntity(e) | InvalidElement(e) |
+            InvalidAttribute(e) | InvalidCdata(e) | InvalidCharData(e) => e.pos,
+            UnknownToken(pos) => *pos,
+        }
+    }
+}
+
 impl fmt::Display for XmlParseError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use self::XmlParseError::*;
@@ -300,6 +343,26 @@ pub enum XmlError {
     ParserError(XmlParseError),
 }
 
+impl XmlError {
+    /// Returns the position of the error in the XML source, if the error has a position
+    pub fn get_pos(&self) -> Option<XmlTextPos> {
+        use self::XmlError::*;
+        match self {
+            InvalidXmlPrefixUri(pos) | UnexpectedXmlUri(pos) | UnexpectedXmlnsUri(pos) |
+            InvalidElementNamePrefix(pos) | UnexpectedEntityCloseTag(pos) |
+            MalformedEntityReference(pos) | EntityReferenceLoop(pos) |
+            InvalidAttributeValue(pos) => Some(*pos),
+            DuplicatedNamespace(e) => Some(e.pos),
+            UnknownNamespace(e) => Some(e.pos),
+            UnexpectedCloseTag(e) => Some(e.pos),
+            UnknownEntityReference(e) => Some(e.pos),
+            DuplicatedAttribute(e) => Some(e.pos),
+            ParserError(e) => Some(e.get_pos()),
+            NoParserAvailable | NoRootNode | SizeLimit | DtdDetected | MalformedHierarchy(..) => None,
+        }
+    }
+}
+
 impl fmt::Display for XmlError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use self::XmlError::*;
@@ -462,6 +525,21 @@ impl FilteredComponentArguments {
     fn new() -> Self {
         Self::default()
     }
+
+    /// Returns the `name => value` pairs of all arguments (and loop variables) in
+    /// scope, used to replace the `{name}` variables when rendering the DOM
+    pub fn get_variables(&self) -> ComponentArgumentTypes {
+        let mut variables = ComponentArgumentTypes::default();
+        for (name, _) in self.types.iter() {
+            if variables.iter().any(|v| v.0 == *name) {
+                continue;
+            }
+            if let Some(value) = self.values.get(name) {
+                variables.push((name.clone(), value.clone()));
+            }
+        }
+        variables
+    }
 }
 
 
@@ -509,8 +587,9 @@ pub trait XmlComponentTrait {
     /// ```
     ///
     /// If a user instantiates a component with an invalid argument (i.e. `<Calendar asdf="false">`),
-    /// the user will get an error that the component can't handle this argument. The types are not checked,
-    /// but they are necessary for the XML-to-Rust compiler.
+    /// the user will get an error that the component can't handle this argument. The values of the
+    /// builtin types (see `validate_argument_value`) are checked against the type, other types
+    /// have to be checked by the component itself. The types are also necessary for the XML-to-Rust compiler.
     ///
     /// When the XML is then compiled to Rust, the generated Rust code will look like this:
     ///
@@ -544,6 +623,26 @@ pub trait XmlComponentTrait {
         content: &XmlTextContent,
     ) -> Result<StyledDom, RenderDomError>;
 
+    /// (Optional): Renders the component together with the rendered child nodes of the XML node.
+    /// By default, the children are appended to the root node of the DOM returned by `render_dom`,
+    /// components that wrap their children (i.e. a `<Frame>`) can place them somewhere else.
+    ///
+    /// Note: only used when rendering the XML, the XML-to-Rust compiler always
+    /// appends the children to the root node.
+    fn render_dom_with_children(
+        &self,
+        components: &XmlComponentMap,
+        arguments: &FilteredComponentArguments,
+        content: &XmlTextContent,
+        children: Vec<StyledDom>,
+    ) -> Result<StyledDom, RenderDomError> {
+        let mut dom = self.render_dom(components, arguments, content)?;
+        for child in children {
+            dom.append_child(child);
+        }
+        Ok(dom)
+    }
+
     /// (Optional): Used to compile the XML component to Rust code - input
     fn compile_to_rust_code(
         &self,
@@ -553,6 +652,19 @@ pub trait XmlComponentTrait {
     ) -> Result<String, CompileError> {
         Ok(String::new())
     }
+
+    /// (Optional): Compiles the value of an argument with a non-builtin type (see
+    /// `validate_argument_value`) to a Rust expression when the component is
+    /// instantiated in the XML-to-Rust compiler, i.e. `selected_date="2024-05-01"`
+    /// to `Date::new(2024, 5, 1)`. By default, the value is converted with `Type::from("value")`.
+    fn compile_argument_to_rust_code(
+        &self,
+        arg_name: &str,
+        arg_type: &str,
+        value: &str,
+    ) -> Result<String, ComponentError> {
+        Ok(format!("{}::from({:?})", arg_type, value))
+    }
 }
 
 /// Wrapper for the XML parser - necessary to easily create a Dom from
@@ -607,6 +719,8 @@ pub struct XmlNode {
     pub children: XmlNodeVec,
     /// String content of the node, i.e the "Hello" in `<p>Hello</p>`
     pub text: XmlTextContent,
+    /// Location of the start tag in the XML source, used to report errors
+    pub span: XmlSpan,
 }
 
 impl XmlNode {
@@ -651,12 +765,32 @@ pub struct XmlComponentMap {
     /// Stores all known components that can be used during DOM rendering
     /// + whether this component should inherit variables from the parent scope
     pub components: Vec<XmlComponent>,
+    /// Callbacks that `on:event="handler_name"` attributes refer to
+    pub callbacks: XmlCallbackMap,
+}
+
+/// Maps the handler names used in `on:event="handler_name"` attributes
+/// to the callback (and the data passed to the callback) they are bound to
+#[derive(Debug, Default, Clone)]
+pub struct XmlCallbackMap {
+    pub callbacks: BTreeMap<String, (RefAny, Callback)>,
+}
+
+impl XmlCallbackMap {
+    pub fn register_callback(&mut self, name: &str, data: RefAny, callback: CallbackType) {
+        self.callbacks.insert(name.to_string(), (data, Callback { cb: callback }));
+    }
+
+    pub fn get_callback(&self, name: &str) -> Option<&(RefAny, Callback)> {
+        self.callbacks.get(name)
+    }
 }
 
 impl Default for XmlComponentMap {
     fn default() -> Self {
         let mut map = Self {
             components: Vec::new(),
+            callbacks: XmlCallbackMap::default(),
         };
         map.register_component(XmlComponent { 
             id: normalize_casing("body"),
@@ -684,6 +818,11 @@ impl XmlComponentMap {
     ) {
         self.components.push(comp);
     }
+
+    /// Registers the callback that the `on:event="name"` attributes with the handler `name` are bound to
+    pub fn register_callback(&mut self, name: &str, data: RefAny, callback: CallbackType) {
+        self.callbacks.register_callback(name, data, callback);
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -696,10 +835,7 @@ pub enum DomXmlParseError {
     NoBodyInHtml,
     /// The DOM can only have one <body> node, not multiple.
     MultipleBodyNodes,
-    /// Note: Sadly, the error type can only be a string because xmlparser
-    /// returns all errors as strings. There is an open PR to fix
-    /// this deficiency, but since the XML parsing is only needed for
-    /// hot-reloading and compiling, it doesn't matter that much.
+    /// The XML could not be parsed, see `XmlError::get_pos` for the location of the error
     Xml(XmlError),
     /// Invalid hierarchy close tags, i.e `<app></p></app>`
     MalformedHierarchy(AzString, AzString),
@@ -789,6 +925,28 @@ pub enum ComponentError {
     ///
     /// UnknownComponent(component_name)
     UnknownComponent(AzString),
+    /// The value of an argument could not be parsed as the type
+    /// the component expects
+    ///
+    /// InvalidArgumentValue(argument_name, argument_value, expected_type)
+    InvalidArgumentValue(AzString, AzString, AzString),
+    /// An `<if condition>` or `<for each>` refers to a variable that is not in scope
+    UnknownVariable(AzString),
+    /// The `condition` of an `<if>` or the `each` of a `<for>` node could not be parsed
+    ///
+    /// InvalidExpression(expression, reason)
+    InvalidExpression(AzString, AzString),
+    /// An `<else>` node has to directly follow an `<if>` node
+    ElseWithoutIf,
+    /// The event of an `on:event="handler"` attribute is not a known event
+    UnknownEvent(AzString),
+    /// The handler of an `on:event="handler"` attribute was not registered
+    /// in the `XmlCallbackMap` of the component map
+    UnknownCallback(AzString),
+    /// The image of an `<img>` or inline `<svg>` node could not be loaded or decoded
+    ///
+    /// InvalidImage(src, reason)
+    InvalidImage(AzString, AzString),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -796,6 +954,20 @@ pub enum RenderDomError {
     Component(ComponentError),
     /// Error parsing the CSS on the component style
     CssError(CssParseErrorOwned),
+    /// Error in the node at the given location in the XML source,
+    /// i.e. an argument value that does not match the type of the argument
+    InvalidNode(XmlSpan, ComponentError),
+}
+
+impl RenderDomError {
+    /// Attaches the location of the node to a `RenderDomError::Component`
+    /// (errors of child nodes already have a more precise location)
+    pub fn with_node_span(self, span: XmlSpan) -> Self {
+        match self {
+            RenderDomError::Component(e) => RenderDomError::InvalidNode(span, e),
+            other => other,
+        }
+    }
 }
 
 impl From<ComponentError> for RenderDomError {
@@ -804,6 +976,12 @@ impl From<ComponentError> for RenderDomError {
     }
 }
 
+impl From<Box<ComponentError>> for RenderDomError {
+    fn from(e: Box<ComponentError>) -> Self {
+        Self::Component(*e)
+    }
+}
+
 impl From<CssParseErrorOwned> for RenderDomError {
     fn from(e: CssParseErrorOwned) -> Self {
         Self::CssError(e)
@@ -897,6 +1075,16 @@ impl fmt::Display for ComponentError {
                 )
             }
             UnknownComponent(name) => write!(f, "Unknown component: \"{}\"", name),
+            InvalidArgumentValue(k, v, ty) => {
+                write!(f, "Invalid value for component argument \"{}\": \"{}\" is not a valid {}", k, v, ty)
+            }
+            UnknownVariable(name) => write!(f, "Unknown variable: \"{}\"", name),
+            InvalidExpression(e, reason) => write!(f, "Invalid expression \"{}\": {}", e, reason),
+            ElseWithoutIf => write!(f, "<else> node without a preceding <if> node"),
+            UnknownEvent(name) => write!(f, "Unknown event: \"{}\"", name),
+            UnknownCallback(name) => write!(f, "Unknown event handler: \"{}\" (callback was not registered)", name),
+            InvalidImage(src, reason) if src.as_str().is_empty() => write!(f, "Invalid image: {}", reason),
+            InvalidImage(src, reason) => write!(f, "Invalid image \"{}\": {}", src, reason),
         }
     }
 }
@@ -907,6 +1095,9 @@ impl<'a> fmt::Display for RenderDomError {
         match self {
             Component(c) => write!(f, "{}", c),
             CssError(e) => write!(f, "Error parsing CSS in component: {}", e.to_shared()),
+            // nodes that were not parsed from a string have no position
+            InvalidNode(span, c) if span.pos.row == 0 => write!(f, "{}", c),
+            InvalidNode(span, c) => write!(f, "{}: {}", span.pos, c),
         }
     }
 }
@@ -1108,14 +1299,25 @@ pub fn validate_and_filter_component_args(
     for AzStringPair { key, value } in xml_attributes.as_ref().iter() {
         let xml_attribute_name = key;
         let xml_attribute_value = value;
-        if let Some(valid_arg_type) =
-            valid_args.args.iter().find(|s| s.0 == xml_attribute_name.as_str()).map(|q| &q.1)
+        // "selectedDate", "selected-date" and "selected_date" all refer to the "selected_date" argument
+        let normalized_attribute_name = normalize_casing(xml_attribute_name.as_str());
+        if let Some((valid_arg_name, valid_arg_type)) =
+            valid_args.args.iter().find(|s| s.0 == xml_attribute_name.as_str() || normalize_casing(&s.0) == normalized_attribute_name)
         {
-            map.types.push((xml_attribute_name.as_str().to_string(), valid_arg_type.clone()));
-            map.values.insert(xml_attribute_name.as_str().to_string(), xml_attribute_value.as_str().to_string());
+            // values with variables are checked once the variables are instantiated
+            if !xml_attribute_value.as_str().contains('{') {
+                validate_argument_value(valid_arg_name, valid_arg_type, xml_attribute_value.as_str())?;
+            }
+            map.types.push((valid_arg_name.clone(), valid_arg_type.clone()));
+            map.values.insert(valid_arg_name.clone(), xml_attribute_value.as_str().to_string());
         } else if DEFAULT_ARGS.contains(&xml_attribute_name.as_str()) {
             // no error, but don't insert the attribute name
             map.values.insert(xml_attribute_name.as_str().to_string(), xml_attribute_value.as_str().to_string());
+        } else if let Some(event) = xml_attribute_name.as_str().strip_prefix(EVENT_ATTRIBUTE_PREFIX) {
+            // event handlers are bound in set_callbacks, only check that the event exists
+            if parse_event_filter(event).is_none() {
+                return Err(ComponentError::UnknownEvent(event.to_string().into()));
+            }
         } else {
             // key was not expected for this component
             let keys = valid_args.args.iter().map(|s| s.0.clone()).collect();
@@ -1130,6 +1332,60 @@ pub fn validate_and_filter_component_args(
     Ok(map)
 }
 
+/// Checks that the `value` of the argument `arg_name` can be parsed as the `arg_type`:
+///
+/// - `bool`, integers (`u8` - `u64`, `usize`, `i8` - `i64`, `isize`) and floats (`f32`, `f64`)
+/// - enums defined in the component, i.e. `args="kind: primary|secondary"`
+/// - CSS colors (`ColorU`, i.e. `#ff0000` or `red`) and lengths (`PixelValue`, i.e. `10px`)
+/// - lists of the types above, i.e. `[u32]`
+///
+/// `String` and all other types (i.e. types of components registered from Rust) are not checked.
+pub fn validate_argument_value(arg_name: &str, arg_type: &str, value: &str) -> Result<(), ComponentError> {
+    let invalid = || ComponentError::InvalidArgumentValue(arg_name.into(), value.to_string().into(), arg_type.into());
+    let value_trimmed = value.trim();
+
+    if let Some(item_type) = get_list_item_type(arg_type) {
+        for item in split_list_value(value) {
+            validate_argument_value(arg_name, item_type, &item)?;
+        }
+        return Ok(());
+    }
+
+    if let Some(variants) = get_enum_variants(arg_type) {
+        return if variants.contains(&value_trimmed) { Ok(()) } else { Err(invalid()) };
+    }
+
+    let is_valid = match arg_type {
+        "bool" => parse_bool(value_trimmed).is_some(),
+        "u8" => value_trimmed.parse::<u8>().is_ok(),
+        "u16" => value_trimmed.parse::<u16>().is_ok(),
+        "u32" => value_trimmed.parse::<u32>().is_ok(),
+        "u64" => value_trimmed.parse::<u64>().is_ok(),
+        "usize" => value_trimmed.parse::<usize>().is_ok(),
+        "i8" => value_trimmed.parse::<i8>().is_ok(),
+        "i16" => value_trimmed.parse::<i16>().is_ok(),
+        "i32" => value_trimmed.parse::<i32>().is_ok(),
+        "i64" => value_trimmed.parse::<i64>().is_ok(),
+        "isize" => value_trimmed.parse::<isize>().is_ok(),
+        "f32" => value_trimmed.parse::<f32>().is_ok(),
+        "f64" => value_trimmed.parse::<f64>().is_ok(),
+        "ColorU" => azul_css_parser::parse_css_color(value_trimmed).is_ok(),
+        "PixelValue" => azul_css_parser::parse_pixel_value(value_trimmed).is_ok(),
+        _ => true,
+    };
+
+    if is_valid { Ok(()) } else { Err(invalid()) }
+}
+
+/// Returns the variants of an enum argument type, i.e. `["primary", "secondary"]` for `primary|secondary`
+pub fn get_enum_variants(arg_type: &str) -> Option<Vec<&str>> {
+    if arg_type.contains('|') {
+        Some(arg_type.split('|').map(|s| s.trim()).collect())
+    } else {
+        None
+    }
+}
+
 /// Find the one and only `<body>` node, return error if
 /// there is no app node or there are multiple app nodes
 pub fn get_html_node<'a>(root_nodes: &'a [XmlNode]) -> Result<&'a XmlNode, DomXmlParseError> {
@@ -1253,20 +1509,7 @@ pub fn str_to_dom<'a>(
 
     if let Some(head_node) = find_node_by_type(html_node.children.as_ref(), "head") {
         // parse all dynamic XML components from the head node
-        for node in head_node.children.as_ref() {
-            match DynamicXmlComponent::new(node) {
-                Ok(node) => {
-                    let node_name = node.name.clone();
-                    component_map.register_component(XmlComponent {
-                        id: normalize_casing(&node_name),
-                        renderer: Box::new(node), 
-                        inherit_vars: false,
-                    });
-                }
-                Err(ComponentParseError::NotAComponent) => {} // not a <component /> node, ignore
-                Err(e) => return Err(e.into()), // Error during parsing the XML component, bail
-            }
-        }
+        register_components(head_node.children.as_ref(), component_map)?;
 
         // parse the <style></style> tag contents, if present
         if let Some(style_node) = find_node_by_type(head_node.children.as_ref(), "style") {
@@ -1289,38 +1532,7 @@ pub fn str_to_rust_code<'a>(
 ) -> Result<String, CompileError> {
     let html_node = get_html_node(&root_nodes)?;
     let body_node = get_body_node(html_node.children.as_ref())?;
-    let mut global_style = Css::empty();
-
-    if let Some(head_node) = html_node
-        .children
-        .as_ref()
-        .iter()
-        .find(|n| normalize_casing(&n.node_type).as_str() == "head")
-    {
-        for node in head_node.children.as_ref() {
-            match DynamicXmlComponent::new(node) {
-                Ok(node) => {
-                    let node_name = node.name.clone();
-                    component_map.register_component(XmlComponent {
-                        id: normalize_casing(&node_name),
-                        renderer: Box::new(node), 
-                        inherit_vars: false,
-                    });
-                }
-                Err(ComponentParseError::NotAComponent) => {} // not a <component /> node, ignore
-                Err(e) => return Err(CompileError::Xml(e.into())), // Error during parsing the XML component, bail
-            }
-        }
-
-        if let Some(style_node) = find_node_by_type(head_node.children.as_ref(), "style") {
-            if let Some(text) = style_node.text.as_ref().map(|s| s.as_str()) {
-                let parsed_css = azul_css_parser::new_from_str(&text).map_err(|e| e.to_contained())?;
-                global_style = parsed_css;
-            }
-        }
-    }
-
-    global_style.sort_by_specificity();
+    let global_style = parse_head_node(html_node, component_map)?;
 
     let mut css_blocks = BTreeMap::new();
     let mut extra_blocks = VecContents::default();
@@ -1358,29 +1570,37 @@ pub fn str_to_rust_code<'a>(
 
     let mut extra_block_string = extra_blocks.format(1);
 
-    let main_func = "
+    // event handlers of the body are resolved in the parent module (the crate root)
+    let uses_callback_data = !get_event_handler_names(body_node.children.as_ref(), component_map).is_empty();
+    let (callback_imports, render_args, render_data) = if uses_callback_data {
+        (format!("\r\n    use super::*;\r\n{}", CALLBACK_IMPORTS.trim_end()), "data: &RefAny", "data")
+    } else {
+        (String::new(), "", "_")
+    };
+
+    let main_func = format!("
 
-use azul::{
-    app::{App, AppConfig, LayoutSolver},
+use azul::{{
+    app::{{App, AppConfig, LayoutSolver}},
     css::Css,
     style::StyledDom,
-    callbacks::{RefAny, LayoutCallbackInfo},
-    window::{WindowCreateOptions, WindowFrame},
-};
+    callbacks::{{RefAny, LayoutCallbackInfo}},
+    window::{{WindowCreateOptions, WindowFrame}},
+}};
 
-struct Data { }
+struct Data {{ }}
 
-extern \"C\" fn render(_: &mut RefAny, _: &mut LayoutCallbackInfo) -> StyledDom {
-    crate::ui::render()
+extern \"C\" fn render({render_data}: &mut RefAny, _: &mut LayoutCallbackInfo) -> StyledDom {{
+    crate::ui::render({render_arg})
     .style(Css::empty()) // styles are applied inline
-}
+}}
 
-fn main() {
-    let app = App::new(RefAny::new(Data { }), AppConfig::new(LayoutSolver::Default));
+fn main() {{
+    let app = App::new(RefAny::new(Data {{ }}), AppConfig::new(LayoutSolver::Default));
     let mut window = WindowCreateOptions::new(render);
     window.state.flags.frame = WindowFrame::Maximized;
     app.run(window);
-}";
+}}", render_data = render_data, render_arg = if uses_callback_data { "data" } else { "" });
 
     let source_code = format!(
         "#![windows_subsystem = \"windows\"]\r\n//! Auto-generated UI source code\r\n{}\r\n{}\r\n\r\n{}{}",
@@ -1403,15 +1623,104 @@ fn main() {
         Dom, IdOrClass, TabIndex,
         IdOrClass::{{Id, Class}},
         NodeDataInlineCssProperty,
-    }};\r\n\r\n{}\r\n\r\n{}
+    }};{}\r\n\r\n{}\r\n\r\n{}
 
-    pub fn render() -> Dom {{\r\n{}\r\n    }}\r\n}}", extra_block_string, css_blocks, app_source),
+    pub fn render({}) -> Dom {{\r\n{}\r\n    }}\r\n}}", callback_imports, extra_block_string, css_blocks, render_args, app_source),
         main_func,
     );
 
     Ok(source_code)
 }
 
+/// Registers all `<component />` nodes in the `<head>` of the `html_node` in the `component_map`
+/// and returns the global `<style>` of the document, sorted by specificity
+pub fn parse_head_node(
+    html_node: &XmlNode,
+    component_map: &mut XmlComponentMap,
+) -> Result<Css, CompileError> {
+    let mut global_style = Css::empty();
+
+    if let Some(head_node) = html_node
+        .children
+        .as_ref()
+        .iter()
+        .find(|n| normalize_casing(&n.node_type).as_str() == "head")
+    {
+        register_components(head_node.children.as_ref(), component_map)
+            .map_err(|e| CompileError::Xml(e.into()))?;
+
+        if let Some(style_node) = find_node_by_type(head_node.children.as_ref(), "style") {
+            if let Some(text) = style_node.text.as_ref().map(|s| s.as_str()) {
+                let parsed_css = azul_css_parser::new_from_str(&text).map_err(|e| e.to_contained())?;
+                global_style = parsed_css;
+            }
+        }
+    }
+
+    global_style.sort_by_specificity();
+
+    Ok(global_style)
+}
+
+/// Registers all `<component />` nodes in `nodes` in the `component_map`, other nodes are ignored
+pub fn register_components(
+    nodes: &[XmlNode],
+    component_map: &mut XmlComponentMap,
+) -> Result<(), ComponentParseError> {
+    for node in nodes {
+        match DynamicXmlComponent::new(node) {
+            Ok(node) => {
+                let node_name = node.name.clone();
+                component_map.register_component(XmlComponent {
+                    id: normalize_casing(&node_name),
+                    renderer: Box::new(node),
+                    inherit_vars: false,
+                });
+            }
+            Err(ComponentParseError::NotAComponent) => {} // not a <component /> node, ignore
+            Err(e) => return Err(e), // Error during parsing the XML component, bail
+        }
+    }
+    Ok(())
+}
+
+/// Compiles a component library (`<component />` root nodes, without an `<html>` node)
+/// to a Rust module, which contains one `pub mod` per component
+pub fn str_to_rust_library<'a>(
+    root_nodes: &'a [XmlNode],
+    imports: &str,
+    component_map: &'a mut XmlComponentMap,
+) -> Result<String, CompileError> {
+    register_components(root_nodes, component_map)
+        .map_err(|e| CompileError::Xml(e.into()))?;
+
+    let library_imports = "#[allow(unused_imports)]
+use azul::css::*;
+#[allow(unused_imports)]
+use azul::str::String as AzString;
+#[allow(unused_imports)]
+use azul::vec::{
+    DomVec, IdOrClassVec, NodeDataInlineCssPropertyVec,
+    StyleBackgroundSizeVec, StyleBackgroundRepeatVec,
+    StyleBackgroundContentVec, StyleTransformVec,
+    StyleFontFamilyVec, StyleBackgroundPositionVec,
+    NormalizedLinearColorStopVec, NormalizedRadialColorStopVec,
+};
+#[allow(unused_imports)]
+use azul::dom::{
+    Dom, IdOrClass, TabIndex,
+    IdOrClass::{Id, Class},
+    NodeDataInlineCssProperty,
+};";
+
+    Ok(format!(
+        "//! Auto-generated component library\r\n{}\r\n{}\r\n\r\n{}\r\n",
+        imports,
+        library_imports,
+        compile_components(compile_components_to_rust_code(component_map)?),
+    ))
+}
+
 // Compile all components to source code
 pub fn compile_components(
     components: Vec<
@@ -1420,12 +1729,13 @@ pub fn compile_components(
             CompiledComponent,
             ComponentArguments,
             BTreeMap<String, String>,
+            UsesCallbackData,
         ),
     >,
 ) -> String {
-    let cs = components.iter().map(|(name, function_body, function_args, css_blocks)| {
+    let cs = components.iter().map(|(name, function_body, function_args, css_blocks, uses_callback_data)| {
         let name = &normalize_casing(&name);
-        let f = compile_component(name, function_args, function_body)
+        let f = compile_component(name, function_args, function_body, *uses_callback_data)
         .lines()
         .map(|l| format!("    {}", l))
         .collect::<Vec<String>>()
@@ -1433,7 +1743,7 @@ pub fn compile_components(
 
         // let css_blocks = ...
 
-        format!("#[allow(unused_imports)]\r\npub mod {} {{\r\n    use azul::dom::Dom;\r\n    use azul::str::String as AzString;\r\n{}\r\n}}", name, f)
+        format!("#[allow(unused_imports)]\r\npub mod {} {{\r\n    use super::*;\r\n    use azul::dom::Dom;\r\n    use azul::str::String as AzString;\r\n{}{}\r\n}}", name, if *uses_callback_data { CALLBACK_IMPORTS } else { "" }, f)
     }).collect::<Vec<String>>()
     .join("\r\n\r\n");
 
@@ -1446,27 +1756,40 @@ pub fn compile_components(
     if cs.is_empty() {
         cs
     } else {
-        format!("pub mod components {{\r\n{}\r\n}}", cs)
+        format!("pub mod components {{\r\n    use super::*;\r\n\r\n{}\r\n}}", cs)
     }
 }
 
+/// Formats the arguments in declaration order, which is also the order in which they are passed
 pub fn format_component_args(component_args: &ComponentArgumentTypes) -> String {
-    let mut args = component_args
+    component_args
         .iter()
         .map(|(arg_name, arg_type)| {
-            format!("{}: {}", arg_name, arg_type)
+            format!("{}: {}", arg_name, format_rust_argument_type(arg_type))
         })
-        .collect::<Vec<String>>();
-
-    args.sort_by(|a, b| b.cmp(&a));
+        .collect::<Vec<String>>()
+        .join(", ")
+}
 
-    args.join(", ")
+/// Formats an argument type as a Rust type: list types (`[String]`) are compiled to a `Vec<String>`,
+/// enums (`primary|secondary`) are passed as a `String`, files and inline file contents as bytes
+pub fn format_rust_argument_type(arg_type: &str) -> String {
+    match get_list_item_type(arg_type) {
+        Some(item_type) => format!("Vec<{}>", format_rust_argument_type(item_type)),
+        None if get_enum_variants(arg_type).is_some() => "String".to_string(),
+        None if arg_type == FILE_ARGUMENT_TYPE || arg_type == BYTES_ARGUMENT_TYPE => "&'static [u8]".to_string(),
+        None => arg_type.to_string(),
+    }
 }
 
+/// Imports of the code generated by `set_stringified_callbacks`
+const CALLBACK_IMPORTS: &str = "    use azul::callbacks::RefAny;\r\n    use azul::dom::{EventFilter, HoverEventFilter, FocusEventFilter, WindowEventFilter, NotEventFilter};\r\n";
+
 pub fn compile_component(
     component_name: &str,
     component_args: &ComponentArguments,
     component_function_body: &str,
+    uses_callback_data: bool,
 ) -> String {
     let component_name = &normalize_casing(&component_name);
     let function_args = format_component_args(&component_args.args);
@@ -1477,8 +1800,14 @@ pub fn compile_component(
         .join("\r\n");
     let should_inline = component_function_body.lines().count() == 1;
     format!(
-        "{}pub fn render({}{}{}) -> Dom {{\r\n{}\r\n}}",
+        "{}pub fn render({}{}{}{}) -> Dom {{\r\n{}\r\n}}",
         if should_inline { "#[inline]\r\n" } else { "" },
+        // the callback data is passed before the text and the component arguments
+        match (uses_callback_data, component_args.accepts_text || !function_args.is_empty()) {
+            (true, true) => "data: &RefAny, ",
+            (true, false) => "data: &RefAny",
+            (false, _) => "",
+        },
         // pass the text content as the first
         if component_args.accepts_text {
             "text: AzString"
@@ -1508,13 +1837,12 @@ pub fn render_dom_from_body_node<'a>(
         dom.restyle(CssApiWrapper::from_string(format!("body, html {{ max-width: {max_width}px; }}").into()));
     }
 
-    for child_node in body_node.children.as_ref() {
-        dom.append_child(render_dom_from_body_node_inner(
-            child_node,
-            component_map,
-            &FilteredComponentArguments::default(),
-        )?);
-    }
+    render_dom_children(
+        &mut dom,
+        body_node.children.as_ref(),
+        component_map,
+        &FilteredComponentArguments::default(),
+    )?;
 
     if let Some(global_css) = global_css.clone() {
         dom.restyle(global_css); // apply the CSS again
@@ -1543,37 +1871,209 @@ pub fn render_dom_from_body_node_inner<'a>(
     // Arguments of the current node
     let available_function_args = xml_component.renderer.get_available_arguments();
     let mut filtered_xml_attributes =
-        validate_and_filter_component_args(&xml_node.attributes, &available_function_args)?;
+        validate_and_filter_component_args(&xml_node.attributes, &available_function_args)
+        .map_err(|e| RenderDomError::InvalidNode(xml_node.span, e))?;
 
     if xml_component.inherit_vars {
         // Append all variables that are in scope for the parent node
         filtered_xml_attributes
             .types
             .extend(parent_xml_attributes.types.clone().into_iter());
+        for (name, value) in parent_xml_attributes.get_variables() {
+            filtered_xml_attributes.values.entry(name).or_insert(value);
+        }
     }
 
     // Instantiate the parent arguments in the current child arguments
-    for v in filtered_xml_attributes.types.iter_mut() {
-        v.1 = format_args_dynamic(&v.1, &parent_xml_attributes.types).to_string();
+    let parent_variables = parent_xml_attributes.get_variables();
+    for (name, v) in filtered_xml_attributes.values.iter_mut() {
+        if !v.contains('{') {
+            continue;
+        }
+        *v = format_args_dynamic(v, &parent_variables);
+        if let Some((_, arg_type)) = filtered_xml_attributes.types.iter().find(|t| t.0 == *name) {
+            validate_argument_value(name, arg_type, v)
+                .map_err(|e| RenderDomError::InvalidNode(xml_node.span, e))?;
+        }
     }
 
     let text = xml_node
         .text
         .as_ref()
-        .map(|t| AzString::from(format_args_dynamic(t, &filtered_xml_attributes.types)));
+        .map(|t| AzString::from(format_args_dynamic(t, &filtered_xml_attributes.get_variables())));
+
+    let mut children = Vec::new();
+    render_child_nodes(
+        &mut children,
+        xml_node.children.as_ref(),
+        component_map,
+        &filtered_xml_attributes,
+    )?;
 
-    let mut dom = xml_component.renderer.render_dom(component_map, &filtered_xml_attributes, &text.into())?;
+    let mut dom = xml_component.renderer
+        .render_dom_with_children(component_map, &filtered_xml_attributes, &text.into(), children)
+        .map_err(|e| e.with_node_span(xml_node.span))?;
     set_attributes(&mut dom, &xml_node.attributes, &filtered_xml_attributes);
+    set_callbacks(&mut dom, &xml_node.attributes, &component_map.callbacks)
+        .map_err(|e| RenderDomError::InvalidNode(xml_node.span, e))?;
+
+    Ok(dom)
+}
 
-    for child_node in xml_node.children.as_ref() {
-        dom.append_child(render_dom_from_body_node_inner(
-            child_node,
-            component_map,
-            &filtered_xml_attributes,
-        )?);
+/// Renders the child nodes and appends them to the `dom`, expanding the
+/// `<if condition="...">`, `<else>` and `<for each="item in items">` nodes
+pub fn render_dom_children<'a>(
+    dom: &mut StyledDom,
+    children: &'a [XmlNode],
+    component_map: &'a XmlComponentMap,
+    parent_xml_attributes: &FilteredComponentArguments,
+) -> Result<(), RenderDomError> {
+    let mut rendered_children = Vec::new();
+    render_child_nodes(&mut rendered_children, children, component_map, parent_xml_attributes)?;
+    for child in rendered_children {
+        dom.append_child(child);
     }
+    Ok(())
+}
 
-    Ok(dom)
+/// Same as `render_dom_children`, but pushes the rendered child nodes into `rendered_children`
+fn render_child_nodes<'a>(
+    rendered_children: &mut Vec<StyledDom>,
+    children: &'a [XmlNode],
+    component_map: &'a XmlComponentMap,
+    parent_xml_attributes: &FilteredComponentArguments,
+) -> Result<(), RenderDomError> {
+
+    // result of the last <if> node, None if the last node was not an <if>
+    let mut last_condition = None;
+
+    for child_node in children {
+        let invalid_node = |e| RenderDomError::InvalidNode(child_node.span, e);
+        match normalize_casing(&child_node.node_type).as_str() {
+            "if" => {
+                let condition = find_attribute(child_node, "condition")
+                    .ok_or(ComponentError::InvalidExpression("".into(), "<if> has no \"condition\" attribute".into()))
+                    .map_err(invalid_node)?;
+                let condition = evaluate_condition(condition.as_str(), &parent_xml_attributes.get_variables())
+                    .map_err(invalid_node)?;
+                if condition {
+                    render_child_nodes(rendered_children, child_node.children.as_ref(), component_map, parent_xml_attributes)?;
+                }
+                last_condition = Some(condition);
+            },
+            "else" => {
+                match last_condition.take() {
+                    Some(false) => render_child_nodes(rendered_children, child_node.children.as_ref(), component_map, parent_xml_attributes)?,
+                    Some(true) => { },
+                    None => return Err(invalid_node(ComponentError::ElseWithoutIf)),
+                }
+            },
+            "for" => {
+                let each = find_attribute(child_node, "each")
+                    .ok_or(ComponentError::InvalidExpression("".into(), "<for> has no \"each\" attribute".into()))
+                    .map_err(invalid_node)?;
+                let (item_name, list_name) = parse_for_each(each.as_str()).map_err(invalid_node)?;
+                let list_type = parent_xml_attributes.types.iter()
+                    .find(|t| t.0 == list_name)
+                    .ok_or(ComponentError::UnknownVariable(list_name.clone().into()))
+                    .map_err(invalid_node)?;
+                let item_type = get_list_item_type(&list_type.1).ok_or_else(|| {
+                    ComponentError::InvalidExpression(each.clone(), format!("\"{}\" is a {}, not a list", list_name, list_type.1).into())
+                }).map_err(invalid_node)?;
+                // a list argument that wasn't passed to the component is empty
+                let items = parent_xml_attributes.values.get(&list_name)
+                    .map(|v| split_list_value(v))
+                    .unwrap_or_default();
+
+                for item in items {
+                    let mut item_attributes = parent_xml_attributes.clone();
+                    item_attributes.types.insert(0, (item_name.clone(), item_type.to_string()));
+                    item_attributes.values.insert(item_name.clone(), item);
+                    render_child_nodes(rendered_children, child_node.children.as_ref(), component_map, &item_attributes)?;
+                }
+                last_condition = None;
+            },
+            _ => {
+                rendered_children.push(render_dom_from_body_node_inner(
+                    child_node,
+                    component_map,
+                    parent_xml_attributes,
+                )?);
+                last_condition = None;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks the nodes of the `<body>` and of all XML components in the `component_map` without
+/// rendering them: every node has to be a known component, the argument values without variables
+/// have to match the argument types, all `on:event` handlers have to be registered and the
+/// `<if>`, `<else>` and `<for>` nodes have to be well-formed.
+///
+/// Unlike `str_to_dom`, this doesn't stop at the first error, but returns the errors of all nodes.
+/// Values that contain variables are only known (and checked) when the DOM is rendered.
+pub fn validate_xml_nodes(root_nodes: &[XmlNode], component_map: &XmlComponentMap) -> Vec<RenderDomError> {
+    let mut errors = Vec::new();
+
+    if let Ok(body_node) = get_html_node(root_nodes).and_then(|html| get_body_node(html.children.as_ref())) {
+        validate_xml_children(body_node.children.as_ref(), component_map, &mut errors);
+    }
+
+    for component in component_map.components.iter() {
+        validate_xml_children(component.renderer.get_xml_node().children.as_ref(), component_map, &mut errors);
+    }
+
+    errors
+}
+
+fn validate_xml_children(children: &[XmlNode], component_map: &XmlComponentMap, errors: &mut Vec<RenderDomError>) {
+
+    let mut last_node_type = String::new();
+
+    for child_node in children {
+        let node_type = normalize_casing(&child_node.node_type);
+        let error = match node_type.as_str() {
+            // the <style> of a component is not rendered
+            "style" => None,
+            "if" => find_attribute(child_node, "condition")
+                .is_none()
+                .then(|| ComponentError::InvalidExpression("".into(), "<if> has no \"condition\" attribute".into())),
+            "else" if last_node_type != "if" => Some(ComponentError::ElseWithoutIf),
+            "else" => None,
+            "for" => match find_attribute(child_node, "each") {
+                Some(each) => parse_for_each(each.as_str()).err(),
+                None => Some(ComponentError::InvalidExpression("".into(), "<for> has no \"each\" attribute".into())),
+            },
+            _ => validate_xml_node(child_node, &node_type, component_map).err(),
+        };
+
+        if let Some(e) = error {
+            errors.push(RenderDomError::InvalidNode(child_node.span, e));
+        }
+
+        validate_xml_children(child_node.children.as_ref(), component_map, errors);
+        last_node_type = node_type;
+    }
+}
+
+fn validate_xml_node(xml_node: &XmlNode, component_name: &str, component_map: &XmlComponentMap) -> Result<(), ComponentError> {
+    let xml_component = component_map
+        .components
+        .iter()
+        .find(|s| normalize_casing(&s.id) == component_name)
+        .ok_or(ComponentError::UnknownComponent(component_name.to_string().into()))?;
+
+    validate_and_filter_component_args(&xml_node.attributes, &xml_component.renderer.get_available_arguments())?;
+
+    for (_, handler) in get_event_handlers(&xml_node.attributes) {
+        if component_map.callbacks.get_callback(&handler).is_none() {
+            return Err(ComponentError::UnknownCallback(handler.into()));
+        }
+    }
+
+    Ok(())
 }
 
 pub fn set_attributes(
@@ -1584,17 +2084,20 @@ pub fn set_attributes(
     use crate::dom::IdOrClass::{Class, Id};
     use crate::dom::TabIndex;
 
-    let mut ids_and_classes = Vec::new();
+    let variables = filtered_xml_attributes.get_variables();
     let dom_root = match dom.root.into_crate_internal() {
         Some(s) => s,
         None => return,
     };
     let node_data = &mut dom.node_data.as_container_mut()[dom_root];
 
+    // keep the ids and classes that the component itself set on its root node
+    let mut ids_and_classes = node_data.get_ids_and_classes().clone().into_library_owned_vec();
+
     if let Some(ids) = xml_attributes.get_key("id") {
         for id in ids.split_whitespace() {
             ids_and_classes.push(Id(
-                format_args_dynamic(id, &filtered_xml_attributes.types).into()
+                format_args_dynamic(id, &variables).into()
             ));
         }
     }
@@ -1602,7 +2105,7 @@ pub fn set_attributes(
     if let Some(classes) = xml_attributes.get_key("class") {
         for class in classes.split_whitespace() {
             ids_and_classes.push(Class(
-                format_args_dynamic(class, &filtered_xml_attributes.types).into(),
+                format_args_dynamic(class, &variables).into(),
             ));
         }
     }
@@ -1611,7 +2114,7 @@ pub fn set_attributes(
 
     if let Some(focusable) = xml_attributes
         .get_key("focusable")
-        .map(|f| format_args_dynamic(f.as_str(), &filtered_xml_attributes.types))
+        .map(|f| format_args_dynamic(f.as_str(), &variables))
         .and_then(|f| parse_bool(&f))
     {
         match focusable {
@@ -1622,7 +2125,7 @@ pub fn set_attributes(
 
     if let Some(tab_index) = xml_attributes
         .get_key("tabindex")
-        .map(|val| format_args_dynamic(val, &filtered_xml_attributes.types))
+        .map(|val| format_args_dynamic(val, &variables))
         .and_then(|val| val.parse::<isize>().ok())
     {
         match tab_index {
@@ -1953,58 +2456,440 @@ pub fn parse_bool(input: &str) -> Option<bool> {
     }
 }
 
-pub fn render_component_inner<'a>(
-    map: &mut Vec<
-        (
-            ComponentName,
-            CompiledComponent,
-            ComponentArguments,
-            BTreeMap<String, String>,
-        ),
-    >,
-    component_name: String,
-    xml_component: &'a XmlComponent,
-    component_map: &'a XmlComponentMap,
-    parent_xml_attributes: &ComponentArguments,
-    tabs: usize,
-) -> Result<(), CompileError> {
-
-    let t = String::from("    ").repeat(tabs - 1);
-    let t1 = String::from("    ").repeat(tabs);
+/// Prefix of the attributes that bind an event handler, i.e. `on:leftmouseup="handler_name"`
+pub const EVENT_ATTRIBUTE_PREFIX: &str = "on:";
 
-    let component_name = normalize_casing(&component_name);
-    let xml_node = xml_component.renderer.get_xml_node();
+/// Parses the event name of an `on:event` attribute ("leftmouseup", "left-mouse-up",
+/// "LeftMouseUp", ...) to the `EventFilter` that the handler is bound to:
+///
+/// - mouse, scroll, file and touch events fire when the node is hovered (`EventFilter::Hover`)
+/// - `textinput`, `virtualkeydown` / `virtualkeyup`, `focusreceived` / `focuslost`
+///   fire when the node is focused (`EventFilter::Focus`)
+/// - events prefixed with `window-` fire regardless of the hovered / focused node (`EventFilter::Window`)
+/// - events prefixed with `not-` fire when the node is not hovered (`EventFilter::Not`)
+pub fn parse_event_filter(event: &str) -> Option<EventFilter> {
+    let event = event
+        .chars()
+        .filter(|c| *c != '-' && *c != '_')
+        .flat_map(|c| c.to_lowercase())
+        .collect::<String>();
+
+    if let Some(window_event) = event.strip_prefix("window") {
+        return parse_window_event_filter(window_event).map(EventFilter::Window);
+    }
 
-    let mut css = match find_node_by_type(xml_node.children.as_ref(), "style")
-        .and_then(|style_node| style_node.text.as_ref().map(|s| s.as_str()))
-    {
-        Some(text) => azul_css_parser::new_from_str(&text).map_err(|e| e.to_contained())?,
-        None => Css::empty(),  
-    };
+    if let Some(not_event) = event.strip_prefix("not") {
+        return parse_hover_event_filter(not_event).map(|e| EventFilter::Not(NotEventFilter::Hover(e)));
+    }
 
-    css.sort_by_specificity();
+    match event.as_str() {
+        "textinput" => Some(EventFilter::Focus(FocusEventFilter::TextInput)),
+        "virtualkeydown" => Some(EventFilter::Focus(FocusEventFilter::VirtualKeyDown)),
+        "virtualkeyup" => Some(EventFilter::Focus(FocusEventFilter::VirtualKeyUp)),
+        "focusreceived" => Some(EventFilter::Focus(FocusEventFilter::FocusReceived)),
+        "focuslost" => Some(EventFilter::Focus(FocusEventFilter::FocusLost)),
+        other => parse_hover_event_filter(other).map(EventFilter::Hover),
+    }
+}
 
-    // Arguments of the current node
-    let available_function_arg_types = xml_component.renderer.get_available_arguments();
-    // Types of the filtered xml arguments, important, only for Rust code compilation
-    let mut filtered_xml_attributes = available_function_arg_types.clone(); 
+fn parse_hover_event_filter(event: &str) -> Option<HoverEventFilter> {
+    use self::HoverEventFilter::*;
+    Some(match event {
+        "mouseover" => MouseOver,
+        "mousedown" => MouseDown,
+        "leftmousedown" => LeftMouseDown,
+        "rightmousedown" => RightMouseDown,
+        "middlemousedown" => MiddleMouseDown,
+        "mouseup" => MouseUp,
+        "leftmouseup" => LeftMouseUp,
+        "rightmouseup" => RightMouseUp,
+        "middlemouseup" => MiddleMouseUp,
+        "mouseenter" => MouseEnter,
+        "mouseleave" => MouseLeave,
+        "scroll" => Scroll,
+        "scrollstart" => ScrollStart,
+        "scrollend" => ScrollEnd,
+        "textinput" => TextInput,
+        "virtualkeydown" => VirtualKeyDown,
+        "virtualkeyup" => VirtualKeyUp,
+        "hoveredfile" => HoveredFile,
+        "droppedfile" => DroppedFile,
+        "hoveredfilecancelled" => HoveredFileCancelled,
+        "touchstart" => TouchStart,
+        "touchmove" => TouchMove,
+        "touchend" => TouchEnd,
+        "touchcancel" => TouchCancel,
+        _ => return None,
+    })
+}
 
-    if xml_component.inherit_vars {
-        // Append all variables that are in scope for the parent node
-        filtered_xml_attributes
-            .args
-            .extend(parent_xml_attributes.args.clone().into_iter());
-    }
+fn parse_window_event_filter(event: &str) -> Option<WindowEventFilter> {
+    use self::WindowEventFilter::*;
+    Some(match event {
+        "mouseover" => MouseOver,
+        "mousedown" => MouseDown,
+        "leftmousedown" => LeftMouseDown,
+        "rightmousedown" => RightMouseDown,
+        "middlemousedown" => MiddleMouseDown,
+        "mouseup" => MouseUp,
+        "leftmouseup" => LeftMouseUp,
+        "rightmouseup" => RightMouseUp,
+        "middlemouseup" => MiddleMouseUp,
+        "mouseenter" => MouseEnter,
+        "mouseleave" => MouseLeave,
+        "scroll" => Scroll,
+        "scrollstart" => ScrollStart,
+        "scrollend" => ScrollEnd,
+        "textinput" => TextInput,
+        "virtualkeydown" => VirtualKeyDown,
+        "virtualkeyup" => VirtualKeyUp,
+        "hoveredfile" => HoveredFile,
+        "droppedfile" => DroppedFile,
+        "hoveredfilecancelled" => HoveredFileCancelled,
+        "resized" => Resized,
+        "moved" => Moved,
+        "touchstart" => TouchStart,
+        "touchmove" => TouchMove,
+        "touchend" => TouchEnd,
+        "touchcancel" => TouchCancel,
+        "focusreceived" => WindowFocusReceived,
+        "focuslost" => WindowFocusLost,
+        "closerequested" => CloseRequested,
+        "themechanged" => ThemeChanged,
+        _ => return None,
+    })
+}
 
-    // Instantiate the parent arguments in the current child arguments
-    for v in filtered_xml_attributes.args.iter_mut() {
-        v.1 = format_args_dynamic(&v.1, &parent_xml_attributes.args).to_string();
+/// Formats the `EventFilter` as Rust code, i.e. `EventFilter::Hover(HoverEventFilter::LeftMouseUp)`
+pub fn format_event_filter(event: &EventFilter) -> String {
+    match event {
+        EventFilter::Hover(e) => format!("EventFilter::Hover(HoverEventFilter::{:?})", e),
+        EventFilter::Focus(e) => format!("EventFilter::Focus(FocusEventFilter::{:?})", e),
+        EventFilter::Window(e) => format!("EventFilter::Window(WindowEventFilter::{:?})", e),
+        EventFilter::Not(NotEventFilter::Hover(e)) => format!("EventFilter::Not(NotEventFilter::Hover(HoverEventFilter::{:?}))", e),
+        EventFilter::Not(NotEventFilter::Focus(e)) => format!("EventFilter::Not(NotEventFilter::Focus(FocusEventFilter::{:?}))", e),
+        other => format!("EventFilter::{:?}", other),
     }
+}
 
-    let text = xml_node
-        .text
+/// Returns the `(event, handler_name)` of all `on:event="handler_name"` attributes of the node
+pub fn get_event_handlers(xml_attributes: &XmlAttributeMap) -> Vec<(EventFilter, String)> {
+    xml_attributes
         .as_ref()
-        .map(|t| AzString::from(format_args_dynamic(t, &filtered_xml_attributes.args)));
+        .iter()
+        .filter_map(|AzStringPair { key, value }| {
+            let event = key.as_str().strip_prefix(EVENT_ATTRIBUTE_PREFIX)?;
+            Some((parse_event_filter(event)?, value.as_str().trim().to_string()))
+        })
+        .collect()
+}
+
+/// Returns the names of all event handlers that the nodes (or the XML components
+/// used by the nodes) bind to, so that they can be registered before rendering
+pub fn get_event_handler_names(nodes: &[XmlNode], component_map: &XmlComponentMap) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut visited_components = Vec::new();
+    collect_event_handler_names(nodes, component_map, &mut visited_components, &mut names);
+    names
+}
+
+fn collect_event_handler_names(
+    nodes: &[XmlNode],
+    component_map: &XmlComponentMap,
+    visited_components: &mut Vec<String>,
+    names: &mut Vec<String>,
+) {
+    for node in nodes {
+        for (_, handler) in get_event_handlers(&node.attributes) {
+            if !names.contains(&handler) {
+                names.push(handler);
+            }
+        }
+
+        let component_name = normalize_casing(&node.node_type);
+        if !visited_components.contains(&component_name) {
+            if let Some(component) = component_map.components.iter().find(|c| normalize_casing(&c.id) == component_name) {
+                visited_components.push(component_name);
+                let component_node = component.renderer.get_xml_node();
+                collect_event_handler_names(component_node.children.as_ref(), component_map, visited_components, names);
+            }
+        }
+
+        collect_event_handler_names(node.children.as_ref(), component_map, visited_components, names);
+    }
+}
+
+/// Binds the `on:event="handler_name"` attributes to the callbacks
+/// registered in the `callbacks` and adds them to the root node of the `dom`
+pub fn set_callbacks(
+    dom: &mut StyledDom,
+    xml_attributes: &XmlAttributeMap,
+    callbacks: &XmlCallbackMap,
+) -> Result<(), ComponentError> {
+    let dom_root = match dom.root.into_crate_internal() {
+        Some(s) => s,
+        None => return Ok(()),
+    };
+    let node_data = &mut dom.node_data.as_container_mut()[dom_root];
+
+    for (event, handler) in get_event_handlers(xml_attributes) {
+        let (data, callback) = callbacks
+            .get_callback(&handler)
+            .ok_or(ComponentError::UnknownCallback(handler.clone().into()))?;
+        node_data.add_callback(event, data.clone(), callback.cb);
+    }
+
+    Ok(())
+}
+
+/// Appends a `.with_callback(event, data.clone(), handler_name)` call
+/// for each `on:event="handler_name"` attribute to the `dom_string`
+pub fn set_stringified_callbacks(
+    dom_string: &mut String,
+    xml_attributes: &XmlAttributeMap,
+    tabs: usize,
+) {
+    let t = String::from("    ").repeat(tabs + 1);
+    for (event, handler) in get_event_handlers(xml_attributes) {
+        dom_string.push_str(&format!(
+            "\r\n{}.with_callback({}, data.clone(), {})",
+            t, format_event_filter(&event), handler
+        ));
+    }
+}
+
+/// Operand of an `<if condition="...">` expression
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum ConditionOperand {
+    /// `name` or `{name}`: component argument or loop variable
+    Var(String),
+    /// `'text'`, `"text"`, `true` or `5`
+    Literal(String),
+}
+
+/// Parsed `<if condition="...">` expression: `[!] operand [(== | !=) operand]`
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Condition {
+    negate: bool,
+    lhs: ConditionOperand,
+    /// `(is_equal, rhs)` for `==` and `!=` comparisons
+    comparison: Option<(bool, ConditionOperand)>,
+}
+
+fn parse_condition(input: &str) -> Result<Condition, ComponentError> {
+
+    let invalid = |reason: &str| ComponentError::InvalidExpression(input.to_string().into(), reason.to_string().into());
+
+    let mut expr = input.trim();
+    let negate = expr.starts_with('!') && !expr.starts_with("!=");
+    if negate {
+        expr = expr[1..].trim();
+        if expr.starts_with('(') && expr.ends_with(')') {
+            expr = expr[1..(expr.len() - 1)].trim();
+        }
+    }
+
+    // find the comparison operator (outside of string literals)
+    let mut quote = None;
+    let mut operator = None;
+    let chars = expr.char_indices().collect::<Vec<_>>();
+    for (i, (byte_idx, c)) in chars.iter().enumerate() {
+        match (quote, *c) {
+            (None, '\'') | (None, '"') => quote = Some(*c),
+            (Some(q), c) if q == c => quote = None,
+            (None, '=') | (None, '!') if chars.get(i + 1).map(|n| n.1) == Some('=') => {
+                operator = Some((*byte_idx, *c == '='));
+                break;
+            },
+            _ => { },
+        }
+    }
+
+    let parse_operand = |s: &str| -> Result<ConditionOperand, ComponentError> {
+        let s = s.trim();
+        let is_quoted = s.len() >= 2 && (
+            (s.starts_with('\'') && s.ends_with('\'')) ||
+            (s.starts_with('"') && s.ends_with('"'))
+        );
+        if s.is_empty() {
+            Err(invalid("missing operand"))
+        } else if is_quoted {
+            Ok(ConditionOperand::Literal(s[1..(s.len() - 1)].to_string()))
+        } else if parse_bool(s).is_some() || s.parse::<f64>().is_ok() {
+            Ok(ConditionOperand::Literal(s.to_string()))
+        } else {
+            let name = s.strip_prefix('{').and_then(|s| s.strip_suffix('}')).unwrap_or(s).trim();
+            if !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-') {
+                Ok(ConditionOperand::Var(normalize_casing(name)))
+            } else {
+                Err(invalid(&format!("\"{}\" is not a variable name or a literal", s)))
+            }
+        }
+    };
+
+    let (lhs, comparison) = match operator {
+        Some((idx, is_equal)) => (
+            parse_operand(&expr[..idx])?,
+            Some((is_equal, parse_operand(&expr[(idx + 2)..])?)),
+        ),
+        None => (parse_operand(expr)?, None),
+    };
+
+    Ok(Condition { negate, lhs, comparison })
+}
+
+/// Evaluates an `<if condition="...">` expression given the values of the variables in scope
+///
+/// The condition is either a single bool (`visible`, `!{visible}`) or a comparison of two
+/// operands (`kind == 'primary'`, `count != 0`). Operands are variables or literals,
+/// numbers are compared numerically, everything else is compared as a string.
+pub fn evaluate_condition(condition: &str, variables: &ComponentArgumentTypes) -> Result<bool, ComponentError> {
+
+    let parsed = parse_condition(condition)?;
+
+    let resolve = |operand: &ConditionOperand| -> Result<String, ComponentError> {
+        match operand {
+            ConditionOperand::Var(name) => variables.iter()
+                .find(|v| v.0 == *name)
+                .map(|v| v.1.trim().to_string())
+                .ok_or(ComponentError::UnknownVariable(name.clone().into())),
+            ConditionOperand::Literal(l) => Ok(l.clone()),
+        }
+    };
+
+    let lhs = resolve(&parsed.lhs)?;
+
+    let result = match parsed.comparison.as_ref() {
+        Some((is_equal, rhs)) => {
+            let rhs = resolve(rhs)?;
+            let equal = match (lhs.parse::<f64>(), rhs.parse::<f64>()) {
+                (Ok(a), Ok(b)) => a == b,
+                _ => lhs == rhs,
+            };
+            equal == *is_equal
+        },
+        None => parse_bool(&lhs).ok_or_else(|| {
+            ComponentError::InvalidExpression(condition.into(), format!("\"{}\" is not a bool", lhs).into())
+        })?,
+    };
+
+    Ok(result != parsed.negate)
+}
+
+/// Compiles an `<if condition="...">` expression to a Rust expression, i.e. `!visible` or `kind == "primary"`
+pub fn compile_condition_to_rust_code(condition: &str) -> Result<String, ComponentError> {
+
+    let parsed = parse_condition(condition)?;
+
+    let format_operand = |operand: &ConditionOperand| match operand {
+        ConditionOperand::Var(name) => name.clone(),
+        ConditionOperand::Literal(l) if parse_bool(l).is_some() || l.parse::<f64>().is_ok() => l.clone(),
+        ConditionOperand::Literal(l) => format!("\"{}\"", l.replace('\\', "\\\\").replace('"', "\\\"")),
+    };
+
+    let lhs = format_operand(&parsed.lhs);
+
+    Ok(match (parsed.comparison.as_ref(), parsed.negate) {
+        (None, false) => lhs,
+        (None, true) => format!("!{}", lhs),
+        (Some((is_equal, rhs)), negate) => {
+            let comparison = format!("{} {} {}", lhs, if *is_equal { "==" } else { "!=" }, format_operand(rhs));
+            if negate { format!("!({})", comparison) } else { comparison }
+        },
+    })
+}
+
+/// Parses the `each="item in items"` attribute of a `<for>` node into `(item, items)`
+pub fn parse_for_each(each: &str) -> Result<(String, String), ComponentError> {
+
+    let invalid = || ComponentError::InvalidExpression(
+        each.to_string().into(),
+        "expected \"item in list\"".to_string().into(),
+    );
+
+    let is_name = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-');
+
+    match each.split_whitespace().collect::<Vec<_>>().as_slice() {
+        [item, "in", list] => {
+            let list = list.strip_prefix('{').and_then(|s| s.strip_suffix('}')).unwrap_or(list);
+            if is_name(item) && is_name(list) {
+                Ok((normalize_casing(item), normalize_casing(list)))
+            } else {
+                Err(invalid())
+            }
+        },
+        _ => Err(invalid()),
+    }
+}
+
+/// Returns the item type of a list argument type, i.e. `String` for `[String]`
+pub fn get_list_item_type(arg_type: &str) -> Option<&str> {
+    arg_type.trim().strip_prefix('[')?.strip_suffix(']').map(|s| s.trim())
+}
+
+/// Splits the value of a list argument (`"a, b, c"` or `"[a, b, c]"`) into its items
+pub fn split_list_value(value: &str) -> Vec<String> {
+    let value = value.trim();
+    let value = value.strip_prefix('[').and_then(|s| s.strip_suffix(']')).unwrap_or(value);
+    if value.trim().is_empty() {
+        return Vec::new();
+    }
+    value.split(',').map(|s| s.trim().to_string()).collect()
+}
+
+pub fn render_component_inner<'a>(
+    map: &mut Vec<
+        (
+            ComponentName,
+            CompiledComponent,
+            ComponentArguments,
+            BTreeMap<String, String>,
+            UsesCallbackData,
+        ),
+    >,
+    component_name: String,
+    xml_component: &'a XmlComponent,
+    component_map: &'a XmlComponentMap,
+    parent_xml_attributes: &ComponentArguments,
+    tabs: usize,
+) -> Result<(), CompileError> {
+
+    let t = String::from("    ").repeat(tabs - 1);
+    let t1 = String::from("    ").repeat(tabs);
+
+    let component_name = normalize_casing(&component_name);
+    let xml_node = xml_component.renderer.get_xml_node();
+
+    let mut css = match find_node_by_type(xml_node.children.as_ref(), "style")
+        .and_then(|style_node| style_node.text.as_ref().map(|s| s.as_str()))
+    {
+        Some(text) => azul_css_parser::new_from_str(&text).map_err(|e| e.to_contained())?,
+        None => Css::empty(),  
+    };
+
+    css.sort_by_specificity();
+
+    // Arguments of the current node
+    let available_function_arg_types = xml_component.renderer.get_available_arguments();
+    // Types of the filtered xml arguments, important, only for Rust code compilation
+    let mut filtered_xml_attributes = available_function_arg_types.clone(); 
+
+    if xml_component.inherit_vars {
+        // Append all variables that are in scope for the parent node
+        filtered_xml_attributes
+            .args
+            .extend(parent_xml_attributes.args.clone().into_iter());
+    }
+
+    // Instantiate the parent arguments in the current child arguments
+    for v in filtered_xml_attributes.args.iter_mut() {
+        v.1 = format_args_dynamic(&v.1, &parent_xml_attributes.args).to_string();
+    }
+
+    let text = xml_node
+        .text
+        .as_ref()
+        .map(|t| AzString::from(format_args_dynamic(t, &filtered_xml_attributes.args)));
 
     let mut dom_string = xml_component.renderer
         .compile_to_rust_code(component_map, &filtered_xml_attributes, &text.into())?;
@@ -2016,6 +2901,8 @@ pub fn render_component_inner<'a>(
         tabs,
     );
 
+    let uses_callback_data = !get_event_handler_names(xml_node.children.as_ref(), component_map).is_empty();
+
     // TODO
     let matcher = CssMatcher {
         path: vec![CssPathSelector::Type(NodeTypeTag::Body)],
@@ -2026,7 +2913,22 @@ pub fn render_component_inner<'a>(
     let mut css_blocks = BTreeMap::new();
     let mut extra_blocks = VecContents::default();
 
-    if !xml_node.children.as_ref().is_empty() {
+    if has_control_flow_nodes(xml_node.children.as_ref()) {
+        dom_string.push_str(&format!(
+            "\r\n{}.with_children(DomVec::from_vec({}))",
+            t,
+            compile_control_flow_children_to_rust_code(
+                xml_node.children.as_ref(),
+                component_map,
+                &filtered_xml_attributes,
+                tabs,
+                &mut extra_blocks,
+                &mut css_blocks,
+                &css,
+                &matcher,
+            )?
+        ));
+    } else if !xml_node.children.as_ref().is_empty() {
         dom_string.push_str(&format!(
             "\r\n{}.with_children(DomVec::from_vec(vec![\r\n",
             t
@@ -2060,7 +2962,8 @@ pub fn render_component_inner<'a>(
         component_name, 
         dom_string, 
         filtered_xml_attributes, 
-        css_blocks
+        css_blocks,
+        uses_callback_data,
     ));
 
     Ok(())
@@ -2076,6 +2979,7 @@ pub fn compile_components_to_rust_code(
             CompiledComponent,
             ComponentArguments,
             BTreeMap<String, String>,
+            UsesCallbackData,
         ),
     >,
     CompileError,
@@ -2378,7 +3282,21 @@ pub fn compile_body_node_to_rust_code<'a>(
         ));
     }
 
-    if !body_node.children.as_ref().is_empty() {
+    if has_control_flow_nodes(body_node.children.as_ref()) {
+        dom_string.push_str(&format!(
+            "\r\n.with_children(DomVec::from_vec({}))",
+            compile_control_flow_children_to_rust_code(
+                body_node.children.as_ref(),
+                component_map,
+                &ComponentArguments::default(),
+                1,
+                extra_blocks,
+                css_blocks,
+                css,
+                &matcher,
+            )?
+        ));
+    } else if !body_node.children.as_ref().is_empty() {
         use crate::css::GetHash;
         let children_hash = body_node.children.as_ref().get_hash();
         dom_string.push_str(&format!("\r\n.with_children(DomVec::from_vec(vec![\r\n"));
@@ -2480,6 +3398,65 @@ fn format_args_for_rust_code(input: &str) -> String {
     compile_and_format_dynamic_items(&dynamic_str_items)
 }
 
+// List arguments are passed as a `Vec`: `"a, b"` => `vec![String::from("a"), String::from("b")]`,
+// a single `{variable}` is passed through as-is
+fn format_list_arg_for_rust_code(item_type: &str, input: &str) -> String {
+    if let [DynamicItem::Var(v)] = split_dynamic_string(input.trim()).as_slice() {
+        return normalize_casing(v.trim());
+    }
+    let items = split_list_value(input)
+        .iter()
+        .map(|item| match item_type {
+            "String" => format!("String::from({:?})", item),
+            _ => item.clone(),
+        })
+        .collect::<Vec<_>>();
+    format!("vec![{}]", items.join(", "))
+}
+
+// Compiles the XML value of a component argument to a Rust expression of the
+// argument type, types that are not builtin are compiled by the component
+fn format_arg_value_for_rust_code(
+    renderer: &dyn XmlComponentTrait,
+    arg_name: &str,
+    arg_type: &str,
+    value: &str,
+) -> Result<String, ComponentError> {
+
+    if arg_type == "String" || get_enum_variants(arg_type).is_some() {
+        return Ok(format_args_for_rust_code(value));
+    }
+
+    if let Some(item_type) = get_list_item_type(arg_type) {
+        return Ok(format_list_arg_for_rust_code(item_type, value));
+    }
+
+    // "{variable}" is passed through as-is
+    if let [DynamicItem::Var(v)] = split_dynamic_string(value.trim()).as_slice() {
+        return Ok(normalize_casing(v.trim()));
+    }
+
+    let invalid = || ComponentError::InvalidArgumentValue(arg_name.into(), value.to_string().into(), arg_type.into());
+    let value_trimmed = value.trim();
+
+    match arg_type {
+        FILE_ARGUMENT_TYPE => Ok(format!("include_bytes!({:?})", value)),
+        BYTES_ARGUMENT_TYPE => Ok(format!("{:?}.as_bytes()", value)),
+        "bool" => parse_bool(value_trimmed).map(|b| b.to_string()).ok_or_else(invalid),
+        "u8" | "u16" | "u32" | "u64" | "usize" |
+        "i8" | "i16" | "i32" | "i64" | "isize" => Ok(value_trimmed.to_string()),
+        // "50" is not a valid f32 literal, "50.0" is
+        "f32" | "f64" => value_trimmed.parse::<f64>().map(|f| format!("{:?}", f)).map_err(|_| invalid()),
+        "ColorU" => azul_css_parser::parse_css_color(value_trimmed)
+            .map(|c| format!("ColorU {{ r: {}, g: {}, b: {}, a: {} }}", c.r, c.g, c.b, c.a))
+            .map_err(|_| invalid()),
+        "PixelValue" => azul_css_parser::parse_pixel_value(value_trimmed)
+            .map(|p| format!("PixelValue::from_metric(SizeMetric::{:?}, {:?})", p.metric, p.number.get()))
+            .map_err(|_| invalid()),
+        _ => renderer.compile_argument_to_rust_code(arg_name, arg_type, value),
+    }
+}
+
 pub fn compile_node_to_rust_code_inner<'a>(
     node: &XmlNode,
     component_map: &'a XmlComponentMap,
@@ -2509,7 +3486,8 @@ pub fn compile_node_to_rust_code_inner<'a>(
     // Arguments of the current node
     let available_function_args = xml_component.renderer.get_available_arguments();
     let mut filtered_xml_attributes =
-        validate_and_filter_component_args(&node.attributes, &available_function_args)?;
+        validate_and_filter_component_args(&node.attributes, &available_function_args)
+        .map_err(|e| RenderDomError::InvalidNode(node.span, e))?;
 
     if xml_component.inherit_vars {
         // Append all variables that are in scope for the parent node
@@ -2523,31 +3501,21 @@ pub fn compile_node_to_rust_code_inner<'a>(
         v.1 = format_args_dynamic(&v.1, &parent_xml_attributes.args).to_string();
     }
 
-    let instantiated_function_arguments = {
-        let mut args = filtered_xml_attributes
-            .types
-            .iter()
-            .filter_map(
-                |(xml_attribute_key, _xml_attribute_type)| {
-                    match node.attributes.get_key(xml_attribute_key).cloned() {
-                        Some(s) => Some(format_args_for_rust_code(&s)),
-                        None => {
-                            // __TODO__
-                            // let node_text = format_args_for_rust_code(&xml_attribute_key);
-                            //   "{text}" => "text"
-                            //   "{href}" => "href"
-                            //   "{blah}_the_thing" => "format!(\"{blah}_the_thing\", blah)"
-                            None
-                        }
-                    }
-                },
-            )
-            .collect::<Vec<String>>();
-
-        args.sort_by(|a, b| a.cmp(&b));
-
-        args.join(", ")
-    };
+    // the arguments are passed in declaration order (the order of the parameters
+    // of the compiled render() function), arguments without a value are defaulted
+    let instantiated_function_arguments = available_function_args.args
+        .iter()
+        .map(|(arg_name, arg_type)| {
+            let value = filtered_xml_attributes.values.get(arg_name).map(|s| AzString::from(s.as_str()))
+                .or_else(|| node.attributes.get_key(arg_name).cloned());
+            match value {
+                Some(s) => format_arg_value_for_rust_code(&*xml_component.renderer, arg_name, arg_type, s.as_str()),
+                None => Ok(String::from("Default::default()")),
+            }
+        })
+        .collect::<Result<Vec<String>, ComponentError>>()
+        .map_err(|e| RenderDomError::InvalidNode(node.span, e))?
+        .join(", ");
 
     let text_as_first_arg = if filtered_xml_attributes.accepts_text {
         let node_text = node.text.clone().into_option().unwrap_or_default();
@@ -2574,18 +3542,27 @@ pub fn compile_node_to_rust_code_inner<'a>(
         "div" => NodeTypeTag::Div,
         "br" => NodeTypeTag::Br,
         "p" => NodeTypeTag::P,
-        "img" => NodeTypeTag::Img,
-        other => {
-            return Err(CompileError::Dom(RenderDomError::Component(
-                ComponentError::UnknownComponent(other.to_string().into()),
-            )))
-        }
+        // inline <svg> nodes are rendered to an image
+        "img" | "svg" => NodeTypeTag::Img,
+        // custom components are rendered into a <body> root node, see DynamicXmlComponent::render_dom
+        _ => NodeTypeTag::Body,
     });
 
+    // components that bind event handlers need the callback data as the first argument
+    let uses_callback_data = !get_event_handler_names(
+        xml_component.renderer.get_xml_node().children.as_ref(),
+        component_map,
+    ).is_empty();
+    let callback_data_arg = match (uses_callback_data, text_as_first_arg.is_empty() && instantiated_function_arguments.is_empty()) {
+        (true, true) => "data",
+        (true, false) => "data, ",
+        (false, _) => "",
+    };
+
     // The dom string is the function name
     let mut dom_string = format!(
-        "{}{}::render({}{})",
-        t2, component_name, text_as_first_arg, instantiated_function_arguments
+        "{}{}::render({}{}{})",
+        t2, component_name, callback_data_arg, text_as_first_arg, instantiated_function_arguments
     );
 
     matcher.path.push(node_type);
@@ -2674,6 +3651,28 @@ pub fn compile_node_to_rust_code_inner<'a>(
         &filtered_xml_attributes.types,
         tabs,
     );
+    set_stringified_callbacks(&mut dom_string, &node.attributes, tabs);
+
+    if has_control_flow_nodes(node.children.as_ref()) {
+        dom_string.push_str(&format!(
+            "\r\n{}.with_children(DomVec::from_vec({}))",
+            t2,
+            compile_control_flow_children_to_rust_code(
+                node.children.as_ref(),
+                component_map,
+                &ComponentArguments {
+                    args: filtered_xml_attributes.types.clone(),
+                    accepts_text: filtered_xml_attributes.accepts_text,
+                },
+                tabs + 1,
+                extra_blocks,
+                css_blocks,
+                css,
+                &matcher,
+            )?
+        ));
+        return Ok(dom_string);
+    }
 
     let mut children_string = node
         .children
@@ -2713,6 +3712,142 @@ pub fn compile_node_to_rust_code_inner<'a>(
     Ok(dom_string)
 }
 
+/// Returns whether any of the nodes is an `<if>`, `<else>` or `<for>` node
+fn has_control_flow_nodes(nodes: &[XmlNode]) -> bool {
+    nodes.iter().any(|n| matches!(normalize_casing(&n.node_type).as_str(), "if" | "else" | "for"))
+}
+
+/// Compiles children that contain `<if>` / `<else>` / `<for>` nodes: since these can't
+/// be expressed in a `vec![]`, the children are pushed into a `Vec` in a block instead:
+///
+/// ```rust,no_run,ignore
+/// {
+///     let mut children = Vec::new();
+///     if visible {
+///         children.push(p::render(...));
+///     }
+///     for item in items.iter() {
+///         children.push(p::render(...));
+///     }
+///     children
+/// }
+/// ```
+fn compile_control_flow_children_to_rust_code(
+    children: &[XmlNode],
+    component_map: &XmlComponentMap,
+    parent_xml_attributes: &ComponentArguments,
+    tabs: usize,
+    extra_blocks: &mut VecContents,
+    css_blocks: &mut BTreeMap<String, String>,
+    css: &Css,
+    matcher: &CssMatcher,
+) -> Result<String, CompileError> {
+    let t = String::from("    ").repeat(tabs);
+    let mut block = format!("{{\r\n{}    let mut children = Vec::new();\r\n", t);
+    compile_child_statements_to_rust_code(
+        children,
+        component_map,
+        parent_xml_attributes,
+        tabs + 1,
+        extra_blocks,
+        css_blocks,
+        css,
+        matcher,
+        &mut block,
+    )?;
+    block.push_str(&format!("{}    children\r\n{}}}", t, t));
+    Ok(block)
+}
+
+fn compile_child_statements_to_rust_code(
+    children: &[XmlNode],
+    component_map: &XmlComponentMap,
+    parent_xml_attributes: &ComponentArguments,
+    tabs: usize,
+    extra_blocks: &mut VecContents,
+    css_blocks: &mut BTreeMap<String, String>,
+    css: &Css,
+    matcher: &CssMatcher,
+    out: &mut String,
+) -> Result<(), CompileError> {
+
+    let t = String::from("    ").repeat(tabs);
+    let mut child_iter = children.iter().enumerate().peekable();
+
+    while let Some((child_idx, child_node)) = child_iter.next() {
+        match normalize_casing(&child_node.node_type).as_str() {
+            "if" => {
+                let condition = find_attribute(child_node, "condition")
+                    .ok_or(ComponentError::InvalidExpression("".into(), "<if> has no \"condition\" attribute".into()))?;
+                out.push_str(&format!("{}if {} {{\r\n", t, compile_condition_to_rust_code(condition.as_str())?));
+                compile_child_statements_to_rust_code(
+                    child_node.children.as_ref(), component_map, parent_xml_attributes,
+                    tabs + 1, extra_blocks, css_blocks, css, matcher, out,
+                )?;
+
+                let has_else = child_iter.peek()
+                    .map(|(_, next)| normalize_casing(&next.node_type) == "else")
+                    .unwrap_or(false);
+
+                if has_else {
+                    let (_, else_node) = child_iter.next().unwrap();
+                    out.push_str(&format!("{}}} else {{\r\n", t));
+                    compile_child_statements_to_rust_code(
+                        else_node.children.as_ref(), component_map, parent_xml_attributes,
+                        tabs + 1, extra_blocks, css_blocks, css, matcher, out,
+                    )?;
+                }
+
+                out.push_str(&format!("{}}}\r\n", t));
+            },
+            "else" => return Err(ComponentError::ElseWithoutIf.into()),
+            "for" => {
+                let each = find_attribute(child_node, "each")
+                    .ok_or(ComponentError::InvalidExpression("".into(), "<for> has no \"each\" attribute".into()))?;
+                let (item_name, list_name) = parse_for_each(each.as_str())?;
+                let list_type = parent_xml_attributes.args.iter()
+                    .find(|a| a.0 == list_name)
+                    .ok_or(ComponentError::UnknownVariable(list_name.clone().into()))?;
+                let item_type = get_list_item_type(&list_type.1).ok_or_else(|| {
+                    ComponentError::InvalidExpression(each.clone(), format!("\"{}\" is a {}, not a list", list_name, list_type.1).into())
+                })?;
+
+                let mut item_attributes = parent_xml_attributes.clone();
+                item_attributes.args.insert(0, (item_name.clone(), item_type.to_string()));
+
+                out.push_str(&format!("{}for {} in {}.iter() {{\r\n", t, item_name, list_name));
+                compile_child_statements_to_rust_code(
+                    child_node.children.as_ref(), component_map, &item_attributes,
+                    tabs + 1, extra_blocks, css_blocks, css, matcher, out,
+                )?;
+                out.push_str(&format!("{}}}\r\n", t));
+            },
+            _ => {
+                // note: nth-child selectors see the position of the node in the XML, not in the final DOM
+                let mut matcher = matcher.clone();
+                matcher.path.push(CssPathSelector::Children);
+                matcher.indices_in_parent.push(child_idx);
+                matcher.children_length.push(children.len());
+
+                let child_string = compile_node_to_rust_code_inner(
+                    child_node,
+                    component_map,
+                    parent_xml_attributes,
+                    tabs,
+                    extra_blocks,
+                    css_blocks,
+                    css,
+                    matcher,
+                )?;
+
+                out.push_str(&format!("{}children.push({});\r\n", t, child_string.trim_start()));
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// Component that was created from a XML node (instead of being registered from Rust code).
 /// Necessary to
 pub struct DynamicXmlComponent {
@@ -2784,13 +3919,21 @@ impl XmlComponentTrait for DynamicXmlComponent {
             None => None,
         };
 
+        // all declared arguments are in scope, even if they weren't set on the component
+        let mut scope = arguments.clone();
+        for arg in self.arguments.args.iter() {
+            if !scope.types.iter().any(|t| t.0 == arg.0) {
+                scope.types.push(arg.clone());
+            }
+        }
+        if self.arguments.accepts_text {
+            scope.types.push((String::from("text"), String::from("String")));
+            scope.values.insert(String::from("text"), content.as_ref().map(|s| s.as_str().to_string()).unwrap_or_default());
+        }
+
         let mut dom = StyledDom::default();
 
-        for child_node in self.root.children.as_ref() {
-            dom.append_child(render_dom_from_body_node_inner(
-                child_node, components, arguments,
-            )?);
-        }
+        render_dom_children(&mut dom, self.root.children.as_ref(), components, &scope)?;
 
         if let Some(css) = component_css.clone() {
             dom.restyle(css);
@@ -2809,6 +3952,929 @@ impl XmlComponentTrait for DynamicXmlComponent {
     }
 }
 
+/// Parses an XML string and returns a `String`, which contains the C source code
+/// (i.e. it compiles the XML to a C program against `azul.h`)
+pub fn str_to_c_code<'a>(
+    root_nodes: &'a [XmlNode],
+    component_map: &'a mut XmlComponentMap,
+) -> Result<String, CompileError> {
+    str_to_source_code(root_nodes, component_map, SourceLanguage::C)
+}
+
+/// Parses an XML string and returns a `String`, which contains the C++ source code
+/// (i.e. it compiles the XML to a C++ program against `azul.hpp`)
+pub fn str_to_cpp_code<'a>(
+    root_nodes: &'a [XmlNode],
+    component_map: &'a mut XmlComponentMap,
+) -> Result<String, CompileError> {
+    str_to_source_code(root_nodes, component_map, SourceLanguage::Cpp)
+}
+
+/// Parses an XML string and returns a `String`, which contains the Python source code
+/// (i.e. it compiles the XML to a Python script using the `azul` module)
+pub fn str_to_python_code<'a>(
+    root_nodes: &'a [XmlNode],
+    component_map: &'a mut XmlComponentMap,
+) -> Result<String, CompileError> {
+    str_to_source_code(root_nodes, component_map, SourceLanguage::Python)
+}
+
+/// Target language of the non-Rust code generators
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum SourceLanguage {
+    C,
+    Cpp,
+    Python,
+}
+
+const C_PRELUDE: &str = "\
+// Auto-generated UI source code
+#include <azul.h>";
+
+const C_MAIN: &str = "\
+typedef struct {
+    uint8_t _unused; // C does not allow empty structs
+} Data;
+
+void Data_destructor(void* restrict data) { }
+AZ_REFLECT(Data, Data_destructor);
+
+AzStyledDom render(AzRefAny* restrict data, AzLayoutCallbackInfo* restrict info) {
+    AzDom dom = ui_render();
+    return AzDom_style(&dom, AzCss_empty()); // styles are applied inline
+}
+
+int main() {
+    Data data = { ._unused = 0 };
+    AzApp app = AzApp_new(Data_upcast(data), AzAppConfig_new(AzLayoutSolver_Default));
+    AzWindowCreateOptions window = AzWindowCreateOptions_new(render);
+    window.state.flags.frame = AzWindowFrame_Maximized;
+    AzApp_run(&app, window);
+    AzApp_delete(&app);
+    return 0;
+}";
+
+const CPP_PRELUDE: &str = "\
+// Auto-generated UI source code
+#include <azul.hpp>
+
+using namespace dll;
+
+// Turns a string literal into a String that is never freed
+template<size_t N>
+inline String az_str(const char (&s)[N]) {
+    return String { .vec = {
+        .ptr = reinterpret_cast<const uint8_t*>(s),
+        .len = N - 1,
+        .cap = N - 1,
+        .destructor = { .NoDestructor = { .tag = U8VecDestructorTag::NoDestructor } },
+    } };
+}
+
+// Turns a stack-allocated array of format arguments into a FmtArgVec
+template<size_t N>
+inline FmtArgVec az_fmt_args(FmtArg (&args)[N]) {
+    return FmtArgVec {
+        .ptr = args,
+        .len = N,
+        .cap = N,
+        .destructor = { .NoDestructor = { .tag = FmtArgVecDestructorTag::NoDestructor } },
+    };
+}";
+
+const CPP_MAIN: &str = "\
+struct Data { };
+
+static uint64_t const Data_RttiTypePtrId = 0;
+
+void Data_destructor(void* restrict data) { }
+
+StyledDom render(RefAny* restrict data, LayoutCallbackInfo* restrict info) {
+    auto dom = ui_render();
+    return Dom_style(&dom, Css_empty()); // styles are applied inline
+}
+
+int main() {
+    Data data { };
+    auto refany = RefAny_newC(&data, sizeof(Data), reinterpret_cast<uint64_t>(&Data_RttiTypePtrId), az_str(\"Data\"), Data_destructor);
+    auto app = App_new(std::move(refany), AppConfig_new(LayoutSolver::Default));
+    auto window = WindowCreateOptions_new(render);
+    window.state.flags.frame = WindowFrame::Maximized;
+    App_run(&app, std::move(window));
+    App_delete(&app);
+    return 0;
+}";
+
+const PYTHON_PRELUDE: &str = "\
+# Auto-generated UI source code
+from azul import *";
+
+const PYTHON_MAIN: &str = "\
+class Data:
+    pass
+
+def render(data, info):
+    return ui_render().style(Css.empty()) # styles are applied inline
+
+app = App(Data(), AppConfig(LayoutSolver.Default))
+window = WindowCreateOptions(render)
+window.state.flags.frame = WindowFrame.Maximized
+app.run(window)";
+
+impl SourceLanguage {
+    fn prelude(&self) -> &'static str {
+        match self {
+            SourceLanguage::C => C_PRELUDE,
+            SourceLanguage::Cpp => CPP_PRELUDE,
+            SourceLanguage::Python => PYTHON_PRELUDE,
+        }
+    }
+
+    fn main(&self) -> &'static str {
+        match self {
+            SourceLanguage::C => C_MAIN,
+            SourceLanguage::Cpp => CPP_MAIN,
+            SourceLanguage::Python => PYTHON_MAIN,
+        }
+    }
+
+    fn comment(&self, s: &str) -> String {
+        match self {
+            SourceLanguage::C | SourceLanguage::Cpp => format!("// {}", s),
+            SourceLanguage::Python => format!("# {}", s),
+        }
+    }
+
+    /// Escapes the string so that it can be placed between double quotes
+    fn escape(s: &str) -> String {
+        let mut out = String::with_capacity(s.len());
+        for c in s.chars() {
+            match c {
+                '\\' => out.push_str("\\\\"),
+                '"' => out.push_str("\\\""),
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                c => out.push(c),
+            }
+        }
+        out
+    }
+
+    fn string_literal(&self, s: &str) -> String {
+        match self {
+            SourceLanguage::C => format!("AzString_fromConstStr(\"{}\")", Self::escape(s)),
+            SourceLanguage::Cpp => format!("az_str(\"{}\")", Self::escape(s)),
+            SourceLanguage::Python => format!("\"{}\"", Self::escape(s)),
+        }
+    }
+
+    /// Passes a `String` variable that is in scope to a function
+    fn string_variable(&self, name: &str) -> String {
+        match self {
+            SourceLanguage::Cpp => format!("{}.clone()", name),
+            SourceLanguage::C | SourceLanguage::Python => name.to_string(),
+        }
+    }
+
+    fn bool_literal(&self, b: bool) -> &'static str {
+        match (self, b) {
+            (SourceLanguage::Python, true) => "True",
+            (SourceLanguage::Python, false) => "False",
+            (_, true) => "true",
+            (_, false) => "false",
+        }
+    }
+
+    /// `Dom::body()`, `Dom::div()`, `Dom::text(...)`, ...
+    fn dom_constructor(&self, constructor: &str, arg: &str) -> String {
+        match self {
+            SourceLanguage::C => format!("AzDom_{}({})", constructor, arg),
+            SourceLanguage::Cpp => format!("Dom_{}({})", constructor, arg),
+            SourceLanguage::Python => format!("Dom.{}({})", constructor, arg),
+        }
+    }
+
+    /// Calls a `&mut self` method of the `Dom` stored in `var`
+    fn dom_method(&self, var: &str, method: &str, arg: &str) -> String {
+        match self {
+            SourceLanguage::C => format!("AzDom_{}(&{}, {});", lower_camel_case(method), var, arg),
+            SourceLanguage::Cpp => format!("Dom_{}(&{}, {});", lower_camel_case(method), var, arg),
+            SourceLanguage::Python => format!("{}.{}({})", var, method, arg),
+        }
+    }
+
+    fn add_child(&self, parent: &str, child: &str) -> String {
+        match self {
+            SourceLanguage::Cpp => self.dom_method(parent, "add_child", &format!("std::move({})", child)),
+            SourceLanguage::C | SourceLanguage::Python => self.dom_method(parent, "add_child", child),
+        }
+    }
+
+    fn declare_dom(&self, var: &str, expr: &str) -> String {
+        match self {
+            SourceLanguage::C => format!("AzDom {} = {};", var, expr),
+            SourceLanguage::Cpp => format!("auto {} = {};", var, expr),
+            SourceLanguage::Python => format!("{} = {}", var, expr),
+        }
+    }
+
+    fn tab_index(&self, tab_index: &TabIndex) -> String {
+        match (self, tab_index) {
+            (SourceLanguage::C, TabIndex::Auto) => format!("(AzTabIndex)AzTabIndex_Auto"),
+            (SourceLanguage::C, TabIndex::OverrideInParent(i)) => format!("(AzTabIndex)AzTabIndex_OverrideInParent({})", i),
+            (SourceLanguage::C, TabIndex::NoKeyboardFocus) => format!("(AzTabIndex)AzTabIndex_NoKeyboardFocus"),
+            (SourceLanguage::Cpp, TabIndex::Auto) => format!("TabIndex {{ .Auto = {{ .tag = TabIndexTag::Auto }} }}"),
+            (SourceLanguage::Cpp, TabIndex::OverrideInParent(i)) => format!("TabIndex {{ .OverrideInParent = {{ .tag = TabIndexTag::OverrideInParent, .payload = {} }} }}", i),
+            (SourceLanguage::Cpp, TabIndex::NoKeyboardFocus) => format!("TabIndex {{ .NoKeyboardFocus = {{ .tag = TabIndexTag::NoKeyboardFocus }} }}"),
+            (SourceLanguage::Python, TabIndex::Auto) => format!("TabIndex.Auto"),
+            (SourceLanguage::Python, TabIndex::OverrideInParent(i)) => format!("TabIndex.OverrideInParent({})", i),
+            (SourceLanguage::Python, TabIndex::NoKeyboardFocus) => format!("TabIndex.NoKeyboardFocus"),
+        }
+    }
+
+    /// Declares a CSS constant at the top of the file
+    fn css_constant(&self, name: &str, css: &str) -> String {
+        match self {
+            SourceLanguage::C => format!("static AzString const {} = {};", name, self.string_literal(css)),
+            SourceLanguage::Cpp => format!("static const char {}[] = \"{}\";", name, Self::escape(css)),
+            SourceLanguage::Python => format!("{} = {}", name, self.string_literal(css)),
+        }
+    }
+
+    /// Uses a CSS constant declared with `css_constant` as a function argument
+    fn css_constant_ref(&self, name: &str) -> String {
+        match self {
+            SourceLanguage::Cpp => format!("az_str({})", name),
+            SourceLanguage::C | SourceLanguage::Python => name.to_string(),
+        }
+    }
+
+    /// Maps the type of a component argument to the type of the function argument,
+    /// `None` if the type can't be expressed as a literal in the XML
+    fn argument_type(&self, ty: &str) -> Option<&'static str> {
+        let (c, cpp, python) = match ty {
+            "String" => ("AzString", "String const&", "str"),
+            "bool" => ("bool", "bool", "bool"),
+            "u8" => ("uint8_t", "uint8_t", "int"),
+            "u16" => ("uint16_t", "uint16_t", "int"),
+            "u32" => ("uint32_t", "uint32_t", "int"),
+            "u64" => ("uint64_t", "uint64_t", "int"),
+            "usize" => ("size_t", "size_t", "int"),
+            "i8" => ("int8_t", "int8_t", "int"),
+            "i16" => ("int16_t", "int16_t", "int"),
+            "i32" => ("int32_t", "int32_t", "int"),
+            "i64" => ("int64_t", "int64_t", "int"),
+            "isize" => ("ssize_t", "ssize_t", "int"),
+            "f32" => ("float", "float", "float"),
+            "f64" => ("double", "double", "float"),
+            ty if get_enum_variants(ty).is_some() => ("AzString", "String const&", "str"),
+            _ => return None,
+        };
+        Some(match self {
+            SourceLanguage::C => c,
+            SourceLanguage::Cpp => cpp,
+            SourceLanguage::Python => python,
+        })
+    }
+
+    fn function_signature(&self, name: &str, args: &[(String, &str)]) -> String {
+        match self {
+            SourceLanguage::C | SourceLanguage::Cpp => {
+                let args = args
+                    .iter()
+                    .map(|(arg_name, arg_type)| format!("{} {}", arg_type, arg_name))
+                    .collect::<Vec<_>>();
+                let args = if args.is_empty() && *self == SourceLanguage::C {
+                    String::from("void")
+                } else {
+                    args.join(", ")
+                };
+                let return_type = if *self == SourceLanguage::C { "AzDom" } else { "Dom" };
+                format!("{} {}({})", return_type, name, args)
+            }
+            SourceLanguage::Python => {
+                let args = args
+                    .iter()
+                    .map(|(arg_name, arg_type)| format!("{}: {}", arg_name, arg_type))
+                    .collect::<Vec<_>>();
+                format!("def {}({}) -> Dom", name, args.join(", "))
+            }
+        }
+    }
+
+    fn function(&self, signature: &str, body: &[String], return_var: &str) -> String {
+        let mut lines = Vec::new();
+        match self {
+            SourceLanguage::C | SourceLanguage::Cpp => {
+                lines.push(format!("{} {{", signature));
+                lines.extend(body.iter().map(|l| format!("    {}", l)));
+                lines.push(format!("    return {};", return_var));
+                lines.push(String::from("}"));
+            }
+            SourceLanguage::Python => {
+                lines.push(format!("{}:", signature));
+                lines.extend(body.iter().map(|l| format!("    {}", l)));
+                lines.push(format!("    return {}", return_var));
+            }
+        }
+        lines.join("\r\n")
+    }
+}
+
+/// `add_child` -> `addChild`
+fn lower_camel_case(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut uppercase_next = false;
+    for c in input.chars() {
+        if c == '_' {
+            uppercase_next = true;
+        } else if uppercase_next {
+            out.extend(c.to_uppercase());
+            uppercase_next = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Variant of the `FmtValue` enum that a variable of the given type is formatted with
+fn fmt_value_variant(ty: &str) -> Option<&'static str> {
+    match ty {
+        "String" => Some("Str"),
+        "bool" => Some("Bool"),
+        "u8" => Some("Uchar"),
+        "i8" => Some("Schar"),
+        "u16" => Some("Ushort"),
+        "i16" => Some("Sshort"),
+        "u32" => Some("Uint"),
+        "i32" => Some("Sint"),
+        "u64" => Some("Ulong"),
+        "i64" => Some("Slong"),
+        "isize" => Some("Isize"),
+        "usize" => Some("Usize"),
+        "f32" => Some("Float"),
+        "f64" => Some("Double"),
+        _ => None,
+    }
+}
+
+/// Compiles the body and all components to statement-based source code:
+/// every node is stored in a local variable and appended to its parent
+struct SourceCodeCompiler<'a> {
+    language: SourceLanguage,
+    component_map: &'a XmlComponentMap,
+    /// `CSS_MATCH_{hash}` => (matched CSS paths, CSS declarations)
+    css_constants: BTreeMap<String, (Vec<String>, String)>,
+    /// Counters to generate unique variable names within the current function
+    var_counter: usize,
+    fmt_args_counter: usize,
+}
+
+impl<'a> SourceCodeCompiler<'a> {
+    fn new_var(&mut self, prefix: &str) -> String {
+        self.var_counter += 1;
+        format!("{}_{}", prefix, self.var_counter)
+    }
+
+    fn reset_vars(&mut self) {
+        self.var_counter = 0;
+        self.fmt_args_counter = 0;
+    }
+
+    /// Compiles the `<component>` node to a function `{name}_render(text, args...)`
+    fn compile_component(
+        &mut self,
+        component_name: &str,
+        arguments: &ComponentArguments,
+        component_node: &XmlNode,
+    ) -> Result<String, CompileError> {
+        self.reset_vars();
+
+        let mut css = match find_node_by_type(component_node.children.as_ref(), "style")
+            .and_then(|style_node| style_node.text.as_ref().map(|s| s.as_str()))
+        {
+            Some(text) => azul_css_parser::new_from_str(&text).map_err(|e| e.to_contained())?,
+            None => Css::empty(),
+        };
+
+        css.sort_by_specificity();
+
+        let mut scope = ComponentArgumentTypes::default();
+        if arguments.accepts_text {
+            scope.push((String::from("text"), String::from("String")));
+        }
+        scope.extend(arguments.args.iter().cloned());
+
+        let mut function_args = Vec::new();
+        for (arg_name, arg_type) in scope.iter() {
+            let ty = self.language.argument_type(arg_type).ok_or_else(|| {
+                ComponentError::InvalidArgumentValue(
+                    arg_name.clone().into(),
+                    String::new().into(),
+                    arg_type.clone().into(),
+                )
+            })?;
+            function_args.push((arg_name.clone(), ty));
+        }
+
+        // the component is wrapped in a div, so that it can have multiple children
+        let mut body = Vec::new();
+        let root_var = String::from("component");
+        body.push(self.language.declare_dom(&root_var, &self.language.dom_constructor("div", "")));
+
+        let matcher = CssMatcher {
+            path: vec![CssPathSelector::Type(NodeTypeTag::Div)],
+            indices_in_parent: vec![0],
+            children_length: vec![1],
+        };
+
+        self.compile_children(component_node, &root_var, &scope, &css, &matcher, &mut body)?;
+
+        let signature = self
+            .language
+            .function_signature(&format!("{}_render", normalize_casing(component_name)), &function_args);
+
+        Ok(self.language.function(&signature, &body, &root_var))
+    }
+
+    /// Compiles the `<body>` node to a function `ui_render()`
+    fn compile_body(&mut self, body_node: &XmlNode, css: &Css) -> Result<String, CompileError> {
+        self.reset_vars();
+
+        let mut body = Vec::new();
+        let matcher = CssMatcher {
+            path: Vec::new(),
+            indices_in_parent: vec![0],
+            children_length: vec![1],
+        };
+
+        let root_var = self.compile_node(
+            body_node,
+            &ComponentArgumentTypes::default(),
+            css,
+            matcher,
+            &mut body,
+        )?;
+
+        let signature = self.language.function_signature("ui_render", &[]);
+        Ok(self.language.function(&signature, &body, &root_var))
+    }
+
+    fn compile_children(
+        &mut self,
+        node: &XmlNode,
+        node_var: &str,
+        scope: &ComponentArgumentTypes,
+        css: &Css,
+        matcher: &CssMatcher,
+        out: &mut Vec<String>,
+    ) -> Result<(), CompileError> {
+        // <style> nodes are compiled to CSS constants, not to DOM nodes
+        let children = node
+            .children
+            .as_ref()
+            .iter()
+            .filter(|c| normalize_casing(&c.node_type) != "style")
+            .collect::<Vec<_>>();
+
+        for (child_idx, child_node) in children.iter().enumerate() {
+            let mut matcher = matcher.clone();
+            matcher.path.push(CssPathSelector::Children);
+            matcher.indices_in_parent.push(child_idx);
+            matcher.children_length.push(children.len());
+
+            let child_var = self.compile_node(child_node, scope, css, matcher, out)?;
+            out.push(self.language.add_child(node_var, &child_var));
+        }
+
+        Ok(())
+    }
+
+    /// Compiles the node and its children to statements, returns the name
+    /// of the variable that holds the compiled node
+    fn compile_node(
+        &mut self,
+        node: &XmlNode,
+        scope: &ComponentArgumentTypes,
+        css: &Css,
+        mut matcher: CssMatcher,
+        out: &mut Vec<String>,
+    ) -> Result<String, CompileError> {
+        let component_name = normalize_casing(&node.node_type);
+
+        let component_map = self.component_map;
+        let xml_component = component_map
+            .components
+            .iter()
+            .find(|s| normalize_casing(&s.id) == component_name)
+            .ok_or(ComponentError::UnknownComponent(
+                component_name.clone().into(),
+            ))?;
+
+        let available_function_args = xml_component.renderer.get_available_arguments();
+        let filtered_xml_attributes =
+            validate_and_filter_component_args(&node.attributes, &available_function_args)
+            .map_err(|e| RenderDomError::InvalidNode(node.span, e))?;
+
+        let node_text = node
+            .text
+            .as_ref()
+            .map(|s| prepare_string(s.as_str()))
+            .unwrap_or_default();
+
+        let is_xml_component =
+            normalize_casing(&xml_component.renderer.get_xml_node().node_type) == "component";
+
+        let node_var = self.new_var(&component_name);
+
+        let constructor = match component_name.as_str() {
+            "body" | "div" if !is_xml_component => {
+                self.language.dom_constructor(&component_name, "")
+            }
+            "p" if !is_xml_component => {
+                let text = self.compile_string(&node_text, scope, out);
+                self.language.dom_constructor("text", &text)
+            }
+            _ if is_xml_component => {
+                let mut call_args = Vec::new();
+                if available_function_args.accepts_text {
+                    call_args.push(self.compile_string(&node_text, scope, out));
+                }
+                for (arg_name, arg_type) in available_function_args.args.iter() {
+                    let value = filtered_xml_attributes.values.get(arg_name).map(|s| s.as_str());
+                    call_args.push(self.compile_argument(arg_name, arg_type, value, scope, out)?);
+                }
+                format!("{}_render({})", component_name, call_args.join(", "))
+            }
+            // components registered from Rust code can't be compiled to other languages
+            _ => return Err(ComponentError::UnknownComponent(component_name.clone().into()).into()),
+        };
+
+        out.push(self.language.declare_dom(&node_var, &constructor));
+
+        // XML components are wrapped in a div
+        matcher.path.push(CssPathSelector::Type(match component_name.as_str() {
+            "body" if !is_xml_component => NodeTypeTag::Body,
+            "p" if !is_xml_component => NodeTypeTag::P,
+            _ => NodeTypeTag::Div,
+        }));
+
+        let ids = node
+            .attributes
+            .get_key("id")
+            .map(|s| s.split_whitespace().map(|s| s.to_string()).collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        let classes = node
+            .attributes
+            .get_key("class")
+            .map(|s| s.split_whitespace().map(|s| s.to_string()).collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        for id in ids.iter() {
+            matcher.path.push(CssPathSelector::Id(id.clone().into()));
+            let id = self.compile_string(id, scope, out);
+            out.push(self.language.dom_method(&node_var, "add_id", &id));
+        }
+
+        for class in classes.iter() {
+            matcher.path.push(CssPathSelector::Class(class.clone().into()));
+            let class = self.compile_string(class, scope, out);
+            out.push(self.language.dom_method(&node_var, "add_class", &class));
+        }
+
+        // inline CSS properties are resolved first-match-wins,
+        // so the style="" attribute has to come before the <style> blocks
+        if let Some(style) = node.attributes.get_key("style") {
+            let style = self.compile_string(style.as_str(), scope, out);
+            out.push(self.language.dom_method(&node_var, "set_inline_style", &style));
+        }
+
+        self.compile_css_blocks(css, &matcher, &node_var, out);
+
+        let focusable = node
+            .attributes
+            .get_key("focusable")
+            .and_then(|f| parse_bool(f.as_str()))
+            .map(|f| if f { TabIndex::Auto } else { TabIndex::NoKeyboardFocus });
+
+        let tab_index = node
+            .attributes
+            .get_key("tabindex")
+            .and_then(|val| val.as_str().trim().parse::<isize>().ok())
+            .map(|i| match i {
+                0 => TabIndex::Auto,
+                i if i > 0 => TabIndex::OverrideInParent(i as u32),
+                _ => TabIndex::NoKeyboardFocus,
+            });
+
+        if let Some(tab_index) = tab_index.or(focusable) {
+            let tab_index = self.language.tab_index(&tab_index);
+            out.push(self.language.dom_method(&node_var, "set_tab_index", &tab_index));
+        }
+
+        self.compile_children(node, &node_var, scope, css, &matcher, out)?;
+
+        Ok(node_var)
+    }
+
+    /// Registers the CSS blocks matching the node as constants and applies them
+    fn compile_css_blocks(
+        &mut self,
+        css: &Css,
+        matcher: &CssMatcher,
+        node_var: &str,
+        out: &mut Vec<String>,
+    ) {
+        let css_blocks_for_this_node = get_css_blocks(css, matcher);
+        if css_blocks_for_this_node.is_empty() {
+            return;
+        }
+
+        let matcher_hash = matcher.get_hash();
+
+        let states = [
+            ("", "set_inline_style", None),
+            ("_HOVER", "set_inline_hover_style", Some(CssPathPseudoSelector::Hover)),
+            ("_ACTIVE", "set_inline_active_style", Some(CssPathPseudoSelector::Active)),
+            ("_FOCUS", "set_inline_focus_style", Some(CssPathPseudoSelector::Focus)),
+        ];
+
+        for (suffix, method, pseudo_selector) in states.iter() {
+            // inline CSS properties are resolved first-match-wins, the most specific block has to come first
+            let blocks = css_blocks_for_this_node
+                .iter()
+                .rev()
+                .filter(|css_block| match (&css_block.ending, pseudo_selector) {
+                    (Some(CssPathPseudoSelector::Hover), Some(CssPathPseudoSelector::Hover)) => true,
+                    (Some(CssPathPseudoSelector::Active), Some(CssPathPseudoSelector::Active)) => true,
+                    (Some(CssPathPseudoSelector::Focus), Some(CssPathPseudoSelector::Focus)) => true,
+                    (Some(CssPathPseudoSelector::Hover), _) => false,
+                    (Some(CssPathPseudoSelector::Active), _) => false,
+                    (Some(CssPathPseudoSelector::Focus), _) => false,
+                    (_, None) => true,
+                    (_, Some(_)) => false,
+                })
+                .collect::<Vec<_>>();
+
+            if blocks.is_empty() {
+                continue;
+            }
+
+            let paths = blocks
+                .iter()
+                .map(|css_block| css_block.block.path.to_string())
+                .collect::<Vec<_>>();
+
+            let declarations = blocks
+                .iter()
+                .flat_map(|css_block| css_block.block.declarations.as_ref().iter().rev())
+                .map(|declaration| match declaration {
+                    CssDeclaration::Static(s) => s.format_css(),
+                    CssDeclaration::Dynamic(d) => d.default_value.format_css(),
+                })
+                .collect::<Vec<_>>();
+
+            let constant_name = format!("CSS_MATCH_{:09}{}", matcher_hash, suffix);
+            let constant_ref = self.language.css_constant_ref(&constant_name);
+            self.css_constants
+                .insert(constant_name, (paths, declarations.join(" ")));
+            out.push(self.language.dom_method(node_var, method, &constant_ref));
+        }
+    }
+
+    /// Compiles a string with `{var}` placeholders to a `String` expression, pushes
+    /// the statements that are necessary to format the string to `out`
+    fn compile_string(
+        &mut self,
+        input: &str,
+        scope: &ComponentArgumentTypes,
+        out: &mut Vec<String>,
+    ) -> String {
+        use self::DynamicItem::*;
+
+        // resolve the variables: variables that are not in scope
+        // (or can't be formatted) are printed as-is
+        let mut items: Vec<DynamicItem> = Vec::new();
+        let mut variables = Vec::new();
+        for item in split_dynamic_string(input) {
+            let item = match item {
+                Var(v) => {
+                    let variable_name = normalize_casing(v.trim());
+                    match scope.iter().find(|s| s.0 == variable_name) {
+                        Some((name, ty)) if fmt_value_variant(ty).is_some() => {
+                            if !variables.iter().any(|(n, _)| n == name) {
+                                variables.push((name.clone(), ty.clone()));
+                            }
+                            Var(name.clone())
+                        }
+                        _ => Str(format!("{{{}}}", v)),
+                    }
+                }
+                Str(s) => Str(s),
+            };
+            match (items.last_mut(), item) {
+                (Some(Str(last)), Str(s)) => last.push_str(&s),
+                (_, item) => items.push(item),
+            }
+        }
+
+        let format_string = items
+            .iter()
+            .map(|item| match item {
+                Var(v) => format!("{{{}}}", v),
+                Str(s) => s.replace("{", "{{").replace("}", "}}"),
+            })
+            .collect::<String>();
+
+        match items.as_slice() {
+            [] => return self.language.string_literal(""),
+            [Str(s)] => return self.language.string_literal(s),
+            [Var(v)] if variables[0].1 == "String" => return self.language.string_variable(v),
+            _ => {}
+        }
+
+        match self.language {
+            SourceLanguage::Python => format!("f\"{}\"", SourceLanguage::escape(&format_string)),
+            SourceLanguage::C | SourceLanguage::Cpp => {
+                self.fmt_args_counter += 1;
+                let args_var = format!("fmt_args_{}", self.fmt_args_counter);
+                let (array_type, format_fn, args_fn) = if self.language == SourceLanguage::C {
+                    ("AzFmtArg", "AzString_format", "(AzFmtArgVec)AzFmtArgVec_fromConstArray")
+                } else {
+                    ("FmtArg", "String_format", "az_fmt_args")
+                };
+                out.push(format!("{} {}[] = {{", array_type, args_var));
+                for (name, ty) in variables.iter() {
+                    let variant = fmt_value_variant(ty).unwrap_or("Str");
+                    let value = if ty == "String" {
+                        self.language.string_variable(name)
+                    } else {
+                        name.clone()
+                    };
+                    let value = if self.language == SourceLanguage::C {
+                        format!("AzFmtValue_{}({})", variant, value)
+                    } else {
+                        format!("{{ .{} = {{ .tag = FmtValueTag::{}, .payload = {} }} }}", variant, variant, value)
+                    };
+                    out.push(format!(
+                        "    {{ .key = {}, .value = {} }},",
+                        self.language.string_literal(name),
+                        value
+                    ));
+                }
+                out.push(String::from("};"));
+                format!(
+                    "{}({}, {}({}))",
+                    format_fn,
+                    self.language.string_literal(&format_string),
+                    args_fn,
+                    args_var
+                )
+            }
+        }
+    }
+
+    /// Compiles the value of a component argument to an expression of the argument type
+    fn compile_argument(
+        &mut self,
+        arg_name: &str,
+        arg_type: &str,
+        value: Option<&str>,
+        scope: &ComponentArgumentTypes,
+        out: &mut Vec<String>,
+    ) -> Result<String, CompileError> {
+        let invalid = || -> CompileError {
+            ComponentError::InvalidArgumentValue(
+                arg_name.to_string().into(),
+                value.unwrap_or_default().to_string().into(),
+                arg_type.to_string().into(),
+            )
+            .into()
+        };
+
+        // enum values are passed as strings
+        if arg_type == "String" || get_enum_variants(arg_type).is_some() {
+            return Ok(self.compile_string(value.unwrap_or_default(), scope, out));
+        }
+
+        let value = match value {
+            Some(s) => s.trim(),
+            // argument was not set on the node, use the default value of the type
+            None => match arg_type {
+                "bool" => "false",
+                "f32" | "f64" => "0.0",
+                _ => "0",
+            },
+        };
+
+        // pass a variable of the same type through, i.e. count="{count}"
+        if let [DynamicItem::Var(v)] = split_dynamic_string(value).as_slice() {
+            let variable_name = normalize_casing(v.trim());
+            return match scope.iter().find(|s| s.0 == variable_name) {
+                Some((name, ty)) if ty == arg_type => Ok(name.clone()),
+                _ => Err(invalid()),
+            };
+        }
+
+        let literal = match arg_type {
+            "bool" => parse_bool(value).map(|b| self.language.bool_literal(b).to_string()),
+            "u8" => value.parse::<u8>().ok().map(|v| v.to_string()),
+            "u16" => value.parse::<u16>().ok().map(|v| v.to_string()),
+            "u32" => value.parse::<u32>().ok().map(|v| v.to_string()),
+            "u64" => value.parse::<u64>().ok().map(|v| v.to_string()),
+            "usize" => value.parse::<usize>().ok().map(|v| v.to_string()),
+            "i8" => value.parse::<i8>().ok().map(|v| v.to_string()),
+            "i16" => value.parse::<i16>().ok().map(|v| v.to_string()),
+            "i32" => value.parse::<i32>().ok().map(|v| v.to_string()),
+            "i64" => value.parse::<i64>().ok().map(|v| v.to_string()),
+            "isize" => value.parse::<isize>().ok().map(|v| v.to_string()),
+            "f32" => value.parse::<f32>().ok().map(|v| match self.language {
+                SourceLanguage::Python => format!("{:?}", v),
+                _ => format!("{:?}f", v),
+            }),
+            "f64" => value.parse::<f64>().ok().map(|v| format!("{:?}", v)),
+            _ => None,
+        };
+
+        literal.ok_or_else(invalid)
+    }
+}
+
+fn str_to_source_code<'a>(
+    root_nodes: &'a [XmlNode],
+    component_map: &'a mut XmlComponentMap,
+    language: SourceLanguage,
+) -> Result<String, CompileError> {
+    let html_node = get_html_node(&root_nodes)?;
+    let body_node = get_body_node(html_node.children.as_ref())?;
+    let global_style = parse_head_node(html_node, component_map)?;
+
+    let mut compiler = SourceCodeCompiler {
+        language,
+        component_map: &*component_map,
+        css_constants: BTreeMap::new(),
+        var_counter: 0,
+        fmt_args_counter: 0,
+    };
+
+    let mut component_signatures = Vec::new();
+    let mut components = Vec::new();
+    for xml_component in component_map.components.iter() {
+        let component_node = xml_component.renderer.get_xml_node();
+        if normalize_casing(&component_node.node_type) != "component" {
+            continue; // builtin components are compiled inline
+        }
+        let function = compiler.compile_component(
+            &xml_component.id,
+            &xml_component.renderer.get_available_arguments(),
+            &component_node,
+        )?;
+        if let Some(signature) = function.lines().next() {
+            component_signatures.push(signature.trim_end_matches(" {").to_string());
+        }
+        components.push(function);
+    }
+
+    let ui = compiler.compile_body(body_node, &global_style)?;
+
+    let mut sections = vec![language.prelude().to_string()];
+
+    if !compiler.css_constants.is_empty() {
+        let css_constants = compiler
+            .css_constants
+            .iter()
+            .map(|(name, (paths, css))| {
+                let mut lines = paths.iter().map(|p| language.comment(p)).collect::<Vec<_>>();
+                lines.push(language.css_constant(name, css));
+                lines.join("\r\n")
+            })
+            .collect::<Vec<_>>()
+            .join("\r\n");
+        sections.push(css_constants);
+    }
+
+    // C and C++ need forward declarations, since components can use each other
+    if language != SourceLanguage::Python && !component_signatures.is_empty() {
+        sections.push(
+            component_signatures
+                .iter()
+                .map(|s| format!("{};", s))
+                .collect::<Vec<_>>()
+                .join("\r\n"),
+        );
+    }
+
+    sections.extend(components);
+    sections.push(ui);
+    sections.push(language.main().to_string());
+
+    let source_code = format!("{}\n", sections.join("\r\n\r\n"));
+    Ok(source_code.replace("\r\n", "\n").replace("\n", "\r\n"))
+}
+
 // -- Tests
 #[cfg(test)]
 mod tests {
@@ -2832,6 +4898,7 @@ mod tests {
                 component_name,
                 searched_component_args,
                 searched_component_source,
+                false,
             );
 
             // TODO!
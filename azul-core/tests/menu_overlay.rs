@@ -0,0 +1,91 @@
+use azul_core::callbacks::DomNodeId;
+use azul_core::menu_overlay::{parse_mnemonic, MenuOverlay, MenuOverlayEvent};
+use azul_core::window::{
+    LogicalPosition, LogicalRect, LogicalSize, Menu, MenuItem, MenuItemState, StringMenuItem,
+    VirtualKeyCode,
+};
+
+fn test_menu() -> Menu {
+    Menu::new(
+        vec![
+            MenuItem::String(StringMenuItem::new("&Open".into())),
+            MenuItem::Separator,
+            MenuItem::String(StringMenuItem {
+                state: MenuItemState::Greyed,
+                ..StringMenuItem::new("Save".into())
+            }),
+            MenuItem::String(StringMenuItem::new("Export".into()).with_children(
+                vec![
+                    MenuItem::String(StringMenuItem::new("PNG".into())),
+                    MenuItem::String(StringMenuItem::new("PDF".into())),
+                ]
+                .into(),
+            )),
+            MenuItem::String(StringMenuItem::new("E&xit".into())),
+        ]
+        .into(),
+    )
+}
+
+fn open(menu: &Menu) -> MenuOverlay {
+    MenuOverlay::context_menu(
+        menu,
+        DomNodeId::ROOT,
+        LogicalPosition::new(10.0, 10.0),
+        LogicalRect::zero(),
+        LogicalSize::new(800.0, 600.0),
+    )
+}
+
+#[test]
+fn mnemonic_parsing() {
+    assert_eq!(parse_mnemonic("&File"), ("File".into(), Some('f')));
+    assert_eq!(parse_mnemonic("Save &As"), ("Save As".into(), Some('a')));
+    assert_eq!(parse_mnemonic("Fish && Chips"), ("Fish & Chips".into(), None));
+}
+
+#[test]
+fn keyboard_navigation_skips_separators_and_greyed_items() {
+    let menu = test_menu();
+    let mut overlay = open(&menu);
+    let down = Some(VirtualKeyCode::Down);
+    assert_eq!(overlay.on_key_down(down, None), MenuOverlayEvent::Redraw);
+    assert_eq!(overlay.popups[0].selected, Some(0));
+    overlay.on_key_down(down, None);
+    assert_eq!(overlay.popups[0].selected, Some(3));
+    assert_eq!(
+        overlay.on_key_down(Some(VirtualKeyCode::Right), None),
+        MenuOverlayEvent::Redraw
+    );
+    assert_eq!(overlay.popups.len(), 2);
+    assert_eq!(overlay.popups[1].selected, Some(0));
+    overlay.on_key_down(Some(VirtualKeyCode::Escape), None);
+    assert_eq!(overlay.popups.len(), 1);
+    assert_eq!(
+        overlay.on_key_down(Some(VirtualKeyCode::Escape), None),
+        MenuOverlayEvent::Close
+    );
+}
+
+#[test]
+fn mouse_hover_opens_submenu() {
+    let menu = test_menu();
+    let mut overlay = open(&menu);
+    let export = overlay.popups[0].item_rect(3).unwrap();
+    let center = LogicalPosition::new(export.min_x() + 5.0, export.min_y() + 5.0);
+    assert_eq!(overlay.on_mouse_move(center), MenuOverlayEvent::Redraw);
+    assert_eq!(overlay.popups.len(), 2);
+    assert_eq!(
+        overlay.on_mouse_down(LogicalPosition::new(700.0, 500.0)),
+        MenuOverlayEvent::Close
+    );
+}
+
+#[test]
+fn mnemonic_activates_item() {
+    let menu = test_menu();
+    let mut overlay = open(&menu);
+    // "Exit" has no callback, activating it just closes the menu
+    assert_eq!(overlay.on_key_down(None, Some('X')), MenuOverlayEvent::Close);
+    assert_eq!(overlay.popups[0].selected, Some(4));
+}
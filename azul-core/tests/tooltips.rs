@@ -0,0 +1,94 @@
+use azul_core::callbacks::DomNodeId;
+use azul_core::dom::{AccessibilityInfo, AccessibilityRole, Dom, NodeData, Tooltip};
+use azul_core::id_tree::NodeId;
+use azul_core::styled_dom::{DomId, NodeHierarchyItemId, StyledDom};
+use azul_core::window::{LogicalPosition, TooltipState, TooltipUpdate};
+use azul_css::AzString;
+use azul_css_parser::CssApiWrapper;
+
+#[test]
+fn test_accessible_description() {
+
+    // no tooltip, no accessibility info
+    assert_eq!(NodeData::div().get_accessible_description(), None);
+
+    // tooltip text is used as the fallback description
+    let node = NodeData::div().with_tooltip(Tooltip::text("Save the file".into()));
+    assert_eq!(node.get_accessible_description(), Some("Save the file".into()));
+
+    // text nodes of a DOM tooltip are concatenated
+    let tooltip_dom = Dom::div()
+        .with_child(Dom::text("Save"))
+        .with_child(Dom::div().with_child(Dom::text("(Ctrl+S)")));
+    let node = NodeData::div().with_tooltip(Tooltip::dom(tooltip_dom));
+    assert_eq!(node.get_accessible_description(), Some("Save (Ctrl+S)".into()));
+
+    // explicit description takes precedence over the tooltip
+    let mut node = NodeData::div().with_tooltip(Tooltip::text("tooltip".into()));
+    node.set_accessibility_info(AccessibilityInfo {
+        name: None.into(),
+        value: None.into(),
+        role: AccessibilityRole::PushButton,
+        states: Vec::new().into(),
+        accelerator: None.into(),
+        default_action: None.into(),
+        description: Some(AzString::from("a \"quoted\" <description>")).into(),
+    });
+    assert_eq!(node.get_accessible_description(), Some("a \"quoted\" <description>".into()));
+
+    // the description is exported as aria-description
+    let mut dom = Dom::body().with_child(Dom::div().with_tooltip(Tooltip::text("tooltip & more".into())));
+    let html = StyledDom::new(&mut dom, CssApiWrapper::empty()).get_html_string("", "", true);
+    assert!(html.contains("aria-description=\"tooltip &amp; more\""), "{}", html);
+
+    let mut dom = Dom::body();
+    dom.root = node;
+    let html = StyledDom::new(&mut dom, CssApiWrapper::empty()).get_html_string("", "", true);
+    assert!(html.contains("aria-description=\"a &quot;quoted&quot; &lt;description>\""), "{}", html);
+}
+
+#[test]
+fn test_tooltip_state_update() {
+
+    let node = |id: usize| DomNodeId {
+        dom: DomId::ROOT_ID,
+        node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(id))),
+    };
+    let delay = Tooltip::DEFAULT_DELAY;
+    let show = |state: &mut TooltipState| {
+        state.visible = Some((Tooltip::text("tooltip".into()), LogicalPosition::zero()));
+    };
+
+    let mut state = TooltipState::default();
+
+    // hovering a new node starts the timer, hovering it again doesn't
+    let update = state.update(Some((delay, node(1))), false);
+    assert_eq!(update, TooltipUpdate { should_regenerate_dom: false, start_timer: Some(delay) });
+    assert_eq!(state.hovered_node, Some(node(1)));
+    let update = state.update(Some((delay, node(1))), false);
+    assert_eq!(update.start_timer, None);
+
+    // pressing the mouse hides the tooltip until the node is left
+    show(&mut state);
+    let update = state.update(Some((delay, node(1))), true);
+    assert_eq!(update, TooltipUpdate { should_regenerate_dom: true, start_timer: None });
+    assert!(state.dismissed && state.visible.is_none());
+    assert_eq!(state.update(Some((delay, node(1))), false).start_timer, None);
+    assert!(state.dismissed);
+
+    // moving to another node restarts the timer
+    let update = state.update(Some((delay, node(2))), false);
+    assert_eq!(update.start_timer, Some(delay));
+    assert!(!state.dismissed);
+
+    // leaving the node hides the tooltip
+    show(&mut state);
+    let update = state.update(None, false);
+    assert_eq!(update, TooltipUpdate { should_regenerate_dom: true, start_timer: None });
+    assert_eq!(state, TooltipState::default());
+
+    // entering a node with the mouse pressed doesn't start the timer
+    let update = state.update(Some((delay, node(3))), true);
+    assert_eq!(update.start_timer, None);
+    assert!(state.dismissed);
+}
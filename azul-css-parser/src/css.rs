@@ -4,13 +4,16 @@ use core::{
     fmt,
 };
 use alloc::collections::BTreeMap;
-use alloc::string::ToString;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 pub use azul_simplecss::Error as CssSyntaxError;
 use azul_simplecss::Tokenizer;
 
 use crate::css_parser;
-pub use crate::css_parser::{CssParsingError, CssParsingErrorOwned};
+pub use crate::css_parser::{
+    CssParsingError, CssParsingErrorOwned, CssFontFace, CssFontFaceStyle,
+    CssFontFaceParseError, CssFontFaceParseErrorOwned,
+};
 use azul_css::{
     Css, CssDeclaration, Stylesheet, DynamicCssProperty, AzString,
     CssPropertyType, CssRuleBlock, CssPath, CssPathSelector,
@@ -105,6 +108,8 @@ pub enum CssParseErrorInner<'a> {
     /// when setting the variable, whether all sides should be set, instead, you have to use `margin-top: var(--blah)`,
     /// `margin-bottom: var(--baz)` in order to work around this limitation.
     VarOnShorthandProperty { key: CombinedCssPropertyType, value: &'a str },
+    /// Invalid or incomplete `@font-face` rule
+    FontFace(CssFontFaceParseError<'a>),
 }
 
 
@@ -118,6 +123,7 @@ pub enum CssParseErrorInnerOwned {
     NodeTypeTag(NodeTypeTagParseErrorOwned),
     UnknownPropertyKey(String, String),
     VarOnShorthandProperty { key: CombinedCssPropertyType, value: String },
+    FontFace(CssFontFaceParseErrorOwned),
 }
 
 impl<'a> CssParseErrorInner<'a> {
@@ -134,6 +140,7 @@ impl<'a> CssParseErrorInner<'a> {
                 key: key.clone(),
                 value: value.to_string(),
             },
+            CssParseErrorInner::FontFace(e) => CssParseErrorInnerOwned::FontFace(e.to_contained()),
         }
     }
 }
@@ -152,6 +159,7 @@ impl CssParseErrorInnerOwned {
                 key: key.clone(),
                 value,
             },
+            CssParseErrorInnerOwned::FontFace(e) => CssParseErrorInner::FontFace(e.to_shared()),
         }
     }
 }
@@ -168,6 +176,7 @@ impl_display!{ CssParseErrorInner<'a>, {
         "Error while parsing: \"{}: {};\": var() cannot be used on shorthand properties - use `{}-top` or `{}-x` as the key instead: ",
         key, value, key, key
     ),
+    FontFace(e) => format!("Failed to parse @font-face rule: {}", e),
 }}
 
 impl<'a> From<CssSyntaxError> for CssParseErrorInner<'a> {
@@ -179,6 +188,7 @@ impl<'a> From<CssSyntaxError> for CssParseErrorInner<'a> {
 impl_from! { DynamicCssParseError<'a>, CssParseErrorInner::DynamicCssParseError }
 impl_from! { NodeTypeTagParseError<'a>, CssParseErrorInner::NodeTypeTag }
 impl_from! { CssPseudoSelectorParseError<'a>, CssParseErrorInner::PseudoSelectorParseError }
+impl_from! { CssFontFaceParseError<'a>, CssParseErrorInner::FontFace }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CssPseudoSelectorParseError<'a> {
@@ -419,20 +429,18 @@ pub struct ErrorLocation {
 }
 
 impl ErrorLocation {
-    /// Given an error location, returns the (line, column)
+    /// Given an error location, returns the (line, column), both starting at 1
     pub fn get_line_column_from_error(&self, css_string: &str) -> (usize, usize) {
 
-        let error_location = self.original_pos.saturating_sub(1);
-        let (mut line_number, mut total_characters) = (0, 0);
-
-        for line in css_string[0..error_location].lines() {
-            line_number += 1;
-            total_characters += line.chars().count();
+        let mut error_location = self.original_pos.min(css_string.len());
+        while !css_string.is_char_boundary(error_location) {
+            error_location -= 1;
         }
 
-        // Rust doesn't count "\n" as a character, so we have to add the line number count on top
-        let total_characters = total_characters + line_number;
-        let column_pos = error_location - total_characters.saturating_sub(2);
+        let before_error = &css_string[..error_location];
+        let line_number = before_error.matches('\n').count() + 1;
+        let line_start = before_error.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let column_pos = before_error[line_start..].chars().count() + 1;
 
         (line_number, column_pos)
     }
@@ -451,10 +459,257 @@ impl<'a> fmt::Display for CssParseError<'a> {
     }
 }
 
+/// Parses a CSS string, including its `@font-face` rules (see `Css::font_faces`)
+///
+/// Every `font-family` declaration that references the family name of a
+/// `@font-face` rule is expanded, so that the sources of the matching faces are tried first:
+/// `font-family: MyFont` with `@font-face { font-family: MyFont; src: url(a.ttf), local(B); }`
+/// results in `font-family: File("a.ttf"), System("B"), System("MyFont")`.
 pub fn new_from_str<'a>(css_string: &'a str) -> Result<Css, CssParseError<'a>> {
     let mut tokenizer = Tokenizer::new(css_string);
-    let (stylesheet, _warnings) = new_from_str_inner(css_string, &mut tokenizer)?;
-    Ok(Css { stylesheets: vec![stylesheet].into() })
+    let (stylesheet, font_faces, _warnings) = new_from_str_inner(css_string, &mut tokenizer)?;
+    Ok(Css {
+        stylesheets: vec![stylesheet].into(),
+        font_faces: font_faces.into(),
+    })
+}
+
+/// Resolves the relative `url()` sources of all `@font-face` rules against `base_path`
+/// (usually the directory of the stylesheet), so that the fonts are found independent
+/// of the current working directory. Absolute paths and URLs with a scheme are left as-is.
+///
+/// Returns the string unchanged if it can't be tokenized, so that the error is
+/// reported when the result is parsed.
+///
+/// ```rust
+/// # extern crate azul_css_parser;
+/// # use azul_css_parser::resolve_font_face_urls;
+/// assert_eq!(
+///     resolve_font_face_urls("@font-face { font-family: A; src: url(a.ttf), local(B); }", "fonts"),
+///     "@font-face { font-family: A; src: url(\"fonts/a.ttf\"), local(\"B\"); }",
+/// );
+/// ```
+pub fn resolve_font_face_urls(css_string: &str, base_path: &str) -> String {
+
+    use azul_simplecss::Token;
+    use crate::css_parser::{parse_font_face_src, CssFontFaceSource};
+
+    fn is_relative(url: &str) -> bool {
+        // "/abs", "\\server", "C:/fonts", "https://", "data:"
+        !(url.starts_with('/') || url.starts_with('\\') || url.contains(':'))
+    }
+
+    let base_path = base_path.trim_end_matches(|c| c == '/' || c == '\\');
+    if base_path.is_empty() {
+        return css_string.to_string();
+    }
+
+    // (start, end, new value) of the "src" declarations to replace
+    let mut replacements = Vec::new();
+    let mut tokenizer = Tokenizer::new(css_string);
+    let mut in_font_face = false;
+
+    loop {
+        match tokenizer.parse_next() {
+            Ok(Token::AtRule("font-face")) => { in_font_face = true; },
+            Ok(Token::BlockEnd) => { in_font_face = false; },
+            Ok(Token::Declaration("src", value)) if in_font_face => {
+                let sources = match parse_font_face_src(value) {
+                    Ok(o) => o,
+                    Err(_) => continue,
+                };
+                if !sources.iter().any(|s| matches!(s, CssFontFaceSource::Url(u) if is_relative(u.url.as_str()))) {
+                    continue;
+                }
+                let new_value = sources.iter().map(|source| match source {
+                    CssFontFaceSource::Url(u) => {
+                        let url = if is_relative(u.url.as_str()) {
+                            format!("{}/{}", base_path, u.url.as_str())
+                        } else {
+                            u.url.as_str().to_string()
+                        };
+                        match u.format.as_ref() {
+                            Some(format) => format!("url({:?}) format({:?})", url, format.as_str()),
+                            None => format!("url({:?})", url),
+                        }
+                    },
+                    CssFontFaceSource::Local(local) => format!("local({:?})", local.as_str()),
+                }).collect::<Vec<_>>().join(", ");
+                let start = value.as_ptr() as usize - css_string.as_ptr() as usize;
+                replacements.push((start, start + value.len(), new_value));
+            },
+            Ok(Token::EndOfStream) => break,
+            Ok(_) => { },
+            Err(_) => return css_string.to_string(),
+        }
+    }
+
+    let mut resolved = String::with_capacity(css_string.len());
+    let mut last_end = 0;
+    for (start, end, new_value) in replacements {
+        resolved.push_str(&css_string[last_end..start]);
+        resolved.push_str(&new_value);
+        last_end = end;
+    }
+    resolved.push_str(&css_string[last_end..]);
+    resolved
+}
+
+/// Replaces all `font-family` names of a rule block which match one of the `font_faces` with
+/// the sources of the best matching faces (the original name stays as the last fallback)
+///
+/// The faces are selected with the font matching algorithm of CSS Fonts Level 4, using the
+/// `font-weight` and `font-style` declared in the same rule block: first the closest style,
+/// then the closest weight. All faces with the selected style and weight are kept, since they
+/// may only differ in their `unicode-range` (see `CssPropertyCache::get_font_id_or_default`).
+fn apply_font_faces(
+    declarations: &mut [CssDeclaration],
+    font_faces: &[CssFontFace],
+    font_weight: u16,
+    font_style: CssFontFaceStyle,
+) {
+
+    use azul_css::{CssProperty, CssPropertyValue, StyleFontFamily, StyleFontFamilyVec};
+    use crate::css_parser::CssFontFaceSource;
+
+    if font_faces.is_empty() {
+        return;
+    }
+
+    let expand_families = |families: &StyleFontFamilyVec| -> StyleFontFamilyVec {
+        let mut expanded = Vec::new();
+        for family in families.as_ref().iter() {
+            let name = match family {
+                StyleFontFamily::System(name) => name,
+                other => { expanded.push(other.clone()); continue; },
+            };
+
+            let family_faces = font_faces.iter()
+                .filter(|face| face.font_family.as_str().eq_ignore_ascii_case(name.as_str()))
+                .collect::<Vec<_>>();
+
+            for face in match_font_faces(&family_faces, font_weight, font_style) {
+                for source in face.src.iter() {
+                    let source_family = match source {
+                        CssFontFaceSource::Url(u) => StyleFontFamily::File(u.url.clone()),
+                        CssFontFaceSource::Local(local) => StyleFontFamily::System(local.clone()),
+                    };
+                    if !expanded.contains(&source_family) {
+                        expanded.push(source_family);
+                    }
+                }
+            }
+
+            if !expanded.contains(family) {
+                expanded.push(family.clone());
+            }
+        }
+        expanded.into()
+    };
+
+    for declaration in declarations.iter_mut() {
+        let property = match declaration {
+            CssDeclaration::Static(property) => property,
+            CssDeclaration::Dynamic(dynamic) => &mut dynamic.default_value,
+        };
+        if let CssProperty::FontFamily(CssPropertyValue::Exact(families)) = property {
+            *families = expand_families(families);
+        }
+    }
+}
+
+/// Selects the faces of one font family that match the requested weight and style best
+fn match_font_faces<'b>(faces: &[&'b CssFontFace], font_weight: u16, font_style: CssFontFaceStyle) -> Vec<&'b CssFontFace> {
+
+    use self::CssFontFaceStyle::*;
+
+    let style_preference: &[CssFontFaceStyle] = match font_style {
+        Normal => &[Normal, Oblique, Italic],
+        Italic => &[Italic, Oblique, Normal],
+        Oblique => &[Oblique, Italic, Normal],
+    };
+
+    let best_style = match style_preference.iter().find(|s| faces.iter().any(|f| f.font_style == **s)) {
+        Some(s) => *s,
+        None => return Vec::new(),
+    };
+
+    // lower = better: weights between 400 and 500 first look up to 500, then down, then above 500,
+    // lighter weights look further down, bolder weights look further up
+    let weight_preference = |w: u16| -> (u8, u16) {
+        let (desired, w) = (font_weight, w);
+        if w == desired {
+            (0, 0)
+        } else if desired >= 400 && desired <= 500 {
+            if w > desired && w <= 500 { (1, w - desired) }
+            else if w < desired { (2, desired - w) }
+            else { (3, w - 500) }
+        } else if desired < 400 {
+            if w < desired { (1, desired - w) } else { (2, w - desired) }
+        } else {
+            if w > desired { (1, w - desired) } else { (2, desired - w) }
+        }
+    };
+
+    let best_weight = match faces.iter()
+        .filter(|f| f.font_style == best_style)
+        .map(|f| f.font_weight)
+        .min_by_key(|w| weight_preference(*w)) {
+        Some(s) => s,
+        None => return Vec::new(),
+    };
+
+    faces.iter()
+        .filter(|f| f.font_style == best_style && f.font_weight == best_weight)
+        .copied()
+        .collect()
+}
+
+/// Parses the declarations of a single `@font-face { ... }` block
+fn parse_font_face<'a>(
+    css_string: &'a str,
+    declarations: &BTreeMap<&'a str, (&'a str, (ErrorLocation, ErrorLocation))>,
+    block_location: (ErrorLocation, ErrorLocation),
+) -> Result<CssFontFace, CssParseError<'a>> {
+
+    use crate::css_parser::{
+        parse_font_face_src, parse_font_face_weight,
+        parse_font_face_style, parse_font_face_unicode_range,
+    };
+
+    let mut font_face = CssFontFace {
+        font_family: AzString::from_const_str(""),
+        src: Vec::new().into(),
+        font_weight: 400,
+        font_style: Default::default(),
+        unicode_range: Vec::new().into(),
+    };
+
+    for (key, (value, location)) in declarations.iter() {
+        let location = *location;
+        let map_err = |e: CssFontFaceParseError<'a>| CssParseError { css_string, error: e.into(), location };
+        match *key {
+            "font-family" => {
+                let family = value.trim().trim_matches('\'').trim_matches('"').trim();
+                font_face.font_family = family.to_string().into();
+            },
+            "src" => { font_face.src = parse_font_face_src(value).map_err(map_err)?.into(); },
+            "font-weight" => { font_face.font_weight = parse_font_face_weight(value).map_err(map_err)?; },
+            "font-style" => { font_face.font_style = parse_font_face_style(value).map_err(map_err)?; },
+            "unicode-range" => { font_face.unicode_range = parse_font_face_unicode_range(value).map_err(map_err)?.into(); },
+            _ => { }, // font-display, font-stretch, etc. are ignored
+        }
+    }
+
+    if font_face.font_family.as_str().is_empty() {
+        return Err(CssParseError { css_string, error: CssFontFaceParseError::MissingFontFamily.into(), location: block_location });
+    }
+
+    if font_face.src.is_empty() {
+        return Err(CssParseError { css_string, error: CssFontFaceParseError::MissingSrc.into(), location: block_location });
+    }
+
+    Ok(font_face)
 }
 
 /// Returns the location of where the parser is currently in the document
@@ -710,7 +965,7 @@ impl CssParseWarnMsgInnerOwned {
 /// instead of being actual errors. These warnings may be ignored by the caller,
 /// but can be useful for debugging.
 fn new_from_str_inner<'a>(css_string: &'a str, tokenizer: &mut Tokenizer<'a>)
--> Result<(Stylesheet, Vec<CssParseWarnMsg<'a>>), CssParseError<'a>> {
+-> Result<(Stylesheet, Vec<CssFontFace>, Vec<CssParseWarnMsg<'a>>), CssParseError<'a>> {
 
     use azul_simplecss::{Token, Combinator};
 
@@ -731,6 +986,11 @@ fn new_from_str_inner<'a>(css_string: &'a str, tokenizer: &mut Tokenizer<'a>)
 
     let mut last_error_location = ErrorLocation { original_pos: 0 };
 
+    // Whether the current block is a `@font-face { ... }` block
+    let mut in_font_face = false;
+    let mut font_face_start = ErrorLocation { original_pos: 0 };
+    let mut font_faces = Vec::new();
+
     loop {
 
         let token = tokenizer.parse_next().map_err(|e| CssParseError {
@@ -760,11 +1020,18 @@ fn new_from_str_inner<'a>(css_string: &'a str, tokenizer: &mut Tokenizer<'a>)
         }}
 
         match token {
+            Token::AtRule("font-face") => {
+                check_parser_is_outside_block!();
+                in_font_face = true;
+                font_face_start = last_error_location;
+            },
             Token::BlockStart => {
                 check_parser_is_outside_block!();
                 parser_in_block = true;
                 block_nesting += 1;
-                current_paths.push(last_path.clone());
+                if !in_font_face {
+                    current_paths.push(last_path.clone());
+                }
                 last_path.clear();
             },
             Token::Comma => {
@@ -778,6 +1045,15 @@ fn new_from_str_inner<'a>(css_string: &'a str, tokenizer: &mut Tokenizer<'a>)
                 check_parser_is_inside_block!();
                 parser_in_block = false;
 
+                if in_font_face {
+                    in_font_face = false;
+                    let block_location = (font_face_start, get_error_location(tokenizer));
+                    font_faces.push(parse_font_face(css_string, &current_rules, block_location)?);
+                    current_rules.clear();
+                    last_error_location = get_error_location(tokenizer);
+                    continue;
+                }
+
                 css_blocks.extend(current_paths.drain(..).map(|path| {
                     UnparsedCssRuleBlock {
                         path: CssPath { selectors: path.into() },
@@ -855,11 +1131,17 @@ fn new_from_str_inner<'a>(css_string: &'a str, tokenizer: &mut Tokenizer<'a>)
         last_error_location = get_error_location(tokenizer);
     }
 
-    unparsed_css_blocks_to_stylesheet(css_blocks, css_string)
+    let (stylesheet, warnings) = unparsed_css_blocks_to_stylesheet(css_blocks, &font_faces, css_string)?;
+    Ok((stylesheet, font_faces, warnings))
 }
 
-fn unparsed_css_blocks_to_stylesheet<'a>(css_blocks: Vec<UnparsedCssRuleBlock<'a>>, css_string: &'a str)
--> Result<(Stylesheet, Vec<CssParseWarnMsg<'a>>), CssParseError<'a>> {
+fn unparsed_css_blocks_to_stylesheet<'a>(
+    css_blocks: Vec<UnparsedCssRuleBlock<'a>>,
+    font_faces: &[CssFontFace],
+    css_string: &'a str,
+) -> Result<(Stylesheet, Vec<CssParseWarnMsg<'a>>), CssParseError<'a>> {
+
+    use crate::css_parser::{parse_font_face_weight, parse_font_face_style};
 
     // Actually parse the properties (TODO: this could be done in parallel and in a separate function)
     let css_key_map = azul_css::get_css_key_map();
@@ -870,6 +1152,14 @@ fn unparsed_css_blocks_to_stylesheet<'a>(css_blocks: Vec<UnparsedCssRuleBlock<'a
 
         let mut declarations = Vec::<CssDeclaration>::new();
 
+        // font-weight and font-style are not styling properties yet, they only select the @font-face
+        let font_weight = unparsed_css_block.declarations.get("font-weight")
+            .and_then(|(value, _)| parse_font_face_weight(value).ok())
+            .unwrap_or(400);
+        let font_style = unparsed_css_block.declarations.get("font-style")
+            .and_then(|(value, _)| parse_font_face_style(value).ok())
+            .unwrap_or_default();
+
         for (unparsed_css_key, (unparsed_css_value, location)) in unparsed_css_block.declarations {
             parse_css_declaration(
                 unparsed_css_key,
@@ -885,6 +1175,8 @@ fn unparsed_css_blocks_to_stylesheet<'a>(css_blocks: Vec<UnparsedCssRuleBlock<'a
             })?;
         }
 
+        apply_font_faces(&mut declarations, font_faces, font_weight, font_style);
+
         Ok(CssRuleBlock {
             path: unparsed_css_block.path.into(),
             declarations: declarations.into(),
@@ -983,6 +1275,15 @@ fn parse_css_variable_brace_contents<'a>(input: &'a str) -> Option<(&'a str, Opt
     Some((&var_name[2..], split_comma_iter.next()))
 }
 
+#[test]
+fn test_error_location_line_column() {
+    let css = "div {\n    color: red;\n}";
+    assert_eq!(ErrorLocation { original_pos: 0 }.get_line_column_from_error(css), (1, 1));
+    assert_eq!(ErrorLocation { original_pos: 4 }.get_line_column_from_error(css), (1, 5));
+    assert_eq!(ErrorLocation { original_pos: 10 }.get_line_column_from_error(css), (2, 5));
+    assert_eq!(ErrorLocation { original_pos: 100 }.get_line_column_from_error(css), (3, 2));
+}
+
 #[test]
 fn test_css_parse_1() {
 
@@ -1019,9 +1320,7 @@ fn test_css_parse_1() {
 
     assert_eq!(
         parsed_css,
-        Css {
-            stylesheets: vec![expected_css_rules].into(),
-        }
+        Css::new(vec![expected_css_rules])
     );
 }
 
@@ -1039,14 +1338,12 @@ fn test_css_simple_selector_parse() {
         Children,
         Class("new".to_string().into())
     ];
-    assert_eq!(new_from_str(css).unwrap(), Css {
-        stylesheets: vec![Stylesheet {
-            rules: vec![CssRuleBlock {
-                path: CssPath { selectors: parsed.into() },
-                declarations: Vec::new().into(),
-            }].into(),
+    assert_eq!(new_from_str(css).unwrap(), Css::new(vec![Stylesheet {
+        rules: vec![CssRuleBlock {
+            path: CssPath { selectors: parsed.into() },
+            declarations: Vec::new().into(),
         }].into(),
-    });
+    }]));
 }
 
 #[cfg(test)]
@@ -1057,7 +1354,7 @@ mod stylesheet_parse {
 
     fn test_css(css: &str, expected: Vec<CssRuleBlock>) {
         let css = new_from_str(css).unwrap();
-        assert_eq!(css, Css { stylesheets: vec![expected.into()].into() });
+        assert_eq!(css, Css::new(vec![expected.into()]));
     }
 
     // Tests that an element with a single class always gets the CSS element applied properly
@@ -1163,7 +1460,7 @@ fn test_multiple_rules() {
         CssRuleBlock { path: CssPath { selectors: vec![Type(NodeTypeTag::Div), Class("my_class".to_string().into()), Class("specific".to_string().into()), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
     ];
 
-    assert_eq!(parsed_css, Css { stylesheets: vec![expected_rules.into()].into() });
+    assert_eq!(parsed_css, Css::new(vec![expected_rules.into()]));
 }
 
 #[test]
@@ -1203,5 +1500,142 @@ fn test_case_issue_93() {
         declaration(&[Class("tabwidget-tab".to_string().into()), Class("active".to_string().into()), Children, Class("tabwidget-tab-close".to_string().into())], ColorU { r: 255, g: 0, b: 0, a: 255 }),
     ];
 
-    assert_eq!(parsed_css, Css { stylesheets: vec![expected_rules.into()].into() });
-}
\ No newline at end of file
+    assert_eq!(parsed_css, Css::new(vec![expected_rules.into()]));
+}
+
+#[test]
+fn test_font_face_rule() {
+
+    use azul_css::*;
+    use self::CssPathSelector::*;
+    use crate::css_parser::{CssFontFaceSource, CssFontFaceUrl, CssFontFaceStyle, CssUnicodeRange};
+
+    let parsed_css = new_from_str("
+        @font-face {
+            font-family: \"My Font\";
+            src: url(fonts/MyFont-Bold.ttf) format(\"truetype\");
+            font-weight: bold;
+        }
+
+        @font-face {
+            font-family: 'My Font';
+            src: local(MyFontRegular), url('fonts/MyFont.ttf');
+            unicode-range: U+0000-00FF;
+        }
+
+        p { font-family: My Font, sans-serif; }
+    ").unwrap();
+
+    let expected_faces = vec![
+        CssFontFace {
+            font_family: "My Font".to_string().into(),
+            src: vec![CssFontFaceSource::Url(CssFontFaceUrl {
+                url: "fonts/MyFont-Bold.ttf".to_string().into(),
+                format: Some(AzString::from("truetype".to_string())).into(),
+            })].into(),
+            font_weight: 700,
+            font_style: CssFontFaceStyle::Normal,
+            unicode_range: Vec::new().into(),
+        },
+        CssFontFace {
+            font_family: "My Font".to_string().into(),
+            src: vec![
+                CssFontFaceSource::Local("MyFontRegular".to_string().into()),
+                CssFontFaceSource::Url(CssFontFaceUrl { url: "fonts/MyFont.ttf".to_string().into(), format: None.into() }),
+            ].into(),
+            font_weight: 400,
+            font_style: CssFontFaceStyle::Normal,
+            unicode_range: vec![CssUnicodeRange { start: 0, end: 0xFF }].into(),
+        },
+    ];
+
+    // only the regular face matches, the original family name stays as a fallback
+    let expected_rules = vec![CssRuleBlock {
+        path: CssPath { selectors: vec![Type(NodeTypeTag::P)].into() },
+        declarations: vec![CssDeclaration::Static(CssProperty::FontFamily(CssPropertyValue::Exact(vec![
+            StyleFontFamily::System("MyFontRegular".to_string().into()),
+            StyleFontFamily::File("fonts/MyFont.ttf".to_string().into()),
+            StyleFontFamily::System("My Font".to_string().into()),
+            StyleFontFamily::System("sans-serif".to_string().into()),
+        ].into())))].into(),
+    }];
+
+    assert_eq!(parsed_css, Css {
+        stylesheets: vec![expected_rules.into()].into(),
+        font_faces: expected_faces.into(),
+    });
+}
+
+#[test]
+fn test_font_face_matching() {
+
+    use azul_css::*;
+
+    fn families(css: &Css) -> Vec<String> {
+        css.rules().map(|rule| {
+            rule.declarations.iter().filter_map(|d| match d {
+                CssDeclaration::Static(CssProperty::FontFamily(CssPropertyValue::Exact(f))) => {
+                    Some(f.iter().map(|f| match f {
+                        StyleFontFamily::System(s) | StyleFontFamily::File(s) => s.as_str().to_string(),
+                        StyleFontFamily::Ref(_) => String::new(),
+                    }).collect::<Vec<_>>().join(","))
+                },
+                _ => None,
+            }).collect::<Vec<_>>().join(";")
+        }).collect()
+    }
+
+    let faces = "
+        @font-face { font-family: F; src: url(300.ttf); font-weight: 300; }
+        @font-face { font-family: F; src: url(400.ttf); }
+        @font-face { font-family: F; src: url(400-cyrillic.ttf); unicode-range: U+0400-04FF; }
+        @font-face { font-family: F; src: url(600.ttf); font-weight: 600; }
+        @font-face { font-family: F; src: url(900.ttf); font-weight: 900; }
+        @font-face { font-family: F; src: url(400-oblique.ttf); font-style: oblique; }
+    ";
+
+    let matched = |block: &str| families(&new_from_str(&format!("{} {}", faces, block)).unwrap());
+
+    // all faces of the matching weight are kept, they may cover different unicode ranges
+    assert_eq!(matched("p { font-family: F; }"), vec!["400.ttf,400-cyrillic.ttf,F"]);
+    // family names are case-insensitive
+    assert_eq!(matched("p { font-family: f; }"), vec!["400.ttf,400-cyrillic.ttf,f"]);
+    assert_eq!(matched("p { font-family: F; font-weight: bold; }"), vec!["900.ttf,F"]);
+    assert_eq!(matched("p { font-family: F; font-weight: 500; }"), vec!["400.ttf,400-cyrillic.ttf,F"]);
+    assert_eq!(matched("p { font-family: F; font-weight: 550; }"), vec!["600.ttf,F"]);
+    assert_eq!(matched("p { font-family: F; font-weight: 450; }"), vec!["400.ttf,400-cyrillic.ttf,F"]);
+    assert_eq!(matched("p { font-family: F; font-weight: 200; }"), vec!["300.ttf,F"]);
+    assert_eq!(matched("p { font-family: F; font-weight: 950; }"), vec!["900.ttf,F"]);
+    // italic falls back to oblique before normal
+    assert_eq!(matched("p { font-family: F; font-style: italic; }"), vec!["400-oblique.ttf,F"]);
+    assert_eq!(matched("p { font-family: F; font-style: italic; font-weight: bold; }"), vec!["400-oblique.ttf,F"]);
+}
+
+#[test]
+fn test_resolve_font_face_urls() {
+    let css = "@font-face { font-family: A; src: url(a.ttf) format(\"truetype\"), url(/abs/b.ttf), url(https://x.org/c.ttf); }\n\
+               p { font-family: A; background: url(d.png); }";
+    assert_eq!(
+        resolve_font_face_urls(css, "../fonts/"),
+        "@font-face { font-family: A; src: url(\"../fonts/a.ttf\") format(\"truetype\"), url(\"/abs/b.ttf\"), url(\"https://x.org/c.ttf\"); }\n\
+         p { font-family: A; background: url(d.png); }",
+    );
+    assert_eq!(resolve_font_face_urls(css, ""), css);
+    assert_eq!(resolve_font_face_urls("@font-face { src: url(a.ttf)", "fonts"), "@font-face { src: url(a.ttf)");
+}
+
+#[test]
+fn test_font_face_rule_errors() {
+    assert_eq!(
+        new_from_str("@font-face { src: url(a.ttf); }").map_err(|e| e.error),
+        Err(CssParseErrorInner::FontFace(CssFontFaceParseError::MissingFontFamily)),
+    );
+    assert_eq!(
+        new_from_str("@font-face { font-family: A; }").map_err(|e| e.error),
+        Err(CssParseErrorInner::FontFace(CssFontFaceParseError::MissingSrc)),
+    );
+    assert_eq!(
+        new_from_str("@font-face { font-family: A; src: a.ttf; }").map_err(|e| e.error),
+        Err(CssParseErrorInner::FontFace(CssFontFaceParseError::InvalidSrc("a.ttf"))),
+    );
+}
@@ -5,6 +5,8 @@ use core::fmt;
 use alloc::vec::Vec;
 use alloc::string::String;
 
+pub use azul_css::{CssFontFace, CssFontFaceSource, CssFontFaceUrl, CssFontFaceStyle, CssUnicodeRange};
+
 use azul_css::{
     CssPropertyType, CssProperty, CombinedCssPropertyType, CssPropertyValue,
     LayoutOverflow, Shape, PixelValue, AngleValue, AngleMetric, PixelValueNoPercent,
@@ -17,7 +19,7 @@ use azul_css::{
     RadialGradientSize, AzString, NormalizedLinearColorStop, NormalizedRadialColorStop,
 
     StyleFilter, StyleMixBlendMode,
-    StyleTextColor, StyleFontSize, StyleFontFamily, StyleTextAlign,
+    StyleTextColor, StyleFontSize, StyleFontFamily, StyleTextAlign, StyleTextDecoration,
     StyleLetterSpacing, StyleLineHeight, StyleWordSpacing, StyleTabWidth,
     StyleCursor, StyleBackgroundContent, StyleBackgroundPosition, StyleBackgroundSize,
     StyleBackgroundRepeat, StyleBorderTopLeftRadius, StyleBorderTopRightRadius,
@@ -36,7 +38,7 @@ use azul_css::{
     LayoutFlexDirection, LayoutFlexGrow, LayoutFlexShrink, LayoutJustifyContent,
     LayoutAlignItems, LayoutAlignContent, LayoutPaddingRight, LayoutPaddingBottom,
     LayoutMarginTop, LayoutMarginLeft, LayoutMarginRight, LayoutMarginBottom,
-    LayoutPaddingTop, LayoutPaddingLeft,
+    LayoutPaddingTop, LayoutPaddingLeft, LayoutVerticalAlign,
 };
 
 pub trait FormatAsCssValue {
@@ -293,6 +295,8 @@ pub fn parse_css_property<'a>(key: CssPropertyType, value: &'a str) -> Result<Cs
             Filter                      => CssProperty::Filter(CssPropertyValue::Exact(parse_style_filter_vec(value)?)).into(),
             BackdropFilter              => CssProperty::BackdropFilter(CssPropertyValue::Exact(parse_style_filter_vec(value)?)).into(),
             TextShadow                  => CssProperty::TextShadow(CssPropertyValue::Exact(parse_style_box_shadow(value)?)).into(),
+            VerticalAlign               => parse_layout_vertical_align(value)?.into(),
+            TextDecoration              => parse_style_text_decoration(value)?.into(),
         }
     })
 }
@@ -4073,9 +4077,9 @@ impl CssStyleFontFamilyParseErrorOwned {
 /// # use azul_css::{StyleFontFamily, StyleFontFamilyVec};
 /// let input = "\"Helvetica\", 'Arial', Times New Roman";
 /// let fonts: StyleFontFamilyVec = vec![
-///     StyleFontFamily::Native("Helvetica".into()),
-///     StyleFontFamily::Native("Arial".into()),
-///     StyleFontFamily::Native("Times New Roman".into()),
+///     StyleFontFamily::System("Helvetica".into()),
+///     StyleFontFamily::System("Arial".into()),
+///     StyleFontFamily::System("Times New Roman".into()),
 /// ].into();
 ///
 /// assert_eq!(parse_style_font_family(input), Ok(fonts));
@@ -4097,6 +4101,221 @@ pub fn parse_style_font_family<'a>(input: &'a str) -> Result<StyleFontFamilyVec,
     Ok(fonts.into())
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum CssFontFaceParseError<'a> {
+    MissingFontFamily,
+    MissingSrc,
+    InvalidSrc(&'a str),
+    InvalidFontWeight(&'a str),
+    InvalidFontStyle(&'a str),
+    InvalidUnicodeRange(&'a str),
+    UnclosedQuotes(&'a str),
+}
+
+impl_display!{CssFontFaceParseError<'a>, {
+    MissingFontFamily => format!("@font-face rule is missing the \"font-family\" descriptor"),
+    MissingSrc => format!("@font-face rule is missing the \"src\" descriptor"),
+    InvalidSrc(val) => format!("Invalid @font-face src: \"{}\" - expected url(...) or local(...)", val),
+    InvalidFontWeight(val) => format!("Invalid @font-face font-weight: \"{}\"", val),
+    InvalidFontStyle(val) => format!("Invalid @font-face font-style: \"{}\"", val),
+    InvalidUnicodeRange(val) => format!("Invalid @font-face unicode-range: \"{}\"", val),
+    UnclosedQuotes(val) => format!("Unclosed quotes: \"{}\"", val),
+}}
+
+impl<'a> From<UnclosedQuotesError<'a>> for CssFontFaceParseError<'a> {
+    fn from(err: UnclosedQuotesError<'a>) -> Self {
+        CssFontFaceParseError::UnclosedQuotes(err.0)
+    }
+}
+
+/// Owned version of CssFontFaceParseError.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CssFontFaceParseErrorOwned {
+    MissingFontFamily,
+    MissingSrc,
+    InvalidSrc(String),
+    InvalidFontWeight(String),
+    InvalidFontStyle(String),
+    InvalidUnicodeRange(String),
+    UnclosedQuotes(String),
+}
+
+impl<'a> CssFontFaceParseError<'a> {
+    pub fn to_contained(&self) -> CssFontFaceParseErrorOwned {
+        match self {
+            CssFontFaceParseError::MissingFontFamily => CssFontFaceParseErrorOwned::MissingFontFamily,
+            CssFontFaceParseError::MissingSrc => CssFontFaceParseErrorOwned::MissingSrc,
+            CssFontFaceParseError::InvalidSrc(s) => CssFontFaceParseErrorOwned::InvalidSrc(s.to_string()),
+            CssFontFaceParseError::InvalidFontWeight(s) => CssFontFaceParseErrorOwned::InvalidFontWeight(s.to_string()),
+            CssFontFaceParseError::InvalidFontStyle(s) => CssFontFaceParseErrorOwned::InvalidFontStyle(s.to_string()),
+            CssFontFaceParseError::InvalidUnicodeRange(s) => CssFontFaceParseErrorOwned::InvalidUnicodeRange(s.to_string()),
+            CssFontFaceParseError::UnclosedQuotes(s) => CssFontFaceParseErrorOwned::UnclosedQuotes(s.to_string()),
+        }
+    }
+}
+
+impl CssFontFaceParseErrorOwned {
+    pub fn to_shared<'a>(&'a self) -> CssFontFaceParseError<'a> {
+        match self {
+            CssFontFaceParseErrorOwned::MissingFontFamily => CssFontFaceParseError::MissingFontFamily,
+            CssFontFaceParseErrorOwned::MissingSrc => CssFontFaceParseError::MissingSrc,
+            CssFontFaceParseErrorOwned::InvalidSrc(s) => CssFontFaceParseError::InvalidSrc(s.as_str()),
+            CssFontFaceParseErrorOwned::InvalidFontWeight(s) => CssFontFaceParseError::InvalidFontWeight(s.as_str()),
+            CssFontFaceParseErrorOwned::InvalidFontStyle(s) => CssFontFaceParseError::InvalidFontStyle(s.as_str()),
+            CssFontFaceParseErrorOwned::InvalidUnicodeRange(s) => CssFontFaceParseError::InvalidUnicodeRange(s.as_str()),
+            CssFontFaceParseErrorOwned::UnclosedQuotes(s) => CssFontFaceParseError::UnclosedQuotes(s.as_str()),
+        }
+    }
+}
+
+/// Strips the quotes of a value if the value is quoted, otherwise returns the trimmed value
+fn strip_optional_quotes<'a>(input: &'a str) -> Result<&'a str, UnclosedQuotesError<'a>> {
+    let input = input.trim();
+    if input.starts_with('"') || input.starts_with('\'') {
+        strip_quotes(input).map(|q| q.0)
+    } else {
+        Ok(input)
+    }
+}
+
+/// Parses the value of the `src` descriptor of a `@font-face` rule
+///
+/// ```rust
+/// # extern crate azul_css_parser;
+/// # use azul_css_parser::{parse_font_face_src, CssFontFaceSource, CssFontFaceUrl};
+/// assert_eq!(parse_font_face_src("local(Roboto), url('Roboto.ttf') format(\"truetype\")"), Ok(vec![
+///     CssFontFaceSource::Local("Roboto".into()),
+///     CssFontFaceSource::Url(CssFontFaceUrl { url: "Roboto.ttf".into(), format: Some("truetype".into()).into() }),
+/// ]));
+/// ```
+pub fn parse_font_face_src<'a>(input: &'a str) -> Result<Vec<CssFontFaceSource>, CssFontFaceParseError<'a>> {
+
+    use alloc::string::ToString;
+
+    let mut sources = Vec::new();
+
+    for source in split_string_respect_comma(input) {
+
+        let source = source.trim();
+        let open_brace = source.find('(').ok_or(CssFontFaceParseError::InvalidSrc(source))?;
+        // url() may not contain nested braces, so the first closing brace ends the function
+        let close_brace = source.find(')').ok_or(CssFontFaceParseError::InvalidSrc(source))?;
+        if close_brace < open_brace {
+            return Err(CssFontFaceParseError::InvalidSrc(source));
+        }
+
+        let function_name = &source[..open_brace];
+        let function_args = strip_optional_quotes(&source[(open_brace + 1)..close_brace])?;
+        let remainder = source[(close_brace + 1)..].trim();
+
+        if function_args.is_empty() {
+            return Err(CssFontFaceParseError::InvalidSrc(source));
+        }
+
+        match function_name {
+            "url" => {
+                let format: Option<AzString> = if remainder.is_empty() {
+                    None
+                } else {
+                    let (_, format) = parse_parentheses(remainder, &["format"])
+                        .map_err(|_| CssFontFaceParseError::InvalidSrc(source))?;
+                    Some(strip_optional_quotes(format)?.to_string().into())
+                };
+                sources.push(CssFontFaceSource::Url(CssFontFaceUrl { url: function_args.to_string().into(), format: format.into() }));
+            },
+            "local" if remainder.is_empty() => {
+                sources.push(CssFontFaceSource::Local(function_args.to_string().into()));
+            },
+            _ => return Err(CssFontFaceParseError::InvalidSrc(source)),
+        }
+    }
+
+    if sources.is_empty() {
+        return Err(CssFontFaceParseError::MissingSrc);
+    }
+
+    Ok(sources)
+}
+
+/// Parses the `font-weight` descriptor of a `@font-face` rule: `normal`, `bold` or a number
+/// between 1 and 1000. For weight ranges (`100 900`), only the lower bound is used.
+pub fn parse_font_face_weight<'a>(input: &'a str) -> Result<u16, CssFontFaceParseError<'a>> {
+    let input = input.trim();
+    let first = input.split_whitespace().next().ok_or(CssFontFaceParseError::InvalidFontWeight(input))?;
+    match first {
+        "normal" => Ok(400),
+        "bold" => Ok(700),
+        other => match other.parse::<u16>() {
+            Ok(w) if w >= 1 && w <= 1000 => Ok(w),
+            _ => Err(CssFontFaceParseError::InvalidFontWeight(input)),
+        },
+    }
+}
+
+/// Parses the `font-style` descriptor of a `@font-face` rule
+pub fn parse_font_face_style<'a>(input: &'a str) -> Result<CssFontFaceStyle, CssFontFaceParseError<'a>> {
+    let input = input.trim();
+    // "oblique 10deg" - the angle is ignored
+    match input.split_whitespace().next() {
+        Some("normal") => Ok(CssFontFaceStyle::Normal),
+        Some("italic") => Ok(CssFontFaceStyle::Italic),
+        Some("oblique") => Ok(CssFontFaceStyle::Oblique),
+        _ => Err(CssFontFaceParseError::InvalidFontStyle(input)),
+    }
+}
+
+/// Parses the `unicode-range` descriptor of a `@font-face` rule
+///
+/// ```rust
+/// # extern crate azul_css_parser;
+/// # use azul_css_parser::{parse_font_face_unicode_range, CssUnicodeRange};
+/// assert_eq!(parse_font_face_unicode_range("U+0025-00FF, u+4??"), Ok(vec![
+///     CssUnicodeRange { start: 0x25, end: 0xFF },
+///     CssUnicodeRange { start: 0x400, end: 0x4FF },
+/// ]));
+/// ```
+pub fn parse_font_face_unicode_range<'a>(input: &'a str) -> Result<Vec<CssUnicodeRange>, CssFontFaceParseError<'a>> {
+
+    fn parse_hex<'a>(s: &str, original: &'a str) -> Result<u32, CssFontFaceParseError<'a>> {
+        if s.is_empty() || s.len() > 6 {
+            return Err(CssFontFaceParseError::InvalidUnicodeRange(original));
+        }
+        u32::from_str_radix(s, 16).map_err(|_| CssFontFaceParseError::InvalidUnicodeRange(original))
+    }
+
+    let mut ranges = Vec::new();
+
+    for range in input.split(',') {
+
+        let range = range.trim();
+        let hex = if range.starts_with("U+") || range.starts_with("u+") {
+            &range[2..]
+        } else {
+            return Err(CssFontFaceParseError::InvalidUnicodeRange(range));
+        };
+
+        let (start, end) = if hex.contains('?') {
+            // U+4?? = U+400-4FF
+            let start = hex.replace('?', "0");
+            let end = hex.replace('?', "F");
+            (parse_hex(&start, range)?, parse_hex(&end, range)?)
+        } else if let Some(dash) = hex.find('-') {
+            (parse_hex(&hex[..dash], range)?, parse_hex(&hex[(dash + 1)..], range)?)
+        } else {
+            let single = parse_hex(hex, range)?;
+            (single, single)
+        };
+
+        if start > end || end > 0x10FFFF {
+            return Err(CssFontFaceParseError::InvalidUnicodeRange(range));
+        }
+
+        ranges.push(CssUnicodeRange { start, end });
+    }
+
+    Ok(ranges)
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd)]
 pub enum ParenthesisParseError<'a> {
     UnclosedBraces,
@@ -4299,12 +4518,21 @@ multi_type_parser!(parse_layout_display, LayoutDisplay,
                     ["none", None],
                     ["flex", Flex],
                     ["block", Block],
-                    ["inline-block", InlineBlock]);
+                    ["inline-block", InlineBlock],
+                    ["inline", Inline]);
 
 multi_type_parser!(parse_layout_float, LayoutFloat,
                     ["left", Left],
                     ["right", Right]);
 
+multi_type_parser!(parse_layout_vertical_align, LayoutVerticalAlign,
+                    ["baseline", Baseline],
+                    ["top", Top],
+                    ["middle", Middle],
+                    ["bottom", Bottom],
+                    ["sub", Sub],
+                    ["super", Super]);
+
 multi_type_parser!(parse_layout_box_sizing, LayoutBoxSizing,
     ["content-box", ContentBox],
     ["border-box", BorderBox]);
@@ -4362,6 +4590,12 @@ multi_type_parser!(parse_layout_text_align, StyleTextAlign,
                     ["left", Left],
                     ["right", Right]);
 
+multi_type_parser!(parse_style_text_decoration, StyleTextDecoration,
+                    ["none", None],
+                    ["underline", Underline],
+                    ["overline", Overline],
+                    ["line-through", LineThrough]);
+
 #[cfg(test)]
 mod css_tests {
     use super::*;
@@ -5221,8 +5455,8 @@ mod css_tests {
         use azul_css::{AzString, StringVec};
         use crate::alloc::string::ToString;
         let fonts0: Vec<StyleFontFamily> = vec![
-            StyleFontFamily::Native("Webly Sleeky UI".to_string().into()),
-            StyleFontFamily::Native("monospace".to_string().into()),
+            StyleFontFamily::System("Webly Sleeky UI".to_string().into()),
+            StyleFontFamily::System("monospace".to_string().into()),
         ];
         let fonts0: StyleFontFamilyVec = fonts0.into();
         assert_eq!(parse_style_font_family("\"Webly Sleeky UI\", monospace"), Ok(fonts0));
@@ -5233,7 +5467,7 @@ mod css_tests {
         use azul_css::{AzString, StringVec};
         use crate::alloc::string::ToString;
         let fonts0: Vec<StyleFontFamily> = vec![
-            StyleFontFamily::Native("Webly Sleeky UI".to_string().into()),
+            StyleFontFamily::System("Webly Sleeky UI".to_string().into()),
         ];
         let fonts0: StyleFontFamilyVec = fonts0.into();
         assert_eq!(parse_style_font_family("'Webly Sleeky UI'"), Ok(fonts0));
@@ -5339,4 +5573,30 @@ mod css_tests {
             Ok(AngleValue::grad(20.4))
         );
     }
+
+    #[test]
+    fn test_parse_layout_display_inline() {
+        assert_eq!(
+            parse_layout_display("inline"),
+            Ok(LayoutDisplay::Inline)
+        );
+    }
+
+    #[test]
+    fn test_parse_layout_vertical_align() {
+        assert_eq!(
+            parse_layout_vertical_align("super"),
+            Ok(LayoutVerticalAlign::Super)
+        );
+        assert!(parse_layout_vertical_align("sideways").is_err());
+    }
+
+    #[test]
+    fn test_parse_style_text_decoration() {
+        assert_eq!(
+            parse_style_text_decoration("line-through"),
+            Ok(StyleTextDecoration::LineThrough)
+        );
+        assert!(parse_style_text_decoration("blink").is_err());
+    }
 }
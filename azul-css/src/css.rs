@@ -1,6 +1,6 @@
 //! Types and methods used to describe the style of an application
 use crate::css_properties::{CssProperty, CssPropertyType};
-use crate::AzString;
+use crate::{AzString, OptionAzString};
 use alloc::string::String;
 use alloc::vec::Vec;
 use core::fmt;
@@ -14,6 +14,9 @@ pub struct Css {
     /// For example, when overriding native styles, the `.sort_by_specificy()` function
     /// should not mix the two stylesheets during sorting.
     pub stylesheets: StylesheetVec,
+    /// `@font-face` rules of all stylesheets, the `font-family` declarations
+    /// referencing them are already expanded to the sources of the matching faces
+    pub font_faces: CssFontFaceVec,
 }
 
 impl_vec!(Stylesheet, StylesheetVec, StylesheetVecDestructor);
@@ -31,10 +34,97 @@ impl Css {
     pub fn new(stylesheets: Vec<Stylesheet>) -> Self {
         Self {
             stylesheets: stylesheets.into(),
+            font_faces: CssFontFaceVec::from_const_slice(&[]),
         }
     }
 }
 
+/// Parsed contents of an `@font-face { ... }` block
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct CssFontFace {
+    /// Name under which the face can be referenced via `font-family`
+    pub font_family: AzString,
+    /// Sources of the face, in order of preference
+    pub src: CssFontFaceSourceVec,
+    /// Numeric weight (`normal` = 400, `bold` = 700), default: 400
+    pub font_weight: u16,
+    pub font_style: CssFontFaceStyle,
+    /// Code point ranges covered by the face, empty if the face covers all code points
+    pub unicode_range: CssUnicodeRangeVec,
+}
+
+impl CssFontFace {
+    /// Returns whether the face has a glyph for the given character according to its `unicode-range`
+    pub fn covers(&self, c: char) -> bool {
+        self.unicode_range.is_empty() || self.unicode_range.iter().any(|r| r.contains(c))
+    }
+}
+
+impl_vec!(CssFontFace, CssFontFaceVec, CssFontFaceVecDestructor);
+impl_vec_clone!(CssFontFace, CssFontFaceVec, CssFontFaceVecDestructor);
+impl_vec_debug!(CssFontFace, CssFontFaceVec);
+impl_vec_partialeq!(CssFontFace, CssFontFaceVec);
+impl_vec_partialord!(CssFontFace, CssFontFaceVec);
+
+/// Single entry of the `src: ...` list in a `@font-face` rule
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C, u8)]
+pub enum CssFontFaceSource {
+    /// `url("fonts/Roboto.ttf") format("truetype")`
+    Url(CssFontFaceUrl),
+    /// `local("Roboto")` - font installed on the system
+    Local(AzString),
+}
+
+/// `url("fonts/Roboto.ttf") format("truetype")`: relative URLs are resolved against
+/// the location of the stylesheet, if it is known (see `azul_css_parser::resolve_font_face_urls`)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C)]
+pub struct CssFontFaceUrl {
+    pub url: AzString,
+    pub format: OptionAzString,
+}
+
+impl_vec!(CssFontFaceSource, CssFontFaceSourceVec, CssFontFaceSourceVecDestructor);
+impl_vec_clone!(CssFontFaceSource, CssFontFaceSourceVec, CssFontFaceSourceVecDestructor);
+impl_vec_debug!(CssFontFaceSource, CssFontFaceSourceVec);
+impl_vec_partialeq!(CssFontFaceSource, CssFontFaceSourceVec);
+impl_vec_partialord!(CssFontFaceSource, CssFontFaceSourceVec);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C)]
+pub enum CssFontFaceStyle {
+    Normal,
+    Italic,
+    Oblique,
+}
+
+impl Default for CssFontFaceStyle {
+    fn default() -> Self { CssFontFaceStyle::Normal }
+}
+
+/// `U+0000-00FF`: inclusive range of unicode code points
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C)]
+pub struct CssUnicodeRange {
+    pub start: u32,
+    pub end: u32,
+}
+
+impl CssUnicodeRange {
+    pub fn contains(&self, c: char) -> bool {
+        let c = c as u32;
+        c >= self.start && c <= self.end
+    }
+}
+
+impl_vec!(CssUnicodeRange, CssUnicodeRangeVec, CssUnicodeRangeVecDestructor);
+impl_vec_clone!(CssUnicodeRange, CssUnicodeRangeVec, CssUnicodeRangeVecDestructor);
+impl_vec_debug!(CssUnicodeRange, CssUnicodeRangeVec);
+impl_vec_partialeq!(CssUnicodeRange, CssUnicodeRangeVec);
+impl_vec_partialord!(CssUnicodeRange, CssUnicodeRangeVec);
+
 #[derive(Debug, Default, PartialEq, PartialOrd, Clone)]
 #[repr(C)]
 pub struct Stylesheet {
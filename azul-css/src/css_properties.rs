@@ -32,7 +32,7 @@ const COMBINED_CSS_PROPERTIES_KEY_MAP: [(CombinedCssPropertyType, &'static str);
 ];
 
 /// Map between CSS keys and a statically typed enum
-const CSS_PROPERTY_KEY_MAP: [(CssPropertyType, &'static str); 74] = [
+const CSS_PROPERTY_KEY_MAP: [(CssPropertyType, &'static str); 76] = [
     (CssPropertyType::Display, "display"),
     (CssPropertyType::Float, "float"),
     (CssPropertyType::BoxSizing, "box-sizing"),
@@ -119,6 +119,8 @@ const CSS_PROPERTY_KEY_MAP: [(CssPropertyType, &'static str); 74] = [
     (CssPropertyType::Filter, "filter"),
     (CssPropertyType::BackdropFilter, "backdrop-filter"),
     (CssPropertyType::TextShadow, "text-shadow"),
+    (CssPropertyType::VerticalAlign, "vertical-align"),
+    (CssPropertyType::TextDecoration, "text-decoration"),
 ];
 
 // The following types are present in webrender, however, azul-css should not
@@ -1070,6 +1072,8 @@ pub enum CssPropertyType {
     Filter,
     BackdropFilter,
     TextShadow,
+    VerticalAlign,
+    TextDecoration,
 }
 
 impl CssPropertyType {
@@ -1166,6 +1170,8 @@ impl CssPropertyType {
             CssPropertyType::Filter => "filter",
             CssPropertyType::BackdropFilter => "backdrop-filter",
             CssPropertyType::TextShadow => "text-shadow",
+            CssPropertyType::VerticalAlign => "vertical-align",
+            CssPropertyType::TextDecoration => "text-decoration",
         }
     }
 
@@ -1173,7 +1179,7 @@ impl CssPropertyType {
     pub fn is_inheritable(&self) -> bool {
         use self::CssPropertyType::*;
         match self {
-            TextColor | FontFamily | FontSize | LineHeight | TextAlign => true,
+            TextColor | FontFamily | FontSize | LineHeight | TextAlign | TextDecoration => true,
             _ => false,
         }
     }
@@ -1220,7 +1226,8 @@ impl CssPropertyType {
             | MixBlendMode
             | Filter
             | BackdropFilter
-            | TextShadow => false,
+            | TextShadow
+            | TextDecoration => false,
             _ => true,
         }
     }
@@ -1325,6 +1332,8 @@ pub enum CssProperty {
     Filter(StyleFilterVecValue),
     BackdropFilter(StyleFilterVecValue),
     TextShadow(StyleBoxShadowValue),
+    VerticalAlign(LayoutVerticalAlignValue),
+    TextDecoration(StyleTextDecorationValue),
 }
 
 impl_option!(
@@ -1523,6 +1532,12 @@ macro_rules! css_property_from_type {
             CssPropertyType::TextShadow => {
                 CssProperty::TextShadow(StyleBoxShadowValue::$content_type)
             }
+            CssPropertyType::VerticalAlign => {
+                CssProperty::VerticalAlign(LayoutVerticalAlignValue::$content_type)
+            }
+            CssPropertyType::TextDecoration => {
+                CssProperty::TextDecoration(StyleTextDecorationValue::$content_type)
+            }
         }
     }};
 }
@@ -1605,6 +1620,8 @@ impl CssProperty {
             Filter(c) => c.is_initial(),
             BackdropFilter(c) => c.is_initial(),
             TextShadow(c) => c.is_initial(),
+            VerticalAlign(c) => c.is_initial(),
+            TextDecoration(c) => c.is_initial(),
         }
     }
 
@@ -1828,6 +1845,12 @@ impl CssProperty {
     pub const fn const_backface_visiblity(input: StyleBackfaceVisibility) -> Self {
         CssProperty::BackfaceVisibility(StyleBackfaceVisibilityValue::Exact(input))
     }
+    pub const fn const_vertical_align(input: LayoutVerticalAlign) -> Self {
+        CssProperty::VerticalAlign(LayoutVerticalAlignValue::Exact(input))
+    }
+    pub const fn const_text_decoration(input: StyleTextDecoration) -> Self {
+        CssProperty::TextDecoration(StyleTextDecorationValue::Exact(input))
+    }
 }
 #[derive(Debug, Copy, Clone, PartialEq)]
 #[repr(C, u8)]
@@ -2364,6 +2387,8 @@ impl CssProperty {
             CssProperty::Filter(v) => v.get_css_value_fmt(),
             CssProperty::BackdropFilter(v) => v.get_css_value_fmt(),
             CssProperty::TextShadow(v) => v.get_css_value_fmt(),
+            CssProperty::VerticalAlign(v) => v.get_css_value_fmt(),
+            CssProperty::TextDecoration(v) => v.get_css_value_fmt(),
         }
     }
 
@@ -2807,6 +2832,12 @@ macro_rules! css_property_from_type {
                 CssProperty::BackdropFilter(CssPropertyValue::$content_type)
             }
             CssPropertyType::TextShadow => CssProperty::TextShadow(CssPropertyValue::$content_type),
+            CssPropertyType::VerticalAlign => {
+                CssProperty::VerticalAlign(CssPropertyValue::$content_type)
+            }
+            CssPropertyType::TextDecoration => {
+                CssProperty::TextDecoration(CssPropertyValue::$content_type)
+            }
         }
     }};
 }
@@ -2889,6 +2920,8 @@ impl CssProperty {
             CssProperty::Filter(_) => CssPropertyType::Filter,
             CssProperty::BackdropFilter(_) => CssPropertyType::BackdropFilter,
             CssProperty::TextShadow(_) => CssPropertyType::TextShadow,
+            CssProperty::VerticalAlign(_) => CssPropertyType::VerticalAlign,
+            CssProperty::TextDecoration(_) => CssPropertyType::TextDecoration,
         }
     }
 
@@ -3114,6 +3147,12 @@ impl CssProperty {
     pub const fn backface_visiblity(input: StyleBackfaceVisibility) -> Self {
         CssProperty::BackfaceVisibility(CssPropertyValue::Exact(input))
     }
+    pub const fn vertical_align(input: LayoutVerticalAlign) -> Self {
+        CssProperty::VerticalAlign(CssPropertyValue::Exact(input))
+    }
+    pub const fn text_decoration(input: StyleTextDecoration) -> Self {
+        CssProperty::TextDecoration(CssPropertyValue::Exact(input))
+    }
 
     // functions that downcast to the concrete CSS type (style)
 
@@ -3362,6 +3401,18 @@ impl CssProperty {
             _ => None,
         }
     }
+    pub const fn as_vertical_align(&self) -> Option<&LayoutVerticalAlignValue> {
+        match self {
+            CssProperty::VerticalAlign(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_text_decoration(&self) -> Option<&StyleTextDecorationValue> {
+        match self {
+            CssProperty::TextDecoration(f) => Some(f),
+            _ => None,
+        }
+    }
     pub const fn as_box_sizing(&self) -> Option<&LayoutBoxSizingValue> {
         match self {
             CssProperty::BoxSizing(f) => Some(f),
@@ -3576,6 +3627,7 @@ impl_from_css_prop!(StyleTextColor, CssProperty::TextColor);
 impl_from_css_prop!(StyleFontSize, CssProperty::FontSize);
 impl_from_css_prop!(StyleFontFamilyVec, CssProperty::FontFamily);
 impl_from_css_prop!(StyleTextAlign, CssProperty::TextAlign);
+impl_from_css_prop!(StyleTextDecoration, CssProperty::TextDecoration);
 impl_from_css_prop!(StyleLetterSpacing, CssProperty::LetterSpacing);
 impl_from_css_prop!(StyleLineHeight, CssProperty::LineHeight);
 impl_from_css_prop!(StyleWordSpacing, CssProperty::WordSpacing);
@@ -3583,6 +3635,7 @@ impl_from_css_prop!(StyleTabWidth, CssProperty::TabWidth);
 impl_from_css_prop!(StyleCursor, CssProperty::Cursor);
 impl_from_css_prop!(LayoutDisplay, CssProperty::Display);
 impl_from_css_prop!(LayoutFloat, CssProperty::Float);
+impl_from_css_prop!(LayoutVerticalAlign, CssProperty::VerticalAlign);
 impl_from_css_prop!(LayoutBoxSizing, CssProperty::BoxSizing);
 impl_from_css_prop!(LayoutWidth, CssProperty::Width);
 impl_from_css_prop!(LayoutHeight, CssProperty::Height);
@@ -5531,6 +5584,7 @@ pub enum LayoutDisplay {
     Flex,
     Block,
     InlineBlock,
+    Inline,
 }
 
 impl Default for LayoutDisplay {
@@ -5553,6 +5607,27 @@ impl Default for LayoutFloat {
     }
 }
 
+/// Represents a `vertical-align` attribute - default: `Baseline`
+///
+/// Only affects `display: inline` content and text nodes that are
+/// laid out as part of an inline formatting context.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum LayoutVerticalAlign {
+    Baseline,
+    Top,
+    Middle,
+    Bottom,
+    Sub,
+    Super,
+}
+
+impl Default for LayoutVerticalAlign {
+    fn default() -> Self {
+        LayoutVerticalAlign::Baseline
+    }
+}
+
 /// Represents a `position` attribute - default: `Static`
 ///
 /// NOTE: No inline positioning is supported.
@@ -5722,6 +5797,22 @@ impl Default for StyleTextAlign {
     }
 }
 
+/// Line that is drawn under, over or through text (`text-decoration`) - default: `None`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum StyleTextDecoration {
+    None,
+    Underline,
+    Overline,
+    LineThrough,
+}
+
+impl Default for StyleTextDecoration {
+    fn default() -> Self {
+        StyleTextDecoration::None
+    }
+}
+
 /// Vertical text alignment enum (top, center, bottom) - default: `Center`
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
@@ -5950,6 +6041,7 @@ pub type StyleFontSizeValue = CssPropertyValue<StyleFontSize>;
 pub type StyleFontFamilyVecValue = CssPropertyValue<StyleFontFamilyVec>;
 pub type StyleTextColorValue = CssPropertyValue<StyleTextColor>;
 pub type StyleTextAlignValue = CssPropertyValue<StyleTextAlign>;
+pub type StyleTextDecorationValue = CssPropertyValue<StyleTextDecoration>;
 pub type StyleLineHeightValue = CssPropertyValue<StyleLineHeight>;
 pub type StyleLetterSpacingValue = CssPropertyValue<StyleLetterSpacing>;
 pub type StyleWordSpacingValue = CssPropertyValue<StyleWordSpacing>;
@@ -5990,6 +6082,13 @@ impl_option!(
     copy = false,
     [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
 );
+pub type LayoutVerticalAlignValue = CssPropertyValue<LayoutVerticalAlign>;
+impl_option!(
+    LayoutVerticalAlignValue,
+    OptionLayoutVerticalAlignValue,
+    copy = false,
+    [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
+);
 pub type LayoutBoxSizingValue = CssPropertyValue<LayoutBoxSizing>;
 impl_option!(
     LayoutBoxSizingValue,
@@ -154,6 +154,7 @@ impl PrintAsCssValue for LayoutDisplay {
             LayoutDisplay::Flex => "flex",
             LayoutDisplay::Block => "block",
             LayoutDisplay::InlineBlock => "inline-block",
+            LayoutDisplay::Inline => "inline",
         })
     }
 }
@@ -167,6 +168,30 @@ impl PrintAsCssValue for LayoutFloat {
     }
 }
 
+impl PrintAsCssValue for StyleTextDecoration {
+    fn print_as_css_value(&self) -> String {
+        String::from(match self {
+            StyleTextDecoration::None => "none",
+            StyleTextDecoration::Underline => "underline",
+            StyleTextDecoration::Overline => "overline",
+            StyleTextDecoration::LineThrough => "line-through",
+        })
+    }
+}
+
+impl PrintAsCssValue for LayoutVerticalAlign {
+    fn print_as_css_value(&self) -> String {
+        String::from(match self {
+            LayoutVerticalAlign::Baseline => "baseline",
+            LayoutVerticalAlign::Top => "top",
+            LayoutVerticalAlign::Middle => "middle",
+            LayoutVerticalAlign::Bottom => "bottom",
+            LayoutVerticalAlign::Sub => "sub",
+            LayoutVerticalAlign::Super => "super",
+        })
+    }
+}
+
 impl PrintAsCssValue for LayoutBoxSizing {
     fn print_as_css_value(&self) -> String {
         String::from(match self {
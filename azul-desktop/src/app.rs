@@ -1,12 +1,12 @@
 use alloc::sync::Arc;
 use azul_core::{
-    app_resources::{AppConfig, ImageCache, ImageRef},
+    app_resources::{AppConfig, ImageCache, ImageRef, LoadedFontSource},
     callbacks::{RefAny, Update},
     display_list::RenderCallbacks,
     task::{Timer, TimerId},
     window::{MonitorVec, WindowCreateOptions},
 };
-use azul_css::AzString;
+use azul_css::{AzString, U8Vec};
 use clipboard2::{Clipboard as _, ClipboardError, SystemClipboard};
 use rust_fontconfig::FcFontCache;
 use std::fmt;
@@ -53,6 +53,13 @@ impl AzAppPtr {
         }
     }
 
+    pub fn add_font(&mut self, family: AzString, font_bytes: U8Vec) -> bool {
+        match self.ptr.try_lock() {
+            Ok(mut l) => l.add_font(family, font_bytes),
+            Err(_) => false,
+        }
+    }
+
     pub fn get_monitors(&self) -> MonitorVec {
         self.ptr
             .lock()
@@ -140,6 +147,28 @@ impl App {
         self.image_cache.add_css_image_id(css_id, image);
     }
 
+    /// Registers a font (TTF / OTF bytes) under a font family name, so that it
+    /// can be used via `font-family: "family"` without being installed on the system.
+    ///
+    /// Returns false if the font could not be parsed.
+    pub fn add_font(&mut self, family: AzString, font_bytes: U8Vec) -> bool {
+        let source = LoadedFontSource {
+            data: font_bytes,
+            index: 0,
+            // same as system fonts: glyph outlines are only needed for
+            // `FontRef::text_to_path` and the CPU renderer, both of which
+            // decode the outlines of the glyphs they use on demand
+            load_outlines: false,
+        };
+        match (CALLBACKS.parse_font_fn)(source) {
+            Some(font_ref) => {
+                self.image_cache.add_css_font_family(family, font_ref);
+                true
+            },
+            None => false,
+        }
+    }
+
     /// Spawn a new window on the screen. Note that this should only be used to
     /// create extra windows, the default window will be the window submitted to
     /// the `.run` method.
@@ -320,3 +349,47 @@ pub mod extra {
         azulc_lib::xml::domxml_from_str(s, &mut XmlComponentMap::default()).parsed_dom
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use azul_core::app_resources::LayoutSolverVersion;
+    use azul_core::ui_solver::ResolvedTextLayoutOptions;
+    use azul_text_layout::text_shaping::ParsedFont;
+
+    const KOHO: &[u8] = include_bytes!("../../examples/assets/fonts/KoHo-Light.ttf");
+
+    fn test_app() -> App {
+        App {
+            data: RefAny::new(Dummy { _dummy: 0 }),
+            config: AppConfig::new(LayoutSolverVersion::Default),
+            windows: Vec::new(),
+            image_cache: ImageCache::new(),
+            fc_cache: LazyFcCache::Resolved(FcFontCache::default()),
+        }
+    }
+
+    #[test]
+    fn test_add_font_does_not_parse_outlines() {
+        let mut app = test_app();
+        assert!(app.add_font("KoHo".into(), KOHO.to_vec().into()));
+
+        let font = app.image_cache.get_css_font_family(&"koho".into()).cloned().unwrap();
+        let parsed = unsafe { &*(font.get_data().parsed as *const ParsedFont) };
+        assert!(!parsed.glyph_records_decoded.is_empty());
+        assert!(parsed.glyph_records_decoded.values().all(|g| g.outline.is_none()));
+
+        // outlines are still available on demand
+        let options = ResolvedTextLayoutOptions { font_size_px: 16.0, ..Default::default() };
+        let path = azul_text_layout::text_layout::text_to_path(&font, "Hello", &options);
+        assert!(!path.rings.as_ref().is_empty());
+    }
+
+    #[test]
+    fn test_add_font_invalid_bytes() {
+        let mut app = test_app();
+        assert!(!app.add_font("Invalid".into(), vec![0_u8; 64].into()));
+        assert!(app.image_cache.get_css_font_family(&"invalid".into()).is_none());
+    }
+}
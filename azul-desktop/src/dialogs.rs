@@ -1,3 +1,9 @@
+//! Native OS dialogs (via `tinyfiledialogs`)
+//!
+//! These functions block the calling thread until the dialog is closed.
+//! For non-blocking message boxes, confirmations and prompts rendered
+//! inside of the window, use the `Dialog` widget instead.
+
 #![allow(missing_copy_implementations)]
 
 use core::ffi::c_void;
@@ -62,6 +62,9 @@ pub mod str;
 pub mod file;
 /// Bindings to the native file-chooser, color picker, etc. dialogs
 pub mod dialogs;
+/// Live preview window with hot reload for XML files
+#[cfg(all(feature = "xml", feature = "css_parser"))]
+pub mod preview;
 pub use azul_core::dom;
 pub use azul_core::gl;
 pub use azul_core::styled_dom;
@@ -94,7 +97,7 @@ pub mod traits {
 
 /// Handles text layout (modularized, can be used as a standalone module)
 pub mod text_layout {
-    pub use azul_layout::callback_info_shape_text;
+    pub use azul_layout::{callback_info_shape_text, layout_callback_info_measure_text};
     pub use azul_text_layout::*;
     pub use azul_text_layout::text_layout::*;
     pub use azul_text_layout::text_shaping::*;
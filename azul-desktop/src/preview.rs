@@ -0,0 +1,212 @@
+//! Live preview window for XML files (`azulc --preview file.xml`)
+//!
+//! The window renders the XML file via `str_to_dom` and polls the file (plus
+//! all files it imports via `<import src="..." />` or references via
+//! `<link rel="stylesheet" href="..." />`) for changes. On a change, only the DOM is rebuilt - the window itself stays
+//! open, so the window size is kept and the scroll positions are restored
+//! (scroll states are keyed by the node hash, not by the DOM instance).
+//!
+//! Parse errors never close the window: the last successfully parsed DOM
+//! stays visible and the error is shown in an overlay on top of it.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use azul_core::{
+    app_resources::{AppConfig, LayoutSolverVersion},
+    callbacks::{Callback, CallbackInfo, LayoutCallbackInfo, RefAny, TimerCallbackInfo, TimerCallbackReturn, Update},
+    dom::Dom,
+    styled_dom::StyledDom,
+    task::{TerminateTimer, Timer},
+    window::WindowCreateOptions,
+    xml::{str_to_dom, XmlComponentMap},
+};
+use azul_css_parser::CssApiWrapper;
+use azulc_lib::xml::{check_xml_project, parse_xml_file, register_image_components, register_placeholder_callbacks_with_hook};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration as StdDuration, SystemTime},
+};
+use crate::app::App;
+
+/// How often the watched files are checked for modifications
+const POLL_INTERVAL_MS: u64 = 300;
+
+const ERROR_OVERLAY_STYLE: &str = "
+    position: absolute;
+    top: 0px;
+    left: 0px;
+    right: 0px;
+    padding: 10px;
+    background: #a00000e6;
+    color: white;
+    font-family: monospace;
+    font-size: 13px;
+";
+
+/// State of the preview window
+pub struct PreviewData {
+    /// XML file being previewed
+    xml_path: PathBuf,
+    /// Creates the component map for every reload (`str_to_dom` registers
+    /// the `<component>` nodes of the file into the map, so it can't be reused)
+    component_map: fn() -> XmlComponentMap,
+    /// Watched files with their last known modification time
+    watched_files: Vec<(PathBuf, Option<SystemTime>)>,
+    /// Last DOM that could be built without errors
+    last_dom: Option<StyledDom>,
+    /// Error of the last reload, displayed as an overlay
+    error: Option<String>,
+}
+
+impl PreviewData {
+
+    pub fn new(xml_path: PathBuf, component_map: fn() -> XmlComponentMap) -> Self {
+        let mut data = Self {
+            xml_path,
+            component_map,
+            watched_files: Vec::new(),
+            last_dom: None,
+            error: None,
+        };
+        data.reload();
+        data
+    }
+
+    /// Re-reads and re-parses the XML file, updates the list of watched files
+    pub fn reload(&mut self) {
+
+        // if the file can't be loaded, keep watching the files of the last load
+        let mut watched = self.watched_files.iter().map(|(path, _)| path.clone()).collect::<Vec<_>>();
+        if !watched.contains(&self.xml_path) {
+            watched.push(self.xml_path.clone());
+        }
+
+        let result = parse_xml_file(&self.xml_path)
+            .map_err(|e| format!("{}", e))
+            .and_then(|xml_file| {
+                watched = xml_file.files;
+                let mut component_map = (self.component_map)();
+                register_image_components(&mut component_map, self.xml_path.parent().unwrap_or_else(|| Path::new("")));
+                register_placeholder_callbacks_with_hook(&xml_file.root_nodes, &mut component_map, log_placeholder_call);
+                str_to_dom(&xml_file.root_nodes, &mut component_map, None).map_err(|e| format!("{}", e))
+            });
+
+        match result {
+            Ok(dom) => {
+                self.last_dom = Some(dom);
+                self.error = None;
+            },
+            Err(e) => {
+                // show all errors with their location in the source, the checker
+                // continues after the first error (unlike parse_xml_file / str_to_dom)
+                let diagnostics = check_xml_project(&self.xml_path, self.component_map);
+                self.error = Some(match diagnostics.is_empty() {
+                    true => e,
+                    false => diagnostics.iter().map(|d| d.to_string()).collect::<Vec<_>>().join("\n\n"),
+                });
+            },
+        }
+
+        self.watched_files = watched.into_iter().map(|path| {
+            let modified = get_modified(&path);
+            (path, modified)
+        }).collect();
+    }
+
+    /// Returns whether any watched file was modified, created or deleted since the last call
+    pub fn files_changed(&mut self) -> bool {
+        let mut changed = false;
+        for (path, last_modified) in self.watched_files.iter_mut() {
+            let modified = get_modified(path);
+            if modified != *last_modified {
+                *last_modified = modified;
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Returns the last valid DOM, with the error overlay on top if the last reload failed
+    pub fn get_dom(&self) -> StyledDom {
+
+        let mut dom = match self.last_dom.as_ref() {
+            Some(s) => s.clone(),
+            None => Dom::body().style(CssApiWrapper::empty()),
+        };
+
+        if let Some(error) = self.error.as_ref() {
+            let overlay = Dom::div()
+            .with_inline_style(ERROR_OVERLAY_STYLE)
+            .with_children(error.lines().map(|line| {
+                Dom::div().with_children(vec![Dom::text(line.to_string())].into())
+            }).collect::<Vec<_>>().into())
+            .style(CssApiWrapper::empty());
+            dom.append_child(overlay);
+        }
+
+        dom
+    }
+}
+
+/// Opens the preview window for the XML file and blocks until it is closed
+#[cfg(all(not(test), feature = "std"))]
+pub fn run(xml_path: PathBuf, component_map: fn() -> XmlComponentMap) {
+
+    let title = format!("{} - azul preview", xml_path.file_name().map(|f| f.to_string_lossy().into_owned()).unwrap_or_default());
+    let data = RefAny::new(PreviewData::new(xml_path, component_map));
+
+    let app = App::new(data, AppConfig::new(LayoutSolverVersion::Default));
+    let mut window = WindowCreateOptions::new(layout);
+    window.state.title = title.into();
+    window.create_callback = Some(Callback { cb: start_watching }).into();
+    app.run(window);
+}
+
+fn get_modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+// the event handlers of the previewed file are not implemented, calls are only logged
+fn log_placeholder_call(handler: &str) {
+    #[cfg(feature = "logging")] {
+        log::info!("event handler \"{}\" called", handler);
+    }
+    #[cfg(not(feature = "logging"))] {
+        eprintln!("event handler \"{}\" called", handler);
+    }
+}
+
+extern "C" fn layout(data: &mut RefAny, _: &mut LayoutCallbackInfo) -> StyledDom {
+    match data.downcast_ref::<PreviewData>() {
+        Some(s) => s.get_dom(),
+        None => StyledDom::default(),
+    }
+}
+
+extern "C" fn start_watching(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+    let timer = Timer::new(data.clone(), check_for_changes, info.get_system_time_fn())
+        .with_interval(StdDuration::from_millis(POLL_INTERVAL_MS).into());
+    info.start_timer(timer);
+    Update::DoNothing
+}
+
+extern "C" fn check_for_changes(data: &mut RefAny, _: &mut TimerCallbackInfo) -> TimerCallbackReturn {
+
+    let should_update = match data.downcast_mut::<PreviewData>() {
+        Some(mut s) => {
+            if s.files_changed() {
+                s.reload();
+                Update::RefreshDom
+            } else {
+                Update::DoNothing
+            }
+        },
+        None => Update::DoNothing,
+    };
+
+    TimerCallbackReturn {
+        should_update,
+        should_terminate: TerminateTimer::Continue,
+    }
+}
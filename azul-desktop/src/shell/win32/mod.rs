@@ -94,6 +94,8 @@ type TIMERPTR = winapi::shared::basetsd::UINT_PTR;
 const AZ_TICK_REGENERATE_DOM: usize = 1;
 // ID sent by WM_TIMER to check the thread results
 const AZ_THREAD_TICK: usize = 2;
+// ID sent by WM_TIMER when the mouse rested long enough on a node with a tooltip
+const AZ_TOOLTIP_TICK: usize = 3;
 
 const AZ_REGENERATE_DOM: u32 = WM_APP + 1;
 const AZ_REGENERATE_DISPLAY_LIST: u32 = WM_APP + 2;
@@ -1930,6 +1932,7 @@ impl Window {
                         window_create_options: options.clone(),
                         document_id,
                         id_namespace,
+                        in_window_menus: false,
                     },
                     data,
                     image_cache,
@@ -2773,6 +2776,18 @@ unsafe extern "system" fn WindowProc(
                             &mut destroyed_windows,
                         );
 
+                        // show / hide the tooltip of the hovered node
+                        let tooltip_update = current_window.internal.update_tooltip();
+                        if let Some(delay) = tooltip_update.start_timer {
+                            use winapi::um::winuser::SetTimer;
+                            let millis = delay.millis().min(u32::MAX as u64) as u32;
+                            SetTimer(cur_hwnd, AZ_TOOLTIP_TICK, millis, None);
+                        }
+                        if tooltip_update.should_regenerate_dom &&
+                           ret < ProcessEventResult::ShouldRegenerateDomCurrentWindow {
+                            ret = ProcessEventResult::ShouldRegenerateDomCurrentWindow;
+                        }
+
                         let mut gl = &mut current_window.gl_functions.functions;
                         gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
                         gl.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
@@ -3551,6 +3566,18 @@ unsafe extern "system" fn WindowProc(
                         mem::drop(app_borrow);
                         return DefWindowProcW(hwnd, msg, wparam, lparam)
                     },
+                    AZ_TOOLTIP_TICK => {
+                        // hover delay elapsed: inject the tooltip into the DOM
+                        use winapi::um::winuser::KillTimer;
+                        KillTimer(hwnd, AZ_TOOLTIP_TICK);
+                        if let Some(current_window) = windows.get_mut(&hwnd_key) {
+                            if current_window.internal.show_tooltip_if_ready() {
+                                PostMessageW(hwnd, AZ_REGENERATE_DOM, 0, 0);
+                            }
+                        }
+                        mem::drop(app_borrow);
+                        return DefWindowProcW(hwnd, msg, wparam, lparam)
+                    },
                     AZ_THREAD_TICK => {
 
                         // tick every 16ms to process new thread messages
@@ -3980,6 +4007,7 @@ fn process_event(
     };
     use azul_core::window::FullWindowState;
     use azul_core::callbacks::Update;
+    use azul_core::dom::WindowEventFilter;
 
     // TODO:
     // window.internal.current_window_state.monitor =
@@ -3991,6 +4019,45 @@ fn process_event(
         &window.internal.previous_window_state,
     );
 
+    // Keyboard accelerators (menu items, shortcut tables) take
+    // precedence over the regular VirtualKeyDown callbacks
+    if events.window_events.contains(&WindowEventFilter::VirtualKeyDown) {
+
+        use azul_core::window::{RawWindowHandle, WindowsHandle};
+
+        let window_handle = RawWindowHandle::Windows(WindowsHandle {
+            hwnd: window.hwnd as *mut _,
+            hinstance: hinstance as *mut _,
+        });
+
+        let internal = &mut window.internal;
+        let gl_context_ptr = &window.gl_context_ptr;
+        let accelerator_result = fc_cache.apply_closure(|fc_cache| {
+            internal.invoke_accelerator(
+                &window_handle,
+                gl_context_ptr,
+                image_cache,
+                fc_cache,
+                &config.system_callbacks,
+            )
+        });
+
+        if let Some(callback_result) = accelerator_result {
+            return process_callback_results(
+                callback_result,
+                window,
+                &NodesToCheck::empty(
+                    window.internal.current_window_state.mouse_state.mouse_down(),
+                    window.internal.current_window_state.focused_node,
+                ),
+                image_cache,
+                fc_cache,
+                new_windows,
+                destroyed_windows
+            );
+        }
+    }
+
     // Get nodes for events
     let nodes_to_check = NodesToCheck::new(
         &window.internal.current_window_state.last_hit_test,
@@ -32,7 +32,9 @@ use azul_core::{
     styled_dom::DomId,
     dom::NodeId,
     display_list::RenderCallbacks,
+    menu_overlay::MenuOverlayEvent,
     window::{
+        CursorPosition, LogicalPosition, VirtualKeyCode,
         LogicalSize, Menu, MenuCallback, MenuItem,
         MonitorVec, WindowCreateOptions, WindowInternal,
         WindowState, FullWindowState, ScrollResult,
@@ -68,6 +70,7 @@ use webrender::{
 };
 use std::ffi::{CString, OsStr};
 use std::os::raw;
+use std::time::{Duration as StdDuration, Instant as StdInstant};
 use gl_context_loader::gl;
 
 // TODO: Cache compiled shaders between renderers
@@ -156,6 +159,7 @@ type XCloseDisplayFuncType = extern "C" fn(*mut Display) -> c_int;
 type XPendingFuncType = extern "C" fn(*mut Display) -> c_int;
 type XNextEventFuncType = extern "C" fn(*mut Display, *mut XEvent) -> c_int;
 type XSelectInputFuncType = extern "C" fn(_: *mut Display, _: c_ulong, _: c_long) -> c_int;
+type XLookupStringFuncType = extern "C" fn(*mut XKeyEvent, *mut c_char, c_int, *mut c_ulong, *mut c_void) -> c_int;
 
 const EGL_NO_DISPLAY: EGLDisplay = 0 as *mut c_void;
 const EGL_OPENGL_API: EGLenum = 0x30A2;
@@ -922,9 +926,21 @@ const X11_KEY_RELEASE_MASK: c_long = 0x0000_0002;
 const X11_POINTER_MOTION_MASK: c_long = 0x0000_0040;
 const X11_BUTTON_PRESS_MASK: c_long = 0x0000_0004;
 const X11_BUTTON_RELEASE_MASK: c_long = 0x0000_0008;
+const X11_LEAVE_WINDOW_MASK: c_long = 0x0000_0020;
 
 const X11_FALSE: X11Bool = 0;
 
+// modifier state bits in XKeyEvent.state
+const X11_SHIFT_MASK: c_uint = 1 << 0;
+const X11_CONTROL_MASK: c_uint = 1 << 2;
+const X11_MOD1_MASK: c_uint = 1 << 3; // Alt
+const X11_MOD4_MASK: c_uint = 1 << 6; // Super
+
+const X11_KEY_PRESS: c_int = 2;
+const X11_BUTTON_PRESS: c_int = 4;
+const X11_BUTTON_RELEASE: c_int = 5;
+const X11_MOTION_NOTIFY: c_int = 6;
+const X11_LEAVE_NOTIFY: c_int = 8;
 const X11_EXPOSE: c_int = 12;
 const X11_RESIZE_REQUEST: c_int = 25;
 const X11_CLIENT_MESSAGE: c_int = 33;
@@ -1008,6 +1024,16 @@ pub fn run(app: App, mut root_window: WindowCreateOptions) -> Result<isize, Linu
 
         for (window_id, window) in active_windows.iter_mut() {
 
+            // the X11 shell has no timers: while a tooltip is pending,
+            // poll for events until the hover delay has elapsed
+            if !window.wait_for_event_or_tooltip() {
+                let mut app_borrow = app_data_inner.borrow_mut();
+                if window.internal.show_tooltip_if_ready() {
+                    window.regenerate_dom(&mut app_borrow);
+                }
+                continue;
+            }
+
             // blocks until next event
             unsafe { (xlib.XNextEvent)(window.dpy.get(), &mut cur_xevent) };
 
@@ -1099,6 +1125,81 @@ pub fn run(app: App, mut root_window: WindowCreateOptions) -> Result<isize, Linu
                         return Err(Create(EglError(format!("EGL: eglSwapBuffers(): Failed to swap OpenGL buffers: {}", swap_result))));
                     }
                 },
+                // mouse moved: update the hovered item of an open menu
+                X11_MOTION_NOTIFY => {
+                    let motion = unsafe { cur_xevent.motion };
+                    let position = window.set_cursor_position(motion.x, motion.y);
+                    let menu_event = match window.internal.menu_overlay.as_mut() {
+                        Some(menu_overlay) => menu_overlay.on_mouse_move(position),
+                        None => MenuOverlayEvent::DoNothing,
+                    };
+                    let mut app_borrow = app_data_inner.borrow_mut();
+                    window.handle_menu_overlay_event(menu_event, &mut app_borrow);
+                    window.redo_hit_test();
+                    window.update_tooltip(&mut app_borrow);
+                },
+                // mouse left the window: hide the tooltip
+                X11_LEAVE_NOTIFY => {
+                    let mouse_state = &mut window.internal.current_window_state.mouse_state;
+                    if let Some(position) = mouse_state.cursor_position.get_position() {
+                        mouse_state.cursor_position = CursorPosition::OutOfWindow(position);
+                    }
+                    let mut app_borrow = app_data_inner.borrow_mut();
+                    window.update_tooltip(&mut app_borrow);
+                },
+                X11_BUTTON_PRESS => {
+                    let button = unsafe { cur_xevent.button };
+                    let position = window.set_cursor_position(button.x, button.y);
+                    window.set_mouse_button(button.button, true);
+
+                    let menu_event = match window.internal.menu_overlay.as_mut() {
+                        Some(menu_overlay) => menu_overlay.on_mouse_down(position),
+                        None => {
+                            window.redo_hit_test();
+                            if window.internal.open_context_menu_overlay() {
+                                MenuOverlayEvent::Redraw
+                            } else {
+                                window.internal.click_menu_bar(position)
+                            }
+                        },
+                    };
+
+                    let mut app_borrow = app_data_inner.borrow_mut();
+                    window.handle_menu_overlay_event(menu_event, &mut app_borrow);
+                    window.update_tooltip(&mut app_borrow);
+                },
+                X11_BUTTON_RELEASE => {
+                    let button = unsafe { cur_xevent.button };
+                    window.set_mouse_button(button.button, false);
+                    let mut app_borrow = app_data_inner.borrow_mut();
+                    window.update_tooltip(&mut app_borrow);
+                },
+                // keyboard navigation of open menus, F10 / Alt + mnemonic open the menu bar
+                X11_KEY_PRESS => {
+                    let mut key_event = unsafe { cur_xevent.key };
+                    let (virtual_key, character) = window.lookup_key(&mut key_event);
+                    let alt_down = key_event.state & X11_MOD1_MASK != 0;
+                    window.set_keyboard_state(key_event.state, virtual_key, character);
+
+                    let menu_event = match window.internal.menu_overlay.as_mut() {
+                        Some(menu_overlay) => menu_overlay.on_key_down(virtual_key, character),
+                        None => if let Some((menu_callback, hit_dom_node)) = window.internal.get_accelerator_callback() {
+                            MenuOverlayEvent::Invoke(menu_callback, hit_dom_node)
+                        } else {
+                            let opened = if virtual_key == Some(VirtualKeyCode::F10) {
+                                window.internal.open_menu_bar_with_keyboard(None)
+                            } else if alt_down && character.is_some() {
+                                window.internal.open_menu_bar_with_keyboard(character)
+                            } else {
+                                false
+                            };
+                            if opened { MenuOverlayEvent::Redraw } else { MenuOverlayEvent::DoNothing }
+                        },
+                    };
+
+                    let mut app_borrow = app_data_inner.borrow_mut();
+                    window.handle_menu_overlay_event(menu_event, &mut app_borrow);
+                },
                 // window closed
                 X11_CLIENT_MESSAGE => {
                     let xclient_data = unsafe { cur_xevent.client_message };
@@ -1214,6 +1315,8 @@ struct X11Window {
     pub renderer: Option<WrRenderer>,
     /// Hit-tester, lazily initialized and updated every time the display list changes layout
     pub hit_tester: AsyncHitTester,
+    /// When to show the tooltip of the hovered node (see `update_tooltip`)
+    pub tooltip_deadline: Option<StdInstant>,
 }
 
 struct Xlib {
@@ -1230,6 +1333,7 @@ struct Xlib {
     pub XPending: XPendingFuncType,
     pub XNextEvent: XNextEventFuncType,
     pub XSelectInput: XSelectInputFuncType,
+    pub XLookupString: XLookupStringFuncType,
 }
 
 impl Xlib {
@@ -1290,6 +1394,13 @@ impl Xlib {
             .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
             .ok_or(Create(Egl(format!("X11: no function XSelectInput"))))?;
 
+        // SAFETY: the pointer is non-null and XLookupStringFuncType matches the
+        // signature of XLookupString in <X11/Xutil.h>, `x11` outlives the pointer
+        // because the library is stored in the returned Xlib
+        let XLookupString: XLookupStringFuncType = x11.get("XLookupString")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or_else(|| Create(Egl("X11: no function XLookupString".to_string())))?;
+
         Ok(Xlib {
             library: x11,
             XDefaultScreen,
@@ -1304,6 +1415,7 @@ impl Xlib {
             XPending,
             XNextEvent,
             XSelectInput,
+            XLookupString,
         })
     }
 }
@@ -1421,6 +1533,7 @@ impl X11Window {
             X11_POINTER_MOTION_MASK |
             X11_BUTTON_PRESS_MASK |
             X11_BUTTON_RELEASE_MASK |
+            X11_LEAVE_WINDOW_MASK |
             X11_STRUCTURE_NOTIFY_MASK;
 
         let mut xattr: XSetWindowAttributes = unsafe { mem::zeroed() };
@@ -1428,8 +1541,6 @@ impl X11Window {
 
         let dpi_scale_factor = dpy.get_dpi_scale_factor();
         options.state.size.dpi = (dpi_scale_factor.max(0.0) * 96.0).round() as u32;
-        options.state.size.hidpi_factor = dpi_scale_factor;
-        options.state.size.system_hidpi_factor = dpi_scale_factor;
 
         let logical_size = options.state.size.dimensions;
         let physical_size = logical_size.to_physical(dpi_scale_factor);
@@ -1646,6 +1757,8 @@ impl X11Window {
                     window_create_options: options.clone(),
                     document_id,
                     id_namespace,
+                    // no native menus on X11
+                    in_window_menus: true,
                 },
                 data,
                 image_cache,
@@ -1661,7 +1774,7 @@ impl X11Window {
                         window_state.focused_node,
                         layout_results,
                         &window_state.mouse_state.cursor_position,
-                        window_state.size.hidpi_factor,
+                        window_state.size.get_hidpi_factor(),
                     )
                 },
             )
@@ -1730,6 +1843,7 @@ impl X11Window {
             egl,
             render_api,
             hit_tester: AsyncHitTester::Requested(hit_tester),
+            tooltip_deadline: None,
             internal,
             renderer: Some(renderer),
             gl_functions,
@@ -1749,6 +1863,287 @@ impl X11Window {
     fn show(&mut self) {
         unsafe { (self.xlib.XMapWindow)(self.dpy.get(), self.id) };
     }
+
+    /// Stores the cursor position (in physical pixels) as a logical position
+    fn set_cursor_position(&mut self, x: c_int, y: c_int) -> LogicalPosition {
+        let hidpi_factor = self.internal.current_window_state.size.get_hidpi_factor();
+        let position = LogicalPosition::new(x as f32 / hidpi_factor, y as f32 / hidpi_factor);
+        self.internal.current_window_state.mouse_state.cursor_position = CursorPosition::InWindow(position);
+        position
+    }
+
+    fn set_mouse_button(&mut self, button: c_uint, is_down: bool) {
+        let mouse_state = &mut self.internal.current_window_state.mouse_state;
+        match button {
+            1 => mouse_state.left_down = is_down,
+            2 => mouse_state.middle_down = is_down,
+            3 => mouse_state.right_down = is_down,
+            _ => { },
+        }
+    }
+
+    /// Translates the key event into a virtual key code and the typed character
+    fn lookup_key(&self, key_event: &mut XKeyEvent) -> (Option<VirtualKeyCode>, Option<char>) {
+        let mut buffer = [0 as c_char; 8];
+        let mut keysym: c_ulong = 0;
+        let len = (self.xlib.XLookupString)(
+            key_event,
+            buffer.as_mut_ptr(),
+            buffer.len() as c_int,
+            &mut keysym,
+            ptr::null_mut(),
+        );
+
+        let character = if len > 0 {
+            let bytes = buffer[..len as usize].iter().map(|c| *c as u8).collect::<Vec<_>>();
+            String::from_utf8(bytes).ok().and_then(|s| s.chars().next()).filter(|c| !c.is_control())
+        } else {
+            None
+        };
+
+        (keysym_to_virtual_keycode(keysym), character)
+    }
+
+    /// Updates the keyboard state from the modifier mask and the pressed key
+    /// (the X11 shell doesn't track key releases, so only the modifiers and
+    /// the last pressed key are stored as "pressed")
+    fn set_keyboard_state(&mut self, modifier_state: c_uint, virtual_key: Option<VirtualKeyCode>, character: Option<char>) {
+
+        let mut pressed_virtual_keycodes = Vec::new();
+        let modifiers = [
+            (X11_SHIFT_MASK, VirtualKeyCode::LShift),
+            (X11_CONTROL_MASK, VirtualKeyCode::LControl),
+            (X11_MOD1_MASK, VirtualKeyCode::LAlt),
+            (X11_MOD4_MASK, VirtualKeyCode::LWin),
+        ];
+        for (mask, key) in modifiers.iter() {
+            if modifier_state & *mask != 0 {
+                pressed_virtual_keycodes.push(*key);
+            }
+        }
+        pressed_virtual_keycodes.extend(virtual_key);
+
+        self.internal.previous_window_state = Some(self.internal.current_window_state.clone());
+        let keyboard_state = &mut self.internal.current_window_state.keyboard_state;
+        keyboard_state.current_char = character.map(|c| c as u32).into();
+        keyboard_state.current_virtual_keycode = virtual_key.into();
+        keyboard_state.pressed_virtual_keycodes = pressed_virtual_keycodes.into();
+    }
+
+    /// Hit-tests the current cursor position against the current display list
+    fn redo_hit_test(&mut self) {
+        let hit_test = crate::wr_translate::fullhittest_new_webrender(
+            &*self.hit_tester.resolve(),
+            self.internal.document_id,
+            self.internal.current_window_state.focused_node,
+            &self.internal.layout_results,
+            &self.internal.current_window_state.mouse_state.cursor_position,
+            self.internal.current_window_state.size.get_hidpi_factor(),
+        );
+        self.internal.current_window_state.last_hit_test = hit_test;
+    }
+
+    /// Shows / hides the tooltip of the hovered node after the hit test was
+    /// updated and (re-)starts the hover delay when a new node is hovered
+    fn update_tooltip(&mut self, app_data: &mut ApplicationData) {
+        let tooltip_update = self.internal.update_tooltip();
+        if let Some(delay) = tooltip_update.start_timer {
+            self.tooltip_deadline = Some(StdInstant::now() + StdDuration::from_millis(delay.millis()));
+        }
+        if self.internal.tooltip.hovered_node.is_none() {
+            self.tooltip_deadline = None;
+        }
+        if tooltip_update.should_regenerate_dom {
+            self.regenerate_dom(app_data);
+        }
+    }
+
+    /// Returns true if an event is ready to be read with `XNextEvent`, returns
+    /// false (and resets the deadline) once the tooltip delay has elapsed first
+    fn wait_for_event_or_tooltip(&mut self) -> bool {
+        let deadline = match self.tooltip_deadline {
+            Some(s) => s,
+            None => return true,
+        };
+
+        loop {
+            if (self.xlib.XPending)(self.dpy.get()) > 0 {
+                return true;
+            }
+            let now = StdInstant::now();
+            if now >= deadline {
+                self.tooltip_deadline = None;
+                return false;
+            }
+            std::thread::sleep((deadline - now).min(StdDuration::from_millis(10)));
+        }
+    }
+
+    /// Closes / redraws the in-window menu or invokes the activated menu callback
+    fn handle_menu_overlay_event(&mut self, event: MenuOverlayEvent, app_data: &mut ApplicationData) {
+
+        use azul_core::window::{RawWindowHandle, XlibHandle};
+
+        match event {
+            MenuOverlayEvent::DoNothing => return,
+            MenuOverlayEvent::Redraw => { },
+            MenuOverlayEvent::Close => {
+                self.internal.menu_overlay = None;
+            },
+            MenuOverlayEvent::Invoke(mut menu_callback, hit_dom_node) => {
+                self.internal.menu_overlay = None;
+
+                let window_handle = RawWindowHandle::Xlib(XlibHandle {
+                    window: self.id,
+                    display: self.dpy.get() as *mut Display as *mut c_void,
+                });
+
+                let internal = &mut self.internal;
+                let gl_context_ptr = &self.gl_context_ptr;
+                let image_cache = &mut app_data.image_cache;
+                let system_callbacks = &app_data.config.system_callbacks;
+
+                // NOTE: the X11 shell doesn't process timers, threads or new windows
+                // returned from callbacks yet, the DOM is always regenerated below
+                let _ = app_data.fc_cache.apply_closure(|fc_cache| {
+                    internal.invoke_menu_callback(
+                        &mut menu_callback,
+                        hit_dom_node,
+                        &window_handle,
+                        gl_context_ptr,
+                        image_cache,
+                        fc_cache,
+                        system_callbacks,
+                    )
+                });
+            },
+        }
+
+        self.regenerate_dom(app_data);
+    }
+
+    /// Calls the layout() callback again, rebuilds the display list and renders the new frame
+    fn regenerate_dom(&mut self, app_data: &mut ApplicationData) {
+
+        use crate::wr_translate::wr_translate_document_id;
+
+        self.make_current();
+
+        let document_id = self.internal.document_id;
+        let hit_tester = &mut self.hit_tester;
+        let internal = &mut self.internal;
+        let gl_context = &self.gl_context_ptr;
+        let data = &mut app_data.data;
+        let image_cache = &app_data.image_cache;
+
+        let mut resource_updates = Vec::new();
+        app_data.fc_cache.apply_closure(|fc_cache| {
+            internal.regenerate_styled_dom(
+                data,
+                image_cache,
+                gl_context,
+                &mut resource_updates,
+                internal.get_dpi_scale_factor(),
+                &crate::app::CALLBACKS,
+                fc_cache,
+                azul_layout::do_the_relayout,
+                |window_state, scroll_states, layout_results| {
+                    crate::wr_translate::fullhittest_new_webrender(
+                        &*hit_tester.resolve(),
+                        document_id,
+                        window_state.focused_node,
+                        layout_results,
+                        &window_state.mouse_state.cursor_position,
+                        window_state.size.get_hidpi_factor(),
+                    )
+                }
+            );
+        });
+
+        rebuild_display_list(
+            &mut self.internal,
+            &mut self.render_api,
+            &app_data.image_cache,
+            resource_updates,
+        );
+        self.render_api.flush_scene_builder();
+
+        generate_frame(&mut self.internal, &mut self.render_api, true);
+        self.render_api.flush_scene_builder();
+
+        self.hit_tester = AsyncHitTester::Requested(
+            self.render_api.request_hit_tester(wr_translate_document_id(document_id))
+        );
+
+        self.render_frame();
+    }
+
+    /// Renders the last generated frame and swaps the buffers
+    fn render_frame(&mut self) {
+
+        let physical_size = self.internal.current_window_state.size.get_physical_size();
+        let width = physical_size.width as i32;
+        let height = physical_size.height as i32;
+
+        self.make_current();
+
+        let mut current_program = [0_i32];
+        unsafe {
+            self.gl_functions.functions.get_integer_v(
+                gl_context_loader::gl::CURRENT_PROGRAM,
+                (&mut current_program[..]).into()
+            );
+        }
+
+        self.gl_functions.functions.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+        self.gl_functions.functions.viewport(0, 0, width, height);
+
+        if let Some(r) = self.renderer.as_mut() {
+            r.update();
+            let _ = r.render(WrDeviceIntSize::new(width, height), 0);
+        }
+
+        (self.egl.eglSwapBuffers)(self.egl_display, self.egl_surface);
+
+        self.gl_functions.functions.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+        self.gl_functions.functions.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
+        self.gl_functions.functions.use_program(current_program[0] as u32);
+    }
+}
+
+/// Maps the X11 KeySyms that are relevant for keyboard navigation
+/// and keyboard shortcuts to virtual key codes
+fn keysym_to_virtual_keycode(keysym: c_ulong) -> Option<VirtualKeyCode> {
+    use azul_core::window::VirtualKeyCode::*;
+
+    const LETTERS: [VirtualKeyCode; 26] = [
+        A, B, C, D, E, F, G, H, I, J, K, L, M,
+        N, O, P, Q, R, S, T, U, V, W, X, Y, Z,
+    ];
+    const DIGITS: [VirtualKeyCode; 10] = [Key0, Key1, Key2, Key3, Key4, Key5, Key6, Key7, Key8, Key9];
+    const FUNCTION_KEYS: [VirtualKeyCode; 12] = [F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12];
+
+    let key = match keysym {
+        0x0061..=0x007a => LETTERS[(keysym - 0x0061) as usize], // a - z
+        0x0041..=0x005a => LETTERS[(keysym - 0x0041) as usize], // A - Z
+        0x0030..=0x0039 => DIGITS[(keysym - 0x0030) as usize],
+        0xffbe..=0xffc9 => FUNCTION_KEYS[(keysym - 0xffbe) as usize],
+        0xff08 => Back,
+        0xff09 => Tab,
+        0xff0d => Return,
+        0xff1b => Escape,
+        0xff50 => Home,
+        0xff51 => Left,
+        0xff52 => Up,
+        0xff53 => Right,
+        0xff54 => Down,
+        0xff57 => End,
+        0xff8d => NumpadEnter,
+        0xffff => Delete,
+        0x0020 => Space,
+        _ => return None,
+    };
+    Some(key)
 }
 
 struct X11Display {
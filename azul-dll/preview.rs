@@ -0,0 +1,15 @@
+// `azul-preview file.xml`: opens a window that renders the XML file and reloads it on changes,
+// started by `azulc --preview file.xml` (azulc can't depend on azul-desktop itself)
+
+fn main() {
+
+    let xml_path = match std::env::args().nth(1) {
+        Some(s) => s,
+        None => {
+            eprintln!("usage: azul-preview file.xml");
+            std::process::exit(-1);
+        }
+    };
+
+    azul_desktop::preview::run(xml_path.into(), azul::widgets::widgets_component_map);
+}
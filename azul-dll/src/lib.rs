@@ -27,6 +27,8 @@ pub use AzAppTT as AzApp;
 #[no_mangle] pub extern "C" fn AzApp_addWindow(app: &mut AzApp, window: AzWindowCreateOptions) { app.add_window(window) }
 /// Adds a new image identified by an ID to the image cache
 #[no_mangle] pub extern "C" fn AzApp_addImage(app: &mut AzApp, id: AzString, image: AzImageRef) { app.add_image(id, image) }
+/// Registers a font (TTF / OTF bytes) under a font family name, so that it can be used via `font-family` without being installed. Returns false if the font could not be parsed.
+#[no_mangle] pub extern "C" fn AzApp_addFont(app: &mut AzApp, family: AzString, font_bytes: AzU8Vec) -> bool { app.add_font(family, font_bytes) }
 /// Returns a list of monitors - useful for setting the monitor that a window should spawn on.
 #[no_mangle] pub extern "C" fn AzApp_getMonitors(app: &AzApp) -> AzMonitorVec { app.get_monitors() }
 /// Runs the application. Due to platform restrictions (specifically `WinMain` on Windows), this function never returns.
@@ -504,9 +506,25 @@ pub use azul_impl::callbacks::InlineText as AzInlineTextTT;
 pub use AzInlineTextTT as AzInlineText;
 /// Hit-tests the inline text, returns detailed information about which glyph / word / line, etc. the position (usually the mouse cursor) is currently over. Result may be empty (no hits) or contain more than one result (cursor is hovering over multiple overlapping glyphs at once).
 #[no_mangle] pub extern "C" fn AzInlineText_hitTest(inlinetext: &AzInlineText, position: AzLogicalPosition) -> AzInlineTextHitVec { inlinetext.hit_test(position).into() }
+/// Returns the size of the text and of its individual lines
+#[no_mangle] pub extern "C" fn AzInlineText_getTextMetrics(inlinetext: &AzInlineText) -> AzTextMetrics { inlinetext.get_text_metrics() }
 /// Destructor: Takes ownership of the `InlineText` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzInlineText_delete(object: &mut AzInlineText) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Size of a string measured without laying out a DOM
+pub use azul_impl::callbacks::TextMetrics as AzTextMetricsTT;
+pub use AzTextMetricsTT as AzTextMetrics;
+/// Destructor: Takes ownership of the `TextMetrics` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextMetrics_delete(object: &mut AzTextMetrics) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Metrics of a single line of a measured text
+pub use azul_impl::callbacks::TextLineMetrics as AzTextLineMetricsTT;
+pub use AzTextLineMetricsTT as AzTextLineMetrics;
+
+/// Re-export of rust-allocated (stack based) `InlineTextRun` struct
+pub use azul_impl::callbacks::InlineTextRun as AzInlineTextRunTT;
+pub use AzInlineTextRunTT as AzInlineTextRun;
+
 /// Re-export of rust-allocated (stack based) `InlineLine` struct
 pub use azul_impl::callbacks::InlineLine as AzInlineLineTT;
 pub use AzInlineLineTT as AzInlineLine;
@@ -688,6 +706,8 @@ pub use AzLayoutCallbackInfoTT as AzLayoutCallbackInfo;
 #[no_mangle] pub extern "C" fn AzLayoutCallbackInfo_getSystemFonts(layoutcallbackinfo: &AzLayoutCallbackInfo) -> AzStringPairVec { layoutcallbackinfo.get_system_fonts().into() }
 /// Returns an `ImageRef` referenced by a CSS ID
 #[no_mangle] pub extern "C" fn AzLayoutCallbackInfo_getImage(layoutcallbackinfo: &AzLayoutCallbackInfo, id: AzString) -> AzOptionImageRef { layoutcallbackinfo.get_image(&id).into() }
+/// Measures the size of a text before the layout (i.e. to size table columns to their content), using the first loadable font of the font families. Returns `None` if none of the fonts could be loaded
+#[no_mangle] pub extern "C" fn AzLayoutCallbackInfo_measureText(layoutcallbackinfo: &AzLayoutCallbackInfo, text: AzString, font_families: AzStyleFontFamilyVec, options: AzResolvedTextLayoutOptions) -> AzOptionTextMetrics { azul_impl::text_layout::layout_callback_info_measure_text(layoutcallbackinfo, text, font_families, options).into() }
 /// Destructor: Takes ownership of the `LayoutCallbackInfo` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzLayoutCallbackInfo_delete(object: &mut AzLayoutCallbackInfo) {  unsafe { core::ptr::drop_in_place(object); } }
 
@@ -807,6 +827,16 @@ pub use AzDomTT as AzDom;
 #[no_mangle] pub extern "C" fn AzDom_setContextMenu(dom: &mut AzDom, context_menu: AzMenu) { dom.root.set_context_menu(context_menu) }
 /// Same as set_context_menu, but as a builder method
 #[no_mangle] pub extern "C" fn AzDom_withContextMenu(dom: &mut AzDom, context_menu: AzMenu) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_context_menu(context_menu); dom }
+/// Sets the tooltip for the DOM root node, shown after the mouse rested on the node for `tooltip.delay`
+#[no_mangle] pub extern "C" fn AzDom_setTooltip(dom: &mut AzDom, tooltip: AzTooltip) { dom.root.set_tooltip(tooltip) }
+/// Same as set_tooltip, but as a builder method
+#[no_mangle] pub extern "C" fn AzDom_withTooltip(dom: &mut AzDom, tooltip: AzTooltip) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_tooltip(tooltip); dom }
+/// Sets keyboard shortcuts that are active while the DOM root node or one of its children is focused. Set them on the root of the window for window-wide shortcuts
+#[no_mangle] pub extern "C" fn AzDom_setKeyboardShortcuts(dom: &mut AzDom, keyboard_shortcuts: AzKeyboardShortcutVec) { dom.root.set_keyboard_shortcuts(keyboard_shortcuts) }
+/// Same as set_keyboard_shortcuts, but as a builder method
+#[no_mangle] pub extern "C" fn AzDom_withKeyboardShortcuts(dom: &mut AzDom, keyboard_shortcuts: AzKeyboardShortcutVec) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_keyboard_shortcuts(keyboard_shortcuts); dom }
+/// Makes this node and all of its children inert: they are still rendered, but can't receive events or the keyboard focus anymore - used to block the content behind modal dialogs
+#[no_mangle] pub extern "C" fn AzDom_makeInert(dom: &mut AzDom) { dom.make_inert() }
 /// Calculates the hash of this node (note: in order to be truly unique, you also have to hash the DOM and Node ID).
 #[no_mangle] pub extern "C" fn AzDom_hash(dom: &AzDom) -> u64 { dom.root.calculate_node_data_hash().0 }
 /// Returns the number of nodes in the DOM, including all child DOM trees. Result is equal to `self.total_children + 1` (count of all child trees + the root node)
@@ -905,6 +935,10 @@ pub use AzNodeDataTT as AzNodeData;
 #[no_mangle] pub extern "C" fn AzNodeData_setMenuBar(nodedata: &mut AzNodeData, menu_bar: AzMenu) { nodedata.set_menu_bar(menu_bar) }
 /// Signalizes that this node has a (native) context-aware menu. If set, the user can left-click the node to open the menu
 #[no_mangle] pub extern "C" fn AzNodeData_setContextMenu(nodedata: &mut AzNodeData, context_menu: AzMenu) { nodedata.set_context_menu(context_menu) }
+/// Sets a tooltip that is shown when the mouse rests over this node. The tooltip text is also used as the accessible description of the node
+#[no_mangle] pub extern "C" fn AzNodeData_setTooltip(nodedata: &mut AzNodeData, tooltip: AzTooltip) { nodedata.set_tooltip(tooltip) }
+/// Sets keyboard shortcuts that are active while this node or one of its children is focused
+#[no_mangle] pub extern "C" fn AzNodeData_setKeyboardShortcuts(nodedata: &mut AzNodeData, keyboard_shortcuts: AzKeyboardShortcutVec) { nodedata.set_keyboard_shortcuts(keyboard_shortcuts) }
 /// Calculates the hash of this node (note: in order to be truly unique, you also have to hash the DOM and Node ID).
 #[no_mangle] pub extern "C" fn AzNodeData_hash(nodedata: &AzNodeData) -> u64 { nodedata.calculate_node_data_hash().0 }
 /// Destructor: Takes ownership of the `NodeData` pointer and deletes it.
@@ -956,6 +990,24 @@ pub use AzAccessibilityInfoTT as AzAccessibilityInfo;
 /// Destructor: Takes ownership of the `AccessibilityInfo` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzAccessibilityInfo_delete(object: &mut AzAccessibilityInfo) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Hover hint of a DOM node, see `Dom::with_tooltip()`
+pub use azul_impl::dom::Tooltip as AzTooltipTT;
+pub use AzTooltipTT as AzTooltip;
+/// Creates a new text tooltip with the default hover delay (500ms)
+#[no_mangle] pub extern "C" fn AzTooltip_text(text: AzString) -> AzTooltip { AzTooltip::text(text) }
+/// Creates a new tooltip rendering a custom DOM, with the default hover delay (500ms)
+#[no_mangle] pub extern "C" fn AzTooltip_dom(dom: AzDom) -> AzTooltip { AzTooltip::dom(dom) }
+/// Sets the hover delay before the tooltip is shown (builder method)
+#[no_mangle] pub extern "C" fn AzTooltip_withDelay(tooltip: &mut AzTooltip, delay: AzDuration) -> AzTooltip { let mut tooltip = tooltip.swap_with_default(); tooltip.delay = delay; tooltip }
+/// Destructor: Takes ownership of the `Tooltip` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTooltip_delete(object: &mut AzTooltip) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Content of a `Tooltip`: either a plain text or a custom DOM
+pub use azul_impl::dom::TooltipContent as AzTooltipContentTT;
+pub use AzTooltipContentTT as AzTooltipContent;
+/// Destructor: Takes ownership of the `TooltipContent` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTooltipContent_delete(object: &mut AzTooltipContent) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// MSAA Accessibility role constants. For information on what each role does, see the <a href="https://docs.microsoft.com/en-us/windows/win32/winauto/object-roles">MSDN Role Constants page</a>
 pub use azul_impl::dom::AccessibilityRole as AzAccessibilityRoleTT;
 pub use AzAccessibilityRoleTT as AzAccessibilityRole;
@@ -1029,9 +1081,20 @@ pub use AzStringMenuItemTT as AzStringMenuItem;
 /// Combination of virtual key codes that have to be pressed together
 pub use azul_core::window::VirtualKeyCodeCombo as AzVirtualKeyCodeComboTT;
 pub use AzVirtualKeyCodeComboTT as AzVirtualKeyCodeCombo;
+/// Returns whether the key combination was just completed: the last pressed key is a non-modifier key of the combination, all other keys of the combination are held down and no additional modifier is pressed
+#[no_mangle] pub extern "C" fn AzVirtualKeyCodeCombo_matches(virtualkeycodecombo: &AzVirtualKeyCodeCombo, keyboard_state: AzKeyboardState) -> bool { virtualkeycodecombo.matches(&keyboard_state) }
 /// Destructor: Takes ownership of the `VirtualKeyCodeCombo` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzVirtualKeyCodeCombo_delete(object: &mut AzVirtualKeyCodeCombo) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Keyboard shortcut that is not attached to a menu item: when the key combination is pressed, the callback is invoked. See `Dom::with_keyboard_shortcuts()`
+pub use azul_core::window::KeyboardShortcut as AzKeyboardShortcutTT;
+pub use AzKeyboardShortcutTT as AzKeyboardShortcut;
+/// Creates a new `KeyboardShortcut` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `KeyboardShortcut::new()` constructor.
+#[no_mangle] pub extern "C" fn AzKeyboardShortcut_new(keys: AzVirtualKeyCodeCombo, data: AzRefAny, callback: AzCallbackType) -> AzKeyboardShortcut { AzKeyboardShortcut::new(keys, data, callback) }
+/// Destructor: Takes ownership of the `KeyboardShortcut` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzKeyboardShortcut_delete(object: &mut AzKeyboardShortcut) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Similar to `dom.CallbackData`, stores some data + a callback to call when the menu is activated
 pub use azul_core::window::MenuCallback as AzMenuCallbackTT;
 pub use AzMenuCallbackTT as AzMenuCallback;
@@ -1113,6 +1176,32 @@ pub use AzCssTT as AzCss;
 /// Destructor: Takes ownership of the `Css` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzCss_delete(object: &mut AzCss) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Parsed contents of an `@font-face { ... }` block
+pub use azul_impl::css::CssFontFace as AzCssFontFaceTT;
+pub use AzCssFontFaceTT as AzCssFontFace;
+/// Destructor: Takes ownership of the `CssFontFace` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCssFontFace_delete(object: &mut AzCssFontFace) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Single entry of the `src: ...` list in a `@font-face` rule
+pub use azul_impl::css::CssFontFaceSource as AzCssFontFaceSourceTT;
+pub use AzCssFontFaceSourceTT as AzCssFontFaceSource;
+/// Destructor: Takes ownership of the `CssFontFaceSource` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCssFontFaceSource_delete(object: &mut AzCssFontFaceSource) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// `url("fonts/Roboto.ttf") format("truetype")`
+pub use azul_impl::css::CssFontFaceUrl as AzCssFontFaceUrlTT;
+pub use AzCssFontFaceUrlTT as AzCssFontFaceUrl;
+/// Destructor: Takes ownership of the `CssFontFaceUrl` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCssFontFaceUrl_delete(object: &mut AzCssFontFaceUrl) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `CssFontFaceStyle` struct
+pub use azul_impl::css::CssFontFaceStyle as AzCssFontFaceStyleTT;
+pub use AzCssFontFaceStyleTT as AzCssFontFaceStyle;
+
+/// `U+0000-00FF`: inclusive range of unicode code points
+pub use azul_impl::css::CssUnicodeRange as AzCssUnicodeRangeTT;
+pub use AzCssUnicodeRangeTT as AzCssUnicodeRange;
+
 /// Re-export of rust-allocated (stack based) `CssPropertyType` struct
 pub use azul_impl::css::CssPropertyType as AzCssPropertyTypeTT;
 pub use AzCssPropertyTypeTT as AzCssPropertyType;
@@ -1299,6 +1388,10 @@ pub use AzLayoutRightTT as AzLayoutRight;
 pub use azul_impl::css::LayoutTop as AzLayoutTopTT;
 pub use AzLayoutTopTT as AzLayoutTop;
 
+/// Re-export of rust-allocated (stack based) `LayoutVerticalAlign` struct
+pub use azul_impl::css::LayoutVerticalAlign as AzLayoutVerticalAlignTT;
+pub use AzLayoutVerticalAlignTT as AzLayoutVerticalAlign;
+
 /// Re-export of rust-allocated (stack based) `LayoutWidth` struct
 pub use azul_impl::css::LayoutWidth as AzLayoutWidthTT;
 pub use AzLayoutWidthTT as AzLayoutWidth;
@@ -1563,6 +1656,10 @@ pub use AzStyleTransformSkew2DTT as AzStyleTransformSkew2D;
 pub use azul_impl::css::StyleTextAlign as AzStyleTextAlignTT;
 pub use AzStyleTextAlignTT as AzStyleTextAlign;
 
+/// Re-export of rust-allocated (stack based) `StyleTextDecoration` struct
+pub use azul_impl::css::StyleTextDecoration as AzStyleTextDecorationTT;
+pub use AzStyleTextDecorationTT as AzStyleTextDecoration;
+
 /// Re-export of rust-allocated (stack based) `StyleTextColor` struct
 pub use azul_impl::css::StyleTextColor as AzStyleTextColorTT;
 pub use AzStyleTextColorTT as AzStyleTextColor;
@@ -1683,6 +1780,10 @@ pub use AzLayoutRightValueTT as AzLayoutRightValue;
 pub use azul_impl::css::LayoutTopValue as AzLayoutTopValueTT;
 pub use AzLayoutTopValueTT as AzLayoutTopValue;
 
+/// Re-export of rust-allocated (stack based) `LayoutVerticalAlignValue` struct
+pub use azul_impl::css::LayoutVerticalAlignValue as AzLayoutVerticalAlignValueTT;
+pub use AzLayoutVerticalAlignValueTT as AzLayoutVerticalAlignValue;
+
 /// Re-export of rust-allocated (stack based) `LayoutWidthValue` struct
 pub use azul_impl::css::LayoutWidthValue as AzLayoutWidthValueTT;
 pub use AzLayoutWidthValueTT as AzLayoutWidthValue;
@@ -1819,6 +1920,10 @@ pub use AzStyleTabWidthValueTT as AzStyleTabWidthValue;
 pub use azul_impl::css::StyleTextAlignValue as AzStyleTextAlignValueTT;
 pub use AzStyleTextAlignValueTT as AzStyleTextAlignValue;
 
+/// Re-export of rust-allocated (stack based) `StyleTextDecorationValue` struct
+pub use azul_impl::css::StyleTextDecorationValue as AzStyleTextDecorationValueTT;
+pub use AzStyleTextDecorationValueTT as AzStyleTextDecorationValue;
+
 /// Re-export of rust-allocated (stack based) `StyleTextColorValue` struct
 pub use azul_impl::css::StyleTextColorValue as AzStyleTextColorValueTT;
 pub use AzStyleTextColorValueTT as AzStyleTextColorValue;
@@ -2298,6 +2403,10 @@ pub use AzFrameTT as AzFrame;
 /// Re-export of rust-allocated (stack based) `NodeGraph` struct
 pub use crate::widgets::node_graph::NodeGraph as AzNodeGraphTT;
 pub use AzNodeGraphTT as AzNodeGraph;
+/// Loads a graph saved with `to_json`: returns a default `NodeGraph` with only the `nodes`, `offset` and `scale_factor` set, the node types and callbacks have to be set again by the application
+#[no_mangle] pub extern "C" fn AzNodeGraph_fromJson(json: AzString) -> AzResultNodeGraphNodeGraphError { AzNodeGraph::from_json(json.as_str()).into() }
+/// Serializes the nodes (including positions, field values and connections), the offset and the zoom level of the graph to JSON
+#[no_mangle] pub extern "C" fn AzNodeGraph_toJson(nodegraph: &AzNodeGraph) -> AzString { nodegraph.to_json() }
 /// Equivalent to the Rust `NodeGraph::dom()` function.
 #[no_mangle] pub extern "C" fn AzNodeGraph_dom(nodegraph: &mut AzNodeGraph) -> AzDom { let mut nodegraph = nodegraph.swap_with_default(); nodegraph.dom() }
 /// Destructor: Takes ownership of the `NodeGraph` pointer and deletes it.
@@ -2419,6 +2528,39 @@ pub use AzNodeGraphOnNodeFieldEditedTT as AzNodeGraphOnNodeFieldEdited;
 /// Destructor: Takes ownership of the `NodeGraphOnNodeFieldEdited` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzNodeGraphOnNodeFieldEdited_delete(object: &mut AzNodeGraphOnNodeFieldEdited) {  unsafe { core::ptr::drop_in_place(object); } }
 
+pub type AzNodeGraphOnNodeGraphZoomedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, f32, AzLogicalPosition) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `NodeGraphOnNodeGraphZoomedCallback` struct
+pub use crate::widgets::node_graph::OnNodeGraphZoomedCallback as AzNodeGraphOnNodeGraphZoomedCallbackTT;
+pub use AzNodeGraphOnNodeGraphZoomedCallbackTT as AzNodeGraphOnNodeGraphZoomedCallback;
+
+/// Re-export of rust-allocated (stack based) `NodeGraphOnNodeGraphZoomed` struct
+pub use crate::widgets::node_graph::OnNodeGraphZoomed as AzNodeGraphOnNodeGraphZoomedTT;
+pub use AzNodeGraphOnNodeGraphZoomedTT as AzNodeGraphOnNodeGraphZoomed;
+/// Destructor: Takes ownership of the `NodeGraphOnNodeGraphZoomed` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzNodeGraphOnNodeGraphZoomed_delete(object: &mut AzNodeGraphOnNodeGraphZoomed) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzNodeGraphOnSelectionChangedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphNodeIdVec) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `NodeGraphOnSelectionChangedCallback` struct
+pub use crate::widgets::node_graph::OnNodeGraphSelectionChangedCallback as AzNodeGraphOnSelectionChangedCallbackTT;
+pub use AzNodeGraphOnSelectionChangedCallbackTT as AzNodeGraphOnSelectionChangedCallback;
+
+/// Re-export of rust-allocated (stack based) `NodeGraphOnSelectionChanged` struct
+pub use crate::widgets::node_graph::OnNodeGraphSelectionChanged as AzNodeGraphOnSelectionChangedTT;
+pub use AzNodeGraphOnSelectionChangedTT as AzNodeGraphOnSelectionChanged;
+/// Destructor: Takes ownership of the `NodeGraphOnSelectionChanged` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzNodeGraphOnSelectionChanged_delete(object: &mut AzNodeGraphOnSelectionChanged) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzNodeGraphOnHistoryChangedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphHistory) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `NodeGraphOnHistoryChangedCallback` struct
+pub use crate::widgets::node_graph::OnNodeGraphHistoryChangedCallback as AzNodeGraphOnHistoryChangedCallbackTT;
+pub use AzNodeGraphOnHistoryChangedCallbackTT as AzNodeGraphOnHistoryChangedCallback;
+
+/// Re-export of rust-allocated (stack based) `NodeGraphOnHistoryChanged` struct
+pub use crate::widgets::node_graph::OnNodeGraphHistoryChanged as AzNodeGraphOnHistoryChangedTT;
+pub use AzNodeGraphOnHistoryChangedTT as AzNodeGraphOnHistoryChanged;
+/// Destructor: Takes ownership of the `NodeGraphOnHistoryChanged` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzNodeGraphOnHistoryChanged_delete(object: &mut AzNodeGraphOnHistoryChanged) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `InputOutputTypeId` struct
 pub use crate::widgets::node_graph::InputOutputTypeId as AzInputOutputTypeIdTT;
 pub use AzInputOutputTypeIdTT as AzInputOutputTypeId;
@@ -2493,14 +2635,126 @@ pub use AzGraphDragAmountTT as AzGraphDragAmount;
 pub use crate::widgets::node_graph::NodeDragAmount as AzNodeDragAmountTT;
 pub use AzNodeDragAmountTT as AzNodeDragAmount;
 
-/// Re-export of rust-allocated (stack based) `ListView` struct
+/// Re-export of rust-allocated (stack based) `NodeGraphError` struct
+pub use crate::widgets::node_graph::NodeGraphError as AzNodeGraphErrorTT;
+pub use AzNodeGraphErrorTT as AzNodeGraphError;
+
+/// Connection from the output of one node to the input of another node
+pub use crate::widgets::node_graph::NodeGraphConnection as AzNodeGraphConnectionTT;
+pub use AzNodeGraphConnectionTT as AzNodeGraphConnection;
+
+/// Node that was moved by `amount` (in graph coordinates)
+pub use crate::widgets::node_graph::NodeGraphNodeMove as AzNodeGraphNodeMoveTT;
+pub use AzNodeGraphNodeMoveTT as AzNodeGraphNodeMove;
+
+/// Field of a node that was changed from `old_value` to `new_value`
+pub use crate::widgets::node_graph::NodeGraphFieldEdit as AzNodeGraphFieldEditTT;
+pub use AzNodeGraphFieldEditTT as AzNodeGraphFieldEdit;
+/// Destructor: Takes ownership of the `NodeGraphFieldEdit` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzNodeGraphFieldEdit_delete(object: &mut AzNodeGraphFieldEdit) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Single modification of the node graph, recorded in the undo history
+pub use crate::widgets::node_graph::NodeGraphEditOp as AzNodeGraphEditOpTT;
+pub use AzNodeGraphEditOpTT as AzNodeGraphEditOp;
+/// Returns the operation that reverts this operation
+#[no_mangle] pub extern "C" fn AzNodeGraphEditOp_invert(nodegrapheditop: &AzNodeGraphEditOp) -> AzNodeGraphEditOp { nodegrapheditop.invert() }
+/// Destructor: Takes ownership of the `NodeGraphEditOp` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzNodeGraphEditOp_delete(object: &mut AzNodeGraphEditOp) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// One undoable user action (for example "paste three nodes"), consisting of one or more operations
+pub use crate::widgets::node_graph::NodeGraphEdit as AzNodeGraphEditTT;
+pub use AzNodeGraphEditTT as AzNodeGraphEdit;
+/// Destructor: Takes ownership of the `NodeGraphEdit` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzNodeGraphEdit_delete(object: &mut AzNodeGraphEdit) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Undo / redo stacks of the node graph: the last edit is at the end of each stack
+pub use crate::widgets::node_graph::NodeGraphHistory as AzNodeGraphHistoryTT;
+pub use AzNodeGraphHistoryTT as AzNodeGraphHistory;
+/// Equivalent to the Rust `NodeGraphHistory::can_undo()` function.
+#[no_mangle] pub extern "C" fn AzNodeGraphHistory_canUndo(nodegraphhistory: &AzNodeGraphHistory) -> bool { nodegraphhistory.can_undo() }
+/// Equivalent to the Rust `NodeGraphHistory::can_redo()` function.
+#[no_mangle] pub extern "C" fn AzNodeGraphHistory_canRedo(nodegraphhistory: &AzNodeGraphHistory) -> bool { nodegraphhistory.can_redo() }
+/// Destructor: Takes ownership of the `NodeGraphHistory` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzNodeGraphHistory_delete(object: &mut AzNodeGraphHistory) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Table with a header row. If a `row_provider` is set, the ListView is virtualized: only the rows that fit into the visible area are requested from the row provider and rendered. The callbacks report scrolling, selection and column resizing, the application stores the new `first_visible_row`, selection state and `column_widths` for the next frame
 pub use crate::widgets::list_view::ListView as AzListViewTT;
 pub use AzListViewTT as AzListView;
 /// Creates a new `ListView` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `ListView::new()` constructor.
 #[no_mangle] pub extern "C" fn AzListView_new(columns: AzStringVec) -> AzListView { AzListView::new(columns) }
+/// Equivalent to the Rust `ListView::set_columns()` function.
+#[no_mangle] pub extern "C" fn AzListView_setColumns(listview: &mut AzListView, columns: AzStringVec) { listview.set_columns(columns) }
+/// Equivalent to the Rust `ListView::with_columns()` function.
+#[no_mangle] pub extern "C" fn AzListView_withColumns(listview: &mut AzListView, columns: AzStringVec) -> AzListView { listview.with_columns(columns) }
+/// Equivalent to the Rust `ListView::set_rows()` function.
+#[no_mangle] pub extern "C" fn AzListView_setRows(listview: &mut AzListView, rows: AzListViewRowVec) { listview.set_rows(rows) }
 /// Equivalent to the Rust `ListView::with_rows()` function.
 #[no_mangle] pub extern "C" fn AzListView_withRows(listview: &mut AzListView, rows: AzListViewRowVec) -> AzListView { listview.with_rows(rows) }
+/// Equivalent to the Rust `ListView::set_sorted_by()` function.
+#[no_mangle] pub extern "C" fn AzListView_setSortedBy(listview: &mut AzListView, sorted_by: AzOptionUsize) { listview.set_sorted_by(sorted_by) }
+/// Equivalent to the Rust `ListView::with_sorted_by()` function.
+#[no_mangle] pub extern "C" fn AzListView_withSortedBy(listview: &mut AzListView, sorted_by: AzOptionUsize) -> AzListView { listview.with_sorted_by(sorted_by) }
+/// Equivalent to the Rust `ListView::set_sort_descending()` function.
+#[no_mangle] pub extern "C" fn AzListView_setSortDescending(listview: &mut AzListView, sort_descending: bool) { listview.set_sort_descending(sort_descending) }
+/// Equivalent to the Rust `ListView::with_sort_descending()` function.
+#[no_mangle] pub extern "C" fn AzListView_withSortDescending(listview: &mut AzListView, sort_descending: bool) -> AzListView { listview.with_sort_descending(sort_descending) }
+/// Equivalent to the Rust `ListView::set_scroll_offset()` function.
+#[no_mangle] pub extern "C" fn AzListView_setScrollOffset(listview: &mut AzListView, scroll_offset: AzPixelValueNoPercent) { listview.set_scroll_offset(scroll_offset) }
+/// Equivalent to the Rust `ListView::with_scroll_offset()` function.
+#[no_mangle] pub extern "C" fn AzListView_withScrollOffset(listview: &mut AzListView, scroll_offset: AzPixelValueNoPercent) -> AzListView { listview.with_scroll_offset(scroll_offset) }
+/// Equivalent to the Rust `ListView::set_content_height()` function.
+#[no_mangle] pub extern "C" fn AzListView_setContentHeight(listview: &mut AzListView, content_height: AzPixelValueNoPercent) { listview.set_content_height(content_height) }
+/// Equivalent to the Rust `ListView::with_content_height()` function.
+#[no_mangle] pub extern "C" fn AzListView_withContentHeight(listview: &mut AzListView, content_height: AzPixelValueNoPercent) -> AzListView { listview.with_content_height(content_height) }
+/// Equivalent to the Rust `ListView::set_column_context_menu()` function.
+#[no_mangle] pub extern "C" fn AzListView_setColumnContextMenu(listview: &mut AzListView, context_menu: AzMenu) { listview.set_column_context_menu(context_menu) }
+/// Equivalent to the Rust `ListView::with_column_context_menu()` function.
+#[no_mangle] pub extern "C" fn AzListView_withColumnContextMenu(listview: &mut AzListView, context_menu: AzMenu) -> AzListView { listview.with_column_context_menu(context_menu) }
+/// Equivalent to the Rust `ListView::set_row_height()` function.
+#[no_mangle] pub extern "C" fn AzListView_setRowHeight(listview: &mut AzListView, row_height: f32) { listview.set_row_height(row_height) }
+/// Equivalent to the Rust `ListView::with_row_height()` function.
+#[no_mangle] pub extern "C" fn AzListView_withRowHeight(listview: &mut AzListView, row_height: f32) -> AzListView { listview.with_row_height(row_height) }
+/// Equivalent to the Rust `ListView::set_first_visible_row()` function.
+#[no_mangle] pub extern "C" fn AzListView_setFirstVisibleRow(listview: &mut AzListView, first_visible_row: usize) { listview.set_first_visible_row(first_visible_row) }
+/// Equivalent to the Rust `ListView::with_first_visible_row()` function.
+#[no_mangle] pub extern "C" fn AzListView_withFirstVisibleRow(listview: &mut AzListView, first_visible_row: usize) -> AzListView { listview.with_first_visible_row(first_visible_row) }
+/// Equivalent to the Rust `ListView::set_column_widths()` function.
+#[no_mangle] pub extern "C" fn AzListView_setColumnWidths(listview: &mut AzListView, column_widths: AzF32Vec) { listview.set_column_widths(column_widths) }
+/// Equivalent to the Rust `ListView::with_column_widths()` function.
+#[no_mangle] pub extern "C" fn AzListView_withColumnWidths(listview: &mut AzListView, column_widths: AzF32Vec) -> AzListView { listview.with_column_widths(column_widths) }
+/// Equivalent to the Rust `ListView::set_selection_state()` function.
+#[no_mangle] pub extern "C" fn AzListView_setSelectionState(listview: &mut AzListView, focused_row: AzOptionUsize, selection_anchor: AzOptionUsize) { listview.set_selection_state(focused_row, selection_anchor) }
+/// Equivalent to the Rust `ListView::with_selection_state()` function.
+#[no_mangle] pub extern "C" fn AzListView_withSelectionState(listview: &mut AzListView, focused_row: AzOptionUsize, selection_anchor: AzOptionUsize) -> AzListView { listview.with_selection_state(focused_row, selection_anchor) }
+/// Equivalent to the Rust `ListView::set_row_provider()` function.
+#[no_mangle] pub extern "C" fn AzListView_setRowProvider(listview: &mut AzListView, row_count: usize, data: AzRefAny, callback: AzListViewRowProviderCallbackType) { listview.set_row_provider(row_count, data, callback) }
+/// Equivalent to the Rust `ListView::with_row_provider()` function.
+#[no_mangle] pub extern "C" fn AzListView_withRowProvider(listview: &mut AzListView, row_count: usize, data: AzRefAny, callback: AzListViewRowProviderCallbackType) -> AzListView { listview.with_row_provider(row_count, data, callback) }
+/// Equivalent to the Rust `ListView::set_on_lazy_load_scroll()` function.
+#[no_mangle] pub extern "C" fn AzListView_setOnLazyLoadScroll(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnLazyLoadScrollCallbackType) { listview.set_on_lazy_load_scroll(data, callback) }
+/// Equivalent to the Rust `ListView::with_on_lazy_load_scroll()` function.
+#[no_mangle] pub extern "C" fn AzListView_withOnLazyLoadScroll(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnLazyLoadScrollCallbackType) -> AzListView { listview.with_on_lazy_load_scroll(data, callback) }
+/// Equivalent to the Rust `ListView::set_on_column_click()` function.
+#[no_mangle] pub extern "C" fn AzListView_setOnColumnClick(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnColumnClickCallbackType) { listview.set_on_column_click(data, callback) }
+/// Equivalent to the Rust `ListView::with_on_column_click()` function.
+#[no_mangle] pub extern "C" fn AzListView_withOnColumnClick(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnColumnClickCallbackType) -> AzListView { listview.with_on_column_click(data, callback) }
+/// Equivalent to the Rust `ListView::set_on_row_click()` function.
+#[no_mangle] pub extern "C" fn AzListView_setOnRowClick(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnRowClickCallbackType) { listview.set_on_row_click(data, callback) }
+/// Equivalent to the Rust `ListView::with_on_row_click()` function.
+#[no_mangle] pub extern "C" fn AzListView_withOnRowClick(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnRowClickCallbackType) -> AzListView { listview.with_on_row_click(data, callback) }
+/// Equivalent to the Rust `ListView::set_on_row_select()` function.
+#[no_mangle] pub extern "C" fn AzListView_setOnRowSelect(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnRowSelectCallbackType) { listview.set_on_row_select(data, callback) }
+/// Equivalent to the Rust `ListView::with_on_row_select()` function.
+#[no_mangle] pub extern "C" fn AzListView_withOnRowSelect(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnRowSelectCallbackType) -> AzListView { listview.with_on_row_select(data, callback) }
+/// Equivalent to the Rust `ListView::set_on_column_resize()` function.
+#[no_mangle] pub extern "C" fn AzListView_setOnColumnResize(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnColumnResizeCallbackType) { listview.set_on_column_resize(data, callback) }
+/// Equivalent to the Rust `ListView::with_on_column_resize()` function.
+#[no_mangle] pub extern "C" fn AzListView_withOnColumnResize(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnColumnResizeCallbackType) -> AzListView { listview.with_on_column_resize(data, callback) }
+/// Equivalent to the Rust `ListView::get_total_row_count()` function.
+#[no_mangle] pub extern "C" fn AzListView_getTotalRowCount(listview: &mut AzListView) -> usize { listview.get_total_row_count() }
+/// Updates the `is_selected` flags of the rows the same way the ListView does it internally
+#[no_mangle] pub extern "C" fn AzListView_applySelection(listview: &mut AzListView, row_id: usize, change: AzListViewSelectionChange) { listview.apply_selection(row_id, change) }
 /// Equivalent to the Rust `ListView::dom()` function.
 #[no_mangle] pub extern "C" fn AzListView_dom(listview: &mut AzListView) -> AzDom { listview.swap_with_default().dom() }
 /// Destructor: Takes ownership of the `ListView` pointer and deletes it.
@@ -2518,6 +2772,10 @@ pub use AzListViewStateTT as AzListViewState;
 /// Destructor: Takes ownership of the `ListViewState` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzListViewState_delete(object: &mut AzListViewState) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// How the selection should change in response to a user action
+pub use crate::widgets::list_view::ListViewSelectionChange as AzListViewSelectionChangeTT;
+pub use AzListViewSelectionChangeTT as AzListViewSelectionChange;
+
 pub type AzListViewOnLazyLoadScrollCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzListViewState) -> AzUpdate;
 /// Re-export of rust-allocated (stack based) `ListViewOnLazyLoadScrollCallback` struct
 pub use crate::widgets::list_view::ListViewOnLazyLoadScrollCallback as AzListViewOnLazyLoadScrollCallbackTT;
@@ -2551,17 +2809,638 @@ pub use AzListViewOnRowClickTT as AzListViewOnRowClick;
 /// Destructor: Takes ownership of the `ListViewOnRowClick` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzListViewOnRowClick_delete(object: &mut AzListViewOnRowClick) {  unsafe { core::ptr::drop_in_place(object); } }
 
-/// Re-export of rust-allocated (stack based) `TreeView` struct
+pub type AzListViewOnRowSelectCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzListViewState, usize, AzListViewSelectionChange) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `ListViewOnRowSelectCallback` struct
+pub use crate::widgets::list_view::ListViewOnRowSelectCallback as AzListViewOnRowSelectCallbackTT;
+pub use AzListViewOnRowSelectCallbackTT as AzListViewOnRowSelectCallback;
+
+/// Re-export of rust-allocated (stack based) `ListViewOnRowSelect` struct
+pub use crate::widgets::list_view::ListViewOnRowSelect as AzListViewOnRowSelectTT;
+pub use AzListViewOnRowSelectTT as AzListViewOnRowSelect;
+/// Destructor: Takes ownership of the `ListViewOnRowSelect` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzListViewOnRowSelect_delete(object: &mut AzListViewOnRowSelect) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzListViewOnColumnResizeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzListViewState, usize, f32) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `ListViewOnColumnResizeCallback` struct
+pub use crate::widgets::list_view::ListViewOnColumnResizeCallback as AzListViewOnColumnResizeCallbackTT;
+pub use AzListViewOnColumnResizeCallbackTT as AzListViewOnColumnResizeCallback;
+
+/// Re-export of rust-allocated (stack based) `ListViewOnColumnResize` struct
+pub use crate::widgets::list_view::ListViewOnColumnResize as AzListViewOnColumnResizeTT;
+pub use AzListViewOnColumnResizeTT as AzListViewOnColumnResize;
+/// Destructor: Takes ownership of the `ListViewOnColumnResize` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzListViewOnColumnResize_delete(object: &mut AzListViewOnColumnResize) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzListViewRowProviderCallbackType = extern "C" fn(&mut AzRefAny, usize, usize) -> AzListViewRowVec;
+/// Re-export of rust-allocated (stack based) `ListViewRowProviderCallback` struct
+pub use crate::widgets::list_view::ListViewRowProviderCallback as AzListViewRowProviderCallbackTT;
+pub use AzListViewRowProviderCallbackTT as AzListViewRowProviderCallback;
+
+/// Re-export of rust-allocated (stack based) `ListViewRowProvider` struct
+pub use crate::widgets::list_view::ListViewRowProvider as AzListViewRowProviderTT;
+pub use AzListViewRowProviderTT as AzListViewRowProvider;
+/// Destructor: Takes ownership of the `ListViewRowProvider` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzListViewRowProvider_delete(object: &mut AzListViewRowProvider) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Hierarchical tree of items with expand / collapse and selection. Only the rows between `first_visible_row` and `first_visible_row + max_visible_rows` are rendered. The callbacks only report what the user did, the application applies the change to its own copy of the TreeView (`set_expanded`, `apply_selection`, `set_children`, `set_first_visible_row`)
 pub use crate::widgets::tree_view::TreeView as AzTreeViewTT;
 pub use AzTreeViewTT as AzTreeView;
 /// Creates a new `TreeView` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `TreeView::new()` constructor.
-#[no_mangle] pub extern "C" fn AzTreeView_new(root: AzString) -> AzTreeView { AzTreeView::new(root) }
+#[no_mangle] pub extern "C" fn AzTreeView_new(items: AzTreeViewItemVec) -> AzTreeView { AzTreeView::new(items) }
+/// Equivalent to the Rust `TreeView::set_items()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setItems(treeview: &mut AzTreeView, items: AzTreeViewItemVec) { treeview.set_items(items) }
+/// Equivalent to the Rust `TreeView::with_items()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_withItems(treeview: &mut AzTreeView, items: AzTreeViewItemVec) -> AzTreeView { treeview.with_items(items) }
+/// Equivalent to the Rust `TreeView::set_selection_mode()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setSelectionMode(treeview: &mut AzTreeView, selection_mode: AzTreeViewSelectionMode) { treeview.set_selection_mode(selection_mode) }
+/// Equivalent to the Rust `TreeView::with_selection_mode()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_withSelectionMode(treeview: &mut AzTreeView, selection_mode: AzTreeViewSelectionMode) -> AzTreeView { treeview.with_selection_mode(selection_mode) }
+/// Equivalent to the Rust `TreeView::set_first_visible_row()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setFirstVisibleRow(treeview: &mut AzTreeView, first_visible_row: usize) { treeview.set_first_visible_row(first_visible_row) }
+/// Equivalent to the Rust `TreeView::set_max_visible_rows()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setMaxVisibleRows(treeview: &mut AzTreeView, max_visible_rows: usize) { treeview.set_max_visible_rows(max_visible_rows) }
+/// Equivalent to the Rust `TreeView::set_on_expand()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setOnExpand(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnExpandCallbackType) { treeview.set_on_expand(data, callback) }
+/// Equivalent to the Rust `TreeView::with_on_expand()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_withOnExpand(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnExpandCallbackType) -> AzTreeView { treeview.with_on_expand(data, callback) }
+/// Equivalent to the Rust `TreeView::set_on_select()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setOnSelect(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnSelectCallbackType) { treeview.set_on_select(data, callback) }
+/// Equivalent to the Rust `TreeView::with_on_select()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_withOnSelect(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnSelectCallbackType) -> AzTreeView { treeview.with_on_select(data, callback) }
+/// Equivalent to the Rust `TreeView::set_on_lazy_load()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setOnLazyLoad(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnLazyLoadCallbackType) { treeview.set_on_lazy_load(data, callback) }
+/// Equivalent to the Rust `TreeView::with_on_lazy_load()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_withOnLazyLoad(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnLazyLoadCallbackType) -> AzTreeView { treeview.with_on_lazy_load(data, callback) }
+/// Equivalent to the Rust `TreeView::set_on_scroll()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setOnScroll(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnScrollCallbackType) { treeview.set_on_scroll(data, callback) }
+/// Equivalent to the Rust `TreeView::with_on_scroll()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_withOnScroll(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnScrollCallbackType) -> AzTreeView { treeview.with_on_scroll(data, callback) }
+/// Returns the number of rows that are visible when all collapsed items are skipped
+#[no_mangle] pub extern "C" fn AzTreeView_getVisibleRowCount(treeview: &AzTreeView) -> usize { treeview.get_visible_row_count() }
+/// Expands or collapses the item with the given ID, returns false if the item could not be found
+#[no_mangle] pub extern "C" fn AzTreeView_setExpanded(treeview: &mut AzTreeView, item_id: usize, expanded: bool) -> bool { treeview.set_expanded(item_id, expanded) }
+/// Replaces the children of the item with the given ID and clears the `has_lazy_children` flag
+#[no_mangle] pub extern "C" fn AzTreeView_setChildren(treeview: &mut AzTreeView, item_id: usize, children: AzTreeViewItemVec) -> bool { treeview.set_children(item_id, children) }
+/// Updates the `is_selected` flags of the items the same way the TreeView does it internally
+#[no_mangle] pub extern "C" fn AzTreeView_applySelection(treeview: &mut AzTreeView, item_id: usize, change: AzTreeViewSelectionChange) { treeview.apply_selection(item_id, change) }
 /// Equivalent to the Rust `TreeView::dom()` function.
 #[no_mangle] pub extern "C" fn AzTreeView_dom(treeview: &mut AzTreeView) -> AzDom { treeview.swap_with_default().dom() }
 /// Destructor: Takes ownership of the `TreeView` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzTreeView_delete(object: &mut AzTreeView) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `TreeViewItem` struct
+pub use crate::widgets::tree_view::TreeViewItem as AzTreeViewItemTT;
+pub use AzTreeViewItemTT as AzTreeViewItem;
+/// Creates a new `TreeViewItem` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `TreeViewItem::new()` constructor.
+#[no_mangle] pub extern "C" fn AzTreeViewItem_new(id: usize, label: AzString) -> AzTreeViewItem { AzTreeViewItem::new(id, label) }
+/// Destructor: Takes ownership of the `TreeViewItem` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewItem_delete(object: &mut AzTreeViewItem) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TreeViewSelectionMode` struct
+pub use crate::widgets::tree_view::TreeViewSelectionMode as AzTreeViewSelectionModeTT;
+pub use AzTreeViewSelectionModeTT as AzTreeViewSelectionMode;
+
+/// Re-export of rust-allocated (stack based) `TreeViewSelectionChange` struct
+pub use crate::widgets::tree_view::TreeViewSelectionChange as AzTreeViewSelectionChangeTT;
+pub use AzTreeViewSelectionChangeTT as AzTreeViewSelectionChange;
+
+pub type AzTreeViewOnExpandCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, bool) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `TreeViewOnExpandCallback` struct
+pub use crate::widgets::tree_view::TreeViewOnExpandCallback as AzTreeViewOnExpandCallbackTT;
+pub use AzTreeViewOnExpandCallbackTT as AzTreeViewOnExpandCallback;
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnExpand` struct
+pub use crate::widgets::tree_view::TreeViewOnExpand as AzTreeViewOnExpandTT;
+pub use AzTreeViewOnExpandTT as AzTreeViewOnExpand;
+/// Destructor: Takes ownership of the `TreeViewOnExpand` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewOnExpand_delete(object: &mut AzTreeViewOnExpand) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzTreeViewOnSelectCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, AzTreeViewSelectionChange) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `TreeViewOnSelectCallback` struct
+pub use crate::widgets::tree_view::TreeViewOnSelectCallback as AzTreeViewOnSelectCallbackTT;
+pub use AzTreeViewOnSelectCallbackTT as AzTreeViewOnSelectCallback;
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnSelect` struct
+pub use crate::widgets::tree_view::TreeViewOnSelect as AzTreeViewOnSelectTT;
+pub use AzTreeViewOnSelectTT as AzTreeViewOnSelect;
+/// Destructor: Takes ownership of the `TreeViewOnSelect` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewOnSelect_delete(object: &mut AzTreeViewOnSelect) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzTreeViewOnLazyLoadCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `TreeViewOnLazyLoadCallback` struct
+pub use crate::widgets::tree_view::TreeViewOnLazyLoadCallback as AzTreeViewOnLazyLoadCallbackTT;
+pub use AzTreeViewOnLazyLoadCallbackTT as AzTreeViewOnLazyLoadCallback;
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnLazyLoad` struct
+pub use crate::widgets::tree_view::TreeViewOnLazyLoad as AzTreeViewOnLazyLoadTT;
+pub use AzTreeViewOnLazyLoadTT as AzTreeViewOnLazyLoad;
+/// Destructor: Takes ownership of the `TreeViewOnLazyLoad` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewOnLazyLoad_delete(object: &mut AzTreeViewOnLazyLoad) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzTreeViewOnScrollCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `TreeViewOnScrollCallback` struct
+pub use crate::widgets::tree_view::TreeViewOnScrollCallback as AzTreeViewOnScrollCallbackTT;
+pub use AzTreeViewOnScrollCallbackTT as AzTreeViewOnScrollCallback;
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnScroll` struct
+pub use crate::widgets::tree_view::TreeViewOnScroll as AzTreeViewOnScrollTT;
+pub use AzTreeViewOnScrollTT as AzTreeViewOnScroll;
+/// Destructor: Takes ownership of the `TreeViewOnScroll` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewOnScroll_delete(object: &mut AzTreeViewOnScroll) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Spreadsheet-like grid of text cells. The DataGrid does not store any cell data: the text of each visible cell is requested from the `cell_provider`, only the cells that fit into the visible area are rendered. Scrolling, selection, resizing and editing are reported through the callbacks, the application stores the new state for the next frame
+pub use crate::widgets::spreadsheet::DataGrid as AzDataGridTT;
+pub use AzDataGridTT as AzDataGrid;
+/// Creates a new `DataGrid` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `DataGrid::new()` constructor.
+#[no_mangle] pub extern "C" fn AzDataGrid_new(row_count: usize, column_count: usize) -> AzDataGrid { AzDataGrid::new(row_count, column_count) }
+/// Equivalent to the Rust `DataGrid::set_column_headers()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_setColumnHeaders(datagrid: &mut AzDataGrid, column_headers: AzStringVec) { datagrid.set_column_headers(column_headers) }
+/// Equivalent to the Rust `DataGrid::with_column_headers()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_withColumnHeaders(datagrid: &mut AzDataGrid, column_headers: AzStringVec) -> AzDataGrid { datagrid.with_column_headers(column_headers) }
+/// Equivalent to the Rust `DataGrid::set_column_widths()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_setColumnWidths(datagrid: &mut AzDataGrid, column_widths: AzF32Vec) { datagrid.set_column_widths(column_widths) }
+/// Equivalent to the Rust `DataGrid::with_column_widths()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_withColumnWidths(datagrid: &mut AzDataGrid, column_widths: AzF32Vec) -> AzDataGrid { datagrid.with_column_widths(column_widths) }
+/// Equivalent to the Rust `DataGrid::set_row_heights()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_setRowHeights(datagrid: &mut AzDataGrid, row_heights: AzF32Vec) { datagrid.set_row_heights(row_heights) }
+/// Equivalent to the Rust `DataGrid::with_row_heights()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_withRowHeights(datagrid: &mut AzDataGrid, row_heights: AzF32Vec) -> AzDataGrid { datagrid.with_row_heights(row_heights) }
+/// Equivalent to the Rust `DataGrid::set_scroll_position()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_setScrollPosition(datagrid: &mut AzDataGrid, first_visible_row: usize, first_visible_column: usize) { datagrid.set_scroll_position(first_visible_row, first_visible_column) }
+/// Equivalent to the Rust `DataGrid::with_scroll_position()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_withScrollPosition(datagrid: &mut AzDataGrid, first_visible_row: usize, first_visible_column: usize) -> AzDataGrid { datagrid.with_scroll_position(first_visible_row, first_visible_column) }
+/// Equivalent to the Rust `DataGrid::set_selection()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_setSelection(datagrid: &mut AzDataGrid, selection: AzOptionDataGridRange) { datagrid.set_selection(selection) }
+/// Equivalent to the Rust `DataGrid::with_selection()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_withSelection(datagrid: &mut AzDataGrid, selection: AzOptionDataGridRange) -> AzDataGrid { datagrid.with_selection(selection) }
+/// Equivalent to the Rust `DataGrid::set_cell_provider()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_setCellProvider(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridCellProviderCallbackType) { datagrid.set_cell_provider(data, callback) }
+/// Equivalent to the Rust `DataGrid::with_cell_provider()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_withCellProvider(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridCellProviderCallbackType) -> AzDataGrid { datagrid.with_cell_provider(data, callback) }
+/// Equivalent to the Rust `DataGrid::set_cell_style()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_setCellStyle(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridCellStyleCallbackType) { datagrid.set_cell_style(data, callback) }
+/// Equivalent to the Rust `DataGrid::with_cell_style()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_withCellStyle(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridCellStyleCallbackType) -> AzDataGrid { datagrid.with_cell_style(data, callback) }
+/// Equivalent to the Rust `DataGrid::set_on_select()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_setOnSelect(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnSelectCallbackType) { datagrid.set_on_select(data, callback) }
+/// Equivalent to the Rust `DataGrid::with_on_select()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_withOnSelect(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnSelectCallbackType) -> AzDataGrid { datagrid.with_on_select(data, callback) }
+/// Equivalent to the Rust `DataGrid::set_on_scroll()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_setOnScroll(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnScrollCallbackType) { datagrid.set_on_scroll(data, callback) }
+/// Equivalent to the Rust `DataGrid::with_on_scroll()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_withOnScroll(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnScrollCallbackType) -> AzDataGrid { datagrid.with_on_scroll(data, callback) }
+/// Equivalent to the Rust `DataGrid::set_on_cell_edit()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_setOnCellEdit(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnCellEditCallbackType) { datagrid.set_on_cell_edit(data, callback) }
+/// Equivalent to the Rust `DataGrid::with_on_cell_edit()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_withOnCellEdit(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnCellEditCallbackType) -> AzDataGrid { datagrid.with_on_cell_edit(data, callback) }
+/// Equivalent to the Rust `DataGrid::set_on_column_resize()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_setOnColumnResize(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnColumnResizeCallbackType) { datagrid.set_on_column_resize(data, callback) }
+/// Equivalent to the Rust `DataGrid::with_on_column_resize()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_withOnColumnResize(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnColumnResizeCallbackType) -> AzDataGrid { datagrid.with_on_column_resize(data, callback) }
+/// Equivalent to the Rust `DataGrid::set_on_row_resize()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_setOnRowResize(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnRowResizeCallbackType) { datagrid.set_on_row_resize(data, callback) }
+/// Equivalent to the Rust `DataGrid::with_on_row_resize()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_withOnRowResize(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnRowResizeCallbackType) -> AzDataGrid { datagrid.with_on_row_resize(data, callback) }
+/// Equivalent to the Rust `DataGrid::set_on_copy_range()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_setOnCopyRange(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnCopyRangeCallbackType) { datagrid.set_on_copy_range(data, callback) }
+/// Equivalent to the Rust `DataGrid::with_on_copy_range()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_withOnCopyRange(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnCopyRangeCallbackType) -> AzDataGrid { datagrid.with_on_copy_range(data, callback) }
+/// Equivalent to the Rust `DataGrid::set_on_clear_range()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_setOnClearRange(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnClearRangeCallbackType) { datagrid.set_on_clear_range(data, callback) }
+/// Equivalent to the Rust `DataGrid::with_on_clear_range()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_withOnClearRange(datagrid: &mut AzDataGrid, data: AzRefAny, callback: AzDataGridOnClearRangeCallbackType) -> AzDataGrid { datagrid.with_on_clear_range(data, callback) }
+/// Equivalent to the Rust `DataGrid::get_column_width()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_getColumnWidth(datagrid: &AzDataGrid, column: usize) -> f32 { datagrid.get_column_width(column) }
+/// Equivalent to the Rust `DataGrid::get_row_height()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_getRowHeight(datagrid: &AzDataGrid, row: usize) -> f32 { datagrid.get_row_height(row) }
+/// Equivalent to the Rust `DataGrid::get_column_name()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_getColumnName(datagrid: &AzDataGrid, column: usize) -> AzString { datagrid.get_column_name(column) }
+/// Equivalent to the Rust `DataGrid::get_cell_text()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_getCellText(datagrid: &mut AzDataGrid, row: usize, column: usize) -> AzString { datagrid.get_cell_text(row, column) }
+/// Equivalent to the Rust `DataGrid::get_selection_tsv()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_getSelectionTsv(datagrid: &mut AzDataGrid) -> AzString { datagrid.get_selection_tsv() }
+/// Returns the selection, clipped to the size of the grid (`None` if nothing is selected or the selection is out of bounds)
+#[no_mangle] pub extern "C" fn AzDataGrid_getClippedSelection(datagrid: &AzDataGrid) -> AzOptionDataGridRange { datagrid.get_clipped_selection().into() }
+/// Equivalent to the Rust `DataGrid::dom()` function.
+#[no_mangle] pub extern "C" fn AzDataGrid_dom(datagrid: &mut AzDataGrid) -> AzDom { datagrid.swap_with_default().dom() }
+/// Destructor: Takes ownership of the `DataGrid` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzDataGrid_delete(object: &mut AzDataGrid) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Position of a cell in the DataGrid
+pub use crate::widgets::spreadsheet::DataGridCell as AzDataGridCellTT;
+pub use AzDataGridCellTT as AzDataGridCell;
+
+/// Rectangular range of selected cells
+pub use crate::widgets::spreadsheet::DataGridRange as AzDataGridRangeTT;
+pub use AzDataGridRangeTT as AzDataGridRange;
+/// Selection of a single cell
+#[no_mangle] pub extern "C" fn AzDataGridRange_new(row: usize, column: usize) -> AzDataGridRange { AzDataGridRange::new(row, column) }
+/// Equivalent to the Rust `DataGridRange::get_top_left()` function.
+#[no_mangle] pub extern "C" fn AzDataGridRange_getTopLeft(datagridrange: &AzDataGridRange) -> AzDataGridCell { datagridrange.get_top_left() }
+/// Equivalent to the Rust `DataGridRange::get_bottom_right()` function.
+#[no_mangle] pub extern "C" fn AzDataGridRange_getBottomRight(datagridrange: &AzDataGridRange) -> AzDataGridCell { datagridrange.get_bottom_right() }
+/// Equivalent to the Rust `DataGridRange::contains()` function.
+#[no_mangle] pub extern "C" fn AzDataGridRange_contains(datagridrange: &AzDataGridRange, row: usize, column: usize) -> bool { datagridrange.contains(row, column) }
+/// Number of cells in the range (saturates at `usize::MAX`)
+#[no_mangle] pub extern "C" fn AzDataGridRange_getCellCount(datagridrange: &AzDataGridRange) -> usize { datagridrange.get_cell_count() }
+
+pub type AzDataGridCellProviderCallbackType = extern "C" fn(&mut AzRefAny, usize, usize) -> AzString;
+/// Re-export of rust-allocated (stack based) `DataGridCellProviderCallback` struct
+pub use crate::widgets::spreadsheet::DataGridCellProviderCallback as AzDataGridCellProviderCallbackTT;
+pub use AzDataGridCellProviderCallbackTT as AzDataGridCellProviderCallback;
+
+/// Re-export of rust-allocated (stack based) `DataGridCellProvider` struct
+pub use crate::widgets::spreadsheet::DataGridCellProvider as AzDataGridCellProviderTT;
+pub use AzDataGridCellProviderTT as AzDataGridCellProvider;
+/// Destructor: Takes ownership of the `DataGridCellProvider` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzDataGridCellProvider_delete(object: &mut AzDataGridCellProvider) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzDataGridCellStyleCallbackType = extern "C" fn(&mut AzRefAny, usize, usize, bool) -> AzNodeDataInlineCssPropertyVec;
+/// Re-export of rust-allocated (stack based) `DataGridCellStyleCallback` struct
+pub use crate::widgets::spreadsheet::DataGridCellStyleCallback as AzDataGridCellStyleCallbackTT;
+pub use AzDataGridCellStyleCallbackTT as AzDataGridCellStyleCallback;
+
+/// Re-export of rust-allocated (stack based) `DataGridCellStyle` struct
+pub use crate::widgets::spreadsheet::DataGridCellStyle as AzDataGridCellStyleTT;
+pub use AzDataGridCellStyleTT as AzDataGridCellStyle;
+/// Destructor: Takes ownership of the `DataGridCellStyle` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzDataGridCellStyle_delete(object: &mut AzDataGridCellStyle) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzDataGridOnSelectCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzDataGridRange) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `DataGridOnSelectCallback` struct
+pub use crate::widgets::spreadsheet::DataGridOnSelectCallback as AzDataGridOnSelectCallbackTT;
+pub use AzDataGridOnSelectCallbackTT as AzDataGridOnSelectCallback;
+
+/// Re-export of rust-allocated (stack based) `DataGridOnSelect` struct
+pub use crate::widgets::spreadsheet::DataGridOnSelect as AzDataGridOnSelectTT;
+pub use AzDataGridOnSelectTT as AzDataGridOnSelect;
+/// Destructor: Takes ownership of the `DataGridOnSelect` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzDataGridOnSelect_delete(object: &mut AzDataGridOnSelect) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzDataGridOnScrollCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, usize) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `DataGridOnScrollCallback` struct
+pub use crate::widgets::spreadsheet::DataGridOnScrollCallback as AzDataGridOnScrollCallbackTT;
+pub use AzDataGridOnScrollCallbackTT as AzDataGridOnScrollCallback;
+
+/// Re-export of rust-allocated (stack based) `DataGridOnScroll` struct
+pub use crate::widgets::spreadsheet::DataGridOnScroll as AzDataGridOnScrollTT;
+pub use AzDataGridOnScrollTT as AzDataGridOnScroll;
+/// Destructor: Takes ownership of the `DataGridOnScroll` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzDataGridOnScroll_delete(object: &mut AzDataGridOnScroll) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzDataGridOnCellEditCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, usize, AzString) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `DataGridOnCellEditCallback` struct
+pub use crate::widgets::spreadsheet::DataGridOnCellEditCallback as AzDataGridOnCellEditCallbackTT;
+pub use AzDataGridOnCellEditCallbackTT as AzDataGridOnCellEditCallback;
+
+/// Re-export of rust-allocated (stack based) `DataGridOnCellEdit` struct
+pub use crate::widgets::spreadsheet::DataGridOnCellEdit as AzDataGridOnCellEditTT;
+pub use AzDataGridOnCellEditTT as AzDataGridOnCellEdit;
+/// Destructor: Takes ownership of the `DataGridOnCellEdit` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzDataGridOnCellEdit_delete(object: &mut AzDataGridOnCellEdit) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzDataGridOnColumnResizeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, f32) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `DataGridOnColumnResizeCallback` struct
+pub use crate::widgets::spreadsheet::DataGridOnColumnResizeCallback as AzDataGridOnColumnResizeCallbackTT;
+pub use AzDataGridOnColumnResizeCallbackTT as AzDataGridOnColumnResizeCallback;
+
+/// Re-export of rust-allocated (stack based) `DataGridOnColumnResize` struct
+pub use crate::widgets::spreadsheet::DataGridOnColumnResize as AzDataGridOnColumnResizeTT;
+pub use AzDataGridOnColumnResizeTT as AzDataGridOnColumnResize;
+/// Destructor: Takes ownership of the `DataGridOnColumnResize` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzDataGridOnColumnResize_delete(object: &mut AzDataGridOnColumnResize) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzDataGridOnRowResizeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, f32) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `DataGridOnRowResizeCallback` struct
+pub use crate::widgets::spreadsheet::DataGridOnRowResizeCallback as AzDataGridOnRowResizeCallbackTT;
+pub use AzDataGridOnRowResizeCallbackTT as AzDataGridOnRowResizeCallback;
+
+/// Re-export of rust-allocated (stack based) `DataGridOnRowResize` struct
+pub use crate::widgets::spreadsheet::DataGridOnRowResize as AzDataGridOnRowResizeTT;
+pub use AzDataGridOnRowResizeTT as AzDataGridOnRowResize;
+/// Destructor: Takes ownership of the `DataGridOnRowResize` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzDataGridOnRowResize_delete(object: &mut AzDataGridOnRowResize) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzDataGridOnCopyRangeCallbackType = extern "C" fn(&mut AzRefAny, AzDataGridRange) -> AzString;
+/// Re-export of rust-allocated (stack based) `DataGridOnCopyRangeCallback` struct
+pub use crate::widgets::spreadsheet::DataGridOnCopyRangeCallback as AzDataGridOnCopyRangeCallbackTT;
+pub use AzDataGridOnCopyRangeCallbackTT as AzDataGridOnCopyRangeCallback;
+
+/// Re-export of rust-allocated (stack based) `DataGridOnCopyRange` struct
+pub use crate::widgets::spreadsheet::DataGridOnCopyRange as AzDataGridOnCopyRangeTT;
+pub use AzDataGridOnCopyRangeTT as AzDataGridOnCopyRange;
+/// Destructor: Takes ownership of the `DataGridOnCopyRange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzDataGridOnCopyRange_delete(object: &mut AzDataGridOnCopyRange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzDataGridOnClearRangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzDataGridRange) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `DataGridOnClearRangeCallback` struct
+pub use crate::widgets::spreadsheet::DataGridOnClearRangeCallback as AzDataGridOnClearRangeCallbackTT;
+pub use AzDataGridOnClearRangeCallbackTT as AzDataGridOnClearRangeCallback;
+
+/// Re-export of rust-allocated (stack based) `DataGridOnClearRange` struct
+pub use crate::widgets::spreadsheet::DataGridOnClearRange as AzDataGridOnClearRangeTT;
+pub use AzDataGridOnClearRangeTT as AzDataGridOnClearRange;
+/// Destructor: Takes ownership of the `DataGridOnClearRange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzDataGridOnClearRange_delete(object: &mut AzDataGridOnClearRange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Calendar date (proleptic Gregorian calendar), not validated on construction
+pub use crate::widgets::date_picker::Date as AzDateTT;
+pub use AzDateTT as AzDate;
+/// Creates a new `Date` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `Date::new()` constructor.
+#[no_mangle] pub extern "C" fn AzDate_new(year: i32, month: u8, day: u8) -> AzDate { AzDate::new(year, month, day) }
+/// Returns the current date (UTC)
+#[no_mangle] pub extern "C" fn AzDate_today() -> AzDate { AzDate::today() }
+/// Equivalent to the Rust `Date::is_valid()` function.
+#[no_mangle] pub extern "C" fn AzDate_isValid(date: &AzDate) -> bool { date.is_valid() }
+/// Equivalent to the Rust `Date::weekday()` function.
+#[no_mangle] pub extern "C" fn AzDate_weekday(date: &AzDate) -> AzWeekday { date.weekday() }
+/// Equivalent to the Rust `Date::add_days()` function.
+#[no_mangle] pub extern "C" fn AzDate_addDays(date: &AzDate, days: i64) -> AzDate { date.add_days(days) }
+/// Adds (or subtracts) months, the day is clamped to the length of the target month
+#[no_mangle] pub extern "C" fn AzDate_addMonths(date: &AzDate, months: i64) -> AzDate { date.add_months(months) }
+/// Clamps the date to the (optional) minimum and maximum date
+#[no_mangle] pub extern "C" fn AzDate_clampTo(date: &AzDate, minimum: AzOptionDate, maximum: AzOptionDate) -> AzDate { date.clamp_to(minimum, maximum) }
+
+/// Day of the week
+pub use crate::widgets::date_picker::Weekday as AzWeekdayTT;
+pub use AzWeekdayTT as AzWeekday;
+
+/// Time of day, not validated on construction
+pub use crate::widgets::date_picker::Time as AzTimeTT;
+pub use AzTimeTT as AzTime;
+/// Creates a new `Time` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `Time::new()` constructor.
+#[no_mangle] pub extern "C" fn AzTime_new(hour: u8, minute: u8, second: u8) -> AzTime { AzTime::new(hour, minute, second) }
+/// Equivalent to the Rust `Time::is_valid()` function.
+#[no_mangle] pub extern "C" fn AzTime_isValid(time: &AzTime) -> bool { time.is_valid() }
+
+/// State of a `DatePicker`, reported by `on_date_change`
+pub use crate::widgets::date_picker::DatePickerState as AzDatePickerStateTT;
+pub use AzDatePickerStateTT as AzDatePickerState;
+
+pub type AzDatePickerOnDateChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzDatePickerState) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `DatePickerOnDateChangeCallback` struct
+pub use crate::widgets::date_picker::DatePickerOnDateChangeCallback as AzDatePickerOnDateChangeCallbackTT;
+pub use AzDatePickerOnDateChangeCallbackTT as AzDatePickerOnDateChangeCallback;
+
+/// Re-export of rust-allocated (stack based) `DatePickerOnDateChange` struct
+pub use crate::widgets::date_picker::DatePickerOnDateChange as AzDatePickerOnDateChangeTT;
+pub use AzDatePickerOnDateChangeTT as AzDatePickerOnDateChange;
+/// Destructor: Takes ownership of the `DatePickerOnDateChange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzDatePickerOnDateChange_delete(object: &mut AzDatePickerOnDateChange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Month calendar to select a single date. Selecting a date and navigating between months is reported through `on_date_change`, the application stores the new `DatePickerState` for the next frame. Arrow keys, PageUp / PageDown and Home / End move the cursor, Enter / Space select the date under the cursor
+pub use crate::widgets::date_picker::DatePicker as AzDatePickerTT;
+pub use AzDatePickerTT as AzDatePicker;
+/// Creates a DatePicker with the `date` selected and the cursor on it
+#[no_mangle] pub extern "C" fn AzDatePicker_new(date: AzDate) -> AzDatePicker { AzDatePicker::new(date) }
+/// Equivalent to the Rust `DatePicker::set_state()` function.
+#[no_mangle] pub extern "C" fn AzDatePicker_setState(datepicker: &mut AzDatePicker, state: AzDatePickerState) { datepicker.set_state(state) }
+/// Equivalent to the Rust `DatePicker::with_state()` function.
+#[no_mangle] pub extern "C" fn AzDatePicker_withState(datepicker: &mut AzDatePicker, state: AzDatePickerState) -> AzDatePicker { datepicker.with_state(state) }
+/// Equivalent to the Rust `DatePicker::set_minimum_date()` function.
+#[no_mangle] pub extern "C" fn AzDatePicker_setMinimumDate(datepicker: &mut AzDatePicker, minimum_date: AzOptionDate) { datepicker.set_minimum_date(minimum_date) }
+/// Equivalent to the Rust `DatePicker::with_minimum_date()` function.
+#[no_mangle] pub extern "C" fn AzDatePicker_withMinimumDate(datepicker: &mut AzDatePicker, minimum_date: AzOptionDate) -> AzDatePicker { datepicker.with_minimum_date(minimum_date) }
+/// Equivalent to the Rust `DatePicker::set_maximum_date()` function.
+#[no_mangle] pub extern "C" fn AzDatePicker_setMaximumDate(datepicker: &mut AzDatePicker, maximum_date: AzOptionDate) { datepicker.set_maximum_date(maximum_date) }
+/// Equivalent to the Rust `DatePicker::with_maximum_date()` function.
+#[no_mangle] pub extern "C" fn AzDatePicker_withMaximumDate(datepicker: &mut AzDatePicker, maximum_date: AzOptionDate) -> AzDatePicker { datepicker.with_maximum_date(maximum_date) }
+/// Equivalent to the Rust `DatePicker::set_first_day_of_week()` function.
+#[no_mangle] pub extern "C" fn AzDatePicker_setFirstDayOfWeek(datepicker: &mut AzDatePicker, first_day_of_week: AzWeekday) { datepicker.set_first_day_of_week(first_day_of_week) }
+/// Equivalent to the Rust `DatePicker::with_first_day_of_week()` function.
+#[no_mangle] pub extern "C" fn AzDatePicker_withFirstDayOfWeek(datepicker: &mut AzDatePicker, first_day_of_week: AzWeekday) -> AzDatePicker { datepicker.with_first_day_of_week(first_day_of_week) }
+/// Equivalent to the Rust `DatePicker::set_on_date_change()` function.
+#[no_mangle] pub extern "C" fn AzDatePicker_setOnDateChange(datepicker: &mut AzDatePicker, data: AzRefAny, callback: AzDatePickerOnDateChangeCallbackType) { datepicker.set_on_date_change(data, callback) }
+/// Equivalent to the Rust `DatePicker::with_on_date_change()` function.
+#[no_mangle] pub extern "C" fn AzDatePicker_withOnDateChange(datepicker: &mut AzDatePicker, data: AzRefAny, callback: AzDatePickerOnDateChangeCallbackType) -> AzDatePicker { datepicker.with_on_date_change(data, callback) }
+/// Equivalent to the Rust `DatePicker::dom()` function.
+#[no_mangle] pub extern "C" fn AzDatePicker_dom(datepicker: &mut AzDatePicker) -> AzDom { datepicker.swap_with_default().dom() }
+/// Destructor: Takes ownership of the `DatePicker` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzDatePicker_delete(object: &mut AzDatePicker) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzTimePickerOnTimeChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTime) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `TimePickerOnTimeChangeCallback` struct
+pub use crate::widgets::date_picker::TimePickerOnTimeChangeCallback as AzTimePickerOnTimeChangeCallbackTT;
+pub use AzTimePickerOnTimeChangeCallbackTT as AzTimePickerOnTimeChangeCallback;
+
+/// Re-export of rust-allocated (stack based) `TimePickerOnTimeChange` struct
+pub use crate::widgets::date_picker::TimePickerOnTimeChange as AzTimePickerOnTimeChangeTT;
+pub use AzTimePickerOnTimeChangeTT as AzTimePickerOnTimeChange;
+/// Destructor: Takes ownership of the `TimePickerOnTimeChange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTimePickerOnTimeChange_delete(object: &mut AzTimePickerOnTimeChange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Hour / minute / second input with spin buttons, changes are reported through `on_time_change`
+pub use crate::widgets::date_picker::TimePicker as AzTimePickerTT;
+pub use AzTimePickerTT as AzTimePicker;
+/// Creates a new `TimePicker` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `TimePicker::new()` constructor.
+#[no_mangle] pub extern "C" fn AzTimePicker_new(time: AzTime) -> AzTimePicker { AzTimePicker::new(time) }
+/// Equivalent to the Rust `TimePicker::set_show_seconds()` function.
+#[no_mangle] pub extern "C" fn AzTimePicker_setShowSeconds(timepicker: &mut AzTimePicker, show_seconds: bool) { timepicker.set_show_seconds(show_seconds) }
+/// Equivalent to the Rust `TimePicker::with_show_seconds()` function.
+#[no_mangle] pub extern "C" fn AzTimePicker_withShowSeconds(timepicker: &mut AzTimePicker, show_seconds: bool) -> AzTimePicker { timepicker.with_show_seconds(show_seconds) }
+/// Equivalent to the Rust `TimePicker::set_on_time_change()` function.
+#[no_mangle] pub extern "C" fn AzTimePicker_setOnTimeChange(timepicker: &mut AzTimePicker, data: AzRefAny, callback: AzTimePickerOnTimeChangeCallbackType) { timepicker.set_on_time_change(data, callback) }
+/// Equivalent to the Rust `TimePicker::with_on_time_change()` function.
+#[no_mangle] pub extern "C" fn AzTimePicker_withOnTimeChange(timepicker: &mut AzTimePicker, data: AzRefAny, callback: AzTimePickerOnTimeChangeCallbackType) -> AzTimePicker { timepicker.with_on_time_change(data, callback) }
+/// Equivalent to the Rust `TimePicker::dom()` function.
+#[no_mangle] pub extern "C" fn AzTimePicker_dom(timepicker: &mut AzTimePicker) -> AzDom { timepicker.swap_with_default().dom() }
+/// Destructor: Takes ownership of the `TimePicker` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTimePicker_delete(object: &mut AzTimePicker) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// State of a `DateTimePicker`, reported by `on_date_time_change`
+pub use crate::widgets::date_picker::DateTimePickerState as AzDateTimePickerStateTT;
+pub use AzDateTimePickerStateTT as AzDateTimePickerState;
+
+pub type AzDateTimePickerOnDateTimeChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzDateTimePickerState) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `DateTimePickerOnDateTimeChangeCallback` struct
+pub use crate::widgets::date_picker::DateTimePickerOnDateTimeChangeCallback as AzDateTimePickerOnDateTimeChangeCallbackTT;
+pub use AzDateTimePickerOnDateTimeChangeCallbackTT as AzDateTimePickerOnDateTimeChangeCallback;
+
+/// Re-export of rust-allocated (stack based) `DateTimePickerOnDateTimeChange` struct
+pub use crate::widgets::date_picker::DateTimePickerOnDateTimeChange as AzDateTimePickerOnDateTimeChangeTT;
+pub use AzDateTimePickerOnDateTimeChangeTT as AzDateTimePickerOnDateTimeChange;
+/// Destructor: Takes ownership of the `DateTimePickerOnDateTimeChange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzDateTimePickerOnDateTimeChange_delete(object: &mut AzDateTimePickerOnDateTimeChange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Drop-down field showing the selected date (and time), which opens a `DatePicker` (and `TimePicker`) below the field when clicked. Opening / closing the popup and changing the date or time is reported through `on_date_time_change`
+pub use crate::widgets::date_picker::DateTimePicker as AzDateTimePickerTT;
+pub use AzDateTimePickerTT as AzDateTimePicker;
+/// Creates a closed DateTimePicker with the `date` selected
+#[no_mangle] pub extern "C" fn AzDateTimePicker_new(date: AzDate) -> AzDateTimePicker { AzDateTimePicker::new(date) }
+/// Equivalent to the Rust `DateTimePicker::set_state()` function.
+#[no_mangle] pub extern "C" fn AzDateTimePicker_setState(datetimepicker: &mut AzDateTimePicker, state: AzDateTimePickerState) { datetimepicker.set_state(state) }
+/// Equivalent to the Rust `DateTimePicker::with_state()` function.
+#[no_mangle] pub extern "C" fn AzDateTimePicker_withState(datetimepicker: &mut AzDateTimePicker, state: AzDateTimePickerState) -> AzDateTimePicker { datetimepicker.with_state(state) }
+/// Equivalent to the Rust `DateTimePicker::set_minimum_date()` function.
+#[no_mangle] pub extern "C" fn AzDateTimePicker_setMinimumDate(datetimepicker: &mut AzDateTimePicker, minimum_date: AzOptionDate) { datetimepicker.set_minimum_date(minimum_date) }
+/// Equivalent to the Rust `DateTimePicker::with_minimum_date()` function.
+#[no_mangle] pub extern "C" fn AzDateTimePicker_withMinimumDate(datetimepicker: &mut AzDateTimePicker, minimum_date: AzOptionDate) -> AzDateTimePicker { datetimepicker.with_minimum_date(minimum_date) }
+/// Equivalent to the Rust `DateTimePicker::set_maximum_date()` function.
+#[no_mangle] pub extern "C" fn AzDateTimePicker_setMaximumDate(datetimepicker: &mut AzDateTimePicker, maximum_date: AzOptionDate) { datetimepicker.set_maximum_date(maximum_date) }
+/// Equivalent to the Rust `DateTimePicker::with_maximum_date()` function.
+#[no_mangle] pub extern "C" fn AzDateTimePicker_withMaximumDate(datetimepicker: &mut AzDateTimePicker, maximum_date: AzOptionDate) -> AzDateTimePicker { datetimepicker.with_maximum_date(maximum_date) }
+/// Equivalent to the Rust `DateTimePicker::set_first_day_of_week()` function.
+#[no_mangle] pub extern "C" fn AzDateTimePicker_setFirstDayOfWeek(datetimepicker: &mut AzDateTimePicker, first_day_of_week: AzWeekday) { datetimepicker.set_first_day_of_week(first_day_of_week) }
+/// Equivalent to the Rust `DateTimePicker::with_first_day_of_week()` function.
+#[no_mangle] pub extern "C" fn AzDateTimePicker_withFirstDayOfWeek(datetimepicker: &mut AzDateTimePicker, first_day_of_week: AzWeekday) -> AzDateTimePicker { datetimepicker.with_first_day_of_week(first_day_of_week) }
+/// Equivalent to the Rust `DateTimePicker::set_show_time()` function.
+#[no_mangle] pub extern "C" fn AzDateTimePicker_setShowTime(datetimepicker: &mut AzDateTimePicker, show_time: bool, show_seconds: bool) { datetimepicker.set_show_time(show_time, show_seconds) }
+/// Equivalent to the Rust `DateTimePicker::with_show_time()` function.
+#[no_mangle] pub extern "C" fn AzDateTimePicker_withShowTime(datetimepicker: &mut AzDateTimePicker, show_time: bool, show_seconds: bool) -> AzDateTimePicker { datetimepicker.with_show_time(show_time, show_seconds) }
+/// Equivalent to the Rust `DateTimePicker::set_on_date_time_change()` function.
+#[no_mangle] pub extern "C" fn AzDateTimePicker_setOnDateTimeChange(datetimepicker: &mut AzDateTimePicker, data: AzRefAny, callback: AzDateTimePickerOnDateTimeChangeCallbackType) { datetimepicker.set_on_date_time_change(data, callback) }
+/// Equivalent to the Rust `DateTimePicker::with_on_date_time_change()` function.
+#[no_mangle] pub extern "C" fn AzDateTimePicker_withOnDateTimeChange(datetimepicker: &mut AzDateTimePicker, data: AzRefAny, callback: AzDateTimePickerOnDateTimeChangeCallbackType) -> AzDateTimePicker { datetimepicker.with_on_date_time_change(data, callback) }
+/// Equivalent to the Rust `DateTimePicker::dom()` function.
+#[no_mangle] pub extern "C" fn AzDateTimePicker_dom(datetimepicker: &mut AzDateTimePicker) -> AzDom { datetimepicker.swap_with_default().dom() }
+/// Destructor: Takes ownership of the `DateTimePicker` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzDateTimePicker_delete(object: &mut AzDateTimePicker) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Icon shown left of the message of a `Dialog`
+pub use crate::widgets::dialog::DialogIcon as AzDialogIconTT;
+pub use AzDialogIconTT as AzDialogIcon;
+
+/// Set of buttons shown at the bottom of a `Dialog`
+pub use crate::widgets::dialog::DialogButtons as AzDialogButtonsTT;
+pub use AzDialogButtonsTT as AzDialogButtons;
+
+/// Button that closed a `Dialog`
+pub use crate::widgets::dialog::DialogButton as AzDialogButtonTT;
+pub use AzDialogButtonTT as AzDialogButton;
+
+/// Result of a `Dialog`, reported by `on_close`
+pub use crate::widgets::dialog::DialogResult as AzDialogResultTT;
+pub use AzDialogResultTT as AzDialogResult;
+/// Returns whether the dialog was closed with "Ok" or "Yes"
+#[no_mangle] pub extern "C" fn AzDialogResult_isAccepted(dialogresult: &AzDialogResult) -> bool { dialogresult.is_accepted() }
+/// Destructor: Takes ownership of the `DialogResult` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzDialogResult_delete(object: &mut AzDialogResult) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzDialogOnCloseCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzDialogResult) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `DialogOnCloseCallback` struct
+pub use crate::widgets::dialog::DialogOnCloseCallback as AzDialogOnCloseCallbackTT;
+pub use AzDialogOnCloseCallbackTT as AzDialogOnCloseCallback;
+
+/// Re-export of rust-allocated (stack based) `DialogOnClose` struct
+pub use crate::widgets::dialog::DialogOnClose as AzDialogOnCloseTT;
+pub use AzDialogOnCloseTT as AzDialogOnClose;
+/// Destructor: Takes ownership of the `DialogOnClose` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzDialogOnClose_delete(object: &mut AzDialogOnClose) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Modal dialog rendered inside of the window. Dialogs are stateless: the application stores the `Dialog` while it is open and removes it again in `on_close`. Use `dom_over()` to render the dialog on top of the rest of the UI: the content below the dialog is made inert, so the keyboard focus can't leave the dialog and clicks don't reach the content anymore
+pub use crate::widgets::dialog::Dialog as AzDialogTT;
+pub use AzDialogTT as AzDialog;
+/// Creates a message box with an "OK" button
+#[no_mangle] pub extern "C" fn AzDialog_msgBox(title: AzString, message: AzString) -> AzDialog { AzDialog::msg_box(title, message) }
+/// Creates a question with "OK" / "Cancel" buttons
+#[no_mangle] pub extern "C" fn AzDialog_confirm(title: AzString, message: AzString) -> AzDialog { AzDialog::confirm(title, message) }
+/// Creates a dialog asking for a line of text, pre-filled with `default_value`
+#[no_mangle] pub extern "C" fn AzDialog_prompt(title: AzString, message: AzString, default_value: AzString) -> AzDialog { AzDialog::prompt(title, message, default_value) }
+/// Creates a dialog displaying a custom DOM with "OK" / "Cancel" buttons
+#[no_mangle] pub extern "C" fn AzDialog_custom(title: AzString, content: AzDom) -> AzDialog { AzDialog::custom(title, content) }
+/// Equivalent to the Rust `Dialog::set_icon()` function.
+#[no_mangle] pub extern "C" fn AzDialog_setIcon(dialog: &mut AzDialog, icon: AzDialogIcon) { dialog.set_icon(icon) }
+/// Equivalent to the Rust `Dialog::with_icon()` function.
+#[no_mangle] pub extern "C" fn AzDialog_withIcon(dialog: &mut AzDialog, icon: AzDialogIcon) -> AzDialog { dialog.with_icon(icon) }
+/// Equivalent to the Rust `Dialog::set_buttons()` function.
+#[no_mangle] pub extern "C" fn AzDialog_setButtons(dialog: &mut AzDialog, buttons: AzDialogButtons) { dialog.set_buttons(buttons) }
+/// Equivalent to the Rust `Dialog::with_buttons()` function.
+#[no_mangle] pub extern "C" fn AzDialog_withButtons(dialog: &mut AzDialog, buttons: AzDialogButtons) -> AzDialog { dialog.with_buttons(buttons) }
+/// Equivalent to the Rust `Dialog::set_on_close()` function.
+#[no_mangle] pub extern "C" fn AzDialog_setOnClose(dialog: &mut AzDialog, data: AzRefAny, callback: AzDialogOnCloseCallbackType) { dialog.set_on_close(data, callback) }
+/// Equivalent to the Rust `Dialog::with_on_close()` function.
+#[no_mangle] pub extern "C" fn AzDialog_withOnClose(dialog: &mut AzDialog, data: AzRefAny, callback: AzDialogOnCloseCallbackType) -> AzDialog { dialog.with_on_close(data, callback) }
+/// Renders the backdrop with the dialog centered in it. The backdrop is positioned absolutely, so it has to be the last child of a container spanning the window - prefer `dom_over()`, which also blocks the input to the rest of the UI
+#[no_mangle] pub extern "C" fn AzDialog_dom(dialog: &mut AzDialog) -> AzDom { dialog.swap_with_default().dom() }
+/// Renders the dialog on top of the `content`, which is made inert (see `Dom::make_inert`) while the dialog is open
+#[no_mangle] pub extern "C" fn AzDialog_domOver(dialog: &mut AzDialog, content: AzDom) -> AzDom { dialog.swap_with_default().dom_over(content) }
+/// Destructor: Takes ownership of the `Dialog` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzDialog_delete(object: &mut AzDialog) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Direction in which the panes of a `Splitter` are laid out
+pub use crate::widgets::splitter::SplitterDirection as AzSplitterDirectionTT;
+pub use AzSplitterDirectionTT as AzSplitterDirection;
+
+/// Size of a single pane, as reported by `on_resize`
+pub use crate::widgets::splitter::SplitterPaneState as AzSplitterPaneStateTT;
+pub use AzSplitterPaneStateTT as AzSplitterPaneState;
+
+/// Sizes of all panes of a `Splitter`
+pub use crate::widgets::splitter::SplitterState as AzSplitterStateTT;
+pub use AzSplitterStateTT as AzSplitterState;
+/// Destructor: Takes ownership of the `SplitterState` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzSplitterState_delete(object: &mut AzSplitterState) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Pane of a `Splitter`
+pub use crate::widgets::splitter::SplitterPane as AzSplitterPaneTT;
+pub use AzSplitterPaneTT as AzSplitterPane;
+/// Creates a flexible pane, which takes up the remaining space
+#[no_mangle] pub extern "C" fn AzSplitterPane_new(content: AzDom) -> AzSplitterPane { AzSplitterPane::new(content) }
+/// Gives the pane a fixed size (in pixels) instead of taking up the remaining space
+#[no_mangle] pub extern "C" fn AzSplitterPane_setSize(splitterpane: &mut AzSplitterPane, size: f32) { splitterpane.set_size(size) }
+/// Gives the pane a fixed size (in pixels) instead of taking up the remaining space
+#[no_mangle] pub extern "C" fn AzSplitterPane_withSize(splitterpane: &mut AzSplitterPane, size: f32) -> AzSplitterPane { splitterpane.with_size(size) }
+/// Equivalent to the Rust `SplitterPane::set_min_size()` function.
+#[no_mangle] pub extern "C" fn AzSplitterPane_setMinSize(splitterpane: &mut AzSplitterPane, min_size: f32) { splitterpane.set_min_size(min_size) }
+/// Equivalent to the Rust `SplitterPane::with_min_size()` function.
+#[no_mangle] pub extern "C" fn AzSplitterPane_withMinSize(splitterpane: &mut AzSplitterPane, min_size: f32) -> AzSplitterPane { splitterpane.with_min_size(min_size) }
+/// Equivalent to the Rust `SplitterPane::set_max_size()` function.
+#[no_mangle] pub extern "C" fn AzSplitterPane_setMaxSize(splitterpane: &mut AzSplitterPane, max_size: f32) { splitterpane.set_max_size(max_size) }
+/// Equivalent to the Rust `SplitterPane::with_max_size()` function.
+#[no_mangle] pub extern "C" fn AzSplitterPane_withMaxSize(splitterpane: &mut AzSplitterPane, max_size: f32) -> AzSplitterPane { splitterpane.with_max_size(max_size) }
+/// Equivalent to the Rust `SplitterPane::set_collapsed()` function.
+#[no_mangle] pub extern "C" fn AzSplitterPane_setCollapsed(splitterpane: &mut AzSplitterPane, collapsed: bool) { splitterpane.set_collapsed(collapsed) }
+/// Equivalent to the Rust `SplitterPane::with_collapsed()` function.
+#[no_mangle] pub extern "C" fn AzSplitterPane_withCollapsed(splitterpane: &mut AzSplitterPane, collapsed: bool) -> AzSplitterPane { splitterpane.with_collapsed(collapsed) }
+/// Destructor: Takes ownership of the `SplitterPane` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzSplitterPane_delete(object: &mut AzSplitterPane) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzSplitterOnResizeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzSplitterState) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `SplitterOnResizeCallback` struct
+pub use crate::widgets::splitter::SplitterOnResizeCallback as AzSplitterOnResizeCallbackTT;
+pub use AzSplitterOnResizeCallbackTT as AzSplitterOnResizeCallback;
+
+/// Re-export of rust-allocated (stack based) `SplitterOnResize` struct
+pub use crate::widgets::splitter::SplitterOnResize as AzSplitterOnResizeTT;
+pub use AzSplitterOnResizeTT as AzSplitterOnResize;
+/// Destructor: Takes ownership of the `SplitterOnResize` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzSplitterOnResize_delete(object: &mut AzSplitterOnResize) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Container which splits its area into resizable panes. Panes with a fixed size keep their size when the splitter is resized, the remaining space is distributed between the flexible panes. Dragging a handle resizes the panes on both sides of it within their `min_size` / `max_size`; if both panes are flexible, the first one is given a fixed size. Double-clicking a handle (or pressing Enter while it is focused) collapses the fixed pane next to it
+pub use crate::widgets::splitter::Splitter as AzSplitterTT;
+pub use AzSplitterTT as AzSplitter;
+/// Creates a new `Splitter` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `Splitter::new()` constructor.
+#[no_mangle] pub extern "C" fn AzSplitter_new(direction: AzSplitterDirection) -> AzSplitter { AzSplitter::new(direction) }
+/// Equivalent to the Rust `Splitter::with_pane()` function.
+#[no_mangle] pub extern "C" fn AzSplitter_withPane(splitter: &mut AzSplitter, pane: AzSplitterPane) -> AzSplitter { splitter.with_pane(pane) }
+/// Equivalent to the Rust `Splitter::add_pane()` function.
+#[no_mangle] pub extern "C" fn AzSplitter_addPane(splitter: &mut AzSplitter, pane: AzSplitterPane) { splitter.add_pane(pane) }
+/// Equivalent to the Rust `Splitter::set_handle_size()` function.
+#[no_mangle] pub extern "C" fn AzSplitter_setHandleSize(splitter: &mut AzSplitter, handle_size: f32) { splitter.set_handle_size(handle_size) }
+/// Equivalent to the Rust `Splitter::with_handle_size()` function.
+#[no_mangle] pub extern "C" fn AzSplitter_withHandleSize(splitter: &mut AzSplitter, handle_size: f32) -> AzSplitter { splitter.with_handle_size(handle_size) }
+/// Returns the current sizes of all panes
+#[no_mangle] pub extern "C" fn AzSplitter_getState(splitter: &AzSplitter) -> AzSplitterState { splitter.get_state() }
+/// Restores the pane sizes previously reported by `on_resize`
+#[no_mangle] pub extern "C" fn AzSplitter_setState(splitter: &mut AzSplitter, state: AzSplitterState) { splitter.set_state(state) }
+/// Restores the pane sizes previously reported by `on_resize`
+#[no_mangle] pub extern "C" fn AzSplitter_withState(splitter: &mut AzSplitter, state: AzSplitterState) -> AzSplitter { splitter.with_state(state) }
+/// Equivalent to the Rust `Splitter::set_on_resize()` function.
+#[no_mangle] pub extern "C" fn AzSplitter_setOnResize(splitter: &mut AzSplitter, data: AzRefAny, callback: AzSplitterOnResizeCallbackType) { splitter.set_on_resize(data, callback) }
+/// Equivalent to the Rust `Splitter::with_on_resize()` function.
+#[no_mangle] pub extern "C" fn AzSplitter_withOnResize(splitter: &mut AzSplitter, data: AzRefAny, callback: AzSplitterOnResizeCallbackType) -> AzSplitter { splitter.with_on_resize(data, callback) }
+/// Equivalent to the Rust `Splitter::dom()` function.
+#[no_mangle] pub extern "C" fn AzSplitter_dom(splitter: &mut AzSplitter) -> AzDom { splitter.swap_with_default().dom() }
+/// Destructor: Takes ownership of the `Splitter` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzSplitter_delete(object: &mut AzSplitter) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `DropDown` struct
 pub use crate::widgets::drop_down::DropDown as AzDropDownTT;
 pub use AzDropDownTT as AzDropDown;
@@ -2636,9 +3515,9 @@ pub use AzStyledDomTT as AzStyledDom;
 /// Returns a default, empty `Dom`, usually returned if you don't want to crash in an error case.
 #[no_mangle] pub extern "C" fn AzStyledDom_default() -> AzStyledDom { AzStyledDom::default() }
 /// Returns a DOM loaded from an XML file
-#[no_mangle] pub extern "C" fn AzStyledDom_fromXml(xml_string: AzString) -> AzStyledDom { azul_impl::app::extra::styled_dom_from_str(xml_string.as_str()) }
+#[no_mangle] pub extern "C" fn AzStyledDom_fromXml(xml_string: AzString) -> AzStyledDom { crate::widgets::styled_dom_from_str(xml_string.as_str()) }
 /// Same as `from_xml`, but loads the file relative to the current directory
-#[no_mangle] pub extern "C" fn AzStyledDom_fromFile(xml_file_path: AzString) -> AzStyledDom { azul_impl::app::extra::styled_dom_from_file(xml_file_path.as_str()) }
+#[no_mangle] pub extern "C" fn AzStyledDom_fromFile(xml_file_path: AzString) -> AzStyledDom { crate::widgets::styled_dom_from_file(xml_file_path.as_str()) }
 /// Appends an already styled list of DOM nodes to the current `dom.root` - complexity `O(count(dom.dom_nodes))`
 #[no_mangle] pub extern "C" fn AzStyledDom_appendChild(styleddom: &mut AzStyledDom, dom: AzStyledDom) { styleddom.append_child(dom); }
 /// Same as `append_child()`, but as a builder method
@@ -3289,7 +4168,7 @@ pub use AzTextureFlagsTT as AzTextureFlags;
 pub use azul_impl::resources::ImageRef as AzImageRefTT;
 pub use AzImageRefTT as AzImageRef;
 /// Creates an "invalid" image with a width and height that reserves an image key, but does not render anything
-#[no_mangle] pub extern "C" fn AzImageRef_invalid(width: usize, height: usize, format: AzRawImageFormat) -> AzImageRef { AzImageRef::invalid(width, height, format) }
+#[no_mangle] pub extern "C" fn AzImageRef_invalid(width: usize, height: usize, format: AzRawImageFormat) -> AzImageRef { AzImageRef::null_image(width, height, format, Vec::new()) }
 /// Creates an image reference from a CPU-backed buffer
 #[no_mangle] pub extern "C" fn AzImageRef_rawImage(data: AzRawImage) -> AzOptionImageRef { AzImageRef::new_rawimage(data).into() }
 /// Creates an image reference from an OpenGL texture
@@ -3437,6 +4316,10 @@ pub use AzFontRefTT as AzFontRef;
 #[no_mangle] pub extern "C" fn AzFontRef_getFontMetrics(fontref: &AzFontRef) -> AzFontMetrics { azul_impl::text_layout::get_font_metrics_fontref(fontref) }
 /// Returns the text layout of the shaped text
 #[no_mangle] pub extern "C" fn AzFontRef_shapeText(fontref: &AzFontRef, text: AzRefstr, options: AzResolvedTextLayoutOptions) -> AzInlineText { azul_impl::text_layout::shape_text(fontref, text.as_str(), &options) }
+/// Measures the size of the text (width, height and line metrics) without laying out a DOM
+#[no_mangle] pub extern "C" fn AzFontRef_measureText(fontref: &AzFontRef, text: AzRefstr, options: AzResolvedTextLayoutOptions) -> AzTextMetrics { azul_impl::text_layout::measure_text(fontref, text.as_str(), &options) }
+/// Shapes the text and returns the outlines of the positioned glyphs (relative to the top left corner of the text), so that the text can be tessellated, stroked or used in boolean operations
+#[no_mangle] pub extern "C" fn AzFontRef_textToPath(fontref: &AzFontRef, text: AzRefstr, options: AzResolvedTextLayoutOptions) -> AzSvgMultiPolygon { azul_impl::text_layout::text_to_path(fontref, text.as_str(), &options) }
 /// Returns the hash of the FontRef (fast)
 #[no_mangle] pub extern "C" fn AzFontRef_getHash(fontref: &AzFontRef) -> u64 { azul_core::app_resources::font_ref_get_hash(fontref) }
 /// Destructor: Takes ownership of the `FontRef` pointer and deletes it.
@@ -3849,6 +4732,10 @@ pub use AzXmlNodeTT as AzXmlNode;
 /// Destructor: Takes ownership of the `XmlNode` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzXmlNode_delete(object: &mut AzXmlNode) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Location of the start tag of an `XmlNode` in the XML source
+pub use azul_impl::xml::XmlSpan as AzXmlSpanTT;
+pub use AzXmlSpanTT as AzXmlSpan;
+
 /// **Reference-counted** file handle
 pub use azul_impl::file::File as AzFileTT;
 pub use AzFileTT as AzFile;
@@ -4140,6 +5027,24 @@ pub use AzListViewRowVecTT as AzListViewRowVec;
 /// Destructor: Takes ownership of the `ListViewRowVec` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzListViewRowVec_delete(object: &mut AzListViewRowVec) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Wrapper over a Rust-allocated `Vec<TreeViewItem>`
+pub use crate::widgets::tree_view::TreeViewItemVec as AzTreeViewItemVecTT;
+pub use AzTreeViewItemVecTT as AzTreeViewItemVec;
+/// Destructor: Takes ownership of the `TreeViewItemVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewItemVec_delete(object: &mut AzTreeViewItemVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<SplitterPane>`
+pub use crate::widgets::splitter::SplitterPaneVec as AzSplitterPaneVecTT;
+pub use AzSplitterPaneVecTT as AzSplitterPaneVec;
+/// Destructor: Takes ownership of the `SplitterPaneVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzSplitterPaneVec_delete(object: &mut AzSplitterPaneVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<SplitterPaneState>`
+pub use crate::widgets::splitter::SplitterPaneStateVec as AzSplitterPaneStateVecTT;
+pub use AzSplitterPaneStateVecTT as AzSplitterPaneStateVec;
+/// Destructor: Takes ownership of the `SplitterPaneStateVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzSplitterPaneStateVec_delete(object: &mut AzSplitterPaneStateVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Wrapper over a Rust-allocated `Vec<StyleFilter>`
 pub use azul_impl::css::StyleFilterVec as AzStyleFilterVecTT;
 pub use AzStyleFilterVecTT as AzStyleFilterVec;
@@ -4164,6 +5069,24 @@ pub use AzInputOutputTypeIdInfoMapVecTT as AzInputOutputTypeIdInfoMapVec;
 /// Destructor: Takes ownership of the `InputOutputTypeIdInfoMapVec` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzInputOutputTypeIdInfoMapVec_delete(object: &mut AzInputOutputTypeIdInfoMapVec) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Wrapper over a Rust-allocated `Vec<NodeGraphNodeId>`
+pub use crate::widgets::node_graph::NodeGraphNodeIdVec as AzNodeGraphNodeIdVecTT;
+pub use AzNodeGraphNodeIdVecTT as AzNodeGraphNodeIdVec;
+/// Destructor: Takes ownership of the `NodeGraphNodeIdVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzNodeGraphNodeIdVec_delete(object: &mut AzNodeGraphNodeIdVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<NodeGraphEditOp>`
+pub use crate::widgets::node_graph::NodeGraphEditOpVec as AzNodeGraphEditOpVecTT;
+pub use AzNodeGraphEditOpVecTT as AzNodeGraphEditOpVec;
+/// Destructor: Takes ownership of the `NodeGraphEditOpVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzNodeGraphEditOpVec_delete(object: &mut AzNodeGraphEditOpVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<NodeGraphEdit>`
+pub use crate::widgets::node_graph::NodeGraphEditVec as AzNodeGraphEditVecTT;
+pub use AzNodeGraphEditVecTT as AzNodeGraphEditVec;
+/// Destructor: Takes ownership of the `NodeGraphEditVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzNodeGraphEditVec_delete(object: &mut AzNodeGraphEditVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Wrapper over a Rust-allocated `Vec<NodeIdNodeMap>`
 pub use crate::widgets::node_graph::NodeIdNodeMapVec as AzNodeIdNodeMapVecTT;
 pub use AzNodeIdNodeMapVecTT as AzNodeIdNodeMapVec;
@@ -4212,6 +5135,12 @@ pub use AzAccessibilityStateVecTT as AzAccessibilityStateVec;
 /// Destructor: Takes ownership of the `AccessibilityStateVec` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzAccessibilityStateVec_delete(object: &mut AzAccessibilityStateVec) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Wrapper over a Rust-allocated `Vec<KeyboardShortcut>`
+pub use azul_core::window::KeyboardShortcutVec as AzKeyboardShortcutVecTT;
+pub use AzKeyboardShortcutVecTT as AzKeyboardShortcutVec;
+/// Destructor: Takes ownership of the `KeyboardShortcutVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzKeyboardShortcutVec_delete(object: &mut AzKeyboardShortcutVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Wrapper over a Rust-allocated `Vec<MenuItem>`
 pub use azul_core::window::MenuItemVec as AzMenuItemVecTT;
 pub use AzMenuItemVecTT as AzMenuItemVec;
@@ -4270,6 +5199,18 @@ pub use AzInlineGlyphVecTT as AzInlineGlyphVec;
 /// Destructor: Takes ownership of the `InlineGlyphVec` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzInlineGlyphVec_delete(object: &mut AzInlineGlyphVec) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Wrapper over a Rust-allocated `Vec<TextLineMetrics>`
+pub use azul_impl::callbacks::TextLineMetricsVec as AzTextLineMetricsVecTT;
+pub use AzTextLineMetricsVecTT as AzTextLineMetricsVec;
+/// Destructor: Takes ownership of the `TextLineMetricsVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextLineMetricsVec_delete(object: &mut AzTextLineMetricsVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<InlineTextRun>`
+pub use azul_impl::callbacks::InlineTextRunVec as AzInlineTextRunVecTT;
+pub use AzInlineTextRunVecTT as AzInlineTextRunVec;
+/// Destructor: Takes ownership of the `InlineTextRunVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzInlineTextRunVec_delete(object: &mut AzInlineTextRunVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Wrapper over a Rust-allocated `Vec<InlineTextHit>`
 pub use azul_impl::callbacks::InlineTextHitVec as AzInlineTextHitVecTT;
 pub use AzInlineTextHitVecTT as AzInlineTextHitVec;
@@ -4432,6 +5373,24 @@ pub use AzStylesheetVecTT as AzStylesheetVec;
 /// Destructor: Takes ownership of the `StylesheetVec` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzStylesheetVec_delete(object: &mut AzStylesheetVec) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Wrapper over a Rust-allocated `Vec<CssFontFace>`
+pub use azul_impl::css::CssFontFaceVec as AzCssFontFaceVecTT;
+pub use AzCssFontFaceVecTT as AzCssFontFaceVec;
+/// Destructor: Takes ownership of the `CssFontFaceVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCssFontFaceVec_delete(object: &mut AzCssFontFaceVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<CssFontFaceSource>`
+pub use azul_impl::css::CssFontFaceSourceVec as AzCssFontFaceSourceVecTT;
+pub use AzCssFontFaceSourceVecTT as AzCssFontFaceSourceVec;
+/// Destructor: Takes ownership of the `CssFontFaceSourceVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCssFontFaceSourceVec_delete(object: &mut AzCssFontFaceSourceVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<CssUnicodeRange>`
+pub use azul_impl::css::CssUnicodeRangeVec as AzCssUnicodeRangeVecTT;
+pub use AzCssUnicodeRangeVecTT as AzCssUnicodeRangeVec;
+/// Destructor: Takes ownership of the `CssUnicodeRangeVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCssUnicodeRangeVec_delete(object: &mut AzCssUnicodeRangeVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Wrapper over a Rust-allocated `CssRuleBlock`
 pub use azul_impl::css::CssRuleBlockVec as AzCssRuleBlockVecTT;
 pub use AzCssRuleBlockVecTT as AzCssRuleBlockVec;
@@ -4554,6 +5513,21 @@ pub use crate::widgets::list_view::ListViewRowVecDestructor as AzListViewRowVecD
 pub use AzListViewRowVecDestructorTT as AzListViewRowVecDestructor;
 
 pub type AzListViewRowVecDestructorType = extern "C" fn(&mut AzListViewRowVec);
+/// Re-export of rust-allocated (stack based) `TreeViewItemVecDestructor` struct
+pub use crate::widgets::tree_view::TreeViewItemVecDestructor as AzTreeViewItemVecDestructorTT;
+pub use AzTreeViewItemVecDestructorTT as AzTreeViewItemVecDestructor;
+
+pub type AzTreeViewItemVecDestructorType = extern "C" fn(&mut AzTreeViewItemVec);
+/// Re-export of rust-allocated (stack based) `SplitterPaneVecDestructor` struct
+pub use crate::widgets::splitter::SplitterPaneVecDestructor as AzSplitterPaneVecDestructorTT;
+pub use AzSplitterPaneVecDestructorTT as AzSplitterPaneVecDestructor;
+
+pub type AzSplitterPaneVecDestructorType = extern "C" fn(&mut AzSplitterPaneVec);
+/// Re-export of rust-allocated (stack based) `SplitterPaneStateVecDestructor` struct
+pub use crate::widgets::splitter::SplitterPaneStateVecDestructor as AzSplitterPaneStateVecDestructorTT;
+pub use AzSplitterPaneStateVecDestructorTT as AzSplitterPaneStateVecDestructor;
+
+pub type AzSplitterPaneStateVecDestructorType = extern "C" fn(&mut AzSplitterPaneStateVec);
 /// Re-export of rust-allocated (stack based) `StyleFilterVecDestructor` struct
 pub use azul_impl::css::StyleFilterVecDestructor as AzStyleFilterVecDestructorTT;
 pub use AzStyleFilterVecDestructorTT as AzStyleFilterVecDestructor;
@@ -4574,6 +5548,21 @@ pub use crate::widgets::node_graph::InputOutputTypeIdInfoMapVecDestructor as AzI
 pub use AzInputOutputTypeIdInfoMapVecDestructorTT as AzInputOutputTypeIdInfoMapVecDestructor;
 
 pub type AzInputOutputTypeIdInfoMapVecDestructorType = extern "C" fn(&mut AzInputOutputTypeIdInfoMapVec);
+/// Re-export of rust-allocated (stack based) `NodeGraphNodeIdVecDestructor` struct
+pub use crate::widgets::node_graph::NodeGraphNodeIdVecDestructor as AzNodeGraphNodeIdVecDestructorTT;
+pub use AzNodeGraphNodeIdVecDestructorTT as AzNodeGraphNodeIdVecDestructor;
+
+pub type AzNodeGraphNodeIdVecDestructorType = extern "C" fn(&mut AzNodeGraphNodeIdVec);
+/// Re-export of rust-allocated (stack based) `NodeGraphEditOpVecDestructor` struct
+pub use crate::widgets::node_graph::NodeGraphEditOpVecDestructor as AzNodeGraphEditOpVecDestructorTT;
+pub use AzNodeGraphEditOpVecDestructorTT as AzNodeGraphEditOpVecDestructor;
+
+pub type AzNodeGraphEditOpVecDestructorType = extern "C" fn(&mut AzNodeGraphEditOpVec);
+/// Re-export of rust-allocated (stack based) `NodeGraphEditVecDestructor` struct
+pub use crate::widgets::node_graph::NodeGraphEditVecDestructor as AzNodeGraphEditVecDestructorTT;
+pub use AzNodeGraphEditVecDestructorTT as AzNodeGraphEditVecDestructor;
+
+pub type AzNodeGraphEditVecDestructorType = extern "C" fn(&mut AzNodeGraphEditVec);
 /// Re-export of rust-allocated (stack based) `NodeIdNodeMapVecDestructor` struct
 pub use crate::widgets::node_graph::NodeIdNodeMapVecDestructor as AzNodeIdNodeMapVecDestructorTT;
 pub use AzNodeIdNodeMapVecDestructorTT as AzNodeIdNodeMapVecDestructor;
@@ -4614,6 +5603,11 @@ pub use azul_impl::dom::AccessibilityStateVecDestructor as AzAccessibilityStateV
 pub use AzAccessibilityStateVecDestructorTT as AzAccessibilityStateVecDestructor;
 
 pub type AzAccessibilityStateVecDestructorType = extern "C" fn(&mut AzAccessibilityStateVec);
+/// Re-export of rust-allocated (stack based) `KeyboardShortcutVecDestructor` struct
+pub use azul_core::window::KeyboardShortcutVecDestructor as AzKeyboardShortcutVecDestructorTT;
+pub use AzKeyboardShortcutVecDestructorTT as AzKeyboardShortcutVecDestructor;
+
+pub type AzKeyboardShortcutVecDestructorType = extern "C" fn(&mut AzKeyboardShortcutVec);
 /// Re-export of rust-allocated (stack based) `MenuItemVecDestructor` struct
 pub use azul_core::window::MenuItemVecDestructor as AzMenuItemVecDestructorTT;
 pub use AzMenuItemVecDestructorTT as AzMenuItemVecDestructor;
@@ -4654,6 +5648,16 @@ pub use azul_impl::callbacks::InlineGlyphVecDestructor as AzInlineGlyphVecDestru
 pub use AzInlineGlyphVecDestructorTT as AzInlineGlyphVecDestructor;
 
 pub type AzInlineGlyphVecDestructorType = extern "C" fn(&mut AzInlineGlyphVec);
+/// Re-export of rust-allocated (stack based) `TextLineMetricsVecDestructor` struct
+pub use azul_impl::callbacks::TextLineMetricsVecDestructor as AzTextLineMetricsVecDestructorTT;
+pub use AzTextLineMetricsVecDestructorTT as AzTextLineMetricsVecDestructor;
+
+pub type AzTextLineMetricsVecDestructorType = extern "C" fn(&mut AzTextLineMetricsVec);
+/// Re-export of rust-allocated (stack based) `InlineTextRunVecDestructor` struct
+pub use azul_impl::callbacks::InlineTextRunVecDestructor as AzInlineTextRunVecDestructorTT;
+pub use AzInlineTextRunVecDestructorTT as AzInlineTextRunVecDestructor;
+
+pub type AzInlineTextRunVecDestructorType = extern "C" fn(&mut AzInlineTextRunVec);
 /// Re-export of rust-allocated (stack based) `InlineTextHitVecDestructor` struct
 pub use azul_impl::callbacks::InlineTextHitVecDestructor as AzInlineTextHitVecDestructorTT;
 pub use AzInlineTextHitVecDestructorTT as AzInlineTextHitVecDestructor;
@@ -4789,6 +5793,21 @@ pub use azul_impl::css::StylesheetVecDestructor as AzStylesheetVecDestructorTT;
 pub use AzStylesheetVecDestructorTT as AzStylesheetVecDestructor;
 
 pub type AzStylesheetVecDestructorType = extern "C" fn(&mut AzStylesheetVec);
+/// Re-export of rust-allocated (stack based) `CssFontFaceVecDestructor` struct
+pub use azul_impl::css::CssFontFaceVecDestructor as AzCssFontFaceVecDestructorTT;
+pub use AzCssFontFaceVecDestructorTT as AzCssFontFaceVecDestructor;
+
+pub type AzCssFontFaceVecDestructorType = extern "C" fn(&mut AzCssFontFaceVec);
+/// Re-export of rust-allocated (stack based) `CssFontFaceSourceVecDestructor` struct
+pub use azul_impl::css::CssFontFaceSourceVecDestructor as AzCssFontFaceSourceVecDestructorTT;
+pub use AzCssFontFaceSourceVecDestructorTT as AzCssFontFaceSourceVecDestructor;
+
+pub type AzCssFontFaceSourceVecDestructorType = extern "C" fn(&mut AzCssFontFaceSourceVec);
+/// Re-export of rust-allocated (stack based) `CssUnicodeRangeVecDestructor` struct
+pub use azul_impl::css::CssUnicodeRangeVecDestructor as AzCssUnicodeRangeVecDestructorTT;
+pub use AzCssUnicodeRangeVecDestructorTT as AzCssUnicodeRangeVecDestructor;
+
+pub type AzCssUnicodeRangeVecDestructorType = extern "C" fn(&mut AzCssUnicodeRangeVec);
 /// Re-export of rust-allocated (stack based) `CssRuleBlockVecDestructor` struct
 pub use azul_impl::css::CssRuleBlockVecDestructor as AzCssRuleBlockVecDestructorTT;
 pub use AzCssRuleBlockVecDestructorTT as AzCssRuleBlockVecDestructor;
@@ -4883,12 +5902,150 @@ pub type AzNodeDataVecDestructorType = extern "C" fn(&mut AzNodeDataVec);
 pub use azul_impl::css::OptionSvgPoint as AzOptionSvgPointTT;
 pub use AzOptionSvgPointTT as AzOptionSvgPoint;
 
+/// Re-export of rust-allocated (stack based) `OptionTreeViewOnExpand` struct
+pub use crate::widgets::tree_view::OptionTreeViewOnExpand as AzOptionTreeViewOnExpandTT;
+pub use AzOptionTreeViewOnExpandTT as AzOptionTreeViewOnExpand;
+/// Destructor: Takes ownership of the `OptionTreeViewOnExpand` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTreeViewOnExpand_delete(object: &mut AzOptionTreeViewOnExpand) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionTreeViewOnSelect` struct
+pub use crate::widgets::tree_view::OptionTreeViewOnSelect as AzOptionTreeViewOnSelectTT;
+pub use AzOptionTreeViewOnSelectTT as AzOptionTreeViewOnSelect;
+/// Destructor: Takes ownership of the `OptionTreeViewOnSelect` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTreeViewOnSelect_delete(object: &mut AzOptionTreeViewOnSelect) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionTreeViewOnLazyLoad` struct
+pub use crate::widgets::tree_view::OptionTreeViewOnLazyLoad as AzOptionTreeViewOnLazyLoadTT;
+pub use AzOptionTreeViewOnLazyLoadTT as AzOptionTreeViewOnLazyLoad;
+/// Destructor: Takes ownership of the `OptionTreeViewOnLazyLoad` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTreeViewOnLazyLoad_delete(object: &mut AzOptionTreeViewOnLazyLoad) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionTreeViewOnScroll` struct
+pub use crate::widgets::tree_view::OptionTreeViewOnScroll as AzOptionTreeViewOnScrollTT;
+pub use AzOptionTreeViewOnScrollTT as AzOptionTreeViewOnScroll;
+/// Destructor: Takes ownership of the `OptionTreeViewOnScroll` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTreeViewOnScroll_delete(object: &mut AzOptionTreeViewOnScroll) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `OptionListViewOnRowClick` struct
 pub use crate::widgets::list_view::OptionListViewOnRowClick as AzOptionListViewOnRowClickTT;
 pub use AzOptionListViewOnRowClickTT as AzOptionListViewOnRowClick;
 /// Destructor: Takes ownership of the `OptionListViewOnRowClick` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzOptionListViewOnRowClick_delete(object: &mut AzOptionListViewOnRowClick) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `OptionListViewOnRowSelect` struct
+pub use crate::widgets::list_view::OptionListViewOnRowSelect as AzOptionListViewOnRowSelectTT;
+pub use AzOptionListViewOnRowSelectTT as AzOptionListViewOnRowSelect;
+/// Destructor: Takes ownership of the `OptionListViewOnRowSelect` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionListViewOnRowSelect_delete(object: &mut AzOptionListViewOnRowSelect) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionListViewOnColumnResize` struct
+pub use crate::widgets::list_view::OptionListViewOnColumnResize as AzOptionListViewOnColumnResizeTT;
+pub use AzOptionListViewOnColumnResizeTT as AzOptionListViewOnColumnResize;
+/// Destructor: Takes ownership of the `OptionListViewOnColumnResize` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionListViewOnColumnResize_delete(object: &mut AzOptionListViewOnColumnResize) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionListViewRowProvider` struct
+pub use crate::widgets::list_view::OptionListViewRowProvider as AzOptionListViewRowProviderTT;
+pub use AzOptionListViewRowProviderTT as AzOptionListViewRowProvider;
+/// Destructor: Takes ownership of the `OptionListViewRowProvider` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionListViewRowProvider_delete(object: &mut AzOptionListViewRowProvider) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionDataGridRange` struct
+pub use crate::widgets::spreadsheet::OptionDataGridRange as AzOptionDataGridRangeTT;
+pub use AzOptionDataGridRangeTT as AzOptionDataGridRange;
+
+/// Re-export of rust-allocated (stack based) `OptionDataGridCellProvider` struct
+pub use crate::widgets::spreadsheet::OptionDataGridCellProvider as AzOptionDataGridCellProviderTT;
+pub use AzOptionDataGridCellProviderTT as AzOptionDataGridCellProvider;
+/// Destructor: Takes ownership of the `OptionDataGridCellProvider` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionDataGridCellProvider_delete(object: &mut AzOptionDataGridCellProvider) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionDataGridCellStyle` struct
+pub use crate::widgets::spreadsheet::OptionDataGridCellStyle as AzOptionDataGridCellStyleTT;
+pub use AzOptionDataGridCellStyleTT as AzOptionDataGridCellStyle;
+/// Destructor: Takes ownership of the `OptionDataGridCellStyle` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionDataGridCellStyle_delete(object: &mut AzOptionDataGridCellStyle) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionDataGridOnSelect` struct
+pub use crate::widgets::spreadsheet::OptionDataGridOnSelect as AzOptionDataGridOnSelectTT;
+pub use AzOptionDataGridOnSelectTT as AzOptionDataGridOnSelect;
+/// Destructor: Takes ownership of the `OptionDataGridOnSelect` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionDataGridOnSelect_delete(object: &mut AzOptionDataGridOnSelect) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionDataGridOnScroll` struct
+pub use crate::widgets::spreadsheet::OptionDataGridOnScroll as AzOptionDataGridOnScrollTT;
+pub use AzOptionDataGridOnScrollTT as AzOptionDataGridOnScroll;
+/// Destructor: Takes ownership of the `OptionDataGridOnScroll` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionDataGridOnScroll_delete(object: &mut AzOptionDataGridOnScroll) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionDataGridOnCellEdit` struct
+pub use crate::widgets::spreadsheet::OptionDataGridOnCellEdit as AzOptionDataGridOnCellEditTT;
+pub use AzOptionDataGridOnCellEditTT as AzOptionDataGridOnCellEdit;
+/// Destructor: Takes ownership of the `OptionDataGridOnCellEdit` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionDataGridOnCellEdit_delete(object: &mut AzOptionDataGridOnCellEdit) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionDataGridOnColumnResize` struct
+pub use crate::widgets::spreadsheet::OptionDataGridOnColumnResize as AzOptionDataGridOnColumnResizeTT;
+pub use AzOptionDataGridOnColumnResizeTT as AzOptionDataGridOnColumnResize;
+/// Destructor: Takes ownership of the `OptionDataGridOnColumnResize` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionDataGridOnColumnResize_delete(object: &mut AzOptionDataGridOnColumnResize) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionDataGridOnRowResize` struct
+pub use crate::widgets::spreadsheet::OptionDataGridOnRowResize as AzOptionDataGridOnRowResizeTT;
+pub use AzOptionDataGridOnRowResizeTT as AzOptionDataGridOnRowResize;
+/// Destructor: Takes ownership of the `OptionDataGridOnRowResize` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionDataGridOnRowResize_delete(object: &mut AzOptionDataGridOnRowResize) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionDataGridOnCopyRange` struct
+pub use crate::widgets::spreadsheet::OptionDataGridOnCopyRange as AzOptionDataGridOnCopyRangeTT;
+pub use AzOptionDataGridOnCopyRangeTT as AzOptionDataGridOnCopyRange;
+/// Destructor: Takes ownership of the `OptionDataGridOnCopyRange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionDataGridOnCopyRange_delete(object: &mut AzOptionDataGridOnCopyRange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionDataGridOnClearRange` struct
+pub use crate::widgets::spreadsheet::OptionDataGridOnClearRange as AzOptionDataGridOnClearRangeTT;
+pub use AzOptionDataGridOnClearRangeTT as AzOptionDataGridOnClearRange;
+/// Destructor: Takes ownership of the `OptionDataGridOnClearRange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionDataGridOnClearRange_delete(object: &mut AzOptionDataGridOnClearRange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionDate` struct
+pub use crate::widgets::date_picker::OptionDate as AzOptionDateTT;
+pub use AzOptionDateTT as AzOptionDate;
+
+/// Re-export of rust-allocated (stack based) `OptionTime` struct
+pub use crate::widgets::date_picker::OptionTime as AzOptionTimeTT;
+pub use AzOptionTimeTT as AzOptionTime;
+
+/// Re-export of rust-allocated (stack based) `OptionDatePickerOnDateChange` struct
+pub use crate::widgets::date_picker::OptionDatePickerOnDateChange as AzOptionDatePickerOnDateChangeTT;
+pub use AzOptionDatePickerOnDateChangeTT as AzOptionDatePickerOnDateChange;
+/// Destructor: Takes ownership of the `OptionDatePickerOnDateChange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionDatePickerOnDateChange_delete(object: &mut AzOptionDatePickerOnDateChange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionTimePickerOnTimeChange` struct
+pub use crate::widgets::date_picker::OptionTimePickerOnTimeChange as AzOptionTimePickerOnTimeChangeTT;
+pub use AzOptionTimePickerOnTimeChangeTT as AzOptionTimePickerOnTimeChange;
+/// Destructor: Takes ownership of the `OptionTimePickerOnTimeChange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTimePickerOnTimeChange_delete(object: &mut AzOptionTimePickerOnTimeChange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionDateTimePickerOnDateTimeChange` struct
+pub use crate::widgets::date_picker::OptionDateTimePickerOnDateTimeChange as AzOptionDateTimePickerOnDateTimeChangeTT;
+pub use AzOptionDateTimePickerOnDateTimeChangeTT as AzOptionDateTimePickerOnDateTimeChange;
+/// Destructor: Takes ownership of the `OptionDateTimePickerOnDateTimeChange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionDateTimePickerOnDateTimeChange_delete(object: &mut AzOptionDateTimePickerOnDateTimeChange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionDialogOnClose` struct
+pub use crate::widgets::dialog::OptionDialogOnClose as AzOptionDialogOnCloseTT;
+pub use AzOptionDialogOnCloseTT as AzOptionDialogOnClose;
+/// Destructor: Takes ownership of the `OptionDialogOnClose` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionDialogOnClose_delete(object: &mut AzOptionDialogOnClose) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionSplitterOnResize` struct
+pub use crate::widgets::splitter::OptionSplitterOnResize as AzOptionSplitterOnResizeTT;
+pub use AzOptionSplitterOnResizeTT as AzOptionSplitterOnResize;
+/// Destructor: Takes ownership of the `OptionSplitterOnResize` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionSplitterOnResize_delete(object: &mut AzOptionSplitterOnResize) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `OptionListViewOnColumnClick` struct
 pub use crate::widgets::list_view::OptionListViewOnColumnClick as AzOptionListViewOnColumnClickTT;
 pub use AzOptionListViewOnColumnClickTT as AzOptionListViewOnColumnClick;
@@ -4923,6 +6080,24 @@ pub use AzOptionResolvedTextLayoutOptionsTT as AzOptionResolvedTextLayoutOptions
 /// Destructor: Takes ownership of the `OptionResolvedTextLayoutOptions` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzOptionResolvedTextLayoutOptions_delete(object: &mut AzOptionResolvedTextLayoutOptions) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `OptionNodeGraphOnNodeGraphZoomed` struct
+pub use crate::widgets::node_graph::OptionOnNodeGraphZoomed as AzOptionNodeGraphOnNodeGraphZoomedTT;
+pub use AzOptionNodeGraphOnNodeGraphZoomedTT as AzOptionNodeGraphOnNodeGraphZoomed;
+/// Destructor: Takes ownership of the `OptionNodeGraphOnNodeGraphZoomed` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionNodeGraphOnNodeGraphZoomed_delete(object: &mut AzOptionNodeGraphOnNodeGraphZoomed) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionNodeGraphOnSelectionChanged` struct
+pub use crate::widgets::node_graph::OptionOnNodeGraphSelectionChanged as AzOptionNodeGraphOnSelectionChangedTT;
+pub use AzOptionNodeGraphOnSelectionChangedTT as AzOptionNodeGraphOnSelectionChanged;
+/// Destructor: Takes ownership of the `OptionNodeGraphOnSelectionChanged` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionNodeGraphOnSelectionChanged_delete(object: &mut AzOptionNodeGraphOnSelectionChanged) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionNodeGraphOnHistoryChanged` struct
+pub use crate::widgets::node_graph::OptionOnNodeGraphHistoryChanged as AzOptionNodeGraphOnHistoryChangedTT;
+pub use AzOptionNodeGraphOnHistoryChangedTT as AzOptionNodeGraphOnHistoryChanged;
+/// Destructor: Takes ownership of the `OptionNodeGraphOnHistoryChanged` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionNodeGraphOnHistoryChanged_delete(object: &mut AzOptionNodeGraphOnHistoryChanged) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `OptionNodeGraphOnNodeAdded` struct
 pub use crate::widgets::node_graph::OptionOnNodeAdded as AzOptionNodeGraphOnNodeAddedTT;
 pub use AzOptionNodeGraphOnNodeAddedTT as AzOptionNodeGraphOnNodeAdded;
@@ -5185,6 +6360,12 @@ pub use AzOptionInlineTextTT as AzOptionInlineText;
 /// Destructor: Takes ownership of the `OptionInlineText` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzOptionInlineText_delete(object: &mut AzOptionInlineText) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `OptionTextMetrics` struct
+pub use azul_impl::callbacks::OptionTextMetrics as AzOptionTextMetricsTT;
+pub use AzOptionTextMetricsTT as AzOptionTextMetrics;
+/// Destructor: Takes ownership of the `OptionTextMetrics` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTextMetrics_delete(object: &mut AzOptionTextMetrics) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `OptionLayoutPoint` struct
 pub use azul_impl::css::OptionLayoutPoint as AzOptionLayoutPointTT;
 pub use AzOptionLayoutPointTT as AzOptionLayoutPoint;
@@ -5357,6 +6538,12 @@ pub use AzResultSvgXmlNodeSvgParseErrorTT as AzResultSvgXmlNodeSvgParseError;
 /// Destructor: Takes ownership of the `ResultSvgXmlNodeSvgParseError` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzResultSvgXmlNodeSvgParseError_delete(object: &mut AzResultSvgXmlNodeSvgParseError) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `ResultNodeGraphNodeGraphError` struct
+pub use crate::widgets::node_graph::ResultNodeGraphNodeGraphError as AzResultNodeGraphNodeGraphErrorTT;
+pub use AzResultNodeGraphNodeGraphErrorTT as AzResultNodeGraphNodeGraphError;
+/// Destructor: Takes ownership of the `ResultNodeGraphNodeGraphError` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzResultNodeGraphNodeGraphError_delete(object: &mut AzResultNodeGraphNodeGraphError) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `ResultSvgSvgParseError` struct
 pub use azul_impl::svg::ResultSvgSvgParseError as AzResultSvgSvgParseErrorTT;
 pub use AzResultSvgSvgParseErrorTT as AzResultSvgSvgParseError;
@@ -6438,6 +7625,21 @@ mod test_sizes {
         pub offset: u32,
     }
 
+    /// Re-export of rust-allocated (stack based) `CssFontFaceStyle` struct
+    #[repr(C)]
+    pub enum AzCssFontFaceStyle {
+        Normal,
+        Italic,
+        Oblique,
+    }
+
+    /// `U+0000-00FF`: inclusive range of unicode code points
+    #[repr(C)]
+    pub struct AzCssUnicodeRange {
+        pub start: u32,
+        pub end: u32,
+    }
+
     /// Re-export of rust-allocated (stack based) `CssPropertyType` struct
     #[repr(C)]
     pub enum AzCssPropertyType {
@@ -6515,6 +7717,8 @@ mod test_sizes {
         Filter,
         BackdropFilter,
         TextShadow,
+        VerticalAlign,
+        TextDecoration,
     }
 
     /// Re-export of rust-allocated (stack based) `ColorU` struct
@@ -6616,6 +7820,7 @@ mod test_sizes {
         Flex,
         Block,
         InlineBlock,
+        Inline,
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutFloat` struct
@@ -6645,6 +7850,17 @@ mod test_sizes {
         Fixed,
     }
 
+    /// Re-export of rust-allocated (stack based) `LayoutVerticalAlign` struct
+    #[repr(C)]
+    pub enum AzLayoutVerticalAlign {
+        Baseline,
+        Top,
+        Middle,
+        Bottom,
+        Sub,
+        Super,
+    }
+
     /// Re-export of rust-allocated (stack based) `LayoutFlexWrap` struct
     #[repr(C)]
     pub enum AzLayoutFlexWrap {
@@ -6787,6 +8003,15 @@ mod test_sizes {
         Right,
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleTextDecoration` struct
+    #[repr(C)]
+    pub enum AzStyleTextDecoration {
+        None,
+        Underline,
+        Overline,
+        LineThrough,
+    }
+
     /// Re-export of rust-allocated (stack based) `Ribbon` struct
     #[repr(C)]
     pub struct AzRibbon {
@@ -7004,6 +8229,33 @@ mod test_sizes {
         pub cb: AzNodeGraphOnNodeFieldEditedCallbackType,
     }
 
+    /// `AzNodeGraphOnNodeGraphZoomedCallbackType` struct
+    pub type AzNodeGraphOnNodeGraphZoomedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, f32, AzLogicalPosition) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeGraphZoomedCallback` struct
+    #[repr(C)]
+    pub struct AzNodeGraphOnNodeGraphZoomedCallback {
+        pub cb: AzNodeGraphOnNodeGraphZoomedCallbackType,
+    }
+
+    /// `AzNodeGraphOnSelectionChangedCallbackType` struct
+    pub type AzNodeGraphOnSelectionChangedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphNodeIdVec) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `NodeGraphOnSelectionChangedCallback` struct
+    #[repr(C)]
+    pub struct AzNodeGraphOnSelectionChangedCallback {
+        pub cb: AzNodeGraphOnSelectionChangedCallbackType,
+    }
+
+    /// `AzNodeGraphOnHistoryChangedCallbackType` struct
+    pub type AzNodeGraphOnHistoryChangedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphHistory) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `NodeGraphOnHistoryChangedCallback` struct
+    #[repr(C)]
+    pub struct AzNodeGraphOnHistoryChangedCallback {
+        pub cb: AzNodeGraphOnHistoryChangedCallbackType,
+    }
+
     /// Re-export of rust-allocated (stack based) `InputOutputTypeId` struct
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -7052,6 +8304,24 @@ mod test_sizes {
         pub y: f32,
     }
 
+    /// Re-export of rust-allocated (stack based) `NodeGraphError` struct
+    #[repr(C)]
+    pub enum AzNodeGraphError {
+        NodeMimeTypeMismatch,
+        NodeInvalidIndex,
+        NodeInvalidNode,
+        NoRootNode,
+        InvalidJson,
+    }
+
+    /// How the selection should change in response to a user action
+    #[repr(C)]
+    pub enum AzListViewSelectionChange {
+        Replace,
+        Toggle,
+        Extend,
+    }
+
     /// `AzListViewOnLazyLoadScrollCallbackType` struct
     pub type AzListViewOnLazyLoadScrollCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzListViewState) -> AzUpdate;
 
@@ -7079,6 +8349,280 @@ mod test_sizes {
         pub cb: AzListViewOnRowClickCallbackType,
     }
 
+    /// `AzListViewOnRowSelectCallbackType` struct
+    pub type AzListViewOnRowSelectCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzListViewState, usize, AzListViewSelectionChange) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `ListViewOnRowSelectCallback` struct
+    #[repr(C)]
+    pub struct AzListViewOnRowSelectCallback {
+        pub cb: AzListViewOnRowSelectCallbackType,
+    }
+
+    /// `AzListViewOnColumnResizeCallbackType` struct
+    pub type AzListViewOnColumnResizeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzListViewState, usize, f32) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `ListViewOnColumnResizeCallback` struct
+    #[repr(C)]
+    pub struct AzListViewOnColumnResizeCallback {
+        pub cb: AzListViewOnColumnResizeCallbackType,
+    }
+
+    /// `AzListViewRowProviderCallbackType` struct
+    pub type AzListViewRowProviderCallbackType = extern "C" fn(&mut AzRefAny, usize, usize) -> AzListViewRowVec;
+
+    /// Re-export of rust-allocated (stack based) `ListViewRowProviderCallback` struct
+    #[repr(C)]
+    pub struct AzListViewRowProviderCallback {
+        pub cb: AzListViewRowProviderCallbackType,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewSelectionMode` struct
+    #[repr(C)]
+    pub enum AzTreeViewSelectionMode {
+        Single,
+        Multiple,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewSelectionChange` struct
+    #[repr(C)]
+    pub enum AzTreeViewSelectionChange {
+        Replace,
+        Toggle,
+        Extend,
+    }
+
+    /// `AzTreeViewOnExpandCallbackType` struct
+    pub type AzTreeViewOnExpandCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, bool) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnExpandCallback` struct
+    #[repr(C)]
+    pub struct AzTreeViewOnExpandCallback {
+        pub cb: AzTreeViewOnExpandCallbackType,
+    }
+
+    /// `AzTreeViewOnSelectCallbackType` struct
+    pub type AzTreeViewOnSelectCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, AzTreeViewSelectionChange) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnSelectCallback` struct
+    #[repr(C)]
+    pub struct AzTreeViewOnSelectCallback {
+        pub cb: AzTreeViewOnSelectCallbackType,
+    }
+
+    /// `AzTreeViewOnLazyLoadCallbackType` struct
+    pub type AzTreeViewOnLazyLoadCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnLazyLoadCallback` struct
+    #[repr(C)]
+    pub struct AzTreeViewOnLazyLoadCallback {
+        pub cb: AzTreeViewOnLazyLoadCallbackType,
+    }
+
+    /// `AzTreeViewOnScrollCallbackType` struct
+    pub type AzTreeViewOnScrollCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnScrollCallback` struct
+    #[repr(C)]
+    pub struct AzTreeViewOnScrollCallback {
+        pub cb: AzTreeViewOnScrollCallbackType,
+    }
+
+    /// Position of a cell in the DataGrid
+    #[repr(C)]
+    pub struct AzDataGridCell {
+        pub row: usize,
+        pub column: usize,
+    }
+
+    /// `AzDataGridCellProviderCallbackType` struct
+    pub type AzDataGridCellProviderCallbackType = extern "C" fn(&mut AzRefAny, usize, usize) -> AzString;
+
+    /// Re-export of rust-allocated (stack based) `DataGridCellProviderCallback` struct
+    #[repr(C)]
+    pub struct AzDataGridCellProviderCallback {
+        pub cb: AzDataGridCellProviderCallbackType,
+    }
+
+    /// `AzDataGridCellStyleCallbackType` struct
+    pub type AzDataGridCellStyleCallbackType = extern "C" fn(&mut AzRefAny, usize, usize, bool) -> AzNodeDataInlineCssPropertyVec;
+
+    /// Re-export of rust-allocated (stack based) `DataGridCellStyleCallback` struct
+    #[repr(C)]
+    pub struct AzDataGridCellStyleCallback {
+        pub cb: AzDataGridCellStyleCallbackType,
+    }
+
+    /// `AzDataGridOnSelectCallbackType` struct
+    pub type AzDataGridOnSelectCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzDataGridRange) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `DataGridOnSelectCallback` struct
+    #[repr(C)]
+    pub struct AzDataGridOnSelectCallback {
+        pub cb: AzDataGridOnSelectCallbackType,
+    }
+
+    /// `AzDataGridOnScrollCallbackType` struct
+    pub type AzDataGridOnScrollCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, usize) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `DataGridOnScrollCallback` struct
+    #[repr(C)]
+    pub struct AzDataGridOnScrollCallback {
+        pub cb: AzDataGridOnScrollCallbackType,
+    }
+
+    /// `AzDataGridOnCellEditCallbackType` struct
+    pub type AzDataGridOnCellEditCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, usize, AzString) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `DataGridOnCellEditCallback` struct
+    #[repr(C)]
+    pub struct AzDataGridOnCellEditCallback {
+        pub cb: AzDataGridOnCellEditCallbackType,
+    }
+
+    /// `AzDataGridOnColumnResizeCallbackType` struct
+    pub type AzDataGridOnColumnResizeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, f32) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `DataGridOnColumnResizeCallback` struct
+    #[repr(C)]
+    pub struct AzDataGridOnColumnResizeCallback {
+        pub cb: AzDataGridOnColumnResizeCallbackType,
+    }
+
+    /// `AzDataGridOnRowResizeCallbackType` struct
+    pub type AzDataGridOnRowResizeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, f32) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `DataGridOnRowResizeCallback` struct
+    #[repr(C)]
+    pub struct AzDataGridOnRowResizeCallback {
+        pub cb: AzDataGridOnRowResizeCallbackType,
+    }
+
+    /// `AzDataGridOnCopyRangeCallbackType` struct
+    pub type AzDataGridOnCopyRangeCallbackType = extern "C" fn(&mut AzRefAny, AzDataGridRange) -> AzString;
+
+    /// Re-export of rust-allocated (stack based) `DataGridOnCopyRangeCallback` struct
+    #[repr(C)]
+    pub struct AzDataGridOnCopyRangeCallback {
+        pub cb: AzDataGridOnCopyRangeCallbackType,
+    }
+
+    /// `AzDataGridOnClearRangeCallbackType` struct
+    pub type AzDataGridOnClearRangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzDataGridRange) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `DataGridOnClearRangeCallback` struct
+    #[repr(C)]
+    pub struct AzDataGridOnClearRangeCallback {
+        pub cb: AzDataGridOnClearRangeCallbackType,
+    }
+
+    /// Calendar date (proleptic Gregorian calendar), not validated on construction
+    #[repr(C)]
+    pub struct AzDate {
+        pub year: i32,
+        pub month: u8,
+        pub day: u8,
+    }
+
+    /// Day of the week
+    #[repr(C)]
+    pub enum AzWeekday {
+        Monday,
+        Tuesday,
+        Wednesday,
+        Thursday,
+        Friday,
+        Saturday,
+        Sunday,
+    }
+
+    /// Time of day, not validated on construction
+    #[repr(C)]
+    pub struct AzTime {
+        pub hour: u8,
+        pub minute: u8,
+        pub second: u8,
+    }
+
+    /// `AzDatePickerOnDateChangeCallbackType` struct
+    pub type AzDatePickerOnDateChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzDatePickerState) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `DatePickerOnDateChangeCallback` struct
+    #[repr(C)]
+    pub struct AzDatePickerOnDateChangeCallback {
+        pub cb: AzDatePickerOnDateChangeCallbackType,
+    }
+
+    /// `AzTimePickerOnTimeChangeCallbackType` struct
+    pub type AzTimePickerOnTimeChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTime) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TimePickerOnTimeChangeCallback` struct
+    #[repr(C)]
+    pub struct AzTimePickerOnTimeChangeCallback {
+        pub cb: AzTimePickerOnTimeChangeCallbackType,
+    }
+
+    /// `AzDateTimePickerOnDateTimeChangeCallbackType` struct
+    pub type AzDateTimePickerOnDateTimeChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzDateTimePickerState) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `DateTimePickerOnDateTimeChangeCallback` struct
+    #[repr(C)]
+    pub struct AzDateTimePickerOnDateTimeChangeCallback {
+        pub cb: AzDateTimePickerOnDateTimeChangeCallbackType,
+    }
+
+    /// Icon shown left of the message of a `Dialog`
+    #[repr(C)]
+    pub enum AzDialogIcon {
+        None,
+        Info,
+        Warning,
+        Error,
+        Question,
+    }
+
+    /// Set of buttons shown at the bottom of a `Dialog`
+    #[repr(C)]
+    pub enum AzDialogButtons {
+        Ok,
+        OkCancel,
+        YesNo,
+        YesNoCancel,
+    }
+
+    /// Button that closed a `Dialog`
+    #[repr(C)]
+    pub enum AzDialogButton {
+        Ok,
+        Cancel,
+        Yes,
+        No,
+    }
+
+    /// `AzDialogOnCloseCallbackType` struct
+    pub type AzDialogOnCloseCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzDialogResult) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `DialogOnCloseCallback` struct
+    #[repr(C)]
+    pub struct AzDialogOnCloseCallback {
+        pub cb: AzDialogOnCloseCallbackType,
+    }
+
+    /// Direction in which the panes of a `Splitter` are laid out
+    #[repr(C)]
+    pub enum AzSplitterDirection {
+        Horizontal,
+        Vertical,
+    }
+
+    /// `AzSplitterOnResizeCallbackType` struct
+    pub type AzSplitterOnResizeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzSplitterState) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `SplitterOnResizeCallback` struct
+    #[repr(C)]
+    pub struct AzSplitterOnResizeCallback {
+        pub cb: AzSplitterOnResizeCallbackType,
+    }
+
     /// `AzDropDownOnChoiceChangeCallbackType` struct
     pub type AzDropDownOnChoiceChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize) -> AzUpdate;
 
@@ -7734,6 +9278,39 @@ mod test_sizes {
     /// `AzListViewRowVecDestructorType` struct
     pub type AzListViewRowVecDestructorType = extern "C" fn(&mut AzListViewRowVec);
 
+    /// Re-export of rust-allocated (stack based) `TreeViewItemVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzTreeViewItemVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzTreeViewItemVecDestructorType),
+    }
+
+    /// `AzTreeViewItemVecDestructorType` struct
+    pub type AzTreeViewItemVecDestructorType = extern "C" fn(&mut AzTreeViewItemVec);
+
+    /// Re-export of rust-allocated (stack based) `SplitterPaneVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzSplitterPaneVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzSplitterPaneVecDestructorType),
+    }
+
+    /// `AzSplitterPaneVecDestructorType` struct
+    pub type AzSplitterPaneVecDestructorType = extern "C" fn(&mut AzSplitterPaneVec);
+
+    /// Re-export of rust-allocated (stack based) `SplitterPaneStateVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzSplitterPaneStateVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzSplitterPaneStateVecDestructorType),
+    }
+
+    /// `AzSplitterPaneStateVecDestructorType` struct
+    pub type AzSplitterPaneStateVecDestructorType = extern "C" fn(&mut AzSplitterPaneStateVec);
+
     /// Re-export of rust-allocated (stack based) `StyleFilterVecDestructor` struct
     #[repr(C, u8)]
     pub enum AzStyleFilterVecDestructor {
@@ -7778,6 +9355,39 @@ mod test_sizes {
     /// `AzInputOutputTypeIdInfoMapVecDestructorType` struct
     pub type AzInputOutputTypeIdInfoMapVecDestructorType = extern "C" fn(&mut AzInputOutputTypeIdInfoMapVec);
 
+    /// Re-export of rust-allocated (stack based) `NodeGraphNodeIdVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzNodeGraphNodeIdVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzNodeGraphNodeIdVecDestructorType),
+    }
+
+    /// `AzNodeGraphNodeIdVecDestructorType` struct
+    pub type AzNodeGraphNodeIdVecDestructorType = extern "C" fn(&mut AzNodeGraphNodeIdVec);
+
+    /// Re-export of rust-allocated (stack based) `NodeGraphEditOpVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzNodeGraphEditOpVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzNodeGraphEditOpVecDestructorType),
+    }
+
+    /// `AzNodeGraphEditOpVecDestructorType` struct
+    pub type AzNodeGraphEditOpVecDestructorType = extern "C" fn(&mut AzNodeGraphEditOpVec);
+
+    /// Re-export of rust-allocated (stack based) `NodeGraphEditVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzNodeGraphEditVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzNodeGraphEditVecDestructorType),
+    }
+
+    /// `AzNodeGraphEditVecDestructorType` struct
+    pub type AzNodeGraphEditVecDestructorType = extern "C" fn(&mut AzNodeGraphEditVec);
+
     /// Re-export of rust-allocated (stack based) `NodeIdNodeMapVecDestructor` struct
     #[repr(C, u8)]
     pub enum AzNodeIdNodeMapVecDestructor {
@@ -7866,6 +9476,17 @@ mod test_sizes {
     /// `AzAccessibilityStateVecDestructorType` struct
     pub type AzAccessibilityStateVecDestructorType = extern "C" fn(&mut AzAccessibilityStateVec);
 
+    /// Re-export of rust-allocated (stack based) `KeyboardShortcutVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzKeyboardShortcutVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzKeyboardShortcutVecDestructorType),
+    }
+
+    /// `AzKeyboardShortcutVecDestructorType` struct
+    pub type AzKeyboardShortcutVecDestructorType = extern "C" fn(&mut AzKeyboardShortcutVec);
+
     /// Re-export of rust-allocated (stack based) `MenuItemVecDestructor` struct
     #[repr(C, u8)]
     pub enum AzMenuItemVecDestructor {
@@ -7954,12 +9575,34 @@ mod test_sizes {
     /// `AzInlineGlyphVecDestructorType` struct
     pub type AzInlineGlyphVecDestructorType = extern "C" fn(&mut AzInlineGlyphVec);
 
-    /// Re-export of rust-allocated (stack based) `InlineTextHitVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `TextLineMetricsVecDestructor` struct
     #[repr(C, u8)]
-    pub enum AzInlineTextHitVecDestructor {
+    pub enum AzTextLineMetricsVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzInlineTextHitVecDestructorType),
+        External(AzTextLineMetricsVecDestructorType),
+    }
+
+    /// `AzTextLineMetricsVecDestructorType` struct
+    pub type AzTextLineMetricsVecDestructorType = extern "C" fn(&mut AzTextLineMetricsVec);
+
+    /// Re-export of rust-allocated (stack based) `InlineTextRunVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzInlineTextRunVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzInlineTextRunVecDestructorType),
+    }
+
+    /// `AzInlineTextRunVecDestructorType` struct
+    pub type AzInlineTextRunVecDestructorType = extern "C" fn(&mut AzInlineTextRunVec);
+
+    /// Re-export of rust-allocated (stack based) `InlineTextHitVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzInlineTextHitVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzInlineTextHitVecDestructorType),
     }
 
     /// `AzInlineTextHitVecDestructorType` struct
@@ -8251,6 +9894,39 @@ mod test_sizes {
     /// `AzStylesheetVecDestructorType` struct
     pub type AzStylesheetVecDestructorType = extern "C" fn(&mut AzStylesheetVec);
 
+    /// Re-export of rust-allocated (stack based) `CssFontFaceVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzCssFontFaceVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzCssFontFaceVecDestructorType),
+    }
+
+    /// `AzCssFontFaceVecDestructorType` struct
+    pub type AzCssFontFaceVecDestructorType = extern "C" fn(&mut AzCssFontFaceVec);
+
+    /// Re-export of rust-allocated (stack based) `CssFontFaceSourceVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzCssFontFaceSourceVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzCssFontFaceSourceVecDestructorType),
+    }
+
+    /// `AzCssFontFaceSourceVecDestructorType` struct
+    pub type AzCssFontFaceSourceVecDestructorType = extern "C" fn(&mut AzCssFontFaceSourceVec);
+
+    /// Re-export of rust-allocated (stack based) `CssUnicodeRangeVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzCssUnicodeRangeVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzCssUnicodeRangeVecDestructorType),
+    }
+
+    /// `AzCssUnicodeRangeVecDestructorType` struct
+    pub type AzCssUnicodeRangeVecDestructorType = extern "C" fn(&mut AzCssUnicodeRangeVec);
+
     /// Re-export of rust-allocated (stack based) `CssRuleBlockVecDestructor` struct
     #[repr(C, u8)]
     pub enum AzCssRuleBlockVecDestructor {
@@ -8650,6 +10326,23 @@ mod test_sizes {
         pub hidpi_factor: f32,
     }
 
+    /// Metrics of a single line of a measured text
+    #[repr(C)]
+    pub struct AzTextLineMetrics {
+        pub bounds: AzLogicalRect,
+        pub baseline_px: f32,
+        pub glyph_count: usize,
+    }
+
+    /// Re-export of rust-allocated (stack based) `InlineTextRun` struct
+    #[repr(C)]
+    pub struct AzInlineTextRun {
+        pub node_id: AzNodeId,
+        pub glyph_start: usize,
+        pub glyph_end: usize,
+        pub font_size_px: f32,
+    }
+
     /// Re-export of rust-allocated (stack based) `InlineGlyph` struct
     #[repr(C)]
     pub struct AzInlineGlyph {
@@ -9521,6 +11214,16 @@ mod test_sizes {
         Exact(AzLayoutTop),
     }
 
+    /// Re-export of rust-allocated (stack based) `LayoutVerticalAlignValue` struct
+    #[repr(C, u8)]
+    pub enum AzLayoutVerticalAlignValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutVerticalAlign),
+    }
+
     /// Re-export of rust-allocated (stack based) `LayoutWidthValue` struct
     #[repr(C, u8)]
     pub enum AzLayoutWidthValue {
@@ -9771,6 +11474,16 @@ mod test_sizes {
         Exact(AzStyleTextAlign),
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleTextDecorationValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleTextDecorationValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleTextDecoration),
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleTextColorValue` struct
     #[repr(C, u8)]
     pub enum AzStyleTextColorValue {
@@ -9987,6 +11700,27 @@ mod test_sizes {
         pub callback: AzNodeGraphOnNodeFieldEditedCallback,
     }
 
+    /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeGraphZoomed` struct
+    #[repr(C)]
+    pub struct AzNodeGraphOnNodeGraphZoomed {
+        pub data: AzRefAny,
+        pub callback: AzNodeGraphOnNodeGraphZoomedCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `NodeGraphOnSelectionChanged` struct
+    #[repr(C)]
+    pub struct AzNodeGraphOnSelectionChanged {
+        pub data: AzRefAny,
+        pub callback: AzNodeGraphOnSelectionChangedCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `NodeGraphOnHistoryChanged` struct
+    #[repr(C)]
+    pub struct AzNodeGraphOnHistoryChanged {
+        pub data: AzRefAny,
+        pub callback: AzNodeGraphOnHistoryChangedCallback,
+    }
+
     /// Re-export of rust-allocated (stack based) `OutputNodeAndIndex` struct
     #[repr(C)]
     pub struct AzOutputNodeAndIndex {
@@ -10001,6 +11735,22 @@ mod test_sizes {
         pub input_index: usize,
     }
 
+    /// Connection from the output of one node to the input of another node
+    #[repr(C)]
+    pub struct AzNodeGraphConnection {
+        pub output: AzNodeGraphNodeId,
+        pub output_index: usize,
+        pub input: AzNodeGraphNodeId,
+        pub input_index: usize,
+    }
+
+    /// Node that was moved by `amount` (in graph coordinates)
+    #[repr(C)]
+    pub struct AzNodeGraphNodeMove {
+        pub node_id: AzNodeGraphNodeId,
+        pub amount: AzNodeDragAmount,
+    }
+
     /// Re-export of rust-allocated (stack based) `ListViewOnLazyLoadScroll` struct
     #[repr(C)]
     pub struct AzListViewOnLazyLoadScroll {
@@ -10022,6 +11772,167 @@ mod test_sizes {
         pub callback: AzListViewOnRowClickCallback,
     }
 
+    /// Re-export of rust-allocated (stack based) `ListViewOnRowSelect` struct
+    #[repr(C)]
+    pub struct AzListViewOnRowSelect {
+        pub data: AzRefAny,
+        pub callback: AzListViewOnRowSelectCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `ListViewOnColumnResize` struct
+    #[repr(C)]
+    pub struct AzListViewOnColumnResize {
+        pub data: AzRefAny,
+        pub callback: AzListViewOnColumnResizeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `ListViewRowProvider` struct
+    #[repr(C)]
+    pub struct AzListViewRowProvider {
+        pub data: AzRefAny,
+        pub callback: AzListViewRowProviderCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnExpand` struct
+    #[repr(C)]
+    pub struct AzTreeViewOnExpand {
+        pub data: AzRefAny,
+        pub callback: AzTreeViewOnExpandCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnSelect` struct
+    #[repr(C)]
+    pub struct AzTreeViewOnSelect {
+        pub data: AzRefAny,
+        pub callback: AzTreeViewOnSelectCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnLazyLoad` struct
+    #[repr(C)]
+    pub struct AzTreeViewOnLazyLoad {
+        pub data: AzRefAny,
+        pub callback: AzTreeViewOnLazyLoadCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnScroll` struct
+    #[repr(C)]
+    pub struct AzTreeViewOnScroll {
+        pub data: AzRefAny,
+        pub callback: AzTreeViewOnScrollCallback,
+    }
+
+    /// Rectangular range of selected cells
+    #[repr(C)]
+    pub struct AzDataGridRange {
+        pub anchor: AzDataGridCell,
+        pub cursor: AzDataGridCell,
+    }
+
+    /// Re-export of rust-allocated (stack based) `DataGridCellProvider` struct
+    #[repr(C)]
+    pub struct AzDataGridCellProvider {
+        pub data: AzRefAny,
+        pub callback: AzDataGridCellProviderCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `DataGridCellStyle` struct
+    #[repr(C)]
+    pub struct AzDataGridCellStyle {
+        pub data: AzRefAny,
+        pub callback: AzDataGridCellStyleCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `DataGridOnSelect` struct
+    #[repr(C)]
+    pub struct AzDataGridOnSelect {
+        pub data: AzRefAny,
+        pub callback: AzDataGridOnSelectCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `DataGridOnScroll` struct
+    #[repr(C)]
+    pub struct AzDataGridOnScroll {
+        pub data: AzRefAny,
+        pub callback: AzDataGridOnScrollCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `DataGridOnCellEdit` struct
+    #[repr(C)]
+    pub struct AzDataGridOnCellEdit {
+        pub data: AzRefAny,
+        pub callback: AzDataGridOnCellEditCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `DataGridOnColumnResize` struct
+    #[repr(C)]
+    pub struct AzDataGridOnColumnResize {
+        pub data: AzRefAny,
+        pub callback: AzDataGridOnColumnResizeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `DataGridOnRowResize` struct
+    #[repr(C)]
+    pub struct AzDataGridOnRowResize {
+        pub data: AzRefAny,
+        pub callback: AzDataGridOnRowResizeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `DataGridOnCopyRange` struct
+    #[repr(C)]
+    pub struct AzDataGridOnCopyRange {
+        pub data: AzRefAny,
+        pub callback: AzDataGridOnCopyRangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `DataGridOnClearRange` struct
+    #[repr(C)]
+    pub struct AzDataGridOnClearRange {
+        pub data: AzRefAny,
+        pub callback: AzDataGridOnClearRangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `DatePickerOnDateChange` struct
+    #[repr(C)]
+    pub struct AzDatePickerOnDateChange {
+        pub data: AzRefAny,
+        pub callback: AzDatePickerOnDateChangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TimePickerOnTimeChange` struct
+    #[repr(C)]
+    pub struct AzTimePickerOnTimeChange {
+        pub data: AzRefAny,
+        pub callback: AzTimePickerOnTimeChangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `DateTimePickerOnDateTimeChange` struct
+    #[repr(C)]
+    pub struct AzDateTimePickerOnDateTimeChange {
+        pub data: AzRefAny,
+        pub callback: AzDateTimePickerOnDateTimeChangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `DialogOnClose` struct
+    #[repr(C)]
+    pub struct AzDialogOnClose {
+        pub data: AzRefAny,
+        pub callback: AzDialogOnCloseCallback,
+    }
+
+    /// Size of a single pane, as reported by `on_resize`
+    #[repr(C)]
+    pub struct AzSplitterPaneState {
+        pub size: AzOptionF32,
+        pub collapsed: bool,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SplitterOnResize` struct
+    #[repr(C)]
+    pub struct AzSplitterOnResize {
+        pub data: AzRefAny,
+        pub callback: AzSplitterOnResizeCallback,
+    }
+
     /// Re-export of rust-allocated (stack based) `DropDownOnChoiceChange` struct
     #[repr(C)]
     pub struct AzDropDownOnChoiceChange {
@@ -10166,6 +12077,15 @@ mod test_sizes {
         pub high_quality_aa: bool,
     }
 
+    /// Location of the start tag of an `XmlNode` in the XML source
+    #[repr(C)]
+    pub struct AzXmlSpan {
+        pub file: usize,
+        pub start: usize,
+        pub end: usize,
+        pub pos: AzSvgParseErrorPosition,
+    }
+
     /// Re-export of rust-allocated (stack based) `InstantPtr` struct
     #[repr(C)]
     pub struct AzInstantPtr {
@@ -10197,6 +12117,24 @@ mod test_sizes {
         pub callback: AzWriteBackCallback,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<TreeViewItem>`
+    #[repr(C)]
+    pub struct AzTreeViewItemVec {
+        pub(crate) ptr: *const AzTreeViewItem,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTreeViewItemVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<SplitterPaneState>`
+    #[repr(C)]
+    pub struct AzSplitterPaneStateVec {
+        pub(crate) ptr: *const AzSplitterPaneState,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzSplitterPaneStateVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<LogicalRect>`
     #[repr(C)]
     pub struct AzLogicalRectVec {
@@ -10206,6 +12144,15 @@ mod test_sizes {
         pub destructor: AzLogicalRectVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<NodeGraphNodeId>`
+    #[repr(C)]
+    pub struct AzNodeGraphNodeIdVec {
+        pub(crate) ptr: *const AzNodeGraphNodeId,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzNodeGraphNodeIdVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<InputOutputTypeId>`
     #[repr(C)]
     pub struct AzInputOutputTypeIdVec {
@@ -10269,6 +12216,24 @@ mod test_sizes {
         pub destructor: AzInlineGlyphVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<TextLineMetrics>`
+    #[repr(C)]
+    pub struct AzTextLineMetricsVec {
+        pub(crate) ptr: *const AzTextLineMetrics,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTextLineMetricsVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<InlineTextRun>`
+    #[repr(C)]
+    pub struct AzInlineTextRunVec {
+        pub(crate) ptr: *const AzInlineTextRun,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzInlineTextRunVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<InlineTextHit>`
     #[repr(C)]
     pub struct AzInlineTextHitVec {
@@ -10386,6 +12351,15 @@ mod test_sizes {
         pub destructor: AzScanCodeVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<CssUnicodeRange>`
+    #[repr(C)]
+    pub struct AzCssUnicodeRangeVec {
+        pub(crate) ptr: *const AzCssUnicodeRange,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzCssUnicodeRangeVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<u16>`
     #[repr(C)]
     pub struct AzU16Vec {
@@ -10483,6 +12457,34 @@ mod test_sizes {
         Some(AzSvgPoint),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionTreeViewOnExpand` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTreeViewOnExpand {
+        None,
+        Some(AzTreeViewOnExpand),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTreeViewOnSelect` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTreeViewOnSelect {
+        None,
+        Some(AzTreeViewOnSelect),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTreeViewOnLazyLoad` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTreeViewOnLazyLoad {
+        None,
+        Some(AzTreeViewOnLazyLoad),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTreeViewOnScroll` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTreeViewOnScroll {
+        None,
+        Some(AzTreeViewOnScroll),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionListViewOnRowClick` struct
     #[repr(C, u8)]
     pub enum AzOptionListViewOnRowClick {
@@ -10490,6 +12492,146 @@ mod test_sizes {
         Some(AzListViewOnRowClick),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionListViewOnRowSelect` struct
+    #[repr(C, u8)]
+    pub enum AzOptionListViewOnRowSelect {
+        None,
+        Some(AzListViewOnRowSelect),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionListViewOnColumnResize` struct
+    #[repr(C, u8)]
+    pub enum AzOptionListViewOnColumnResize {
+        None,
+        Some(AzListViewOnColumnResize),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionListViewRowProvider` struct
+    #[repr(C, u8)]
+    pub enum AzOptionListViewRowProvider {
+        None,
+        Some(AzListViewRowProvider),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionDataGridRange` struct
+    #[repr(C, u8)]
+    pub enum AzOptionDataGridRange {
+        None,
+        Some(AzDataGridRange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionDataGridCellProvider` struct
+    #[repr(C, u8)]
+    pub enum AzOptionDataGridCellProvider {
+        None,
+        Some(AzDataGridCellProvider),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionDataGridCellStyle` struct
+    #[repr(C, u8)]
+    pub enum AzOptionDataGridCellStyle {
+        None,
+        Some(AzDataGridCellStyle),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionDataGridOnSelect` struct
+    #[repr(C, u8)]
+    pub enum AzOptionDataGridOnSelect {
+        None,
+        Some(AzDataGridOnSelect),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionDataGridOnScroll` struct
+    #[repr(C, u8)]
+    pub enum AzOptionDataGridOnScroll {
+        None,
+        Some(AzDataGridOnScroll),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionDataGridOnCellEdit` struct
+    #[repr(C, u8)]
+    pub enum AzOptionDataGridOnCellEdit {
+        None,
+        Some(AzDataGridOnCellEdit),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionDataGridOnColumnResize` struct
+    #[repr(C, u8)]
+    pub enum AzOptionDataGridOnColumnResize {
+        None,
+        Some(AzDataGridOnColumnResize),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionDataGridOnRowResize` struct
+    #[repr(C, u8)]
+    pub enum AzOptionDataGridOnRowResize {
+        None,
+        Some(AzDataGridOnRowResize),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionDataGridOnCopyRange` struct
+    #[repr(C, u8)]
+    pub enum AzOptionDataGridOnCopyRange {
+        None,
+        Some(AzDataGridOnCopyRange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionDataGridOnClearRange` struct
+    #[repr(C, u8)]
+    pub enum AzOptionDataGridOnClearRange {
+        None,
+        Some(AzDataGridOnClearRange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionDate` struct
+    #[repr(C, u8)]
+    pub enum AzOptionDate {
+        None,
+        Some(AzDate),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTime` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTime {
+        None,
+        Some(AzTime),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionDatePickerOnDateChange` struct
+    #[repr(C, u8)]
+    pub enum AzOptionDatePickerOnDateChange {
+        None,
+        Some(AzDatePickerOnDateChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTimePickerOnTimeChange` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTimePickerOnTimeChange {
+        None,
+        Some(AzTimePickerOnTimeChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionDateTimePickerOnDateTimeChange` struct
+    #[repr(C, u8)]
+    pub enum AzOptionDateTimePickerOnDateTimeChange {
+        None,
+        Some(AzDateTimePickerOnDateTimeChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionDialogOnClose` struct
+    #[repr(C, u8)]
+    pub enum AzOptionDialogOnClose {
+        None,
+        Some(AzDialogOnClose),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionSplitterOnResize` struct
+    #[repr(C, u8)]
+    pub enum AzOptionSplitterOnResize {
+        None,
+        Some(AzSplitterOnResize),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionListViewOnColumnClick` struct
     #[repr(C, u8)]
     pub enum AzOptionListViewOnColumnClick {
@@ -10518,6 +12660,27 @@ mod test_sizes {
         Some(AzDropDownOnChoiceChange),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionNodeGraphOnNodeGraphZoomed` struct
+    #[repr(C, u8)]
+    pub enum AzOptionNodeGraphOnNodeGraphZoomed {
+        None,
+        Some(AzNodeGraphOnNodeGraphZoomed),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionNodeGraphOnSelectionChanged` struct
+    #[repr(C, u8)]
+    pub enum AzOptionNodeGraphOnSelectionChanged {
+        None,
+        Some(AzNodeGraphOnSelectionChanged),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionNodeGraphOnHistoryChanged` struct
+    #[repr(C, u8)]
+    pub enum AzOptionNodeGraphOnHistoryChanged {
+        None,
+        Some(AzNodeGraphOnHistoryChanged),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionNodeGraphOnNodeAdded` struct
     #[repr(C, u8)]
     pub enum AzOptionNodeGraphOnNodeAdded {
@@ -11013,6 +13176,15 @@ mod test_sizes {
         pub cb: AzMarshaledLayoutCallbackInner,
     }
 
+    /// Size of a string measured without laying out a DOM
+    #[repr(C)]
+    pub struct AzTextMetrics {
+        pub width: f32,
+        pub height: f32,
+        pub line_count: usize,
+        pub lines: AzTextLineMetricsVec,
+    }
+
     /// Re-export of rust-allocated (stack based) `InlineTextContents` struct
     #[repr(C)]
     pub struct AzInlineTextContents {
@@ -11070,6 +13242,7 @@ mod test_sizes {
         pub image_cache: *const c_void,
         pub gl_context: *const AzOptionGl,
         pub system_fonts: *const c_void,
+        pub callbacks: *const c_void,
         pub _reserved_ref: *const c_void,
         pub _reserved_mut: *mut c_void,
     }
@@ -11099,6 +13272,13 @@ mod test_sizes {
         pub keys: AzVirtualKeyCodeVec,
     }
 
+    /// Keyboard shortcut that is not attached to a menu item: when the key combination is pressed, the callback is invoked. See `Dom::with_keyboard_shortcuts()`
+    #[repr(C)]
+    pub struct AzKeyboardShortcut {
+        pub keys: AzVirtualKeyCodeCombo,
+        pub callback: AzMenuCallback,
+    }
+
     /// Re-export of rust-allocated (stack based) `CssPathPseudoSelector` struct
     #[repr(C, u8)]
     pub enum AzCssPathPseudoSelector {
@@ -11254,6 +13434,9 @@ mod test_sizes {
         pub on_node_input_disconnected: AzOptionNodeGraphOnNodeInputDisconnected,
         pub on_node_output_disconnected: AzOptionNodeGraphOnNodeOutputDisconnected,
         pub on_node_field_edited: AzOptionNodeGraphOnNodeFieldEdited,
+        pub on_node_graph_zoomed: AzOptionNodeGraphOnNodeGraphZoomed,
+        pub on_selection_changed: AzOptionNodeGraphOnSelectionChanged,
+        pub on_history_changed: AzOptionNodeGraphOnHistoryChanged,
     }
 
     /// Re-export of rust-allocated (stack based) `InputConnection` struct
@@ -11275,6 +13458,73 @@ mod test_sizes {
     pub struct AzListViewRow {
         pub cells: AzDomVec,
         pub height: AzOptionPixelValueNoPercent,
+        pub is_selected: bool,
+    }
+
+    /// Hierarchical tree of items with expand / collapse and selection. Only the rows between `first_visible_row` and `first_visible_row + max_visible_rows` are rendered. The callbacks only report what the user did, the application applies the change to its own copy of the TreeView (`set_expanded`, `apply_selection`, `set_children`, `set_first_visible_row`)
+    #[repr(C)]
+    pub struct AzTreeView {
+        pub items: AzTreeViewItemVec,
+        pub selection_mode: AzTreeViewSelectionMode,
+        pub first_visible_row: usize,
+        pub max_visible_rows: usize,
+        pub focused_item: AzOptionUsize,
+        pub selection_anchor: AzOptionUsize,
+        pub on_expand: AzOptionTreeViewOnExpand,
+        pub on_select: AzOptionTreeViewOnSelect,
+        pub on_lazy_load: AzOptionTreeViewOnLazyLoad,
+        pub on_scroll: AzOptionTreeViewOnScroll,
+    }
+
+    /// State of a `DatePicker`, reported by `on_date_change`
+    #[repr(C)]
+    pub struct AzDatePickerState {
+        pub selected: AzOptionDate,
+        pub cursor: AzDate,
+    }
+
+    /// Month calendar to select a single date. Selecting a date and navigating between months is reported through `on_date_change`, the application stores the new `DatePickerState` for the next frame. Arrow keys, PageUp / PageDown and Home / End move the cursor, Enter / Space select the date under the cursor
+    #[repr(C)]
+    pub struct AzDatePicker {
+        pub state: AzDatePickerState,
+        pub minimum_date: AzOptionDate,
+        pub maximum_date: AzOptionDate,
+        pub first_day_of_week: AzWeekday,
+        pub on_date_change: AzOptionDatePickerOnDateChange,
+    }
+
+    /// Hour / minute / second input with spin buttons, changes are reported through `on_time_change`
+    #[repr(C)]
+    pub struct AzTimePicker {
+        pub time: AzTime,
+        pub show_seconds: bool,
+        pub on_time_change: AzOptionTimePickerOnTimeChange,
+    }
+
+    /// State of a `DateTimePicker`, reported by `on_date_time_change`
+    #[repr(C)]
+    pub struct AzDateTimePickerState {
+        pub date: AzDatePickerState,
+        pub time: AzTime,
+        pub is_open: bool,
+    }
+
+    /// Drop-down field showing the selected date (and time), which opens a `DatePicker` (and `TimePicker`) below the field when clicked. Opening / closing the popup and changing the date or time is reported through `on_date_time_change`
+    #[repr(C)]
+    pub struct AzDateTimePicker {
+        pub state: AzDateTimePickerState,
+        pub minimum_date: AzOptionDate,
+        pub maximum_date: AzOptionDate,
+        pub first_day_of_week: AzWeekday,
+        pub show_time: bool,
+        pub show_seconds: bool,
+        pub on_date_time_change: AzOptionDateTimePickerOnDateTimeChange,
+    }
+
+    /// Sizes of all panes of a `Splitter`
+    #[repr(C)]
+    pub struct AzSplitterState {
+        pub panes: AzSplitterPaneStateVec,
     }
 
     /// Re-export of rust-allocated (stack based) `StyledNode` struct
@@ -11453,6 +13703,15 @@ mod test_sizes {
         pub destructor: AzOutputConnectionVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<KeyboardShortcut>`
+    #[repr(C)]
+    pub struct AzKeyboardShortcutVec {
+        pub(crate) ptr: *const AzKeyboardShortcut,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzKeyboardShortcutVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<TessellatedSvgNode>`
     #[repr(C)]
     pub struct AzTessellatedSvgNodeVec {
@@ -11565,6 +13824,13 @@ mod test_sizes {
         Some(AzThreadReceiveMsg),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionTextMetrics` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTextMetrics {
+        None,
+        Some(AzTextMetrics),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionTaskBarIcon` struct
     #[repr(C, u8)]
     pub enum AzOptionTaskBarIcon {
@@ -11767,6 +14033,7 @@ mod test_sizes {
         pub states: AzAccessibilityStateVec,
         pub accelerator: AzOptionVirtualKeyCodeCombo,
         pub default_action: AzOptionString,
+        pub description: AzOptionString,
     }
 
     /// Re-export of rust-allocated (stack based) `IdOrClass` struct
@@ -11799,6 +14066,13 @@ mod test_sizes {
         Children,
     }
 
+    /// `url("fonts/Roboto.ttf") format("truetype")`
+    #[repr(C)]
+    pub struct AzCssFontFaceUrl {
+        pub url: AzString,
+        pub format: AzOptionString,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleBackgroundContent` struct
     #[repr(C, u8)]
     pub enum AzStyleBackgroundContent {
@@ -11928,7 +14202,17 @@ mod test_sizes {
         pub color: AzColorU,
     }
 
-    /// Re-export of rust-allocated (stack based) `ListView` struct
+    /// Field of a node that was changed from `old_value` to `new_value`
+    #[repr(C)]
+    pub struct AzNodeGraphFieldEdit {
+        pub node_id: AzNodeGraphNodeId,
+        pub node_type: AzNodeTypeId,
+        pub field_idx: usize,
+        pub old_value: AzNodeTypeFieldValue,
+        pub new_value: AzNodeTypeFieldValue,
+    }
+
+    /// Table with a header row. If a `row_provider` is set, the ListView is virtualized: only the rows that fit into the visible area are requested from the row provider and rendered. The callbacks report scrolling, selection and column resizing, the application stores the new `first_visible_row`, selection state and `column_widths` for the next frame
     #[repr(C)]
     pub struct AzListView {
         pub columns: AzStringVec,
@@ -11940,6 +14224,16 @@ mod test_sizes {
         pub on_lazy_load_scroll: AzOptionListViewOnLazyLoadScroll,
         pub on_column_click: AzOptionListViewOnColumnClick,
         pub on_row_click: AzOptionListViewOnRowClick,
+        pub row_count: usize,
+        pub row_height: f32,
+        pub first_visible_row: usize,
+        pub column_widths: AzF32Vec,
+        pub sort_descending: bool,
+        pub focused_row: AzOptionUsize,
+        pub selection_anchor: AzOptionUsize,
+        pub row_provider: AzOptionListViewRowProvider,
+        pub on_row_select: AzOptionListViewOnRowSelect,
+        pub on_column_resize: AzOptionListViewOnColumnResize,
     }
 
     /// Re-export of rust-allocated (stack based) `ListViewState` struct
@@ -11951,12 +14245,51 @@ mod test_sizes {
         pub scroll_offset: AzPixelValueNoPercent,
         pub current_scroll_position: AzLogicalPosition,
         pub current_content_height: AzLogicalSize,
+        pub row_count: usize,
+        pub first_visible_row: usize,
+        pub focused_row: AzOptionUsize,
+        pub selection_anchor: AzOptionUsize,
     }
 
-    /// Re-export of rust-allocated (stack based) `TreeView` struct
+    /// Re-export of rust-allocated (stack based) `TreeViewItem` struct
     #[repr(C)]
-    pub struct AzTreeView {
-        pub root: AzString,
+    pub struct AzTreeViewItem {
+        pub id: usize,
+        pub label: AzString,
+        pub icon: AzOptionImageRef,
+        pub children: AzTreeViewItemVec,
+        pub has_lazy_children: bool,
+        pub is_expanded: bool,
+        pub is_selected: bool,
+    }
+
+    /// Spreadsheet-like grid of text cells. The DataGrid does not store any cell data: the text of each visible cell is requested from the `cell_provider`, only the cells that fit into the visible area are rendered. Scrolling, selection, resizing and editing are reported through the callbacks, the application stores the new state for the next frame
+    #[repr(C)]
+    pub struct AzDataGrid {
+        pub row_count: usize,
+        pub column_count: usize,
+        pub column_headers: AzStringVec,
+        pub column_widths: AzF32Vec,
+        pub row_heights: AzF32Vec,
+        pub first_visible_row: usize,
+        pub first_visible_column: usize,
+        pub selection: AzOptionDataGridRange,
+        pub cell_provider: AzOptionDataGridCellProvider,
+        pub cell_style: AzOptionDataGridCellStyle,
+        pub on_select: AzOptionDataGridOnSelect,
+        pub on_scroll: AzOptionDataGridOnScroll,
+        pub on_cell_edit: AzOptionDataGridOnCellEdit,
+        pub on_column_resize: AzOptionDataGridOnColumnResize,
+        pub on_row_resize: AzOptionDataGridOnRowResize,
+        pub on_copy_range: AzOptionDataGridOnCopyRange,
+        pub on_clear_range: AzOptionDataGridOnClearRange,
+    }
+
+    /// Result of a `Dialog`, reported by `on_close`
+    #[repr(C)]
+    pub struct AzDialogResult {
+        pub button: AzDialogButton,
+        pub input: AzString,
     }
 
     /// Re-export of rust-allocated (stack based) `DropDown` struct
@@ -12296,6 +14629,13 @@ mod test_sizes {
         pub selectors: AzCssPathSelectorVec,
     }
 
+    /// Single entry of the `src: ...` list in a `@font-face` rule
+    #[repr(C, u8)]
+    pub enum AzCssFontFaceSource {
+        Url(AzCssFontFaceUrl),
+        Local(AzString),
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleBackgroundContentVecValue` struct
     #[repr(C, u8)]
     pub enum AzStyleBackgroundContentVecValue {
@@ -12393,6 +14733,8 @@ mod test_sizes {
         Filter(AzStyleFilterVecValue),
         BackdropFilter(AzStyleFilterVecValue),
         TextShadow(AzStyleBoxShadowValue),
+        VerticalAlign(AzLayoutVerticalAlignValue),
+        TextDecoration(AzStyleTextDecorationValue),
     }
 
     /// Re-export of rust-allocated (stack based) `FileInputStateWrapper` struct
@@ -12521,6 +14863,7 @@ mod test_sizes {
         pub attributes: AzStringPairVec,
         pub children: AzXmlNodeVec,
         pub text: AzOptionString,
+        pub span: AzXmlSpan,
     }
 
     /// Wrapper over a Rust-allocated `Vec<NodeTypeIdInfoMap>`
@@ -12586,6 +14929,15 @@ mod test_sizes {
         pub destructor: AzSvgSimpleNodeVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<CssFontFaceSource>`
+    #[repr(C)]
+    pub struct AzCssFontFaceSourceVec {
+        pub(crate) ptr: *const AzCssFontFaceSource,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzCssFontFaceSourceVecDestructor,
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionCssProperty` struct
     #[repr(C, u8)]
     pub enum AzOptionCssProperty {
@@ -12672,6 +15024,7 @@ mod test_sizes {
         pub font_size_px: f32,
         pub last_word_index: usize,
         pub baseline_descender_px: f32,
+        pub runs: AzInlineTextRunVec,
     }
 
     /// CSS path to set the keyboard input focus
@@ -12721,6 +15074,16 @@ mod test_sizes {
         pub default_value: AzCssProperty,
     }
 
+    /// Parsed contents of an `@font-face { ... }` block
+    #[repr(C)]
+    pub struct AzCssFontFace {
+        pub font_family: AzString,
+        pub src: AzCssFontFaceSourceVec,
+        pub font_weight: u16,
+        pub font_style: AzCssFontFaceStyle,
+        pub unicode_range: AzCssUnicodeRangeVec,
+    }
+
     /// Re-export of rust-allocated (stack based) `Node` struct
     #[repr(C)]
     pub struct AzNode {
@@ -12758,6 +15121,15 @@ mod test_sizes {
         pub destructor: AzNodeDataInlineCssPropertyVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<CssFontFace>`
+    #[repr(C)]
+    pub struct AzCssFontFaceVec {
+        pub(crate) ptr: *const AzCssFontFace,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzCssFontFaceVecDestructor,
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionWindowState` struct
     #[repr(C, u8)]
     pub enum AzOptionWindowState {
@@ -12896,6 +15268,26 @@ mod test_sizes {
         pub node: AzNode,
     }
 
+    /// Single modification of the node graph, recorded in the undo history
+    #[repr(C, u8)]
+    pub enum AzNodeGraphEditOp {
+        AddNode(AzNodeIdNodeMap),
+        RemoveNode(AzNodeIdNodeMap),
+        MoveNode(AzNodeGraphNodeMove),
+        Connect(AzNodeGraphConnection),
+        Disconnect(AzNodeGraphConnection),
+        EditField(AzNodeGraphFieldEdit),
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<NodeGraphEditOp>`
+    #[repr(C)]
+    pub struct AzNodeGraphEditOpVec {
+        pub(crate) ptr: *const AzNodeGraphEditOp,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzNodeGraphEditOpVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<NodeIdNodeMap>`
     #[repr(C)]
     pub struct AzNodeIdNodeMapVec {
@@ -12953,6 +15345,13 @@ mod test_sizes {
         pub total_children: usize,
     }
 
+    /// Content of a `Tooltip`: either a plain text or a custom DOM
+    #[repr(C, u8)]
+    pub enum AzTooltipContent {
+        Text(AzString),
+        Dom(AzDom),
+    }
+
     /// Re-export of rust-allocated (stack based) `CssRuleBlock` struct
     #[repr(C)]
     pub struct AzCssRuleBlock {
@@ -12975,18 +15374,19 @@ mod test_sizes {
         pub content: AzDom,
     }
 
-    /// Re-export of rust-allocated (stack based) `NodeGraph` struct
+    /// One undoable user action (for example "paste three nodes"), consisting of one or more operations
     #[repr(C)]
-    pub struct AzNodeGraph {
-        pub node_types: AzNodeTypeIdInfoMapVec,
-        pub input_output_types: AzInputOutputTypeIdInfoMapVec,
-        pub nodes: AzNodeIdNodeMapVec,
-        pub allow_multiple_root_nodes: bool,
-        pub offset: AzLogicalPosition,
-        pub style: AzNodeGraphStyle,
-        pub callbacks: AzNodeGraphCallbacks,
-        pub add_node_str: AzString,
-        pub scale_factor: f32,
+    pub struct AzNodeGraphEdit {
+        pub ops: AzNodeGraphEditOpVec,
+    }
+
+    /// Pane of a `Splitter`
+    #[repr(C)]
+    pub struct AzSplitterPane {
+        pub content: AzDom,
+        pub state: AzSplitterPaneState,
+        pub min_size: f32,
+        pub max_size: AzOptionF32,
     }
 
     /// Re-export of rust-allocated (stack based) `StyledDom` struct
@@ -13005,6 +15405,24 @@ mod test_sizes {
         pub css_property_cache: AzCssPropertyCache,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<SplitterPane>`
+    #[repr(C)]
+    pub struct AzSplitterPaneVec {
+        pub(crate) ptr: *const AzSplitterPane,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzSplitterPaneVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<NodeGraphEdit>`
+    #[repr(C)]
+    pub struct AzNodeGraphEditVec {
+        pub(crate) ptr: *const AzNodeGraphEdit,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzNodeGraphEditVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `CssRuleBlock`
     #[repr(C)]
     pub struct AzCssRuleBlockVec {
@@ -13049,12 +15467,47 @@ mod test_sizes {
         pub virtual_scroll_offset: AzLogicalPosition,
     }
 
+    /// Hover hint of a DOM node, see `Dom::with_tooltip()`
+    #[repr(C)]
+    pub struct AzTooltip {
+        pub content: AzTooltipContent,
+        pub delay: AzDuration,
+    }
+
     /// Re-export of rust-allocated (stack based) `Stylesheet` struct
     #[repr(C)]
     pub struct AzStylesheet {
         pub rules: AzCssRuleBlockVec,
     }
 
+    /// Undo / redo stacks of the node graph: the last edit is at the end of each stack
+    #[repr(C)]
+    pub struct AzNodeGraphHistory {
+        pub undo: AzNodeGraphEditVec,
+        pub redo: AzNodeGraphEditVec,
+    }
+
+    /// Modal dialog rendered inside of the window. Dialogs are stateless: the application stores the `Dialog` while it is open and removes it again in `on_close`. Use `dom_over()` to render the dialog on top of the rest of the UI: the content below the dialog is made inert, so the keyboard focus can't leave the dialog and clicks don't reach the content anymore
+    #[repr(C)]
+    pub struct AzDialog {
+        pub title: AzString,
+        pub message: AzString,
+        pub icon: AzDialogIcon,
+        pub buttons: AzDialogButtons,
+        pub input: AzOptionString,
+        pub content: AzOptionDom,
+        pub on_close: AzOptionDialogOnClose,
+    }
+
+    /// Container which splits its area into resizable panes. Panes with a fixed size keep their size when the splitter is resized, the remaining space is distributed between the flexible panes. Dragging a handle resizes the panes on both sides of it within their `min_size` / `max_size`; if both panes are flexible, the first one is given a fixed size. Double-clicking a handle (or pressing Enter while it is focused) collapses the fixed pane next to it
+    #[repr(C)]
+    pub struct AzSplitter {
+        pub direction: AzSplitterDirection,
+        pub panes: AzSplitterPaneVec,
+        pub handle_size: f32,
+        pub on_resize: AzOptionSplitterOnResize,
+    }
+
     /// Wrapper over a Rust-allocated `Stylesheet`
     #[repr(C)]
     pub struct AzStylesheetVec {
@@ -13082,6 +15535,30 @@ mod test_sizes {
     #[repr(C)]
     pub struct AzCss {
         pub stylesheets: AzStylesheetVec,
+        pub font_faces: AzCssFontFaceVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `NodeGraph` struct
+    #[repr(C)]
+    pub struct AzNodeGraph {
+        pub node_types: AzNodeTypeIdInfoMapVec,
+        pub input_output_types: AzInputOutputTypeIdInfoMapVec,
+        pub nodes: AzNodeIdNodeMapVec,
+        pub allow_multiple_root_nodes: bool,
+        pub offset: AzLogicalPosition,
+        pub style: AzNodeGraphStyle,
+        pub callbacks: AzNodeGraphCallbacks,
+        pub add_node_str: AzString,
+        pub scale_factor: f32,
+        pub selected_nodes: AzNodeGraphNodeIdVec,
+        pub history: AzNodeGraphHistory,
+    }
+
+    /// Re-export of rust-allocated (stack based) `ResultNodeGraphNodeGraphError` struct
+    #[repr(C, u8)]
+    pub enum AzResultNodeGraphNodeGraphError {
+        Ok(AzNodeGraph),
+        Err(AzNodeGraphError),
     }
 
     use core::ffi::c_void;
@@ -13152,6 +15629,8 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::MenuItemState>(), "AzMenuItemState"), (Layout::new::<AzMenuItemState>(), "AzMenuItemState"));
         assert_eq!((Layout::new::<azul_impl::css::NodeTypeTag>(), "AzNodeTypeKey"), (Layout::new::<AzNodeTypeKey>(), "AzNodeTypeKey"));
         assert_eq!((Layout::new::<azul_impl::css::CssNthChildPattern>(), "AzCssNthChildPattern"), (Layout::new::<AzCssNthChildPattern>(), "AzCssNthChildPattern"));
+        assert_eq!((Layout::new::<azul_impl::css::CssFontFaceStyle>(), "AzCssFontFaceStyle"), (Layout::new::<AzCssFontFaceStyle>(), "AzCssFontFaceStyle"));
+        assert_eq!((Layout::new::<azul_impl::css::CssUnicodeRange>(), "AzCssUnicodeRange"), (Layout::new::<AzCssUnicodeRange>(), "AzCssUnicodeRange"));
         assert_eq!((Layout::new::<azul_impl::css::CssPropertyType>(), "AzCssPropertyType"), (Layout::new::<AzCssPropertyType>(), "AzCssPropertyType"));
         assert_eq!((Layout::new::<azul_impl::css::ColorU>(), "AzColorU"), (Layout::new::<AzColorU>(), "AzColorU"));
         assert_eq!((Layout::new::<azul_impl::css::SizeMetric>(), "AzSizeMetric"), (Layout::new::<AzSizeMetric>(), "AzSizeMetric"));
@@ -13166,6 +15645,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::LayoutFloat>(), "AzLayoutFloat"), (Layout::new::<AzLayoutFloat>(), "AzLayoutFloat"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutJustifyContent>(), "AzLayoutJustifyContent"), (Layout::new::<AzLayoutJustifyContent>(), "AzLayoutJustifyContent"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutPosition>(), "AzLayoutPosition"), (Layout::new::<AzLayoutPosition>(), "AzLayoutPosition"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutVerticalAlign>(), "AzLayoutVerticalAlign"), (Layout::new::<AzLayoutVerticalAlign>(), "AzLayoutVerticalAlign"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutFlexWrap>(), "AzLayoutFlexWrap"), (Layout::new::<AzLayoutFlexWrap>(), "AzLayoutFlexWrap"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutOverflow>(), "AzLayoutOverflow"), (Layout::new::<AzLayoutOverflow>(), "AzLayoutOverflow"));
         assert_eq!((Layout::new::<azul_impl::css::AngleMetric>(), "AzAngleMetric"), (Layout::new::<AzAngleMetric>(), "AzAngleMetric"));
@@ -13178,6 +15658,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleCursor>(), "AzStyleCursor"), (Layout::new::<AzStyleCursor>(), "AzStyleCursor"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackfaceVisibility>(), "AzStyleBackfaceVisibility"), (Layout::new::<AzStyleBackfaceVisibility>(), "AzStyleBackfaceVisibility"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTextAlign>(), "AzStyleTextAlign"), (Layout::new::<AzStyleTextAlign>(), "AzStyleTextAlign"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTextDecoration>(), "AzStyleTextDecoration"), (Layout::new::<AzStyleTextDecoration>(), "AzStyleTextDecoration"));
         assert_eq!((Layout::new::<crate::widgets::ribbon::Ribbon>(), "AzRibbon"), (Layout::new::<AzRibbon>(), "AzRibbon"));
         assert_eq!((Layout::new::<crate::widgets::ribbon::RibbonOnTabClickedCallback>(), "AzRibbonOnTabClickedCallback"), (Layout::new::<AzRibbonOnTabClickedCallback>(), "AzRibbonOnTabClickedCallback"));
         assert_eq!((Layout::new::<crate::widgets::file_input::FileInputOnPathChangeCallback>(), "AzFileInputOnPathChangeCallback"), (Layout::new::<AzFileInputOnPathChangeCallback>(), "AzFileInputOnPathChangeCallback"));
@@ -13204,15 +15685,51 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::node_graph::OnNodeInputDisconnectedCallback>(), "AzNodeGraphOnNodeInputDisconnectedCallback"), (Layout::new::<AzNodeGraphOnNodeInputDisconnectedCallback>(), "AzNodeGraphOnNodeInputDisconnectedCallback"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OnNodeOutputDisconnectedCallback>(), "AzNodeGraphOnNodeOutputDisconnectedCallback"), (Layout::new::<AzNodeGraphOnNodeOutputDisconnectedCallback>(), "AzNodeGraphOnNodeOutputDisconnectedCallback"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OnNodeFieldEditedCallback>(), "AzNodeGraphOnNodeFieldEditedCallback"), (Layout::new::<AzNodeGraphOnNodeFieldEditedCallback>(), "AzNodeGraphOnNodeFieldEditedCallback"));
+        assert_eq!((Layout::new::<crate::widgets::node_graph::OnNodeGraphZoomedCallback>(), "AzNodeGraphOnNodeGraphZoomedCallback"), (Layout::new::<AzNodeGraphOnNodeGraphZoomedCallback>(), "AzNodeGraphOnNodeGraphZoomedCallback"));
+        assert_eq!((Layout::new::<crate::widgets::node_graph::OnNodeGraphSelectionChangedCallback>(), "AzNodeGraphOnSelectionChangedCallback"), (Layout::new::<AzNodeGraphOnSelectionChangedCallback>(), "AzNodeGraphOnSelectionChangedCallback"));
+        assert_eq!((Layout::new::<crate::widgets::node_graph::OnNodeGraphHistoryChangedCallback>(), "AzNodeGraphOnHistoryChangedCallback"), (Layout::new::<AzNodeGraphOnHistoryChangedCallback>(), "AzNodeGraphOnHistoryChangedCallback"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::InputOutputTypeId>(), "AzInputOutputTypeId"), (Layout::new::<AzInputOutputTypeId>(), "AzInputOutputTypeId"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeTypeId>(), "AzNodeTypeId"), (Layout::new::<AzNodeTypeId>(), "AzNodeTypeId"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeGraphNodeId>(), "AzNodeGraphNodeId"), (Layout::new::<AzNodeGraphNodeId>(), "AzNodeGraphNodeId"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodePosition>(), "AzNodePosition"), (Layout::new::<AzNodePosition>(), "AzNodePosition"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::GraphDragAmount>(), "AzGraphDragAmount"), (Layout::new::<AzGraphDragAmount>(), "AzGraphDragAmount"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeDragAmount>(), "AzNodeDragAmount"), (Layout::new::<AzNodeDragAmount>(), "AzNodeDragAmount"));
+        assert_eq!((Layout::new::<crate::widgets::node_graph::NodeGraphError>(), "AzNodeGraphError"), (Layout::new::<AzNodeGraphError>(), "AzNodeGraphError"));
+        assert_eq!((Layout::new::<crate::widgets::list_view::ListViewSelectionChange>(), "AzListViewSelectionChange"), (Layout::new::<AzListViewSelectionChange>(), "AzListViewSelectionChange"));
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewOnLazyLoadScrollCallback>(), "AzListViewOnLazyLoadScrollCallback"), (Layout::new::<AzListViewOnLazyLoadScrollCallback>(), "AzListViewOnLazyLoadScrollCallback"));
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewOnColumnClickCallback>(), "AzListViewOnColumnClickCallback"), (Layout::new::<AzListViewOnColumnClickCallback>(), "AzListViewOnColumnClickCallback"));
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewOnRowClickCallback>(), "AzListViewOnRowClickCallback"), (Layout::new::<AzListViewOnRowClickCallback>(), "AzListViewOnRowClickCallback"));
+        assert_eq!((Layout::new::<crate::widgets::list_view::ListViewOnRowSelectCallback>(), "AzListViewOnRowSelectCallback"), (Layout::new::<AzListViewOnRowSelectCallback>(), "AzListViewOnRowSelectCallback"));
+        assert_eq!((Layout::new::<crate::widgets::list_view::ListViewOnColumnResizeCallback>(), "AzListViewOnColumnResizeCallback"), (Layout::new::<AzListViewOnColumnResizeCallback>(), "AzListViewOnColumnResizeCallback"));
+        assert_eq!((Layout::new::<crate::widgets::list_view::ListViewRowProviderCallback>(), "AzListViewRowProviderCallback"), (Layout::new::<AzListViewRowProviderCallback>(), "AzListViewRowProviderCallback"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewSelectionMode>(), "AzTreeViewSelectionMode"), (Layout::new::<AzTreeViewSelectionMode>(), "AzTreeViewSelectionMode"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewSelectionChange>(), "AzTreeViewSelectionChange"), (Layout::new::<AzTreeViewSelectionChange>(), "AzTreeViewSelectionChange"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewOnExpandCallback>(), "AzTreeViewOnExpandCallback"), (Layout::new::<AzTreeViewOnExpandCallback>(), "AzTreeViewOnExpandCallback"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewOnSelectCallback>(), "AzTreeViewOnSelectCallback"), (Layout::new::<AzTreeViewOnSelectCallback>(), "AzTreeViewOnSelectCallback"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewOnLazyLoadCallback>(), "AzTreeViewOnLazyLoadCallback"), (Layout::new::<AzTreeViewOnLazyLoadCallback>(), "AzTreeViewOnLazyLoadCallback"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewOnScrollCallback>(), "AzTreeViewOnScrollCallback"), (Layout::new::<AzTreeViewOnScrollCallback>(), "AzTreeViewOnScrollCallback"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::DataGridCell>(), "AzDataGridCell"), (Layout::new::<AzDataGridCell>(), "AzDataGridCell"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::DataGridCellProviderCallback>(), "AzDataGridCellProviderCallback"), (Layout::new::<AzDataGridCellProviderCallback>(), "AzDataGridCellProviderCallback"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::DataGridCellStyleCallback>(), "AzDataGridCellStyleCallback"), (Layout::new::<AzDataGridCellStyleCallback>(), "AzDataGridCellStyleCallback"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::DataGridOnSelectCallback>(), "AzDataGridOnSelectCallback"), (Layout::new::<AzDataGridOnSelectCallback>(), "AzDataGridOnSelectCallback"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::DataGridOnScrollCallback>(), "AzDataGridOnScrollCallback"), (Layout::new::<AzDataGridOnScrollCallback>(), "AzDataGridOnScrollCallback"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::DataGridOnCellEditCallback>(), "AzDataGridOnCellEditCallback"), (Layout::new::<AzDataGridOnCellEditCallback>(), "AzDataGridOnCellEditCallback"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::DataGridOnColumnResizeCallback>(), "AzDataGridOnColumnResizeCallback"), (Layout::new::<AzDataGridOnColumnResizeCallback>(), "AzDataGridOnColumnResizeCallback"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::DataGridOnRowResizeCallback>(), "AzDataGridOnRowResizeCallback"), (Layout::new::<AzDataGridOnRowResizeCallback>(), "AzDataGridOnRowResizeCallback"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::DataGridOnCopyRangeCallback>(), "AzDataGridOnCopyRangeCallback"), (Layout::new::<AzDataGridOnCopyRangeCallback>(), "AzDataGridOnCopyRangeCallback"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::DataGridOnClearRangeCallback>(), "AzDataGridOnClearRangeCallback"), (Layout::new::<AzDataGridOnClearRangeCallback>(), "AzDataGridOnClearRangeCallback"));
+        assert_eq!((Layout::new::<crate::widgets::date_picker::Date>(), "AzDate"), (Layout::new::<AzDate>(), "AzDate"));
+        assert_eq!((Layout::new::<crate::widgets::date_picker::Weekday>(), "AzWeekday"), (Layout::new::<AzWeekday>(), "AzWeekday"));
+        assert_eq!((Layout::new::<crate::widgets::date_picker::Time>(), "AzTime"), (Layout::new::<AzTime>(), "AzTime"));
+        assert_eq!((Layout::new::<crate::widgets::date_picker::DatePickerOnDateChangeCallback>(), "AzDatePickerOnDateChangeCallback"), (Layout::new::<AzDatePickerOnDateChangeCallback>(), "AzDatePickerOnDateChangeCallback"));
+        assert_eq!((Layout::new::<crate::widgets::date_picker::TimePickerOnTimeChangeCallback>(), "AzTimePickerOnTimeChangeCallback"), (Layout::new::<AzTimePickerOnTimeChangeCallback>(), "AzTimePickerOnTimeChangeCallback"));
+        assert_eq!((Layout::new::<crate::widgets::date_picker::DateTimePickerOnDateTimeChangeCallback>(), "AzDateTimePickerOnDateTimeChangeCallback"), (Layout::new::<AzDateTimePickerOnDateTimeChangeCallback>(), "AzDateTimePickerOnDateTimeChangeCallback"));
+        assert_eq!((Layout::new::<crate::widgets::dialog::DialogIcon>(), "AzDialogIcon"), (Layout::new::<AzDialogIcon>(), "AzDialogIcon"));
+        assert_eq!((Layout::new::<crate::widgets::dialog::DialogButtons>(), "AzDialogButtons"), (Layout::new::<AzDialogButtons>(), "AzDialogButtons"));
+        assert_eq!((Layout::new::<crate::widgets::dialog::DialogButton>(), "AzDialogButton"), (Layout::new::<AzDialogButton>(), "AzDialogButton"));
+        assert_eq!((Layout::new::<crate::widgets::dialog::DialogOnCloseCallback>(), "AzDialogOnCloseCallback"), (Layout::new::<AzDialogOnCloseCallback>(), "AzDialogOnCloseCallback"));
+        assert_eq!((Layout::new::<crate::widgets::splitter::SplitterDirection>(), "AzSplitterDirection"), (Layout::new::<AzSplitterDirection>(), "AzSplitterDirection"));
+        assert_eq!((Layout::new::<crate::widgets::splitter::SplitterOnResizeCallback>(), "AzSplitterOnResizeCallback"), (Layout::new::<AzSplitterOnResizeCallback>(), "AzSplitterOnResizeCallback"));
         assert_eq!((Layout::new::<crate::widgets::drop_down::DropDownOnChoiceChangeCallback>(), "AzDropDownOnChoiceChangeCallback"), (Layout::new::<AzDropDownOnChoiceChangeCallback>(), "AzDropDownOnChoiceChangeCallback"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::NodeHierarchyItem>(), "AzNodeHierarchyItem"), (Layout::new::<AzNodeHierarchyItem>(), "AzNodeHierarchyItem"));
         assert_eq!((Layout::new::<azul_impl::style::CascadeInfo>(), "AzCascadeInfo"), (Layout::new::<AzCascadeInfo>(), "AzCascadeInfo"));
@@ -13293,10 +15810,16 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::task::ThreadSenderDestructorCallback>(), "AzThreadSenderDestructorFn"), (Layout::new::<AzThreadSenderDestructorFn>(), "AzThreadSenderDestructorFn"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFontFamilyVecDestructor>(), "AzStyleFontFamilyVecDestructor"), (Layout::new::<AzStyleFontFamilyVecDestructor>(), "AzStyleFontFamilyVecDestructor"));
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewRowVecDestructor>(), "AzListViewRowVecDestructor"), (Layout::new::<AzListViewRowVecDestructor>(), "AzListViewRowVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewItemVecDestructor>(), "AzTreeViewItemVecDestructor"), (Layout::new::<AzTreeViewItemVecDestructor>(), "AzTreeViewItemVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::splitter::SplitterPaneVecDestructor>(), "AzSplitterPaneVecDestructor"), (Layout::new::<AzSplitterPaneVecDestructor>(), "AzSplitterPaneVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::splitter::SplitterPaneStateVecDestructor>(), "AzSplitterPaneStateVecDestructor"), (Layout::new::<AzSplitterPaneStateVecDestructor>(), "AzSplitterPaneStateVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFilterVecDestructor>(), "AzStyleFilterVecDestructor"), (Layout::new::<AzStyleFilterVecDestructor>(), "AzStyleFilterVecDestructor"));
         assert_eq!((Layout::new::<azul_core::window::LogicalRectVecDestructor>(), "AzLogicalRectVecDestructor"), (Layout::new::<AzLogicalRectVecDestructor>(), "AzLogicalRectVecDestructor"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeTypeIdInfoMapVecDestructor>(), "AzNodeTypeIdInfoMapVecDestructor"), (Layout::new::<AzNodeTypeIdInfoMapVecDestructor>(), "AzNodeTypeIdInfoMapVecDestructor"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::InputOutputTypeIdInfoMapVecDestructor>(), "AzInputOutputTypeIdInfoMapVecDestructor"), (Layout::new::<AzInputOutputTypeIdInfoMapVecDestructor>(), "AzInputOutputTypeIdInfoMapVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::node_graph::NodeGraphNodeIdVecDestructor>(), "AzNodeGraphNodeIdVecDestructor"), (Layout::new::<AzNodeGraphNodeIdVecDestructor>(), "AzNodeGraphNodeIdVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::node_graph::NodeGraphEditOpVecDestructor>(), "AzNodeGraphEditOpVecDestructor"), (Layout::new::<AzNodeGraphEditOpVecDestructor>(), "AzNodeGraphEditOpVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::node_graph::NodeGraphEditVecDestructor>(), "AzNodeGraphEditVecDestructor"), (Layout::new::<AzNodeGraphEditVecDestructor>(), "AzNodeGraphEditVecDestructor"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeIdNodeMapVecDestructor>(), "AzNodeIdNodeMapVecDestructor"), (Layout::new::<AzNodeIdNodeMapVecDestructor>(), "AzNodeIdNodeMapVecDestructor"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::InputOutputTypeIdVecDestructor>(), "AzInputOutputTypeIdVecDestructor"), (Layout::new::<AzInputOutputTypeIdVecDestructor>(), "AzInputOutputTypeIdVecDestructor"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeTypeFieldVecDestructor>(), "AzNodeTypeFieldVecDestructor"), (Layout::new::<AzNodeTypeFieldVecDestructor>(), "AzNodeTypeFieldVecDestructor"));
@@ -13305,6 +15828,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::node_graph::OutputConnectionVecDestructor>(), "AzOutputConnectionVecDestructor"), (Layout::new::<AzOutputConnectionVecDestructor>(), "AzOutputConnectionVecDestructor"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::InputNodeAndIndexVecDestructor>(), "AzInputNodeAndIndexVecDestructor"), (Layout::new::<AzInputNodeAndIndexVecDestructor>(), "AzInputNodeAndIndexVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::dom::AccessibilityStateVecDestructor>(), "AzAccessibilityStateVecDestructor"), (Layout::new::<AzAccessibilityStateVecDestructor>(), "AzAccessibilityStateVecDestructor"));
+        assert_eq!((Layout::new::<azul_core::window::KeyboardShortcutVecDestructor>(), "AzKeyboardShortcutVecDestructor"), (Layout::new::<AzKeyboardShortcutVecDestructor>(), "AzKeyboardShortcutVecDestructor"));
         assert_eq!((Layout::new::<azul_core::window::MenuItemVecDestructor>(), "AzMenuItemVecDestructor"), (Layout::new::<AzMenuItemVecDestructor>(), "AzMenuItemVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::svg::TessellatedSvgNodeVecDestructor>(), "AzTessellatedSvgNodeVecDestructor"), (Layout::new::<AzTessellatedSvgNodeVecDestructor>(), "AzTessellatedSvgNodeVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::svg::TessellatedColoredSvgNodeVecDestructor>(), "AzTessellatedColoredSvgNodeVecDestructor"), (Layout::new::<AzTessellatedColoredSvgNodeVecDestructor>(), "AzTessellatedColoredSvgNodeVecDestructor"));
@@ -13313,6 +15837,8 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineLineVecDestructor>(), "AzInlineLineVecDestructor"), (Layout::new::<AzInlineLineVecDestructor>(), "AzInlineLineVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineWordVecDestructor>(), "AzInlineWordVecDestructor"), (Layout::new::<AzInlineWordVecDestructor>(), "AzInlineWordVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineGlyphVecDestructor>(), "AzInlineGlyphVecDestructor"), (Layout::new::<AzInlineGlyphVecDestructor>(), "AzInlineGlyphVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::TextLineMetricsVecDestructor>(), "AzTextLineMetricsVecDestructor"), (Layout::new::<AzTextLineMetricsVecDestructor>(), "AzTextLineMetricsVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::InlineTextRunVecDestructor>(), "AzInlineTextRunVecDestructor"), (Layout::new::<AzInlineTextRunVecDestructor>(), "AzInlineTextRunVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineTextHitVecDestructor>(), "AzInlineTextHitVecDestructor"), (Layout::new::<AzInlineTextHitVecDestructor>(), "AzInlineTextHitVecDestructor"));
         assert_eq!((Layout::new::<azul_core::window::MonitorVecDestructor>(), "AzMonitorVecDestructor"), (Layout::new::<AzMonitorVecDestructor>(), "AzMonitorVecDestructor"));
         assert_eq!((Layout::new::<azul_core::window::VideoModeVecDestructor>(), "AzVideoModeVecDestructor"), (Layout::new::<AzVideoModeVecDestructor>(), "AzVideoModeVecDestructor"));
@@ -13340,6 +15866,9 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::CssDeclarationVecDestructor>(), "AzCssDeclarationVecDestructor"), (Layout::new::<AzCssDeclarationVecDestructor>(), "AzCssDeclarationVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::CssPathSelectorVecDestructor>(), "AzCssPathSelectorVecDestructor"), (Layout::new::<AzCssPathSelectorVecDestructor>(), "AzCssPathSelectorVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::StylesheetVecDestructor>(), "AzStylesheetVecDestructor"), (Layout::new::<AzStylesheetVecDestructor>(), "AzStylesheetVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::CssFontFaceVecDestructor>(), "AzCssFontFaceVecDestructor"), (Layout::new::<AzCssFontFaceVecDestructor>(), "AzCssFontFaceVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::CssFontFaceSourceVecDestructor>(), "AzCssFontFaceSourceVecDestructor"), (Layout::new::<AzCssFontFaceSourceVecDestructor>(), "AzCssFontFaceSourceVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::CssUnicodeRangeVecDestructor>(), "AzCssUnicodeRangeVecDestructor"), (Layout::new::<AzCssUnicodeRangeVecDestructor>(), "AzCssUnicodeRangeVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::CssRuleBlockVecDestructor>(), "AzCssRuleBlockVecDestructor"), (Layout::new::<AzCssRuleBlockVecDestructor>(), "AzCssRuleBlockVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::F32VecDestructor>(), "AzF32VecDestructor"), (Layout::new::<AzF32VecDestructor>(), "AzF32VecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::U16VecDestructor>(), "AzU16VecDestructor"), (Layout::new::<AzU16VecDestructor>(), "AzU16VecDestructor"));
@@ -13382,6 +15911,8 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::callbacks::DomNodeId>(), "AzDomNodeId"), (Layout::new::<AzDomNodeId>(), "AzDomNodeId"));
         assert_eq!((Layout::new::<azul_impl::ui_solver::PositionInfo>(), "AzPositionInfo"), (Layout::new::<AzPositionInfo>(), "AzPositionInfo"));
         assert_eq!((Layout::new::<azul_impl::callbacks::HidpiAdjustedBounds>(), "AzHidpiAdjustedBounds"), (Layout::new::<AzHidpiAdjustedBounds>(), "AzHidpiAdjustedBounds"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::TextLineMetrics>(), "AzTextLineMetrics"), (Layout::new::<AzTextLineMetrics>(), "AzTextLineMetrics"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::InlineTextRun>(), "AzInlineTextRun"), (Layout::new::<AzInlineTextRun>(), "AzInlineTextRun"));
         assert_eq!((Layout::new::<azul_core::callbacks::InlineGlyph>(), "AzInlineGlyph"), (Layout::new::<AzInlineGlyph>(), "AzInlineGlyph"));
         assert_eq!((Layout::new::<azul_core::callbacks::InlineTextHit>(), "AzInlineTextHit"), (Layout::new::<AzInlineTextHit>(), "AzInlineTextHit"));
         assert_eq!((Layout::new::<azul_impl::callbacks::IFrameCallbackInfo>(), "AzIFrameCallbackInfo"), (Layout::new::<AzIFrameCallbackInfo>(), "AzIFrameCallbackInfo"));
@@ -13490,6 +16021,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::LayoutPositionValue>(), "AzLayoutPositionValue"), (Layout::new::<AzLayoutPositionValue>(), "AzLayoutPositionValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutRightValue>(), "AzLayoutRightValue"), (Layout::new::<AzLayoutRightValue>(), "AzLayoutRightValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutTopValue>(), "AzLayoutTopValue"), (Layout::new::<AzLayoutTopValue>(), "AzLayoutTopValue"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutVerticalAlignValue>(), "AzLayoutVerticalAlignValue"), (Layout::new::<AzLayoutVerticalAlignValue>(), "AzLayoutVerticalAlignValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutWidthValue>(), "AzLayoutWidthValue"), (Layout::new::<AzLayoutWidthValue>(), "AzLayoutWidthValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutFlexWrapValue>(), "AzLayoutFlexWrapValue"), (Layout::new::<AzLayoutFlexWrapValue>(), "AzLayoutFlexWrapValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutOverflowValue>(), "AzLayoutOverflowValue"), (Layout::new::<AzLayoutOverflowValue>(), "AzLayoutOverflowValue"));
@@ -13515,6 +16047,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleLineHeightValue>(), "AzStyleLineHeightValue"), (Layout::new::<AzStyleLineHeightValue>(), "AzStyleLineHeightValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTabWidthValue>(), "AzStyleTabWidthValue"), (Layout::new::<AzStyleTabWidthValue>(), "AzStyleTabWidthValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTextAlignValue>(), "AzStyleTextAlignValue"), (Layout::new::<AzStyleTextAlignValue>(), "AzStyleTextAlignValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTextDecorationValue>(), "AzStyleTextDecorationValue"), (Layout::new::<AzStyleTextDecorationValue>(), "AzStyleTextDecorationValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTextColorValue>(), "AzStyleTextColorValue"), (Layout::new::<AzStyleTextColorValue>(), "AzStyleTextColorValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleWordSpacingValue>(), "AzStyleWordSpacingValue"), (Layout::new::<AzStyleWordSpacingValue>(), "AzStyleWordSpacingValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleOpacityValue>(), "AzStyleOpacityValue"), (Layout::new::<AzStyleOpacityValue>(), "AzStyleOpacityValue"));
@@ -13543,11 +16076,39 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::node_graph::OnNodeInputDisconnected>(), "AzNodeGraphOnNodeInputDisconnected"), (Layout::new::<AzNodeGraphOnNodeInputDisconnected>(), "AzNodeGraphOnNodeInputDisconnected"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OnNodeOutputDisconnected>(), "AzNodeGraphOnNodeOutputDisconnected"), (Layout::new::<AzNodeGraphOnNodeOutputDisconnected>(), "AzNodeGraphOnNodeOutputDisconnected"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OnNodeFieldEdited>(), "AzNodeGraphOnNodeFieldEdited"), (Layout::new::<AzNodeGraphOnNodeFieldEdited>(), "AzNodeGraphOnNodeFieldEdited"));
+        assert_eq!((Layout::new::<crate::widgets::node_graph::OnNodeGraphZoomed>(), "AzNodeGraphOnNodeGraphZoomed"), (Layout::new::<AzNodeGraphOnNodeGraphZoomed>(), "AzNodeGraphOnNodeGraphZoomed"));
+        assert_eq!((Layout::new::<crate::widgets::node_graph::OnNodeGraphSelectionChanged>(), "AzNodeGraphOnSelectionChanged"), (Layout::new::<AzNodeGraphOnSelectionChanged>(), "AzNodeGraphOnSelectionChanged"));
+        assert_eq!((Layout::new::<crate::widgets::node_graph::OnNodeGraphHistoryChanged>(), "AzNodeGraphOnHistoryChanged"), (Layout::new::<AzNodeGraphOnHistoryChanged>(), "AzNodeGraphOnHistoryChanged"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OutputNodeAndIndex>(), "AzOutputNodeAndIndex"), (Layout::new::<AzOutputNodeAndIndex>(), "AzOutputNodeAndIndex"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::InputNodeAndIndex>(), "AzInputNodeAndIndex"), (Layout::new::<AzInputNodeAndIndex>(), "AzInputNodeAndIndex"));
+        assert_eq!((Layout::new::<crate::widgets::node_graph::NodeGraphConnection>(), "AzNodeGraphConnection"), (Layout::new::<AzNodeGraphConnection>(), "AzNodeGraphConnection"));
+        assert_eq!((Layout::new::<crate::widgets::node_graph::NodeGraphNodeMove>(), "AzNodeGraphNodeMove"), (Layout::new::<AzNodeGraphNodeMove>(), "AzNodeGraphNodeMove"));
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewOnLazyLoadScroll>(), "AzListViewOnLazyLoadScroll"), (Layout::new::<AzListViewOnLazyLoadScroll>(), "AzListViewOnLazyLoadScroll"));
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewOnColumnClick>(), "AzListViewOnColumnClick"), (Layout::new::<AzListViewOnColumnClick>(), "AzListViewOnColumnClick"));
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewOnRowClick>(), "AzListViewOnRowClick"), (Layout::new::<AzListViewOnRowClick>(), "AzListViewOnRowClick"));
+        assert_eq!((Layout::new::<crate::widgets::list_view::ListViewOnRowSelect>(), "AzListViewOnRowSelect"), (Layout::new::<AzListViewOnRowSelect>(), "AzListViewOnRowSelect"));
+        assert_eq!((Layout::new::<crate::widgets::list_view::ListViewOnColumnResize>(), "AzListViewOnColumnResize"), (Layout::new::<AzListViewOnColumnResize>(), "AzListViewOnColumnResize"));
+        assert_eq!((Layout::new::<crate::widgets::list_view::ListViewRowProvider>(), "AzListViewRowProvider"), (Layout::new::<AzListViewRowProvider>(), "AzListViewRowProvider"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewOnExpand>(), "AzTreeViewOnExpand"), (Layout::new::<AzTreeViewOnExpand>(), "AzTreeViewOnExpand"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewOnSelect>(), "AzTreeViewOnSelect"), (Layout::new::<AzTreeViewOnSelect>(), "AzTreeViewOnSelect"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewOnLazyLoad>(), "AzTreeViewOnLazyLoad"), (Layout::new::<AzTreeViewOnLazyLoad>(), "AzTreeViewOnLazyLoad"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewOnScroll>(), "AzTreeViewOnScroll"), (Layout::new::<AzTreeViewOnScroll>(), "AzTreeViewOnScroll"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::DataGridRange>(), "AzDataGridRange"), (Layout::new::<AzDataGridRange>(), "AzDataGridRange"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::DataGridCellProvider>(), "AzDataGridCellProvider"), (Layout::new::<AzDataGridCellProvider>(), "AzDataGridCellProvider"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::DataGridCellStyle>(), "AzDataGridCellStyle"), (Layout::new::<AzDataGridCellStyle>(), "AzDataGridCellStyle"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::DataGridOnSelect>(), "AzDataGridOnSelect"), (Layout::new::<AzDataGridOnSelect>(), "AzDataGridOnSelect"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::DataGridOnScroll>(), "AzDataGridOnScroll"), (Layout::new::<AzDataGridOnScroll>(), "AzDataGridOnScroll"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::DataGridOnCellEdit>(), "AzDataGridOnCellEdit"), (Layout::new::<AzDataGridOnCellEdit>(), "AzDataGridOnCellEdit"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::DataGridOnColumnResize>(), "AzDataGridOnColumnResize"), (Layout::new::<AzDataGridOnColumnResize>(), "AzDataGridOnColumnResize"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::DataGridOnRowResize>(), "AzDataGridOnRowResize"), (Layout::new::<AzDataGridOnRowResize>(), "AzDataGridOnRowResize"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::DataGridOnCopyRange>(), "AzDataGridOnCopyRange"), (Layout::new::<AzDataGridOnCopyRange>(), "AzDataGridOnCopyRange"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::DataGridOnClearRange>(), "AzDataGridOnClearRange"), (Layout::new::<AzDataGridOnClearRange>(), "AzDataGridOnClearRange"));
+        assert_eq!((Layout::new::<crate::widgets::date_picker::DatePickerOnDateChange>(), "AzDatePickerOnDateChange"), (Layout::new::<AzDatePickerOnDateChange>(), "AzDatePickerOnDateChange"));
+        assert_eq!((Layout::new::<crate::widgets::date_picker::TimePickerOnTimeChange>(), "AzTimePickerOnTimeChange"), (Layout::new::<AzTimePickerOnTimeChange>(), "AzTimePickerOnTimeChange"));
+        assert_eq!((Layout::new::<crate::widgets::date_picker::DateTimePickerOnDateTimeChange>(), "AzDateTimePickerOnDateTimeChange"), (Layout::new::<AzDateTimePickerOnDateTimeChange>(), "AzDateTimePickerOnDateTimeChange"));
+        assert_eq!((Layout::new::<crate::widgets::dialog::DialogOnClose>(), "AzDialogOnClose"), (Layout::new::<AzDialogOnClose>(), "AzDialogOnClose"));
+        assert_eq!((Layout::new::<crate::widgets::splitter::SplitterPaneState>(), "AzSplitterPaneState"), (Layout::new::<AzSplitterPaneState>(), "AzSplitterPaneState"));
+        assert_eq!((Layout::new::<crate::widgets::splitter::SplitterOnResize>(), "AzSplitterOnResize"), (Layout::new::<AzSplitterOnResize>(), "AzSplitterOnResize"));
         assert_eq!((Layout::new::<crate::widgets::drop_down::DropDownOnChoiceChange>(), "AzDropDownOnChoiceChange"), (Layout::new::<AzDropDownOnChoiceChange>(), "AzDropDownOnChoiceChange"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::ParentWithNodeDepth>(), "AzParentWithNodeDepth"), (Layout::new::<AzParentWithNodeDepth>(), "AzParentWithNodeDepth"));
         assert_eq!((Layout::new::<azul_impl::gl::GlContextPtr>(), "AzGl"), (Layout::new::<AzGl>(), "AzGl"));
@@ -13559,11 +16120,15 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::svg::SvgCubicCurve>(), "AzSvgCubicCurve"), (Layout::new::<AzSvgCubicCurve>(), "AzSvgCubicCurve"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgXmlOptions>(), "AzSvgStringFormatOptions"), (Layout::new::<AzSvgStringFormatOptions>(), "AzSvgStringFormatOptions"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgFillStyle>(), "AzSvgFillStyle"), (Layout::new::<AzSvgFillStyle>(), "AzSvgFillStyle"));
+        assert_eq!((Layout::new::<azul_impl::xml::XmlSpan>(), "AzXmlSpan"), (Layout::new::<AzXmlSpan>(), "AzXmlSpan"));
         assert_eq!((Layout::new::<azul_impl::task::AzInstantPtr>(), "AzInstantPtr"), (Layout::new::<AzInstantPtr>(), "AzInstantPtr"));
         assert_eq!((Layout::new::<azul_impl::task::Duration>(), "AzDuration"), (Layout::new::<AzDuration>(), "AzDuration"));
         assert_eq!((Layout::new::<azul_impl::task::ThreadSendMsg>(), "AzThreadSendMsg"), (Layout::new::<AzThreadSendMsg>(), "AzThreadSendMsg"));
         assert_eq!((Layout::new::<azul_impl::task::ThreadWriteBackMsg>(), "AzThreadWriteBackMsg"), (Layout::new::<AzThreadWriteBackMsg>(), "AzThreadWriteBackMsg"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewItemVec>(), "AzTreeViewItemVec"), (Layout::new::<AzTreeViewItemVec>(), "AzTreeViewItemVec"));
+        assert_eq!((Layout::new::<crate::widgets::splitter::SplitterPaneStateVec>(), "AzSplitterPaneStateVec"), (Layout::new::<AzSplitterPaneStateVec>(), "AzSplitterPaneStateVec"));
         assert_eq!((Layout::new::<azul_core::window::LogicalRectVec>(), "AzLogicalRectVec"), (Layout::new::<AzLogicalRectVec>(), "AzLogicalRectVec"));
+        assert_eq!((Layout::new::<crate::widgets::node_graph::NodeGraphNodeIdVec>(), "AzNodeGraphNodeIdVec"), (Layout::new::<AzNodeGraphNodeIdVec>(), "AzNodeGraphNodeIdVec"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::InputOutputTypeIdVec>(), "AzInputOutputTypeIdVec"), (Layout::new::<AzInputOutputTypeIdVec>(), "AzInputOutputTypeIdVec"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OutputNodeAndIndexVec>(), "AzOutputNodeAndIndexVec"), (Layout::new::<AzOutputNodeAndIndexVec>(), "AzOutputNodeAndIndexVec"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::InputNodeAndIndexVec>(), "AzInputNodeAndIndexVec"), (Layout::new::<AzInputNodeAndIndexVec>(), "AzInputNodeAndIndexVec"));
@@ -13571,6 +16136,8 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::MenuItemVec>(), "AzMenuItemVec"), (Layout::new::<AzMenuItemVec>(), "AzMenuItemVec"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlNodeVec>(), "AzXmlNodeVec"), (Layout::new::<AzXmlNodeVec>(), "AzXmlNodeVec"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineGlyphVec>(), "AzInlineGlyphVec"), (Layout::new::<AzInlineGlyphVec>(), "AzInlineGlyphVec"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::TextLineMetricsVec>(), "AzTextLineMetricsVec"), (Layout::new::<AzTextLineMetricsVec>(), "AzTextLineMetricsVec"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::InlineTextRunVec>(), "AzInlineTextRunVec"), (Layout::new::<AzInlineTextRunVec>(), "AzInlineTextRunVec"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineTextHitVec>(), "AzInlineTextHitVec"), (Layout::new::<AzInlineTextHitVec>(), "AzInlineTextHitVec"));
         assert_eq!((Layout::new::<azul_core::window::VideoModeVec>(), "AzVideoModeVec"), (Layout::new::<AzVideoModeVec>(), "AzVideoModeVec"));
         assert_eq!((Layout::new::<azul_impl::dom::DomVec>(), "AzDomVec"), (Layout::new::<AzDomVec>(), "AzDomVec"));
@@ -13584,6 +16151,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::VirtualKeyCodeVec>(), "AzVirtualKeyCodeVec"), (Layout::new::<AzVirtualKeyCodeVec>(), "AzVirtualKeyCodeVec"));
         assert_eq!((Layout::new::<azul_impl::style::CascadeInfoVec>(), "AzCascadeInfoVec"), (Layout::new::<AzCascadeInfoVec>(), "AzCascadeInfoVec"));
         assert_eq!((Layout::new::<azul_core::window::ScanCodeVec>(), "AzScanCodeVec"), (Layout::new::<AzScanCodeVec>(), "AzScanCodeVec"));
+        assert_eq!((Layout::new::<azul_impl::css::CssUnicodeRangeVec>(), "AzCssUnicodeRangeVec"), (Layout::new::<AzCssUnicodeRangeVec>(), "AzCssUnicodeRangeVec"));
         assert_eq!((Layout::new::<azul_impl::css::U16Vec>(), "AzU16Vec"), (Layout::new::<AzU16Vec>(), "AzU16Vec"));
         assert_eq!((Layout::new::<azul_impl::css::F32Vec>(), "AzF32Vec"), (Layout::new::<AzF32Vec>(), "AzF32Vec"));
         assert_eq!((Layout::new::<azul_impl::css::U8Vec>(), "AzU8Vec"), (Layout::new::<AzU8Vec>(), "AzU8Vec"));
@@ -13595,11 +16163,38 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::styled_dom::NodeHierarchyItemVec>(), "AzNodeHierarchyItemVec"), (Layout::new::<AzNodeHierarchyItemVec>(), "AzNodeHierarchyItemVec"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::ParentWithNodeDepthVec>(), "AzParentWithNodeDepthVec"), (Layout::new::<AzParentWithNodeDepthVec>(), "AzParentWithNodeDepthVec"));
         assert_eq!((Layout::new::<azul_impl::css::OptionSvgPoint>(), "AzOptionSvgPoint"), (Layout::new::<AzOptionSvgPoint>(), "AzOptionSvgPoint"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::OptionTreeViewOnExpand>(), "AzOptionTreeViewOnExpand"), (Layout::new::<AzOptionTreeViewOnExpand>(), "AzOptionTreeViewOnExpand"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::OptionTreeViewOnSelect>(), "AzOptionTreeViewOnSelect"), (Layout::new::<AzOptionTreeViewOnSelect>(), "AzOptionTreeViewOnSelect"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::OptionTreeViewOnLazyLoad>(), "AzOptionTreeViewOnLazyLoad"), (Layout::new::<AzOptionTreeViewOnLazyLoad>(), "AzOptionTreeViewOnLazyLoad"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::OptionTreeViewOnScroll>(), "AzOptionTreeViewOnScroll"), (Layout::new::<AzOptionTreeViewOnScroll>(), "AzOptionTreeViewOnScroll"));
         assert_eq!((Layout::new::<crate::widgets::list_view::OptionListViewOnRowClick>(), "AzOptionListViewOnRowClick"), (Layout::new::<AzOptionListViewOnRowClick>(), "AzOptionListViewOnRowClick"));
+        assert_eq!((Layout::new::<crate::widgets::list_view::OptionListViewOnRowSelect>(), "AzOptionListViewOnRowSelect"), (Layout::new::<AzOptionListViewOnRowSelect>(), "AzOptionListViewOnRowSelect"));
+        assert_eq!((Layout::new::<crate::widgets::list_view::OptionListViewOnColumnResize>(), "AzOptionListViewOnColumnResize"), (Layout::new::<AzOptionListViewOnColumnResize>(), "AzOptionListViewOnColumnResize"));
+        assert_eq!((Layout::new::<crate::widgets::list_view::OptionListViewRowProvider>(), "AzOptionListViewRowProvider"), (Layout::new::<AzOptionListViewRowProvider>(), "AzOptionListViewRowProvider"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::OptionDataGridRange>(), "AzOptionDataGridRange"), (Layout::new::<AzOptionDataGridRange>(), "AzOptionDataGridRange"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::OptionDataGridCellProvider>(), "AzOptionDataGridCellProvider"), (Layout::new::<AzOptionDataGridCellProvider>(), "AzOptionDataGridCellProvider"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::OptionDataGridCellStyle>(), "AzOptionDataGridCellStyle"), (Layout::new::<AzOptionDataGridCellStyle>(), "AzOptionDataGridCellStyle"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::OptionDataGridOnSelect>(), "AzOptionDataGridOnSelect"), (Layout::new::<AzOptionDataGridOnSelect>(), "AzOptionDataGridOnSelect"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::OptionDataGridOnScroll>(), "AzOptionDataGridOnScroll"), (Layout::new::<AzOptionDataGridOnScroll>(), "AzOptionDataGridOnScroll"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::OptionDataGridOnCellEdit>(), "AzOptionDataGridOnCellEdit"), (Layout::new::<AzOptionDataGridOnCellEdit>(), "AzOptionDataGridOnCellEdit"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::OptionDataGridOnColumnResize>(), "AzOptionDataGridOnColumnResize"), (Layout::new::<AzOptionDataGridOnColumnResize>(), "AzOptionDataGridOnColumnResize"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::OptionDataGridOnRowResize>(), "AzOptionDataGridOnRowResize"), (Layout::new::<AzOptionDataGridOnRowResize>(), "AzOptionDataGridOnRowResize"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::OptionDataGridOnCopyRange>(), "AzOptionDataGridOnCopyRange"), (Layout::new::<AzOptionDataGridOnCopyRange>(), "AzOptionDataGridOnCopyRange"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::OptionDataGridOnClearRange>(), "AzOptionDataGridOnClearRange"), (Layout::new::<AzOptionDataGridOnClearRange>(), "AzOptionDataGridOnClearRange"));
+        assert_eq!((Layout::new::<crate::widgets::date_picker::OptionDate>(), "AzOptionDate"), (Layout::new::<AzOptionDate>(), "AzOptionDate"));
+        assert_eq!((Layout::new::<crate::widgets::date_picker::OptionTime>(), "AzOptionTime"), (Layout::new::<AzOptionTime>(), "AzOptionTime"));
+        assert_eq!((Layout::new::<crate::widgets::date_picker::OptionDatePickerOnDateChange>(), "AzOptionDatePickerOnDateChange"), (Layout::new::<AzOptionDatePickerOnDateChange>(), "AzOptionDatePickerOnDateChange"));
+        assert_eq!((Layout::new::<crate::widgets::date_picker::OptionTimePickerOnTimeChange>(), "AzOptionTimePickerOnTimeChange"), (Layout::new::<AzOptionTimePickerOnTimeChange>(), "AzOptionTimePickerOnTimeChange"));
+        assert_eq!((Layout::new::<crate::widgets::date_picker::OptionDateTimePickerOnDateTimeChange>(), "AzOptionDateTimePickerOnDateTimeChange"), (Layout::new::<AzOptionDateTimePickerOnDateTimeChange>(), "AzOptionDateTimePickerOnDateTimeChange"));
+        assert_eq!((Layout::new::<crate::widgets::dialog::OptionDialogOnClose>(), "AzOptionDialogOnClose"), (Layout::new::<AzOptionDialogOnClose>(), "AzOptionDialogOnClose"));
+        assert_eq!((Layout::new::<crate::widgets::splitter::OptionSplitterOnResize>(), "AzOptionSplitterOnResize"), (Layout::new::<AzOptionSplitterOnResize>(), "AzOptionSplitterOnResize"));
         assert_eq!((Layout::new::<crate::widgets::list_view::OptionListViewOnColumnClick>(), "AzOptionListViewOnColumnClick"), (Layout::new::<AzOptionListViewOnColumnClick>(), "AzOptionListViewOnColumnClick"));
         assert_eq!((Layout::new::<crate::widgets::list_view::OptionListViewOnLazyLoadScroll>(), "AzOptionListViewOnLazyLoadScroll"), (Layout::new::<AzOptionListViewOnLazyLoadScroll>(), "AzOptionListViewOnLazyLoadScroll"));
         assert_eq!((Layout::new::<azul_impl::css::OptionPixelValueNoPercent>(), "AzOptionPixelValueNoPercent"), (Layout::new::<AzOptionPixelValueNoPercent>(), "AzOptionPixelValueNoPercent"));
         assert_eq!((Layout::new::<crate::widgets::drop_down::OptionDropDownOnChoiceChange>(), "AzOptionDropDownOnChoiceChange"), (Layout::new::<AzOptionDropDownOnChoiceChange>(), "AzOptionDropDownOnChoiceChange"));
+        assert_eq!((Layout::new::<crate::widgets::node_graph::OptionOnNodeGraphZoomed>(), "AzOptionNodeGraphOnNodeGraphZoomed"), (Layout::new::<AzOptionNodeGraphOnNodeGraphZoomed>(), "AzOptionNodeGraphOnNodeGraphZoomed"));
+        assert_eq!((Layout::new::<crate::widgets::node_graph::OptionOnNodeGraphSelectionChanged>(), "AzOptionNodeGraphOnSelectionChanged"), (Layout::new::<AzOptionNodeGraphOnSelectionChanged>(), "AzOptionNodeGraphOnSelectionChanged"));
+        assert_eq!((Layout::new::<crate::widgets::node_graph::OptionOnNodeGraphHistoryChanged>(), "AzOptionNodeGraphOnHistoryChanged"), (Layout::new::<AzOptionNodeGraphOnHistoryChanged>(), "AzOptionNodeGraphOnHistoryChanged"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OptionOnNodeAdded>(), "AzOptionNodeGraphOnNodeAdded"), (Layout::new::<AzOptionNodeGraphOnNodeAdded>(), "AzOptionNodeGraphOnNodeAdded"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OptionOnNodeRemoved>(), "AzOptionNodeGraphOnNodeRemoved"), (Layout::new::<AzOptionNodeGraphOnNodeRemoved>(), "AzOptionNodeGraphOnNodeRemoved"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OptionOnNodeGraphDragged>(), "AzOptionNodeGraphOnNodeGraphDragged"), (Layout::new::<AzOptionNodeGraphOnNodeGraphDragged>(), "AzOptionNodeGraphOnNodeGraphDragged"));
@@ -13668,6 +16263,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::KeyboardState>(), "AzKeyboardState"), (Layout::new::<AzKeyboardState>(), "AzKeyboardState"));
         assert_eq!((Layout::new::<azul_core::window::MouseState>(), "AzMouseState"), (Layout::new::<AzMouseState>(), "AzMouseState"));
         assert_eq!((Layout::new::<azul_impl::callbacks::MarshaledLayoutCallback>(), "AzMarshaledLayoutCallback"), (Layout::new::<AzMarshaledLayoutCallback>(), "AzMarshaledLayoutCallback"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::TextMetrics>(), "AzTextMetrics"), (Layout::new::<AzTextMetrics>(), "AzTextMetrics"));
         assert_eq!((Layout::new::<azul_core::callbacks::InlineTextContents>(), "AzInlineTextContents"), (Layout::new::<AzInlineTextContents>(), "AzInlineTextContents"));
         assert_eq!((Layout::new::<azul_impl::ui_solver::ResolvedTextLayoutOptions>(), "AzResolvedTextLayoutOptions"), (Layout::new::<AzResolvedTextLayoutOptions>(), "AzResolvedTextLayoutOptions"));
         assert_eq!((Layout::new::<azul_impl::css::AnimationInterpolationFunction>(), "AzAnimationEasing"), (Layout::new::<AzAnimationEasing>(), "AzAnimationEasing"));
@@ -13676,6 +16272,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::dom::EventFilter>(), "AzEventFilter"), (Layout::new::<AzEventFilter>(), "AzEventFilter"));
         assert_eq!((Layout::new::<azul_core::window::Menu>(), "AzMenu"), (Layout::new::<AzMenu>(), "AzMenu"));
         assert_eq!((Layout::new::<azul_core::window::VirtualKeyCodeCombo>(), "AzVirtualKeyCodeCombo"), (Layout::new::<AzVirtualKeyCodeCombo>(), "AzVirtualKeyCodeCombo"));
+        assert_eq!((Layout::new::<azul_core::window::KeyboardShortcut>(), "AzKeyboardShortcut"), (Layout::new::<AzKeyboardShortcut>(), "AzKeyboardShortcut"));
         assert_eq!((Layout::new::<azul_impl::css::CssPathPseudoSelector>(), "AzCssPathPseudoSelector"), (Layout::new::<AzCssPathPseudoSelector>(), "AzCssPathPseudoSelector"));
         assert_eq!((Layout::new::<azul_impl::css::AnimationInterpolationFunction>(), "AzAnimationInterpolationFunction"), (Layout::new::<AzAnimationInterpolationFunction>(), "AzAnimationInterpolationFunction"));
         assert_eq!((Layout::new::<azul_impl::css::InterpolateResolver>(), "AzInterpolateContext"), (Layout::new::<AzInterpolateContext>(), "AzInterpolateContext"));
@@ -13693,6 +16290,13 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::node_graph::InputConnection>(), "AzInputConnection"), (Layout::new::<AzInputConnection>(), "AzInputConnection"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OutputConnection>(), "AzOutputConnection"), (Layout::new::<AzOutputConnection>(), "AzOutputConnection"));
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewRow>(), "AzListViewRow"), (Layout::new::<AzListViewRow>(), "AzListViewRow"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeView>(), "AzTreeView"), (Layout::new::<AzTreeView>(), "AzTreeView"));
+        assert_eq!((Layout::new::<crate::widgets::date_picker::DatePickerState>(), "AzDatePickerState"), (Layout::new::<AzDatePickerState>(), "AzDatePickerState"));
+        assert_eq!((Layout::new::<crate::widgets::date_picker::DatePicker>(), "AzDatePicker"), (Layout::new::<AzDatePicker>(), "AzDatePicker"));
+        assert_eq!((Layout::new::<crate::widgets::date_picker::TimePicker>(), "AzTimePicker"), (Layout::new::<AzTimePicker>(), "AzTimePicker"));
+        assert_eq!((Layout::new::<crate::widgets::date_picker::DateTimePickerState>(), "AzDateTimePickerState"), (Layout::new::<AzDateTimePickerState>(), "AzDateTimePickerState"));
+        assert_eq!((Layout::new::<crate::widgets::date_picker::DateTimePicker>(), "AzDateTimePicker"), (Layout::new::<AzDateTimePicker>(), "AzDateTimePicker"));
+        assert_eq!((Layout::new::<crate::widgets::splitter::SplitterState>(), "AzSplitterState"), (Layout::new::<AzSplitterState>(), "AzSplitterState"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::StyledNode>(), "AzStyledNode"), (Layout::new::<AzStyledNode>(), "AzStyledNode"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::TagIdToNodeIdMapping>(), "AzTagIdToNodeIdMapping"), (Layout::new::<AzTagIdToNodeIdMapping>(), "AzTagIdToNodeIdMapping"));
         assert_eq!((Layout::new::<azul_impl::gl::Texture>(), "AzTexture"), (Layout::new::<AzTexture>(), "AzTexture"));
@@ -13714,6 +16318,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleFilterVec>(), "AzStyleFilterVec"), (Layout::new::<AzStyleFilterVec>(), "AzStyleFilterVec"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::InputConnectionVec>(), "AzInputConnectionVec"), (Layout::new::<AzInputConnectionVec>(), "AzInputConnectionVec"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OutputConnectionVec>(), "AzOutputConnectionVec"), (Layout::new::<AzOutputConnectionVec>(), "AzOutputConnectionVec"));
+        assert_eq!((Layout::new::<azul_core::window::KeyboardShortcutVec>(), "AzKeyboardShortcutVec"), (Layout::new::<AzKeyboardShortcutVec>(), "AzKeyboardShortcutVec"));
         assert_eq!((Layout::new::<azul_impl::svg::TessellatedSvgNodeVec>(), "AzTessellatedSvgNodeVec"), (Layout::new::<AzTessellatedSvgNodeVec>(), "AzTessellatedSvgNodeVec"));
         assert_eq!((Layout::new::<azul_impl::svg::TessellatedColoredSvgNodeVec>(), "AzTessellatedColoredSvgNodeVec"), (Layout::new::<AzTessellatedColoredSvgNodeVec>(), "AzTessellatedColoredSvgNodeVec"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformVec>(), "AzStyleTransformVec"), (Layout::new::<AzStyleTransformVec>(), "AzStyleTransformVec"));
@@ -13728,6 +16333,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::OptionKeyboardState>(), "AzOptionKeyboardState"), (Layout::new::<AzOptionKeyboardState>(), "AzOptionKeyboardState"));
         assert_eq!((Layout::new::<azul_impl::css::OptionStringVec>(), "AzOptionStringVec"), (Layout::new::<AzOptionStringVec>(), "AzOptionStringVec"));
         assert_eq!((Layout::new::<azul_impl::task::OptionThreadReceiveMsg>(), "AzOptionThreadReceiveMsg"), (Layout::new::<AzOptionThreadReceiveMsg>(), "AzOptionThreadReceiveMsg"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::OptionTextMetrics>(), "AzOptionTextMetrics"), (Layout::new::<AzOptionTextMetrics>(), "AzOptionTextMetrics"));
         assert_eq!((Layout::new::<azul_core::window::OptionTaskBarIcon>(), "AzOptionTaskBarIcon"), (Layout::new::<AzOptionTaskBarIcon>(), "AzOptionTaskBarIcon"));
         assert_eq!((Layout::new::<azul_core::window::OptionWindowIcon>(), "AzOptionWindowIcon"), (Layout::new::<AzOptionWindowIcon>(), "AzOptionWindowIcon"));
         assert_eq!((Layout::new::<azul_impl::css::OptionAzString>(), "AzOptionString"), (Layout::new::<AzOptionString>(), "AzOptionString"));
@@ -13751,6 +16357,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::dom::IdOrClass>(), "AzIdOrClass"), (Layout::new::<AzIdOrClass>(), "AzIdOrClass"));
         assert_eq!((Layout::new::<azul_core::window::StringMenuItem>(), "AzStringMenuItem"), (Layout::new::<AzStringMenuItem>(), "AzStringMenuItem"));
         assert_eq!((Layout::new::<azul_impl::css::CssPathSelector>(), "AzCssPathSelector"), (Layout::new::<AzCssPathSelector>(), "AzCssPathSelector"));
+        assert_eq!((Layout::new::<azul_impl::css::CssFontFaceUrl>(), "AzCssFontFaceUrl"), (Layout::new::<AzCssFontFaceUrl>(), "AzCssFontFaceUrl"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundContent>(), "AzStyleBackgroundContent"), (Layout::new::<AzStyleBackgroundContent>(), "AzStyleBackgroundContent"));
         assert_eq!((Layout::new::<azul_impl::css::ScrollbarInfo>(), "AzScrollbarInfo"), (Layout::new::<AzScrollbarInfo>(), "AzScrollbarInfo"));
         assert_eq!((Layout::new::<azul_impl::css::ScrollbarStyle>(), "AzScrollbarStyle"), (Layout::new::<AzScrollbarStyle>(), "AzScrollbarStyle"));
@@ -13765,9 +16372,12 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeTypeFieldValue>(), "AzNodeTypeFieldValue"), (Layout::new::<AzNodeTypeFieldValue>(), "AzNodeTypeFieldValue"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeTypeInfo>(), "AzNodeTypeInfo"), (Layout::new::<AzNodeTypeInfo>(), "AzNodeTypeInfo"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::InputOutputInfo>(), "AzInputOutputInfo"), (Layout::new::<AzInputOutputInfo>(), "AzInputOutputInfo"));
+        assert_eq!((Layout::new::<crate::widgets::node_graph::NodeGraphFieldEdit>(), "AzNodeGraphFieldEdit"), (Layout::new::<AzNodeGraphFieldEdit>(), "AzNodeGraphFieldEdit"));
         assert_eq!((Layout::new::<crate::widgets::list_view::ListView>(), "AzListView"), (Layout::new::<AzListView>(), "AzListView"));
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewState>(), "AzListViewState"), (Layout::new::<AzListViewState>(), "AzListViewState"));
-        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeView>(), "AzTreeView"), (Layout::new::<AzTreeView>(), "AzTreeView"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewItem>(), "AzTreeViewItem"), (Layout::new::<AzTreeViewItem>(), "AzTreeViewItem"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::DataGrid>(), "AzDataGrid"), (Layout::new::<AzDataGrid>(), "AzDataGrid"));
+        assert_eq!((Layout::new::<crate::widgets::dialog::DialogResult>(), "AzDialogResult"), (Layout::new::<AzDialogResult>(), "AzDialogResult"));
         assert_eq!((Layout::new::<crate::widgets::drop_down::DropDown>(), "AzDropDown"), (Layout::new::<AzDropDown>(), "AzDropDown"));
         assert_eq!((Layout::new::<azul_impl::gl::VertexAttribute>(), "AzVertexAttribute"), (Layout::new::<AzVertexAttribute>(), "AzVertexAttribute"));
         assert_eq!((Layout::new::<azul_impl::gl::AzDebugMessage>(), "AzDebugMessage"), (Layout::new::<AzDebugMessage>(), "AzDebugMessage"));
@@ -13804,6 +16414,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineLine>(), "AzInlineLine"), (Layout::new::<AzInlineLine>(), "AzInlineLine"));
         assert_eq!((Layout::new::<azul_core::window::MenuItem>(), "AzMenuItem"), (Layout::new::<AzMenuItem>(), "AzMenuItem"));
         assert_eq!((Layout::new::<azul_impl::css::CssPath>(), "AzCssPath"), (Layout::new::<AzCssPath>(), "AzCssPath"));
+        assert_eq!((Layout::new::<azul_impl::css::CssFontFaceSource>(), "AzCssFontFaceSource"), (Layout::new::<AzCssFontFaceSource>(), "AzCssFontFaceSource"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundContentVecValue>(), "AzStyleBackgroundContentVecValue"), (Layout::new::<AzStyleBackgroundContentVecValue>(), "AzStyleBackgroundContentVecValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFontFamilyVecValue>(), "AzStyleFontFamilyVecValue"), (Layout::new::<AzStyleFontFamilyVecValue>(), "AzStyleFontFamilyVecValue"));
         assert_eq!((Layout::new::<azul_impl::css::CssProperty>(), "AzCssProperty"), (Layout::new::<AzCssProperty>(), "AzCssProperty"));
@@ -13829,6 +16440,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::CssPropertyVec>(), "AzCssPropertyVec"), (Layout::new::<AzCssPropertyVec>(), "AzCssPropertyVec"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgMultiPolygonVec>(), "AzSvgMultiPolygonVec"), (Layout::new::<AzSvgMultiPolygonVec>(), "AzSvgMultiPolygonVec"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgSimpleNodeVec>(), "AzSvgSimpleNodeVec"), (Layout::new::<AzSvgSimpleNodeVec>(), "AzSvgSimpleNodeVec"));
+        assert_eq!((Layout::new::<azul_impl::css::CssFontFaceSourceVec>(), "AzCssFontFaceSourceVec"), (Layout::new::<AzCssFontFaceSourceVec>(), "AzCssFontFaceSourceVec"));
         assert_eq!((Layout::new::<azul_impl::css::OptionCssProperty>(), "AzOptionCssProperty"), (Layout::new::<AzOptionCssProperty>(), "AzOptionCssProperty"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlTextError>(), "AzXmlTextError"), (Layout::new::<AzXmlTextError>(), "AzXmlTextError"));
         assert_eq!((Layout::new::<azul_core::window::PlatformSpecificOptions>(), "AzPlatformSpecificOptions"), (Layout::new::<AzPlatformSpecificOptions>(), "AzPlatformSpecificOptions"));
@@ -13840,10 +16452,12 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::callbacks::TimerCallbackInfo>(), "AzTimerCallbackInfo"), (Layout::new::<AzTimerCallbackInfo>(), "AzTimerCallbackInfo"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeDataInlineCssProperty>(), "AzNodeDataInlineCssProperty"), (Layout::new::<AzNodeDataInlineCssProperty>(), "AzNodeDataInlineCssProperty"));
         assert_eq!((Layout::new::<azul_impl::css::DynamicCssProperty>(), "AzDynamicCssProperty"), (Layout::new::<AzDynamicCssProperty>(), "AzDynamicCssProperty"));
+        assert_eq!((Layout::new::<azul_impl::css::CssFontFace>(), "AzCssFontFace"), (Layout::new::<AzCssFontFace>(), "AzCssFontFace"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::Node>(), "AzNode"), (Layout::new::<AzNode>(), "AzNode"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgNode>(), "AzSvgNode"), (Layout::new::<AzSvgNode>(), "AzSvgNode"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgStyledNode>(), "AzSvgStyledNode"), (Layout::new::<AzSvgStyledNode>(), "AzSvgStyledNode"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeDataInlineCssPropertyVec>(), "AzNodeDataInlineCssPropertyVec"), (Layout::new::<AzNodeDataInlineCssPropertyVec>(), "AzNodeDataInlineCssPropertyVec"));
+        assert_eq!((Layout::new::<azul_impl::css::CssFontFaceVec>(), "AzCssFontFaceVec"), (Layout::new::<AzCssFontFaceVec>(), "AzCssFontFaceVec"));
         assert_eq!((Layout::new::<azul_core::window::OptionWindowState>(), "AzOptionWindowState"), (Layout::new::<AzOptionWindowState>(), "AzOptionWindowState"));
         assert_eq!((Layout::new::<azul_impl::callbacks::OptionInlineText>(), "AzOptionInlineText"), (Layout::new::<AzOptionInlineText>(), "AzOptionInlineText"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlParseError>(), "AzXmlParseError"), (Layout::new::<AzXmlParseError>(), "AzXmlParseError"));
@@ -13859,25 +16473,37 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInput>(), "AzTextInput"), (Layout::new::<AzTextInput>(), "AzTextInput"));
         assert_eq!((Layout::new::<crate::widgets::number_input::NumberInput>(), "AzNumberInput"), (Layout::new::<AzNumberInput>(), "AzNumberInput"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeIdNodeMap>(), "AzNodeIdNodeMap"), (Layout::new::<AzNodeIdNodeMap>(), "AzNodeIdNodeMap"));
+        assert_eq!((Layout::new::<crate::widgets::node_graph::NodeGraphEditOp>(), "AzNodeGraphEditOp"), (Layout::new::<AzNodeGraphEditOp>(), "AzNodeGraphEditOp"));
+        assert_eq!((Layout::new::<crate::widgets::node_graph::NodeGraphEditOpVec>(), "AzNodeGraphEditOpVec"), (Layout::new::<AzNodeGraphEditOpVec>(), "AzNodeGraphEditOpVec"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeIdNodeMapVec>(), "AzNodeIdNodeMapVec"), (Layout::new::<AzNodeIdNodeMapVec>(), "AzNodeIdNodeMapVec"));
         assert_eq!((Layout::new::<azul_impl::css::CssDeclarationVec>(), "AzCssDeclarationVec"), (Layout::new::<AzCssDeclarationVec>(), "AzCssDeclarationVec"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeDataVec>(), "AzNodeDataVec"), (Layout::new::<AzNodeDataVec>(), "AzNodeDataVec"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlError>(), "AzXmlError"), (Layout::new::<AzXmlError>(), "AzXmlError"));
         assert_eq!((Layout::new::<azul_impl::dom::Dom>(), "AzDom"), (Layout::new::<AzDom>(), "AzDom"));
+        assert_eq!((Layout::new::<azul_impl::dom::TooltipContent>(), "AzTooltipContent"), (Layout::new::<AzTooltipContent>(), "AzTooltipContent"));
         assert_eq!((Layout::new::<azul_impl::css::CssRuleBlock>(), "AzCssRuleBlock"), (Layout::new::<AzCssRuleBlock>(), "AzCssRuleBlock"));
         assert_eq!((Layout::new::<crate::widgets::tabs::TabContent>(), "AzTabContent"), (Layout::new::<AzTabContent>(), "AzTabContent"));
         assert_eq!((Layout::new::<crate::widgets::frame::Frame>(), "AzFrame"), (Layout::new::<AzFrame>(), "AzFrame"));
-        assert_eq!((Layout::new::<crate::widgets::node_graph::NodeGraph>(), "AzNodeGraph"), (Layout::new::<AzNodeGraph>(), "AzNodeGraph"));
+        assert_eq!((Layout::new::<crate::widgets::node_graph::NodeGraphEdit>(), "AzNodeGraphEdit"), (Layout::new::<AzNodeGraphEdit>(), "AzNodeGraphEdit"));
+        assert_eq!((Layout::new::<crate::widgets::splitter::SplitterPane>(), "AzSplitterPane"), (Layout::new::<AzSplitterPane>(), "AzSplitterPane"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::StyledDom>(), "AzStyledDom"), (Layout::new::<AzStyledDom>(), "AzStyledDom"));
+        assert_eq!((Layout::new::<crate::widgets::splitter::SplitterPaneVec>(), "AzSplitterPaneVec"), (Layout::new::<AzSplitterPaneVec>(), "AzSplitterPaneVec"));
+        assert_eq!((Layout::new::<crate::widgets::node_graph::NodeGraphEditVec>(), "AzNodeGraphEditVec"), (Layout::new::<AzNodeGraphEditVec>(), "AzNodeGraphEditVec"));
         assert_eq!((Layout::new::<azul_impl::css::CssRuleBlockVec>(), "AzCssRuleBlockVec"), (Layout::new::<AzCssRuleBlockVec>(), "AzCssRuleBlockVec"));
         assert_eq!((Layout::new::<azul_impl::dom::OptionDom>(), "AzOptionDom"), (Layout::new::<AzOptionDom>(), "AzOptionDom"));
         assert_eq!((Layout::new::<azul_impl::xml::ResultXmlXmlError>(), "AzResultXmlXmlError"), (Layout::new::<AzResultXmlXmlError>(), "AzResultXmlXmlError"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgParseError>(), "AzSvgParseError"), (Layout::new::<AzSvgParseError>(), "AzSvgParseError"));
         assert_eq!((Layout::new::<azul_impl::callbacks::IFrameCallbackReturn>(), "AzIFrameCallbackReturn"), (Layout::new::<AzIFrameCallbackReturn>(), "AzIFrameCallbackReturn"));
+        assert_eq!((Layout::new::<azul_impl::dom::Tooltip>(), "AzTooltip"), (Layout::new::<AzTooltip>(), "AzTooltip"));
         assert_eq!((Layout::new::<azul_impl::css::Stylesheet>(), "AzStylesheet"), (Layout::new::<AzStylesheet>(), "AzStylesheet"));
+        assert_eq!((Layout::new::<crate::widgets::node_graph::NodeGraphHistory>(), "AzNodeGraphHistory"), (Layout::new::<AzNodeGraphHistory>(), "AzNodeGraphHistory"));
+        assert_eq!((Layout::new::<crate::widgets::dialog::Dialog>(), "AzDialog"), (Layout::new::<AzDialog>(), "AzDialog"));
+        assert_eq!((Layout::new::<crate::widgets::splitter::Splitter>(), "AzSplitter"), (Layout::new::<AzSplitter>(), "AzSplitter"));
         assert_eq!((Layout::new::<azul_impl::css::StylesheetVec>(), "AzStylesheetVec"), (Layout::new::<AzStylesheetVec>(), "AzStylesheetVec"));
         assert_eq!((Layout::new::<azul_impl::svg::ResultSvgXmlNodeSvgParseError>(), "AzResultSvgXmlNodeSvgParseError"), (Layout::new::<AzResultSvgXmlNodeSvgParseError>(), "AzResultSvgXmlNodeSvgParseError"));
         assert_eq!((Layout::new::<azul_impl::svg::ResultSvgSvgParseError>(), "AzResultSvgSvgParseError"), (Layout::new::<AzResultSvgSvgParseError>(), "AzResultSvgSvgParseError"));
         assert_eq!((Layout::new::<azul_impl::css::Css>(), "AzCss"), (Layout::new::<AzCss>(), "AzCss"));
+        assert_eq!((Layout::new::<crate::widgets::node_graph::NodeGraph>(), "AzNodeGraph"), (Layout::new::<AzNodeGraph>(), "AzNodeGraph"));
+        assert_eq!((Layout::new::<crate::widgets::node_graph::ResultNodeGraphNodeGraphError>(), "AzResultNodeGraphNodeGraphError"), (Layout::new::<AzResultNodeGraphNodeGraphError>(), "AzResultNodeGraphNodeGraphError"));
     }
 }
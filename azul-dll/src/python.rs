@@ -1586,6 +1586,21 @@ pub struct AzCssNthChildPattern {
     pub offset: u32,
 }
 
+/// Re-export of rust-allocated (stack based) `CssFontFaceStyle` struct
+#[repr(C)]
+pub enum AzCssFontFaceStyle {
+    Normal,
+    Italic,
+    Oblique,
+}
+
+/// `U+0000-00FF`: inclusive range of unicode code points
+#[repr(C)]
+pub struct AzCssUnicodeRange {
+    pub start: u32,
+    pub end: u32,
+}
+
 /// Re-export of rust-allocated (stack based) `CssPropertyType` struct
 #[repr(C)]
 pub enum AzCssPropertyType {
@@ -1663,6 +1678,8 @@ pub enum AzCssPropertyType {
     Filter,
     BackdropFilter,
     TextShadow,
+    VerticalAlign,
+    TextDecoration,
 }
 
 /// Re-export of rust-allocated (stack based) `ColorU` struct
@@ -1760,6 +1777,7 @@ pub enum AzLayoutDisplay {
     Flex,
     Block,
     InlineBlock,
+    Inline,
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutFloat` struct
@@ -1789,6 +1807,17 @@ pub enum AzLayoutPosition {
     Fixed,
 }
 
+/// Re-export of rust-allocated (stack based) `LayoutVerticalAlign` struct
+#[repr(C)]
+pub enum AzLayoutVerticalAlign {
+    Baseline,
+    Top,
+    Middle,
+    Bottom,
+    Sub,
+    Super,
+}
+
 /// Re-export of rust-allocated (stack based) `LayoutFlexWrap` struct
 #[repr(C)]
 pub enum AzLayoutFlexWrap {
@@ -1925,6 +1954,15 @@ pub enum AzStyleTextAlign {
     Right,
 }
 
+/// Re-export of rust-allocated (stack based) `StyleTextDecoration` struct
+#[repr(C)]
+pub enum AzStyleTextDecoration {
+    None,
+    Underline,
+    Overline,
+    LineThrough,
+}
+
 /// Re-export of rust-allocated (stack based) `Ribbon` struct
 #[repr(C)]
 pub struct AzRibbon {
@@ -2141,6 +2179,33 @@ pub struct AzNodeGraphOnNodeFieldEditedCallback {
     pub cb: AzNodeGraphOnNodeFieldEditedCallbackType,
 }
 
+/// `AzNodeGraphOnNodeGraphZoomedCallbackType` struct
+pub type AzNodeGraphOnNodeGraphZoomedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, f32, AzLogicalPosition) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `NodeGraphOnNodeGraphZoomedCallback` struct
+#[repr(C)]
+pub struct AzNodeGraphOnNodeGraphZoomedCallback {
+    pub cb: AzNodeGraphOnNodeGraphZoomedCallbackType,
+}
+
+/// `AzNodeGraphOnSelectionChangedCallbackType` struct
+pub type AzNodeGraphOnSelectionChangedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphNodeIdVec) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `NodeGraphOnSelectionChangedCallback` struct
+#[repr(C)]
+pub struct AzNodeGraphOnSelectionChangedCallback {
+    pub cb: AzNodeGraphOnSelectionChangedCallbackType,
+}
+
+/// `AzNodeGraphOnHistoryChangedCallbackType` struct
+pub type AzNodeGraphOnHistoryChangedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphHistory) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `NodeGraphOnHistoryChangedCallback` struct
+#[repr(C)]
+pub struct AzNodeGraphOnHistoryChangedCallback {
+    pub cb: AzNodeGraphOnHistoryChangedCallbackType,
+}
+
 /// Re-export of rust-allocated (stack based) `InputOutputTypeId` struct
 #[repr(C)]
 pub struct AzInputOutputTypeId {
@@ -2180,6 +2245,24 @@ pub struct AzNodeDragAmount {
     pub y: f32,
 }
 
+/// Re-export of rust-allocated (stack based) `NodeGraphError` struct
+#[repr(C)]
+pub enum AzNodeGraphError {
+    NodeMimeTypeMismatch,
+    NodeInvalidIndex,
+    NodeInvalidNode,
+    NoRootNode,
+    InvalidJson,
+}
+
+/// How the selection should change in response to a user action
+#[repr(C)]
+pub enum AzListViewSelectionChange {
+    Replace,
+    Toggle,
+    Extend,
+}
+
 /// `AzListViewOnLazyLoadScrollCallbackType` struct
 pub type AzListViewOnLazyLoadScrollCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzListViewState) -> AzUpdate;
 
@@ -2207,6 +2290,280 @@ pub struct AzListViewOnRowClickCallback {
     pub cb: AzListViewOnRowClickCallbackType,
 }
 
+/// `AzListViewOnRowSelectCallbackType` struct
+pub type AzListViewOnRowSelectCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzListViewState, usize, AzListViewSelectionChange) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `ListViewOnRowSelectCallback` struct
+#[repr(C)]
+pub struct AzListViewOnRowSelectCallback {
+    pub cb: AzListViewOnRowSelectCallbackType,
+}
+
+/// `AzListViewOnColumnResizeCallbackType` struct
+pub type AzListViewOnColumnResizeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzListViewState, usize, f32) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `ListViewOnColumnResizeCallback` struct
+#[repr(C)]
+pub struct AzListViewOnColumnResizeCallback {
+    pub cb: AzListViewOnColumnResizeCallbackType,
+}
+
+/// `AzListViewRowProviderCallbackType` struct
+pub type AzListViewRowProviderCallbackType = extern "C" fn(&mut AzRefAny, usize, usize) -> AzListViewRowVec;
+
+/// Re-export of rust-allocated (stack based) `ListViewRowProviderCallback` struct
+#[repr(C)]
+pub struct AzListViewRowProviderCallback {
+    pub cb: AzListViewRowProviderCallbackType,
+}
+
+/// Re-export of rust-allocated (stack based) `TreeViewSelectionMode` struct
+#[repr(C)]
+pub enum AzTreeViewSelectionMode {
+    Single,
+    Multiple,
+}
+
+/// Re-export of rust-allocated (stack based) `TreeViewSelectionChange` struct
+#[repr(C)]
+pub enum AzTreeViewSelectionChange {
+    Replace,
+    Toggle,
+    Extend,
+}
+
+/// `AzTreeViewOnExpandCallbackType` struct
+pub type AzTreeViewOnExpandCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, bool) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnExpandCallback` struct
+#[repr(C)]
+pub struct AzTreeViewOnExpandCallback {
+    pub cb: AzTreeViewOnExpandCallbackType,
+}
+
+/// `AzTreeViewOnSelectCallbackType` struct
+pub type AzTreeViewOnSelectCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, AzTreeViewSelectionChange) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnSelectCallback` struct
+#[repr(C)]
+pub struct AzTreeViewOnSelectCallback {
+    pub cb: AzTreeViewOnSelectCallbackType,
+}
+
+/// `AzTreeViewOnLazyLoadCallbackType` struct
+pub type AzTreeViewOnLazyLoadCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnLazyLoadCallback` struct
+#[repr(C)]
+pub struct AzTreeViewOnLazyLoadCallback {
+    pub cb: AzTreeViewOnLazyLoadCallbackType,
+}
+
+/// `AzTreeViewOnScrollCallbackType` struct
+pub type AzTreeViewOnScrollCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnScrollCallback` struct
+#[repr(C)]
+pub struct AzTreeViewOnScrollCallback {
+    pub cb: AzTreeViewOnScrollCallbackType,
+}
+
+/// Position of a cell in the DataGrid
+#[repr(C)]
+pub struct AzDataGridCell {
+    pub row: usize,
+    pub column: usize,
+}
+
+/// `AzDataGridCellProviderCallbackType` struct
+pub type AzDataGridCellProviderCallbackType = extern "C" fn(&mut AzRefAny, usize, usize) -> AzString;
+
+/// Re-export of rust-allocated (stack based) `DataGridCellProviderCallback` struct
+#[repr(C)]
+pub struct AzDataGridCellProviderCallback {
+    pub cb: AzDataGridCellProviderCallbackType,
+}
+
+/// `AzDataGridCellStyleCallbackType` struct
+pub type AzDataGridCellStyleCallbackType = extern "C" fn(&mut AzRefAny, usize, usize, bool) -> AzNodeDataInlineCssPropertyVec;
+
+/// Re-export of rust-allocated (stack based) `DataGridCellStyleCallback` struct
+#[repr(C)]
+pub struct AzDataGridCellStyleCallback {
+    pub cb: AzDataGridCellStyleCallbackType,
+}
+
+/// `AzDataGridOnSelectCallbackType` struct
+pub type AzDataGridOnSelectCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzDataGridRange) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `DataGridOnSelectCallback` struct
+#[repr(C)]
+pub struct AzDataGridOnSelectCallback {
+    pub cb: AzDataGridOnSelectCallbackType,
+}
+
+/// `AzDataGridOnScrollCallbackType` struct
+pub type AzDataGridOnScrollCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, usize) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `DataGridOnScrollCallback` struct
+#[repr(C)]
+pub struct AzDataGridOnScrollCallback {
+    pub cb: AzDataGridOnScrollCallbackType,
+}
+
+/// `AzDataGridOnCellEditCallbackType` struct
+pub type AzDataGridOnCellEditCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, usize, AzString) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `DataGridOnCellEditCallback` struct
+#[repr(C)]
+pub struct AzDataGridOnCellEditCallback {
+    pub cb: AzDataGridOnCellEditCallbackType,
+}
+
+/// `AzDataGridOnColumnResizeCallbackType` struct
+pub type AzDataGridOnColumnResizeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, f32) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `DataGridOnColumnResizeCallback` struct
+#[repr(C)]
+pub struct AzDataGridOnColumnResizeCallback {
+    pub cb: AzDataGridOnColumnResizeCallbackType,
+}
+
+/// `AzDataGridOnRowResizeCallbackType` struct
+pub type AzDataGridOnRowResizeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, f32) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `DataGridOnRowResizeCallback` struct
+#[repr(C)]
+pub struct AzDataGridOnRowResizeCallback {
+    pub cb: AzDataGridOnRowResizeCallbackType,
+}
+
+/// `AzDataGridOnCopyRangeCallbackType` struct
+pub type AzDataGridOnCopyRangeCallbackType = extern "C" fn(&mut AzRefAny, AzDataGridRange) -> AzString;
+
+/// Re-export of rust-allocated (stack based) `DataGridOnCopyRangeCallback` struct
+#[repr(C)]
+pub struct AzDataGridOnCopyRangeCallback {
+    pub cb: AzDataGridOnCopyRangeCallbackType,
+}
+
+/// `AzDataGridOnClearRangeCallbackType` struct
+pub type AzDataGridOnClearRangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzDataGridRange) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `DataGridOnClearRangeCallback` struct
+#[repr(C)]
+pub struct AzDataGridOnClearRangeCallback {
+    pub cb: AzDataGridOnClearRangeCallbackType,
+}
+
+/// Calendar date (proleptic Gregorian calendar), not validated on construction
+#[repr(C)]
+pub struct AzDate {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+/// Day of the week
+#[repr(C)]
+pub enum AzWeekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+/// Time of day, not validated on construction
+#[repr(C)]
+pub struct AzTime {
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+/// `AzDatePickerOnDateChangeCallbackType` struct
+pub type AzDatePickerOnDateChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzDatePickerState) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `DatePickerOnDateChangeCallback` struct
+#[repr(C)]
+pub struct AzDatePickerOnDateChangeCallback {
+    pub cb: AzDatePickerOnDateChangeCallbackType,
+}
+
+/// `AzTimePickerOnTimeChangeCallbackType` struct
+pub type AzTimePickerOnTimeChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTime) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `TimePickerOnTimeChangeCallback` struct
+#[repr(C)]
+pub struct AzTimePickerOnTimeChangeCallback {
+    pub cb: AzTimePickerOnTimeChangeCallbackType,
+}
+
+/// `AzDateTimePickerOnDateTimeChangeCallbackType` struct
+pub type AzDateTimePickerOnDateTimeChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzDateTimePickerState) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `DateTimePickerOnDateTimeChangeCallback` struct
+#[repr(C)]
+pub struct AzDateTimePickerOnDateTimeChangeCallback {
+    pub cb: AzDateTimePickerOnDateTimeChangeCallbackType,
+}
+
+/// Icon shown left of the message of a `Dialog`
+#[repr(C)]
+pub enum AzDialogIcon {
+    None,
+    Info,
+    Warning,
+    Error,
+    Question,
+}
+
+/// Set of buttons shown at the bottom of a `Dialog`
+#[repr(C)]
+pub enum AzDialogButtons {
+    Ok,
+    OkCancel,
+    YesNo,
+    YesNoCancel,
+}
+
+/// Button that closed a `Dialog`
+#[repr(C)]
+pub enum AzDialogButton {
+    Ok,
+    Cancel,
+    Yes,
+    No,
+}
+
+/// `AzDialogOnCloseCallbackType` struct
+pub type AzDialogOnCloseCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzDialogResult) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `DialogOnCloseCallback` struct
+#[repr(C)]
+pub struct AzDialogOnCloseCallback {
+    pub cb: AzDialogOnCloseCallbackType,
+}
+
+/// Direction in which the panes of a `Splitter` are laid out
+#[repr(C)]
+pub enum AzSplitterDirection {
+    Horizontal,
+    Vertical,
+}
+
+/// `AzSplitterOnResizeCallbackType` struct
+pub type AzSplitterOnResizeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzSplitterState) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `SplitterOnResizeCallback` struct
+#[repr(C)]
+pub struct AzSplitterOnResizeCallback {
+    pub cb: AzSplitterOnResizeCallbackType,
+}
+
 /// `AzDropDownOnChoiceChangeCallbackType` struct
 pub type AzDropDownOnChoiceChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize) -> AzUpdate;
 
@@ -2857,6 +3214,39 @@ pub enum AzListViewRowVecDestructor {
 /// `AzListViewRowVecDestructorType` struct
 pub type AzListViewRowVecDestructorType = extern "C" fn(&mut AzListViewRowVec);
 
+/// Re-export of rust-allocated (stack based) `TreeViewItemVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzTreeViewItemVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzTreeViewItemVecDestructorType),
+}
+
+/// `AzTreeViewItemVecDestructorType` struct
+pub type AzTreeViewItemVecDestructorType = extern "C" fn(&mut AzTreeViewItemVec);
+
+/// Re-export of rust-allocated (stack based) `SplitterPaneVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzSplitterPaneVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzSplitterPaneVecDestructorType),
+}
+
+/// `AzSplitterPaneVecDestructorType` struct
+pub type AzSplitterPaneVecDestructorType = extern "C" fn(&mut AzSplitterPaneVec);
+
+/// Re-export of rust-allocated (stack based) `SplitterPaneStateVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzSplitterPaneStateVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzSplitterPaneStateVecDestructorType),
+}
+
+/// `AzSplitterPaneStateVecDestructorType` struct
+pub type AzSplitterPaneStateVecDestructorType = extern "C" fn(&mut AzSplitterPaneStateVec);
+
 /// Re-export of rust-allocated (stack based) `StyleFilterVecDestructor` struct
 #[repr(C, u8)]
 pub enum AzStyleFilterVecDestructor {
@@ -2901,6 +3291,39 @@ pub enum AzInputOutputTypeIdInfoMapVecDestructor {
 /// `AzInputOutputTypeIdInfoMapVecDestructorType` struct
 pub type AzInputOutputTypeIdInfoMapVecDestructorType = extern "C" fn(&mut AzInputOutputTypeIdInfoMapVec);
 
+/// Re-export of rust-allocated (stack based) `NodeGraphNodeIdVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzNodeGraphNodeIdVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzNodeGraphNodeIdVecDestructorType),
+}
+
+/// `AzNodeGraphNodeIdVecDestructorType` struct
+pub type AzNodeGraphNodeIdVecDestructorType = extern "C" fn(&mut AzNodeGraphNodeIdVec);
+
+/// Re-export of rust-allocated (stack based) `NodeGraphEditOpVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzNodeGraphEditOpVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzNodeGraphEditOpVecDestructorType),
+}
+
+/// `AzNodeGraphEditOpVecDestructorType` struct
+pub type AzNodeGraphEditOpVecDestructorType = extern "C" fn(&mut AzNodeGraphEditOpVec);
+
+/// Re-export of rust-allocated (stack based) `NodeGraphEditVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzNodeGraphEditVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzNodeGraphEditVecDestructorType),
+}
+
+/// `AzNodeGraphEditVecDestructorType` struct
+pub type AzNodeGraphEditVecDestructorType = extern "C" fn(&mut AzNodeGraphEditVec);
+
 /// Re-export of rust-allocated (stack based) `NodeIdNodeMapVecDestructor` struct
 #[repr(C, u8)]
 pub enum AzNodeIdNodeMapVecDestructor {
@@ -2989,6 +3412,17 @@ pub enum AzAccessibilityStateVecDestructor {
 /// `AzAccessibilityStateVecDestructorType` struct
 pub type AzAccessibilityStateVecDestructorType = extern "C" fn(&mut AzAccessibilityStateVec);
 
+/// Re-export of rust-allocated (stack based) `KeyboardShortcutVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzKeyboardShortcutVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzKeyboardShortcutVecDestructorType),
+}
+
+/// `AzKeyboardShortcutVecDestructorType` struct
+pub type AzKeyboardShortcutVecDestructorType = extern "C" fn(&mut AzKeyboardShortcutVec);
+
 /// Re-export of rust-allocated (stack based) `MenuItemVecDestructor` struct
 #[repr(C, u8)]
 pub enum AzMenuItemVecDestructor {
@@ -3077,6 +3511,28 @@ pub enum AzInlineGlyphVecDestructor {
 /// `AzInlineGlyphVecDestructorType` struct
 pub type AzInlineGlyphVecDestructorType = extern "C" fn(&mut AzInlineGlyphVec);
 
+/// Re-export of rust-allocated (stack based) `TextLineMetricsVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzTextLineMetricsVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzTextLineMetricsVecDestructorType),
+}
+
+/// `AzTextLineMetricsVecDestructorType` struct
+pub type AzTextLineMetricsVecDestructorType = extern "C" fn(&mut AzTextLineMetricsVec);
+
+/// Re-export of rust-allocated (stack based) `InlineTextRunVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzInlineTextRunVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzInlineTextRunVecDestructorType),
+}
+
+/// `AzInlineTextRunVecDestructorType` struct
+pub type AzInlineTextRunVecDestructorType = extern "C" fn(&mut AzInlineTextRunVec);
+
 /// Re-export of rust-allocated (stack based) `InlineTextHitVecDestructor` struct
 #[repr(C, u8)]
 pub enum AzInlineTextHitVecDestructor {
@@ -3374,6 +3830,39 @@ pub enum AzStylesheetVecDestructor {
 /// `AzStylesheetVecDestructorType` struct
 pub type AzStylesheetVecDestructorType = extern "C" fn(&mut AzStylesheetVec);
 
+/// Re-export of rust-allocated (stack based) `CssFontFaceVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzCssFontFaceVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzCssFontFaceVecDestructorType),
+}
+
+/// `AzCssFontFaceVecDestructorType` struct
+pub type AzCssFontFaceVecDestructorType = extern "C" fn(&mut AzCssFontFaceVec);
+
+/// Re-export of rust-allocated (stack based) `CssFontFaceSourceVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzCssFontFaceSourceVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzCssFontFaceSourceVecDestructorType),
+}
+
+/// `AzCssFontFaceSourceVecDestructorType` struct
+pub type AzCssFontFaceSourceVecDestructorType = extern "C" fn(&mut AzCssFontFaceSourceVec);
+
+/// Re-export of rust-allocated (stack based) `CssUnicodeRangeVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzCssUnicodeRangeVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzCssUnicodeRangeVecDestructorType),
+}
+
+/// `AzCssUnicodeRangeVecDestructorType` struct
+pub type AzCssUnicodeRangeVecDestructorType = extern "C" fn(&mut AzCssUnicodeRangeVec);
+
 /// Re-export of rust-allocated (stack based) `CssRuleBlockVecDestructor` struct
 #[repr(C, u8)]
 pub enum AzCssRuleBlockVecDestructor {
@@ -3763,6 +4252,23 @@ pub struct AzHidpiAdjustedBounds {
     pub hidpi_factor: f32,
 }
 
+/// Metrics of a single line of a measured text
+#[repr(C)]
+pub struct AzTextLineMetrics {
+    pub bounds: AzLogicalRect,
+    pub baseline_px: f32,
+    pub glyph_count: usize,
+}
+
+/// Re-export of rust-allocated (stack based) `InlineTextRun` struct
+#[repr(C)]
+pub struct AzInlineTextRun {
+    pub node_id: AzNodeId,
+    pub glyph_start: usize,
+    pub glyph_end: usize,
+    pub font_size_px: f32,
+}
+
 /// Re-export of rust-allocated (stack based) `InlineGlyph` struct
 #[repr(C)]
 pub struct AzInlineGlyph {
@@ -4619,6 +5125,16 @@ pub enum AzLayoutTopValue {
     Exact(AzLayoutTop),
 }
 
+/// Re-export of rust-allocated (stack based) `LayoutVerticalAlignValue` struct
+#[repr(C, u8)]
+pub enum AzLayoutVerticalAlignValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzLayoutVerticalAlign),
+}
+
 /// Re-export of rust-allocated (stack based) `LayoutWidthValue` struct
 #[repr(C, u8)]
 pub enum AzLayoutWidthValue {
@@ -4869,6 +5385,16 @@ pub enum AzStyleTextAlignValue {
     Exact(AzStyleTextAlign),
 }
 
+/// Re-export of rust-allocated (stack based) `StyleTextDecorationValue` struct
+#[repr(C, u8)]
+pub enum AzStyleTextDecorationValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleTextDecoration),
+}
+
 /// Re-export of rust-allocated (stack based) `StyleTextColorValue` struct
 #[repr(C, u8)]
 pub enum AzStyleTextColorValue {
@@ -5085,6 +5611,27 @@ pub struct AzNodeGraphOnNodeFieldEdited {
     pub callback: AzNodeGraphOnNodeFieldEditedCallback,
 }
 
+/// Re-export of rust-allocated (stack based) `NodeGraphOnNodeGraphZoomed` struct
+#[repr(C)]
+pub struct AzNodeGraphOnNodeGraphZoomed {
+    pub data: AzRefAny,
+    pub callback: AzNodeGraphOnNodeGraphZoomedCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `NodeGraphOnSelectionChanged` struct
+#[repr(C)]
+pub struct AzNodeGraphOnSelectionChanged {
+    pub data: AzRefAny,
+    pub callback: AzNodeGraphOnSelectionChangedCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `NodeGraphOnHistoryChanged` struct
+#[repr(C)]
+pub struct AzNodeGraphOnHistoryChanged {
+    pub data: AzRefAny,
+    pub callback: AzNodeGraphOnHistoryChangedCallback,
+}
+
 /// Re-export of rust-allocated (stack based) `OutputNodeAndIndex` struct
 #[repr(C)]
 pub struct AzOutputNodeAndIndex {
@@ -5099,6 +5646,22 @@ pub struct AzInputNodeAndIndex {
     pub input_index: usize,
 }
 
+/// Connection from the output of one node to the input of another node
+#[repr(C)]
+pub struct AzNodeGraphConnection {
+    pub output: AzNodeGraphNodeId,
+    pub output_index: usize,
+    pub input: AzNodeGraphNodeId,
+    pub input_index: usize,
+}
+
+/// Node that was moved by `amount` (in graph coordinates)
+#[repr(C)]
+pub struct AzNodeGraphNodeMove {
+    pub node_id: AzNodeGraphNodeId,
+    pub amount: AzNodeDragAmount,
+}
+
 /// Re-export of rust-allocated (stack based) `ListViewOnLazyLoadScroll` struct
 #[repr(C)]
 pub struct AzListViewOnLazyLoadScroll {
@@ -5120,6 +5683,167 @@ pub struct AzListViewOnRowClick {
     pub callback: AzListViewOnRowClickCallback,
 }
 
+/// Re-export of rust-allocated (stack based) `ListViewOnRowSelect` struct
+#[repr(C)]
+pub struct AzListViewOnRowSelect {
+    pub data: AzRefAny,
+    pub callback: AzListViewOnRowSelectCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `ListViewOnColumnResize` struct
+#[repr(C)]
+pub struct AzListViewOnColumnResize {
+    pub data: AzRefAny,
+    pub callback: AzListViewOnColumnResizeCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `ListViewRowProvider` struct
+#[repr(C)]
+pub struct AzListViewRowProvider {
+    pub data: AzRefAny,
+    pub callback: AzListViewRowProviderCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnExpand` struct
+#[repr(C)]
+pub struct AzTreeViewOnExpand {
+    pub data: AzRefAny,
+    pub callback: AzTreeViewOnExpandCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnSelect` struct
+#[repr(C)]
+pub struct AzTreeViewOnSelect {
+    pub data: AzRefAny,
+    pub callback: AzTreeViewOnSelectCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnLazyLoad` struct
+#[repr(C)]
+pub struct AzTreeViewOnLazyLoad {
+    pub data: AzRefAny,
+    pub callback: AzTreeViewOnLazyLoadCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnScroll` struct
+#[repr(C)]
+pub struct AzTreeViewOnScroll {
+    pub data: AzRefAny,
+    pub callback: AzTreeViewOnScrollCallback,
+}
+
+/// Rectangular range of selected cells
+#[repr(C)]
+pub struct AzDataGridRange {
+    pub anchor: AzDataGridCell,
+    pub cursor: AzDataGridCell,
+}
+
+/// Re-export of rust-allocated (stack based) `DataGridCellProvider` struct
+#[repr(C)]
+pub struct AzDataGridCellProvider {
+    pub data: AzRefAny,
+    pub callback: AzDataGridCellProviderCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `DataGridCellStyle` struct
+#[repr(C)]
+pub struct AzDataGridCellStyle {
+    pub data: AzRefAny,
+    pub callback: AzDataGridCellStyleCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `DataGridOnSelect` struct
+#[repr(C)]
+pub struct AzDataGridOnSelect {
+    pub data: AzRefAny,
+    pub callback: AzDataGridOnSelectCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `DataGridOnScroll` struct
+#[repr(C)]
+pub struct AzDataGridOnScroll {
+    pub data: AzRefAny,
+    pub callback: AzDataGridOnScrollCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `DataGridOnCellEdit` struct
+#[repr(C)]
+pub struct AzDataGridOnCellEdit {
+    pub data: AzRefAny,
+    pub callback: AzDataGridOnCellEditCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `DataGridOnColumnResize` struct
+#[repr(C)]
+pub struct AzDataGridOnColumnResize {
+    pub data: AzRefAny,
+    pub callback: AzDataGridOnColumnResizeCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `DataGridOnRowResize` struct
+#[repr(C)]
+pub struct AzDataGridOnRowResize {
+    pub data: AzRefAny,
+    pub callback: AzDataGridOnRowResizeCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `DataGridOnCopyRange` struct
+#[repr(C)]
+pub struct AzDataGridOnCopyRange {
+    pub data: AzRefAny,
+    pub callback: AzDataGridOnCopyRangeCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `DataGridOnClearRange` struct
+#[repr(C)]
+pub struct AzDataGridOnClearRange {
+    pub data: AzRefAny,
+    pub callback: AzDataGridOnClearRangeCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `DatePickerOnDateChange` struct
+#[repr(C)]
+pub struct AzDatePickerOnDateChange {
+    pub data: AzRefAny,
+    pub callback: AzDatePickerOnDateChangeCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `TimePickerOnTimeChange` struct
+#[repr(C)]
+pub struct AzTimePickerOnTimeChange {
+    pub data: AzRefAny,
+    pub callback: AzTimePickerOnTimeChangeCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `DateTimePickerOnDateTimeChange` struct
+#[repr(C)]
+pub struct AzDateTimePickerOnDateTimeChange {
+    pub data: AzRefAny,
+    pub callback: AzDateTimePickerOnDateTimeChangeCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `DialogOnClose` struct
+#[repr(C)]
+pub struct AzDialogOnClose {
+    pub data: AzRefAny,
+    pub callback: AzDialogOnCloseCallback,
+}
+
+/// Size of a single pane, as reported by `on_resize`
+#[repr(C)]
+pub struct AzSplitterPaneState {
+    pub size: AzOptionF32EnumWrapper,
+    pub collapsed: bool,
+}
+
+/// Re-export of rust-allocated (stack based) `SplitterOnResize` struct
+#[repr(C)]
+pub struct AzSplitterOnResize {
+    pub data: AzRefAny,
+    pub callback: AzSplitterOnResizeCallback,
+}
+
 /// Re-export of rust-allocated (stack based) `DropDownOnChoiceChange` struct
 #[repr(C)]
 pub struct AzDropDownOnChoiceChange {
@@ -5261,6 +5985,15 @@ pub struct AzSvgFillStyle {
     pub high_quality_aa: bool,
 }
 
+/// Location of the start tag of an `XmlNode` in the XML source
+#[repr(C)]
+pub struct AzXmlSpan {
+    pub file: usize,
+    pub start: usize,
+    pub end: usize,
+    pub pos: AzSvgParseErrorPosition,
+}
+
 /// Re-export of rust-allocated (stack based) `InstantPtr` struct
 #[repr(C)]
 pub struct AzInstantPtr {
@@ -5292,6 +6025,24 @@ pub struct AzThreadWriteBackMsg {
     pub callback: AzWriteBackCallback,
 }
 
+/// Wrapper over a Rust-allocated `Vec<TreeViewItem>`
+#[repr(C)]
+pub struct AzTreeViewItemVec {
+    pub(crate) ptr: *const AzTreeViewItem,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzTreeViewItemVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<SplitterPaneState>`
+#[repr(C)]
+pub struct AzSplitterPaneStateVec {
+    pub(crate) ptr: *const AzSplitterPaneState,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzSplitterPaneStateVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<LogicalRect>`
 #[repr(C)]
 pub struct AzLogicalRectVec {
@@ -5301,6 +6052,15 @@ pub struct AzLogicalRectVec {
     pub destructor: AzLogicalRectVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<NodeGraphNodeId>`
+#[repr(C)]
+pub struct AzNodeGraphNodeIdVec {
+    pub(crate) ptr: *const AzNodeGraphNodeId,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzNodeGraphNodeIdVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<InputOutputTypeId>`
 #[repr(C)]
 pub struct AzInputOutputTypeIdVec {
@@ -5364,6 +6124,24 @@ pub struct AzInlineGlyphVec {
     pub destructor: AzInlineGlyphVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<TextLineMetrics>`
+#[repr(C)]
+pub struct AzTextLineMetricsVec {
+    pub(crate) ptr: *const AzTextLineMetrics,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzTextLineMetricsVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<InlineTextRun>`
+#[repr(C)]
+pub struct AzInlineTextRunVec {
+    pub(crate) ptr: *const AzInlineTextRun,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzInlineTextRunVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<InlineTextHit>`
 #[repr(C)]
 pub struct AzInlineTextHitVec {
@@ -5481,6 +6259,15 @@ pub struct AzScanCodeVec {
     pub destructor: AzScanCodeVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<CssUnicodeRange>`
+#[repr(C)]
+pub struct AzCssUnicodeRangeVec {
+    pub(crate) ptr: *const AzCssUnicodeRange,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzCssUnicodeRangeVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<u16>`
 #[repr(C)]
 pub struct AzU16Vec {
@@ -5578,6 +6365,34 @@ pub enum AzOptionSvgPoint {
     Some(AzSvgPoint),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionTreeViewOnExpand` struct
+#[repr(C, u8)]
+pub enum AzOptionTreeViewOnExpand {
+    None,
+    Some(AzTreeViewOnExpand),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionTreeViewOnSelect` struct
+#[repr(C, u8)]
+pub enum AzOptionTreeViewOnSelect {
+    None,
+    Some(AzTreeViewOnSelect),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionTreeViewOnLazyLoad` struct
+#[repr(C, u8)]
+pub enum AzOptionTreeViewOnLazyLoad {
+    None,
+    Some(AzTreeViewOnLazyLoad),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionTreeViewOnScroll` struct
+#[repr(C, u8)]
+pub enum AzOptionTreeViewOnScroll {
+    None,
+    Some(AzTreeViewOnScroll),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionListViewOnRowClick` struct
 #[repr(C, u8)]
 pub enum AzOptionListViewOnRowClick {
@@ -5585,6 +6400,146 @@ pub enum AzOptionListViewOnRowClick {
     Some(AzListViewOnRowClick),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionListViewOnRowSelect` struct
+#[repr(C, u8)]
+pub enum AzOptionListViewOnRowSelect {
+    None,
+    Some(AzListViewOnRowSelect),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionListViewOnColumnResize` struct
+#[repr(C, u8)]
+pub enum AzOptionListViewOnColumnResize {
+    None,
+    Some(AzListViewOnColumnResize),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionListViewRowProvider` struct
+#[repr(C, u8)]
+pub enum AzOptionListViewRowProvider {
+    None,
+    Some(AzListViewRowProvider),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionDataGridRange` struct
+#[repr(C, u8)]
+pub enum AzOptionDataGridRange {
+    None,
+    Some(AzDataGridRange),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionDataGridCellProvider` struct
+#[repr(C, u8)]
+pub enum AzOptionDataGridCellProvider {
+    None,
+    Some(AzDataGridCellProvider),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionDataGridCellStyle` struct
+#[repr(C, u8)]
+pub enum AzOptionDataGridCellStyle {
+    None,
+    Some(AzDataGridCellStyle),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionDataGridOnSelect` struct
+#[repr(C, u8)]
+pub enum AzOptionDataGridOnSelect {
+    None,
+    Some(AzDataGridOnSelect),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionDataGridOnScroll` struct
+#[repr(C, u8)]
+pub enum AzOptionDataGridOnScroll {
+    None,
+    Some(AzDataGridOnScroll),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionDataGridOnCellEdit` struct
+#[repr(C, u8)]
+pub enum AzOptionDataGridOnCellEdit {
+    None,
+    Some(AzDataGridOnCellEdit),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionDataGridOnColumnResize` struct
+#[repr(C, u8)]
+pub enum AzOptionDataGridOnColumnResize {
+    None,
+    Some(AzDataGridOnColumnResize),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionDataGridOnRowResize` struct
+#[repr(C, u8)]
+pub enum AzOptionDataGridOnRowResize {
+    None,
+    Some(AzDataGridOnRowResize),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionDataGridOnCopyRange` struct
+#[repr(C, u8)]
+pub enum AzOptionDataGridOnCopyRange {
+    None,
+    Some(AzDataGridOnCopyRange),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionDataGridOnClearRange` struct
+#[repr(C, u8)]
+pub enum AzOptionDataGridOnClearRange {
+    None,
+    Some(AzDataGridOnClearRange),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionDate` struct
+#[repr(C, u8)]
+pub enum AzOptionDate {
+    None,
+    Some(AzDate),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionTime` struct
+#[repr(C, u8)]
+pub enum AzOptionTime {
+    None,
+    Some(AzTime),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionDatePickerOnDateChange` struct
+#[repr(C, u8)]
+pub enum AzOptionDatePickerOnDateChange {
+    None,
+    Some(AzDatePickerOnDateChange),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionTimePickerOnTimeChange` struct
+#[repr(C, u8)]
+pub enum AzOptionTimePickerOnTimeChange {
+    None,
+    Some(AzTimePickerOnTimeChange),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionDateTimePickerOnDateTimeChange` struct
+#[repr(C, u8)]
+pub enum AzOptionDateTimePickerOnDateTimeChange {
+    None,
+    Some(AzDateTimePickerOnDateTimeChange),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionDialogOnClose` struct
+#[repr(C, u8)]
+pub enum AzOptionDialogOnClose {
+    None,
+    Some(AzDialogOnClose),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionSplitterOnResize` struct
+#[repr(C, u8)]
+pub enum AzOptionSplitterOnResize {
+    None,
+    Some(AzSplitterOnResize),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionListViewOnColumnClick` struct
 #[repr(C, u8)]
 pub enum AzOptionListViewOnColumnClick {
@@ -5613,6 +6568,27 @@ pub enum AzOptionDropDownOnChoiceChange {
     Some(AzDropDownOnChoiceChange),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionNodeGraphOnNodeGraphZoomed` struct
+#[repr(C, u8)]
+pub enum AzOptionNodeGraphOnNodeGraphZoomed {
+    None,
+    Some(AzNodeGraphOnNodeGraphZoomed),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionNodeGraphOnSelectionChanged` struct
+#[repr(C, u8)]
+pub enum AzOptionNodeGraphOnSelectionChanged {
+    None,
+    Some(AzNodeGraphOnSelectionChanged),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionNodeGraphOnHistoryChanged` struct
+#[repr(C, u8)]
+pub enum AzOptionNodeGraphOnHistoryChanged {
+    None,
+    Some(AzNodeGraphOnHistoryChanged),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionNodeGraphOnNodeAdded` struct
 #[repr(C, u8)]
 pub enum AzOptionNodeGraphOnNodeAdded {
@@ -6105,6 +7081,15 @@ pub struct AzMarshaledLayoutCallback {
     pub cb: AzMarshaledLayoutCallbackInner,
 }
 
+/// Size of a string measured without laying out a DOM
+#[repr(C)]
+pub struct AzTextMetrics {
+    pub width: f32,
+    pub height: f32,
+    pub line_count: usize,
+    pub lines: AzTextLineMetricsVec,
+}
+
 /// Re-export of rust-allocated (stack based) `InlineTextContents` struct
 #[repr(C)]
 pub struct AzInlineTextContents {
@@ -6161,6 +7146,7 @@ pub struct AzLayoutCallbackInfo {
     pub image_cache: *const c_void,
     pub gl_context: *const AzOptionGlEnumWrapper,
     pub system_fonts: *const c_void,
+    pub callbacks: *const c_void,
     pub _reserved_ref: *const c_void,
     pub _reserved_mut: *mut c_void,
 }
@@ -6190,6 +7176,13 @@ pub struct AzVirtualKeyCodeCombo {
     pub keys: AzVirtualKeyCodeVec,
 }
 
+/// Keyboard shortcut that is not attached to a menu item: when the key combination is pressed, the callback is invoked. See `Dom::with_keyboard_shortcuts()`
+#[repr(C)]
+pub struct AzKeyboardShortcut {
+    pub keys: AzVirtualKeyCodeCombo,
+    pub callback: AzMenuCallback,
+}
+
 /// Re-export of rust-allocated (stack based) `CssPathPseudoSelector` struct
 #[repr(C, u8)]
 pub enum AzCssPathPseudoSelector {
@@ -6345,6 +7338,9 @@ pub struct AzNodeGraphCallbacks {
     pub on_node_input_disconnected: AzOptionNodeGraphOnNodeInputDisconnectedEnumWrapper,
     pub on_node_output_disconnected: AzOptionNodeGraphOnNodeOutputDisconnectedEnumWrapper,
     pub on_node_field_edited: AzOptionNodeGraphOnNodeFieldEditedEnumWrapper,
+    pub on_node_graph_zoomed: AzOptionNodeGraphOnNodeGraphZoomedEnumWrapper,
+    pub on_selection_changed: AzOptionNodeGraphOnSelectionChangedEnumWrapper,
+    pub on_history_changed: AzOptionNodeGraphOnHistoryChangedEnumWrapper,
 }
 
 /// Re-export of rust-allocated (stack based) `InputConnection` struct
@@ -6366,6 +7362,73 @@ pub struct AzOutputConnection {
 pub struct AzListViewRow {
     pub cells: AzDomVec,
     pub height: AzOptionPixelValueNoPercentEnumWrapper,
+    pub is_selected: bool,
+}
+
+/// Hierarchical tree of items with expand / collapse and selection. Only the rows between `first_visible_row` and `first_visible_row + max_visible_rows` are rendered. The callbacks only report what the user did, the application applies the change to its own copy of the TreeView (`set_expanded`, `apply_selection`, `set_children`, `set_first_visible_row`)
+#[repr(C)]
+pub struct AzTreeView {
+    pub items: AzTreeViewItemVec,
+    pub selection_mode: AzTreeViewSelectionModeEnumWrapper,
+    pub first_visible_row: usize,
+    pub max_visible_rows: usize,
+    pub focused_item: AzOptionUsizeEnumWrapper,
+    pub selection_anchor: AzOptionUsizeEnumWrapper,
+    pub on_expand: AzOptionTreeViewOnExpandEnumWrapper,
+    pub on_select: AzOptionTreeViewOnSelectEnumWrapper,
+    pub on_lazy_load: AzOptionTreeViewOnLazyLoadEnumWrapper,
+    pub on_scroll: AzOptionTreeViewOnScrollEnumWrapper,
+}
+
+/// State of a `DatePicker`, reported by `on_date_change`
+#[repr(C)]
+pub struct AzDatePickerState {
+    pub selected: AzOptionDateEnumWrapper,
+    pub cursor: AzDate,
+}
+
+/// Month calendar to select a single date. Selecting a date and navigating between months is reported through `on_date_change`, the application stores the new `DatePickerState` for the next frame. Arrow keys, PageUp / PageDown and Home / End move the cursor, Enter / Space select the date under the cursor
+#[repr(C)]
+pub struct AzDatePicker {
+    pub state: AzDatePickerState,
+    pub minimum_date: AzOptionDateEnumWrapper,
+    pub maximum_date: AzOptionDateEnumWrapper,
+    pub first_day_of_week: AzWeekdayEnumWrapper,
+    pub on_date_change: AzOptionDatePickerOnDateChangeEnumWrapper,
+}
+
+/// Hour / minute / second input with spin buttons, changes are reported through `on_time_change`
+#[repr(C)]
+pub struct AzTimePicker {
+    pub time: AzTime,
+    pub show_seconds: bool,
+    pub on_time_change: AzOptionTimePickerOnTimeChangeEnumWrapper,
+}
+
+/// State of a `DateTimePicker`, reported by `on_date_time_change`
+#[repr(C)]
+pub struct AzDateTimePickerState {
+    pub date: AzDatePickerState,
+    pub time: AzTime,
+    pub is_open: bool,
+}
+
+/// Drop-down field showing the selected date (and time), which opens a `DatePicker` (and `TimePicker`) below the field when clicked. Opening / closing the popup and changing the date or time is reported through `on_date_time_change`
+#[repr(C)]
+pub struct AzDateTimePicker {
+    pub state: AzDateTimePickerState,
+    pub minimum_date: AzOptionDateEnumWrapper,
+    pub maximum_date: AzOptionDateEnumWrapper,
+    pub first_day_of_week: AzWeekdayEnumWrapper,
+    pub show_time: bool,
+    pub show_seconds: bool,
+    pub on_date_time_change: AzOptionDateTimePickerOnDateTimeChangeEnumWrapper,
+}
+
+/// Sizes of all panes of a `Splitter`
+#[repr(C)]
+pub struct AzSplitterState {
+    pub panes: AzSplitterPaneStateVec,
 }
 
 /// Re-export of rust-allocated (stack based) `StyledNode` struct
@@ -6543,6 +7606,15 @@ pub struct AzOutputConnectionVec {
     pub destructor: AzOutputConnectionVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<KeyboardShortcut>`
+#[repr(C)]
+pub struct AzKeyboardShortcutVec {
+    pub(crate) ptr: *const AzKeyboardShortcut,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzKeyboardShortcutVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<TessellatedSvgNode>`
 #[repr(C)]
 pub struct AzTessellatedSvgNodeVec {
@@ -6655,6 +7727,13 @@ pub enum AzOptionThreadReceiveMsg {
     Some(AzThreadReceiveMsg),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionTextMetrics` struct
+#[repr(C, u8)]
+pub enum AzOptionTextMetrics {
+    None,
+    Some(AzTextMetrics),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionTaskBarIcon` struct
 #[repr(C, u8)]
 pub enum AzOptionTaskBarIcon {
@@ -6855,6 +7934,7 @@ pub struct AzAccessibilityInfo {
     pub states: AzAccessibilityStateVec,
     pub accelerator: AzOptionVirtualKeyCodeComboEnumWrapper,
     pub default_action: AzOptionStringEnumWrapper,
+    pub description: AzOptionStringEnumWrapper,
 }
 
 /// Re-export of rust-allocated (stack based) `IdOrClass` struct
@@ -6887,6 +7967,13 @@ pub enum AzCssPathSelector {
     Children,
 }
 
+/// `url("fonts/Roboto.ttf") format("truetype")`
+#[repr(C)]
+pub struct AzCssFontFaceUrl {
+    pub url: AzString,
+    pub format: AzOptionStringEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `StyleBackgroundContent` struct
 #[repr(C, u8)]
 pub enum AzStyleBackgroundContent {
@@ -7013,7 +8100,17 @@ pub struct AzInputOutputInfo {
     pub color: AzColorU,
 }
 
-/// Re-export of rust-allocated (stack based) `ListView` struct
+/// Field of a node that was changed from `old_value` to `new_value`
+#[repr(C)]
+pub struct AzNodeGraphFieldEdit {
+    pub node_id: AzNodeGraphNodeId,
+    pub node_type: AzNodeTypeId,
+    pub field_idx: usize,
+    pub old_value: AzNodeTypeFieldValueEnumWrapper,
+    pub new_value: AzNodeTypeFieldValueEnumWrapper,
+}
+
+/// Table with a header row. If a `row_provider` is set, the ListView is virtualized: only the rows that fit into the visible area are requested from the row provider and rendered. The callbacks report scrolling, selection and column resizing, the application stores the new `first_visible_row`, selection state and `column_widths` for the next frame
 #[repr(C)]
 pub struct AzListView {
     pub columns: AzStringVec,
@@ -7025,6 +8122,16 @@ pub struct AzListView {
     pub on_lazy_load_scroll: AzOptionListViewOnLazyLoadScrollEnumWrapper,
     pub on_column_click: AzOptionListViewOnColumnClickEnumWrapper,
     pub on_row_click: AzOptionListViewOnRowClickEnumWrapper,
+    pub row_count: usize,
+    pub row_height: f32,
+    pub first_visible_row: usize,
+    pub column_widths: AzF32Vec,
+    pub sort_descending: bool,
+    pub focused_row: AzOptionUsizeEnumWrapper,
+    pub selection_anchor: AzOptionUsizeEnumWrapper,
+    pub row_provider: AzOptionListViewRowProviderEnumWrapper,
+    pub on_row_select: AzOptionListViewOnRowSelectEnumWrapper,
+    pub on_column_resize: AzOptionListViewOnColumnResizeEnumWrapper,
 }
 
 /// Re-export of rust-allocated (stack based) `ListViewState` struct
@@ -7036,12 +8143,51 @@ pub struct AzListViewState {
     pub scroll_offset: AzPixelValueNoPercent,
     pub current_scroll_position: AzLogicalPosition,
     pub current_content_height: AzLogicalSize,
+    pub row_count: usize,
+    pub first_visible_row: usize,
+    pub focused_row: AzOptionUsizeEnumWrapper,
+    pub selection_anchor: AzOptionUsizeEnumWrapper,
 }
 
-/// Re-export of rust-allocated (stack based) `TreeView` struct
+/// Re-export of rust-allocated (stack based) `TreeViewItem` struct
 #[repr(C)]
-pub struct AzTreeView {
-    pub root: AzString,
+pub struct AzTreeViewItem {
+    pub id: usize,
+    pub label: AzString,
+    pub icon: AzOptionImageRefEnumWrapper,
+    pub children: AzTreeViewItemVec,
+    pub has_lazy_children: bool,
+    pub is_expanded: bool,
+    pub is_selected: bool,
+}
+
+/// Spreadsheet-like grid of text cells. The DataGrid does not store any cell data: the text of each visible cell is requested from the `cell_provider`, only the cells that fit into the visible area are rendered. Scrolling, selection, resizing and editing are reported through the callbacks, the application stores the new state for the next frame
+#[repr(C)]
+pub struct AzDataGrid {
+    pub row_count: usize,
+    pub column_count: usize,
+    pub column_headers: AzStringVec,
+    pub column_widths: AzF32Vec,
+    pub row_heights: AzF32Vec,
+    pub first_visible_row: usize,
+    pub first_visible_column: usize,
+    pub selection: AzOptionDataGridRangeEnumWrapper,
+    pub cell_provider: AzOptionDataGridCellProviderEnumWrapper,
+    pub cell_style: AzOptionDataGridCellStyleEnumWrapper,
+    pub on_select: AzOptionDataGridOnSelectEnumWrapper,
+    pub on_scroll: AzOptionDataGridOnScrollEnumWrapper,
+    pub on_cell_edit: AzOptionDataGridOnCellEditEnumWrapper,
+    pub on_column_resize: AzOptionDataGridOnColumnResizeEnumWrapper,
+    pub on_row_resize: AzOptionDataGridOnRowResizeEnumWrapper,
+    pub on_copy_range: AzOptionDataGridOnCopyRangeEnumWrapper,
+    pub on_clear_range: AzOptionDataGridOnClearRangeEnumWrapper,
+}
+
+/// Result of a `Dialog`, reported by `on_close`
+#[repr(C)]
+pub struct AzDialogResult {
+    pub button: AzDialogButtonEnumWrapper,
+    pub input: AzString,
 }
 
 /// Re-export of rust-allocated (stack based) `DropDown` struct
@@ -7380,6 +8526,13 @@ pub struct AzCssPath {
     pub selectors: AzCssPathSelectorVec,
 }
 
+/// Single entry of the `src: ...` list in a `@font-face` rule
+#[repr(C, u8)]
+pub enum AzCssFontFaceSource {
+    Url(AzCssFontFaceUrl),
+    Local(AzString),
+}
+
 /// Re-export of rust-allocated (stack based) `StyleBackgroundContentVecValue` struct
 #[repr(C, u8)]
 pub enum AzStyleBackgroundContentVecValue {
@@ -7477,6 +8630,8 @@ pub enum AzCssProperty {
     Filter(AzStyleFilterVecValue),
     BackdropFilter(AzStyleFilterVecValue),
     TextShadow(AzStyleBoxShadowValue),
+    VerticalAlign(AzLayoutVerticalAlignValue),
+    TextDecoration(AzStyleTextDecorationValue),
 }
 
 /// Re-export of rust-allocated (stack based) `FileInputStateWrapper` struct
@@ -7602,6 +8757,7 @@ pub struct AzXmlNode {
     pub attributes: AzStringPairVec,
     pub children: AzXmlNodeVec,
     pub text: AzOptionStringEnumWrapper,
+    pub span: AzXmlSpan,
 }
 
 /// Wrapper over a Rust-allocated `Vec<NodeTypeIdInfoMap>`
@@ -7667,6 +8823,15 @@ pub struct AzSvgSimpleNodeVec {
     pub destructor: AzSvgSimpleNodeVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<CssFontFaceSource>`
+#[repr(C)]
+pub struct AzCssFontFaceSourceVec {
+    pub(crate) ptr: *const AzCssFontFaceSourceEnumWrapper,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzCssFontFaceSourceVecDestructorEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `OptionCssProperty` struct
 #[repr(C, u8)]
 pub enum AzOptionCssProperty {
@@ -7753,6 +8918,7 @@ pub struct AzInlineText {
     pub font_size_px: f32,
     pub last_word_index: usize,
     pub baseline_descender_px: f32,
+    pub runs: AzInlineTextRunVec,
 }
 
 /// CSS path to set the keyboard input focus
@@ -7802,6 +8968,16 @@ pub struct AzDynamicCssProperty {
     pub default_value: AzCssPropertyEnumWrapper,
 }
 
+/// Parsed contents of an `@font-face { ... }` block
+#[repr(C)]
+pub struct AzCssFontFace {
+    pub font_family: AzString,
+    pub src: AzCssFontFaceSourceVec,
+    pub font_weight: u16,
+    pub font_style: AzCssFontFaceStyleEnumWrapper,
+    pub unicode_range: AzCssUnicodeRangeVec,
+}
+
 /// Re-export of rust-allocated (stack based) `Node` struct
 #[repr(C)]
 pub struct AzNode {
@@ -7839,6 +9015,15 @@ pub struct AzNodeDataInlineCssPropertyVec {
     pub destructor: AzNodeDataInlineCssPropertyVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<CssFontFace>`
+#[repr(C)]
+pub struct AzCssFontFaceVec {
+    pub(crate) ptr: *const AzCssFontFace,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzCssFontFaceVecDestructorEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `OptionWindowState` struct
 #[repr(C, u8)]
 pub enum AzOptionWindowState {
@@ -7977,6 +9162,26 @@ pub struct AzNodeIdNodeMap {
     pub node: AzNode,
 }
 
+/// Single modification of the node graph, recorded in the undo history
+#[repr(C, u8)]
+pub enum AzNodeGraphEditOp {
+    AddNode(AzNodeIdNodeMap),
+    RemoveNode(AzNodeIdNodeMap),
+    MoveNode(AzNodeGraphNodeMove),
+    Connect(AzNodeGraphConnection),
+    Disconnect(AzNodeGraphConnection),
+    EditField(AzNodeGraphFieldEdit),
+}
+
+/// Wrapper over a Rust-allocated `Vec<NodeGraphEditOp>`
+#[repr(C)]
+pub struct AzNodeGraphEditOpVec {
+    pub(crate) ptr: *const AzNodeGraphEditOpEnumWrapper,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzNodeGraphEditOpVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<NodeIdNodeMap>`
 #[repr(C)]
 pub struct AzNodeIdNodeMapVec {
@@ -8034,6 +9239,13 @@ pub struct AzDom {
     pub total_children: usize,
 }
 
+/// Content of a `Tooltip`: either a plain text or a custom DOM
+#[repr(C, u8)]
+pub enum AzTooltipContent {
+    Text(AzString),
+    Dom(AzDom),
+}
+
 /// Re-export of rust-allocated (stack based) `CssRuleBlock` struct
 #[repr(C)]
 pub struct AzCssRuleBlock {
@@ -8056,18 +9268,19 @@ pub struct AzFrame {
     pub content: AzDom,
 }
 
-/// Re-export of rust-allocated (stack based) `NodeGraph` struct
+/// One undoable user action (for example "paste three nodes"), consisting of one or more operations
 #[repr(C)]
-pub struct AzNodeGraph {
-    pub node_types: AzNodeTypeIdInfoMapVec,
-    pub input_output_types: AzInputOutputTypeIdInfoMapVec,
-    pub nodes: AzNodeIdNodeMapVec,
-    pub allow_multiple_root_nodes: bool,
-    pub offset: AzLogicalPosition,
-    pub style: AzNodeGraphStyleEnumWrapper,
-    pub callbacks: AzNodeGraphCallbacks,
-    pub add_node_str: AzString,
-    pub scale_factor: f32,
+pub struct AzNodeGraphEdit {
+    pub ops: AzNodeGraphEditOpVec,
+}
+
+/// Pane of a `Splitter`
+#[repr(C)]
+pub struct AzSplitterPane {
+    pub content: AzDom,
+    pub state: AzSplitterPaneState,
+    pub min_size: f32,
+    pub max_size: AzOptionF32EnumWrapper,
 }
 
 /// Re-export of rust-allocated (stack based) `StyledDom` struct
@@ -8086,6 +9299,24 @@ pub struct AzStyledDom {
     pub css_property_cache: AzCssPropertyCache,
 }
 
+/// Wrapper over a Rust-allocated `Vec<SplitterPane>`
+#[repr(C)]
+pub struct AzSplitterPaneVec {
+    pub(crate) ptr: *const AzSplitterPane,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzSplitterPaneVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<NodeGraphEdit>`
+#[repr(C)]
+pub struct AzNodeGraphEditVec {
+    pub(crate) ptr: *const AzNodeGraphEdit,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzNodeGraphEditVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `CssRuleBlock`
 #[repr(C)]
 pub struct AzCssRuleBlockVec {
@@ -8130,12 +9361,47 @@ pub struct AzIFrameCallbackReturn {
     pub virtual_scroll_offset: AzLogicalPosition,
 }
 
+/// Hover hint of a DOM node, see `Dom::with_tooltip()`
+#[repr(C)]
+pub struct AzTooltip {
+    pub content: AzTooltipContentEnumWrapper,
+    pub delay: AzDurationEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `Stylesheet` struct
 #[repr(C)]
 pub struct AzStylesheet {
     pub rules: AzCssRuleBlockVec,
 }
 
+/// Undo / redo stacks of the node graph: the last edit is at the end of each stack
+#[repr(C)]
+pub struct AzNodeGraphHistory {
+    pub undo: AzNodeGraphEditVec,
+    pub redo: AzNodeGraphEditVec,
+}
+
+/// Modal dialog rendered inside of the window. Dialogs are stateless: the application stores the `Dialog` while it is open and removes it again in `on_close`. Use `dom_over()` to render the dialog on top of the rest of the UI: the content below the dialog is made inert, so the keyboard focus can't leave the dialog and clicks don't reach the content anymore
+#[repr(C)]
+pub struct AzDialog {
+    pub title: AzString,
+    pub message: AzString,
+    pub icon: AzDialogIconEnumWrapper,
+    pub buttons: AzDialogButtonsEnumWrapper,
+    pub input: AzOptionStringEnumWrapper,
+    pub content: AzOptionDomEnumWrapper,
+    pub on_close: AzOptionDialogOnCloseEnumWrapper,
+}
+
+/// Container which splits its area into resizable panes. Panes with a fixed size keep their size when the splitter is resized, the remaining space is distributed between the flexible panes. Dragging a handle resizes the panes on both sides of it within their `min_size` / `max_size`; if both panes are flexible, the first one is given a fixed size. Double-clicking a handle (or pressing Enter while it is focused) collapses the fixed pane next to it
+#[repr(C)]
+pub struct AzSplitter {
+    pub direction: AzSplitterDirectionEnumWrapper,
+    pub panes: AzSplitterPaneVec,
+    pub handle_size: f32,
+    pub on_resize: AzOptionSplitterOnResizeEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Stylesheet`
 #[repr(C)]
 pub struct AzStylesheetVec {
@@ -8163,6 +9429,30 @@ pub enum AzResultSvgSvgParseError {
 #[repr(C)]
 pub struct AzCss {
     pub stylesheets: AzStylesheetVec,
+    pub font_faces: AzCssFontFaceVec,
+}
+
+/// Re-export of rust-allocated (stack based) `NodeGraph` struct
+#[repr(C)]
+pub struct AzNodeGraph {
+    pub node_types: AzNodeTypeIdInfoMapVec,
+    pub input_output_types: AzInputOutputTypeIdInfoMapVec,
+    pub nodes: AzNodeIdNodeMapVec,
+    pub allow_multiple_root_nodes: bool,
+    pub offset: AzLogicalPosition,
+    pub style: AzNodeGraphStyleEnumWrapper,
+    pub callbacks: AzNodeGraphCallbacks,
+    pub add_node_str: AzString,
+    pub scale_factor: f32,
+    pub selected_nodes: AzNodeGraphNodeIdVec,
+    pub history: AzNodeGraphHistory,
+}
+
+/// Re-export of rust-allocated (stack based) `ResultNodeGraphNodeGraphError` struct
+#[repr(C, u8)]
+pub enum AzResultNodeGraphNodeGraphError {
+    Ok(AzNodeGraph),
+    Err(AzNodeGraphError),
 }
 
 /// `AzAppLogLevelEnumWrapper` struct
@@ -8339,6 +9629,12 @@ pub struct AzNodeTypeKeyEnumWrapper {
     pub inner: AzNodeTypeKey,
 }
 
+/// `AzCssFontFaceStyleEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzCssFontFaceStyleEnumWrapper {
+    pub inner: AzCssFontFaceStyle,
+}
+
 /// `AzCssPropertyTypeEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzCssPropertyTypeEnumWrapper {
@@ -8411,6 +9707,12 @@ pub struct AzLayoutPositionEnumWrapper {
     pub inner: AzLayoutPosition,
 }
 
+/// `AzLayoutVerticalAlignEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzLayoutVerticalAlignEnumWrapper {
+    pub inner: AzLayoutVerticalAlign,
+}
+
 /// `AzLayoutFlexWrapEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzLayoutFlexWrapEnumWrapper {
@@ -8483,6 +9785,12 @@ pub struct AzStyleTextAlignEnumWrapper {
     pub inner: AzStyleTextAlign,
 }
 
+/// `AzStyleTextDecorationEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleTextDecorationEnumWrapper {
+    pub inner: AzStyleTextDecoration,
+}
+
 /// `AzTextInputValidEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzTextInputValidEnumWrapper {
@@ -8495,6 +9803,60 @@ pub struct AzNodeGraphStyleEnumWrapper {
     pub inner: AzNodeGraphStyle,
 }
 
+/// `AzNodeGraphErrorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzNodeGraphErrorEnumWrapper {
+    pub inner: AzNodeGraphError,
+}
+
+/// `AzListViewSelectionChangeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzListViewSelectionChangeEnumWrapper {
+    pub inner: AzListViewSelectionChange,
+}
+
+/// `AzTreeViewSelectionModeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzTreeViewSelectionModeEnumWrapper {
+    pub inner: AzTreeViewSelectionMode,
+}
+
+/// `AzTreeViewSelectionChangeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzTreeViewSelectionChangeEnumWrapper {
+    pub inner: AzTreeViewSelectionChange,
+}
+
+/// `AzWeekdayEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzWeekdayEnumWrapper {
+    pub inner: AzWeekday,
+}
+
+/// `AzDialogIconEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzDialogIconEnumWrapper {
+    pub inner: AzDialogIcon,
+}
+
+/// `AzDialogButtonsEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzDialogButtonsEnumWrapper {
+    pub inner: AzDialogButtons,
+}
+
+/// `AzDialogButtonEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzDialogButtonEnumWrapper {
+    pub inner: AzDialogButton,
+}
+
+/// `AzSplitterDirectionEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzSplitterDirectionEnumWrapper {
+    pub inner: AzSplitterDirection,
+}
+
 /// `AzVertexAttributeTypeEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzVertexAttributeTypeEnumWrapper {
@@ -8621,6 +9983,24 @@ pub struct AzListViewRowVecDestructorEnumWrapper {
     pub inner: AzListViewRowVecDestructor,
 }
 
+/// `AzTreeViewItemVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzTreeViewItemVecDestructorEnumWrapper {
+    pub inner: AzTreeViewItemVecDestructor,
+}
+
+/// `AzSplitterPaneVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzSplitterPaneVecDestructorEnumWrapper {
+    pub inner: AzSplitterPaneVecDestructor,
+}
+
+/// `AzSplitterPaneStateVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzSplitterPaneStateVecDestructorEnumWrapper {
+    pub inner: AzSplitterPaneStateVecDestructor,
+}
+
 /// `AzStyleFilterVecDestructorEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleFilterVecDestructorEnumWrapper {
@@ -8645,6 +10025,24 @@ pub struct AzInputOutputTypeIdInfoMapVecDestructorEnumWrapper {
     pub inner: AzInputOutputTypeIdInfoMapVecDestructor,
 }
 
+/// `AzNodeGraphNodeIdVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzNodeGraphNodeIdVecDestructorEnumWrapper {
+    pub inner: AzNodeGraphNodeIdVecDestructor,
+}
+
+/// `AzNodeGraphEditOpVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzNodeGraphEditOpVecDestructorEnumWrapper {
+    pub inner: AzNodeGraphEditOpVecDestructor,
+}
+
+/// `AzNodeGraphEditVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzNodeGraphEditVecDestructorEnumWrapper {
+    pub inner: AzNodeGraphEditVecDestructor,
+}
+
 /// `AzNodeIdNodeMapVecDestructorEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzNodeIdNodeMapVecDestructorEnumWrapper {
@@ -8693,6 +10091,12 @@ pub struct AzAccessibilityStateVecDestructorEnumWrapper {
     pub inner: AzAccessibilityStateVecDestructor,
 }
 
+/// `AzKeyboardShortcutVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzKeyboardShortcutVecDestructorEnumWrapper {
+    pub inner: AzKeyboardShortcutVecDestructor,
+}
+
 /// `AzMenuItemVecDestructorEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzMenuItemVecDestructorEnumWrapper {
@@ -8741,6 +10145,18 @@ pub struct AzInlineGlyphVecDestructorEnumWrapper {
     pub inner: AzInlineGlyphVecDestructor,
 }
 
+/// `AzTextLineMetricsVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzTextLineMetricsVecDestructorEnumWrapper {
+    pub inner: AzTextLineMetricsVecDestructor,
+}
+
+/// `AzInlineTextRunVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzInlineTextRunVecDestructorEnumWrapper {
+    pub inner: AzInlineTextRunVecDestructor,
+}
+
 /// `AzInlineTextHitVecDestructorEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzInlineTextHitVecDestructorEnumWrapper {
@@ -8903,6 +10319,24 @@ pub struct AzStylesheetVecDestructorEnumWrapper {
     pub inner: AzStylesheetVecDestructor,
 }
 
+/// `AzCssFontFaceVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzCssFontFaceVecDestructorEnumWrapper {
+    pub inner: AzCssFontFaceVecDestructor,
+}
+
+/// `AzCssFontFaceSourceVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzCssFontFaceSourceVecDestructorEnumWrapper {
+    pub inner: AzCssFontFaceSourceVecDestructor,
+}
+
+/// `AzCssUnicodeRangeVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzCssUnicodeRangeVecDestructorEnumWrapper {
+    pub inner: AzCssUnicodeRangeVecDestructor,
+}
+
 /// `AzCssRuleBlockVecDestructorEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzCssRuleBlockVecDestructorEnumWrapper {
@@ -9317,6 +10751,12 @@ pub struct AzLayoutTopValueEnumWrapper {
     pub inner: AzLayoutTopValue,
 }
 
+/// `AzLayoutVerticalAlignValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzLayoutVerticalAlignValueEnumWrapper {
+    pub inner: AzLayoutVerticalAlignValue,
+}
+
 /// `AzLayoutWidthValueEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzLayoutWidthValueEnumWrapper {
@@ -9467,6 +10907,12 @@ pub struct AzStyleTextAlignValueEnumWrapper {
     pub inner: AzStyleTextAlignValue,
 }
 
+/// `AzStyleTextDecorationValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleTextDecorationValueEnumWrapper {
+    pub inner: AzStyleTextDecorationValue,
+}
+
 /// `AzStyleTextColorValueEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleTextColorValueEnumWrapper {
@@ -9533,12 +10979,156 @@ pub struct AzOptionSvgPointEnumWrapper {
     pub inner: AzOptionSvgPoint,
 }
 
+/// `AzOptionTreeViewOnExpandEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionTreeViewOnExpandEnumWrapper {
+    pub inner: AzOptionTreeViewOnExpand,
+}
+
+/// `AzOptionTreeViewOnSelectEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionTreeViewOnSelectEnumWrapper {
+    pub inner: AzOptionTreeViewOnSelect,
+}
+
+/// `AzOptionTreeViewOnLazyLoadEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionTreeViewOnLazyLoadEnumWrapper {
+    pub inner: AzOptionTreeViewOnLazyLoad,
+}
+
+/// `AzOptionTreeViewOnScrollEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionTreeViewOnScrollEnumWrapper {
+    pub inner: AzOptionTreeViewOnScroll,
+}
+
 /// `AzOptionListViewOnRowClickEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionListViewOnRowClickEnumWrapper {
     pub inner: AzOptionListViewOnRowClick,
 }
 
+/// `AzOptionListViewOnRowSelectEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionListViewOnRowSelectEnumWrapper {
+    pub inner: AzOptionListViewOnRowSelect,
+}
+
+/// `AzOptionListViewOnColumnResizeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionListViewOnColumnResizeEnumWrapper {
+    pub inner: AzOptionListViewOnColumnResize,
+}
+
+/// `AzOptionListViewRowProviderEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionListViewRowProviderEnumWrapper {
+    pub inner: AzOptionListViewRowProvider,
+}
+
+/// `AzOptionDataGridRangeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionDataGridRangeEnumWrapper {
+    pub inner: AzOptionDataGridRange,
+}
+
+/// `AzOptionDataGridCellProviderEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionDataGridCellProviderEnumWrapper {
+    pub inner: AzOptionDataGridCellProvider,
+}
+
+/// `AzOptionDataGridCellStyleEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionDataGridCellStyleEnumWrapper {
+    pub inner: AzOptionDataGridCellStyle,
+}
+
+/// `AzOptionDataGridOnSelectEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionDataGridOnSelectEnumWrapper {
+    pub inner: AzOptionDataGridOnSelect,
+}
+
+/// `AzOptionDataGridOnScrollEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionDataGridOnScrollEnumWrapper {
+    pub inner: AzOptionDataGridOnScroll,
+}
+
+/// `AzOptionDataGridOnCellEditEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionDataGridOnCellEditEnumWrapper {
+    pub inner: AzOptionDataGridOnCellEdit,
+}
+
+/// `AzOptionDataGridOnColumnResizeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionDataGridOnColumnResizeEnumWrapper {
+    pub inner: AzOptionDataGridOnColumnResize,
+}
+
+/// `AzOptionDataGridOnRowResizeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionDataGridOnRowResizeEnumWrapper {
+    pub inner: AzOptionDataGridOnRowResize,
+}
+
+/// `AzOptionDataGridOnCopyRangeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionDataGridOnCopyRangeEnumWrapper {
+    pub inner: AzOptionDataGridOnCopyRange,
+}
+
+/// `AzOptionDataGridOnClearRangeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionDataGridOnClearRangeEnumWrapper {
+    pub inner: AzOptionDataGridOnClearRange,
+}
+
+/// `AzOptionDateEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionDateEnumWrapper {
+    pub inner: AzOptionDate,
+}
+
+/// `AzOptionTimeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionTimeEnumWrapper {
+    pub inner: AzOptionTime,
+}
+
+/// `AzOptionDatePickerOnDateChangeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionDatePickerOnDateChangeEnumWrapper {
+    pub inner: AzOptionDatePickerOnDateChange,
+}
+
+/// `AzOptionTimePickerOnTimeChangeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionTimePickerOnTimeChangeEnumWrapper {
+    pub inner: AzOptionTimePickerOnTimeChange,
+}
+
+/// `AzOptionDateTimePickerOnDateTimeChangeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionDateTimePickerOnDateTimeChangeEnumWrapper {
+    pub inner: AzOptionDateTimePickerOnDateTimeChange,
+}
+
+/// `AzOptionDialogOnCloseEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionDialogOnCloseEnumWrapper {
+    pub inner: AzOptionDialogOnClose,
+}
+
+/// `AzOptionSplitterOnResizeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionSplitterOnResizeEnumWrapper {
+    pub inner: AzOptionSplitterOnResize,
+}
+
 /// `AzOptionListViewOnColumnClickEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionListViewOnColumnClickEnumWrapper {
@@ -9563,6 +11153,24 @@ pub struct AzOptionDropDownOnChoiceChangeEnumWrapper {
     pub inner: AzOptionDropDownOnChoiceChange,
 }
 
+/// `AzOptionNodeGraphOnNodeGraphZoomedEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionNodeGraphOnNodeGraphZoomedEnumWrapper {
+    pub inner: AzOptionNodeGraphOnNodeGraphZoomed,
+}
+
+/// `AzOptionNodeGraphOnSelectionChangedEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionNodeGraphOnSelectionChangedEnumWrapper {
+    pub inner: AzOptionNodeGraphOnSelectionChanged,
+}
+
+/// `AzOptionNodeGraphOnHistoryChangedEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionNodeGraphOnHistoryChangedEnumWrapper {
+    pub inner: AzOptionNodeGraphOnHistoryChanged,
+}
+
 /// `AzOptionNodeGraphOnNodeAddedEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionNodeGraphOnNodeAddedEnumWrapper {
@@ -10013,6 +11621,12 @@ pub struct AzOptionThreadReceiveMsgEnumWrapper {
     pub inner: AzOptionThreadReceiveMsg,
 }
 
+/// `AzOptionTextMetricsEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionTextMetricsEnumWrapper {
+    pub inner: AzOptionTextMetrics,
+}
+
 /// `AzOptionTaskBarIconEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionTaskBarIconEnumWrapper {
@@ -10163,6 +11777,12 @@ pub struct AzMenuItemEnumWrapper {
     pub inner: AzMenuItem,
 }
 
+/// `AzCssFontFaceSourceEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzCssFontFaceSourceEnumWrapper {
+    pub inner: AzCssFontFaceSource,
+}
+
 /// `AzStyleBackgroundContentVecValueEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleBackgroundContentVecValueEnumWrapper {
@@ -10241,12 +11861,24 @@ pub struct AzCssDeclarationEnumWrapper {
     pub inner: AzCssDeclaration,
 }
 
+/// `AzNodeGraphEditOpEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzNodeGraphEditOpEnumWrapper {
+    pub inner: AzNodeGraphEditOp,
+}
+
 /// `AzXmlErrorEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzXmlErrorEnumWrapper {
     pub inner: AzXmlError,
 }
 
+/// `AzTooltipContentEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzTooltipContentEnumWrapper {
+    pub inner: AzTooltipContent,
+}
+
 /// `AzOptionDomEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionDomEnumWrapper {
@@ -10277,6 +11909,12 @@ pub struct AzResultSvgSvgParseErrorEnumWrapper {
     pub inner: AzResultSvgSvgParseError,
 }
 
+/// `AzResultNodeGraphNodeGraphErrorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzResultNodeGraphNodeGraphErrorEnumWrapper {
+    pub inner: AzResultNodeGraphNodeGraphError,
+}
+
 
 // Necessary because the Python interpreter may send structs across different threads
 unsafe impl Send for AzApp { }
@@ -10323,7 +11961,10 @@ unsafe impl Send for AzGl { }
 unsafe impl Send for AzRefstrVecRef { }
 unsafe impl Send for AzFontMetrics { }
 unsafe impl Send for AzInstantPtr { }
+unsafe impl Send for AzTreeViewItemVec { }
+unsafe impl Send for AzSplitterPaneStateVec { }
 unsafe impl Send for AzLogicalRectVec { }
+unsafe impl Send for AzNodeGraphNodeIdVec { }
 unsafe impl Send for AzInputOutputTypeIdVec { }
 unsafe impl Send for AzOutputNodeAndIndexVec { }
 unsafe impl Send for AzInputNodeAndIndexVec { }
@@ -10331,6 +11972,8 @@ unsafe impl Send for AzAccessibilityStateVec { }
 unsafe impl Send for AzMenuItemVec { }
 unsafe impl Send for AzXmlNodeVec { }
 unsafe impl Send for AzInlineGlyphVec { }
+unsafe impl Send for AzTextLineMetricsVec { }
+unsafe impl Send for AzInlineTextRunVec { }
 unsafe impl Send for AzInlineTextHitVec { }
 unsafe impl Send for AzVideoModeVec { }
 unsafe impl Send for AzDomVec { }
@@ -10344,6 +11987,7 @@ unsafe impl Send for AzXWindowTypeVec { }
 unsafe impl Send for AzVirtualKeyCodeVec { }
 unsafe impl Send for AzCascadeInfoVec { }
 unsafe impl Send for AzScanCodeVec { }
+unsafe impl Send for AzCssUnicodeRangeVec { }
 unsafe impl Send for AzU16Vec { }
 unsafe impl Send for AzF32Vec { }
 unsafe impl Send for AzU8Vec { }
@@ -10363,6 +12007,7 @@ unsafe impl Send for AzListViewRowVec { }
 unsafe impl Send for AzStyleFilterVec { }
 unsafe impl Send for AzInputConnectionVec { }
 unsafe impl Send for AzOutputConnectionVec { }
+unsafe impl Send for AzKeyboardShortcutVec { }
 unsafe impl Send for AzTessellatedSvgNodeVec { }
 unsafe impl Send for AzTessellatedColoredSvgNodeVec { }
 unsafe impl Send for AzStyleTransformVec { }
@@ -10393,13 +12038,18 @@ unsafe impl Send for AzInlineLineVec { }
 unsafe impl Send for AzCssPropertyVec { }
 unsafe impl Send for AzSvgMultiPolygonVec { }
 unsafe impl Send for AzSvgSimpleNodeVec { }
+unsafe impl Send for AzCssFontFaceSourceVec { }
 unsafe impl Send for AzCallbackInfo { }
 unsafe impl Send for AzTimerCallbackInfo { }
 unsafe impl Send for AzNodeDataInlineCssPropertyVec { }
+unsafe impl Send for AzCssFontFaceVec { }
 unsafe impl Send for AzNodeData { }
+unsafe impl Send for AzNodeGraphEditOpVec { }
 unsafe impl Send for AzNodeIdNodeMapVec { }
 unsafe impl Send for AzCssDeclarationVec { }
 unsafe impl Send for AzNodeDataVec { }
+unsafe impl Send for AzSplitterPaneVec { }
+unsafe impl Send for AzNodeGraphEditVec { }
 unsafe impl Send for AzCssRuleBlockVec { }
 unsafe impl Send for AzStylesheetVec { }
 
@@ -10467,6 +12117,8 @@ impl Clone for AzMenuPopupPositionEnumWrapper { fn clone(&self) -> Self { let r:
 impl Clone for AzMenuItemStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuItemState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeTypeKeyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::NodeTypeTag = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssNthChildPattern { fn clone(&self) -> Self { let r: &azul_impl::css::CssNthChildPattern = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssFontFaceStyleEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssFontFaceStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssUnicodeRange { fn clone(&self) -> Self { let r: &azul_impl::css::CssUnicodeRange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPropertyTypeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssPropertyType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzColorU { fn clone(&self) -> Self { let r: &azul_impl::css::ColorU = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSizeMetricEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::SizeMetric = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10481,6 +12133,7 @@ impl Clone for AzLayoutDisplayEnumWrapper { fn clone(&self) -> Self { let r: &az
 impl Clone for AzLayoutFloatEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutFloat = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutJustifyContentEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutJustifyContent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutPositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutVerticalAlignEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutVerticalAlign = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutFlexWrapEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutFlexWrap = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutOverflowEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutOverflow = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAngleMetricEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::AngleMetric = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10493,6 +12146,7 @@ impl Clone for AzBorderStyleEnumWrapper { fn clone(&self) -> Self { let r: &azul
 impl Clone for AzStyleCursorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCursor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackfaceVisibilityEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackfaceVisibility = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTextAlignEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTextAlign = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTextDecorationEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTextDecoration = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRibbon { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::Ribbon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRibbonOnTabClickedCallback { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::RibbonOnTabClickedCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFileInputOnPathChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::file_input::FileInputOnPathChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10519,15 +12173,51 @@ impl Clone for AzNodeGraphOnNodeConnectedCallback { fn clone(&self) -> Self { le
 impl Clone for AzNodeGraphOnNodeInputDisconnectedCallback { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OnNodeInputDisconnectedCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeGraphOnNodeOutputDisconnectedCallback { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OnNodeOutputDisconnectedCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeGraphOnNodeFieldEditedCallback { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OnNodeFieldEditedCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNodeGraphOnNodeGraphZoomedCallback { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OnNodeGraphZoomedCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNodeGraphOnSelectionChangedCallback { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OnNodeGraphSelectionChangedCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNodeGraphOnHistoryChangedCallback { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OnNodeGraphHistoryChangedCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInputOutputTypeId { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::InputOutputTypeId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeTypeId { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeTypeId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeGraphNodeId { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeGraphNodeId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodePosition { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodePosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzGraphDragAmount { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::GraphDragAmount = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeDragAmount { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeDragAmount = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNodeGraphErrorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeGraphError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzListViewSelectionChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewSelectionChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzListViewOnLazyLoadScrollCallback { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewOnLazyLoadScrollCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzListViewOnColumnClickCallback { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewOnColumnClickCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzListViewOnRowClickCallback { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewOnRowClickCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzListViewOnRowSelectCallback { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewOnRowSelectCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzListViewOnColumnResizeCallback { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewOnColumnResizeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzListViewRowProviderCallback { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewRowProviderCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewSelectionModeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewSelectionMode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewSelectionChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewSelectionChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewOnExpandCallback { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewOnExpandCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewOnSelectCallback { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewOnSelectCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewOnLazyLoadCallback { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewOnLazyLoadCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewOnScrollCallback { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewOnScrollCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDataGridCell { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::DataGridCell = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDataGridCellProviderCallback { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::DataGridCellProviderCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDataGridCellStyleCallback { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::DataGridCellStyleCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDataGridOnSelectCallback { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::DataGridOnSelectCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDataGridOnScrollCallback { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::DataGridOnScrollCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDataGridOnCellEditCallback { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::DataGridOnCellEditCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDataGridOnColumnResizeCallback { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::DataGridOnColumnResizeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDataGridOnRowResizeCallback { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::DataGridOnRowResizeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDataGridOnCopyRangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::DataGridOnCopyRangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDataGridOnClearRangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::DataGridOnClearRangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDate { fn clone(&self) -> Self { let r: &crate::widgets::date_picker::Date = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzWeekdayEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::date_picker::Weekday = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTime { fn clone(&self) -> Self { let r: &crate::widgets::date_picker::Time = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDatePickerOnDateChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::date_picker::DatePickerOnDateChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTimePickerOnTimeChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::date_picker::TimePickerOnTimeChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDateTimePickerOnDateTimeChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::date_picker::DateTimePickerOnDateTimeChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDialogIconEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::dialog::DialogIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDialogButtonsEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::dialog::DialogButtons = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDialogButtonEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::dialog::DialogButton = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDialogOnCloseCallback { fn clone(&self) -> Self { let r: &crate::widgets::dialog::DialogOnCloseCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitterDirectionEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::splitter::SplitterDirection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitterOnResizeCallback { fn clone(&self) -> Self { let r: &crate::widgets::splitter::SplitterOnResizeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDropDownOnChoiceChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::DropDownOnChoiceChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeHierarchyItem { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::NodeHierarchyItem = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCascadeInfo { fn clone(&self) -> Self { let r: &azul_impl::style::CascadeInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10608,10 +12298,16 @@ impl Clone for AzThreadReceiverDestructorFn { fn clone(&self) -> Self { let r: &
 impl Clone for AzThreadSenderDestructorFn { fn clone(&self) -> Self { let r: &azul_impl::task::ThreadSenderDestructorCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFontFamilyVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontFamilyVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzListViewRowVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewRowVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewItemVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewItemVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitterPaneVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::splitter::SplitterPaneVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitterPaneStateVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::splitter::SplitterPaneStateVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFilterVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFilterVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLogicalRectVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::LogicalRectVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeTypeIdInfoMapVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeTypeIdInfoMapVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInputOutputTypeIdInfoMapVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::InputOutputTypeIdInfoMapVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNodeGraphNodeIdVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeGraphNodeIdVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNodeGraphEditOpVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeGraphEditOpVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNodeGraphEditVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeGraphEditVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeIdNodeMapVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeIdNodeMapVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInputOutputTypeIdVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::InputOutputTypeIdVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeTypeFieldVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeTypeFieldVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10620,6 +12316,7 @@ impl Clone for AzOutputNodeAndIndexVecDestructorEnumWrapper { fn clone(&self) ->
 impl Clone for AzOutputConnectionVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OutputConnectionVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInputNodeAndIndexVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::InputNodeAndIndexVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAccessibilityStateVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::AccessibilityStateVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzKeyboardShortcutVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::KeyboardShortcutVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuItemVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuItemVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTessellatedSvgNodeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::TessellatedSvgNodeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTessellatedColoredSvgNodeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::TessellatedColoredSvgNodeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10628,6 +12325,8 @@ impl Clone for AzFmtArgVecDestructorEnumWrapper { fn clone(&self) -> Self { let
 impl Clone for AzInlineLineVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineLineVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineWordVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineWordVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineGlyphVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineGlyphVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextLineMetricsVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::TextLineMetricsVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzInlineTextRunVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineTextRunVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineTextHitVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineTextHitVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMonitorVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MonitorVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVideoModeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::VideoModeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10655,6 +12354,9 @@ impl Clone for AzScanCodeVecDestructorEnumWrapper { fn clone(&self) -> Self { le
 impl Clone for AzCssDeclarationVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssDeclarationVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPathSelectorVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssPathSelectorVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStylesheetVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StylesheetVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssFontFaceVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssFontFaceVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssFontFaceSourceVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssFontFaceSourceVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssUnicodeRangeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssUnicodeRangeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssRuleBlockVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssRuleBlockVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzF32VecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::F32VecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzU16VecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::U16VecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10697,6 +12399,8 @@ impl Clone for AzVideoMode { fn clone(&self) -> Self { let r: &azul_core::window
 impl Clone for AzDomNodeId { fn clone(&self) -> Self { let r: &azul_impl::callbacks::DomNodeId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzPositionInfoEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::ui_solver::PositionInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzHidpiAdjustedBounds { fn clone(&self) -> Self { let r: &azul_impl::callbacks::HidpiAdjustedBounds = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextLineMetrics { fn clone(&self) -> Self { let r: &azul_impl::callbacks::TextLineMetrics = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzInlineTextRun { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineTextRun = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineGlyph { fn clone(&self) -> Self { let r: &azul_core::callbacks::InlineGlyph = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineTextHit { fn clone(&self) -> Self { let r: &azul_core::callbacks::InlineTextHit = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzIFrameCallbackInfo { fn clone(&self) -> Self { let r: &azul_impl::callbacks::IFrameCallbackInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10805,6 +12509,7 @@ impl Clone for AzLayoutPaddingTopValueEnumWrapper { fn clone(&self) -> Self { le
 impl Clone for AzLayoutPositionValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutPositionValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutRightValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutRightValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutTopValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutTopValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutVerticalAlignValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutVerticalAlignValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutWidthValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutWidthValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutFlexWrapValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutFlexWrapValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutOverflowValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutOverflowValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10830,6 +12535,7 @@ impl Clone for AzStyleLetterSpacingValueEnumWrapper { fn clone(&self) -> Self {
 impl Clone for AzStyleLineHeightValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleLineHeightValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTabWidthValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTabWidthValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTextAlignValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTextAlignValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTextDecorationValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTextDecorationValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTextColorValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTextColorValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleWordSpacingValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleWordSpacingValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleOpacityValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleOpacityValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10858,11 +12564,39 @@ impl Clone for AzNodeGraphOnNodeConnected { fn clone(&self) -> Self { let r: &cr
 impl Clone for AzNodeGraphOnNodeInputDisconnected { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OnNodeInputDisconnected = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeGraphOnNodeOutputDisconnected { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OnNodeOutputDisconnected = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeGraphOnNodeFieldEdited { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OnNodeFieldEdited = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNodeGraphOnNodeGraphZoomed { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OnNodeGraphZoomed = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNodeGraphOnSelectionChanged { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OnNodeGraphSelectionChanged = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNodeGraphOnHistoryChanged { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OnNodeGraphHistoryChanged = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOutputNodeAndIndex { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OutputNodeAndIndex = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInputNodeAndIndex { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::InputNodeAndIndex = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNodeGraphConnection { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeGraphConnection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNodeGraphNodeMove { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeGraphNodeMove = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzListViewOnLazyLoadScroll { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewOnLazyLoadScroll = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzListViewOnColumnClick { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewOnColumnClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzListViewOnRowClick { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewOnRowClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzListViewOnRowSelect { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewOnRowSelect = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzListViewOnColumnResize { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewOnColumnResize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzListViewRowProvider { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewRowProvider = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewOnExpand { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewOnExpand = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewOnSelect { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewOnSelect = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewOnLazyLoad { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewOnLazyLoad = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewOnScroll { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewOnScroll = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDataGridRange { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::DataGridRange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDataGridCellProvider { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::DataGridCellProvider = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDataGridCellStyle { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::DataGridCellStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDataGridOnSelect { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::DataGridOnSelect = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDataGridOnScroll { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::DataGridOnScroll = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDataGridOnCellEdit { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::DataGridOnCellEdit = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDataGridOnColumnResize { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::DataGridOnColumnResize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDataGridOnRowResize { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::DataGridOnRowResize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDataGridOnCopyRange { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::DataGridOnCopyRange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDataGridOnClearRange { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::DataGridOnClearRange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDatePickerOnDateChange { fn clone(&self) -> Self { let r: &crate::widgets::date_picker::DatePickerOnDateChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTimePickerOnTimeChange { fn clone(&self) -> Self { let r: &crate::widgets::date_picker::TimePickerOnTimeChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDateTimePickerOnDateTimeChange { fn clone(&self) -> Self { let r: &crate::widgets::date_picker::DateTimePickerOnDateTimeChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDialogOnClose { fn clone(&self) -> Self { let r: &crate::widgets::dialog::DialogOnClose = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitterPaneState { fn clone(&self) -> Self { let r: &crate::widgets::splitter::SplitterPaneState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitterOnResize { fn clone(&self) -> Self { let r: &crate::widgets::splitter::SplitterOnResize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDropDownOnChoiceChange { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::DropDownOnChoiceChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzParentWithNodeDepth { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::ParentWithNodeDepth = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzGl { fn clone(&self) -> Self { let r: &azul_impl::gl::GlContextPtr = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10874,11 +12608,15 @@ impl Clone for AzSvgQuadraticCurve { fn clone(&self) -> Self { let r: &azul_impl
 impl Clone for AzSvgCubicCurve { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgCubicCurve = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgStringFormatOptions { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgXmlOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgFillStyle { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgFillStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzXmlSpan { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlSpan = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInstantPtr { fn clone(&self) -> Self { let r: &azul_impl::task::AzInstantPtr = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDurationEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::task::Duration = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzThreadSendMsgEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::task::ThreadSendMsg = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzThreadWriteBackMsg { fn clone(&self) -> Self { let r: &azul_impl::task::ThreadWriteBackMsg = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewItemVec { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewItemVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitterPaneStateVec { fn clone(&self) -> Self { let r: &crate::widgets::splitter::SplitterPaneStateVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLogicalRectVec { fn clone(&self) -> Self { let r: &azul_core::window::LogicalRectVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNodeGraphNodeIdVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeGraphNodeIdVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInputOutputTypeIdVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::InputOutputTypeIdVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOutputNodeAndIndexVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OutputNodeAndIndexVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInputNodeAndIndexVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::InputNodeAndIndexVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10886,6 +12624,8 @@ impl Clone for AzAccessibilityStateVec { fn clone(&self) -> Self { let r: &azul_
 impl Clone for AzMenuItemVec { fn clone(&self) -> Self { let r: &azul_core::window::MenuItemVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlNodeVec { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineGlyphVec { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineGlyphVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextLineMetricsVec { fn clone(&self) -> Self { let r: &azul_impl::callbacks::TextLineMetricsVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzInlineTextRunVec { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineTextRunVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineTextHitVec { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineTextHitVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVideoModeVec { fn clone(&self) -> Self { let r: &azul_core::window::VideoModeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDomVec { fn clone(&self) -> Self { let r: &azul_impl::dom::DomVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10899,6 +12639,7 @@ impl Clone for AzXWindowTypeVec { fn clone(&self) -> Self { let r: &azul_core::w
 impl Clone for AzVirtualKeyCodeVec { fn clone(&self) -> Self { let r: &azul_core::window::VirtualKeyCodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCascadeInfoVec { fn clone(&self) -> Self { let r: &azul_impl::style::CascadeInfoVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzScanCodeVec { fn clone(&self) -> Self { let r: &azul_core::window::ScanCodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssUnicodeRangeVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssUnicodeRangeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzU16Vec { fn clone(&self) -> Self { let r: &azul_impl::css::U16Vec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzF32Vec { fn clone(&self) -> Self { let r: &azul_impl::css::F32Vec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzU8Vec { fn clone(&self) -> Self { let r: &azul_impl::css::U8Vec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10910,11 +12651,38 @@ impl Clone for AzNodeIdVec { fn clone(&self) -> Self { let r: &azul_impl::styled
 impl Clone for AzNodeHierarchyItemVec { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::NodeHierarchyItemVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzParentWithNodeDepthVec { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::ParentWithNodeDepthVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionSvgPointEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionSvgPoint = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTreeViewOnExpandEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::OptionTreeViewOnExpand = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTreeViewOnSelectEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::OptionTreeViewOnSelect = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTreeViewOnLazyLoadEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::OptionTreeViewOnLazyLoad = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTreeViewOnScrollEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::OptionTreeViewOnScroll = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionListViewOnRowClickEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::list_view::OptionListViewOnRowClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionListViewOnRowSelectEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::list_view::OptionListViewOnRowSelect = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionListViewOnColumnResizeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::list_view::OptionListViewOnColumnResize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionListViewRowProviderEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::list_view::OptionListViewRowProvider = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionDataGridRangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::OptionDataGridRange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionDataGridCellProviderEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::OptionDataGridCellProvider = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionDataGridCellStyleEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::OptionDataGridCellStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionDataGridOnSelectEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::OptionDataGridOnSelect = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionDataGridOnScrollEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::OptionDataGridOnScroll = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionDataGridOnCellEditEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::OptionDataGridOnCellEdit = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionDataGridOnColumnResizeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::OptionDataGridOnColumnResize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionDataGridOnRowResizeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::OptionDataGridOnRowResize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionDataGridOnCopyRangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::OptionDataGridOnCopyRange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionDataGridOnClearRangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::OptionDataGridOnClearRange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionDateEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::date_picker::OptionDate = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTimeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::date_picker::OptionTime = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionDatePickerOnDateChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::date_picker::OptionDatePickerOnDateChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTimePickerOnTimeChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::date_picker::OptionTimePickerOnTimeChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionDateTimePickerOnDateTimeChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::date_picker::OptionDateTimePickerOnDateTimeChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionDialogOnCloseEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::dialog::OptionDialogOnClose = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionSplitterOnResizeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::splitter::OptionSplitterOnResize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionListViewOnColumnClickEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::list_view::OptionListViewOnColumnClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionListViewOnLazyLoadScrollEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::list_view::OptionListViewOnLazyLoadScroll = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionPixelValueNoPercentEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionPixelValueNoPercent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionDropDownOnChoiceChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::OptionDropDownOnChoiceChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionNodeGraphOnNodeGraphZoomedEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OptionOnNodeGraphZoomed = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionNodeGraphOnSelectionChangedEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OptionOnNodeGraphSelectionChanged = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionNodeGraphOnHistoryChangedEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OptionOnNodeGraphHistoryChanged = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionNodeGraphOnNodeAddedEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OptionOnNodeAdded = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionNodeGraphOnNodeRemovedEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OptionOnNodeRemoved = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionNodeGraphOnNodeGraphDraggedEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OptionOnNodeGraphDragged = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10983,6 +12751,7 @@ impl Clone for AzWindowSize { fn clone(&self) -> Self { let r: &azul_core::windo
 impl Clone for AzKeyboardState { fn clone(&self) -> Self { let r: &azul_core::window::KeyboardState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMouseState { fn clone(&self) -> Self { let r: &azul_core::window::MouseState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMarshaledLayoutCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::MarshaledLayoutCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextMetrics { fn clone(&self) -> Self { let r: &azul_impl::callbacks::TextMetrics = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineTextContents { fn clone(&self) -> Self { let r: &azul_core::callbacks::InlineTextContents = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzResolvedTextLayoutOptions { fn clone(&self) -> Self { let r: &azul_impl::ui_solver::ResolvedTextLayoutOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAnimationEasingEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::AnimationInterpolationFunction = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10991,6 +12760,7 @@ impl Clone for AzLayoutCallbackInfo { fn clone(&self) -> Self { let r: &azul_imp
 impl Clone for AzEventFilterEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::EventFilter = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenu { fn clone(&self) -> Self { let r: &azul_core::window::Menu = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVirtualKeyCodeCombo { fn clone(&self) -> Self { let r: &azul_core::window::VirtualKeyCodeCombo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzKeyboardShortcut { fn clone(&self) -> Self { let r: &azul_core::window::KeyboardShortcut = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPathPseudoSelectorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssPathPseudoSelector = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAnimationInterpolationFunctionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::AnimationInterpolationFunction = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInterpolateContext { fn clone(&self) -> Self { let r: &azul_impl::css::InterpolateResolver = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11008,6 +12778,13 @@ impl Clone for AzNodeGraphCallbacks { fn clone(&self) -> Self { let r: &crate::w
 impl Clone for AzInputConnection { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::InputConnection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOutputConnection { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OutputConnection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzListViewRow { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewRow = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeView { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeView = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDatePickerState { fn clone(&self) -> Self { let r: &crate::widgets::date_picker::DatePickerState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDatePicker { fn clone(&self) -> Self { let r: &crate::widgets::date_picker::DatePicker = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTimePicker { fn clone(&self) -> Self { let r: &crate::widgets::date_picker::TimePicker = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDateTimePickerState { fn clone(&self) -> Self { let r: &crate::widgets::date_picker::DateTimePickerState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDateTimePicker { fn clone(&self) -> Self { let r: &crate::widgets::date_picker::DateTimePicker = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitterState { fn clone(&self) -> Self { let r: &crate::widgets::splitter::SplitterState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyledNode { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::StyledNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTagIdToNodeIdMapping { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::TagIdToNodeIdMapping = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTexture { fn clone(&self) -> Self { let r: &azul_impl::gl::Texture = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11029,6 +12806,7 @@ impl Clone for AzListViewRowVec { fn clone(&self) -> Self { let r: &crate::widge
 impl Clone for AzStyleFilterVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFilterVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInputConnectionVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::InputConnectionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOutputConnectionVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OutputConnectionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzKeyboardShortcutVec { fn clone(&self) -> Self { let r: &azul_core::window::KeyboardShortcutVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTessellatedSvgNodeVec { fn clone(&self) -> Self { let r: &azul_impl::svg::TessellatedSvgNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTessellatedColoredSvgNodeVec { fn clone(&self) -> Self { let r: &azul_impl::svg::TessellatedColoredSvgNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11043,6 +12821,7 @@ impl Clone for AzOptionMouseStateEnumWrapper { fn clone(&self) -> Self { let r:
 impl Clone for AzOptionKeyboardStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionKeyboardState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionStringVecEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionStringVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionThreadReceiveMsgEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::task::OptionThreadReceiveMsg = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTextMetricsEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::OptionTextMetrics = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionTaskBarIconEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionTaskBarIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionWindowIconEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionWindowIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionStringEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionAzString = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11066,6 +12845,7 @@ impl Clone for AzAccessibilityInfo { fn clone(&self) -> Self { let r: &azul_impl
 impl Clone for AzIdOrClassEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::IdOrClass = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStringMenuItem { fn clone(&self) -> Self { let r: &azul_core::window::StringMenuItem = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPathSelectorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssPathSelector = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssFontFaceUrl { fn clone(&self) -> Self { let r: &azul_impl::css::CssFontFaceUrl = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundContentEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundContent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzScrollbarInfo { fn clone(&self) -> Self { let r: &azul_impl::css::ScrollbarInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzScrollbarStyle { fn clone(&self) -> Self { let r: &azul_impl::css::ScrollbarStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11080,9 +12860,12 @@ impl Clone for AzTabHeader { fn clone(&self) -> Self { let r: &crate::widgets::t
 impl Clone for AzNodeTypeFieldValueEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeTypeFieldValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeTypeInfo { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeTypeInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInputOutputInfo { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::InputOutputInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNodeGraphFieldEdit { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeGraphFieldEdit = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzListView { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListView = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzListViewState { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzTreeView { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeView = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewItem { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewItem = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDataGrid { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::DataGrid = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDialogResult { fn clone(&self) -> Self { let r: &crate::widgets::dialog::DialogResult = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDropDown { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::DropDown = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVertexAttribute { fn clone(&self) -> Self { let r: &azul_impl::gl::VertexAttribute = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDebugMessage { fn clone(&self) -> Self { let r: &azul_impl::gl::AzDebugMessage = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11119,6 +12902,7 @@ impl Clone for AzLinuxWindowOptions { fn clone(&self) -> Self { let r: &azul_cor
 impl Clone for AzInlineLine { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineLine = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuItemEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuItem = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPath { fn clone(&self) -> Self { let r: &azul_impl::css::CssPath = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssFontFaceSourceEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssFontFaceSource = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundContentVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundContentVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFontFamilyVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontFamilyVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPropertyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11144,6 +12928,7 @@ impl Clone for AzInlineLineVec { fn clone(&self) -> Self { let r: &azul_impl::ca
 impl Clone for AzCssPropertyVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssPropertyVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgMultiPolygonVec { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgMultiPolygonVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgSimpleNodeVec { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgSimpleNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssFontFaceSourceVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssFontFaceSourceVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionCssPropertyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionCssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlTextError { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlTextError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzPlatformSpecificOptions { fn clone(&self) -> Self { let r: &azul_core::window::PlatformSpecificOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11155,10 +12940,12 @@ impl Clone for AzAnimation { fn clone(&self) -> Self { let r: &azul_impl::callba
 impl Clone for AzTimerCallbackInfo { fn clone(&self) -> Self { let r: &azul_impl::callbacks::TimerCallbackInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeDataInlineCssPropertyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeDataInlineCssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDynamicCssProperty { fn clone(&self) -> Self { let r: &azul_impl::css::DynamicCssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssFontFace { fn clone(&self) -> Self { let r: &azul_impl::css::CssFontFace = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNode { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::Node = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgNodeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgStyledNode { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgStyledNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeDataInlineCssPropertyVec { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeDataInlineCssPropertyVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssFontFaceVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssFontFaceVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionWindowStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionWindowState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionInlineTextEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::OptionInlineText = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlParseErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlParseError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11174,26 +12961,38 @@ impl Clone for AzColorInput { fn clone(&self) -> Self { let r: &crate::widgets::
 impl Clone for AzTextInput { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInput = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNumberInput { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInput = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeIdNodeMap { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeIdNodeMap = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNodeGraphEditOpEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeGraphEditOp = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNodeGraphEditOpVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeGraphEditOpVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeIdNodeMapVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeIdNodeMapVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssDeclarationVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssDeclarationVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeDataVec { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeDataVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDom { fn clone(&self) -> Self { let r: &azul_impl::dom::Dom = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTooltipContentEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::TooltipContent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssRuleBlock { fn clone(&self) -> Self { let r: &azul_impl::css::CssRuleBlock = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTabContent { fn clone(&self) -> Self { let r: &crate::widgets::tabs::TabContent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFrame { fn clone(&self) -> Self { let r: &crate::widgets::frame::Frame = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzNodeGraph { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeGraph = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNodeGraphEdit { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeGraphEdit = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitterPane { fn clone(&self) -> Self { let r: &crate::widgets::splitter::SplitterPane = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyledDom { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::StyledDom = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitterPaneVec { fn clone(&self) -> Self { let r: &crate::widgets::splitter::SplitterPaneVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNodeGraphEditVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeGraphEditVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssRuleBlockVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssRuleBlockVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionDomEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::OptionDom = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzResultXmlXmlErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::ResultXmlXmlError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgParseErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgParseError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzIFrameCallbackReturn { fn clone(&self) -> Self { let r: &azul_impl::callbacks::IFrameCallbackReturn = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTooltip { fn clone(&self) -> Self { let r: &azul_impl::dom::Tooltip = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStylesheet { fn clone(&self) -> Self { let r: &azul_impl::css::Stylesheet = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNodeGraphHistory { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeGraphHistory = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDialog { fn clone(&self) -> Self { let r: &crate::widgets::dialog::Dialog = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitter { fn clone(&self) -> Self { let r: &crate::widgets::splitter::Splitter = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStylesheetVec { fn clone(&self) -> Self { let r: &azul_impl::css::StylesheetVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzResultSvgXmlNodeSvgParseErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::ResultSvgXmlNodeSvgParseError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzResultSvgSvgParseErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::ResultSvgSvgParseError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCss { fn clone(&self) -> Self { let r: &azul_impl::css::Css = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNodeGraph { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeGraph = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzResultNodeGraphNodeGraphErrorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::ResultNodeGraphNodeGraphError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 
 // Implement Drop for all objects with drop constructors
 impl Drop for AzApp { fn drop(&mut self) { crate::AzApp_delete(unsafe { mem::transmute(self) }); } }
@@ -11212,7 +13011,10 @@ impl Drop for AzThreadReceiver { fn drop(&mut self) { crate::AzThreadReceiver_de
 impl Drop for AzRefAny { fn drop(&mut self) { crate::AzRefAny_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzGl { fn drop(&mut self) { crate::AzGl_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInstantPtr { fn drop(&mut self) { crate::AzInstantPtr_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzTreeViewItemVec { fn drop(&mut self) { crate::AzTreeViewItemVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzSplitterPaneStateVec { fn drop(&mut self) { crate::AzSplitterPaneStateVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzLogicalRectVec { fn drop(&mut self) { crate::AzLogicalRectVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzNodeGraphNodeIdVec { fn drop(&mut self) { crate::AzNodeGraphNodeIdVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInputOutputTypeIdVec { fn drop(&mut self) { crate::AzInputOutputTypeIdVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzOutputNodeAndIndexVec { fn drop(&mut self) { crate::AzOutputNodeAndIndexVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInputNodeAndIndexVec { fn drop(&mut self) { crate::AzInputNodeAndIndexVec_delete(unsafe { mem::transmute(self) }); } }
@@ -11220,6 +13022,8 @@ impl Drop for AzAccessibilityStateVec { fn drop(&mut self) { crate::AzAccessibil
 impl Drop for AzMenuItemVec { fn drop(&mut self) { crate::AzMenuItemVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzXmlNodeVec { fn drop(&mut self) { crate::AzXmlNodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInlineGlyphVec { fn drop(&mut self) { crate::AzInlineGlyphVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzTextLineMetricsVec { fn drop(&mut self) { crate::AzTextLineMetricsVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzInlineTextRunVec { fn drop(&mut self) { crate::AzInlineTextRunVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInlineTextHitVec { fn drop(&mut self) { crate::AzInlineTextHitVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzVideoModeVec { fn drop(&mut self) { crate::AzVideoModeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzDomVec { fn drop(&mut self) { crate::AzDomVec_delete(unsafe { mem::transmute(self) }); } }
@@ -11233,6 +13037,7 @@ impl Drop for AzXWindowTypeVec { fn drop(&mut self) { crate::AzXWindowTypeVec_de
 impl Drop for AzVirtualKeyCodeVec { fn drop(&mut self) { crate::AzVirtualKeyCodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzCascadeInfoVec { fn drop(&mut self) { crate::AzCascadeInfoVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzScanCodeVec { fn drop(&mut self) { crate::AzScanCodeVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzCssUnicodeRangeVec { fn drop(&mut self) { crate::AzCssUnicodeRangeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzU16Vec { fn drop(&mut self) { crate::AzU16Vec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzF32Vec { fn drop(&mut self) { crate::AzF32Vec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzU8Vec { fn drop(&mut self) { crate::AzU8Vec_delete(unsafe { mem::transmute(self) }); } }
@@ -11248,6 +13053,7 @@ impl Drop for AzListViewRowVec { fn drop(&mut self) { crate::AzListViewRowVec_de
 impl Drop for AzStyleFilterVec { fn drop(&mut self) { crate::AzStyleFilterVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInputConnectionVec { fn drop(&mut self) { crate::AzInputConnectionVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzOutputConnectionVec { fn drop(&mut self) { crate::AzOutputConnectionVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzKeyboardShortcutVec { fn drop(&mut self) { crate::AzKeyboardShortcutVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzTessellatedSvgNodeVec { fn drop(&mut self) { crate::AzTessellatedSvgNodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzTessellatedColoredSvgNodeVec { fn drop(&mut self) { crate::AzTessellatedColoredSvgNodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStyleTransformVec { fn drop(&mut self) { crate::AzStyleTransformVec_delete(unsafe { mem::transmute(self) }); } }
@@ -11277,10 +13083,15 @@ impl Drop for AzInlineLineVec { fn drop(&mut self) { crate::AzInlineLineVec_dele
 impl Drop for AzCssPropertyVec { fn drop(&mut self) { crate::AzCssPropertyVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzSvgMultiPolygonVec { fn drop(&mut self) { crate::AzSvgMultiPolygonVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzSvgSimpleNodeVec { fn drop(&mut self) { crate::AzSvgSimpleNodeVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzCssFontFaceSourceVec { fn drop(&mut self) { crate::AzCssFontFaceSourceVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzNodeDataInlineCssPropertyVec { fn drop(&mut self) { crate::AzNodeDataInlineCssPropertyVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzCssFontFaceVec { fn drop(&mut self) { crate::AzCssFontFaceVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzNodeGraphEditOpVec { fn drop(&mut self) { crate::AzNodeGraphEditOpVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzNodeIdNodeMapVec { fn drop(&mut self) { crate::AzNodeIdNodeMapVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzCssDeclarationVec { fn drop(&mut self) { crate::AzCssDeclarationVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzNodeDataVec { fn drop(&mut self) { crate::AzNodeDataVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzSplitterPaneVec { fn drop(&mut self) { crate::AzSplitterPaneVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzNodeGraphEditVec { fn drop(&mut self) { crate::AzNodeGraphEditVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzCssRuleBlockVec { fn drop(&mut self) { crate::AzCssRuleBlockVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStylesheetVec { fn drop(&mut self) { crate::AzStylesheetVec_delete(unsafe { mem::transmute(self) }); } }
 
@@ -11301,6 +13112,15 @@ impl AzApp {
             mem::transmute(image),
         )) }
     }
+    fn add_font(&mut self, family: String, font_bytes: Vec<u8>) -> bool {
+        let family = pystring_to_azstring(&family);
+        let font_bytes = pyvecu8_to_vecu8(&font_bytes);
+        unsafe { mem::transmute(crate::AzApp_addFont(
+            mem::transmute(self),
+            mem::transmute(family),
+            mem::transmute(font_bytes),
+        )) }
+    }
     fn get_monitors(&self) -> AzMonitorVec {
         unsafe { mem::transmute(crate::AzApp_getMonitors(
             mem::transmute(self),
@@ -13971,13 +15791,14 @@ impl PyObjectProtocol for AzHidpiAdjustedBounds {
 #[pymethods]
 impl AzInlineText {
     #[new]
-    fn __new__(lines: AzInlineLineVec, content_size: AzLogicalSize, font_size_px: f32, last_word_index: usize, baseline_descender_px: f32) -> Self {
+    fn __new__(lines: AzInlineLineVec, content_size: AzLogicalSize, font_size_px: f32, last_word_index: usize, baseline_descender_px: f32, runs: AzInlineTextRunVec) -> Self {
         Self {
             lines,
             content_size,
             font_size_px,
             last_word_index,
             baseline_descender_px,
+            runs,
         }
     }
 
@@ -13987,6 +15808,11 @@ impl AzInlineText {
             mem::transmute(position),
         )) }
     }
+    fn get_text_metrics(&self) -> AzTextMetrics {
+        unsafe { mem::transmute(crate::AzInlineText_getTextMetrics(
+            mem::transmute(self),
+        )) }
+    }
 }
 
 #[pyproto]
@@ -13999,6 +15825,77 @@ impl PyObjectProtocol for AzInlineText {
     }
 }
 
+#[pymethods]
+impl AzTextMetrics {
+    #[new]
+    fn __new__(width: f32, height: f32, line_count: usize, lines: AzTextLineMetricsVec) -> Self {
+        Self {
+            width,
+            height,
+            line_count,
+            lines,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextMetrics {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::TextMetrics = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::TextMetrics = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextLineMetrics {
+    #[new]
+    fn __new__(bounds: AzLogicalRect, baseline_px: f32, glyph_count: usize) -> Self {
+        Self {
+            bounds,
+            baseline_px,
+            glyph_count,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextLineMetrics {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::TextLineMetrics = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::TextLineMetrics = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzInlineTextRun {
+    #[new]
+    fn __new__(node_id: AzNodeId, glyph_start: usize, glyph_end: usize, font_size_px: f32) -> Self {
+        Self {
+            node_id,
+            glyph_start,
+            glyph_end,
+            font_size_px,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzInlineTextRun {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::InlineTextRun = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::InlineTextRun = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzInlineLine {
     #[new]
@@ -14696,6 +16593,20 @@ impl AzLayoutCallbackInfo {
         }
 
     }
+    fn measure_text(&self, text: String, font_families: AzStyleFontFamilyVec, options: AzResolvedTextLayoutOptions) -> Option<AzTextMetrics> {
+        let text = pystring_to_azstring(&text);
+        let m: AzOptionTextMetrics = unsafe { mem::transmute(crate::AzLayoutCallbackInfo_measureText(
+            mem::transmute(self),
+            mem::transmute(text),
+            mem::transmute(font_families),
+            mem::transmute(options),
+        )) };
+        match m {
+            AzOptionTextMetrics::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionTextMetrics::None => None,
+        }
+
+    }
 }
 
 #[pyproto]
@@ -15005,6 +16916,35 @@ impl AzDom {
             mem::transmute(context_menu),
         )) }
     }
+    fn set_tooltip(&mut self, tooltip: AzTooltip) -> () {
+        unsafe { mem::transmute(crate::AzDom_setTooltip(
+            mem::transmute(self),
+            mem::transmute(tooltip),
+        )) }
+    }
+    fn with_tooltip(&mut self, tooltip: AzTooltip) -> AzDom {
+        unsafe { mem::transmute(crate::AzDom_withTooltip(
+            mem::transmute(self),
+            mem::transmute(tooltip),
+        )) }
+    }
+    fn set_keyboard_shortcuts(&mut self, keyboard_shortcuts: AzKeyboardShortcutVec) -> () {
+        unsafe { mem::transmute(crate::AzDom_setKeyboardShortcuts(
+            mem::transmute(self),
+            mem::transmute(keyboard_shortcuts),
+        )) }
+    }
+    fn with_keyboard_shortcuts(&mut self, keyboard_shortcuts: AzKeyboardShortcutVec) -> AzDom {
+        unsafe { mem::transmute(crate::AzDom_withKeyboardShortcuts(
+            mem::transmute(self),
+            mem::transmute(keyboard_shortcuts),
+        )) }
+    }
+    fn make_inert(&mut self) -> () {
+        unsafe { mem::transmute(crate::AzDom_makeInert(
+            mem::transmute(self),
+        )) }
+    }
     fn hash(&self) -> u64 {
         unsafe { mem::transmute(crate::AzDom_hash(
             mem::transmute(self),
@@ -15337,6 +17277,18 @@ impl AzNodeData {
             mem::transmute(context_menu),
         )) }
     }
+    fn set_tooltip(&mut self, tooltip: AzTooltip) -> () {
+        unsafe { mem::transmute(crate::AzNodeData_setTooltip(
+            mem::transmute(self),
+            mem::transmute(tooltip),
+        )) }
+    }
+    fn set_keyboard_shortcuts(&mut self, keyboard_shortcuts: AzKeyboardShortcutVec) -> () {
+        unsafe { mem::transmute(crate::AzNodeData_setKeyboardShortcuts(
+            mem::transmute(self),
+            mem::transmute(keyboard_shortcuts),
+        )) }
+    }
     fn hash(&self) -> u64 {
         unsafe { mem::transmute(crate::AzNodeData_hash(
             mem::transmute(self),
@@ -15893,7 +17845,7 @@ impl PyObjectProtocol for AzApplicationEventFilterEnumWrapper {
 #[pymethods]
 impl AzAccessibilityInfo {
     #[new]
-    fn __new__(name: AzOptionStringEnumWrapper, value: AzOptionStringEnumWrapper, role: AzAccessibilityRoleEnumWrapper, states: AzAccessibilityStateVec, accelerator: AzOptionVirtualKeyCodeComboEnumWrapper, default_action: AzOptionStringEnumWrapper) -> Self {
+    fn __new__(name: AzOptionStringEnumWrapper, value: AzOptionStringEnumWrapper, role: AzAccessibilityRoleEnumWrapper, states: AzAccessibilityStateVec, accelerator: AzOptionVirtualKeyCodeComboEnumWrapper, default_action: AzOptionStringEnumWrapper, description: AzOptionStringEnumWrapper) -> Self {
         Self {
             name,
             value,
@@ -15901,6 +17853,7 @@ impl AzAccessibilityInfo {
             states,
             accelerator,
             default_action,
+            description,
         }
     }
 
@@ -15916,6 +17869,68 @@ impl PyObjectProtocol for AzAccessibilityInfo {
     }
 }
 
+#[pymethods]
+impl AzTooltip {
+    #[staticmethod]
+    fn text(text: String) -> AzTooltip {
+        let text = pystring_to_azstring(&text);
+        unsafe { mem::transmute(crate::AzTooltip_text(
+            mem::transmute(text),
+        )) }
+    }
+    #[staticmethod]
+    fn dom(dom: AzDom) -> AzTooltip {
+        unsafe { mem::transmute(crate::AzTooltip_dom(
+            mem::transmute(dom),
+        )) }
+    }
+    fn with_delay(&mut self, delay: AzDurationEnumWrapper) -> AzTooltip {
+        unsafe { mem::transmute(crate::AzTooltip_withDelay(
+            mem::transmute(self),
+            mem::transmute(delay),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTooltip {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::Tooltip = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::Tooltip = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTooltipContentEnumWrapper {
+    #[staticmethod]
+    fn Text(v: AzString) -> AzTooltipContentEnumWrapper { AzTooltipContentEnumWrapper { inner: AzTooltipContent::Text(v) } }
+    #[staticmethod]
+    fn Dom(v: AzDom) -> AzTooltipContentEnumWrapper { AzTooltipContentEnumWrapper { inner: AzTooltipContent::Dom(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzTooltipContent;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzTooltipContent::Text(v) => Ok(vec!["Text".into_py(py), v.clone().into_py(py)]),
+            AzTooltipContent::Dom(v) => Ok(vec!["Dom".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTooltipContentEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::TooltipContent = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::TooltipContent = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzAccessibilityRoleEnumWrapper {
     #[classattr]
@@ -16412,6 +18427,12 @@ impl AzVirtualKeyCodeCombo {
         }
     }
 
+    fn matches(&self, keyboard_state: AzKeyboardState) -> bool {
+        unsafe { mem::transmute(crate::AzVirtualKeyCodeCombo_matches(
+            mem::transmute(self),
+            mem::transmute(keyboard_state),
+        )) }
+    }
 }
 
 #[pyproto]
@@ -16424,6 +18445,20 @@ impl PyObjectProtocol for AzVirtualKeyCodeCombo {
     }
 }
 
+#[pymethods]
+impl AzKeyboardShortcut {
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzKeyboardShortcut {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::KeyboardShortcut = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::KeyboardShortcut = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzMenuCallback {
 }
@@ -16815,6 +18850,134 @@ impl PyObjectProtocol for AzCss {
     }
 }
 
+#[pymethods]
+impl AzCssFontFace {
+    #[new]
+    fn __new__(font_family: AzString, src: AzCssFontFaceSourceVec, font_weight: u16, font_style: AzCssFontFaceStyleEnumWrapper, unicode_range: AzCssUnicodeRangeVec) -> Self {
+        Self {
+            font_family,
+            src,
+            font_weight,
+            font_style,
+            unicode_range,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssFontFace {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssFontFace = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssFontFace = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssFontFaceSourceEnumWrapper {
+    #[staticmethod]
+    fn Url(v: AzCssFontFaceUrl) -> AzCssFontFaceSourceEnumWrapper { AzCssFontFaceSourceEnumWrapper { inner: AzCssFontFaceSource::Url(v) } }
+    #[staticmethod]
+    fn Local(v: AzString) -> AzCssFontFaceSourceEnumWrapper { AzCssFontFaceSourceEnumWrapper { inner: AzCssFontFaceSource::Local(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzCssFontFaceSource;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzCssFontFaceSource::Url(v) => Ok(vec!["Url".into_py(py), v.clone().into_py(py)]),
+            AzCssFontFaceSource::Local(v) => Ok(vec!["Local".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssFontFaceSourceEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssFontFaceSource = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssFontFaceSource = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssFontFaceUrl {
+    #[new]
+    fn __new__(url: AzString, format: AzOptionStringEnumWrapper) -> Self {
+        Self {
+            url,
+            format,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssFontFaceUrl {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssFontFaceUrl = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssFontFaceUrl = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssFontFaceStyleEnumWrapper {
+    #[classattr]
+    fn Normal() -> AzCssFontFaceStyleEnumWrapper { AzCssFontFaceStyleEnumWrapper { inner: AzCssFontFaceStyle::Normal } }
+    #[classattr]
+    fn Italic() -> AzCssFontFaceStyleEnumWrapper { AzCssFontFaceStyleEnumWrapper { inner: AzCssFontFaceStyle::Italic } }
+    #[classattr]
+    fn Oblique() -> AzCssFontFaceStyleEnumWrapper { AzCssFontFaceStyleEnumWrapper { inner: AzCssFontFaceStyle::Oblique } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssFontFaceStyleEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssFontFaceStyle = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssFontFaceStyle = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzCssFontFaceStyleEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzCssUnicodeRange {
+    #[new]
+    fn __new__(start: u32, end: u32) -> Self {
+        Self {
+            start,
+            end,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssUnicodeRange {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssUnicodeRange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssUnicodeRange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCssPropertyTypeEnumWrapper {
     #[classattr]
@@ -16965,6 +19128,10 @@ impl AzCssPropertyTypeEnumWrapper {
     fn BackdropFilter() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::BackdropFilter } }
     #[classattr]
     fn TextShadow() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::TextShadow } }
+    #[classattr]
+    fn VerticalAlign() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::VerticalAlign } }
+    #[classattr]
+    fn TextDecoration() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::TextDecoration } }
 }
 
 #[pyproto]
@@ -17600,6 +19767,8 @@ impl AzLayoutDisplayEnumWrapper {
     fn Block() -> AzLayoutDisplayEnumWrapper { AzLayoutDisplayEnumWrapper { inner: AzLayoutDisplay::Block } }
     #[classattr]
     fn InlineBlock() -> AzLayoutDisplayEnumWrapper { AzLayoutDisplayEnumWrapper { inner: AzLayoutDisplay::InlineBlock } }
+    #[classattr]
+    fn Inline() -> AzLayoutDisplayEnumWrapper { AzLayoutDisplayEnumWrapper { inner: AzLayoutDisplay::Inline } }
 }
 
 #[pyproto]
@@ -18096,6 +20265,42 @@ impl PyObjectProtocol for AzLayoutTop {
     }
 }
 
+#[pymethods]
+impl AzLayoutVerticalAlignEnumWrapper {
+    #[classattr]
+    fn Baseline() -> AzLayoutVerticalAlignEnumWrapper { AzLayoutVerticalAlignEnumWrapper { inner: AzLayoutVerticalAlign::Baseline } }
+    #[classattr]
+    fn Top() -> AzLayoutVerticalAlignEnumWrapper { AzLayoutVerticalAlignEnumWrapper { inner: AzLayoutVerticalAlign::Top } }
+    #[classattr]
+    fn Middle() -> AzLayoutVerticalAlignEnumWrapper { AzLayoutVerticalAlignEnumWrapper { inner: AzLayoutVerticalAlign::Middle } }
+    #[classattr]
+    fn Bottom() -> AzLayoutVerticalAlignEnumWrapper { AzLayoutVerticalAlignEnumWrapper { inner: AzLayoutVerticalAlign::Bottom } }
+    #[classattr]
+    fn Sub() -> AzLayoutVerticalAlignEnumWrapper { AzLayoutVerticalAlignEnumWrapper { inner: AzLayoutVerticalAlign::Sub } }
+    #[classattr]
+    fn Super() -> AzLayoutVerticalAlignEnumWrapper { AzLayoutVerticalAlignEnumWrapper { inner: AzLayoutVerticalAlign::Super } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutVerticalAlignEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutVerticalAlign = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutVerticalAlign = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzLayoutVerticalAlignEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzLayoutWidth {
     #[new]
@@ -19783,6 +21988,38 @@ impl PyObjectProtocol for AzStyleTextAlignEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStyleTextDecorationEnumWrapper {
+    #[classattr]
+    fn None() -> AzStyleTextDecorationEnumWrapper { AzStyleTextDecorationEnumWrapper { inner: AzStyleTextDecoration::None } }
+    #[classattr]
+    fn Underline() -> AzStyleTextDecorationEnumWrapper { AzStyleTextDecorationEnumWrapper { inner: AzStyleTextDecoration::Underline } }
+    #[classattr]
+    fn Overline() -> AzStyleTextDecorationEnumWrapper { AzStyleTextDecorationEnumWrapper { inner: AzStyleTextDecoration::Overline } }
+    #[classattr]
+    fn LineThrough() -> AzStyleTextDecorationEnumWrapper { AzStyleTextDecorationEnumWrapper { inner: AzStyleTextDecoration::LineThrough } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTextDecorationEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTextDecoration = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTextDecoration = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzStyleTextDecorationEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzStyleTextColor {
     #[new]
@@ -20889,6 +23126,44 @@ impl PyObjectProtocol for AzLayoutTopValueEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzLayoutVerticalAlignValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzLayoutVerticalAlignValueEnumWrapper { AzLayoutVerticalAlignValueEnumWrapper { inner: AzLayoutVerticalAlignValue::Auto } }
+    #[classattr]
+    fn None() -> AzLayoutVerticalAlignValueEnumWrapper { AzLayoutVerticalAlignValueEnumWrapper { inner: AzLayoutVerticalAlignValue::None } }
+    #[classattr]
+    fn Inherit() -> AzLayoutVerticalAlignValueEnumWrapper { AzLayoutVerticalAlignValueEnumWrapper { inner: AzLayoutVerticalAlignValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzLayoutVerticalAlignValueEnumWrapper { AzLayoutVerticalAlignValueEnumWrapper { inner: AzLayoutVerticalAlignValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzLayoutVerticalAlignEnumWrapper) -> AzLayoutVerticalAlignValueEnumWrapper { AzLayoutVerticalAlignValueEnumWrapper { inner: AzLayoutVerticalAlignValue::Exact(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzLayoutVerticalAlignValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzLayoutVerticalAlignValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzLayoutVerticalAlignValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzLayoutVerticalAlignValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzLayoutVerticalAlignValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzLayoutVerticalAlignValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzLayoutVerticalAlignEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutVerticalAlignValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutVerticalAlignValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutVerticalAlignValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzLayoutWidthValueEnumWrapper {
     #[classattr]
@@ -22067,6 +24342,44 @@ impl PyObjectProtocol for AzStyleTextAlignValueEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStyleTextDecorationValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleTextDecorationValueEnumWrapper { AzStyleTextDecorationValueEnumWrapper { inner: AzStyleTextDecorationValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleTextDecorationValueEnumWrapper { AzStyleTextDecorationValueEnumWrapper { inner: AzStyleTextDecorationValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleTextDecorationValueEnumWrapper { AzStyleTextDecorationValueEnumWrapper { inner: AzStyleTextDecorationValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleTextDecorationValueEnumWrapper { AzStyleTextDecorationValueEnumWrapper { inner: AzStyleTextDecorationValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleTextDecorationEnumWrapper) -> AzStyleTextDecorationValueEnumWrapper { AzStyleTextDecorationValueEnumWrapper { inner: AzStyleTextDecorationValue::Exact(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTextDecorationValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTextDecorationValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleTextDecorationValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleTextDecorationValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleTextDecorationValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleTextDecorationValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzStyleTextDecorationEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTextDecorationValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTextDecorationValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTextDecorationValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleTextColorValueEnumWrapper {
     #[classattr]
@@ -22559,6 +24872,10 @@ impl AzCssPropertyEnumWrapper {
     fn BackdropFilter(v: AzStyleFilterVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::BackdropFilter(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
     fn TextShadow(v: AzStyleBoxShadowValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::TextShadow(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn VerticalAlign(v: AzLayoutVerticalAlignValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::VerticalAlign(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn TextDecoration(v: AzStyleTextDecorationValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::TextDecoration(unsafe { mem::transmute(v) }) } }
 
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzCssProperty;
@@ -22640,6 +24957,8 @@ impl AzCssPropertyEnumWrapper {
             AzCssProperty::Filter(v) => Ok(vec!["Filter".into_py(py), { let m: &AzStyleFilterVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::BackdropFilter(v) => Ok(vec!["BackdropFilter".into_py(py), { let m: &AzStyleFilterVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::TextShadow(v) => Ok(vec!["TextShadow".into_py(py), { let m: &AzStyleBoxShadowValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::VerticalAlign(v) => Ok(vec!["VerticalAlign".into_py(py), { let m: &AzLayoutVerticalAlignValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::TextDecoration(v) => Ok(vec!["TextDecoration".into_py(py), { let m: &AzStyleTextDecorationValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
         }
     }
 }
@@ -23941,21 +26260,23 @@ impl PyObjectProtocol for AzFrame {
 
 #[pymethods]
 impl AzNodeGraph {
-    #[new]
-    fn __new__(node_types: AzNodeTypeIdInfoMapVec, input_output_types: AzInputOutputTypeIdInfoMapVec, nodes: AzNodeIdNodeMapVec, allow_multiple_root_nodes: bool, offset: AzLogicalPosition, style: AzNodeGraphStyleEnumWrapper, callbacks: AzNodeGraphCallbacks, add_node_str: AzString, scale_factor: f32) -> Self {
-        Self {
-            node_types,
-            input_output_types,
-            nodes,
-            allow_multiple_root_nodes,
-            offset,
-            style,
-            callbacks,
-            add_node_str,
-            scale_factor,
+    #[staticmethod]
+    fn from_json(json: String) -> Result<AzNodeGraph, PyErr> {
+        let json = pystring_to_azstring(&json);
+        let m: AzResultNodeGraphNodeGraphError = unsafe { mem::transmute(crate::AzNodeGraph_fromJson(
+            mem::transmute(json),
+        )) };
+        match m {
+            AzResultNodeGraphNodeGraphError::Ok(o) => Ok(o.into()),
+            AzResultNodeGraphNodeGraphError::Err(e) => Err(e.into()),
         }
-    }
 
+    }
+    fn to_json(&self) -> String {
+        az_string_to_py_string(unsafe { mem::transmute(crate::AzNodeGraph_toJson(
+            mem::transmute(self),
+        )) })
+    }
     fn dom(&mut self) -> AzDom {
         unsafe { mem::transmute(crate::AzNodeGraph_dom(
             mem::transmute(self),
@@ -24068,7 +26389,7 @@ impl PyObjectProtocol for AzNodeGraphStyleEnumWrapper {
 #[pymethods]
 impl AzNodeGraphCallbacks {
     #[new]
-    fn __new__(on_node_added: AzOptionNodeGraphOnNodeAddedEnumWrapper, on_node_removed: AzOptionNodeGraphOnNodeRemovedEnumWrapper, on_node_dragged: AzOptionNodeGraphOnNodeDraggedEnumWrapper, on_node_graph_dragged: AzOptionNodeGraphOnNodeGraphDraggedEnumWrapper, on_node_connected: AzOptionNodeGraphOnNodeConnectedEnumWrapper, on_node_input_disconnected: AzOptionNodeGraphOnNodeInputDisconnectedEnumWrapper, on_node_output_disconnected: AzOptionNodeGraphOnNodeOutputDisconnectedEnumWrapper, on_node_field_edited: AzOptionNodeGraphOnNodeFieldEditedEnumWrapper) -> Self {
+    fn __new__(on_node_added: AzOptionNodeGraphOnNodeAddedEnumWrapper, on_node_removed: AzOptionNodeGraphOnNodeRemovedEnumWrapper, on_node_dragged: AzOptionNodeGraphOnNodeDraggedEnumWrapper, on_node_graph_dragged: AzOptionNodeGraphOnNodeGraphDraggedEnumWrapper, on_node_connected: AzOptionNodeGraphOnNodeConnectedEnumWrapper, on_node_input_disconnected: AzOptionNodeGraphOnNodeInputDisconnectedEnumWrapper, on_node_output_disconnected: AzOptionNodeGraphOnNodeOutputDisconnectedEnumWrapper, on_node_field_edited: AzOptionNodeGraphOnNodeFieldEditedEnumWrapper, on_node_graph_zoomed: AzOptionNodeGraphOnNodeGraphZoomedEnumWrapper, on_selection_changed: AzOptionNodeGraphOnSelectionChangedEnumWrapper, on_history_changed: AzOptionNodeGraphOnHistoryChangedEnumWrapper) -> Self {
         Self {
             on_node_added,
             on_node_removed,
@@ -24078,6 +26399,9 @@ impl AzNodeGraphCallbacks {
             on_node_input_disconnected,
             on_node_output_disconnected,
             on_node_field_edited,
+            on_node_graph_zoomed,
+            on_selection_changed,
+            on_history_changed,
         }
     }
 
@@ -24429,6 +26753,132 @@ impl PyObjectProtocol for AzNodeGraphOnNodeFieldEdited {
     }
 }
 
+#[pymethods]
+impl AzNodeGraphOnNodeGraphZoomedCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNodeGraphOnNodeGraphZoomedCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::OnNodeGraphZoomedCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::OnNodeGraphZoomedCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzNodeGraphOnNodeGraphZoomed {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzNodeGraphOnNodeGraphZoomedCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNodeGraphOnNodeGraphZoomed {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::OnNodeGraphZoomed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::OnNodeGraphZoomed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzNodeGraphOnSelectionChangedCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNodeGraphOnSelectionChangedCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::OnNodeGraphSelectionChangedCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::OnNodeGraphSelectionChangedCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzNodeGraphOnSelectionChanged {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzNodeGraphOnSelectionChangedCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNodeGraphOnSelectionChanged {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::OnNodeGraphSelectionChanged = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::OnNodeGraphSelectionChanged = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzNodeGraphOnHistoryChangedCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNodeGraphOnHistoryChangedCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::OnNodeGraphHistoryChangedCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::OnNodeGraphHistoryChangedCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzNodeGraphOnHistoryChanged {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzNodeGraphOnHistoryChangedCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNodeGraphOnHistoryChanged {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::OnNodeGraphHistoryChanged = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::OnNodeGraphHistoryChanged = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzInputOutputTypeId {
     #[new]
@@ -24777,6 +27227,205 @@ impl PyObjectProtocol for AzNodeDragAmount {
     }
 }
 
+#[pymethods]
+impl AzNodeGraphErrorEnumWrapper {
+    #[classattr]
+    fn NodeMimeTypeMismatch() -> AzNodeGraphErrorEnumWrapper { AzNodeGraphErrorEnumWrapper { inner: AzNodeGraphError::NodeMimeTypeMismatch } }
+    #[classattr]
+    fn NodeInvalidIndex() -> AzNodeGraphErrorEnumWrapper { AzNodeGraphErrorEnumWrapper { inner: AzNodeGraphError::NodeInvalidIndex } }
+    #[classattr]
+    fn NodeInvalidNode() -> AzNodeGraphErrorEnumWrapper { AzNodeGraphErrorEnumWrapper { inner: AzNodeGraphError::NodeInvalidNode } }
+    #[classattr]
+    fn NoRootNode() -> AzNodeGraphErrorEnumWrapper { AzNodeGraphErrorEnumWrapper { inner: AzNodeGraphError::NoRootNode } }
+    #[classattr]
+    fn InvalidJson() -> AzNodeGraphErrorEnumWrapper { AzNodeGraphErrorEnumWrapper { inner: AzNodeGraphError::InvalidJson } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNodeGraphErrorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphError = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphError = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzNodeGraphErrorEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzNodeGraphConnection {
+    #[new]
+    fn __new__(output: AzNodeGraphNodeId, output_index: usize, input: AzNodeGraphNodeId, input_index: usize) -> Self {
+        Self {
+            output,
+            output_index,
+            input,
+            input_index,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNodeGraphConnection {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphConnection = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphConnection = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzNodeGraphNodeMove {
+    #[new]
+    fn __new__(node_id: AzNodeGraphNodeId, amount: AzNodeDragAmount) -> Self {
+        Self {
+            node_id,
+            amount,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNodeGraphNodeMove {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphNodeMove = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphNodeMove = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzNodeGraphFieldEdit {
+    #[new]
+    fn __new__(node_id: AzNodeGraphNodeId, node_type: AzNodeTypeId, field_idx: usize, old_value: AzNodeTypeFieldValueEnumWrapper, new_value: AzNodeTypeFieldValueEnumWrapper) -> Self {
+        Self {
+            node_id,
+            node_type,
+            field_idx,
+            old_value,
+            new_value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNodeGraphFieldEdit {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphFieldEdit = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphFieldEdit = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzNodeGraphEditOpEnumWrapper {
+    #[staticmethod]
+    fn AddNode(v: AzNodeIdNodeMap) -> AzNodeGraphEditOpEnumWrapper { AzNodeGraphEditOpEnumWrapper { inner: AzNodeGraphEditOp::AddNode(v) } }
+    #[staticmethod]
+    fn RemoveNode(v: AzNodeIdNodeMap) -> AzNodeGraphEditOpEnumWrapper { AzNodeGraphEditOpEnumWrapper { inner: AzNodeGraphEditOp::RemoveNode(v) } }
+    #[staticmethod]
+    fn MoveNode(v: AzNodeGraphNodeMove) -> AzNodeGraphEditOpEnumWrapper { AzNodeGraphEditOpEnumWrapper { inner: AzNodeGraphEditOp::MoveNode(v) } }
+    #[staticmethod]
+    fn Connect(v: AzNodeGraphConnection) -> AzNodeGraphEditOpEnumWrapper { AzNodeGraphEditOpEnumWrapper { inner: AzNodeGraphEditOp::Connect(v) } }
+    #[staticmethod]
+    fn Disconnect(v: AzNodeGraphConnection) -> AzNodeGraphEditOpEnumWrapper { AzNodeGraphEditOpEnumWrapper { inner: AzNodeGraphEditOp::Disconnect(v) } }
+    #[staticmethod]
+    fn EditField(v: AzNodeGraphFieldEdit) -> AzNodeGraphEditOpEnumWrapper { AzNodeGraphEditOpEnumWrapper { inner: AzNodeGraphEditOp::EditField(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzNodeGraphEditOp;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzNodeGraphEditOp::AddNode(v) => Ok(vec!["AddNode".into_py(py), v.clone().into_py(py)]),
+            AzNodeGraphEditOp::RemoveNode(v) => Ok(vec!["RemoveNode".into_py(py), v.clone().into_py(py)]),
+            AzNodeGraphEditOp::MoveNode(v) => Ok(vec!["MoveNode".into_py(py), v.clone().into_py(py)]),
+            AzNodeGraphEditOp::Connect(v) => Ok(vec!["Connect".into_py(py), v.clone().into_py(py)]),
+            AzNodeGraphEditOp::Disconnect(v) => Ok(vec!["Disconnect".into_py(py), v.clone().into_py(py)]),
+            AzNodeGraphEditOp::EditField(v) => Ok(vec!["EditField".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNodeGraphEditOpEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphEditOp = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphEditOp = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzNodeGraphEdit {
+    #[new]
+    fn __new__(ops: AzNodeGraphEditOpVec) -> Self {
+        Self {
+            ops,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNodeGraphEdit {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphEdit = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphEdit = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzNodeGraphHistory {
+    #[new]
+    fn __new__(undo: AzNodeGraphEditVec, redo: AzNodeGraphEditVec) -> Self {
+        Self {
+            undo,
+            redo,
+        }
+    }
+
+    fn can_undo(&self) -> bool {
+        unsafe { mem::transmute(crate::AzNodeGraphHistory_canUndo(
+            mem::transmute(self),
+        )) }
+    }
+    fn can_redo(&self) -> bool {
+        unsafe { mem::transmute(crate::AzNodeGraphHistory_canRedo(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNodeGraphHistory {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphHistory = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphHistory = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzListView {
     #[new]
@@ -24785,12 +27434,154 @@ impl AzListView {
             mem::transmute(columns),
         )) }
     }
+    fn set_columns(&mut self, columns: AzStringVec) -> () {
+        unsafe { mem::transmute(crate::AzListView_setColumns(
+            mem::transmute(self),
+            mem::transmute(columns),
+        )) }
+    }
+    fn with_columns(&mut self, columns: AzStringVec) -> AzListView {
+        unsafe { mem::transmute(crate::AzListView_withColumns(
+            mem::transmute(self),
+            mem::transmute(columns),
+        )) }
+    }
+    fn set_rows(&mut self, rows: AzListViewRowVec) -> () {
+        unsafe { mem::transmute(crate::AzListView_setRows(
+            mem::transmute(self),
+            mem::transmute(rows),
+        )) }
+    }
     fn with_rows(&mut self, rows: AzListViewRowVec) -> AzListView {
         unsafe { mem::transmute(crate::AzListView_withRows(
             mem::transmute(self),
             mem::transmute(rows),
         )) }
     }
+    fn set_sorted_by(&mut self, sorted_by: AzOptionUsizeEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzListView_setSortedBy(
+            mem::transmute(self),
+            mem::transmute(sorted_by),
+        )) }
+    }
+    fn with_sorted_by(&mut self, sorted_by: AzOptionUsizeEnumWrapper) -> AzListView {
+        unsafe { mem::transmute(crate::AzListView_withSortedBy(
+            mem::transmute(self),
+            mem::transmute(sorted_by),
+        )) }
+    }
+    fn set_sort_descending(&mut self, sort_descending: bool) -> () {
+        unsafe { mem::transmute(crate::AzListView_setSortDescending(
+            mem::transmute(self),
+            mem::transmute(sort_descending),
+        )) }
+    }
+    fn with_sort_descending(&mut self, sort_descending: bool) -> AzListView {
+        unsafe { mem::transmute(crate::AzListView_withSortDescending(
+            mem::transmute(self),
+            mem::transmute(sort_descending),
+        )) }
+    }
+    fn set_scroll_offset(&mut self, scroll_offset: AzPixelValueNoPercent) -> () {
+        unsafe { mem::transmute(crate::AzListView_setScrollOffset(
+            mem::transmute(self),
+            mem::transmute(scroll_offset),
+        )) }
+    }
+    fn with_scroll_offset(&mut self, scroll_offset: AzPixelValueNoPercent) -> AzListView {
+        unsafe { mem::transmute(crate::AzListView_withScrollOffset(
+            mem::transmute(self),
+            mem::transmute(scroll_offset),
+        )) }
+    }
+    fn set_content_height(&mut self, content_height: AzPixelValueNoPercent) -> () {
+        unsafe { mem::transmute(crate::AzListView_setContentHeight(
+            mem::transmute(self),
+            mem::transmute(content_height),
+        )) }
+    }
+    fn with_content_height(&mut self, content_height: AzPixelValueNoPercent) -> AzListView {
+        unsafe { mem::transmute(crate::AzListView_withContentHeight(
+            mem::transmute(self),
+            mem::transmute(content_height),
+        )) }
+    }
+    fn set_column_context_menu(&mut self, context_menu: AzMenu) -> () {
+        unsafe { mem::transmute(crate::AzListView_setColumnContextMenu(
+            mem::transmute(self),
+            mem::transmute(context_menu),
+        )) }
+    }
+    fn with_column_context_menu(&mut self, context_menu: AzMenu) -> AzListView {
+        unsafe { mem::transmute(crate::AzListView_withColumnContextMenu(
+            mem::transmute(self),
+            mem::transmute(context_menu),
+        )) }
+    }
+    fn set_row_height(&mut self, row_height: f32) -> () {
+        unsafe { mem::transmute(crate::AzListView_setRowHeight(
+            mem::transmute(self),
+            mem::transmute(row_height),
+        )) }
+    }
+    fn with_row_height(&mut self, row_height: f32) -> AzListView {
+        unsafe { mem::transmute(crate::AzListView_withRowHeight(
+            mem::transmute(self),
+            mem::transmute(row_height),
+        )) }
+    }
+    fn set_first_visible_row(&mut self, first_visible_row: usize) -> () {
+        unsafe { mem::transmute(crate::AzListView_setFirstVisibleRow(
+            mem::transmute(self),
+            mem::transmute(first_visible_row),
+        )) }
+    }
+    fn with_first_visible_row(&mut self, first_visible_row: usize) -> AzListView {
+        unsafe { mem::transmute(crate::AzListView_withFirstVisibleRow(
+            mem::transmute(self),
+            mem::transmute(first_visible_row),
+        )) }
+    }
+    fn set_column_widths(&mut self, column_widths: Vec<f32>) -> () {
+        let column_widths = pyvecf32_to_vecf32(&column_widths);
+        unsafe { mem::transmute(crate::AzListView_setColumnWidths(
+            mem::transmute(self),
+            mem::transmute(column_widths),
+        )) }
+    }
+    fn with_column_widths(&mut self, column_widths: Vec<f32>) -> AzListView {
+        let column_widths = pyvecf32_to_vecf32(&column_widths);
+        unsafe { mem::transmute(crate::AzListView_withColumnWidths(
+            mem::transmute(self),
+            mem::transmute(column_widths),
+        )) }
+    }
+    fn set_selection_state(&mut self, focused_row: AzOptionUsizeEnumWrapper, selection_anchor: AzOptionUsizeEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzListView_setSelectionState(
+            mem::transmute(self),
+            mem::transmute(focused_row),
+            mem::transmute(selection_anchor),
+        )) }
+    }
+    fn with_selection_state(&mut self, focused_row: AzOptionUsizeEnumWrapper, selection_anchor: AzOptionUsizeEnumWrapper) -> AzListView {
+        unsafe { mem::transmute(crate::AzListView_withSelectionState(
+            mem::transmute(self),
+            mem::transmute(focused_row),
+            mem::transmute(selection_anchor),
+        )) }
+    }
+    fn get_total_row_count(&mut self) -> usize {
+        unsafe { mem::transmute(crate::AzListView_getTotalRowCount(
+            mem::transmute(self),
+        )) }
+    }
+    fn apply_selection(&mut self, row_id: usize, change: AzListViewSelectionChangeEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzListView_applySelection(
+            mem::transmute(self),
+            mem::transmute(row_id),
+            mem::transmute(change),
+        )) }
+    }
     fn dom(&mut self) -> AzDom {
         unsafe { mem::transmute(crate::AzListView_dom(
             mem::transmute(self),
@@ -24811,10 +27602,11 @@ impl PyObjectProtocol for AzListView {
 #[pymethods]
 impl AzListViewRow {
     #[new]
-    fn __new__(cells: AzDomVec, height: AzOptionPixelValueNoPercentEnumWrapper) -> Self {
+    fn __new__(cells: AzDomVec, height: AzOptionPixelValueNoPercentEnumWrapper, is_selected: bool) -> Self {
         Self {
             cells,
             height,
+            is_selected,
         }
     }
 
@@ -24833,7 +27625,7 @@ impl PyObjectProtocol for AzListViewRow {
 #[pymethods]
 impl AzListViewState {
     #[new]
-    fn __new__(columns: AzStringVec, sorted_by: AzOptionUsizeEnumWrapper, current_row_count: usize, scroll_offset: AzPixelValueNoPercent, current_scroll_position: AzLogicalPosition, current_content_height: AzLogicalSize) -> Self {
+    fn __new__(columns: AzStringVec, sorted_by: AzOptionUsizeEnumWrapper, current_row_count: usize, scroll_offset: AzPixelValueNoPercent, current_scroll_position: AzLogicalPosition, current_content_height: AzLogicalSize, row_count: usize, first_visible_row: usize, focused_row: AzOptionUsizeEnumWrapper, selection_anchor: AzOptionUsizeEnumWrapper) -> Self {
         Self {
             columns,
             sorted_by,
@@ -24841,6 +27633,10 @@ impl AzListViewState {
             scroll_offset,
             current_scroll_position,
             current_content_height,
+            row_count,
+            first_visible_row,
+            focused_row,
+            selection_anchor,
         }
     }
 
@@ -24856,6 +27652,36 @@ impl PyObjectProtocol for AzListViewState {
     }
 }
 
+#[pymethods]
+impl AzListViewSelectionChangeEnumWrapper {
+    #[classattr]
+    fn Replace() -> AzListViewSelectionChangeEnumWrapper { AzListViewSelectionChangeEnumWrapper { inner: AzListViewSelectionChange::Replace } }
+    #[classattr]
+    fn Toggle() -> AzListViewSelectionChangeEnumWrapper { AzListViewSelectionChangeEnumWrapper { inner: AzListViewSelectionChange::Toggle } }
+    #[classattr]
+    fn Extend() -> AzListViewSelectionChangeEnumWrapper { AzListViewSelectionChangeEnumWrapper { inner: AzListViewSelectionChange::Extend } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzListViewSelectionChangeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewSelectionChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewSelectionChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzListViewSelectionChangeEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzListViewOnLazyLoadScrollCallback {
     #[new]
@@ -24867,19 +27693,1323 @@ impl AzListViewOnLazyLoadScrollCallback {
 }
 
 #[pyproto]
-impl PyObjectProtocol for AzListViewOnLazyLoadScrollCallback {
+impl PyObjectProtocol for AzListViewOnLazyLoadScrollCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewOnLazyLoadScrollCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewOnLazyLoadScrollCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzListViewOnLazyLoadScroll {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzListViewOnLazyLoadScrollCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzListViewOnLazyLoadScroll {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewOnLazyLoadScroll = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewOnLazyLoadScroll = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzListViewOnColumnClickCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzListViewOnColumnClickCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewOnColumnClickCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewOnColumnClickCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzListViewOnColumnClick {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzListViewOnColumnClickCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzListViewOnColumnClick {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewOnColumnClick = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewOnColumnClick = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzListViewOnRowClickCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzListViewOnRowClickCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewOnRowClickCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewOnRowClickCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzListViewOnRowClick {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzListViewOnRowClickCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzListViewOnRowClick {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewOnRowClick = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewOnRowClick = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzListViewOnRowSelectCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzListViewOnRowSelectCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewOnRowSelectCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewOnRowSelectCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzListViewOnRowSelect {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzListViewOnRowSelectCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzListViewOnRowSelect {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewOnRowSelect = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewOnRowSelect = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzListViewOnColumnResizeCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzListViewOnColumnResizeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewOnColumnResizeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewOnColumnResizeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzListViewOnColumnResize {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzListViewOnColumnResizeCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzListViewOnColumnResize {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewOnColumnResize = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewOnColumnResize = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzListViewRowProviderCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzListViewRowProviderCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewRowProviderCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewRowProviderCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzListViewRowProvider {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzListViewRowProviderCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzListViewRowProvider {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewRowProvider = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewRowProvider = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeView {
+    #[new]
+    fn new(items: AzTreeViewItemVec) -> AzTreeView {
+        unsafe { mem::transmute(crate::AzTreeView_new(
+            mem::transmute(items),
+        )) }
+    }
+    fn set_items(&mut self, items: AzTreeViewItemVec) -> () {
+        unsafe { mem::transmute(crate::AzTreeView_setItems(
+            mem::transmute(self),
+            mem::transmute(items),
+        )) }
+    }
+    fn with_items(&mut self, items: AzTreeViewItemVec) -> AzTreeView {
+        unsafe { mem::transmute(crate::AzTreeView_withItems(
+            mem::transmute(self),
+            mem::transmute(items),
+        )) }
+    }
+    fn set_selection_mode(&mut self, selection_mode: AzTreeViewSelectionModeEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzTreeView_setSelectionMode(
+            mem::transmute(self),
+            mem::transmute(selection_mode),
+        )) }
+    }
+    fn with_selection_mode(&mut self, selection_mode: AzTreeViewSelectionModeEnumWrapper) -> AzTreeView {
+        unsafe { mem::transmute(crate::AzTreeView_withSelectionMode(
+            mem::transmute(self),
+            mem::transmute(selection_mode),
+        )) }
+    }
+    fn set_first_visible_row(&mut self, first_visible_row: usize) -> () {
+        unsafe { mem::transmute(crate::AzTreeView_setFirstVisibleRow(
+            mem::transmute(self),
+            mem::transmute(first_visible_row),
+        )) }
+    }
+    fn set_max_visible_rows(&mut self, max_visible_rows: usize) -> () {
+        unsafe { mem::transmute(crate::AzTreeView_setMaxVisibleRows(
+            mem::transmute(self),
+            mem::transmute(max_visible_rows),
+        )) }
+    }
+    fn get_visible_row_count(&self) -> usize {
+        unsafe { mem::transmute(crate::AzTreeView_getVisibleRowCount(
+            mem::transmute(self),
+        )) }
+    }
+    fn set_expanded(&mut self, item_id: usize, expanded: bool) -> bool {
+        unsafe { mem::transmute(crate::AzTreeView_setExpanded(
+            mem::transmute(self),
+            mem::transmute(item_id),
+            mem::transmute(expanded),
+        )) }
+    }
+    fn set_children(&mut self, item_id: usize, children: AzTreeViewItemVec) -> bool {
+        unsafe { mem::transmute(crate::AzTreeView_setChildren(
+            mem::transmute(self),
+            mem::transmute(item_id),
+            mem::transmute(children),
+        )) }
+    }
+    fn apply_selection(&mut self, item_id: usize, change: AzTreeViewSelectionChangeEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzTreeView_applySelection(
+            mem::transmute(self),
+            mem::transmute(item_id),
+            mem::transmute(change),
+        )) }
+    }
+    fn dom(&mut self) -> AzDom {
+        unsafe { mem::transmute(crate::AzTreeView_dom(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeView {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeView = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeView = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewItem {
+    #[new]
+    fn new(id: usize, label: String) -> AzTreeViewItem {
+        let label = pystring_to_azstring(&label);
+        unsafe { mem::transmute(crate::AzTreeViewItem_new(
+            mem::transmute(id),
+            mem::transmute(label),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewItem {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewItem = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewItem = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewSelectionModeEnumWrapper {
+    #[classattr]
+    fn Single() -> AzTreeViewSelectionModeEnumWrapper { AzTreeViewSelectionModeEnumWrapper { inner: AzTreeViewSelectionMode::Single } }
+    #[classattr]
+    fn Multiple() -> AzTreeViewSelectionModeEnumWrapper { AzTreeViewSelectionModeEnumWrapper { inner: AzTreeViewSelectionMode::Multiple } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewSelectionModeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewSelectionMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewSelectionMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzTreeViewSelectionModeEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzTreeViewSelectionChangeEnumWrapper {
+    #[classattr]
+    fn Replace() -> AzTreeViewSelectionChangeEnumWrapper { AzTreeViewSelectionChangeEnumWrapper { inner: AzTreeViewSelectionChange::Replace } }
+    #[classattr]
+    fn Toggle() -> AzTreeViewSelectionChangeEnumWrapper { AzTreeViewSelectionChangeEnumWrapper { inner: AzTreeViewSelectionChange::Toggle } }
+    #[classattr]
+    fn Extend() -> AzTreeViewSelectionChangeEnumWrapper { AzTreeViewSelectionChangeEnumWrapper { inner: AzTreeViewSelectionChange::Extend } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewSelectionChangeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewSelectionChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewSelectionChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzTreeViewSelectionChangeEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzTreeViewOnExpandCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewOnExpandCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnExpandCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnExpandCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewOnExpand {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTreeViewOnExpandCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewOnExpand {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnExpand = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnExpand = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewOnSelectCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewOnSelectCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnSelectCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnSelectCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewOnSelect {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTreeViewOnSelectCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewOnSelect {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnSelect = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnSelect = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewOnLazyLoadCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewOnLazyLoadCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnLazyLoadCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnLazyLoadCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewOnLazyLoad {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTreeViewOnLazyLoadCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewOnLazyLoad {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnLazyLoad = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnLazyLoad = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewOnScrollCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewOnScrollCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnScrollCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnScrollCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewOnScroll {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTreeViewOnScrollCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewOnScroll {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnScroll = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnScroll = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDataGrid {
+    #[new]
+    fn new(row_count: usize, column_count: usize) -> AzDataGrid {
+        unsafe { mem::transmute(crate::AzDataGrid_new(
+            mem::transmute(row_count),
+            mem::transmute(column_count),
+        )) }
+    }
+    fn set_column_headers(&mut self, column_headers: AzStringVec) -> () {
+        unsafe { mem::transmute(crate::AzDataGrid_setColumnHeaders(
+            mem::transmute(self),
+            mem::transmute(column_headers),
+        )) }
+    }
+    fn with_column_headers(&mut self, column_headers: AzStringVec) -> AzDataGrid {
+        unsafe { mem::transmute(crate::AzDataGrid_withColumnHeaders(
+            mem::transmute(self),
+            mem::transmute(column_headers),
+        )) }
+    }
+    fn set_column_widths(&mut self, column_widths: Vec<f32>) -> () {
+        let column_widths = pyvecf32_to_vecf32(&column_widths);
+        unsafe { mem::transmute(crate::AzDataGrid_setColumnWidths(
+            mem::transmute(self),
+            mem::transmute(column_widths),
+        )) }
+    }
+    fn with_column_widths(&mut self, column_widths: Vec<f32>) -> AzDataGrid {
+        let column_widths = pyvecf32_to_vecf32(&column_widths);
+        unsafe { mem::transmute(crate::AzDataGrid_withColumnWidths(
+            mem::transmute(self),
+            mem::transmute(column_widths),
+        )) }
+    }
+    fn set_row_heights(&mut self, row_heights: Vec<f32>) -> () {
+        let row_heights = pyvecf32_to_vecf32(&row_heights);
+        unsafe { mem::transmute(crate::AzDataGrid_setRowHeights(
+            mem::transmute(self),
+            mem::transmute(row_heights),
+        )) }
+    }
+    fn with_row_heights(&mut self, row_heights: Vec<f32>) -> AzDataGrid {
+        let row_heights = pyvecf32_to_vecf32(&row_heights);
+        unsafe { mem::transmute(crate::AzDataGrid_withRowHeights(
+            mem::transmute(self),
+            mem::transmute(row_heights),
+        )) }
+    }
+    fn set_scroll_position(&mut self, first_visible_row: usize, first_visible_column: usize) -> () {
+        unsafe { mem::transmute(crate::AzDataGrid_setScrollPosition(
+            mem::transmute(self),
+            mem::transmute(first_visible_row),
+            mem::transmute(first_visible_column),
+        )) }
+    }
+    fn with_scroll_position(&mut self, first_visible_row: usize, first_visible_column: usize) -> AzDataGrid {
+        unsafe { mem::transmute(crate::AzDataGrid_withScrollPosition(
+            mem::transmute(self),
+            mem::transmute(first_visible_row),
+            mem::transmute(first_visible_column),
+        )) }
+    }
+    fn set_selection(&mut self, selection: AzOptionDataGridRangeEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzDataGrid_setSelection(
+            mem::transmute(self),
+            mem::transmute(selection),
+        )) }
+    }
+    fn with_selection(&mut self, selection: AzOptionDataGridRangeEnumWrapper) -> AzDataGrid {
+        unsafe { mem::transmute(crate::AzDataGrid_withSelection(
+            mem::transmute(self),
+            mem::transmute(selection),
+        )) }
+    }
+    fn get_column_width(&self, column: usize) -> f32 {
+        unsafe { mem::transmute(crate::AzDataGrid_getColumnWidth(
+            mem::transmute(self),
+            mem::transmute(column),
+        )) }
+    }
+    fn get_row_height(&self, row: usize) -> f32 {
+        unsafe { mem::transmute(crate::AzDataGrid_getRowHeight(
+            mem::transmute(self),
+            mem::transmute(row),
+        )) }
+    }
+    fn get_column_name(&self, column: usize) -> String {
+        az_string_to_py_string(unsafe { mem::transmute(crate::AzDataGrid_getColumnName(
+            mem::transmute(self),
+            mem::transmute(column),
+        )) })
+    }
+    fn get_cell_text(&mut self, row: usize, column: usize) -> String {
+        az_string_to_py_string(unsafe { mem::transmute(crate::AzDataGrid_getCellText(
+            mem::transmute(self),
+            mem::transmute(row),
+            mem::transmute(column),
+        )) })
+    }
+    fn get_selection_tsv(&mut self) -> String {
+        az_string_to_py_string(unsafe { mem::transmute(crate::AzDataGrid_getSelectionTsv(
+            mem::transmute(self),
+        )) })
+    }
+    fn get_clipped_selection(&self) -> Option<AzDataGridRange> {
+        let m: AzOptionDataGridRange = unsafe { mem::transmute(crate::AzDataGrid_getClippedSelection(
+            mem::transmute(self),
+        )) };
+        match m {
+            AzOptionDataGridRange::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionDataGridRange::None => None,
+        }
+
+    }
+    fn dom(&mut self) -> AzDom {
+        unsafe { mem::transmute(crate::AzDataGrid_dom(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDataGrid {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGrid = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGrid = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDataGridCell {
+    #[new]
+    fn __new__(row: usize, column: usize) -> Self {
+        Self {
+            row,
+            column,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDataGridCell {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridCell = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridCell = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDataGridRange {
+    #[new]
+    fn new(row: usize, column: usize) -> AzDataGridRange {
+        unsafe { mem::transmute(crate::AzDataGridRange_new(
+            mem::transmute(row),
+            mem::transmute(column),
+        )) }
+    }
+    fn get_top_left(&self) -> AzDataGridCell {
+        unsafe { mem::transmute(crate::AzDataGridRange_getTopLeft(
+            mem::transmute(self),
+        )) }
+    }
+    fn get_bottom_right(&self) -> AzDataGridCell {
+        unsafe { mem::transmute(crate::AzDataGridRange_getBottomRight(
+            mem::transmute(self),
+        )) }
+    }
+    fn contains(&self, row: usize, column: usize) -> bool {
+        unsafe { mem::transmute(crate::AzDataGridRange_contains(
+            mem::transmute(self),
+            mem::transmute(row),
+            mem::transmute(column),
+        )) }
+    }
+    fn get_cell_count(&self) -> usize {
+        unsafe { mem::transmute(crate::AzDataGridRange_getCellCount(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDataGridRange {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridRange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridRange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDataGridCellProviderCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDataGridCellProviderCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridCellProviderCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridCellProviderCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDataGridCellProvider {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzDataGridCellProviderCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDataGridCellProvider {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridCellProvider = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridCellProvider = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDataGridCellStyleCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDataGridCellStyleCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridCellStyleCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridCellStyleCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDataGridCellStyle {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzDataGridCellStyleCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDataGridCellStyle {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridCellStyle = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridCellStyle = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDataGridOnSelectCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDataGridOnSelectCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnSelectCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnSelectCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDataGridOnSelect {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzDataGridOnSelectCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDataGridOnSelect {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnSelect = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnSelect = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDataGridOnScrollCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDataGridOnScrollCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnScrollCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnScrollCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDataGridOnScroll {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzDataGridOnScrollCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDataGridOnScroll {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnScroll = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnScroll = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDataGridOnCellEditCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDataGridOnCellEditCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnCellEditCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnCellEditCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDataGridOnCellEdit {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzDataGridOnCellEditCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDataGridOnCellEdit {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnCellEdit = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnCellEdit = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDataGridOnColumnResizeCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDataGridOnColumnResizeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnColumnResizeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnColumnResizeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDataGridOnColumnResize {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzDataGridOnColumnResizeCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDataGridOnColumnResize {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnColumnResize = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnColumnResize = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDataGridOnRowResizeCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDataGridOnRowResizeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnRowResizeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnRowResizeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDataGridOnRowResize {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzDataGridOnRowResizeCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDataGridOnRowResize {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnRowResize = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnRowResize = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDataGridOnCopyRangeCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDataGridOnCopyRangeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnCopyRangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnCopyRangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDataGridOnCopyRange {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzDataGridOnCopyRangeCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDataGridOnCopyRange {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnCopyRange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnCopyRange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDataGridOnClearRangeCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDataGridOnClearRangeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnClearRangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnClearRangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDataGridOnClearRange {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzDataGridOnClearRangeCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDataGridOnClearRange {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnClearRange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::DataGridOnClearRange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDate {
+    #[new]
+    fn new(year: i32, month: u8, day: u8) -> AzDate {
+        unsafe { mem::transmute(crate::AzDate_new(
+            mem::transmute(year),
+            mem::transmute(month),
+            mem::transmute(day),
+        )) }
+    }
+    #[staticmethod]
+    fn today() -> AzDate {
+        unsafe { mem::transmute(crate::AzDate_today()) }
+    }
+    fn is_valid(&self) -> bool {
+        unsafe { mem::transmute(crate::AzDate_isValid(
+            mem::transmute(self),
+        )) }
+    }
+    fn weekday(&self) -> AzWeekdayEnumWrapper {
+        unsafe { mem::transmute(crate::AzDate_weekday(
+            mem::transmute(self),
+        )) }
+    }
+    fn add_days(&self, days: i64) -> AzDate {
+        unsafe { mem::transmute(crate::AzDate_addDays(
+            mem::transmute(self),
+            mem::transmute(days),
+        )) }
+    }
+    fn add_months(&self, months: i64) -> AzDate {
+        unsafe { mem::transmute(crate::AzDate_addMonths(
+            mem::transmute(self),
+            mem::transmute(months),
+        )) }
+    }
+    fn clamp_to(&self, minimum: AzOptionDateEnumWrapper, maximum: AzOptionDateEnumWrapper) -> AzDate {
+        unsafe { mem::transmute(crate::AzDate_clampTo(
+            mem::transmute(self),
+            mem::transmute(minimum),
+            mem::transmute(maximum),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDate {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::date_picker::Date = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::date_picker::Date = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzWeekdayEnumWrapper {
+    #[classattr]
+    fn Monday() -> AzWeekdayEnumWrapper { AzWeekdayEnumWrapper { inner: AzWeekday::Monday } }
+    #[classattr]
+    fn Tuesday() -> AzWeekdayEnumWrapper { AzWeekdayEnumWrapper { inner: AzWeekday::Tuesday } }
+    #[classattr]
+    fn Wednesday() -> AzWeekdayEnumWrapper { AzWeekdayEnumWrapper { inner: AzWeekday::Wednesday } }
+    #[classattr]
+    fn Thursday() -> AzWeekdayEnumWrapper { AzWeekdayEnumWrapper { inner: AzWeekday::Thursday } }
+    #[classattr]
+    fn Friday() -> AzWeekdayEnumWrapper { AzWeekdayEnumWrapper { inner: AzWeekday::Friday } }
+    #[classattr]
+    fn Saturday() -> AzWeekdayEnumWrapper { AzWeekdayEnumWrapper { inner: AzWeekday::Saturday } }
+    #[classattr]
+    fn Sunday() -> AzWeekdayEnumWrapper { AzWeekdayEnumWrapper { inner: AzWeekday::Sunday } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzWeekdayEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::date_picker::Weekday = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::date_picker::Weekday = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzWeekdayEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzTime {
+    #[new]
+    fn new(hour: u8, minute: u8, second: u8) -> AzTime {
+        unsafe { mem::transmute(crate::AzTime_new(
+            mem::transmute(hour),
+            mem::transmute(minute),
+            mem::transmute(second),
+        )) }
+    }
+    fn is_valid(&self) -> bool {
+        unsafe { mem::transmute(crate::AzTime_isValid(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTime {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::date_picker::Time = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::date_picker::Time = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDatePickerState {
+    #[new]
+    fn __new__(selected: AzOptionDateEnumWrapper, cursor: AzDate) -> Self {
+        Self {
+            selected,
+            cursor,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDatePickerState {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::date_picker::DatePickerState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::date_picker::DatePickerState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDatePickerOnDateChangeCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDatePickerOnDateChangeCallback {
     fn __str__(&self) -> Result<String, PyErr> { 
-        let m: &crate::widgets::list_view::ListViewOnLazyLoadScrollCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+        let m: &crate::widgets::date_picker::DatePickerOnDateChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
     }
     fn __repr__(&self) -> Result<String, PyErr> { 
-        let m: &crate::widgets::list_view::ListViewOnLazyLoadScrollCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+        let m: &crate::widgets::date_picker::DatePickerOnDateChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
     }
 }
 
 #[pymethods]
-impl AzListViewOnLazyLoadScroll {
+impl AzDatePickerOnDateChange {
     #[new]
-    fn __new__(data: AzRefAny, callback: AzListViewOnLazyLoadScrollCallback) -> Self {
+    fn __new__(data: AzRefAny, callback: AzDatePickerOnDateChangeCallback) -> Self {
         Self {
             data,
             callback,
@@ -24889,17 +29019,90 @@ impl AzListViewOnLazyLoadScroll {
 }
 
 #[pyproto]
-impl PyObjectProtocol for AzListViewOnLazyLoadScroll {
+impl PyObjectProtocol for AzDatePickerOnDateChange {
     fn __str__(&self) -> Result<String, PyErr> { 
-        let m: &crate::widgets::list_view::ListViewOnLazyLoadScroll = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+        let m: &crate::widgets::date_picker::DatePickerOnDateChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
     }
     fn __repr__(&self) -> Result<String, PyErr> { 
-        let m: &crate::widgets::list_view::ListViewOnLazyLoadScroll = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+        let m: &crate::widgets::date_picker::DatePickerOnDateChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
     }
 }
 
 #[pymethods]
-impl AzListViewOnColumnClickCallback {
+impl AzDatePicker {
+    #[new]
+    fn new(date: AzDate) -> AzDatePicker {
+        unsafe { mem::transmute(crate::AzDatePicker_new(
+            mem::transmute(date),
+        )) }
+    }
+    fn set_state(&mut self, state: AzDatePickerState) -> () {
+        unsafe { mem::transmute(crate::AzDatePicker_setState(
+            mem::transmute(self),
+            mem::transmute(state),
+        )) }
+    }
+    fn with_state(&mut self, state: AzDatePickerState) -> AzDatePicker {
+        unsafe { mem::transmute(crate::AzDatePicker_withState(
+            mem::transmute(self),
+            mem::transmute(state),
+        )) }
+    }
+    fn set_minimum_date(&mut self, minimum_date: AzOptionDateEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzDatePicker_setMinimumDate(
+            mem::transmute(self),
+            mem::transmute(minimum_date),
+        )) }
+    }
+    fn with_minimum_date(&mut self, minimum_date: AzOptionDateEnumWrapper) -> AzDatePicker {
+        unsafe { mem::transmute(crate::AzDatePicker_withMinimumDate(
+            mem::transmute(self),
+            mem::transmute(minimum_date),
+        )) }
+    }
+    fn set_maximum_date(&mut self, maximum_date: AzOptionDateEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzDatePicker_setMaximumDate(
+            mem::transmute(self),
+            mem::transmute(maximum_date),
+        )) }
+    }
+    fn with_maximum_date(&mut self, maximum_date: AzOptionDateEnumWrapper) -> AzDatePicker {
+        unsafe { mem::transmute(crate::AzDatePicker_withMaximumDate(
+            mem::transmute(self),
+            mem::transmute(maximum_date),
+        )) }
+    }
+    fn set_first_day_of_week(&mut self, first_day_of_week: AzWeekdayEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzDatePicker_setFirstDayOfWeek(
+            mem::transmute(self),
+            mem::transmute(first_day_of_week),
+        )) }
+    }
+    fn with_first_day_of_week(&mut self, first_day_of_week: AzWeekdayEnumWrapper) -> AzDatePicker {
+        unsafe { mem::transmute(crate::AzDatePicker_withFirstDayOfWeek(
+            mem::transmute(self),
+            mem::transmute(first_day_of_week),
+        )) }
+    }
+    fn dom(&mut self) -> AzDom {
+        unsafe { mem::transmute(crate::AzDatePicker_dom(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDatePicker {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::date_picker::DatePicker = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::date_picker::DatePicker = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTimePickerOnTimeChangeCallback {
     #[new]
     fn __new__() -> Self {
         Self {
@@ -24909,19 +29112,19 @@ impl AzListViewOnColumnClickCallback {
 }
 
 #[pyproto]
-impl PyObjectProtocol for AzListViewOnColumnClickCallback {
+impl PyObjectProtocol for AzTimePickerOnTimeChangeCallback {
     fn __str__(&self) -> Result<String, PyErr> { 
-        let m: &crate::widgets::list_view::ListViewOnColumnClickCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+        let m: &crate::widgets::date_picker::TimePickerOnTimeChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
     }
     fn __repr__(&self) -> Result<String, PyErr> { 
-        let m: &crate::widgets::list_view::ListViewOnColumnClickCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+        let m: &crate::widgets::date_picker::TimePickerOnTimeChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
     }
 }
 
 #[pymethods]
-impl AzListViewOnColumnClick {
+impl AzTimePickerOnTimeChange {
     #[new]
-    fn __new__(data: AzRefAny, callback: AzListViewOnColumnClickCallback) -> Self {
+    fn __new__(data: AzRefAny, callback: AzTimePickerOnTimeChangeCallback) -> Self {
         Self {
             data,
             callback,
@@ -24931,17 +29134,77 @@ impl AzListViewOnColumnClick {
 }
 
 #[pyproto]
-impl PyObjectProtocol for AzListViewOnColumnClick {
+impl PyObjectProtocol for AzTimePickerOnTimeChange {
     fn __str__(&self) -> Result<String, PyErr> { 
-        let m: &crate::widgets::list_view::ListViewOnColumnClick = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+        let m: &crate::widgets::date_picker::TimePickerOnTimeChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
     }
     fn __repr__(&self) -> Result<String, PyErr> { 
-        let m: &crate::widgets::list_view::ListViewOnColumnClick = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+        let m: &crate::widgets::date_picker::TimePickerOnTimeChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
     }
 }
 
 #[pymethods]
-impl AzListViewOnRowClickCallback {
+impl AzTimePicker {
+    #[new]
+    fn new(time: AzTime) -> AzTimePicker {
+        unsafe { mem::transmute(crate::AzTimePicker_new(
+            mem::transmute(time),
+        )) }
+    }
+    fn set_show_seconds(&mut self, show_seconds: bool) -> () {
+        unsafe { mem::transmute(crate::AzTimePicker_setShowSeconds(
+            mem::transmute(self),
+            mem::transmute(show_seconds),
+        )) }
+    }
+    fn with_show_seconds(&mut self, show_seconds: bool) -> AzTimePicker {
+        unsafe { mem::transmute(crate::AzTimePicker_withShowSeconds(
+            mem::transmute(self),
+            mem::transmute(show_seconds),
+        )) }
+    }
+    fn dom(&mut self) -> AzDom {
+        unsafe { mem::transmute(crate::AzTimePicker_dom(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTimePicker {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::date_picker::TimePicker = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::date_picker::TimePicker = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDateTimePickerState {
+    #[new]
+    fn __new__(date: AzDatePickerState, time: AzTime, is_open: bool) -> Self {
+        Self {
+            date,
+            time,
+            is_open,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDateTimePickerState {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::date_picker::DateTimePickerState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::date_picker::DateTimePickerState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDateTimePickerOnDateTimeChangeCallback {
     #[new]
     fn __new__() -> Self {
         Self {
@@ -24951,19 +29214,19 @@ impl AzListViewOnRowClickCallback {
 }
 
 #[pyproto]
-impl PyObjectProtocol for AzListViewOnRowClickCallback {
+impl PyObjectProtocol for AzDateTimePickerOnDateTimeChangeCallback {
     fn __str__(&self) -> Result<String, PyErr> { 
-        let m: &crate::widgets::list_view::ListViewOnRowClickCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+        let m: &crate::widgets::date_picker::DateTimePickerOnDateTimeChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
     }
     fn __repr__(&self) -> Result<String, PyErr> { 
-        let m: &crate::widgets::list_view::ListViewOnRowClickCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+        let m: &crate::widgets::date_picker::DateTimePickerOnDateTimeChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
     }
 }
 
 #[pymethods]
-impl AzListViewOnRowClick {
+impl AzDateTimePickerOnDateTimeChange {
     #[new]
-    fn __new__(data: AzRefAny, callback: AzListViewOnRowClickCallback) -> Self {
+    fn __new__(data: AzRefAny, callback: AzDateTimePickerOnDateTimeChangeCallback) -> Self {
         Self {
             data,
             callback,
@@ -24973,38 +29236,599 @@ impl AzListViewOnRowClick {
 }
 
 #[pyproto]
-impl PyObjectProtocol for AzListViewOnRowClick {
+impl PyObjectProtocol for AzDateTimePickerOnDateTimeChange {
     fn __str__(&self) -> Result<String, PyErr> { 
-        let m: &crate::widgets::list_view::ListViewOnRowClick = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+        let m: &crate::widgets::date_picker::DateTimePickerOnDateTimeChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
     }
     fn __repr__(&self) -> Result<String, PyErr> { 
-        let m: &crate::widgets::list_view::ListViewOnRowClick = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+        let m: &crate::widgets::date_picker::DateTimePickerOnDateTimeChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
     }
 }
 
 #[pymethods]
-impl AzTreeView {
+impl AzDateTimePicker {
     #[new]
-    fn new(root: String) -> AzTreeView {
-        let root = pystring_to_azstring(&root);
-        unsafe { mem::transmute(crate::AzTreeView_new(
-            mem::transmute(root),
+    fn new(date: AzDate) -> AzDateTimePicker {
+        unsafe { mem::transmute(crate::AzDateTimePicker_new(
+            mem::transmute(date),
+        )) }
+    }
+    fn set_state(&mut self, state: AzDateTimePickerState) -> () {
+        unsafe { mem::transmute(crate::AzDateTimePicker_setState(
+            mem::transmute(self),
+            mem::transmute(state),
+        )) }
+    }
+    fn with_state(&mut self, state: AzDateTimePickerState) -> AzDateTimePicker {
+        unsafe { mem::transmute(crate::AzDateTimePicker_withState(
+            mem::transmute(self),
+            mem::transmute(state),
+        )) }
+    }
+    fn set_minimum_date(&mut self, minimum_date: AzOptionDateEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzDateTimePicker_setMinimumDate(
+            mem::transmute(self),
+            mem::transmute(minimum_date),
+        )) }
+    }
+    fn with_minimum_date(&mut self, minimum_date: AzOptionDateEnumWrapper) -> AzDateTimePicker {
+        unsafe { mem::transmute(crate::AzDateTimePicker_withMinimumDate(
+            mem::transmute(self),
+            mem::transmute(minimum_date),
+        )) }
+    }
+    fn set_maximum_date(&mut self, maximum_date: AzOptionDateEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzDateTimePicker_setMaximumDate(
+            mem::transmute(self),
+            mem::transmute(maximum_date),
+        )) }
+    }
+    fn with_maximum_date(&mut self, maximum_date: AzOptionDateEnumWrapper) -> AzDateTimePicker {
+        unsafe { mem::transmute(crate::AzDateTimePicker_withMaximumDate(
+            mem::transmute(self),
+            mem::transmute(maximum_date),
+        )) }
+    }
+    fn set_first_day_of_week(&mut self, first_day_of_week: AzWeekdayEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzDateTimePicker_setFirstDayOfWeek(
+            mem::transmute(self),
+            mem::transmute(first_day_of_week),
+        )) }
+    }
+    fn with_first_day_of_week(&mut self, first_day_of_week: AzWeekdayEnumWrapper) -> AzDateTimePicker {
+        unsafe { mem::transmute(crate::AzDateTimePicker_withFirstDayOfWeek(
+            mem::transmute(self),
+            mem::transmute(first_day_of_week),
+        )) }
+    }
+    fn set_show_time(&mut self, show_time: bool, show_seconds: bool) -> () {
+        unsafe { mem::transmute(crate::AzDateTimePicker_setShowTime(
+            mem::transmute(self),
+            mem::transmute(show_time),
+            mem::transmute(show_seconds),
+        )) }
+    }
+    fn with_show_time(&mut self, show_time: bool, show_seconds: bool) -> AzDateTimePicker {
+        unsafe { mem::transmute(crate::AzDateTimePicker_withShowTime(
+            mem::transmute(self),
+            mem::transmute(show_time),
+            mem::transmute(show_seconds),
         )) }
     }
     fn dom(&mut self) -> AzDom {
-        unsafe { mem::transmute(crate::AzTreeView_dom(
+        unsafe { mem::transmute(crate::AzDateTimePicker_dom(
             mem::transmute(self),
         )) }
     }
 }
 
 #[pyproto]
-impl PyObjectProtocol for AzTreeView {
+impl PyObjectProtocol for AzDateTimePicker {
     fn __str__(&self) -> Result<String, PyErr> { 
-        let m: &crate::widgets::tree_view::TreeView = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+        let m: &crate::widgets::date_picker::DateTimePicker = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
     }
     fn __repr__(&self) -> Result<String, PyErr> { 
-        let m: &crate::widgets::tree_view::TreeView = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+        let m: &crate::widgets::date_picker::DateTimePicker = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDialogIconEnumWrapper {
+    #[classattr]
+    fn None() -> AzDialogIconEnumWrapper { AzDialogIconEnumWrapper { inner: AzDialogIcon::None } }
+    #[classattr]
+    fn Info() -> AzDialogIconEnumWrapper { AzDialogIconEnumWrapper { inner: AzDialogIcon::Info } }
+    #[classattr]
+    fn Warning() -> AzDialogIconEnumWrapper { AzDialogIconEnumWrapper { inner: AzDialogIcon::Warning } }
+    #[classattr]
+    fn Error() -> AzDialogIconEnumWrapper { AzDialogIconEnumWrapper { inner: AzDialogIcon::Error } }
+    #[classattr]
+    fn Question() -> AzDialogIconEnumWrapper { AzDialogIconEnumWrapper { inner: AzDialogIcon::Question } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDialogIconEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::dialog::DialogIcon = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::dialog::DialogIcon = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzDialogIconEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzDialogButtonsEnumWrapper {
+    #[classattr]
+    fn Ok() -> AzDialogButtonsEnumWrapper { AzDialogButtonsEnumWrapper { inner: AzDialogButtons::Ok } }
+    #[classattr]
+    fn OkCancel() -> AzDialogButtonsEnumWrapper { AzDialogButtonsEnumWrapper { inner: AzDialogButtons::OkCancel } }
+    #[classattr]
+    fn YesNo() -> AzDialogButtonsEnumWrapper { AzDialogButtonsEnumWrapper { inner: AzDialogButtons::YesNo } }
+    #[classattr]
+    fn YesNoCancel() -> AzDialogButtonsEnumWrapper { AzDialogButtonsEnumWrapper { inner: AzDialogButtons::YesNoCancel } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDialogButtonsEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::dialog::DialogButtons = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::dialog::DialogButtons = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzDialogButtonsEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzDialogButtonEnumWrapper {
+    #[classattr]
+    fn Ok() -> AzDialogButtonEnumWrapper { AzDialogButtonEnumWrapper { inner: AzDialogButton::Ok } }
+    #[classattr]
+    fn Cancel() -> AzDialogButtonEnumWrapper { AzDialogButtonEnumWrapper { inner: AzDialogButton::Cancel } }
+    #[classattr]
+    fn Yes() -> AzDialogButtonEnumWrapper { AzDialogButtonEnumWrapper { inner: AzDialogButton::Yes } }
+    #[classattr]
+    fn No() -> AzDialogButtonEnumWrapper { AzDialogButtonEnumWrapper { inner: AzDialogButton::No } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDialogButtonEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::dialog::DialogButton = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::dialog::DialogButton = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzDialogButtonEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzDialogResult {
+    #[new]
+    fn __new__(button: AzDialogButtonEnumWrapper, input: AzString) -> Self {
+        Self {
+            button,
+            input,
+        }
+    }
+
+    fn is_accepted(&self) -> bool {
+        unsafe { mem::transmute(crate::AzDialogResult_isAccepted(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDialogResult {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::dialog::DialogResult = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::dialog::DialogResult = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDialogOnCloseCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDialogOnCloseCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::dialog::DialogOnCloseCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::dialog::DialogOnCloseCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDialogOnClose {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzDialogOnCloseCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDialogOnClose {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::dialog::DialogOnClose = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::dialog::DialogOnClose = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDialog {
+    #[staticmethod]
+    fn msg_box(title: String, message: String) -> AzDialog {
+        let title = pystring_to_azstring(&title);
+        let message = pystring_to_azstring(&message);
+        unsafe { mem::transmute(crate::AzDialog_msgBox(
+            mem::transmute(title),
+            mem::transmute(message),
+        )) }
+    }
+    #[staticmethod]
+    fn confirm(title: String, message: String) -> AzDialog {
+        let title = pystring_to_azstring(&title);
+        let message = pystring_to_azstring(&message);
+        unsafe { mem::transmute(crate::AzDialog_confirm(
+            mem::transmute(title),
+            mem::transmute(message),
+        )) }
+    }
+    #[staticmethod]
+    fn prompt(title: String, message: String, default_value: String) -> AzDialog {
+        let title = pystring_to_azstring(&title);
+        let message = pystring_to_azstring(&message);
+        let default_value = pystring_to_azstring(&default_value);
+        unsafe { mem::transmute(crate::AzDialog_prompt(
+            mem::transmute(title),
+            mem::transmute(message),
+            mem::transmute(default_value),
+        )) }
+    }
+    #[staticmethod]
+    fn custom(title: String, content: AzDom) -> AzDialog {
+        let title = pystring_to_azstring(&title);
+        unsafe { mem::transmute(crate::AzDialog_custom(
+            mem::transmute(title),
+            mem::transmute(content),
+        )) }
+    }
+    fn set_icon(&mut self, icon: AzDialogIconEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzDialog_setIcon(
+            mem::transmute(self),
+            mem::transmute(icon),
+        )) }
+    }
+    fn with_icon(&mut self, icon: AzDialogIconEnumWrapper) -> AzDialog {
+        unsafe { mem::transmute(crate::AzDialog_withIcon(
+            mem::transmute(self),
+            mem::transmute(icon),
+        )) }
+    }
+    fn set_buttons(&mut self, buttons: AzDialogButtonsEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzDialog_setButtons(
+            mem::transmute(self),
+            mem::transmute(buttons),
+        )) }
+    }
+    fn with_buttons(&mut self, buttons: AzDialogButtonsEnumWrapper) -> AzDialog {
+        unsafe { mem::transmute(crate::AzDialog_withButtons(
+            mem::transmute(self),
+            mem::transmute(buttons),
+        )) }
+    }
+    fn dom(&mut self) -> AzDom {
+        unsafe { mem::transmute(crate::AzDialog_dom(
+            mem::transmute(self),
+        )) }
+    }
+    fn dom_over(&mut self, content: AzDom) -> AzDom {
+        unsafe { mem::transmute(crate::AzDialog_domOver(
+            mem::transmute(self),
+            mem::transmute(content),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDialog {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::dialog::Dialog = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::dialog::Dialog = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSplitterDirectionEnumWrapper {
+    #[classattr]
+    fn Horizontal() -> AzSplitterDirectionEnumWrapper { AzSplitterDirectionEnumWrapper { inner: AzSplitterDirection::Horizontal } }
+    #[classattr]
+    fn Vertical() -> AzSplitterDirectionEnumWrapper { AzSplitterDirectionEnumWrapper { inner: AzSplitterDirection::Vertical } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitterDirectionEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterDirection = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterDirection = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzSplitterDirectionEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzSplitterPaneState {
+    #[new]
+    fn __new__(size: AzOptionF32EnumWrapper, collapsed: bool) -> Self {
+        Self {
+            size,
+            collapsed,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitterPaneState {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterPaneState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterPaneState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSplitterState {
+    #[new]
+    fn __new__(panes: AzSplitterPaneStateVec) -> Self {
+        Self {
+            panes,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitterState {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSplitterPane {
+    #[new]
+    fn new(content: AzDom) -> AzSplitterPane {
+        unsafe { mem::transmute(crate::AzSplitterPane_new(
+            mem::transmute(content),
+        )) }
+    }
+    fn set_size(&mut self, size: f32) -> () {
+        unsafe { mem::transmute(crate::AzSplitterPane_setSize(
+            mem::transmute(self),
+            mem::transmute(size),
+        )) }
+    }
+    fn with_size(&mut self, size: f32) -> AzSplitterPane {
+        unsafe { mem::transmute(crate::AzSplitterPane_withSize(
+            mem::transmute(self),
+            mem::transmute(size),
+        )) }
+    }
+    fn set_min_size(&mut self, min_size: f32) -> () {
+        unsafe { mem::transmute(crate::AzSplitterPane_setMinSize(
+            mem::transmute(self),
+            mem::transmute(min_size),
+        )) }
+    }
+    fn with_min_size(&mut self, min_size: f32) -> AzSplitterPane {
+        unsafe { mem::transmute(crate::AzSplitterPane_withMinSize(
+            mem::transmute(self),
+            mem::transmute(min_size),
+        )) }
+    }
+    fn set_max_size(&mut self, max_size: f32) -> () {
+        unsafe { mem::transmute(crate::AzSplitterPane_setMaxSize(
+            mem::transmute(self),
+            mem::transmute(max_size),
+        )) }
+    }
+    fn with_max_size(&mut self, max_size: f32) -> AzSplitterPane {
+        unsafe { mem::transmute(crate::AzSplitterPane_withMaxSize(
+            mem::transmute(self),
+            mem::transmute(max_size),
+        )) }
+    }
+    fn set_collapsed(&mut self, collapsed: bool) -> () {
+        unsafe { mem::transmute(crate::AzSplitterPane_setCollapsed(
+            mem::transmute(self),
+            mem::transmute(collapsed),
+        )) }
+    }
+    fn with_collapsed(&mut self, collapsed: bool) -> AzSplitterPane {
+        unsafe { mem::transmute(crate::AzSplitterPane_withCollapsed(
+            mem::transmute(self),
+            mem::transmute(collapsed),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitterPane {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterPane = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterPane = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSplitterOnResizeCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitterOnResizeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterOnResizeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterOnResizeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSplitterOnResize {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzSplitterOnResizeCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitterOnResize {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterOnResize = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterOnResize = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSplitter {
+    #[new]
+    fn new(direction: AzSplitterDirectionEnumWrapper) -> AzSplitter {
+        unsafe { mem::transmute(crate::AzSplitter_new(
+            mem::transmute(direction),
+        )) }
+    }
+    fn with_pane(&mut self, pane: AzSplitterPane) -> AzSplitter {
+        unsafe { mem::transmute(crate::AzSplitter_withPane(
+            mem::transmute(self),
+            mem::transmute(pane),
+        )) }
+    }
+    fn add_pane(&mut self, pane: AzSplitterPane) -> () {
+        unsafe { mem::transmute(crate::AzSplitter_addPane(
+            mem::transmute(self),
+            mem::transmute(pane),
+        )) }
+    }
+    fn set_handle_size(&mut self, handle_size: f32) -> () {
+        unsafe { mem::transmute(crate::AzSplitter_setHandleSize(
+            mem::transmute(self),
+            mem::transmute(handle_size),
+        )) }
+    }
+    fn with_handle_size(&mut self, handle_size: f32) -> AzSplitter {
+        unsafe { mem::transmute(crate::AzSplitter_withHandleSize(
+            mem::transmute(self),
+            mem::transmute(handle_size),
+        )) }
+    }
+    fn get_state(&self) -> AzSplitterState {
+        unsafe { mem::transmute(crate::AzSplitter_getState(
+            mem::transmute(self),
+        )) }
+    }
+    fn set_state(&mut self, state: AzSplitterState) -> () {
+        unsafe { mem::transmute(crate::AzSplitter_setState(
+            mem::transmute(self),
+            mem::transmute(state),
+        )) }
+    }
+    fn with_state(&mut self, state: AzSplitterState) -> AzSplitter {
+        unsafe { mem::transmute(crate::AzSplitter_withState(
+            mem::transmute(self),
+            mem::transmute(state),
+        )) }
+    }
+    fn dom(&mut self) -> AzDom {
+        unsafe { mem::transmute(crate::AzSplitter_dom(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitter {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::Splitter = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::Splitter = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
     }
 }
 
@@ -31211,6 +36035,22 @@ impl AzFontRef {
             mem::transmute(options),
         )) }
     }
+    fn measure_text(&self, text: &str, options: AzResolvedTextLayoutOptions) -> AzTextMetrics {
+        let text = pystring_to_refstr(&text);
+        unsafe { mem::transmute(crate::AzFontRef_measureText(
+            mem::transmute(self),
+            mem::transmute(text),
+            mem::transmute(options),
+        )) }
+    }
+    fn text_to_path(&self, text: &str, options: AzResolvedTextLayoutOptions) -> AzSvgMultiPolygon {
+        let text = pystring_to_refstr(&text);
+        unsafe { mem::transmute(crate::AzFontRef_textToPath(
+            mem::transmute(self),
+            mem::transmute(text),
+            mem::transmute(options),
+        )) }
+    }
     fn get_hash(&self) -> u64 {
         unsafe { mem::transmute(crate::AzFontRef_getHash(
             mem::transmute(self),
@@ -32676,12 +37516,13 @@ impl PyObjectProtocol for AzXml {
 #[pymethods]
 impl AzXmlNode {
     #[new]
-    fn __new__(tag: AzString, attributes: AzStringPairVec, children: AzXmlNodeVec, text: AzOptionStringEnumWrapper) -> Self {
+    fn __new__(tag: AzString, attributes: AzStringPairVec, children: AzXmlNodeVec, text: AzOptionStringEnumWrapper, span: AzXmlSpan) -> Self {
         Self {
             tag,
             attributes,
             children,
             text,
+            span,
         }
     }
 
@@ -32697,6 +37538,30 @@ impl PyObjectProtocol for AzXmlNode {
     }
 }
 
+#[pymethods]
+impl AzXmlSpan {
+    #[new]
+    fn __new__(file: usize, start: usize, end: usize, pos: AzSvgParseErrorPosition) -> Self {
+        Self {
+            file,
+            start,
+            end,
+            pos,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzXmlSpan {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::xml::XmlSpan = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::xml::XmlSpan = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzFile {
     #[staticmethod]
@@ -33782,6 +38647,81 @@ impl PyObjectProtocol for AzListViewRowVec {
     }
 }
 
+#[pymethods]
+impl AzTreeViewItemVec {
+    /// Creates a new `TreeViewItemVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzTreeViewItem>) -> Self {
+        let m: crate::widgets::tree_view::TreeViewItemVec = crate::widgets::tree_view::TreeViewItemVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the TreeViewItem as a Python array
+    fn array(&self) -> Vec<AzTreeViewItem> {
+        let m: &crate::widgets::tree_view::TreeViewItemVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewItemVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewItemVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewItemVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSplitterPaneVec {
+    /// Creates a new `SplitterPaneVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzSplitterPane>) -> Self {
+        let m: crate::widgets::splitter::SplitterPaneVec = crate::widgets::splitter::SplitterPaneVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the SplitterPane as a Python array
+    fn array(&self) -> Vec<AzSplitterPane> {
+        let m: &crate::widgets::splitter::SplitterPaneVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitterPaneVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterPaneVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterPaneVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSplitterPaneStateVec {
+    /// Creates a new `SplitterPaneStateVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzSplitterPaneState>) -> Self {
+        let m: crate::widgets::splitter::SplitterPaneStateVec = crate::widgets::splitter::SplitterPaneStateVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the SplitterPaneState as a Python array
+    fn array(&self) -> Vec<AzSplitterPaneState> {
+        let m: &crate::widgets::splitter::SplitterPaneStateVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitterPaneStateVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterPaneStateVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterPaneStateVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleFilterVec {
     /// Creates a new `StyleFilterEnumWrapperVec` from a Python array
@@ -33882,6 +38822,81 @@ impl PyObjectProtocol for AzInputOutputTypeIdInfoMapVec {
     }
 }
 
+#[pymethods]
+impl AzNodeGraphNodeIdVec {
+    /// Creates a new `NodeGraphNodeIdVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzNodeGraphNodeId>) -> Self {
+        let m: crate::widgets::node_graph::NodeGraphNodeIdVec = crate::widgets::node_graph::NodeGraphNodeIdVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the NodeGraphNodeId as a Python array
+    fn array(&self) -> Vec<AzNodeGraphNodeId> {
+        let m: &crate::widgets::node_graph::NodeGraphNodeIdVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNodeGraphNodeIdVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphNodeIdVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphNodeIdVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzNodeGraphEditOpVec {
+    /// Creates a new `NodeGraphEditOpEnumWrapperVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzNodeGraphEditOpEnumWrapper>) -> Self {
+        let m: crate::widgets::node_graph::NodeGraphEditOpVec = crate::widgets::node_graph::NodeGraphEditOpVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the NodeGraphEditOpEnumWrapper as a Python array
+    fn array(&self) -> Vec<AzNodeGraphEditOpEnumWrapper> {
+        let m: &crate::widgets::node_graph::NodeGraphEditOpVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNodeGraphEditOpVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphEditOpVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphEditOpVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzNodeGraphEditVec {
+    /// Creates a new `NodeGraphEditVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzNodeGraphEdit>) -> Self {
+        let m: crate::widgets::node_graph::NodeGraphEditVec = crate::widgets::node_graph::NodeGraphEditVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the NodeGraphEdit as a Python array
+    fn array(&self) -> Vec<AzNodeGraphEdit> {
+        let m: &crate::widgets::node_graph::NodeGraphEditVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNodeGraphEditVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphEditVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphEditVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzNodeIdNodeMapVec {
     /// Creates a new `NodeIdNodeMapVec` from a Python array
@@ -34082,6 +39097,31 @@ impl PyObjectProtocol for AzAccessibilityStateVec {
     }
 }
 
+#[pymethods]
+impl AzKeyboardShortcutVec {
+    /// Creates a new `KeyboardShortcutVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzKeyboardShortcut>) -> Self {
+        let m: azul_core::window::KeyboardShortcutVec = azul_core::window::KeyboardShortcutVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the KeyboardShortcut as a Python array
+    fn array(&self) -> Vec<AzKeyboardShortcut> {
+        let m: &azul_core::window::KeyboardShortcutVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzKeyboardShortcutVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::KeyboardShortcutVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::KeyboardShortcutVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzMenuItemVec {
     /// Creates a new `MenuItemEnumWrapperVec` from a Python array
@@ -34317,6 +39357,56 @@ impl PyObjectProtocol for AzInlineGlyphVec {
     }
 }
 
+#[pymethods]
+impl AzTextLineMetricsVec {
+    /// Creates a new `TextLineMetricsVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzTextLineMetrics>) -> Self {
+        let m: azul_impl::callbacks::TextLineMetricsVec = azul_impl::callbacks::TextLineMetricsVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the TextLineMetrics as a Python array
+    fn array(&self) -> Vec<AzTextLineMetrics> {
+        let m: &azul_impl::callbacks::TextLineMetricsVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextLineMetricsVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::TextLineMetricsVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::TextLineMetricsVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzInlineTextRunVec {
+    /// Creates a new `InlineTextRunVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzInlineTextRun>) -> Self {
+        let m: azul_impl::callbacks::InlineTextRunVec = azul_impl::callbacks::InlineTextRunVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the InlineTextRun as a Python array
+    fn array(&self) -> Vec<AzInlineTextRun> {
+        let m: &azul_impl::callbacks::InlineTextRunVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzInlineTextRunVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::InlineTextRunVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::InlineTextRunVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzInlineTextHitVec {
     /// Creates a new `InlineTextHitVec` from a Python array
@@ -34992,6 +40082,81 @@ impl PyObjectProtocol for AzStylesheetVec {
     }
 }
 
+#[pymethods]
+impl AzCssFontFaceVec {
+    /// Creates a new `CssFontFaceVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzCssFontFace>) -> Self {
+        let m: azul_impl::css::CssFontFaceVec = azul_impl::css::CssFontFaceVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the CssFontFace as a Python array
+    fn array(&self) -> Vec<AzCssFontFace> {
+        let m: &azul_impl::css::CssFontFaceVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssFontFaceVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssFontFaceVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssFontFaceVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssFontFaceSourceVec {
+    /// Creates a new `CssFontFaceSourceEnumWrapperVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzCssFontFaceSourceEnumWrapper>) -> Self {
+        let m: azul_impl::css::CssFontFaceSourceVec = azul_impl::css::CssFontFaceSourceVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the CssFontFaceSourceEnumWrapper as a Python array
+    fn array(&self) -> Vec<AzCssFontFaceSourceEnumWrapper> {
+        let m: &azul_impl::css::CssFontFaceSourceVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssFontFaceSourceVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssFontFaceSourceVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssFontFaceSourceVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssUnicodeRangeVec {
+    /// Creates a new `CssUnicodeRangeVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzCssUnicodeRange>) -> Self {
+        let m: azul_impl::css::CssUnicodeRangeVec = azul_impl::css::CssUnicodeRangeVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the CssUnicodeRange as a Python array
+    fn array(&self) -> Vec<AzCssUnicodeRange> {
+        let m: &azul_impl::css::CssUnicodeRangeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssUnicodeRangeVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssUnicodeRangeVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssUnicodeRangeVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCssRuleBlockVec {
     /// Creates a new `CssRuleBlockVec` from a Python array
@@ -35491,6 +40656,96 @@ impl PyObjectProtocol for AzListViewRowVecDestructorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzTreeViewItemVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzTreeViewItemVecDestructorEnumWrapper { AzTreeViewItemVecDestructorEnumWrapper { inner: AzTreeViewItemVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzTreeViewItemVecDestructorEnumWrapper { AzTreeViewItemVecDestructorEnumWrapper { inner: AzTreeViewItemVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzTreeViewItemVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzTreeViewItemVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzTreeViewItemVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzTreeViewItemVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewItemVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewItemVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewItemVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSplitterPaneVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzSplitterPaneVecDestructorEnumWrapper { AzSplitterPaneVecDestructorEnumWrapper { inner: AzSplitterPaneVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzSplitterPaneVecDestructorEnumWrapper { AzSplitterPaneVecDestructorEnumWrapper { inner: AzSplitterPaneVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzSplitterPaneVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzSplitterPaneVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzSplitterPaneVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzSplitterPaneVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitterPaneVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterPaneVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterPaneVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSplitterPaneStateVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzSplitterPaneStateVecDestructorEnumWrapper { AzSplitterPaneStateVecDestructorEnumWrapper { inner: AzSplitterPaneStateVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzSplitterPaneStateVecDestructorEnumWrapper { AzSplitterPaneStateVecDestructorEnumWrapper { inner: AzSplitterPaneStateVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzSplitterPaneStateVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzSplitterPaneStateVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzSplitterPaneStateVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzSplitterPaneStateVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitterPaneStateVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterPaneStateVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterPaneStateVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleFilterVecDestructorEnumWrapper {
     #[classattr]
@@ -35611,6 +40866,96 @@ impl PyObjectProtocol for AzInputOutputTypeIdInfoMapVecDestructorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzNodeGraphNodeIdVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzNodeGraphNodeIdVecDestructorEnumWrapper { AzNodeGraphNodeIdVecDestructorEnumWrapper { inner: AzNodeGraphNodeIdVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzNodeGraphNodeIdVecDestructorEnumWrapper { AzNodeGraphNodeIdVecDestructorEnumWrapper { inner: AzNodeGraphNodeIdVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzNodeGraphNodeIdVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzNodeGraphNodeIdVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzNodeGraphNodeIdVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzNodeGraphNodeIdVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNodeGraphNodeIdVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphNodeIdVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphNodeIdVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzNodeGraphEditOpVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzNodeGraphEditOpVecDestructorEnumWrapper { AzNodeGraphEditOpVecDestructorEnumWrapper { inner: AzNodeGraphEditOpVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzNodeGraphEditOpVecDestructorEnumWrapper { AzNodeGraphEditOpVecDestructorEnumWrapper { inner: AzNodeGraphEditOpVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzNodeGraphEditOpVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzNodeGraphEditOpVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzNodeGraphEditOpVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzNodeGraphEditOpVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNodeGraphEditOpVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphEditOpVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphEditOpVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzNodeGraphEditVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzNodeGraphEditVecDestructorEnumWrapper { AzNodeGraphEditVecDestructorEnumWrapper { inner: AzNodeGraphEditVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzNodeGraphEditVecDestructorEnumWrapper { AzNodeGraphEditVecDestructorEnumWrapper { inner: AzNodeGraphEditVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzNodeGraphEditVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzNodeGraphEditVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzNodeGraphEditVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzNodeGraphEditVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNodeGraphEditVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphEditVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::NodeGraphEditVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzNodeIdNodeMapVecDestructorEnumWrapper {
     #[classattr]
@@ -35851,6 +41196,36 @@ impl PyObjectProtocol for AzAccessibilityStateVecDestructorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzKeyboardShortcutVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzKeyboardShortcutVecDestructorEnumWrapper { AzKeyboardShortcutVecDestructorEnumWrapper { inner: AzKeyboardShortcutVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzKeyboardShortcutVecDestructorEnumWrapper { AzKeyboardShortcutVecDestructorEnumWrapper { inner: AzKeyboardShortcutVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzKeyboardShortcutVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzKeyboardShortcutVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzKeyboardShortcutVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzKeyboardShortcutVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzKeyboardShortcutVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::KeyboardShortcutVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::KeyboardShortcutVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzMenuItemVecDestructorEnumWrapper {
     #[classattr]
@@ -36091,6 +41466,66 @@ impl PyObjectProtocol for AzInlineGlyphVecDestructorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzTextLineMetricsVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzTextLineMetricsVecDestructorEnumWrapper { AzTextLineMetricsVecDestructorEnumWrapper { inner: AzTextLineMetricsVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzTextLineMetricsVecDestructorEnumWrapper { AzTextLineMetricsVecDestructorEnumWrapper { inner: AzTextLineMetricsVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzTextLineMetricsVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzTextLineMetricsVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzTextLineMetricsVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzTextLineMetricsVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextLineMetricsVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::TextLineMetricsVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::TextLineMetricsVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzInlineTextRunVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzInlineTextRunVecDestructorEnumWrapper { AzInlineTextRunVecDestructorEnumWrapper { inner: AzInlineTextRunVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzInlineTextRunVecDestructorEnumWrapper { AzInlineTextRunVecDestructorEnumWrapper { inner: AzInlineTextRunVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzInlineTextRunVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzInlineTextRunVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzInlineTextRunVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzInlineTextRunVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzInlineTextRunVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::InlineTextRunVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::InlineTextRunVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzInlineTextHitVecDestructorEnumWrapper {
     #[classattr]
@@ -36901,6 +42336,96 @@ impl PyObjectProtocol for AzStylesheetVecDestructorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzCssFontFaceVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzCssFontFaceVecDestructorEnumWrapper { AzCssFontFaceVecDestructorEnumWrapper { inner: AzCssFontFaceVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzCssFontFaceVecDestructorEnumWrapper { AzCssFontFaceVecDestructorEnumWrapper { inner: AzCssFontFaceVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzCssFontFaceVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzCssFontFaceVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzCssFontFaceVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzCssFontFaceVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssFontFaceVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssFontFaceVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssFontFaceVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssFontFaceSourceVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzCssFontFaceSourceVecDestructorEnumWrapper { AzCssFontFaceSourceVecDestructorEnumWrapper { inner: AzCssFontFaceSourceVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzCssFontFaceSourceVecDestructorEnumWrapper { AzCssFontFaceSourceVecDestructorEnumWrapper { inner: AzCssFontFaceSourceVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzCssFontFaceSourceVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzCssFontFaceSourceVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzCssFontFaceSourceVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzCssFontFaceSourceVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssFontFaceSourceVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssFontFaceSourceVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssFontFaceSourceVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssUnicodeRangeVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzCssUnicodeRangeVecDestructorEnumWrapper { AzCssUnicodeRangeVecDestructorEnumWrapper { inner: AzCssUnicodeRangeVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzCssUnicodeRangeVecDestructorEnumWrapper { AzCssUnicodeRangeVecDestructorEnumWrapper { inner: AzCssUnicodeRangeVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzCssUnicodeRangeVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzCssUnicodeRangeVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzCssUnicodeRangeVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzCssUnicodeRangeVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssUnicodeRangeVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssUnicodeRangeVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssUnicodeRangeVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCssRuleBlockVecDestructorEnumWrapper {
     #[classattr]
@@ -37470,6 +42995,122 @@ impl PyObjectProtocol for AzOptionSvgPointEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionTreeViewOnExpandEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTreeViewOnExpandEnumWrapper { AzOptionTreeViewOnExpandEnumWrapper { inner: AzOptionTreeViewOnExpand::None } }
+    #[staticmethod]
+    fn Some(v: AzTreeViewOnExpand) -> AzOptionTreeViewOnExpandEnumWrapper { AzOptionTreeViewOnExpandEnumWrapper { inner: AzOptionTreeViewOnExpand::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTreeViewOnExpand;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTreeViewOnExpand::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTreeViewOnExpand::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTreeViewOnExpandEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewOnExpand = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewOnExpand = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionTreeViewOnSelectEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTreeViewOnSelectEnumWrapper { AzOptionTreeViewOnSelectEnumWrapper { inner: AzOptionTreeViewOnSelect::None } }
+    #[staticmethod]
+    fn Some(v: AzTreeViewOnSelect) -> AzOptionTreeViewOnSelectEnumWrapper { AzOptionTreeViewOnSelectEnumWrapper { inner: AzOptionTreeViewOnSelect::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTreeViewOnSelect;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTreeViewOnSelect::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTreeViewOnSelect::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTreeViewOnSelectEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewOnSelect = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewOnSelect = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionTreeViewOnLazyLoadEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTreeViewOnLazyLoadEnumWrapper { AzOptionTreeViewOnLazyLoadEnumWrapper { inner: AzOptionTreeViewOnLazyLoad::None } }
+    #[staticmethod]
+    fn Some(v: AzTreeViewOnLazyLoad) -> AzOptionTreeViewOnLazyLoadEnumWrapper { AzOptionTreeViewOnLazyLoadEnumWrapper { inner: AzOptionTreeViewOnLazyLoad::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTreeViewOnLazyLoad;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTreeViewOnLazyLoad::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTreeViewOnLazyLoad::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTreeViewOnLazyLoadEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewOnLazyLoad = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewOnLazyLoad = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionTreeViewOnScrollEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTreeViewOnScrollEnumWrapper { AzOptionTreeViewOnScrollEnumWrapper { inner: AzOptionTreeViewOnScroll::None } }
+    #[staticmethod]
+    fn Some(v: AzTreeViewOnScroll) -> AzOptionTreeViewOnScrollEnumWrapper { AzOptionTreeViewOnScrollEnumWrapper { inner: AzOptionTreeViewOnScroll::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTreeViewOnScroll;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTreeViewOnScroll::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTreeViewOnScroll::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTreeViewOnScrollEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewOnScroll = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewOnScroll = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionListViewOnRowClickEnumWrapper {
     #[classattr]
@@ -37499,6 +43140,586 @@ impl PyObjectProtocol for AzOptionListViewOnRowClickEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionListViewOnRowSelectEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionListViewOnRowSelectEnumWrapper { AzOptionListViewOnRowSelectEnumWrapper { inner: AzOptionListViewOnRowSelect::None } }
+    #[staticmethod]
+    fn Some(v: AzListViewOnRowSelect) -> AzOptionListViewOnRowSelectEnumWrapper { AzOptionListViewOnRowSelectEnumWrapper { inner: AzOptionListViewOnRowSelect::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionListViewOnRowSelect;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionListViewOnRowSelect::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionListViewOnRowSelect::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionListViewOnRowSelectEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::OptionListViewOnRowSelect = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::OptionListViewOnRowSelect = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionListViewOnColumnResizeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionListViewOnColumnResizeEnumWrapper { AzOptionListViewOnColumnResizeEnumWrapper { inner: AzOptionListViewOnColumnResize::None } }
+    #[staticmethod]
+    fn Some(v: AzListViewOnColumnResize) -> AzOptionListViewOnColumnResizeEnumWrapper { AzOptionListViewOnColumnResizeEnumWrapper { inner: AzOptionListViewOnColumnResize::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionListViewOnColumnResize;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionListViewOnColumnResize::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionListViewOnColumnResize::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionListViewOnColumnResizeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::OptionListViewOnColumnResize = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::OptionListViewOnColumnResize = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionListViewRowProviderEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionListViewRowProviderEnumWrapper { AzOptionListViewRowProviderEnumWrapper { inner: AzOptionListViewRowProvider::None } }
+    #[staticmethod]
+    fn Some(v: AzListViewRowProvider) -> AzOptionListViewRowProviderEnumWrapper { AzOptionListViewRowProviderEnumWrapper { inner: AzOptionListViewRowProvider::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionListViewRowProvider;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionListViewRowProvider::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionListViewRowProvider::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionListViewRowProviderEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::OptionListViewRowProvider = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::OptionListViewRowProvider = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionDataGridRangeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionDataGridRangeEnumWrapper { AzOptionDataGridRangeEnumWrapper { inner: AzOptionDataGridRange::None } }
+    #[staticmethod]
+    fn Some(v: AzDataGridRange) -> AzOptionDataGridRangeEnumWrapper { AzOptionDataGridRangeEnumWrapper { inner: AzOptionDataGridRange::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionDataGridRange;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionDataGridRange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionDataGridRange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionDataGridRangeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionDataGridRange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionDataGridRange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionDataGridCellProviderEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionDataGridCellProviderEnumWrapper { AzOptionDataGridCellProviderEnumWrapper { inner: AzOptionDataGridCellProvider::None } }
+    #[staticmethod]
+    fn Some(v: AzDataGridCellProvider) -> AzOptionDataGridCellProviderEnumWrapper { AzOptionDataGridCellProviderEnumWrapper { inner: AzOptionDataGridCellProvider::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionDataGridCellProvider;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionDataGridCellProvider::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionDataGridCellProvider::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionDataGridCellProviderEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionDataGridCellProvider = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionDataGridCellProvider = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionDataGridCellStyleEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionDataGridCellStyleEnumWrapper { AzOptionDataGridCellStyleEnumWrapper { inner: AzOptionDataGridCellStyle::None } }
+    #[staticmethod]
+    fn Some(v: AzDataGridCellStyle) -> AzOptionDataGridCellStyleEnumWrapper { AzOptionDataGridCellStyleEnumWrapper { inner: AzOptionDataGridCellStyle::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionDataGridCellStyle;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionDataGridCellStyle::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionDataGridCellStyle::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionDataGridCellStyleEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionDataGridCellStyle = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionDataGridCellStyle = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionDataGridOnSelectEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionDataGridOnSelectEnumWrapper { AzOptionDataGridOnSelectEnumWrapper { inner: AzOptionDataGridOnSelect::None } }
+    #[staticmethod]
+    fn Some(v: AzDataGridOnSelect) -> AzOptionDataGridOnSelectEnumWrapper { AzOptionDataGridOnSelectEnumWrapper { inner: AzOptionDataGridOnSelect::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionDataGridOnSelect;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionDataGridOnSelect::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionDataGridOnSelect::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionDataGridOnSelectEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionDataGridOnSelect = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionDataGridOnSelect = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionDataGridOnScrollEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionDataGridOnScrollEnumWrapper { AzOptionDataGridOnScrollEnumWrapper { inner: AzOptionDataGridOnScroll::None } }
+    #[staticmethod]
+    fn Some(v: AzDataGridOnScroll) -> AzOptionDataGridOnScrollEnumWrapper { AzOptionDataGridOnScrollEnumWrapper { inner: AzOptionDataGridOnScroll::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionDataGridOnScroll;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionDataGridOnScroll::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionDataGridOnScroll::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionDataGridOnScrollEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionDataGridOnScroll = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionDataGridOnScroll = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionDataGridOnCellEditEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionDataGridOnCellEditEnumWrapper { AzOptionDataGridOnCellEditEnumWrapper { inner: AzOptionDataGridOnCellEdit::None } }
+    #[staticmethod]
+    fn Some(v: AzDataGridOnCellEdit) -> AzOptionDataGridOnCellEditEnumWrapper { AzOptionDataGridOnCellEditEnumWrapper { inner: AzOptionDataGridOnCellEdit::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionDataGridOnCellEdit;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionDataGridOnCellEdit::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionDataGridOnCellEdit::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionDataGridOnCellEditEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionDataGridOnCellEdit = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionDataGridOnCellEdit = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionDataGridOnColumnResizeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionDataGridOnColumnResizeEnumWrapper { AzOptionDataGridOnColumnResizeEnumWrapper { inner: AzOptionDataGridOnColumnResize::None } }
+    #[staticmethod]
+    fn Some(v: AzDataGridOnColumnResize) -> AzOptionDataGridOnColumnResizeEnumWrapper { AzOptionDataGridOnColumnResizeEnumWrapper { inner: AzOptionDataGridOnColumnResize::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionDataGridOnColumnResize;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionDataGridOnColumnResize::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionDataGridOnColumnResize::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionDataGridOnColumnResizeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionDataGridOnColumnResize = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionDataGridOnColumnResize = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionDataGridOnRowResizeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionDataGridOnRowResizeEnumWrapper { AzOptionDataGridOnRowResizeEnumWrapper { inner: AzOptionDataGridOnRowResize::None } }
+    #[staticmethod]
+    fn Some(v: AzDataGridOnRowResize) -> AzOptionDataGridOnRowResizeEnumWrapper { AzOptionDataGridOnRowResizeEnumWrapper { inner: AzOptionDataGridOnRowResize::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionDataGridOnRowResize;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionDataGridOnRowResize::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionDataGridOnRowResize::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionDataGridOnRowResizeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionDataGridOnRowResize = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionDataGridOnRowResize = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionDataGridOnCopyRangeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionDataGridOnCopyRangeEnumWrapper { AzOptionDataGridOnCopyRangeEnumWrapper { inner: AzOptionDataGridOnCopyRange::None } }
+    #[staticmethod]
+    fn Some(v: AzDataGridOnCopyRange) -> AzOptionDataGridOnCopyRangeEnumWrapper { AzOptionDataGridOnCopyRangeEnumWrapper { inner: AzOptionDataGridOnCopyRange::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionDataGridOnCopyRange;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionDataGridOnCopyRange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionDataGridOnCopyRange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionDataGridOnCopyRangeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionDataGridOnCopyRange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionDataGridOnCopyRange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionDataGridOnClearRangeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionDataGridOnClearRangeEnumWrapper { AzOptionDataGridOnClearRangeEnumWrapper { inner: AzOptionDataGridOnClearRange::None } }
+    #[staticmethod]
+    fn Some(v: AzDataGridOnClearRange) -> AzOptionDataGridOnClearRangeEnumWrapper { AzOptionDataGridOnClearRangeEnumWrapper { inner: AzOptionDataGridOnClearRange::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionDataGridOnClearRange;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionDataGridOnClearRange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionDataGridOnClearRange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionDataGridOnClearRangeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionDataGridOnClearRange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionDataGridOnClearRange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionDateEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionDateEnumWrapper { AzOptionDateEnumWrapper { inner: AzOptionDate::None } }
+    #[staticmethod]
+    fn Some(v: AzDate) -> AzOptionDateEnumWrapper { AzOptionDateEnumWrapper { inner: AzOptionDate::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionDate;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionDate::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionDate::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionDateEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::date_picker::OptionDate = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::date_picker::OptionDate = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionTimeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTimeEnumWrapper { AzOptionTimeEnumWrapper { inner: AzOptionTime::None } }
+    #[staticmethod]
+    fn Some(v: AzTime) -> AzOptionTimeEnumWrapper { AzOptionTimeEnumWrapper { inner: AzOptionTime::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTime;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTime::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTime::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTimeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::date_picker::OptionTime = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::date_picker::OptionTime = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionDatePickerOnDateChangeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionDatePickerOnDateChangeEnumWrapper { AzOptionDatePickerOnDateChangeEnumWrapper { inner: AzOptionDatePickerOnDateChange::None } }
+    #[staticmethod]
+    fn Some(v: AzDatePickerOnDateChange) -> AzOptionDatePickerOnDateChangeEnumWrapper { AzOptionDatePickerOnDateChangeEnumWrapper { inner: AzOptionDatePickerOnDateChange::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionDatePickerOnDateChange;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionDatePickerOnDateChange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionDatePickerOnDateChange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionDatePickerOnDateChangeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::date_picker::OptionDatePickerOnDateChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::date_picker::OptionDatePickerOnDateChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionTimePickerOnTimeChangeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTimePickerOnTimeChangeEnumWrapper { AzOptionTimePickerOnTimeChangeEnumWrapper { inner: AzOptionTimePickerOnTimeChange::None } }
+    #[staticmethod]
+    fn Some(v: AzTimePickerOnTimeChange) -> AzOptionTimePickerOnTimeChangeEnumWrapper { AzOptionTimePickerOnTimeChangeEnumWrapper { inner: AzOptionTimePickerOnTimeChange::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTimePickerOnTimeChange;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTimePickerOnTimeChange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTimePickerOnTimeChange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTimePickerOnTimeChangeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::date_picker::OptionTimePickerOnTimeChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::date_picker::OptionTimePickerOnTimeChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionDateTimePickerOnDateTimeChangeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionDateTimePickerOnDateTimeChangeEnumWrapper { AzOptionDateTimePickerOnDateTimeChangeEnumWrapper { inner: AzOptionDateTimePickerOnDateTimeChange::None } }
+    #[staticmethod]
+    fn Some(v: AzDateTimePickerOnDateTimeChange) -> AzOptionDateTimePickerOnDateTimeChangeEnumWrapper { AzOptionDateTimePickerOnDateTimeChangeEnumWrapper { inner: AzOptionDateTimePickerOnDateTimeChange::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionDateTimePickerOnDateTimeChange;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionDateTimePickerOnDateTimeChange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionDateTimePickerOnDateTimeChange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionDateTimePickerOnDateTimeChangeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::date_picker::OptionDateTimePickerOnDateTimeChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::date_picker::OptionDateTimePickerOnDateTimeChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionDialogOnCloseEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionDialogOnCloseEnumWrapper { AzOptionDialogOnCloseEnumWrapper { inner: AzOptionDialogOnClose::None } }
+    #[staticmethod]
+    fn Some(v: AzDialogOnClose) -> AzOptionDialogOnCloseEnumWrapper { AzOptionDialogOnCloseEnumWrapper { inner: AzOptionDialogOnClose::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionDialogOnClose;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionDialogOnClose::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionDialogOnClose::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionDialogOnCloseEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::dialog::OptionDialogOnClose = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::dialog::OptionDialogOnClose = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionSplitterOnResizeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionSplitterOnResizeEnumWrapper { AzOptionSplitterOnResizeEnumWrapper { inner: AzOptionSplitterOnResize::None } }
+    #[staticmethod]
+    fn Some(v: AzSplitterOnResize) -> AzOptionSplitterOnResizeEnumWrapper { AzOptionSplitterOnResizeEnumWrapper { inner: AzOptionSplitterOnResize::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionSplitterOnResize;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionSplitterOnResize::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionSplitterOnResize::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionSplitterOnResizeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::OptionSplitterOnResize = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::OptionSplitterOnResize = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionListViewOnColumnClickEnumWrapper {
     #[classattr]
@@ -37673,6 +43894,93 @@ impl PyObjectProtocol for AzOptionResolvedTextLayoutOptionsEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionNodeGraphOnNodeGraphZoomedEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionNodeGraphOnNodeGraphZoomedEnumWrapper { AzOptionNodeGraphOnNodeGraphZoomedEnumWrapper { inner: AzOptionNodeGraphOnNodeGraphZoomed::None } }
+    #[staticmethod]
+    fn Some(v: AzNodeGraphOnNodeGraphZoomed) -> AzOptionNodeGraphOnNodeGraphZoomedEnumWrapper { AzOptionNodeGraphOnNodeGraphZoomedEnumWrapper { inner: AzOptionNodeGraphOnNodeGraphZoomed::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionNodeGraphOnNodeGraphZoomed;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionNodeGraphOnNodeGraphZoomed::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionNodeGraphOnNodeGraphZoomed::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionNodeGraphOnNodeGraphZoomedEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::OptionOnNodeGraphZoomed = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::OptionOnNodeGraphZoomed = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionNodeGraphOnSelectionChangedEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionNodeGraphOnSelectionChangedEnumWrapper { AzOptionNodeGraphOnSelectionChangedEnumWrapper { inner: AzOptionNodeGraphOnSelectionChanged::None } }
+    #[staticmethod]
+    fn Some(v: AzNodeGraphOnSelectionChanged) -> AzOptionNodeGraphOnSelectionChangedEnumWrapper { AzOptionNodeGraphOnSelectionChangedEnumWrapper { inner: AzOptionNodeGraphOnSelectionChanged::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionNodeGraphOnSelectionChanged;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionNodeGraphOnSelectionChanged::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionNodeGraphOnSelectionChanged::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionNodeGraphOnSelectionChangedEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::OptionOnNodeGraphSelectionChanged = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::OptionOnNodeGraphSelectionChanged = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionNodeGraphOnHistoryChangedEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionNodeGraphOnHistoryChangedEnumWrapper { AzOptionNodeGraphOnHistoryChangedEnumWrapper { inner: AzOptionNodeGraphOnHistoryChanged::None } }
+    #[staticmethod]
+    fn Some(v: AzNodeGraphOnHistoryChanged) -> AzOptionNodeGraphOnHistoryChangedEnumWrapper { AzOptionNodeGraphOnHistoryChangedEnumWrapper { inner: AzOptionNodeGraphOnHistoryChanged::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionNodeGraphOnHistoryChanged;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionNodeGraphOnHistoryChanged::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionNodeGraphOnHistoryChanged::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionNodeGraphOnHistoryChangedEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::OptionOnNodeGraphHistoryChanged = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::OptionOnNodeGraphHistoryChanged = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionNodeGraphOnNodeAddedEnumWrapper {
     #[classattr]
@@ -39065,6 +45373,35 @@ impl PyObjectProtocol for AzOptionInlineTextEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionTextMetricsEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTextMetricsEnumWrapper { AzOptionTextMetricsEnumWrapper { inner: AzOptionTextMetrics::None } }
+    #[staticmethod]
+    fn Some(v: AzTextMetrics) -> AzOptionTextMetricsEnumWrapper { AzOptionTextMetricsEnumWrapper { inner: AzOptionTextMetrics::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTextMetrics;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTextMetrics::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTextMetrics::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTextMetricsEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::OptionTextMetrics = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::OptionTextMetrics = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionLayoutPointEnumWrapper {
     #[classattr]
@@ -40105,6 +46442,35 @@ impl PyObjectProtocol for AzResultSvgXmlNodeSvgParseErrorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzResultNodeGraphNodeGraphErrorEnumWrapper {
+    #[staticmethod]
+    fn Ok(v: AzNodeGraph) -> AzResultNodeGraphNodeGraphErrorEnumWrapper { AzResultNodeGraphNodeGraphErrorEnumWrapper { inner: AzResultNodeGraphNodeGraphError::Ok(v) } }
+    #[staticmethod]
+    fn Err(v: AzNodeGraphErrorEnumWrapper) -> AzResultNodeGraphNodeGraphErrorEnumWrapper { AzResultNodeGraphNodeGraphErrorEnumWrapper { inner: AzResultNodeGraphNodeGraphError::Err(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzResultNodeGraphNodeGraphError;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzResultNodeGraphNodeGraphError::Ok(v) => Ok(vec!["Ok".into_py(py), v.clone().into_py(py)]),
+            AzResultNodeGraphNodeGraphError::Err(v) => Ok(vec!["Err".into_py(py), { let m: &AzNodeGraphErrorEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzResultNodeGraphNodeGraphErrorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::ResultNodeGraphNodeGraphError = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::node_graph::ResultNodeGraphNodeGraphError = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzResultSvgSvgParseErrorEnumWrapper {
     #[staticmethod]
@@ -40654,6 +47020,13 @@ impl PyObjectProtocol for AzSvgParseErrorPosition {
 }
 
 
+impl core::convert::From<AzNodeGraphError> for PyErr {
+    fn from(err: AzNodeGraphError) -> PyErr {
+        let r: crate::widgets::node_graph::NodeGraphError = unsafe { mem::transmute(err) };
+        PyException::new_err(format!("{}", r))
+    }
+}
+
 impl core::convert::From<AzDecodeImageError> for PyErr {
     fn from(err: AzDecodeImageError) -> PyErr {
         let r: azul_impl::resources::decode::DecodeImageError = unsafe { mem::transmute(err) };
@@ -40773,6 +47146,9 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzPositionInfoInner>()?;
     m.add_class::<AzHidpiAdjustedBounds>()?;
     m.add_class::<AzInlineText>()?;
+    m.add_class::<AzTextMetrics>()?;
+    m.add_class::<AzTextLineMetrics>()?;
+    m.add_class::<AzInlineTextRun>()?;
     m.add_class::<AzInlineLine>()?;
     m.add_class::<AzInlineWordEnumWrapper>()?;
     m.add_class::<AzInlineTextContents>()?;
@@ -40813,6 +47189,8 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzComponentEventFilterEnumWrapper>()?;
     m.add_class::<AzApplicationEventFilterEnumWrapper>()?;
     m.add_class::<AzAccessibilityInfo>()?;
+    m.add_class::<AzTooltip>()?;
+    m.add_class::<AzTooltipContentEnumWrapper>()?;
     m.add_class::<AzAccessibilityRoleEnumWrapper>()?;
     m.add_class::<AzAccessibilityStateEnumWrapper>()?;
     m.add_class::<AzTabIndexEnumWrapper>()?;
@@ -40825,6 +47203,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzMenuItemEnumWrapper>()?;
     m.add_class::<AzStringMenuItem>()?;
     m.add_class::<AzVirtualKeyCodeCombo>()?;
+    m.add_class::<AzKeyboardShortcut>()?;
     m.add_class::<AzMenuCallback>()?;
     m.add_class::<AzMenuItemIconEnumWrapper>()?;
     m.add_class::<AzMenuItemStateEnumWrapper>()?;
@@ -40840,6 +47219,11 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzCssNthChildPattern>()?;
     m.add_class::<AzStylesheet>()?;
     m.add_class::<AzCss>()?;
+    m.add_class::<AzCssFontFace>()?;
+    m.add_class::<AzCssFontFaceSourceEnumWrapper>()?;
+    m.add_class::<AzCssFontFaceUrl>()?;
+    m.add_class::<AzCssFontFaceStyleEnumWrapper>()?;
+    m.add_class::<AzCssUnicodeRange>()?;
     m.add_class::<AzCssPropertyTypeEnumWrapper>()?;
     m.add_class::<AzAnimationInterpolationFunctionEnumWrapper>()?;
     m.add_class::<AzInterpolateContext>()?;
@@ -40883,6 +47267,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzLayoutPositionEnumWrapper>()?;
     m.add_class::<AzLayoutRight>()?;
     m.add_class::<AzLayoutTop>()?;
+    m.add_class::<AzLayoutVerticalAlignEnumWrapper>()?;
     m.add_class::<AzLayoutWidth>()?;
     m.add_class::<AzLayoutFlexWrapEnumWrapper>()?;
     m.add_class::<AzLayoutOverflowEnumWrapper>()?;
@@ -40945,6 +47330,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzStyleTransformScale3D>()?;
     m.add_class::<AzStyleTransformSkew2D>()?;
     m.add_class::<AzStyleTextAlignEnumWrapper>()?;
+    m.add_class::<AzStyleTextDecorationEnumWrapper>()?;
     m.add_class::<AzStyleTextColor>()?;
     m.add_class::<AzStyleWordSpacing>()?;
     m.add_class::<AzStyleBoxShadowValueEnumWrapper>()?;
@@ -40975,6 +47361,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzLayoutPositionValueEnumWrapper>()?;
     m.add_class::<AzLayoutRightValueEnumWrapper>()?;
     m.add_class::<AzLayoutTopValueEnumWrapper>()?;
+    m.add_class::<AzLayoutVerticalAlignValueEnumWrapper>()?;
     m.add_class::<AzLayoutWidthValueEnumWrapper>()?;
     m.add_class::<AzLayoutFlexWrapValueEnumWrapper>()?;
     m.add_class::<AzLayoutOverflowValueEnumWrapper>()?;
@@ -41006,6 +47393,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzStyleLineHeightValueEnumWrapper>()?;
     m.add_class::<AzStyleTabWidthValueEnumWrapper>()?;
     m.add_class::<AzStyleTextAlignValueEnumWrapper>()?;
+    m.add_class::<AzStyleTextDecorationValueEnumWrapper>()?;
     m.add_class::<AzStyleTextColorValueEnumWrapper>()?;
     m.add_class::<AzStyleWordSpacingValueEnumWrapper>()?;
     m.add_class::<AzStyleOpacityValueEnumWrapper>()?;
@@ -41087,6 +47475,12 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzNodeGraphOnNodeOutputDisconnected>()?;
     m.add_class::<AzNodeGraphOnNodeFieldEditedCallback>()?;
     m.add_class::<AzNodeGraphOnNodeFieldEdited>()?;
+    m.add_class::<AzNodeGraphOnNodeGraphZoomedCallback>()?;
+    m.add_class::<AzNodeGraphOnNodeGraphZoomed>()?;
+    m.add_class::<AzNodeGraphOnSelectionChangedCallback>()?;
+    m.add_class::<AzNodeGraphOnSelectionChanged>()?;
+    m.add_class::<AzNodeGraphOnHistoryChangedCallback>()?;
+    m.add_class::<AzNodeGraphOnHistoryChanged>()?;
     m.add_class::<AzInputOutputTypeId>()?;
     m.add_class::<AzNodeTypeId>()?;
     m.add_class::<AzNodeGraphNodeId>()?;
@@ -41102,16 +47496,90 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzNodePosition>()?;
     m.add_class::<AzGraphDragAmount>()?;
     m.add_class::<AzNodeDragAmount>()?;
+    m.add_class::<AzNodeGraphErrorEnumWrapper>()?;
+    m.add_class::<AzNodeGraphConnection>()?;
+    m.add_class::<AzNodeGraphNodeMove>()?;
+    m.add_class::<AzNodeGraphFieldEdit>()?;
+    m.add_class::<AzNodeGraphEditOpEnumWrapper>()?;
+    m.add_class::<AzNodeGraphEdit>()?;
+    m.add_class::<AzNodeGraphHistory>()?;
     m.add_class::<AzListView>()?;
     m.add_class::<AzListViewRow>()?;
     m.add_class::<AzListViewState>()?;
+    m.add_class::<AzListViewSelectionChangeEnumWrapper>()?;
     m.add_class::<AzListViewOnLazyLoadScrollCallback>()?;
     m.add_class::<AzListViewOnLazyLoadScroll>()?;
     m.add_class::<AzListViewOnColumnClickCallback>()?;
     m.add_class::<AzListViewOnColumnClick>()?;
     m.add_class::<AzListViewOnRowClickCallback>()?;
     m.add_class::<AzListViewOnRowClick>()?;
+    m.add_class::<AzListViewOnRowSelectCallback>()?;
+    m.add_class::<AzListViewOnRowSelect>()?;
+    m.add_class::<AzListViewOnColumnResizeCallback>()?;
+    m.add_class::<AzListViewOnColumnResize>()?;
+    m.add_class::<AzListViewRowProviderCallback>()?;
+    m.add_class::<AzListViewRowProvider>()?;
     m.add_class::<AzTreeView>()?;
+    m.add_class::<AzTreeViewItem>()?;
+    m.add_class::<AzTreeViewSelectionModeEnumWrapper>()?;
+    m.add_class::<AzTreeViewSelectionChangeEnumWrapper>()?;
+    m.add_class::<AzTreeViewOnExpandCallback>()?;
+    m.add_class::<AzTreeViewOnExpand>()?;
+    m.add_class::<AzTreeViewOnSelectCallback>()?;
+    m.add_class::<AzTreeViewOnSelect>()?;
+    m.add_class::<AzTreeViewOnLazyLoadCallback>()?;
+    m.add_class::<AzTreeViewOnLazyLoad>()?;
+    m.add_class::<AzTreeViewOnScrollCallback>()?;
+    m.add_class::<AzTreeViewOnScroll>()?;
+    m.add_class::<AzDataGrid>()?;
+    m.add_class::<AzDataGridCell>()?;
+    m.add_class::<AzDataGridRange>()?;
+    m.add_class::<AzDataGridCellProviderCallback>()?;
+    m.add_class::<AzDataGridCellProvider>()?;
+    m.add_class::<AzDataGridCellStyleCallback>()?;
+    m.add_class::<AzDataGridCellStyle>()?;
+    m.add_class::<AzDataGridOnSelectCallback>()?;
+    m.add_class::<AzDataGridOnSelect>()?;
+    m.add_class::<AzDataGridOnScrollCallback>()?;
+    m.add_class::<AzDataGridOnScroll>()?;
+    m.add_class::<AzDataGridOnCellEditCallback>()?;
+    m.add_class::<AzDataGridOnCellEdit>()?;
+    m.add_class::<AzDataGridOnColumnResizeCallback>()?;
+    m.add_class::<AzDataGridOnColumnResize>()?;
+    m.add_class::<AzDataGridOnRowResizeCallback>()?;
+    m.add_class::<AzDataGridOnRowResize>()?;
+    m.add_class::<AzDataGridOnCopyRangeCallback>()?;
+    m.add_class::<AzDataGridOnCopyRange>()?;
+    m.add_class::<AzDataGridOnClearRangeCallback>()?;
+    m.add_class::<AzDataGridOnClearRange>()?;
+    m.add_class::<AzDate>()?;
+    m.add_class::<AzWeekdayEnumWrapper>()?;
+    m.add_class::<AzTime>()?;
+    m.add_class::<AzDatePickerState>()?;
+    m.add_class::<AzDatePickerOnDateChangeCallback>()?;
+    m.add_class::<AzDatePickerOnDateChange>()?;
+    m.add_class::<AzDatePicker>()?;
+    m.add_class::<AzTimePickerOnTimeChangeCallback>()?;
+    m.add_class::<AzTimePickerOnTimeChange>()?;
+    m.add_class::<AzTimePicker>()?;
+    m.add_class::<AzDateTimePickerState>()?;
+    m.add_class::<AzDateTimePickerOnDateTimeChangeCallback>()?;
+    m.add_class::<AzDateTimePickerOnDateTimeChange>()?;
+    m.add_class::<AzDateTimePicker>()?;
+    m.add_class::<AzDialogIconEnumWrapper>()?;
+    m.add_class::<AzDialogButtonsEnumWrapper>()?;
+    m.add_class::<AzDialogButtonEnumWrapper>()?;
+    m.add_class::<AzDialogResult>()?;
+    m.add_class::<AzDialogOnCloseCallback>()?;
+    m.add_class::<AzDialogOnClose>()?;
+    m.add_class::<AzDialog>()?;
+    m.add_class::<AzSplitterDirectionEnumWrapper>()?;
+    m.add_class::<AzSplitterPaneState>()?;
+    m.add_class::<AzSplitterState>()?;
+    m.add_class::<AzSplitterPane>()?;
+    m.add_class::<AzSplitterOnResizeCallback>()?;
+    m.add_class::<AzSplitterOnResize>()?;
+    m.add_class::<AzSplitter>()?;
     m.add_class::<AzDropDown>()?;
     m.add_class::<AzDropDownOnChoiceChangeCallback>()?;
     m.add_class::<AzDropDownOnChoiceChange>()?;
@@ -41214,6 +47682,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
 
     m.add_class::<AzXml>()?;
     m.add_class::<AzXmlNode>()?;
+    m.add_class::<AzXmlSpan>()?;
 
     m.add_class::<AzFile>()?;
 
@@ -41262,10 +47731,16 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzString>()?;
 
     m.add_class::<AzListViewRowVec>()?;
+    m.add_class::<AzTreeViewItemVec>()?;
+    m.add_class::<AzSplitterPaneVec>()?;
+    m.add_class::<AzSplitterPaneStateVec>()?;
     m.add_class::<AzStyleFilterVec>()?;
     m.add_class::<AzLogicalRectVec>()?;
     m.add_class::<AzNodeTypeIdInfoMapVec>()?;
     m.add_class::<AzInputOutputTypeIdInfoMapVec>()?;
+    m.add_class::<AzNodeGraphNodeIdVec>()?;
+    m.add_class::<AzNodeGraphEditOpVec>()?;
+    m.add_class::<AzNodeGraphEditVec>()?;
     m.add_class::<AzNodeIdNodeMapVec>()?;
     m.add_class::<AzInputOutputTypeIdVec>()?;
     m.add_class::<AzNodeTypeFieldVec>()?;
@@ -41274,6 +47749,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzOutputConnectionVec>()?;
     m.add_class::<AzInputNodeAndIndexVec>()?;
     m.add_class::<AzAccessibilityStateVec>()?;
+    m.add_class::<AzKeyboardShortcutVec>()?;
     m.add_class::<AzMenuItemVec>()?;
     m.add_class::<AzTessellatedSvgNodeVec>()?;
     m.add_class::<AzTessellatedColoredSvgNodeVec>()?;
@@ -41283,6 +47759,8 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzInlineLineVec>()?;
     m.add_class::<AzInlineWordVec>()?;
     m.add_class::<AzInlineGlyphVec>()?;
+    m.add_class::<AzTextLineMetricsVec>()?;
+    m.add_class::<AzInlineTextRunVec>()?;
     m.add_class::<AzInlineTextHitVec>()?;
     m.add_class::<AzMonitorVec>()?;
     m.add_class::<AzVideoModeVec>()?;
@@ -41310,6 +47788,9 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzCssDeclarationVec>()?;
     m.add_class::<AzCssPathSelectorVec>()?;
     m.add_class::<AzStylesheetVec>()?;
+    m.add_class::<AzCssFontFaceVec>()?;
+    m.add_class::<AzCssFontFaceSourceVec>()?;
+    m.add_class::<AzCssUnicodeRangeVec>()?;
     m.add_class::<AzCssRuleBlockVec>()?;
     m.add_class::<AzU16Vec>()?;
     m.add_class::<AzF32Vec>()?;
@@ -41330,10 +47811,16 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzNodeDataVec>()?;
     m.add_class::<AzStyleFontFamilyVecDestructorEnumWrapper>()?;
     m.add_class::<AzListViewRowVecDestructorEnumWrapper>()?;
+    m.add_class::<AzTreeViewItemVecDestructorEnumWrapper>()?;
+    m.add_class::<AzSplitterPaneVecDestructorEnumWrapper>()?;
+    m.add_class::<AzSplitterPaneStateVecDestructorEnumWrapper>()?;
     m.add_class::<AzStyleFilterVecDestructorEnumWrapper>()?;
     m.add_class::<AzLogicalRectVecDestructorEnumWrapper>()?;
     m.add_class::<AzNodeTypeIdInfoMapVecDestructorEnumWrapper>()?;
     m.add_class::<AzInputOutputTypeIdInfoMapVecDestructorEnumWrapper>()?;
+    m.add_class::<AzNodeGraphNodeIdVecDestructorEnumWrapper>()?;
+    m.add_class::<AzNodeGraphEditOpVecDestructorEnumWrapper>()?;
+    m.add_class::<AzNodeGraphEditVecDestructorEnumWrapper>()?;
     m.add_class::<AzNodeIdNodeMapVecDestructorEnumWrapper>()?;
     m.add_class::<AzInputOutputTypeIdVecDestructorEnumWrapper>()?;
     m.add_class::<AzNodeTypeFieldVecDestructorEnumWrapper>()?;
@@ -41342,6 +47829,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzOutputConnectionVecDestructorEnumWrapper>()?;
     m.add_class::<AzInputNodeAndIndexVecDestructorEnumWrapper>()?;
     m.add_class::<AzAccessibilityStateVecDestructorEnumWrapper>()?;
+    m.add_class::<AzKeyboardShortcutVecDestructorEnumWrapper>()?;
     m.add_class::<AzMenuItemVecDestructorEnumWrapper>()?;
     m.add_class::<AzTessellatedSvgNodeVecDestructorEnumWrapper>()?;
     m.add_class::<AzTessellatedColoredSvgNodeVecDestructorEnumWrapper>()?;
@@ -41350,6 +47838,8 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzInlineLineVecDestructorEnumWrapper>()?;
     m.add_class::<AzInlineWordVecDestructorEnumWrapper>()?;
     m.add_class::<AzInlineGlyphVecDestructorEnumWrapper>()?;
+    m.add_class::<AzTextLineMetricsVecDestructorEnumWrapper>()?;
+    m.add_class::<AzInlineTextRunVecDestructorEnumWrapper>()?;
     m.add_class::<AzInlineTextHitVecDestructorEnumWrapper>()?;
     m.add_class::<AzMonitorVecDestructorEnumWrapper>()?;
     m.add_class::<AzVideoModeVecDestructorEnumWrapper>()?;
@@ -41377,6 +47867,9 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzCssDeclarationVecDestructorEnumWrapper>()?;
     m.add_class::<AzCssPathSelectorVecDestructorEnumWrapper>()?;
     m.add_class::<AzStylesheetVecDestructorEnumWrapper>()?;
+    m.add_class::<AzCssFontFaceVecDestructorEnumWrapper>()?;
+    m.add_class::<AzCssFontFaceSourceVecDestructorEnumWrapper>()?;
+    m.add_class::<AzCssUnicodeRangeVecDestructorEnumWrapper>()?;
     m.add_class::<AzCssRuleBlockVecDestructorEnumWrapper>()?;
     m.add_class::<AzF32VecDestructorEnumWrapper>()?;
     m.add_class::<AzU16VecDestructorEnumWrapper>()?;
@@ -41397,13 +47890,40 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzNodeDataVecDestructorEnumWrapper>()?;
 
     m.add_class::<AzOptionSvgPointEnumWrapper>()?;
+    m.add_class::<AzOptionTreeViewOnExpandEnumWrapper>()?;
+    m.add_class::<AzOptionTreeViewOnSelectEnumWrapper>()?;
+    m.add_class::<AzOptionTreeViewOnLazyLoadEnumWrapper>()?;
+    m.add_class::<AzOptionTreeViewOnScrollEnumWrapper>()?;
     m.add_class::<AzOptionListViewOnRowClickEnumWrapper>()?;
+    m.add_class::<AzOptionListViewOnRowSelectEnumWrapper>()?;
+    m.add_class::<AzOptionListViewOnColumnResizeEnumWrapper>()?;
+    m.add_class::<AzOptionListViewRowProviderEnumWrapper>()?;
+    m.add_class::<AzOptionDataGridRangeEnumWrapper>()?;
+    m.add_class::<AzOptionDataGridCellProviderEnumWrapper>()?;
+    m.add_class::<AzOptionDataGridCellStyleEnumWrapper>()?;
+    m.add_class::<AzOptionDataGridOnSelectEnumWrapper>()?;
+    m.add_class::<AzOptionDataGridOnScrollEnumWrapper>()?;
+    m.add_class::<AzOptionDataGridOnCellEditEnumWrapper>()?;
+    m.add_class::<AzOptionDataGridOnColumnResizeEnumWrapper>()?;
+    m.add_class::<AzOptionDataGridOnRowResizeEnumWrapper>()?;
+    m.add_class::<AzOptionDataGridOnCopyRangeEnumWrapper>()?;
+    m.add_class::<AzOptionDataGridOnClearRangeEnumWrapper>()?;
+    m.add_class::<AzOptionDateEnumWrapper>()?;
+    m.add_class::<AzOptionTimeEnumWrapper>()?;
+    m.add_class::<AzOptionDatePickerOnDateChangeEnumWrapper>()?;
+    m.add_class::<AzOptionTimePickerOnTimeChangeEnumWrapper>()?;
+    m.add_class::<AzOptionDateTimePickerOnDateTimeChangeEnumWrapper>()?;
+    m.add_class::<AzOptionDialogOnCloseEnumWrapper>()?;
+    m.add_class::<AzOptionSplitterOnResizeEnumWrapper>()?;
     m.add_class::<AzOptionListViewOnColumnClickEnumWrapper>()?;
     m.add_class::<AzOptionListViewOnLazyLoadScrollEnumWrapper>()?;
     m.add_class::<AzOptionMenuEnumWrapper>()?;
     m.add_class::<AzOptionPixelValueNoPercentEnumWrapper>()?;
     m.add_class::<AzOptionDropDownOnChoiceChangeEnumWrapper>()?;
     m.add_class::<AzOptionResolvedTextLayoutOptionsEnumWrapper>()?;
+    m.add_class::<AzOptionNodeGraphOnNodeGraphZoomedEnumWrapper>()?;
+    m.add_class::<AzOptionNodeGraphOnSelectionChangedEnumWrapper>()?;
+    m.add_class::<AzOptionNodeGraphOnHistoryChangedEnumWrapper>()?;
     m.add_class::<AzOptionNodeGraphOnNodeAddedEnumWrapper>()?;
     m.add_class::<AzOptionNodeGraphOnNodeRemovedEnumWrapper>()?;
     m.add_class::<AzOptionNodeGraphOnNodeGraphDraggedEnumWrapper>()?;
@@ -41452,6 +47972,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzOptionLayoutRectEnumWrapper>()?;
     m.add_class::<AzOptionRefAnyEnumWrapper>()?;
     m.add_class::<AzOptionInlineTextEnumWrapper>()?;
+    m.add_class::<AzOptionTextMetricsEnumWrapper>()?;
     m.add_class::<AzOptionLayoutPointEnumWrapper>()?;
     m.add_class::<AzOptionLayoutSizeEnumWrapper>()?;
     m.add_class::<AzOptionWindowThemeEnumWrapper>()?;
@@ -41489,6 +48010,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzResultRawImageDecodeImageErrorEnumWrapper>()?;
     m.add_class::<AzResultU8VecEncodeImageErrorEnumWrapper>()?;
     m.add_class::<AzResultSvgXmlNodeSvgParseErrorEnumWrapper>()?;
+    m.add_class::<AzResultNodeGraphNodeGraphErrorEnumWrapper>()?;
     m.add_class::<AzResultSvgSvgParseErrorEnumWrapper>()?;
     m.add_class::<AzSvgParseErrorEnumWrapper>()?;
     m.add_class::<AzXmlErrorEnumWrapper>()?;
@@ -1,3 +1,10 @@
+use azul_desktop::xml::{
+    XmlComponentTrait, XmlComponentMap, XmlNode, XmlTextContent,
+    ComponentArguments, FilteredComponentArguments,
+    RenderDomError, CompileError,
+};
+use azul_desktop::styled_dom::StyledDom;
+use super::get_xml_string_arg;
 use azul_desktop::{
     css::*,
     css::AzString,
@@ -330,6 +337,64 @@ impl Button {
     }
 }
 
+/// Renders a `<button label="Save" />` XML node
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ButtonRenderer {
+    node: XmlNode,
+}
+
+impl ButtonRenderer {
+    pub fn new() -> Self {
+        Self {
+            node: XmlNode::new("button"),
+        }
+    }
+}
+
+impl Default for ButtonRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XmlComponentTrait for ButtonRenderer {
+    fn get_type_id(&self) -> String {
+        "button".to_string()
+    }
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        ComponentArguments {
+            args: vec![
+                ("label".to_string(), "String".to_string()),
+            ],
+            accepts_text: false,
+        }
+    }
+
+    fn render_dom(
+        &self,
+        _: &XmlComponentMap,
+        args: &FilteredComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<StyledDom, RenderDomError> {
+        let label = get_xml_string_arg(args, "label").unwrap_or_default();
+        Ok(Button::new(label).dom().style(Css::empty()))
+    }
+
+    fn compile_to_rust_code(
+        &self,
+        _: &XmlComponentMap,
+        _: &ComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<String, CompileError> {
+        Ok(String::from("Button::new(label.into()).dom()"))
+    }
+
+    fn get_xml_node(&self) -> XmlNode {
+        self.node.clone()
+    }
+}
+
 #[cfg(test)]
 mod ui_test {
 
@@ -1,3 +1,10 @@
+use azul_desktop::xml::{
+    XmlComponentTrait, XmlComponentMap, XmlNode, XmlTextContent,
+    ComponentArguments, FilteredComponentArguments,
+    RenderDomError, CompileError,
+};
+use azul_desktop::styled_dom::StyledDom;
+use super::{parse_xml_arg, parse_xml_bool};
 use azul_desktop::{
     css::*,
     dom::{
@@ -216,3 +223,61 @@ impl From<CheckBox> for Dom {
         b.dom()
     }
 }
+
+/// Renders a `<check-box checked="true" />` XML node
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CheckBoxRenderer {
+    node: XmlNode,
+}
+
+impl CheckBoxRenderer {
+    pub fn new() -> Self {
+        Self {
+            node: XmlNode::new("check_box"),
+        }
+    }
+}
+
+impl Default for CheckBoxRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XmlComponentTrait for CheckBoxRenderer {
+    fn get_type_id(&self) -> String {
+        "check_box".to_string()
+    }
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        ComponentArguments {
+            args: vec![
+                ("checked".to_string(), "bool".to_string()),
+            ],
+            accepts_text: false,
+        }
+    }
+
+    fn render_dom(
+        &self,
+        _: &XmlComponentMap,
+        args: &FilteredComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<StyledDom, RenderDomError> {
+        let checked = parse_xml_arg(args, "checked", "bool", parse_xml_bool)?.unwrap_or(false);
+        Ok(CheckBox::new(checked).dom().style(Css::empty()))
+    }
+
+    fn compile_to_rust_code(
+        &self,
+        _: &XmlComponentMap,
+        _: &ComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<String, CompileError> {
+        Ok(String::from("CheckBox::new(checked).dom()"))
+    }
+
+    fn get_xml_node(&self) -> XmlNode {
+        self.node.clone()
+    }
+}
@@ -1,5 +1,12 @@
 //! Rectangular input that, when clicked, spawns a color dialog
 
+use azul_desktop::xml::{
+    XmlComponentTrait, XmlComponentMap, XmlNode, XmlTextContent,
+    ComponentArguments, FilteredComponentArguments,
+    RenderDomError, CompileError,
+};
+use azul_desktop::styled_dom::StyledDom;
+use super::parse_xml_arg;
 use azul_desktop::css::*;
 use azul_desktop::dom::{
     Dom, NodeDataInlineCssProperty, NodeDataInlineCssPropertyVec,
@@ -164,4 +171,62 @@ extern "C" fn on_color_input_clicked(data: &mut RefAny, info: &mut CallbackInfo)
     };
 
     result
-}
\ No newline at end of file
+}
+
+/// Renders a `<color-input value="#ff0000" />` XML node
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ColorInputRenderer {
+    node: XmlNode,
+}
+
+impl ColorInputRenderer {
+    pub fn new() -> Self {
+        Self {
+            node: XmlNode::new("color_input"),
+        }
+    }
+}
+
+impl Default for ColorInputRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XmlComponentTrait for ColorInputRenderer {
+    fn get_type_id(&self) -> String {
+        "color_input".to_string()
+    }
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        ComponentArguments {
+            args: vec![
+                ("value".to_string(), "ColorU".to_string()),
+            ],
+            accepts_text: false,
+        }
+    }
+
+    fn render_dom(
+        &self,
+        _: &XmlComponentMap,
+        args: &FilteredComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<StyledDom, RenderDomError> {
+        let value = parse_xml_arg(args, "value", "ColorU", |s| css_parser::parse_css_color(s.trim()).ok())?;
+        Ok(ColorInput::new(value.unwrap_or_default()).dom().style(Css::empty()))
+    }
+
+    fn compile_to_rust_code(
+        &self,
+        _: &XmlComponentMap,
+        _: &ComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<String, CompileError> {
+        Ok(String::from("ColorInput::new(value).dom()"))
+    }
+
+    fn get_xml_node(&self) -> XmlNode {
+        self.node.clone()
+    }
+}
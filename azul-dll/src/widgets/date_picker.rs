@@ -0,0 +1,1731 @@
+use std::vec::Vec;
+use std::string::String;
+use core::fmt;
+use azul_desktop::css::*;
+use azul_desktop::css::AzString;
+use azul_desktop::callbacks::{CallbackInfo, RefAny, Update, Callback};
+use azul_desktop::styled_dom::StyledDom;
+use azul_desktop::xml::{
+    XmlComponentTrait, XmlComponentMap, XmlNode, XmlTextContent,
+    ComponentArguments, FilteredComponentArguments,
+    RenderDomError, CompileError, ComponentError,
+};
+use super::{parse_xml_arg, parse_xml_bool};
+use azul_desktop::dom::{
+    Dom, IdOrClass, TabIndex,
+    IdOrClass::Class,
+    NodeDataInlineCssProperty,
+    IdOrClassVec, NodeDataInlineCssPropertyVec,
+    CallbackData, EventFilter, HoverEventFilter,
+    FocusEventFilter,
+};
+
+/// Number of weeks shown in the month grid (enough for every month,
+/// so that the size of the DatePicker doesn't change between months)
+const WEEKS_PER_PAGE: usize = 6;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June",
+    "July", "August", "September", "October", "November", "December",
+];
+
+const STRING_SANS_SERIF: AzString = AzString::from_const_str("sans-serif");
+const FONT_FAMILY_SANS_SERIF: &[StyleFontFamily] = &[StyleFontFamily::System(STRING_SANS_SERIF)];
+
+const COLOR_WHITE: ColorU = ColorU { r: 255, g: 255, b: 255, a: 255 };
+const COLOR_BORDER: ColorU = ColorU { r: 172, g: 172, b: 172, a: 255 };
+const COLOR_CURSOR: ColorU = ColorU { r: 38, g: 160, b: 218, a: 255 };
+const COLOR_OTHER_MONTH: ColorU = ColorU { r: 155, g: 155, b: 155, a: 255 };
+const COLOR_DISABLED: ColorU = ColorU { r: 210, g: 210, b: 210, a: 255 };
+
+const BACKGROUND_WHITE: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_WHITE)];
+const BACKGROUND_HOVER: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(ColorU { r: 229, g: 243, b: 251, a: 255 })];
+const BACKGROUND_SELECTED: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_CURSOR)];
+const BACKGROUND_BUTTON: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(ColorU { r: 240, g: 240, b: 240, a: 255 })];
+
+// .__azul-native-datepicker, .__azul-native-timepicker, .__azul-native-datetimepicker-popup
+static CONTAINER_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_top(LayoutPaddingTop::const_px(4))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(4))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(4))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_bottom(LayoutPaddingBottom::const_px(4))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_font_size(StyleFontSize::const_px(12))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_font_family(StyleFontFamilyVec::from_const_slice(FONT_FAMILY_SANS_SERIF))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_BORDER })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_BORDER })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_BORDER })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_BORDER })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BACKGROUND_WHITE))),
+];
+
+// .__azul-native-datepicker-header, .__azul-native-datepicker-week
+static ROW_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_align_items(LayoutAlignItems::Center)),
+];
+
+// .__azul-native-datepicker-title
+static TITLE_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_grow(LayoutFlexGrow { inner: FloatValue::const_new(1) })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_text_align(StyleTextAlign::Center)),
+];
+
+// .__azul-native-datepicker-nav, .__azul-native-timepicker-button
+static BUTTON_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_width(LayoutWidth::const_px(20))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_justify_content(LayoutJustifyContent::Center)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_text_align(StyleTextAlign::Center)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_cursor(StyleCursor::Pointer)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BACKGROUND_BUTTON))),
+    NodeDataInlineCssProperty::Hover(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BACKGROUND_HOVER))),
+];
+
+// .__azul-native-datepicker-weekday
+static WEEKDAY_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_width(LayoutWidth::const_px(28))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_height(LayoutHeight::const_px(20))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_text_align(StyleTextAlign::Center)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_OTHER_MONTH })),
+];
+
+// .__azul-native-datepicker-day
+static DAY_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_width(LayoutWidth::const_px(28))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_height(LayoutHeight::const_px(22))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_justify_content(LayoutJustifyContent::Center)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_text_align(StyleTextAlign::Center)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_WHITE })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_WHITE })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_WHITE })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_WHITE })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_cursor(StyleCursor::Pointer)),
+    NodeDataInlineCssProperty::Hover(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BACKGROUND_HOVER))),
+];
+
+// .__azul-native-datepicker-day.other-month
+static DAY_OTHER_MONTH_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_OTHER_MONTH })),
+];
+
+// .__azul-native-datepicker-day.disabled
+static DAY_DISABLED_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_DISABLED })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_cursor(StyleCursor::Default)),
+    NodeDataInlineCssProperty::Hover(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BACKGROUND_WHITE))),
+];
+
+// .__azul-native-datepicker-day.selected
+static DAY_SELECTED_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_WHITE })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BACKGROUND_SELECTED))),
+    NodeDataInlineCssProperty::Hover(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BACKGROUND_SELECTED))),
+];
+
+// .__azul-native-datepicker-day.cursor
+static DAY_CURSOR_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_CURSOR })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_CURSOR })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_CURSOR })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_CURSOR })),
+];
+
+// .__azul-native-timepicker-field
+static TIME_FIELD_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_width(LayoutWidth::const_px(24))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_justify_content(LayoutJustifyContent::Center)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_text_align(StyleTextAlign::Center)),
+    NodeDataInlineCssProperty::Focus(CssProperty::const_text_color(StyleTextColor { inner: COLOR_WHITE })),
+    NodeDataInlineCssProperty::Focus(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BACKGROUND_SELECTED))),
+];
+
+// .__azul-native-timepicker-buttons
+static TIME_BUTTONS_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_font_size(StyleFontSize::const_px(7))),
+];
+
+// .__azul-native-datetimepicker
+static DROPDOWN_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_font_size(StyleFontSize::const_px(12))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_font_family(StyleFontFamilyVec::from_const_slice(FONT_FAMILY_SANS_SERIF))),
+];
+
+// .__azul-native-datetimepicker-header
+static DROPDOWN_HEADER_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_height(LayoutHeight::const_px(22))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_align_items(LayoutAlignItems::Center)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(4))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_cursor(StyleCursor::Pointer)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_BORDER })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_BORDER })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_BORDER })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_BORDER })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BACKGROUND_WHITE))),
+    NodeDataInlineCssProperty::Focus(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_CURSOR })),
+    NodeDataInlineCssProperty::Focus(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_CURSOR })),
+    NodeDataInlineCssProperty::Focus(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_CURSOR })),
+    NodeDataInlineCssProperty::Focus(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_CURSOR })),
+];
+
+// .__azul-native-datetimepicker-popup (in addition to CONTAINER_STYLE)
+static DROPDOWN_POPUP_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_top(LayoutTop::const_px(24))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_left(LayoutLeft::const_px(0))),
+];
+
+const IDS_AND_CLASSES_DATEPICKER: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-datepicker"))];
+const IDS_AND_CLASSES_HEADER: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-datepicker-header"))];
+const IDS_AND_CLASSES_NAV: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-datepicker-nav"))];
+const IDS_AND_CLASSES_TITLE: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-datepicker-title"))];
+const IDS_AND_CLASSES_WEEK: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-datepicker-week"))];
+const IDS_AND_CLASSES_WEEKDAY: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-datepicker-weekday"))];
+const IDS_AND_CLASSES_TIMEPICKER: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-timepicker"))];
+const IDS_AND_CLASSES_TIME_FIELD: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-timepicker-field"))];
+const IDS_AND_CLASSES_TIME_BUTTONS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-timepicker-buttons"))];
+const IDS_AND_CLASSES_TIME_BUTTON: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-timepicker-button"))];
+const IDS_AND_CLASSES_DROPDOWN: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-datetimepicker"))];
+const IDS_AND_CLASSES_DROPDOWN_HEADER: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-datetimepicker-header"))];
+const IDS_AND_CLASSES_DROPDOWN_POPUP: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-datetimepicker-popup"))];
+
+pub type DatePickerOnDateChangeCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &DatePickerState) -> Update;
+impl_callback!(DatePickerOnDateChange, OptionDatePickerOnDateChange, DatePickerOnDateChangeCallback, DatePickerOnDateChangeCallbackType);
+
+pub type TimePickerOnTimeChangeCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &Time) -> Update;
+impl_callback!(TimePickerOnTimeChange, OptionTimePickerOnTimeChange, TimePickerOnTimeChangeCallback, TimePickerOnTimeChangeCallbackType);
+
+pub type DateTimePickerOnDateTimeChangeCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &DateTimePickerState) -> Update;
+impl_callback!(DateTimePickerOnDateTimeChange, OptionDateTimePickerOnDateTimeChange, DateTimePickerOnDateTimeChangeCallback, DateTimePickerOnDateTimeChangeCallbackType);
+
+/// Calendar date (proleptic Gregorian calendar), not validated on construction
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct Date {
+    pub year: i32,
+    /// 1 - 12
+    pub month: u8,
+    /// 1 - 31
+    pub day: u8,
+}
+
+impl_option!(Date, OptionDate, [Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+
+impl Default for Date {
+    fn default() -> Self {
+        Self::new(1970, 1, 1)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl Date {
+
+    pub const fn new(year: i32, month: u8, day: u8) -> Self {
+        Self { year, month, day }
+    }
+
+    /// Returns the current date (UTC)
+    pub fn today() -> Self {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        let seconds = match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(o) => o.as_secs() as i64,
+            Err(e) => -(e.duration().as_secs() as i64),
+        };
+        Self::from_days(seconds.div_euclid(86400))
+    }
+
+    /// Parses `YYYY-MM-DD` (ISO 8601) or `DD.MM.YYYY`, returns `None` for invalid dates
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let (year, month, day) = if s.contains('.') {
+            let mut it = s.split('.');
+            let day = it.next()?;
+            let month = it.next()?;
+            let year = it.next()?;
+            if it.next().is_some() { return None; }
+            (year, month, day)
+        } else {
+            // split at the last two dashes, so that negative years work
+            let mut it = s.rsplitn(3, '-');
+            let day = it.next()?;
+            let month = it.next()?;
+            let year = it.next()?;
+            (year, month, day)
+        };
+
+        let date = Self::new(year.trim().parse().ok()?, month.trim().parse().ok()?, day.trim().parse().ok()?);
+        if date.is_valid() { Some(date) } else { None }
+    }
+
+    pub fn is_leap_year(year: i32) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
+
+    /// Returns the number of days in the given month (1 - 12) or 0 for invalid months
+    pub fn days_in_month(year: i32, month: u8) -> u8 {
+        match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if Self::is_leap_year(year) => 29,
+            2 => 28,
+            _ => 0,
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.day >= 1 && self.day <= Self::days_in_month(self.year, self.month)
+    }
+
+    /// Days since 1970-01-01
+    pub fn to_days(&self) -> i64 {
+        // see http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+        let month = self.month as i64;
+        let year = self.year as i64 - if month <= 2 { 1 } else { 0 };
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146097 + day_of_era - 719468
+    }
+
+    /// Inverse of `to_days`
+    pub fn from_days(days: i64) -> Self {
+        // see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let days = days + 719468;
+        let era = days.div_euclid(146097);
+        let day_of_era = days - era * 146097;
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+        Self::new(year as i32, month as u8, day as u8)
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        // 1970-01-01 was a thursday
+        Weekday::from_index((self.to_days() + 3).rem_euclid(7) as usize)
+    }
+
+    pub fn add_days(&self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    /// Adds (or subtracts) months, the day is clamped to the
+    /// length of the target month (i.e. Jan 31 + 1 month = Feb 28)
+    pub fn add_months(&self, months: i64) -> Self {
+        let month_index = self.year as i64 * 12 + (self.month as i64 - 1) + months;
+        let year = month_index.div_euclid(12) as i32;
+        let month = month_index.rem_euclid(12) as u8 + 1;
+        Self::new(year, month, self.day.min(Self::days_in_month(year, month)))
+    }
+
+    pub fn first_of_month(&self) -> Self {
+        Self::new(self.year, self.month, 1)
+    }
+
+    pub fn last_of_month(&self) -> Self {
+        Self::new(self.year, self.month, Self::days_in_month(self.year, self.month))
+    }
+
+    /// Clamps the date to the (optional) minimum and maximum date
+    pub fn clamp_to(&self, minimum: OptionDate, maximum: OptionDate) -> Self {
+        let mut date = *self;
+        if let Some(max) = maximum.into_option() {
+            date = date.min(max);
+        }
+        if let Some(min) = minimum.into_option() {
+            date = date.max(min);
+        }
+        date
+    }
+}
+
+/// Day of the week
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum Weekday {
+    #[default]
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+
+    const ALL: [Weekday; 7] = [
+        Weekday::Monday, Weekday::Tuesday, Weekday::Wednesday, Weekday::Thursday,
+        Weekday::Friday, Weekday::Saturday, Weekday::Sunday,
+    ];
+
+    /// Monday = 0, Sunday = 6
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    /// Inverse of `index`, wraps around after sunday
+    pub fn from_index(index: usize) -> Self {
+        Self::ALL[index % 7]
+    }
+
+    /// Parses the (case-insensitive) english name of the weekday or its first three letters
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim().to_lowercase();
+        if s.len() < 3 {
+            return None;
+        }
+        Self::ALL.iter().copied().find(|w| {
+            let name = w.get_name().to_lowercase();
+            name == s || (s.len() == 3 && name.starts_with(&s))
+        })
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Weekday::Monday => "Monday",
+            Weekday::Tuesday => "Tuesday",
+            Weekday::Wednesday => "Wednesday",
+            Weekday::Thursday => "Thursday",
+            Weekday::Friday => "Friday",
+            Weekday::Saturday => "Saturday",
+            Weekday::Sunday => "Sunday",
+        }
+    }
+}
+
+/// Time of day, not validated on construction
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct Time {
+    /// 0 - 23
+    pub hour: u8,
+    /// 0 - 59
+    pub minute: u8,
+    /// 0 - 59
+    pub second: u8,
+}
+
+impl_option!(Time, OptionTime, [Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+}
+
+impl Time {
+
+    pub const fn new(hour: u8, minute: u8, second: u8) -> Self {
+        Self { hour, minute, second }
+    }
+
+    /// Parses `HH:MM` or `HH:MM:SS`, returns `None` for invalid times
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut it = s.trim().split(':');
+        let hour = it.next()?.trim().parse().ok()?;
+        let minute = it.next()?.trim().parse().ok()?;
+        let second = match it.next() {
+            Some(s) => s.trim().parse().ok()?,
+            None => 0,
+        };
+        if it.next().is_some() {
+            return None;
+        }
+        let time = Self::new(hour, minute, second);
+        if time.is_valid() { Some(time) } else { None }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.hour < 24 && self.minute < 60 && self.second < 60
+    }
+
+    /// Formats the time as `HH:MM` or `HH:MM:SS`
+    pub fn format(&self, show_seconds: bool) -> String {
+        if show_seconds {
+            format!("{}", self)
+        } else {
+            format!("{:02}:{:02}", self.hour, self.minute)
+        }
+    }
+
+    // Adds delta to a single field, wrapping around without
+    // carrying over (the way spin buttons usually behave)
+    fn add_to_field(&self, field: TimePickerField, delta: i32) -> Self {
+        let mut time = *self;
+        match field {
+            TimePickerField::Hour => time.hour = (time.hour as i32 + delta).rem_euclid(24) as u8,
+            TimePickerField::Minute => time.minute = (time.minute as i32 + delta).rem_euclid(60) as u8,
+            TimePickerField::Second => time.second = (time.second as i32 + delta).rem_euclid(60) as u8,
+        }
+        time
+    }
+}
+
+/// State of a `DatePicker`, reported by `on_date_change`
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct DatePickerState {
+    /// Date selected by clicking it or pressing Enter / Space
+    pub selected: OptionDate,
+    /// Date that has the keyboard cursor, determines which month is shown
+    pub cursor: Date,
+}
+
+/// Month calendar to select a single date
+///
+/// Like the other widgets, the DatePicker does not keep any state between
+/// frames: selecting a date and navigating between months is reported
+/// through `on_date_change` and the application has to create the
+/// DatePicker with the new `DatePickerState` for the next frame.
+///
+/// Keyboard: arrow keys move the cursor by one day / one week,
+/// PageUp / PageDown by one month (one year with Shift), Home / End
+/// jump to the first / last day of the month, Enter / Space select
+/// the date under the cursor. Days outside of the minimum / maximum
+/// date are greyed out and can't be selected.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[repr(C)]
+pub struct DatePicker {
+    pub state: DatePickerState,
+    /// Dates before this date can't be selected
+    pub minimum_date: OptionDate,
+    /// Dates after this date can't be selected
+    pub maximum_date: OptionDate,
+    /// Weekday of the first column of the month grid
+    pub first_day_of_week: Weekday,
+    /// Called when the selected date or the cursor changes
+    pub on_date_change: OptionDatePickerOnDateChange,
+}
+
+// dataset of the day cells and the prev / next month buttons
+struct DatePickerDayLocalDataset {
+    /// date the cursor is moved to (and selected, if `select` is set)
+    date: Date,
+    select: bool,
+    backref: RefAny, // RefAny<DatePicker>
+}
+
+impl DatePicker {
+
+    /// Creates a DatePicker with the `date` selected and the cursor on it
+    pub fn new(date: Date) -> Self {
+        Self {
+            state: DatePickerState {
+                selected: Some(date).into(),
+                cursor: date,
+            },
+            .. Default::default()
+        }
+    }
+
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut m = Self::default();
+        core::mem::swap(&mut m, self);
+        m
+    }
+
+    pub fn with_state(&mut self, state: DatePickerState) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_state(state);
+        m
+    }
+
+    pub fn set_state(&mut self, state: DatePickerState) {
+        self.state = state;
+    }
+
+    pub fn with_minimum_date(&mut self, minimum_date: OptionDate) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_minimum_date(minimum_date);
+        m
+    }
+
+    pub fn set_minimum_date(&mut self, minimum_date: OptionDate) {
+        self.minimum_date = minimum_date;
+    }
+
+    pub fn with_maximum_date(&mut self, maximum_date: OptionDate) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_maximum_date(maximum_date);
+        m
+    }
+
+    pub fn set_maximum_date(&mut self, maximum_date: OptionDate) {
+        self.maximum_date = maximum_date;
+    }
+
+    pub fn with_first_day_of_week(&mut self, first_day_of_week: Weekday) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_first_day_of_week(first_day_of_week);
+        m
+    }
+
+    pub fn set_first_day_of_week(&mut self, first_day_of_week: Weekday) {
+        self.first_day_of_week = first_day_of_week;
+    }
+
+    pub fn with_on_date_change(&mut self, data: RefAny, on_date_change: DatePickerOnDateChangeCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_date_change(data, on_date_change);
+        m
+    }
+
+    pub fn set_on_date_change(&mut self, data: RefAny, on_date_change: DatePickerOnDateChangeCallbackType) {
+        self.on_date_change = Some(DatePickerOnDateChange {
+            data,
+            callback: DatePickerOnDateChangeCallback { cb: on_date_change }
+        }).into();
+    }
+
+    /// Returns whether the date is within the minimum / maximum date
+    pub fn is_selectable(&self, date: Date) -> bool {
+        date.clamp_to(self.minimum_date, self.maximum_date) == date
+    }
+
+    /// Returns the first date shown in the month grid (the first
+    /// `first_day_of_week` before or on the first day of the month)
+    pub fn get_first_visible_date(&self) -> Date {
+        let first = self.state.cursor.first_of_month();
+        let offset = (first.weekday().index() + 7 - self.first_day_of_week.index()) % 7;
+        first.add_days(-(offset as i64))
+    }
+
+    pub fn dom(self) -> Dom {
+
+        let cursor = self.state.cursor;
+        let selected = self.state.selected.into_option();
+        let first_visible = self.get_first_visible_date();
+        let prev_month = cursor.add_months(-1).clamp_to(self.minimum_date, self.maximum_date);
+        let next_month = cursor.add_months(1).clamp_to(self.minimum_date, self.maximum_date);
+        let title = format!("{} {}", MONTH_NAMES[(cursor.month.clamp(1, 12) - 1) as usize], cursor.year);
+
+        let day_styles = (0..(WEEKS_PER_PAGE * 7)).map(|i| {
+            let date = first_visible.add_days(i as i64);
+            (date, self.is_selectable(date))
+        }).collect::<Vec<_>>();
+
+        let date_picker_local_dataset = RefAny::new(self);
+
+        let nav_button = |text: &'static str, date: Date| {
+            Dom::text(AzString::from_const_str(text))
+            .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_NAV))
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(BUTTON_STYLE))
+            .with_callbacks(vec![CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::LeftMouseUp),
+                data: RefAny::new(DatePickerDayLocalDataset { date, select: false, backref: date_picker_local_dataset.clone() }),
+                callback: Callback { cb: self::input::default_on_day_click },
+            }].into())
+        };
+
+        let header = Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_HEADER))
+        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(ROW_STYLE))
+        .with_children(vec![
+            nav_button("\u{2039}", prev_month),
+            Dom::text(title)
+            .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_TITLE))
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TITLE_STYLE)),
+            nav_button("\u{203a}", next_month),
+        ].into());
+
+        let first_day_of_week = first_visible.weekday().index();
+        let weekdays = Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_WEEK))
+        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(ROW_STYLE))
+        .with_children((0..7).map(|i| {
+            let name = &Weekday::from_index(first_day_of_week + i).get_name()[..2];
+            Dom::text(name)
+            .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_WEEKDAY))
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(WEEKDAY_STYLE))
+        }).collect::<Vec<_>>().into());
+
+        let mut children = vec![header, weekdays];
+
+        for week in day_styles.chunks(7) {
+            children.push(
+                Dom::div()
+                .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_WEEK))
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(ROW_STYLE))
+                .with_children(week.iter().map(|(date, is_selectable)| {
+
+                    let mut classes = vec![Class("__azul-native-datepicker-day".into())];
+                    let mut style = DAY_STYLE.to_vec();
+
+                    if date.month != cursor.month {
+                        classes.push(Class("other-month".into()));
+                        style.extend_from_slice(DAY_OTHER_MONTH_STYLE);
+                    }
+                    if !is_selectable {
+                        classes.push(Class("disabled".into()));
+                        style.extend_from_slice(DAY_DISABLED_STYLE);
+                    } else if selected == Some(*date) {
+                        classes.push(Class("selected".into()));
+                        style.extend_from_slice(DAY_SELECTED_STYLE);
+                    }
+                    if *date == cursor {
+                        classes.push(Class("cursor".into()));
+                        style.extend_from_slice(DAY_CURSOR_STYLE);
+                    }
+
+                    let day = Dom::text(format!("{}", date.day))
+                    .with_ids_and_classes(classes.into())
+                    .with_inline_css_props(style.into());
+
+                    if *is_selectable {
+                        day.with_callbacks(vec![CallbackData {
+                            event: EventFilter::Hover(HoverEventFilter::LeftMouseUp),
+                            data: RefAny::new(DatePickerDayLocalDataset {
+                                date: *date,
+                                select: true,
+                                backref: date_picker_local_dataset.clone(),
+                            }),
+                            callback: Callback { cb: self::input::default_on_day_click },
+                        }].into())
+                    } else {
+                        day
+                    }
+                }).collect::<Vec<_>>().into())
+            );
+        }
+
+        Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_DATEPICKER))
+        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(CONTAINER_STYLE))
+        .with_tab_index(TabIndex::Auto)
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                data: date_picker_local_dataset.clone(),
+                callback: Callback { cb: self::input::default_on_mouse_down },
+            },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                data: date_picker_local_dataset,
+                callback: Callback { cb: self::input::default_on_date_picker_key_down },
+            },
+        ].into())
+        .with_children(children.into())
+    }
+}
+
+impl From<DatePicker> for Dom {
+    fn from(d: DatePicker) -> Dom {
+        d.dom()
+    }
+}
+
+/// Hour / minute / second input with spin buttons
+///
+/// Stateless like the `DatePicker`: changes are reported through
+/// `on_time_change`. Up / Down change the focused field by one,
+/// PageUp / PageDown by ten, Left / Right move to the previous / next field.
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TimePicker {
+    pub time: Time,
+    /// Whether to show the seconds field (default: false)
+    pub show_seconds: bool,
+    /// Called when one of the fields is changed
+    pub on_time_change: OptionTimePickerOnTimeChange,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum TimePickerField {
+    Hour,
+    Minute,
+    Second,
+}
+
+// dataset of the hour / minute / second fields and their spin buttons
+struct TimePickerFieldLocalDataset {
+    field: TimePickerField,
+    /// how much a click on the node changes the field (0 for the field itself)
+    delta: i32,
+    backref: RefAny, // RefAny<TimePicker>
+}
+
+impl Default for TimePicker {
+    fn default() -> Self {
+        Self {
+            time: Time::default(),
+            show_seconds: false,
+            on_time_change: None.into(),
+        }
+    }
+}
+
+impl TimePicker {
+
+    pub fn new(time: Time) -> Self {
+        Self {
+            time,
+            .. Default::default()
+        }
+    }
+
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut m = Self::default();
+        core::mem::swap(&mut m, self);
+        m
+    }
+
+    pub fn with_show_seconds(&mut self, show_seconds: bool) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_show_seconds(show_seconds);
+        m
+    }
+
+    pub fn set_show_seconds(&mut self, show_seconds: bool) {
+        self.show_seconds = show_seconds;
+    }
+
+    pub fn with_on_time_change(&mut self, data: RefAny, on_time_change: TimePickerOnTimeChangeCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_time_change(data, on_time_change);
+        m
+    }
+
+    pub fn set_on_time_change(&mut self, data: RefAny, on_time_change: TimePickerOnTimeChangeCallbackType) {
+        self.on_time_change = Some(TimePickerOnTimeChange {
+            data,
+            callback: TimePickerOnTimeChangeCallback { cb: on_time_change }
+        }).into();
+    }
+
+    pub fn dom(self) -> Dom {
+
+        let time = self.time;
+        let mut fields = vec![(TimePickerField::Hour, time.hour), (TimePickerField::Minute, time.minute)];
+        if self.show_seconds {
+            fields.push((TimePickerField::Second, time.second));
+        }
+
+        let time_picker_local_dataset = RefAny::new(self);
+        let dataset = |field: TimePickerField, delta: i32| RefAny::new(TimePickerFieldLocalDataset {
+            field,
+            delta,
+            backref: time_picker_local_dataset.clone(),
+        });
+
+        let spin_button = |text: &'static str, field: TimePickerField, delta: i32| {
+            Dom::text(AzString::from_const_str(text))
+            .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_TIME_BUTTON))
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(BUTTON_STYLE))
+            .with_callbacks(vec![CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::LeftMouseUp),
+                data: dataset(field, delta),
+                callback: Callback { cb: self::input::default_on_time_button_click },
+            }].into())
+        };
+
+        let mut children = Vec::new();
+        for (i, (field, value)) in fields.into_iter().enumerate() {
+            if i != 0 {
+                children.push(Dom::text(AzString::from_const_str(":")));
+            }
+            children.push(
+                Dom::text(format!("{:02}", value))
+                .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_TIME_FIELD))
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TIME_FIELD_STYLE))
+                .with_tab_index(TabIndex::Auto)
+                .with_callbacks(vec![CallbackData {
+                    event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                    data: dataset(field, 0),
+                    callback: Callback { cb: self::input::default_on_time_field_key_down },
+                }].into())
+            );
+            children.push(
+                Dom::div()
+                .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_TIME_BUTTONS))
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TIME_BUTTONS_STYLE))
+                .with_children(vec![
+                    spin_button("\u{25b2}", field, 1),
+                    spin_button("\u{25bc}", field, -1),
+                ].into())
+            );
+        }
+
+        Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_TIMEPICKER))
+        .with_inline_css_props(
+            CONTAINER_STYLE.iter().chain(ROW_STYLE.iter()).cloned().collect::<Vec<_>>().into()
+        )
+        .with_children(children.into())
+    }
+}
+
+impl From<TimePicker> for Dom {
+    fn from(t: TimePicker) -> Dom {
+        t.dom()
+    }
+}
+
+/// State of a `DateTimePicker`, reported by `on_date_time_change`
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct DateTimePickerState {
+    pub date: DatePickerState,
+    pub time: Time,
+    /// Whether the popup with the calendar is open
+    pub is_open: bool,
+}
+
+/// Drop-down field showing the selected date (and time), which opens
+/// a `DatePicker` (and `TimePicker`) below the field when clicked
+///
+/// Stateless like the `DatePicker`: opening / closing the popup and
+/// changing the date or time is reported through `on_date_time_change`.
+/// Selecting a date closes the popup if `show_time` is not set,
+/// Escape always closes it.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[repr(C)]
+pub struct DateTimePicker {
+    pub state: DateTimePickerState,
+    /// Dates before this date can't be selected
+    pub minimum_date: OptionDate,
+    /// Dates after this date can't be selected
+    pub maximum_date: OptionDate,
+    /// Weekday of the first column of the month grid
+    pub first_day_of_week: Weekday,
+    /// Whether to show a `TimePicker` below the calendar (default: false)
+    pub show_time: bool,
+    /// Whether the `TimePicker` shows the seconds field (default: false)
+    pub show_seconds: bool,
+    /// Called when the popup is opened / closed or the date or time changes
+    pub on_date_time_change: OptionDateTimePickerOnDateTimeChange,
+}
+
+impl DateTimePicker {
+
+    /// Creates a closed DateTimePicker with the `date` selected
+    pub fn new(date: Date) -> Self {
+        Self {
+            state: DateTimePickerState {
+                date: DatePicker::new(date).state,
+                .. Default::default()
+            },
+            .. Default::default()
+        }
+    }
+
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut m = Self::default();
+        core::mem::swap(&mut m, self);
+        m
+    }
+
+    pub fn with_state(&mut self, state: DateTimePickerState) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_state(state);
+        m
+    }
+
+    pub fn set_state(&mut self, state: DateTimePickerState) {
+        self.state = state;
+    }
+
+    pub fn with_minimum_date(&mut self, minimum_date: OptionDate) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_minimum_date(minimum_date);
+        m
+    }
+
+    pub fn set_minimum_date(&mut self, minimum_date: OptionDate) {
+        self.minimum_date = minimum_date;
+    }
+
+    pub fn with_maximum_date(&mut self, maximum_date: OptionDate) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_maximum_date(maximum_date);
+        m
+    }
+
+    pub fn set_maximum_date(&mut self, maximum_date: OptionDate) {
+        self.maximum_date = maximum_date;
+    }
+
+    pub fn with_first_day_of_week(&mut self, first_day_of_week: Weekday) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_first_day_of_week(first_day_of_week);
+        m
+    }
+
+    pub fn set_first_day_of_week(&mut self, first_day_of_week: Weekday) {
+        self.first_day_of_week = first_day_of_week;
+    }
+
+    pub fn with_show_time(&mut self, show_time: bool, show_seconds: bool) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_show_time(show_time, show_seconds);
+        m
+    }
+
+    pub fn set_show_time(&mut self, show_time: bool, show_seconds: bool) {
+        self.show_time = show_time;
+        self.show_seconds = show_seconds;
+    }
+
+    pub fn with_on_date_time_change(&mut self, data: RefAny, on_date_time_change: DateTimePickerOnDateTimeChangeCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_date_time_change(data, on_date_time_change);
+        m
+    }
+
+    pub fn set_on_date_time_change(&mut self, data: RefAny, on_date_time_change: DateTimePickerOnDateTimeChangeCallbackType) {
+        self.on_date_time_change = Some(DateTimePickerOnDateTimeChange {
+            data,
+            callback: DateTimePickerOnDateTimeChangeCallback { cb: on_date_time_change }
+        }).into();
+    }
+
+    /// Returns the text shown in the (closed) drop-down field
+    pub fn get_display_text(&self) -> String {
+        match self.state.date.selected.into_option() {
+            Some(date) if self.show_time => format!("{} {}", date, self.state.time.format(self.show_seconds)),
+            Some(date) => format!("{}", date),
+            None => String::from("Select a date"),
+        }
+    }
+
+    pub fn dom(self) -> Dom {
+
+        let text = self.get_display_text();
+        let state = self.state;
+        let minimum_date = self.minimum_date;
+        let maximum_date = self.maximum_date;
+        let first_day_of_week = self.first_day_of_week;
+        let show_time = self.show_time;
+        let show_seconds = self.show_seconds;
+
+        let date_time_picker_local_dataset = RefAny::new(self);
+
+        let mut children = vec![
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_DROPDOWN_HEADER))
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(DROPDOWN_HEADER_STYLE))
+            .with_tab_index(TabIndex::Auto)
+            .with_callbacks(vec![
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::LeftMouseUp),
+                    data: date_time_picker_local_dataset.clone(),
+                    callback: Callback { cb: self::input::default_on_dropdown_header_click },
+                },
+                CallbackData {
+                    event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                    data: date_time_picker_local_dataset.clone(),
+                    callback: Callback { cb: self::input::default_on_dropdown_key_down },
+                },
+            ].into())
+            .with_children(vec![
+                Dom::text(text)
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TITLE_STYLE)),
+                Dom::text(AzString::from_const_str("\u{25be}"))
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(WEEKDAY_STYLE)),
+            ].into())
+        ];
+
+        if state.is_open {
+
+            let mut date_picker = DatePicker {
+                state: state.date,
+                minimum_date,
+                maximum_date,
+                first_day_of_week,
+                on_date_change: Some(DatePickerOnDateChange {
+                    data: date_time_picker_local_dataset.clone(),
+                    callback: DatePickerOnDateChangeCallback { cb: self::input::default_on_popup_date_change },
+                }).into(),
+            }.dom();
+
+            // the calendar has the keyboard focus while the popup is open,
+            // so it also has to close the popup when Escape is pressed
+            date_picker.root.add_callback(
+                EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                date_time_picker_local_dataset.clone(),
+                self::input::default_on_popup_key_down,
+            );
+
+            let mut popup_children = vec![date_picker];
+            if show_time {
+                popup_children.push(
+                    TimePicker {
+                        time: state.time,
+                        show_seconds,
+                        on_time_change: Some(TimePickerOnTimeChange {
+                            data: date_time_picker_local_dataset.clone(),
+                            callback: TimePickerOnTimeChangeCallback { cb: self::input::default_on_popup_time_change },
+                        }).into(),
+                    }.dom()
+                );
+            }
+
+            children.push(
+                Dom::div()
+                .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_DROPDOWN_POPUP))
+                .with_inline_css_props(
+                    CONTAINER_STYLE.iter().chain(DROPDOWN_POPUP_STYLE.iter()).cloned().collect::<Vec<_>>().into()
+                )
+                .with_children(popup_children.into())
+            );
+        }
+
+        Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_DROPDOWN))
+        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(DROPDOWN_STYLE))
+        .with_children(children.into())
+    }
+}
+
+impl From<DateTimePicker> for Dom {
+    fn from(d: DateTimePicker) -> Dom {
+        d.dom()
+    }
+}
+
+/// Renders a `<date_picker selected_date="2024-05-01" first_day_of_week="sunday" />` XML node
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DatePickerRenderer {
+    node: XmlNode,
+}
+
+impl DatePickerRenderer {
+    pub fn new() -> Self {
+        Self {
+            node: XmlNode::new("date_picker"),
+        }
+    }
+}
+
+impl Default for DatePickerRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XmlComponentTrait for DatePickerRenderer {
+    fn get_type_id(&self) -> String {
+        "date_picker".to_string()
+    }
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        ComponentArguments {
+            args: vec![
+                ("selected_date".to_string(), "OptionDate".to_string()),
+                ("minimum_date".to_string(), "OptionDate".to_string()),
+                ("maximum_date".to_string(), "OptionDate".to_string()),
+                ("first_day_of_week".to_string(), "Weekday".to_string()),
+            ],
+            accepts_text: false,
+        }
+    }
+
+    fn render_dom(
+        &self,
+        _: &XmlComponentMap,
+        args: &FilteredComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<StyledDom, RenderDomError> {
+        let minimum_date = parse_xml_arg(args, "minimum_date", "Date", Date::parse)?;
+        let maximum_date = parse_xml_arg(args, "maximum_date", "Date", Date::parse)?;
+        let date_picker = DatePicker {
+            state: get_xml_date_picker_state(args, minimum_date, maximum_date)?,
+            minimum_date: minimum_date.into(),
+            maximum_date: maximum_date.into(),
+            first_day_of_week: parse_xml_arg(args, "first_day_of_week", "Weekday", Weekday::parse)?.unwrap_or_default(),
+            on_date_change: None.into(),
+        };
+        Ok(date_picker.dom().style(Css::empty()))
+    }
+
+    fn compile_to_rust_code(
+        &self,
+        _: &XmlComponentMap,
+        _: &ComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<String, CompileError> {
+        Ok(String::from(
+            "let cursor = selected_date.into_option().unwrap_or_else(|| Date::today().clamp_to(minimum_date, maximum_date));\r\n\
+            DatePicker::new(cursor)\r\n\
+            .with_state(DatePickerState { selected: selected_date, cursor })\r\n\
+            .with_minimum_date(minimum_date)\r\n\
+            .with_maximum_date(maximum_date)\r\n\
+            .with_first_day_of_week(first_day_of_week)\r\n\
+            .dom()"
+        ))
+    }
+
+    fn compile_argument_to_rust_code(&self, arg_name: &str, arg_type: &str, value: &str) -> Result<String, ComponentError> {
+        compile_xml_arg_to_rust_code(arg_name, arg_type, value)
+    }
+
+    fn get_xml_node(&self) -> XmlNode {
+        self.node.clone()
+    }
+}
+
+/// Renders a `<time_picker time="13:30" show_seconds="false" />` XML node
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimePickerRenderer {
+    node: XmlNode,
+}
+
+impl TimePickerRenderer {
+    pub fn new() -> Self {
+        Self {
+            node: XmlNode::new("time_picker"),
+        }
+    }
+}
+
+impl Default for TimePickerRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XmlComponentTrait for TimePickerRenderer {
+    fn get_type_id(&self) -> String {
+        "time_picker".to_string()
+    }
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        ComponentArguments {
+            args: vec![
+                ("time".to_string(), "Time".to_string()),
+                ("show_seconds".to_string(), "bool".to_string()),
+            ],
+            accepts_text: false,
+        }
+    }
+
+    fn render_dom(
+        &self,
+        _: &XmlComponentMap,
+        args: &FilteredComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<StyledDom, RenderDomError> {
+        let time_picker = TimePicker {
+            time: parse_xml_arg(args, "time", "Time", Time::parse)?.unwrap_or_default(),
+            show_seconds: parse_xml_arg(args, "show_seconds", "bool", parse_xml_bool)?.unwrap_or(false),
+            on_time_change: None.into(),
+        };
+        Ok(time_picker.dom().style(Css::empty()))
+    }
+
+    fn compile_to_rust_code(
+        &self,
+        _: &XmlComponentMap,
+        _: &ComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<String, CompileError> {
+        Ok(String::from("TimePicker::new(time).with_show_seconds(show_seconds).dom()"))
+    }
+
+    fn compile_argument_to_rust_code(&self, arg_name: &str, arg_type: &str, value: &str) -> Result<String, ComponentError> {
+        compile_xml_arg_to_rust_code(arg_name, arg_type, value)
+    }
+
+    fn get_xml_node(&self) -> XmlNode {
+        self.node.clone()
+    }
+}
+
+/// Renders a `<date_time_picker selected_date="2024-05-01" show_time="true" />` XML node
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTimePickerRenderer {
+    node: XmlNode,
+}
+
+impl DateTimePickerRenderer {
+    pub fn new() -> Self {
+        Self {
+            node: XmlNode::new("date_time_picker"),
+        }
+    }
+}
+
+impl Default for DateTimePickerRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XmlComponentTrait for DateTimePickerRenderer {
+    fn get_type_id(&self) -> String {
+        "date_time_picker".to_string()
+    }
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        ComponentArguments {
+            args: vec![
+                ("selected_date".to_string(), "OptionDate".to_string()),
+                ("minimum_date".to_string(), "OptionDate".to_string()),
+                ("maximum_date".to_string(), "OptionDate".to_string()),
+                ("first_day_of_week".to_string(), "Weekday".to_string()),
+                ("time".to_string(), "Time".to_string()),
+                ("show_time".to_string(), "bool".to_string()),
+                ("show_seconds".to_string(), "bool".to_string()),
+                ("is_open".to_string(), "bool".to_string()),
+            ],
+            accepts_text: false,
+        }
+    }
+
+    fn render_dom(
+        &self,
+        _: &XmlComponentMap,
+        args: &FilteredComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<StyledDom, RenderDomError> {
+        let minimum_date = parse_xml_arg(args, "minimum_date", "Date", Date::parse)?;
+        let maximum_date = parse_xml_arg(args, "maximum_date", "Date", Date::parse)?;
+        let date_time_picker = DateTimePicker {
+            state: DateTimePickerState {
+                date: get_xml_date_picker_state(args, minimum_date, maximum_date)?,
+                time: parse_xml_arg(args, "time", "Time", Time::parse)?.unwrap_or_default(),
+                is_open: parse_xml_arg(args, "is_open", "bool", parse_xml_bool)?.unwrap_or(false),
+            },
+            minimum_date: minimum_date.into(),
+            maximum_date: maximum_date.into(),
+            first_day_of_week: parse_xml_arg(args, "first_day_of_week", "Weekday", Weekday::parse)?.unwrap_or_default(),
+            show_time: parse_xml_arg(args, "show_time", "bool", parse_xml_bool)?.unwrap_or(false),
+            show_seconds: parse_xml_arg(args, "show_seconds", "bool", parse_xml_bool)?.unwrap_or(false),
+            on_date_time_change: None.into(),
+        };
+        Ok(date_time_picker.dom().style(Css::empty()))
+    }
+
+    fn compile_to_rust_code(
+        &self,
+        _: &XmlComponentMap,
+        _: &ComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<String, CompileError> {
+        Ok(String::from(
+            "let cursor = selected_date.into_option().unwrap_or_else(|| Date::today().clamp_to(minimum_date, maximum_date));\r\n\
+            DateTimePicker::new(cursor)\r\n\
+            .with_state(DateTimePickerState { date: DatePickerState { selected: selected_date, cursor }, time, is_open })\r\n\
+            .with_minimum_date(minimum_date)\r\n\
+            .with_maximum_date(maximum_date)\r\n\
+            .with_first_day_of_week(first_day_of_week)\r\n\
+            .with_show_time(show_time, show_seconds)\r\n\
+            .dom()"
+        ))
+    }
+
+    fn compile_argument_to_rust_code(&self, arg_name: &str, arg_type: &str, value: &str) -> Result<String, ComponentError> {
+        compile_xml_arg_to_rust_code(arg_name, arg_type, value)
+    }
+
+    fn get_xml_node(&self) -> XmlNode {
+        self.node.clone()
+    }
+}
+
+// Compiles the XML value of a date / time argument to a constructor call, i.e. "2024-05-01" to "Date::new(2024, 5, 1)"
+fn compile_xml_arg_to_rust_code(arg_name: &str, arg_type: &str, value: &str) -> Result<String, ComponentError> {
+    let invalid = || ComponentError::InvalidArgumentValue(arg_name.into(), value.into(), arg_type.into());
+    match arg_type {
+        "OptionDate" => Date::parse(value)
+            .map(|d| format!("Some(Date::new({}, {}, {})).into()", d.year, d.month, d.day))
+            .ok_or_else(invalid),
+        "Time" => Time::parse(value)
+            .map(|t| format!("Time::new({}, {}, {})", t.hour, t.minute, t.second))
+            .ok_or_else(invalid),
+        "Weekday" => Weekday::parse(value)
+            .map(|w| format!("Weekday::{:?}", w))
+            .ok_or_else(invalid),
+        _ => Ok(format!("{}::from({:?})", arg_type, value)),
+    }
+}
+
+// Selected date from the XML arguments, without a selected date the calendar shows today
+fn get_xml_date_picker_state(
+    args: &FilteredComponentArguments,
+    minimum_date: Option<Date>,
+    maximum_date: Option<Date>,
+) -> Result<DatePickerState, Box<ComponentError>> {
+    let selected = parse_xml_arg(args, "selected_date", "Date", Date::parse)?;
+    Ok(DatePickerState {
+        selected: selected.into(),
+        cursor: selected.unwrap_or_else(|| Date::today().clamp_to(minimum_date.into(), maximum_date.into())),
+    })
+}
+
+// handle input events for the date and time pickers
+mod input {
+
+    use azul_desktop::callbacks::{RefAny, CallbackInfo, Update, FocusTarget};
+    use azul_core::window::VirtualKeyCode;
+    use super::{
+        Date, Time, DatePicker, DatePickerState, TimePicker,
+        DateTimePicker, DateTimePickerState,
+        DatePickerDayLocalDataset, TimePickerFieldLocalDataset,
+        DatePickerOnDateChange, TimePickerOnTimeChange,
+        DateTimePickerOnDateTimeChange,
+    };
+
+    pub(in super) extern "C" fn default_on_mouse_down(_data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+        // focus the DatePicker, so that the keyboard navigation works after clicking a day
+        let date_picker_node = info.get_hit_node();
+        info.set_focus(FocusTarget::Id(date_picker_node));
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_day_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut day_local_dataset = match data.downcast_mut::<DatePickerDayLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let date = day_local_dataset.date;
+        let select = day_local_dataset.select;
+        let mut date_picker = match day_local_dataset.backref.downcast_mut::<DatePicker>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        move_cursor(&mut date_picker, info, date, select)
+    }
+
+    pub(in super) extern "C" fn default_on_date_picker_key_down(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut date_picker = match data.downcast_mut::<DatePicker>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let keyboard_state = info.get_current_keyboard_state();
+        let keycode = match keyboard_state.current_virtual_keycode.into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let months = if keyboard_state.shift_down() { 12 } else { 1 };
+        let cursor = date_picker.state.cursor;
+        let (target, select) = match keycode {
+            VirtualKeyCode::Left => (cursor.add_days(-1), false),
+            VirtualKeyCode::Right => (cursor.add_days(1), false),
+            VirtualKeyCode::Up => (cursor.add_days(-7), false),
+            VirtualKeyCode::Down => (cursor.add_days(7), false),
+            VirtualKeyCode::PageUp => (cursor.add_months(-months), false),
+            VirtualKeyCode::PageDown => (cursor.add_months(months), false),
+            VirtualKeyCode::Home => (cursor.first_of_month(), false),
+            VirtualKeyCode::End => (cursor.last_of_month(), false),
+            VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter | VirtualKeyCode::Space => (cursor, true),
+            _ => return Update::DoNothing,
+        };
+
+        let target = target.clamp_to(date_picker.minimum_date, date_picker.maximum_date);
+        move_cursor(&mut date_picker, info, target, select)
+    }
+
+    // Moves the cursor (and the selection, if `select` is set) to `date`
+    // and calls on_date_change if the state changed
+    fn move_cursor(date_picker: &mut DatePicker, info: &mut CallbackInfo, date: Date, select: bool) -> Update {
+
+        let mut new_state = DatePickerState {
+            selected: date_picker.state.selected,
+            cursor: date,
+        };
+
+        if select {
+            if !date_picker.is_selectable(date) {
+                return Update::DoNothing;
+            }
+            new_state.selected = Some(date).into();
+        }
+
+        if new_state == date_picker.state {
+            return Update::DoNothing;
+        }
+
+        date_picker.state = new_state;
+
+        match date_picker.on_date_change.as_mut() {
+            Some(DatePickerOnDateChange { callback, data }) => (callback.cb)(data, info, &new_state),
+            None => Update::DoNothing,
+        }
+    }
+
+    pub(in super) extern "C" fn default_on_time_button_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut field_local_dataset = match data.downcast_mut::<TimePickerFieldLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let field = field_local_dataset.field;
+        let delta = field_local_dataset.delta;
+        let mut time_picker = match field_local_dataset.backref.downcast_mut::<TimePicker>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let new_time = time_picker.time.add_to_field(field, delta);
+        set_time(&mut time_picker, info, new_time)
+    }
+
+    pub(in super) extern "C" fn default_on_time_field_key_down(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut field_local_dataset = match data.downcast_mut::<TimePickerFieldLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let keycode = match info.get_current_keyboard_state().current_virtual_keycode.into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let delta = match keycode {
+            VirtualKeyCode::Up => 1,
+            VirtualKeyCode::Down => -1,
+            VirtualKeyCode::PageUp => 10,
+            VirtualKeyCode::PageDown => -10,
+            VirtualKeyCode::Left => {
+                info.set_focus(FocusTarget::Previous);
+                return Update::DoNothing;
+            },
+            VirtualKeyCode::Right => {
+                info.set_focus(FocusTarget::Next);
+                return Update::DoNothing;
+            },
+            _ => return Update::DoNothing,
+        };
+
+        let field = field_local_dataset.field;
+        let mut time_picker = match field_local_dataset.backref.downcast_mut::<TimePicker>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let new_time = time_picker.time.add_to_field(field, delta);
+        set_time(&mut time_picker, info, new_time)
+    }
+
+    fn set_time(time_picker: &mut TimePicker, info: &mut CallbackInfo, new_time: Time) -> Update {
+
+        if new_time == time_picker.time {
+            return Update::DoNothing;
+        }
+
+        time_picker.time = new_time;
+
+        match time_picker.on_time_change.as_mut() {
+            Some(TimePickerOnTimeChange { callback, data }) => (callback.cb)(data, info, &new_time),
+            None => Update::DoNothing,
+        }
+    }
+
+    pub(in super) extern "C" fn default_on_dropdown_header_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut date_time_picker = match data.downcast_mut::<DateTimePicker>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let mut new_state = date_time_picker.state;
+        new_state.is_open = !new_state.is_open;
+        set_date_time_state(&mut date_time_picker, info, new_state)
+    }
+
+    pub(in super) extern "C" fn default_on_dropdown_key_down(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut date_time_picker = match data.downcast_mut::<DateTimePicker>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let keycode = match info.get_current_keyboard_state().current_virtual_keycode.into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let mut new_state = date_time_picker.state;
+        match keycode {
+            VirtualKeyCode::Escape => new_state.is_open = false,
+            VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter |
+            VirtualKeyCode::Space | VirtualKeyCode::Down => new_state.is_open = true,
+            _ => return Update::DoNothing,
+        }
+
+        set_date_time_state(&mut date_time_picker, info, new_state)
+    }
+
+    pub(in super) extern "C" fn default_on_popup_key_down(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut date_time_picker = match data.downcast_mut::<DateTimePicker>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let is_escape = info.get_current_keyboard_state().current_virtual_keycode.into_option() == Some(VirtualKeyCode::Escape);
+        if !is_escape {
+            return Update::DoNothing;
+        }
+
+        let mut new_state = date_time_picker.state;
+        new_state.is_open = false;
+        set_date_time_state(&mut date_time_picker, info, new_state)
+    }
+
+    pub(in super) extern "C" fn default_on_popup_date_change(data: &mut RefAny, info: &mut CallbackInfo, date_picker_state: &DatePickerState) -> Update {
+
+        let mut date_time_picker = match data.downcast_mut::<DateTimePicker>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let mut new_state = date_time_picker.state;
+        let date_selected = new_state.date.selected != date_picker_state.selected;
+        new_state.date = *date_picker_state;
+        if date_selected && !date_time_picker.show_time {
+            new_state.is_open = false;
+        }
+
+        set_date_time_state(&mut date_time_picker, info, new_state)
+    }
+
+    pub(in super) extern "C" fn default_on_popup_time_change(data: &mut RefAny, info: &mut CallbackInfo, time: &Time) -> Update {
+
+        let mut date_time_picker = match data.downcast_mut::<DateTimePicker>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let mut new_state = date_time_picker.state;
+        new_state.time = *time;
+        set_date_time_state(&mut date_time_picker, info, new_state)
+    }
+
+    fn set_date_time_state(date_time_picker: &mut DateTimePicker, info: &mut CallbackInfo, new_state: DateTimePickerState) -> Update {
+
+        if new_state == date_time_picker.state {
+            return Update::DoNothing;
+        }
+
+        date_time_picker.state = new_state;
+
+        match date_time_picker.on_date_time_change.as_mut() {
+            Some(DateTimePickerOnDateTimeChange { callback, data }) => (callback.cb)(data, info, &new_state),
+            None => Update::DoNothing,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_date_days_roundtrip() {
+        assert_eq!(Date::new(1970, 1, 1).to_days(), 0);
+        assert_eq!(Date::new(2000, 3, 1).to_days(), 11017);
+        assert_eq!(Date::from_days(-1), Date::new(1969, 12, 31));
+        for days in -800_000..800_000i64 {
+            if days % 997 == 0 {
+                assert_eq!(Date::from_days(days).to_days(), days);
+            }
+        }
+        assert_eq!(Date::new(2024, 5, 1).weekday(), Weekday::Wednesday);
+        assert_eq!(Date::new(2000, 1, 1).weekday(), Weekday::Saturday);
+    }
+
+    #[test]
+    fn test_date_arithmetic() {
+        assert_eq!(Date::new(2024, 1, 31).add_months(1), Date::new(2024, 2, 29));
+        assert_eq!(Date::new(2023, 1, 31).add_months(1), Date::new(2023, 2, 28));
+        assert_eq!(Date::new(2024, 1, 15).add_months(-1), Date::new(2023, 12, 15));
+        assert_eq!(Date::new(2024, 12, 31).add_days(1), Date::new(2025, 1, 1));
+        assert_eq!(Date::new(2024, 3, 1).add_days(-1), Date::new(2024, 2, 29));
+        assert_eq!(Date::days_in_month(1900, 2), 28);
+        assert_eq!(Date::days_in_month(2000, 2), 29);
+
+        let min = Some(Date::new(2024, 1, 10)).into();
+        let max = Some(Date::new(2024, 1, 20)).into();
+        assert_eq!(Date::new(2024, 1, 5).clamp_to(min, max), Date::new(2024, 1, 10));
+        assert_eq!(Date::new(2024, 2, 5).clamp_to(min, max), Date::new(2024, 1, 20));
+        assert_eq!(Date::new(2024, 1, 15).clamp_to(min, max), Date::new(2024, 1, 15));
+    }
+
+    #[test]
+    fn test_date_time_parse() {
+        assert_eq!(Date::parse("2024-05-01"), Some(Date::new(2024, 5, 1)));
+        assert_eq!(Date::parse("01.01.2018"), Some(Date::new(2018, 1, 1)));
+        assert_eq!(Date::parse("2023-02-29"), None);
+        assert_eq!(Date::parse("2024-13-01"), None);
+        assert_eq!(Date::parse("yesterday"), None);
+        assert_eq!(format!("{}", Date::new(987, 6, 5)), "0987-06-05");
+
+        assert_eq!(Time::parse("13:30"), Some(Time::new(13, 30, 0)));
+        assert_eq!(Time::parse("23:59:59"), Some(Time::new(23, 59, 59)));
+        assert_eq!(Time::parse("24:00"), None);
+        assert_eq!(Time::new(9, 5, 0).format(false), "09:05");
+        assert_eq!(Time::new(23, 0, 0).add_to_field(TimePickerField::Hour, 1), Time::new(0, 0, 0));
+        assert_eq!(Time::new(0, 0, 0).add_to_field(TimePickerField::Minute, -10), Time::new(0, 50, 0));
+
+        assert_eq!(Weekday::parse("Sunday"), Some(Weekday::Sunday));
+        assert_eq!(Weekday::parse("tue"), Some(Weekday::Tuesday));
+        assert_eq!(Weekday::parse("t"), None);
+    }
+
+    #[test]
+    fn test_date_picker_grid() {
+        // May 2024 starts on a wednesday
+        let date_picker = DatePicker::new(Date::new(2024, 5, 17));
+        assert_eq!(date_picker.get_first_visible_date(), Date::new(2024, 4, 29));
+        let date_picker = DatePicker::new(Date::new(2024, 5, 17)).with_first_day_of_week(Weekday::Sunday);
+        assert_eq!(date_picker.get_first_visible_date(), Date::new(2024, 4, 28));
+        // September 2024 starts on a sunday
+        let date_picker = DatePicker::new(Date::new(2024, 9, 1)).with_first_day_of_week(Weekday::Sunday);
+        assert_eq!(date_picker.get_first_visible_date(), Date::new(2024, 9, 1));
+
+        let date_picker = DatePicker::new(Date::new(2024, 5, 17))
+            .with_minimum_date(Some(Date::new(2024, 5, 10)).into());
+        assert!(!date_picker.is_selectable(Date::new(2024, 5, 9)));
+        assert!(date_picker.is_selectable(Date::new(2024, 5, 10)));
+    }
+}
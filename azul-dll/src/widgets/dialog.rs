@@ -0,0 +1,822 @@
+//! In-window modal dialogs (message boxes, confirmations, prompts and custom dialogs)
+//!
+//! Unlike the functions in `azul_desktop::dialogs`, these dialogs are rendered
+//! as a regular part of the DOM, so they don't block the thread, look the same
+//! on every platform and can be styled via CSS. The result is reported through
+//! the `on_close` callback - the application then removes the dialog from its
+//! state and rebuilds the DOM.
+
+use std::vec::Vec;
+use azul_desktop::css::*;
+use azul_desktop::css::AzString;
+use azul_desktop::callbacks::{CallbackInfo, RefAny, Update, Callback};
+use azul_desktop::dom::{
+    Dom, IdOrClass, OptionDom,
+    IdOrClass::Class,
+    NodeDataInlineCssProperty,
+    IdOrClassVec, NodeDataInlineCssPropertyVec,
+    CallbackData, EventFilter, WindowEventFilter,
+};
+use crate::widgets::button::Button;
+use crate::widgets::text_input::{TextInput, TextInputState, OnTextInputReturn, TextInputValid};
+use azul_desktop::xml::{
+    XmlComponentTrait, XmlComponentMap, XmlNode, XmlTextContent,
+    ComponentArguments, FilteredComponentArguments,
+    RenderDomError, CompileError,
+};
+use azul_desktop::styled_dom::StyledDom;
+use super::get_xml_string_arg;
+
+pub type DialogOnCloseCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &DialogResult) -> Update;
+impl_callback!(DialogOnClose, OptionDialogOnClose, DialogOnCloseCallback, DialogOnCloseCallbackType);
+
+const STRING_SANS_SERIF: AzString = AzString::from_const_str("sans-serif");
+const FONT_FAMILY_SANS_SERIF: &[StyleFontFamily] = &[StyleFontFamily::System(STRING_SANS_SERIF)];
+
+const COLOR_WHITE: ColorU = ColorU { r: 255, g: 255, b: 255, a: 255 };
+const COLOR_BORDER: ColorU = ColorU { r: 172, g: 172, b: 172, a: 255 };
+const COLOR_INFO: ColorU = ColorU { r: 38, g: 160, b: 218, a: 255 };
+const COLOR_WARNING: ColorU = ColorU { r: 230, g: 160, b: 0, a: 255 };
+const COLOR_ERROR: ColorU = ColorU { r: 215, g: 45, b: 45, a: 255 };
+
+const BACKGROUND_BACKDROP: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(ColorU { r: 0, g: 0, b: 0, a: 96 })];
+const BACKGROUND_WHITE: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_WHITE)];
+const BACKGROUND_TITLE: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(ColorU { r: 240, g: 240, b: 240, a: 255 })];
+
+// .__azul-native-dialog-host
+static HOST_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_grow(LayoutFlexGrow { inner: FloatValue::const_new(1) })),
+];
+
+// .__azul-native-dialog-backdrop
+static BACKDROP_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_top(LayoutTop::const_px(0))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_left(LayoutLeft::const_px(0))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_width(LayoutWidth::const_percent(100))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_height(LayoutHeight::const_percent(100))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_justify_content(LayoutJustifyContent::Center)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_align_items(LayoutAlignItems::Center)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BACKGROUND_BACKDROP))),
+];
+
+// .__azul-native-dialog
+static DIALOG_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_min_width(LayoutMinWidth::const_px(300))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_max_width(LayoutMaxWidth::const_px(600))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_font_size(StyleFontSize::const_px(13))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_font_family(StyleFontFamilyVec::from_const_slice(FONT_FAMILY_SANS_SERIF))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_BORDER })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_BORDER })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_BORDER })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_BORDER })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BACKGROUND_WHITE))),
+];
+
+// .__azul-native-dialog-title
+static TITLE_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_top(LayoutPaddingTop::const_px(6))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(10))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(10))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_bottom(LayoutPaddingBottom::const_px(6))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BACKGROUND_TITLE))),
+];
+
+// .__azul-native-dialog-body
+static BODY_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_top(LayoutPaddingTop::const_px(10))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(10))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(10))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_bottom(LayoutPaddingBottom::const_px(10))),
+];
+
+// .__azul-native-dialog-icon
+static ICON_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_width(LayoutWidth::const_px(32))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_font_size(StyleFontSize::const_px(24))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_margin_right(LayoutMarginRight::const_px(10))),
+];
+
+// .__azul-native-dialog-content
+static CONTENT_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_grow(LayoutFlexGrow { inner: FloatValue::const_new(1) })),
+];
+
+// .__azul-native-dialog-buttons
+static BUTTONS_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_justify_content(LayoutJustifyContent::End)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(10))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(10))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_bottom(LayoutPaddingBottom::const_px(10))),
+];
+
+// .__azul-native-dialog-button
+static BUTTON_WRAPPER_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_min_width(LayoutMinWidth::const_px(80))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_margin_left(LayoutMarginLeft::const_px(6))),
+];
+
+const IDS_AND_CLASSES_HOST: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-dialog-host"))];
+const IDS_AND_CLASSES_BACKDROP: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-dialog-backdrop"))];
+const IDS_AND_CLASSES_DIALOG: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-dialog"))];
+const IDS_AND_CLASSES_TITLE: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-dialog-title"))];
+const IDS_AND_CLASSES_BODY: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-dialog-body"))];
+const IDS_AND_CLASSES_ICON: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-dialog-icon"))];
+const IDS_AND_CLASSES_CONTENT: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-dialog-content"))];
+const IDS_AND_CLASSES_MESSAGE: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-dialog-message"))];
+const IDS_AND_CLASSES_BUTTONS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-dialog-buttons"))];
+const IDS_AND_CLASSES_BUTTON: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-dialog-button"))];
+const IDS_AND_CLASSES_BUTTON_DEFAULT: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-dialog-button")),
+    Class(AzString::from_const_str("default")),
+];
+
+/// Icon shown left of the message of a `Dialog`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum DialogIcon {
+    None,
+    Info,
+    Warning,
+    Error,
+    Question,
+}
+
+impl DialogIcon {
+    fn get_glyph(&self) -> Option<(&'static str, ColorU)> {
+        match self {
+            DialogIcon::None => None,
+            DialogIcon::Info => Some(("\u{2139}", COLOR_INFO)),
+            DialogIcon::Warning => Some(("\u{26a0}", COLOR_WARNING)),
+            DialogIcon::Error => Some(("\u{2716}", COLOR_ERROR)),
+            DialogIcon::Question => Some(("?", COLOR_INFO)),
+        }
+    }
+}
+
+/// Set of buttons shown at the bottom of a `Dialog`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum DialogButtons {
+    Ok,
+    OkCancel,
+    YesNo,
+    YesNoCancel,
+}
+
+impl DialogButtons {
+
+    /// Returns the buttons in the order they are displayed
+    pub fn get_buttons(&self) -> &'static [DialogButton] {
+        match self {
+            DialogButtons::Ok => &[DialogButton::Ok],
+            DialogButtons::OkCancel => &[DialogButton::Ok, DialogButton::Cancel],
+            DialogButtons::YesNo => &[DialogButton::Yes, DialogButton::No],
+            DialogButtons::YesNoCancel => &[DialogButton::Yes, DialogButton::No, DialogButton::Cancel],
+        }
+    }
+
+    /// Button that is "pressed" when the user hits Enter
+    pub fn get_default_button(&self) -> DialogButton {
+        self.get_buttons()[0]
+    }
+
+    /// Button that is "pressed" when the user hits Escape
+    pub fn get_cancel_button(&self) -> DialogButton {
+        match self {
+            DialogButtons::Ok => DialogButton::Ok,
+            DialogButtons::YesNo => DialogButton::No,
+            DialogButtons::OkCancel | DialogButtons::YesNoCancel => DialogButton::Cancel,
+        }
+    }
+}
+
+/// Button that closed a `Dialog`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum DialogButton {
+    Ok,
+    Cancel,
+    Yes,
+    No,
+}
+
+impl DialogButton {
+    pub fn get_label(&self) -> &'static str {
+        match self {
+            DialogButton::Ok => "OK",
+            DialogButton::Cancel => "Cancel",
+            DialogButton::Yes => "Yes",
+            DialogButton::No => "No",
+        }
+    }
+}
+
+/// Result of a `Dialog`, reported by `on_close`
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct DialogResult {
+    /// Button that was pressed (Enter presses the first button, Escape "Cancel" or "No")
+    pub button: DialogButton,
+    /// Text of the input field (empty if the dialog isn't a prompt)
+    pub input: AzString,
+}
+
+impl DialogResult {
+    /// Returns whether the dialog was closed with "Ok" or "Yes"
+    pub fn is_accepted(&self) -> bool {
+        matches!(self.button, DialogButton::Ok | DialogButton::Yes)
+    }
+}
+
+/// Modal dialog rendered inside of the window
+///
+/// Dialogs are stateless: the application stores the `Dialog`
+/// (or whatever it needs to rebuild it) while the dialog is open and
+/// removes it again in `on_close`. Use `dom_over()` to render the dialog
+/// on top of the rest of the UI: the content below the dialog is made inert,
+/// so the keyboard focus can't leave the dialog and clicks don't reach the
+/// content anymore.
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct Dialog {
+    pub title: AzString,
+    /// Text of the dialog, not shown if `content` is set
+    pub message: AzString,
+    pub icon: DialogIcon,
+    pub buttons: DialogButtons,
+    /// Current text of the input field: if set, the dialog shows a text input below the message
+    pub input: OptionAzString,
+    /// Custom DOM displayed instead of the `message`
+    pub content: OptionDom,
+    /// Called when a button is pressed or Enter / Escape are hit
+    pub on_close: OptionDialogOnClose,
+}
+
+impl Default for Dialog {
+    fn default() -> Self {
+        Self {
+            title: AzString::from_const_str(""),
+            message: AzString::from_const_str(""),
+            icon: DialogIcon::None,
+            buttons: DialogButtons::Ok,
+            input: None.into(),
+            content: None.into(),
+            on_close: None.into(),
+        }
+    }
+}
+
+struct DialogButtonLocalDataset {
+    button: DialogButton,
+    backref: RefAny, // RefAny<Dialog>
+}
+
+impl Dialog {
+
+    /// Creates a message box with an "OK" button
+    pub fn msg_box(title: AzString, message: AzString) -> Self {
+        Self {
+            title,
+            message,
+            icon: DialogIcon::Info,
+            buttons: DialogButtons::Ok,
+            .. Default::default()
+        }
+    }
+
+    /// Creates a question with "OK" / "Cancel" buttons
+    pub fn confirm(title: AzString, message: AzString) -> Self {
+        Self {
+            title,
+            message,
+            icon: DialogIcon::Question,
+            buttons: DialogButtons::OkCancel,
+            .. Default::default()
+        }
+    }
+
+    /// Creates a dialog asking for a line of text, pre-filled with `default_value`
+    pub fn prompt(title: AzString, message: AzString, default_value: AzString) -> Self {
+        Self {
+            title,
+            message,
+            buttons: DialogButtons::OkCancel,
+            input: Some(default_value).into(),
+            .. Default::default()
+        }
+    }
+
+    /// Creates a dialog displaying a custom DOM with "OK" / "Cancel" buttons
+    pub fn custom(title: AzString, content: Dom) -> Self {
+        Self {
+            title,
+            buttons: DialogButtons::OkCancel,
+            content: Some(content).into(),
+            .. Default::default()
+        }
+    }
+
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut m = Self::default();
+        core::mem::swap(&mut m, self);
+        m
+    }
+
+    pub fn with_icon(&mut self, icon: DialogIcon) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_icon(icon);
+        m
+    }
+
+    pub fn set_icon(&mut self, icon: DialogIcon) {
+        self.icon = icon;
+    }
+
+    pub fn with_buttons(&mut self, buttons: DialogButtons) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_buttons(buttons);
+        m
+    }
+
+    pub fn set_buttons(&mut self, buttons: DialogButtons) {
+        self.buttons = buttons;
+    }
+
+    pub fn with_on_close(&mut self, data: RefAny, on_close: DialogOnCloseCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_close(data, on_close);
+        m
+    }
+
+    pub fn set_on_close(&mut self, data: RefAny, on_close: DialogOnCloseCallbackType) {
+        self.on_close = Some(DialogOnClose {
+            data,
+            callback: DialogOnCloseCallback { cb: on_close }
+        }).into();
+    }
+
+    /// Renders the dialog on top of the `content`, which is made inert
+    /// (see `Dom::make_inert`) while the dialog is open
+    pub fn dom_over(self, mut content: Dom) -> Dom {
+        content.make_inert();
+        Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_HOST))
+        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(HOST_STYLE))
+        .with_children(vec![content, self.dom()].into())
+    }
+
+    /// Renders the backdrop with the dialog centered in it. The backdrop is
+    /// positioned absolutely, so it has to be the last child of a container
+    /// spanning the window - prefer `dom_over()`, which also blocks the input
+    /// to the rest of the UI.
+    pub fn dom(self) -> Dom {
+        let DialogDom { backdrop, dialog, title, body, icon, content, content_children, buttons } = self.dom_parts();
+        let mut body_children = icon.into_iter().collect::<Vec<_>>();
+        body_children.push(content.with_children(content_children.into()));
+        backdrop.with_children(vec![
+            dialog.with_children(vec![
+                title,
+                body.with_children(body_children.into()),
+                buttons,
+            ].into())
+        ].into())
+    }
+
+    // Returns the nodes of the dialog, without their children
+    // (except for the title and the buttons)
+    fn dom_parts(self) -> DialogDom {
+
+        let title = self.title.clone();
+        let message = self.message.clone();
+        let icon = self.icon;
+        let buttons = self.buttons;
+        let input = self.input.clone();
+        let mut dialog = self;
+        let content = core::mem::replace(&mut dialog.content, None.into());
+
+        let dialog_local_dataset = RefAny::new(dialog);
+
+        let mut content_children = Vec::new();
+        match content.into_option() {
+            Some(custom) => content_children.push(custom),
+            None => {
+                content_children.push(
+                    Dom::text(message)
+                    .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_MESSAGE))
+                );
+            }
+        }
+
+        if let Some(text) = input.into_option() {
+            let mut text_input = TextInput::new().with_text(text);
+            text_input.set_on_text_input(dialog_local_dataset.clone(), self::input::default_on_input_text_input);
+            text_input.set_on_virtual_key_down(dialog_local_dataset.clone(), self::input::default_on_input_virtual_key_down);
+            content_children.push(text_input.dom());
+        }
+
+        let icon = icon.get_glyph().map(|(glyph, color)| {
+            Dom::text(AzString::from_const_str(glyph))
+            .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_ICON))
+            .with_inline_css_props(
+                ICON_STYLE.iter().cloned()
+                .chain(Some(NodeDataInlineCssProperty::Normal(CssProperty::const_text_color(StyleTextColor { inner: color }))))
+                .collect::<Vec<_>>().into()
+            )
+        });
+
+        let default_button = buttons.get_default_button();
+        let button_children = buttons.get_buttons().iter().map(|button| {
+            let data = RefAny::new(DialogButtonLocalDataset {
+                button: *button,
+                backref: dialog_local_dataset.clone(),
+            });
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from_const_slice(
+                if *button == default_button { IDS_AND_CLASSES_BUTTON_DEFAULT } else { IDS_AND_CLASSES_BUTTON }
+            ))
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(BUTTON_WRAPPER_STYLE))
+            .with_children(vec![
+                Button::new(AzString::from_const_str(button.get_label()))
+                .with_on_click(data, self::input::default_on_button_click)
+                .dom()
+            ].into())
+        }).collect::<Vec<_>>();
+
+        DialogDom {
+            backdrop: Dom::div()
+                .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_BACKDROP))
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(BACKDROP_STYLE)),
+            dialog: Dom::div()
+                .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_DIALOG))
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(DIALOG_STYLE))
+                .with_callbacks(vec![CallbackData {
+                    // window-wide: Enter / Escape have to work even if nothing is focused
+                    event: EventFilter::Window(WindowEventFilter::VirtualKeyDown),
+                    data: dialog_local_dataset.clone(),
+                    callback: Callback { cb: self::input::default_on_dialog_key_down },
+                }].into()),
+            title: Dom::text(title)
+                .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_TITLE))
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TITLE_STYLE)),
+            body: Dom::div()
+                .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_BODY))
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(BODY_STYLE)),
+            icon,
+            content: Dom::div()
+                .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_CONTENT))
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(CONTENT_STYLE)),
+            content_children,
+            buttons: Dom::div()
+                .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_BUTTONS))
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(BUTTONS_STYLE))
+                .with_children(button_children.into()),
+        }
+    }
+}
+
+// nodes of a dialog: backdrop > dialog > [title, body > [icon, content > content_children], buttons]
+struct DialogDom {
+    backdrop: Dom,
+    dialog: Dom,
+    title: Dom,
+    body: Dom,
+    icon: Option<Dom>,
+    content: Dom,
+    // message (or custom content) and the text input of the dialog
+    content_children: Vec<Dom>,
+    buttons: Dom,
+}
+
+impl From<Dialog> for Dom {
+    fn from(d: Dialog) -> Dom {
+        d.dom()
+    }
+}
+
+// handle input events for the dialog
+mod input {
+
+    use azul_desktop::callbacks::{RefAny, CallbackInfo, Update, FocusTarget};
+    use azul_core::window::VirtualKeyCode;
+    use super::{
+        Dialog, DialogButton, DialogResult, DialogOnClose,
+        DialogButtonLocalDataset, TextInputState,
+        OnTextInputReturn, TextInputValid,
+    };
+
+    pub(in super) extern "C" fn default_on_button_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut button_local_dataset = match data.downcast_mut::<DialogButtonLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let button = button_local_dataset.button;
+        let mut dialog = match button_local_dataset.backref.downcast_mut::<Dialog>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        close(&mut dialog, info, button)
+    }
+
+    pub(in super) extern "C" fn default_on_dialog_key_down(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut dialog = match data.downcast_mut::<Dialog>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let keyboard_state = info.get_current_keyboard_state();
+        let keycode = match keyboard_state.current_virtual_keycode.into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        match keycode {
+            VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter => {
+                let button = dialog.buttons.get_default_button();
+                close(&mut dialog, info, button)
+            },
+            VirtualKeyCode::Escape => {
+                let button = dialog.buttons.get_cancel_button();
+                close(&mut dialog, info, button)
+            },
+            VirtualKeyCode::Tab => {
+                // the content below the dialog is inert, so the
+                // focus can only cycle between the nodes of the dialog
+                if keyboard_state.shift_down() {
+                    info.set_focus(FocusTarget::Previous);
+                } else {
+                    info.set_focus(FocusTarget::Next);
+                }
+                Update::DoNothing
+            },
+            _ => Update::DoNothing,
+        }
+    }
+
+    pub(in super) extern "C" fn default_on_input_text_input(data: &mut RefAny, _info: &mut CallbackInfo, state: &TextInputState) -> OnTextInputReturn {
+        store_input_text(data, state);
+        OnTextInputReturn { update: Update::DoNothing, valid: TextInputValid::Yes }
+    }
+
+    pub(in super) extern "C" fn default_on_input_virtual_key_down(data: &mut RefAny, _info: &mut CallbackInfo, state: &TextInputState) -> OnTextInputReturn {
+        // Backspace / Delete don't trigger on_text_input
+        store_input_text(data, state);
+        OnTextInputReturn { update: Update::DoNothing, valid: TextInputValid::Yes }
+    }
+
+    fn store_input_text(data: &mut RefAny, state: &TextInputState) {
+        if let Some(mut dialog) = data.downcast_mut::<Dialog>() {
+            dialog.input = Some(state.get_text().into()).into();
+        }
+    }
+
+    fn close(dialog: &mut Dialog, info: &mut CallbackInfo, button: DialogButton) -> Update {
+
+        let result = DialogResult {
+            button,
+            input: dialog.input.as_ref().cloned().unwrap_or_default(),
+        };
+
+        match dialog.on_close.as_mut() {
+            Some(DialogOnClose { callback, data }) => (callback.cb)(data, info, &result),
+            None => Update::DoNothing,
+        }
+    }
+}
+
+/// Renders a `<dialog title="Save" message="Save changes?" buttons="yes_no" />` XML node -
+/// if the node has child nodes, they are shown instead of the message. Like `Dialog::dom()`,
+/// the dialog has to be the last child of a container spanning the window.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DialogRenderer {
+    node: XmlNode,
+}
+
+impl DialogRenderer {
+    pub fn new() -> Self {
+        Self {
+            node: XmlNode::new("dialog"),
+        }
+    }
+}
+
+impl Default for DialogRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XmlComponentTrait for DialogRenderer {
+    fn get_type_id(&self) -> String {
+        "dialog".to_string()
+    }
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        ComponentArguments {
+            args: vec![
+                ("title".to_string(), "String".to_string()),
+                ("message".to_string(), "String".to_string()),
+                ("icon".to_string(), "none|info|warning|error|question".to_string()),
+                ("buttons".to_string(), "ok|ok_cancel|yes_no|yes_no_cancel".to_string()),
+            ],
+            accepts_text: false,
+        }
+    }
+
+    fn render_dom(
+        &self,
+        components: &XmlComponentMap,
+        args: &FilteredComponentArguments,
+        content: &XmlTextContent,
+    ) -> Result<StyledDom, RenderDomError> {
+        self.render_dom_with_children(components, args, content, Vec::new())
+    }
+
+    fn render_dom_with_children(
+        &self,
+        _: &XmlComponentMap,
+        args: &FilteredComponentArguments,
+        _: &XmlTextContent,
+        children: Vec<StyledDom>,
+    ) -> Result<StyledDom, RenderDomError> {
+        let icon = match get_xml_string_arg(args, "icon").as_ref().map(|s| s.as_str()) {
+            Some("info") => DialogIcon::Info,
+            Some("warning") => DialogIcon::Warning,
+            Some("error") => DialogIcon::Error,
+            Some("question") => DialogIcon::Question,
+            _ => DialogIcon::None,
+        };
+        let buttons = match get_xml_string_arg(args, "buttons").as_ref().map(|s| s.as_str()) {
+            Some("ok_cancel") => DialogButtons::OkCancel,
+            Some("yes_no") => DialogButtons::YesNo,
+            Some("yes_no_cancel") => DialogButtons::YesNoCancel,
+            _ => DialogButtons::Ok,
+        };
+        let dialog = Dialog {
+            title: get_xml_string_arg(args, "title").unwrap_or_default(),
+            message: get_xml_string_arg(args, "message").unwrap_or_default(),
+            icon,
+            buttons,
+            .. Default::default()
+        };
+
+        let DialogDom { mut backdrop, mut dialog, mut title, mut body, icon, mut content, content_children, mut buttons } = dialog.dom_parts();
+
+        let mut content = content.style(Css::empty());
+        if children.is_empty() {
+            for mut child in content_children {
+                content.append_child(child.style(Css::empty()));
+            }
+        } else {
+            for child in children {
+                content.append_child(child);
+            }
+        }
+
+        let mut body = body.style(Css::empty());
+        if let Some(mut icon) = icon {
+            body.append_child(icon.style(Css::empty()));
+        }
+        body.append_child(content);
+
+        let mut dialog = dialog.style(Css::empty());
+        dialog.append_child(title.style(Css::empty()));
+        dialog.append_child(body);
+        dialog.append_child(buttons.style(Css::empty()));
+
+        let mut backdrop = backdrop.style(Css::empty());
+        backdrop.append_child(dialog);
+        Ok(backdrop)
+    }
+
+    fn compile_to_rust_code(
+        &self,
+        _: &XmlComponentMap,
+        _: &ComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<String, CompileError> {
+        Ok(String::from(
+            "Dialog {\r\n\
+                title: title.into(),\r\n\
+                message: message.into(),\r\n\
+                icon: match icon.as_str() {\r\n\
+                    \"info\" => DialogIcon::Info,\r\n\
+                    \"warning\" => DialogIcon::Warning,\r\n\
+                    \"error\" => DialogIcon::Error,\r\n\
+                    \"question\" => DialogIcon::Question,\r\n\
+                    _ => DialogIcon::None,\r\n\
+                },\r\n\
+                buttons: match buttons.as_str() {\r\n\
+                    \"ok_cancel\" => DialogButtons::OkCancel,\r\n\
+                    \"yes_no\" => DialogButtons::YesNo,\r\n\
+                    \"yes_no_cancel\" => DialogButtons::YesNoCancel,\r\n\
+                    _ => DialogButtons::Ok,\r\n\
+                },\r\n\
+                .. Default::default()\r\n\
+            }.dom()"
+        ))
+    }
+
+    fn get_xml_node(&self) -> XmlNode {
+        self.node.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_dialog_default_and_cancel_buttons() {
+        assert_eq!(DialogButtons::Ok.get_default_button(), DialogButton::Ok);
+        assert_eq!(DialogButtons::Ok.get_cancel_button(), DialogButton::Ok);
+        assert_eq!(DialogButtons::OkCancel.get_default_button(), DialogButton::Ok);
+        assert_eq!(DialogButtons::OkCancel.get_cancel_button(), DialogButton::Cancel);
+        assert_eq!(DialogButtons::YesNo.get_default_button(), DialogButton::Yes);
+        assert_eq!(DialogButtons::YesNo.get_cancel_button(), DialogButton::No);
+        assert_eq!(DialogButtons::YesNoCancel.get_buttons(), &[DialogButton::Yes, DialogButton::No, DialogButton::Cancel]);
+        assert_eq!(DialogButtons::YesNoCancel.get_cancel_button(), DialogButton::Cancel);
+
+        let result = DialogResult { button: DialogButton::Yes, input: AzString::from_const_str("") };
+        assert!(result.is_accepted());
+        let result = DialogResult { button: DialogButton::Cancel, input: AzString::from_const_str("") };
+        assert!(!result.is_accepted());
+    }
+
+    #[test]
+    fn test_dialog_over_list_view_makes_rows_inert() {
+        use azul_core::callbacks::{HidpiAdjustedBounds, IFrameCallbackInfo};
+        use azul_core::styled_dom::StyledDom;
+        use azul_core::window::{LogicalPosition, LogicalSize, WindowTheme};
+        use azul_desktop::resources::ImageCache;
+        use crate::widgets::list_view::{ListView, ListViewRow};
+        use rust_fontconfig::FcFontCache;
+
+        // invokes the callback of the first IFrame in the DOM, like the layout solver does
+        fn render_iframe(dom: &mut Dom) -> Option<StyledDom> {
+            if let Some(iframe) = dom.root.get_iframe_node() {
+                let fc_cache = FcFontCache::default();
+                let image_cache = ImageCache::new();
+                let size = LogicalSize::new(400.0, 300.0);
+                let mut info = IFrameCallbackInfo::new(
+                    &fc_cache,
+                    &image_cache,
+                    WindowTheme::LightMode,
+                    HidpiAdjustedBounds::from_bounds(LayoutSize::new(400, 300), 1.0),
+                    size,
+                    LogicalPosition::zero(),
+                    size,
+                    LogicalPosition::zero(),
+                );
+                return Some((iframe.callback.cb)(&mut iframe.data, &mut info).dom);
+            }
+            dom.children.as_mut().iter_mut().find_map(render_iframe)
+        }
+
+        fn is_interactive(styled_dom: &StyledDom) -> bool {
+            styled_dom.node_data.as_ref().iter().any(|n| !n.get_callbacks().is_empty() || n.get_tab_index().is_some()) ||
+            styled_dom.tag_ids_to_node_ids.as_ref().iter().any(|t| t.tab_index.is_some())
+        }
+
+        let list_view = || {
+            ListView::new(vec![AzString::from_const_str("Name")].into())
+            .with_rows((0..5).map(|_| ListViewRow {
+                cells: vec![Dom::text("cell")].into(),
+                height: None.into(),
+                is_selected: false,
+            }).collect::<Vec<_>>().into())
+            .dom()
+        };
+
+        // the rows of the ListView are rendered in an IFrame and can be clicked ...
+        let rows = render_iframe(&mut list_view()).unwrap();
+        assert!(is_interactive(&rows));
+
+        // ... unless the ListView is below a dialog
+        let mut dom = Dialog::msg_box(AzString::from_const_str("Title"), AzString::from_const_str("Message"))
+            .dom_over(list_view());
+        let rows = render_iframe(&mut dom).unwrap();
+        assert!(rows.node_data.len() > 1);
+        assert!(!is_interactive(&rows));
+    }
+}
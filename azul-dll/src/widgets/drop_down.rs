@@ -1,3 +1,9 @@
+use azul_desktop::xml::{
+    XmlComponentTrait, XmlComponentMap, XmlNode, XmlTextContent,
+    ComponentArguments, FilteredComponentArguments,
+    RenderDomError, CompileError,
+};
+use super::{parse_xml_arg, parse_xml_list, parse_xml_number};
 use azul_core::{callbacks::{
         Callback, FocusTarget,
         MarshaledLayoutCallback,
@@ -271,6 +277,7 @@ impl DropDown {
     }
 
     pub fn dom(self) -> Dom {
+        let selected_text = self.choices.as_ref().get(self.selected).cloned().unwrap_or_default();
         let data = RefAny::new(self);
 
         Dom::div()
@@ -308,7 +315,7 @@ impl DropDown {
                     IdOrClassVec::from_const_slice(IDS_AND_CLASSES_11862789041977911489)
                 })
                 .with_children(DomVec::from_vec(vec![
-                    Dom::text(AzString::from_const_str("Checkbox"))
+                    Dom::text(selected_text)
                     .with_inline_css_props(CSS_MATCH_16432538576103237591)
                 ])),
                 Dom::div()
@@ -513,4 +520,74 @@ fn close_choice_window(_: &mut RefAny, info: &mut CallbackInfo) -> Update {
     flags.is_about_to_close = true;
     info.set_window_flags(flags);
     Update::RefreshDom
-}
\ No newline at end of file
+}
+
+/// Renders a `<drop-down choices="A, B, C" selected="1" />` XML node
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DropDownRenderer {
+    node: XmlNode,
+}
+
+impl DropDownRenderer {
+    pub fn new() -> Self {
+        Self {
+            node: XmlNode::new("drop_down"),
+        }
+    }
+}
+
+impl Default for DropDownRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XmlComponentTrait for DropDownRenderer {
+    fn get_type_id(&self) -> String {
+        "drop_down".to_string()
+    }
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        ComponentArguments {
+            args: vec![
+                ("choices".to_string(), "[String]".to_string()),
+                ("selected".to_string(), "usize".to_string()),
+            ],
+            accepts_text: false,
+        }
+    }
+
+    fn render_dom(
+        &self,
+        _: &XmlComponentMap,
+        args: &FilteredComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<StyledDom, RenderDomError> {
+        let drop_down = DropDown {
+            choices: parse_xml_arg(args, "choices", "[String]", parse_xml_list)?.unwrap_or_default(),
+            selected: parse_xml_arg(args, "selected", "usize", parse_xml_number)?.unwrap_or(0),
+            on_choice_change: None.into(),
+        };
+        Ok(drop_down.dom().style(Css::empty()))
+    }
+
+    fn compile_to_rust_code(
+        &self,
+        _: &XmlComponentMap,
+        _: &ComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<String, CompileError> {
+        Ok(String::from(
+            "DropDown {\r\n\
+                choices: StringVec::from_vec(choices.into_iter().map(AzString::from).collect()),\r\n\
+                selected,\r\n\
+                on_choice_change: None.into(),\r\n\
+            }\r\n\
+            .dom()"
+        ))
+    }
+
+    fn get_xml_node(&self) -> XmlNode {
+        self.node.clone()
+    }
+}
@@ -1,6 +1,13 @@
 //! File input button, same as `Button`, but selects and
 //! opens a file dialog instead
 
+use azul_desktop::xml::{
+    XmlComponentTrait, XmlComponentMap, XmlNode, XmlTextContent,
+    ComponentArguments, FilteredComponentArguments,
+    RenderDomError, CompileError,
+};
+use azul_desktop::styled_dom::StyledDom;
+use super::get_xml_string_arg;
 use azul_desktop::{
     css::*,
     css::AzString,
@@ -200,3 +207,69 @@ extern "C" fn fileinput_on_click(data: &mut RefAny, info: &mut CallbackInfo) ->
 
     result
 }
+
+/// Renders a `<file-input path="./file.txt" default_text="Select File..." />` XML node
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FileInputRenderer {
+    node: XmlNode,
+}
+
+impl FileInputRenderer {
+    pub fn new() -> Self {
+        Self {
+            node: XmlNode::new("file_input"),
+        }
+    }
+}
+
+impl Default for FileInputRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XmlComponentTrait for FileInputRenderer {
+    fn get_type_id(&self) -> String {
+        "file_input".to_string()
+    }
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        ComponentArguments {
+            args: vec![
+                ("path".to_string(), "String".to_string()),
+                ("default_text".to_string(), "String".to_string()),
+            ],
+            accepts_text: false,
+        }
+    }
+
+    fn render_dom(
+        &self,
+        _: &XmlComponentMap,
+        args: &FilteredComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<StyledDom, RenderDomError> {
+        let mut file_input = FileInput::new(get_xml_string_arg(args, "path").into());
+        if let Some(default_text) = get_xml_string_arg(args, "default_text") {
+            file_input.set_default_text(default_text);
+        }
+        Ok(file_input.dom().style(Css::empty()))
+    }
+
+    fn compile_to_rust_code(
+        &self,
+        _: &XmlComponentMap,
+        _: &ComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<String, CompileError> {
+        Ok(String::from(
+            "FileInput::new(Some(AzString::from(path)).into())\r\n\
+            .with_default_text(default_text.into())\r\n\
+            .dom()"
+        ))
+    }
+
+    fn get_xml_node(&self) -> XmlNode {
+        self.node.clone()
+    }
+}
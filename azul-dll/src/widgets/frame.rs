@@ -8,6 +8,13 @@ use azul_desktop::dom::{
     NodeDataInlineCssProperty,
     DomVec, IdOrClassVec, NodeDataInlineCssPropertyVec,
 };
+use azul_desktop::xml::{
+    XmlComponentTrait, XmlComponentMap, XmlNode, XmlTextContent,
+    ComponentArguments, FilteredComponentArguments,
+    RenderDomError, CompileError,
+};
+use azul_desktop::styled_dom::StyledDom;
+use super::{get_xml_string_arg, parse_xml_arg, parse_xml_number};
 
 const STRING_16146701490593874959: AzString = AzString::from_const_str("sans-serif");
 const STYLE_BACKGROUND_CONTENT_11062356617965867290_ITEMS: &[StyleBackgroundContent] = &[
@@ -104,85 +111,171 @@ impl Frame {
     }
 
     pub fn dom(self) -> Dom {
+        frame_dom(self.title)
+        .with_child(frame_content_dom(self.flex_grow).with_child(self.content))
+    }
+}
+
+// frame with the title, the content is appended as the last child
+fn frame_dom(title: AzString) -> Dom {
+    Dom::div()
+    .with_inline_css_props(CSS_MATCH_8602559445190067154)
+    .with_ids_and_classes({
+        const IDS_AND_CLASSES_14615537625743340639: &[IdOrClass] = &[
+            Class(AzString::from_const_str("__azul-native-frame")),
+        ];
+        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_14615537625743340639)
+    })
+    .with_children(DomVec::from_vec(vec![
         Dom::div()
-        .with_inline_css_props(CSS_MATCH_8602559445190067154)
+        .with_inline_css_props(CSS_MATCH_16739370686243728873)
         .with_ids_and_classes({
-            const IDS_AND_CLASSES_14615537625743340639: &[IdOrClass] = &[
-                Class(AzString::from_const_str("__azul-native-frame")),
+            const IDS_AND_CLASSES_17776797146874875377: &[IdOrClass] = &[
+                Class(AzString::from_const_str("__azul-native-frame-header")),
+
             ];
-            IdOrClassVec::from_const_slice(IDS_AND_CLASSES_14615537625743340639)
+            IdOrClassVec::from_const_slice(IDS_AND_CLASSES_17776797146874875377)
         })
         .with_children(DomVec::from_vec(vec![
             Dom::div()
-            .with_inline_css_props(CSS_MATCH_16739370686243728873)
+            .with_inline_css_props(CSS_MATCH_15775557796860201720)
             .with_ids_and_classes({
-                const IDS_AND_CLASSES_17776797146874875377: &[IdOrClass] = &[
-                    Class(AzString::from_const_str("__azul-native-frame-header")),
-
+                const IDS_AND_CLASSES_15264202958442287530: &[IdOrClass] = &[
+                    Class(AzString::from_const_str("__azul-native-frame-header-before")),
                 ];
-                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_17776797146874875377)
+                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_15264202958442287530)
             })
             .with_children(DomVec::from_vec(vec![
                 Dom::div()
-                .with_inline_css_props(CSS_MATCH_15775557796860201720)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_15264202958442287530: &[IdOrClass] = &[
-                        Class(AzString::from_const_str("__azul-native-frame-header-before")),
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_15264202958442287530)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                ])),
-                Dom::text(self.title)
-                .with_inline_css_props(CSS_MATCH_4236783900531286611),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_9156589477016488419)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_5689091102265932280: &[IdOrClass] = &[
-                        Class(AzString::from_const_str("__azul-native-frame-header-after")),
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_5689091102265932280)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                ]))
             ])),
+            Dom::text(title)
+            .with_inline_css_props(CSS_MATCH_4236783900531286611),
             Dom::div()
-            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_vec(vec![
-                // .__azul-native-frame .__azul-native-frame-content
-                NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow::new(self.flex_grow)))),
-                NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(5) }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(5) }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(5) }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(5) }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(11) }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS)))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BorderTopWidth(LayoutBorderTopWidthValue::None)),
-                NodeDataInlineCssProperty::Normal(CssProperty::BorderTopStyle(StyleBorderTopStyleValue::None)),
-                NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::None)),
-                NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BorderTopWidth(LayoutBorderTopWidthValue::Exact(LayoutBorderTopWidth { inner: PixelValue::const_px(1) }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Solid }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftStyle(StyleBorderLeftStyleValue::Exact(StyleBorderLeftStyle { inner: BorderStyle::Solid }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Solid }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BorderTopStyle(StyleBorderTopStyleValue::Exact(StyleBorderTopStyle { inner: BorderStyle::Solid }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 221, g: 221, b: 221, a: 255 } }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 221, g: 221, b: 221, a: 255 } }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 221, g: 221, b: 221, a: 255 } }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 221, g: 221, b: 221, a: 255 } })))
-            ]))
+            .with_inline_css_props(CSS_MATCH_9156589477016488419)
             .with_ids_and_classes({
-                const IDS_AND_CLASSES_9898887665724137124: &[IdOrClass] = &[
-                    Class(AzString::from_const_str("__azul-native-frame-content")),
+                const IDS_AND_CLASSES_5689091102265932280: &[IdOrClass] = &[
+                    Class(AzString::from_const_str("__azul-native-frame-header-after")),
                 ];
-                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_9898887665724137124)
+                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_5689091102265932280)
             })
-            .with_children(vec![
-                self.content
-            ].into())
+            .with_children(DomVec::from_vec(vec![
+                Dom::div()
+            ]))
         ]))
+    ]))
+}
+
+// bordered container around the content of the frame
+fn frame_content_dom(flex_grow: f32) -> Dom {
+    Dom::div()
+    .with_inline_css_props(NodeDataInlineCssPropertyVec::from_vec(vec![
+        // .__azul-native-frame .__azul-native-frame-content
+        NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow::new(flex_grow)))),
+        NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(5) }))),
+        NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(5) }))),
+        NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(5) }))),
+        NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(5) }))),
+        NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(11) }))),
+        NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS)))),
+        NodeDataInlineCssProperty::Normal(CssProperty::BorderTopWidth(LayoutBorderTopWidthValue::None)),
+        NodeDataInlineCssProperty::Normal(CssProperty::BorderTopStyle(StyleBorderTopStyleValue::None)),
+        NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::None)),
+        NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
+        NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
+        NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
+        NodeDataInlineCssProperty::Normal(CssProperty::BorderTopWidth(LayoutBorderTopWidthValue::Exact(LayoutBorderTopWidth { inner: PixelValue::const_px(1) }))),
+        NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Solid }))),
+        NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftStyle(StyleBorderLeftStyleValue::Exact(StyleBorderLeftStyle { inner: BorderStyle::Solid }))),
+        NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Solid }))),
+        NodeDataInlineCssProperty::Normal(CssProperty::BorderTopStyle(StyleBorderTopStyleValue::Exact(StyleBorderTopStyle { inner: BorderStyle::Solid }))),
+        NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 221, g: 221, b: 221, a: 255 } }))),
+        NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 221, g: 221, b: 221, a: 255 } }))),
+        NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 221, g: 221, b: 221, a: 255 } }))),
+        NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 221, g: 221, b: 221, a: 255 } })))
+    ]))
+    .with_ids_and_classes({
+        const IDS_AND_CLASSES_9898887665724137124: &[IdOrClass] = &[
+            Class(AzString::from_const_str("__azul-native-frame-content")),
+        ];
+        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_9898887665724137124)
+    })
+}
+
+/// Renders a `<frame title="Settings">...</frame>` XML node,
+/// the child nodes are placed inside of the frame
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FrameRenderer {
+    node: XmlNode,
+}
+
+impl FrameRenderer {
+    pub fn new() -> Self {
+        Self {
+            node: XmlNode::new("frame"),
+        }
+    }
+}
+
+impl Default for FrameRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XmlComponentTrait for FrameRenderer {
+    fn get_type_id(&self) -> String {
+        "frame".to_string()
+    }
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        ComponentArguments {
+            args: vec![
+                ("title".to_string(), "String".to_string()),
+                ("flex_grow".to_string(), "f32".to_string()),
+            ],
+            accepts_text: false,
+        }
+    }
+
+    fn render_dom(
+        &self,
+        components: &XmlComponentMap,
+        args: &FilteredComponentArguments,
+        content: &XmlTextContent,
+    ) -> Result<StyledDom, RenderDomError> {
+        self.render_dom_with_children(components, args, content, Vec::new())
     }
-}
\ No newline at end of file
+
+    fn render_dom_with_children(
+        &self,
+        _: &XmlComponentMap,
+        args: &FilteredComponentArguments,
+        _: &XmlTextContent,
+        children: Vec<StyledDom>,
+    ) -> Result<StyledDom, RenderDomError> {
+        let title = get_xml_string_arg(args, "title").unwrap_or_default();
+        let flex_grow = parse_xml_arg(args, "flex_grow", "f32", parse_xml_number)?.unwrap_or(0.0);
+
+        let mut content = frame_content_dom(flex_grow).style(Css::empty());
+        for child in children {
+            content.append_child(child);
+        }
+
+        let mut frame = frame_dom(title).style(Css::empty());
+        frame.append_child(content);
+        Ok(frame)
+    }
+
+    fn compile_to_rust_code(
+        &self,
+        _: &XmlComponentMap,
+        _: &ComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<String, CompileError> {
+        Ok(String::from("Frame { title: title.into(), flex_grow, content: Dom::div() }.dom()"))
+    }
+
+    fn get_xml_node(&self) -> XmlNode {
+        self.node.clone()
+    }
+}
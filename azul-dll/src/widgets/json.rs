@@ -0,0 +1,282 @@
+use alloc::vec::Vec;
+use alloc::string::String;
+use azul_desktop::css::ColorU;
+
+// nesting limit, so that malicious input can't overflow the stack
+const MAX_DEPTH: usize = 64;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+
+    pub(super) fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            JsonValue::Object(o) => o.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_f64(&self) -> Option<f64> {
+        match self {
+            JsonValue::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_u64(&self) -> Option<u64> {
+        match self {
+            JsonValue::Number(n) if *n >= 0.0 && n.fract() == 0.0 => Some(*n as u64),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_bool(&self) -> Option<bool> {
+        match self {
+            JsonValue::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_str(&self) -> Option<&str> {
+        match self {
+            JsonValue::String(s) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_array(&self) -> Option<&[JsonValue]> {
+        match self {
+            JsonValue::Array(a) => Some(a.as_slice()),
+            _ => None,
+        }
+    }
+}
+
+/// Parses a JSON document, returns None on any syntax error
+pub(super) fn parse(input: &str) -> Option<JsonValue> {
+    let mut parser = Parser { input: input.as_bytes(), pos: 0 };
+    let value = parser.parse_value(0)?;
+    parser.skip_whitespace();
+    if parser.pos == parser.input.len() { Some(value) } else { None }
+}
+
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.input.get(self.pos) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, s: &[u8]) -> Option<()> {
+        if self.input[self.pos..].starts_with(s) {
+            self.pos += s.len();
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn parse_value(&mut self, depth: usize) -> Option<JsonValue> {
+
+        if depth > MAX_DEPTH {
+            return None;
+        }
+
+        self.skip_whitespace();
+
+        match *self.input.get(self.pos)? {
+            b'n' => self.expect(b"null").map(|_| JsonValue::Null),
+            b't' => self.expect(b"true").map(|_| JsonValue::Bool(true)),
+            b'f' => self.expect(b"false").map(|_| JsonValue::Bool(false)),
+            b'"' => self.parse_string().map(JsonValue::String),
+            b'[' => {
+                self.pos += 1;
+                let mut items = Vec::new();
+                self.skip_whitespace();
+                if self.input.get(self.pos) == Some(&b']') {
+                    self.pos += 1;
+                    return Some(JsonValue::Array(items));
+                }
+                loop {
+                    items.push(self.parse_value(depth + 1)?);
+                    self.skip_whitespace();
+                    match *self.input.get(self.pos)? {
+                        b',' => self.pos += 1,
+                        b']' => { self.pos += 1; return Some(JsonValue::Array(items)); },
+                        _ => return None,
+                    }
+                }
+            },
+            b'{' => {
+                self.pos += 1;
+                let mut items = Vec::new();
+                self.skip_whitespace();
+                if self.input.get(self.pos) == Some(&b'}') {
+                    self.pos += 1;
+                    return Some(JsonValue::Object(items));
+                }
+                loop {
+                    self.skip_whitespace();
+                    if self.input.get(self.pos) != Some(&b'"') {
+                        return None;
+                    }
+                    let key = self.parse_string()?;
+                    self.skip_whitespace();
+                    self.expect(b":")?;
+                    items.push((key, self.parse_value(depth + 1)?));
+                    self.skip_whitespace();
+                    match *self.input.get(self.pos)? {
+                        b',' => self.pos += 1,
+                        b'}' => { self.pos += 1; return Some(JsonValue::Object(items)); },
+                        _ => return None,
+                    }
+                }
+            },
+            b'-' | b'0'..=b'9' => {
+                let start = self.pos;
+                while let Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9') = self.input.get(self.pos) {
+                    self.pos += 1;
+                }
+                let s = core::str::from_utf8(&self.input[start..self.pos]).ok()?;
+                s.parse::<f64>().ok().filter(|f| f.is_finite()).map(JsonValue::Number)
+            },
+            _ => None,
+        }
+    }
+
+    // parses a quoted string, self.pos is at the opening quote
+    fn parse_string(&mut self) -> Option<String> {
+
+        self.pos += 1;
+        let mut bytes = Vec::new();
+
+        loop {
+            match *self.input.get(self.pos)? {
+                b'"' => {
+                    self.pos += 1;
+                    return String::from_utf8(bytes).ok();
+                },
+                b'\\' => {
+                    let escaped = *self.input.get(self.pos + 1)?;
+                    self.pos += 2;
+                    let c = match escaped {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => {
+                            let high = self.parse_hex4()?;
+                            if (0xD800..0xDC00).contains(&high) {
+                                // surrogate pair
+                                self.expect(b"\\u")?;
+                                let low = self.parse_hex4()?;
+                                if !(0xDC00..0xE000).contains(&low) {
+                                    return None;
+                                }
+                                char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))?
+                            } else {
+                                char::from_u32(high)?
+                            }
+                        },
+                        _ => return None,
+                    };
+                    let mut buf = [0; 4];
+                    bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+                },
+                b if b < 0x20 => return None,
+                b => {
+                    bytes.push(b);
+                    self.pos += 1;
+                },
+            }
+        }
+    }
+
+    // parses the four hex digits of a "\uXXXX" escape
+    fn parse_hex4(&mut self) -> Option<u32> {
+        let digits = self.input.get(self.pos..(self.pos + 4))?;
+        let value = u32::from_str_radix(core::str::from_utf8(digits).ok()?, 16).ok()?;
+        self.pos += 4;
+        Some(value)
+    }
+}
+
+/// Writes a quoted and escaped JSON string
+pub(super) fn write_str(out: &mut String, s: &str) {
+    use core::fmt::Write;
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => { let _ = write!(out, "\\u{:04x}", c as u32); },
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Formats a float as a JSON number (JSON has no NaN / infinity)
+pub(super) fn f32_str(f: f32) -> String {
+    if f.is_finite() { format!("{}", f) } else { String::from("0") }
+}
+
+/// Parses a "#rrggbbaa" color, as written by `ColorU::to_hash`
+pub(super) fn parse_color(s: &str) -> Option<ColorU> {
+    let hex = s.strip_prefix('#')?;
+    if hex.len() != 8 || !hex.is_ascii() {
+        return None;
+    }
+    let c = |i: usize| u8::from_str_radix(&hex[i..(i + 2)], 16).ok();
+    Some(ColorU { r: c(0)?, g: c(2)?, b: c(4)?, a: c(6)? })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_json_parse() {
+        let v = parse(r#" { "a" : [1, -2.5e1, true, null], "b": "x\"\\\n\u00e4\ud83d\ude00" } "#).unwrap();
+        let a = v.get("a").and_then(|a| a.as_array()).unwrap();
+        assert_eq!(a[0].as_u64(), Some(1));
+        assert_eq!(a[1].as_f64(), Some(-25.0));
+        assert_eq!(a[2].as_bool(), Some(true));
+        assert_eq!(a[3], JsonValue::Null);
+        assert_eq!(v.get("b").and_then(|b| b.as_str()), Some("x\"\\\n\u{e4}\u{1F600}"));
+
+        assert!(parse("").is_none());
+        assert!(parse("{\"a\":1,}").is_none());
+        assert!(parse("[1, 2").is_none());
+        assert!(parse("\"\\ud83d\"").is_none());
+        assert!(parse("{} x").is_none());
+        assert!(parse(&"[".repeat(1000)).is_none());
+
+        let mut s = String::new();
+        write_str(&mut s, "tab\tquote\"\u{1}");
+        assert_eq!(s, "\"tab\\tquote\\\"\\u0001\"");
+        assert_eq!(parse(&s).and_then(|v| v.as_str().map(|s| s.to_string())).as_deref(), Some("tab\tquote\"\u{1}"));
+    }
+}
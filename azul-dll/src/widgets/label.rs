@@ -1,3 +1,9 @@
+use azul_desktop::xml::{
+    XmlComponentTrait, XmlComponentMap, XmlNode, XmlTextContent,
+    ComponentArguments, FilteredComponentArguments,
+    RenderDomError, CompileError,
+};
+use azul_desktop::styled_dom::StyledDom;
 use azul_desktop::{
     dom::{
         Dom, NodeDataInlineCssProperty, NodeDataInlineCssPropertyVec,
@@ -103,4 +109,60 @@ impl From<Label> for Dom {
     fn from(l: Label) -> Dom {
         l.dom()
     }
-}
\ No newline at end of file
+}
+
+/// Renders a `<label>Text</label>` XML node
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LabelRenderer {
+    node: XmlNode,
+}
+
+impl LabelRenderer {
+    pub fn new() -> Self {
+        Self {
+            node: XmlNode::new("label"),
+        }
+    }
+}
+
+impl Default for LabelRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XmlComponentTrait for LabelRenderer {
+    fn get_type_id(&self) -> String {
+        "label".to_string()
+    }
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        ComponentArguments {
+            args: Vec::new(),
+            accepts_text: true,
+        }
+    }
+
+    fn render_dom(
+        &self,
+        _: &XmlComponentMap,
+        _: &FilteredComponentArguments,
+        text: &XmlTextContent,
+    ) -> Result<StyledDom, RenderDomError> {
+        let text = text.as_ref().cloned().unwrap_or_default();
+        Ok(Label::new(text).dom().style(Css::empty()))
+    }
+
+    fn compile_to_rust_code(
+        &self,
+        _: &XmlComponentMap,
+        _: &ComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<String, CompileError> {
+        Ok(String::from("Label::new(text).dom()"))
+    }
+
+    fn get_xml_node(&self) -> XmlNode {
+        self.node.clone()
+    }
+}
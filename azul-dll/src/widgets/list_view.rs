@@ -1,3 +1,10 @@
+use azul_desktop::xml::{
+    XmlComponentTrait, XmlComponentMap, XmlNode, XmlTextContent,
+    ComponentArguments, FilteredComponentArguments,
+    RenderDomError, CompileError,
+};
+use azul_desktop::styled_dom::StyledDom;
+use super::{parse_xml_arg, parse_xml_bool, parse_xml_list, parse_xml_number};
 use alloc::vec::Vec;
 use azul_desktop::css::*;
 use azul_desktop::css::AzString;
@@ -7,13 +14,16 @@ use azul_core::window::{
 };
 use azul_desktop::gl::OptionUsize;
 use azul_desktop::callbacks::{
-    CallbackInfo, RefAny, Update
+    CallbackInfo, RefAny, Update, Callback, DomNodeId,
+    IFrameCallbackInfo, IFrameCallbackReturn,
 };
 use azul_desktop::dom::{
     Dom, IdOrClass, TabIndex,
     IdOrClass::{Id, Class},
     NodeDataInlineCssProperty,
     DomVec, IdOrClassVec, NodeDataInlineCssPropertyVec,
+    CallbackData, EventFilter, HoverEventFilter,
+    FocusEventFilter, WindowEventFilter,
 };
 
 const STRING_16146701490593874959: AzString = AzString::from_const_str("sans-serif");
@@ -415,6 +425,119 @@ const IDS_AND_CLASSES_18330792117162403422: &[IdOrClass] = &[
 const COLUMN_NAME_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_18330792117162403422);
 
 
+const IDS_AND_CLASSES_5407283618361838446: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul_native-list-header-dragwidth")),
+];
+const DRAGWIDTH_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_5407283618361838446);
+
+const IDS_AND_CLASSES_14591931524950217425: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul_native-list-header-dragwidth-drag")),
+];
+const DRAGWIDTH_DRAG_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_14591931524950217425);
+
+const IDS_AND_CLASSES_7130373893632716387: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul_native-list-header-arrow-down")),
+];
+const ARROW_DOWN_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_7130373893632716387);
+
+const IDS_AND_CLASSES_2541585316245906744: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul_native-list-header-arrow-up")),
+];
+const ARROW_UP_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_2541585316245906744);
+
+const IDS_AND_CLASSES_11734474213929620593: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul_native-list-header-arrow-down-inner")),
+];
+const ARROW_INNER_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_11734474213929620593);
+
+const IDS_AND_CLASSES_16307785893338467411: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul_native-list-header-arrow-down-inner-deco")),
+];
+const ARROW_INNER_DECO_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_16307785893338467411);
+
+const IDS_AND_CLASSES_1893400117618307326: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul_native-list-rows-row")),
+    Class(AzString::from_const_str("selected")),
+];
+const ROW_SELECTED_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_1893400117618307326);
+
+const IDS_AND_CLASSES_12883512440458093014: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul_native-list-scrollbar")),
+];
+const SCROLLBAR_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_12883512440458093014);
+
+const IDS_AND_CLASSES_6629460306371962195: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul_native-list-scrollbar-thumb")),
+];
+const SCROLLBAR_THUMB_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_6629460306371962195);
+
+// same as .__azul_native-list-header-arrow-down, but rotated by 180 degrees
+const STYLE_TRANSFORM_ARROW_UP_ITEMS: &[StyleTransform] = &[
+    StyleTransform::Translate(StyleTransformTranslate2D { x: PixelValue::const_px(7), y: PixelValue::const_px(0) }),
+    StyleTransform::Rotate(AngleValue::const_deg(180)),
+];
+static ARROW_UP_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_transform(StyleTransformVec::from_const_slice(STYLE_TRANSFORM_ARROW_UP_ITEMS))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(3))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(3))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_top(LayoutPaddingTop::const_px(3))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_bottom(LayoutPaddingBottom::const_px(3))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_justify_content(LayoutJustifyContent::Center)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+];
+
+// .__azul_native-list-header-dragwidth-drag, but spanning the full height of the header
+static DRAG_HANDLE_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_width(LayoutWidth::const_px(4))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_right(LayoutRight::const_px(0))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_top(LayoutTop::const_px(0))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_bottom(LayoutBottom::const_px(0))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_cursor(StyleCursor::ColResize)),
+];
+
+const SCROLLBAR_TRACK_BACKGROUND: &[StyleBackgroundContent] = &[
+    StyleBackgroundContent::Color(ColorU { r: 240, g: 240, b: 240, a: 255 })
+];
+const SCROLLBAR_THUMB_BACKGROUND: &[StyleBackgroundContent] = &[
+    StyleBackgroundContent::Color(ColorU { r: 193, g: 193, b: 193, a: 255 })
+];
+
+static SCROLLBAR_TRACK_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_width(LayoutWidth::const_px(SCROLLBAR_WIDTH))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_shrink(LayoutFlexShrink { inner: FloatValue::const_new(0) })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SCROLLBAR_TRACK_BACKGROUND))),
+];
+
+static ROWS_BODY_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_grow(LayoutFlexGrow { inner: FloatValue::const_new(1) })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+];
+
+static ROWS_CONTAINER_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_grow(LayoutFlexGrow { inner: FloatValue::const_new(1) })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+];
+
+/// Width of a column if `column_widths` has no entry for it
+const DEFAULT_COLUMN_WIDTH: f32 = 100.0;
+/// Columns can't be resized to less than this width
+const MIN_COLUMN_WIDTH: f32 = 20.0;
+/// Default height of a row in pixels
+const DEFAULT_ROW_HEIGHT: f32 = 20.0;
+/// Width of the vertical scrollbar
+const SCROLLBAR_WIDTH: isize = 10;
+/// Minimum height of the scrollbar thumb
+const MIN_SCROLLBAR_THUMB_HEIGHT: f32 = 20.0;
+/// How many rows to scroll per mouse wheel "notch"
+const ROWS_PER_SCROLL_NOTCH: f32 = 3.0;
+
 pub type ListViewOnLazyLoadScrollCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &ListViewState) -> Update;
 impl_callback!(ListViewOnLazyLoadScroll, OptionListViewOnLazyLoadScroll, ListViewOnLazyLoadScrollCallback, ListViewOnLazyLoadScrollCallbackType);
 
@@ -424,6 +547,28 @@ impl_callback!(ListViewOnColumnClick, OptionListViewOnColumnClick, ListViewOnCol
 pub type ListViewOnRowClickCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &ListViewState, row_clicked: usize) -> Update;
 impl_callback!(ListViewOnRowClick, OptionListViewOnRowClick, ListViewOnRowClickCallback, ListViewOnRowClickCallbackType);
 
+pub type ListViewOnRowSelectCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &ListViewState, row: usize, change: ListViewSelectionChange) -> Update;
+impl_callback!(ListViewOnRowSelect, OptionListViewOnRowSelect, ListViewOnRowSelectCallback, ListViewOnRowSelectCallbackType);
+
+pub type ListViewOnColumnResizeCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &ListViewState, column: usize, new_width: f32) -> Update;
+impl_callback!(ListViewOnColumnResize, OptionListViewOnColumnResize, ListViewOnColumnResizeCallback, ListViewOnColumnResizeCallbackType);
+
+/// Returns the rows `first_row..first_row + row_count` of a virtualized ListView
+pub type ListViewRowProviderCallbackType = extern "C" fn(&mut RefAny, first_row: usize, row_count: usize) -> ListViewRowVec;
+impl_callback!(ListViewRowProvider, OptionListViewRowProvider, ListViewRowProviderCallback, ListViewRowProviderCallbackType);
+
+/// How the selection should change in response to a user action
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum ListViewSelectionChange {
+    /// Deselect everything, then select the row (plain click / arrow keys)
+    Replace,
+    /// Flip the selection state of the row (Ctrl + click / Space)
+    Toggle,
+    /// Select all rows between `ListViewState::selection_anchor` and the row (Shift + click)
+    Extend,
+}
+
 /// State of the ListView, but without row data
 #[derive(Debug, Clone)]
 #[repr(C)]
@@ -440,9 +585,25 @@ pub struct ListViewState {
     pub current_scroll_position: LogicalPosition,
     /// Current height of the row container
     pub current_content_height: LogicalSize,
+    /// Total number of rows
+    pub row_count: usize,
+    /// Index of the first row that should be rendered
+    pub first_visible_row: usize,
+    /// Row that has the keyboard cursor
+    pub focused_row: OptionUsize,
+    /// Row from which Shift + click extends the selection
+    pub selection_anchor: OptionUsize,
 }
 
 /// List view, optionally able to lazy-load data
+///
+/// If a `row_provider` is set, the ListView is virtualized: only the rows
+/// that fit into the visible area are requested from the row provider
+/// and rendered (inside of an IFrame, so that the ListView knows its
+/// own height). The ListView does not keep any state between frames:
+/// `on_lazy_load_scroll` and `on_row_select` report the new
+/// `first_visible_row` / `focused_row` / `selection_anchor`, which the
+/// application has to store and set on the next ListView it creates.
 #[derive(Debug, Clone)]
 #[repr(C)]
 pub struct ListView {
@@ -471,6 +632,26 @@ pub struct ListView {
     /// What to do when the user left-clicks a row
     /// (usually used for selecting the row depending on the state)
     pub on_row_click: OptionListViewOnRowClick,
+    /// Total number of rows (only used if `row_provider` is set)
+    pub row_count: usize,
+    /// Height of every row in pixels (default: 20)
+    pub row_height: f32,
+    /// Index of the first rendered row
+    pub first_visible_row: usize,
+    /// Width of each column in pixels (default: 100 for columns without an entry)
+    pub column_widths: F32Vec,
+    /// Whether the `sorted_by` column is sorted in descending order
+    pub sort_descending: bool,
+    /// Row that has the keyboard cursor
+    pub focused_row: OptionUsize,
+    /// Row from which Shift + click extends the selection
+    pub selection_anchor: OptionUsize,
+    /// Returns the rows to render, makes the ListView virtualized
+    pub row_provider: OptionListViewRowProvider,
+    /// Called when the user selects a row with the mouse or keyboard
+    pub on_row_select: OptionListViewOnRowSelect,
+    /// Called when the user has finished resizing a column
+    pub on_column_resize: OptionListViewOnColumnResize,
 }
 
 impl Default for ListView {
@@ -487,6 +668,16 @@ impl Default for ListView {
             on_lazy_load_scroll: None.into(),
             on_column_click: None.into(),
             on_row_click: None.into(),
+            row_count: 0,
+            row_height: DEFAULT_ROW_HEIGHT,
+            first_visible_row: 0,
+            column_widths: F32Vec::from_const_slice(&[]),
+            sort_descending: false,
+            focused_row: None.into(),
+            selection_anchor: None.into(),
+            row_provider: None.into(),
+            on_row_select: None.into(),
+            on_column_resize: None.into(),
         }
     }
 }
@@ -499,6 +690,8 @@ pub struct ListViewRow {
     pub cells: DomVec,
     /// Height of the row, if known beforehand
     pub height: OptionPixelValueNoPercent,
+    /// Whether the row is rendered as selected
+    pub is_selected: bool,
 }
 
 impl_vec!(ListViewRow, ListViewRowVec, ListViewRowVecDestructor);
@@ -552,6 +745,16 @@ impl ListView {
         self.sorted_by = sorted_by;
     }
 
+    pub fn with_sort_descending(&mut self, sort_descending: bool) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_sort_descending(sort_descending);
+        m
+    }
+
+    pub fn set_sort_descending(&mut self, sort_descending: bool) {
+        self.sort_descending = sort_descending;
+    }
+
     pub fn with_scroll_offset(&mut self, scroll_offset: PixelValueNoPercent) -> Self {
         let mut m = self.swap_with_default();
         m.set_scroll_offset(scroll_offset);
@@ -582,6 +785,76 @@ impl ListView {
         self.column_context_menu = Some(column_context_menu).into();
     }
 
+    pub fn with_row_height(&mut self, row_height: f32) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_row_height(row_height);
+        m
+    }
+
+    pub fn set_row_height(&mut self, row_height: f32) {
+        self.row_height = row_height;
+    }
+
+    pub fn with_first_visible_row(&mut self, first_visible_row: usize) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_first_visible_row(first_visible_row);
+        m
+    }
+
+    pub fn set_first_visible_row(&mut self, first_visible_row: usize) {
+        self.first_visible_row = first_visible_row;
+    }
+
+    pub fn with_column_widths(&mut self, column_widths: F32Vec) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_column_widths(column_widths);
+        m
+    }
+
+    pub fn set_column_widths(&mut self, column_widths: F32Vec) {
+        self.column_widths = column_widths;
+    }
+
+    /// Restores the keyboard cursor and selection anchor
+    /// reported by the last `on_row_select` callback
+    pub fn with_selection_state(&mut self, focused_row: OptionUsize, selection_anchor: OptionUsize) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_selection_state(focused_row, selection_anchor);
+        m
+    }
+
+    pub fn set_selection_state(&mut self, focused_row: OptionUsize, selection_anchor: OptionUsize) {
+        self.focused_row = focused_row;
+        self.selection_anchor = selection_anchor;
+    }
+
+    pub fn with_row_provider(&mut self, row_count: usize, data: RefAny, row_provider: ListViewRowProviderCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_row_provider(row_count, data, row_provider);
+        m
+    }
+
+    pub fn set_row_provider(&mut self, row_count: usize, data: RefAny, row_provider: ListViewRowProviderCallbackType) {
+        self.row_count = row_count;
+        self.row_provider = Some(ListViewRowProvider {
+            data,
+            callback: ListViewRowProviderCallback { cb: row_provider }
+        }).into();
+    }
+
+    pub fn with_on_lazy_load_scroll(&mut self, data: RefAny, on_lazy_load_scroll: ListViewOnLazyLoadScrollCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_lazy_load_scroll(data, on_lazy_load_scroll);
+        m
+    }
+
+    pub fn set_on_lazy_load_scroll(&mut self, data: RefAny, on_lazy_load_scroll: ListViewOnLazyLoadScrollCallbackType) {
+        self.on_lazy_load_scroll = Some(ListViewOnLazyLoadScroll {
+            data,
+            callback: ListViewOnLazyLoadScrollCallback { cb: on_lazy_load_scroll }
+        }).into();
+    }
+
     pub fn with_on_column_click(&mut self, data: RefAny, on_column_click: ListViewOnColumnClickCallbackType) -> Self {
         let mut m = self.swap_with_default();
         m.set_on_column_click(data, on_column_click);
@@ -608,43 +881,933 @@ impl ListView {
         }).into();
     }
 
+    pub fn with_on_row_select(&mut self, data: RefAny, on_row_select: ListViewOnRowSelectCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_row_select(data, on_row_select);
+        m
+    }
+
+    pub fn set_on_row_select(&mut self, data: RefAny, on_row_select: ListViewOnRowSelectCallbackType) {
+        self.on_row_select = Some(ListViewOnRowSelect {
+            data,
+            callback: ListViewOnRowSelectCallback { cb: on_row_select }
+        }).into();
+    }
+
+    pub fn with_on_column_resize(&mut self, data: RefAny, on_column_resize: ListViewOnColumnResizeCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_column_resize(data, on_column_resize);
+        m
+    }
+
+    pub fn set_on_column_resize(&mut self, data: RefAny, on_column_resize: ListViewOnColumnResizeCallbackType) {
+        self.on_column_resize = Some(ListViewOnColumnResize {
+            data,
+            callback: ListViewOnColumnResizeCallback { cb: on_column_resize }
+        }).into();
+    }
+
+    /// Total number of rows: `row_count` if the ListView is virtualized,
+    /// otherwise the number of `rows`
+    pub fn get_total_row_count(&self) -> usize {
+        if self.row_provider.is_some() {
+            self.row_count
+        } else {
+            self.rows.len()
+        }
+    }
+
+    /// Updates the `focused_row`, the `selection_anchor` and the `is_selected` flags
+    /// of the `rows` the same way the ListView does it internally - usually called
+    /// from `on_row_select`. If the ListView is virtualized, the `row_provider`
+    /// has to return the selection state of the rows instead.
+    pub fn apply_selection(&mut self, row_id: usize, change: ListViewSelectionChange) {
+
+        use self::ListViewSelectionChange::*;
+
+        let anchor = match change {
+            Replace | Toggle => row_id,
+            Extend => self.selection_anchor.into_option().unwrap_or(row_id),
+        };
+
+        let rows = self.rows.as_mut();
+        match change {
+            Replace => {
+                for (i, row) in rows.iter_mut().enumerate() {
+                    row.is_selected = i == row_id;
+                }
+            },
+            Toggle => {
+                if let Some(row) = rows.get_mut(row_id) {
+                    row.is_selected = !row.is_selected;
+                }
+            },
+            Extend => {
+                let selected = anchor.min(row_id)..=anchor.max(row_id);
+                for (i, row) in rows.iter_mut().enumerate() {
+                    row.is_selected = selected.contains(&i);
+                }
+            },
+        }
+
+        self.focused_row = Some(row_id).into();
+        self.selection_anchor = Some(anchor).into();
+    }
+
+    fn get_column_width(&self, column: usize) -> f32 {
+        self.column_widths.as_ref().get(column).copied().unwrap_or(DEFAULT_COLUMN_WIDTH)
+    }
+
+    fn get_state(&self, current_row_count: usize, current_content_height: LogicalSize) -> ListViewState {
+        ListViewState {
+            columns: self.columns.clone(),
+            sorted_by: self.sorted_by,
+            current_row_count,
+            scroll_offset: PixelValueNoPercent {
+                inner: PixelValue::px(self.first_visible_row as f32 * self.row_height),
+            },
+            current_scroll_position: LogicalPosition::new(0.0, self.first_visible_row as f32 * self.row_height),
+            current_content_height,
+            row_count: self.get_total_row_count(),
+            first_visible_row: self.first_visible_row,
+            focused_row: self.focused_row,
+            selection_anchor: self.selection_anchor,
+        }
+    }
+
+    // Returns the rows first_row..first_row + count, either from the row provider or from self.rows
+    fn get_rows(&mut self, first_row: usize, count: usize) -> Vec<ListViewRow> {
+        match self.row_provider.as_mut() {
+            Some(ListViewRowProvider { data, callback }) => {
+                let count = count.min(self.row_count.saturating_sub(first_row));
+                if count == 0 {
+                    Vec::new()
+                } else {
+                    (callback.cb)(data, first_row, count).into_library_owned_vec()
+                }
+            },
+            None => {
+                self.rows.as_ref().iter().skip(first_row).take(count).cloned().collect()
+            }
+        }
+    }
+
     pub fn dom(self) -> Dom {
+
+        let header_items = self.columns.iter().enumerate().map(|(column_id, col)| {
+
+            let mut children = vec![
+                Dom::text(col.clone())
+                .with_inline_css_props(CSS_MATCH_15673486787900743642)
+            ];
+
+            if self.sorted_by.into_option() == Some(column_id) {
+                let (arrow_style, arrow_class) = if self.sort_descending {
+                    (CSS_MATCH_1085706216385961159, ARROW_DOWN_CLASS)
+                } else {
+                    (NodeDataInlineCssPropertyVec::from_const_slice(ARROW_UP_STYLE), ARROW_UP_CLASS)
+                };
+
+                children.push(
+                    Dom::div()
+                    .with_inline_css_props(arrow_style)
+                    .with_ids_and_classes(arrow_class)
+                    .with_child(
+                        Dom::div()
+                        .with_inline_css_props(CSS_MATCH_13758717721055992976)
+                        .with_ids_and_classes(ARROW_INNER_CLASS)
+                        .with_child(
+                            Dom::div()
+                            .with_inline_css_props(CSS_MATCH_1574792189506859253)
+                            .with_ids_and_classes(ARROW_INNER_DECO_CLASS)
+                        )
+                    )
+                );
+            }
+
+            (column_id, self.get_column_width(column_id), children)
+        }).collect::<Vec<_>>();
+
+        let list_view_local_dataset = RefAny::new(ListViewLocalDataset {
+            list_view: self,
+            visible_row_count: 0,
+            current_content_height: LogicalSize::zero(),
+            column_drag: None,
+            suppress_column_click: false,
+        });
+
+        let header_items = header_items.into_iter().map(|(column_id, width, mut children)| {
+
+            let column_local_dataset = RefAny::new(ListViewColumnLocalDataset {
+                column_id,
+                backref: list_view_local_dataset.clone(),
+            });
+
+            // drag handle at the right edge of the column header
+            children.push(
+                Dom::div()
+                .with_inline_css_props(CSS_MATCH_6002662151290653203)
+                .with_ids_and_classes(DRAGWIDTH_CLASS)
+                .with_child(
+                    Dom::div()
+                    .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(DRAG_HANDLE_STYLE))
+                    .with_ids_and_classes(DRAGWIDTH_DRAG_CLASS)
+                    .with_callbacks(vec![
+                        CallbackData {
+                            event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                            data: column_local_dataset.clone(),
+                            callback: Callback { cb: self::input::default_on_column_drag_start },
+                        }
+                    ].into())
+                )
+            );
+
+            Dom::div()
+            .with_inline_css_props(with_fixed_width(CSS_MATCH_12498280255863106397_PROPERTIES, width))
+            .with_ids_and_classes(COLUMN_NAME_CLASS)
+            .with_callbacks(vec![
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::LeftMouseUp),
+                    data: column_local_dataset,
+                    callback: Callback { cb: self::input::default_on_column_click },
+                }
+            ].into())
+            .with_children(children.into())
+        }).collect::<Vec<_>>();
+
         Dom::div()
         .with_inline_css_props(CSS_MATCH_17553577885456905601)
         .with_ids_and_classes(LIST_VIEW_CONTAINER_CLASS)
+        .with_tab_index(TabIndex::Auto)
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                data: list_view_local_dataset.clone(),
+                callback: Callback { cb: self::input::default_on_mouse_down },
+            },
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::Scroll),
+                data: list_view_local_dataset.clone(),
+                callback: Callback { cb: self::input::default_on_mouse_wheel },
+            },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                data: list_view_local_dataset.clone(),
+                callback: Callback { cb: self::input::default_on_key_down },
+            },
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::MouseOver),
+                data: list_view_local_dataset.clone(),
+                callback: Callback { cb: self::input::default_on_column_drag },
+            },
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::LeftMouseUp),
+                data: list_view_local_dataset.clone(),
+                callback: Callback { cb: self::input::default_on_column_drag_end },
+            },
+        ].into())
         .with_children(DomVec::from_vec(vec![
 
             // header
             Dom::div()
             .with_inline_css_props(CSS_MATCH_15315949193378715186)
             .with_ids_and_classes(HEADER_CONTAINER_CLASS)
-            .with_children(self.columns.iter().map(|col| {
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_12498280255863106397)
-                .with_ids_and_classes(COLUMN_NAME_CLASS)
-                .with_child({
-                    Dom::text(col.clone())
-                    .with_inline_css_props(CSS_MATCH_15673486787900743642)
-                })
-            }).collect::<Vec<_>>().into()),
-
-            // rows
-            Dom::div()
-            .with_inline_css_props(CSS_MATCH_4852927511892172364)
-            .with_ids_and_classes(ROW_CONTAINER_CLASS)
-            .with_children(self.rows.into_iter().map(|row| {
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_7894335449545988724)
-                .with_tab_index(TabIndex::Auto)
-                .with_ids_and_classes(ROW_CLASS.clone())
-                .with_tab_index(TabIndex::Auto)
-                .with_children(row.cells.as_ref().iter().map(|cell| {
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_12980082330151137475)
-                    .with_ids_and_classes(CELL_CLASS)
-                    .with_child(cell.clone())
-                }).collect::<Vec<_>>().into())
-            }).collect::<Vec<_>>().into())
+            .with_children(header_items.into()),
+
+            // rows, rendered in an IFrame so that only the visible rows are rendered
+            Dom::iframe(list_view_local_dataset, render_rows)
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(ROWS_CONTAINER_STYLE))
         ]))
     }
 }
+
+// Copies the properties without any width / min-width / flex-grow and
+// sets a fixed width instead (used for column headers and cells)
+fn with_fixed_width(props: &[NodeDataInlineCssProperty], width: f32) -> NodeDataInlineCssPropertyVec {
+    props.iter()
+    .filter(|p| !matches!(p,
+        NodeDataInlineCssProperty::Normal(CssProperty::Width(_)) |
+        NodeDataInlineCssProperty::Normal(CssProperty::MinWidth(_)) |
+        NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(_))
+    ))
+    .cloned()
+    .chain(vec![
+        NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::px(width) }))),
+        NodeDataInlineCssProperty::Normal(CssProperty::const_flex_shrink(LayoutFlexShrink { inner: FloatValue::const_new(0) })),
+        NodeDataInlineCssProperty::Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    ])
+    .collect::<Vec<_>>()
+    .into()
+}
+
+// Copies the properties without any height / flex-grow and sets a fixed height (used for rows)
+fn with_fixed_height(props: &[NodeDataInlineCssProperty], height: f32) -> NodeDataInlineCssPropertyVec {
+    props.iter()
+    .filter(|p| !matches!(p,
+        NodeDataInlineCssProperty::Normal(CssProperty::Height(_)) |
+        NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(_))
+    ))
+    .cloned()
+    .chain(vec![
+        NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::px(height) }))),
+        NodeDataInlineCssProperty::Normal(CssProperty::const_flex_shrink(LayoutFlexShrink { inner: FloatValue::const_new(0) })),
+    ])
+    .collect::<Vec<_>>()
+    .into()
+}
+
+// Number of rows that fit into the given height (including the partially visible last row)
+fn rows_for_height(height: f32, row_height: f32) -> usize {
+    if row_height <= 0.0 {
+        return 0;
+    }
+    (height / row_height).ceil().max(0.0) as usize
+}
+
+// Returns the (offset, height) of the scrollbar thumb in a track of the given height
+fn get_scrollbar_thumb(track_height: f32, first_row: usize, visible_rows: usize, total_rows: usize) -> (f32, f32) {
+    if total_rows == 0 || visible_rows >= total_rows {
+        return (0.0, track_height);
+    }
+    let thumb_height = (track_height * visible_rows as f32 / total_rows as f32)
+        .max(MIN_SCROLLBAR_THUMB_HEIGHT)
+        .min(track_height);
+    let max_first_row = total_rows - visible_rows;
+    let offset = (track_height - thumb_height) * (first_row.min(max_first_row) as f32 / max_first_row as f32);
+    (offset, thumb_height)
+}
+
+// IFrame callback: renders the rows that fit into the IFrame + the vertical scrollbar
+extern "C" fn render_rows(data: &mut RefAny, info: &mut IFrameCallbackInfo) -> IFrameCallbackReturn {
+
+    let size = info.get_bounds().get_logical_size();
+
+    let backref = data.clone();
+    let mut list_view_local_dataset = match data.downcast_mut::<ListViewLocalDataset>() {
+        Some(s) => s,
+        None => return IFrameCallbackReturn::default(),
+    };
+
+    let list_view_local_dataset = &mut *list_view_local_dataset;
+    let list_view = &mut list_view_local_dataset.list_view;
+
+    let row_height = list_view.row_height;
+    let total_rows = list_view.get_total_row_count();
+    let visible_rows = rows_for_height(size.height, row_height);
+    let first_row = list_view.first_visible_row.min(total_rows.saturating_sub(1));
+
+    let rows = list_view.get_rows(first_row, visible_rows);
+    let column_widths = (0..list_view.columns.len()).map(|c| list_view.get_column_width(c)).collect::<Vec<_>>();
+
+    list_view_local_dataset.visible_row_count = visible_rows;
+    list_view_local_dataset.current_content_height = LogicalSize::new(size.width, total_rows as f32 * row_height);
+
+    let row_style = with_fixed_height(CSS_MATCH_7894335449545988724_PROPERTIES, row_height);
+    let row_style_selected = with_fixed_height(CSS_MATCH_6827198030119836081_PROPERTIES, row_height);
+    let cell_styles = column_widths.iter().map(|w| with_fixed_width(CSS_MATCH_12980082330151137475_PROPERTIES, *w)).collect::<Vec<_>>();
+
+    let rows = rows.into_iter().enumerate().map(|(i, row)| {
+
+        let row_id = first_row + i;
+        let (style, class) = if row.is_selected {
+            (row_style_selected.clone(), ROW_SELECTED_CLASS)
+        } else {
+            (row_style.clone(), ROW_CLASS)
+        };
+
+        Dom::div()
+        .with_inline_css_props(style)
+        .with_ids_and_classes(class)
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::LeftMouseUp),
+                data: RefAny::new(ListViewRowLocalDataset {
+                    row_id,
+                    backref: backref.clone(),
+                }),
+                callback: Callback { cb: self::input::default_on_row_click },
+            }
+        ].into())
+        .with_children(row.cells.as_ref().iter().enumerate().map(|(column_id, cell)| {
+            Dom::div()
+            .with_inline_css_props(cell_styles.get(column_id).cloned().unwrap_or_default())
+            .with_ids_and_classes(CELL_CLASS)
+            .with_child(cell.clone())
+        }).collect::<Vec<_>>().into())
+    }).collect::<Vec<_>>();
+
+    let (thumb_offset, thumb_height) = get_scrollbar_thumb(size.height, first_row, visible_rows, total_rows);
+
+    let mut dom = Dom::div()
+    .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(ROWS_BODY_STYLE))
+    .with_children(DomVec::from_vec(vec![
+        Dom::div()
+        .with_inline_css_props(CSS_MATCH_4852927511892172364)
+        .with_ids_and_classes(ROW_CONTAINER_CLASS)
+        .with_children(rows.into()),
+
+        Dom::div()
+        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(SCROLLBAR_TRACK_STYLE))
+        .with_ids_and_classes(SCROLLBAR_CLASS)
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                data: backref.clone(),
+                callback: Callback { cb: self::input::default_on_scrollbar_click },
+            }
+        ].into())
+        .with_child(
+            Dom::div()
+            .with_inline_css_props(vec![
+                NodeDataInlineCssProperty::Normal(CssProperty::MarginTop(LayoutMarginTopValue::Exact(LayoutMarginTop { inner: PixelValue::px(thumb_offset) }))),
+                NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::px(thumb_height) }))),
+                NodeDataInlineCssProperty::Normal(CssProperty::const_flex_shrink(LayoutFlexShrink { inner: FloatValue::const_new(0) })),
+                NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SCROLLBAR_THUMB_BACKGROUND))),
+            ].into())
+            .with_ids_and_classes(SCROLLBAR_THUMB_CLASS)
+        )
+    ]));
+
+    IFrameCallbackReturn {
+        dom: dom.style(Css::empty()),
+        scroll_size: size,
+        scroll_offset: LogicalPosition::zero(),
+        virtual_scroll_size: size,
+        virtual_scroll_offset: LogicalPosition::zero(),
+    }
+}
+
+// dataset set on the top-level ListView node (and the rows IFrame)
+struct ListViewLocalDataset {
+    list_view: ListView,
+    // number of rows that fit into the IFrame, set when the rows are rendered
+    visible_row_count: usize,
+    current_content_height: LogicalSize,
+    column_drag: Option<ColumnDrag>,
+    // set when a column resize starts, so that releasing the mouse
+    // on the column header doesn't count as a column click
+    suppress_column_click: bool,
+}
+
+// column currently being resized by dragging the header
+struct ColumnDrag {
+    column_id: usize,
+    header_node: DomNodeId,
+    start_cursor_x: f32,
+    start_width: f32,
+    current_width: f32,
+}
+
+// dataset set on each column header
+struct ListViewColumnLocalDataset {
+    column_id: usize,
+    backref: RefAny, // RefAny<ListViewLocalDataset>
+}
+
+// dataset set on each rendered row
+struct ListViewRowLocalDataset {
+    row_id: usize,
+    backref: RefAny, // RefAny<ListViewLocalDataset>
+}
+
+// handle input events for the list view
+mod input {
+
+    use azul_desktop::callbacks::{RefAny, CallbackInfo, Update, FocusTarget};
+    use azul_desktop::css::{CssProperty, LayoutWidth, LayoutWidthValue, PixelValue};
+    use azul_core::window::VirtualKeyCode;
+    use super::{
+        ListViewLocalDataset, ListViewColumnLocalDataset, ListViewRowLocalDataset,
+        ListViewSelectionChange, ColumnDrag,
+        ListViewOnLazyLoadScroll, ListViewOnColumnClick, ListViewOnRowClick,
+        ListViewOnRowSelect, ListViewOnColumnResize,
+        MIN_COLUMN_WIDTH, ROWS_PER_SCROLL_NOTCH,
+        get_scrollbar_thumb,
+    };
+
+    pub(in super) extern "C" fn default_on_mouse_down(_data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+        // focus the ListView, so that the keyboard navigation works after clicking a row
+        let list_view_node = info.get_hit_node();
+        info.set_focus(FocusTarget::Id(list_view_node));
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_column_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut data = match data.downcast_mut::<ListViewColumnLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let column_id = data.column_id;
+        let mut list_view_local_dataset = match data.backref.downcast_mut::<ListViewLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if list_view_local_dataset.suppress_column_click || list_view_local_dataset.column_drag.is_some() {
+            list_view_local_dataset.suppress_column_click = false;
+            return Update::DoNothing;
+        }
+
+        let list_view_local_dataset = &mut *list_view_local_dataset;
+        let state = list_view_local_dataset.get_state();
+        match list_view_local_dataset.list_view.on_column_click.as_mut() {
+            Some(ListViewOnColumnClick { callback, data }) => (callback.cb)(data, info, &state, column_id),
+            None => Update::DoNothing,
+        }
+    }
+
+    pub(in super) extern "C" fn default_on_column_drag_start(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut data = match data.downcast_mut::<ListViewColumnLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let column_id = data.column_id;
+        let mut list_view_local_dataset = match data.backref.downcast_mut::<ListViewLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let cursor_x = match info.get_current_mouse_state().cursor_position.get_position() {
+            Some(s) => s.x,
+            None => return Update::DoNothing,
+        };
+
+        // drag handle -> dragwidth container -> column header
+        let hit_node = info.get_hit_node();
+        let header_node = match info.get_parent(hit_node).and_then(|p| info.get_parent(p)) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let start_width = list_view_local_dataset.list_view.get_column_width(column_id);
+
+        list_view_local_dataset.suppress_column_click = true;
+        list_view_local_dataset.column_drag = Some(ColumnDrag {
+            column_id,
+            header_node,
+            start_cursor_x: cursor_x,
+            start_width,
+            current_width: start_width,
+        });
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_column_drag(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut list_view_local_dataset = match data.downcast_mut::<ListViewLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let mouse_state = info.get_current_mouse_state();
+
+        let column_drag = match list_view_local_dataset.column_drag.as_mut() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if !mouse_state.left_down {
+            return Update::DoNothing;
+        }
+
+        let cursor_x = match mouse_state.cursor_position.get_position() {
+            Some(s) => s.x,
+            None => return Update::DoNothing,
+        };
+
+        let new_width = (column_drag.start_width + cursor_x - column_drag.start_cursor_x).max(MIN_COLUMN_WIDTH);
+        if new_width == column_drag.current_width {
+            return Update::DoNothing;
+        }
+
+        // only resize the header while dragging, the rows are
+        // re-rendered once the application has stored the new width
+        column_drag.current_width = new_width;
+        info.set_css_property(column_drag.header_node, CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::px(new_width) })));
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_column_drag_end(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut list_view_local_dataset = match data.downcast_mut::<ListViewLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let column_drag = match list_view_local_dataset.column_drag.take() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if column_drag.current_width == column_drag.start_width {
+            return Update::DoNothing;
+        }
+
+        let list_view_local_dataset = &mut *list_view_local_dataset;
+        let state = list_view_local_dataset.get_state();
+        match list_view_local_dataset.list_view.on_column_resize.as_mut() {
+            Some(ListViewOnColumnResize { callback, data }) => (callback.cb)(data, info, &state, column_drag.column_id, column_drag.current_width),
+            None => Update::DoNothing,
+        }
+    }
+
+    pub(in super) extern "C" fn default_on_row_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut data = match data.downcast_mut::<ListViewRowLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let row_id = data.row_id;
+        let mut list_view_local_dataset = match data.backref.downcast_mut::<ListViewLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let keyboard_state = info.get_current_keyboard_state();
+        let change = if keyboard_state.shift_down() {
+            ListViewSelectionChange::Extend
+        } else if keyboard_state.ctrl_down() {
+            ListViewSelectionChange::Toggle
+        } else {
+            ListViewSelectionChange::Replace
+        };
+
+        let list_view_local_dataset = &mut *list_view_local_dataset;
+        let mut result = select_row(list_view_local_dataset, info, row_id, change);
+
+        let state = list_view_local_dataset.get_state();
+        result.max_self(match list_view_local_dataset.list_view.on_row_click.as_mut() {
+            Some(ListViewOnRowClick { callback, data }) => (callback.cb)(data, info, &state, row_id),
+            None => Update::DoNothing,
+        });
+
+        result
+    }
+
+    pub(in super) extern "C" fn default_on_scrollbar_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut list_view_local_dataset = match data.downcast_mut::<ListViewLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let cursor_y = match info.get_cursor_relative_to_node().into_option() {
+            Some(s) => s.y,
+            None => return Update::DoNothing,
+        };
+
+        let track_height = match info.get_node_size(info.get_hit_node()) {
+            Some(s) => s.height,
+            None => return Update::DoNothing,
+        };
+
+        // clicking above / below the thumb scrolls by one page
+        let list_view_local_dataset = &mut *list_view_local_dataset;
+        let page_size = list_view_local_dataset.visible_row_count.max(1);
+        let total_rows = list_view_local_dataset.list_view.get_total_row_count();
+        let first_row = list_view_local_dataset.list_view.first_visible_row;
+        let (thumb_offset, thumb_height) = get_scrollbar_thumb(track_height, first_row, page_size, total_rows);
+
+        let new_first_row = if cursor_y < thumb_offset {
+            first_row.saturating_sub(page_size)
+        } else if cursor_y > thumb_offset + thumb_height {
+            first_row + page_size
+        } else {
+            return Update::DoNothing;
+        };
+
+        scroll_to_row(list_view_local_dataset, info, new_first_row)
+    }
+
+    pub(in super) extern "C" fn default_on_mouse_wheel(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut list_view_local_dataset = match data.downcast_mut::<ListViewLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // scroll_y is in wheel "notches", positive = scrolling up
+        let scroll_y = info.get_current_mouse_state().get_scroll_y();
+        let delta = -(scroll_y * ROWS_PER_SCROLL_NOTCH).round() as isize;
+        if delta == 0 {
+            return Update::DoNothing;
+        }
+
+        let first_row = list_view_local_dataset.list_view.first_visible_row;
+        let new_first_row = if delta < 0 {
+            first_row.saturating_sub(delta.unsigned_abs())
+        } else {
+            first_row.saturating_add(delta.unsigned_abs())
+        };
+        scroll_to_row(&mut list_view_local_dataset, info, new_first_row)
+    }
+
+    pub(in super) extern "C" fn default_on_key_down(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut list_view_local_dataset = match data.downcast_mut::<ListViewLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let keyboard_state = info.get_current_keyboard_state();
+        let keycode = match keyboard_state.current_virtual_keycode.into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let list_view_local_dataset = &mut *list_view_local_dataset;
+        let total_rows = list_view_local_dataset.list_view.get_total_row_count();
+        if total_rows == 0 {
+            return Update::DoNothing;
+        }
+
+        let last_row = total_rows - 1;
+        let page_size = list_view_local_dataset.visible_row_count.max(1);
+        let current_row = list_view_local_dataset.list_view.focused_row.into_option();
+
+        let target_row = match (keycode, current_row) {
+            (VirtualKeyCode::Up, Some(r)) => r.saturating_sub(1),
+            (VirtualKeyCode::Down, Some(r)) => (r + 1).min(last_row),
+            (VirtualKeyCode::PageUp, Some(r)) => r.saturating_sub(page_size),
+            (VirtualKeyCode::PageDown, Some(r)) => (r + page_size).min(last_row),
+            (VirtualKeyCode::Up, None) | (VirtualKeyCode::Down, None) |
+            (VirtualKeyCode::PageUp, None) | (VirtualKeyCode::PageDown, None) |
+            (VirtualKeyCode::Home, _) => 0,
+            (VirtualKeyCode::End, _) => last_row,
+            (VirtualKeyCode::Space, Some(r)) => {
+                return select_row(list_view_local_dataset, info, r, ListViewSelectionChange::Toggle);
+            },
+            _ => return Update::DoNothing,
+        };
+
+        if current_row == Some(target_row) {
+            return Update::DoNothing;
+        }
+
+        let change = if keyboard_state.shift_down() {
+            ListViewSelectionChange::Extend
+        } else {
+            ListViewSelectionChange::Replace
+        };
+
+        let mut result = select_row(list_view_local_dataset, info, target_row, change);
+
+        // scroll the row into view
+        let first_row = list_view_local_dataset.list_view.first_visible_row;
+        // the last row might only be partially visible
+        let fully_visible = page_size.saturating_sub(1).max(1);
+        if target_row < first_row {
+            result.max_self(scroll_to_row(list_view_local_dataset, info, target_row));
+        } else if target_row >= first_row + fully_visible {
+            result.max_self(scroll_to_row(list_view_local_dataset, info, target_row + 1 - fully_visible));
+        }
+
+        result
+    }
+
+    fn select_row(list_view_local_dataset: &mut ListViewLocalDataset, info: &mut CallbackInfo, row_id: usize, change: ListViewSelectionChange) -> Update {
+
+        list_view_local_dataset.list_view.apply_selection(row_id, change);
+
+        let state = list_view_local_dataset.get_state();
+        match list_view_local_dataset.list_view.on_row_select.as_mut() {
+            Some(ListViewOnRowSelect { callback, data }) => (callback.cb)(data, info, &state, row_id, change),
+            None => Update::DoNothing,
+        }
+    }
+
+    fn scroll_to_row(list_view_local_dataset: &mut ListViewLocalDataset, info: &mut CallbackInfo, first_row: usize) -> Update {
+
+        // don't scroll past the point where the last row is at the bottom
+        let total_rows = list_view_local_dataset.list_view.get_total_row_count();
+        let page_size = list_view_local_dataset.visible_row_count.max(1);
+        let first_row = first_row.min(total_rows.saturating_sub(page_size.saturating_sub(1)));
+
+        if first_row == list_view_local_dataset.list_view.first_visible_row {
+            return Update::DoNothing;
+        }
+
+        list_view_local_dataset.list_view.first_visible_row = first_row;
+
+        let state = list_view_local_dataset.get_state();
+        match list_view_local_dataset.list_view.on_lazy_load_scroll.as_mut() {
+            Some(ListViewOnLazyLoadScroll { callback, data }) => (callback.cb)(data, info, &state),
+            None => Update::DoNothing,
+        }
+    }
+}
+
+impl ListViewLocalDataset {
+    fn get_state(&self) -> ListViewState {
+        self.list_view.get_state(self.visible_row_count, self.current_content_height)
+    }
+}
+
+impl From<ListView> for Dom {
+    fn from(l: ListView) -> Dom {
+        l.dom()
+    }
+}
+
+/// Renders a `<list-view columns="Name, Size" rows="a.txt | 5 KB, b.txt | 3 KB" />` XML node
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ListViewRenderer {
+    node: XmlNode,
+}
+
+impl ListViewRenderer {
+    pub fn new() -> Self {
+        Self {
+            node: XmlNode::new("list_view"),
+        }
+    }
+}
+
+impl Default for ListViewRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XmlComponentTrait for ListViewRenderer {
+    fn get_type_id(&self) -> String {
+        "list_view".to_string()
+    }
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        ComponentArguments {
+            args: vec![
+                ("columns".to_string(), "[String]".to_string()),
+                ("rows".to_string(), "[String]".to_string()),
+                ("row_height".to_string(), "f32".to_string()),
+                ("sorted_by".to_string(), "usize".to_string()),
+                ("sort_descending".to_string(), "bool".to_string()),
+            ],
+            accepts_text: false,
+        }
+    }
+
+    fn render_dom(
+        &self,
+        _: &XmlComponentMap,
+        args: &FilteredComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<StyledDom, RenderDomError> {
+        let mut list_view = ListView::new(parse_xml_arg(args, "columns", "[String]", parse_xml_list)?.unwrap_or_default());
+        // each row is a list of cells, separated by "|"
+        let rows = parse_xml_arg(args, "rows", "[String]", parse_xml_list)?.unwrap_or_default();
+        list_view.set_rows(rows.as_ref().iter().map(|row| ListViewRow {
+            cells: row.as_str().split('|').map(|cell| Dom::text(AzString::from(cell.trim()))).collect::<Vec<_>>().into(),
+            height: None.into(),
+            is_selected: false,
+        }).collect::<Vec<_>>().into());
+        if let Some(row_height) = parse_xml_arg(args, "row_height", "f32", parse_xml_number)? {
+            list_view.set_row_height(row_height);
+        }
+        list_view.set_sorted_by(parse_xml_arg(args, "sorted_by", "usize", parse_xml_number)?.into());
+        list_view.set_sort_descending(parse_xml_arg(args, "sort_descending", "bool", parse_xml_bool)?.unwrap_or(false));
+        Ok(list_view.dom().style(Css::empty()))
+    }
+
+    fn compile_to_rust_code(
+        &self,
+        _: &XmlComponentMap,
+        _: &ComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<String, CompileError> {
+        Ok(String::from(
+            "ListView::new(StringVec::from_vec(columns.into_iter().map(AzString::from).collect()))\r\n\
+            .with_rows(ListViewRowVec::from_vec(rows.iter().map(|row| ListViewRow {\r\n\
+                cells: DomVec::from_vec(row.split('|').map(|cell| Dom::text(AzString::from(cell.trim()))).collect()),\r\n\
+                height: None.into(),\r\n\
+                is_selected: false,\r\n\
+            }).collect()))\r\n\
+            .with_row_height(row_height)\r\n\
+            .with_sorted_by(Some(sorted_by).into())\r\n\
+            .with_sort_descending(sort_descending)\r\n\
+            .dom()"
+        ))
+    }
+
+    fn get_xml_node(&self) -> XmlNode {
+        self.node.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_list_view_visible_rows() {
+        assert_eq!(rows_for_height(100.0, 20.0), 5);
+        assert_eq!(rows_for_height(101.0, 20.0), 6);
+        assert_eq!(rows_for_height(0.0, 20.0), 0);
+        assert_eq!(rows_for_height(100.0, 0.0), 0);
+
+        let mut list_view = ListView::new(StringVec::from_const_slice(&[]))
+            .with_rows((0..10).map(|_| ListViewRow {
+                cells: DomVec::from_const_slice(&[]),
+                height: None.into(),
+                is_selected: false,
+            }).collect::<Vec<_>>().into());
+
+        assert_eq!(list_view.get_total_row_count(), 10);
+        assert_eq!(list_view.get_rows(8, 5).len(), 2);
+        assert_eq!(list_view.get_rows(20, 5).len(), 0);
+    }
+
+    #[test]
+    fn test_list_view_scrollbar_thumb() {
+        // all rows visible: thumb fills the whole track
+        assert_eq!(get_scrollbar_thumb(100.0, 0, 10, 5), (0.0, 100.0));
+        // 10 of 100 rows visible, scrolled to the top / bottom
+        assert_eq!(get_scrollbar_thumb(200.0, 0, 10, 100), (0.0, 20.0));
+        assert_eq!(get_scrollbar_thumb(200.0, 90, 10, 100), (180.0, 20.0));
+        // thumb never gets smaller than the minimum height
+        assert_eq!(get_scrollbar_thumb(200.0, 0, 1, 1000).1, MIN_SCROLLBAR_THUMB_HEIGHT);
+    }
+
+    #[test]
+    fn test_list_view_apply_selection() {
+
+        fn selected(list_view: &ListView) -> Vec<usize> {
+            list_view.rows.iter().enumerate().filter(|(_, r)| r.is_selected).map(|(i, _)| i).collect()
+        }
+
+        let mut list_view = ListView::new(StringVec::from_const_slice(&[]))
+            .with_rows((0..6).map(|_| ListViewRow {
+                cells: DomVec::from_const_slice(&[]),
+                height: None.into(),
+                is_selected: false,
+            }).collect::<Vec<_>>().into());
+
+        list_view.apply_selection(1, ListViewSelectionChange::Replace);
+        assert_eq!(selected(&list_view), vec![1]);
+
+        // Shift + click selects the range from the anchor, in both directions
+        list_view.apply_selection(4, ListViewSelectionChange::Extend);
+        assert_eq!(selected(&list_view), vec![1, 2, 3, 4]);
+        list_view.apply_selection(0, ListViewSelectionChange::Extend);
+        assert_eq!(selected(&list_view), vec![0, 1]);
+        assert_eq!(list_view.selection_anchor.into_option(), Some(1));
+        assert_eq!(list_view.focused_row.into_option(), Some(0));
+
+        // Ctrl + click toggles a single row and moves the anchor
+        list_view.apply_selection(5, ListViewSelectionChange::Toggle);
+        list_view.apply_selection(0, ListViewSelectionChange::Toggle);
+        assert_eq!(selected(&list_view), vec![1, 5]);
+        assert_eq!(list_view.selection_anchor.into_option(), Some(0));
+
+        list_view.apply_selection(3, ListViewSelectionChange::Replace);
+        assert_eq!(selected(&list_view), vec![3]);
+    }
+}
@@ -110,9 +110,199 @@ pub mod tree_view;
 pub mod drop_down;
 /// Ribbon widget
 pub mod ribbon;
-// /// Spreadsheet (iframe) widget
-// pub mod spreadsheet;
+/// Spreadsheet-like data grid widget
+pub mod spreadsheet;
+/// Date picker, time picker and drop-down date / time picker widgets
+pub mod date_picker;
+/// In-window modal dialogs: message boxes, confirmations, prompts and custom dialogs
+pub mod dialog;
+/// Container with resizable, collapsible panes separated by draggable handles
+pub mod splitter;
+/// Minimal JSON reader / writer, used for saving / loading node
+/// graphs and for copying nodes to the clipboard
+mod json;
 // /// Slider widget
 // pub mod slider;
 // /// Multi-line text input
-// pub mod text_edit;
\ No newline at end of file
+// pub mod text_edit;
+use azul_desktop::xml::{
+    XmlComponent, XmlComponentMap, XmlComponentTrait, FilteredComponentArguments,
+    ComponentError, normalize_casing, split_list_value,
+};
+use azul_desktop::styled_dom::StyledDom;
+use azul_desktop::css::{AzString, StringVec};
+
+/// Returns the default XML components (`body`, `div`, `p`) and the
+/// built-in widgets, so that XML files can use them as components
+///
+/// The child nodes of the widgets that wrap other DOM nodes (`Frame`, `Splitter`,
+/// `Dialog`) are placed inside of the widget, i.e. each child of a `Splitter` is a pane.
+pub fn widgets_component_map() -> XmlComponentMap {
+    let mut map = XmlComponentMap::default();
+    let widgets: Vec<(&str, Box<dyn XmlComponentTrait>)> = vec![
+        ("Button", Box::new(button::ButtonRenderer::new())),
+        ("CheckBox", Box::new(check_box::CheckBoxRenderer::new())),
+        ("Label", Box::new(label::LabelRenderer::new())),
+        ("TextInput", Box::new(text_input::TextInputRenderer::new())),
+        ("NumberInput", Box::new(number_input::NumberInputRenderer::new())),
+        ("ColorInput", Box::new(color_input::ColorInputRenderer::new())),
+        ("FileInput", Box::new(file_input::FileInputRenderer::new())),
+        ("DropDown", Box::new(drop_down::DropDownRenderer::new())),
+        ("ProgressBar", Box::new(progressbar::ProgressBarRenderer::new())),
+        ("TabHeader", Box::new(tabs::TabHeaderRenderer::new())),
+        ("ListView", Box::new(list_view::ListViewRenderer::new())),
+        ("TreeView", Box::new(tree_view::TreeViewRenderer::new())),
+        ("DataGrid", Box::new(spreadsheet::DataGridRenderer::new())),
+        ("DatePicker", Box::new(date_picker::DatePickerRenderer::new())),
+        ("TimePicker", Box::new(date_picker::TimePickerRenderer::new())),
+        ("DateTimePicker", Box::new(date_picker::DateTimePickerRenderer::new())),
+        ("Frame", Box::new(frame::FrameRenderer::new())),
+        ("Splitter", Box::new(splitter::SplitterRenderer::new())),
+        ("Dialog", Box::new(dialog::DialogRenderer::new())),
+        ("NodeGraph", Box::new(node_graph::NodeGraphRenderer::new())),
+    ];
+    for (id, renderer) in widgets {
+        map.register_component(XmlComponent {
+            id: normalize_casing(id),
+            renderer,
+            inherit_vars: false,
+        });
+    }
+    map
+}
+
+// Parses the value of an (optional) XML argument, returns an error if the value is invalid
+pub(crate) fn parse_xml_arg<T, F: Fn(&str) -> Option<T>>(
+    args: &FilteredComponentArguments,
+    name: &str,
+    ty: &str,
+    parse: F,
+) -> Result<Option<T>, Box<ComponentError>> {
+    match args.values.get(name) {
+        None => Ok(None),
+        Some(value) => match parse(value) {
+            Some(s) => Ok(Some(s)),
+            None => Err(Box::new(ComponentError::InvalidArgumentValue(name.into(), value.as_str().into(), ty.into()))),
+        }
+    }
+}
+
+pub(crate) fn get_xml_string_arg(args: &FilteredComponentArguments, name: &str) -> Option<AzString> {
+    args.values.get(name).map(|s| AzString::from(s.as_str()))
+}
+
+pub(crate) fn parse_xml_bool(s: &str) -> Option<bool> {
+    azul_desktop::xml::parse_bool(s.trim())
+}
+
+pub(crate) fn parse_xml_number<T: core::str::FromStr>(s: &str) -> Option<T> {
+    s.trim().parse().ok()
+}
+
+// "a, b, c" or "[a, b, c]"
+pub(crate) fn parse_xml_list(s: &str) -> Option<StringVec> {
+    Some(split_list_value(s).into_iter().map(AzString::from).collect::<Vec<_>>().into())
+}
+
+/// Same as `azul_desktop::app::extra::styled_dom_from_str`, but with the built-in widgets as XML components
+#[cfg(feature = "xml")]
+pub fn styled_dom_from_str(s: &str) -> StyledDom {
+    azul_desktop::xml::domxml_from_str(s, &mut widgets_component_map()).parsed_dom
+}
+
+#[cfg(not(feature = "xml"))]
+pub fn styled_dom_from_str(s: &str) -> StyledDom {
+    azul_desktop::app::extra::styled_dom_from_str(s)
+}
+
+/// Same as `azul_desktop::app::extra::styled_dom_from_file`, but with the built-in widgets as XML components
+#[cfg(feature = "xml")]
+pub fn styled_dom_from_file(path: &str) -> StyledDom {
+    azul_desktop::xml::domxml_from_file(path, &mut widgets_component_map()).parsed_dom
+}
+
+#[cfg(not(feature = "xml"))]
+pub fn styled_dom_from_file(path: &str) -> StyledDom {
+    azul_desktop::app::extra::styled_dom_from_file(path)
+}
+
+#[cfg(all(test, feature = "xml"))]
+mod xml_test {
+
+    fn render_html(body: &str) -> String {
+        let xml = format!("<html><body>{}</body></html>", body);
+        super::styled_dom_from_str(&xml).get_html_string("", "", true).as_str().to_string()
+    }
+
+    // indentation of the first line of the HTML that contains the pattern
+    fn get_indent(html: &str, pattern: &str) -> usize {
+        let line = html.lines().find(|l| l.contains(pattern)).expect(pattern);
+        line.len() - line.trim_start().len()
+    }
+
+    #[test]
+    fn test_widgets_as_xml_components() {
+        let html = render_html("
+            <Button label=\"Save\" />
+            <Label>Name</Label>
+            <ProgressBar percent_done=\"50\" />
+        ");
+        assert!(html.contains("__azul-native-button-container"), "{}", html);
+        assert!(html.contains(">Save</p>"), "{}", html);
+        assert!(html.contains(">Name</p>"), "{}", html);
+        assert!(html.contains("__azul-native-progress-bar-container"), "{}", html);
+    }
+
+    #[test]
+    fn test_container_widgets_wrap_children() {
+        // the children are rendered inside of the bordered content of the frame
+        let html = render_html("<Frame title=\"Settings\"><Label>Inside</Label></Frame>");
+        assert_eq!(get_indent(&html, "__azul-native-frame-content"), get_indent(&html, "__azul-native-frame-header\""), "{}", html);
+        assert_eq!(get_indent(&html, ">Inside</p>"), get_indent(&html, "__azul-native-frame-content") + 4, "{}", html);
+
+        // each child of a splitter is a pane
+        let html = render_html("<Splitter direction=\"vertical\"><Label>A</Label><Label>B</Label></Splitter>");
+        assert_eq!(html.matches("class=\"__azul-native-splitter-pane\"").count(), 2, "{}", html);
+        assert_eq!(html.matches("class=\"__azul-native-splitter-handle\"").count(), 1, "{}", html);
+        assert_eq!(get_indent(&html, ">B</p>"), get_indent(&html, "__azul-native-splitter-handle") + 4, "{}", html);
+
+        // the children of a dialog replace the message
+        let html = render_html("<Dialog title=\"Save\" message=\"Message\" buttons=\"yes_no\"><Label>Custom</Label></Dialog>");
+        assert!(html.contains(">Custom</p>") && !html.contains(">Message</p>"), "{}", html);
+        assert_eq!(get_indent(&html, ">Custom</p>"), get_indent(&html, "__azul-native-dialog-content") + 4, "{}", html);
+        assert!(html.contains(">Yes</p>") && html.contains(">No</p>"), "{}", html);
+    }
+
+    #[test]
+    fn test_list_argument_compiles_to_vec() {
+        let xml = "<html><body><TreeView items=\"Documents, Pictures\" /></body></html>";
+        let root_nodes = azul_desktop::xml::parse_xml_string(xml).unwrap();
+        let source = azul_desktop::xml::str_to_rust_code(&root_nodes, "", &mut super::widgets_component_map()).unwrap();
+        assert!(source.contains("pub fn render(items: Vec<String>) -> Dom {"), "{}", source);
+        assert!(source.contains("tree_view::render(vec![String::from(\"Documents\"), String::from(\"Pictures\")])"), "{}", source);
+    }
+
+    #[test]
+    fn test_date_picker_arguments_compile_to_constructors() {
+        let xml = "<html><body>\
+            <DatePicker selected_date=\"2024-05-01\" first_day_of_week=\"sunday\" />\
+            <TimePicker time=\"13:30\" show_seconds=\"true\" />\
+        </body></html>";
+        let root_nodes = azul_desktop::xml::parse_xml_string(xml).unwrap();
+        let source = azul_desktop::xml::str_to_rust_code(&root_nodes, "", &mut super::widgets_component_map()).unwrap();
+        assert!(source.contains("pub fn render(selected_date: OptionDate, minimum_date: OptionDate, maximum_date: OptionDate, first_day_of_week: Weekday) -> Dom {"), "{}", source);
+        assert!(source.contains("date_picker::render(Some(Date::new(2024, 5, 1)).into(), Default::default(), Default::default(), Weekday::Sunday)"), "{}", source);
+        assert!(source.contains("pub fn render(time: Time, show_seconds: bool) -> Dom {"), "{}", source);
+        assert!(source.contains("time_picker::render(Time::new(13, 30, 0), true)"), "{}", source);
+
+        let xml = "<html><body><TimePicker time=\"25:00\" /></body></html>";
+        let root_nodes = azul_desktop::xml::parse_xml_string(xml).unwrap();
+        assert!(azul_desktop::xml::str_to_rust_code(&root_nodes, "", &mut super::widgets_component_map()).is_err());
+    }
+
+    #[test]
+    fn test_invalid_widget_argument() {
+        let html = render_html("<CheckBox checked=\"maybe\" />");
+        assert!(html.contains("Invalid value for component argument \"checked\": \"maybe\" is not a valid bool"), "{}", html);
+    }
+}
@@ -4,7 +4,7 @@ use azul_desktop::css::*;
 use azul_desktop::css::AzString;
 use azul_desktop::dom::{
     Dom, IdOrClass, TabIndex,
-    HoverEventFilter, EventFilter,
+    HoverEventFilter, FocusEventFilter, EventFilter,
     CallbackData,
     IdOrClass::{Id, Class},
     NodeDataInlineCssProperty,
@@ -19,7 +19,7 @@ use azul_core::svg::{
 };
 use azul_desktop::callbacks::{
     Update, RefAny, CallbackInfo, Callback,
-    RenderImageCallbackInfo,
+    RenderImageCallbackInfo, DomNodeId, FocusTarget,
 };
 use azul_core::app_resources::{
     ImageRef, RawImageFormat
@@ -29,7 +29,14 @@ use azul_core::window::{
     CursorPosition::InWindow, Menu, MenuItem, StringMenuItem,
 };
 
+use azul_desktop::xml::{
+    XmlComponentTrait, XmlComponentMap, XmlNode, XmlTextContent,
+    ComponentArguments, FilteredComponentArguments,
+    RenderDomError, CompileError,
+};
+use azul_desktop::styled_dom::StyledDom;
 use crate::widgets::{
+    json,
     file_input::{FileInput, FileInputState},
     text_input::{TextInput, TextInputState},
     number_input::{NumberInput, NumberInputState},
@@ -50,6 +57,10 @@ pub struct NodeGraph {
     pub callbacks: NodeGraphCallbacks,
     pub add_node_str: AzString,
     pub scale_factor: f32,
+    /// Currently selected nodes, highlighted in the UI
+    pub selected_nodes: NodeGraphNodeIdVec,
+    /// Undo / redo history, modified by the NodeGraph and reported via `on_history_changed`
+    pub history: NodeGraphHistory,
 }
 
 impl Default for NodeGraph {
@@ -64,6 +75,8 @@ impl Default for NodeGraph {
             callbacks: NodeGraphCallbacks::default(),
             add_node_str: AzString::from_const_str(""),
             scale_factor: 1.0,
+            selected_nodes: Vec::new().into(),
+            history: NodeGraphHistory::default(),
         }
     }
 }
@@ -136,6 +149,9 @@ pub struct NodeGraphCallbacks {
     pub on_node_input_disconnected: OptionOnNodeInputDisconnected,
     pub on_node_output_disconnected: OptionOnNodeOutputDisconnected,
     pub on_node_field_edited: OptionOnNodeFieldEdited,
+    pub on_node_graph_zoomed: OptionOnNodeGraphZoomed,
+    pub on_selection_changed: OptionOnNodeGraphSelectionChanged,
+    pub on_history_changed: OptionOnNodeGraphHistoryChanged,
 }
 
 pub type OnNodeAddedCallbackType = extern "C" fn(data: &mut RefAny, info: &mut CallbackInfo, new_node_type: NodeTypeId, new_node_id: NodeGraphNodeId, new_node_position: NodePosition) -> Update;
@@ -162,6 +178,15 @@ impl_callback!(OnNodeOutputDisconnected, OptionOnNodeOutputDisconnected, OnNodeO
 pub type OnNodeFieldEditedCallbackType = extern "C" fn(data: &mut RefAny, info: &mut CallbackInfo, node_id: NodeGraphNodeId, field_id: usize, node_type: NodeTypeId, new_value: NodeTypeFieldValue) -> Update;
 impl_callback!(OnNodeFieldEdited, OptionOnNodeFieldEdited, OnNodeFieldEditedCallback, OnNodeFieldEditedCallbackType);
 
+pub type OnNodeGraphZoomedCallbackType = extern "C" fn(data: &mut RefAny, info: &mut CallbackInfo, new_scale_factor: f32, new_offset: LogicalPosition) -> Update;
+impl_callback!(OnNodeGraphZoomed, OptionOnNodeGraphZoomed, OnNodeGraphZoomedCallback, OnNodeGraphZoomedCallbackType);
+
+pub type OnNodeGraphSelectionChangedCallbackType = extern "C" fn(data: &mut RefAny, info: &mut CallbackInfo, selected_nodes: NodeGraphNodeIdVec) -> Update;
+impl_callback!(OnNodeGraphSelectionChanged, OptionOnNodeGraphSelectionChanged, OnNodeGraphSelectionChangedCallback, OnNodeGraphSelectionChangedCallbackType);
+
+pub type OnNodeGraphHistoryChangedCallbackType = extern "C" fn(data: &mut RefAny, info: &mut CallbackInfo, new_history: NodeGraphHistory) -> Update;
+impl_callback!(OnNodeGraphHistoryChanged, OptionOnNodeGraphHistoryChanged, OnNodeGraphHistoryChangedCallback, OnNodeGraphHistoryChangedCallbackType);
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub struct InputOutputTypeId {
@@ -185,6 +210,12 @@ pub struct NodeGraphNodeId {
     pub inner: u64
 }
 
+impl_vec!(NodeGraphNodeId, NodeGraphNodeIdVec, NodeGraphNodeIdVecDestructor);
+impl_vec_clone!(NodeGraphNodeId, NodeGraphNodeIdVec, NodeGraphNodeIdVecDestructor);
+impl_vec_debug!(NodeGraphNodeId, NodeGraphNodeIdVec);
+impl_vec_mut!(NodeGraphNodeId, NodeGraphNodeIdVec);
+impl_vec_partialeq!(NodeGraphNodeId, NodeGraphNodeIdVec);
+
 #[derive(Debug, Clone)]
 #[repr(C)]
 pub struct Node {
@@ -207,7 +238,7 @@ impl_vec_clone!(NodeTypeField, NodeTypeFieldVec, NodeTypeFieldVecDestructor);
 impl_vec_debug!(NodeTypeField, NodeTypeFieldVec);
 impl_vec_mut!(NodeTypeField, NodeTypeFieldVec);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 #[repr(C, u8)]
 pub enum NodeTypeFieldValue {
     TextInput(AzString),
@@ -310,6 +341,9 @@ pub enum NodeGraphError {
     NodeInvalidNode,
     /// Root node is missing from the graph tree
     NoRootNode,
+    /// The JSON passed to `NodeGraph::from_json` is malformed or
+    /// references nodes that do not exist
+    InvalidJson,
 }
 
 impl fmt::Display for NodeGraphError {
@@ -320,6 +354,7 @@ impl fmt::Display for NodeGraphError {
             NodeInvalidIndex => write!(f, "Invalid node index"),
             NodeInvalidNode => write!(f, "Invalid node"),
             NoRootNode => write!(f, "No root node found"),
+            InvalidJson => write!(f, "Invalid node graph JSON"),
         }
     }
 }
@@ -338,6 +373,126 @@ pub struct NodeDragAmount {
     pub y: f32,
 }
 
+/// Connection from the output of one node to the input of another node
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct NodeGraphConnection {
+    pub output: NodeGraphNodeId,
+    pub output_index: usize,
+    pub input: NodeGraphNodeId,
+    pub input_index: usize,
+}
+
+/// Node that was moved by `amount` (in graph coordinates)
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[repr(C)]
+pub struct NodeGraphNodeMove {
+    pub node_id: NodeGraphNodeId,
+    pub amount: NodeDragAmount,
+}
+
+/// Field of a node that was changed from `old_value` to `new_value`
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct NodeGraphFieldEdit {
+    pub node_id: NodeGraphNodeId,
+    pub node_type: NodeTypeId,
+    pub field_idx: usize,
+    pub old_value: NodeTypeFieldValue,
+    pub new_value: NodeTypeFieldValue,
+}
+
+/// Single modification of the node graph, recorded in the undo history
+///
+/// Every operation can be inverted (`AddNode` <-> `RemoveNode`,
+/// `Connect` <-> `Disconnect`, etc.), undoing an edit replays the
+/// inverted operations via the regular NodeGraph callbacks.
+#[derive(Debug, Clone)]
+#[repr(C, u8)]
+pub enum NodeGraphEditOp {
+    /// Node was added, `on_node_added` + `on_node_field_edited` for every field
+    AddNode(NodeIdNodeMap),
+    /// Node was removed, `on_node_removed`
+    RemoveNode(NodeIdNodeMap),
+    /// Node was moved, `on_node_dragged`
+    MoveNode(NodeGraphNodeMove),
+    /// Output was connected to an input, `on_node_connected`
+    Connect(NodeGraphConnection),
+    /// Connection was removed, `on_node_input_disconnected`
+    Disconnect(NodeGraphConnection),
+    /// Field value was edited, `on_node_field_edited`
+    EditField(NodeGraphFieldEdit),
+}
+
+impl_vec!(NodeGraphEditOp, NodeGraphEditOpVec, NodeGraphEditOpVecDestructor);
+impl_vec_clone!(NodeGraphEditOp, NodeGraphEditOpVec, NodeGraphEditOpVecDestructor);
+impl_vec_debug!(NodeGraphEditOp, NodeGraphEditOpVec);
+impl_vec_mut!(NodeGraphEditOp, NodeGraphEditOpVec);
+
+impl NodeGraphEditOp {
+    /// Returns the operation that reverts this operation
+    pub fn invert(&self) -> Self {
+        use self::NodeGraphEditOp::*;
+        match self {
+            AddNode(n) => RemoveNode(n.clone()),
+            RemoveNode(n) => AddNode(n.clone()),
+            MoveNode(m) => MoveNode(NodeGraphNodeMove {
+                node_id: m.node_id,
+                amount: NodeDragAmount { x: -m.amount.x, y: -m.amount.y },
+            }),
+            Connect(c) => Disconnect(*c),
+            Disconnect(c) => Connect(*c),
+            EditField(e) => EditField(NodeGraphFieldEdit {
+                node_id: e.node_id,
+                node_type: e.node_type,
+                field_idx: e.field_idx,
+                old_value: e.new_value.clone(),
+                new_value: e.old_value.clone(),
+            }),
+        }
+    }
+}
+
+/// One undoable user action (for example "paste three nodes"),
+/// consisting of one or more operations
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct NodeGraphEdit {
+    pub ops: NodeGraphEditOpVec,
+}
+
+impl_vec!(NodeGraphEdit, NodeGraphEditVec, NodeGraphEditVecDestructor);
+impl_vec_clone!(NodeGraphEdit, NodeGraphEditVec, NodeGraphEditVecDestructor);
+impl_vec_debug!(NodeGraphEdit, NodeGraphEditVec);
+impl_vec_mut!(NodeGraphEdit, NodeGraphEditVec);
+
+/// Undo / redo stacks of the node graph: the last edit is at the end of each stack
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct NodeGraphHistory {
+    pub undo: NodeGraphEditVec,
+    pub redo: NodeGraphEditVec,
+}
+
+impl Default for NodeGraphHistory {
+    fn default() -> Self {
+        Self {
+            undo: Vec::new().into(),
+            redo: Vec::new().into(),
+        }
+    }
+}
+
+impl NodeGraphHistory {
+    pub fn can_undo(&self) -> bool { !self.undo.is_empty() }
+    pub fn can_redo(&self) -> bool { !self.redo.is_empty() }
+}
+
+/// Maximum number of edits kept in the undo stack
+const MAX_UNDO_STEPS: usize = 100;
+
+impl_result!(NodeGraph, NodeGraphError, ResultNodeGraphNodeGraphError, copy = false, [Debug, Clone]);
+
 impl NodeGraph {
 
     pub fn swap_with_default(&mut self) -> Self {
@@ -593,6 +748,354 @@ impl NodeGraph {
         Ok(())
     }
 
+    /// Serializes the nodes (including positions, field values and connections),
+    /// the offset and the zoom level of the graph to JSON
+    ///
+    /// The node and input / output types are not serialized, they are
+    /// defined by the application and must be set again after loading.
+    pub fn to_json(&self) -> AzString {
+        let node_ids = self.nodes.iter().map(|n| n.node_id).collect::<Vec<_>>();
+        self.subgraph_to_json(&node_ids).into()
+    }
+
+    /// Loads a graph saved with `to_json`: returns a default `NodeGraph` with
+    /// only the `nodes`, `offset` and `scale_factor` set
+    pub fn from_json(json: &str) -> Result<Self, NodeGraphError> {
+
+        use self::json::JsonValue;
+
+        fn get_f32(v: &JsonValue, key: &str) -> Result<f32, NodeGraphError> {
+            v.get(key).and_then(|v| v.as_f64()).map(|f| f as f32).ok_or(NodeGraphError::InvalidJson)
+        }
+
+        fn get_usize(v: &JsonValue, key: &str) -> Result<usize, NodeGraphError> {
+            v.get(key).and_then(|v| v.as_u64()).map(|u| u as usize).ok_or(NodeGraphError::InvalidJson)
+        }
+
+        fn get_node_id(v: &JsonValue, key: &str) -> Result<NodeGraphNodeId, NodeGraphError> {
+            v.get(key).and_then(|v| v.as_u64()).map(|inner| NodeGraphNodeId { inner }).ok_or(NodeGraphError::InvalidJson)
+        }
+
+        fn parse_field_value(v: &JsonValue) -> Option<NodeTypeFieldValue> {
+            let (variant, value) = match v {
+                JsonValue::Object(o) if o.len() == 1 => (o[0].0.as_str(), &o[0].1),
+                _ => return None,
+            };
+            match variant {
+                "TextInput" => Some(NodeTypeFieldValue::TextInput(value.as_str()?.to_string().into())),
+                "NumberInput" => Some(NodeTypeFieldValue::NumberInput(value.as_f64()? as f32)),
+                "CheckBox" => Some(NodeTypeFieldValue::CheckBox(value.as_bool()?)),
+                "ColorInput" => Some(NodeTypeFieldValue::ColorInput(json::parse_color(value.as_str()?)?)),
+                "FileInput" => Some(NodeTypeFieldValue::FileInput(match value {
+                    JsonValue::Null => None,
+                    other => Some(AzString::from(other.as_str()?.to_string())),
+                }.into())),
+                _ => None,
+            }
+        }
+
+        let root = json::parse(json).ok_or(NodeGraphError::InvalidJson)?;
+
+        let mut node_graph = NodeGraph::default();
+
+        if let Some(offset) = root.get("offset") {
+            node_graph.offset = LogicalPosition::new(get_f32(offset, "x")?, get_f32(offset, "y")?);
+        }
+
+        if let Some(scale_factor) = root.get("scale_factor") {
+            node_graph.scale_factor = scale_factor.as_f64().ok_or(NodeGraphError::InvalidJson)? as f32;
+        }
+
+        let mut nodes = Vec::new();
+        for n in root.get("nodes").and_then(|n| n.as_array()).ok_or(NodeGraphError::InvalidJson)? {
+            let position = n.get("position").ok_or(NodeGraphError::InvalidJson)?;
+            let mut fields = Vec::new();
+            for field in n.get("fields").and_then(|f| f.as_array()).unwrap_or(&[]) {
+                fields.push(NodeTypeField {
+                    key: field.get("key").and_then(|k| k.as_str()).ok_or(NodeGraphError::InvalidJson)?.to_string().into(),
+                    value: field.get("value").and_then(parse_field_value).ok_or(NodeGraphError::InvalidJson)?,
+                });
+            }
+            let node_id = get_node_id(n, "id")?;
+            if nodes.iter().any(|n: &NodeIdNodeMap| n.node_id == node_id) {
+                return Err(NodeGraphError::InvalidJson);
+            }
+            nodes.push(NodeIdNodeMap {
+                node_id,
+                node: Node {
+                    node_type: NodeTypeId { inner: n.get("type").and_then(|t| t.as_u64()).ok_or(NodeGraphError::InvalidJson)? },
+                    position: NodePosition { x: get_f32(position, "x")?, y: get_f32(position, "y")? },
+                    fields: fields.into(),
+                    connect_in: Vec::new().into(),
+                    connect_out: Vec::new().into(),
+                },
+            });
+        }
+        node_graph.nodes = nodes.into();
+
+        for c in root.get("connections").and_then(|c| c.as_array()).unwrap_or(&[]) {
+            let connection = NodeGraphConnection {
+                output: get_node_id(c, "output")?,
+                output_index: get_usize(c, "output_index")?,
+                input: get_node_id(c, "input")?,
+                input_index: get_usize(c, "input_index")?,
+            };
+            node_graph.insert_connection(connection)?;
+        }
+
+        Ok(node_graph)
+    }
+
+    // serializes the given nodes and the connections between them
+    fn subgraph_to_json(&self, node_ids: &[NodeGraphNodeId]) -> String {
+
+        use core::fmt::Write;
+
+        let mut out = String::new();
+        let _ = write!(out, "{{\"offset\":{{\"x\":{},\"y\":{}}},\"scale_factor\":{},\"nodes\":[",
+            json::f32_str(self.offset.x), json::f32_str(self.offset.y), json::f32_str(self.scale_factor));
+
+        let nodes = self.nodes.iter().filter(|n| node_ids.contains(&n.node_id));
+        for (i, NodeIdNodeMap { node_id, node }) in nodes.enumerate() {
+            if i != 0 {
+                out.push(',');
+            }
+            let _ = write!(out, "{{\"id\":{},\"type\":{},\"position\":{{\"x\":{},\"y\":{}}},\"fields\":[",
+                node_id.inner, node.node_type.inner, json::f32_str(node.position.x), json::f32_str(node.position.y));
+            for (j, field) in node.fields.iter().enumerate() {
+                if j != 0 {
+                    out.push(',');
+                }
+                out.push_str("{\"key\":");
+                json::write_str(&mut out, field.key.as_str());
+                out.push_str(",\"value\":");
+                match &field.value {
+                    NodeTypeFieldValue::TextInput(s) => {
+                        out.push_str("{\"TextInput\":");
+                        json::write_str(&mut out, s.as_str());
+                    },
+                    NodeTypeFieldValue::NumberInput(f) => {
+                        let _ = write!(out, "{{\"NumberInput\":{}", json::f32_str(*f));
+                    },
+                    NodeTypeFieldValue::CheckBox(b) => {
+                        let _ = write!(out, "{{\"CheckBox\":{}", b);
+                    },
+                    NodeTypeFieldValue::ColorInput(c) => {
+                        out.push_str("{\"ColorInput\":");
+                        json::write_str(&mut out, &c.to_hash());
+                    },
+                    NodeTypeFieldValue::FileInput(f) => {
+                        out.push_str("{\"FileInput\":");
+                        match f.as_ref() {
+                            Some(s) => json::write_str(&mut out, s.as_str()),
+                            None => out.push_str("null"),
+                        }
+                    },
+                }
+                out.push_str("}}");
+            }
+            out.push_str("]}");
+        }
+
+        out.push_str("],\"connections\":[");
+        let connections = self.get_connections().into_iter()
+            .filter(|c| node_ids.contains(&c.input) && node_ids.contains(&c.output));
+        for (i, c) in connections.enumerate() {
+            if i != 0 {
+                out.push(',');
+            }
+            let _ = write!(out, "{{\"output\":{},\"output_index\":{},\"input\":{},\"input_index\":{}}}",
+                c.output.inner, c.output_index, c.input.inner, c.input_index);
+        }
+        out.push_str("]}");
+        out
+    }
+
+    /// Returns all connections between the nodes of the graph
+    pub fn get_connections(&self) -> Vec<NodeGraphConnection> {
+        let mut connections = Vec::new();
+        for NodeIdNodeMap { node_id, node } in self.nodes.iter() {
+            for output in node.connect_out.iter() {
+                for input in output.connects_to.iter() {
+                    connections.push(NodeGraphConnection {
+                        output: *node_id,
+                        output_index: output.output_index,
+                        input: input.node_id,
+                        input_index: input.input_index,
+                    });
+                }
+            }
+        }
+        connections
+    }
+
+    // inserts a connection into both the input and the output node, without type checking
+    fn insert_connection(&mut self, c: NodeGraphConnection) -> Result<(), NodeGraphError> {
+
+        if !self.nodes.iter().any(|n| n.node_id == c.output) {
+            return Err(NodeGraphError::NodeInvalidNode);
+        }
+
+        let input_node = self.nodes.as_mut().iter_mut()
+            .find(|i| i.node_id == c.input)
+            .ok_or(NodeGraphError::NodeInvalidNode)?;
+
+        let output = OutputNodeAndIndex { node_id: c.output, output_index: c.output_index };
+        match input_node.node.connect_in.iter_mut().find(|i| i.input_index == c.input_index) {
+            Some(s) if s.connects_to.iter().any(|o| o.node_id == output.node_id && o.output_index == output.output_index) => return Ok(()),
+            Some(s) => s.connects_to.push(output),
+            None => input_node.node.connect_in.push(InputConnection {
+                input_index: c.input_index,
+                connects_to: vec![output].into(),
+            }),
+        }
+
+        let output_node = self.nodes.as_mut().iter_mut()
+            .find(|i| i.node_id == c.output)
+            .ok_or(NodeGraphError::NodeInvalidNode)?;
+
+        let input = InputNodeAndIndex { node_id: c.input, input_index: c.input_index };
+        match output_node.node.connect_out.iter_mut().find(|i| i.output_index == c.output_index) {
+            Some(s) => s.connects_to.push(input),
+            None => output_node.node.connect_out.push(OutputConnection {
+                output_index: c.output_index,
+                connects_to: vec![input].into(),
+            }),
+        }
+
+        Ok(())
+    }
+
+    // removes a single connection from both the input and the output node
+    fn remove_connection(&mut self, c: NodeGraphConnection) {
+        for n in self.nodes.as_mut().iter_mut() {
+            if n.node_id == c.input {
+                if let Some(pos) = n.node.connect_in.iter().position(|i| i.input_index == c.input_index) {
+                    let connects_to = &mut n.node.connect_in.as_mut()[pos].connects_to;
+                    if let Some(o) = connects_to.iter().position(|o| o.node_id == c.output && o.output_index == c.output_index) {
+                        connects_to.remove(o);
+                    }
+                    if connects_to.is_empty() {
+                        n.node.connect_in.remove(pos);
+                    }
+                }
+            }
+            if n.node_id == c.output {
+                if let Some(pos) = n.node.connect_out.iter().position(|i| i.output_index == c.output_index) {
+                    let connects_to = &mut n.node.connect_out.as_mut()[pos].connects_to;
+                    if let Some(i) = connects_to.iter().position(|i| i.node_id == c.input && i.input_index == c.input_index) {
+                        connects_to.remove(i);
+                    }
+                    if connects_to.is_empty() {
+                        n.node.connect_out.remove(pos);
+                    }
+                }
+            }
+        }
+    }
+
+    // applies an edit operation to the node graph model (without invoking any callbacks)
+    fn apply_edit_op(&mut self, op: &NodeGraphEditOp) {
+        use self::NodeGraphEditOp::*;
+        match op {
+            AddNode(n) => {
+                if self.nodes.iter().any(|i| i.node_id == n.node_id) {
+                    return;
+                }
+                let mut n = n.clone();
+                n.node.connect_in = Vec::new().into();
+                n.node.connect_out = Vec::new().into();
+                // the nodes may be a &'static or externally allocated vec, don't push in-place
+                let mut nodes = self.nodes.clone().into_library_owned_vec();
+                nodes.push(n);
+                self.nodes = nodes.into();
+            },
+            RemoveNode(n) => {
+                for c in self.get_connections() {
+                    if c.input == n.node_id || c.output == n.node_id {
+                        self.remove_connection(c);
+                    }
+                }
+                if let Some(pos) = self.nodes.iter().position(|i| i.node_id == n.node_id) {
+                    self.nodes.remove(pos);
+                }
+                if let Some(pos) = self.selected_nodes.iter().position(|i| *i == n.node_id) {
+                    self.selected_nodes.remove(pos);
+                }
+            },
+            MoveNode(m) => {
+                if let Some(n) = self.nodes.as_mut().iter_mut().find(|i| i.node_id == m.node_id) {
+                    n.node.position.x += m.amount.x;
+                    n.node.position.y += m.amount.y;
+                }
+            },
+            Connect(c) => {
+                let _ = self.insert_connection(*c);
+            },
+            Disconnect(c) => {
+                self.remove_connection(*c);
+            },
+            EditField(e) => {
+                if let Some(n) = self.nodes.as_mut().iter_mut().find(|i| i.node_id == e.node_id) {
+                    if let Some(field) = n.node.fields.as_mut().get_mut(e.field_idx) {
+                        field.value = e.new_value.clone();
+                    }
+                }
+            },
+        }
+    }
+
+    // returns the operations necessary to remove the given nodes:
+    // first all connections of the nodes are removed, then the nodes themselves
+    fn get_remove_nodes_ops(&self, node_ids: &[NodeGraphNodeId]) -> Vec<NodeGraphEditOp> {
+
+        let mut ops = self.get_connections()
+            .into_iter()
+            .filter(|c| node_ids.contains(&c.input) || node_ids.contains(&c.output))
+            .map(NodeGraphEditOp::Disconnect)
+            .collect::<Vec<_>>();
+
+        ops.extend(self.nodes.iter().filter(|n| node_ids.contains(&n.node_id)).map(|n| {
+            let mut n = n.clone();
+            n.node.connect_in = Vec::new().into();
+            n.node.connect_out = Vec::new().into();
+            NodeGraphEditOp::RemoveNode(n)
+        }));
+
+        ops
+    }
+
+    // returns the operations necessary to insert the nodes and connections of
+    // another graph (for example from the clipboard) into this graph, with
+    // newly generated node IDs and the positions moved by `offset`
+    fn get_insert_subgraph_ops(&self, subgraph: &NodeGraph, offset: NodeDragAmount) -> Vec<NodeGraphEditOp> {
+
+        let mut next_id = self.generate_unique_node_id().inner;
+        let mut id_map = Vec::new();
+        let mut ops = Vec::new();
+
+        for NodeIdNodeMap { node_id, node } in subgraph.nodes.iter() {
+            let new_id = NodeGraphNodeId { inner: next_id };
+            next_id = next_id.saturating_add(1);
+            id_map.push((*node_id, new_id));
+            let mut node = node.clone();
+            node.position.x += offset.x;
+            node.position.y += offset.y;
+            node.connect_in = Vec::new().into();
+            node.connect_out = Vec::new().into();
+            ops.push(NodeGraphEditOp::AddNode(NodeIdNodeMap { node_id: new_id, node }));
+        }
+
+        let map_id = |id: NodeGraphNodeId| id_map.iter().find(|(old, _)| *old == id).map(|(_, new)| *new);
+        for c in subgraph.get_connections() {
+            if let (Some(output), Some(input)) = (map_id(c.output), map_id(c.input)) {
+                ops.push(NodeGraphEditOp::Connect(NodeGraphConnection { output, input, .. c }));
+            }
+        }
+
+        ops
+    }
+
     pub fn dom(self) -> Dom {
 
         static NODEGRAPH_CLASS: &[IdOrClass] = &[
@@ -631,14 +1134,35 @@ impl NodeGraph {
             Normal(CssProperty::position(LayoutPosition::Relative)),
         ];
 
+        static NODEGRAPH_SELECTION_BOX_CLASS: &[IdOrClass] = &[
+            Class(AzString::from_const_str("nodegraph-selection-box"))
+        ];
+
+        static NODEGRAPH_SELECTION_BOX_BACKGROUND: &[StyleBackgroundContent] = &[
+            StyleBackgroundContent::Color(ColorU { r: 0, g: 180, b: 219, a: 50 })
+        ];
+
+        static NODEGRAPH_SELECTION_BOX_PROPS: &[NodeDataInlineCssProperty] = &[
+            Normal(CssProperty::position(LayoutPosition::Absolute)),
+            Normal(CssProperty::width(LayoutWidth::const_px(0))),
+            Normal(CssProperty::height(LayoutHeight::const_px(0))),
+            Normal(CssProperty::background_content(StyleBackgroundContentVec::from_const_slice(NODEGRAPH_SELECTION_BOX_BACKGROUND))),
+        ];
+
         let node_connection_marker = RefAny::new(NodeConnectionMarkerDataset { });
+        let selection_box_marker = RefAny::new(NodeSelectionBoxMarkerDataset { });
 
         let node_graph_local_dataset = RefAny::new(NodeGraphLocalDataset {
             node_graph: self.clone(), // TODO: expensive
             last_input_or_output_clicked: None,
             active_node_being_dragged: None,
             node_connection_marker: node_connection_marker.clone(),
+            selection_box_marker: selection_box_marker.clone(),
             callbacks: self.callbacks.clone(),
+            drag_amount: NodeDragAmount { x: 0.0, y: 0.0 },
+            graph_dragged: false,
+            selection_changed: false,
+            selection_box_start: None,
         });
 
         let context_menu = Menu::new(vec![
@@ -672,6 +1196,7 @@ impl NodeGraph {
            Dom::div()
            .with_ids_and_classes(IdOrClassVec::from_const_slice(NODEGRAPH_CLASS))
            .with_inline_css_props(nodegraph_props.into())
+           .with_tab_index(TabIndex::Auto)
            .with_callbacks(vec![
                CallbackData {
                    event: EventFilter::Hover(HoverEventFilter::MouseOver),
@@ -683,6 +1208,16 @@ impl NodeGraph {
                    data: node_graph_local_dataset.clone(),
                    callback: Callback { cb: nodegraph_unset_active_node },
                },
+               CallbackData {
+                   event: EventFilter::Hover(HoverEventFilter::Scroll),
+                   data: node_graph_local_dataset.clone(),
+                   callback: Callback { cb: nodegraph_zoom },
+               },
+               CallbackData {
+                   event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                   data: node_graph_local_dataset.clone(),
+                   callback: Callback { cb: nodegraph_on_key_down },
+               },
            ].into())
            .with_children({
                 vec![
@@ -705,12 +1240,19 @@ impl NodeGraph {
                             &node_type_info.node_type_info,
                             node_local_dataset,
                             self.scale_factor,
+                            self.selected_nodes.as_ref().contains(node_id),
                         ))
                     })
                     .collect::<Dom>()
                     .with_ids_and_classes(IdOrClassVec::from_const_slice(NODEGRAPH_NODES_CONTAINER_CLASS))
                     .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(NODEGRAPH_NODES_CONTAINER_PROPS)),
 
+                    // selection rectangle, resized while shift-dragging on the background
+                    Dom::div()
+                    .with_ids_and_classes(IdOrClassVec::from_const_slice(NODEGRAPH_SELECTION_BOX_CLASS))
+                    .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(NODEGRAPH_SELECTION_BOX_PROPS))
+                    .with_dataset(Some(selection_box_marker).into()),
+
                 ].into()
             })
         ].into())
@@ -723,10 +1265,21 @@ impl NodeGraph {
 struct NodeGraphLocalDataset {
     node_graph: NodeGraph,
     last_input_or_output_clicked: Option<(NodeGraphNodeId, InputOrOutput)>,
-    // Ref<NodeLocalDataSet> - used as a marker for getting the visual node ID
-    active_node_being_dragged: Option<(NodeGraphNodeId, RefAny)>,
+    // node that the mouse was pressed on
+    active_node_being_dragged: Option<NodeGraphNodeId>,
     node_connection_marker: RefAny, // Ref<NodeConnectionMarkerDataset>
+    selection_box_marker: RefAny, // Ref<NodeSelectionBoxMarkerDataset>
     callbacks: NodeGraphCallbacks,
+    // accumulated movement of the nodes dragged in the current
+    // mouse press, recorded as one undo step on mouse up
+    drag_amount: NodeDragAmount,
+    // whether the graph was panned in the current mouse press
+    graph_dragged: bool,
+    // whether the selection was changed in the current mouse press,
+    // on_selection_changed is invoked on mouse up
+    selection_changed: bool,
+    // start of the selection rectangle (shift + drag on the background), in window coordinates
+    selection_box_start: Option<LogicalPosition>,
 }
 
 struct ContextMenuEntryLocalDataset {
@@ -736,6 +1289,8 @@ struct ContextMenuEntryLocalDataset {
 
 struct NodeConnectionMarkerDataset { }
 
+struct NodeSelectionBoxMarkerDataset { }
+
 struct NodeLocalDataset {
     node_id: NodeGraphNodeId,
     backref: RefAny, // RefAny<NodeGraphLocalDataset>
@@ -774,6 +1329,7 @@ fn render_node(
     node_info: &NodeTypeInfo,
     mut node_local_dataset: NodeLocalDataset,
     scale_factor: f32,
+    is_selected: bool,
 ) -> Dom {
 
     use azul_desktop::css::*;
@@ -1768,6 +2324,8 @@ fn render_node(
 
     // NODE RENDER FUNCTION BEGIN
 
+    let border_color = get_node_border_color(is_selected);
+
     let node_transform = StyleTransformTranslate2D {
         x: PixelValue::px(graph_offset.0 + node.position.x),
         y: PixelValue::px(graph_offset.1 + node.position.y),
@@ -1821,42 +2379,22 @@ fn render_node(
            )),
            NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(
                StyleBorderTopColorValue::Exact(StyleBorderTopColor {
-                   inner: ColorU {
-                       r: 0,
-                       g: 180,
-                       b: 219,
-                       a: 255,
-                   },
+                   inner: border_color,
                }),
            )),
            NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(
                StyleBorderRightColorValue::Exact(StyleBorderRightColor {
-                   inner: ColorU {
-                       r: 0,
-                       g: 180,
-                       b: 219,
-                       a: 255,
-                   },
+                   inner: border_color,
                }),
            )),
            NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(
                StyleBorderLeftColorValue::Exact(StyleBorderLeftColor {
-                   inner: ColorU {
-                       r: 0,
-                       g: 180,
-                       b: 219,
-                       a: 255,
-                   },
+                   inner: border_color,
                }),
            )),
            NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(
                StyleBorderBottomColorValue::Exact(StyleBorderBottomColor {
-                   inner: ColorU {
-                       r: 0,
-                       g: 180,
-                       b: 219,
-                       a: 255,
-                   },
+                   inner: border_color,
                }),
            )),
            NodeDataInlineCssProperty::Normal(CssProperty::BorderTopStyle(
@@ -2077,6 +2615,13 @@ fn render_node(
                }),
            Dom::text(node_info.name.clone())
                .with_inline_css_props(CSS_MATCH_1739273067404038547)
+               .with_callbacks(vec![
+                   CallbackData {
+                       event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                       data: node_local_dataset.clone(),
+                       callback: Callback { cb: nodegraph_focus_graph },
+                   },
+               ].into())
                .with_ids_and_classes({
                    const IDS_AND_CLASSES_15777790571346582635: &[IdOrClass] =
                        &[Class(AzString::from_const_str("node_label"))];
@@ -2513,10 +3058,11 @@ extern "C"
 fn draw_connection(data: &mut RefAny, info: &mut RenderImageCallbackInfo) -> ImageRef {
 
     let size = info.get_bounds().get_physical_size();
-    let invalid = ImageRef::invalid(
+    let invalid = ImageRef::null_image(
         size.width as usize,
         size.height as usize,
         RawImageFormat::R8,
+        Vec::new(),
     );
 
     match draw_connection_inner(data, info, size) {
@@ -2652,166 +3198,667 @@ fn get_rect(node_graph: &NodeGraph, connection: ConnectionLocalDataset) -> Optio
 }
 
 
-extern "C" fn nodegraph_set_active_node(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
-    let data_clone = data.clone();
-    if let Some(mut data) = data.downcast_mut::<NodeLocalDataset>() {
-        let node_id = data.node_id.clone();
-        if let Some(mut backref) = data.backref.downcast_mut::<NodeGraphLocalDataset>() {
-            backref.active_node_being_dragged = Some((node_id, data_clone));
-        }
+// zoom factor per mouse wheel notch and the zoom limits
+const ZOOM_STEP: f32 = 1.1;
+const MIN_ZOOM: f32 = 0.1;
+const MAX_ZOOM: f32 = 4.0;
+// distance between copied and pasted nodes
+const PASTE_OFFSET: f32 = 20.0;
+
+// border color of a node, highlighted if the node is selected
+fn get_node_border_color(is_selected: bool) -> ColorU {
+    if is_selected {
+        ColorU { r: 255, g: 170, b: 0, a: 255 }
+    } else {
+        ColorU { r: 0, g: 180, b: 219, a: 255 }
     }
-    Update::DoNothing
 }
 
-extern "C" fn nodegraph_unset_active_node(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
-    if let Some(mut data) = data.downcast_mut::<NodeGraphLocalDataset>() {
-        data.active_node_being_dragged = None;
+// transform of a node or connection at position x / y (already including the graph offset)
+fn get_graph_transform(x: f32, y: f32, scale_factor: f32) -> CssProperty {
+    let translate = StyleTransform::Translate(StyleTransformTranslate2D {
+        x: PixelValue::px(x),
+        y: PixelValue::px(y),
+    });
+    CssProperty::transform(if scale_factor != 1.0 {
+        vec![
+            translate,
+            StyleTransform::ScaleX(PercentageValue::new(scale_factor * 100.0)),
+            StyleTransform::ScaleY(PercentageValue::new(scale_factor * 100.0)),
+        ]
+    } else {
+        vec![translate]
+    }.into())
+}
+
+// returns the nodes that are moved when dragging the active node:
+// all selected nodes if the active node is selected, otherwise only the active node
+fn get_dragged_nodes(node_graph: &NodeGraph, active_node: NodeGraphNodeId) -> Vec<NodeGraphNodeId> {
+    if node_graph.selected_nodes.as_ref().contains(&active_node) {
+        node_graph.selected_nodes.clone().into_library_owned_vec()
+    } else {
+        vec![active_node]
     }
-    Update::DoNothing
 }
 
-// drag either the graph or the currently active nodes
-extern "C" fn nodegraph_drag_graph_or_nodes(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+// returns the `.node_graph_node` DOM nodes of all rendered nodes, together with their node ID
+fn get_visual_nodes(info: &mut CallbackInfo, node_connection_marker: &RefAny) -> Vec<(DomNodeId, NodeGraphNodeId)> {
 
-    let mut data = match data.downcast_mut::<NodeGraphLocalDataset>() {
-        Some(s) => s,
-        None => return Update::DoNothing,
-    };
-    let mut data = &mut *data;
+    let mut visual_nodes = Vec::new();
 
-    let prev = match info.get_previous_mouse_state() {
+    // the nodes container is the next sibling of the connections container
+    let node_container = match info
+        .get_node_id_of_root_dataset(node_connection_marker.clone())
+        .and_then(|c| info.get_next_sibling(c)) {
         Some(s) => s,
-        None => return Update::DoNothing,
+        None => return visual_nodes,
     };
-    let cur = info.get_current_mouse_state();
-    if !(cur.left_down && prev.left_down) {
-        // event is not a drag event
-        return Update::DoNothing;
-    }
 
-    let (current_mouse_pos, previous_mouse_pos) = match (cur.cursor_position, prev.cursor_position) {
-        (InWindow(c), InWindow(p)) => (c, p),
-        _ => return Update::DoNothing,
-    };
+    let mut node = info.get_first_child(node_container);
 
-    let dx = (current_mouse_pos.x - previous_mouse_pos.x) * (1.0 / data.node_graph.scale_factor);
-    let dy = (current_mouse_pos.y - previous_mouse_pos.y) * (1.0 / data.node_graph.scale_factor);
-    let nodegraph_node = info.get_hit_node();
+    while let Some(node_id) = node {
 
-    let should_update = match data.active_node_being_dragged.clone() {
-        // drag node
-        Some((node_graph_node_id, data_marker)) => {
+        node = info.get_next_sibling(node_id);
 
-            let node_connection_marker = &mut data.node_connection_marker;
+        let node_first_child = match info.get_first_child(node_id) {
+            Some(s) => s,
+            None => continue,
+        };
 
-            let nodegraph_node = info.get_hit_node();
-            let result = match data.callbacks.on_node_dragged.as_mut() {
-                Some(OnNodeDragged { callback, data }) => (callback.cb)(data, info, node_graph_node_id, NodeDragAmount { x: dx, y: dy }),
-                None => Update::DoNothing,
-            };
+        let mut node_local_dataset = match info.get_dataset(node_first_child) {
+            Some(s) => s,
+            None => continue,
+        };
 
-            // update the visual transform of the node in the UI
-            let node_position = match data.node_graph.nodes.iter_mut().find(|i| i.node_id == node_graph_node_id) {
-                Some(s) => {
-                    s.node.position.x += dx;
-                    s.node.position.y += dy;
-                    s.node.position
-                },
-                None => return Update::DoNothing,
-            };
+        let node_id = match node_local_dataset.downcast_ref::<NodeLocalDataset>() {
+            Some(s) => s.node_id,
+            None => continue,
+        };
 
-            let visual_node_id = match info.get_node_id_of_root_dataset(data_marker) {
-                Some(s) => s,
-                None => return Update::DoNothing,
-            };
+        visual_nodes.push((node_first_child, node_id));
+    }
 
-            let node_transform = StyleTransformTranslate2D {
-                x: PixelValue::px(node_position.x + data.node_graph.offset.x),
-                y: PixelValue::px(node_position.y + data.node_graph.offset.y),
-            };
+    visual_nodes
+}
 
-            info.set_css_property(visual_node_id, CssProperty::transform(
-                if data.node_graph.scale_factor != 1.0 {
-                     vec![
-                         StyleTransform::Translate(node_transform),
-                         StyleTransform::ScaleX(PercentageValue::new(data.node_graph.scale_factor * 100.0)),
-                         StyleTransform::ScaleY(PercentageValue::new(data.node_graph.scale_factor * 100.0)),
-                     ]
-                } else {
-                     vec![
-                          StyleTransform::Translate(node_transform)
-                     ]
-                }.into())
-            );
-
-            // get the NodeId of the node containing all the connection lines
-            let connection_container_nodeid = match info.get_node_id_of_root_dataset(node_connection_marker.clone()) {
-                Some(s) => s,
-                None => return result,
-            };
+// updates the transform of the rendered nodes (only the nodes in `moved_nodes` if set)
+fn update_node_transforms(
+    node_graph: &NodeGraph,
+    info: &mut CallbackInfo,
+    node_connection_marker: &RefAny,
+    moved_nodes: Option<&[NodeGraphNodeId]>,
+) {
+    for (visual_node_id, node_id) in get_visual_nodes(info, node_connection_marker) {
+
+        if !moved_nodes.map(|m| m.contains(&node_id)).unwrap_or(true) {
+            continue;
+        }
 
-            // animate all the connections
-            let mut first_connection_child = info.get_first_child(connection_container_nodeid);
+        let node_position = match node_graph.nodes.iter().find(|i| i.node_id == node_id) {
+            Some(s) => s.node.position,
+            None => continue,
+        };
 
-            while let Some(connection_nodeid) = first_connection_child {
+        info.set_css_property(visual_node_id, get_graph_transform(
+            node_position.x + node_graph.offset.x,
+            node_position.y + node_graph.offset.y,
+            node_graph.scale_factor,
+        ));
+    }
+}
 
-                first_connection_child = info.get_next_sibling(connection_nodeid);
+// updates the rects of the rendered connections (only the connections
+// from / to the nodes in `moved_nodes` if set)
+fn update_connection_transforms(
+    node_graph: &NodeGraph,
+    info: &mut CallbackInfo,
+    node_connection_marker: &RefAny,
+    moved_nodes: Option<&[NodeGraphNodeId]>,
+) {
+
+    // get the NodeId of the node containing all the connection lines
+    let connection_container_nodeid = match info.get_node_id_of_root_dataset(node_connection_marker.clone()) {
+        Some(s) => s,
+        None => return,
+    };
 
-                let first_child = match info.get_first_child(connection_nodeid) {
-                    Some(s) => s,
-                    None => continue,
-                };
+    let mut first_connection_child = info.get_first_child(connection_container_nodeid);
 
-                let mut dataset = match info.get_dataset(first_child) {
-                    Some(s) => s,
-                    None => continue,
-                };
+    while let Some(connection_nodeid) = first_connection_child {
 
-                let mut cld = match dataset.downcast_mut::<ConnectionLocalDataset>() {
-                    Some(s) => s,
-                    None => continue,
-                };
+        first_connection_child = info.get_next_sibling(connection_nodeid);
+
+        let first_child = match info.get_first_child(connection_nodeid) {
+            Some(s) => s,
+            None => continue,
+        };
+
+        let mut dataset = match info.get_dataset(first_child) {
+            Some(s) => s,
+            None => continue,
+        };
+
+        let mut cld = match dataset.downcast_mut::<ConnectionLocalDataset>() {
+            Some(s) => s,
+            None => continue,
+        };
+
+        if let Some(m) = moved_nodes {
+            if !(m.contains(&cld.out_node_id) || m.contains(&cld.in_node_id)) {
+                continue; // connection does not need to be modified
+            }
+        }
+
+        let (new_rect, swap_vert, swap_horz) = match get_rect(node_graph, *cld) {
+            Some(s) => s,
+            None => continue,
+        };
+
+        cld.swap_vert = swap_vert;
+        cld.swap_horz = swap_horz;
+
+        info.set_css_property(first_child, get_graph_transform(
+            node_graph.offset.x + new_rect.origin.x,
+            node_graph.offset.y + new_rect.origin.y,
+            node_graph.scale_factor,
+        ));
+        info.set_css_property(first_child, CssProperty::Width(LayoutWidthValue::Exact(
+            LayoutWidth { inner: PixelValue::px(new_rect.size.width) },
+        )));
+        info.set_css_property(first_child, CssProperty::Height(LayoutHeightValue::Exact(
+            LayoutHeight { inner: PixelValue::px(new_rect.size.height) },
+        )));
+    }
+}
+
+// updates the border color of all rendered nodes to reflect the current selection
+fn update_node_highlights(node_graph: &NodeGraph, info: &mut CallbackInfo, node_connection_marker: &RefAny) {
+    for (visual_node_id, node_id) in get_visual_nodes(info, node_connection_marker) {
+        let inner = get_node_border_color(node_graph.selected_nodes.as_ref().contains(&node_id));
+        info.set_css_property(visual_node_id, CssProperty::border_top_color(StyleBorderTopColor { inner }));
+        info.set_css_property(visual_node_id, CssProperty::border_right_color(StyleBorderRightColor { inner }));
+        info.set_css_property(visual_node_id, CssProperty::border_left_color(StyleBorderLeftColor { inner }));
+        info.set_css_property(visual_node_id, CssProperty::border_bottom_color(StyleBorderBottomColor { inner }));
+    }
+}
+
+// returns the position of the `.nodegraph` node in the window
+fn get_nodegraph_origin(info: &CallbackInfo, nodegraph_node: DomNodeId) -> LogicalPosition {
+    info.get_node_position(nodegraph_node)
+        .map(|p| p.get_static_offset())
+        .unwrap_or(LogicalPosition::zero())
+}
+
+// replaces the selected nodes and updates the node highlights
+fn set_selection(data: &mut NodeGraphLocalDataset, info: &mut CallbackInfo, selected_nodes: Vec<NodeGraphNodeId>) {
+    let selected_nodes = NodeGraphNodeIdVec::from(selected_nodes);
+    if selected_nodes == data.node_graph.selected_nodes {
+        return;
+    }
+    data.node_graph.selected_nodes = selected_nodes;
+    data.selection_changed = true;
+    update_node_highlights(&data.node_graph, info, &data.node_connection_marker);
+}
+
+// invokes on_selection_changed if the selection was changed
+fn invoke_selection_changed(data: &mut NodeGraphLocalDataset, info: &mut CallbackInfo) -> Update {
+    if !data.selection_changed {
+        return Update::DoNothing;
+    }
+    data.selection_changed = false;
+    let selected_nodes = data.node_graph.selected_nodes.clone();
+    match data.callbacks.on_selection_changed.as_mut() {
+        Some(OnNodeGraphSelectionChanged { callback, data }) => (callback.cb)(data, info, selected_nodes),
+        None => Update::DoNothing,
+    }
+}
+
+// invokes the callbacks of the application for an edit operation, that has
+// already been applied to `node_graph` (used to replay the undo history)
+fn invoke_edit_op_callbacks(
+    node_graph: &NodeGraph,
+    callbacks: &mut NodeGraphCallbacks,
+    info: &mut CallbackInfo,
+    op: &NodeGraphEditOp,
+) -> Update {
 
-                if !(cld.out_node_id == node_graph_node_id || cld.in_node_id == node_graph_node_id) {
-                    continue; // connection does not need to be modified
+    use self::NodeGraphEditOp::*;
+
+    let mut result = Update::DoNothing;
+
+    match op {
+        AddNode(NodeIdNodeMap { node_id, node }) => {
+            result.max_self(match callbacks.on_node_added.as_mut() {
+                Some(OnNodeAdded { callback, data }) => (callback.cb)(data, info, node.node_type, *node_id, node.position),
+                None => Update::DoNothing,
+            });
+            // restore the field values of the node
+            if let Some(OnNodeFieldEdited { callback, data }) = callbacks.on_node_field_edited.as_mut() {
+                for (field_idx, field) in node.fields.iter().enumerate() {
+                    result.max_self((callback.cb)(data, info, *node_id, field_idx, node.node_type, field.value.clone()));
                 }
+            }
+        },
+        RemoveNode(n) => {
+            result.max_self(match callbacks.on_node_removed.as_mut() {
+                Some(OnNodeRemoved { callback, data }) => (callback.cb)(data, info, n.node_id),
+                None => Update::DoNothing,
+            });
+        },
+        MoveNode(m) => {
+            result.max_self(match callbacks.on_node_dragged.as_mut() {
+                Some(OnNodeDragged { callback, data }) => (callback.cb)(data, info, m.node_id, m.amount),
+                None => Update::DoNothing,
+            });
+        },
+        Connect(c) => {
+            result.max_self(match callbacks.on_node_connected.as_mut() {
+                Some(OnNodeConnected { callback, data }) => (callback.cb)(data, info, c.input, c.input_index, c.output, c.output_index),
+                None => Update::DoNothing,
+            });
+        },
+        Disconnect(c) => {
+            result.max_self(match callbacks.on_node_input_disconnected.as_mut() {
+                Some(OnNodeInputDisconnected { callback, data }) => (callback.cb)(data, info, c.input, c.input_index),
+                None => Update::DoNothing,
+            });
+            // on_node_input_disconnected removes all connections
+            // of the input: reconnect the remaining connections
+            if let Some(OnNodeConnected { callback, data }) = callbacks.on_node_connected.as_mut() {
+                for r in node_graph.get_connections() {
+                    if r.input == c.input && r.input_index == c.input_index {
+                        result.max_self((callback.cb)(data, info, r.input, r.input_index, r.output, r.output_index));
+                    }
+                }
+            }
+        },
+        EditField(e) => {
+            result.max_self(match callbacks.on_node_field_edited.as_mut() {
+                Some(OnNodeFieldEdited { callback, data }) => (callback.cb)(data, info, e.node_id, e.field_idx, e.node_type, e.new_value.clone()),
+                None => Update::DoNothing,
+            });
+        },
+    }
 
+    result
+}
 
-                let (new_rect, swap_vert, swap_horz) = match get_rect(&data.node_graph, *cld) {
-                    Some(s) => s,
-                    None => continue,
-                };
+// applies the edit operations to the local node graph and invokes the callbacks of the application
+fn run_edit_ops(data: &mut NodeGraphLocalDataset, info: &mut CallbackInfo, ops: &[NodeGraphEditOp]) -> Update {
+    let mut result = Update::DoNothing;
+    for op in ops {
+        data.node_graph.apply_edit_op(op);
+        result.max_self(invoke_edit_op_callbacks(&data.node_graph, &mut data.callbacks, info, op));
+    }
+    result
+}
+
+// records the operations as one undo step, clears the redo stack and invokes on_history_changed
+fn push_history(data: &mut NodeGraphLocalDataset, info: &mut CallbackInfo, ops: Vec<NodeGraphEditOp>) -> Update {
+
+    if ops.is_empty() {
+        return Update::DoNothing;
+    }
+
+    let history = &mut data.node_graph.history;
+    history.undo.push(NodeGraphEdit { ops: ops.into() });
+    if history.undo.len() > MAX_UNDO_STEPS {
+        history.undo.remove(0);
+    }
+    history.redo = Vec::new().into();
+
+    invoke_history_changed(data, info)
+}
+
+fn invoke_history_changed(data: &mut NodeGraphLocalDataset, info: &mut CallbackInfo) -> Update {
+    let history = data.node_graph.history.clone();
+    match data.callbacks.on_history_changed.as_mut() {
+        Some(OnNodeGraphHistoryChanged { callback, data }) => (callback.cb)(data, info, history),
+        None => Update::DoNothing,
+    }
+}
+
+// reverts the last edit by replaying the inverted operations in reverse order
+fn undo(data: &mut NodeGraphLocalDataset, info: &mut CallbackInfo) -> Update {
+
+    let edit = match data.node_graph.history.undo.pop() {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+
+    let ops = edit.ops.iter().rev().map(|op| op.invert()).collect::<Vec<_>>();
+    let selected_nodes = data.node_graph.selected_nodes.clone();
+
+    let mut result = run_edit_ops(data, info, &ops);
+    data.node_graph.history.redo.push(edit);
+    result.max_self(invoke_history_changed(data, info));
+
+    data.selection_changed |= selected_nodes != data.node_graph.selected_nodes;
+    result.max_self(invoke_selection_changed(data, info));
+
+    result
+}
+
+// re-applies the last undone edit
+fn redo(data: &mut NodeGraphLocalDataset, info: &mut CallbackInfo) -> Update {
+
+    let edit = match data.node_graph.history.redo.pop() {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+
+    let ops = edit.ops.clone().into_library_owned_vec();
+    let selected_nodes = data.node_graph.selected_nodes.clone();
+
+    let mut result = run_edit_ops(data, info, &ops);
+    data.node_graph.history.undo.push(edit);
+    result.max_self(invoke_history_changed(data, info));
+
+    data.selection_changed |= selected_nodes != data.node_graph.selected_nodes;
+    result.max_self(invoke_selection_changed(data, info));
+
+    result
+}
+
+// removes the nodes and all their connections as one undo step
+fn remove_nodes(data: &mut NodeGraphLocalDataset, info: &mut CallbackInfo, node_ids: &[NodeGraphNodeId]) -> Update {
+
+    let ops = data.node_graph.get_remove_nodes_ops(node_ids);
+    if ops.is_empty() {
+        return Update::DoNothing;
+    }
+
+    let selected_nodes = data.node_graph.selected_nodes.clone();
+
+    let mut result = run_edit_ops(data, info, &ops);
+    result.max_self(push_history(data, info, ops));
+
+    data.selection_changed |= selected_nodes != data.node_graph.selected_nodes;
+    result.max_self(invoke_selection_changed(data, info));
+
+    result
+}
+
+// copies the selected nodes and the connections between them to the clipboard as JSON
+fn copy_selection(data: &mut NodeGraphLocalDataset) {
 
-                cld.swap_vert = swap_vert;
-                cld.swap_horz = swap_horz;
+    use azul_desktop::app::Clipboard;
 
-                let node_transform = StyleTransformTranslate2D {
-                    x: PixelValue::px(data.node_graph.offset.x + new_rect.origin.x),
-                    y: PixelValue::px(data.node_graph.offset.y + new_rect.origin.y),
+    if data.node_graph.selected_nodes.is_empty() {
+        return;
+    }
+
+    let json = data.node_graph.subgraph_to_json(data.node_graph.selected_nodes.as_ref());
+    if let Some(mut clipboard) = Clipboard::new() {
+        let _ = clipboard.set_clipboard_string(json.into());
+    }
+}
+
+// inserts the nodes from the clipboard with new node IDs and selects them
+fn paste(data: &mut NodeGraphLocalDataset, info: &mut CallbackInfo) -> Update {
+
+    use azul_desktop::app::Clipboard;
+
+    let text = match Clipboard::new().and_then(|c| c.get_clipboard_string()) {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+
+    let subgraph = match NodeGraph::from_json(text.as_str()) {
+        Ok(o) => o,
+        Err(_) => return Update::DoNothing,
+    };
+
+    let ops = data.node_graph.get_insert_subgraph_ops(&subgraph, NodeDragAmount { x: PASTE_OFFSET, y: PASTE_OFFSET });
+    if ops.is_empty() {
+        return Update::DoNothing;
+    }
+
+    let pasted_nodes = ops.iter().filter_map(|op| match op {
+        NodeGraphEditOp::AddNode(n) => Some(n.node_id),
+        _ => None,
+    }).collect::<Vec<_>>();
+
+    let mut result = run_edit_ops(data, info, &ops);
+    result.max_self(push_history(data, info, ops));
+
+    set_selection(data, info, pasted_nodes);
+    result.max_self(invoke_selection_changed(data, info));
+
+    result
+}
+
+extern "C" fn nodegraph_set_active_node(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    let (node_id, mut backref) = match data.downcast_ref::<NodeLocalDataset>() {
+        Some(s) => (s.node_id, s.backref.clone()),
+        None => return Update::DoNothing,
+    };
+
+    let mut backref = match backref.downcast_mut::<NodeGraphLocalDataset>() {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+    let backref = &mut *backref;
+
+    backref.active_node_being_dragged = Some(node_id);
+
+    // ctrl + click toggles the selection of the node, clicking
+    // on a node that is not selected selects only this node
+    let mut selected_nodes = backref.node_graph.selected_nodes.clone().into_library_owned_vec();
+    let position = selected_nodes.iter().position(|i| *i == node_id);
+    if info.get_current_keyboard_state().ctrl_down() {
+        match position {
+            Some(p) => { selected_nodes.remove(p); },
+            None => selected_nodes.push(node_id),
+        }
+    } else if position.is_none() {
+        selected_nodes = vec![node_id];
+    }
+
+    set_selection(backref, info, selected_nodes);
+
+    Update::DoNothing
+}
+
+// focuses the node graph when clicking on the title of a node, so
+// that the keyboard shortcuts (copy, paste, delete, ...) work
+extern "C" fn nodegraph_focus_graph(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    let backref = match data.downcast_ref::<NodeLocalDataset>() {
+        Some(s) => s.backref.clone(),
+        None => return Update::DoNothing,
+    };
+
+    let nodegraph_node = info
+        .get_node_id_of_root_dataset(backref)
+        .and_then(|wrapper| info.get_first_child(wrapper));
+
+    if let Some(nodegraph_node) = nodegraph_node {
+        info.set_focus(FocusTarget::Id(nodegraph_node));
+    }
+
+    Update::DoNothing
+}
+
+extern "C" fn nodegraph_unset_active_node(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    let mut data = match data.downcast_mut::<NodeGraphLocalDataset>() {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+    let data = &mut *data;
+
+    let nodegraph_node = info.get_hit_node();
+    let mut result = Update::DoNothing;
+
+    match (data.active_node_being_dragged.take(), data.selection_box_start.take()) {
+        // end of a node drag: record the movement of the nodes as one undo step
+        (Some(active_node), _) => {
+            let drag_amount = data.drag_amount;
+            if drag_amount.x != 0.0 || drag_amount.y != 0.0 {
+                let ops = get_dragged_nodes(&data.node_graph, active_node)
+                    .into_iter()
+                    .map(|node_id| NodeGraphEditOp::MoveNode(NodeGraphNodeMove { node_id, amount: drag_amount }))
+                    .collect();
+                result.max_self(push_history(data, info, ops));
+            }
+        },
+        // end of a selection rectangle: select all nodes intersecting the rectangle
+        (None, Some(selection_box_start)) => {
+            info.set_focus(FocusTarget::Id(nodegraph_node));
+            if let InWindow(cursor) = info.get_current_mouse_state().cursor_position {
+                let selected_nodes = get_nodes_in_selection_box(data, info, nodegraph_node, selection_box_start, cursor);
+                let selected_nodes = if info.get_current_keyboard_state().ctrl_down() {
+                    let mut s = data.node_graph.selected_nodes.clone().into_library_owned_vec();
+                    s.extend(selected_nodes.into_iter().filter(|n| !s.contains(n)).collect::<Vec<_>>());
+                    s
+                } else {
+                    selected_nodes
                 };
+                set_selection(data, info, selected_nodes);
+            }
+            if let Some(selection_box) = info.get_node_id_of_root_dataset(data.selection_box_marker.clone()) {
+                info.set_css_property(selection_box, CssProperty::width(LayoutWidth::const_px(0)));
+                info.set_css_property(selection_box, CssProperty::height(LayoutHeight::const_px(0)));
+            }
+        },
+        // click on the background: clear the selection
+        (None, None) => {
+            info.set_focus(FocusTarget::Id(nodegraph_node));
+            if !data.graph_dragged {
+                set_selection(data, info, Vec::new());
+            }
+        },
+    }
+
+    data.drag_amount = NodeDragAmount { x: 0.0, y: 0.0 };
+    data.graph_dragged = false;
+
+    result.max_self(invoke_selection_changed(data, info));
+
+    result
+}
+
+// returns the nodes that intersect the rectangle between two cursor positions
+fn get_nodes_in_selection_box(
+    data: &NodeGraphLocalDataset,
+    info: &mut CallbackInfo,
+    nodegraph_node: DomNodeId,
+    start: LogicalPosition,
+    end: LogicalPosition,
+) -> Vec<NodeGraphNodeId> {
+
+    let origin = get_nodegraph_origin(info, nodegraph_node);
+    let node_graph = &data.node_graph;
+
+    // convert from window to graph coordinates
+    let to_graph = |p: LogicalPosition| LogicalPosition {
+        x: (p.x - origin.x) / node_graph.scale_factor - node_graph.offset.x,
+        y: (p.y - origin.y) / node_graph.scale_factor - node_graph.offset.y,
+    };
+
+    let (a, b) = (to_graph(start), to_graph(end));
+    let (min_x, max_x) = (a.x.min(b.x), a.x.max(b.x));
+    let (min_y, max_y) = (a.y.min(b.y), a.y.max(b.y));
+
+    get_visual_nodes(info, &data.node_connection_marker)
+    .into_iter()
+    .filter_map(|(visual_node_id, node_id)| {
+        let position = node_graph.nodes.iter().find(|i| i.node_id == node_id)?.node.position;
+        // layout size, not affected by the scale transform
+        let size = info.get_node_size(visual_node_id).unwrap_or(LogicalSize::new(NODE_WIDTH, 0.0));
+        let intersects = position.x <= max_x && position.x + size.width >= min_x &&
+                         position.y <= max_y && position.y + size.height >= min_y;
+        if intersects { Some(node_id) } else { None }
+    })
+    .collect()
+}
+
+// drag either the graph, the currently active nodes or the selection rectangle
+extern "C" fn nodegraph_drag_graph_or_nodes(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    let mut data = match data.downcast_mut::<NodeGraphLocalDataset>() {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+    let data = &mut *data;
+
+    let prev = match info.get_previous_mouse_state() {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+    let cur = info.get_current_mouse_state();
+    if !(cur.left_down && prev.left_down) {
+        // event is not a drag event
+        return Update::DoNothing;
+    }
+
+    let (current_mouse_pos, previous_mouse_pos) = match (cur.cursor_position, prev.cursor_position) {
+        (InWindow(c), InWindow(p)) => (c, p),
+        _ => return Update::DoNothing,
+    };
+
+    let dx = (current_mouse_pos.x - previous_mouse_pos.x) * (1.0 / data.node_graph.scale_factor);
+    let dy = (current_mouse_pos.y - previous_mouse_pos.y) * (1.0 / data.node_graph.scale_factor);
+    let nodegraph_node = info.get_hit_node();
+
+    // shift + drag on the background draws a selection rectangle
+    let is_selection_box = data.active_node_being_dragged.is_none() && (
+        data.selection_box_start.is_some() ||
+        (!data.graph_dragged && info.get_current_keyboard_state().shift_down())
+    );
+
+    if is_selection_box {
+
+        let start = *data.selection_box_start.get_or_insert(previous_mouse_pos);
+        let origin = get_nodegraph_origin(info, nodegraph_node);
+
+        if let Some(selection_box) = info.get_node_id_of_root_dataset(data.selection_box_marker.clone()) {
+            info.set_css_property(selection_box, get_graph_transform(
+                start.x.min(current_mouse_pos.x) - origin.x,
+                start.y.min(current_mouse_pos.y) - origin.y,
+                1.0,
+            ));
+            info.set_css_property(selection_box, CssProperty::Width(LayoutWidthValue::Exact(
+                LayoutWidth { inner: PixelValue::px((current_mouse_pos.x - start.x).abs()) },
+            )));
+            info.set_css_property(selection_box, CssProperty::Height(LayoutHeightValue::Exact(
+                LayoutHeight { inner: PixelValue::px((current_mouse_pos.y - start.y).abs()) },
+            )));
+        }
+
+        info.stop_propagation();
+        return Update::DoNothing;
+    }
+
+    let should_update = match data.active_node_being_dragged {
+        // drag node(s)
+        Some(active_node) => {
+
+            let dragged_nodes = get_dragged_nodes(&data.node_graph, active_node);
+            let mut result = Update::DoNothing;
+
+            for node_id in dragged_nodes.iter() {
+
+                result.max_self(match data.callbacks.on_node_dragged.as_mut() {
+                    Some(OnNodeDragged { callback, data }) => (callback.cb)(data, info, *node_id, NodeDragAmount { x: dx, y: dy }),
+                    None => Update::DoNothing,
+                });
 
-                info.set_css_property(first_child, CssProperty::transform(
-                    if data.node_graph.scale_factor != 1.0 {
-                         vec![
-                             StyleTransform::Translate(node_transform),
-                             StyleTransform::ScaleX(PercentageValue::new(data.node_graph.scale_factor * 100.0)),
-                             StyleTransform::ScaleY(PercentageValue::new(data.node_graph.scale_factor * 100.0)),
-                         ]
-                    } else {
-                         vec![
-                              StyleTransform::Translate(node_transform)
-                         ]
-                    }.into())
-                );
-
-                info.set_css_property(first_child, CssProperty::Width(LayoutWidthValue::Exact(
-                    LayoutWidth { inner: PixelValue::px(new_rect.size.width) },
-                )));
-                info.set_css_property(first_child, CssProperty::Height(LayoutHeightValue::Exact(
-                    LayoutHeight { inner: PixelValue::px(new_rect.size.height) },
-                )));
+                if let Some(s) = data.node_graph.nodes.as_mut().iter_mut().find(|i| i.node_id == *node_id) {
+                    s.node.position.x += dx;
+                    s.node.position.y += dy;
+                }
             }
 
+            data.drag_amount.x += dx;
+            data.drag_amount.y += dy;
+
+            // update the visual transform of the nodes and connections in the UI
+            update_node_transforms(&data.node_graph, info, &data.node_connection_marker, Some(&dragged_nodes));
+            update_connection_transforms(&data.node_graph, info, &data.node_connection_marker, Some(&dragged_nodes));
+
             result
         },
         // drag graph
@@ -2824,123 +3871,117 @@ extern "C" fn nodegraph_drag_graph_or_nodes(data: &mut RefAny, info: &mut Callba
 
             data.node_graph.offset.x += dx;
             data.node_graph.offset.y += dy;
+            data.graph_dragged = true;
 
-            // Update the visual node positions
-            let node_container = match info.get_first_child(nodegraph_node) {
-                Some(s) => s,
-                None => return Update::DoNothing,
-            };
-
-            let node_container = match info.get_next_sibling(node_container) {
-                Some(s) => s,
-                None => return Update::DoNothing,
-            };
+            // update the visual node and connection positions
+            update_node_transforms(&data.node_graph, info, &data.node_connection_marker, None);
+            update_connection_transforms(&data.node_graph, info, &data.node_connection_marker, None);
 
-            let mut node = match info.get_first_child(node_container) {
-                Some(s) => s,
-                None => return Update::DoNothing,
-            };
-
-            loop {
+            result
+        }
+    };
 
-                let mut node_first_child = match info.get_first_child(node) {
-                    Some(s) => s,
-                    None => return Update::DoNothing,
-                };
+    info.stop_propagation();
 
-                let mut node_local_dataset = match info.get_dataset(node_first_child) {
-                    None => return Update::DoNothing,
-                    Some(s) => s,
-                };
+    should_update
+}
 
-                let mut node_graph_node_id = match node_local_dataset.downcast_ref::<NodeLocalDataset>() {
-                    Some(s) => s,
-                    None => continue,
-                };
+// zooms the graph with the mouse wheel, keeping the point under the cursor in place
+extern "C" fn nodegraph_zoom(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
 
-                let node_graph_node_id = node_graph_node_id.node_id;
+    let mut data = match data.downcast_mut::<NodeGraphLocalDataset>() {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+    let data = &mut *data;
 
-                let node_position = match data.node_graph.nodes.iter().find(|i| i.node_id == node_graph_node_id) {
-                    Some(s) => s.node.position,
-                    None => continue,
-                };
+    let mouse_state = info.get_current_mouse_state();
 
-                let node_transform = StyleTransformTranslate2D {
-                    x: PixelValue::px(node_position.x + data.node_graph.offset.x),
-                    y: PixelValue::px(node_position.y + data.node_graph.offset.y),
-                };
+    // scroll_y is in wheel "notches", positive = scrolling up = zooming in
+    let scroll_y = mouse_state.get_scroll_y();
+    if scroll_y == 0.0 {
+        return Update::DoNothing;
+    }
 
-                info.set_css_property(node_first_child, CssProperty::transform(
-                    if data.node_graph.scale_factor != 1.0 {
-                         vec![
-                             StyleTransform::Translate(node_transform),
-                             StyleTransform::ScaleX(PercentageValue::new(data.node_graph.scale_factor * 100.0)),
-                             StyleTransform::ScaleY(PercentageValue::new(data.node_graph.scale_factor * 100.0)),
-                         ]
-                    } else {
-                         vec![
-                              StyleTransform::Translate(node_transform)
-                         ]
-                    }.into())
-                );
-
-                node = match info.get_next_sibling(node) {
-                    Some(s) => s,
-                    None => break,
-                };
-            }
+    let cursor = match mouse_state.cursor_position {
+        InWindow(c) => c,
+        _ => return Update::DoNothing,
+    };
 
-            let node_connection_marker = &mut data.node_connection_marker;
+    let origin = get_nodegraph_origin(info, info.get_hit_node());
+    let cursor = LogicalPosition::new(cursor.x - origin.x, cursor.y - origin.y);
 
-            // Update the connection positions
-            let connection_container_nodeid = match info.get_node_id_of_root_dataset(node_connection_marker.clone()) {
-                Some(s) => s,
-                None => return result,
-            };
+    let old_scale_factor = data.node_graph.scale_factor;
+    let new_scale_factor = (old_scale_factor * ZOOM_STEP.powf(scroll_y)).clamp(MIN_ZOOM, MAX_ZOOM);
+    if new_scale_factor == old_scale_factor {
+        return Update::DoNothing;
+    }
 
-            let mut first_connection_child = info.get_first_child(connection_container_nodeid);
+    // screen position = (node position + offset) * scale_factor,
+    // solve for the offset that keeps the cursor position constant
+    data.node_graph.offset.x += cursor.x / new_scale_factor - cursor.x / old_scale_factor;
+    data.node_graph.offset.y += cursor.y / new_scale_factor - cursor.y / old_scale_factor;
+    data.node_graph.scale_factor = new_scale_factor;
 
-            while let Some(connection_nodeid) = first_connection_child {
+    let new_offset = data.node_graph.offset;
+    let result = match data.callbacks.on_node_graph_zoomed.as_mut() {
+        Some(OnNodeGraphZoomed { callback, data }) => (callback.cb)(data, info, new_scale_factor, new_offset),
+        None => Update::DoNothing,
+    };
 
-                first_connection_child = info.get_next_sibling(connection_nodeid);
+    update_node_transforms(&data.node_graph, info, &data.node_connection_marker, None);
+    update_connection_transforms(&data.node_graph, info, &data.node_connection_marker, None);
 
-                let first_child = match info.get_first_child(connection_nodeid) {
-                    Some(s) => s,
-                    None => continue,
-                };
+    info.stop_propagation();
 
-                let mut dataset = match info.get_dataset(first_child) {
-                    Some(s) => s,
-                    None => continue,
-                };
+    result
+}
 
-                let cld = match dataset.downcast_ref::<ConnectionLocalDataset>() {
-                    Some(s) => s,
-                    None => continue,
-                };
+// keyboard shortcuts: delete, copy / cut / paste, select all, undo / redo
+extern "C" fn nodegraph_on_key_down(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
 
-                let (new_rect, _, _) = match get_rect(&data.node_graph, *cld) {
-                    Some(s) => s,
-                    None => continue,
-                };
+    use azul_core::window::VirtualKeyCode;
 
-                info.set_css_property(first_child, CssProperty::transform(vec![
-                    StyleTransform::Translate(StyleTransformTranslate2D {
-                        x: PixelValue::px(data.node_graph.offset.x + new_rect.origin.x),
-                        y: PixelValue::px(data.node_graph.offset.y + new_rect.origin.y),
-                    }),
-                    StyleTransform::ScaleX(PercentageValue::new(data.node_graph.scale_factor * 100.0)),
-                    StyleTransform::ScaleY(PercentageValue::new(data.node_graph.scale_factor * 100.0)),
-                ].into()));
-            }
+    let mut data = match data.downcast_mut::<NodeGraphLocalDataset>() {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+    let data = &mut *data;
 
-            result
-        }
+    let keyboard_state = info.get_current_keyboard_state();
+    let keycode = match keyboard_state.current_virtual_keycode.into_option() {
+        Some(s) => s,
+        None => return Update::DoNothing,
     };
 
-    info.stop_propagation();
+    let ctrl = keyboard_state.ctrl_down();
+    let shift = keyboard_state.shift_down();
 
-    should_update
+    match keycode {
+        VirtualKeyCode::Delete | VirtualKeyCode::Back => {
+            let selected_nodes = data.node_graph.selected_nodes.clone();
+            remove_nodes(data, info, selected_nodes.as_ref())
+        },
+        VirtualKeyCode::A if ctrl => {
+            let all_nodes = data.node_graph.nodes.iter().map(|n| n.node_id).collect();
+            set_selection(data, info, all_nodes);
+            invoke_selection_changed(data, info)
+        },
+        VirtualKeyCode::C if ctrl => {
+            copy_selection(data);
+            Update::DoNothing
+        },
+        VirtualKeyCode::X if ctrl => {
+            copy_selection(data);
+            let selected_nodes = data.node_graph.selected_nodes.clone();
+            remove_nodes(data, info, selected_nodes.as_ref())
+        },
+        VirtualKeyCode::V if ctrl => paste(data, info),
+        VirtualKeyCode::Z if ctrl && shift => redo(data, info),
+        VirtualKeyCode::Z if ctrl => undo(data, info),
+        VirtualKeyCode::Y if ctrl => redo(data, info),
+        _ => Update::DoNothing,
+    }
 }
 
 extern "C" fn nodegraph_duplicate_node(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
@@ -2967,12 +4008,7 @@ extern "C" fn nodegraph_delete_node(data: &mut RefAny, info: &mut CallbackInfo)
         None => return Update::DoNothing,
     };
 
-    let result = match backref.callbacks.on_node_removed.as_mut() {
-        Some(OnNodeRemoved { callback, data }) => (callback.cb)(data, info, node_id),
-        None => Update::DoNothing
-    };
-
-    result
+    remove_nodes(&mut backref, info, &[node_id])
 }
 
 extern "C" fn nodegraph_context_menu_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
@@ -3015,10 +4051,19 @@ extern "C" fn nodegraph_context_menu_click(data: &mut RefAny, info: &mut Callbac
 
     let new_node_id = backref.node_graph.generate_unique_node_id();
 
-    let result = match backref.callbacks.on_node_added.as_mut() {
-        Some(OnNodeAdded { callback, data }) => (callback.cb)(data, info, new_node_type, new_node_id, new_node_pos),
-        None => Update::DoNothing,
-    };
+    let op = NodeGraphEditOp::AddNode(NodeIdNodeMap {
+        node_id: new_node_id,
+        node: Node {
+            node_type: new_node_type,
+            position: new_node_pos,
+            fields: Vec::new().into(),
+            connect_in: Vec::new().into(),
+            connect_out: Vec::new().into(),
+        },
+    });
+
+    let mut result = run_edit_ops(&mut backref, info, core::slice::from_ref(&op));
+    result.max_self(push_history(&mut backref, info, vec![op]));
 
     result
 }
@@ -3075,15 +4120,20 @@ extern "C" fn nodegraph_input_output_connect(data: &mut RefAny, info: &mut Callb
         }
     }
 
-    let result = match backref.callbacks.on_node_connected.as_mut() {
-        Some(OnNodeConnected { callback, data }) => {
-            let r = (callback.cb)(data, info, input_node, input_index, output_node, output_index);
-            backref.last_input_or_output_clicked = None;
-            r
-        },
+    backref.last_input_or_output_clicked = None;
+
+    let mut result = match backref.callbacks.on_node_connected.as_mut() {
+        Some(OnNodeConnected { callback, data }) => (callback.cb)(data, info, input_node, input_index, output_node, output_index),
         None => Update::DoNothing,
     };
 
+    result.max_self(push_history(&mut backref, info, vec![NodeGraphEditOp::Connect(NodeGraphConnection {
+        output: output_node,
+        output_index,
+        input: input_node,
+        input_index,
+    })]));
+
     result
 }
 
@@ -3110,6 +4160,15 @@ extern "C" fn nodegraph_input_output_disconnect(data: &mut RefAny, info: &mut Ca
         None => return Update::DoNothing,
     };
 
+    // connections that are removed by disconnecting the input / output
+    let removed_connections = backref.node_graph.get_connections()
+        .into_iter()
+        .filter(|c| match io_id {
+            Input(i) => c.input == node_id && c.input_index == i,
+            Output(o) => c.output == node_id && c.output_index == o,
+        })
+        .collect::<Vec<_>>();
+
     let mut result = Update::DoNothing;
     match io_id {
         Input(i) => {
@@ -3126,10 +4185,18 @@ extern "C" fn nodegraph_input_output_disconnect(data: &mut RefAny, info: &mut Ca
         }
     };
 
+    for c in removed_connections.iter() {
+        backref.node_graph.remove_connection(*c);
+    }
+
+    let ops = removed_connections.into_iter().map(NodeGraphEditOp::Disconnect).collect();
+    result.max_self(push_history(&mut backref, info, ops));
+
     result
 }
 
-extern "C" fn nodegraph_on_textinput_focus_lost(data: &mut RefAny, info: &mut CallbackInfo, textinputstate: &TextInputState) -> Update {
+// records the new value of a node field as an undo step and invokes on_node_field_edited
+fn nodegraph_edit_field(data: &mut RefAny, info: &mut CallbackInfo, new_value: NodeTypeFieldValue) -> Update {
 
     let mut data = match data.downcast_mut::<NodeFieldLocalDataset>() {
         Some(s) => s,
@@ -3149,151 +4216,263 @@ extern "C" fn nodegraph_on_textinput_focus_lost(data: &mut RefAny, info: &mut Ca
         Some(s) => s,
         None => return Update::DoNothing,
     };
+    let node_graph = &mut *node_graph;
 
-    let node_type = match node_graph.node_graph.nodes.iter().find(|i| i.node_id == node_id) {
-        Some(s) => s.node.node_type,
+    let (node_type, old_value) = match node_graph.node_graph.nodes.iter().find(|i| i.node_id == node_id) {
+        Some(s) => (s.node.node_type, s.node.fields.get(field_idx).map(|f| f.value.clone())),
         None => return Update::DoNothing,
     };
 
-    let result = match node_graph.callbacks.on_node_field_edited.as_mut() {
-        Some(OnNodeFieldEdited { data, callback }) => (callback.cb)(data, info, node_id, field_idx, node_type, NodeTypeFieldValue::TextInput(textinputstate.get_text().into())),
-        None => Update::DoNothing,
-    };
+    match old_value {
+        Some(old_value) => {
+            if old_value == new_value {
+                return Update::DoNothing;
+            }
+            let op = NodeGraphEditOp::EditField(NodeGraphFieldEdit {
+                node_id,
+                node_type,
+                field_idx,
+                old_value,
+                new_value,
+            });
+            let mut result = run_edit_ops(node_graph, info, core::slice::from_ref(&op));
+            result.max_self(push_history(node_graph, info, vec![op]));
+            result
+        },
+        // field is not stored in the node graph, can't be undone
+        None => match node_graph.callbacks.on_node_field_edited.as_mut() {
+            Some(OnNodeFieldEdited { data, callback }) => (callback.cb)(data, info, node_id, field_idx, node_type, new_value),
+            None => Update::DoNothing,
+        },
+    }
+}
 
-    result
+extern "C" fn nodegraph_on_textinput_focus_lost(data: &mut RefAny, info: &mut CallbackInfo, textinputstate: &TextInputState) -> Update {
+    nodegraph_edit_field(data, info, NodeTypeFieldValue::TextInput(textinputstate.get_text().into()))
 }
 
 extern "C" fn nodegraph_on_numberinput_focus_lost(data: &mut RefAny, info: &mut CallbackInfo, numberinputstate: &NumberInputState) -> Update {
-
-    let mut data = match data.downcast_mut::<NodeFieldLocalDataset>() {
-        Some(s) => s,
-        None => return Update::DoNothing,
-    };
-
-    let field_idx = data.field_idx;
-
-    let mut node_local_dataset = match data.backref.downcast_mut::<NodeLocalDataset>() {
-        Some(s) => s,
-        None => return Update::DoNothing,
-    };
-
-    let node_id = node_local_dataset.node_id;
-
-    let mut node_graph = match node_local_dataset.backref.downcast_mut::<NodeGraphLocalDataset>() {
-        Some(s) => s,
-        None => return Update::DoNothing,
-    };
-
-    let node_type = match node_graph.node_graph.nodes.iter().find(|i| i.node_id == node_id) {
-        Some(s) => s.node.node_type,
-        None => return Update::DoNothing,
-    };
-
-    let result = match node_graph.callbacks.on_node_field_edited.as_mut() {
-        Some(OnNodeFieldEdited { data, callback }) => (callback.cb)(data, info, node_id, field_idx, node_type, NodeTypeFieldValue::NumberInput(numberinputstate.number)),
-        None => Update::DoNothing,
-    };
-
-    result
+    nodegraph_edit_field(data, info, NodeTypeFieldValue::NumberInput(numberinputstate.number))
 }
 
 extern "C" fn nodegraph_on_checkbox_value_changed(data: &mut RefAny, info: &mut CallbackInfo, checkboxinputstate: &CheckBoxState) -> Update {
+    nodegraph_edit_field(data, info, NodeTypeFieldValue::CheckBox(checkboxinputstate.checked))
+}
 
-    let mut data = match data.downcast_mut::<NodeFieldLocalDataset>() {
-        Some(s) => s,
-        None => return Update::DoNothing,
-    };
-
-    let field_idx = data.field_idx;
+extern "C" fn nodegraph_on_colorinput_value_changed(data: &mut RefAny, info: &mut CallbackInfo, colorinputstate: &ColorInputState) -> Update {
+    nodegraph_edit_field(data, info, NodeTypeFieldValue::ColorInput(colorinputstate.color))
+}
 
-    let mut node_local_dataset = match data.backref.downcast_mut::<NodeLocalDataset>() {
-        Some(s) => s,
-        None => return Update::DoNothing,
-    };
+extern "C" fn nodegraph_on_fileinput_button_clicked(data: &mut RefAny, info: &mut CallbackInfo, file: &FileInputState) -> Update {
+    nodegraph_edit_field(data, info, NodeTypeFieldValue::FileInput(file.path.clone()))
+}
 
-    let node_id = node_local_dataset.node_id;
+/// Renders a `<node-graph />` XML node: an empty graph, since the node types
+/// and the nodes have to be set by the application
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeGraphRenderer {
+    node: XmlNode,
+}
 
-    let mut node_graph = match node_local_dataset.backref.downcast_mut::<NodeGraphLocalDataset>() {
-        Some(s) => s,
-        None => return Update::DoNothing,
-    };
+impl NodeGraphRenderer {
+    pub fn new() -> Self {
+        Self {
+            node: XmlNode::new("node_graph"),
+        }
+    }
+}
 
-    let node_type = match node_graph.node_graph.nodes.iter().find(|i| i.node_id == node_id) {
-        Some(s) => s.node.node_type,
-        None => return Update::DoNothing,
-    };
+impl Default for NodeGraphRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-    let result = match node_graph.callbacks.on_node_field_edited.as_mut() {
-        Some(OnNodeFieldEdited { data, callback }) => (callback.cb)(data, info, node_id, field_idx, node_type, NodeTypeFieldValue::CheckBox(checkboxinputstate.checked)),
-        None => Update::DoNothing,
-    };
+impl XmlComponentTrait for NodeGraphRenderer {
+    fn get_type_id(&self) -> String {
+        "node_graph".to_string()
+    }
 
-    result
-}
+    fn get_available_arguments(&self) -> ComponentArguments {
+        ComponentArguments {
+            args: Vec::new(),
+            accepts_text: false,
+        }
+    }
 
-extern "C" fn nodegraph_on_colorinput_value_changed(data: &mut RefAny, info: &mut CallbackInfo, colorinputstate: &ColorInputState) -> Update {
+    fn render_dom(
+        &self,
+        _: &XmlComponentMap,
+        _: &FilteredComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<StyledDom, RenderDomError> {
+        Ok(NodeGraph::default().dom().style(Css::empty()))
+    }
 
-    let mut data = match data.downcast_mut::<NodeFieldLocalDataset>() {
-        Some(s) => s,
-        None => return Update::DoNothing,
-    };
+    fn compile_to_rust_code(
+        &self,
+        _: &XmlComponentMap,
+        _: &ComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<String, CompileError> {
+        Ok(String::from("NodeGraph::default().dom()"))
+    }
 
-    let field_idx = data.field_idx;
+    fn get_xml_node(&self) -> XmlNode {
+        self.node.clone()
+    }
+}
 
-    let mut node_local_dataset = match data.backref.downcast_mut::<NodeLocalDataset>() {
-        Some(s) => s,
-        None => return Update::DoNothing,
-    };
+#[cfg(test)]
+mod tests {
 
-    let node_id = node_local_dataset.node_id;
-    let mut node_graph = match node_local_dataset.backref.downcast_mut::<NodeGraphLocalDataset>() {
-        Some(s) => s,
-        None => return Update::DoNothing,
-    };
+    use super::*;
 
-    let node_type = match node_graph.node_graph.nodes.iter().find(|i| i.node_id == node_id) {
-        Some(s) => s.node.node_type,
-        None => return Update::DoNothing,
-    };
+    fn test_node(node_id: u64, fields: Vec<NodeTypeField>) -> NodeIdNodeMap {
+        NodeIdNodeMap {
+            node_id: NodeGraphNodeId { inner: node_id },
+            node: Node {
+                node_type: NodeTypeId { inner: 1 },
+                position: NodePosition { x: node_id as f32 * 10.0, y: -5.5 },
+                fields: fields.into(),
+                connect_in: Vec::new().into(),
+                connect_out: Vec::new().into(),
+            },
+        }
+    }
 
-    let result = match node_graph.callbacks.on_node_field_edited.as_mut() {
-        Some(OnNodeFieldEdited { data, callback }) => (callback.cb)(data, info, node_id, field_idx, node_type, NodeTypeFieldValue::ColorInput(colorinputstate.color)),
-        None => Update::DoNothing,
-    };
+    fn test_graph() -> NodeGraph {
+        let mut node_graph = NodeGraph::default();
+        node_graph.offset = LogicalPosition::new(12.5, -3.0);
+        node_graph.scale_factor = 0.75;
+        node_graph.nodes = vec![
+            test_node(1, vec![
+                NodeTypeField { key: "text".into(), value: NodeTypeFieldValue::TextInput("a \"quoted\"\n\u{e4} \u{1F600}".into()) },
+                NodeTypeField { key: "number".into(), value: NodeTypeFieldValue::NumberInput(1.5) },
+                NodeTypeField { key: "check".into(), value: NodeTypeFieldValue::CheckBox(true) },
+                NodeTypeField { key: "color".into(), value: NodeTypeFieldValue::ColorInput(ColorU { r: 255, g: 0, b: 128, a: 64 }) },
+                NodeTypeField { key: "file".into(), value: NodeTypeFieldValue::FileInput(None.into()) },
+                NodeTypeField { key: "file2".into(), value: NodeTypeFieldValue::FileInput(Some(AzString::from("C:\\path\\file.txt")).into()) },
+            ]),
+            test_node(2, Vec::new()),
+            test_node(3, Vec::new()),
+        ].into();
+        node_graph.insert_connection(NodeGraphConnection {
+            output: NodeGraphNodeId { inner: 1 }, output_index: 0,
+            input: NodeGraphNodeId { inner: 2 }, input_index: 1,
+        }).unwrap();
+        node_graph.insert_connection(NodeGraphConnection {
+            output: NodeGraphNodeId { inner: 2 }, output_index: 0,
+            input: NodeGraphNodeId { inner: 3 }, input_index: 0,
+        }).unwrap();
+        node_graph
+    }
 
-    result
-}
+    fn assert_same_graph(a: &NodeGraph, b: &NodeGraph) {
+        assert_eq!(a.nodes.len(), b.nodes.len());
+        for (a, b) in a.nodes.iter().zip(b.nodes.iter()) {
+            assert_eq!(a.node_id, b.node_id);
+            assert_eq!(a.node.node_type, b.node.node_type);
+            assert_eq!((a.node.position.x, a.node.position.y), (b.node.position.x, b.node.position.y));
+            assert_eq!(a.node.fields.len(), b.node.fields.len());
+            for (fa, fb) in a.node.fields.iter().zip(b.node.fields.iter()) {
+                assert_eq!(fa.key, fb.key);
+                assert_eq!(fa.value, fb.value);
+            }
+        }
+        let mut ca = a.get_connections();
+        let mut cb = b.get_connections();
+        ca.sort();
+        cb.sort();
+        assert_eq!(ca, cb);
+    }
 
-extern "C" fn nodegraph_on_fileinput_button_clicked(data: &mut RefAny, info: &mut CallbackInfo, file: &FileInputState) -> Update {
+    #[test]
+    fn test_node_graph_json_roundtrip() {
+        let node_graph = test_graph();
+        let json = node_graph.to_json();
+        let loaded = NodeGraph::from_json(json.as_str()).unwrap();
+        assert_same_graph(&node_graph, &loaded);
+        assert_eq!(loaded.offset, node_graph.offset);
+        assert_eq!(loaded.scale_factor, node_graph.scale_factor);
+        assert_eq!(loaded.to_json(), json);
+
+        assert!(NodeGraph::from_json("{}").is_err());
+        assert!(NodeGraph::from_json("{\"nodes\":[{\"id\":1}]}").is_err());
+        // connection to a node that does not exist
+        assert!(NodeGraph::from_json(r#"{"nodes":[],"connections":[{"output":1,"output_index":0,"input":2,"input_index":0}]}"#).is_err());
+    }
 
-    let mut data = match data.downcast_mut::<NodeFieldLocalDataset>() {
-        Some(s) => s,
-        None => return Update::DoNothing,
-    };
+    #[test]
+    fn test_node_graph_undo_roundtrip() {
+        let original = test_graph();
+        let mut node_graph = original.clone();
+
+        let ops = vec![
+            NodeGraphEditOp::MoveNode(NodeGraphNodeMove { node_id: NodeGraphNodeId { inner: 3 }, amount: NodeDragAmount { x: 5.0, y: 7.0 } }),
+            NodeGraphEditOp::EditField(NodeGraphFieldEdit {
+                node_id: NodeGraphNodeId { inner: 1 },
+                node_type: NodeTypeId { inner: 1 },
+                field_idx: 1,
+                old_value: NodeTypeFieldValue::NumberInput(1.5),
+                new_value: NodeTypeFieldValue::NumberInput(3.0),
+            }),
+            NodeGraphEditOp::Connect(NodeGraphConnection {
+                output: NodeGraphNodeId { inner: 1 }, output_index: 0,
+                input: NodeGraphNodeId { inner: 3 }, input_index: 1,
+            }),
+        ].into_iter()
+        .chain(original.get_remove_nodes_ops(&[NodeGraphNodeId { inner: 2 }]))
+        .collect::<Vec<_>>();
 
-    let field_idx = data.field_idx;
+        for op in ops.iter() {
+            node_graph.apply_edit_op(op);
+        }
 
-    let mut node_local_dataset = match data.backref.downcast_mut::<NodeLocalDataset>() {
-        Some(s) => s,
-        None => return Update::DoNothing,
-    };
+        assert_eq!(node_graph.nodes.len(), 2);
+        assert_eq!(node_graph.get_connections(), vec![NodeGraphConnection {
+            output: NodeGraphNodeId { inner: 1 }, output_index: 0,
+            input: NodeGraphNodeId { inner: 3 }, input_index: 1,
+        }]);
 
-    let node_id = node_local_dataset.node_id;
-    let mut node_graph = match node_local_dataset.backref.downcast_mut::<NodeGraphLocalDataset>() {
-        Some(s) => s,
-        None => return Update::DoNothing,
-    };
+        for op in ops.iter().rev() {
+            node_graph.apply_edit_op(&op.invert());
+        }
 
-    let node_type = match node_graph.node_graph.nodes.iter().find(|i| i.node_id == node_id) {
-        Some(s) => s.node.node_type,
-        None => return Update::DoNothing,
-    };
+        // removed nodes are appended at the end when restored
+        let mut nodes = node_graph.nodes.clone().into_library_owned_vec();
+        nodes.sort_by_key(|n| n.node_id);
+        node_graph.nodes = nodes.into();
+        assert_same_graph(&original, &node_graph);
+    }
 
-    // If a new file was selected, invoke callback
-    let mut result = match node_graph.callbacks.on_node_field_edited.as_mut() {
-        Some(OnNodeFieldEdited { data, callback }) => (callback.cb)(data, info, node_id, field_idx, node_type, NodeTypeFieldValue::FileInput(file.path.clone())),
-        None => return Update::DoNothing,
-    };
+    #[test]
+    fn test_node_graph_paste_remaps_ids() {
+        let node_graph = test_graph();
+        let subgraph = NodeGraph::from_json(&node_graph.subgraph_to_json(&[
+            NodeGraphNodeId { inner: 1 },
+            NodeGraphNodeId { inner: 2 },
+        ])).unwrap();
+
+        // only the connection between the two copied nodes is copied
+        assert_eq!(subgraph.nodes.len(), 2);
+        assert_eq!(subgraph.get_connections().len(), 1);
+
+        let ops = node_graph.get_insert_subgraph_ops(&subgraph, NodeDragAmount { x: 20.0, y: 20.0 });
+        let mut pasted = node_graph.clone();
+        for op in ops.iter() {
+            pasted.apply_edit_op(op);
+        }
 
-    result
-}
\ No newline at end of file
+        assert_eq!(pasted.nodes.len(), 5);
+        let new_ids = pasted.nodes.iter().skip(3).map(|n| n.node_id.inner).collect::<Vec<_>>();
+        assert_eq!(new_ids, vec![4, 5]);
+        let position = pasted.nodes.as_ref()[3].node.position;
+        assert_eq!((position.x, position.y), (30.0, 14.5));
+        assert!(pasted.get_connections().contains(&NodeGraphConnection {
+            output: NodeGraphNodeId { inner: 4 }, output_index: 0,
+            input: NodeGraphNodeId { inner: 5 }, input_index: 1,
+        }));
+        assert_eq!(pasted.get_connections().len(), 3);
+    }
+}
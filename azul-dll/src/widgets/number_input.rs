@@ -1,5 +1,13 @@
 //! Same as TextInput, but only allows a number
 
+use azul_desktop::xml::{
+    XmlComponentTrait, XmlComponentMap, XmlNode, XmlTextContent,
+    ComponentArguments, FilteredComponentArguments,
+    RenderDomError, CompileError,
+};
+use azul_desktop::styled_dom::StyledDom;
+use azul_desktop::css::Css;
+use super::{parse_xml_arg, parse_xml_number};
 use azul_desktop::css::AzString;
 use azul_desktop::callbacks::{RefAny, CallbackInfo, Update};
 use azul_desktop::dom::{Dom, NodeDataInlineCssPropertyVec};
@@ -198,4 +206,62 @@ extern "C" fn validate_text_input(data: &mut RefAny, info: &mut CallbackInfo, st
         update: result,
         valid: TextInputValid::Yes
     }
-}
\ No newline at end of file
+}
+
+/// Renders a `<number-input value="5.0" />` XML node
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NumberInputRenderer {
+    node: XmlNode,
+}
+
+impl NumberInputRenderer {
+    pub fn new() -> Self {
+        Self {
+            node: XmlNode::new("number_input"),
+        }
+    }
+}
+
+impl Default for NumberInputRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XmlComponentTrait for NumberInputRenderer {
+    fn get_type_id(&self) -> String {
+        "number_input".to_string()
+    }
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        ComponentArguments {
+            args: vec![
+                ("value".to_string(), "f32".to_string()),
+            ],
+            accepts_text: false,
+        }
+    }
+
+    fn render_dom(
+        &self,
+        _: &XmlComponentMap,
+        args: &FilteredComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<StyledDom, RenderDomError> {
+        let value = parse_xml_arg(args, "value", "f32", parse_xml_number)?.unwrap_or(0.0);
+        Ok(NumberInput::new(value).dom().style(Css::empty()))
+    }
+
+    fn compile_to_rust_code(
+        &self,
+        _: &XmlComponentMap,
+        _: &ComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<String, CompileError> {
+        Ok(String::from("NumberInput::new(value).dom()"))
+    }
+
+    fn get_xml_node(&self) -> XmlNode {
+        self.node.clone()
+    }
+}
@@ -1,3 +1,10 @@
+use azul_desktop::xml::{
+    XmlComponentTrait, XmlComponentMap, XmlNode, XmlTextContent,
+    ComponentArguments, FilteredComponentArguments,
+    RenderDomError, CompileError,
+};
+use azul_desktop::styled_dom::StyledDom;
+use super::{parse_xml_arg, parse_xml_number};
 use azul_desktop::{
     dom::{
         Dom, NodeDataInlineCssProperty, NodeDataInlineCssPropertyVec,
@@ -223,4 +230,62 @@ impl ProgressBar {
             })
         ]))
     }
-}
\ No newline at end of file
+}
+
+/// Renders a `<progress-bar percent_done="50" />` XML node
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ProgressBarRenderer {
+    node: XmlNode,
+}
+
+impl ProgressBarRenderer {
+    pub fn new() -> Self {
+        Self {
+            node: XmlNode::new("progress_bar"),
+        }
+    }
+}
+
+impl Default for ProgressBarRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XmlComponentTrait for ProgressBarRenderer {
+    fn get_type_id(&self) -> String {
+        "progress_bar".to_string()
+    }
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        ComponentArguments {
+            args: vec![
+                ("percent_done".to_string(), "f32".to_string()),
+            ],
+            accepts_text: false,
+        }
+    }
+
+    fn render_dom(
+        &self,
+        _: &XmlComponentMap,
+        args: &FilteredComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<StyledDom, RenderDomError> {
+        let percent_done = parse_xml_arg(args, "percent_done", "f32", parse_xml_number)?.unwrap_or(0.0);
+        Ok(ProgressBar::new(percent_done).dom().style(Css::empty()))
+    }
+
+    fn compile_to_rust_code(
+        &self,
+        _: &XmlComponentMap,
+        _: &ComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<String, CompileError> {
+        Ok(String::from("ProgressBar::new(percent_done).dom()"))
+    }
+
+    fn get_xml_node(&self) -> XmlNode {
+        self.node.clone()
+    }
+}
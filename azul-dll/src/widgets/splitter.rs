@@ -0,0 +1,958 @@
+//! Container that splits its area into resizable panes
+//!
+//! The panes are separated by handles, which can be dragged with the mouse,
+//! moved with the arrow keys (when focused) and double-clicked to collapse or
+//! restore the adjacent pane. The new pane sizes are applied immediately and
+//! reported through `on_resize`, so that the application can persist them and
+//! restore them later via `Splitter::with_state`.
+
+use std::vec::Vec;
+use azul_desktop::css::*;
+use azul_desktop::css::AzString;
+use azul_desktop::callbacks::{CallbackInfo, RefAny, Update, Callback, DomNodeId};
+use azul_desktop::dom::{
+    Dom, IdOrClass, TabIndex,
+    IdOrClass::Class,
+    NodeDataInlineCssProperty,
+    IdOrClassVec, NodeDataInlineCssPropertyVec,
+    CallbackData, EventFilter, HoverEventFilter,
+    FocusEventFilter, WindowEventFilter,
+};
+use azul_desktop::task::Instant;
+use azul_desktop::xml::{
+    XmlComponentTrait, XmlComponentMap, XmlNode, XmlTextContent,
+    ComponentArguments, FilteredComponentArguments,
+    RenderDomError, CompileError,
+};
+use azul_desktop::styled_dom::StyledDom;
+use super::get_xml_string_arg;
+
+pub type SplitterOnResizeCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &SplitterState) -> Update;
+impl_callback!(SplitterOnResize, OptionSplitterOnResize, SplitterOnResizeCallback, SplitterOnResizeCallbackType);
+
+/// Two clicks on the same handle within this time count as a double-click
+const DOUBLE_CLICK_MS: u64 = 500;
+/// Distance a focused handle moves per arrow key press (times 5 with Shift)
+const KEYBOARD_STEP: f32 = 10.0;
+
+const BACKGROUND_HANDLE: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(ColorU { r: 225, g: 225, b: 225, a: 255 })];
+const BACKGROUND_HANDLE_HOVER: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(ColorU { r: 190, g: 190, b: 190, a: 255 })];
+const BACKGROUND_HANDLE_FOCUS: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(ColorU { r: 0, g: 120, b: 215, a: 255 })];
+
+// .__azul-native-splitter (horizontal)
+static SPLITTER_HORIZONTAL_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_grow(LayoutFlexGrow { inner: FloatValue::const_new(1) })),
+];
+
+// .__azul-native-splitter (vertical)
+static SPLITTER_VERTICAL_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_grow(LayoutFlexGrow { inner: FloatValue::const_new(1) })),
+];
+
+// .__azul-native-splitter-pane
+static PANE_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+];
+
+// .__azul-native-splitter-handle (horizontal)
+static HANDLE_HORIZONTAL_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_cursor(StyleCursor::ColResize)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_shrink(LayoutFlexShrink { inner: FloatValue::const_new(0) })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BACKGROUND_HANDLE))),
+    NodeDataInlineCssProperty::Hover(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BACKGROUND_HANDLE_HOVER))),
+    NodeDataInlineCssProperty::Focus(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BACKGROUND_HANDLE_FOCUS))),
+];
+
+// .__azul-native-splitter-handle (vertical)
+static HANDLE_VERTICAL_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_cursor(StyleCursor::RowResize)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_shrink(LayoutFlexShrink { inner: FloatValue::const_new(0) })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BACKGROUND_HANDLE))),
+    NodeDataInlineCssProperty::Hover(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BACKGROUND_HANDLE_HOVER))),
+    NodeDataInlineCssProperty::Focus(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BACKGROUND_HANDLE_FOCUS))),
+];
+
+const IDS_AND_CLASSES_SPLITTER: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-splitter"))];
+const IDS_AND_CLASSES_PANE: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-splitter-pane"))];
+const IDS_AND_CLASSES_PANE_COLLAPSED: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-splitter-pane")),
+    Class(AzString::from_const_str("collapsed")),
+];
+const IDS_AND_CLASSES_HANDLE: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-splitter-handle"))];
+
+/// Direction in which the panes of a `Splitter` are laid out
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum SplitterDirection {
+    /// Panes are laid out from left to right, the handles are vertical bars
+    Horizontal,
+    /// Panes are laid out from top to bottom, the handles are horizontal bars
+    Vertical,
+}
+
+/// Size of a single pane, as reported by `on_resize`
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct SplitterPaneState {
+    /// Size of the pane in pixels along the splitter direction -
+    /// `None` if the pane takes up the remaining space
+    pub size: OptionF32,
+    /// Whether the pane is currently collapsed (hidden)
+    pub collapsed: bool,
+}
+
+impl Default for SplitterPaneState {
+    fn default() -> Self {
+        Self {
+            size: None.into(),
+            collapsed: false,
+        }
+    }
+}
+
+impl_vec!(SplitterPaneState, SplitterPaneStateVec, SplitterPaneStateVecDestructor);
+impl_vec_clone!(SplitterPaneState, SplitterPaneStateVec, SplitterPaneStateVecDestructor);
+impl_vec_mut!(SplitterPaneState, SplitterPaneStateVec);
+impl_vec_debug!(SplitterPaneState, SplitterPaneStateVec);
+impl_vec_partialeq!(SplitterPaneState, SplitterPaneStateVec);
+
+/// Sizes of all panes of a `Splitter`
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct SplitterState {
+    pub panes: SplitterPaneStateVec,
+}
+
+/// Pane of a `Splitter`
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct SplitterPane {
+    pub content: Dom,
+    pub state: SplitterPaneState,
+    /// The pane can't be resized to less than this size (in pixels)
+    pub min_size: f32,
+    /// The pane can't be resized to more than this size (in pixels)
+    pub max_size: OptionF32,
+}
+
+impl Default for SplitterPane {
+    fn default() -> Self {
+        Self {
+            content: Dom::div(),
+            state: SplitterPaneState::default(),
+            min_size: 0.0,
+            max_size: None.into(),
+        }
+    }
+}
+
+impl_vec!(SplitterPane, SplitterPaneVec, SplitterPaneVecDestructor);
+impl_vec_clone!(SplitterPane, SplitterPaneVec, SplitterPaneVecDestructor);
+impl_vec_mut!(SplitterPane, SplitterPaneVec);
+impl_vec_debug!(SplitterPane, SplitterPaneVec);
+impl_vec_partialeq!(SplitterPane, SplitterPaneVec);
+
+impl SplitterPane {
+
+    /// Creates a flexible pane, which takes up the remaining space
+    pub fn new(content: Dom) -> Self {
+        Self {
+            content,
+            .. Default::default()
+        }
+    }
+
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut m = Self::default();
+        core::mem::swap(&mut m, self);
+        m
+    }
+
+    pub fn with_size(&mut self, size: f32) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_size(size);
+        m
+    }
+
+    /// Gives the pane a fixed size (in pixels) instead of taking up the remaining space
+    pub fn set_size(&mut self, size: f32) {
+        self.state.size = Some(size).into();
+    }
+
+    pub fn with_min_size(&mut self, min_size: f32) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_min_size(min_size);
+        m
+    }
+
+    pub fn set_min_size(&mut self, min_size: f32) {
+        self.min_size = min_size;
+    }
+
+    pub fn with_max_size(&mut self, max_size: f32) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_max_size(max_size);
+        m
+    }
+
+    pub fn set_max_size(&mut self, max_size: f32) {
+        self.max_size = Some(max_size).into();
+    }
+
+    pub fn with_collapsed(&mut self, collapsed: bool) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_collapsed(collapsed);
+        m
+    }
+
+    pub fn set_collapsed(&mut self, collapsed: bool) {
+        self.state.collapsed = collapsed;
+    }
+
+    fn get_max_size(&self) -> f32 {
+        self.max_size.into_option().unwrap_or(f32::MAX)
+    }
+}
+
+/// Container which splits its area into resizable panes
+///
+/// Panes with a fixed size keep their size when the splitter is resized,
+/// the remaining space is distributed between the flexible panes. Dragging
+/// a handle resizes the panes on both sides of it within their `min_size` /
+/// `max_size`; if both panes are flexible, the first one is given a fixed
+/// size. Double-clicking a handle (or pressing Enter while it is focused)
+/// collapses the fixed pane next to it.
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct Splitter {
+    pub direction: SplitterDirection,
+    pub panes: SplitterPaneVec,
+    /// Width of the handles between the panes (in pixels)
+    pub handle_size: f32,
+    /// Called after a pane was resized, collapsed or restored
+    pub on_resize: OptionSplitterOnResize,
+}
+
+impl Default for Splitter {
+    fn default() -> Self {
+        Self {
+            direction: SplitterDirection::Horizontal,
+            panes: SplitterPaneVec::from_const_slice(&[]),
+            handle_size: 5.0,
+            on_resize: None.into(),
+        }
+    }
+}
+
+// dataset set on the top-level Splitter node
+struct SplitterLocalDataset {
+    splitter: Splitter,
+    handle_drag: Option<HandleDrag>,
+    // handle and time of the last click, used to detect double-clicks
+    last_click: Option<(usize, Instant)>,
+}
+
+// handle currently being dragged
+struct HandleDrag {
+    handle_id: usize,
+    panes: AdjacentPanes,
+    start_cursor: f32,
+    current_delta: f32,
+}
+
+// the panes before and after a handle, with their sizes before the resize
+#[derive(Debug, Copy, Clone)]
+struct AdjacentPanes {
+    prev_node: DomNodeId,
+    next_node: DomNodeId,
+    prev_size: f32,
+    next_size: f32,
+    resize_prev: bool,
+    resize_next: bool,
+}
+
+// dataset set on each handle
+struct SplitterHandleLocalDataset {
+    handle_id: usize,
+    backref: RefAny, // RefAny<SplitterLocalDataset>
+}
+
+impl Splitter {
+
+    pub fn new(direction: SplitterDirection) -> Self {
+        Self {
+            direction,
+            .. Default::default()
+        }
+    }
+
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut m = Self::default();
+        core::mem::swap(&mut m, self);
+        m
+    }
+
+    pub fn with_pane(&mut self, pane: SplitterPane) -> Self {
+        let mut m = self.swap_with_default();
+        m.add_pane(pane);
+        m
+    }
+
+    pub fn add_pane(&mut self, pane: SplitterPane) {
+        let mut panes = self.panes.clone().into_library_owned_vec();
+        panes.push(pane);
+        self.panes = panes.into();
+    }
+
+    pub fn with_handle_size(&mut self, handle_size: f32) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_handle_size(handle_size);
+        m
+    }
+
+    pub fn set_handle_size(&mut self, handle_size: f32) {
+        self.handle_size = handle_size;
+    }
+
+    /// Returns the current sizes of all panes
+    pub fn get_state(&self) -> SplitterState {
+        SplitterState {
+            panes: self.panes.iter().map(|p| p.state).collect::<Vec<_>>().into(),
+        }
+    }
+
+    pub fn with_state(&mut self, state: SplitterState) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_state(state);
+        m
+    }
+
+    /// Restores the pane sizes previously reported by `on_resize`
+    /// (extra pane states are ignored, missing ones keep their current size)
+    pub fn set_state(&mut self, state: SplitterState) {
+        for (pane, pane_state) in self.panes.as_mut().iter_mut().zip(state.panes.iter()) {
+            pane.state = *pane_state;
+        }
+    }
+
+    pub fn with_on_resize(&mut self, data: RefAny, on_resize: SplitterOnResizeCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_resize(data, on_resize);
+        m
+    }
+
+    pub fn set_on_resize(&mut self, data: RefAny, on_resize: SplitterOnResizeCallbackType) {
+        self.on_resize = Some(SplitterOnResize {
+            data,
+            callback: SplitterOnResizeCallback { cb: on_resize }
+        }).into();
+    }
+
+    pub fn dom(self) -> Dom {
+        let (splitter, panes) = self.dom_parts();
+        let mut children = Vec::new();
+        for mut pane in panes {
+            children.extend(pane.handle);
+            children.push(pane.pane.with_child(pane.content));
+        }
+        splitter.with_children(children.into())
+    }
+
+    // Returns the splitter node (without children) and the nodes of the panes
+    fn dom_parts(self) -> (Dom, Vec<PaneDom>) {
+
+        let direction = self.direction;
+        let handle_size = self.handle_size;
+        let mut splitter = self;
+
+        // move the content out of the panes, only the sizes are kept in the dataset
+        let contents = splitter.panes.as_mut().iter_mut().map(|p| core::mem::replace(&mut p.content, Dom::div())).collect::<Vec<_>>();
+        let pane_styles = splitter.panes.iter().map(|p| pane_style(direction, &p.state)).collect::<Vec<_>>();
+        let collapsed = splitter.panes.iter().map(|p| p.state.collapsed).collect::<Vec<_>>();
+
+        let splitter_local_dataset = RefAny::new(SplitterLocalDataset {
+            splitter,
+            handle_drag: None,
+            last_click: None,
+        });
+
+        let handle_style = match direction {
+            SplitterDirection::Horizontal => HANDLE_HORIZONTAL_STYLE,
+            SplitterDirection::Vertical => HANDLE_VERTICAL_STYLE,
+        };
+
+        let mut panes = Vec::new();
+        for (pane_id, ((content, style), collapsed)) in contents.into_iter().zip(pane_styles).zip(collapsed).enumerate() {
+
+            let handle = if pane_id != 0 {
+                let handle_id = pane_id - 1;
+                Some(
+                    Dom::div()
+                    .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_HANDLE))
+                    .with_inline_css_props(
+                        handle_style.iter().cloned()
+                        .chain(Some(NodeDataInlineCssProperty::Normal(size_property(direction, handle_size))))
+                        .collect::<Vec<_>>().into()
+                    )
+                    .with_tab_index(TabIndex::Auto)
+                    .with_callbacks(vec![
+                        CallbackData {
+                            event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                            data: RefAny::new(SplitterHandleLocalDataset {
+                                handle_id,
+                                backref: splitter_local_dataset.clone(),
+                            }),
+                            callback: Callback { cb: self::input::default_on_handle_mouse_down },
+                        },
+                        CallbackData {
+                            event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                            data: RefAny::new(SplitterHandleLocalDataset {
+                                handle_id,
+                                backref: splitter_local_dataset.clone(),
+                            }),
+                            callback: Callback { cb: self::input::default_on_handle_key_down },
+                        },
+                    ].into())
+                )
+            } else {
+                None
+            };
+
+            panes.push(PaneDom {
+                handle,
+                pane: Dom::div()
+                    .with_ids_and_classes(IdOrClassVec::from_const_slice(
+                        if collapsed { IDS_AND_CLASSES_PANE_COLLAPSED } else { IDS_AND_CLASSES_PANE }
+                    ))
+                    .with_inline_css_props(style),
+                content,
+            });
+        }
+
+        let splitter = Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_SPLITTER))
+        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(match direction {
+            SplitterDirection::Horizontal => SPLITTER_HORIZONTAL_STYLE,
+            SplitterDirection::Vertical => SPLITTER_VERTICAL_STYLE,
+        }))
+        .with_callbacks(vec![
+            // window-wide, so that the drag continues if the cursor leaves the handle
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::MouseOver),
+                data: splitter_local_dataset.clone(),
+                callback: Callback { cb: self::input::default_on_handle_drag },
+            },
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::LeftMouseUp),
+                data: splitter_local_dataset,
+                callback: Callback { cb: self::input::default_on_handle_drag_end },
+            },
+        ].into());
+
+        (splitter, panes)
+    }
+}
+
+// nodes of a single pane: the handle before the pane (none for the first pane),
+// the pane node itself (without children) and the content of the pane
+struct PaneDom {
+    handle: Option<Dom>,
+    pane: Dom,
+    content: Dom,
+}
+
+impl From<Splitter> for Dom {
+    fn from(s: Splitter) -> Dom {
+        s.dom()
+    }
+}
+
+// Size along the splitter direction: width for horizontal, height for vertical splitters
+fn size_property(direction: SplitterDirection, size: f32) -> CssProperty {
+    match direction {
+        SplitterDirection::Horizontal => CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::px(size) })),
+        SplitterDirection::Vertical => CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::px(size) })),
+    }
+}
+
+fn auto_size_property(direction: SplitterDirection) -> CssProperty {
+    match direction {
+        SplitterDirection::Horizontal => CssProperty::Width(LayoutWidthValue::Auto),
+        SplitterDirection::Vertical => CssProperty::Height(LayoutHeightValue::Auto),
+    }
+}
+
+fn flex_grow_property(flex_grow: f32) -> CssProperty {
+    CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::new(flex_grow) }))
+}
+
+// Properties a pane needs for its size: collapsed and fixed panes don't grow or shrink,
+// flexible panes share the remaining space
+fn pane_size_properties(direction: SplitterDirection, state: &SplitterPaneState) -> [CssProperty; 3] {
+    let no_shrink = CssProperty::FlexShrink(LayoutFlexShrinkValue::Exact(LayoutFlexShrink { inner: FloatValue::const_new(0) }));
+    if state.collapsed {
+        return [size_property(direction, 0.0), flex_grow_property(0.0), no_shrink];
+    }
+    match state.size.into_option() {
+        Some(size) => [size_property(direction, size), flex_grow_property(0.0), no_shrink],
+        None => [
+            auto_size_property(direction),
+            flex_grow_property(1.0),
+            CssProperty::FlexShrink(LayoutFlexShrinkValue::Exact(LayoutFlexShrink { inner: FloatValue::const_new(1) })),
+        ],
+    }
+}
+
+fn pane_style(direction: SplitterDirection, state: &SplitterPaneState) -> NodeDataInlineCssPropertyVec {
+    PANE_STYLE.iter().cloned()
+    .chain(pane_size_properties(direction, state).iter().cloned().map(NodeDataInlineCssProperty::Normal))
+    .collect::<Vec<_>>()
+    .into()
+}
+
+// Limits the distance a handle can be moved by, so that both adjacent panes
+// stay within their min / max size (positive delta = handle moves right / down)
+fn clamp_handle_delta(delta: f32, prev_size: f32, next_size: f32, prev: &SplitterPane, next: &SplitterPane) -> f32 {
+    let lower = (prev.min_size - prev_size).max(next_size - next.get_max_size());
+    let upper = (prev.get_max_size() - prev_size).min(next_size - next.min_size);
+    if lower > upper {
+        // panes can't be resized in either direction
+        return 0.0;
+    }
+    delta.max(lower).min(upper)
+}
+
+impl SplitterLocalDataset {
+
+    // Looks up the panes around the handle and their current sizes,
+    // returns None if one of them is collapsed
+    fn get_adjacent_panes(&self, info: &CallbackInfo, handle_id: usize, handle_node: DomNodeId) -> Option<AdjacentPanes> {
+
+        let prev = self.splitter.panes.get(handle_id)?;
+        let next = self.splitter.panes.get(handle_id + 1)?;
+        if prev.state.collapsed || next.state.collapsed {
+            return None;
+        }
+
+        let prev_node = info.get_previous_sibling(handle_node)?;
+        let next_node = info.get_next_sibling(handle_node)?;
+        let size_of = |node_id: DomNodeId| info.get_node_size(node_id).map(|s| match self.splitter.direction {
+            SplitterDirection::Horizontal => s.width,
+            SplitterDirection::Vertical => s.height,
+        });
+
+        let prev_fixed = prev.state.size.is_some();
+        let next_fixed = next.state.size.is_some();
+
+        Some(AdjacentPanes {
+            prev_node,
+            next_node,
+            prev_size: size_of(prev_node)?,
+            next_size: size_of(next_node)?,
+            // if both panes are flexible, the first one gets a fixed size
+            resize_prev: prev_fixed || !next_fixed,
+            resize_next: next_fixed,
+        })
+    }
+
+    // Moves the handle by the (clamped) delta without storing the new sizes yet
+    fn preview_resize(&self, info: &mut CallbackInfo, handle_id: usize, panes: &AdjacentPanes, delta: f32) -> f32 {
+
+        let direction = self.splitter.direction;
+        let prev = &self.splitter.panes.as_ref()[handle_id];
+        let next = &self.splitter.panes.as_ref()[handle_id + 1];
+        let delta = clamp_handle_delta(delta, panes.prev_size, panes.next_size, prev, next);
+
+        if panes.resize_prev {
+            let state = SplitterPaneState { size: Some(panes.prev_size + delta).into(), collapsed: false };
+            for prop in pane_size_properties(direction, &state).iter() {
+                info.set_css_property(panes.prev_node, prop.clone());
+            }
+        }
+
+        if panes.resize_next {
+            let state = SplitterPaneState { size: Some(panes.next_size - delta).into(), collapsed: false };
+            for prop in pane_size_properties(direction, &state).iter() {
+                info.set_css_property(panes.next_node, prop.clone());
+            }
+        }
+
+        delta
+    }
+
+    // Stores the sizes after the handle has been moved by the delta
+    fn commit_resize(&mut self, handle_id: usize, panes: &AdjacentPanes, delta: f32) {
+        let pane_states = self.splitter.panes.as_mut();
+        if panes.resize_prev {
+            pane_states[handle_id].state.size = Some(panes.prev_size + delta).into();
+        }
+        if panes.resize_next {
+            pane_states[handle_id + 1].state.size = Some(panes.next_size - delta).into();
+        }
+    }
+
+    // Collapses or restores the fixed pane next to the handle
+    // (or the pane before it, if both are flexible)
+    fn toggle_collapsed(&mut self, info: &mut CallbackInfo, handle_id: usize, handle_node: DomNodeId) -> bool {
+
+        let direction = self.splitter.direction;
+        let pane_states = self.splitter.panes.as_mut();
+        if handle_id + 1 >= pane_states.len() {
+            return false;
+        }
+
+        let prev = &pane_states[handle_id].state;
+        let next = &pane_states[handle_id + 1].state;
+        let prev_is_target = if prev.collapsed || next.collapsed {
+            prev.collapsed
+        } else {
+            prev.size.is_some() || next.size.is_none()
+        };
+
+        let (pane_id, node_id) = if prev_is_target {
+            (handle_id, info.get_previous_sibling(handle_node))
+        } else {
+            (handle_id + 1, info.get_next_sibling(handle_node))
+        };
+
+        let node_id = match node_id {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let state = &mut pane_states[pane_id].state;
+        state.collapsed = !state.collapsed;
+        for prop in pane_size_properties(direction, state).iter() {
+            info.set_css_property(node_id, prop.clone());
+        }
+
+        true
+    }
+
+    fn invoke_on_resize(&mut self, info: &mut CallbackInfo) -> Update {
+        let state = self.splitter.get_state();
+        match self.splitter.on_resize.as_mut() {
+            Some(SplitterOnResize { callback, data }) => (callback.cb)(data, info, &state),
+            None => Update::DoNothing,
+        }
+    }
+}
+
+// handle input events for the splitter
+mod input {
+
+    use azul_desktop::callbacks::{RefAny, CallbackInfo, Update, FocusTarget};
+    use azul_core::window::VirtualKeyCode;
+    use super::{
+        SplitterLocalDataset, SplitterHandleLocalDataset,
+        SplitterDirection, HandleDrag,
+        DOUBLE_CLICK_MS, KEYBOARD_STEP,
+    };
+
+    pub(in super) extern "C" fn default_on_handle_mouse_down(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut data = match data.downcast_mut::<SplitterHandleLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let handle_id = data.handle_id;
+        let mut splitter_local_dataset = match data.backref.downcast_mut::<SplitterLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // focus the handle, so that it can be moved with the arrow keys
+        let handle_node = info.get_hit_node();
+        info.set_focus(FocusTarget::Id(handle_node));
+
+        let now = info.get_current_time();
+        let is_double_click = match splitter_local_dataset.last_click.take() {
+            Some((last_handle, last_time)) => last_handle == handle_id && now.duration_since(&last_time).millis() < DOUBLE_CLICK_MS,
+            None => false,
+        };
+
+        if is_double_click {
+            splitter_local_dataset.handle_drag = None;
+            if !splitter_local_dataset.toggle_collapsed(info, handle_id, handle_node) {
+                return Update::DoNothing;
+            }
+            return splitter_local_dataset.invoke_on_resize(info);
+        }
+
+        splitter_local_dataset.last_click = Some((handle_id, now));
+
+        let cursor = match info.get_current_mouse_state().cursor_position.get_position() {
+            Some(s) => match splitter_local_dataset.splitter.direction {
+                SplitterDirection::Horizontal => s.x,
+                SplitterDirection::Vertical => s.y,
+            },
+            None => return Update::DoNothing,
+        };
+
+        let panes = match splitter_local_dataset.get_adjacent_panes(info, handle_id, handle_node) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        splitter_local_dataset.handle_drag = Some(HandleDrag {
+            handle_id,
+            panes,
+            start_cursor: cursor,
+            current_delta: 0.0,
+        });
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_handle_drag(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut splitter_local_dataset = match data.downcast_mut::<SplitterLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let mouse_state = info.get_current_mouse_state();
+        if !mouse_state.left_down {
+            return Update::DoNothing;
+        }
+
+        let (handle_id, panes, start_cursor, current_delta) = match splitter_local_dataset.handle_drag.as_ref() {
+            Some(s) => (s.handle_id, s.panes, s.start_cursor, s.current_delta),
+            None => return Update::DoNothing,
+        };
+
+        let cursor = match mouse_state.cursor_position.get_position() {
+            Some(s) => match splitter_local_dataset.splitter.direction {
+                SplitterDirection::Horizontal => s.x,
+                SplitterDirection::Vertical => s.y,
+            },
+            None => return Update::DoNothing,
+        };
+
+        let delta = super::clamp_handle_delta(
+            cursor - start_cursor,
+            panes.prev_size,
+            panes.next_size,
+            &splitter_local_dataset.splitter.panes.as_ref()[handle_id],
+            &splitter_local_dataset.splitter.panes.as_ref()[handle_id + 1],
+        );
+
+        if delta == current_delta {
+            return Update::DoNothing;
+        }
+
+        // only the CSS is updated while dragging, the new sizes
+        // are stored and reported once the mouse is released
+        let delta = splitter_local_dataset.preview_resize(info, handle_id, &panes, delta);
+        if let Some(handle_drag) = splitter_local_dataset.handle_drag.as_mut() {
+            handle_drag.current_delta = delta;
+        }
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_handle_drag_end(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut splitter_local_dataset = match data.downcast_mut::<SplitterLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let handle_drag = match splitter_local_dataset.handle_drag.take() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if handle_drag.current_delta == 0.0 {
+            return Update::DoNothing;
+        }
+
+        splitter_local_dataset.commit_resize(handle_drag.handle_id, &handle_drag.panes, handle_drag.current_delta);
+        splitter_local_dataset.invoke_on_resize(info)
+    }
+
+    pub(in super) extern "C" fn default_on_handle_key_down(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut data = match data.downcast_mut::<SplitterHandleLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let handle_id = data.handle_id;
+        let mut splitter_local_dataset = match data.backref.downcast_mut::<SplitterLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let keyboard_state = info.get_current_keyboard_state();
+        let keycode = match keyboard_state.current_virtual_keycode.into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let handle_node = info.get_hit_node();
+        let step = if keyboard_state.shift_down() { KEYBOARD_STEP * 5.0 } else { KEYBOARD_STEP };
+        let direction = splitter_local_dataset.splitter.direction;
+
+        let delta = match (direction, keycode) {
+            (_, VirtualKeyCode::Return) | (_, VirtualKeyCode::Space) => {
+                if !splitter_local_dataset.toggle_collapsed(info, handle_id, handle_node) {
+                    return Update::DoNothing;
+                }
+                return splitter_local_dataset.invoke_on_resize(info);
+            },
+            (SplitterDirection::Horizontal, VirtualKeyCode::Left) |
+            (SplitterDirection::Vertical, VirtualKeyCode::Up) => -step,
+            (SplitterDirection::Horizontal, VirtualKeyCode::Right) |
+            (SplitterDirection::Vertical, VirtualKeyCode::Down) => step,
+            _ => return Update::DoNothing,
+        };
+
+        let panes = match splitter_local_dataset.get_adjacent_panes(info, handle_id, handle_node) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let delta = splitter_local_dataset.preview_resize(info, handle_id, &panes, delta);
+        if delta == 0.0 {
+            return Update::DoNothing;
+        }
+
+        splitter_local_dataset.commit_resize(handle_id, &panes, delta);
+        splitter_local_dataset.invoke_on_resize(info)
+    }
+}
+
+/// Renders a `<splitter direction="vertical">...</splitter>` XML node,
+/// each child node is placed into a separate, flexible pane
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SplitterRenderer {
+    node: XmlNode,
+}
+
+impl SplitterRenderer {
+    pub fn new() -> Self {
+        Self {
+            node: XmlNode::new("splitter"),
+        }
+    }
+}
+
+impl Default for SplitterRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XmlComponentTrait for SplitterRenderer {
+    fn get_type_id(&self) -> String {
+        "splitter".to_string()
+    }
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        ComponentArguments {
+            args: vec![
+                ("direction".to_string(), "horizontal|vertical".to_string()),
+            ],
+            accepts_text: false,
+        }
+    }
+
+    fn render_dom(
+        &self,
+        components: &XmlComponentMap,
+        args: &FilteredComponentArguments,
+        content: &XmlTextContent,
+    ) -> Result<StyledDom, RenderDomError> {
+        self.render_dom_with_children(components, args, content, Vec::new())
+    }
+
+    fn render_dom_with_children(
+        &self,
+        _: &XmlComponentMap,
+        args: &FilteredComponentArguments,
+        _: &XmlTextContent,
+        children: Vec<StyledDom>,
+    ) -> Result<StyledDom, RenderDomError> {
+        let direction = match get_xml_string_arg(args, "direction").as_ref().map(|s| s.as_str()) {
+            Some("vertical") => SplitterDirection::Vertical,
+            _ => SplitterDirection::Horizontal,
+        };
+
+        let mut splitter = Splitter::new(direction);
+        for _ in 0..children.len() {
+            splitter.add_pane(SplitterPane::default());
+        }
+
+        let (mut splitter_dom, panes) = splitter.dom_parts();
+        let mut splitter = splitter_dom.style(Css::empty());
+        for (mut pane, child) in panes.into_iter().zip(children) {
+            if let Some(mut handle) = pane.handle {
+                splitter.append_child(handle.style(Css::empty()));
+            }
+            let mut pane_dom = pane.pane.style(Css::empty());
+            pane_dom.append_child(child);
+            splitter.append_child(pane_dom);
+        }
+        Ok(splitter)
+    }
+
+    fn compile_to_rust_code(
+        &self,
+        _: &XmlComponentMap,
+        _: &ComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<String, CompileError> {
+        Ok(String::from(
+            "Splitter::new(if direction == \"vertical\" { SplitterDirection::Vertical } else { SplitterDirection::Horizontal }).dom()"
+        ))
+    }
+
+    fn get_xml_node(&self) -> XmlNode {
+        self.node.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_splitter_clamp_handle_delta() {
+        let prev = SplitterPane { min_size: 50.0, max_size: Some(300.0).into(), .. Default::default() };
+        let next = SplitterPane { min_size: 100.0, .. Default::default() };
+
+        // within limits
+        assert_eq!(clamp_handle_delta(20.0, 200.0, 400.0, &prev, &next), 20.0);
+        assert_eq!(clamp_handle_delta(-20.0, 200.0, 400.0, &prev, &next), -20.0);
+        // prev pane can't shrink below its min size
+        assert_eq!(clamp_handle_delta(-500.0, 200.0, 400.0, &prev, &next), -150.0);
+        // prev pane can't grow above its max size
+        assert_eq!(clamp_handle_delta(500.0, 200.0, 400.0, &prev, &next), 100.0);
+        // next pane can't shrink below its min size
+        assert_eq!(clamp_handle_delta(500.0, 200.0, 150.0, &prev, &next), 50.0);
+        // no space left to move the handle
+        assert_eq!(clamp_handle_delta(10.0, 50.0, 100.0, &prev, &next), 0.0);
+    }
+
+    #[test]
+    fn test_splitter_state_roundtrip() {
+        let mut splitter = Splitter::new(SplitterDirection::Horizontal)
+        .with_pane(SplitterPane::new(Dom::div()).with_size(200.0))
+        .with_pane(SplitterPane::new(Dom::div()));
+
+        let mut state = splitter.get_state();
+        assert_eq!(state.panes.as_ref()[0].size, Some(200.0).into());
+        assert_eq!(state.panes.as_ref()[1].size, None.into());
+
+        state.panes.as_mut()[0] = SplitterPaneState { size: Some(250.0).into(), collapsed: true };
+        splitter.set_state(state.clone());
+        assert_eq!(splitter.get_state(), state);
+    }
+}
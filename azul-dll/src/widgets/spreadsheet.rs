@@ -0,0 +1,1907 @@
+use azul_desktop::xml::{
+    XmlComponentTrait, XmlComponentMap, XmlNode, XmlTextContent,
+    ComponentArguments, FilteredComponentArguments,
+    RenderDomError, CompileError,
+};
+use azul_desktop::styled_dom::StyledDom;
+use super::{parse_xml_arg, parse_xml_list, parse_xml_number};
+use std::vec::Vec;
+use std::string::String;
+use azul_desktop::css::*;
+use azul_desktop::css::AzString;
+use azul_core::window::LogicalPosition;
+use azul_desktop::callbacks::{
+    CallbackInfo, RefAny, Update, Callback, DomNodeId,
+    IFrameCallbackInfo, IFrameCallbackReturn,
+};
+use azul_desktop::dom::{
+    Dom, IdOrClass, TabIndex,
+    IdOrClass::Class,
+    NodeDataInlineCssProperty,
+    DomVec, IdOrClassVec, NodeDataInlineCssPropertyVec,
+    CallbackData, EventFilter, HoverEventFilter,
+    FocusEventFilter, WindowEventFilter,
+};
+use crate::widgets::text_input::TextInput;
+
+/// Height of the frozen header row (column names)
+const HEADER_HEIGHT: f32 = 20.0;
+/// Width of the frozen header column (row numbers)
+const ROW_HEADER_WIDTH: f32 = 40.0;
+/// Default height of a row in pixels
+const DEFAULT_ROW_HEIGHT: f32 = 20.0;
+/// Default width of a column in pixels
+const DEFAULT_COLUMN_WIDTH: f32 = 80.0;
+/// Columns can't be resized to less than this width
+const MIN_COLUMN_WIDTH: f32 = 20.0;
+/// Rows can't be resized to less than this height
+const MIN_ROW_HEIGHT: f32 = 10.0;
+/// How many rows / columns to scroll per mouse wheel "notch"
+const CELLS_PER_SCROLL_NOTCH: f32 = 3.0;
+/// The cell editor is not rendered if the cursor is further than this
+/// away from the top left visible cell (i.e. definitely not on screen)
+const MAX_EDITOR_OFFSET: f32 = 16384.0;
+/// Copying / clearing a selection cell-by-cell (without the `on_copy_range` /
+/// `on_clear_range` callbacks) is not done for selections larger than this,
+/// otherwise Ctrl+A, Ctrl+C on a large grid would call the `cell_provider`
+/// billions of times
+const MAX_SELECTION_CELLS: usize = 100_000;
+
+const STRING_SANS_SERIF: AzString = AzString::from_const_str("sans-serif");
+const FONT_FAMILY_SANS_SERIF: &[StyleFontFamily] = &[StyleFontFamily::System(STRING_SANS_SERIF)];
+
+const COLOR_WHITE: ColorU = ColorU { r: 255, g: 255, b: 255, a: 255 };
+const COLOR_GRID_LINE: ColorU = ColorU { r: 226, g: 226, b: 226, a: 255 };
+const COLOR_HEADER_LINE: ColorU = ColorU { r: 200, g: 200, b: 200, a: 255 };
+const COLOR_BORDER: ColorU = ColorU { r: 172, g: 172, b: 172, a: 255 };
+const COLOR_CURSOR: ColorU = ColorU { r: 38, g: 160, b: 218, a: 255 };
+
+const BACKGROUND_WHITE: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_WHITE)];
+const BACKGROUND_HEADER: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(ColorU { r: 240, g: 240, b: 240, a: 255 })];
+const BACKGROUND_HEADER_SELECTED: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(ColorU { r: 211, g: 222, b: 233, a: 255 })];
+const BACKGROUND_SELECTED: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(ColorU { r: 204, g: 232, b: 255, a: 255 })];
+
+// .__azul-native-datagrid
+static CONTAINER_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_grow(LayoutFlexGrow { inner: FloatValue::const_new(1) })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_BORDER })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_BORDER })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_BORDER })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_BORDER })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BACKGROUND_WHITE))),
+];
+
+// IFrame node and the root of the IFrame DOM
+static GRID_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_grow(LayoutFlexGrow { inner: FloatValue::const_new(1) })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+];
+
+// .__azul-native-datagrid-row
+static ROW_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_shrink(LayoutFlexShrink { inner: FloatValue::const_new(0) })),
+];
+
+// .__azul-native-datagrid-header
+static HEADER_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_shrink(LayoutFlexShrink { inner: FloatValue::const_new(0) })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_justify_content(LayoutJustifyContent::Center)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_font_size(StyleFontSize::const_px(11))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_font_family(StyleFontFamilyVec::from_const_slice(FONT_FAMILY_SANS_SERIF))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_text_align(StyleTextAlign::Center)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_HEADER_LINE })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_HEADER_LINE })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BACKGROUND_HEADER))),
+];
+
+// .__azul-native-datagrid-header.selected
+static HEADER_SELECTED_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BACKGROUND_HEADER_SELECTED))),
+];
+
+// .__azul-native-datagrid-cell
+static CELL_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_shrink(LayoutFlexShrink { inner: FloatValue::const_new(0) })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_justify_content(LayoutJustifyContent::Center)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(3))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_font_size(StyleFontSize::const_px(11))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_font_family(StyleFontFamilyVec::from_const_slice(FONT_FAMILY_SANS_SERIF))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_GRID_LINE })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_GRID_LINE })),
+];
+
+// .__azul-native-datagrid-cell.selected
+static CELL_SELECTED_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BACKGROUND_SELECTED))),
+];
+
+// .__azul-native-datagrid-column-resize
+static COLUMN_RESIZE_HANDLE_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_width(LayoutWidth::const_px(4))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_right(LayoutRight::const_px(0))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_top(LayoutTop::const_px(0))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_bottom(LayoutBottom::const_px(0))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_cursor(StyleCursor::ColResize)),
+];
+
+// .__azul-native-datagrid-row-resize
+static ROW_RESIZE_HANDLE_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_height(LayoutHeight::const_px(4))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_left(LayoutLeft::const_px(0))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_right(LayoutRight::const_px(0))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_bottom(LayoutBottom::const_px(0))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_cursor(StyleCursor::RowResize)),
+];
+
+// .__azul-native-datagrid-editor
+static EDITOR_STYLE: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_justify_content(LayoutJustifyContent::Center)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(2))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_font_size(StyleFontSize::const_px(11))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_font_family(StyleFontFamilyVec::from_const_slice(FONT_FAMILY_SANS_SERIF))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(2))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(2))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(2))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(2))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_CURSOR })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_CURSOR })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_CURSOR })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_CURSOR })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BACKGROUND_WHITE))),
+];
+
+const IDS_AND_CLASSES_CONTAINER: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-datagrid"))];
+const CONTAINER_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_CONTAINER);
+
+const IDS_AND_CLASSES_ROW: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-datagrid-row"))];
+const ROW_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_ROW);
+
+const IDS_AND_CLASSES_HEADER: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-datagrid-header"))];
+const HEADER_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_HEADER);
+
+const IDS_AND_CLASSES_HEADER_SELECTED: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-datagrid-header")),
+    Class(AzString::from_const_str("selected")),
+];
+const HEADER_SELECTED_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_HEADER_SELECTED);
+
+const IDS_AND_CLASSES_CELL: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-datagrid-cell"))];
+const CELL_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_CELL);
+
+const IDS_AND_CLASSES_CELL_SELECTED: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-datagrid-cell")),
+    Class(AzString::from_const_str("selected")),
+];
+const CELL_SELECTED_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_CELL_SELECTED);
+
+const IDS_AND_CLASSES_COLUMN_RESIZE: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-datagrid-column-resize"))];
+const COLUMN_RESIZE_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_COLUMN_RESIZE);
+
+const IDS_AND_CLASSES_ROW_RESIZE: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-datagrid-row-resize"))];
+const ROW_RESIZE_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_ROW_RESIZE);
+
+const IDS_AND_CLASSES_EDITOR: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-datagrid-editor"))];
+const EDITOR_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_EDITOR);
+
+/// Returns the text of the cell at (`row`, `column`)
+pub type DataGridCellProviderCallbackType = extern "C" fn(&mut RefAny, row: usize, column: usize) -> AzString;
+impl_callback!(DataGridCellProvider, OptionDataGridCellProvider, DataGridCellProviderCallback, DataGridCellProviderCallbackType);
+
+/// Returns additional CSS properties for the cell at (`row`, `column`)
+pub type DataGridCellStyleCallbackType = extern "C" fn(&mut RefAny, row: usize, column: usize, is_selected: bool) -> NodeDataInlineCssPropertyVec;
+impl_callback!(DataGridCellStyle, OptionDataGridCellStyle, DataGridCellStyleCallback, DataGridCellStyleCallbackType);
+
+pub type DataGridOnSelectCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, selection: DataGridRange) -> Update;
+impl_callback!(DataGridOnSelect, OptionDataGridOnSelect, DataGridOnSelectCallback, DataGridOnSelectCallbackType);
+
+pub type DataGridOnScrollCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, first_visible_row: usize, first_visible_column: usize) -> Update;
+impl_callback!(DataGridOnScroll, OptionDataGridOnScroll, DataGridOnScrollCallback, DataGridOnScrollCallbackType);
+
+pub type DataGridOnCellEditCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, row: usize, column: usize, new_text: AzString) -> Update;
+impl_callback!(DataGridOnCellEdit, OptionDataGridOnCellEdit, DataGridOnCellEditCallback, DataGridOnCellEditCallbackType);
+
+pub type DataGridOnColumnResizeCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, column: usize, new_width: f32) -> Update;
+impl_callback!(DataGridOnColumnResize, OptionDataGridOnColumnResize, DataGridOnColumnResizeCallback, DataGridOnColumnResizeCallbackType);
+
+pub type DataGridOnRowResizeCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, row: usize, new_height: f32) -> Update;
+impl_callback!(DataGridOnRowResize, OptionDataGridOnRowResize, DataGridOnRowResizeCallback, DataGridOnRowResizeCallbackType);
+
+/// Returns the cells in `range` as tab-separated values
+pub type DataGridOnCopyRangeCallbackType = extern "C" fn(&mut RefAny, range: DataGridRange) -> AzString;
+impl_callback!(DataGridOnCopyRange, OptionDataGridOnCopyRange, DataGridOnCopyRangeCallback, DataGridOnCopyRangeCallbackType);
+
+/// Clears all cells in `range`
+pub type DataGridOnClearRangeCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, range: DataGridRange) -> Update;
+impl_callback!(DataGridOnClearRange, OptionDataGridOnClearRange, DataGridOnClearRangeCallback, DataGridOnClearRangeCallbackType);
+
+/// Position of a cell in the grid
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct DataGridCell {
+    pub row: usize,
+    pub column: usize,
+}
+
+/// Rectangular range of selected cells
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct DataGridRange {
+    /// Cell where the selection started (stays fixed when extending the selection)
+    pub anchor: DataGridCell,
+    /// Cell that has the keyboard cursor, this is the cell that is edited
+    pub cursor: DataGridCell,
+}
+
+impl_option!(DataGridRange, OptionDataGridRange, [Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+
+impl DataGridRange {
+
+    /// Selection of a single cell
+    pub fn new(row: usize, column: usize) -> Self {
+        let cell = DataGridCell { row, column };
+        Self { anchor: cell, cursor: cell }
+    }
+
+    pub fn get_top_left(&self) -> DataGridCell {
+        DataGridCell {
+            row: self.anchor.row.min(self.cursor.row),
+            column: self.anchor.column.min(self.cursor.column),
+        }
+    }
+
+    pub fn get_bottom_right(&self) -> DataGridCell {
+        DataGridCell {
+            row: self.anchor.row.max(self.cursor.row),
+            column: self.anchor.column.max(self.cursor.column),
+        }
+    }
+
+    pub fn contains(&self, row: usize, column: usize) -> bool {
+        let top_left = self.get_top_left();
+        let bottom_right = self.get_bottom_right();
+        row >= top_left.row && row <= bottom_right.row &&
+        column >= top_left.column && column <= bottom_right.column
+    }
+
+    pub fn contains_row(&self, row: usize) -> bool {
+        row >= self.anchor.row.min(self.cursor.row) && row <= self.anchor.row.max(self.cursor.row)
+    }
+
+    pub fn contains_column(&self, column: usize) -> bool {
+        column >= self.anchor.column.min(self.cursor.column) && column <= self.anchor.column.max(self.cursor.column)
+    }
+
+    /// Number of cells in the range (saturates at `usize::MAX`)
+    pub fn get_cell_count(&self) -> usize {
+        let top_left = self.get_top_left();
+        let bottom_right = self.get_bottom_right();
+        (bottom_right.row - top_left.row).saturating_add(1)
+        .saturating_mul((bottom_right.column - top_left.column).saturating_add(1))
+    }
+}
+
+/// Spreadsheet-like grid of text cells
+///
+/// The DataGrid does not store any cell data: the text of each visible
+/// cell is requested from the `cell_provider`, so the grid can have
+/// millions of rows and columns. Only the cells that fit into the visible
+/// area are rendered (inside of an IFrame, so that the DataGrid knows its
+/// own size). Like the other widgets, the DataGrid does not keep any state
+/// between frames: scrolling, selection, resizing and editing are reported
+/// through the callbacks and the application sets the new
+/// `first_visible_row` / `selection` / `column_widths` / cell contents
+/// on the DataGrid it creates for the next frame.
+///
+/// The cell under the cursor is rendered as a `TextInput`: double-click
+/// it or press F2 to edit it, Enter / Tab commit the edit, Escape cancels.
+/// Ctrl+C / Ctrl+X / Ctrl+V copy, cut and paste the selection as
+/// tab-separated values. Without the `on_copy_range` / `on_clear_range`
+/// callbacks, copying and clearing go through the `cell_provider` and
+/// `on_cell_edit` one cell at a time, which is only done for selections of
+/// up to 100 000 cells - set the range callbacks for large grids, so that
+/// Ctrl+A, Ctrl+C / Delete work on the whole grid.
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct DataGrid {
+    /// Total number of rows
+    pub row_count: usize,
+    /// Total number of columns
+    pub column_count: usize,
+    /// Names of the columns, columns without an entry are named
+    /// like spreadsheet columns ("A", "B", ..., "Z", "AA", ...)
+    pub column_headers: StringVec,
+    /// Width of each column in pixels (default: 80 for columns without an entry)
+    pub column_widths: F32Vec,
+    /// Height of each row in pixels (default: 20 for rows without an entry)
+    pub row_heights: F32Vec,
+    /// Index of the first rendered row
+    pub first_visible_row: usize,
+    /// Index of the first rendered column
+    pub first_visible_column: usize,
+    /// Currently selected cells
+    pub selection: OptionDataGridRange,
+    /// Returns the text of each cell
+    pub cell_provider: OptionDataGridCellProvider,
+    /// Returns additional CSS properties for each cell
+    pub cell_style: OptionDataGridCellStyle,
+    /// Called when the selection changes
+    pub on_select: OptionDataGridOnSelect,
+    /// Called when the user scrolls the grid
+    pub on_scroll: OptionDataGridOnScroll,
+    /// Called when the text of a cell is changed by editing, pasting or deleting
+    pub on_cell_edit: OptionDataGridOnCellEdit,
+    /// Called when the user has finished resizing a column
+    pub on_column_resize: OptionDataGridOnColumnResize,
+    /// Called when the user has finished resizing a row
+    pub on_row_resize: OptionDataGridOnRowResize,
+    /// Returns the selected range as tab-separated values (instead of
+    /// calling the `cell_provider` for each selected cell)
+    pub on_copy_range: OptionDataGridOnCopyRange,
+    /// Called when the selected range is deleted or cut (instead of
+    /// calling `on_cell_edit` for each selected cell)
+    pub on_clear_range: OptionDataGridOnClearRange,
+}
+
+impl Default for DataGrid {
+    fn default() -> Self {
+        Self {
+            row_count: 0,
+            column_count: 0,
+            column_headers: StringVec::from_const_slice(&[]),
+            column_widths: F32Vec::from_const_slice(&[]),
+            row_heights: F32Vec::from_const_slice(&[]),
+            first_visible_row: 0,
+            first_visible_column: 0,
+            selection: None.into(),
+            cell_provider: None.into(),
+            cell_style: None.into(),
+            on_select: None.into(),
+            on_scroll: None.into(),
+            on_cell_edit: None.into(),
+            on_column_resize: None.into(),
+            on_row_resize: None.into(),
+            on_copy_range: None.into(),
+            on_clear_range: None.into(),
+        }
+    }
+}
+
+impl DataGrid {
+
+    pub fn new(row_count: usize, column_count: usize) -> Self {
+        Self {
+            row_count,
+            column_count,
+            .. Default::default()
+        }
+    }
+
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut m = Self::default();
+        core::mem::swap(&mut m, self);
+        m
+    }
+
+    pub fn with_column_headers(&mut self, column_headers: StringVec) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_column_headers(column_headers);
+        m
+    }
+
+    pub fn set_column_headers(&mut self, column_headers: StringVec) {
+        self.column_headers = column_headers;
+    }
+
+    pub fn with_column_widths(&mut self, column_widths: F32Vec) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_column_widths(column_widths);
+        m
+    }
+
+    pub fn set_column_widths(&mut self, column_widths: F32Vec) {
+        self.column_widths = column_widths;
+    }
+
+    pub fn with_row_heights(&mut self, row_heights: F32Vec) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_row_heights(row_heights);
+        m
+    }
+
+    pub fn set_row_heights(&mut self, row_heights: F32Vec) {
+        self.row_heights = row_heights;
+    }
+
+    pub fn with_scroll_position(&mut self, first_visible_row: usize, first_visible_column: usize) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_scroll_position(first_visible_row, first_visible_column);
+        m
+    }
+
+    pub fn set_scroll_position(&mut self, first_visible_row: usize, first_visible_column: usize) {
+        self.first_visible_row = first_visible_row;
+        self.first_visible_column = first_visible_column;
+    }
+
+    pub fn with_selection(&mut self, selection: OptionDataGridRange) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_selection(selection);
+        m
+    }
+
+    pub fn set_selection(&mut self, selection: OptionDataGridRange) {
+        self.selection = selection;
+    }
+
+    pub fn with_cell_provider(&mut self, data: RefAny, cell_provider: DataGridCellProviderCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_cell_provider(data, cell_provider);
+        m
+    }
+
+    pub fn set_cell_provider(&mut self, data: RefAny, cell_provider: DataGridCellProviderCallbackType) {
+        self.cell_provider = Some(DataGridCellProvider {
+            data,
+            callback: DataGridCellProviderCallback { cb: cell_provider }
+        }).into();
+    }
+
+    pub fn with_cell_style(&mut self, data: RefAny, cell_style: DataGridCellStyleCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_cell_style(data, cell_style);
+        m
+    }
+
+    pub fn set_cell_style(&mut self, data: RefAny, cell_style: DataGridCellStyleCallbackType) {
+        self.cell_style = Some(DataGridCellStyle {
+            data,
+            callback: DataGridCellStyleCallback { cb: cell_style }
+        }).into();
+    }
+
+    pub fn with_on_select(&mut self, data: RefAny, on_select: DataGridOnSelectCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_select(data, on_select);
+        m
+    }
+
+    pub fn set_on_select(&mut self, data: RefAny, on_select: DataGridOnSelectCallbackType) {
+        self.on_select = Some(DataGridOnSelect {
+            data,
+            callback: DataGridOnSelectCallback { cb: on_select }
+        }).into();
+    }
+
+    pub fn with_on_scroll(&mut self, data: RefAny, on_scroll: DataGridOnScrollCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_scroll(data, on_scroll);
+        m
+    }
+
+    pub fn set_on_scroll(&mut self, data: RefAny, on_scroll: DataGridOnScrollCallbackType) {
+        self.on_scroll = Some(DataGridOnScroll {
+            data,
+            callback: DataGridOnScrollCallback { cb: on_scroll }
+        }).into();
+    }
+
+    pub fn with_on_cell_edit(&mut self, data: RefAny, on_cell_edit: DataGridOnCellEditCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_cell_edit(data, on_cell_edit);
+        m
+    }
+
+    pub fn set_on_cell_edit(&mut self, data: RefAny, on_cell_edit: DataGridOnCellEditCallbackType) {
+        self.on_cell_edit = Some(DataGridOnCellEdit {
+            data,
+            callback: DataGridOnCellEditCallback { cb: on_cell_edit }
+        }).into();
+    }
+
+    pub fn with_on_column_resize(&mut self, data: RefAny, on_column_resize: DataGridOnColumnResizeCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_column_resize(data, on_column_resize);
+        m
+    }
+
+    pub fn set_on_column_resize(&mut self, data: RefAny, on_column_resize: DataGridOnColumnResizeCallbackType) {
+        self.on_column_resize = Some(DataGridOnColumnResize {
+            data,
+            callback: DataGridOnColumnResizeCallback { cb: on_column_resize }
+        }).into();
+    }
+
+    pub fn with_on_row_resize(&mut self, data: RefAny, on_row_resize: DataGridOnRowResizeCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_row_resize(data, on_row_resize);
+        m
+    }
+
+    pub fn set_on_row_resize(&mut self, data: RefAny, on_row_resize: DataGridOnRowResizeCallbackType) {
+        self.on_row_resize = Some(DataGridOnRowResize {
+            data,
+            callback: DataGridOnRowResizeCallback { cb: on_row_resize }
+        }).into();
+    }
+
+    pub fn with_on_copy_range(&mut self, data: RefAny, on_copy_range: DataGridOnCopyRangeCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_copy_range(data, on_copy_range);
+        m
+    }
+
+    pub fn set_on_copy_range(&mut self, data: RefAny, on_copy_range: DataGridOnCopyRangeCallbackType) {
+        self.on_copy_range = Some(DataGridOnCopyRange {
+            data,
+            callback: DataGridOnCopyRangeCallback { cb: on_copy_range }
+        }).into();
+    }
+
+    pub fn with_on_clear_range(&mut self, data: RefAny, on_clear_range: DataGridOnClearRangeCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_clear_range(data, on_clear_range);
+        m
+    }
+
+    pub fn set_on_clear_range(&mut self, data: RefAny, on_clear_range: DataGridOnClearRangeCallbackType) {
+        self.on_clear_range = Some(DataGridOnClearRange {
+            data,
+            callback: DataGridOnClearRangeCallback { cb: on_clear_range }
+        }).into();
+    }
+
+    pub fn get_column_width(&self, column: usize) -> f32 {
+        self.column_widths.as_ref().get(column).copied().unwrap_or(DEFAULT_COLUMN_WIDTH)
+    }
+
+    pub fn get_row_height(&self, row: usize) -> f32 {
+        self.row_heights.as_ref().get(row).copied().unwrap_or(DEFAULT_ROW_HEIGHT)
+    }
+
+    /// Returns the header text of the column
+    pub fn get_column_name(&self, column: usize) -> AzString {
+        match self.column_headers.as_ref().get(column) {
+            Some(s) => s.clone(),
+            None => column_letters(column).into(),
+        }
+    }
+
+    /// Returns the text of the cell or an empty string if there is no `cell_provider`
+    pub fn get_cell_text(&mut self, row: usize, column: usize) -> AzString {
+        match self.cell_provider.as_mut() {
+            Some(DataGridCellProvider { data, callback }) => (callback.cb)(data, row, column),
+            None => AzString::from_const_str(""),
+        }
+    }
+
+    /// Returns the selection, clipped to the size of the grid
+    /// (`None` if nothing is selected or the selection is out of bounds)
+    pub fn get_clipped_selection(&self) -> Option<DataGridRange> {
+        let selection = self.selection.into_option()?;
+        let top_left = selection.get_top_left();
+        let bottom_right = selection.get_bottom_right();
+        if top_left.row >= self.row_count || top_left.column >= self.column_count {
+            return None;
+        }
+        Some(DataGridRange {
+            anchor: top_left,
+            cursor: DataGridCell {
+                row: bottom_right.row.min(self.row_count - 1),
+                column: bottom_right.column.min(self.column_count - 1),
+            },
+        })
+    }
+
+    /// Returns the selected cells as tab-separated values (the format
+    /// spreadsheet applications use for copying ranges of cells)
+    ///
+    /// Uses the `on_copy_range` callback if set, otherwise the `cell_provider`
+    /// is called for each cell - in that case, an empty string is returned
+    /// for selections with more than 100 000 cells.
+    pub fn get_selection_tsv(&mut self) -> AzString {
+        let selection = match self.get_clipped_selection() {
+            Some(s) => s,
+            None => return AzString::from_const_str(""),
+        };
+
+        if let Some(DataGridOnCopyRange { data, callback }) = self.on_copy_range.as_mut() {
+            return (callback.cb)(data, selection);
+        }
+
+        if selection.get_cell_count() > MAX_SELECTION_CELLS {
+            return AzString::from_const_str("");
+        }
+
+        let top_left = selection.get_top_left();
+        let bottom_right = selection.get_bottom_right();
+
+        let rows = (top_left.row..=bottom_right.row).map(|row| {
+            (top_left.column..=bottom_right.column)
+            .map(|column| self.get_cell_text(row, column).as_str().to_string())
+            .collect::<Vec<_>>()
+        }).collect::<Vec<_>>();
+
+        encode_tsv(&rows).into()
+    }
+
+    pub fn dom(self) -> Dom {
+
+        let mut data_grid_local_dataset = DataGridLocalDataset {
+            data_grid: self,
+            visible_rows: 0,
+            visible_columns: 0,
+            resize_drag: None,
+            suppress_header_click: false,
+        };
+
+        // the cell editor is rendered on top of the IFrame (and not inside of it),
+        // so that it lives in the same DOM as the container and can be focused
+        // from the keyboard handler of the container
+        let editor_position = get_editor_position(&data_grid_local_dataset.data_grid);
+        let editor_text = editor_position.map(|(cell, _, _, _, _)| {
+            data_grid_local_dataset.data_grid.get_cell_text(cell.row, cell.column)
+        });
+
+        let data_grid_local_dataset = RefAny::new(data_grid_local_dataset);
+
+        let mut children = vec![
+            Dom::iframe(data_grid_local_dataset.clone(), render_grid)
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(GRID_STYLE))
+        ];
+
+        if let (Some((cell, x, y, width, height)), Some(text)) = (editor_position, editor_text) {
+
+            let editor_local_dataset = RefAny::new(DataGridEditorLocalDataset {
+                cell,
+                original_text: text.as_str().to_string(),
+                committed: false,
+                backref: data_grid_local_dataset.clone(),
+            });
+
+            let mut text_input = TextInput::new().with_text(text);
+            text_input.set_on_virtual_key_down(editor_local_dataset.clone(), self::input::default_on_editor_key_down);
+            text_input.set_on_focus_lost(editor_local_dataset, self::input::default_on_editor_focus_lost);
+            text_input.set_container_style(
+                EDITOR_STYLE.iter().cloned()
+                .chain(vec![
+                    NodeDataInlineCssProperty::Normal(CssProperty::Left(LayoutLeftValue::Exact(LayoutLeft { inner: PixelValue::px(x) }))),
+                    NodeDataInlineCssProperty::Normal(CssProperty::Top(LayoutTopValue::Exact(LayoutTop { inner: PixelValue::px(y) }))),
+                    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::px(width) }))),
+                    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::px(height) }))),
+                ])
+                .collect::<Vec<_>>()
+                .into()
+            );
+
+            children.push(text_input.dom().with_ids_and_classes(EDITOR_CLASS));
+        }
+
+        Dom::div()
+        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(CONTAINER_STYLE))
+        .with_ids_and_classes(CONTAINER_CLASS)
+        .with_tab_index(TabIndex::Auto)
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                data: data_grid_local_dataset.clone(),
+                callback: Callback { cb: self::input::default_on_mouse_down },
+            },
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::Scroll),
+                data: data_grid_local_dataset.clone(),
+                callback: Callback { cb: self::input::default_on_mouse_wheel },
+            },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                data: data_grid_local_dataset.clone(),
+                callback: Callback { cb: self::input::default_on_key_down },
+            },
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::MouseOver),
+                data: data_grid_local_dataset.clone(),
+                callback: Callback { cb: self::input::default_on_resize_drag },
+            },
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::LeftMouseUp),
+                data: data_grid_local_dataset,
+                callback: Callback { cb: self::input::default_on_resize_drag_end },
+            },
+        ].into())
+        .with_children(children.into())
+    }
+}
+
+impl From<DataGrid> for Dom {
+    fn from(d: DataGrid) -> Dom {
+        d.dom()
+    }
+}
+
+/// Returns the spreadsheet-style name of a column: A, B, ..., Z, AA, AB, ...
+fn column_letters(mut column: usize) -> String {
+    let mut letters = Vec::new();
+    loop {
+        letters.push((b'A' + (column % 26) as u8) as char);
+        if column < 26 {
+            break;
+        }
+        column = column / 26 - 1;
+    }
+    letters.iter().rev().collect()
+}
+
+// Encodes a range of cells as tab-separated values, cells containing
+// tabs, newlines or quotes are quoted (same as spreadsheet applications do)
+fn encode_tsv(rows: &[Vec<String>]) -> String {
+    let mut tsv = String::new();
+    for row in rows {
+        for (i, cell) in row.iter().enumerate() {
+            if i != 0 {
+                tsv.push('\t');
+            }
+            if cell.contains(['\t', '\n', '\r', '"']) {
+                tsv.push('"');
+                tsv.push_str(&cell.replace('"', "\"\""));
+                tsv.push('"');
+            } else {
+                tsv.push_str(cell);
+            }
+        }
+        tsv.push('\n');
+    }
+    tsv
+}
+
+// Decodes tab-separated values (inverse of encode_tsv), accepts \n and \r\n line endings
+fn decode_tsv(tsv: &str) -> Vec<Vec<String>> {
+
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut cell = String::new();
+    let mut chars = tsv.chars().peekable();
+    let mut in_quotes = false;
+    let mut at_cell_start = true;
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    cell.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                cell.push(c);
+            }
+            continue;
+        }
+
+        match c {
+            '"' if at_cell_start => {
+                in_quotes = true;
+                at_cell_start = false;
+            },
+            '\t' => {
+                row.push(core::mem::take(&mut cell));
+                at_cell_start = true;
+            },
+            '\r' if chars.peek() == Some(&'\n') => { },
+            '\n' => {
+                row.push(core::mem::take(&mut cell));
+                rows.push(core::mem::take(&mut row));
+                at_cell_start = true;
+            },
+            c => {
+                cell.push(c);
+                at_cell_start = false;
+            }
+        }
+    }
+
+    // last line without a trailing newline
+    if !cell.is_empty() || !row.is_empty() {
+        row.push(cell);
+        rows.push(row);
+    }
+
+    rows
+}
+
+// Returns how many items (rows or columns) starting at `first` are
+// (at least partially) visible in `available` pixels
+fn count_visible<F: Fn(usize) -> f32>(size_of: F, first: usize, total: usize, available: f32) -> usize {
+    let mut used = 0.0;
+    let mut count = 0;
+    for i in first..total {
+        if used >= available {
+            break;
+        }
+        used += size_of(i);
+        count += 1;
+    }
+    count
+}
+
+// Returns the cell under the cursor and its (x, y, width, height),
+// relative to the top left corner of the grid
+fn get_editor_position(data_grid: &DataGrid) -> Option<(DataGridCell, f32, f32, f32, f32)> {
+
+    let cursor = data_grid.selection.into_option()?.cursor;
+
+    if data_grid.cell_provider.is_none() ||
+       cursor.row >= data_grid.row_count ||
+       cursor.column >= data_grid.column_count ||
+       cursor.row < data_grid.first_visible_row ||
+       cursor.column < data_grid.first_visible_column {
+        return None;
+    }
+
+    let mut x = ROW_HEADER_WIDTH;
+    for column in data_grid.first_visible_column..cursor.column {
+        x += data_grid.get_column_width(column);
+        if x > MAX_EDITOR_OFFSET {
+            return None;
+        }
+    }
+
+    let mut y = HEADER_HEIGHT;
+    for row in data_grid.first_visible_row..cursor.row {
+        y += data_grid.get_row_height(row);
+        if y > MAX_EDITOR_OFFSET {
+            return None;
+        }
+    }
+
+    Some((cursor, x, y, data_grid.get_column_width(cursor.column), data_grid.get_row_height(cursor.row)))
+}
+
+fn with_size(props: &[NodeDataInlineCssProperty], extra: &[NodeDataInlineCssProperty], width: f32, height: f32) -> NodeDataInlineCssPropertyVec {
+    props.iter()
+    .chain(extra.iter())
+    .cloned()
+    .chain(vec![
+        NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::px(width) }))),
+        NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::px(height) }))),
+    ])
+    .collect::<Vec<_>>()
+    .into()
+}
+
+// IFrame callback: renders the frozen header row / column and the visible cells
+extern "C" fn render_grid(data: &mut RefAny, info: &mut IFrameCallbackInfo) -> IFrameCallbackReturn {
+
+    let size = info.get_bounds().get_logical_size();
+
+    let backref = data.clone();
+    let mut data_grid_local_dataset = match data.downcast_mut::<DataGridLocalDataset>() {
+        Some(s) => s,
+        None => return IFrameCallbackReturn::default(),
+    };
+
+    let data_grid_local_dataset = &mut *data_grid_local_dataset;
+    let data_grid = &mut data_grid_local_dataset.data_grid;
+
+    let first_row = data_grid.first_visible_row.min(data_grid.row_count.saturating_sub(1));
+    let first_column = data_grid.first_visible_column.min(data_grid.column_count.saturating_sub(1));
+
+    let visible_rows = count_visible(|r| data_grid.get_row_height(r), first_row, data_grid.row_count, size.height - HEADER_HEIGHT);
+    let visible_columns = count_visible(|c| data_grid.get_column_width(c), first_column, data_grid.column_count, size.width - ROW_HEADER_WIDTH);
+
+    data_grid_local_dataset.visible_rows = visible_rows;
+    data_grid_local_dataset.visible_columns = visible_columns;
+
+    let selection = data_grid.selection.into_option();
+    let columns = first_column..(first_column + visible_columns);
+
+    // frozen header row: corner + column names
+    let mut header_row = vec![
+        Dom::div()
+        .with_inline_css_props(with_size(HEADER_STYLE, &[], ROW_HEADER_WIDTH, HEADER_HEIGHT))
+        .with_ids_and_classes(HEADER_CLASS)
+    ];
+
+    header_row.extend(columns.clone().map(|column| {
+        let is_selected = selection.map(|s| s.contains_column(column)).unwrap_or(false);
+        let header_local_dataset = RefAny::new(DataGridHeaderLocalDataset {
+            index: column,
+            backref: backref.clone(),
+        });
+
+        Dom::div()
+        .with_inline_css_props(with_size(
+            HEADER_STYLE,
+            if is_selected { HEADER_SELECTED_STYLE } else { &[] },
+            data_grid.get_column_width(column),
+            HEADER_HEIGHT
+        ))
+        .with_ids_and_classes(if is_selected { HEADER_SELECTED_CLASS } else { HEADER_CLASS })
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::LeftMouseUp),
+                data: header_local_dataset.clone(),
+                callback: Callback { cb: self::input::default_on_column_header_click },
+            }
+        ].into())
+        .with_children(vec![
+            Dom::text(data_grid.get_column_name(column)),
+            Dom::div()
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(COLUMN_RESIZE_HANDLE_STYLE))
+            .with_ids_and_classes(COLUMN_RESIZE_CLASS)
+            .with_callbacks(vec![
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                    data: header_local_dataset,
+                    callback: Callback { cb: self::input::default_on_column_resize_start },
+                }
+            ].into())
+        ].into())
+    }));
+
+    let mut rows = vec![
+        Dom::div()
+        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(ROW_STYLE))
+        .with_ids_and_classes(ROW_CLASS)
+        .with_children(header_row.into())
+    ];
+
+    for row in first_row..(first_row + visible_rows) {
+
+        let row_height = data_grid.get_row_height(row);
+        let is_row_selected = selection.map(|s| s.contains_row(row)).unwrap_or(false);
+        let header_local_dataset = RefAny::new(DataGridHeaderLocalDataset {
+            index: row,
+            backref: backref.clone(),
+        });
+
+        // frozen header column: row number
+        let mut cells = vec![
+            Dom::div()
+            .with_inline_css_props(with_size(
+                HEADER_STYLE,
+                if is_row_selected { HEADER_SELECTED_STYLE } else { &[] },
+                ROW_HEADER_WIDTH,
+                row_height
+            ))
+            .with_ids_and_classes(if is_row_selected { HEADER_SELECTED_CLASS } else { HEADER_CLASS })
+            .with_callbacks(vec![
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::LeftMouseUp),
+                    data: header_local_dataset.clone(),
+                    callback: Callback { cb: self::input::default_on_row_header_click },
+                }
+            ].into())
+            .with_children(vec![
+                Dom::text(format!("{}", row + 1)),
+                Dom::div()
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(ROW_RESIZE_HANDLE_STYLE))
+                .with_ids_and_classes(ROW_RESIZE_CLASS)
+                .with_callbacks(vec![
+                    CallbackData {
+                        event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                        data: header_local_dataset,
+                        callback: Callback { cb: self::input::default_on_row_resize_start },
+                    }
+                ].into())
+            ].into())
+        ];
+
+        for column in columns.clone() {
+
+            let is_selected = selection.map(|s| s.contains(row, column)).unwrap_or(false);
+            let text = data_grid.get_cell_text(row, column);
+
+            let mut style = CELL_STYLE.to_vec();
+            if is_selected {
+                style.extend(CELL_SELECTED_STYLE.iter().cloned());
+            }
+            if let Some(DataGridCellStyle { data, callback }) = data_grid.cell_style.as_mut() {
+                style.extend((callback.cb)(data, row, column, is_selected).into_library_owned_vec());
+            }
+
+            cells.push(
+                Dom::div()
+                .with_inline_css_props(with_size(&style, &[], data_grid.get_column_width(column), row_height))
+                .with_ids_and_classes(if is_selected { CELL_SELECTED_CLASS } else { CELL_CLASS })
+                .with_callbacks(vec![
+                    CallbackData {
+                        event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                        data: RefAny::new(DataGridCellLocalDataset {
+                            cell: DataGridCell { row, column },
+                            backref: backref.clone(),
+                        }),
+                        callback: Callback { cb: self::input::default_on_cell_mouse_down },
+                    },
+                    CallbackData {
+                        event: EventFilter::Hover(HoverEventFilter::MouseEnter),
+                        data: RefAny::new(DataGridCellLocalDataset {
+                            cell: DataGridCell { row, column },
+                            backref: backref.clone(),
+                        }),
+                        callback: Callback { cb: self::input::default_on_cell_mouse_enter },
+                    },
+                ].into())
+                .with_child(Dom::text(text))
+            );
+        }
+
+        rows.push(
+            Dom::div()
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(ROW_STYLE))
+            .with_ids_and_classes(ROW_CLASS)
+            .with_children(cells.into())
+        );
+    }
+
+    let mut dom = Dom::div()
+    .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(GRID_STYLE))
+    .with_children(DomVec::from_vec(rows));
+
+    IFrameCallbackReturn {
+        dom: dom.style(Css::empty()),
+        scroll_size: size,
+        scroll_offset: LogicalPosition::zero(),
+        virtual_scroll_size: size,
+        virtual_scroll_offset: LogicalPosition::zero(),
+    }
+}
+
+// dataset set on the top-level DataGrid node (and the grid IFrame)
+struct DataGridLocalDataset {
+    data_grid: DataGrid,
+    // number of (partially) visible rows / columns, set when the grid is rendered
+    visible_rows: usize,
+    visible_columns: usize,
+    resize_drag: Option<ResizeDrag>,
+    // set when a resize starts, so that releasing the mouse
+    // on the header doesn't select the column / row
+    suppress_header_click: bool,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum ResizeDirection {
+    Column,
+    Row,
+}
+
+// column or row currently being resized by dragging its header
+struct ResizeDrag {
+    direction: ResizeDirection,
+    index: usize,
+    header_node: DomNodeId,
+    start_cursor: f32,
+    start_size: f32,
+    current_size: f32,
+}
+
+// dataset set on each column / row header
+struct DataGridHeaderLocalDataset {
+    index: usize,
+    backref: RefAny, // RefAny<DataGridLocalDataset>
+}
+
+// dataset set on each rendered cell
+struct DataGridCellLocalDataset {
+    cell: DataGridCell,
+    backref: RefAny, // RefAny<DataGridLocalDataset>
+}
+
+// dataset of the cell editor
+struct DataGridEditorLocalDataset {
+    cell: DataGridCell,
+    original_text: String,
+    // set after Enter / Tab, so that losing focus doesn't commit the text again
+    committed: bool,
+    backref: RefAny, // RefAny<DataGridLocalDataset>
+}
+
+// handle input events for the data grid
+mod input {
+
+    use std::string::String;
+    use azul_desktop::app::Clipboard;
+    use azul_desktop::callbacks::{RefAny, CallbackInfo, Update, FocusTarget};
+    use azul_desktop::css::{
+        AzString, CssProperty, PixelValue,
+        LayoutWidth, LayoutWidthValue,
+        LayoutHeight, LayoutHeightValue,
+    };
+    use azul_core::window::VirtualKeyCode;
+    use crate::widgets::text_input::{TextInputState, OnTextInputReturn, TextInputValid};
+    use super::{
+        DataGridLocalDataset, DataGridHeaderLocalDataset, DataGridCellLocalDataset,
+        DataGridEditorLocalDataset, DataGridRange, DataGridCell,
+        ResizeDrag, ResizeDirection,
+        DataGridOnSelect, DataGridOnScroll, DataGridOnCellEdit,
+        DataGridOnColumnResize, DataGridOnRowResize, DataGridOnClearRange,
+        MIN_COLUMN_WIDTH, MIN_ROW_HEIGHT, CELLS_PER_SCROLL_NOTCH, MAX_SELECTION_CELLS,
+        decode_tsv,
+    };
+
+    pub(in super) extern "C" fn default_on_mouse_down(_data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+        // focus the grid, so that the keyboard navigation works after clicking a cell
+        let data_grid_node = info.get_hit_node();
+        info.set_focus(FocusTarget::Id(data_grid_node));
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_cell_mouse_down(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut data = match data.downcast_mut::<DataGridCellLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let cell = data.cell;
+        let mut data_grid_local_dataset = match data.backref.downcast_mut::<DataGridLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let extend = info.get_current_keyboard_state().shift_down();
+        select(&mut data_grid_local_dataset, info, cell, extend)
+    }
+
+    pub(in super) extern "C" fn default_on_cell_mouse_enter(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        // extend the selection while dragging the mouse over the cells
+        if !info.get_current_mouse_state().left_down {
+            return Update::DoNothing;
+        }
+
+        let mut data = match data.downcast_mut::<DataGridCellLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let cell = data.cell;
+        let mut data_grid_local_dataset = match data.backref.downcast_mut::<DataGridLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if data_grid_local_dataset.resize_drag.is_some() ||
+           data_grid_local_dataset.data_grid.selection.is_none() {
+            return Update::DoNothing;
+        }
+
+        select(&mut data_grid_local_dataset, info, cell, true)
+    }
+
+    pub(in super) extern "C" fn default_on_column_header_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+        on_header_click(data, info, ResizeDirection::Column)
+    }
+
+    pub(in super) extern "C" fn default_on_row_header_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+        on_header_click(data, info, ResizeDirection::Row)
+    }
+
+    // selects the whole column / row
+    fn on_header_click(data: &mut RefAny, info: &mut CallbackInfo, direction: ResizeDirection) -> Update {
+
+        let mut data = match data.downcast_mut::<DataGridHeaderLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let index = data.index;
+        let mut data_grid_local_dataset = match data.backref.downcast_mut::<DataGridLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if data_grid_local_dataset.suppress_header_click || data_grid_local_dataset.resize_drag.is_some() {
+            data_grid_local_dataset.suppress_header_click = false;
+            return Update::DoNothing;
+        }
+
+        let data_grid = &data_grid_local_dataset.data_grid;
+        let last_row = data_grid.row_count.saturating_sub(1);
+        let last_column = data_grid.column_count.saturating_sub(1);
+
+        let selection = match direction {
+            ResizeDirection::Column => DataGridRange {
+                anchor: DataGridCell { row: 0, column: index },
+                cursor: DataGridCell { row: last_row, column: index },
+            },
+            ResizeDirection::Row => DataGridRange {
+                anchor: DataGridCell { row: index, column: 0 },
+                cursor: DataGridCell { row: index, column: last_column },
+            },
+        };
+
+        set_selection(&mut data_grid_local_dataset, info, selection)
+    }
+
+    pub(in super) extern "C" fn default_on_column_resize_start(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+        on_resize_start(data, info, ResizeDirection::Column)
+    }
+
+    pub(in super) extern "C" fn default_on_row_resize_start(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+        on_resize_start(data, info, ResizeDirection::Row)
+    }
+
+    fn on_resize_start(data: &mut RefAny, info: &mut CallbackInfo, direction: ResizeDirection) -> Update {
+
+        let mut data = match data.downcast_mut::<DataGridHeaderLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let index = data.index;
+        let mut data_grid_local_dataset = match data.backref.downcast_mut::<DataGridLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let cursor = match info.get_current_mouse_state().cursor_position.get_position() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // resize handle -> header
+        let header_node = match info.get_parent(info.get_hit_node()) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let (start_cursor, start_size) = match direction {
+            ResizeDirection::Column => (cursor.x, data_grid_local_dataset.data_grid.get_column_width(index)),
+            ResizeDirection::Row => (cursor.y, data_grid_local_dataset.data_grid.get_row_height(index)),
+        };
+
+        data_grid_local_dataset.suppress_header_click = true;
+        data_grid_local_dataset.resize_drag = Some(ResizeDrag {
+            direction,
+            index,
+            header_node,
+            start_cursor,
+            start_size,
+            current_size: start_size,
+        });
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_resize_drag(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut data_grid_local_dataset = match data.downcast_mut::<DataGridLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let mouse_state = info.get_current_mouse_state();
+
+        let resize_drag = match data_grid_local_dataset.resize_drag.as_mut() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if !mouse_state.left_down {
+            return Update::DoNothing;
+        }
+
+        let cursor = match mouse_state.cursor_position.get_position() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // only the header is resized while dragging, the cells are
+        // re-rendered once the application has stored the new size
+        match resize_drag.direction {
+            ResizeDirection::Column => {
+                let new_width = (resize_drag.start_size + cursor.x - resize_drag.start_cursor).max(MIN_COLUMN_WIDTH);
+                if new_width != resize_drag.current_size {
+                    resize_drag.current_size = new_width;
+                    info.set_css_property(resize_drag.header_node, CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::px(new_width) })));
+                }
+            },
+            ResizeDirection::Row => {
+                let new_height = (resize_drag.start_size + cursor.y - resize_drag.start_cursor).max(MIN_ROW_HEIGHT);
+                if new_height != resize_drag.current_size {
+                    resize_drag.current_size = new_height;
+                    info.set_css_property(resize_drag.header_node, CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::px(new_height) })));
+                }
+            },
+        }
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_resize_drag_end(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut data_grid_local_dataset = match data.downcast_mut::<DataGridLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let resize_drag = match data_grid_local_dataset.resize_drag.take() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if resize_drag.current_size == resize_drag.start_size {
+            return Update::DoNothing;
+        }
+
+        let data_grid = &mut data_grid_local_dataset.data_grid;
+        match resize_drag.direction {
+            ResizeDirection::Column => match data_grid.on_column_resize.as_mut() {
+                Some(DataGridOnColumnResize { callback, data }) => (callback.cb)(data, info, resize_drag.index, resize_drag.current_size),
+                None => Update::DoNothing,
+            },
+            ResizeDirection::Row => match data_grid.on_row_resize.as_mut() {
+                Some(DataGridOnRowResize { callback, data }) => (callback.cb)(data, info, resize_drag.index, resize_drag.current_size),
+                None => Update::DoNothing,
+            },
+        }
+    }
+
+    pub(in super) extern "C" fn default_on_mouse_wheel(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut data_grid_local_dataset = match data.downcast_mut::<DataGridLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // scroll_y is in wheel "notches", positive = scrolling up,
+        // Shift + wheel scrolls horizontally
+        let mouse_state = info.get_current_mouse_state();
+        let horizontal = info.get_current_keyboard_state().shift_down();
+        let delta = -(mouse_state.get_scroll_y() * CELLS_PER_SCROLL_NOTCH).round() as isize;
+        if delta == 0 {
+            return Update::DoNothing;
+        }
+
+        let data_grid = &data_grid_local_dataset.data_grid;
+        let (first_row, first_column) = if horizontal {
+            (data_grid.first_visible_row, (data_grid.first_visible_column as isize + delta).max(0) as usize)
+        } else {
+            ((data_grid.first_visible_row as isize + delta).max(0) as usize, data_grid.first_visible_column)
+        };
+
+        scroll_to(&mut data_grid_local_dataset, info, first_row, first_column)
+    }
+
+    pub(in super) extern "C" fn default_on_key_down(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut data_grid_local_dataset = match data.downcast_mut::<DataGridLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let keyboard_state = info.get_current_keyboard_state();
+        let keycode = match keyboard_state.current_virtual_keycode.into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let data_grid_local_dataset = &mut *data_grid_local_dataset;
+        let row_count = data_grid_local_dataset.data_grid.row_count;
+        let column_count = data_grid_local_dataset.data_grid.column_count;
+        if row_count == 0 || column_count == 0 {
+            return Update::DoNothing;
+        }
+
+        let last_row = row_count - 1;
+        let last_column = column_count - 1;
+        let shift = keyboard_state.shift_down();
+        let ctrl = keyboard_state.ctrl_down();
+        let page_size = data_grid_local_dataset.visible_rows.saturating_sub(1).max(1);
+
+        let selection = data_grid_local_dataset.data_grid.selection.into_option();
+        let cursor = selection.map(|s| s.cursor).unwrap_or_default();
+
+        // Ctrl + key shortcuts
+        if ctrl {
+            match keycode {
+                VirtualKeyCode::A => {
+                    return set_selection(data_grid_local_dataset, info, DataGridRange {
+                        anchor: DataGridCell { row: 0, column: 0 },
+                        cursor: DataGridCell { row: last_row, column: last_column },
+                    });
+                },
+                VirtualKeyCode::C => {
+                    copy_selection(data_grid_local_dataset);
+                    return Update::DoNothing;
+                },
+                VirtualKeyCode::X => {
+                    copy_selection(data_grid_local_dataset);
+                    return clear_selection(data_grid_local_dataset, info);
+                },
+                VirtualKeyCode::V => {
+                    return paste(data_grid_local_dataset, info);
+                },
+                _ => { },
+            }
+        }
+
+        let (target, extend) = match keycode {
+            VirtualKeyCode::Up if ctrl => (DataGridCell { row: 0, .. cursor }, shift),
+            VirtualKeyCode::Down if ctrl => (DataGridCell { row: last_row, .. cursor }, shift),
+            VirtualKeyCode::Left if ctrl => (DataGridCell { column: 0, .. cursor }, shift),
+            VirtualKeyCode::Right if ctrl => (DataGridCell { column: last_column, .. cursor }, shift),
+            VirtualKeyCode::Up => (DataGridCell { row: cursor.row.saturating_sub(1), .. cursor }, shift),
+            VirtualKeyCode::Down => (DataGridCell { row: (cursor.row + 1).min(last_row), .. cursor }, shift),
+            VirtualKeyCode::Left => (DataGridCell { column: cursor.column.saturating_sub(1), .. cursor }, shift),
+            VirtualKeyCode::Right => (DataGridCell { column: (cursor.column + 1).min(last_column), .. cursor }, shift),
+            VirtualKeyCode::PageUp => (DataGridCell { row: cursor.row.saturating_sub(page_size), .. cursor }, shift),
+            VirtualKeyCode::PageDown => (DataGridCell { row: (cursor.row + page_size).min(last_row), .. cursor }, shift),
+            VirtualKeyCode::Home if ctrl => (DataGridCell { row: 0, column: 0 }, shift),
+            VirtualKeyCode::End if ctrl => (DataGridCell { row: last_row, column: last_column }, shift),
+            VirtualKeyCode::Home => (DataGridCell { column: 0, .. cursor }, shift),
+            VirtualKeyCode::End => (DataGridCell { column: last_column, .. cursor }, shift),
+            VirtualKeyCode::Tab if shift => (DataGridCell { column: cursor.column.saturating_sub(1), .. cursor }, false),
+            VirtualKeyCode::Tab => (DataGridCell { column: (cursor.column + 1).min(last_column), .. cursor }, false),
+            VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter if shift => (DataGridCell { row: cursor.row.saturating_sub(1), .. cursor }, false),
+            VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter => (DataGridCell { row: (cursor.row + 1).min(last_row), .. cursor }, false),
+            VirtualKeyCode::F2 => {
+                focus_editor(info);
+                return Update::DoNothing;
+            },
+            VirtualKeyCode::Delete | VirtualKeyCode::Back => {
+                return clear_selection(data_grid_local_dataset, info);
+            },
+            _ => return Update::DoNothing,
+        };
+
+        if selection.is_some() && target == cursor && !extend {
+            return Update::DoNothing;
+        }
+
+        select(data_grid_local_dataset, info, target, extend)
+    }
+
+    pub(in super) extern "C" fn default_on_editor_key_down(data: &mut RefAny, info: &mut CallbackInfo, state: &TextInputState) -> OnTextInputReturn {
+
+        let mut data = match data.downcast_mut::<DataGridEditorLocalDataset>() {
+            Some(s) => s,
+            None => return OnTextInputReturn { update: Update::DoNothing, valid: TextInputValid::Yes },
+        };
+
+        let keyboard_state = info.get_current_keyboard_state();
+        let keycode = match keyboard_state.current_virtual_keycode.into_option() {
+            Some(s) => s,
+            None => return OnTextInputReturn { update: Update::DoNothing, valid: TextInputValid::Yes },
+        };
+
+        // move the focus back to the grid: text input -> grid container
+        let data_grid_node = info.get_parent(info.get_hit_node());
+
+        let data = &mut *data;
+        let cell = data.cell;
+        let update = match keycode {
+            VirtualKeyCode::Escape => {
+                // cancel editing: re-render the editor with the original text
+                data.committed = true;
+                if let Some(node) = data_grid_node {
+                    info.set_focus(FocusTarget::Id(node));
+                }
+                Update::RefreshDom
+            },
+            VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter | VirtualKeyCode::Tab => {
+
+                let text = state.get_text();
+                let mut update = if text != data.original_text {
+                    data.original_text = text.clone();
+                    commit(data, info, text)
+                } else {
+                    Update::DoNothing
+                };
+                data.committed = true;
+
+                if let Some(node) = data_grid_node {
+                    info.set_focus(FocusTarget::Id(node));
+                }
+
+                let shift = keyboard_state.shift_down();
+                if let Some(mut data_grid_local_dataset) = data.backref.downcast_mut::<DataGridLocalDataset>() {
+                    let data_grid = &data_grid_local_dataset.data_grid;
+                    let target = match keycode {
+                        VirtualKeyCode::Tab if shift => DataGridCell { column: cell.column.saturating_sub(1), .. cell },
+                        VirtualKeyCode::Tab => DataGridCell { column: (cell.column + 1).min(data_grid.column_count.saturating_sub(1)), .. cell },
+                        _ if shift => DataGridCell { row: cell.row.saturating_sub(1), .. cell },
+                        _ => DataGridCell { row: (cell.row + 1).min(data_grid.row_count.saturating_sub(1)), .. cell },
+                    };
+                    update.max_self(select(&mut data_grid_local_dataset, info, target, false));
+                }
+
+                update
+            },
+            _ => Update::DoNothing,
+        };
+
+        OnTextInputReturn { update, valid: TextInputValid::Yes }
+    }
+
+    pub(in super) extern "C" fn default_on_editor_focus_lost(data: &mut RefAny, info: &mut CallbackInfo, state: &TextInputState) -> Update {
+
+        let mut data = match data.downcast_mut::<DataGridEditorLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let text = state.get_text();
+        if data.committed || text == data.original_text {
+            return Update::DoNothing;
+        }
+
+        data.committed = true;
+        data.original_text = text.clone();
+        commit(&mut data, info, text)
+    }
+
+    fn commit(data: &mut DataGridEditorLocalDataset, info: &mut CallbackInfo, text: String) -> Update {
+        let cell = data.cell;
+        match data.backref.downcast_mut::<DataGridLocalDataset>() {
+            Some(mut s) => edit_cell(&mut s, info, cell, text.into()),
+            None => Update::DoNothing,
+        }
+    }
+
+    // the editor is the last child of the grid container
+    fn focus_editor(info: &mut CallbackInfo) {
+        let data_grid_node = info.get_hit_node();
+        if let Some(editor) = info.get_last_child(data_grid_node) {
+            if info.get_first_child(data_grid_node) != Some(editor) {
+                info.set_focus(FocusTarget::Id(editor));
+            }
+        }
+    }
+
+    fn edit_cell(data_grid_local_dataset: &mut DataGridLocalDataset, info: &mut CallbackInfo, cell: DataGridCell, text: AzString) -> Update {
+        match data_grid_local_dataset.data_grid.on_cell_edit.as_mut() {
+            Some(DataGridOnCellEdit { callback, data }) => (callback.cb)(data, info, cell.row, cell.column, text),
+            None => Update::DoNothing,
+        }
+    }
+
+    fn copy_selection(data_grid_local_dataset: &mut DataGridLocalDataset) {
+        let tsv = data_grid_local_dataset.data_grid.get_selection_tsv();
+        if tsv.as_str().is_empty() {
+            return;
+        }
+        if let Some(mut clipboard) = Clipboard::new() {
+            let _ = clipboard.set_clipboard_string(tsv);
+        }
+    }
+
+    // sets all selected cells to an empty string, either via on_clear_range
+    // or (for selections of up to MAX_SELECTION_CELLS) via on_cell_edit
+    fn clear_selection(data_grid_local_dataset: &mut DataGridLocalDataset, info: &mut CallbackInfo) -> Update {
+
+        let selection = match data_grid_local_dataset.data_grid.get_clipped_selection() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if let Some(DataGridOnClearRange { data, callback }) = data_grid_local_dataset.data_grid.on_clear_range.as_mut() {
+            return (callback.cb)(data, info, selection);
+        }
+
+        if selection.get_cell_count() > MAX_SELECTION_CELLS {
+            return Update::DoNothing;
+        }
+
+        let top_left = selection.get_top_left();
+        let bottom_right = selection.get_bottom_right();
+
+        let mut result = Update::DoNothing;
+        for row in top_left.row..=bottom_right.row {
+            for column in top_left.column..=bottom_right.column {
+                result.max_self(edit_cell(data_grid_local_dataset, info, DataGridCell { row, column }, AzString::from_const_str("")));
+            }
+        }
+        result
+    }
+
+    // pastes tab-separated values from the clipboard, starting at the top left selected cell
+    fn paste(data_grid_local_dataset: &mut DataGridLocalDataset, info: &mut CallbackInfo) -> Update {
+
+        let top_left = match data_grid_local_dataset.data_grid.selection.into_option() {
+            Some(s) => s.get_top_left(),
+            None => return Update::DoNothing,
+        };
+
+        let text = match Clipboard::new().and_then(|c| c.get_clipboard_string()) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let rows = decode_tsv(text.as_str());
+        let row_count = data_grid_local_dataset.data_grid.row_count;
+        let column_count = data_grid_local_dataset.data_grid.column_count;
+
+        let mut result = Update::DoNothing;
+        let mut bottom_right = top_left;
+        for (row_offset, cells) in rows.into_iter().enumerate() {
+            let row = top_left.row + row_offset;
+            if row >= row_count {
+                break;
+            }
+            for (column_offset, text) in cells.into_iter().enumerate() {
+                let column = top_left.column + column_offset;
+                if column >= column_count {
+                    break;
+                }
+                bottom_right.row = bottom_right.row.max(row);
+                bottom_right.column = bottom_right.column.max(column);
+                result.max_self(edit_cell(data_grid_local_dataset, info, DataGridCell { row, column }, text.into()));
+            }
+        }
+
+        // select the pasted range
+        result.max_self(set_selection(data_grid_local_dataset, info, DataGridRange {
+            anchor: top_left,
+            cursor: bottom_right,
+        }));
+
+        result
+    }
+
+    // moves the cursor to the cell, scrolls it into view and reports the new selection
+    fn select(data_grid_local_dataset: &mut DataGridLocalDataset, info: &mut CallbackInfo, cell: DataGridCell, extend: bool) -> Update {
+
+        let selection = match (extend, data_grid_local_dataset.data_grid.selection.into_option()) {
+            (true, Some(s)) => DataGridRange { anchor: s.anchor, cursor: cell },
+            _ => DataGridRange::new(cell.row, cell.column),
+        };
+
+        let mut result = set_selection(data_grid_local_dataset, info, selection);
+
+        // scroll the cursor into view, the last row / column might only be partially visible
+        let data_grid = &data_grid_local_dataset.data_grid;
+        let fully_visible_rows = data_grid_local_dataset.visible_rows.saturating_sub(1).max(1);
+        let fully_visible_columns = data_grid_local_dataset.visible_columns.saturating_sub(1).max(1);
+
+        let mut first_row = data_grid.first_visible_row;
+        if cell.row < first_row {
+            first_row = cell.row;
+        } else if cell.row >= first_row + fully_visible_rows {
+            first_row = cell.row + 1 - fully_visible_rows;
+        }
+
+        let mut first_column = data_grid.first_visible_column;
+        if cell.column < first_column {
+            first_column = cell.column;
+        } else if cell.column >= first_column + fully_visible_columns {
+            first_column = cell.column + 1 - fully_visible_columns;
+        }
+
+        result.max_self(scroll_to(data_grid_local_dataset, info, first_row, first_column));
+        result
+    }
+
+    fn set_selection(data_grid_local_dataset: &mut DataGridLocalDataset, info: &mut CallbackInfo, selection: DataGridRange) -> Update {
+
+        if data_grid_local_dataset.data_grid.selection.into_option() == Some(selection) {
+            return Update::DoNothing;
+        }
+
+        data_grid_local_dataset.data_grid.selection = Some(selection).into();
+
+        match data_grid_local_dataset.data_grid.on_select.as_mut() {
+            Some(DataGridOnSelect { callback, data }) => (callback.cb)(data, info, selection),
+            None => Update::DoNothing,
+        }
+    }
+
+    fn scroll_to(data_grid_local_dataset: &mut DataGridLocalDataset, info: &mut CallbackInfo, first_row: usize, first_column: usize) -> Update {
+
+        let data_grid = &mut data_grid_local_dataset.data_grid;
+
+        // don't scroll past the point where the last row / column is at the bottom / right
+        let first_row = first_row.min(data_grid.row_count.saturating_sub(data_grid_local_dataset.visible_rows.saturating_sub(1).max(1)));
+        let first_column = first_column.min(data_grid.column_count.saturating_sub(data_grid_local_dataset.visible_columns.saturating_sub(1).max(1)));
+
+        if first_row == data_grid.first_visible_row && first_column == data_grid.first_visible_column {
+            return Update::DoNothing;
+        }
+
+        data_grid.first_visible_row = first_row;
+        data_grid.first_visible_column = first_column;
+
+        match data_grid.on_scroll.as_mut() {
+            Some(DataGridOnScroll { callback, data }) => (callback.cb)(data, info, first_row, first_column),
+            None => Update::DoNothing,
+        }
+    }
+}
+
+/// Renders a `<data-grid rows="100" columns="10" column_headers="A, B" />` XML node
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DataGridRenderer {
+    node: XmlNode,
+}
+
+impl DataGridRenderer {
+    pub fn new() -> Self {
+        Self {
+            node: XmlNode::new("data_grid"),
+        }
+    }
+}
+
+impl Default for DataGridRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XmlComponentTrait for DataGridRenderer {
+    fn get_type_id(&self) -> String {
+        "data_grid".to_string()
+    }
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        ComponentArguments {
+            args: vec![
+                ("rows".to_string(), "usize".to_string()),
+                ("columns".to_string(), "usize".to_string()),
+                ("column_headers".to_string(), "[String]".to_string()),
+            ],
+            accepts_text: false,
+        }
+    }
+
+    fn render_dom(
+        &self,
+        _: &XmlComponentMap,
+        args: &FilteredComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<StyledDom, RenderDomError> {
+        let mut data_grid = DataGrid::new(
+            parse_xml_arg(args, "rows", "usize", parse_xml_number)?.unwrap_or(0),
+            parse_xml_arg(args, "columns", "usize", parse_xml_number)?.unwrap_or(0),
+        );
+        if let Some(column_headers) = parse_xml_arg(args, "column_headers", "[String]", parse_xml_list)? {
+            data_grid.set_column_headers(column_headers);
+        }
+        Ok(data_grid.dom().style(Css::empty()))
+    }
+
+    fn compile_to_rust_code(
+        &self,
+        _: &XmlComponentMap,
+        _: &ComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<String, CompileError> {
+        Ok(String::from(
+            "DataGrid::new(rows, columns)\r\n\
+            .with_column_headers(StringVec::from_vec(column_headers.into_iter().map(AzString::from).collect()))\r\n\
+            .dom()"
+        ))
+    }
+
+    fn get_xml_node(&self) -> XmlNode {
+        self.node.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_data_grid_column_letters() {
+        assert_eq!(column_letters(0), "A");
+        assert_eq!(column_letters(25), "Z");
+        assert_eq!(column_letters(26), "AA");
+        assert_eq!(column_letters(27), "AB");
+        assert_eq!(column_letters(701), "ZZ");
+        assert_eq!(column_letters(702), "AAA");
+    }
+
+    #[test]
+    fn test_data_grid_tsv() {
+        let rows = vec![
+            vec!["1".to_string(), "two words".to_string()],
+            vec!["tab\there".to_string(), "\"quoted\"\nline".to_string()],
+        ];
+        let tsv = encode_tsv(&rows);
+        assert_eq!(tsv, "1\ttwo words\n\"tab\there\"\t\"\"\"quoted\"\"\nline\"\n");
+        assert_eq!(decode_tsv(&tsv), rows);
+        assert_eq!(decode_tsv("a\tb\r\nc\td"), vec![vec!["a", "b"], vec!["c", "d"]]);
+        assert_eq!(decode_tsv("a\t\tb\n"), vec![vec!["a", "", "b"]]);
+    }
+
+    #[test]
+    fn test_data_grid_range() {
+        let range = DataGridRange {
+            anchor: DataGridCell { row: 5, column: 1 },
+            cursor: DataGridCell { row: 2, column: 3 },
+        };
+        assert_eq!(range.get_top_left(), DataGridCell { row: 2, column: 1 });
+        assert_eq!(range.get_bottom_right(), DataGridCell { row: 5, column: 3 });
+        assert!(range.contains(2, 1) && range.contains(5, 3));
+        assert!(!range.contains(1, 1) && !range.contains(2, 4));
+        assert_eq!(range.get_cell_count(), 12);
+        assert_eq!(count_visible(|_| 20.0, 0, 100, 50.0), 3);
+        assert_eq!(count_visible(|_| 20.0, 98, 100, 50.0), 2);
+    }
+
+    extern "C" fn test_cell_provider(data: &mut RefAny, row: usize, column: usize) -> AzString {
+        if let Some(mut calls) = data.downcast_mut::<usize>() {
+            *calls += 1;
+        }
+        format!("{}{}", column_letters(column), row + 1).into()
+    }
+
+    extern "C" fn test_copy_range(_: &mut RefAny, range: DataGridRange) -> AzString {
+        format!("{:?}", range.get_bottom_right()).into()
+    }
+
+    #[test]
+    fn test_data_grid_large_selection() {
+        let calls = RefAny::new(0_usize);
+        let mut grid = DataGrid::new(1_000_000, 1_000)
+            .with_cell_provider(calls.clone(), test_cell_provider)
+            .with_selection(Some(DataGridRange {
+                anchor: DataGridCell { row: 1, column: 0 },
+                cursor: DataGridCell { row: 0, column: 1 },
+            }).into());
+
+        assert_eq!(grid.get_selection_tsv().as_str(), "A1\tB1\nA2\tB2\n");
+
+        // selection is clipped to the grid
+        grid.selection = Some(DataGridRange {
+            anchor: DataGridCell { row: 0, column: 0 },
+            cursor: DataGridCell { row: usize::MAX, column: usize::MAX },
+        }).into();
+        assert_eq!(grid.get_clipped_selection().map(|s| s.get_bottom_right()), Some(DataGridCell { row: 999_999, column: 999 }));
+
+        // Ctrl+A on a large grid does not call the cell_provider for every cell
+        let mut calls_copy = calls.clone();
+        *calls_copy.downcast_mut::<usize>().unwrap() = 0;
+        assert_eq!(grid.get_selection_tsv().as_str(), "");
+        assert_eq!(*calls_copy.downcast_ref::<usize>().unwrap(), 0);
+
+        // ... unless on_copy_range is set
+        grid.set_on_copy_range(RefAny::new(()), test_copy_range);
+        assert_eq!(grid.get_selection_tsv().as_str(), "DataGridCell { row: 999999, column: 999 }");
+
+        grid.selection = Some(DataGridRange::new(1_000_000, 0)).into();
+        assert_eq!(grid.get_clipped_selection(), None);
+        assert_eq!(grid.get_selection_tsv().as_str(), "");
+    }
+}
@@ -1,4 +1,11 @@
 
+use azul_desktop::xml::{
+    XmlComponentTrait, XmlComponentMap, XmlNode, XmlTextContent,
+    ComponentArguments, FilteredComponentArguments,
+    RenderDomError, CompileError,
+};
+use azul_desktop::styled_dom::StyledDom;
+use super::{parse_xml_arg, parse_xml_list, parse_xml_number};
 use alloc::vec::Vec;
 use azul_desktop::css::*;
 use azul_desktop::css::AzString;
@@ -560,4 +567,68 @@ extern "C" fn on_tab_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update
     let _ = select_new_tab_inner(data, info);
 
     Update::RefreshDom
-}
\ No newline at end of file
+}
+
+/// Renders a `<tab-header tabs="General, Advanced" active_tab="0" />` XML node
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TabHeaderRenderer {
+    node: XmlNode,
+}
+
+impl TabHeaderRenderer {
+    pub fn new() -> Self {
+        Self {
+            node: XmlNode::new("tab_header"),
+        }
+    }
+}
+
+impl Default for TabHeaderRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XmlComponentTrait for TabHeaderRenderer {
+    fn get_type_id(&self) -> String {
+        "tab_header".to_string()
+    }
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        ComponentArguments {
+            args: vec![
+                ("tabs".to_string(), "[String]".to_string()),
+                ("active_tab".to_string(), "usize".to_string()),
+            ],
+            accepts_text: false,
+        }
+    }
+
+    fn render_dom(
+        &self,
+        _: &XmlComponentMap,
+        args: &FilteredComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<StyledDom, RenderDomError> {
+        let mut tab_header = TabHeader::new(parse_xml_arg(args, "tabs", "[String]", parse_xml_list)?.unwrap_or_default());
+        tab_header.set_active_tab(parse_xml_arg(args, "active_tab", "usize", parse_xml_number)?.unwrap_or(0));
+        Ok(tab_header.dom().style(Css::empty()))
+    }
+
+    fn compile_to_rust_code(
+        &self,
+        _: &XmlComponentMap,
+        _: &ComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<String, CompileError> {
+        Ok(String::from(
+            "TabHeader::new(StringVec::from_vec(tabs.into_iter().map(AzString::from).collect()))\r\n\
+            .with_active_tab(active_tab)\r\n\
+            .dom()"
+        ))
+    }
+
+    fn get_xml_node(&self) -> XmlNode {
+        self.node.clone()
+    }
+}
@@ -1,5 +1,11 @@
 //! Text input (demonstrates two-way data binding)
 
+use azul_desktop::xml::{
+    XmlComponentTrait, XmlComponentMap, XmlNode, XmlTextContent,
+    ComponentArguments, FilteredComponentArguments,
+    RenderDomError, CompileError,
+};
+use super::get_xml_string_arg;
 use core::ops::Range;
 use azul_desktop::{
     css::*,
@@ -747,4 +753,74 @@ fn default_on_mouse_hover(
     // println!("default_on_mouse_hover");
 
     Update::DoNothing
-}
\ No newline at end of file
+}
+
+/// Renders a `<text-input value="Hello" placeholder="Name" />` XML node
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TextInputRenderer {
+    node: XmlNode,
+}
+
+impl TextInputRenderer {
+    pub fn new() -> Self {
+        Self {
+            node: XmlNode::new("text_input"),
+        }
+    }
+}
+
+impl Default for TextInputRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XmlComponentTrait for TextInputRenderer {
+    fn get_type_id(&self) -> String {
+        "text_input".to_string()
+    }
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        ComponentArguments {
+            args: vec![
+                ("value".to_string(), "String".to_string()),
+                ("placeholder".to_string(), "String".to_string()),
+            ],
+            accepts_text: false,
+        }
+    }
+
+    fn render_dom(
+        &self,
+        _: &XmlComponentMap,
+        args: &FilteredComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<StyledDom, RenderDomError> {
+        let mut text_input = TextInput::new();
+        if let Some(value) = get_xml_string_arg(args, "value") {
+            text_input.set_text(value);
+        }
+        if let Some(placeholder) = get_xml_string_arg(args, "placeholder") {
+            text_input.set_placeholder(placeholder);
+        }
+        Ok(text_input.dom().style(Css::empty()))
+    }
+
+    fn compile_to_rust_code(
+        &self,
+        _: &XmlComponentMap,
+        _: &ComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<String, CompileError> {
+        Ok(String::from(
+            "TextInput::new()\r\n\
+            .with_text(value.into())\r\n\
+            .with_placeholder(placeholder.into())\r\n\
+            .dom()"
+        ))
+    }
+
+    fn get_xml_node(&self) -> XmlNode {
+        self.node.clone()
+    }
+}
@@ -1,3 +1,10 @@
+use azul_desktop::xml::{
+    XmlComponentTrait, XmlComponentMap, XmlNode, XmlTextContent,
+    ComponentArguments, FilteredComponentArguments,
+    RenderDomError, CompileError,
+};
+use azul_desktop::styled_dom::StyledDom;
+use super::{parse_xml_arg, parse_xml_list};
 use azul_desktop::{
     css::*,
     css::AzString,
@@ -7,10 +14,13 @@ use azul_desktop::{
         NodeDataInlineCssPropertyVec,
         IdOrClassVec, DomVec,
         NodeDataInlineCssProperty,
-        NodeDataInlineCssProperty::{Normal, Active, Hover, Focus},
+        NodeDataInlineCssProperty::Normal,
+        CallbackData, EventFilter,
+        HoverEventFilter, FocusEventFilter,
     },
-    resources::{ImageRef, OptionImageRef},
-    callbacks::{RefAny, Update, CallbackInfo},
+    gl::OptionUsize,
+    resources::OptionImageRef,
+    callbacks::{RefAny, Update, CallbackInfo, Callback, CallbackType},
 };
 use std::vec::Vec;
 
@@ -25,9 +35,6 @@ const STYLE_BACKGROUND_CONTENT_3386545019168565479_ITEMS: &[StyleBackgroundConte
         stops: NormalizedLinearColorStopVec::from_const_slice(LINEAR_COLOR_STOP_8524009933333352376_ITEMS),
     })
 ];
-const STYLE_BACKGROUND_CONTENT_11062356617965867290_ITEMS: &[StyleBackgroundContent] = &[
-    StyleBackgroundContent::Color(ColorU { r: 240, g: 240, b: 240, a: 255 })
-];
 const STYLE_BACKGROUND_CONTENT_15987977139837592998_ITEMS: &[StyleBackgroundContent] = &[
     StyleBackgroundContent::Color(ColorU { r: 0, g: 0, b: 0, a: 255 })
 ];
@@ -42,21 +49,6 @@ const LINEAR_COLOR_STOP_8524009933333352376_ITEMS: &[NormalizedLinearColorStop]
 NormalizedLinearColorStop { offset: PercentageValue::const_new(100), color: ColorU { r: 227, g: 227, b: 227, a: 255 } }
 ];
 
-const CSS_MATCH_10250347571702901767_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul-native-tree-t-content-minus
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(9) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Top(LayoutTopValue::Exact(LayoutTop { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Absolute))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowY(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowX(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Left(LayoutLeftValue::Exact(LayoutLeft { inner: PixelValue::const_px(9) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(9) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Dotted }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } })))
-];
-const CSS_MATCH_10250347571702901767: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_10250347571702901767_PROPERTIES);    
-
 const CSS_MATCH_11045010670475678001_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul-native-tree-minus-icon
     NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(4) }))),
@@ -66,25 +58,7 @@ const CSS_MATCH_11045010670475678001_PROPERTIES: &[NodeDataInlineCssProperty] =
     NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(1) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_15987977139837592998_ITEMS))))
 ];
-const CSS_MATCH_11045010670475678001: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_11045010670475678001_PROPERTIES);    
-
-const CSS_MATCH_1250869685159433269_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul-native-tree-cross-content-2
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(9) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Top(LayoutTopValue::Exact(LayoutTop { inner: PixelValue::const_px(8) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Absolute))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowY(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowX(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Left(LayoutLeftValue::Exact(LayoutLeft { inner: PixelValue::const_px(8) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(9) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopWidth(LayoutBorderTopWidthValue::Exact(LayoutBorderTopWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopStyle(StyleBorderTopStyleValue::Exact(StyleBorderTopStyle { inner: BorderStyle::Dotted }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftStyle(StyleBorderLeftStyleValue::Exact(StyleBorderLeftStyle { inner: BorderStyle::Dotted }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } })))
-];
-const CSS_MATCH_1250869685159433269: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_1250869685159433269_PROPERTIES);    
+const CSS_MATCH_11045010670475678001: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_11045010670475678001_PROPERTIES);
 
 const CSS_MATCH_13401060217940352039_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul-native-tree-view
@@ -96,6 +70,8 @@ const CSS_MATCH_13401060217940352039_PROPERTIES: &[NodeDataInlineCssProperty] =
     NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(11) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_18001933966972968559_ITEMS)))),
     NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::OverflowY(LayoutOverflowValue::Exact(LayoutOverflow::Hidden))),
+    NodeDataInlineCssProperty::Normal(CssProperty::OverflowX(LayoutOverflowValue::Exact(LayoutOverflow::Hidden))),
     NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
@@ -110,16 +86,7 @@ const CSS_MATCH_13401060217940352039_PROPERTIES: &[NodeDataInlineCssProperty] =
     NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 221, g: 221, b: 221, a: 170 } }))),
     NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_2444935983575427872_ITEMS))))
 ];
-const CSS_MATCH_13401060217940352039: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_13401060217940352039_PROPERTIES);    
-
-const CSS_MATCH_13463400830017583629_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul-native-tree-pipe-down
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(18) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Relative))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(18) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Display(LayoutDisplayValue::Exact(LayoutDisplay::Block)))
-];
-const CSS_MATCH_13463400830017583629: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_13463400830017583629_PROPERTIES);    
+const CSS_MATCH_13401060217940352039: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_13401060217940352039_PROPERTIES);
 
 const CSS_MATCH_14249021884908901216_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul-native-tree-view-row-label-focusable-wrapper:hover
@@ -156,119 +123,7 @@ const CSS_MATCH_14249021884908901216_PROPERTIES: &[NodeDataInlineCssProperty] =
     NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } }))),
     NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } })))
 ];
-const CSS_MATCH_14249021884908901216: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_14249021884908901216_PROPERTIES);    
-
-const CSS_MATCH_14455923367901630186_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul-native-tree-space-1-filled
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(8) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Relative))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowY(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowX(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(18) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Display(LayoutDisplayValue::Exact(LayoutDisplay::Block)))
-];
-const CSS_MATCH_14455923367901630186: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_14455923367901630186_PROPERTIES);    
-
-const CSS_MATCH_15054086665198995512_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul-native-tree-view-row-label-focusable-wrapper.focused
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopWidth(LayoutBorderTopWidthValue::Exact(LayoutBorderTopWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Dotted }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftStyle(StyleBorderLeftStyleValue::Exact(StyleBorderLeftStyle { inner: BorderStyle::Dotted }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Dotted }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopStyle(StyleBorderTopStyleValue::Exact(StyleBorderTopStyle { inner: BorderStyle::Dotted }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } }))),
-    // .__azul-native-tree-view-row-label-focusable-wrapper:hover
-    NodeDataInlineCssProperty::Hover(CssProperty::TextColor(StyleTextColorValue::Exact(StyleTextColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
-    NodeDataInlineCssProperty::Hover(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Hover(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Hover(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Hover(CssProperty::BorderTopWidth(LayoutBorderTopWidthValue::Exact(LayoutBorderTopWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Hover(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Dotted }))),
-    NodeDataInlineCssProperty::Hover(CssProperty::BorderLeftStyle(StyleBorderLeftStyleValue::Exact(StyleBorderLeftStyle { inner: BorderStyle::Dotted }))),
-    NodeDataInlineCssProperty::Hover(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Dotted }))),
-    NodeDataInlineCssProperty::Hover(CssProperty::BorderTopStyle(StyleBorderTopStyleValue::Exact(StyleBorderTopStyle { inner: BorderStyle::Dotted }))),
-    NodeDataInlineCssProperty::Hover(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
-    NodeDataInlineCssProperty::Hover(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
-    NodeDataInlineCssProperty::Hover(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
-    NodeDataInlineCssProperty::Hover(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
-    NodeDataInlineCssProperty::Hover(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_16215943235627030128_ITEMS)))),
-    // .__azul-native-tree-view-row-label-focusable-wrapper
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(2) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(2) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Display(LayoutDisplayValue::Exact(LayoutDisplay::Block))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopWidth(LayoutBorderTopWidthValue::Exact(LayoutBorderTopWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftStyle(StyleBorderLeftStyleValue::Exact(StyleBorderLeftStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopStyle(StyleBorderTopStyleValue::Exact(StyleBorderTopStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } })))
-];
-const CSS_MATCH_15054086665198995512: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_15054086665198995512_PROPERTIES);    
-
-const CSS_MATCH_17035174955428217627_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul-native-tree-t-content
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(8) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Top(LayoutTopValue::Exact(LayoutTop { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Absolute))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Left(LayoutLeftValue::Exact(LayoutLeft { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(18) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Dotted }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } })))
-];
-const CSS_MATCH_17035174955428217627: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_17035174955428217627_PROPERTIES);    
-
-const CSS_MATCH_17631951240816806439_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul-native-tree-space-1
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(9) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(18) })))
-];
-const CSS_MATCH_17631951240816806439: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_17631951240816806439_PROPERTIES);    
-
-const CSS_MATCH_17932671798964167701_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul-native-tree-space-1-filled-content
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(18) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Top(LayoutTopValue::Exact(LayoutTop { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Absolute))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowY(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowX(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Left(LayoutLeftValue::Exact(LayoutLeft { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(9) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Dotted }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } })))
-];
-const CSS_MATCH_17932671798964167701: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_17932671798964167701_PROPERTIES);    
-
-const CSS_MATCH_2883986488332352590_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // body
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(5) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(5) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(5) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(5) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_11062356617965867290_ITEMS))))
-];
-const CSS_MATCH_2883986488332352590: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_2883986488332352590_PROPERTIES);    
-
-const CSS_MATCH_2919526787497691572_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul-native-tree-view-row
-    NodeDataInlineCssProperty::Normal(CssProperty::FlexDirection(LayoutFlexDirectionValue::Exact(LayoutFlexDirection::Row)))
-];
-const CSS_MATCH_2919526787497691572: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_2919526787497691572_PROPERTIES);    
+const CSS_MATCH_14249021884908901216: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_14249021884908901216_PROPERTIES);
 
 const CSS_MATCH_3920366294746786702_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul-native-tree-view-row-label
@@ -279,38 +134,7 @@ const CSS_MATCH_3920366294746786702_PROPERTIES: &[NodeDataInlineCssProperty] = &
     NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(18) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::Display(LayoutDisplayValue::Exact(LayoutDisplay::Block)))
 ];
-const CSS_MATCH_3920366294746786702: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_3920366294746786702_PROPERTIES);    
-
-const CSS_MATCH_5479296065075700509_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul-native-tree-l-content
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(11) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Top(LayoutTopValue::Exact(LayoutTop { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Absolute))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowY(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowX(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Left(LayoutLeftValue::Exact(LayoutLeft { inner: PixelValue::const_px(7) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(9) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftStyle(StyleBorderLeftStyleValue::Exact(StyleBorderLeftStyle { inner: BorderStyle::Dotted }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Dotted }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } })))
-];
-const CSS_MATCH_5479296065075700509: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_5479296065075700509_PROPERTIES);    
-
-const CSS_MATCH_5748554468056235124_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul-native-tree-pipe-down-content
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(8) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Top(LayoutTopValue::Exact(LayoutTop { inner: PixelValue::const_px(-1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Absolute))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Left(LayoutLeftValue::Exact(LayoutLeft { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(19) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Dotted }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } })))
-];
-const CSS_MATCH_5748554468056235124: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_5748554468056235124_PROPERTIES);    
+const CSS_MATCH_3920366294746786702: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_3920366294746786702_PROPERTIES);
 
 const CSS_MATCH_6438488809014395635_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul-native-tree-minus-content
@@ -335,18 +159,7 @@ const CSS_MATCH_6438488809014395635_PROPERTIES: &[NodeDataInlineCssProperty] = &
     NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_3386545019168565479_ITEMS)))),
     NodeDataInlineCssProperty::Normal(CssProperty::AlignItems(LayoutAlignItemsValue::Exact(LayoutAlignItems::Center)))
 ];
-const CSS_MATCH_6438488809014395635: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_6438488809014395635_PROPERTIES);    
-
-const CSS_MATCH_6621536559891676126_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul-native-tree-cross
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(18) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Relative))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowY(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowX(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(18) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Display(LayoutDisplayValue::Exact(LayoutDisplay::Block)))
-];
-const CSS_MATCH_6621536559891676126: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_6621536559891676126_PROPERTIES);    
+const CSS_MATCH_6438488809014395635: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_6438488809014395635_PROPERTIES);
 
 const CSS_MATCH_8394859448076413888_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul-native-tree-minus
@@ -354,55 +167,432 @@ const CSS_MATCH_8394859448076413888_PROPERTIES: &[NodeDataInlineCssProperty] = &
     NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Relative))),
     NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(18) })))
 ];
-const CSS_MATCH_8394859448076413888: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_8394859448076413888_PROPERTIES);    
+const CSS_MATCH_8394859448076413888: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_8394859448076413888_PROPERTIES);
+
+/// Height of a single tree row in pixels - all rows have the same
+/// height, so that the visible window can be computed without layout
+const ROW_HEIGHT: isize = 18;
+/// Horizontal indentation per tree level in pixels
+const INDENT_WIDTH: isize = 18;
+/// How many rows to scroll per mouse wheel "notch"
+const ROWS_PER_SCROLL_NOTCH: f32 = 3.0;
+/// Width of the vertical scrollbar
+const SCROLLBAR_WIDTH: isize = 10;
+/// Minimum height of the scrollbar thumb
+const MIN_SCROLLBAR_THUMB_HEIGHT: isize = 20;
+
+const COLOR_SELECTED: ColorU = ColorU { r: 0, g: 120, b: 215, a: 255 };
+const SELECTED_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_SELECTED)];
+const COLOR_WHITE: ColorU = ColorU { r: 255, g: 255, b: 255, a: 255 };
+const COLOR_TRANSPARENT: ColorU = ColorU { r: 255, g: 255, b: 255, a: 0 };
+
+static TREE_VIEW_ROW_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(ROW_HEIGHT))),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink { inner: FloatValue::const_new(0) })),
+];
 
-const CSS_MATCH_9438342815980407130_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul-native-tree-cross-content-1
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(9) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Top(LayoutTopValue::Exact(LayoutTop { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Absolute))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowY(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowX(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Left(LayoutLeftValue::Exact(LayoutLeft { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(9) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Dotted }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Dotted }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } })))
+static TREE_VIEW_ROW_LABEL_SELECTED_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_display(LayoutDisplay::Block)),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_WHITE })),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SELECTED_BACKGROUND))),
+
+    // padding: 0px 2px
+
+    Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(2))),
+    Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(2))),
+
+    // border: 1px solid transparent (same box as the unselected label)
+
+    Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(1))),
+    Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+
+    Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+
+    Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_TRANSPARENT })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_TRANSPARENT })),
+    Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_TRANSPARENT })),
+    Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_TRANSPARENT })),
 ];
-const CSS_MATCH_9438342815980407130: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_9438342815980407130_PROPERTIES);    
 
-const CSS_MATCH_9496626968151854549_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul-native-tree-l
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(18) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Relative))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowY(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowX(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(18) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Display(LayoutDisplayValue::Exact(LayoutDisplay::Block)))
+// vertical bar of the "+" icon, placed on top of the "-" icon
+static TREE_VIEW_PLUS_ICON_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_left(LayoutLeft::const_px(3))),
+    Normal(CssProperty::const_top(LayoutTop::const_px(2))),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(1))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(5))),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_15987977139837592998_ITEMS))),
 ];
-const CSS_MATCH_9496626968151854549: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_9496626968151854549_PROPERTIES);    
 
-const CSS_MATCH_9703015952013196920_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul-native-tree-t
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(18) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Relative))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(18) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Display(LayoutDisplayValue::Exact(LayoutDisplay::Block)))
+// placeholder for the expander of items without children
+static TREE_VIEW_LEAF_SPACER_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_width(LayoutWidth::const_px(ROW_HEIGHT))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(ROW_HEIGHT))),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink { inner: FloatValue::const_new(0) })),
+];
+
+// rows + scrollbar, fills the tree view container
+static TREE_VIEW_BODY_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow { inner: FloatValue::const_new(1) })),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+];
+static TREE_VIEW_ROWS_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow { inner: FloatValue::const_new(1) })),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+];
+const SCROLLBAR_TRACK_BACKGROUND: &[StyleBackgroundContent] = &[
+    StyleBackgroundContent::Color(ColorU { r: 240, g: 240, b: 240, a: 255 })
+];
+const SCROLLBAR_THUMB_BACKGROUND: &[StyleBackgroundContent] = &[
+    StyleBackgroundContent::Color(ColorU { r: 193, g: 193, b: 193, a: 255 })
+];
+static TREE_VIEW_SCROLLBAR_TRACK_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_width(LayoutWidth::const_px(SCROLLBAR_WIDTH))),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink { inner: FloatValue::const_new(0) })),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SCROLLBAR_TRACK_BACKGROUND))),
+];
+static TREE_VIEW_ICON_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_width(LayoutWidth::const_px(16))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(16))),
+    Normal(CssProperty::const_margin_right(LayoutMarginRight::const_px(2))),
+];
+
+const IDS_AND_CLASSES_9837365222714915139: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-view")),
 ];
-const CSS_MATCH_9703015952013196920: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_9703015952013196920_PROPERTIES);
+const TREE_VIEW_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_9837365222714915139);
+
+const IDS_AND_CLASSES_15453577716812400238: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-view-row")),
+];
+const TREE_VIEW_ROW_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_15453577716812400238);
+
+const IDS_AND_CLASSES_5562274544924627603: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-minus")),
+];
+const TREE_VIEW_MINUS_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_5562274544924627603);
+
+const IDS_AND_CLASSES_15170138310983987150: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-minus-content")),
+];
+const TREE_VIEW_MINUS_CONTENT_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_15170138310983987150);
+
+const IDS_AND_CLASSES_276637619792188049: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-minus-icon")),
+];
+const TREE_VIEW_MINUS_ICON_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_276637619792188049);
+
+const IDS_AND_CLASSES_7470312357251624338: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-plus-icon")),
+];
+const TREE_VIEW_PLUS_ICON_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_7470312357251624338);
+
+const IDS_AND_CLASSES_17022478219263932868: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-view-row-label")),
+];
+const TREE_VIEW_ROW_LABEL_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_17022478219263932868);
+
+const IDS_AND_CLASSES_12039918700145849527: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-view-row-label-focusable-wrapper")),
+];
+const TREE_VIEW_ROW_LABEL_WRAPPER_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_12039918700145849527);
+
+const IDS_AND_CLASSES_2207423384652218493: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-view-row-label-focusable-wrapper")),
+    Class(AzString::from_const_str("selected")),
+];
+const TREE_VIEW_ROW_LABEL_WRAPPER_SELECTED_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_2207423384652218493);
+
+const IDS_AND_CLASSES_14747897529056839219: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-view-scrollbar")),
+];
+const TREE_VIEW_SCROLLBAR_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_14747897529056839219);
+
+const IDS_AND_CLASSES_2527967634623573354: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-view-scrollbar-thumb")),
+];
+const TREE_VIEW_SCROLLBAR_THUMB_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_2527967634623573354);
+
+pub type TreeViewOnExpandCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, item_id: usize, expanded: bool) -> Update;
+impl_callback!(TreeViewOnExpand, OptionTreeViewOnExpand, TreeViewOnExpandCallback, TreeViewOnExpandCallbackType);
+
+pub type TreeViewOnSelectCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, item_id: usize, change: TreeViewSelectionChange) -> Update;
+impl_callback!(TreeViewOnSelect, OptionTreeViewOnSelect, TreeViewOnSelectCallback, TreeViewOnSelectCallbackType);
+
+pub type TreeViewOnLazyLoadCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, item_id: usize) -> Update;
+impl_callback!(TreeViewOnLazyLoad, OptionTreeViewOnLazyLoad, TreeViewOnLazyLoadCallback, TreeViewOnLazyLoadCallbackType);
+
+pub type TreeViewOnScrollCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, first_visible_row: usize) -> Update;
+impl_callback!(TreeViewOnScroll, OptionTreeViewOnScroll, TreeViewOnScrollCallback, TreeViewOnScrollCallbackType);
+
+/// Whether the TreeView allows selecting more than one item
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum TreeViewSelectionMode {
+    /// At most one item can be selected
+    #[default]
+    Single,
+    /// Ctrl + click toggles items, Shift + click selects a range
+    Multiple,
+}
 
-#[derive(Default)]
+/// How the selection should change in response to a user action,
+/// passed to the `on_select` callback and to `TreeView::apply_selection`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum TreeViewSelectionChange {
+    /// Deselect everything, then select the item (plain click / arrow keys)
+    Replace,
+    /// Flip the selection state of the item (Ctrl + click / Space)
+    Toggle,
+    /// Select all visible rows between the selection anchor and the item (Shift + click)
+    Extend,
+}
+
+/// Item (node) of the TreeView
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TreeViewItem {
+    /// User-defined ID of this item, passed to the callbacks - should be unique within the tree
+    pub id: usize,
+    /// Text of the item
+    pub label: AzString,
+    /// Optional icon, rendered at 16x16 pixels in front of the label
+    pub icon: OptionImageRef,
+    /// Child items
+    pub children: TreeViewItemVec,
+    /// Whether the item has children that are not loaded yet: the item
+    /// can be expanded and `on_lazy_load` is called when it is expanded
+    pub has_lazy_children: bool,
+    /// Whether the children of this item are visible
+    pub is_expanded: bool,
+    /// Whether the item is currently selected
+    pub is_selected: bool,
+}
+
+impl_vec!(TreeViewItem, TreeViewItemVec, TreeViewItemVecDestructor);
+impl_vec_clone!(TreeViewItem, TreeViewItemVec, TreeViewItemVecDestructor);
+impl_vec_mut!(TreeViewItem, TreeViewItemVec);
+impl_vec_debug!(TreeViewItem, TreeViewItemVec);
+impl_vec_partialeq!(TreeViewItem, TreeViewItemVec);
+
+impl TreeViewItem {
+
+    pub fn new(id: usize, label: AzString) -> Self {
+        Self {
+            id,
+            label,
+            icon: None.into(),
+            children: TreeViewItemVec::from_const_slice(&[]),
+            has_lazy_children: false,
+            is_expanded: false,
+            is_selected: false,
+        }
+    }
+
+    fn has_children(&self) -> bool {
+        self.has_lazy_children || !self.children.is_empty()
+    }
+}
+
+/// Hierarchical tree of items with expand / collapse and selection.
+///
+/// The TreeView does not store any state between frames: like the
+/// `TabHeader`, the callbacks only report what the user did and the
+/// application is expected to apply the change to its own copy of the
+/// TreeView (using `set_expanded`, `apply_selection`, `set_children`
+/// and `set_first_visible_row`) and return `Update::RefreshDom`.
+///
+/// Only the rows between `first_visible_row` and
+/// `first_visible_row + max_visible_rows` are rendered, so the size of
+/// the DOM does not depend on the number of items in the tree. The
+/// scrollbar next to the rows shows the position of this window in
+/// the tree, clicking above / below its thumb scrolls by one page.
+#[derive(Debug, Clone)]
 #[repr(C)]
 pub struct TreeView {
-    pub root: AzString,
+    /// Top-level items of the tree
+    pub items: TreeViewItemVec,
+    /// Single or multi-selection
+    pub selection_mode: TreeViewSelectionMode,
+    /// Index of the first rendered row (in the list of all currently visible rows)
+    pub first_visible_row: usize,
+    /// Maximum number of rows to render (default: 100)
+    pub max_visible_rows: usize,
+    /// Item that has the keyboard cursor (last selected item)
+    pub focused_item: OptionUsize,
+    /// Item from which Shift + click extends the selection
+    pub selection_anchor: OptionUsize,
+    /// Called when an item is expanded or collapsed
+    pub on_expand: OptionTreeViewOnExpand,
+    /// Called when the user selects an item
+    pub on_select: OptionTreeViewOnSelect,
+    /// Called when an item with `has_lazy_children` and no children is expanded
+    pub on_lazy_load: OptionTreeViewOnLazyLoad,
+    /// Called when the user scrolls the rendered window of rows
+    pub on_scroll: OptionTreeViewOnScroll,
+}
+
+impl Default for TreeView {
+    fn default() -> Self {
+        Self {
+            items: TreeViewItemVec::from_const_slice(&[]),
+            selection_mode: TreeViewSelectionMode::default(),
+            first_visible_row: 0,
+            max_visible_rows: 100,
+            focused_item: None.into(),
+            selection_anchor: None.into(),
+            on_expand: None.into(),
+            on_select: None.into(),
+            on_lazy_load: None.into(),
+            on_scroll: None.into(),
+        }
+    }
+}
+
+/// Row of the flattened tree, only contains items whose
+/// parents are all expanded, in the order they are displayed
+#[derive(Debug, Copy, Clone)]
+struct TreeViewRow<'a> {
+    item: &'a TreeViewItem,
+    depth: usize,
+    parent: Option<usize>,
+}
+
+// Returns all rows that are visible when the tree is fully scrolled out,
+// iterative so that deeply nested trees can't overflow the stack
+fn get_visible_rows(items: &[TreeViewItem]) -> Vec<TreeViewRow<'_>> {
+    let mut rows = Vec::new();
+    let mut stack = items.iter().rev().map(|item| TreeViewRow { item, depth: 0, parent: None }).collect::<Vec<_>>();
+    while let Some(row) = stack.pop() {
+        if row.item.is_expanded {
+            stack.extend(row.item.children.iter().rev().map(|child| TreeViewRow {
+                item: child,
+                depth: row.depth + 1,
+                parent: Some(row.item.id),
+            }));
+        }
+        rows.push(row);
+    }
+    rows
+}
+
+/// Flattened row without the borrow of the item, cached in the
+/// `TreeViewLocalDataset` so that scrolling and keyboard navigation
+/// don't have to walk the whole tree on every event
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct TreeViewRowInfo {
+    item_id: usize,
+    depth: usize,
+    parent: Option<usize>,
+    is_expanded: bool,
+    has_children: bool,
+    first_child: Option<usize>,
+}
+
+impl<'a> From<&TreeViewRow<'a>> for TreeViewRowInfo {
+    fn from(row: &TreeViewRow<'a>) -> Self {
+        Self {
+            item_id: row.item.id,
+            depth: row.depth,
+            parent: row.parent,
+            is_expanded: row.item.is_expanded,
+            has_children: row.item.has_children(),
+            first_child: row.item.children.as_ref().first().map(|c| c.id),
+        }
+    }
+}
+
+/// What `TreeView::dom` needs to render one visible row: the
+/// children of the item are not copied, only whether it has any
+struct TreeViewRowContent {
+    info: TreeViewRowInfo,
+    label: AzString,
+    icon: OptionImageRef,
+    is_selected: bool,
+}
+
+impl<'a> From<&TreeViewRow<'a>> for TreeViewRowContent {
+    fn from(row: &TreeViewRow<'a>) -> Self {
+        Self {
+            info: TreeViewRowInfo::from(row),
+            label: row.item.label.clone(),
+            icon: row.item.icon.clone(),
+            is_selected: row.item.is_selected,
+        }
+    }
+}
+
+fn get_visible_row_infos(items: &[TreeViewItem]) -> Vec<TreeViewRowInfo> {
+    get_visible_rows(items).iter().map(TreeViewRowInfo::from).collect()
+}
+
+// Returns the flex-grow factors of the (space above the thumb, thumb, space below
+// the thumb) of the scrollbar: the tree view doesn't know its own height, so the
+// thumb is sized relative to the rendered window of rows instead of in pixels
+fn get_scrollbar_proportions(first_row: usize, rendered_rows: usize, total_rows: usize) -> (f32, f32, f32) {
+    if total_rows == 0 || rendered_rows >= total_rows {
+        return (0.0, 1.0, 0.0);
+    }
+    let first_row = first_row.min(total_rows - rendered_rows);
+    (first_row as f32, rendered_rows as f32, (total_rows - first_row - rendered_rows) as f32)
+}
+
+fn find_item(items: &[TreeViewItem], item_id: usize) -> Option<&TreeViewItem> {
+    let mut stack = vec![items];
+    while let Some(items) = stack.pop() {
+        for item in items.iter() {
+            if item.id == item_id {
+                return Some(item);
+            }
+            stack.push(item.children.as_ref());
+        }
+    }
+    None
+}
+
+fn find_item_mut(items: &mut [TreeViewItem], item_id: usize) -> Option<&mut TreeViewItem> {
+    let mut stack = vec![items];
+    while let Some(items) = stack.pop() {
+        for item in items.iter_mut() {
+            if item.id == item_id {
+                return Some(item);
+            }
+            stack.push(item.children.as_mut());
+        }
+    }
+    None
+}
+
+fn for_each_item_mut<F: FnMut(&mut TreeViewItem)>(items: &mut [TreeViewItem], mut f: F) {
+    let mut stack = vec![items];
+    while let Some(items) = stack.pop() {
+        for item in items.iter_mut() {
+            f(item);
+            stack.push(item.children.as_mut());
+        }
+    }
 }
 
 impl TreeView {
-    pub fn new(root: AzString) -> Self {
-        Self { root }
+
+    pub fn new(items: TreeViewItemVec) -> Self {
+        Self {
+            items,
+            .. Default::default()
+        }
     }
 
     pub fn swap_with_default(&mut self) -> Self {
@@ -411,511 +601,801 @@ impl TreeView {
         m
     }
 
+    pub fn with_items(&mut self, items: TreeViewItemVec) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_items(items);
+        m
+    }
+
+    pub fn set_items(&mut self, items: TreeViewItemVec) {
+        self.items = items;
+    }
+
+    pub fn with_selection_mode(&mut self, selection_mode: TreeViewSelectionMode) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_selection_mode(selection_mode);
+        m
+    }
+
+    pub fn set_selection_mode(&mut self, selection_mode: TreeViewSelectionMode) {
+        self.selection_mode = selection_mode;
+    }
+
+    pub fn with_first_visible_row(&mut self, first_visible_row: usize) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_first_visible_row(first_visible_row);
+        m
+    }
+
+    pub fn set_first_visible_row(&mut self, first_visible_row: usize) {
+        self.first_visible_row = first_visible_row;
+    }
+
+    pub fn with_max_visible_rows(&mut self, max_visible_rows: usize) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_max_visible_rows(max_visible_rows);
+        m
+    }
+
+    pub fn set_max_visible_rows(&mut self, max_visible_rows: usize) {
+        self.max_visible_rows = max_visible_rows;
+    }
+
+    pub fn with_on_expand(&mut self, data: RefAny, on_expand: TreeViewOnExpandCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_expand(data, on_expand);
+        m
+    }
+
+    pub fn set_on_expand(&mut self, data: RefAny, on_expand: TreeViewOnExpandCallbackType) {
+        self.on_expand = Some(TreeViewOnExpand {
+            data,
+            callback: TreeViewOnExpandCallback { cb: on_expand }
+        }).into();
+    }
+
+    pub fn with_on_select(&mut self, data: RefAny, on_select: TreeViewOnSelectCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_select(data, on_select);
+        m
+    }
+
+    pub fn set_on_select(&mut self, data: RefAny, on_select: TreeViewOnSelectCallbackType) {
+        self.on_select = Some(TreeViewOnSelect {
+            data,
+            callback: TreeViewOnSelectCallback { cb: on_select }
+        }).into();
+    }
+
+    pub fn with_on_lazy_load(&mut self, data: RefAny, on_lazy_load: TreeViewOnLazyLoadCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_lazy_load(data, on_lazy_load);
+        m
+    }
+
+    pub fn set_on_lazy_load(&mut self, data: RefAny, on_lazy_load: TreeViewOnLazyLoadCallbackType) {
+        self.on_lazy_load = Some(TreeViewOnLazyLoad {
+            data,
+            callback: TreeViewOnLazyLoadCallback { cb: on_lazy_load }
+        }).into();
+    }
+
+    pub fn with_on_scroll(&mut self, data: RefAny, on_scroll: TreeViewOnScrollCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_scroll(data, on_scroll);
+        m
+    }
+
+    pub fn set_on_scroll(&mut self, data: RefAny, on_scroll: TreeViewOnScrollCallbackType) {
+        self.on_scroll = Some(TreeViewOnScroll {
+            data,
+            callback: TreeViewOnScrollCallback { cb: on_scroll }
+        }).into();
+    }
+
+    /// Returns the number of rows that are visible when all collapsed
+    /// items are skipped, i.e. the maximum for `first_visible_row`
+    pub fn get_visible_row_count(&self) -> usize {
+        get_visible_rows(self.items.as_ref()).len()
+    }
+
+    /// Expands or collapses the item with the given ID,
+    /// returns false if the item could not be found
+    pub fn set_expanded(&mut self, item_id: usize, expanded: bool) -> bool {
+        match find_item_mut(self.items.as_mut(), item_id) {
+            Some(item) => { item.is_expanded = expanded; true },
+            None => false,
+        }
+    }
+
+    /// Replaces the children of the item with the given ID and clears the
+    /// `has_lazy_children` flag (used to answer the `on_lazy_load` callback)
+    pub fn set_children(&mut self, item_id: usize, children: TreeViewItemVec) -> bool {
+        match find_item_mut(self.items.as_mut(), item_id) {
+            Some(item) => {
+                item.children = children;
+                item.has_lazy_children = false;
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Updates the `is_selected` flags of the items, the same way the
+    /// TreeView would do it internally - usually called from `on_select`
+    pub fn apply_selection(&mut self, item_id: usize, change: TreeViewSelectionChange) {
+        self.apply_selection_inner(item_id, change, None);
+    }
+
+    // `rows` are the cached visible rows, only needed to extend the selection
+    fn apply_selection_inner(&mut self, item_id: usize, change: TreeViewSelectionChange, rows: Option<&[TreeViewRowInfo]>) {
+
+        use self::TreeViewSelectionChange::*;
+
+        let change = match self.selection_mode {
+            TreeViewSelectionMode::Single => Replace,
+            TreeViewSelectionMode::Multiple => change,
+        };
+
+        match change {
+            Replace => {
+                for_each_item_mut(self.items.as_mut(), |item| item.is_selected = item.id == item_id);
+                self.selection_anchor = Some(item_id).into();
+            },
+            Toggle => {
+                if let Some(item) = find_item_mut(self.items.as_mut(), item_id) {
+                    item.is_selected = !item.is_selected;
+                }
+                self.selection_anchor = Some(item_id).into();
+            },
+            Extend => {
+                let anchor = self.selection_anchor.into_option().unwrap_or(item_id);
+                let selected = {
+                    let computed_rows;
+                    let rows = match rows {
+                        Some(s) => s,
+                        None => {
+                            computed_rows = get_visible_row_infos(self.items.as_ref());
+                            &computed_rows[..]
+                        },
+                    };
+                    let anchor_row = rows.iter().position(|r| r.item_id == anchor);
+                    let item_row = rows.iter().position(|r| r.item_id == item_id);
+                    match (anchor_row, item_row) {
+                        (Some(a), Some(b)) => rows[a.min(b)..=a.max(b)].iter().map(|r| r.item_id).collect::<Vec<_>>(),
+                        _ => vec![item_id],
+                    }
+                };
+                for_each_item_mut(self.items.as_mut(), |item| item.is_selected = selected.contains(&item.id));
+                self.selection_anchor = Some(anchor).into();
+            },
+        }
+
+        self.focused_item = Some(item_id).into();
+    }
+
     pub fn dom(self) -> Dom {
-        Dom::div()
-        .with_inline_css_props(CSS_MATCH_13401060217940352039)
-        .with_ids_and_classes({
-            const IDS_AND_CLASSES_9837365222714915139: &[IdOrClass] = &[
-                    Class(AzString::from_const_str("__azul-native-tree-view")),
 
-            ];
-            IdOrClassVec::from_const_slice(IDS_AND_CLASSES_9837365222714915139)
-        })
-        .with_children(DomVec::from_vec(vec![
-            Dom::div()
-            .with_inline_css_props(CSS_MATCH_2919526787497691572)
-            .with_ids_and_classes({
-                const IDS_AND_CLASSES_15453577716812400238: &[IdOrClass] = &[
-                            Class(AzString::from_const_str("__azul-native-tree-view-row")),
+        // the tree is only flattened once per DOM rebuild, the callbacks use the cached rows
+        let (rows, visible_rows) = {
+            let rows = get_visible_rows(self.items.as_ref());
+            let visible_rows = rows.iter()
+                .skip(self.first_visible_row)
+                .take(self.max_visible_rows)
+                .map(TreeViewRowContent::from)
+                .collect::<Vec<_>>();
+            (rows.iter().map(TreeViewRowInfo::from).collect::<Vec<_>>(), visible_rows)
+        };
 
-                ];
-                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_15453577716812400238)
-            })
-            .with_children(DomVec::from_vec(vec![
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_8394859448076413888)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_5562274544924627603: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-minus")),
+        let (space_above, thumb, space_below) = get_scrollbar_proportions(self.first_visible_row, visible_rows.len(), rows.len());
 
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_5562274544924627603)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_6438488809014395635)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_15170138310983987150: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-minus-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_15170138310983987150)
-                    })
-                    .with_children(DomVec::from_vec(vec![
-                        Dom::div()
-                        .with_inline_css_props(CSS_MATCH_11045010670475678001)
-                        .with_ids_and_classes({
-                            const IDS_AND_CLASSES_276637619792188049: &[IdOrClass] = &[
-                                                    Class(AzString::from_const_str("__azul-native-tree-minus-icon")),
-
-                            ];
-                            IdOrClassVec::from_const_slice(IDS_AND_CLASSES_276637619792188049)
-                        })
-                    ]))
-                ])),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_3920366294746786702)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_17022478219263932868: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-view-row-label")),
+        let tree_view_local_dataset = RefAny::new(TreeViewLocalDataset {
+            tree_view: self,
+            rows,
+        });
 
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_17022478219263932868)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_14249021884908901216)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_12039918700145849527: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-view-row-label-focusable-wrapper")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_12039918700145849527)
-                    })
-                        .with_tab_index(TabIndex::Auto)
-                    .with_children(DomVec::from_vec(vec![
-                        Dom::text(AzString::from_const_str("Hello"))
-                    ]))
-                ]))
-            ])),
-            Dom::div()
-            .with_inline_css_props(CSS_MATCH_2919526787497691572)
-            .with_ids_and_classes({
-                const IDS_AND_CLASSES_15453577716812400238: &[IdOrClass] = &[
-                            Class(AzString::from_const_str("__azul-native-tree-view-row")),
+        let rows = visible_rows.into_iter().map(|row| {
 
-                ];
-                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_15453577716812400238)
-            })
-            .with_children(DomVec::from_vec(vec![
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_13463400830017583629)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_8562870525116426737: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-pipe-down")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_8562870525116426737)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_5748554468056235124)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_12623539011723615844: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-pipe-down-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_12623539011723615844)
-                    })
-                ])),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_17631951240816806439)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_13969147764958421470: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-space-1")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_13969147764958421470)
-                }),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_9703015952013196920)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_12683940372377849649: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-t")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_12683940372377849649)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_17035174955428217627)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_6948782902341484076: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-t-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_6948782902341484076)
-                    }),
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_10250347571702901767)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_7986348685827112423: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-t-content-minus")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_7986348685827112423)
-                    })
-                ])),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_3920366294746786702)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_17022478219263932868: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-view-row-label")),
+            let TreeViewRowContent { info, label, icon, is_selected } = row;
 
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_17022478219263932868)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_15054086665198995512)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_966274871881623987: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-view-row-label-focusable-wrapper")),
-                        Class(AzString::from_const_str("focused")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_966274871881623987)
-                    })
-                        .with_tab_index(TabIndex::Auto)
-                    .with_children(DomVec::from_vec(vec![
-                        Dom::text(AzString::from_const_str("Hello"))
-                    ]))
-                ]))
-            ])),
-            Dom::div()
-            .with_inline_css_props(CSS_MATCH_2919526787497691572)
-            .with_ids_and_classes({
-                const IDS_AND_CLASSES_15453577716812400238: &[IdOrClass] = &[
-                            Class(AzString::from_const_str("__azul-native-tree-view-row")),
+            let row_local_dataset = RefAny::new(TreeViewRowLocalDataset {
+                item_id: info.item_id,
+                backref: tree_view_local_dataset.clone(),
+            });
 
-                ];
-                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_15453577716812400238)
-            })
-            .with_children(DomVec::from_vec(vec![
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_9703015952013196920)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_12683940372377849649: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-t")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_12683940372377849649)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_17035174955428217627)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_6948782902341484076: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-t-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_6948782902341484076)
-                    }),
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_10250347571702901767)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_7986348685827112423: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-t-content-minus")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_7986348685827112423)
-                    })
-                ])),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_14455923367901630186)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_2250273140132504407: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-space-1-filled")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_2250273140132504407)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_17932671798964167701)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_11324832106902074912: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-space-1-filled-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_11324832106902074912)
-                    })
-                ])),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_6621536559891676126)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_3445414501074686586: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-cross")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_3445414501074686586)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_9438342815980407130)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_1166576799478159097: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-cross-content-1")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_1166576799478159097)
-                    }),
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_1250869685159433269)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_5610655148321459708: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-cross-content-2")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_5610655148321459708)
-                    })
-                ])),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_3920366294746786702)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_17022478219263932868: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-view-row-label")),
+            let mut row_children = Vec::new();
 
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_17022478219263932868)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_14249021884908901216)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_12039918700145849527: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-view-row-label-focusable-wrapper")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_12039918700145849527)
-                    })
-                        .with_tab_index(TabIndex::Auto)
-                    .with_children(DomVec::from_vec(vec![
-                        Dom::text(AzString::from_const_str("Hello"))
-                    ]))
-                ]))
-            ])),
-            Dom::div()
-            .with_inline_css_props(CSS_MATCH_2919526787497691572)
-            .with_ids_and_classes({
-                const IDS_AND_CLASSES_15453577716812400238: &[IdOrClass] = &[
-                            Class(AzString::from_const_str("__azul-native-tree-view-row")),
+            if info.depth > 0 {
+                row_children.push(Dom::div().with_inline_css_props(vec![
+                    Normal(CssProperty::const_width(LayoutWidth::const_px(info.depth as isize * INDENT_WIDTH))),
+                    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink { inner: FloatValue::const_new(0) })),
+                ].into()));
+            }
 
-                ];
-                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_15453577716812400238)
-            })
-            .with_children(DomVec::from_vec(vec![
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_9703015952013196920)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_12683940372377849649: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-t")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_12683940372377849649)
-                })
-                .with_children(DomVec::from_vec(vec![
+            row_children.push(if info.has_children {
+                let mut icons = vec![
                     Dom::div()
-                    .with_inline_css_props(CSS_MATCH_17035174955428217627)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_6948782902341484076: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-t-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_6948782902341484076)
-                    }),
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_10250347571702901767)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_7986348685827112423: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-t-content-minus")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_7986348685827112423)
-                    })
-                ])),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_14455923367901630186)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_2250273140132504407: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-space-1-filled")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_2250273140132504407)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_17932671798964167701)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_11324832106902074912: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-space-1-filled-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_11324832106902074912)
-                    })
-                ])),
+                    .with_inline_css_props(CSS_MATCH_11045010670475678001)
+                    .with_ids_and_classes(TREE_VIEW_MINUS_ICON_CLASS)
+                ];
+                if !info.is_expanded {
+                    icons.push(
+                        Dom::div()
+                        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TREE_VIEW_PLUS_ICON_STYLE))
+                        .with_ids_and_classes(TREE_VIEW_PLUS_ICON_CLASS)
+                    );
+                }
+
                 Dom::div()
                 .with_inline_css_props(CSS_MATCH_8394859448076413888)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_5562274544924627603: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-minus")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_5562274544924627603)
-                })
+                .with_ids_and_classes(TREE_VIEW_MINUS_CLASS)
+                .with_callbacks(vec![
+                    CallbackData {
+                        event: EventFilter::Hover(HoverEventFilter::LeftMouseUp),
+                        data: row_local_dataset.clone(),
+                        callback: Callback { cb: self::input::default_on_expander_click },
+                    }
+                ].into())
                 .with_children(DomVec::from_vec(vec![
                     Dom::div()
                     .with_inline_css_props(CSS_MATCH_6438488809014395635)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_15170138310983987150: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-minus-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_15170138310983987150)
-                    })
-                    .with_children(DomVec::from_vec(vec![
-                        Dom::div()
-                        .with_inline_css_props(CSS_MATCH_11045010670475678001)
-                        .with_ids_and_classes({
-                            const IDS_AND_CLASSES_276637619792188049: &[IdOrClass] = &[
-                                                    Class(AzString::from_const_str("__azul-native-tree-minus-icon")),
-
-                            ];
-                            IdOrClassVec::from_const_slice(IDS_AND_CLASSES_276637619792188049)
-                        })
-                    ]))
-                ])),
+                    .with_ids_and_classes(TREE_VIEW_MINUS_CONTENT_CLASS)
+                    .with_children(DomVec::from_vec(icons))
+                ]))
+            } else {
+                Dom::div()
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TREE_VIEW_LEAF_SPACER_STYLE))
+            });
+
+            let mut label_children = Vec::new();
+            if let Some(icon) = icon.into_option() {
+                label_children.push(
+                    Dom::image(icon)
+                    .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TREE_VIEW_ICON_STYLE))
+                );
+            }
+            label_children.push(Dom::text(label));
+
+            let (label_style, label_class) = if is_selected {
+                (NodeDataInlineCssPropertyVec::from_const_slice(TREE_VIEW_ROW_LABEL_SELECTED_STYLE), TREE_VIEW_ROW_LABEL_WRAPPER_SELECTED_CLASS)
+            } else {
+                (CSS_MATCH_14249021884908901216, TREE_VIEW_ROW_LABEL_WRAPPER_CLASS)
+            };
+
+            row_children.push(
                 Dom::div()
                 .with_inline_css_props(CSS_MATCH_3920366294746786702)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_17022478219263932868: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-view-row-label")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_17022478219263932868)
-                })
+                .with_ids_and_classes(TREE_VIEW_ROW_LABEL_CLASS)
+                .with_callbacks(vec![
+                    CallbackData {
+                        event: EventFilter::Hover(HoverEventFilter::LeftMouseUp),
+                        data: row_local_dataset.clone(),
+                        callback: Callback { cb: self::input::default_on_row_click },
+                    }
+                ].into())
                 .with_children(DomVec::from_vec(vec![
                     Dom::div()
-                    .with_inline_css_props(CSS_MATCH_14249021884908901216)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_12039918700145849527: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-view-row-label-focusable-wrapper")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_12039918700145849527)
-                    })
-                        .with_tab_index(TabIndex::Auto)
-                    .with_children(DomVec::from_vec(vec![
-                        Dom::text(AzString::from_const_str("Hello"))
-                    ]))
+                    .with_inline_css_props(label_style)
+                    .with_ids_and_classes(label_class)
+                    .with_children(DomVec::from_vec(label_children))
                 ]))
-            ])),
+            );
+
             Dom::div()
-            .with_inline_css_props(CSS_MATCH_2919526787497691572)
-            .with_ids_and_classes({
-                const IDS_AND_CLASSES_15453577716812400238: &[IdOrClass] = &[
-                            Class(AzString::from_const_str("__azul-native-tree-view-row")),
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TREE_VIEW_ROW_STYLE))
+            .with_ids_and_classes(TREE_VIEW_ROW_CLASS)
+            .with_children(DomVec::from_vec(row_children))
+        }).collect::<Vec<_>>();
 
-                ];
-                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_15453577716812400238)
-            })
+        // clicking above / below the thumb scrolls by one page
+        let scrollbar_space = |flex_grow: f32, cb: CallbackType| {
+            Dom::div()
+            .with_inline_css_props(vec![
+                Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::new(flex_grow) }))),
+            ].into())
+            .with_callbacks(vec![
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                    data: tree_view_local_dataset.clone(),
+                    callback: Callback { cb },
+                }
+            ].into())
+        };
+
+        let scrollbar = Dom::div()
+        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TREE_VIEW_SCROLLBAR_TRACK_STYLE))
+        .with_ids_and_classes(TREE_VIEW_SCROLLBAR_CLASS)
+        .with_children(DomVec::from_vec(vec![
+            scrollbar_space(space_above, self::input::default_on_scrollbar_page_up),
+            Dom::div()
+            .with_inline_css_props(vec![
+                Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::new(thumb) }))),
+                Normal(CssProperty::const_min_height(LayoutMinHeight::const_px(MIN_SCROLLBAR_THUMB_HEIGHT))),
+                Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SCROLLBAR_THUMB_BACKGROUND))),
+            ].into())
+            .with_ids_and_classes(TREE_VIEW_SCROLLBAR_THUMB_CLASS),
+            scrollbar_space(space_below, self::input::default_on_scrollbar_page_down),
+        ]));
+
+        Dom::div()
+        .with_inline_css_props(CSS_MATCH_13401060217940352039)
+        .with_ids_and_classes(TREE_VIEW_CLASS)
+        .with_tab_index(TabIndex::Auto)
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::Scroll),
+                data: tree_view_local_dataset.clone(),
+                callback: Callback { cb: self::input::default_on_mouse_wheel },
+            },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                data: tree_view_local_dataset,
+                callback: Callback { cb: self::input::default_on_key_down },
+            },
+        ].into())
+        .with_child(
+            Dom::div()
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TREE_VIEW_BODY_STYLE))
             .with_children(DomVec::from_vec(vec![
                 Dom::div()
-                .with_inline_css_props(CSS_MATCH_9496626968151854549)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_11091968853782313624: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-l")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_11091968853782313624)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_5479296065075700509)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_7201172733362059285: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-l-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_7201172733362059285)
-                    })
-                ])),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_14455923367901630186)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_2250273140132504407: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-space-1-filled")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_2250273140132504407)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_17932671798964167701)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_11324832106902074912: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-space-1-filled-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_11324832106902074912)
-                    })
-                ])),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_8394859448076413888)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_5562274544924627603: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-minus")),
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TREE_VIEW_ROWS_STYLE))
+                .with_children(DomVec::from_vec(rows)),
+                scrollbar,
+            ]))
+        )
+    }
+}
 
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_5562274544924627603)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_6438488809014395635)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_15170138310983987150: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-minus-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_15170138310983987150)
-                    })
-                    .with_children(DomVec::from_vec(vec![
-                        Dom::div()
-                        .with_inline_css_props(CSS_MATCH_11045010670475678001)
-                        .with_ids_and_classes({
-                            const IDS_AND_CLASSES_276637619792188049: &[IdOrClass] = &[
-                                                    Class(AzString::from_const_str("__azul-native-tree-minus-icon")),
-
-                            ];
-                            IdOrClassVec::from_const_slice(IDS_AND_CLASSES_276637619792188049)
-                        })
-                    ]))
-                ])),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_3920366294746786702)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_17022478219263932868: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-view-row-label")),
+// dataset set on the top-level TreeView node
+struct TreeViewLocalDataset {
+    tree_view: TreeView,
+    // visible rows of the tree_view, updated when an item is expanded / collapsed
+    rows: Vec<TreeViewRowInfo>,
+}
 
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_17022478219263932868)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_14249021884908901216)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_12039918700145849527: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-view-row-label-focusable-wrapper")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_12039918700145849527)
-                    })
-                        .with_tab_index(TabIndex::Auto)
-                    .with_children(DomVec::from_vec(vec![
-                        Dom::text(AzString::from_const_str("Hello"))
-                    ]))
-                ]))
-            ]))
-        ]))
+// dataset set on the expander and the label of each rendered row
+struct TreeViewRowLocalDataset {
+    item_id: usize,
+    backref: RefAny, // RefAny<TreeViewLocalDataset>
+}
+
+// handle input events for the tree view
+mod input {
+
+    use azul_desktop::callbacks::{RefAny, CallbackInfo, Update, FocusTarget};
+    use azul_core::window::VirtualKeyCode;
+    use super::{
+        TreeView, TreeViewLocalDataset, TreeViewRowLocalDataset,
+        TreeViewSelectionChange, TreeViewSelectionMode,
+        TreeViewOnExpand, TreeViewOnSelect, TreeViewOnLazyLoad, TreeViewOnScroll,
+        ROW_HEIGHT, ROWS_PER_SCROLL_NOTCH,
+        find_item, get_visible_row_infos,
+    };
+
+    pub(in super) extern "C" fn default_on_expander_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut data = match data.downcast_mut::<TreeViewRowLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let item_id = data.item_id;
+        let mut tree_view_local_dataset = match data.backref.downcast_mut::<TreeViewLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let expanded = match find_item(tree_view_local_dataset.tree_view.items.as_ref(), item_id) {
+            Some(item) => !item.is_expanded,
+            None => return Update::DoNothing,
+        };
+
+        expand_item(&mut tree_view_local_dataset, info, item_id, expanded)
+    }
+
+    pub(in super) extern "C" fn default_on_row_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut data = match data.downcast_mut::<TreeViewRowLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let item_id = data.item_id;
+        let mut tree_view_local_dataset = match data.backref.downcast_mut::<TreeViewLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // row label -> row -> tree view container: focus the container,
+        // so that the keyboard navigation works after clicking a row
+        let hit_node = info.get_hit_node();
+        if let Some(container) = info.get_parent(hit_node).and_then(|row| info.get_parent(row)) {
+            info.set_focus(FocusTarget::Id(container));
+        }
+
+        let keyboard_state = info.get_current_keyboard_state();
+        let change = if keyboard_state.shift_down() {
+            TreeViewSelectionChange::Extend
+        } else if keyboard_state.ctrl_down() {
+            TreeViewSelectionChange::Toggle
+        } else {
+            TreeViewSelectionChange::Replace
+        };
+
+        select_item(&mut tree_view_local_dataset, info, item_id, change)
+    }
+
+    pub(in super) extern "C" fn default_on_mouse_wheel(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut tree_view_local_dataset = match data.downcast_mut::<TreeViewLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // scroll_y is in wheel "notches", positive = scrolling up
+        let scroll_y = info.get_current_mouse_state().get_scroll_y();
+        let delta = -(scroll_y * ROWS_PER_SCROLL_NOTCH).round() as isize;
+        if delta == 0 {
+            return Update::DoNothing;
+        }
+
+        let row_count = tree_view_local_dataset.rows.len();
+        let tree_view = &mut tree_view_local_dataset.tree_view;
+        let new_first_row = (tree_view.first_visible_row as isize + delta)
+            .max(0)
+            .min(row_count.saturating_sub(1) as isize) as usize;
+
+        scroll_to_row(tree_view, info, new_first_row)
+    }
+
+    pub(in super) extern "C" fn default_on_scrollbar_page_up(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+        scroll_by_page(data, info, false)
+    }
+
+    pub(in super) extern "C" fn default_on_scrollbar_page_down(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+        scroll_by_page(data, info, true)
+    }
+
+    // Scrolls by the number of rows that fit into the scrollbar track
+    fn scroll_by_page(data: &mut RefAny, info: &mut CallbackInfo, down: bool) -> Update {
+
+        let mut tree_view_local_dataset = match data.downcast_mut::<TreeViewLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // space above / below the thumb -> scrollbar track
+        let page_size = info.get_parent(info.get_hit_node())
+            .and_then(|track| info.get_node_size(track))
+            .map(|size| (size.height / ROW_HEIGHT as f32) as usize)
+            .unwrap_or(0)
+            .max(1);
+
+        let row_count = tree_view_local_dataset.rows.len();
+        let tree_view = &mut tree_view_local_dataset.tree_view;
+        let new_first_row = if down {
+            (tree_view.first_visible_row + page_size).min(row_count.saturating_sub(1))
+        } else {
+            tree_view.first_visible_row.saturating_sub(page_size)
+        };
+
+        scroll_to_row(tree_view, info, new_first_row)
+    }
+
+    pub(in super) extern "C" fn default_on_key_down(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+        let mut tree_view_local_dataset = match data.downcast_mut::<TreeViewLocalDataset>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let keyboard_state = info.get_current_keyboard_state();
+        let keycode = match keyboard_state.current_virtual_keycode.into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // number of rows that fit into the container
+        let page_size = info.get_node_size(info.get_hit_node())
+            .map(|size| (size.height / ROW_HEIGHT as f32) as usize)
+            .unwrap_or(0)
+            .max(1);
+
+        let tree_view_local_dataset = &mut *tree_view_local_dataset;
+
+        let (current_row, row_count, current, target_row) = {
+            let rows = &tree_view_local_dataset.rows;
+            if rows.is_empty() {
+                return Update::DoNothing;
+            }
+
+            let current_row = tree_view_local_dataset.tree_view.focused_item
+                .into_option()
+                .and_then(|id| rows.iter().position(|r| r.item_id == id));
+
+            let current = current_row.map(|i| rows[i]);
+
+            let last_row = rows.len() - 1;
+            let target_row = match (keycode, current_row) {
+                (VirtualKeyCode::Up, Some(r)) => Some(r.saturating_sub(1)),
+                (VirtualKeyCode::Down, Some(r)) => Some((r + 1).min(last_row)),
+                (VirtualKeyCode::PageUp, Some(r)) => Some(r.saturating_sub(page_size)),
+                (VirtualKeyCode::PageDown, Some(r)) => Some((r + page_size).min(last_row)),
+                (VirtualKeyCode::Up, None) | (VirtualKeyCode::Down, None) |
+                (VirtualKeyCode::PageUp, None) | (VirtualKeyCode::PageDown, None) |
+                (VirtualKeyCode::Home, _) => Some(0),
+                (VirtualKeyCode::End, _) => Some(last_row),
+                _ => None,
+            }.map(|r| (r, rows[r].item_id));
+
+            (current_row, rows.len(), current, target_row)
+        };
+
+        let extend = keyboard_state.shift_down() && tree_view_local_dataset.tree_view.selection_mode == TreeViewSelectionMode::Multiple;
+
+        if let Some((target_row, target_id)) = target_row {
+            if current_row == Some(target_row) {
+                return Update::DoNothing;
+            }
+            let change = if extend { TreeViewSelectionChange::Extend } else { TreeViewSelectionChange::Replace };
+            let mut result = select_item(tree_view_local_dataset, info, target_id, change);
+            result.max_self(scroll_row_into_view(&mut tree_view_local_dataset.tree_view, info, target_row, page_size, row_count));
+            return result;
+        }
+
+        let current = match current {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        match keycode {
+            VirtualKeyCode::Left => {
+                if current.is_expanded && current.has_children {
+                    expand_item(tree_view_local_dataset, info, current.item_id, false)
+                } else if let Some(parent) = current.parent {
+                    select_item(tree_view_local_dataset, info, parent, TreeViewSelectionChange::Replace)
+                } else {
+                    Update::DoNothing
+                }
+            },
+            VirtualKeyCode::Right => {
+                if !current.is_expanded && current.has_children {
+                    expand_item(tree_view_local_dataset, info, current.item_id, true)
+                } else if let Some(first_child) = current.first_child {
+                    select_item(tree_view_local_dataset, info, first_child, TreeViewSelectionChange::Replace)
+                } else {
+                    Update::DoNothing
+                }
+            },
+            VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter => {
+                if current.has_children {
+                    expand_item(tree_view_local_dataset, info, current.item_id, !current.is_expanded)
+                } else {
+                    Update::DoNothing
+                }
+            },
+            VirtualKeyCode::Space => {
+                select_item(tree_view_local_dataset, info, current.item_id, TreeViewSelectionChange::Toggle)
+            },
+            _ => Update::DoNothing,
+        }
+    }
+
+    // Calls on_expand (and on_lazy_load if the children still have to be loaded)
+    fn expand_item(tree_view_local_dataset: &mut TreeViewLocalDataset, info: &mut CallbackInfo, item_id: usize, expanded: bool) -> Update {
+
+        let tree_view = &mut tree_view_local_dataset.tree_view;
+
+        let needs_lazy_load = expanded && find_item(tree_view.items.as_ref(), item_id)
+            .map(|item| item.has_lazy_children && item.children.is_empty())
+            .unwrap_or(false);
+
+        // the rows only change when an item is expanded / collapsed
+        if tree_view.set_expanded(item_id, expanded) {
+            tree_view_local_dataset.rows = get_visible_row_infos(tree_view.items.as_ref());
+        }
+
+        let mut result = match tree_view.on_expand.as_mut() {
+            Some(TreeViewOnExpand { callback, data }) => (callback.cb)(data, info, item_id, expanded),
+            None => Update::DoNothing,
+        };
+
+        if needs_lazy_load {
+            result.max_self(match tree_view.on_lazy_load.as_mut() {
+                Some(TreeViewOnLazyLoad { callback, data }) => (callback.cb)(data, info, item_id),
+                None => Update::DoNothing,
+            });
+        }
+
+        result
+    }
+
+    fn select_item(tree_view_local_dataset: &mut TreeViewLocalDataset, info: &mut CallbackInfo, item_id: usize, change: TreeViewSelectionChange) -> Update {
+
+        let tree_view = &mut tree_view_local_dataset.tree_view;
+        tree_view.apply_selection_inner(item_id, change, Some(&tree_view_local_dataset.rows));
+
+        match tree_view.on_select.as_mut() {
+            Some(TreeViewOnSelect { callback, data }) => (callback.cb)(data, info, item_id, change),
+            None => Update::DoNothing,
+        }
+    }
+
+    fn scroll_to_row(tree_view: &mut TreeView, info: &mut CallbackInfo, first_visible_row: usize) -> Update {
+
+        if tree_view.first_visible_row == first_visible_row {
+            return Update::DoNothing;
+        }
+
+        tree_view.set_first_visible_row(first_visible_row);
+
+        match tree_view.on_scroll.as_mut() {
+            Some(TreeViewOnScroll { callback, data }) => (callback.cb)(data, info, first_visible_row),
+            None => Update::DoNothing,
+        }
+    }
+
+    fn scroll_row_into_view(tree_view: &mut TreeView, info: &mut CallbackInfo, row: usize, page_size: usize, row_count: usize) -> Update {
+
+        let first = tree_view.first_visible_row;
+        let new_first = if row < first {
+            row
+        } else if row >= first + page_size {
+            (row + 1 - page_size).min(row_count.saturating_sub(1))
+        } else {
+            return Update::DoNothing;
+        };
+
+        scroll_to_row(tree_view, info, new_first)
+    }
+}
+
+impl From<TreeView> for Dom {
+    fn from(t: TreeView) -> Dom {
+        t.dom()
+    }
+}
+
+/// Renders a `<tree-view items="Documents, Pictures" />` XML node
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TreeViewRenderer {
+    node: XmlNode,
+}
+
+impl TreeViewRenderer {
+    pub fn new() -> Self {
+        Self {
+            node: XmlNode::new("tree_view"),
+        }
+    }
+}
+
+impl Default for TreeViewRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XmlComponentTrait for TreeViewRenderer {
+    fn get_type_id(&self) -> String {
+        "tree_view".to_string()
+    }
+
+    fn get_available_arguments(&self) -> ComponentArguments {
+        ComponentArguments {
+            args: vec![
+                ("items".to_string(), "[String]".to_string()),
+            ],
+            accepts_text: false,
+        }
+    }
+
+    fn render_dom(
+        &self,
+        _: &XmlComponentMap,
+        args: &FilteredComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<StyledDom, RenderDomError> {
+        let items = parse_xml_arg(args, "items", "[String]", parse_xml_list)?.unwrap_or_default();
+        let items = items.as_ref().iter().enumerate().map(|(id, label)| TreeViewItem::new(id, label.clone())).collect::<Vec<_>>();
+        Ok(TreeView::new(items.into()).dom().style(Css::empty()))
+    }
+
+    fn compile_to_rust_code(
+        &self,
+        _: &XmlComponentMap,
+        _: &ComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<String, CompileError> {
+        Ok(String::from(
+            "TreeView::new(TreeViewItemVec::from_vec(items.into_iter().enumerate().map(|(id, label)| TreeViewItem::new(id, AzString::from(label))).collect()))\r\n\
+            .dom()"
+        ))
+    }
+
+    fn get_xml_node(&self) -> XmlNode {
+        self.node.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    // root 0 with 3 children (1, 2, 3), child 2 has one child (4)
+    fn test_tree() -> TreeView {
+        let mut child_2 = TreeViewItem::new(2, "2".into());
+        child_2.children = vec![TreeViewItem::new(4, "4".into())].into();
+        let mut root = TreeViewItem::new(0, "0".into());
+        root.is_expanded = true;
+        root.children = vec![TreeViewItem::new(1, "1".into()), child_2, TreeViewItem::new(3, "3".into())].into();
+        let mut tree = TreeView::new(vec![root].into());
+        tree.set_selection_mode(TreeViewSelectionMode::Multiple);
+        tree
+    }
+
+    fn selected(tree: &TreeView) -> Vec<usize> {
+        get_visible_rows(tree.items.as_ref()).iter().filter(|r| r.item.is_selected).map(|r| r.item.id).collect()
+    }
+
+    #[test]
+    fn test_tree_view_visible_rows() {
+        let mut tree = test_tree();
+        assert_eq!(tree.get_visible_row_count(), 4);
+        assert!(tree.set_expanded(2, true));
+        let rows = get_visible_rows(tree.items.as_ref());
+        assert_eq!(rows.iter().map(|r| (r.item.id, r.depth)).collect::<Vec<_>>(), vec![(0, 0), (1, 1), (2, 1), (4, 2), (3, 1)]);
+        assert_eq!(rows[3].parent, Some(2));
+        assert!(!tree.set_expanded(99, true));
+    }
+
+    #[test]
+    fn test_tree_view_selection() {
+        let mut tree = test_tree();
+        tree.apply_selection(1, TreeViewSelectionChange::Replace);
+        tree.apply_selection(3, TreeViewSelectionChange::Extend);
+        assert_eq!(selected(&tree), vec![1, 2, 3]);
+        tree.apply_selection(2, TreeViewSelectionChange::Toggle);
+        assert_eq!(selected(&tree), vec![1, 3]);
+        tree.set_selection_mode(TreeViewSelectionMode::Single);
+        tree.apply_selection(0, TreeViewSelectionChange::Extend);
+        assert_eq!(selected(&tree), vec![0]);
+    }
+
+    #[test]
+    fn test_tree_view_lazy_children() {
+        let mut tree = test_tree();
+        let mut lazy = TreeViewItem::new(5, "5".into());
+        lazy.has_lazy_children = true;
+        assert!(lazy.has_children());
+        assert!(tree.set_children(1, vec![lazy].into()));
+        assert!(tree.set_expanded(1, true));
+        assert_eq!(tree.get_visible_row_count(), 5);
+    }
+
+    #[test]
+    fn test_tree_view_cached_rows() {
+        let mut tree = test_tree();
+        let rows = get_visible_row_infos(tree.items.as_ref());
+        assert_eq!(rows.iter().map(|r| r.item_id).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+        assert_eq!((rows[0].is_expanded, rows[0].has_children, rows[0].first_child), (true, true, Some(1)));
+        assert_eq!((rows[2].depth, rows[2].parent, rows[2].first_child), (1, Some(0), Some(4)));
+
+        // extending the selection with the cached rows selects the same range
+        tree.apply_selection_inner(1, TreeViewSelectionChange::Replace, Some(&rows));
+        tree.apply_selection_inner(3, TreeViewSelectionChange::Extend, Some(&rows));
+        assert_eq!(selected(&tree), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_tree_view_scrollbar_proportions() {
+        // all rows rendered: thumb fills the whole track
+        assert_eq!(get_scrollbar_proportions(0, 4, 4), (0.0, 1.0, 0.0));
+        assert_eq!(get_scrollbar_proportions(0, 0, 0), (0.0, 1.0, 0.0));
+        assert_eq!(get_scrollbar_proportions(0, 100, 1000), (0.0, 100.0, 900.0));
+        assert_eq!(get_scrollbar_proportions(500, 100, 1000), (500.0, 100.0, 400.0));
+        // only the last 50 rows are left to render: thumb at the end of the track
+        assert_eq!(get_scrollbar_proportions(950, 50, 1000), (950.0, 50.0, 0.0));
     }
 }
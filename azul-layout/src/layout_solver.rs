@@ -22,7 +22,8 @@ use azul_core::{
         WidthCalculatedRect, HeightCalculatedRect,
         HorizontalSolvedPosition, VerticalSolvedPosition,
         GpuValueCache, RelayoutChanges, PositionInfoInner,
-        StyleBoxShadowOffsets,
+        StyleBoxShadowOffsets, InlineFormattingContext, InlineTextLayout,
+        ResolvedTextLayoutOptions,
     },
     app_resources::{
         ResourceUpdate, IdNamespace, RendererResources,
@@ -38,7 +39,9 @@ use azul_core::{
 };
 use rust_fontconfig::FcFontCache;
 #[cfg(feature = "text_layout")]
-use azul_core::callbacks::{InlineText, DomNodeId, CallbackInfo};
+use azul_core::callbacks::{InlineText, DomNodeId, CallbackInfo, LayoutCallbackInfo, TextMetrics};
+#[cfg(feature = "text_layout")]
+use azul_text_layout::text_layout::InlineRunsLayout;
 
 const DEFAULT_FLEX_GROW_FACTOR: f32 = 0.0;
 
@@ -1557,7 +1560,7 @@ pub fn do_the_layout(
 
         for (parent_dom_id, dom_id, styled_dom, rect) in doms.drain(..) {
 
-            use azul_core::app_resources::add_fonts_and_images;
+            use azul_core::app_resources::{add_fonts_and_images, add_bitmap_glyph_images};
 
             add_fonts_and_images(
                 image_cache,
@@ -1583,6 +1586,17 @@ pub fn do_the_layout(
                 rect,
             );
 
+            // color bitmap glyphs (emojis) are only known after the text is shaped
+            add_bitmap_glyph_images(
+                renderer_resources,
+                id_namespace,
+                epoch,
+                document_id,
+                all_resource_updates,
+                &layout_result.shaped_words_cache,
+                callbacks.insert_into_active_gl_textures_fn,
+            );
+
             let mut iframe_mapping = BTreeMap::new();
 
             for iframe_node_id in layout_result.styled_dom.scan_for_iframe_callbacks() {
@@ -1734,10 +1748,6 @@ pub fn do_the_layout_internal(
     let rect_size = bounds.size;
     let rect_offset = bounds.origin;
 
-    // TODO: Filter all inline text blocks: inline blocks + their padding + margin
-    // The NodeId has to be the **next** NodeId (the next sibling after the inline element)
-    // let mut inline_text_blocks = BTreeMap::<NodeId, InlineText>::new();
-
     let all_parents_btreeset = styled_dom.non_leaf_nodes.iter().filter_map(|p| {
         Some(p.node_id.into_crate_internal()?)
     }).collect::<BTreeSet<_>>();
@@ -1763,9 +1773,25 @@ pub fn do_the_layout_internal(
         &styled_dom.node_hierarchy.as_container(),
         &layout_display_info.as_ref(),
     );
+
+    // text nodes inside of inline formatting contexts are
+    // laid out together with the other runs of their context
+    let inline_formatting_contexts = get_inline_formatting_contexts(
+        &styled_dom,
+        &layout_display_info.as_ref(),
+        &layout_position_info.as_ref(),
+        &display_none_nodes,
+        &word_cache,
+    );
+    let inline_run_nodes = inline_formatting_contexts.values()
+        .flat_map(|ifc| ifc.runs.iter().copied())
+        .collect::<BTreeSet<_>>();
+
     let all_word_nodes_btreeset = (0..styled_dom.node_data.as_container().len())
         .filter(|n| !display_none_nodes[*n]) // if the word block is marked as display:none, ignore
-        .map(|n| NodeId::new(n)).collect::<BTreeSet<_>>();
+        .map(|n| NodeId::new(n))
+        .filter(|n| !inline_run_nodes.contains(n))
+        .collect::<BTreeSet<_>>();
 
     // Layout all words as if there was no max-width constraint
     // (to get the texts "content width").
@@ -1803,6 +1829,21 @@ pub fn do_the_layout_internal(
         content_widths_pre.as_ref_mut()[*node_id] = Some(word_positions.0.content_size.width);
     }
 
+    // the content width of an inline formatting context is the width of its longest (unwrapped) line
+    for (root_id, inline_formatting_context) in inline_formatting_contexts.iter() {
+        if let Some((inline_runs_layout, _)) = position_inline_formatting_context(
+            *root_id,
+            inline_formatting_context,
+            None,
+            renderer_resources,
+            &word_cache,
+            &shaped_words,
+            &styled_dom,
+        ) {
+            content_widths_pre.as_ref_mut()[*root_id] = Some(inline_runs_layout.content_size.width);
+        }
+    }
+
     let mut width_calculated_arena = width_calculated_rect_arena_from_rect_layout_arena(
         &layout_width_heights.as_ref(),
         &layout_offsets.as_ref(),
@@ -1854,7 +1895,7 @@ pub fn do_the_layout_internal(
         &styled_dom,
         Some(&width_calculated_arena.as_ref()),
     );
-    let word_positions_with_max_width = word_positions_no_max_width;
+    let mut word_positions_with_max_width = word_positions_no_max_width;
 
     // Calculate the content height of the (text / image) content based on its width
     let mut content_heights_pre = styled_dom.node_data.as_container_mut()
@@ -1883,6 +1924,26 @@ pub fn do_the_layout_internal(
         content_heights_pre.as_ref_mut()[*node_id] = Some(word_positions.0.content_size.height);
     }
 
+    // wrap the lines of the inline formatting contexts at the solved width
+    for (root_id, inline_formatting_context) in inline_formatting_contexts.iter() {
+        let available_width = get_inline_formatting_context_width(
+            *root_id,
+            &width_calculated_arena.as_ref(),
+            &styled_dom.node_hierarchy.as_container(),
+        );
+        if let Some((inline_runs_layout, _)) = position_inline_formatting_context(
+            *root_id,
+            inline_formatting_context,
+            Some(available_width),
+            renderer_resources,
+            &word_cache,
+            &shaped_words,
+            &styled_dom,
+        ) {
+            content_heights_pre.as_ref_mut()[*root_id] = Some(inline_runs_layout.content_size.height);
+        }
+    }
+
     // TODO: The content height is not the final height!
     let mut height_calculated_arena = height_calculated_rect_arena_from_rect_layout_arena(
         &layout_width_heights.as_ref(),
@@ -1941,6 +2002,21 @@ pub fn do_the_layout_internal(
         &all_parents_btreeset,
     );
 
+    let all_inline_formatting_contexts = inline_formatting_contexts.keys().copied().collect();
+    let inline_text_layouts = position_inline_formatting_contexts(
+        &inline_formatting_contexts,
+        &all_inline_formatting_contexts,
+        renderer_resources,
+        &word_cache,
+        &shaped_words,
+        &styled_dom,
+        &mut width_calculated_arena.as_ref_mut(),
+        &mut height_calculated_arena.as_ref_mut(),
+        &mut x_positions.as_ref_mut(),
+        &mut y_positions.as_ref_mut(),
+        &mut word_positions_with_max_width,
+    );
+
     let mut positioned_rects = NodeDataContainer {
         internal: vec![PositionedRectangle::default(); styled_dom.node_data.len()].into()
     };
@@ -1961,6 +2037,7 @@ pub fn do_the_layout_internal(
         &word_cache,
         &shaped_words,
         &word_positions_with_max_width,
+        &inline_text_layouts,
         document_id
     );
 
@@ -2000,6 +2077,7 @@ pub fn do_the_layout_internal(
         words_cache: word_cache,
         shaped_words_cache: shaped_words,
         positioned_words_cache: word_positions_with_max_width,
+        inline_formatting_contexts,
         scrollable_nodes: overflowing_rects,
         iframe_mapping: BTreeMap::new(),
         gpu_value_cache,
@@ -2079,6 +2157,7 @@ fn position_nodes<'a>(
     word_cache: &BTreeMap<NodeId, Words>,
     shaped_words: &BTreeMap<NodeId, ShapedWords>,
     word_positions: &BTreeMap<NodeId, (WordPositions, FontInstanceKey)>,
+    inline_text_layouts: &BTreeMap<NodeId, InlineTextLayout>,
     document_id: &DocumentId,
 ) {
 
@@ -2223,7 +2302,10 @@ fn position_nodes<'a>(
                 shaped_words.get(&child_node_id),
                 word_positions.get(&child_node_id)
             ) {
-                if nodes_that_need_to_redraw_text.contains(&child_node_id) {
+                if let Some(inline_text_layout) = inline_text_layouts.get(&child_node_id) {
+                    // already aligned by the inline formatting context
+                    Some((word_positions.text_layout_options.clone(), inline_text_layout.clone()))
+                } else if nodes_that_need_to_redraw_text.contains(&child_node_id) {
                     #[cfg(feature = "text_layout")] {
                         use azul_text_layout::InlineText;
 
@@ -2328,6 +2410,19 @@ pub fn callback_info_shape_text(
     Some(azul_text_layout::text_layout::shape_text(&font_ref, text.as_str(), &text_layout_options))
 }
 
+// measures a text before the layout, so that the layout() callback can
+// size nodes (i.e. table columns) to their text content
+#[cfg(feature = "text_layout")]
+pub fn layout_callback_info_measure_text(
+    layoutcallbackinfo: &LayoutCallbackInfo,
+    text: AzString,
+    font_families: StyleFontFamilyVec,
+    text_layout_options: ResolvedTextLayoutOptions,
+) -> Option<TextMetrics> {
+    let font_ref = layoutcallbackinfo.get_font_ref(font_families.as_ref())?;
+    Some(azul_text_layout::text_layout::measure_text(&font_ref, text.as_str(), &text_layout_options))
+}
+
 #[cfg(feature = "text_layout")]
 pub fn create_shaped_words<'a>(
     renderer_resources: &RendererResources,
@@ -2379,11 +2474,6 @@ fn create_word_positions<'a>(
 
     use rayon::prelude::*;
     use azul_text_layout::text_layout::position_words;
-    use azul_core::app_resources::font_size_to_au;
-    use azul_core::ui_solver::{
-        ResolvedTextLayoutOptions,
-        DEFAULT_LETTER_SPACING, DEFAULT_WORD_SPACING
-    };
 
     let css_property_cache = styled_dom.get_css_property_cache();
     let node_data_container = styled_dom.node_data.as_container();
@@ -2393,25 +2483,7 @@ fn create_word_positions<'a>(
     .par_iter()
     .filter_map(|(node_id, words)| {
 
-        use azul_core::styled_dom::StyleFontFamiliesHash;
-
         if !word_positions_to_generate.contains(node_id) { return None; }
-        let node_data = &node_data_container[*node_id];
-
-        let styled_node_state = &styled_dom.styled_nodes.as_container()[*node_id].state;
-        let font_size = css_property_cache
-            .get_font_size_or_default(node_data, node_id, &styled_node_state);
-        let font_size_au = font_size_to_au(font_size);
-        let font_size_px = font_size.inner.to_pixels(DEFAULT_FONT_SIZE_PX as f32);
-
-
-        let css_font_families = css_property_cache.get_font_id_or_default(node_data, node_id, styled_node_state);
-        let css_font_families_hash = StyleFontFamiliesHash::new(css_font_families.as_ref());
-        let css_font_family = renderer_resources.get_font_family(&css_font_families_hash)?;
-        let font_key = renderer_resources.get_font_key(&css_font_family)?;
-        let (_, font_instances) = renderer_resources.get_registered_font(&font_key)?;
-
-        let font_instance_key = font_instances.iter().find(|(k, v)| k.0 == font_size_au).map(|(_, v)| v)?;
 
         let shaped_words = shaped_words.get(&node_id)?;
 
@@ -2446,36 +2518,16 @@ fn create_word_positions<'a>(
             cur_node = parent;
         }
 
-        let letter_spacing = css_property_cache
-        .get_letter_spacing(node_data, node_id, &styled_node_state)
-        .and_then(|ls| Some(ls.get_property()?.inner.to_pixels(DEFAULT_LETTER_SPACING)));
-
-        let word_spacing = css_property_cache
-        .get_word_spacing(node_data, node_id, &styled_node_state)
-        .and_then(|ws| Some(ws.get_property()?.inner.to_pixels(DEFAULT_WORD_SPACING)));
-
-        let line_height = css_property_cache
-        .get_line_height(node_data, node_id, &styled_node_state)
-        .and_then(|lh| Some(lh.get_property()?.inner.get()));
-
-        let tab_width = css_property_cache
-        .get_tab_width(node_data, node_id, &styled_node_state)
-        .and_then(|tw| Some(tw.get_property()?.inner.get()));
-
-        let text_layout_options = ResolvedTextLayoutOptions {
-            max_horizontal_width: max_text_width.into(),
-            leading: None.into(), // TODO
-            holes: Vec::new().into(), // TODO
-            font_size_px,
-            word_spacing: word_spacing.into(),
-            letter_spacing: letter_spacing.into(),
-            line_height: line_height.into(),
-            tab_width: tab_width.into(),
-        };
+        let (text_layout_options, font_instance_key) = resolve_text_layout_options(
+            *node_id,
+            max_text_width,
+            renderer_resources,
+            styled_dom,
+        )?;
 
         let w = position_words(words, shaped_words, &text_layout_options);
 
-        Some((*node_id, (w, *font_instance_key)))
+        Some((*node_id, (w, font_instance_key)))
     }).collect::<Vec<_>>();
 
     collected
@@ -2485,6 +2537,394 @@ fn create_word_positions<'a>(
     });
 }
 
+/// Resolves the font size, spacing and line height of a text node
+/// and looks up the font instance used to render it
+#[cfg(feature = "text_layout")]
+fn resolve_text_layout_options(
+    node_id: NodeId,
+    max_text_width: Option<f32>,
+    renderer_resources: &RendererResources,
+    styled_dom: &StyledDom,
+) -> Option<(ResolvedTextLayoutOptions, FontInstanceKey)> {
+
+    use azul_core::app_resources::font_size_to_au;
+    use azul_core::styled_dom::StyleFontFamiliesHash;
+    use azul_core::ui_solver::{DEFAULT_LETTER_SPACING, DEFAULT_WORD_SPACING};
+
+    let css_property_cache = styled_dom.get_css_property_cache();
+    let node_data = &styled_dom.node_data.as_container()[node_id];
+    let styled_node_state = &styled_dom.styled_nodes.as_container()[node_id].state;
+
+    let font_size = css_property_cache
+        .get_font_size_or_default(node_data, &node_id, &styled_node_state);
+    let font_size_au = font_size_to_au(font_size);
+    let font_size_px = font_size.inner.to_pixels(DEFAULT_FONT_SIZE_PX as f32);
+
+    let css_font_families = css_property_cache.get_font_id_or_default(node_data, &node_id, styled_node_state);
+    let css_font_families_hash = StyleFontFamiliesHash::new(css_font_families.as_ref());
+    let css_font_family = renderer_resources.get_font_family(&css_font_families_hash)?;
+    let font_key = renderer_resources.get_font_key(&css_font_family)?;
+    let (_, font_instances) = renderer_resources.get_registered_font(&font_key)?;
+
+    let font_instance_key = font_instances.iter().find(|(k, _)| k.0 == font_size_au).map(|(_, v)| *v)?;
+
+    let letter_spacing = css_property_cache
+    .get_letter_spacing(node_data, &node_id, &styled_node_state)
+    .and_then(|ls| Some(ls.get_property()?.inner.to_pixels(DEFAULT_LETTER_SPACING)));
+
+    let word_spacing = css_property_cache
+    .get_word_spacing(node_data, &node_id, &styled_node_state)
+    .and_then(|ws| Some(ws.get_property()?.inner.to_pixels(DEFAULT_WORD_SPACING)));
+
+    let line_height = css_property_cache
+    .get_line_height(node_data, &node_id, &styled_node_state)
+    .and_then(|lh| Some(lh.get_property()?.inner.get()));
+
+    let tab_width = css_property_cache
+    .get_tab_width(node_data, &node_id, &styled_node_state)
+    .and_then(|tw| Some(tw.get_property()?.inner.get()));
+
+    let text_layout_options = ResolvedTextLayoutOptions {
+        max_horizontal_width: max_text_width.into(),
+        leading: None.into(), // TODO
+        holes: Vec::new().into(), // TODO
+        font_size_px,
+        word_spacing: word_spacing.into(),
+        letter_spacing: letter_spacing.into(),
+        line_height: line_height.into(),
+        tab_width: tab_width.into(),
+    };
+
+    Some((text_layout_options, font_instance_key))
+}
+
+/// Returns all blocks that contain at least one `display: inline` child and whose
+/// other in-flow children are text nodes or `display: inline` elements, too.
+///
+/// The text of these blocks is laid out in shared line boxes (an "inline formatting
+/// context") instead of laying out every text node as a separate flex item.
+///
+/// NOTE: non-inline elements inside of inline elements are not part of the line boxes
+#[cfg(feature = "text_layout")]
+fn get_inline_formatting_contexts<'a>(
+    styled_dom: &StyledDom,
+    layout_displays: &NodeDataContainerRef<'a, CssPropertyValue<LayoutDisplay>>,
+    layout_positions: &NodeDataContainerRef<'a, LayoutPosition>,
+    display_none_nodes: &[bool],
+    words: &BTreeMap<NodeId, Words>,
+) -> BTreeMap<NodeId, InlineFormattingContext> {
+
+    let node_hierarchy = styled_dom.node_hierarchy.as_container();
+    let node_data = styled_dom.node_data.as_container();
+
+    let is_inline = |node_id: NodeId| {
+        layout_displays[node_id] == CssPropertyValue::Exact(LayoutDisplay::Inline)
+    };
+    let is_in_flow = |node_id: NodeId| {
+        !display_none_nodes[node_id.index()] &&
+        layout_positions[node_id] != LayoutPosition::Absolute &&
+        layout_positions[node_id] != LayoutPosition::Fixed
+    };
+    let is_text = |node_id: NodeId| {
+        match node_data[node_id].get_node_type() {
+            NodeType::Text(_) => true,
+            _ => false,
+        }
+    };
+
+    let mut inline_formatting_contexts = BTreeMap::new();
+
+    for ParentWithNodeDepth { depth: _, node_id } in styled_dom.non_leaf_nodes.iter() {
+
+        let root_id = match node_id.into_crate_internal() { Some(s) => s, None => continue, };
+        if display_none_nodes[root_id.index()] || is_inline(root_id) { continue; }
+
+        let children = root_id.az_children(&node_hierarchy)
+            .filter(|child_id| is_in_flow(*child_id))
+            .collect::<Vec<_>>();
+
+        if !children.iter().any(|child_id| is_inline(*child_id)) ||
+           !children.iter().all(|child_id| is_inline(*child_id) || is_text(*child_id)) {
+            continue;
+        }
+
+        // collect the text runs in DOM order
+        let mut inline_formatting_context = InlineFormattingContext::default();
+        let mut stack = children.into_iter().rev().collect::<Vec<_>>();
+
+        while let Some(current) = stack.pop() {
+            if !is_in_flow(current) { continue; }
+            if words.contains_key(&current) {
+                inline_formatting_context.runs.push(current);
+                inline_formatting_context.inline_nodes.push(current);
+            } else if is_inline(current) {
+                inline_formatting_context.inline_nodes.push(current);
+                let children = current.az_children(&node_hierarchy).collect::<Vec<_>>();
+                stack.extend(children.into_iter().rev());
+            }
+        }
+
+        inline_formatting_contexts.insert(root_id, inline_formatting_context);
+    }
+
+    inline_formatting_contexts
+}
+
+/// Lays out the text runs of an inline formatting context, returns the layout
+/// and the node IDs + font instances of the runs that could be laid out
+#[cfg(feature = "text_layout")]
+fn position_inline_formatting_context(
+    root_id: NodeId,
+    inline_formatting_context: &InlineFormattingContext,
+    max_horizontal_width: Option<f32>,
+    renderer_resources: &RendererResources,
+    words: &BTreeMap<NodeId, Words>,
+    shaped_words: &BTreeMap<NodeId, ShapedWords>,
+    styled_dom: &StyledDom,
+) -> Option<(InlineRunsLayout, Vec<(NodeId, FontInstanceKey)>)> {
+
+    use azul_text_layout::text_layout::{InlineRun, position_inline_runs};
+
+    let css_property_cache = styled_dom.get_css_property_cache();
+    let node_hierarchy = styled_dom.node_hierarchy.as_container();
+    let node_data = styled_dom.node_data.as_container();
+    let styled_nodes = styled_dom.styled_nodes.as_container();
+
+    // vertical-align is set on the inline element, but applies to all text inside of it
+    let get_vertical_align = |run_id: NodeId| {
+        let mut current = Some(run_id);
+        while let Some(node_id) = current {
+            if node_id == root_id { break; }
+            let vertical_align = css_property_cache
+                .get_vertical_align(&node_data[node_id], &node_id, &styled_nodes[node_id].state)
+                .and_then(|p| p.get_property().copied());
+            if let Some(s) = vertical_align {
+                return s;
+            }
+            current = node_hierarchy[node_id].parent_id();
+        }
+        LayoutVerticalAlign::default()
+    };
+
+    let mut runs = Vec::with_capacity(inline_formatting_context.runs.len());
+    let mut run_ids = Vec::with_capacity(inline_formatting_context.runs.len());
+
+    for run_id in inline_formatting_context.runs.iter() {
+        let run_words = match words.get(run_id) { Some(s) => s, None => continue, };
+        let run_shaped_words = match shaped_words.get(run_id) { Some(s) => s, None => continue, };
+        let (text_layout_options, font_instance_key) = match resolve_text_layout_options(
+            *run_id,
+            max_horizontal_width,
+            renderer_resources,
+            styled_dom,
+        ) {
+            Some(s) => s,
+            None => continue,
+        };
+
+        runs.push(InlineRun {
+            words: run_words,
+            shaped_words: run_shaped_words,
+            text_layout_options,
+            vertical_align: get_vertical_align(*run_id),
+            spacing_before: 0.0,
+            spacing_after: 0.0,
+        });
+        run_ids.push((*run_id, font_instance_key));
+    }
+
+    if runs.is_empty() {
+        return None;
+    }
+
+    // the horizontal margin, border and padding of an inline element is
+    // inserted in front of its first run and behind its last run
+    let mut first_runs = BTreeMap::new();
+    let mut last_runs = BTreeMap::new();
+    for (run_idx, (run_id, _)) in run_ids.iter().enumerate() {
+        let mut current = node_hierarchy[*run_id].parent_id();
+        while let Some(node_id) = current {
+            if node_id == root_id { break; }
+            first_runs.entry(node_id).or_insert(run_idx);
+            last_runs.insert(node_id, run_idx);
+            current = node_hierarchy[node_id].parent_id();
+        }
+    }
+
+    let percent_resolve = max_horizontal_width.unwrap_or(0.0);
+    let to_pixels = |p: Option<PixelValue>| p.map(|p| p.to_pixels(percent_resolve)).unwrap_or(0.0);
+
+    for (node_id, run_idx) in first_runs {
+        let node_data = &node_data[node_id];
+        let node_state = &styled_nodes[node_id].state;
+        runs[run_idx].spacing_before +=
+            to_pixels(css_property_cache.get_margin_left(node_data, &node_id, node_state).and_then(|p| Some(p.get_property()?.inner))) +
+            to_pixels(css_property_cache.get_border_left_width(node_data, &node_id, node_state).and_then(|p| Some(p.get_property()?.inner))) +
+            to_pixels(css_property_cache.get_padding_left(node_data, &node_id, node_state).and_then(|p| Some(p.get_property()?.inner)));
+    }
+
+    for (node_id, run_idx) in last_runs {
+        let node_data = &node_data[node_id];
+        let node_state = &styled_nodes[node_id].state;
+        runs[run_idx].spacing_after +=
+            to_pixels(css_property_cache.get_margin_right(node_data, &node_id, node_state).and_then(|p| Some(p.get_property()?.inner))) +
+            to_pixels(css_property_cache.get_border_right_width(node_data, &node_id, node_state).and_then(|p| Some(p.get_property()?.inner))) +
+            to_pixels(css_property_cache.get_padding_right(node_data, &node_id, node_state).and_then(|p| Some(p.get_property()?.inner)));
+    }
+
+    Some((position_inline_runs(&runs, max_horizontal_width), run_ids))
+}
+
+/// Returns the width available for the lines of an inline formatting context:
+/// the content width of the root node, but not larger than the content width of its parent
+#[cfg(feature = "text_layout")]
+fn get_inline_formatting_context_width<'a>(
+    root_id: NodeId,
+    solved_widths: &NodeDataContainerRef<'a, WidthCalculatedRect>,
+    node_hierarchy: &NodeDataContainerRef<'a, NodeHierarchyItem>,
+) -> f32 {
+
+    let get_content_width = |node_id: NodeId| {
+        let parent_width = node_hierarchy[node_id].parent_id()
+            .map(|p| solved_widths[p].total())
+            .unwrap_or(solved_widths[node_id].total());
+        let width = solved_widths[node_id];
+        (width.total() - width.get_horizontal_padding(parent_width)).max(0.0)
+    };
+
+    let root_width = get_content_width(root_id);
+
+    match node_hierarchy[root_id].parent_id() {
+        Some(parent_id) => root_width.min(get_content_width(parent_id)),
+        None => root_width,
+    }
+}
+
+/// Lays out the inline formatting contexts inside of the (already solved) boxes
+/// of their root nodes, then overwrites the sizes and positions of the inline nodes
+/// with the bounds of their text (plus their padding and border). Returns the final
+/// line layout of each text run.
+///
+/// NOTE: an inline element that spans multiple lines gets one box around all of its lines
+#[cfg(feature = "text_layout")]
+fn position_inline_formatting_contexts<'a>(
+    inline_formatting_contexts: &BTreeMap<NodeId, InlineFormattingContext>,
+    contexts_to_update: &BTreeSet<NodeId>,
+    renderer_resources: &RendererResources,
+    words: &BTreeMap<NodeId, Words>,
+    shaped_words: &BTreeMap<NodeId, ShapedWords>,
+    styled_dom: &StyledDom,
+    solved_widths: &mut NodeDataContainerRefMut<'a, WidthCalculatedRect>,
+    solved_heights: &mut NodeDataContainerRefMut<'a, HeightCalculatedRect>,
+    x_positions: &mut NodeDataContainerRefMut<'a, HorizontalSolvedPosition>,
+    y_positions: &mut NodeDataContainerRefMut<'a, VerticalSolvedPosition>,
+    word_positions: &mut BTreeMap<NodeId, (WordPositions, FontInstanceKey)>,
+) -> BTreeMap<NodeId, InlineTextLayout> {
+
+    let css_property_cache = styled_dom.get_css_property_cache();
+    let node_hierarchy = styled_dom.node_hierarchy.as_container();
+    let node_data = styled_dom.node_data.as_container();
+    let styled_nodes = styled_dom.styled_nodes.as_container();
+
+    let mut inline_text_layouts = BTreeMap::new();
+
+    for (root_id, inline_formatting_context) in inline_formatting_contexts.iter() {
+
+        if !contexts_to_update.contains(root_id) { continue; }
+
+        let available_width = get_inline_formatting_context_width(
+            *root_id,
+            &NodeDataContainerRef { internal: &*solved_widths.internal },
+            &node_hierarchy,
+        );
+
+        let (inline_runs_layout, run_ids) = match position_inline_formatting_context(
+            *root_id,
+            inline_formatting_context,
+            Some(available_width),
+            renderer_resources,
+            words,
+            shaped_words,
+            styled_dom,
+        ) {
+            Some(s) => s,
+            None => continue,
+        };
+
+        let text_align = css_property_cache
+            .get_text_align(&node_data[*root_id], root_id, &styled_nodes[*root_id].state)
+            .and_then(|p| p.get_property().copied())
+            .unwrap_or_default();
+
+        let line_shifts = inline_runs_layout.get_line_shifts(available_width, text_align);
+
+        // top left corner of the content box of the root node
+        let parent_id = node_hierarchy[*root_id].parent_id().unwrap_or(*root_id);
+        let content_origin = LogicalPosition::new(
+            x_positions[*root_id].0 + solved_widths[*root_id].get_padding_left(solved_widths[parent_id].total()),
+            y_positions[*root_id].0 + solved_heights[*root_id].get_padding_top(solved_heights[parent_id].total()),
+        );
+
+        // border boxes of the inline nodes, relative to the content box of the root node
+        let mut inline_node_bounds = BTreeMap::new();
+
+        for (run_idx, (run_id, font_instance_key)) in run_ids.iter().enumerate() {
+
+            let (run_bounds, inline_text_layout) = match inline_runs_layout.get_run_layout(run_idx, &line_shifts) {
+                Some(s) => s,
+                None => continue,
+            };
+
+            let mut run_word_positions = inline_runs_layout.word_positions[run_idx].clone();
+            run_word_positions.content_size = run_bounds.size;
+            word_positions.insert(*run_id, (run_word_positions, *font_instance_key));
+            inline_text_layouts.insert(*run_id, inline_text_layout);
+            inline_node_bounds.insert(*run_id, run_bounds);
+        }
+
+        // the content box of an inline element is the union of the boxes of its children,
+        // the (horizontal) space for its padding and border is already reserved in the lines
+        let root_width = solved_widths[*root_id].total();
+        let root_height = solved_heights[*root_id].total();
+        for node_id in inline_formatting_context.inline_nodes.iter().rev() {
+
+            if inline_node_bounds.contains_key(node_id) { continue; }
+
+            let content_box = match LogicalRect::union(
+                node_id.az_children(&node_hierarchy).filter_map(|child_id| inline_node_bounds.get(&child_id).copied())
+            ) {
+                Some(s) => s,
+                None => continue,
+            };
+
+            let width = solved_widths[*node_id];
+            let height = solved_heights[*node_id];
+
+            inline_node_bounds.insert(*node_id, LogicalRect::new(
+                LogicalPosition::new(
+                    content_box.origin.x - width.get_padding_left(root_width),
+                    content_box.origin.y - height.get_padding_top(root_height),
+                ),
+                LogicalSize::new(
+                    content_box.size.width + width.get_horizontal_padding(root_width),
+                    content_box.size.height + height.get_vertical_padding(root_height),
+                ),
+            ));
+        }
+
+        for (node_id, bounds) in inline_node_bounds {
+            solved_widths[node_id].min_inner_size_px = bounds.size.width;
+            solved_widths[node_id].flex_grow_px = 0.0;
+            solved_heights[node_id].min_inner_size_px = bounds.size.height;
+            solved_heights[node_id].flex_grow_px = 0.0;
+            x_positions[node_id] = HorizontalSolvedPosition(content_origin.x + bounds.origin.x);
+            y_positions[node_id] = VerticalSolvedPosition(content_origin.y + bounds.origin.y);
+        }
+    }
+
+    inline_text_layouts
+}
+
 /// For a given rectangle, determines what text alignment should be used
 fn determine_text_alignment(
     align_items: LayoutAlignItems,
@@ -2814,6 +3254,10 @@ pub fn do_the_relayout(
 
     let mut node_ids_that_changed_text_content = BTreeSet::new();
 
+    let inline_run_nodes = layout_result.inline_formatting_contexts.values()
+        .flat_map(|ifc| ifc.runs.iter().copied())
+        .collect::<BTreeSet<_>>();
+
     // Update words cache and shaped words cache
     if let Some(words_to_relayout) = words_to_relayout {
         for (node_id, new_string) in words_to_relayout.iter() {
@@ -2892,7 +3336,10 @@ pub fn do_the_relayout(
 
             let new_word_dimensions = new_word_positions.content_size;
 
-            layout_result.preferred_widths.as_ref_mut()[*node_id] = Some(new_word_positions.content_size.width);
+            // the width of text runs in inline formatting contexts is determined by their root
+            if !inline_run_nodes.contains(node_id) {
+                layout_result.preferred_widths.as_ref_mut()[*node_id] = Some(new_word_positions.content_size.width);
+            }
             *layout_result.words_cache.get_mut(node_id).unwrap() = new_words;
             *layout_result.shaped_words_cache.get_mut(node_id).unwrap() = new_shaped_words;
             layout_result.positioned_words_cache.get_mut(node_id).unwrap().0 = new_word_positions;
@@ -3367,6 +3814,7 @@ pub fn do_the_relayout(
     }
 
     updated_word_caches.extend(node_ids_that_changed_text_content.clone().into_iter());
+    updated_word_caches.retain(|node_id| !inline_run_nodes.contains(node_id));
 
     #[cfg(feature = "text_layout")]
     create_word_positions(
@@ -3379,6 +3827,35 @@ pub fn do_the_relayout(
         Some(&layout_result.width_calculated_rects.as_ref()),
     );
 
+    // re-flow the inline formatting contexts that were resized, moved or had their text changed
+    let inline_formatting_contexts_to_update = layout_result.inline_formatting_contexts.iter()
+    .filter(|(root_id, inline_formatting_context)| {
+        parents_that_need_to_recalc_width_of_children.contains(root_id) ||
+        parents_that_need_to_recalc_height_of_children.contains(root_id) ||
+        parents_that_need_to_reposition_children_x.contains(root_id) ||
+        parents_that_need_to_reposition_children_y.contains(root_id) ||
+        inline_formatting_context.runs.iter().any(|run_id| node_ids_that_changed_text_content.contains(run_id))
+    })
+    .map(|(root_id, _)| *root_id)
+    .collect::<BTreeSet<_>>();
+
+    #[cfg(feature = "text_layout")]
+    let inline_text_layouts = position_inline_formatting_contexts(
+        &layout_result.inline_formatting_contexts,
+        &inline_formatting_contexts_to_update,
+        renderer_resources,
+        &layout_result.words_cache,
+        &layout_result.shaped_words_cache,
+        &layout_result.styled_dom,
+        &mut layout_result.width_calculated_rects.as_ref_mut(),
+        &mut layout_result.height_calculated_rects.as_ref_mut(),
+        &mut layout_result.solved_pos_x.as_ref_mut(),
+        &mut layout_result.solved_pos_y.as_ref_mut(),
+        &mut layout_result.positioned_words_cache,
+    );
+    #[cfg(not(feature = "text_layout"))]
+    let inline_text_layouts = BTreeMap::new();
+
     // determine which nodes changed their size and return
     let mut nodes_that_changed_size = BTreeSet::new();
     for parent_id in parents_that_need_to_recalc_width_of_children {
@@ -3400,6 +3877,12 @@ pub fn do_the_relayout(
         nodes_that_changed_size.insert(parent);
     }
     nodes_that_changed_size.extend(node_ids_that_changed_text_content.into_iter());
+    for root_id in inline_formatting_contexts_to_update.iter() {
+        nodes_that_changed_size.insert(*root_id);
+        if let Some(inline_formatting_context) = layout_result.inline_formatting_contexts.get(root_id) {
+            nodes_that_changed_size.extend(inline_formatting_context.inline_nodes.iter().copied());
+        }
+    }
 
     let css_property_cache = layout_result.styled_dom.get_css_property_cache();
     let node_data_container = layout_result.styled_dom.node_data.as_container();
@@ -3436,6 +3919,7 @@ pub fn do_the_relayout(
         &layout_result.words_cache,
         &layout_result.shaped_words_cache,
         &layout_result.positioned_words_cache,
+        &inline_text_layouts,
         document_id,
     );
 
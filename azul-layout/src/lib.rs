@@ -25,6 +25,6 @@ pub use layout_solver::{
 };
 
 #[cfg(feature = "text_layout")]
-pub use layout_solver::callback_info_shape_text;
+pub use layout_solver::{callback_info_shape_text, layout_callback_info_measure_text};
 #[cfg(feature = "text_layout")]
 pub use azul_text_layout::parse_font_fn;
@@ -325,8 +325,8 @@ mod tests {
 
     #[test]
     fn test_script_name() {
-        assert_eq!(Script::Cyrillic.name(), "Cyrillic");
-        assert_eq!(Script::Katakana.name(), "Katakana");
+        assert_eq!(format!("{:?}", Script::Cyrillic), "Cyrillic");
+        assert_eq!(format!("{:?}", Script::Katakana), "Katakana");
     }
 
     #[test]
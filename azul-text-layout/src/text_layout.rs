@@ -2,23 +2,28 @@
 //! the positions of words / lines and do glyph positioning
 
 pub use crate::text_shaping::ParsedFont;
+use crate::text_shaping::{get_glyph_outline, GlyphOutline, GlyphOutlineOperation};
 pub use azul_core::{
     app_resources::{
         Words, Word, WordType,
-        ShapedWords, ShapedWord, WordIndex, GlyphIndex, LineLength, IndexOfLineBreak,
+        ShapedWords, ShapedWord, ColorGlyph, ColorGlyphVec, BitmapGlyphVec, WordIndex, GlyphIndex, LineLength, IndexOfLineBreak,
         RemainingSpaceToRight, LineBreaks, WordPositions, LayoutedGlyphs, FontMetrics,
     },
-    callbacks::InlineText,
+    callbacks::{InlineText, TextMetrics},
     display_list::GlyphInstance,
+    svg::{SvgMultiPolygon, SvgPath, SvgPathElement, SvgLine, SvgPoint, SvgQuadraticCurve, SvgCubicCurve},
     ui_solver::{
         ResolvedTextLayoutOptions, TextLayoutOptions, InlineTextLayout,
+        calculate_horizontal_shift_multiplier,
         DEFAULT_LINE_HEIGHT, DEFAULT_WORD_SPACING, DEFAULT_LETTER_SPACING, DEFAULT_TAB_WIDTH,
     },
     window::{LogicalRect, LogicalSize, LogicalPosition},
 };
 pub use azul_css::FontRef;
+use azul_css::{LayoutVerticalAlign, StyleTextAlign};
 use alloc::vec::Vec;
 use alloc::string::String;
+use alloc::borrow::Cow;
 
 /// Creates a font from a font file (TTF, OTF, WOFF, etc.)
 ///
@@ -164,17 +169,76 @@ pub fn shape_words(words: &Words, font: &ParsedFont) -> ShapedWords {
             glyph_infos: infos.into(),
             word_width,
         }
-    }).collect();
+    }).collect::<Vec<_>>();
+
+    let color_glyphs = get_used_color_glyphs(&shaped_words, font);
+    let bitmap_glyphs = get_used_bitmap_glyphs(&shaped_words, font);
 
     ShapedWords {
-        items: shaped_words,
+        items: shaped_words.into(),
         longest_word_width: longest_word_width,
         space_advance,
         font_metrics_units_per_em: font.font_metrics.units_per_em,
         font_metrics_ascender: font.font_metrics.get_ascender_unscaled(),
         font_metrics_descender: font.font_metrics.get_descender_unscaled(),
         font_metrics_line_gap: font.font_metrics.get_line_gap_unscaled(),
+        color_glyphs,
+        bitmap_glyphs,
+    }
+}
+
+/// Collects the layers of all color glyphs (`COLR` table) that are used in the shaped words
+fn get_used_color_glyphs(shaped_words: &[ShapedWord], font: &ParsedFont) -> ColorGlyphVec {
+
+    use alloc::collections::btree_map::BTreeMap;
+
+    if font.color_glyph_layers.is_empty() {
+        return ColorGlyphVec::from_const_slice(&[]);
+    }
+
+    let mut used_color_glyphs = BTreeMap::new();
+
+    for word in shaped_words.iter() {
+        for glyph_info in word.glyph_infos.iter() {
+            let glyph_index = glyph_info.glyph.glyph_index;
+            if let Some(layers) = font.get_color_glyph_layers(glyph_index) {
+                used_color_glyphs.entry(glyph_index).or_insert_with(|| ColorGlyph {
+                    glyph_index,
+                    layers: layers.to_vec().into(),
+                });
+            }
+        }
+    }
+
+    // BTreeMap::into_iter is sorted by glyph index
+    used_color_glyphs.into_iter().map(|(_, v)| v).collect::<Vec<_>>().into()
+}
+
+/// Collects the decoded bitmaps of all color bitmap glyphs (`CBDT` / `sbix` tables) that are used in the shaped words
+fn get_used_bitmap_glyphs(shaped_words: &[ShapedWord], font: &ParsedFont) -> BitmapGlyphVec {
+
+    use alloc::collections::btree_map::BTreeMap;
+
+    if font.bitmap_glyph_tables.is_none() {
+        return BitmapGlyphVec::from_const_slice(&[]);
+    }
+
+    let mut used_bitmap_glyphs = BTreeMap::new();
+
+    for word in shaped_words.iter() {
+        for glyph_info in word.glyph_infos.iter() {
+            let glyph_index = glyph_info.glyph.glyph_index;
+            if used_bitmap_glyphs.contains_key(&glyph_index) {
+                continue;
+            }
+            if let Some(bitmap_glyph) = font.get_bitmap_glyph(glyph_index) {
+                used_bitmap_glyphs.insert(glyph_index, bitmap_glyph);
+            }
+        }
     }
+
+    // BTreeMap::into_iter is sorted by glyph index
+    used_bitmap_glyphs.into_iter().map(|(_, v)| v).collect::<Vec<_>>().into()
 }
 
 /// Positions the words on the screen (does not layout any glyph positions!), necessary for estimating
@@ -381,6 +445,271 @@ pub fn word_positions_to_inline_text_layout(word_positions: &WordPositions) -> I
     }
 }
 
+/// Offset of the baseline of `vertical-align: sub` text, relative to the font size
+const SUBSCRIPT_SHIFT: f32 = 0.2;
+/// Offset of the baseline of `vertical-align: super` text, relative to the font size
+const SUPERSCRIPT_SHIFT: f32 = 0.33;
+
+/// One styled text run of an inline formatting context, i.e. one text node
+/// with its own font, font size and `vertical-align`
+#[derive(Debug, Clone)]
+pub struct InlineRun<'a> {
+    pub words: &'a Words,
+    pub shaped_words: &'a ShapedWords,
+    /// `max_horizontal_width` and `leading` are ignored, since they are
+    /// determined by the inline formatting context
+    pub text_layout_options: ResolvedTextLayoutOptions,
+    pub vertical_align: LayoutVerticalAlign,
+    /// Horizontal margin + border + padding of the inline elements
+    /// that start right before this run (space in front of the first word)
+    pub spacing_before: f32,
+    /// Horizontal margin + border + padding of the inline elements
+    /// that end right after this run (space behind the last word)
+    pub spacing_after: f32,
+}
+
+/// Multiple text runs, laid out into shared line boxes
+#[derive(Debug, Clone, PartialEq)]
+pub struct InlineRunsLayout {
+    /// Positioned words of each run (in the same order as the input runs),
+    /// relative to the top left corner of the inline formatting context
+    pub word_positions: Vec<WordPositions>,
+    /// Index of the shared line that the first line of each run is placed on
+    pub first_lines: Vec<usize>,
+    /// Left-aligned bounds of the shared line boxes
+    pub lines: Vec<LogicalRect>,
+    pub content_size: LogicalSize,
+}
+
+impl InlineRunsLayout {
+
+    /// Returns the horizontal offset of each shared line for the given text alignment
+    pub fn get_line_shifts(&self, available_width: f32, text_align: StyleTextAlign) -> Vec<f32> {
+        let shift_multiplier = calculate_horizontal_shift_multiplier(text_align).unwrap_or(0.0);
+        self.lines.iter()
+        .map(|line| ((available_width - line.size.width) * shift_multiplier).max(0.0))
+        .collect()
+    }
+
+    /// Returns the bounding box of one run (relative to the inline formatting context)
+    /// and the lines of the run, relative to the top left corner of the bounding box.
+    ///
+    /// `line_shifts` contains the horizontal offset of each shared line (see `get_line_shifts`).
+    pub fn get_run_layout(&self, run_index: usize, line_shifts: &[f32]) -> Option<(LogicalRect, InlineTextLayout)> {
+
+        use azul_core::ui_solver::InlineTextLine;
+
+        let word_positions = self.word_positions.get(run_index)?;
+        let first_line = *self.first_lines.get(run_index)?;
+        let leading = word_positions.text_layout_options.leading.as_ref().copied().unwrap_or(0.0);
+
+        let mut min_x = f32::MAX;
+        let mut min_y = f32::MAX;
+        let mut max_x = 0.0_f32;
+        let mut max_y = 0.0_f32;
+
+        for (line_idx, line) in word_positions.line_breaks.iter().enumerate() {
+            let shift = line_shifts.get(first_line + line_idx).copied().unwrap_or(0.0);
+            let start_x = if line_idx == 0 { leading } else { 0.0 };
+            min_x = min_x.min(shift + start_x);
+            max_x = max_x.max(shift + line.bounds.size.width);
+            // line bounds: origin.y is the bottom of the line
+            min_y = min_y.min(line.bounds.origin.y - line.bounds.size.height);
+            max_y = max_y.max(line.bounds.origin.y);
+        }
+
+        if word_positions.line_breaks.is_empty() {
+            return None;
+        }
+
+        let bounds = LogicalRect::new(
+            LogicalPosition::new(min_x, min_y),
+            LogicalSize::new((max_x - min_x).max(0.0), max_y - min_y),
+        );
+
+        let lines = word_positions.line_breaks.iter().enumerate().map(|(line_idx, line)| {
+            let shift = line_shifts.get(first_line + line_idx).copied().unwrap_or(0.0);
+            InlineTextLine {
+                bounds: LogicalRect::new(
+                    LogicalPosition::new(shift - bounds.origin.x, line.bounds.origin.y - bounds.origin.y),
+                    line.bounds.size,
+                ),
+                word_start: line.word_start,
+                word_end: line.word_end,
+            }
+        }).collect::<Vec<_>>();
+
+        Some((bounds, InlineTextLayout {
+            lines: lines.into(),
+            content_size: bounds.size,
+        }))
+    }
+}
+
+/// Positions multiple text runs (with different fonts / font sizes) in shared line boxes:
+/// every run continues on the line where the previous run stopped and the runs on
+/// each line are aligned vertically according to their `vertical-align`.
+pub fn position_inline_runs(runs: &[InlineRun], max_horizontal_width: Option<f32>) -> InlineRunsLayout {
+
+    use azul_core::ui_solver::InlineTextLine;
+
+    let mut word_positions = Vec::with_capacity(runs.len());
+    let mut first_lines = Vec::with_capacity(runs.len());
+    let mut current_line = 0;
+    let mut leading = 0.0_f32;
+
+    for run in runs.iter() {
+
+        let mut text_layout_options = run.text_layout_options.clone();
+        text_layout_options.max_horizontal_width = max_horizontal_width.into();
+
+        let mut run_leading = leading + run.spacing_before;
+
+        // position_words() can only break the line *after* the first word,
+        // so if the first word doesn't fit behind the last run, start a new line
+        if let Some(max) = max_horizontal_width {
+            let first_word_width = get_first_word_width(run.words, run.shaped_words, &text_layout_options);
+            if leading > 0.0 && run_leading + first_word_width > max {
+                run_leading = run.spacing_before;
+                current_line += 1;
+            }
+        }
+
+        text_layout_options.leading = Some(run_leading).into();
+
+        let run_word_positions = position_words(run.words, run.shaped_words, &text_layout_options);
+        first_lines.push(current_line);
+        current_line += run_word_positions.number_of_lines.saturating_sub(1);
+        leading = run_word_positions.trailing + run.spacing_after;
+        word_positions.push(run_word_positions);
+    }
+
+    let number_of_lines = if runs.is_empty() { 0 } else { current_line + 1 };
+
+    // (line height, distance from top of line to baseline, baseline shift) of each run
+    let run_metrics = runs.iter().zip(word_positions.iter()).map(|(run, run_word_positions)| {
+        let font_size_px = run_word_positions.text_layout_options.font_size_px;
+        let line_height = run_word_positions.line_breaks.first()
+            .map(|l| l.bounds.size.height)
+            .unwrap_or(font_size_px);
+        // descender is NEGATIVE
+        let baseline = line_height + run.shaped_words.get_descender(font_size_px);
+        let baseline_shift = match run.vertical_align {
+            LayoutVerticalAlign::Sub => font_size_px * SUBSCRIPT_SHIFT,
+            LayoutVerticalAlign::Super => -font_size_px * SUPERSCRIPT_SHIFT,
+            _ => 0.0,
+        };
+        (line_height, baseline, baseline_shift)
+    }).collect::<Vec<_>>();
+
+    // ascent / descent of the baseline-aligned runs and
+    // height of the top / middle / bottom aligned runs
+    let mut line_ascents = vec![0.0_f32; number_of_lines];
+    let mut line_descents = vec![0.0_f32; number_of_lines];
+    let mut line_min_heights = vec![0.0_f32; number_of_lines];
+    let mut line_widths = vec![0.0_f32; number_of_lines];
+
+    for (run_idx, run) in runs.iter().enumerate() {
+        let (line_height, baseline, baseline_shift) = run_metrics[run_idx];
+        let last_line = word_positions[run_idx].line_breaks.len().saturating_sub(1);
+        for (line_idx, line) in word_positions[run_idx].line_breaks.iter().enumerate() {
+            let shared_line = first_lines[run_idx] + line_idx;
+            let spacing_after = if line_idx == last_line { run.spacing_after } else { 0.0 };
+            line_widths[shared_line] = line_widths[shared_line].max(line.bounds.size.width + spacing_after);
+            match run.vertical_align {
+                LayoutVerticalAlign::Top |
+                LayoutVerticalAlign::Middle |
+                LayoutVerticalAlign::Bottom => {
+                    line_min_heights[shared_line] = line_min_heights[shared_line].max(line_height);
+                },
+                LayoutVerticalAlign::Baseline |
+                LayoutVerticalAlign::Sub |
+                LayoutVerticalAlign::Super => {
+                    line_ascents[shared_line] = line_ascents[shared_line].max(baseline - baseline_shift);
+                    line_descents[shared_line] = line_descents[shared_line].max(line_height - baseline + baseline_shift);
+                },
+            }
+        }
+    }
+
+    let mut lines = Vec::with_capacity(number_of_lines);
+    let mut current_y = 0.0;
+    for line_idx in 0..number_of_lines {
+        let line_height = line_min_heights[line_idx].max(line_ascents[line_idx] + line_descents[line_idx]);
+        lines.push(LogicalRect::new(
+            LogicalPosition::new(0.0, current_y),
+            LogicalSize::new(line_widths[line_idx], line_height),
+        ));
+        current_y += line_height;
+    }
+
+    let longest_line_width = line_widths.iter().copied().fold(0.0_f32, f32::max);
+    let content_size = LogicalSize::new(
+        max_horizontal_width.unwrap_or(longest_line_width),
+        current_y,
+    );
+
+    // move the lines + words of each run onto the shared lines
+    for (run_idx, run) in runs.iter().enumerate() {
+        let (line_height, baseline, baseline_shift) = run_metrics[run_idx];
+        let run_word_positions = &mut word_positions[run_idx];
+
+        for line_idx in 0..run_word_positions.line_breaks.len() {
+            let shared_line = first_lines[run_idx] + line_idx;
+            let line_top = lines[shared_line].origin.y;
+            let shared_line_height = lines[shared_line].size.height;
+
+            // NOTE: the y position of a line is the bottom of the line
+            let line_bottom = match run.vertical_align {
+                LayoutVerticalAlign::Top => line_top + line_height,
+                LayoutVerticalAlign::Bottom => line_top + shared_line_height,
+                LayoutVerticalAlign::Middle => line_top + (shared_line_height + line_height) / 2.0,
+                LayoutVerticalAlign::Baseline |
+                LayoutVerticalAlign::Sub |
+                LayoutVerticalAlign::Super => {
+                    line_top + line_ascents[shared_line] + baseline_shift - baseline + line_height
+                },
+            };
+
+            let InlineTextLine { word_start, word_end, .. } = run_word_positions.line_breaks[line_idx];
+            run_word_positions.line_breaks[line_idx].bounds.origin.y = line_bottom;
+            for word_idx in word_start..=word_end {
+                if let Some(word_position) = run_word_positions.word_positions.get_mut(word_idx) {
+                    word_position.position.y = line_bottom;
+                }
+            }
+        }
+
+        run_word_positions.content_size = content_size;
+    }
+
+    InlineRunsLayout {
+        word_positions,
+        first_lines,
+        lines,
+        content_size,
+    }
+}
+
+/// Returns the width of the first word of a text run (0 if the text doesn't start with a word)
+fn get_first_word_width(words: &Words, shaped_words: &ShapedWords, text_layout_options: &ResolvedTextLayoutOptions) -> f32 {
+
+    let starts_with_word = words.items.as_ref().first().map(|w| w.word_type == WordType::Word).unwrap_or(false);
+    if !starts_with_word {
+        return 0.0;
+    }
+
+    let shaped_word = match shaped_words.items.get(0) {
+        Some(s) => s,
+        None => return 0.0,
+    };
+
+    let spacing_multiplier = text_layout_options.letter_spacing.as_ref().copied().unwrap_or(0.0);
+    let letter_spacing_px = spacing_multiplier * shaped_word.number_of_glyphs().saturating_sub(1) as f32;
+
+    shaped_word.get_word_width(shaped_words.font_metrics_units_per_em, text_layout_options.font_size_px) + letter_spacing_px
+}
+
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
 enum LineCaretIntersection {
     /// In order to not intersect with any holes, the caret needs to
@@ -447,18 +776,119 @@ pub fn shape_text(font: &FontRef, text: &str, options: &ResolvedTextLayoutOption
     )
 }
 
+/// Measures the size of a text without laying out a DOM, see `TextMetrics`
+pub fn measure_text(font: &FontRef, text: &str, options: &ResolvedTextLayoutOptions) -> TextMetrics {
+    shape_text(font, text, options).get_text_metrics()
+}
+
+/// Shapes and positions the text like `shape_text()`, but returns the outlines of the
+/// glyphs (relative to the top left corner of the text), so that the text can be
+/// tessellated, stroked or used in boolean operations like any other SVG shape
+///
+/// NOTE: Glyphs without outlines (spaces, bitmap glyphs) are skipped. If the font
+/// was parsed without glyph outlines, the outlines are parsed from the font bytes.
+pub fn text_to_path(font: &FontRef, text: &str, options: &ResolvedTextLayoutOptions) -> SvgMultiPolygon {
+
+    let font_data = font.get_data();
+    let parsed_font_downcasted = unsafe { &*(font_data.parsed as *const ParsedFont) };
+    let face = ttf_parser::Face::from_slice(font_data.bytes.as_ref(), font_data.font_index).ok();
+
+    let units_per_em = parsed_font_downcasted.font_metrics.units_per_em.max(1) as f32;
+    let scale = options.font_size_px / units_per_em;
+
+    let layouted_glyphs = shape_text(font, text, options).get_layouted_glyphs();
+
+    let rings = layouted_glyphs.glyphs.iter().flat_map(|glyph| {
+        let glyph_index = glyph.index as u16;
+        let outline = match parsed_font_downcasted.get_glyph_outline(glyph_index) {
+            Some(s) => Some(Cow::Borrowed(s)),
+            None => face.as_ref().and_then(|f| get_glyph_outline(f, glyph_index)).map(Cow::Owned),
+        };
+        outline
+        .map(|o| glyph_outline_to_svg_paths(&o, glyph.point, scale))
+        .unwrap_or_default()
+    }).collect::<Vec<_>>();
+
+    SvgMultiPolygon { rings: rings.into() }
+}
+
+/// Converts the outline of a glyph to closed `SvgPath` rings, `origin` is the
+/// position of the glyph on the baseline, `scale` converts font units to pixels
+fn glyph_outline_to_svg_paths(outline: &GlyphOutline, origin: LogicalPosition, scale: f32) -> Vec<SvgPath> {
+
+    // font units have the y-axis pointing up
+    let transform = |x: f32, y: f32| SvgPoint { x: origin.x + x * scale, y: origin.y - y * scale };
+
+    fn close_ring(items: &mut Vec<SvgPathElement>, rings: &mut Vec<SvgPath>, current: SvgPoint, ring_start: SvgPoint) {
+        if items.is_empty() {
+            return;
+        }
+        if current != ring_start {
+            items.push(SvgPathElement::Line(SvgLine { start: current, end: ring_start }));
+        }
+        rings.push(SvgPath { items: core::mem::replace(items, Vec::new()).into() });
+    }
+
+    let mut rings = Vec::new();
+    let mut items = Vec::new();
+    let mut ring_start = SvgPoint::default();
+    let mut current = SvgPoint::default();
+
+    for op in outline.operations.iter() {
+        match op {
+            GlyphOutlineOperation::MoveTo(m) => {
+                close_ring(&mut items, &mut rings, current, ring_start);
+                ring_start = transform(m.x, m.y);
+                current = ring_start;
+            },
+            GlyphOutlineOperation::LineTo(l) => {
+                let end = transform(l.x, l.y);
+                items.push(SvgPathElement::Line(SvgLine { start: current, end }));
+                current = end;
+            },
+            GlyphOutlineOperation::QuadraticCurveTo(q) => {
+                let end = transform(q.end_x, q.end_y);
+                items.push(SvgPathElement::QuadraticCurve(SvgQuadraticCurve {
+                    start: current,
+                    ctrl: transform(q.ctrl_1_x, q.ctrl_1_y),
+                    end,
+                }));
+                current = end;
+            },
+            GlyphOutlineOperation::CubicCurveTo(c) => {
+                let end = transform(c.end_x, c.end_y);
+                items.push(SvgPathElement::CubicCurve(SvgCubicCurve {
+                    start: current,
+                    ctrl_1: transform(c.ctrl_1_x, c.ctrl_1_y),
+                    ctrl_2: transform(c.ctrl_2_x, c.ctrl_2_y),
+                    end,
+                }));
+                current = end;
+            },
+            GlyphOutlineOperation::ClosePath => {
+                close_ring(&mut items, &mut rings, current, ring_start);
+                current = ring_start;
+            },
+        }
+    }
+
+    close_ring(&mut items, &mut rings, current, ring_start);
+
+    rings
+}
+
 #[test]
 fn test_split_words() {
 
     fn print_words(w: &Words) {
         println!("-- string: {:?}", w.get_str());
-        for item in &w.items {
+        for item in w.items.iter() {
             println!("{:?} - ({}..{}) = {:?}", w.get_substr(item), item.start, item.end, item.word_type);
         }
     }
 
-    fn string_to_vec(s: String) -> Vec<char> {
-        s.chars().collect()
+    fn string_to_vec(s: String) -> azul_css::U32Vec {
+        s.chars().map(|c| c as u32).collect::<Vec<_>>().into()
     }
 
     fn assert_words(expected: &Words, got_words: &Words) {
@@ -477,7 +907,7 @@ fn test_split_words() {
     let ascii_str = String::from("abc\tdef  \nghi\r\njkl");
     let words_ascii = split_text_into_words(&ascii_str);
     let words_ascii_expected = Words {
-        internal_str: ascii_str.clone(),
+        internal_str: ascii_str.clone().into(),
         internal_chars: string_to_vec(ascii_str),
         items: vec![
             Word { start: 0,    end: 3,     word_type: WordType::Word     }, // "abc" - (0..3) = Word
@@ -489,7 +919,7 @@ fn test_split_words() {
             Word { start: 10,   end: 13,    word_type: WordType::Word     }, // "ghi" - (10..13) = Word
             Word { start: 13,   end: 15,    word_type: WordType::Return   }, // "\r\n" - (13..15) = Return
             Word { start: 15,   end: 18,    word_type: WordType::Word     }, // "jkl" - (15..18) = Word
-        ],
+        ].into(),
     };
 
     assert_words(&words_ascii_expected, &words_ascii);
@@ -497,13 +927,13 @@ fn test_split_words() {
     let unicode_str = String::from("㌊㌋㌌㌍㌎㌏㌐㌑ ㌒㌓㌔㌕㌖㌗");
     let words_unicode = split_text_into_words(&unicode_str);
     let words_unicode_expected = Words {
-        internal_str: unicode_str.clone(),
+        internal_str: unicode_str.clone().into(),
         internal_chars: string_to_vec(unicode_str),
         items: vec![
             Word { start: 0,        end: 8,         word_type: WordType::Word   }, // "㌊㌋㌌㌍㌎㌏㌐㌑"
             Word { start: 8,        end: 9,         word_type: WordType::Space  }, // " "
             Word { start: 9,        end: 15,        word_type: WordType::Word   }, // "㌒㌓㌔㌕㌖㌗"
-        ],
+        ].into(),
     };
 
     assert_words(&words_unicode_expected, &words_unicode);
@@ -511,149 +941,371 @@ fn test_split_words() {
     let single_str = String::from("A");
     let words_single_str = split_text_into_words(&single_str);
     let words_single_str_expected = Words {
-        internal_str: single_str.clone(),
+        internal_str: single_str.clone().into(),
         internal_chars: string_to_vec(single_str),
         items: vec![
             Word { start: 0,        end: 1,         word_type: WordType::Word   }, // "A"
-        ],
+        ].into(),
     };
 
     assert_words(&words_single_str_expected, &words_single_str);
 }
 
-// Scenario 1:
-//
-// +---------+
-// |+ ------>|+
-// |         |
-// +---------+
-// rectangle: 100x200
-// max-width: none, line-height 1.0, font-size: 20
-// cursor is at: 0x, 20y
-// expect cursor to advance to 100x, 20y
-//
+// The caret is at 40x, 20y, words are 30px wide, lines are 20px high
 #[test]
-fn test_caret_intersects_with_holes_1() {
-    let line_caret_x = 0.0;
-    let line_number = 0;
-    let font_size_px = 20.0;
-    let line_height_px = 0.0;
-    let max_width = None;
-    let holes = vec![LogicalRect::new(LogicalPosition::new(0.0, 0.0), LogicalSize::new(200.0, 100.0))];
-
-    let result = caret_intersects_with_holes(
-        line_caret_x,
-        line_number,
-        font_size_px,
-        line_height_px,
-        &holes,
-        max_width,
+fn test_line_caret_intersection() {
+
+    use self::LineCaretIntersection::*;
+
+    // no max-width: the word is always appended to the current line
+    assert_eq!(
+        LineCaretIntersection::new(40.0, 30.0, 20.0, 20.0, None),
+        NoLineBreak { new_x: 70.0, new_y: 20.0 },
+    );
+
+    // the word fits into the line
+    assert_eq!(
+        LineCaretIntersection::new(40.0, 30.0, 20.0, 20.0, Some(100.0)),
+        NoLineBreak { new_x: 70.0, new_y: 20.0 },
+    );
+
+    // the word overflows the line: break the line, the caret
+    // moves to the start of the next line
+    assert_eq!(
+        LineCaretIntersection::new(80.0, 30.0, 20.0, 20.0, Some(100.0)),
+        LineBreak { new_x: 0.0, new_y: 40.0 },
     );
 
-    assert_eq!(result, LineCaretIntersection::AdvanceCaretTo(200.0));
+    // the first word of a line is wider than the max-width: don't break
+    assert_eq!(
+        LineCaretIntersection::new(0.0, 130.0, 20.0, 20.0, Some(100.0)),
+        NoLineBreak { new_x: 130.0, new_y: 20.0 },
+    );
 }
 
-// Scenario 2:
-//
-// +---------+
-// |+ -----> |
-// |-------> |
-// |---------|
-// |+        |
-// |         |
-// +---------+
-// rectangle: 100x200
-// max-width: 200px, line-height 1.0, font-size: 20
-// cursor is at: 0x, 20y
-// expect cursor to advance to 0x, 100y (+= 4 lines)
-//
 #[test]
-fn test_caret_intersects_with_holes_2() {
-    let line_caret_x = 0.0;
-    let line_number = 0;
-    let font_size_px = 20.0;
-    let line_height_px = 0.0;
-    let max_width = Some(200.0);
-    let holes = vec![LogicalRect::new(LogicalPosition::new(0.0, 0.0), LogicalSize::new(200.0, 100.0))];
-
-    let result = caret_intersects_with_holes(
-        line_caret_x,
-        line_number,
-        font_size_px,
-        line_height_px,
-        &holes,
-        max_width,
+fn test_split_color_glyph_layers() {
+
+    use azul_core::app_resources::{ColorGlyphLayer, ShapedWordVec};
+    use azul_core::display_list::split_color_glyph_layers;
+    use azul_css::{ColorU, OptionColorU};
+
+    let red = ColorU { r: 255, g: 0, b: 0, a: 255 };
+    let text_color = ColorU { r: 0, g: 0, b: 0, a: 255 };
+
+    // glyph 5 is drawn as glyph 10 (red) with glyph 11 (text color) on top
+    let shaped_words = ShapedWords {
+        items: ShapedWordVec::from_const_slice(&[]),
+        longest_word_width: 0,
+        space_advance: 0,
+        font_metrics_units_per_em: 1000,
+        font_metrics_ascender: 800,
+        font_metrics_descender: -200,
+        font_metrics_line_gap: 0,
+        color_glyphs: vec![ColorGlyph {
+            glyph_index: 5,
+            layers: vec![
+                ColorGlyphLayer { glyph_index: 10, color: OptionColorU::Some(red) },
+                ColorGlyphLayer { glyph_index: 11, color: OptionColorU::None },
+            ].into(),
+        }].into(),
+        bitmap_glyphs: Vec::new().into(),
+    };
+
+    let glyph = |index, x| GlyphInstance {
+        index,
+        point: LogicalPosition::new(x, 0.0),
+        size: LogicalSize::new(10.0, 10.0),
+    };
+
+    let (regular, layers) = split_color_glyph_layers(
+        vec![glyph(1, 0.0), glyph(5, 10.0), glyph(2, 20.0), glyph(5, 30.0)],
+        &shaped_words,
+        text_color,
     );
 
-    assert_eq!(result, LineCaretIntersection::PushCaretOntoNextLine(4, 0.0));
+    // regular glyphs keep their order, layers are grouped into one run
+    // per (layer, color), bottom layer first
+    assert_eq!(regular, vec![glyph(1, 0.0), glyph(2, 20.0)]);
+    assert_eq!(layers, vec![
+        (red, vec![glyph(10, 10.0), glyph(10, 30.0)]),
+        (text_color, vec![glyph(11, 10.0), glyph(11, 30.0)]),
+    ]);
 }
 
-// Scenario 3:
-//
-// +----------------+
-// |      |         |  +----->
-// |------->+       |
-// |------+         |
-// |                |
-// |                |
-// +----------------+
-// rectangle: 100x200
-// max-width: 400px, line-height 1.0, font-size: 20
-// cursor is at: 450x, 20y
-// expect cursor to advance to 200x, 40y (+= 1 lines, leading of 200px)
-//
 #[test]
-fn test_caret_intersects_with_holes_3() {
-    let line_caret_x = 450.0;
-    let line_number = 0;
-    let font_size_px = 20.0;
-    let line_height_px = 0.0;
-    let max_width = Some(400.0);
-    let holes = vec![LogicalRect::new(LogicalPosition::new(0.0, 0.0), LogicalSize::new(200.0, 100.0))];
-
-    let result = caret_intersects_with_holes(
-        line_caret_x,
-        line_number,
-        font_size_px,
-        line_height_px,
-        &holes,
-        max_width,
-    );
+fn test_glyph_outline_to_svg_paths() {
 
-    assert_eq!(result, LineCaretIntersection::PushCaretOntoNextLine(1, 200.0));
+    use crate::text_shaping::{OutlineLineTo, OutlineMoveTo};
+
+    // unclosed triangle in font units (y-axis pointing up)
+    let outline = GlyphOutline {
+        operations: vec![
+            GlyphOutlineOperation::MoveTo(OutlineMoveTo { x: 0.0, y: 0.0 }),
+            GlyphOutlineOperation::LineTo(OutlineLineTo { x: 100.0, y: 0.0 }),
+            GlyphOutlineOperation::LineTo(OutlineLineTo { x: 100.0, y: 200.0 }),
+        ].into(),
+    };
+
+    let rings = glyph_outline_to_svg_paths(&outline, LogicalPosition::new(10.0, 50.0), 0.1);
+
+    let point = |x, y| SvgPoint { x, y };
+    let line = |start, end| SvgPathElement::Line(SvgLine { start, end });
+
+    // moved to the origin on the baseline, scaled, y-flipped and closed
+    assert_eq!(rings, vec![SvgPath {
+        items: vec![
+            line(point(10.0, 50.0), point(20.0, 50.0)),
+            line(point(20.0, 50.0), point(20.0, 30.0)),
+            line(point(20.0, 30.0), point(10.0, 50.0)),
+        ].into(),
+    }]);
 }
 
-// Scenario 4:
-//
-// +----------------+
-// | +   +------+   |
-// |     |      |   |
-// |     |      |   |
-// |     +------+   |
-// |                |
-// +----------------+
-// rectangle: 100x200 @ 80.0x, 20.0y
-// max-width: 400px, line-height 1.0, font-size: 20
-// cursor is at: 40x, 20y
-// expect cursor to not advance at all
-//
 #[test]
-fn test_caret_intersects_with_holes_4() {
-    let line_caret_x = 40.0;
-    let line_number = 0;
-    let font_size_px = 20.0;
-    let line_height_px = 0.0;
-    let max_width = Some(400.0);
-    let holes = vec![LogicalRect::new(LogicalPosition::new(80.0, 20.0), LogicalSize::new(200.0, 100.0))];
-
-    let result = caret_intersects_with_holes(
-        line_caret_x,
-        line_number,
-        font_size_px,
-        line_height_px,
-        &holes,
-        max_width,
+fn test_text_to_path() {
+
+    use azul_core::app_resources::LoadedFontSource;
+
+    const KOHO: &[u8] = include_bytes!("../../examples/assets/fonts/KoHo-Light.ttf");
+
+    fn points(rings: &[SvgPath]) -> Vec<SvgPoint> {
+        rings.iter().flat_map(|r| r.items.iter()).map(|i| i.get_start()).collect()
+    }
+
+    // parsed without outlines, the outlines are read from the font bytes
+    let font = crate::parse_font_fn(LoadedFontSource {
+        data: KOHO.to_vec().into(),
+        index: 0,
+        load_outlines: false,
+    }).unwrap();
+    let parsed_font = unsafe { &*(font.get_data().parsed as *const ParsedFont) };
+    let face = ttf_parser::Face::from_slice(KOHO, 0).unwrap();
+
+    let options = ResolvedTextLayoutOptions { font_size_px: 20.0, ..Default::default() };
+    let scale = 20.0 / parsed_font.font_metrics.units_per_em as f32;
+
+    let glyph_index = parsed_font.lookup_glyph_index('l' as u32).unwrap();
+    let outline = get_glyph_outline(&face, glyph_index).unwrap();
+    let glyph_rings = |origin| glyph_outline_to_svg_paths(&outline, origin, scale);
+
+    let glyphs = shape_text(&font, "ll", &options).get_layouted_glyphs().glyphs;
+    assert_eq!(glyphs.len(), 2);
+
+    // the second glyph is moved by the x-advance (including kerning)
+    // of the first glyph, both glyphs are on the same baseline
+    let shaped_words = shape_words(&split_text_into_words("ll"), parsed_font);
+    let first_glyph = &shaped_words.items.as_ref()[0].glyph_infos.as_ref()[0];
+    let advance = first_glyph.size.get_x_advance_total_unscaled() as f32 * scale;
+    assert!((glyphs[1].point.x - glyphs[0].point.x - advance).abs() < 0.01);
+    assert_eq!(glyphs[1].point.y, glyphs[0].point.y);
+
+    let path = text_to_path(&font, "ll", &options);
+    let mut expected = glyph_rings(glyphs[0].point);
+    expected.extend(glyph_rings(glyphs[1].point));
+    assert_eq!(path.rings.as_ref(), expected.as_slice());
+
+    // the baseline is below the top of the text and the glyph is drawn above
+    // the baseline: the y-axis of the font units is flipped
+    let baseline = glyphs[0].point.y;
+    let ys = points(path.rings.as_ref()).iter().map(|p| p.y).collect::<Vec<_>>();
+    let top = ys.iter().cloned().fold(f32::MAX, f32::min);
+    let bottom = ys.iter().cloned().fold(f32::MIN, f32::max);
+    assert!(baseline > 0.0);
+    assert!(top >= 0.0 && top < baseline);
+    assert!((bottom - baseline).abs() < 1.0);
+}
+
+#[test]
+fn test_split_bitmap_glyphs() {
+
+    use alloc::collections::btree_map::BTreeMap;
+    use azul_core::app_resources::{
+        add_bitmap_glyph_images, BitmapGlyph, Epoch, IdNamespace, ImageRef,
+        RawImage, RawImageData, RawImageFormat, RendererResources, ShapedWordVec,
+    };
+    use azul_core::callbacks::DocumentId;
+    use azul_core::display_list::split_bitmap_glyphs;
+    use azul_core::gl::insert_into_active_gl_textures;
+    use azul_core::id_tree::NodeId;
+    use azul_core::window::LogicalRect;
+
+    let image = |width, height| ImageRef::new_rawimage(RawImage {
+        pixels: RawImageData::U8(vec![255; width * height * 4].into()),
+        width,
+        height,
+        premultiplied_alpha: false,
+        data_format: RawImageFormat::RGBA8,
+        tag: Vec::new().into(),
+    }).unwrap();
+
+    // glyph 5 is a registered bitmap, glyph 6 has a bitmap that was not registered yet
+    let registered = BitmapGlyph {
+        glyph_index: 5,
+        image: image(4, 4),
+        min_x: 100, min_y: -200, max_x: 900, max_y: 600,
+    };
+    let unregistered = BitmapGlyph {
+        glyph_index: 6,
+        image: image(2, 2),
+        min_x: 0, min_y: 0, max_x: 500, max_y: 500,
+    };
+
+    let shaped_words = |bitmap_glyphs: Vec<BitmapGlyph>| ShapedWords {
+        items: ShapedWordVec::from_const_slice(&[]),
+        longest_word_width: 0,
+        space_advance: 0,
+        font_metrics_units_per_em: 1000,
+        font_metrics_ascender: 800,
+        font_metrics_descender: -200,
+        font_metrics_line_gap: 0,
+        color_glyphs: Vec::new().into(),
+        bitmap_glyphs: bitmap_glyphs.into(),
+    };
+
+    let namespace = IdNamespace(0);
+    let document_id = DocumentId { namespace_id: namespace, id: 0 };
+    let mut renderer_resources = RendererResources::default();
+    let mut resource_updates = Vec::new();
+    let mut shaped_words_cache = BTreeMap::new();
+    shaped_words_cache.insert(NodeId::ZERO, shaped_words(vec![registered.clone()]));
+    add_bitmap_glyph_images(
+        &mut renderer_resources,
+        namespace,
+        Epoch::new(),
+        &document_id,
+        &mut resource_updates,
+        &shaped_words_cache,
+        insert_into_active_gl_textures,
     );
+    assert_eq!(resource_updates.len(), 1);
+    let image_key = renderer_resources.get_image(&registered.image.get_hash()).unwrap().key;
+
+    let glyph = |index, x| GlyphInstance {
+        index,
+        point: LogicalPosition::new(x, 50.0),
+        size: LogicalSize::new(10.0, 10.0),
+    };
 
-    assert_eq!(result, LineCaretIntersection::NoIntersection);
+    let (regular, bitmaps) = split_bitmap_glyphs(
+        vec![glyph(1, 0.0), glyph(5, 10.0), glyph(6, 20.0)],
+        &shaped_words(vec![registered, unregistered]),
+        20.0,
+        &renderer_resources,
+    );
+
+    // 20px / 1000 units per em = 0.02px per unit, the top
+    // of the bitmap is 12px above the baseline at y = 50px
+    assert_eq!(regular, vec![glyph(1, 0.0), glyph(6, 20.0)]);
+    assert_eq!(bitmaps, vec![(
+        image_key,
+        LogicalRect::new(LogicalPosition::new(12.0, 38.0), LogicalSize::new(16.0, 16.0)),
+    )]);
+}
+
+#[cfg(test)]
+fn inline_test_run(text: &str, font_size_px: f32) -> (Words, ShapedWords, ResolvedTextLayoutOptions) {
+
+    // every glyph and the space is half an em wide, the line height is the font size
+    let words = split_text_into_words(text);
+    let shaped_words = ShapedWords {
+        items: words.items.iter()
+            .filter(|w| w.word_type == WordType::Word)
+            .map(|w| ShapedWord {
+                glyph_infos: Vec::new().into(),
+                word_width: 500 * words.get_substr(w).chars().count(),
+            })
+            .collect::<Vec<_>>()
+            .into(),
+        longest_word_width: 0,
+        space_advance: 500,
+        font_metrics_units_per_em: 1000,
+        font_metrics_ascender: 800,
+        font_metrics_descender: -200,
+        font_metrics_line_gap: 0,
+        color_glyphs: Vec::new().into(),
+        bitmap_glyphs: Vec::new().into(),
+    };
+
+    let text_layout_options = ResolvedTextLayoutOptions {
+        font_size_px,
+        line_height: Some(0.0).into(),
+        letter_spacing: None.into(),
+        word_spacing: Some(1.0).into(),
+        tab_width: None.into(),
+        max_horizontal_width: None.into(),
+        leading: None.into(),
+        holes: Vec::new().into(),
+    };
+
+    (words, shaped_words, text_layout_options)
+}
+
+#[test]
+fn test_position_inline_runs_line_breaks() {
+
+    let texts = [("aa bb", 10.0), ("cc dd", 20.0), ("eeeeee", 10.0)];
+    let runs_data = texts.iter().map(|(t, s)| inline_test_run(t, *s)).collect::<Vec<_>>();
+    let mut runs = runs_data.iter().map(|(words, shaped_words, text_layout_options)| InlineRun {
+        words,
+        shaped_words,
+        text_layout_options: text_layout_options.clone(),
+        vertical_align: LayoutVerticalAlign::Baseline,
+        spacing_before: 0.0,
+        spacing_after: 0.0,
+    }).collect::<Vec<_>>();
+
+    // the third run is wrapped in an inline element with padding
+    runs[2].spacing_before = 15.0;
+    runs[2].spacing_after = 5.0;
+
+    let layout = position_inline_runs(&runs, Some(60.0));
+
+    // "aa bb " = 25px, "cc " continues on the first line (25 + 20 <= 60), "dd" is wrapped,
+    // "eeeeee" (30px) doesn't fit behind "dd" + 15px padding, so it starts a new line
+    assert_eq!(layout.first_lines, vec![0, 0, 2]);
+    assert_eq!(layout.lines.len(), 3);
+
+    let second_run = &layout.word_positions[1];
+    assert_eq!(second_run.line_breaks.len(), 2);
+    assert_eq!(second_run.word_positions[0].position.x, 25.0);
+    assert_eq!(second_run.word_positions[2].position.x, 0.0);
+
+    // the padding of the inline element is inserted in front of the first word
+    // and reserved behind the last word of the line
+    assert_eq!(layout.word_positions[2].word_positions[0].position.x, 15.0);
+    assert_eq!(layout.lines[2].size.width, 15.0 + 30.0 + 5.0);
+}
+
+#[test]
+fn test_position_inline_runs_baseline() {
+
+    let texts = [("aa", 10.0), ("bb", 20.0), ("cc", 10.0)];
+    let runs_data = texts.iter().map(|(t, s)| inline_test_run(t, *s)).collect::<Vec<_>>();
+    let vertical_aligns = [LayoutVerticalAlign::Baseline, LayoutVerticalAlign::Baseline, LayoutVerticalAlign::Super];
+    let runs = runs_data.iter().zip(vertical_aligns.iter()).map(|((words, shaped_words, text_layout_options), vertical_align)| InlineRun {
+        words,
+        shaped_words,
+        text_layout_options: text_layout_options.clone(),
+        vertical_align: *vertical_align,
+        spacing_before: 0.0,
+        spacing_after: 0.0,
+    }).collect::<Vec<_>>();
+
+    let layout = position_inline_runs(&runs, None);
+
+    // line height = largest ascent (16px, 20px text) + largest descent (4px, 20px text)
+    assert_eq!(layout.lines.len(), 1);
+    assert_eq!(layout.content_size.height, 20.0);
+
+    // the y position of a line is the bottom of the line box of the run,
+    // the baseline is 20% of the font size above the bottom
+    let baselines = layout.word_positions.iter().zip(texts.iter())
+        .map(|(w, (_, font_size))| w.line_breaks[0].bounds.origin.y - font_size * 0.2)
+        .collect::<Vec<_>>();
+
+    assert_eq!(baselines[0], 16.0);
+    assert_eq!(baselines[1], 16.0);
+    // superscript text is shifted up by 33% of its font size
+    assert!((baselines[2] - (16.0 - 10.0 * SUPERSCRIPT_SHIFT)).abs() < 0.001);
 }
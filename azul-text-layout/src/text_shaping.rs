@@ -1,13 +1,16 @@
 use azul_core::app_resources::{
     FontMetrics, VariationSelector, Anchor,
     GlyphOrigin, RawGlyph, Placement,
-    GlyphInfo, Advance,
+    GlyphInfo, Advance, ColorGlyphLayer, BitmapGlyph,
+    ImageRef, RawImage, RawImageData, RawImageFormat,
 };
+use azul_css::{ColorU, OptionColorU};
 use tinyvec::tiny_vec;
 use alloc::collections::btree_map::BTreeMap;
 use alloc::rc::Rc;
 use alloc::vec::Vec;
 use alloc::boxed::Box;
+use std::sync::Mutex;
 use allsorts::{
     binary::read::ReadScope, font_data::FontData, gsub::RawGlyphFlags, layout::{GDEFTable, LayoutCache, GPOS, GSUB}, tables::{
         cmap::{owned::CmapSubtable as OwnedCmapSubtable, CmapSubtable}, glyf::{BoundingBox, GlyfRecord, GlyfTable, Glyph}, loca::{LocaOffsets, LocaTable}, FontTableProvider, HeadTable, HheaTable, MaxpTable
@@ -212,6 +215,176 @@ pub struct ParsedFont {
     pub glyph_records_decoded: BTreeMap<u16, OwnedGlyph>,
     pub space_width: Option<usize>,
     pub cmap_subtable: Option<OwnedCmapSubtable>,
+    /// Layers of the color glyphs (`COLR` table, colors resolved with the first `CPAL` palette)
+    pub color_glyph_layers: BTreeMap<u16, Vec<ColorGlyphLayer>>,
+    /// Bitmap glyphs (`CBDT` / `sbix` tables), `None` if the font has no bitmap glyphs
+    pub bitmap_glyph_tables: Option<BitmapGlyphTables>,
+}
+
+/// Copies of the tables that are required to look up the size of bitmap glyphs (`CBDT` / `sbix`)
+///
+/// Color emoji fonts contain thousands of bitmap glyphs, so instead of decoding all glyphs when
+/// the font is parsed, the bounding box of a glyph is decoded when the glyph is shaped for the first time
+pub struct BitmapGlyphTables {
+    head: Vec<u8>,
+    hhea: Vec<u8>,
+    maxp: Vec<u8>,
+    cbdt: Option<Vec<u8>>,
+    cblc: Option<Vec<u8>>,
+    sbix: Option<Vec<u8>>,
+    /// Bounding boxes of the glyphs that were already looked up, `None` if the glyph has no bitmap
+    bounding_boxes: Mutex<BTreeMap<u16, Option<OwnedGlyphBoundingBox>>>,
+    /// Decoded bitmaps of the glyphs that were already drawn, `None` if the bitmap can't be decoded
+    images: Mutex<BTreeMap<u16, Option<ImageRef>>>,
+}
+
+impl Clone for BitmapGlyphTables {
+    fn clone(&self) -> Self {
+        let bounding_boxes = self.bounding_boxes.lock().map(|b| b.clone()).unwrap_or_default();
+        let images = self.images.lock().map(|i| i.clone()).unwrap_or_default();
+        Self {
+            head: self.head.clone(),
+            hhea: self.hhea.clone(),
+            maxp: self.maxp.clone(),
+            cbdt: self.cbdt.clone(),
+            cblc: self.cblc.clone(),
+            sbix: self.sbix.clone(),
+            bounding_boxes: Mutex::new(bounding_boxes),
+            images: Mutex::new(images),
+        }
+    }
+}
+
+impl BitmapGlyphTables {
+
+    /// Returns `None` if the font has no `sbix` or `CBDT` + `CBLC` tables
+    pub fn new<T: FontTableProvider>(provider: &T) -> Option<Self> {
+        use allsorts::tag;
+
+        let table = |tag| provider.table_data(tag).ok().and_then(|s| Some(s?.to_vec()));
+
+        let sbix = table(tag::SBIX);
+        let (cbdt, cblc) = match (table(tag::CBDT), table(tag::CBLC)) {
+            (Some(cbdt), Some(cblc)) => (Some(cbdt), Some(cblc)),
+            _ => (None, None),
+        };
+
+        if sbix.is_none() && cbdt.is_none() {
+            return None;
+        }
+
+        Some(Self {
+            head: table(tag::HEAD)?,
+            hhea: table(tag::HHEA)?,
+            maxp: table(tag::MAXP)?,
+            cbdt,
+            cblc,
+            sbix,
+            bounding_boxes: Mutex::new(BTreeMap::new()),
+            images: Mutex::new(BTreeMap::new()),
+        })
+    }
+
+    /// Returns the bounding box of the glyph in font units (relative to the baseline),
+    /// calculated from the largest available bitmap strike
+    pub fn get_bounding_box(&self, glyph_index: u16) -> Option<OwnedGlyphBoundingBox> {
+        let mut bounding_boxes = self.bounding_boxes.lock().ok()?;
+        bounding_boxes
+            .entry(glyph_index)
+            .or_insert_with(|| self.decode_bounding_box(glyph_index))
+            .clone()
+    }
+
+    /// Returns the decoded bitmap of the glyph together with its bounding box,
+    /// the bitmap is decoded when the glyph is drawn for the first time
+    pub fn get_bitmap_glyph(&self, glyph_index: u16) -> Option<BitmapGlyph> {
+        let bounding_box = self.get_bounding_box(glyph_index)?;
+        let image = {
+            let mut images = self.images.lock().ok()?;
+            images
+                .entry(glyph_index)
+                .or_insert_with(|| self.decode_image(glyph_index))
+                .clone()?
+        };
+        Some(BitmapGlyph {
+            glyph_index,
+            image,
+            min_x: bounding_box.min_x,
+            min_y: bounding_box.min_y,
+            max_x: bounding_box.max_x,
+            max_y: bounding_box.max_y,
+        })
+    }
+
+    fn get_face(&self) -> Option<ttf_parser::Face<'_>> {
+        ttf_parser::Face::from_raw_tables(ttf_parser::RawFaceTables {
+            head: &self.head,
+            hhea: &self.hhea,
+            maxp: &self.maxp,
+            cbdt: self.cbdt.as_deref(),
+            cblc: self.cblc.as_deref(),
+            sbix: self.sbix.as_deref(),
+            ..Default::default()
+        }).ok()
+    }
+
+    fn decode_image(&self, glyph_index: u16) -> Option<ImageRef> {
+        let face = self.get_face()?;
+        let image = face.glyph_raster_image(ttf_parser::GlyphId(glyph_index), u16::MAX)?;
+        match image.format {
+            ttf_parser::RasterImageFormat::PNG => ImageRef::new_rawimage(decode_png(image.data)?),
+        }
+    }
+
+    fn decode_bounding_box(&self, glyph_index: u16) -> Option<OwnedGlyphBoundingBox> {
+
+        let face = self.get_face()?;
+
+        let image = face.glyph_raster_image(ttf_parser::GlyphId(glyph_index), u16::MAX)?;
+        if image.pixels_per_em == 0 {
+            return None;
+        }
+
+        // convert from bitmap pixels to font units, y is the offset
+        // of the bottom of the bitmap to the baseline
+        let scale = face.units_per_em() as f32 / image.pixels_per_em as f32;
+
+        Some(OwnedGlyphBoundingBox {
+            min_x: (image.x as f32 * scale) as i16,
+            min_y: (image.y as f32 * scale) as i16,
+            max_x: ((image.x as f32 + image.width as f32) * scale) as i16,
+            max_y: ((image.y as f32 + image.height as f32) * scale) as i16,
+        })
+    }
+}
+
+/// Decodes the PNG data of a bitmap glyph to 8-bit gray / RGB(A) pixels
+fn decode_png(data: &[u8]) -> Option<RawImage> {
+
+    let mut decoder = png::Decoder::new(data);
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+    let mut reader = decoder.read_info().ok()?;
+
+    let mut pixels = vec![0; reader.output_buffer_size()];
+    let frame = reader.next_frame(&mut pixels).ok()?;
+    pixels.truncate(frame.buffer_size());
+
+    let data_format = match frame.color_type {
+        png::ColorType::Grayscale => RawImageFormat::R8,
+        png::ColorType::GrayscaleAlpha => RawImageFormat::RG8,
+        png::ColorType::Rgb => RawImageFormat::RGB8,
+        png::ColorType::Rgba => RawImageFormat::RGBA8,
+        png::ColorType::Indexed => return None, // expanded to RGB(A) by normalize_to_color8
+    };
+
+    Some(RawImage {
+        pixels: RawImageData::U8(pixels.into()),
+        width: frame.width as usize,
+        height: frame.height as usize,
+        premultiplied_alpha: false,
+        data_format,
+        tag: Vec::new().into(),
+    })
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
@@ -289,7 +462,7 @@ impl_vec_debug!(GlyphOutlineOperation, GlyphOutlineOperationVec);
 impl_vec_partialord!(GlyphOutlineOperation, GlyphOutlineOperationVec);
 impl_vec_partialeq!(GlyphOutlineOperation, GlyphOutlineOperationVec);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 #[repr(C)]
 pub struct OwnedGlyphBoundingBox {
     pub max_x: i16,
@@ -399,7 +572,29 @@ impl ParsedFont {
                 }
             }).collect::<Vec<_>>();
 
-        let glyph_records_decoded = glyph_records_decoded.into_iter().collect();
+        let mut glyph_records_decoded = glyph_records_decoded.into_iter().collect::<BTreeMap<_, _>>();
+
+        if parse_glyph_outlines {
+            add_glyph_outlines(font_bytes, font_index, &mut glyph_records_decoded);
+        }
+
+        let colr_data = provider.table_data(tag::COLR).ok().and_then(|s| Some(s?.to_vec()));
+        let cpal_data = provider.table_data(tag::CPAL).ok().and_then(|s| Some(s?.to_vec()));
+        let color_glyph_layers = match colr_data.as_ref() {
+            Some(colr) => parse_colr_layers(colr, cpal_data.as_ref().map(|c| c.as_slice())),
+            None => BTreeMap::new(),
+        };
+
+        // Color bitmap glyphs (CBDT / sbix) and color layer glyphs usually have no outline,
+        // but still need a size and an advance, otherwise they are skipped during shaping
+        let bitmap_glyph_tables = BitmapGlyphTables::new(&provider);
+        add_color_glyph_records(
+            &color_glyph_layers,
+            &maxp_table,
+            &hhea_table,
+            &hmtx_data,
+            &mut glyph_records_decoded,
+        );
 
         let mut font_data_impl = allsorts::font::Font::new(provider).ok()?;
 
@@ -424,6 +619,8 @@ impl ParsedFont {
             cmap_subtable,
             glyph_records_decoded,
             space_width: None,
+            color_glyph_layers,
+            bitmap_glyph_tables,
         };
 
         let space_width = font.get_space_width_internal();
@@ -444,14 +641,41 @@ impl ParsedFont {
     }
 
     pub fn get_horizontal_advance(&self, glyph_index: u16) -> u16 {
-        self.glyph_records_decoded.get(&glyph_index).map(|gi| gi.horz_advance).unwrap_or_default()
+        match self.glyph_records_decoded.get(&glyph_index) {
+            Some(gi) => gi.horz_advance,
+            None => allsorts::glyph_info::advance(&self.maxp_table, &self.hhea_table, &self.hmtx_data, glyph_index).unwrap_or_default(),
+        }
+    }
+
+    /// Returns the layers of the glyph if the glyph is a layered color glyph (`COLR` table)
+    pub fn get_color_glyph_layers(&self, glyph_index: u16) -> Option<&[ColorGlyphLayer]> {
+        self.color_glyph_layers.get(&glyph_index).map(|l| l.as_slice())
+    }
+
+    /// Returns the outline of the glyph (in unscaled units), only
+    /// available if the font was parsed with `parse_glyph_outlines`
+    pub fn get_glyph_outline(&self, glyph_index: u16) -> Option<&GlyphOutline> {
+        self.glyph_records_decoded.get(&glyph_index)?.outline.as_ref()
+    }
+
+    /// Returns the decoded bitmap of a color bitmap glyph (`CBDT` / `sbix` tables)
+    pub fn get_bitmap_glyph(&self, glyph_index: u16) -> Option<BitmapGlyph> {
+        self.bitmap_glyph_tables.as_ref()?.get_bitmap_glyph(glyph_index)
+    }
+
+    /// Returns the bounding box of a glyph in unscaled units, relative to the baseline
+    pub fn get_glyph_bounding_box(&self, glyph_index: u16) -> Option<OwnedGlyphBoundingBox> {
+        match self.glyph_records_decoded.get(&glyph_index) {
+            Some(g) => Some(g.bounding_box.clone()),
+            None => self.bitmap_glyph_tables.as_ref()?.get_bounding_box(glyph_index),
+        }
     }
 
     // get the x and y size of a glyph in unscaled units
     pub fn get_glyph_size(&self, glyph_index: u16) -> Option<(i32, i32)> {
-        let g = self.glyph_records_decoded.get(&glyph_index)?;
-        let glyph_width = g.bounding_box.max_x as i32 - g.bounding_box.min_x as i32; // width
-        let glyph_height = g.bounding_box.max_y as i32 - g.bounding_box.min_y as i32; // height
+        let bounding_box = self.get_glyph_bounding_box(glyph_index)?;
+        let glyph_width = bounding_box.max_x as i32 - bounding_box.min_x as i32; // width
+        let glyph_height = bounding_box.max_y as i32 - bounding_box.min_y as i32; // height
         Some((glyph_width, glyph_height))
     }
 
@@ -467,6 +691,146 @@ impl ParsedFont {
     }
 }
 
+/// Returns the outline of a glyph in unscaled font units
+/// (origin on the baseline, y-axis pointing up)
+pub fn get_glyph_outline(face: &ttf_parser::Face, glyph_index: u16) -> Option<GlyphOutline> {
+    let mut builder = GlyphOutlineBuilder::default();
+    face.outline_glyph(ttf_parser::GlyphId(glyph_index), &mut builder)?;
+    Some(GlyphOutline { operations: builder.operations.into() })
+}
+
+/// Parses the outlines of all decoded glyph records
+fn add_glyph_outlines(
+    font_bytes: &[u8],
+    font_index: usize,
+    glyph_records: &mut BTreeMap<u16, OwnedGlyph>,
+) {
+    let face = match ttf_parser::Face::from_slice(font_bytes, font_index as u32) {
+        Ok(o) => o,
+        Err(_) => return,
+    };
+
+    for (glyph_index, glyph) in glyph_records.iter_mut() {
+        glyph.outline = get_glyph_outline(&face, *glyph_index);
+    }
+}
+
+/// Inserts glyph records for `COLR` base glyphs without an outline,
+/// the bounding box is the union of the bounding boxes of all layers
+fn add_color_glyph_records(
+    color_glyph_layers: &BTreeMap<u16, Vec<ColorGlyphLayer>>,
+    maxp_table: &MaxpTable,
+    hhea_table: &HheaTable,
+    hmtx_data: &[u8],
+    glyph_records: &mut BTreeMap<u16, OwnedGlyph>,
+) {
+    for (base_glyph, layers) in color_glyph_layers.iter() {
+
+        if glyph_records.contains_key(base_glyph) {
+            continue;
+        }
+
+        let bounding_box = layers.iter()
+            .filter_map(|layer| glyph_records.get(&layer.glyph_index))
+            .map(|g| g.bounding_box.clone())
+            .fold(None, |acc: Option<OwnedGlyphBoundingBox>, b| Some(match acc {
+                None => b,
+                Some(a) => OwnedGlyphBoundingBox {
+                    max_x: a.max_x.max(b.max_x),
+                    max_y: a.max_y.max(b.max_y),
+                    min_x: a.min_x.min(b.min_x),
+                    min_y: a.min_y.min(b.min_y),
+                },
+            }));
+
+        let bounding_box = match bounding_box {
+            Some(s) => s,
+            None => continue,
+        };
+
+        let horz_advance = allsorts::glyph_info::advance(maxp_table, hhea_table, hmtx_data, *base_glyph).unwrap_or_default();
+
+        glyph_records.insert(*base_glyph, OwnedGlyph {
+            bounding_box,
+            horz_advance,
+            outline: None,
+        });
+    }
+}
+
+/// Parses the base glyph and layer records of a version 0 `COLR` table,
+/// resolving the palette indices with the first palette of the `CPAL` table
+fn parse_colr_layers(colr: &[u8], cpal: Option<&[u8]>) -> BTreeMap<u16, Vec<ColorGlyphLayer>> {
+
+    fn read_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+        let b = bytes.get(offset..offset + 2)?;
+        Some(u16::from_be_bytes([b[0], b[1]]))
+    }
+
+    fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+        let b = bytes.get(offset..offset + 4)?;
+        Some(u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    /// Palette index 0xFFFF means "use the text color"
+    const FOREGROUND_PALETTE_INDEX: u16 = 0xFFFF;
+
+    // CPAL: first palette, colors are stored as BGRA
+    let palette = cpal.and_then(|cpal| {
+        let num_palette_entries = read_u16(cpal, 2)? as usize;
+        let color_records_offset = read_u32(cpal, 8)? as usize;
+        let first_color_index = read_u16(cpal, 12)? as usize;
+        (0..num_palette_entries).map(|entry| {
+            let record = color_records_offset + (first_color_index + entry) * 4;
+            let bgra = cpal.get(record..record + 4)?;
+            Some(ColorU { r: bgra[2], g: bgra[1], b: bgra[0], a: bgra[3] })
+        }).collect::<Option<Vec<_>>>()
+    }).unwrap_or_default();
+
+    let mut color_glyphs = BTreeMap::new();
+
+    let mut parse_base_glyphs = || -> Option<()> {
+        let num_base_glyphs = read_u16(colr, 2)? as usize;
+        let base_glyphs_offset = read_u32(colr, 4)? as usize;
+        let layers_offset = read_u32(colr, 8)? as usize;
+        let num_layers = read_u16(colr, 12)? as usize;
+
+        for base_glyph_index in 0..num_base_glyphs {
+            let record = base_glyphs_offset + base_glyph_index * 6;
+            let glyph_id = read_u16(colr, record)?;
+            let first_layer = read_u16(colr, record + 2)? as usize;
+            let layer_count = read_u16(colr, record + 4)? as usize;
+
+            if first_layer + layer_count > num_layers {
+                continue;
+            }
+
+            let layers = (first_layer..(first_layer + layer_count)).filter_map(|layer_index| {
+                let layer_record = layers_offset + layer_index * 4;
+                let glyph_index = read_u16(colr, layer_record)?;
+                let palette_index = read_u16(colr, layer_record + 2)?;
+                let color = if palette_index == FOREGROUND_PALETTE_INDEX {
+                    OptionColorU::None
+                } else {
+                    // invalid palette indices fall back to the text color
+                    palette.get(palette_index as usize).copied().into()
+                };
+                Some(ColorGlyphLayer { glyph_index, color })
+            }).collect::<Vec<_>>();
+
+            if !layers.is_empty() {
+                color_glyphs.insert(glyph_id, layers);
+            }
+        }
+
+        Some(())
+    };
+
+    let _ = parse_base_glyphs();
+
+    color_glyphs
+}
+
 #[derive(Debug, PartialEq, Default)]
 pub struct ShapedTextBufferUnsized {
     pub infos: Vec<GlyphInfo>,
@@ -888,4 +1252,146 @@ const fn translate_variation_selector(v: &allsorts::unicode::VariationSelector)
 }
 
 #[inline]
-const fn translate_anchor(anchor: &allsorts::layout::Anchor) -> Anchor { Anchor { x: anchor.x, y: anchor.y } }
\ No newline at end of file
+const fn translate_anchor(anchor: &allsorts::layout::Anchor) -> Anchor { Anchor { x: anchor.x, y: anchor.y } }
+#[test]
+fn test_parse_colr_layers() {
+
+    // COLR v0: one base glyph (5) with two layers: glyph 6 (palette 1) and glyph 7 (foreground)
+    let colr: &[u8] = &[
+        0, 0,           // version
+        0, 1,           // numBaseGlyphRecords
+        0, 0, 0, 14,    // baseGlyphRecordsOffset
+        0, 0, 0, 20,    // layerRecordsOffset
+        0, 2,           // numLayerRecords
+        0, 5, 0, 0, 0, 2,   // base glyph 5, first layer 0, 2 layers
+        0, 6, 0, 1,         // layer: glyph 6, palette index 1
+        0, 7, 0xFF, 0xFF,   // layer: glyph 7, foreground color
+    ];
+
+    // CPAL v0: one palette with two BGRA colors
+    let cpal: &[u8] = &[
+        0, 0,           // version
+        0, 2,           // numPaletteEntries
+        0, 1,           // numPalettes
+        0, 2,           // numColorRecords
+        0, 0, 0, 14,    // colorRecordsArrayOffset
+        0, 0,           // colorRecordIndices[0]
+        0, 0, 255, 255,     // red
+        255, 0, 0, 128,     // half-transparent blue
+    ];
+
+    let layers = parse_colr_layers(colr, Some(cpal));
+
+    assert_eq!(layers.len(), 1);
+    assert_eq!(layers.get(&5), Some(&vec![
+        ColorGlyphLayer { glyph_index: 6, color: OptionColorU::Some(ColorU { r: 0, g: 0, b: 255, a: 128 }) },
+        ColorGlyphLayer { glyph_index: 7, color: OptionColorU::None },
+    ]));
+}
+
+#[cfg(test)]
+fn sbix_test_tables(png: &[u8]) -> BitmapGlyphTables {
+
+    // head: units per em = 1000, short loca format
+    let mut head = vec![0; 54];
+    head[18..20].copy_from_slice(&1000_u16.to_be_bytes());
+
+    // maxp v0.5: two glyphs
+    let maxp = vec![0, 0, 0x50, 0, 0, 2];
+
+    // sbix v1: one strike at 20 ppem, glyph 0 has no bitmap, the bitmap
+    // of glyph 1 starts 1px right of the origin and 2px below the baseline
+    let mut sbix = vec![
+        0, 1,           // version
+        0, 1,           // flags
+        0, 0, 0, 1,     // numStrikes
+        0, 0, 0, 12,    // strikeOffsets[0]
+        0, 20,          // ppem
+        0, 72,          // ppi
+    ];
+    let glyph_start = 4 + 3 * 4;
+    let glyph_end = glyph_start + 8 + png.len();
+    for offset in [glyph_start, glyph_start, glyph_end].iter() {
+        sbix.extend_from_slice(&(*offset as u32).to_be_bytes());
+    }
+    sbix.extend_from_slice(&1_i16.to_be_bytes());
+    sbix.extend_from_slice(&(-2_i16).to_be_bytes());
+    sbix.extend_from_slice(b"png ");
+    sbix.extend_from_slice(&png);
+
+    BitmapGlyphTables {
+        head,
+        hhea: vec![0; 36],
+        maxp,
+        cbdt: None,
+        cblc: None,
+        sbix: Some(sbix),
+        bounding_boxes: Mutex::new(BTreeMap::new()),
+        images: Mutex::new(BTreeMap::new()),
+    }
+}
+
+#[test]
+fn test_bitmap_glyph_bounding_box() {
+
+    // PNG header with a 16x18 IHDR chunk (only the size is read)
+    let mut png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0, 0, 0, 13];
+    png.extend_from_slice(b"IHDR");
+    png.extend_from_slice(&16_u32.to_be_bytes());
+    png.extend_from_slice(&18_u32.to_be_bytes());
+
+    let tables = sbix_test_tables(&png);
+
+    // 1000 units per em / 20 ppem = 50 font units per pixel,
+    // the bottom of the bitmap is 2px below the baseline
+    let expected = OwnedGlyphBoundingBox { min_x: 50, min_y: -100, max_x: 850, max_y: 800 };
+
+    assert_eq!(tables.get_bounding_box(0), None);
+    assert_eq!(tables.get_bounding_box(1), Some(expected.clone()));
+
+    // looked up glyphs are cached, including glyphs without a bitmap
+    assert_eq!(tables.bounding_boxes.lock().unwrap().len(), 2);
+    assert_eq!(tables.clone().get_bounding_box(1), Some(expected));
+}
+
+#[test]
+fn test_bitmap_glyph_image() {
+
+    // 2x3 RGBA bitmap
+    let mut png = Vec::new();
+    {
+        let mut encoder = png::Encoder::new(&mut png, 2, 3);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().unwrap();
+        writer.write_image_data(&[255; 2 * 3 * 4]).unwrap();
+    }
+
+    let tables = sbix_test_tables(&png);
+
+    assert!(tables.get_bitmap_glyph(0).is_none());
+
+    let bitmap_glyph = tables.get_bitmap_glyph(1).unwrap();
+    assert_eq!(bitmap_glyph.glyph_index, 1);
+    assert_eq!(
+        (bitmap_glyph.min_x, bitmap_glyph.min_y, bitmap_glyph.max_x, bitmap_glyph.max_y),
+        (50, -100, 150, 50),
+    );
+    assert_eq!(bitmap_glyph.image.get_size(), azul_core::window::LogicalSize::new(2.0, 3.0));
+
+    // decoded images are cached
+    assert_eq!(tables.get_bitmap_glyph(1).unwrap().image, bitmap_glyph.image);
+    assert_eq!(tables.images.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn test_bitmap_glyph_invalid_png() {
+    // only the PNG header, the image data is missing
+    let mut png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0, 0, 0, 13];
+    png.extend_from_slice(b"IHDR");
+    png.extend_from_slice(&16_u32.to_be_bytes());
+    png.extend_from_slice(&18_u32.to_be_bytes());
+    let tables = sbix_test_tables(&png);
+    assert!(tables.get_bounding_box(1).is_some());
+    assert!(tables.get_bitmap_glyph(1).is_none());
+}
@@ -0,0 +1,1519 @@
+//! CPU rasterizer for display lists
+//!
+//! Used by `azulc --render` to turn a laid-out XML file into a PNG without
+//! needing a GPU (UI previews, screenshot diffs in CI). The painting order and
+//! the background / shadow / border semantics follow the webrender translation
+//! in `azul-desktop/src/wr_translate.rs` as closely as possible.
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use azul_core::{
+    app_resources::{
+        FontKey, FontInstanceKey, ImageKey, ImageData, RawImage,
+        RawImageData, RawImageFormat, ResourceUpdate, ImageDescriptor,
+    },
+    display_list::{
+        BoxShadow, CachedDisplayList, DisplayListFrame, DisplayListMsg,
+        GlyphInstance, LayoutRectContent, RectBackground, StyleBorderColors,
+        StyleBorderRadius, StyleBorderStyles, StyleBorderWidths,
+    },
+    ui_solver::{ComputedTransform3D, PositionInfo},
+    window::{LogicalPosition, LogicalRect, LogicalSize},
+};
+#[cfg(feature = "font_loading")]
+use azul_core::{
+    app_resources::{DpiScaleFactor, Epoch, GlTextureCache, IdNamespace, ImageCache, RendererResources},
+    callbacks::DocumentId,
+    display_list::{RenderCallbacks, SolvedLayout},
+    styled_dom::{DomId, StyledDom},
+    ui_solver::LayoutResult,
+    window::FullWindowState,
+};
+use azul_css::{
+    BorderStyle, BorderStyleNoNone, BoxShadowClipMode, ColorU, ConicGradient,
+    CssPropertyValue, ExtendMode, LayoutPoint, LayoutRect, LayoutSize, LinearGradient,
+    RadialGradient, Shape, StyleBackgroundPosition, StyleBackgroundRepeat,
+    StyleBackgroundSize, StyleBoxShadow, StyleMixBlendMode,
+};
+use azul_text_layout::text_shaping::{GlyphOutlineOperation, ParsedFont};
+use tiny_skia::{
+    BlendMode, ClipMask, Color, FillRule, FilterQuality, GradientStop, LineCap,
+    Paint, Path, PathBuilder, Pattern, Pixmap, PixmapPaint, Point, Rect,
+    Shader, SpreadMode, Stroke, StrokeDash, Transform,
+};
+
+/// Upper limit for the number of background tiles painted per item, so that
+/// a tiny `background-size` can't stall the renderer
+const MAX_BACKGROUND_TILES: usize = 4096;
+
+/// Rasterizes the display list into a non-premultiplied RGBA8 image
+///
+/// `resource_updates` are the updates generated while solving the layout -
+/// they carry the font bytes, font sizes and image pixels that the display
+/// list refers to by key. The image is `root_size * hidpi_factor` pixels
+/// large and filled with `background_color` before painting.
+pub fn render_display_list(
+    display_list: &CachedDisplayList,
+    resource_updates: &[ResourceUpdate],
+    hidpi_factor: f32,
+    background_color: ColorU,
+) -> Option<RawImage> {
+
+    let width = (display_list.root_size.width * hidpi_factor).round().max(0.0) as u32;
+    let height = (display_list.root_size.height * hidpi_factor).round().max(0.0) as u32;
+
+    let mut pixmap = Pixmap::new(width, height)?;
+    pixmap.fill(translate_color(background_color));
+
+    let renderer = Renderer {
+        resources: RenderResources::new(resource_updates),
+        hidpi_factor,
+        width,
+        height,
+    };
+
+    let root_transform = Transform::from_scale(hidpi_factor, hidpi_factor);
+    renderer.draw_msg(&mut pixmap, &display_list.root, root_transform, None, &mut Vec::new(), root_transform);
+
+    // tiny-skia stores premultiplied colors, PNG expects straight alpha
+    let mut pixels = Vec::with_capacity(pixmap.pixels().len() * 4);
+    for px in pixmap.pixels() {
+        let c = px.demultiply();
+        pixels.extend_from_slice(&[c.red(), c.green(), c.blue(), c.alpha()]);
+    }
+
+    Some(RawImage {
+        pixels: RawImageData::U8(pixels.into()),
+        width: width as usize,
+        height: height as usize,
+        premultiplied_alpha: false,
+        data_format: RawImageFormat::RGBA8,
+        tag: Vec::new().into(),
+    })
+}
+
+/// Lays out the `styled_dom` at the given `size`, builds the display list and
+/// rasterizes it with `render_display_list` (fonts are loaded from the system or
+/// from the `@font-face` files, like in `azulc --render`)
+#[cfg(feature = "font_loading")]
+pub fn render_styled_dom(
+    styled_dom: StyledDom,
+    size: LogicalSize,
+    background_color: ColorU,
+) -> Option<RawImage> {
+
+    let epoch = Epoch::new();
+    let document_id = DocumentId {
+        namespace_id: IdNamespace(0),
+        id: 0,
+    };
+    let mut fake_window_state = FullWindowState::default();
+    fake_window_state.size.dimensions = size;
+    let mut renderer_resources = RendererResources::default();
+    let mut resource_updates = Vec::new();
+    let image_cache = ImageCache::default();
+    let fc_cache = crate::font_loading::build_font_cache();
+    let callbacks = RenderCallbacks {
+        insert_into_active_gl_textures_fn: azul_core::gl::insert_into_active_gl_textures,
+        layout_fn: azul_layout::do_the_layout,
+        load_font_fn: crate::font_loading::font_source_get_bytes,
+        parse_font_fn: azul_layout::parse_font_fn,
+    };
+
+    let mut solved_layout = SolvedLayout::new(
+        styled_dom,
+        epoch,
+        &document_id,
+        &fake_window_state,
+        &mut resource_updates,
+        IdNamespace(0),
+        &image_cache,
+        &fc_cache,
+        &callbacks,
+        &mut renderer_resources,
+        DpiScaleFactor { inner: azul_css::FloatValue::new(fake_window_state.size.dpi as f32 / 96.0) }
+    );
+
+    let display_list = LayoutResult::get_cached_display_list(
+        &document_id,
+        DomId { inner: 0 },
+        epoch,
+        &[solved_layout.layout_results.remove(0)],
+        &fake_window_state,
+        &GlTextureCache::default(),
+        &renderer_resources,
+        &image_cache,
+    );
+
+    render_display_list(&display_list, &resource_updates, fake_window_state.size.get_hidpi_factor(), background_color)
+}
+
+/// Fonts, font instances and images referenced by the display list
+struct RenderResources {
+    fonts: BTreeMap<FontKey, ParsedFont>,
+    font_instances: BTreeMap<FontInstanceKey, (FontKey, f32)>,
+    images: BTreeMap<ImageKey, Pixmap>,
+}
+
+impl RenderResources {
+
+    fn new(resource_updates: &[ResourceUpdate]) -> Self {
+
+        let mut fonts = BTreeMap::new();
+        let mut font_instances = BTreeMap::new();
+        let mut images = BTreeMap::new();
+
+        for update in resource_updates {
+            match update {
+                ResourceUpdate::AddFont(f) => {
+                    // the fonts registered during layout are parsed without outlines
+                    if let Some(parsed) = ParsedFont::from_bytes(f.font_bytes.as_ref(), f.font_index as usize, true) {
+                        fonts.insert(f.key, parsed);
+                    }
+                },
+                ResourceUpdate::AddFontInstance(fi) => {
+                    font_instances.insert(fi.key, (fi.font_key, fi.glyph_size.0.into_px()));
+                },
+                ResourceUpdate::AddImage(i) => {
+                    if let Some(pixmap) = translate_image_data(&i.descriptor, &i.data) {
+                        images.insert(i.key, pixmap);
+                    }
+                },
+                ResourceUpdate::UpdateImage(i) => {
+                    if let Some(pixmap) = translate_image_data(&i.descriptor, &i.data) {
+                        images.insert(i.key, pixmap);
+                    }
+                },
+                ResourceUpdate::DeleteFont(k) => { fonts.remove(k); },
+                ResourceUpdate::DeleteFontInstance(k) => { font_instances.remove(k); },
+                ResourceUpdate::DeleteImage(k) => { images.remove(k); },
+            }
+        }
+
+        Self { fonts, font_instances, images }
+    }
+}
+
+/// Converts the (BGRA8, premultiplied) image data of an `AddImage` into a pixmap
+fn translate_image_data(descriptor: &ImageDescriptor, data: &ImageData) -> Option<Pixmap> {
+
+    let bytes = match data {
+        ImageData::Raw(r) => r.as_ref(),
+        ImageData::External(_) => return None,
+    };
+
+    let mut pixmap = Pixmap::new(descriptor.width as u32, descriptor.height as u32)?;
+    let stride = descriptor.stride.into_option().map(|s| s as usize);
+    let offset = descriptor.offset as usize;
+
+    let (bpp, swap_rb) = match descriptor.format {
+        RawImageFormat::BGRA8 => (4, true),
+        RawImageFormat::RGBA8 => (4, false),
+        RawImageFormat::R8 => (1, false),
+        _ => return None,
+    };
+
+    let width = descriptor.width;
+    let stride = stride.unwrap_or(width * bpp);
+    let target = pixmap.data_mut();
+
+    for y in 0..descriptor.height {
+        let row = bytes.get((offset + y * stride)..(offset + y * stride + width * bpp))?;
+        for x in 0..width {
+            let src = &row[(x * bpp)..((x + 1) * bpp)];
+            let (r, g, b, a) = match (bpp, swap_rb) {
+                (1, _) => (src[0], src[0], src[0], 255),
+                (_, true) => (src[2], src[1], src[0], src[3]),
+                (_, false) => (src[0], src[1], src[2], src[3]),
+            };
+            let dst = &mut target[((y * width + x) * 4)..((y * width + x + 1) * 4)];
+            // premultiplied colors must never exceed the alpha value
+            dst.copy_from_slice(&[r.min(a), g.min(a), b.min(a), a]);
+        }
+    }
+
+    Some(pixmap)
+}
+
+struct Renderer {
+    resources: RenderResources,
+    hidpi_factor: f32,
+    width: u32,
+    height: u32,
+}
+
+/// Last positioned ancestor: absolute items are placed relative to it
+type PositionedItem = (Transform, Option<ClipMask>);
+
+impl Renderer {
+
+    fn draw_msg(
+        &self,
+        canvas: &mut Pixmap,
+        msg: &DisplayListMsg,
+        parent_transform: Transform,
+        parent_clip: Option<&ClipMask>,
+        positioned_items: &mut Vec<PositionedItem>,
+        root_transform: Transform,
+    ) {
+        use azul_core::display_list::DisplayListMsg::*;
+
+        let position = msg.get_position();
+
+        let (x_offset, y_offset, base_transform, base_clip) = match &position {
+            PositionInfo::Static(p) | PositionInfo::Relative(p) => {
+                (p.x_offset, p.y_offset, parent_transform, parent_clip.cloned())
+            },
+            PositionInfo::Absolute(p) => {
+                let (t, c) = positioned_items.last().cloned().unwrap_or((root_transform, None));
+                (p.x_offset, p.y_offset, t, c)
+            },
+            PositionInfo::Fixed(p) => (p.x_offset, p.y_offset, root_transform, None),
+        };
+
+        let mut transform = base_transform.pre_translate(x_offset, y_offset);
+        if let Some((_, t)) = msg.get_transform_key() {
+            transform = transform.pre_concat(translate_transform(t));
+        }
+
+        let opacity = msg.get_opacity_key().map(|(_, o)| *o).unwrap_or(1.0);
+        let blend_mode = msg.get_mix_blend_mode().copied().unwrap_or_default();
+        let needs_layer = opacity < 1.0 || blend_mode != StyleMixBlendMode::Normal;
+
+        if opacity <= 0.0 {
+            return;
+        }
+
+        let mut layer = if needs_layer { Pixmap::new(self.width, self.height) } else { None };
+
+        if position.is_positioned() {
+            positioned_items.push((transform, base_clip.clone()));
+        }
+
+        {
+            let target = match layer.as_mut() {
+                Some(s) => s,
+                None => &mut *canvas,
+            };
+
+            match msg {
+                IFrame(_, clip_size, _, cached_display_list) => {
+                    let clip = intersect_clip(
+                        base_clip.as_ref(),
+                        &PathBuilder::from_rect(Rect::from_xywh(0.0, 0.0, clip_size.width, clip_size.height).unwrap_or(Rect::from_xywh(0.0, 0.0, 1.0, 1.0).unwrap())),
+                        transform,
+                        self.width,
+                        self.height,
+                    );
+                    // iframes have their own stack of positioned items
+                    self.draw_msg(target, &cached_display_list.root, transform, clip.as_ref(), &mut Vec::new(), transform);
+                },
+                Frame(f) => {
+                    let content_clip = self.draw_frame_content(target, f, transform, base_clip.as_ref());
+                    let children_clip = match f.clip_children {
+                        Some(_) => content_clip,
+                        None => base_clip.clone(),
+                    };
+                    for child in f.children.iter() {
+                        self.draw_msg(target, child, transform, children_clip.as_ref(), positioned_items, root_transform);
+                    }
+                },
+                ScrollFrame(sf) => {
+                    // only the children scroll, the frame itself stays in place
+                    let content_clip = self.draw_frame_content(target, &sf.frame, transform, base_clip.as_ref());
+                    let scroll_transform = transform.pre_translate(
+                        sf.content_rect.origin.x - sf.parent_rect.origin.x,
+                        sf.content_rect.origin.y - sf.parent_rect.origin.y,
+                    );
+                    for child in sf.frame.children.iter() {
+                        self.draw_msg(target, child, scroll_transform, content_clip.as_ref(), positioned_items, root_transform);
+                    }
+                },
+            }
+        }
+
+        if position.is_positioned() {
+            positioned_items.pop();
+        }
+
+        if let Some(layer) = layer {
+            let paint = PixmapPaint {
+                opacity,
+                blend_mode: translate_blend_mode(blend_mode),
+                quality: FilterQuality::Nearest,
+            };
+            canvas.draw_pixmap(0, 0, layer.as_ref(), &paint, Transform::identity(), None);
+        }
+    }
+
+    /// Paints the content of a single frame, returns the clip of the
+    /// frame (the rounded rect intersected with the parent clip)
+    fn draw_frame_content(
+        &self,
+        canvas: &mut Pixmap,
+        frame: &DisplayListFrame,
+        transform: Transform,
+        parent_clip: Option<&ClipMask>,
+    ) -> Option<ClipMask> {
+
+        let size = frame.size;
+        let radii = BorderRadii::new(&frame.border_radius, size);
+        let bounds = LogicalRect::new(LogicalPosition::zero(), size);
+        let frame_path = rounded_rect_path(bounds, &radii);
+
+        let mut content_clip: Option<Option<ClipMask>> = None;
+        let (width, height) = (self.width, self.height);
+        let mut get_content_clip = |content_clip: &mut Option<Option<ClipMask>>| -> Option<ClipMask> {
+            content_clip.get_or_insert_with(|| match frame_path.as_ref() {
+                Some(p) => intersect_clip(parent_clip, p, transform, width, height),
+                None => parent_clip.cloned(),
+            }).clone()
+        };
+
+        if let Some(box_shadow) = frame.box_shadow.as_ref() {
+            // outset shadows are painted below the content and are not clipped by the item
+            if box_shadow.clip_mode == BoxShadowClipMode::Outset {
+                self.draw_box_shadow(canvas, bounds, BoxShadowClipMode::Outset, box_shadow, &radii, transform, parent_clip);
+            }
+        }
+
+        for content in frame.content.iter() {
+            match content {
+                LayoutRectContent::Text { glyphs, font_instance_key, color, overflow, text_shadow, .. } => {
+                    let clip = if overflow.0 || overflow.1 { get_content_clip(&mut content_clip) } else { parent_clip.cloned() };
+                    self.draw_text(canvas, glyphs, *font_instance_key, *color, text_shadow.as_ref(), transform, clip.as_ref());
+                },
+                LayoutRectContent::Background { content, size: background_size, offset, repeat } => {
+                    let clip = get_content_clip(&mut content_clip);
+                    self.draw_background(canvas, bounds, content, *background_size, *offset, *repeat, transform, clip.as_ref());
+                },
+                LayoutRectContent::Image { size: image_size, offset, image_key, background_color, .. } => {
+                    let clip = get_content_clip(&mut content_clip);
+                    let area = LogicalRect::new(*offset, size);
+                    self.draw_image(canvas, *image_key, area, *image_size, SpreadMode::Repeat, f32::from(background_color.a) / 255.0, transform, clip.as_ref());
+                },
+                LayoutRectContent::Border { widths, colors, styles } => {
+                    // borders are always inside of the item, no clip necessary
+                    self.draw_border(canvas, bounds, &radii, widths, colors, styles, transform, parent_clip);
+                },
+            }
+        }
+
+        if let Some(box_shadow) = frame.box_shadow.as_ref() {
+            if box_shadow.clip_mode == BoxShadowClipMode::Inset {
+                let clip = get_content_clip(&mut content_clip);
+                self.draw_box_shadow(canvas, bounds, BoxShadowClipMode::Inset, box_shadow, &radii, transform, clip.as_ref());
+            }
+        }
+
+        get_content_clip(&mut content_clip)
+    }
+
+    // -- text
+
+    fn draw_text(
+        &self,
+        canvas: &mut Pixmap,
+        glyphs: &[GlyphInstance],
+        font_instance_key: FontInstanceKey,
+        color: ColorU,
+        text_shadow: Option<&StyleBoxShadow>,
+        transform: Transform,
+        clip: Option<&ClipMask>,
+    ) {
+        let (font_key, font_size) = match self.resources.font_instances.get(&font_instance_key) {
+            Some(s) => *s,
+            None => return,
+        };
+
+        let font = match self.resources.fonts.get(&font_key) {
+            Some(s) => s,
+            None => return,
+        };
+
+        let units_per_em = f32::from(font.font_metrics.units_per_em.max(1));
+        let scale = font_size / units_per_em;
+
+        // color glyph layers are already split into separate
+        // runs by the display list, so every run has one color
+        let mut text_path = PathBuilder::new();
+        for glyph in glyphs {
+            push_glyph_outline(&mut text_path, font, glyph.index as u16, glyph.point, scale);
+        }
+
+        let text_path = match text_path.finish() {
+            Some(s) => s,
+            None => return,
+        };
+
+        if let Some(shadow) = text_shadow {
+            let shadow_transform = transform.pre_translate(shadow.offset[0].to_pixels(), shadow.offset[1].to_pixels());
+            fill_path_blurred(canvas, &text_path, shadow.color, shadow.blur_radius.to_pixels(), shadow_transform, clip, None);
+        }
+
+        fill_path(canvas, &text_path, color, FillRule::Winding, transform, clip);
+    }
+
+    // -- backgrounds
+
+    fn draw_background(
+        &self,
+        canvas: &mut Pixmap,
+        bounds: LogicalRect,
+        background: &RectBackground,
+        background_size: Option<StyleBackgroundSize>,
+        background_position: Option<StyleBackgroundPosition>,
+        background_repeat: Option<StyleBackgroundRepeat>,
+        transform: Transform,
+        clip: Option<&ClipMask>,
+    ) {
+        let size = bounds.size;
+        let background_size = calculate_background_size(size, background_size, background.get_content_size());
+        let offset = calculate_background_position(size, background_position.unwrap_or_default(), background_size);
+
+        if background_size.width <= 0.0 || background_size.height <= 0.0 {
+            return;
+        }
+
+        match background {
+            RectBackground::Color(c) => {
+                let rect = LogicalRect::new(offset, background_size);
+                if let Some(r) = translate_rect(rect) {
+                    fill_path(canvas, &PathBuilder::from_rect(r), *c, FillRule::Winding, transform, clip);
+                }
+            },
+            RectBackground::LinearGradient(g) => {
+                let area = LogicalRect::new(offset, size);
+                for_each_tile(area, background_size, |tile| {
+                    if let Some(shader) = linear_gradient_shader(g, tile) {
+                        fill_rect_with_shader(canvas, tile, shader, transform, clip);
+                    }
+                });
+            },
+            RectBackground::RadialGradient(g) => {
+                let area = LogicalRect::new(offset, size);
+                for_each_tile(area, background_size, |tile| {
+                    if let Some(shader) = radial_gradient_shader(g, tile) {
+                        fill_rect_with_shader(canvas, tile, shader, transform, clip);
+                    }
+                });
+            },
+            RectBackground::ConicGradient(g) => {
+                let device_scale = transform_scale(transform);
+                let gradient_pixmap = match conic_gradient_pixmap(g, background_size, device_scale) {
+                    Some(s) => s,
+                    None => return,
+                };
+                let area = LogicalRect::new(offset, size);
+                for_each_tile(area, background_size, |tile| {
+                    let shader = Pattern::new(
+                        gradient_pixmap.as_ref(),
+                        SpreadMode::Pad,
+                        FilterQuality::Bilinear,
+                        1.0,
+                        Transform::from_translate(tile.origin.x, tile.origin.y).pre_scale(1.0 / device_scale, 1.0 / device_scale),
+                    );
+                    fill_rect_with_shader(canvas, tile, shader, transform, clip);
+                });
+            },
+            RectBackground::Image((image_key, _)) => {
+                let area = match background_repeat.unwrap_or_default() {
+                    StyleBackgroundRepeat::NoRepeat => LogicalRect::new(offset, background_size),
+                    StyleBackgroundRepeat::Repeat => LogicalRect::new(offset, size),
+                    StyleBackgroundRepeat::RepeatX => LogicalRect::new(offset, LogicalSize::new(size.width, background_size.height)),
+                    StyleBackgroundRepeat::RepeatY => LogicalRect::new(offset, LogicalSize::new(background_size.width, size.height)),
+                };
+                self.draw_image(canvas, *image_key, area, background_size, SpreadMode::Repeat, 1.0, transform, clip);
+            },
+        }
+    }
+
+    // -- images
+
+    /// Fills `area` with the image, stretched to `image_size` and repeated from the area origin
+    fn draw_image(
+        &self,
+        canvas: &mut Pixmap,
+        image_key: ImageKey,
+        area: LogicalRect,
+        image_size: LogicalSize,
+        spread_mode: SpreadMode,
+        opacity: f32,
+        transform: Transform,
+        clip: Option<&ClipMask>,
+    ) {
+        let image = match self.resources.images.get(&image_key) {
+            Some(s) => s,
+            None => return,
+        };
+
+        if image_size.width <= 0.0 || image_size.height <= 0.0 {
+            return;
+        }
+
+        let shader = Pattern::new(
+            image.as_ref(),
+            spread_mode,
+            FilterQuality::Bilinear,
+            opacity,
+            Transform::from_translate(area.origin.x, area.origin.y).pre_scale(
+                image_size.width / image.width() as f32,
+                image_size.height / image.height() as f32,
+            ),
+        );
+
+        fill_rect_with_shader(canvas, area, shader, transform, clip);
+    }
+
+    // -- borders
+
+    fn draw_border(
+        &self,
+        canvas: &mut Pixmap,
+        bounds: LogicalRect,
+        radii: &BorderRadii,
+        widths: &StyleBorderWidths,
+        colors: &StyleBorderColors,
+        styles: &StyleBorderStyles,
+        transform: Transform,
+        clip: Option<&ClipMask>,
+    ) {
+        let hidpi = self.hidpi_factor;
+        let size = bounds.size;
+
+        // see wr_translate: round border widths to whole device pixels
+        let snap = |w: Option<f32>| w.map(|w| (w * hidpi).floor() / hidpi).unwrap_or(0.0).max(0.0);
+
+        let widths = SideWidths {
+            top: snap(widths.top.and_then(|w| w.get_property_or_default()).map(|w| w.inner.to_pixels(size.height))),
+            right: snap(widths.right.and_then(|w| w.get_property_or_default()).map(|w| w.inner.to_pixels(size.width))),
+            bottom: snap(widths.bottom.and_then(|w| w.get_property_or_default()).map(|w| w.inner.to_pixels(size.height))),
+            left: snap(widths.left.and_then(|w| w.get_property_or_default()).map(|w| w.inner.to_pixels(size.width))),
+        };
+
+        let sides = [
+            (Side::Top, widths.top, styles.top.map(|s| s.map_property(|s| s.inner)), colors.top.and_then(|c| c.get_property_or_default()).map(|c| c.inner)),
+            (Side::Right, widths.right, styles.right.map(|s| s.map_property(|s| s.inner)), colors.right.and_then(|c| c.get_property_or_default()).map(|c| c.inner)),
+            (Side::Bottom, widths.bottom, styles.bottom.map(|s| s.map_property(|s| s.inner)), colors.bottom.and_then(|c| c.get_property_or_default()).map(|c| c.inner)),
+            (Side::Left, widths.left, styles.left.map(|s| s.map_property(|s| s.inner)), colors.left.and_then(|c| c.get_property_or_default()).map(|c| c.inner)),
+        ];
+
+        for (side, width, style, color) in sides.iter() {
+
+            let style = match get_border_style_normalized(*style) {
+                Some(s) => s,
+                None => continue,
+            };
+
+            if *width <= 0.0 || style == BorderStyleNoNone::Hidden {
+                continue;
+            }
+
+            let color = color.unwrap_or_default();
+            let side_clip = match side_clip_path(*side, bounds, &widths) {
+                Some(p) => intersect_clip(clip, &p, transform, self.width, self.height),
+                None => continue,
+            };
+            let side_clip = side_clip.as_ref();
+
+            // 3D styles: the top / left sides get one shade, the bottom / right ones the other
+            let is_top_left = *side == Side::Top || *side == Side::Left;
+            let (darker, lighter) = (shade_color(color, 2.0 / 3.0), shade_color(color, 4.0 / 3.0));
+
+            match style {
+                BorderStyleNoNone::Solid => {
+                    fill_border_ring(canvas, bounds, radii, &widths, 0.0, 1.0, color, transform, side_clip);
+                },
+                BorderStyleNoNone::Double => {
+                    fill_border_ring(canvas, bounds, radii, &widths, 0.0, 1.0 / 3.0, color, transform, side_clip);
+                    fill_border_ring(canvas, bounds, radii, &widths, 2.0 / 3.0, 1.0, color, transform, side_clip);
+                },
+                BorderStyleNoNone::Inset | BorderStyleNoNone::Outset => {
+                    let dark = (style == BorderStyleNoNone::Inset) == is_top_left;
+                    let c = if dark { darker } else { lighter };
+                    fill_border_ring(canvas, bounds, radii, &widths, 0.0, 1.0, c, transform, side_clip);
+                },
+                BorderStyleNoNone::Groove | BorderStyleNoNone::Ridge => {
+                    let dark = (style == BorderStyleNoNone::Groove) == is_top_left;
+                    let (outer, inner) = if dark { (darker, lighter) } else { (lighter, darker) };
+                    fill_border_ring(canvas, bounds, radii, &widths, 0.0, 0.5, outer, transform, side_clip);
+                    fill_border_ring(canvas, bounds, radii, &widths, 0.5, 1.0, inner, transform, side_clip);
+                },
+                BorderStyleNoNone::Dotted | BorderStyleNoNone::Dashed => {
+                    let center_rect = deflate_rect(bounds, &widths, 0.5);
+                    let center_radii = radii.deflate(&widths, 0.5);
+                    let path = match rounded_rect_path(center_rect, &center_radii) {
+                        Some(s) => s,
+                        None => continue,
+                    };
+                    let (dash, line_cap) = match style {
+                        BorderStyleNoNone::Dotted => (StrokeDash::new(vec![0.0, *width * 2.0], 0.0), LineCap::Round),
+                        _ => (StrokeDash::new(vec![*width * 2.0, *width * 2.0], 0.0), LineCap::Butt),
+                    };
+                    let stroke = Stroke { width: *width, line_cap, dash, ..Stroke::default() };
+                    let paint = solid_paint(color);
+                    let _ = canvas.stroke_path(&path, &paint, &stroke, transform, side_clip);
+                },
+                BorderStyleNoNone::Hidden => { },
+            }
+        }
+    }
+
+    // -- box shadows
+
+    /// Port of `wr_translate::box_shadow::push_box_shadow`: one shadow side,
+    /// two opposite sides or all four sides (using the top shadow) are supported
+    fn draw_box_shadow(
+        &self,
+        canvas: &mut Pixmap,
+        bounds: LogicalRect,
+        shadow_type: BoxShadowClipMode,
+        box_shadow: &BoxShadow,
+        radii: &BorderRadii,
+        transform: Transform,
+        clip: Option<&ClipMask>,
+    ) {
+        fn translate_shadow_side(input: &Option<CssPropertyValue<StyleBoxShadow>>) -> Option<StyleBoxShadow> {
+            input.and_then(|prop| prop.get_property().cloned())
+        }
+
+        let top = translate_shadow_side(&box_shadow.top);
+        let right = translate_shadow_side(&box_shadow.right);
+        let bottom = translate_shadow_side(&box_shadow.bottom);
+        let left = translate_shadow_side(&box_shadow.left);
+
+        match [top, right, bottom, left].iter().filter(|s| s.is_some()).count() {
+            1 => {
+                let (shadow, side) = match (top, right, bottom, left) {
+                    (Some(s), None, None, None) => (s, Side::Top),
+                    (None, Some(s), None, None) => (s, Side::Right),
+                    (None, None, Some(s), None) => (s, Side::Bottom),
+                    (None, None, None, Some(s)) => (s, Side::Left),
+                    _ => return,
+                };
+                self.draw_single_box_shadow_edge(canvas, &shadow, side, bounds, radii, shadow_type, transform, clip);
+            },
+            2 => {
+                match (top, right, bottom, left) {
+                    (Some(t), None, Some(b), None) => {
+                        self.draw_single_box_shadow_edge(canvas, &t, Side::Top, bounds, radii, shadow_type, transform, clip);
+                        self.draw_single_box_shadow_edge(canvas, &b, Side::Bottom, bounds, radii, shadow_type, transform, clip);
+                    },
+                    (None, Some(r), None, Some(l)) => {
+                        self.draw_single_box_shadow_edge(canvas, &l, Side::Left, bounds, radii, shadow_type, transform, clip);
+                        self.draw_single_box_shadow_edge(canvas, &r, Side::Right, bounds, radii, shadow_type, transform, clip);
+                    },
+                    _ => return, // reachable, but invalid
+                }
+            },
+            4 => {
+                // assumes that all box shadows are the same, so just use the top shadow
+                if let Some(shadow) = top {
+                    self.draw_box_shadow_inner(canvas, &shadow, bounds, None, radii, shadow_type, transform, clip);
+                }
+            },
+            _ => return,
+        }
+    }
+
+    fn draw_single_box_shadow_edge(
+        &self,
+        canvas: &mut Pixmap,
+        shadow: &StyleBoxShadow,
+        side: Side,
+        bounds: LogicalRect,
+        radii: &BorderRadii,
+        shadow_type: BoxShadowClipMode,
+        transform: Transform,
+        clip: Option<&ClipMask>,
+    ) {
+        let is_inset_shadow = shadow.clip_mode == BoxShadowClipMode::Inset;
+        let origin_displace = (shadow.spread_radius.to_pixels() + shadow.blur_radius.to_pixels()) * 2.0;
+
+        let mut shadow_bounds = bounds;
+        let mut clip_rect = bounds;
+
+        match side {
+            Side::Top | Side::Bottom => {
+                clip_rect.size.height = origin_displace;
+                shadow_bounds.size.width += origin_displace;
+                shadow_bounds.origin.x -= origin_displace / 2.0;
+            },
+            Side::Left | Side::Right => {
+                clip_rect.size.width = origin_displace;
+                shadow_bounds.size.height += origin_displace;
+                shadow_bounds.origin.y -= origin_displace / 2.0;
+            },
+        }
+
+        match (side, is_inset_shadow) {
+            (Side::Top, false) => clip_rect.origin.y -= origin_displace,
+            (Side::Bottom, false) => clip_rect.origin.y += bounds.size.height,
+            (Side::Bottom, true) => clip_rect.origin.y += bounds.size.height - origin_displace,
+            (Side::Left, false) => clip_rect.origin.x -= origin_displace,
+            (Side::Right, false) => clip_rect.origin.x += bounds.size.width,
+            (Side::Right, true) => clip_rect.origin.x += bounds.size.width - origin_displace,
+            (Side::Top, true) | (Side::Left, true) => { },
+        }
+
+        self.draw_box_shadow_inner(canvas, shadow, shadow_bounds, Some(clip_rect), radii, shadow_type, transform, clip);
+    }
+
+    fn draw_box_shadow_inner(
+        &self,
+        canvas: &mut Pixmap,
+        shadow: &StyleBoxShadow,
+        bounds: LogicalRect,
+        clip_rect: Option<LogicalRect>,
+        radii: &BorderRadii,
+        shadow_type: BoxShadowClipMode,
+        transform: Transform,
+        clip: Option<&ClipMask>,
+    ) {
+        if shadow.clip_mode != shadow_type {
+            return;
+        }
+
+        let clip = match clip_rect.and_then(translate_rect) {
+            Some(r) => intersect_clip(clip, &PathBuilder::from_rect(r), transform, self.width, self.height),
+            None => clip.cloned(),
+        };
+
+        let offset_x = shadow.offset[0].to_pixels();
+        let offset_y = shadow.offset[1].to_pixels();
+        let blur_radius = shadow.blur_radius.to_pixels();
+        let spread = shadow.spread_radius.to_pixels();
+
+        let box_path = match rounded_rect_path(bounds, radii) {
+            Some(s) => s,
+            None => return,
+        };
+
+        match shadow_type {
+            BoxShadowClipMode::Outset => {
+                let shadow_rect = inflate_rect(bounds, spread);
+                let shadow_rect = LogicalRect::new(
+                    LogicalPosition::new(shadow_rect.origin.x + offset_x, shadow_rect.origin.y + offset_y),
+                    shadow_rect.size,
+                );
+                let shadow_path = match rounded_rect_path(shadow_rect, &radii.inflate(spread)) {
+                    Some(s) => s,
+                    None => return,
+                };
+                // the shadow is never visible below the item itself
+                fill_path_blurred(canvas, &shadow_path, shadow.color, blur_radius, transform, clip.as_ref(), Some(&box_path));
+            },
+            BoxShadowClipMode::Inset => {
+                // shadow = everything around the (offset, shrunk) inner rect,
+                // clipped to the item bounds
+                let inner_rect = inflate_rect(bounds, -spread);
+                let inner_rect = LogicalRect::new(
+                    LogicalPosition::new(inner_rect.origin.x + offset_x, inner_rect.origin.y + offset_y),
+                    inner_rect.size,
+                );
+                let outer_rect = inflate_rect(bounds, blur_radius * 2.0 + offset_x.abs().max(offset_y.abs()) + spread.abs() + 1.0);
+
+                let mut pb = PathBuilder::new();
+                if let Some(r) = translate_rect(outer_rect) {
+                    pb.push_rect(r.x(), r.y(), r.width(), r.height());
+                }
+                if let Some(inner_path) = rounded_rect_path(inner_rect, &radii.inflate(-spread)) {
+                    push_path(&mut pb, &inner_path);
+                }
+                let shadow_path = match pb.finish() {
+                    Some(s) => s,
+                    None => return,
+                };
+
+                let clip = intersect_clip(clip.as_ref(), &box_path, transform, self.width, self.height);
+                fill_path_blurred_with_rule(canvas, &shadow_path, FillRule::EvenOdd, shadow.color, blur_radius, transform, clip.as_ref(), None);
+            },
+        }
+    }
+}
+
+// -- geometry helpers
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Side {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct SideWidths {
+    top: f32,
+    right: f32,
+    bottom: f32,
+    left: f32,
+}
+
+/// Resolved (horizontal, vertical) radius of each corner
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct BorderRadii {
+    top_left: (f32, f32),
+    top_right: (f32, f32),
+    bottom_right: (f32, f32),
+    bottom_left: (f32, f32),
+}
+
+impl BorderRadii {
+
+    fn new(radius: &StyleBorderRadius, size: LogicalSize) -> Self {
+
+        // percentages resolve against the width (horizontal) or height (vertical)
+        macro_rules! corner {($field:ident) => ({
+            let r = radius.$field.and_then(|r| r.get_property_or_default()).unwrap_or_default().inner;
+            (r.to_pixels(size.width).max(0.0), r.to_pixels(size.height).max(0.0))
+        })}
+
+        Self {
+            top_left: corner!(top_left),
+            top_right: corner!(top_right),
+            bottom_right: corner!(bottom_right),
+            bottom_left: corner!(bottom_left),
+        }.constrain(size)
+    }
+
+    /// Scales all radii down if adjacent radii would overlap (CSS backgrounds 3, 5.5)
+    fn constrain(self, size: LogicalSize) -> Self {
+        let ratios = [
+            size.width / (self.top_left.0 + self.top_right.0),
+            size.width / (self.bottom_left.0 + self.bottom_right.0),
+            size.height / (self.top_left.1 + self.bottom_left.1),
+            size.height / (self.top_right.1 + self.bottom_right.1),
+        ];
+        let f = ratios.iter().filter(|r| r.is_finite()).fold(1.0_f32, |a, b| a.min(*b)).max(0.0);
+        if f >= 1.0 {
+            return self;
+        }
+        let s = |c: (f32, f32)| (c.0 * f, c.1 * f);
+        Self {
+            top_left: s(self.top_left),
+            top_right: s(self.top_right),
+            bottom_right: s(self.bottom_right),
+            bottom_left: s(self.bottom_left),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        [self.top_left, self.top_right, self.bottom_right, self.bottom_left]
+        .iter().all(|(h, v)| *h <= 0.0 || *v <= 0.0)
+    }
+
+    fn inflate(&self, amount: f32) -> Self {
+        let i = |c: (f32, f32)| if c.0 <= 0.0 || c.1 <= 0.0 { c } else { ((c.0 + amount).max(0.0), (c.1 + amount).max(0.0)) };
+        Self {
+            top_left: i(self.top_left),
+            top_right: i(self.top_right),
+            bottom_right: i(self.bottom_right),
+            bottom_left: i(self.bottom_left),
+        }
+    }
+
+    /// Radii of the rect that is `fraction * widths` inside of the border edge
+    fn deflate(&self, widths: &SideWidths, fraction: f32) -> Self {
+        let d = |c: (f32, f32), h: f32, v: f32| ((c.0 - h * fraction).max(0.0), (c.1 - v * fraction).max(0.0));
+        Self {
+            top_left: d(self.top_left, widths.left, widths.top),
+            top_right: d(self.top_right, widths.right, widths.top),
+            bottom_right: d(self.bottom_right, widths.right, widths.bottom),
+            bottom_left: d(self.bottom_left, widths.left, widths.bottom),
+        }
+    }
+}
+
+fn inflate_rect(rect: LogicalRect, amount: f32) -> LogicalRect {
+    LogicalRect::new(
+        LogicalPosition::new(rect.origin.x - amount, rect.origin.y - amount),
+        LogicalSize::new(rect.size.width + amount * 2.0, rect.size.height + amount * 2.0),
+    )
+}
+
+fn deflate_rect(rect: LogicalRect, widths: &SideWidths, fraction: f32) -> LogicalRect {
+    LogicalRect::new(
+        LogicalPosition::new(rect.origin.x + widths.left * fraction, rect.origin.y + widths.top * fraction),
+        LogicalSize::new(
+            rect.size.width - (widths.left + widths.right) * fraction,
+            rect.size.height - (widths.top + widths.bottom) * fraction,
+        ),
+    )
+}
+
+fn translate_rect(rect: LogicalRect) -> Option<Rect> {
+    Rect::from_xywh(rect.origin.x, rect.origin.y, rect.size.width, rect.size.height)
+}
+
+fn rounded_rect_path(rect: LogicalRect, radii: &BorderRadii) -> Option<Path> {
+
+    if radii.is_zero() {
+        return translate_rect(rect).map(PathBuilder::from_rect);
+    }
+
+    if rect.size.width <= 0.0 || rect.size.height <= 0.0 {
+        return None;
+    }
+
+    // control point distance for approximating a quarter ellipse with a cubic curve
+    const KAPPA: f32 = 0.552_284_8;
+
+    let (x0, y0) = (rect.origin.x, rect.origin.y);
+    let (x1, y1) = (x0 + rect.size.width, y0 + rect.size.height);
+    let (tl, tr, br, bl) = (radii.top_left, radii.top_right, radii.bottom_right, radii.bottom_left);
+
+    let mut pb = PathBuilder::new();
+    pb.move_to(x0 + tl.0, y0);
+    pb.line_to(x1 - tr.0, y0);
+    pb.cubic_to(x1 - tr.0 * (1.0 - KAPPA), y0, x1, y0 + tr.1 * (1.0 - KAPPA), x1, y0 + tr.1);
+    pb.line_to(x1, y1 - br.1);
+    pb.cubic_to(x1, y1 - br.1 * (1.0 - KAPPA), x1 - br.0 * (1.0 - KAPPA), y1, x1 - br.0, y1);
+    pb.line_to(x0 + bl.0, y1);
+    pb.cubic_to(x0 + bl.0 * (1.0 - KAPPA), y1, x0, y1 - bl.1 * (1.0 - KAPPA), x0, y1 - bl.1);
+    pb.line_to(x0, y0 + tl.1);
+    pb.cubic_to(x0, y0 + tl.1 * (1.0 - KAPPA), x0 + tl.0 * (1.0 - KAPPA), y0, x0 + tl.0, y0);
+    pb.close();
+    pb.finish()
+}
+
+/// Appends all segments of `path` to the builder
+fn push_path(pb: &mut PathBuilder, path: &Path) {
+    use tiny_skia::PathSegment;
+    for segment in path.segments() {
+        match segment {
+            PathSegment::MoveTo(p) => pb.move_to(p.x, p.y),
+            PathSegment::LineTo(p) => pb.line_to(p.x, p.y),
+            PathSegment::QuadTo(p1, p) => pb.quad_to(p1.x, p1.y, p.x, p.y),
+            PathSegment::CubicTo(p1, p2, p) => pb.cubic_to(p1.x, p1.y, p2.x, p2.y, p.x, p.y),
+            PathSegment::Close => pb.close(),
+        }
+    }
+}
+
+/// Trapezoid from the outer corners to the inner corners of one border side,
+/// used to split the border ring into the four (differently styled) sides
+fn side_clip_path(side: Side, bounds: LogicalRect, widths: &SideWidths) -> Option<Path> {
+
+    let (x0, y0) = (bounds.origin.x, bounds.origin.y);
+    let (x1, y1) = (x0 + bounds.size.width, y0 + bounds.size.height);
+    let (ix0, iy0) = (x0 + widths.left, y0 + widths.top);
+    let (ix1, iy1) = (x1 - widths.right, y1 - widths.bottom);
+
+    let points = match side {
+        Side::Top => [(x0, y0), (x1, y0), (ix1, iy0), (ix0, iy0)],
+        Side::Right => [(x1, y0), (x1, y1), (ix1, iy1), (ix1, iy0)],
+        Side::Bottom => [(x1, y1), (x0, y1), (ix0, iy1), (ix1, iy1)],
+        Side::Left => [(x0, y1), (x0, y0), (ix0, iy0), (ix0, iy1)],
+    };
+
+    let mut pb = PathBuilder::new();
+    pb.move_to(points[0].0, points[0].1);
+    for (x, y) in points[1..].iter() {
+        pb.line_to(*x, *y);
+    }
+    pb.close();
+    pb.finish()
+}
+
+/// Fills the part of the border between `start` and `end` (fractions of the border widths)
+fn fill_border_ring(
+    canvas: &mut Pixmap,
+    bounds: LogicalRect,
+    radii: &BorderRadii,
+    widths: &SideWidths,
+    start: f32,
+    end: f32,
+    color: ColorU,
+    transform: Transform,
+    clip: Option<&ClipMask>,
+) {
+    let mut pb = PathBuilder::new();
+    if let Some(outer) = rounded_rect_path(deflate_rect(bounds, widths, start), &radii.deflate(widths, start)) {
+        push_path(&mut pb, &outer);
+    }
+    if let Some(inner) = rounded_rect_path(deflate_rect(bounds, widths, end), &radii.deflate(widths, end)) {
+        push_path(&mut pb, &inner);
+    }
+    if let Some(path) = pb.finish() {
+        fill_path(canvas, &path, color, FillRule::EvenOdd, transform, clip);
+    }
+}
+
+fn get_border_style_normalized(style: Option<CssPropertyValue<BorderStyle>>) -> Option<BorderStyleNoNone> {
+    style.and_then(|s| s.get_property_or_default()).and_then(|s| s.normalize_border())
+}
+
+fn shade_color(color: ColorU, factor: f32) -> ColorU {
+    let s = |c: u8| (f32::from(c) * factor).round().min(255.0).max(0.0) as u8;
+    ColorU { r: s(color.r), g: s(color.g), b: s(color.b), a: color.a }
+}
+
+/// Appends the outline of a glyph (font units, y-up) at the baseline origin `point`
+fn push_glyph_outline(pb: &mut PathBuilder, font: &ParsedFont, glyph_index: u16, point: LogicalPosition, scale: f32) {
+
+    let outline = match font.glyph_records_decoded.get(&glyph_index).and_then(|g| g.outline.as_ref()) {
+        Some(s) => s,
+        None => return,
+    };
+
+    let x = |v: f32| point.x + v * scale;
+    let y = |v: f32| point.y - v * scale;
+
+    for op in outline.operations.as_ref().iter() {
+        match op {
+            GlyphOutlineOperation::MoveTo(m) => pb.move_to(x(m.x), y(m.y)),
+            GlyphOutlineOperation::LineTo(l) => pb.line_to(x(l.x), y(l.y)),
+            GlyphOutlineOperation::QuadraticCurveTo(q) => pb.quad_to(x(q.ctrl_1_x), y(q.ctrl_1_y), x(q.end_x), y(q.end_y)),
+            GlyphOutlineOperation::CubicCurveTo(c) => pb.cubic_to(
+                x(c.ctrl_1_x), y(c.ctrl_1_y),
+                x(c.ctrl_2_x), y(c.ctrl_2_y),
+                x(c.end_x), y(c.end_y),
+            ),
+            GlyphOutlineOperation::ClosePath => pb.close(),
+        }
+    }
+}
+
+// -- background helpers (see wr_translate::background)
+
+/// Transform a background size such as "cover" or "contain" into actual pixels
+fn calculate_background_size(
+    rect_size: LogicalSize,
+    bg_size: Option<StyleBackgroundSize>,
+    content_size: Option<(f32, f32)>,
+) -> LogicalSize {
+
+    let content_size = content_size.unwrap_or((rect_size.width, rect_size.height));
+
+    let bg_size = match bg_size {
+        None => return LogicalSize::new(content_size.0, content_size.1),
+        Some(s) => s,
+    };
+
+    let ratio = match bg_size {
+        StyleBackgroundSize::ExactSize([w, h]) => {
+            let w = w.to_pixels(rect_size.width);
+            let h = h.to_pixels(rect_size.height);
+            w.min(h)
+        },
+        StyleBackgroundSize::Contain => (rect_size.width / content_size.0).min(rect_size.height / content_size.1),
+        StyleBackgroundSize::Cover => (rect_size.width / content_size.0).max(rect_size.height / content_size.1),
+    };
+
+    LogicalSize::new(content_size.0 * ratio, content_size.1 * ratio)
+}
+
+/// Transform a background-position attribute into pixel coordinates
+fn calculate_background_position(
+    rect_size: LogicalSize,
+    background_position: StyleBackgroundPosition,
+    background_size: LogicalSize,
+) -> LogicalPosition {
+
+    use azul_css::{BackgroundPositionHorizontal, BackgroundPositionVertical};
+
+    let (width, height) = (rect_size.width, rect_size.height);
+
+    let horizontal_offset = match background_position.horizontal {
+        BackgroundPositionHorizontal::Left => 0.0,
+        BackgroundPositionHorizontal::Center => (width - background_size.width) / 2.0,
+        BackgroundPositionHorizontal::Right => width - background_size.width,
+        BackgroundPositionHorizontal::Exact(e) => e.to_pixels(width),
+    };
+
+    let vertical_offset = match background_position.vertical {
+        BackgroundPositionVertical::Top => 0.0,
+        BackgroundPositionVertical::Center => (height - background_size.height) / 2.0,
+        BackgroundPositionVertical::Bottom => height - background_size.height,
+        BackgroundPositionVertical::Exact(e) => e.to_pixels(height),
+    };
+
+    LogicalPosition::new(horizontal_offset, vertical_offset)
+}
+
+/// Calls `f` for every `tile_size` tile that starts inside of `area`
+fn for_each_tile<F: FnMut(LogicalRect)>(area: LogicalRect, tile_size: LogicalSize, mut f: F) {
+
+    if tile_size.width <= 0.0 || tile_size.height <= 0.0 {
+        return;
+    }
+
+    let columns = (area.size.width / tile_size.width).ceil().max(1.0) as usize;
+    let rows = (area.size.height / tile_size.height).ceil().max(1.0) as usize;
+
+    if columns.saturating_mul(rows) > MAX_BACKGROUND_TILES {
+        return;
+    }
+
+    for row in 0..rows {
+        for column in 0..columns {
+            f(LogicalRect::new(
+                LogicalPosition::new(
+                    area.origin.x + column as f32 * tile_size.width,
+                    area.origin.y + row as f32 * tile_size.height,
+                ),
+                tile_size,
+            ));
+        }
+    }
+}
+
+fn linear_gradient_shader(gradient: &LinearGradient, tile: LogicalRect) -> Option<Shader<'static>> {
+
+    let stops = gradient.stops.as_ref().iter().map(|s| {
+        GradientStop::new(s.offset.get() / 100.0, translate_color(s.color))
+    }).collect::<Vec<_>>();
+
+    if stops.len() < 2 {
+        return None;
+    }
+
+    let (begin, end) = gradient.direction.to_points(&LayoutRect {
+        origin: LayoutPoint { x: tile.origin.x.round() as isize, y: tile.origin.y.round() as isize },
+        size: LayoutSize { width: tile.size.width.round() as isize, height: tile.size.height.round() as isize },
+    });
+
+    tiny_skia::LinearGradient::new(
+        Point::from_xy(begin.x as f32, begin.y as f32),
+        Point::from_xy(end.x as f32, end.y as f32),
+        stops,
+        translate_extend_mode(gradient.extend_mode),
+        Transform::identity(),
+    )
+}
+
+fn radial_gradient_shader(gradient: &RadialGradient, tile: LogicalRect) -> Option<Shader<'static>> {
+
+    let stops = gradient.stops.as_ref().iter().map(|s| {
+        GradientStop::new(s.offset.normalized(), translate_color(s.color))
+    }).collect::<Vec<_>>();
+
+    if stops.len() < 2 {
+        return None;
+    }
+
+    let center = calculate_background_position(tile.size, gradient.position, LogicalSize::zero());
+
+    // the radius is the background size, see wr_translate
+    let (rx, ry) = match gradient.shape {
+        Shape::Ellipse => (tile.size.width, tile.size.height),
+        Shape::Circle => {
+            let largest_bound_size = tile.size.width.max(tile.size.height);
+            (largest_bound_size, largest_bound_size)
+        },
+    };
+
+    // unit circle, scaled to an ellipse by the shader transform
+    tiny_skia::RadialGradient::new(
+        Point::from_xy(0.0, 0.0),
+        Point::from_xy(0.0, 0.0),
+        1.0,
+        stops,
+        translate_extend_mode(gradient.extend_mode),
+        Transform::from_row(rx, 0.0, 0.0, ry, tile.origin.x + center.x, tile.origin.y + center.y),
+    )
+}
+
+/// tiny-skia has no conic gradients, so the gradient is computed per device pixel
+fn conic_gradient_pixmap(gradient: &ConicGradient, tile_size: LogicalSize, device_scale: f32) -> Option<Pixmap> {
+
+    // to_degrees() wraps 360deg to 0deg, unwrap the stops so they stay monotonic
+    let mut last_offset = 0.0;
+    let stops = gradient.stops.as_ref().iter()
+        .map(|s| {
+            let mut offset = s.angle.to_degrees() / 360.0;
+            while offset < last_offset {
+                offset += 1.0;
+            }
+            last_offset = offset;
+            (offset, s.color)
+        })
+        .collect::<Vec<_>>();
+
+    if stops.len() < 2 {
+        return None;
+    }
+
+    let width = (tile_size.width * device_scale).ceil().max(1.0) as u32;
+    let height = (tile_size.height * device_scale).ceil().max(1.0) as u32;
+    let mut pixmap = Pixmap::new(width, height)?;
+
+    let center = calculate_background_position(tile_size, gradient.center, LogicalSize::zero());
+    let start = gradient.angle.to_degrees() / 360.0;
+    let repeat = gradient.extend_mode == ExtendMode::Repeat;
+    let period = stops[stops.len() - 1].0 - stops[0].0;
+
+    let pixels = pixmap.pixels_mut();
+    for y in 0..height {
+        for x in 0..width {
+            let dx = (x as f32 + 0.5) / device_scale - center.x;
+            let dy = (y as f32 + 0.5) / device_scale - center.y;
+            // 0deg points up, angles grow clockwise
+            let angle = dx.atan2(-dy) / (2.0 * core::f32::consts::PI);
+            let mut t = (angle - start).rem_euclid(1.0);
+            if repeat && period > 0.0 {
+                t = stops[0].0 + (t - stops[0].0).rem_euclid(period);
+            }
+            let c = interpolate_stops(&stops, t);
+            pixels[(y * width + x) as usize] = translate_color(c).premultiply().to_color_u8();
+        }
+    }
+
+    Some(pixmap)
+}
+
+fn interpolate_stops(stops: &[(f32, ColorU)], t: f32) -> ColorU {
+
+    let first = stops[0];
+    let last = stops[stops.len() - 1];
+
+    if t <= first.0 {
+        return first.1;
+    }
+    if t >= last.0 {
+        return last.1;
+    }
+
+    for w in stops.windows(2) {
+        let (a, b) = (w[0], w[1]);
+        if t >= a.0 && t <= b.0 {
+            let f = if b.0 > a.0 { (t - a.0) / (b.0 - a.0) } else { 0.0 };
+            let mix = |x: u8, y: u8| (f32::from(x) + (f32::from(y) - f32::from(x)) * f).round() as u8;
+            return ColorU { r: mix(a.1.r, b.1.r), g: mix(a.1.g, b.1.g), b: mix(a.1.b, b.1.b), a: mix(a.1.a, b.1.a) };
+        }
+    }
+
+    last.1
+}
+
+// -- painting helpers
+
+fn solid_paint(color: ColorU) -> Paint<'static> {
+    let mut paint = Paint::default();
+    paint.set_color_rgba8(color.r, color.g, color.b, color.a);
+    paint.anti_alias = true;
+    paint
+}
+
+fn fill_path(canvas: &mut Pixmap, path: &Path, color: ColorU, fill_rule: FillRule, transform: Transform, clip: Option<&ClipMask>) {
+    if color.a == 0 {
+        return;
+    }
+    let _ = canvas.fill_path(path, &solid_paint(color), fill_rule, transform, clip);
+}
+
+fn fill_rect_with_shader(canvas: &mut Pixmap, rect: LogicalRect, shader: Shader, transform: Transform, clip: Option<&ClipMask>) {
+    let rect = match translate_rect(rect) {
+        Some(s) => s,
+        None => return,
+    };
+    let paint = Paint { shader, anti_alias: true, ..Paint::default() };
+    let _ = canvas.fill_path(&PathBuilder::from_rect(rect), &paint, FillRule::Winding, transform, clip);
+}
+
+/// Returns the clip mask of `path` (in local coordinates) intersected with `parent`
+fn intersect_clip(parent: Option<&ClipMask>, path: &Path, transform: Transform, width: u32, height: u32) -> Option<ClipMask> {
+
+    let path = match path.clone().transform(transform) {
+        Some(s) => s,
+        None => return parent.cloned(),
+    };
+
+    let mut mask = match parent {
+        Some(p) => p.clone(),
+        None => {
+            let mut m = ClipMask::new();
+            m.set_path(width, height, &path, FillRule::Winding, true)?;
+            return Some(m);
+        },
+    };
+
+    mask.intersect_path(&path, FillRule::Winding, true)?;
+    Some(mask)
+}
+
+fn fill_path_blurred(
+    canvas: &mut Pixmap,
+    path: &Path,
+    color: ColorU,
+    blur_radius: f32,
+    transform: Transform,
+    clip: Option<&ClipMask>,
+    cutout: Option<&Path>,
+) {
+    fill_path_blurred_with_rule(canvas, path, FillRule::Winding, color, blur_radius, transform, clip, cutout)
+}
+
+/// Fills the path into a temporary layer, blurs the layer (CSS: the blur
+/// radius is twice the standard deviation), optionally erases `cutout`
+/// from it and composites it onto the canvas
+fn fill_path_blurred_with_rule(
+    canvas: &mut Pixmap,
+    path: &Path,
+    fill_rule: FillRule,
+    color: ColorU,
+    blur_radius: f32,
+    transform: Transform,
+    clip: Option<&ClipMask>,
+    cutout: Option<&Path>,
+) {
+    if color.a == 0 {
+        return;
+    }
+
+    let sigma = (blur_radius.max(0.0) / 2.0) * transform_scale(transform);
+    let padding = (sigma * 3.0).ceil() + 1.0;
+
+    let device_bounds = match path.clone().transform(transform) {
+        Some(p) => p.bounds(),
+        None => return,
+    };
+
+    // only blur the part of the layer that can end up on the canvas
+    let left = (device_bounds.left() - padding).floor().max(0.0);
+    let top = (device_bounds.top() - padding).floor().max(0.0);
+    let right = (device_bounds.right() + padding).ceil().min(canvas.width() as f32);
+    let bottom = (device_bounds.bottom() + padding).ceil().min(canvas.height() as f32);
+
+    if right <= left || bottom <= top {
+        return;
+    }
+
+    let mut layer = match Pixmap::new((right - left) as u32, (bottom - top) as u32) {
+        Some(s) => s,
+        None => return,
+    };
+
+    let layer_transform = transform.post_translate(-left, -top);
+    let _ = layer.fill_path(path, &solid_paint(color), fill_rule, layer_transform, None);
+
+    if sigma > 0.0 {
+        gaussian_blur(&mut layer, sigma);
+    }
+
+    if let Some(cutout) = cutout {
+        let mut paint = solid_paint(ColorU { r: 0, g: 0, b: 0, a: 255 });
+        paint.blend_mode = BlendMode::Clear;
+        let _ = layer.fill_path(cutout, &paint, FillRule::Winding, layer_transform, None);
+    }
+
+    let _ = canvas.draw_pixmap(left as i32, top as i32, layer.as_ref(), &PixmapPaint::default(), Transform::identity(), clip);
+}
+
+/// Approximates a gaussian blur with three box blurs (horizontal + vertical)
+fn gaussian_blur(pixmap: &mut Pixmap, sigma: f32) {
+
+    let width = pixmap.width() as usize;
+    let height = pixmap.height() as usize;
+    let data = pixmap.data_mut();
+    let mut scratch = vec![0_u8; data.len()];
+
+    for box_size in box_sizes_for_gauss(sigma).iter() {
+        let radius = (box_size - 1) / 2;
+        box_blur_horizontal(data, &mut scratch, width, height, radius);
+        box_blur_vertical(&scratch, data, width, height, radius);
+    }
+}
+
+/// Sizes of three successive box blurs approximating a gaussian blur with `sigma`
+fn box_sizes_for_gauss(sigma: f32) -> [usize; 3] {
+    const PASSES: f32 = 3.0;
+    let ideal_width = ((12.0 * sigma * sigma / PASSES) + 1.0).sqrt();
+    let mut lower = ideal_width.floor() as i32;
+    if lower % 2 == 0 {
+        lower -= 1;
+    }
+    let lower = lower.max(1);
+    let upper = lower + 2;
+    let ideal_m = (12.0 * sigma * sigma - PASSES * (lower * lower) as f32 - 4.0 * PASSES * lower as f32 - 3.0 * PASSES) / (-4.0 * lower as f32 - 4.0);
+    let m = ideal_m.round() as i32;
+    let size = |i: i32| if i < m { lower as usize } else { upper as usize };
+    [size(0), size(1), size(2)]
+}
+
+fn box_blur_horizontal(src: &[u8], dst: &mut [u8], width: usize, height: usize, radius: usize) {
+    let window = (radius * 2 + 1) as u32;
+    for y in 0..height {
+        let row = y * width * 4;
+        for channel in 0..4 {
+            let at = |x: isize| -> u32 {
+                if x < 0 || x >= width as isize { 0 } else { u32::from(src[row + x as usize * 4 + channel]) }
+            };
+            let mut sum = 0_u32;
+            for x in -(radius as isize)..=(radius as isize) {
+                sum += at(x);
+            }
+            for x in 0..width {
+                dst[row + x * 4 + channel] = ((sum + window / 2) / window) as u8;
+                sum += at(x as isize + radius as isize + 1);
+                sum -= at(x as isize - radius as isize);
+            }
+        }
+    }
+}
+
+fn box_blur_vertical(src: &[u8], dst: &mut [u8], width: usize, height: usize, radius: usize) {
+    let window = (radius * 2 + 1) as u32;
+    for x in 0..width {
+        for channel in 0..4 {
+            let at = |y: isize| -> u32 {
+                if y < 0 || y >= height as isize { 0 } else { u32::from(src[(y as usize * width + x) * 4 + channel]) }
+            };
+            let mut sum = 0_u32;
+            for y in -(radius as isize)..=(radius as isize) {
+                sum += at(y);
+            }
+            for y in 0..height {
+                dst[(y * width + x) * 4 + channel] = ((sum + window / 2) / window) as u8;
+                sum += at(y as isize + radius as isize + 1);
+                sum -= at(y as isize - radius as isize);
+            }
+        }
+    }
+}
+
+// -- translation helpers
+
+fn translate_color(c: ColorU) -> Color {
+    Color::from_rgba8(c.r, c.g, c.b, c.a)
+}
+
+fn translate_extend_mode(e: ExtendMode) -> SpreadMode {
+    match e {
+        ExtendMode::Clamp => SpreadMode::Pad,
+        ExtendMode::Repeat => SpreadMode::Repeat,
+    }
+}
+
+/// Projects the 3D transform onto the 2D plane (ignores the z axis)
+fn translate_transform(t: &ComputedTransform3D) -> Transform {
+    Transform::from_row(t.m[0][0], t.m[0][1], t.m[1][0], t.m[1][1], t.m[3][0], t.m[3][1])
+}
+
+/// Approximate scale factor from local to device pixels
+fn transform_scale(t: Transform) -> f32 {
+    let (sx, ky) = (t.sx, t.ky);
+    (sx * sx + ky * ky).sqrt().max(0.0001)
+}
+
+fn translate_blend_mode(m: StyleMixBlendMode) -> BlendMode {
+    match m {
+        StyleMixBlendMode::Normal => BlendMode::SourceOver,
+        StyleMixBlendMode::Multiply => BlendMode::Multiply,
+        StyleMixBlendMode::Screen => BlendMode::Screen,
+        StyleMixBlendMode::Overlay => BlendMode::Overlay,
+        StyleMixBlendMode::Darken => BlendMode::Darken,
+        StyleMixBlendMode::Lighten => BlendMode::Lighten,
+        StyleMixBlendMode::ColorDodge => BlendMode::ColorDodge,
+        StyleMixBlendMode::ColorBurn => BlendMode::ColorBurn,
+        StyleMixBlendMode::HardLight => BlendMode::HardLight,
+        StyleMixBlendMode::SoftLight => BlendMode::SoftLight,
+        StyleMixBlendMode::Difference => BlendMode::Difference,
+        StyleMixBlendMode::Exclusion => BlendMode::Exclusion,
+        StyleMixBlendMode::Hue => BlendMode::Hue,
+        StyleMixBlendMode::Saturation => BlendMode::Saturation,
+        StyleMixBlendMode::Color => BlendMode::Color,
+        StyleMixBlendMode::Luminosity => BlendMode::Luminosity,
+    }
+}
@@ -98,6 +98,7 @@ pub mod decode {
             height: height as usize,
             premultiplied_alpha: false,
             data_format,
+            tag: Vec::new().into(),
         })
     }
 }
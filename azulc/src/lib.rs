@@ -22,6 +22,10 @@ extern crate azul_layout;
 extern crate rust_fontconfig;
 #[cfg(feature = "image_loading")]
 extern crate image as image_crate;
+#[cfg(feature = "cpurender")]
+extern crate azul_text_layout;
+#[cfg(feature = "cpurender")]
+extern crate tiny_skia;
 
 /// XML-based DOM serialization and XML-to-Rust compiler implementation
 pub mod xml;
@@ -30,6 +34,9 @@ pub mod svg;
 pub mod font;
 #[cfg(feature = "image_loading")]
 pub mod image;
+/// CPU rasterizer for display lists (`azulc --render`)
+#[cfg(feature = "cpurender")]
+pub mod cpurender;
 /// Module for compiling CSS to Rust code
 pub mod css {
     pub use azul_core::css::*;
@@ -6,6 +6,9 @@ use std::fs;
 use std::path::Path;
 use std::process::exit;
 
+use azul_css::ColorU;
+use azulc_lib::image::encode::ResultU8VecEncodeImageError;
+
 use azul_core::{
     gl::OptionGlContextPtr,
     window::FullWindowState,
@@ -18,7 +21,7 @@ use azul_core::{
         IdNamespace, LoadFontFn,
         Epoch, RendererResources,
         ImageCache, GlTextureCache,
-        DpiScaleFactor,
+        DpiScaleFactor, ResourceUpdate,
     },
     display_list::{
         SolvedLayout,
@@ -33,23 +36,33 @@ enum Action {
     PrintHtmlCode,
     PrintStyledDom,
     PrintRustCode,
-    PrintCCode, // unimplemented, does nothing
-    PrintCppCode, // unimplemented, does nothing
-    PrintPythonCode, // unimplemented, does nothing
+    PrintCCode,
+    PrintCppCode,
+    PrintPythonCode,
     PrintDebugLayout(LogicalSize),
     PrintScrollClips(LogicalSize),
     PrintDisplayList(LogicalSize),
+    RenderToPng(LogicalSize, String),
+    Preview,
+    PrintRustLibrary,
+    Check,
 }
 
 fn print_help() {
     eprintln!("usage: azulc [OPTIONS] file.xml");
+    eprintln!("       azulc --library directory");
+    eprintln!("       azulc --check [file.xml | directory]");
     eprintln!("");
     eprintln!("[OPTIONS]:");
     eprintln!("    --language=[rust | c | python | cpp | html]: compile XML file to source code");
     eprintln!("    --debug-layout WIDTHxHEIGHT: print a debug output of the layout solver");
     eprintln!("    --display-list WIDTHxHEIGHT: print the display list given WIDTH and HEIGHT");
     eprintln!("    --scroll-clips WIDTHxHEIGHT: print the overflowing scroll clips given WIDTH and HEIGHT");
+    eprintln!("    --render WIDTHxHEIGHT --output out.png: render the file to a PNG image (on the CPU)");
     eprintln!("    --cascade: print the cascaded styled DOM");
+    eprintln!("    --preview: open a window showing the file, reloads when the file changes");
+    eprintln!("    --library: compile all components of the *.xml files in a directory to one Rust module");
+    eprintln!("    --check: validate the file or all *.xml files in a directory without generating code");
     eprintln!("");
     eprintln!("If OPTIONS is empty, the file will be printed to Rust code");
 }
@@ -73,6 +86,9 @@ fn main() {
     let action = match second_arg.as_ref().map(|s| s.as_str()) {
         Some("--help")                  => Action::PrintHelp,
         Some("--cascade")               => Action::PrintStyledDom,
+        Some("--preview")               => Action::Preview,
+        Some("--library")               => Action::PrintRustLibrary,
+        Some("--check")                 => Action::Check,
         Some("--language=rust")         => Action::PrintRustCode,
         Some("--language=html")         => Action::PrintHtmlCode,
         Some("--language=c")            => Action::PrintCCode,
@@ -114,16 +130,49 @@ fn main() {
             };
             Action::PrintDisplayList(LogicalSize::new(size_parsed.0, size_parsed.1))
         },
+        Some("--render")                => {
+            let size = env::args().nth(2).expect("no output size specified for rendering");
+            let size_parsed = match azulc_lib::parse_display_list_size(&size) {
+                Some(s) => s,
+                None => {
+                    eprintln!("error: render size \"{}\" could not be parsed", size);
+                    print_help();
+                    exit(-1);
+                }
+            };
+            let output_path = match args.iter().position(|a| a == "--output").and_then(|p| args.get(p + 1)) {
+                Some(s) => s.clone(),
+                None => {
+                    eprintln!("error: no output file specified for rendering (--output out.png)");
+                    print_help();
+                    exit(-1);
+                }
+            };
+            Action::RenderToPng(LogicalSize::new(size_parsed.0, size_parsed.1), output_path)
+        },
         _ => Action::PrintRustCode,
     };
 
     process(action, input_file)
 }
 
+/// Prints all errors of the file (with their location in the source) and exits -
+/// prints only the `error` if the checker doesn't find the error
+fn exit_with_diagnostics(input_file: &str, error: &dyn std::fmt::Display) -> ! {
+    let diagnostics = azulc_lib::xml::check_xml_project(Path::new(input_file), XmlComponentMap::default);
+    if diagnostics.is_empty() {
+        eprintln!("error: {}", error);
+    }
+    for diagnostic in diagnostics.iter() {
+        eprintln!("{}\n", diagnostic);
+    }
+    exit(-1);
+}
+
 fn process(action: Action, file: Option<&String>) {
 
     use azul_core::xml::*;
-    use azulc_lib::xml::parse_xml_string;
+    use azulc_lib::xml::{parse_xml_file, parse_xml_directory, register_placeholder_callbacks, check_xml_project, register_image_components};
 
     if action == Action::PrintHelp {
         print_help();
@@ -139,31 +188,52 @@ fn process(action: Action, file: Option<&String>) {
        },
    };
 
-    let file_contents = match fs::read_to_string(input_file.clone()) {
-        Ok(s) => s,
-        Err(e) => {
-            eprintln!("error: could not read file: \"{}\" - error:\r\n{}", input_file, e);
-            exit(-1);
-        },
-    };
+    // the preview window shows parse errors itself, so don't parse the file here
+    if action == Action::Preview {
+        preview(input_file);
+    }
 
-    // parse the XML
-    let root_nodes = match parse_xml_string(&file_contents).ok() {
-        Some(s) => s,
-        None => {
-            eprintln!("error: input could not be parsed as xml");
-            print_help();
+    if action == Action::PrintRustLibrary {
+        let library = match parse_xml_directory(Path::new(input_file)) {
+            Ok(s) => s,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                exit(-1);
+            }
+        };
+        let mut component_map = XmlComponentMap::default();
+        register_image_components(&mut component_map, Path::new(input_file));
+        match str_to_rust_library(&library.root_nodes, "", &mut component_map) {
+            Ok(o) => { println!("{}", o); },
+            Err(e) => { eprintln!("{}", e); exit(-1); },
+        }
+        exit(0);
+    }
+
+    if action == Action::Check {
+        let diagnostics = check_xml_project(Path::new(input_file), XmlComponentMap::default);
+        for diagnostic in diagnostics.iter() {
+            eprintln!("{}\n", diagnostic);
+        }
+        if !diagnostics.is_empty() {
             exit(-1);
         }
+        exit(0);
+    }
+
+    // parse the XML, including the imported files
+    let root_nodes = match parse_xml_file(Path::new(input_file)) {
+        Ok(s) => s.root_nodes,
+        Err(e) => exit_with_diagnostics(input_file, &e),
     };
 
-    let styled_dom = match str_to_dom(root_nodes.as_ref(), &mut XmlComponentMap::default(), None) {
+    // the event handlers are never called, but they have to exist in order to render the DOM
+    let mut component_map = new_component_map(input_file);
+    register_placeholder_callbacks(&root_nodes, &mut component_map);
+
+    let styled_dom = match str_to_dom(root_nodes.as_ref(), &mut component_map, None) {
         Ok(s) => s,
-        Err(e) => {
-            eprintln!("error: could not render DOM:\r\n{}", e);
-            print_help();
-            exit(-1);
-        }
+        Err(e) => exit_with_diagnostics(input_file, &e),
     };
 
     match action {
@@ -178,25 +248,25 @@ fn process(action: Action, file: Option<&String>) {
             println!("{}", styled_dom.get_html_string("", "", false));
         },
         Action::PrintRustCode => {
-            match get_rust_code(root_nodes.as_ref()) {
+            match get_rust_code(root_nodes.as_ref(), input_file) {
                 Ok(o) => { println!("{}", o); },
                 Err(e) => { eprintln!("{}", e); },
             }
         },
         Action::PrintCCode => {
-            match get_c_code(root_nodes.as_ref()) {
+            match get_c_code(root_nodes.as_ref(), input_file) {
                 Ok(o) => { println!("{}", o); },
                 Err(e) => { eprintln!("{}", e); },
             }
         },
         Action::PrintCppCode => {
-            match get_cpp_code(root_nodes.as_ref()) {
+            match get_cpp_code(root_nodes.as_ref(), input_file) {
                 Ok(o) => { println!("{}", o); },
                 Err(e) => { eprintln!("{}", e); },
             }
         },
         Action::PrintPythonCode => {
-            match get_python_code(root_nodes.as_ref()) {
+            match get_python_code(root_nodes.as_ref(), input_file) {
                 Ok(o) => { println!("{}", o); },
                 Err(e) => { eprintln!("{}", e); },
             }
@@ -211,7 +281,7 @@ fn process(action: Action, file: Option<&String>) {
             let mut fake_window_state = FullWindowState::default();
             fake_window_state.size.dimensions = size;
             let mut renderer_resources = RendererResources::default();
-            let layout = solve_layout(styled_dom, size, document_id, epoch, &fake_window_state, &mut renderer_resources);
+            let layout = solve_layout(styled_dom, size, document_id, epoch, &fake_window_state, &mut renderer_resources, &mut Vec::new());
             let layout_debug = layout_result_print_layout(&layout);
             println!("{}", layout_debug);
         },
@@ -224,7 +294,7 @@ fn process(action: Action, file: Option<&String>) {
             let mut fake_window_state = FullWindowState::default();
             fake_window_state.size.dimensions = size;
             let mut renderer_resources = RendererResources::default();
-            let layout = solve_layout(styled_dom, size, document_id, epoch, &fake_window_state, &mut renderer_resources);
+            let layout = solve_layout(styled_dom, size, document_id, epoch, &fake_window_state, &mut renderer_resources, &mut Vec::new());
             println!("{:#?}", layout.scrollable_nodes);
         },
         Action::PrintDisplayList(size) => {
@@ -238,7 +308,7 @@ fn process(action: Action, file: Option<&String>) {
             fake_window_state.size.dimensions = size;
             let mut renderer_resources = RendererResources::default();
             let image_cache = ImageCache::default();
-            let layout = solve_layout(styled_dom, size, document_id, epoch, &fake_window_state, &mut renderer_resources);
+            let layout = solve_layout(styled_dom, size, document_id, epoch, &fake_window_state, &mut renderer_resources, &mut Vec::new());
             let display_list = LayoutResult::get_cached_display_list(
                 &document_id,
                 dom_id,
@@ -252,8 +322,52 @@ fn process(action: Action, file: Option<&String>) {
 
             println!("{:#?}", display_list.root);
         },
-        // Action::DisplayFile => // TODO: open window and show the file,
-        // Action::RenderToPng(output_path) -- TODO!
+        Action::RenderToPng(size, output_path) => {
+            let image = match azulc_lib::cpurender::render_styled_dom(styled_dom, size, ColorU::WHITE) {
+                Some(s) => s,
+                None => {
+                    eprintln!("error: could not render display list with size {}x{}", size.width, size.height);
+                    exit(-1);
+                }
+            };
+
+            let png = match azulc_lib::image::encode::encode_png(&image) {
+                ResultU8VecEncodeImageError::Ok(o) => o,
+                ResultU8VecEncodeImageError::Err(e) => {
+                    eprintln!("error: could not encode PNG: {}", e);
+                    exit(-1);
+                }
+            };
+
+            if let Err(e) = fs::write(&output_path, png.as_ref()) {
+                eprintln!("error: could not write file: \"{}\" - error:\r\n{}", output_path, e);
+                exit(-1);
+            }
+        },
+        Action::Preview | Action::PrintRustLibrary | Action::Check => { },
+    }
+}
+
+/// Starts the `azul-preview` binary (from the azul-dll crate, next to the azulc
+/// binary or in the PATH), which opens the window - azulc can't depend on
+/// azul-desktop, since azul-desktop depends on azulc
+fn preview(input_file: &str) -> ! {
+
+    use std::process::Command;
+
+    let exe_name = format!("azul-preview{}", env::consts::EXE_SUFFIX);
+    let preview_exe = env::current_exe().ok()
+        .and_then(|exe| Some(exe.parent()?.join(&exe_name)))
+        .filter(|p| p.exists())
+        .unwrap_or_else(|| exe_name.into());
+
+    match Command::new(&preview_exe).arg(input_file).status() {
+        Ok(status) => exit(status.code().unwrap_or(-1)),
+        Err(e) => {
+            eprintln!("error: could not start \"{}\" - error:\r\n{}", preview_exe.display(), e);
+            eprintln!("the preview window requires the azul-preview binary: cargo install azul-dll --bin azul-preview");
+            exit(-1);
+        }
     }
 }
 
@@ -263,7 +377,8 @@ fn solve_layout(
     document_id: DocumentId,
     epoch: Epoch,
     fake_window_state: &FullWindowState,
-    renderer_resources: &mut RendererResources
+    renderer_resources: &mut RendererResources,
+    resource_updates: &mut Vec<ResourceUpdate>,
 ) -> LayoutResult {
 
     let fc_cache = azulc_lib::font_loading::build_font_cache();
@@ -276,13 +391,12 @@ fn solve_layout(
     };
 
     // Solve the layout (the extra parameters are necessary because of IFrame recursion)
-    let mut resource_updates = Vec::new();
     let mut solved_layout = SolvedLayout::new(
         styled_dom,
         epoch,
         &document_id,
         &fake_window_state,
-        &mut resource_updates,
+        resource_updates,
         IdNamespace(0),
         &image_cache,
         &fc_cache,
@@ -332,18 +446,26 @@ fn layout_result_print_layout(result: &LayoutResult) -> String {
     s
 }
 
-fn get_rust_code(root_nodes: &[XmlNode]) -> Result<String, String> {
-    azul_core::xml::str_to_rust_code(root_nodes, "", &mut XmlComponentMap::default()).map_err(|e| format!("{}", e))
+/// Returns the built-in components, `<img src="...">` paths are relative to the input file
+fn new_component_map(input_file: &str) -> XmlComponentMap {
+    let mut component_map = XmlComponentMap::default();
+    let base_dir = Path::new(input_file).parent().unwrap_or_else(|| Path::new(""));
+    azulc_lib::xml::register_image_components(&mut component_map, base_dir);
+    component_map
+}
+
+fn get_rust_code(root_nodes: &[XmlNode], input_file: &str) -> Result<String, String> {
+    azul_core::xml::str_to_rust_code(root_nodes, "", &mut new_component_map(input_file)).map_err(|e| format!("{}", e))
 }
 
-fn get_c_code(root_nodes: &[XmlNode]) -> Result<String, String> {
-    Ok(String::new()) // TODO
+fn get_c_code(root_nodes: &[XmlNode], input_file: &str) -> Result<String, String> {
+    azul_core::xml::str_to_c_code(root_nodes, &mut new_component_map(input_file)).map_err(|e| format!("{}", e))
 }
 
-fn get_cpp_code(root_nodes: &[XmlNode]) -> Result<String, String> {
-    Ok(String::new()) // TODO
+fn get_cpp_code(root_nodes: &[XmlNode], input_file: &str) -> Result<String, String> {
+    azul_core::xml::str_to_cpp_code(root_nodes, &mut new_component_map(input_file)).map_err(|e| format!("{}", e))
 }
 
-fn get_python_code(root_nodes: &[XmlNode]) -> Result<String, String> {
-    Ok(String::new()) // TODO
+fn get_python_code(root_nodes: &[XmlNode], input_file: &str) -> Result<String, String> {
+    azul_core::xml::str_to_python_code(root_nodes, &mut new_component_map(input_file)).map_err(|e| format!("{}", e))
 }
\ No newline at end of file
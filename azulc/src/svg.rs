@@ -1942,6 +1942,7 @@ pub fn svg_render(s: &Svg, options: SvgRenderOptions) -> Option<RawImage> {
         height: target_height as usize,
         premultiplied_alpha: true,
         data_format: RawImageFormat::RGBA8,
+        tag: Vec::new().into(),
     })
 }
 
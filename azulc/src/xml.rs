@@ -7,16 +7,18 @@ use alloc::string::String;
 use alloc::boxed::Box;
 use azul_core::{
     impl_from,
+    app_resources::ImageRef,
+    callbacks::{CallbackInfo, RefAny, Update},
     dom::Dom,
     styled_dom::StyledDom,
     window::StringPairVec,
 };
 use azul_css::{AzString, U8Vec, OptionAzString, Css};
-use azul_css_parser::{CssParseError, CssApiWrapper};
+use azul_css_parser::{CssParseError, CssApiWrapper, ErrorLocation};
 use xmlparser::Tokenizer;
 
 #[cfg(feature = "std")]
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 pub use azul_core::xml::*;
 
@@ -41,7 +43,8 @@ pub fn domxml_from_str(xml: &str, component_map: &mut XmlComponentMap) -> DomXml
     DomXml { parsed_dom }
 }
 
-/// Loads, parses and builds a DOM from an XML file
+/// Loads, parses and builds a DOM from an XML file (including its `<import />` and `<link />` files),
+/// `<img src="..." />` paths are relative to the directory of the file
 ///
 /// **Warning**: The file is reloaded from disk on every function call - do not
 /// use this in release builds! This function deliberately never fails: In an error case,
@@ -49,22 +52,25 @@ pub fn domxml_from_str(xml: &str, component_map: &mut XmlComponentMap) -> DomXml
 #[cfg(all(feature = "std", feature = "xml"))]
 pub fn domxml_from_file<I: AsRef<Path>>(file_path: I, component_map: &mut XmlComponentMap) -> DomXml {
 
-    use std::fs;
-
     let mut error_css = CssApiWrapper::empty();
 
-    let xml = match fs::read_to_string(file_path.as_ref()) {
-        Ok(xml) => xml,
+    let xml_file = match parse_xml_file(file_path.as_ref()) {
+        Ok(xml_file) => xml_file,
+        Err(e) => return DomXml {
+            parsed_dom: Dom::body().with_children(vec![Dom::text(format!("{}", e))].into()).style(error_css.clone()),
+        },
+    };
+
+    register_image_components(component_map, file_path.as_ref().parent().unwrap_or_else(|| Path::new("")));
+
+    let parsed_dom = match str_to_dom(xml_file.root_nodes.as_ref(), component_map, None) {
+        Ok(o) => o,
         Err(e) => return DomXml {
-            parsed_dom: Dom::body()
-            .with_children(vec![
-                Dom::text(format!("Error reading: \"{}\": {}", file_path.as_ref().to_string_lossy(), e))
-            ].into())
-            .style(error_css.clone()),
+            parsed_dom: Dom::body().with_children(vec![Dom::text(format!("{}", e))].into()).style(error_css.clone()),
         },
     };
 
-    domxml_from_str(&xml, component_map)
+    DomXml { parsed_dom }
 }
 
 /// Parses the XML string into an XML tree, returns
@@ -98,6 +104,7 @@ pub fn parse_xml_string(xml: &str) -> Result<Vec<XmlNode>, XmlError> {
     };
 
     let mut root_node = XmlNode::default();
+    let source = xml;
 
     // Search for "<?xml" and "?>" tags and delete them from the XML
     let mut xml = xml.trim();
@@ -117,17 +124,73 @@ pub fn parse_xml_string(xml: &str) -> Result<Vec<XmlNode>, XmlError> {
         xml = &xml[(pos + 1)..];
     }
 
-    let tokenizer = Tokenizer::from_fragment(xml, 0..xml.len());
+    // the tokenizer only parses the trimmed string, but the positions refer to the whole source
+    let offset = xml.as_ptr() as usize - source.as_ptr() as usize;
+    let tokenizer = Tokenizer::from_fragment(source, offset..(offset + xml.len()));
 
     // In order to insert where the item is, let's say
     // [0 -> 1st element, 5th-element -> node]
     // we need to trach the index of the item in the parent.
     let mut current_hierarchy: Vec<usize> = Vec::new();
 
+    // Inline <svg> nodes are not parsed into child nodes: the source of the whole <svg>
+    // is stored in the "source" attribute, so it can be rendered to an image later on.
+    // 0 = outside of an <svg>, 1 = inside of the <svg> node, 2 = inside of its children, ...
+    let mut svg_depth = 0_usize;
+    let mut svg_has_namespace = false;
+
     for token in tokenizer {
         let token = token.map_err(|e| XmlError::ParserError(translate_xmlparser_error(e)))?;
+
+        if svg_depth > 0 {
+            match token {
+                ElementStart { .. } => { svg_depth += 1; },
+                ElementEnd { end: Close(..), span } | ElementEnd { end: Empty, span } if svg_depth == 1 => {
+                    if let Some(svg) = get_item(&current_hierarchy, &mut root_node) {
+                        let svg_source = &source[svg.span.start..span.end()];
+                        // HTML doesn't require the namespace on inline SVGs, the SVG parser does
+                        let svg_source = match svg_has_namespace {
+                            true => svg_source.to_string(),
+                            false => svg_source.replacen("<svg", "<svg xmlns=\"http://www.w3.org/2000/svg\"", 1),
+                        };
+                        svg.attributes.push(azul_core::window::AzStringPair {
+                            key: "source".into(),
+                            value: svg_source.into(),
+                        });
+                    }
+                    current_hierarchy.pop();
+                    svg_depth = 0;
+                },
+                ElementEnd { end: Open, span } if svg_depth == 1 => {
+                    if let Some(svg) = get_item(&current_hierarchy, &mut root_node) {
+                        svg.span.end = span.end();
+                    }
+                },
+                ElementEnd { end: Close(..), .. } | ElementEnd { end: Empty, .. } => { svg_depth -= 1; },
+                Attribute { prefix, local, .. } if svg_depth == 1 && prefix.is_empty() && local.as_str() == "xmlns" => {
+                    svg_has_namespace = true;
+                },
+                // only keep the id, class and style of the <svg> itself, all
+                // other attributes are only relevant for rendering the SVG
+                Attribute { prefix, local, value, .. } if svg_depth == 1 && prefix.is_empty() && DEFAULT_ARGS.contains(&local.as_str()) => {
+                    if let Some(svg) = get_item(&current_hierarchy, &mut root_node) {
+                        svg.attributes.push(azul_core::window::AzStringPair {
+                            key: local.to_string().into(),
+                            value: value.as_str().to_string().into(),
+                        });
+                    }
+                },
+                _ => { },
+            }
+            continue;
+        }
+
         match token {
-            ElementStart { local, .. } => {
+            ElementStart { local, span, .. } => {
+                if local.as_str() == "svg" {
+                    svg_depth = 1;
+                    svg_has_namespace = false;
+                }
                 if let Some(current_parent) = get_item(&current_hierarchy, &mut root_node) {
                     let children_len = current_parent.children.len();
                     current_parent.children.push(XmlNode {
@@ -135,34 +198,55 @@ pub fn parse_xml_string(xml: &str) -> Result<Vec<XmlNode>, XmlError> {
                         attributes: StringPairVec::new(),
                         children: Vec::new().into(),
                         text: None.into(),
+                        span: XmlSpan {
+                            file: 0,
+                            start: span.start(),
+                            end: span.end(),
+                            pos: get_text_pos(source, span.start()),
+                        },
                     });
                     current_hierarchy.push(children_len);
                 }
             }
-            ElementEnd { end: Empty, .. } => {
+            ElementEnd { end: Open, span } => {
+                if let Some(last) = get_item(&current_hierarchy, &mut root_node) {
+                    last.span.end = span.end();
+                }
+            }
+            ElementEnd { end: Empty, span } => {
+                if let Some(last) = get_item(&current_hierarchy, &mut root_node) {
+                    last.span.end = span.end();
+                }
                 current_hierarchy.pop();
             }
             ElementEnd {
                 end: Close(_, close_value),
-                ..
+                span,
             } => {
                 let i = get_item(&current_hierarchy, &mut root_node);
                 if let Some(last) = i {
                     if last.node_type.as_str() != close_value.as_str() {
-                        return Err(XmlError::MalformedHierarchy(
-                            close_value.to_string().into(),
-                            last.node_type.clone(),
-                        ));
+                        return Err(XmlError::UnexpectedCloseTag(UnexpectedCloseTagError {
+                            expected: last.node_type.clone(),
+                            actual: close_value.to_string().into(),
+                            pos: get_text_pos(source, span.start()),
+                        }));
                     }
                 }
                 current_hierarchy.pop();
             }
-            Attribute { local, value, .. } => {
+            Attribute { prefix, local, value, .. } => {
                 if let Some(last) = get_item(&current_hierarchy, &mut root_node) {
+                    // keep the namespace prefix, "on:click" binds an event handler
+                    let key = if prefix.is_empty() {
+                        local.to_string()
+                    } else {
+                        format!("{}:{}", prefix.as_str(), local.as_str())
+                    };
                     // NOTE: Only lowercase the key ("local"), not the value!
                     last.attributes
                         .push(azul_core::window::AzStringPair {
-                            key: local.to_string().into(), 
+                            key: key.into(), 
                             value: value.as_str().to_string().into(),
                         });
                 }
@@ -189,6 +273,11 @@ pub fn parse_xml_string(xml: &str) -> Result<Vec<XmlNode>, XmlError> {
     Ok(root_node.children.into())
 }
 
+/// Returns the line and column (both starting at 1) of the byte `offset` in the `source`
+pub fn get_text_pos(source: &str, offset: usize) -> XmlTextPos {
+    let (row, col) = ErrorLocation { original_pos: offset }.get_line_column_from_error(source);
+    XmlTextPos { row: row as u32, col: col as u32 }
+}
 
 #[cfg(feature = "xml")]
 pub fn parse_xml(s: &str) -> Result<Xml, XmlError> {
@@ -200,6 +289,817 @@ pub fn parse_xml(s: &str) -> Result<Xml, XmlError> {
     Err(XmlError::NoParserAvailable)
 }
 
+/// Called with the name of the event handler when a placeholder callback is invoked
+pub type PlaceholderCallbackHook = fn(handler: &str);
+
+/// Registers a placeholder callback for every `on:event="handler_name"` handler that
+/// is not registered in the `component_map` yet, so that the XML can be rendered
+/// without the application code (i.e. in the preview). The placeholder only prints
+/// the name of the handler to stderr when it is called.
+#[cfg(feature = "std")]
+pub fn register_placeholder_callbacks(root_nodes: &[XmlNode], component_map: &mut XmlComponentMap) {
+    register_placeholder_callbacks_with_hook(root_nodes, component_map, print_placeholder_call);
+}
+
+/// Same as `register_placeholder_callbacks`, but calls the `hook` instead of printing
+/// the name of the handler (i.e. in order to log it or show it in the UI)
+#[cfg(feature = "std")]
+pub fn register_placeholder_callbacks_with_hook(
+    root_nodes: &[XmlNode],
+    component_map: &mut XmlComponentMap,
+    hook: PlaceholderCallbackHook,
+) {
+    for handler in get_event_handler_names(root_nodes, component_map) {
+        if component_map.callbacks.get_callback(&handler).is_none() {
+            let data = RefAny::new(PlaceholderCallback { handler: handler.clone(), hook });
+            component_map.register_callback(&handler, data, placeholder_callback);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+struct PlaceholderCallback {
+    handler: String,
+    hook: PlaceholderCallbackHook,
+}
+
+#[cfg(feature = "std")]
+extern "C" fn placeholder_callback(data: &mut RefAny, _: &mut CallbackInfo) -> Update {
+    if let Some(placeholder) = data.downcast_ref::<PlaceholderCallback>() {
+        (placeholder.hook)(&placeholder.handler);
+    }
+    Update::DoNothing
+}
+
+#[cfg(feature = "std")]
+fn print_placeholder_call(handler: &str) {
+    eprintln!("event handler \"{}\" called", handler);
+}
+
+/// Registers the `<img src="..." />` and the inline `<svg>` components in the `component_map`:
+/// both are rendered as a `NodeType::Image` that can be sized by CSS. The `src` of an `<img>`
+/// (a raster image or an SVG file) is resolved relative to `base_dir`, the directory of the XML
+/// file. In the compiled Rust code, the image is embedded via `include_bytes!("src")` instead.
+#[cfg(feature = "std")]
+pub fn register_image_components(component_map: &mut XmlComponentMap, base_dir: &Path) {
+    component_map.register_component(XmlComponent {
+        id: normalize_casing("img"),
+        renderer: Box::new(ImageRenderer::new(base_dir)),
+        inherit_vars: false,
+    });
+    component_map.register_component(XmlComponent {
+        id: normalize_casing("svg"),
+        renderer: Box::new(SvgRenderer::new()),
+        inherit_vars: false,
+    });
+}
+
+/// Render for an `<img src="..." />` component
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageRenderer {
+    node: XmlNode,
+    /// Directory that the `src` of the image is relative to
+    base_dir: PathBuf,
+}
+
+#[cfg(feature = "std")]
+impl ImageRenderer {
+    pub fn new(base_dir: &Path) -> Self {
+        Self {
+            node: XmlNode::new("img"),
+            base_dir: base_dir.to_path_buf(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl XmlComponentTrait for ImageRenderer {
+    fn get_available_arguments(&self) -> ComponentArguments {
+        ComponentArguments {
+            args: vec![("src".to_string(), FILE_ARGUMENT_TYPE.to_string())],
+            accepts_text: false,
+        }
+    }
+
+    fn render_dom(
+        &self,
+        _: &XmlComponentMap,
+        arguments: &FilteredComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<StyledDom, RenderDomError> {
+        let src = arguments.values.get("src").map(|s| s.as_str()).unwrap_or("");
+        let invalid = |reason: String| ComponentError::InvalidImage(src.to_string().into(), reason.into());
+        if src.is_empty() {
+            return Err(invalid("<img> without \"src\" attribute".to_string()).into());
+        }
+        let bytes = std::fs::read(self.base_dir.join(src)).map_err(|e| invalid(e.to_string()))?;
+        let image = decode_image(&bytes).map_err(invalid)?;
+        Ok(Dom::image(image).style(CssApiWrapper::empty()))
+    }
+
+    fn compile_to_rust_code(
+        &self,
+        _: &XmlComponentMap,
+        _: &ComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<String, CompileError> {
+        Ok(format!(
+            "let image = match azul::image::RawImage::decode_image_bytes_any(src) {{\r\n    \
+                azul::error::ResultRawImageDecodeImageError::Ok(image) => azul::option::OptionRawImage::Some(image),\r\n    \
+                // not a raster image, try to render it as an SVG\r\n    \
+                azul::error::ResultRawImageDecodeImageError::Err(_) => {},\r\n\
+            }};\r\n{}",
+            compile_svg_render("src").replace("\r\n", "\r\n    "),
+            COMPILED_IMAGE_DOM,
+        ))
+    }
+
+    fn get_xml_node(&self) -> XmlNode {
+        self.node.clone()
+    }
+}
+
+/// Render for an inline `<svg>` component: the parser stores the
+/// source of the whole `<svg>` node in the `source` argument
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgRenderer {
+    node: XmlNode,
+}
+
+#[cfg(feature = "std")]
+impl SvgRenderer {
+    pub fn new() -> Self {
+        Self {
+            node: XmlNode::new("svg"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl XmlComponentTrait for SvgRenderer {
+    fn get_available_arguments(&self) -> ComponentArguments {
+        ComponentArguments {
+            args: vec![("source".to_string(), BYTES_ARGUMENT_TYPE.to_string())],
+            accepts_text: false,
+        }
+    }
+
+    fn render_dom(
+        &self,
+        _: &XmlComponentMap,
+        arguments: &FilteredComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<StyledDom, RenderDomError> {
+        let source = arguments.values.get("source").map(|s| s.as_str()).unwrap_or("");
+        let image = render_svg(source.as_bytes())
+            .map_err(|reason| ComponentError::InvalidImage(AzString::from_const_str(""), reason.into()))?;
+        Ok(Dom::image(image).style(CssApiWrapper::empty()))
+    }
+
+    fn compile_to_rust_code(
+        &self,
+        _: &XmlComponentMap,
+        _: &ComponentArguments,
+        _: &XmlTextContent,
+    ) -> Result<String, CompileError> {
+        Ok(format!("let image = {};\r\n{}", compile_svg_render("source"), COMPILED_IMAGE_DOM))
+    }
+
+    fn get_xml_node(&self) -> XmlNode {
+        self.node.clone()
+    }
+}
+
+/// Rust code that parses the SVG bytes in the variable `bytes` and renders it to an `OptionRawImage`
+#[cfg(feature = "std")]
+fn compile_svg_render(bytes: &str) -> String {
+    format!(
+        "match azul::svg::Svg::from_bytes({}, azul::svg::SvgParseOptions::default()) {{\r\n    \
+            azul::error::ResultSvgSvgParseError::Ok(svg) => svg.render(azul::svg::SvgRenderOptions {{\r\n        \
+                transform: azul::svg::SvgRenderTransform {{ sx: 1.0, kx: 0.0, ky: 0.0, sy: 1.0, tx: 0.0, ty: 0.0 }},\r\n        \
+                .. azul::svg::SvgRenderOptions::default()\r\n    \
+            }}),\r\n    \
+            azul::error::ResultSvgSvgParseError::Err(_) => azul::option::OptionRawImage::None,\r\n\
+        }}",
+        bytes
+    )
+}
+
+/// Rust code that turns the `OptionRawImage` in the variable `image` into
+/// an image node (or an empty `div` if the image could not be decoded)
+#[cfg(feature = "std")]
+const COMPILED_IMAGE_DOM: &str = "match image {\r\n    \
+    azul::option::OptionRawImage::Some(image) => match azul::image::ImageRef::raw_image(image) {\r\n        \
+        azul::option::OptionImageRef::Some(image) => Dom::image(image),\r\n        \
+        azul::option::OptionImageRef::None => Dom::div(),\r\n    \
+    },\r\n    \
+    azul::option::OptionRawImage::None => Dom::div(),\r\n\
+}";
+
+/// Decodes the bytes of an image file: SVG files are rendered at their own size (needs the
+/// `svg` feature), all other files are decoded as raster images (needs `image_loading`)
+#[cfg(feature = "std")]
+fn decode_image(bytes: &[u8]) -> Result<ImageRef, String> {
+
+    if bytes.iter().find(|b| !b.is_ascii_whitespace()) == Some(&b'<') {
+        return render_svg(bytes);
+    }
+
+    #[cfg(feature = "image_loading")] {
+        use crate::image::decode::{decode_raw_image_from_any_bytes, ResultRawImageDecodeImageError};
+        match decode_raw_image_from_any_bytes(bytes) {
+            ResultRawImageDecodeImageError::Ok(image) => ImageRef::new_rawimage(image).ok_or_else(|| "invalid image data".to_string()),
+            ResultRawImageDecodeImageError::Err(e) => Err(e.to_string()),
+        }
+    }
+
+    #[cfg(not(feature = "image_loading"))] {
+        Err("no image decoder available (missing \"image_loading\" feature)".to_string())
+    }
+}
+
+/// Parses an SVG file and renders it at its own size
+#[cfg(feature = "std")]
+fn render_svg(bytes: &[u8]) -> Result<ImageRef, String> {
+    use azul_core::svg::{SvgParseOptions, SvgRenderOptions, SvgRenderTransform};
+
+    let svg = crate::svg::svg_parse(bytes, SvgParseOptions::default()).map_err(|e| e.to_string())?;
+    let options = SvgRenderOptions {
+        transform: SvgRenderTransform { sx: 1.0, kx: 0.0, ky: 0.0, sy: 1.0, tx: 0.0, ty: 0.0 },
+        .. SvgRenderOptions::default()
+    };
+    let image = crate::svg::svg_render(&svg, options).ok_or_else(|| "SVG has no visible content".to_string())?;
+    ImageRef::new_rawimage(image).ok_or_else(|| "invalid image data".to_string())
+}
+
+/// XML document loaded from a file via `parse_xml_file`
+#[cfg(all(feature = "std", feature = "xml"))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct XmlFile {
+    /// Root nodes of the document: the components of all imported files and
+    /// the contents of all linked stylesheets are inlined into the `<head>`
+    pub root_nodes: Vec<XmlNode>,
+    /// All files the document was built from: the file itself,
+    /// all imported XML files and all linked stylesheets
+    /// (`XmlSpan::file` of the nodes is the index in this list)
+    pub files: Vec<PathBuf>,
+}
+
+/// Error that can happen while loading an XML file together with its imports
+#[cfg(all(feature = "std", feature = "xml"))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum XmlImportError {
+    /// A file could not be read: (file, error message)
+    Io(PathBuf, String),
+    /// A file could not be parsed as XML
+    Xml(PathBuf, XmlError),
+    /// `<import />` without `src` or `<link rel="stylesheet" />` without `href`: (file, node type)
+    MissingPath(PathBuf, String),
+    /// A file imports itself (directly or indirectly): the chain of imports,
+    /// starting and ending with the same file
+    ImportCycle(Vec<PathBuf>),
+    /// Two different components are registered under the same name: (component name, file)
+    DuplicateComponent(String, PathBuf),
+}
+
+#[cfg(all(feature = "std", feature = "xml"))]
+impl fmt::Display for XmlImportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::XmlImportError::*;
+        match self {
+            Io(path, e) => write!(f, "Error reading \"{}\": {}", path.display(), e),
+            Xml(path, e) => write!(f, "Error parsing XML in \"{}\": {}", path.display(), e),
+            MissingPath(path, node_type) => match node_type.as_str() {
+                "link" => write!(f, "<link rel=\"stylesheet\" /> in \"{}\" is missing the \"href\" attribute", path.display()),
+                _ => write!(f, "<{} /> in \"{}\" is missing the \"src\" attribute", node_type, path.display()),
+            },
+            ImportCycle(chain) => write!(
+                f,
+                "Import cycle: {}",
+                chain.iter().map(|p| format!("\"{}\"", p.display())).collect::<Vec<_>>().join(" -> "),
+            ),
+            DuplicateComponent(name, path) => write!(
+                f,
+                "Component \"{}\" in \"{}\" is already defined - use <import src=\"...\" prefix=\"...\" /> to avoid name collisions",
+                name, path.display(),
+            ),
+        }
+    }
+}
+
+/// Loads and parses an XML file and resolves its `<import />` and `<link />` nodes
+///
+/// - `<import src="widgets.xml" />` registers all components defined in `widgets.xml`.
+///   With `prefix="ui"`, the component `button` is imported as `ui-button`.
+/// - `<link rel="stylesheet" href="theme.css" />` adds `theme.css` to the global style.
+///
+/// Paths are resolved relative to the file that contains the node. An imported file
+/// can either be a full `<html>` document (the components are taken from its `<head>`)
+/// or a plain list of `<component />` nodes. The resolved components and styles are
+/// inlined into the `<head>` of the returned document, so that it can be passed to
+/// `str_to_dom` or any of the code generators as-is.
+#[cfg(all(feature = "std", feature = "xml"))]
+pub fn parse_xml_file(path: &Path) -> Result<XmlFile, XmlImportError> {
+
+    let mut files = Vec::new();
+    let mut import_stack = vec![canonical_path(path)];
+    let mut root_nodes = read_xml_file(path, &mut files)?;
+
+    let base_dir = path.parent().unwrap_or_else(|| Path::new(""));
+
+    let html_node = root_nodes.iter_mut().find(|n| normalize_casing(&n.node_type).as_str() == "html");
+    let head_node = html_node.and_then(|html| {
+        html.children.as_mut().iter_mut().find(|n| normalize_casing(&n.node_type).as_str() == "head")
+    });
+
+    match head_node {
+        Some(head_node) => {
+            let resolved = resolve_imports(head_node.children.as_ref(), path, base_dir, &mut import_stack, &mut files)?;
+            head_node.children = inline_imports(head_node.children.as_ref(), resolved).into();
+        },
+        None => {
+            // component library without <html> node
+            let resolved = resolve_imports(&root_nodes, path, base_dir, &mut import_stack, &mut files)?;
+            root_nodes = inline_imports(&root_nodes, resolved);
+        },
+    }
+
+    Ok(XmlFile { root_nodes, files })
+}
+
+/// Loads all `*.xml` files in the directory (including their imports) as one component library
+///
+/// The returned root nodes are the `<component />` nodes of all files, so that
+/// they can be passed to `str_to_rust_library`. Components that are imported by
+/// several files only show up once.
+#[cfg(all(feature = "std", feature = "xml"))]
+pub fn parse_xml_directory(dir: &Path) -> Result<XmlFile, XmlImportError> {
+
+    let xml_files = get_xml_files(dir)?;
+
+    let mut files = Vec::new();
+    let mut resolved = ResolvedImports::default();
+
+    for xml_file in xml_files.iter() {
+        let root_nodes = read_xml_file(xml_file, &mut files)?;
+        let base_dir = xml_file.parent().unwrap_or(dir);
+        let mut import_stack = vec![canonical_path(xml_file)];
+        let r = resolve_imports(get_library_nodes(&root_nodes), xml_file, base_dir, &mut import_stack, &mut files)?;
+        resolved.append(r, xml_file)?;
+    }
+
+    Ok(XmlFile {
+        root_nodes: inline_imports(&[], resolved),
+        files,
+    })
+}
+
+/// Returns the `*.xml` files in the directory, sorted by name
+#[cfg(all(feature = "std", feature = "xml"))]
+fn get_xml_files(dir: &Path) -> Result<Vec<PathBuf>, XmlImportError> {
+    let mut xml_files = std::fs::read_dir(dir)
+        .map_err(|e| XmlImportError::Io(dir.to_path_buf(), e.to_string()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| p.is_file() && p.extension().map(|e| e == "xml").unwrap_or(false))
+        .collect::<Vec<_>>();
+
+    xml_files.sort();
+
+    Ok(xml_files)
+}
+
+/// Error in an XML or CSS file, displayed with the location of the error and
+/// the line of the source, with the erroneous part underlined:
+///
+/// ```text
+/// error: Unknown component: "buton"
+///  --> ui/app.xml:5:9
+///   |
+/// 5 |         <buton label="Save" />
+///   |         ^^^^^^^^^^^^^^^^^^^^^^
+/// ```
+#[cfg(all(feature = "std", feature = "xml"))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct XmlDiagnostic {
+    /// File that contains the error
+    pub file: PathBuf,
+    /// Error message (without the location)
+    pub message: String,
+    /// Line and column of the error, `None` if the error isn't tied to a location in the file
+    pub pos: Option<XmlTextPos>,
+    /// Source line of the error and the (column, length) of the underlined part, in characters
+    excerpt: Option<(String, usize, usize)>,
+}
+
+#[cfg(all(feature = "std", feature = "xml"))]
+impl XmlDiagnostic {
+
+    /// Creates a diagnostic for an error that is not tied to a location in the file
+    pub fn new(file: &Path, message: String) -> Self {
+        Self { file: file.to_path_buf(), message, pos: None, excerpt: None }
+    }
+
+    /// Creates a diagnostic for the bytes `start..end` of the `source` of the file -
+    /// only the part of the range on the first line is underlined
+    pub fn with_span(file: &Path, message: String, source: &str, start: usize, end: usize) -> Self {
+        let start = start.min(source.len());
+        let line_start = source[..start].rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let line_end = source[start..].find('\n').map(|i| start + i).unwrap_or(source.len());
+        let line = source[line_start..line_end].trim_end_matches('\r');
+        let underline_end = end.max(start).min(line_start + line.len());
+        let column = source[line_start..start].chars().count();
+        let len = source[start..underline_end].chars().count().max(1);
+
+        Self {
+            file: file.to_path_buf(),
+            message,
+            pos: Some(get_text_pos(source, start)),
+            excerpt: Some((line.to_string(), column, len)),
+        }
+    }
+
+    /// Creates a diagnostic for the character at the line and column `pos` of the `source`
+    pub fn with_pos(file: &Path, message: String, source: &str, pos: XmlTextPos) -> Self {
+        let line_start = source
+            .split_inclusive('\n')
+            .take((pos.row as usize).saturating_sub(1))
+            .map(|line| line.len())
+            .sum::<usize>();
+        let start = source[line_start.min(source.len())..]
+            .char_indices()
+            .nth((pos.col as usize).saturating_sub(1))
+            .map(|(i, _)| line_start + i)
+            .unwrap_or(source.len());
+        Self::with_span(file, message, source, start, start)
+    }
+}
+
+#[cfg(all(feature = "std", feature = "xml"))]
+impl fmt::Display for XmlDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "error: {}", self.message)?;
+
+        let pos = match self.pos {
+            Some(s) => s,
+            None => return write!(f, "\n --> {}", self.file.display()),
+        };
+
+        let gutter = " ".repeat(pos.row.to_string().len());
+        write!(f, "\n{} --> {}:{}:{}", gutter, self.file.display(), pos.row, pos.col)?;
+
+        if let Some((line, column, len)) = self.excerpt.as_ref() {
+            // keep the tabs, so that the underline lines up with the source line
+            let indent = line.chars().take(*column).map(|c| if c == '\t' { '\t' } else { ' ' }).collect::<String>();
+            write!(f, "\n{} |", gutter)?;
+            write!(f, "\n{} | {}", pos.row, line)?;
+            write!(f, "\n{} | {}{}", gutter, indent, "^".repeat(*len))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Validates an XML file - or all `*.xml` files if `path` is a directory - without generating
+/// any code: loads the imports, parses all stylesheets, checks all nodes of the `<body>` and
+/// of all components (see `validate_xml_nodes`) and - if that succeeds - renders the `<body>`
+/// and compiles all components, which checks the values that depend on variables.
+///
+/// Returns the errors of all files instead of stopping at the first error.
+#[cfg(all(feature = "std", feature = "xml"))]
+pub fn check_xml_project(path: &Path, component_map: fn() -> XmlComponentMap) -> Vec<XmlDiagnostic> {
+    let xml_files = if path.is_dir() {
+        match get_xml_files(path) {
+            Ok(o) => o,
+            Err(e) => return vec![XmlDiagnostic::new(path, format!("{}", e))],
+        }
+    } else {
+        vec![path.to_path_buf()]
+    };
+
+    xml_files
+        .iter()
+        .flat_map(|file| check_xml_file(file, component_map))
+        .collect()
+}
+
+#[cfg(all(feature = "std", feature = "xml"))]
+fn check_xml_file(path: &Path, component_map: fn() -> XmlComponentMap) -> Vec<XmlDiagnostic> {
+
+    let xml_file = match parse_xml_file(path) {
+        Ok(o) => o,
+        Err(XmlImportError::Xml(file, e)) => {
+            let source = std::fs::read_to_string(&file).unwrap_or_default();
+            return vec![match e.get_pos() {
+                Some(pos) => XmlDiagnostic::with_pos(&file, format!("{}", e), &source, pos),
+                None => XmlDiagnostic::new(&file, format!("{}", e)),
+            }];
+        },
+        Err(e) => return vec![XmlDiagnostic::new(path, format!("{}", e))],
+    };
+
+    let sources = xml_file.files.iter().map(|f| std::fs::read_to_string(f).unwrap_or_default()).collect::<Vec<_>>();
+    let render_diagnostic = |e: &RenderDomError| match e {
+        RenderDomError::InvalidNode(span, c) if span.pos.row != 0 => {
+            let file = xml_file.files.get(span.file).map(|f| f.as_path()).unwrap_or(path);
+            let source = sources.get(span.file).map(|s| s.as_str()).unwrap_or("");
+            XmlDiagnostic::with_span(file, format!("{}", c), source, span.start, span.end)
+        },
+        other => XmlDiagnostic::new(path, format!("{}", other)),
+    };
+
+    let mut diagnostics = xml_file.files.iter().zip(sources.iter())
+        .flat_map(|(file, source)| check_css(file, source))
+        .collect::<Vec<_>>();
+
+    let new_component_map = || {
+        let mut map = component_map();
+        register_image_components(&mut map, path.parent().unwrap_or_else(|| Path::new("")));
+        register_placeholder_callbacks(&xml_file.root_nodes, &mut map);
+        map
+    };
+
+    // component libraries (files without an <html> node) only contain components
+    let mut library_component_map = new_component_map();
+    if let Err(e) = register_components(get_library_nodes(&xml_file.root_nodes), &mut library_component_map) {
+        diagnostics.push(XmlDiagnostic::new(path, format!("{}", e)));
+        return diagnostics;
+    }
+
+    diagnostics.extend(validate_xml_nodes(&xml_file.root_nodes, &library_component_map).iter().map(render_diagnostic));
+
+    if !diagnostics.is_empty() {
+        return diagnostics;
+    }
+
+    // errors in values that contain variables are only found while rendering
+    if get_html_node(&xml_file.root_nodes).is_ok() {
+        match str_to_dom(&xml_file.root_nodes, &mut new_component_map(), None) {
+            Ok(_) => { },
+            Err(DomXmlParseError::RenderDom(e)) => diagnostics.push(render_diagnostic(&e)),
+            Err(e) => diagnostics.push(XmlDiagnostic::new(path, format!("{}", e))),
+        }
+    }
+
+    match compile_components_to_rust_code(&library_component_map) {
+        Ok(_) => { },
+        Err(CompileError::Dom(e)) => diagnostics.push(render_diagnostic(&e)),
+        Err(e) => diagnostics.push(XmlDiagnostic::new(path, format!("{}", e))),
+    }
+
+    diagnostics
+}
+
+/// Parses a stylesheet or all `<style>` nodes of an XML file, so that
+/// CSS errors can be reported with their location in the file
+#[cfg(all(feature = "std", feature = "xml"))]
+fn check_css(file: &Path, source: &str) -> Vec<XmlDiagnostic> {
+
+    fn check_style_nodes(nodes: &[XmlNode], file: &Path, source: &str, diagnostics: &mut Vec<XmlDiagnostic>) {
+        for node in nodes {
+            if normalize_casing(&node.node_type) == "style" {
+                // the text of the node is trimmed, find the start of the CSS after the start tag
+                let css = node.text.as_ref().map(|t| t.as_str()).unwrap_or("");
+                let offset = source.get(node.span.end..)
+                    .and_then(|s| s.find(css))
+                    .map(|i| node.span.end + i);
+                if let (Err(e), Some(offset)) = (azul_css_parser::new_from_str(css), offset) {
+                    diagnostics.push(css_diagnostic(file, source, offset, &e));
+                }
+            }
+            check_style_nodes(node.children.as_ref(), file, source, diagnostics);
+        }
+    }
+
+    let mut diagnostics = Vec::new();
+
+    if file.extension().map(|e| e == "css").unwrap_or(false) {
+        if let Err(e) = azul_css_parser::new_from_str(source) {
+            diagnostics.push(css_diagnostic(file, source, 0, &e));
+        }
+    } else if let Ok(root_nodes) = parse_xml_string(source) {
+        check_style_nodes(&root_nodes, file, source, &mut diagnostics);
+    }
+
+    diagnostics
+}
+
+/// Creates a diagnostic for a CSS error, `offset` is the byte offset of the CSS in the `source`
+#[cfg(all(feature = "std", feature = "xml"))]
+fn css_diagnostic(file: &Path, source: &str, offset: usize, e: &CssParseError) -> XmlDiagnostic {
+    // the error location starts after the last valid token, skip the whitespace in between
+    let (start, end) = (e.location.0.original_pos, e.location.1.original_pos.max(e.location.0.original_pos));
+    let whitespace = e.css_string.get(start..end).map(|s| s.len() - s.trim_start().len()).unwrap_or(0);
+    XmlDiagnostic::with_span(file, format!("{}", e.error), source, offset + start + whitespace, offset + end)
+}
+
+/// Components and styles collected from the `<import />`, `<link />`,
+/// `<component />` and `<style />` nodes of a file
+#[cfg(all(feature = "std", feature = "xml"))]
+#[derive(Default)]
+struct ResolvedImports {
+    components: Vec<XmlNode>,
+    css: Vec<String>,
+}
+
+#[cfg(all(feature = "std", feature = "xml"))]
+impl ResolvedImports {
+
+    fn add_component(&mut self, component: XmlNode, file: &Path) -> Result<(), XmlImportError> {
+        let name = get_component_name(&component);
+        match self.components.iter().find(|c| get_component_name(c) == name) {
+            // the same file was imported twice (i.e. "a" and "b" both import "c")
+            Some(existing) if *existing == component => Ok(()),
+            Some(_) => Err(XmlImportError::DuplicateComponent(name, file.to_path_buf())),
+            None => {
+                self.components.push(component);
+                Ok(())
+            }
+        }
+    }
+
+    fn append(&mut self, other: Self, file: &Path) -> Result<(), XmlImportError> {
+        for component in other.components {
+            self.add_component(component, file)?;
+        }
+        for css in other.css {
+            if !self.css.contains(&css) {
+                self.css.push(css);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(feature = "std", feature = "xml"))]
+fn read_xml_file(path: &Path, files: &mut Vec<PathBuf>) -> Result<Vec<XmlNode>, XmlImportError> {
+
+    fn set_file_index(node: &mut XmlNode, file: usize) {
+        node.span.file = file;
+        for child in node.children.as_mut().iter_mut() {
+            set_file_index(child, file);
+        }
+    }
+
+    let xml = std::fs::read_to_string(path)
+        .map_err(|e| XmlImportError::Io(path.to_path_buf(), e.to_string()))?;
+
+    // a file that is imported twice keeps its index, so that its components compare as equal
+    let file = match files.iter().position(|f| f == path) {
+        Some(index) => index,
+        None => {
+            files.push(path.to_path_buf());
+            files.len() - 1
+        },
+    };
+
+    let mut root_nodes = parse_xml_string(&xml).map_err(|e| XmlImportError::Xml(path.to_path_buf(), e))?;
+    for node in root_nodes.iter_mut() {
+        set_file_index(node, file);
+    }
+
+    Ok(root_nodes)
+}
+
+#[cfg(all(feature = "std", feature = "xml"))]
+fn canonical_path(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Returns the `<head>` children of a document or the root nodes of a component library
+#[cfg(all(feature = "std", feature = "xml"))]
+fn get_library_nodes(root_nodes: &[XmlNode]) -> &[XmlNode] {
+    get_html_node(root_nodes).ok()
+    .and_then(|html| find_node_by_type(html.children.as_ref(), "head"))
+    .map(|head| head.children.as_ref())
+    .unwrap_or(root_nodes)
+}
+
+#[cfg(all(feature = "std", feature = "xml"))]
+fn get_component_name(component: &XmlNode) -> String {
+    component.attributes.get_key("name").map(|n| normalize_casing(n.as_str())).unwrap_or_default()
+}
+
+#[cfg(all(feature = "std", feature = "xml"))]
+fn resolve_imports(
+    nodes: &[XmlNode],
+    file: &Path,
+    base_dir: &Path,
+    import_stack: &mut Vec<PathBuf>,
+    files: &mut Vec<PathBuf>,
+) -> Result<ResolvedImports, XmlImportError> {
+
+    let mut resolved = ResolvedImports::default();
+
+    for node in nodes {
+        let node_type = normalize_casing(&node.node_type);
+        match node_type.as_str() {
+            "import" => {
+                let src = node.attributes.get_key("src")
+                    .ok_or_else(|| XmlImportError::MissingPath(file.to_path_buf(), node_type.clone()))?;
+                let import_path = base_dir.join(src.as_str());
+                let canonical = canonical_path(&import_path);
+
+                if import_stack.contains(&canonical) {
+                    let mut chain = import_stack.clone();
+                    chain.push(canonical);
+                    return Err(XmlImportError::ImportCycle(chain));
+                }
+
+                let root_nodes = read_xml_file(&import_path, files)?;
+                let import_dir = import_path.parent().unwrap_or(base_dir);
+
+                import_stack.push(canonical);
+                let mut imported = resolve_imports(get_library_nodes(&root_nodes), &import_path, import_dir, import_stack, files)?;
+                import_stack.pop();
+
+                if let Some(prefix) = node.attributes.get_key("prefix") {
+                    imported.components = prefix_components(imported.components, prefix.as_str());
+                }
+
+                resolved.append(imported, file)?;
+            },
+            "link" if node.attributes.get_key("rel").map(|r| r.as_str() == "stylesheet").unwrap_or(false) => {
+                let href = node.attributes.get_key("href")
+                    .ok_or_else(|| XmlImportError::MissingPath(file.to_path_buf(), node_type.clone()))?;
+                let css_path = base_dir.join(href.as_str());
+                let css = std::fs::read_to_string(&css_path)
+                    .map_err(|e| XmlImportError::Io(css_path.clone(), e.to_string()))?;
+                // url() paths in @font-face rules are relative to the stylesheet
+                let css_dir = css_path.parent().unwrap_or(base_dir);
+                resolved.css.push(azul_css_parser::resolve_font_face_urls(&css, &css_dir.to_string_lossy()));
+                if !files.contains(&css_path) {
+                    files.push(css_path);
+                }
+            },
+            "component" => {
+                resolved.add_component(node.clone(), file)?;
+            },
+            "style" => {
+                if let Some(text) = node.text.as_ref() {
+                    resolved.css.push(azul_css_parser::resolve_font_face_urls(text.as_str(), &base_dir.to_string_lossy()));
+                }
+            },
+            _ => { },
+        }
+    }
+
+    Ok(resolved)
+}
+
+/// Renames the components to `prefix-name`, including all usages of the components in each other
+#[cfg(all(feature = "std", feature = "xml"))]
+fn prefix_components(components: Vec<XmlNode>, prefix: &str) -> Vec<XmlNode> {
+
+    fn rename_usages(node: &mut XmlNode, names: &[String], prefix: &str) {
+        for child in node.children.as_mut().iter_mut() {
+            if names.contains(&normalize_casing(&child.node_type)) {
+                child.node_type = format!("{}-{}", prefix, child.node_type.as_str()).into();
+            }
+            rename_usages(child, names, prefix);
+        }
+    }
+
+    let names = components.iter().map(get_component_name).collect::<Vec<_>>();
+
+    components.into_iter().map(|mut component| {
+        if let Some(name) = component.attributes.get_key_mut("name") {
+            name.value = format!("{}-{}", prefix, name.value.as_str()).into();
+        }
+        rename_usages(&mut component, &names, prefix);
+        component
+    }).collect()
+}
+
+/// Replaces the `<import />`, `<link rel="stylesheet" />`, `<component />` and `<style />`
+/// nodes with the resolved components and one `<style />` node containing all styles
+#[cfg(all(feature = "std", feature = "xml"))]
+fn inline_imports(nodes: &[XmlNode], resolved: ResolvedImports) -> Vec<XmlNode> {
+
+    let mut new_nodes = nodes.iter().filter(|n| {
+        match normalize_casing(&n.node_type).as_str() {
+            "import" | "component" | "style" => false,
+            "link" => n.attributes.get_key("rel").map(|r| r.as_str() != "stylesheet").unwrap_or(true),
+            _ => true,
+        }
+    }).cloned().collect::<Vec<_>>();
+
+    new_nodes.extend(resolved.components.into_iter());
+
+    if !resolved.css.is_empty() {
+        new_nodes.push(XmlNode {
+            node_type: "style".into(),
+            attributes: StringPairVec::new(),
+            children: Vec::new().into(),
+            text: Some(resolved.css.join("\n").into()).into(),
+            span: XmlSpan::default(),
+        });
+    }
+
+    new_nodes
+}
+
 // to_string(&self) -> String
 
 #[cfg(feature = "xml")]
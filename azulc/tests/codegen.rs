@@ -0,0 +1,72 @@
+//! Golden-file tests for the XML-to-C / C++ / Python compilers
+//!
+//! Run with `UPDATE_GOLDEN=1` to overwrite the expected output after an intended change
+
+#![cfg(feature = "xml")]
+
+use azul_core::xml::{
+    str_to_c_code, str_to_cpp_code, str_to_python_code, str_to_rust_code, CompileError,
+    XmlComponentMap, XmlNode,
+};
+use azulc_lib::xml::parse_xml_string;
+use std::path::PathBuf;
+
+fn golden_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("golden")
+}
+
+fn check_golden(
+    xml_file: &str,
+    expected_file: &str,
+    compile: fn(&[XmlNode], &mut XmlComponentMap) -> Result<String, CompileError>,
+) {
+    let xml = std::fs::read_to_string(golden_dir().join(xml_file)).unwrap();
+    let root_nodes = parse_xml_string(&xml).unwrap();
+    let source = compile(&root_nodes, &mut XmlComponentMap::default()).unwrap();
+    let source = source.replace("\r\n", "\n");
+
+    let expected_path = golden_dir().join(expected_file);
+    if std::env::var("UPDATE_GOLDEN").is_ok() {
+        std::fs::write(&expected_path, &source).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&expected_path).unwrap().replace("\r\n", "\n");
+    assert_eq!(source, expected, "generated code differs from {}", expected_file);
+}
+
+#[test]
+fn compile_components_to_c() {
+    check_golden("components.xml", "components.c", str_to_c_code);
+}
+
+#[test]
+fn compile_components_to_cpp() {
+    check_golden("components.xml", "components.cpp", str_to_cpp_code);
+}
+
+#[test]
+fn compile_components_to_python() {
+    check_golden("components.xml", "components.py", str_to_python_code);
+}
+
+#[test]
+fn invalid_argument_value_is_an_error() {
+    let xml = "<html><head>
+        <component name=\"Counter\" args=\"count: usize\"><p>{count}</p></component>
+    </head><body><Counter count=\"many\" /></body></html>";
+    let root_nodes = parse_xml_string(xml).unwrap();
+    let err = str_to_c_code(&root_nodes, &mut XmlComponentMap::default()).unwrap_err();
+    assert!(format!("{}", err).contains("\"many\" is not a valid usize"));
+}
+
+#[test]
+fn rust_arguments_are_passed_in_declaration_order() {
+    let xml = "<html><head>
+        <component name=\"Card\" args=\"title: String, count: u32, ratio: f32, visible: bool\"><p>{title}</p></component>
+    </head><body><Card title=\"Hello\" count=\"3\" ratio=\"1\" /></body></html>";
+    let root_nodes = parse_xml_string(xml).unwrap();
+    let source = str_to_rust_code(&root_nodes, "", &mut XmlComponentMap::default()).unwrap();
+    assert!(source.contains("pub fn render(title: String, count: u32, ratio: f32, visible: bool) -> Dom {"), "{}", source);
+    assert!(source.contains("card::render(AzString::from_const_str(\"Hello\"), 3, 1.0, Default::default())"), "{}", source);
+}
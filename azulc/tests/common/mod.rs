@@ -0,0 +1,36 @@
+//! Helpers shared by the integration tests
+
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+/// Temporary directory, removed (including its contents) when dropped
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+    fn deref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+/// Writes the files to a new temporary directory, `test_name` has to be unique per test binary
+pub fn write_files<C: AsRef<[u8]>>(test_name: &str, files: &[(&str, C)]) -> TempDir {
+    let path = std::env::temp_dir().join(format!("azulc-{}-{}", test_name, std::process::id()));
+    let _ = fs::remove_dir_all(&path);
+    fs::create_dir_all(&path).unwrap();
+    for (name, contents) in files {
+        let file_path = path.join(name);
+        fs::create_dir_all(file_path.parent().unwrap()).unwrap();
+        fs::write(file_path, contents).unwrap();
+    }
+    TempDir { path }
+}
@@ -0,0 +1,97 @@
+//! Golden-image tests for the CPU rasterizer (`azulc --render`)
+//!
+//! Run with `UPDATE_GOLDEN=1` to overwrite the expected images after an intended change
+
+#![cfg(all(feature = "cpurender", feature = "font_loading", feature = "png", feature = "xml"))]
+
+use azul_core::{
+    app_resources::RawImage,
+    window::LogicalSize,
+    xml::{str_to_dom, XmlComponentMap},
+};
+use azul_css::ColorU;
+use azulc_lib::{
+    cpurender::render_styled_dom,
+    image::{decode::{decode_raw_image_from_any_bytes, ResultRawImageDecodeImageError}, encode::{encode_png, ResultU8VecEncodeImageError}},
+    xml::{parse_xml_file, register_image_components},
+};
+use std::path::PathBuf;
+
+/// Maximum difference per color channel, so that small changes in the
+/// anti-aliasing don't break the tests
+const TOLERANCE: u8 = 2;
+
+fn golden_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("golden").join("cpurender")
+}
+
+fn render(xml_file: &str, width: f32, height: f32) -> RawImage {
+    let xml_path = golden_dir().join(xml_file);
+    let root_nodes = parse_xml_file(&xml_path).unwrap().root_nodes;
+    let mut component_map = XmlComponentMap::default();
+    register_image_components(&mut component_map, &golden_dir());
+    let styled_dom = str_to_dom(root_nodes.as_ref(), &mut component_map, None).unwrap();
+    render_styled_dom(styled_dom, LogicalSize::new(width, height), ColorU::WHITE).unwrap()
+}
+
+fn encode(image: &RawImage) -> Vec<u8> {
+    match encode_png(image) {
+        ResultU8VecEncodeImageError::Ok(o) => o.as_ref().to_vec(),
+        ResultU8VecEncodeImageError::Err(e) => panic!("could not encode PNG: {}", e),
+    }
+}
+
+fn check_golden(xml_file: &str, expected_file: &str, width: f32, height: f32) {
+    let image = render(xml_file, width, height);
+    let expected_path = golden_dir().join(expected_file);
+    if std::env::var("UPDATE_GOLDEN").is_ok() {
+        std::fs::write(&expected_path, encode(&image)).unwrap();
+        return;
+    }
+
+    let expected = match decode_raw_image_from_any_bytes(&std::fs::read(&expected_path).unwrap()) {
+        ResultRawImageDecodeImageError::Ok(o) => o,
+        ResultRawImageDecodeImageError::Err(e) => panic!("could not decode {}: {}", expected_file, e),
+    };
+    assert_eq!((image.width, image.height), (expected.width, expected.height), "image size differs from {}", expected_file);
+
+    let actual_pixels = image.pixels.get_u8_vec_ref().unwrap();
+    let expected_pixels = expected.pixels.get_u8_vec_ref().unwrap();
+    let mismatch = actual_pixels.as_ref().chunks(4).zip(expected_pixels.as_ref().chunks(4)).position(|(a, e)| {
+        a.iter().zip(e.iter()).any(|(a, e)| a.max(e) - a.min(e) > TOLERANCE)
+    });
+
+    if let Some(pixel) = mismatch {
+        let actual_path = std::env::temp_dir().join(expected_file);
+        std::fs::write(&actual_path, encode(&image)).unwrap();
+        panic!(
+            "rendered image differs from {} at pixel ({}, {}), actual image written to {}",
+            expected_file, pixel % image.width, pixel / image.width, actual_path.display(),
+        );
+    }
+}
+
+#[test]
+fn render_rects() {
+    check_golden("rects.xml", "rects.png", 176.0, 48.0);
+}
+
+#[test]
+fn render_borders() {
+    check_golden("borders.xml", "borders.png", 176.0, 48.0);
+}
+
+#[test]
+fn render_text() {
+    check_golden("text.xml", "text.png", 128.0, 64.0);
+}
+
+#[test]
+fn render_images() {
+    check_golden("images.xml", "images.png", 96.0, 48.0);
+}
+
+#[test]
+fn render_clipping() {
+    check_golden("clipping.xml", "clipping.png", 96.0, 48.0);
+}
@@ -0,0 +1,92 @@
+// Auto-generated UI source code
+#include <azul.h>
+
+// .header
+static AzString const CSS_MATCH_10045573406524404634 = AzString_fromConstStr("font-size: 20px; background: #ff0000ff;");
+// #title:hover
+static AzString const CSS_MATCH_10045573406524404634_HOVER = AzString_fromConstStr("color: #0000ffff;");
+// .card-body
+static AzString const CSS_MATCH_1231365995638891581 = AzString_fromConstStr("padding-right: 5px; padding-left: 5px; padding-bottom: 5px; padding-top: 5px;");
+// .card-body:focus
+static AzString const CSS_MATCH_1231365995638891581_FOCUS = AzString_fromConstStr("border-bottom-width: 1px; border-left-width: 1px; border-right-width: 1px; border-top-width: 1px; border-bottom-style: solid; border-left-style: solid; border-right-style: solid; border-top-style: solid; border-bottom-color: #000000ff; border-left-color: #000000ff; border-right-color: #000000ff; border-top-color: #000000ff;");
+
+AzDom badge_render(size_t count);
+AzDom card_render(AzString text, AzString title, size_t count, bool highlighted);
+
+AzDom badge_render(size_t count) {
+    AzDom component = AzDom_div();
+    AzFmtArg fmt_args_1[] = {
+        { .key = AzString_fromConstStr("count"), .value = AzFmtValue_Usize(count) },
+    };
+    AzDom p_1 = AzDom_text(AzString_format(AzString_fromConstStr("{count}"), (AzFmtArgVec)AzFmtArgVec_fromConstArray(fmt_args_1)));
+    AzDom_addClass(&p_1, AzString_fromConstStr("badge"));
+    AzDom_addChild(&component, p_1);
+    return component;
+}
+
+AzDom card_render(AzString text, AzString title, size_t count, bool highlighted) {
+    AzDom component = AzDom_div();
+    AzDom div_1 = AzDom_div();
+    AzDom_addClass(&div_1, AzString_fromConstStr("card"));
+    AzFmtArg fmt_args_1[] = {
+        { .key = AzString_fromConstStr("title"), .value = AzFmtValue_Str(title) },
+    };
+    AzDom_addClass(&div_1, AzString_format(AzString_fromConstStr("card-{title}"), (AzFmtArgVec)AzFmtArgVec_fromConstArray(fmt_args_1)));
+    AzFmtArg fmt_args_2[] = {
+        { .key = AzString_fromConstStr("title"), .value = AzFmtValue_Str(title) },
+        { .key = AzString_fromConstStr("text"), .value = AzFmtValue_Str(text) },
+    };
+    AzDom p_2 = AzDom_text(AzString_format(AzString_fromConstStr("{title}: {text}"), (AzFmtArgVec)AzFmtArgVec_fromConstArray(fmt_args_2)));
+    AzDom_addChild(&div_1, p_2);
+    AzDom div_3 = AzDom_div();
+    AzDom_addClass(&div_3, AzString_fromConstStr("card-body"));
+    AzDom_setInlineStyle(&div_3, CSS_MATCH_1231365995638891581);
+    AzDom_setInlineFocusStyle(&div_3, CSS_MATCH_1231365995638891581_FOCUS);
+    AzDom_setTabIndex(&div_3, (AzTabIndex)AzTabIndex_Auto);
+    AzDom badge_4 = badge_render(count);
+    AzDom_addChild(&div_3, badge_4);
+    AzDom_addChild(&div_1, div_3);
+    AzDom_addChild(&component, div_1);
+    return component;
+}
+
+AzDom ui_render(void) {
+    AzDom body_1 = AzDom_body();
+    AzDom div_2 = AzDom_div();
+    AzDom_addId(&div_2, AzString_fromConstStr("title"));
+    AzDom_addClass(&div_2, AzString_fromConstStr("header"));
+    AzDom_setInlineStyle(&div_2, AzString_fromConstStr("margin: 10px;"));
+    AzDom_setInlineStyle(&div_2, CSS_MATCH_10045573406524404634);
+    AzDom_setInlineHoverStyle(&div_2, CSS_MATCH_10045573406524404634_HOVER);
+    AzDom_setTabIndex(&div_2, (AzTabIndex)AzTabIndex_OverrideInParent(2));
+    AzDom p_3 = AzDom_text(AzString_fromConstStr("Inbox \"{unknown}\""));
+    AzDom_addChild(&div_2, p_3);
+    AzDom_addChild(&body_1, div_2);
+    AzDom card_4 = card_render(AzString_fromConstStr("You have mail"), AzString_fromConstStr("Messages"), 3, true);
+    AzDom_addChild(&body_1, card_4);
+    AzDom card_5 = card_render(AzString_fromConstStr("Nothing to see"), AzString_fromConstStr("Spam"), 0, false);
+    AzDom_addChild(&body_1, card_5);
+    return body_1;
+}
+
+typedef struct {
+    uint8_t _unused; // C does not allow empty structs
+} Data;
+
+void Data_destructor(void* restrict data) { }
+AZ_REFLECT(Data, Data_destructor);
+
+AzStyledDom render(AzRefAny* restrict data, AzLayoutCallbackInfo* restrict info) {
+    AzDom dom = ui_render();
+    return AzDom_style(&dom, AzCss_empty()); // styles are applied inline
+}
+
+int main() {
+    Data data = { ._unused = 0 };
+    AzApp app = AzApp_new(Data_upcast(data), AzAppConfig_new(AzLayoutSolver_Default));
+    AzWindowCreateOptions window = AzWindowCreateOptions_new(render);
+    window.state.flags.frame = AzWindowFrame_Maximized;
+    AzApp_run(&app, window);
+    AzApp_delete(&app);
+    return 0;
+}
@@ -0,0 +1,116 @@
+// Auto-generated UI source code
+#include <azul.hpp>
+
+using namespace dll;
+
+// Turns a string literal into a String that is never freed
+template<size_t N>
+inline String az_str(const char (&s)[N]) {
+    return String { .vec = {
+        .ptr = reinterpret_cast<const uint8_t*>(s),
+        .len = N - 1,
+        .cap = N - 1,
+        .destructor = { .NoDestructor = { .tag = U8VecDestructorTag::NoDestructor } },
+    } };
+}
+
+// Turns a stack-allocated array of format arguments into a FmtArgVec
+template<size_t N>
+inline FmtArgVec az_fmt_args(FmtArg (&args)[N]) {
+    return FmtArgVec {
+        .ptr = args,
+        .len = N,
+        .cap = N,
+        .destructor = { .NoDestructor = { .tag = FmtArgVecDestructorTag::NoDestructor } },
+    };
+}
+
+// .header
+static const char CSS_MATCH_10045573406524404634[] = "font-size: 20px; background: #ff0000ff;";
+// #title:hover
+static const char CSS_MATCH_10045573406524404634_HOVER[] = "color: #0000ffff;";
+// .card-body
+static const char CSS_MATCH_1231365995638891581[] = "padding-right: 5px; padding-left: 5px; padding-bottom: 5px; padding-top: 5px;";
+// .card-body:focus
+static const char CSS_MATCH_1231365995638891581_FOCUS[] = "border-bottom-width: 1px; border-left-width: 1px; border-right-width: 1px; border-top-width: 1px; border-bottom-style: solid; border-left-style: solid; border-right-style: solid; border-top-style: solid; border-bottom-color: #000000ff; border-left-color: #000000ff; border-right-color: #000000ff; border-top-color: #000000ff;";
+
+Dom badge_render(size_t count);
+Dom card_render(String const& text, String const& title, size_t count, bool highlighted);
+
+Dom badge_render(size_t count) {
+    auto component = Dom_div();
+    FmtArg fmt_args_1[] = {
+        { .key = az_str("count"), .value = { .Usize = { .tag = FmtValueTag::Usize, .payload = count } } },
+    };
+    auto p_1 = Dom_text(String_format(az_str("{count}"), az_fmt_args(fmt_args_1)));
+    Dom_addClass(&p_1, az_str("badge"));
+    Dom_addChild(&component, std::move(p_1));
+    return component;
+}
+
+Dom card_render(String const& text, String const& title, size_t count, bool highlighted) {
+    auto component = Dom_div();
+    auto div_1 = Dom_div();
+    Dom_addClass(&div_1, az_str("card"));
+    FmtArg fmt_args_1[] = {
+        { .key = az_str("title"), .value = { .Str = { .tag = FmtValueTag::Str, .payload = title.clone() } } },
+    };
+    Dom_addClass(&div_1, String_format(az_str("card-{title}"), az_fmt_args(fmt_args_1)));
+    FmtArg fmt_args_2[] = {
+        { .key = az_str("title"), .value = { .Str = { .tag = FmtValueTag::Str, .payload = title.clone() } } },
+        { .key = az_str("text"), .value = { .Str = { .tag = FmtValueTag::Str, .payload = text.clone() } } },
+    };
+    auto p_2 = Dom_text(String_format(az_str("{title}: {text}"), az_fmt_args(fmt_args_2)));
+    Dom_addChild(&div_1, std::move(p_2));
+    auto div_3 = Dom_div();
+    Dom_addClass(&div_3, az_str("card-body"));
+    Dom_setInlineStyle(&div_3, az_str(CSS_MATCH_1231365995638891581));
+    Dom_setInlineFocusStyle(&div_3, az_str(CSS_MATCH_1231365995638891581_FOCUS));
+    Dom_setTabIndex(&div_3, TabIndex { .Auto = { .tag = TabIndexTag::Auto } });
+    auto badge_4 = badge_render(count);
+    Dom_addChild(&div_3, std::move(badge_4));
+    Dom_addChild(&div_1, std::move(div_3));
+    Dom_addChild(&component, std::move(div_1));
+    return component;
+}
+
+Dom ui_render() {
+    auto body_1 = Dom_body();
+    auto div_2 = Dom_div();
+    Dom_addId(&div_2, az_str("title"));
+    Dom_addClass(&div_2, az_str("header"));
+    Dom_setInlineStyle(&div_2, az_str("margin: 10px;"));
+    Dom_setInlineStyle(&div_2, az_str(CSS_MATCH_10045573406524404634));
+    Dom_setInlineHoverStyle(&div_2, az_str(CSS_MATCH_10045573406524404634_HOVER));
+    Dom_setTabIndex(&div_2, TabIndex { .OverrideInParent = { .tag = TabIndexTag::OverrideInParent, .payload = 2 } });
+    auto p_3 = Dom_text(az_str("Inbox \"{unknown}\""));
+    Dom_addChild(&div_2, std::move(p_3));
+    Dom_addChild(&body_1, std::move(div_2));
+    auto card_4 = card_render(az_str("You have mail"), az_str("Messages"), 3, true);
+    Dom_addChild(&body_1, std::move(card_4));
+    auto card_5 = card_render(az_str("Nothing to see"), az_str("Spam"), 0, false);
+    Dom_addChild(&body_1, std::move(card_5));
+    return body_1;
+}
+
+struct Data { };
+
+static uint64_t const Data_RttiTypePtrId = 0;
+
+void Data_destructor(void* restrict data) { }
+
+StyledDom render(RefAny* restrict data, LayoutCallbackInfo* restrict info) {
+    auto dom = ui_render();
+    return Dom_style(&dom, Css_empty()); // styles are applied inline
+}
+
+int main() {
+    Data data { };
+    auto refany = RefAny_newC(&data, sizeof(Data), reinterpret_cast<uint64_t>(&Data_RttiTypePtrId), az_str("Data"), Data_destructor);
+    auto app = App_new(std::move(refany), AppConfig_new(LayoutSolver::Default));
+    auto window = WindowCreateOptions_new(render);
+    window.state.flags.frame = WindowFrame::Maximized;
+    App_run(&app, std::move(window));
+    App_delete(&app);
+    return 0;
+}
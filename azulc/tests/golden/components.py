@@ -0,0 +1,65 @@
+# Auto-generated UI source code
+from azul import *
+
+# .header
+CSS_MATCH_10045573406524404634 = "font-size: 20px; background: #ff0000ff;"
+# #title:hover
+CSS_MATCH_10045573406524404634_HOVER = "color: #0000ffff;"
+# .card-body
+CSS_MATCH_1231365995638891581 = "padding-right: 5px; padding-left: 5px; padding-bottom: 5px; padding-top: 5px;"
+# .card-body:focus
+CSS_MATCH_1231365995638891581_FOCUS = "border-bottom-width: 1px; border-left-width: 1px; border-right-width: 1px; border-top-width: 1px; border-bottom-style: solid; border-left-style: solid; border-right-style: solid; border-top-style: solid; border-bottom-color: #000000ff; border-left-color: #000000ff; border-right-color: #000000ff; border-top-color: #000000ff;"
+
+def badge_render(count: int) -> Dom:
+    component = Dom.div()
+    p_1 = Dom.text(f"{count}")
+    p_1.add_class("badge")
+    component.add_child(p_1)
+    return component
+
+def card_render(text: str, title: str, count: int, highlighted: bool) -> Dom:
+    component = Dom.div()
+    div_1 = Dom.div()
+    div_1.add_class("card")
+    div_1.add_class(f"card-{title}")
+    p_2 = Dom.text(f"{title}: {text}")
+    div_1.add_child(p_2)
+    div_3 = Dom.div()
+    div_3.add_class("card-body")
+    div_3.set_inline_style(CSS_MATCH_1231365995638891581)
+    div_3.set_inline_focus_style(CSS_MATCH_1231365995638891581_FOCUS)
+    div_3.set_tab_index(TabIndex.Auto)
+    badge_4 = badge_render(count)
+    div_3.add_child(badge_4)
+    div_1.add_child(div_3)
+    component.add_child(div_1)
+    return component
+
+def ui_render() -> Dom:
+    body_1 = Dom.body()
+    div_2 = Dom.div()
+    div_2.add_id("title")
+    div_2.add_class("header")
+    div_2.set_inline_style("margin: 10px;")
+    div_2.set_inline_style(CSS_MATCH_10045573406524404634)
+    div_2.set_inline_hover_style(CSS_MATCH_10045573406524404634_HOVER)
+    div_2.set_tab_index(TabIndex.OverrideInParent(2))
+    p_3 = Dom.text("Inbox \"{unknown}\"")
+    div_2.add_child(p_3)
+    body_1.add_child(div_2)
+    card_4 = card_render("You have mail", "Messages", 3, True)
+    body_1.add_child(card_4)
+    card_5 = card_render("Nothing to see", "Spam", 0, False)
+    body_1.add_child(card_5)
+    return body_1
+
+class Data:
+    pass
+
+def render(data, info):
+    return ui_render().style(Css.empty()) # styles are applied inline
+
+app = App(Data(), AppConfig(LayoutSolver.Default))
+window = WindowCreateOptions(render)
+window.state.flags.frame = WindowFrame.Maximized
+app.run(window)
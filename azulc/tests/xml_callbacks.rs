@@ -0,0 +1,88 @@
+//! Tests for the `on:event="handler_name"` attributes in XML components
+
+#![cfg(feature = "xml")]
+
+use azul_core::callbacks::{CallbackInfo, RefAny, Update};
+use azul_core::dom::{EventFilter, FocusEventFilter, HoverEventFilter, NodeType};
+use azul_core::xml::{
+    parse_event_filter, str_to_dom, str_to_rust_code, ComponentError,
+    DomXmlParseError, RenderDomError, XmlComponentMap,
+};
+use azulc_lib::xml::parse_xml_string;
+
+const TOOLBAR: &str = "<component name=\"toolbar\"><p on:left-mouse-up=\"on_save\">Save</p></component>";
+
+extern "C" fn on_save(_: &mut RefAny, _: &mut CallbackInfo) -> Update {
+    Update::RefreshDom
+}
+
+fn get_root_nodes(body: &str) -> Vec<azul_core::xml::XmlNode> {
+    let xml = format!("<html><head>{}</head><body>{}</body></html>", TOOLBAR, body);
+    parse_xml_string(&xml).unwrap()
+}
+
+#[test]
+fn event_names_map_to_event_filters() {
+    assert_eq!(parse_event_filter("leftmouseup"), Some(EventFilter::Hover(HoverEventFilter::LeftMouseUp)));
+    assert_eq!(parse_event_filter("LeftMouseUp"), Some(EventFilter::Hover(HoverEventFilter::LeftMouseUp)));
+    assert_eq!(parse_event_filter("textinput"), Some(EventFilter::Focus(FocusEventFilter::TextInput)));
+    assert_eq!(parse_event_filter("clicked"), None);
+}
+
+#[test]
+fn handlers_are_bound_to_registered_callbacks() {
+    let mut component_map = XmlComponentMap::default();
+    component_map.register_callback("on_save", RefAny::new(5_usize), on_save);
+    component_map.register_callback("on_text", RefAny::new(5_usize), on_save);
+
+    let root_nodes = get_root_nodes("<toolbar /><p on:textinput=\"on_text\">input</p>");
+    let dom = str_to_dom(&root_nodes, &mut component_map, None).unwrap();
+
+    let events = dom.node_data.as_ref().iter().filter_map(|n| {
+        let text = match n.get_node_type() {
+            NodeType::Text(t) => t.as_str().to_string(),
+            _ => return None,
+        };
+        let events = n.get_callbacks().as_ref().iter().map(|c| c.event).collect::<Vec<_>>();
+        Some((text, events))
+    }).collect::<Vec<_>>();
+
+    assert_eq!(events, vec![
+        ("Save".to_string(), vec![EventFilter::Hover(HoverEventFilter::LeftMouseUp)]),
+        ("input".to_string(), vec![EventFilter::Focus(FocusEventFilter::TextInput)]),
+    ]);
+}
+
+#[test]
+fn unregistered_handler_is_an_error() {
+    let root_nodes = get_root_nodes("<toolbar />");
+    match str_to_dom(&root_nodes, &mut XmlComponentMap::default(), None) {
+        Err(DomXmlParseError::RenderDom(RenderDomError::InvalidNode(_, ComponentError::UnknownCallback(name)))) => {
+            assert_eq!(name.as_str(), "on_save");
+        },
+        other => panic!("expected unknown callback, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn unknown_event_is_an_error() {
+    let root_nodes = get_root_nodes("<p on:clicked=\"on_save\">x</p>");
+    let mut component_map = XmlComponentMap::default();
+    component_map.register_callback("on_save", RefAny::new(5_usize), on_save);
+    match str_to_dom(&root_nodes, &mut component_map, None) {
+        Err(DomXmlParseError::RenderDom(RenderDomError::InvalidNode(_, ComponentError::UnknownEvent(name)))) => {
+            assert_eq!(name.as_str(), "clicked");
+        },
+        other => panic!("expected unknown event, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn handlers_are_compiled_to_rust_code() {
+    let root_nodes = get_root_nodes("<toolbar />");
+    let source = str_to_rust_code(&root_nodes, "", &mut XmlComponentMap::default()).unwrap();
+    assert!(source.contains(".with_callback(EventFilter::Hover(HoverEventFilter::LeftMouseUp), data.clone(), on_save)"), "{}", source);
+    assert!(source.contains("pub fn render(data: &RefAny) -> Dom"), "{}", source);
+    assert!(source.contains("toolbar::render(data)"), "{}", source);
+    assert!(source.contains("crate::ui::render(data)"), "{}", source);
+}
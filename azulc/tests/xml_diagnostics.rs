@@ -0,0 +1,80 @@
+//! Tests for the source locations of XML nodes and the diagnostics of `check_xml_project`
+
+#![cfg(all(feature = "std", feature = "xml"))]
+
+mod common;
+
+use std::path::Path;
+use common::write_files;
+use azul_core::xml::{XmlComponentMap, XmlError, XmlTextPos};
+use azulc_lib::xml::{check_xml_project, parse_xml_file, parse_xml_string, XmlDiagnostic};
+
+#[test]
+fn nodes_carry_their_source_span() {
+    let source = "<?xml version=\"1.0\"?>\n<html>\n  <body><p id=\"x\">a</p><br/></body>\n</html>";
+    let root_nodes = parse_xml_string(source).unwrap();
+    let body = &root_nodes[0].children.as_ref()[0];
+    let p = &body.children.as_ref()[0];
+    let br = &body.children.as_ref()[1];
+
+    assert_eq!(&source[p.span.start..p.span.end], "<p id=\"x\">");
+    assert_eq!(p.span.pos, XmlTextPos { row: 3, col: 9 });
+    assert_eq!(&source[br.span.start..br.span.end], "<br/>");
+    assert_eq!(p.span.file, 0);
+}
+
+#[test]
+fn mismatched_close_tag_has_a_position() {
+    match parse_xml_string("<html>\n<body></p></html>") {
+        Err(XmlError::UnexpectedCloseTag(e)) => {
+            assert_eq!((e.expected.as_str(), e.actual.as_str()), ("body", "p"));
+            assert_eq!(e.pos, XmlTextPos { row: 2, col: 7 });
+        },
+        other => panic!("expected unexpected close tag, got {:?}", other),
+    }
+}
+
+#[test]
+fn diagnostic_underlines_the_span() {
+    let source = "<body>\n    <buton label=\"Save\" />\n</body>";
+    let start = source.find("<buton").unwrap();
+    let end = source.find("/>").unwrap() + 2;
+    let diagnostic = XmlDiagnostic::with_span(Path::new("app.xml"), "Unknown component: \"buton\"".to_string(), source, start, end);
+
+    assert_eq!(diagnostic.to_string(), [
+        "error: Unknown component: \"buton\"",
+        "  --> app.xml:2:5",
+        "  |",
+        "2 |     <buton label=\"Save\" />",
+        "  |     ^^^^^^^^^^^^^^^^^^^^^^",
+    ].join("\n"));
+
+    let diagnostic = XmlDiagnostic::with_pos(Path::new("app.xml"), "error".to_string(), source, XmlTextPos { row: 2, col: 6 });
+    assert!(diagnostic.to_string().ends_with("\n  |      ^"), "{}", diagnostic);
+}
+
+#[test]
+fn check_collects_errors_of_all_nodes_and_files() {
+    let dir = write_files("collect", &[
+        ("lib.xml", "<component name=\"toolbar\">\n  <buton />\n</component>"),
+        ("theme.css", "p {\n  color: red;\n}\n}"),
+        ("app.xml", "<html><head>\n<import src=\"lib.xml\" />\n<link rel=\"stylesheet\" href=\"theme.css\" />\n</head><body>\n  <toolbar />\n  <p on:clicked=\"x\">a</p>\n  <else />\n</body></html>"),
+    ]);
+
+    let errors = check_xml_project(&dir.join("app.xml"), XmlComponentMap::default);
+    let locations = errors.iter().map(|e| {
+        (e.file.file_name().unwrap().to_string_lossy().into_owned(), e.pos.map(|p| (p.row, p.col)))
+    }).collect::<Vec<_>>();
+
+    assert_eq!(locations, vec![
+        ("theme.css".to_string(), Some((4, 1))),
+        ("app.xml".to_string(), Some((6, 3))),
+        ("app.xml".to_string(), Some((7, 3))),
+        ("lib.xml".to_string(), Some((2, 3))),
+    ], "{:#?}", errors);
+    assert!(errors[1].message.contains("\"clicked\""), "{:?}", errors);
+    assert!(errors[3].message.contains("\"buton\""), "{:?}", errors);
+
+    let xml_file = parse_xml_file(&dir.join("app.xml")).unwrap();
+    assert_eq!(xml_file.files[1], dir.join("lib.xml"));
+}
@@ -0,0 +1,101 @@
+//! Tests for `<img src="..." />` and inline `<svg>` nodes
+
+#![cfg(all(feature = "std", feature = "xml"))]
+
+mod common;
+
+use std::path::Path;
+use common::write_files;
+use azul_core::xml::{str_to_dom, str_to_rust_code, ComponentError, RenderDomError, DomXmlParseError, XmlComponentMap};
+use azulc_lib::xml::{parse_xml_string, register_image_components};
+
+/// 2x1 red RGBA image
+#[cfg(feature = "png")]
+const PNG: &[u8] = &[
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00, 0x00, 0xf4, 0x22, 0x7f,
+    0x8a, 0x00, 0x00, 0x00, 0x0e, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9c, 0x63, 0xf8, 0xcf, 0xc0, 0xf0,
+    0x1f, 0x84, 0x01, 0x11, 0xf7, 0x03, 0xfd, 0xe3, 0xc5, 0xf5, 0xef, 0x00, 0x00, 0x00, 0x00, 0x49,
+    0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82,
+];
+
+fn component_map(base_dir: &Path) -> XmlComponentMap {
+    let mut component_map = XmlComponentMap::default();
+    register_image_components(&mut component_map, base_dir);
+    component_map
+}
+
+/// Returns the (width, height) of all image nodes
+#[cfg(any(feature = "png", feature = "svg"))]
+fn image_sizes(dom: &azul_core::styled_dom::StyledDom) -> Vec<(f32, f32)> {
+    use azul_core::dom::NodeType;
+    dom.node_data.as_ref().iter().filter_map(|n| match n.get_node_type() {
+        NodeType::Image(image) => Some((image.get_size().width, image.get_size().height)),
+        _ => None,
+    }).collect()
+}
+
+#[test]
+fn inline_svg_is_stored_as_source() {
+    let xml = "<html><body><svg id=\"logo\" width=\"4\" height=\"3\"><g><rect width=\"4\" height=\"3\" /></g></svg><p>after</p></body></html>";
+    let root_nodes = parse_xml_string(xml).unwrap();
+    let body = &root_nodes[0].children.as_ref()[0];
+    let svg = &body.children.as_ref()[0];
+
+    assert_eq!(body.children.as_ref().len(), 2);
+    assert!(svg.children.as_ref().is_empty());
+    assert_eq!(svg.attributes.get_key("id").map(|s| s.as_str()), Some("logo"));
+    assert_eq!(svg.attributes.get_key("width"), None);
+    assert_eq!(
+        svg.attributes.get_key("source").map(|s| s.as_str()),
+        Some("<svg xmlns=\"http://www.w3.org/2000/svg\" id=\"logo\" width=\"4\" height=\"3\"><g><rect width=\"4\" height=\"3\" /></g></svg>"),
+    );
+    assert_eq!(&xml[svg.span.start..svg.span.end], "<svg id=\"logo\" width=\"4\" height=\"3\">");
+}
+
+#[test]
+fn images_compile_to_include_bytes() {
+    let xml = "<html><body><img src=\"assets/logo.png\" class=\"logo\" /><svg viewBox=\"0 0 4 3\"><style>.a { fill: \"red\"; }</style></svg></body></html>";
+    let source = str_to_rust_code(&parse_xml_string(xml).unwrap(), "", &mut component_map(Path::new(""))).unwrap();
+
+    assert!(source.contains("img::render(include_bytes!(\"assets/logo.png\"))"), "{}", source);
+    assert!(source.contains("svg::render(\"<svg xmlns=\\\"http://www.w3.org/2000/svg\\\" viewBox=\\\"0 0 4 3\\\"><style>.a { fill: \\\"red\\\"; }</style></svg>\".as_bytes())"), "{}", source);
+    assert!(source.contains("pub fn render(src: &'static [u8]) -> Dom"), "{}", source);
+    assert!(source.contains("azul::image::RawImage::decode_image_bytes_any(src)"), "{}", source);
+    assert!(source.contains("pub fn render(source: &'static [u8]) -> Dom"), "{}", source);
+}
+
+#[test]
+fn missing_image_file_is_an_error() {
+    let dir = write_files::<&[u8]>("missing", &[]);
+    let root_nodes = parse_xml_string("<html><body>\n<img src=\"missing.png\" /></body></html>").unwrap();
+    match str_to_dom(&root_nodes, &mut component_map(&dir), None) {
+        Err(DomXmlParseError::RenderDom(RenderDomError::InvalidNode(span, ComponentError::InvalidImage(src, _)))) => {
+            assert_eq!(src.as_str(), "missing.png");
+            assert_eq!((span.pos.row, span.pos.col), (2, 1));
+        },
+        Err(e) => panic!("expected invalid image, got {}", e),
+        Ok(_) => panic!("expected invalid image"),
+    }
+}
+
+#[cfg(feature = "png")]
+#[test]
+fn img_renders_relative_image_file() {
+    let dir = write_files("png", &[("assets/red.png", PNG)]);
+    let root_nodes = parse_xml_string("<html><body><img src=\"assets/red.png\" style=\"width: 20px;\" /></body></html>").unwrap();
+    let dom = str_to_dom(&root_nodes, &mut component_map(&dir), None).unwrap();
+    assert_eq!(image_sizes(&dom), vec![(2.0, 1.0)]);
+}
+
+#[cfg(feature = "svg")]
+#[test]
+fn svg_renders_to_image() {
+    let svg = "<svg width=\"4\" height=\"3\"><rect width=\"4\" height=\"3\" fill=\"red\" /></svg>";
+    let svg_file = "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"4\" height=\"3\"><rect width=\"4\" height=\"3\" fill=\"red\" /></svg>";
+    let dir = write_files("svg", &[("icon.svg", svg_file.as_bytes())]);
+    // inline SVGs don't need a namespace
+    let xml = format!("<html><body>{}<img src=\"icon.svg\" /></body></html>", svg);
+    let dom = str_to_dom(&parse_xml_string(&xml).unwrap(), &mut component_map(&dir), None).unwrap();
+    assert_eq!(image_sizes(&dom), vec![(4.0, 3.0), (4.0, 3.0)]);
+}
@@ -0,0 +1,113 @@
+//! Tests for `<import />` and `<link rel="stylesheet" />` in XML files
+
+#![cfg(all(feature = "std", feature = "xml"))]
+
+mod common;
+
+use common::write_files;
+use azul_core::dom::NodeType;
+use azul_core::xml::{str_to_dom, str_to_rust_library, XmlComponentMap};
+use azulc_lib::xml::{parse_xml_directory, parse_xml_file, XmlImportError};
+
+const WIDGETS: &str = "
+    <link rel=\"stylesheet\" href=\"theme.css\" />
+    <component name=\"button\" args=\"label: String\"><p class=\"btn\">{label}</p></component>
+    <component name=\"toolbar\"><button label=\"Save\" /><button label=\"Open\" /></component>
+";
+
+#[test]
+fn import_with_prefix_and_stylesheet() {
+    let dir = write_files("prefix", &[
+        ("lib/widgets.xml", WIDGETS),
+        ("lib/theme.css", ".btn { color: red; }"),
+        ("app.xml", "<html><head>
+            <import src=\"lib/widgets.xml\" prefix=\"ui\" />
+            <component name=\"button\" args=\"text: String\"><p>mine</p></component>
+        </head><body><ui-toolbar /><button text=\"x\" /></body></html>"),
+    ]);
+
+    let xml_file = parse_xml_file(&dir.join("app.xml")).unwrap();
+    assert_eq!(xml_file.files, vec![dir.join("app.xml"), dir.join("lib/widgets.xml"), dir.join("lib/theme.css")]);
+
+    let dom = str_to_dom(&xml_file.root_nodes, &mut XmlComponentMap::default(), None).unwrap();
+    let texts = dom.node_data.as_ref().iter().filter_map(|n| match n.get_node_type() {
+        NodeType::Text(t) => Some(t.as_str().to_string()),
+        _ => None,
+    }).collect::<Vec<_>>();
+    assert_eq!(texts, vec!["Save", "Open", "mine"]);
+}
+
+#[test]
+fn import_cycle_is_an_error() {
+    let dir = write_files("cycle", &[
+        ("a.xml", "<html><head><import src=\"b.xml\" /></head><body></body></html>"),
+        ("b.xml", "<import src=\"a.xml\" />"),
+    ]);
+
+    match parse_xml_file(&dir.join("a.xml")) {
+        Err(XmlImportError::ImportCycle(chain)) => {
+            let names = chain.iter().map(|p| p.file_name().unwrap().to_string_lossy().into_owned()).collect::<Vec<_>>();
+            assert_eq!(names, vec!["a.xml", "b.xml", "a.xml"]);
+        },
+        other => panic!("expected import cycle, got {:?}", other),
+    }
+}
+
+#[test]
+fn name_collision_without_prefix_is_an_error() {
+    let dir = write_files("collision", &[
+        ("widgets.xml", WIDGETS),
+        ("theme.css", ""),
+        ("app.xml", "<html><head>
+            <import src=\"widgets.xml\" />
+            <component name=\"button\"><p>mine</p></component>
+        </head><body></body></html>"),
+    ]);
+
+    match parse_xml_file(&dir.join("app.xml")) {
+        Err(XmlImportError::DuplicateComponent(name, _)) => assert_eq!(name, "button"),
+        other => panic!("expected duplicate component, got {:?}", other),
+    }
+}
+
+#[test]
+fn compile_directory_to_rust_library() {
+    let dir = write_files("library", &[
+        ("widgets.xml", WIDGETS),
+        ("theme.css", ""),
+        ("forms.xml", "<import src=\"widgets.xml\" /><component name=\"form\"><toolbar /></component>"),
+    ]);
+
+    let library = parse_xml_directory(&dir).unwrap();
+    let source = str_to_rust_library(&library.root_nodes, "", &mut XmlComponentMap::default()).unwrap();
+    assert_eq!(source.matches("pub mod button {").count(), 1, "{}", source);
+    assert!(source.contains("pub mod form {"), "{}", source);
+    assert!(source.contains("toolbar::render()"), "{}", source);
+}
+
+#[test]
+fn font_face_urls_are_relative_to_the_stylesheet() {
+    use azul_css_parser::CssFontFaceSource;
+
+    let dir = write_files("font-face", &[
+        ("lib/theme.css", "@font-face { font-family: F; src: url(fonts/f.ttf), local(F); }"),
+        ("app.xml", "<html><head>
+            <link rel=\"stylesheet\" href=\"lib/theme.css\" />
+            <style>@font-face { font-family: G; src: url(g.ttf); } p { font-family: G; }</style>
+        </head><body></body></html>"),
+    ]);
+
+    let xml_file = parse_xml_file(&dir.join("app.xml")).unwrap();
+    let head = &xml_file.root_nodes[0].children.as_ref()[0];
+    let style = head.children.as_ref().iter().find(|n| n.node_type.as_str() == "style").unwrap();
+    let css = azul_css_parser::new_from_str(style.text.as_ref().unwrap().as_str()).unwrap();
+
+    let urls = css.font_faces.iter().flat_map(|f| f.src.iter()).filter_map(|s| match s {
+        CssFontFaceSource::Url(u) => Some(u.url.as_str().to_string()),
+        CssFontFaceSource::Local(_) => None,
+    }).collect::<Vec<_>>();
+    assert_eq!(urls, vec![
+        format!("{}/fonts/f.ttf", dir.join("lib").display()),
+        format!("{}/g.ttf", dir.display()),
+    ]);
+}
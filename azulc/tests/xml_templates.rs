@@ -0,0 +1,77 @@
+//! Tests for the `<if>`, `<else>` and `<for>` nodes in XML components
+
+#![cfg(feature = "xml")]
+
+use azul_core::dom::NodeType;
+use azul_core::xml::{str_to_dom, str_to_rust_code, ComponentError, XmlComponentMap};
+use azulc_lib::xml::parse_xml_string;
+
+const TODO_LIST: &str = "
+    <component name=\"todo-list\" args=\"title: String, items: [String], done: bool, kind: String\">
+        <p>{title}</p>
+        <for each=\"item in items\">
+            <p>- {item}</p>
+        </for>
+        <if condition=\"done\"><p>all done</p></if>
+        <else><p>not done</p></else>
+        <if condition=\"kind == 'urgent'\"><p>urgent!</p></if>
+    </component>
+";
+
+fn render_texts(head: &str, body: &str) -> Result<Vec<String>, String> {
+    let xml = format!("<html><head>{}</head><body>{}</body></html>", head, body);
+    let root_nodes = parse_xml_string(&xml).unwrap();
+    let dom = str_to_dom(&root_nodes, &mut XmlComponentMap::default(), None).map_err(|e| format!("{}", e))?;
+    Ok(dom.node_data.as_ref().iter().filter_map(|n| match n.get_node_type() {
+        NodeType::Text(t) => Some(t.as_str().to_string()),
+        _ => None,
+    }).collect())
+}
+
+#[test]
+fn for_loop_repeats_children() {
+    let texts = render_texts(TODO_LIST, "<todo-list title=\"Groceries\" items=\"apples, pears\" done=\"false\" kind=\"normal\" />").unwrap();
+    assert_eq!(texts, vec!["Groceries", "- apples", "- pears", "not done"]);
+}
+
+#[test]
+fn if_else_and_comparison() {
+    let texts = render_texts(TODO_LIST, "<todo-list title=\"Chores\" items=\"[]\" done=\"true\" kind=\"urgent\" />").unwrap();
+    assert_eq!(texts, vec!["Chores", "all done", "urgent!"]);
+}
+
+#[test]
+fn else_without_if_is_an_error() {
+    let err = render_texts("", "<else><p>x</p></else>").unwrap_err();
+    assert_eq!(err, format!("Error rendering DOM: line 1:26: {}", ComponentError::ElseWithoutIf));
+}
+
+#[test]
+fn condition_on_unknown_variable_is_an_error() {
+    let err = render_texts("", "<if condition=\"missing\"><p>x</p></if>").unwrap_err();
+    assert!(err.contains("Unknown variable: \"missing\""), "{}", err);
+}
+
+#[test]
+fn compiles_to_rust_if_and_for() {
+    let xml = format!("<html><head>{}</head><body><p>x</p></body></html>", TODO_LIST);
+    let root_nodes = parse_xml_string(&xml).unwrap();
+    let source = str_to_rust_code(&root_nodes, "", &mut XmlComponentMap::default()).unwrap();
+    assert!(source.contains("items: Vec<String>"), "{}", source);
+    assert!(source.contains("for item in items.iter() {"), "{}", source);
+    assert!(source.contains("if done {"), "{}", source);
+    assert!(source.contains("} else {"), "{}", source);
+    assert!(source.contains("if kind == \"urgent\" {"), "{}", source);
+}
+
+#[test]
+fn compiles_list_arguments_to_vec() {
+    let xml = format!(
+        "<html><head>{}</head><body><todo-list title=\"Groceries\" items=\"apples, pears\" done=\"false\" kind=\"normal\" /><todo-list title=\"None\" items=\"[]\" done=\"true\" kind=\"normal\" /></body></html>",
+        TODO_LIST
+    );
+    let root_nodes = parse_xml_string(&xml).unwrap();
+    let source = str_to_rust_code(&root_nodes, "", &mut XmlComponentMap::default()).unwrap();
+    assert!(source.contains("vec![String::from(\"apples\"), String::from(\"pears\")]"), "{}", source);
+    assert!(source.contains("vec![]"), "{}", source);
+}
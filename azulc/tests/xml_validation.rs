@@ -0,0 +1,77 @@
+//! Tests for the type checks of XML component arguments and `check_xml_project`
+
+#![cfg(all(feature = "std", feature = "xml"))]
+
+use std::fs;
+use azul_core::xml::{
+    str_to_dom, ComponentError, DomXmlParseError, RenderDomError,
+    XmlComponentMap, XmlTextPos,
+};
+use azulc_lib::xml::{check_xml_project, parse_xml_string};
+
+const COUNTER: &str = "<component name=\"counter\" args=\"start: i32, kind: primary|secondary, color: ColorU, width: PixelValue, steps: [u8]\"><p>{start}</p></component>";
+
+fn get_render_error(body: &str) -> Option<DomXmlParseError> {
+    let xml = format!("<html><head>{}</head>\n<body>\n    {}</body></html>", COUNTER, body);
+    let root_nodes = parse_xml_string(&xml).unwrap();
+    str_to_dom(&root_nodes, &mut XmlComponentMap::default(), None).err()
+}
+
+fn get_invalid_argument(body: &str) -> (XmlTextPos, String, String) {
+    match get_render_error(body) {
+        Some(DomXmlParseError::RenderDom(RenderDomError::InvalidNode(span, ComponentError::InvalidArgumentValue(name, value, _)))) => {
+            (span.pos, name.as_str().to_string(), value.as_str().to_string())
+        },
+        other => panic!("expected invalid argument, got {:?}", other),
+    }
+}
+
+#[test]
+fn valid_arguments_are_accepted() {
+    let error = get_render_error("<counter start=\"-5\" kind=\"secondary\" color=\"#ff0000\" width=\"10px\" steps=\"1, 2, 3\" />");
+    assert_eq!(error, None);
+}
+
+#[test]
+fn invalid_arguments_are_reported_with_position() {
+    let (pos, name, value) = get_invalid_argument("<counter start=\"abc\" />");
+    assert_eq!(pos, XmlTextPos { row: 3, col: 5 });
+    assert_eq!((name.as_str(), value.as_str()), ("start", "abc"));
+
+    assert_eq!(get_invalid_argument("<counter kind=\"tertiary\" />").1, "kind");
+    assert_eq!(get_invalid_argument("<counter color=\"not-a-color\" />").1, "color");
+    assert_eq!(get_invalid_argument("<counter width=\"wide\" />").1, "width");
+    assert_eq!(get_invalid_argument("<counter steps=\"1, 300\" />").1, "steps");
+}
+
+#[test]
+fn variables_are_checked_after_instantiation() {
+    let xml = format!("<html><head>{}
+        <component name=\"wrapper\" args=\"value: String\"><counter start=\"{{value}}\" /></component>
+    </head><body><wrapper value=\"5\" /><wrapper value=\"five\" /></body></html>", COUNTER);
+    let root_nodes = parse_xml_string(&xml).unwrap();
+    match str_to_dom(&root_nodes, &mut XmlComponentMap::default(), None) {
+        Err(DomXmlParseError::RenderDom(RenderDomError::InvalidNode(span, ComponentError::InvalidArgumentValue(name, value, _)))) => {
+            assert_eq!(span.pos.row, 2);
+            assert_eq!((name.as_str(), value.as_str()), ("start", "five"));
+        },
+        other => panic!("expected invalid argument, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn check_project_reports_all_files() {
+    let dir = std::env::temp_dir().join(format!("azulc-xml-check-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("a.xml"), format!("<html><head>{}</head><body><counter start=\"x\" /></body></html>", COUNTER)).unwrap();
+    fs::write(dir.join("b.xml"), format!("{}<component name=\"twice\"><counter kind=\"tertiary\" /></component>", COUNTER)).unwrap();
+    fs::write(dir.join("c.xml"), format!("<html><head>{}</head><body><counter start=\"1\" /></body></html>", COUNTER)).unwrap();
+
+    let errors = check_xml_project(&dir, XmlComponentMap::default);
+    let files = errors.iter().map(|e| e.file.file_name().unwrap().to_string_lossy().into_owned()).collect::<Vec<_>>();
+    assert_eq!(files, vec!["a.xml", "b.xml"], "{:?}", errors);
+    assert!(errors[1].message.contains("\"tertiary\""), "{:?}", errors);
+
+    let _ = fs::remove_dir_all(&dir);
+}
@@ -804,11 +804,13 @@ def sort_structs_map(api_data, structs_map):
         "AzDomVec": {"type": "struct", "name": "AzDom"},
         "AzMenuItemVec": {"type": "struct", "name": "AzMenuItem"},
         "AzXmlNodeVec": {"type": "struct", "name": "AzXmlNode"},
+        "AzTreeViewItemVec": {"type": "struct", "name": "AzTreeViewItem"},
     }
     forward_delcarations = OrderedDict([
         ("AzDomVec", "Dom"),
         ("AzXmlNodeVec", "XmlNode"),
-        ("AzMenuItemVec", "MenuItem")
+        ("AzMenuItemVec", "MenuItem"),
+        ("AzTreeViewItemVec", "TreeViewItem")
     ])
 
     classes_not_found = OrderedDict([])
@@ -1309,6 +1311,7 @@ def generate_python_api(api_data, structs_map, functions_map):
         ("menu", "StringMenuItem", "with_callback"),
         ("menu", "StringMenuItem", "set_callback"),
         ("menu", "MenuCallback", "new"),
+        ("menu", "KeyboardShortcut", "new"),
         ("gl", "Texture", "draw_tesselated_svg_gpu_node"),
         ("gl", "Texture", "draw_tesselated_colored_svg_gpu_node"),
         ("svg", "TessellatedGPUSvgNode", "new"),
@@ -1342,6 +1345,54 @@ def generate_python_api(api_data, structs_map, functions_map):
         ("widgets", "TabHeader", "with_on_click"),
         ("widgets", "TabHeader", "set_on_click"),
         ("widgets", "Ribbon", "dom"),
+        ("widgets", "TreeView", "with_on_expand"),
+        ("widgets", "TreeView", "set_on_expand"),
+        ("widgets", "TreeView", "with_on_select"),
+        ("widgets", "TreeView", "set_on_select"),
+        ("widgets", "TreeView", "with_on_lazy_load"),
+        ("widgets", "TreeView", "set_on_lazy_load"),
+        ("widgets", "TreeView", "with_on_scroll"),
+        ("widgets", "TreeView", "set_on_scroll"),
+        ("widgets", "ListView", "with_row_provider"),
+        ("widgets", "ListView", "set_row_provider"),
+        ("widgets", "ListView", "with_on_lazy_load_scroll"),
+        ("widgets", "ListView", "set_on_lazy_load_scroll"),
+        ("widgets", "ListView", "with_on_column_click"),
+        ("widgets", "ListView", "set_on_column_click"),
+        ("widgets", "ListView", "with_on_row_click"),
+        ("widgets", "ListView", "set_on_row_click"),
+        ("widgets", "ListView", "with_on_row_select"),
+        ("widgets", "ListView", "set_on_row_select"),
+        ("widgets", "ListView", "with_on_column_resize"),
+        ("widgets", "ListView", "set_on_column_resize"),
+        ("widgets", "DataGrid", "with_cell_provider"),
+        ("widgets", "DataGrid", "set_cell_provider"),
+        ("widgets", "DataGrid", "with_cell_style"),
+        ("widgets", "DataGrid", "set_cell_style"),
+        ("widgets", "DataGrid", "with_on_select"),
+        ("widgets", "DataGrid", "set_on_select"),
+        ("widgets", "DataGrid", "with_on_scroll"),
+        ("widgets", "DataGrid", "set_on_scroll"),
+        ("widgets", "DataGrid", "with_on_cell_edit"),
+        ("widgets", "DataGrid", "set_on_cell_edit"),
+        ("widgets", "DataGrid", "with_on_column_resize"),
+        ("widgets", "DataGrid", "set_on_column_resize"),
+        ("widgets", "DataGrid", "with_on_row_resize"),
+        ("widgets", "DataGrid", "set_on_row_resize"),
+        ("widgets", "DataGrid", "with_on_copy_range"),
+        ("widgets", "DataGrid", "set_on_copy_range"),
+        ("widgets", "DataGrid", "with_on_clear_range"),
+        ("widgets", "DataGrid", "set_on_clear_range"),
+        ("widgets", "DatePicker", "with_on_date_change"),
+        ("widgets", "DatePicker", "set_on_date_change"),
+        ("widgets", "TimePicker", "with_on_time_change"),
+        ("widgets", "TimePicker", "set_on_time_change"),
+        ("widgets", "DateTimePicker", "with_on_date_time_change"),
+        ("widgets", "DateTimePicker", "set_on_date_time_change"),
+        ("widgets", "Dialog", "with_on_close"),
+        ("widgets", "Dialog", "set_on_close"),
+        ("widgets", "Splitter", "with_on_resize"),
+        ("widgets", "Splitter", "set_on_resize"),
 
         # unnecessary due to Python string wrappers
         ("str", "String", "as_refstr"),
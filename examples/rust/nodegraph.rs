@@ -13,6 +13,9 @@ struct MyNodeGraph {
     input_output_types: BTreeMap<InputOutputTypeId, InputOutputInfo>,
     nodes: BTreeMap<NodeGraphNodeId, MyNode>,
     offset: LogicalPosition,
+    scale_factor: f32,
+    selected_nodes: Vec<NodeGraphNodeId>,
+    history: NodeGraphHistory,
 }
 
 impl Default for MyNodeGraph {
@@ -22,6 +25,12 @@ impl Default for MyNodeGraph {
             input_output_types: Self::get_available_input_output_types(),
             offset: LogicalPosition { x: 0.0, y: 0.0 },
             nodes: BTreeMap::new(),
+            scale_factor: 0.6,
+            selected_nodes: Vec::new(),
+            history: NodeGraphHistory {
+                undo: Vec::new().into(),
+                redo: Vec::new().into(),
+            },
         }
     }
 }
@@ -241,10 +250,24 @@ fn translate_node_graph(ng: &MyNodeGraph, data: RefAny) -> azul::widgets::NodeGr
             on_node_field_edited: Some(NodeGraphOnNodeFieldEdited {
                 data: data.clone(),
                 callback: NodeGraphOnNodeFieldEditedCallback { cb: userfunc_on_node_field_edited }
-            }).into()
+            }).into(),
+            on_node_graph_zoomed: Some(NodeGraphOnNodeGraphZoomed {
+                data: data.clone(),
+                callback: NodeGraphOnNodeGraphZoomedCallback { cb: userfunc_on_node_graph_zoomed }
+            }).into(),
+            on_selection_changed: Some(NodeGraphOnSelectionChanged {
+                data: data.clone(),
+                callback: NodeGraphOnSelectionChangedCallback { cb: userfunc_on_selection_changed }
+            }).into(),
+            on_history_changed: Some(NodeGraphOnHistoryChanged {
+                data: data.clone(),
+                callback: NodeGraphOnHistoryChangedCallback { cb: userfunc_on_history_changed }
+            }).into(),
         },
         style: NodeGraphStyle::Default,
-        scale_factor: 0.6,
+        scale_factor: ng.scale_factor,
+        selected_nodes: ng.selected_nodes.clone().into(),
+        history: ng.history.clone(),
     }
 }
 
@@ -397,7 +420,13 @@ fn userfunc_on_node_input_disconnected(
     .unwrap_or_default();
 
     for (output_node_id, output_index) in outputs {
-        nodegraph.nodes.get_mut(&output_node_id).unwrap().connect_out.remove(&output_index);
+        let connect_out = &mut nodegraph.nodes.get_mut(&output_node_id).unwrap().connect_out;
+        if let Some(inputs) = connect_out.get_mut(&output_index) {
+            inputs.remove(&input_node_id);
+            if inputs.is_empty() {
+                connect_out.remove(&output_index);
+            }
+        }
     }
 
     Update::RefreshDom
@@ -424,7 +453,13 @@ fn userfunc_on_node_output_disconnected(
     .unwrap_or_default();
 
     for (input_node_id, input_index) in inputs {
-        nodegraph.nodes.get_mut(&input_node_id).unwrap().connect_in.remove(&input_index);
+        let connect_in = &mut nodegraph.nodes.get_mut(&input_node_id).unwrap().connect_in;
+        if let Some(outputs) = connect_in.get_mut(&input_index) {
+            outputs.remove(&output_node_id);
+            if outputs.is_empty() {
+                connect_in.remove(&input_index);
+            }
+        }
     }
 
     Update::RefreshDom
@@ -450,6 +485,60 @@ fn userfunc_on_node_field_edited(
     Update::DoNothing
 }
 
+extern "C"
+fn userfunc_on_node_graph_zoomed(
+    data: &mut RefAny,
+    info: &mut CallbackInfo,
+    new_scale_factor: f32,
+    new_offset: LogicalPosition,
+) -> Update {
+
+    let mut nodegraph = match data.downcast_mut::<MyNodeGraph>() {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+
+    nodegraph.scale_factor = new_scale_factor;
+    nodegraph.offset = new_offset;
+
+    Update::DoNothing
+}
+
+extern "C"
+fn userfunc_on_selection_changed(
+    data: &mut RefAny,
+    info: &mut CallbackInfo,
+    selected_nodes: NodeGraphNodeIdVec,
+) -> Update {
+
+    let mut nodegraph = match data.downcast_mut::<MyNodeGraph>() {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+
+    nodegraph.selected_nodes = selected_nodes.as_ref().to_vec();
+
+    Update::DoNothing
+}
+
+extern "C"
+fn userfunc_on_history_changed(
+    data: &mut RefAny,
+    info: &mut CallbackInfo,
+    new_history: NodeGraphHistory,
+) -> Update {
+
+    let mut nodegraph = match data.downcast_mut::<MyNodeGraph>() {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+
+    // store the undo / redo stacks, so that they survive a DOM refresh
+    nodegraph.history = new_history;
+
+    Update::DoNothing
+}
+
 extern "C" fn layout_window(data: &mut RefAny, _: &mut LayoutCallbackInfo) -> StyledDom {
     let data_clone = data.clone();
 
@@ -1,25 +1,169 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+use std::collections::BTreeMap;
 use azul::prelude::*;
-use azul::widgets::{
-    TableView, TableViewState,
-    TableCellIndex, TableCellSelection
-};
+use azul::widgets::*;
+use azul::str::String as AzString;
+use azul::vec::F32Vec;
+use azul::option::OptionDataGridRange;
 
+#[derive(Default)]
 struct TableDemo {
-    // cells: BTreeMap<TableCell, String>,
+    cells: BTreeMap<(usize, usize), std::string::String>,
+    column_widths: Vec<f32>,
+    row_heights: Vec<f32>,
+    first_visible_row: usize,
+    first_visible_column: usize,
+    selection: Option<DataGridRange>,
 }
 
 extern "C" fn layout(data: &mut RefAny, _: &mut LayoutCallbackInfo) -> StyledDom {
 
-    let mut table_view_state = TableViewState::default();
-    table_view_state.set_cell_content(TableCellIndex { row: 2, column: 2 }, "Hello World");
-    table_view_state.set_selection(Some(TableCellSelection::from(3, 4).to(3, 4)));
+    let (column_widths, row_heights, first_visible_row, first_visible_column, selection) = match data.downcast_ref::<TableDemo>() {
+        Some(s) => (s.column_widths.clone(), s.row_heights.clone(), s.first_visible_row, s.first_visible_column, s.selection),
+        None => return StyledDom::default(),
+    };
 
-    TableView::new(table_view_state).dom().style(Css::empty())
+    Dom::body()
+    .with_inline_style("padding: 5px")
+    .with_child(
+        DataGrid::new(1_000_000, 1_000)
+        .with_column_widths(F32Vec::from(column_widths))
+        .with_row_heights(F32Vec::from(row_heights))
+        .with_scroll_position(first_visible_row, first_visible_column)
+        .with_selection(OptionDataGridRange::from(selection))
+        .with_cell_provider(data.clone(), get_cell)
+        .with_on_select(data.clone(), on_select)
+        .with_on_scroll(data.clone(), on_scroll)
+        .with_on_cell_edit(data.clone(), on_cell_edit)
+        .with_on_column_resize(data.clone(), on_column_resize)
+        .with_on_row_resize(data.clone(), on_row_resize)
+        .with_on_copy_range(data.clone(), on_copy_range)
+        .with_on_clear_range(data.clone(), on_clear_range)
+        .dom()
+    )
+    .style(Css::empty())
+}
+
+extern "C" fn get_cell(data: &mut RefAny, row: usize, column: usize) -> AzString {
+    match data.downcast_ref::<TableDemo>() {
+        Some(s) => s.cells.get(&(row, column)).cloned().unwrap_or_default().into(),
+        None => AzString::from_const_str(""),
+    }
+}
+
+extern "C" fn on_select(data: &mut RefAny, _: &mut CallbackInfo, selection: DataGridRange) -> Update {
+    match data.downcast_mut::<TableDemo>() {
+        Some(mut s) => { s.selection = Some(selection); Update::RefreshDom },
+        None => Update::DoNothing,
+    }
+}
+
+extern "C" fn on_scroll(data: &mut RefAny, _: &mut CallbackInfo, first_visible_row: usize, first_visible_column: usize) -> Update {
+    match data.downcast_mut::<TableDemo>() {
+        Some(mut s) => {
+            s.first_visible_row = first_visible_row;
+            s.first_visible_column = first_visible_column;
+            Update::RefreshDom
+        },
+        None => Update::DoNothing,
+    }
+}
+
+extern "C" fn on_cell_edit(data: &mut RefAny, _: &mut CallbackInfo, row: usize, column: usize, new_text: AzString) -> Update {
+    match data.downcast_mut::<TableDemo>() {
+        Some(mut s) => {
+            if new_text.as_str().is_empty() {
+                s.cells.remove(&(row, column));
+            } else {
+                s.cells.insert((row, column), new_text.as_str().to_string());
+            }
+            Update::RefreshDom
+        },
+        None => Update::DoNothing,
+    }
+}
+
+// only the non-empty cells are stored, so copying / clearing a range
+// (e.g. after Ctrl+A) only has to look at the cells in the BTreeMap
+fn non_empty_cells(s: &TableDemo, range: DataGridRange) -> Vec<(usize, usize)> {
+    let top_left = range.get_top_left();
+    let bottom_right = range.get_bottom_right();
+    s.cells.range((top_left.row, 0)..=(bottom_right.row, usize::MAX))
+    .map(|(k, _)| *k)
+    .filter(|(_, column)| *column >= top_left.column && *column <= bottom_right.column)
+    .collect()
+}
+
+extern "C" fn on_copy_range(data: &mut RefAny, range: DataGridRange) -> AzString {
+    let s = match data.downcast_ref::<TableDemo>() {
+        Some(s) => s,
+        None => return AzString::from_const_str(""),
+    };
+
+    // only copy the rows / columns up to the last non-empty cell
+    let top_left = range.get_top_left();
+    let cells = non_empty_cells(&s, range);
+    let last_row = match cells.iter().map(|(row, _)| *row).max() { Some(r) => r, None => return AzString::from_const_str("") };
+    let last_column = cells.iter().map(|(_, column)| *column).max().unwrap_or(top_left.column);
+
+    let mut tsv = std::string::String::new();
+    for row in top_left.row..=last_row {
+        let line = (top_left.column..=last_column)
+            .map(|column| s.cells.get(&(row, column)).map(|t| quote_tsv(t)).unwrap_or_default())
+            .collect::<Vec<_>>();
+        tsv.push_str(&line.join("\t"));
+        tsv.push('\n');
+    }
+    tsv.into()
+}
+
+fn quote_tsv(text: &str) -> std::string::String {
+    if text.contains(|c| c == '\t' || c == '\n' || c == '"') {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+extern "C" fn on_clear_range(data: &mut RefAny, _: &mut CallbackInfo, range: DataGridRange) -> Update {
+    match data.downcast_mut::<TableDemo>() {
+        Some(mut s) => {
+            for cell in non_empty_cells(&s, range) {
+                s.cells.remove(&cell);
+            }
+            Update::RefreshDom
+        },
+        None => Update::DoNothing,
+    }
+}
+
+extern "C" fn on_column_resize(data: &mut RefAny, _: &mut CallbackInfo, column: usize, new_width: f32) -> Update {
+    match data.downcast_mut::<TableDemo>() {
+        Some(mut s) => { set_size(&mut s.column_widths, column, new_width, 80.0); Update::RefreshDom },
+        None => Update::DoNothing,
+    }
+}
+
+extern "C" fn on_row_resize(data: &mut RefAny, _: &mut CallbackInfo, row: usize, new_height: f32) -> Update {
+    match data.downcast_mut::<TableDemo>() {
+        Some(mut s) => { set_size(&mut s.row_heights, row, new_height, 20.0); Update::RefreshDom },
+        None => Update::DoNothing,
+    }
+}
+
+fn set_size(sizes: &mut Vec<f32>, index: usize, size: f32, default: f32) {
+    if sizes.len() <= index {
+        sizes.resize(index + 1, default);
+    }
+    sizes[index] = size;
 }
 
 fn main() {
-    let app = App::new(RefAny::new(TableDemo { }), AppConfig::new(LayoutSolver::Default));
+    let mut data = TableDemo::default();
+    data.cells.insert((2, 2), "Hello World".into());
+    data.selection = Some(DataGridRange::new(3, 4));
+
+    let app = App::new(RefAny::new(data), AppConfig::new(LayoutSolver::Default));
     app.run(WindowCreateOptions::new(layout));
 }
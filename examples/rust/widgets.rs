@@ -29,6 +29,13 @@ extern "C" fn layout(data: &mut RefAny, _: &mut LayoutCallbackInfo) -> StyledDom
             MenuItem::String(StringMenuItem::new("Submenu Item 1..."))
         ]))
     ]))
+    .with_keyboard_shortcuts(vec![
+        KeyboardShortcut::new(
+            VirtualKeyCodeCombo { keys: vec![VirtualKeyCode::LControl, VirtualKeyCode::P].into() },
+            data.clone(),
+            enable_disable_padding,
+        )
+    ])
     .with_inline_style(if enable_padding {
         "padding: 10px"
     } else {
@@ -47,7 +54,8 @@ extern "C" fn layout(data: &mut RefAny, _: &mut LayoutCallbackInfo) -> StyledDom
                     Button::new(text)
                     .with_on_click(data.clone(), enable_disable_padding)
                     .dom()
-                    .with_inline_style("margin-bottom: 5px;"),
+                    .with_inline_style("margin-bottom: 5px;")
+                    .with_tooltip(Tooltip::text("Toggles the padding of the frame")),
 
                     CheckBox::new(enable_padding)
                     .with_on_toggle(data.clone(), enable_disable_padding_check)
@@ -84,6 +92,7 @@ extern "C" fn layout(data: &mut RefAny, _: &mut LayoutCallbackInfo) -> StyledDom
                             ListViewRow {
                                 cells: vec![Dom::text(format!("{}", i))].into(),
                                 height: None.into(),
+                                is_selected: false,
                             }
                         }).collect::<Vec<_>>())
                         .dom(),